@@ -13,6 +13,7 @@ mod claude;
 pub(crate) mod cli;
 mod codex;
 mod gemini;
+pub(crate) mod harness;
 mod instruction;
 mod prompt;
 mod provider;
@@ -29,10 +30,11 @@ pub use backend::{AgentBackend, AgentBackendError, AgentTransport, BuildCommandR
 pub use instruction::normalize_instruction_conversation_id;
 pub(crate) use instruction::{InstructionDeliveryMode, plan_app_server_instruction_delivery};
 pub use prompt::diff_fence;
-pub(crate) use prompt::{PromptPreparationRequest, prepare_prompt_text};
+pub(crate) use prompt::{PromptPreparationRequest, build_cli_prompt_text, prepare_prompt_text};
 pub(crate) use provider::{
     build_command_stdin_payload, is_app_server_thought_chunk, parse_response,
-    parse_stream_output_line, parse_turn_response, protocol_schema_instruction_mode,
+    parse_stream_activity_line, parse_stream_output_line, parse_turn_response,
+    protocol_schema_instruction_mode,
 };
 pub use provider::{create_app_server_client, create_backend, transport_mode};
 pub(crate) use response_parser::{
@@ -9,6 +9,7 @@ mod client;
 mod codex;
 mod command;
 mod gemini;
+mod session_path;
 mod stdio_transport;
 
 pub(crate) use antigravity::RealAntigravityClient;
@@ -1,5 +1,7 @@
 //! Antigravity persistent-runtime client orchestration.
 
+use std::sync::Arc;
+
 use ag_protocol::{ProtocolSchemaInstructionMode, TurnPrompt};
 use tokio::sync::mpsc;
 
@@ -10,6 +12,7 @@ use crate::app_server::{
     AppServerError, AppServerFuture, AppServerStreamEvent, AppServerTurnRequest,
     BorrowedAppServerFuture,
 };
+use crate::channel::ToolApprovalHandler;
 use crate::model::agent::{AgentKind, ReasoningLevel};
 use crate::model::session::SpeedMode;
 use crate::{agent, app_server_transport};
@@ -48,6 +51,7 @@ impl RuntimeClientProvider for AntigravityRuntimeProvider {
         prompt: &'scope TurnPrompt,
         _reasoning_level: ReasoningLevel,
         _speed_mode: SpeedMode,
+        _tool_approval: Option<Arc<dyn ToolApprovalHandler>>,
         stream_tx: mpsc::UnboundedSender<AppServerStreamEvent>,
     ) -> BorrowedAppServerFuture<'scope, Result<(String, u64, u64), AppServerError>> {
         Box::pin(async move {
@@ -134,6 +138,7 @@ mod tests {
             request_kind: crate::channel::AgentRequestKind::SessionResume,
             session_id: "session-1".to_string(),
             speed_mode: SpeedMode::default(),
+            tool_approval: None,
         }
     }
 
@@ -215,6 +220,7 @@ mod tests {
             &request.prompt,
             ReasoningLevel::Low,
             SpeedMode::Fast,
+            None,
             stream_tx,
         )
         .await;
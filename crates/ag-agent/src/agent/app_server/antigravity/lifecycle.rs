@@ -211,6 +211,9 @@ async fn run_turn_with_timeout<Transport: AppServerRuntimeTransport>(
                 if let Some(event) = stream_parser::stream_event(step_update) {
                     let _ = stream_tx.send(event);
                 }
+                if let Some(activity) = stream_parser::step_activity(step_update) {
+                    let _ = stream_tx.send(AppServerStreamEvent::Activity(activity));
+                }
 
                 continue;
             }
@@ -285,6 +288,7 @@ mod tests {
             request_kind: crate::channel::AgentRequestKind::SessionStart,
             session_id: "session-1".to_string(),
             speed_mode: SpeedMode::default(),
+            tool_approval: None,
         }
     }
 
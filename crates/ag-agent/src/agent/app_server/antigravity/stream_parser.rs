@@ -3,6 +3,7 @@
 use serde_json::Value;
 
 use crate::app_server::AppServerStreamEvent;
+use crate::model::activity::TurnActivity;
 
 /// Returns the provider conversation id carried by any supported event shape.
 pub(super) fn conversation_id(payload: &Value) -> Option<&str> {
@@ -114,6 +115,53 @@ pub(super) fn stream_event(step_update: &Value) -> Option<AppServerStreamEvent>
     Some(AppServerStreamEvent::ProgressUpdate(progress))
 }
 
+/// Maps one tool step update to structured turn activity.
+///
+/// Active tool steps report a started tool; `DONE` and `ERROR` steps report
+/// the finished tool so hosts can pair both by step id.
+pub(super) fn step_activity(step_update: &Value) -> Option<TurnActivity> {
+    let step_type = step_update.get("step_type").and_then(Value::as_str)?;
+    if !step_type.eq_ignore_ascii_case("tool") {
+        return None;
+    }
+
+    let state = step_update
+        .get("state")
+        .and_then(Value::as_str)?
+        .to_ascii_lowercase();
+    let call_id = step_update
+        .get("step_id")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    let command = step_update
+        .get("command")
+        .and_then(Value::as_str)
+        .filter(|command| !command.trim().is_empty())
+        .map(str::to_string);
+    let tool = step_update
+        .get("tool_name")
+        .and_then(Value::as_str)
+        .filter(|tool_name| !tool_name.trim().is_empty())
+        .unwrap_or("tool")
+        .to_string();
+
+    match state.as_str() {
+        "active" => Some(TurnActivity::ToolStarted {
+            call_id,
+            command,
+            tool,
+        }),
+        "done" | "error" | "failed" => Some(TurnActivity::ToolFinished {
+            call_id,
+            command,
+            exit_code: None,
+            succeeded: state == "done",
+            tool,
+        }),
+        _ => None,
+    }
+}
+
 fn value_text(value: &Value) -> Option<String> {
     if let Some(text) = value.as_str().filter(|text| !text.trim().is_empty()) {
         return Some(text.to_string());
@@ -331,4 +379,44 @@ mod tests {
         assert_eq!(stream_event(&unknown), None);
         assert_eq!(stream_event(&serde_json::json!({})), None);
     }
+
+    #[test]
+    fn tool_steps_map_to_started_and_finished_activity() {
+        // Arrange
+        let active = serde_json::json!({
+            "step_type": "tool",
+            "state": "ACTIVE",
+            "step_id": "step-1",
+            "tool_name": "shell",
+            "command": "cargo test",
+        });
+        let failed = serde_json::json!({
+            "step_type": "tool",
+            "state": "ERROR",
+            "step_id": "step-1",
+            "tool_name": "shell",
+        });
+        let reasoning = serde_json::json!({"step_type": "thought", "state": "active"});
+
+        // Act / Assert
+        assert_eq!(
+            step_activity(&active),
+            Some(TurnActivity::ToolStarted {
+                call_id: Some("step-1".to_string()),
+                command: Some("cargo test".to_string()),
+                tool: "shell".to_string(),
+            })
+        );
+        assert_eq!(
+            step_activity(&failed),
+            Some(TurnActivity::ToolFinished {
+                call_id: Some("step-1".to_string()),
+                command: None,
+                exit_code: None,
+                succeeded: false,
+                tool: "shell".to_string(),
+            })
+        );
+        assert_eq!(step_activity(&reasoning), None);
+    }
 }
@@ -1,6 +1,7 @@
 //! Shared app-server runtime client scaffold.
 
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 use ag_protocol::{ProtocolSchemaInstructionMode, TurnPrompt};
 use tokio::sync::mpsc;
@@ -9,6 +10,7 @@ use crate::app_server::{
     self, AppServerClient, AppServerError, AppServerFuture, AppServerSessionRegistry,
     AppServerStreamEvent, AppServerTurnRequest, AppServerTurnResponse, BorrowedAppServerFuture,
 };
+use crate::channel::{ToolApprovalDecision, ToolApprovalHandler, ToolApprovalRequest};
 use crate::model::agent::ReasoningLevel;
 use crate::model::session::SpeedMode;
 
@@ -32,15 +34,32 @@ pub(crate) trait RuntimeClientProvider: Send + Sync + 'static {
     ) -> AppServerFuture<Result<Self::Runtime, AppServerError>>;
 
     /// Runs one turn against an already-started provider runtime.
+    ///
+    /// `tool_approval` answers provider approval requests in ask-mode turns.
     fn run_turn<'scope>(
         runtime: &'scope mut Self::Runtime,
         prompt: &'scope TurnPrompt,
         reasoning_level: ReasoningLevel,
         speed_mode: SpeedMode,
+        tool_approval: Option<Arc<dyn ToolApprovalHandler>>,
         stream_tx: mpsc::UnboundedSender<AppServerStreamEvent>,
     ) -> BorrowedAppServerFuture<'scope, Result<(String, u64, u64), AppServerError>>;
 }
 
+/// Waits for the host decision on one ask-mode approval request.
+///
+/// Turns without a host approval port deny the request instead of letting the
+/// gated action run unattended.
+pub(super) async fn request_tool_approval(
+    tool_approval: Option<&dyn ToolApprovalHandler>,
+    request: &ToolApprovalRequest,
+) -> ToolApprovalDecision {
+    match tool_approval {
+        Some(tool_approval) => tool_approval.request_approval(request.clone()).await,
+        None => ToolApprovalDecision::Deny,
+    }
+}
+
 /// Runtime query and shutdown hooks shared by provider clients.
 pub(crate) trait RuntimeClientRuntime: Send {
     /// Returns whether the runtime can serve one incoming request.
@@ -83,6 +102,7 @@ impl<Provider: RuntimeClientProvider> ProviderRuntimeClient<Provider> {
         let stream_tx = stream_tx.clone();
         let reasoning_level = request.reasoning_level;
         let speed_mode = request.speed_mode;
+        let tool_approval = request.tool_approval.clone();
 
         app_server::run_turn_with_restart_retry(
             sessions,
@@ -102,8 +122,16 @@ impl<Provider: RuntimeClientProvider> ProviderRuntimeClient<Provider> {
             },
             move |runtime, prompt| {
                 let stream_tx = stream_tx.clone();
-
-                Provider::run_turn(runtime, prompt, reasoning_level, speed_mode, stream_tx)
+                let tool_approval = tool_approval.clone();
+
+                Provider::run_turn(
+                    runtime,
+                    prompt,
+                    reasoning_level,
+                    speed_mode,
+                    tool_approval,
+                    stream_tx,
+                )
             },
             RuntimeClientRuntime::shutdown_runtime,
         )
@@ -216,6 +244,7 @@ mod tests {
             _prompt: &'scope TurnPrompt,
             _reasoning_level: ReasoningLevel,
             _speed_mode: SpeedMode,
+            _tool_approval: Option<Arc<dyn ToolApprovalHandler>>,
             _stream_tx: mpsc::UnboundedSender<AppServerStreamEvent>,
         ) -> BorrowedAppServerFuture<'scope, Result<(String, u64, u64), AppServerError>> {
             Box::pin(async {
@@ -281,6 +310,7 @@ mod tests {
             replay_transcript: None,
             session_id: "session-1".to_string(),
             speed_mode: SpeedMode::default(),
+            tool_approval: None,
         };
         let (stream_tx, _stream_rx) = mpsc::unbounded_channel();
 
@@ -1,5 +1,7 @@
 //! Codex app-server client orchestration.
 
+use std::sync::Arc;
+
 use ag_protocol::{ProtocolSchemaInstructionMode, TurnPrompt};
 use tokio::sync::mpsc;
 
@@ -10,6 +12,7 @@ use crate::app_server::{
     AppServerError, AppServerFuture, AppServerStreamEvent, AppServerTurnRequest,
     BorrowedAppServerFuture,
 };
+use crate::channel::ToolApprovalHandler;
 use crate::model::agent::{AgentKind, ReasoningLevel};
 use crate::model::session::SpeedMode;
 use crate::{agent, app_server_transport};
@@ -55,6 +58,7 @@ impl RuntimeClientProvider for CodexRuntimeProvider {
         prompt: &'scope TurnPrompt,
         reasoning_level: ReasoningLevel,
         speed_mode: SpeedMode,
+        tool_approval: Option<Arc<dyn ToolApprovalHandler>>,
         stream_tx: mpsc::UnboundedSender<AppServerStreamEvent>,
     ) -> BorrowedAppServerFuture<'scope, Result<(String, u64, u64), AppServerError>> {
         Box::pin(async move {
@@ -64,6 +68,7 @@ impl RuntimeClientProvider for CodexRuntimeProvider {
                 prompt,
                 reasoning_level,
                 speed_mode,
+                tool_approval.as_deref(),
                 stream_tx,
             )
             .await
@@ -124,6 +129,7 @@ mod tests {
     use crate::agent::app_server::codex::{
         MockCodexRuntimeTransport, lifecycle, policy, stream_parser, usage,
     };
+    use crate::channel::{ToolApprovalDecision, ToolApprovalRequest};
     use crate::model::agent::{AgentModel, ReasoningLevel};
 
     /// Creates runtime state for one synthetic Codex session path.
@@ -194,6 +200,7 @@ mod tests {
             request_kind: crate::channel::AgentRequestKind::SessionResume,
             session_id: "session-1".to_string(),
             speed_mode: SpeedMode::default(),
+            tool_approval: None,
         };
 
         // Act
@@ -220,6 +227,7 @@ mod tests {
             &prompt,
             ReasoningLevel::default(),
             SpeedMode::Fast,
+            None,
             stream_tx,
         )
         .await;
@@ -547,6 +555,41 @@ mod tests {
                 speed_mode: SpeedMode::default(),
                 stream_tx,
                 thread_id: "thread-1",
+                tool_approval: None,
+                turn_timeout: app_server_transport::TURN_TIMEOUT,
+            },
+        )
+        .await;
+
+        // Assert
+        assert_eq!(result.expect("turn should complete"), (String::new(), 0, 0));
+    }
+
+    #[tokio::test]
+    async fn execute_turn_event_loop_waits_for_ask_mode_command_approval() {
+        // Arrange
+        let folder = tempdir().expect("temporary folder should be created");
+        let turn_start_id = Arc::new(Mutex::new(None));
+        let mut transport = MockCodexRuntimeTransport::new();
+        let mut sequence = Sequence::new();
+        let (stream_tx, _stream_rx) = mpsc::unbounded_channel();
+        let tool_approval = RecordingToolApproval::new(ToolApprovalDecision::AllowForSession);
+
+        expect_command_approval_request_turn(&mut transport, &mut sequence, turn_start_id);
+
+        // Act
+        let result = lifecycle::execute_turn_event_loop(
+            &mut transport,
+            lifecycle::CodexTurnEventLoopInput {
+                folder: folder.path(),
+                model: AgentModel::Gpt56Sol.as_str(),
+                permission_mode: crate::model::permission::PermissionMode::Ask,
+                prompt: "Publish the crate".into(),
+                reasoning_level: ReasoningLevel::default(),
+                speed_mode: SpeedMode::default(),
+                stream_tx,
+                thread_id: "thread-1",
+                tool_approval: Some(&tool_approval),
                 turn_timeout: app_server_transport::TURN_TIMEOUT,
             },
         )
@@ -554,6 +597,10 @@ mod tests {
 
         // Assert
         assert_eq!(result.expect("turn should complete"), (String::new(), 0, 0));
+        assert_eq!(
+            tool_approval.requested_summaries(),
+            vec!["cargo publish".to_string()]
+        );
     }
 
     #[tokio::test]
@@ -585,6 +632,7 @@ mod tests {
                 speed_mode: SpeedMode::default(),
                 stream_tx,
                 thread_id: "thread-1",
+                tool_approval: None,
                 turn_timeout: app_server_transport::TURN_TIMEOUT,
             },
         )
@@ -689,6 +737,141 @@ mod tests {
             });
     }
 
+    /// Host approval port that records requests and returns one fixed
+    /// decision.
+    #[derive(Debug)]
+    struct RecordingToolApproval {
+        decision: ToolApprovalDecision,
+        requests: Mutex<Vec<ToolApprovalRequest>>,
+    }
+
+    impl RecordingToolApproval {
+        /// Creates a recorder that answers every request with `decision`.
+        fn new(decision: ToolApprovalDecision) -> Self {
+            Self {
+                decision,
+                requests: Mutex::new(Vec::new()),
+            }
+        }
+
+        /// Returns the summaries of every recorded request in order.
+        fn requested_summaries(&self) -> Vec<String> {
+            self.requests
+                .lock()
+                .expect("approval requests mutex should lock")
+                .iter()
+                .map(|request| request.summary.clone())
+                .collect()
+        }
+    }
+
+    impl ToolApprovalHandler for RecordingToolApproval {
+        fn request_approval(
+            &self,
+            request: ToolApprovalRequest,
+        ) -> crate::channel::AgentFuture<ToolApprovalDecision> {
+            self.requests
+                .lock()
+                .expect("approval requests mutex should lock")
+                .push(request);
+            let decision = self.decision;
+
+            Box::pin(async move { decision })
+        }
+    }
+
+    /// Expects one command approval request to receive the host decision
+    /// before turn completion.
+    fn expect_command_approval_request_turn(
+        transport: &mut MockCodexRuntimeTransport,
+        sequence: &mut Sequence,
+        turn_start_id: Arc<Mutex<Option<String>>>,
+    ) {
+        transport
+            .expect_write_json_line()
+            .times(1)
+            .in_sequence(sequence)
+            .withf(|payload| payload.get("method").and_then(Value::as_str) == Some("turn/start"))
+            .returning({
+                let turn_start_id = Arc::clone(&turn_start_id);
+
+                move |payload| {
+                    remember_request_id(&turn_start_id, &payload);
+
+                    Box::pin(async { Ok(()) })
+                }
+            });
+        transport
+            .expect_next_stdout()
+            .times(1)
+            .in_sequence(sequence)
+            .return_once(move || {
+                let response_id = turn_start_id
+                    .lock()
+                    .expect("turn/start mutex should lock")
+                    .clone()
+                    .expect("turn/start id should be recorded");
+
+                Box::pin(async move {
+                    Ok(Some(
+                        serde_json::json!({
+                            "id": response_id,
+                            "result": {"turn": {"id": "turn-123"}}
+                        })
+                        .to_string(),
+                    ))
+                })
+            });
+        transport
+            .expect_next_stdout()
+            .times(1)
+            .in_sequence(sequence)
+            .return_once(|| {
+                Box::pin(async {
+                    Ok(Some(
+                        serde_json::json!({
+                            "id": "approval-1",
+                            "method": "item/commandExecution/requestApproval",
+                            "params": {"command": "cargo publish"}
+                        })
+                        .to_string(),
+                    ))
+                })
+            });
+        transport
+            .expect_write_json_line()
+            .times(1)
+            .in_sequence(sequence)
+            .withf(|payload| {
+                payload
+                    == &serde_json::json!({
+                        "id": "approval-1",
+                        "result": {"decision": "acceptForSession"}
+                    })
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
+        transport
+            .expect_next_stdout()
+            .times(1)
+            .in_sequence(sequence)
+            .return_once(|| {
+                Box::pin(async {
+                    Ok(Some(
+                        serde_json::json!({
+                            "method": "turn/completed",
+                            "params": {
+                                "turn": {
+                                    "id": "turn-123",
+                                    "status": "completed"
+                                }
+                            }
+                        })
+                        .to_string(),
+                    ))
+                })
+            });
+    }
+
     /// Expects a user-input request to receive an empty response before turn
     /// completion.
     fn expect_user_input_request_turn(
@@ -808,6 +991,7 @@ mod tests {
             "Implement the task",
             ReasoningLevel::default(),
             SpeedMode::default(),
+            None,
             stream_tx,
         )
         .await;
@@ -960,6 +1144,7 @@ mod tests {
             "Implement the task",
             ReasoningLevel::default(),
             SpeedMode::Fast,
+            None,
             stream_tx,
         )
         .await;
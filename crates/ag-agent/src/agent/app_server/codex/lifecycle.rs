@@ -9,11 +9,13 @@ use ag_protocol::{
 use serde_json::Value;
 use tokio::sync::mpsc;
 
+use super::super::client;
 use super::super::stdio_transport::{AppServerRuntimeTransport, AppServerStdioTransport};
 use super::{policy, stream_parser, usage};
 use crate::agent;
 use crate::app_server::{AppServerError, AppServerStreamEvent, AppServerTurnRequest};
 use crate::app_server_transport::{self, extract_json_error_message, response_id_matches};
+use crate::channel::ToolApprovalHandler;
 use crate::model::agent::{AgentKind, ReasoningLevel};
 use crate::model::permission::PermissionMode;
 use crate::model::session::SpeedMode;
@@ -356,6 +358,7 @@ pub(super) async fn run_turn_with_runtime<Transport: AppServerRuntimeTransport>(
     prompt: impl Into<TurnPrompt>,
     reasoning_level: ReasoningLevel,
     speed_mode: SpeedMode,
+    tool_approval: Option<&dyn ToolApprovalHandler>,
     stream_tx: mpsc::UnboundedSender<AppServerStreamEvent>,
 ) -> Result<(String, u64, u64), AppServerError> {
     let prompt = prompt.into();
@@ -379,6 +382,7 @@ pub(super) async fn run_turn_with_runtime<Transport: AppServerRuntimeTransport>(
             speed_mode,
             stream_tx: stream_tx.clone(),
             thread_id: &state.thread_id,
+            tool_approval,
             turn_timeout: app_server_transport::TURN_TIMEOUT,
         },
     )
@@ -408,6 +412,7 @@ pub(super) async fn run_turn_with_runtime<Transport: AppServerRuntimeTransport>(
                     speed_mode,
                     stream_tx,
                     thread_id: &state.thread_id,
+                    tool_approval,
                     turn_timeout: app_server_transport::TURN_TIMEOUT,
                 },
             )
@@ -512,6 +517,8 @@ pub(super) struct CodexTurnEventLoopInput<'a> {
     pub(super) stream_tx: mpsc::UnboundedSender<AppServerStreamEvent>,
     /// Runtime thread id for the active provider conversation.
     pub(super) thread_id: &'a str,
+    /// Host port that answers command approvals in ask-mode turns.
+    pub(super) tool_approval: Option<&'a dyn ToolApprovalHandler>,
     /// Maximum time to wait for the turn completion event.
     pub(super) turn_timeout: Duration,
 }
@@ -525,6 +532,7 @@ pub(super) async fn execute_turn_event_loop<Transport: AppServerRuntimeTransport
     let turn_start_id = write_turn_start_request(transport, &input).await?;
     let folder = input.folder;
     let permission_mode = input.permission_mode;
+    let tool_approval = input.tool_approval;
     let turn_timeout = input.turn_timeout;
     let mut state = CodexTurnEventLoopState::new(input.stream_tx);
 
@@ -538,6 +546,7 @@ pub(super) async fn execute_turn_event_loop<Transport: AppServerRuntimeTransport
                 .process_response(
                     transport,
                     permission_mode,
+                    tool_approval,
                     folder,
                     &turn_start_id,
                     response_value,
@@ -579,10 +588,14 @@ impl CodexTurnEventLoopState {
 
     /// Processes one app-server response and returns a completed turn when
     /// ready.
+    ///
+    /// Ask-mode approval requests block here until `tool_approval` answers,
+    /// so the turn does not advance past the gated action.
     async fn process_response<Transport: AppServerRuntimeTransport>(
         &mut self,
         transport: &mut Transport,
         permission_mode: PermissionMode,
+        tool_approval: Option<&dyn ToolApprovalHandler>,
         folder: &Path,
         turn_start_id: &str,
         response_value: Value,
@@ -593,6 +606,18 @@ impl CodexTurnEventLoopState {
             return Ok(None);
         }
 
+        if let Some(pending_approval) =
+            policy::pending_tool_approval(&response_value, permission_mode, folder)
+        {
+            let decision =
+                client::request_tool_approval(tool_approval, pending_approval.request()).await;
+            transport
+                .write_json_line(pending_approval.response(decision))
+                .await?;
+
+            return Ok(None);
+        }
+
         if let Some(server_request_response) =
             policy::build_server_request_response(&response_value, permission_mode, folder)
         {
@@ -866,8 +891,8 @@ fn update_active_turn_tracking_for_response(
     }
 }
 
-/// Streams progress updates plus assistant delta/completed items from one
-/// response.
+/// Streams progress updates, structured activity, and assistant
+/// delta/completed items from one response.
 fn stream_turn_content_from_response(
     response_value: &Value,
     stream_tx: &mpsc::UnboundedSender<AppServerStreamEvent>,
@@ -878,6 +903,10 @@ fn stream_turn_content_from_response(
         let _ = stream_tx.send(AppServerStreamEvent::ProgressUpdate(progress));
     }
 
+    for activity in stream_parser::extract_item_activity(response_value) {
+        let _ = stream_tx.send(AppServerStreamEvent::Activity(activity));
+    }
+
     if let Some(agent_message) = stream_parser::extract_agent_message_delta(response_value) {
         if let Some(phase) = agent_message.phase.as_deref() {
             emit_phase_progress_update(stream_tx, active_phase, phase);
@@ -1011,6 +1040,7 @@ mod tests {
             reasoning_level: ReasoningLevel::High,
             session_id: "session-1".to_string(),
             speed_mode: SpeedMode::default(),
+            tool_approval: None,
         };
 
         // Act
@@ -1044,6 +1074,7 @@ mod tests {
             reasoning_level: ReasoningLevel::High,
             session_id: "session-1".to_string(),
             speed_mode: SpeedMode::Fast,
+            tool_approval: None,
         };
 
         // Act
@@ -1251,6 +1282,96 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ask_mode_forwards_commands_and_keeps_session_local_edits_automatic() {
+        // Arrange
+        let folder = PathBuf::from("/tmp/agentty-codex-ask");
+        let command_request = serde_json::json!({
+            "id": "approval-1",
+            "method": "item/commandExecution/requestApproval",
+            "params": {"command": "cargo publish", "cwd": "/tmp/agentty-codex-ask"}
+        });
+        let legacy_command_request = serde_json::json!({
+            "id": "approval-2",
+            "method": "execCommandApproval",
+            "params": {"command": ["rm", "-rf", "target"], "reason": "Clean build output"}
+        });
+        let local_patch_request = serde_json::json!({
+            "id": "approval-3",
+            "method": "item/fileChange/requestApproval",
+            "params": {"changes": [{"path": "/tmp/agentty-codex-ask/src/lib.rs"}]}
+        });
+
+        // Act
+        let thread_payload = build_thread_start_payload(
+            &folder,
+            AgentModel::Gpt56Sol.as_str(),
+            PermissionMode::Ask,
+            ReasoningLevel::Medium,
+            SpeedMode::default(),
+            "thread-start-1",
+        );
+        let command_approval =
+            policy::pending_tool_approval(&command_request, PermissionMode::Ask, &folder)
+                .expect("command approval should wait for the user");
+        let legacy_command_approval =
+            policy::pending_tool_approval(&legacy_command_request, PermissionMode::Ask, &folder)
+                .expect("legacy command approval should wait for the user");
+        let local_patch_approval =
+            policy::pending_tool_approval(&local_patch_request, PermissionMode::Ask, &folder);
+        let local_patch_response = policy::build_server_request_response(
+            &local_patch_request,
+            PermissionMode::Ask,
+            &folder,
+        )
+        .expect("local patch response should be generated");
+        let auto_edit_approval =
+            policy::pending_tool_approval(&command_request, PermissionMode::AutoEdit, &folder);
+
+        // Assert
+        assert_eq!(
+            thread_payload.pointer("/params/approvalPolicy"),
+            Some(&Value::String("untrusted".to_string()))
+        );
+        assert_eq!(
+            command_approval.request(),
+            &crate::channel::ToolApprovalRequest {
+                detail: Some("in /tmp/agentty-codex-ask".to_string()),
+                kind: crate::channel::ToolApprovalKind::Command,
+                summary: "cargo publish".to_string(),
+            }
+        );
+        assert_eq!(
+            command_approval
+                .response(crate::channel::ToolApprovalDecision::AllowForSession)
+                .pointer("/result/decision"),
+            Some(&Value::String("acceptForSession".to_string()))
+        );
+        assert_eq!(
+            command_approval
+                .response(crate::channel::ToolApprovalDecision::Deny)
+                .pointer("/result/decision"),
+            Some(&Value::String("reject".to_string()))
+        );
+        assert_eq!(legacy_command_approval.request().summary, "rm -rf target");
+        assert_eq!(
+            legacy_command_approval.request().detail.as_deref(),
+            Some("Clean build output")
+        );
+        assert_eq!(
+            legacy_command_approval
+                .response(crate::channel::ToolApprovalDecision::AllowForSession)
+                .pointer("/result/decision"),
+            Some(&Value::String("approved_for_session".to_string()))
+        );
+        assert!(local_patch_approval.is_none());
+        assert_eq!(
+            local_patch_response.pointer("/result/decision"),
+            Some(&Value::String("accept".to_string()))
+        );
+        assert!(auto_edit_approval.is_none());
+    }
+
     #[test]
     fn build_turn_input_items_emits_single_text_item_when_no_attachments_present() {
         // Arrange
@@ -1,9 +1,11 @@
 //! Codex app-server policy mapping helpers.
 
-use std::path::{Component, Path, PathBuf};
+use std::path::Path;
 
 use serde_json::Value;
 
+use super::super::session_path::path_is_session_local;
+use crate::channel::{ToolApprovalDecision, ToolApprovalKind, ToolApprovalRequest};
 use crate::model::agent::{AgentKind, AgentModel, ReasoningLevel};
 use crate::model::permission::PermissionMode;
 
@@ -41,6 +43,24 @@ const AUTO_EDIT_POLICY: PermissionModePolicy = PermissionModePolicy {
     web_search_mode: "live",
 };
 
+/// Codex app-server policy for Agentty's interactive ask mode.
+///
+/// The `untrusted` approval policy makes Codex request approval before every
+/// command outside its known-safe read-only set, and Agentty forwards those
+/// requests to the user. Full access keeps approved commands from failing in a
+/// sandbox after the user already allowed them.
+const ASK_POLICY: PermissionModePolicy = PermissionModePolicy {
+    approval_policy: "untrusted",
+    legacy_pre_action_decision: "approved",
+    legacy_pre_action_rejection_decision: "denied",
+    pre_action_decision: "accept",
+    pre_action_rejection_decision: "reject",
+    thread_sandbox_mode: "danger-full-access",
+    turn_network_access: true,
+    turn_sandbox_type: "dangerFullAccess",
+    web_search_mode: "live",
+};
+
 /// Codex app-server policy for temporary repository research.
 ///
 /// The read-only sandbox denies filesystem writes and command network access.
@@ -93,6 +113,43 @@ impl PreActionApprovalKind {
     }
 }
 
+/// Pre-action request that waits for a user decision in
+/// [`PermissionMode::Ask`].
+pub(super) struct PendingToolApproval {
+    approval_kind: PreActionApprovalKind,
+    request: ToolApprovalRequest,
+    request_id: Value,
+}
+
+impl PendingToolApproval {
+    /// Returns the user-facing approval request.
+    pub(super) fn request(&self) -> &ToolApprovalRequest {
+        &self.request
+    }
+
+    /// Builds the JSON-RPC response that carries one user decision.
+    pub(super) fn response(&self, decision: ToolApprovalDecision) -> Value {
+        let is_legacy = self.approval_kind.is_legacy();
+        let decision = match decision {
+            ToolApprovalDecision::AllowOnce => {
+                pre_action_approval_decision(PermissionMode::Ask, &self.approval_kind)
+            }
+            ToolApprovalDecision::AllowForSession if is_legacy => "approved_for_session",
+            ToolApprovalDecision::AllowForSession => "acceptForSession",
+            ToolApprovalDecision::Deny => {
+                pre_action_rejection_decision(PermissionMode::Ask, &self.approval_kind)
+            }
+        };
+
+        serde_json::json!({
+            "id": self.request_id,
+            "result": {
+                "decision": decision
+            }
+        })
+    }
+}
+
 /// Proactive compaction threshold for Codex models with a 1.05M context window.
 ///
 /// GPT-5.5 and GPT-5.6 models reserve up to 128k tokens for output, leaving a
@@ -224,10 +281,98 @@ pub(super) fn build_server_request_response(
     }))
 }
 
+/// Returns the pre-action request that must wait for the user, when any.
+///
+/// Only ask-mode turns forward requests. Command approvals always reach the
+/// user, while file changes inside the session worktree keep their automatic
+/// approval so agents can still edit freely.
+pub(super) fn pending_tool_approval(
+    response_value: &Value,
+    permission_mode: PermissionMode,
+    session_folder: &Path,
+) -> Option<PendingToolApproval> {
+    if !permission_mode.asks_for_approval() {
+        return None;
+    }
+
+    let method = response_value.get("method")?.as_str()?;
+    let request_id = response_value.get("id")?.clone();
+    let approval_kind = PreActionApprovalKind::from_method(method)?;
+    if !approval_kind.is_command()
+        && approval_request_paths_are_session_local(response_value, session_folder)
+    {
+        return None;
+    }
+
+    Some(PendingToolApproval {
+        request: tool_approval_request(response_value, &approval_kind),
+        approval_kind,
+        request_id,
+    })
+}
+
+/// Builds the user-facing summary for one Codex pre-action request.
+fn tool_approval_request(
+    response_value: &Value,
+    approval_kind: &PreActionApprovalKind,
+) -> ToolApprovalRequest {
+    let params = response_value.get("params").unwrap_or(&Value::Null);
+    let reason = params
+        .get("reason")
+        .and_then(Value::as_str)
+        .map(ToString::to_string);
+    if approval_kind.is_command() {
+        let summary = command_text(params).unwrap_or_else(|| "Run a shell command".to_string());
+        let cwd = params
+            .get("cwd")
+            .and_then(Value::as_str)
+            .map(|cwd| format!("in {cwd}"));
+
+        return ToolApprovalRequest {
+            detail: reason.or(cwd),
+            kind: ToolApprovalKind::Command,
+            summary,
+        };
+    }
+
+    let mut candidate_paths = Vec::new();
+    collect_candidate_paths(params, None, &mut candidate_paths);
+    candidate_paths.dedup();
+    let summary = if candidate_paths.is_empty() {
+        "Edit files outside the session worktree".to_string()
+    } else {
+        format!("Edit {}", candidate_paths.join(", "))
+    };
+
+    ToolApprovalRequest {
+        detail: reason,
+        kind: ToolApprovalKind::FileChange,
+        summary,
+    }
+}
+
+/// Returns the command line carried by modern string or legacy argv payloads.
+fn command_text(params: &Value) -> Option<String> {
+    match params.get("command")? {
+        Value::String(command) => Some(command.clone()),
+        Value::Array(arguments) => {
+            let arguments = arguments
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>();
+
+            (!arguments.is_empty()).then(|| arguments.join(" "))
+        }
+        _ => None,
+    }
+}
+
 /// Returns the scoped decision for one Codex pre-action request.
 ///
 /// Agentty runs Codex in auto-edit mode with full access, so command approvals
-/// are accepted when Codex still emits a pre-action request. File-change
+/// are accepted when Codex still emits a pre-action request. Ask-mode requests
+/// that reach this point are session-local file changes, because
+/// [`pending_tool_approval`] forwards the rest to the user first. File-change
 /// approvals remain path-scoped and only pass when every declared path stays
 /// inside the session worktree.
 fn scoped_pre_action_decision(
@@ -290,31 +435,6 @@ fn path_key_may_contain_file_path(key: &str) -> bool {
     key.contains("path") || key.contains("file") || key == "cwd"
 }
 
-/// Returns whether `path_text` resolves inside `session_folder`.
-fn path_is_session_local(path_text: &str, session_folder: &Path) -> bool {
-    let candidate_path = PathBuf::from(path_text);
-    if candidate_path.is_absolute() {
-        return candidate_path.starts_with(session_folder);
-    }
-
-    normalize_session_relative_path(session_folder, &candidate_path)
-        .is_some_and(|normalized_path| normalized_path.starts_with(session_folder))
-}
-
-/// Lexically normalizes one relative path under the session worktree.
-fn normalize_session_relative_path(session_folder: &Path, relative_path: &Path) -> Option<PathBuf> {
-    let mut normalized_path = session_folder.to_path_buf();
-    for component in relative_path.components() {
-        match component {
-            Component::Normal(path_component) => normalized_path.push(path_component),
-            Component::CurDir => {}
-            Component::ParentDir | Component::RootDir | Component::Prefix(_) => return None,
-        }
-    }
-
-    Some(normalized_path)
-}
-
 /// Returns the modern pre-action approval decision for one permission mode.
 fn pre_action_approval_decision(
     permission_mode: PermissionMode,
@@ -345,6 +465,7 @@ fn pre_action_rejection_decision(
 fn permission_mode_policy(permission_mode: PermissionMode) -> &'static PermissionModePolicy {
     match permission_mode {
         PermissionMode::AutoEdit => &AUTO_EDIT_POLICY,
+        PermissionMode::Ask => &ASK_POLICY,
         PermissionMode::ReadOnly => &READ_ONLY_POLICY,
     }
 }
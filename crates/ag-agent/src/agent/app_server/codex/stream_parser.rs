@@ -4,6 +4,7 @@ use ag_protocol::parse_agent_response_strict;
 use serde_json::Value;
 
 use crate::agent;
+use crate::model::activity::{TurnActivity, patch_line_counts};
 
 /// Extracted assistant message payload from one Codex stream line.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -485,6 +486,135 @@ pub(super) fn extract_item_started_progress(response_value: &Value) -> Option<St
     agent::compact_codex_progress_message(&normalized_item_type)
 }
 
+/// Extracts structured activity from one `item/started` or `item/completed`
+/// notification.
+///
+/// Command and MCP tool items report start and finish, file changes report
+/// one patch per changed path on completion, and web searches report once
+/// when they start.
+pub(super) fn extract_item_activity(response_value: &Value) -> Vec<TurnActivity> {
+    let is_completed = match response_value.get("method").and_then(Value::as_str) {
+        Some("item/started") => false,
+        Some("item/completed") => true,
+        _ => return Vec::new(),
+    };
+    let Some(item) = response_value
+        .get("params")
+        .and_then(|params| params.get("item"))
+    else {
+        return Vec::new();
+    };
+    let call_id = item.get("id").and_then(Value::as_str).map(str::to_string);
+
+    match item.get("type").and_then(Value::as_str) {
+        Some("commandExecution") => {
+            let command = item_command_text(item);
+            let activity = if is_completed {
+                let exit_code = item
+                    .get("exitCode")
+                    .and_then(Value::as_i64)
+                    .and_then(|exit_code| i32::try_from(exit_code).ok());
+
+                TurnActivity::ToolFinished {
+                    call_id,
+                    command,
+                    exit_code,
+                    succeeded: is_completed_item(item) && exit_code.is_none_or(|code| code == 0),
+                    tool: "command".to_string(),
+                }
+            } else {
+                TurnActivity::ToolStarted {
+                    call_id,
+                    command,
+                    tool: "command".to_string(),
+                }
+            };
+
+            vec![activity]
+        }
+        Some("mcpToolCall") => {
+            let tool = item
+                .get("tool")
+                .and_then(Value::as_str)
+                .unwrap_or("mcp")
+                .to_string();
+            let command = item
+                .get("server")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            let activity = if is_completed {
+                TurnActivity::ToolFinished {
+                    call_id,
+                    command,
+                    exit_code: None,
+                    succeeded: is_completed_item(item),
+                    tool,
+                }
+            } else {
+                TurnActivity::ToolStarted {
+                    call_id,
+                    command,
+                    tool,
+                }
+            };
+
+            vec![activity]
+        }
+        Some("fileChange") if is_completed => item
+            .get("changes")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|change| {
+                let path = change.get("path").and_then(Value::as_str)?;
+                let diff = change.get("diff").and_then(Value::as_str).unwrap_or("");
+                let (added_lines, deleted_lines) = patch_line_counts(diff);
+
+                Some(TurnActivity::FilePatched {
+                    added_lines,
+                    deleted_lines,
+                    path: path.to_string(),
+                })
+            })
+            .collect(),
+        Some("webSearch") if !is_completed => item
+            .get("query")
+            .and_then(Value::as_str)
+            .map(|query| TurnActivity::WebFetch {
+                target: query.to_string(),
+            })
+            .into_iter()
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the command line carried by modern string or legacy argv items.
+fn item_command_text(item: &Value) -> Option<String> {
+    match item.get("command")? {
+        Value::String(command) => Some(command.clone()),
+        Value::Array(arguments) => {
+            let arguments = arguments
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>();
+
+            (!arguments.is_empty()).then(|| arguments.join(" "))
+        }
+        _ => None,
+    }
+}
+
+/// Returns whether one completed item reports a successful status.
+///
+/// Items without a status are treated as successful because older app-server
+/// builds only emit `status` on failure.
+fn is_completed_item(item: &Value) -> bool {
+    item.get("status")
+        .and_then(Value::as_str)
+        .is_none_or(|status| status == "completed")
+}
+
 /// Converts a camelCase string to `snake_case`.
 pub(super) fn camel_to_snake(input: &str) -> String {
     let mut result = String::with_capacity(input.len() + 4);
@@ -592,6 +722,96 @@ mod tests {
         assert_eq!(progress, Some("Running a command".to_string()));
     }
 
+    #[test]
+    fn extract_item_activity_reports_command_start_and_failed_finish() {
+        // Arrange
+        let started = serde_json::json!({
+            "method": "item/started",
+            "params": {
+                "item": {"type": "commandExecution", "id": "call-1", "command": "cargo test"}
+            }
+        });
+        let completed = serde_json::json!({
+            "method": "item/completed",
+            "params": {
+                "item": {
+                    "type": "commandExecution",
+                    "id": "call-1",
+                    "command": ["cargo", "test"],
+                    "exitCode": 101,
+                    "status": "completed"
+                }
+            }
+        });
+
+        // Act
+        let started_activity = extract_item_activity(&started);
+        let completed_activity = extract_item_activity(&completed);
+
+        // Assert
+        assert_eq!(
+            started_activity,
+            vec![TurnActivity::ToolStarted {
+                call_id: Some("call-1".to_string()),
+                command: Some("cargo test".to_string()),
+                tool: "command".to_string(),
+            }]
+        );
+        assert_eq!(
+            completed_activity,
+            vec![TurnActivity::ToolFinished {
+                call_id: Some("call-1".to_string()),
+                command: Some("cargo test".to_string()),
+                exit_code: Some(101),
+                succeeded: false,
+                tool: "command".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn extract_item_activity_reports_one_patch_per_completed_file_change() {
+        // Arrange
+        let completed = serde_json::json!({
+            "method": "item/completed",
+            "params": {
+                "item": {
+                    "type": "fileChange",
+                    "changes": [
+                        {"path": "src/lib.rs", "diff": "@@ -1 +1,2 @@\n-old\n+new\n+more\n"},
+                        {"path": "README.md", "diff": "+docs\n"}
+                    ]
+                }
+            }
+        });
+        let started = serde_json::json!({
+            "method": "item/started",
+            "params": {"item": {"type": "fileChange", "changes": []}}
+        });
+
+        // Act
+        let completed_activity = extract_item_activity(&completed);
+        let started_activity = extract_item_activity(&started);
+
+        // Assert
+        assert_eq!(
+            completed_activity,
+            vec![
+                TurnActivity::FilePatched {
+                    added_lines: 2,
+                    deleted_lines: 1,
+                    path: "src/lib.rs".to_string(),
+                },
+                TurnActivity::FilePatched {
+                    added_lines: 1,
+                    deleted_lines: 0,
+                    path: "README.md".to_string(),
+                },
+            ]
+        );
+        assert_eq!(started_activity, Vec::new());
+    }
+
     #[test]
     fn extract_turn_id_from_turn_started_notification_supports_nested_flat_turn_fields() {
         // Arrange
@@ -1,5 +1,7 @@
 //! Gemini ACP client orchestration.
 
+use std::sync::Arc;
+
 use ag_protocol::{ProtocolSchemaInstructionMode, TurnPrompt};
 use tokio::sync::mpsc;
 
@@ -10,6 +12,7 @@ use crate::app_server::{
     AppServerError, AppServerFuture, AppServerStreamEvent, AppServerTurnRequest,
     BorrowedAppServerFuture,
 };
+use crate::channel::ToolApprovalHandler;
 use crate::model::agent::{AgentKind, ReasoningLevel};
 use crate::model::session::SpeedMode;
 use crate::{agent, app_server_transport};
@@ -54,14 +57,17 @@ impl RuntimeClientProvider for GeminiRuntimeProvider {
         prompt: &'scope TurnPrompt,
         _reasoning_level: ReasoningLevel,
         _speed_mode: SpeedMode,
+        tool_approval: Option<Arc<dyn ToolApprovalHandler>>,
         stream_tx: mpsc::UnboundedSender<AppServerStreamEvent>,
     ) -> BorrowedAppServerFuture<'scope, Result<(String, u64, u64), AppServerError>> {
         Box::pin(async move {
             lifecycle::run_turn_with_runtime(
                 &mut runtime.transport,
                 &runtime.state.session_id,
+                runtime.state.folder.as_path(),
                 runtime.state.permission_mode,
                 prompt,
+                tool_approval.as_deref(),
                 stream_tx,
             )
             .await
@@ -160,6 +166,7 @@ mod tests {
             request_kind: crate::channel::AgentRequestKind::SessionResume,
             session_id: "session-1".to_string(),
             speed_mode: SpeedMode::default(),
+            tool_approval: None,
         };
 
         // Act
@@ -186,6 +193,7 @@ mod tests {
             &prompt,
             ReasoningLevel::default(),
             SpeedMode::Fast,
+            None,
             stream_tx,
         )
         .await;
@@ -14,11 +14,13 @@ use serde::Serialize;
 use serde_json::Value;
 use tokio::sync::mpsc;
 
+use super::super::client;
 use super::super::stdio_transport::{AppServerRuntimeTransport, AppServerStdioTransport};
 use super::{policy, stream_parser, usage};
 use crate::agent;
 use crate::app_server::{AppServerError, AppServerStreamEvent, AppServerTurnRequest};
 use crate::app_server_transport::{self, extract_json_error_message, response_id_matches};
+use crate::channel::ToolApprovalHandler;
 use crate::model::agent::AgentKind;
 use crate::model::permission::PermissionMode;
 
@@ -308,8 +310,10 @@ pub(super) fn parse_session_new_response(response_value: &Value) -> Result<Strin
 pub(super) async fn run_turn_with_runtime<Transport: AppServerRuntimeTransport>(
     transport: &mut Transport,
     session_id: &str,
+    session_folder: &Path,
     permission_mode: PermissionMode,
     prompt: impl Into<TurnPrompt>,
+    tool_approval: Option<&dyn ToolApprovalHandler>,
     stream_tx: mpsc::UnboundedSender<AppServerStreamEvent>,
 ) -> Result<(String, u64, u64), AppServerError> {
     let prompt = prompt.into();
@@ -339,6 +343,21 @@ pub(super) async fn run_turn_with_runtime<Transport: AppServerRuntimeTransport>(
                 continue;
             };
 
+            if let Some(pending_approval) = policy::pending_permission_approval(
+                &response_value,
+                session_id,
+                permission_mode,
+                session_folder,
+            ) {
+                let decision =
+                    client::request_tool_approval(tool_approval, pending_approval.request()).await;
+                transport
+                    .write_json_line(pending_approval.response(decision))
+                    .await?;
+
+                continue;
+            }
+
             if let Some(permission_response) =
                 policy::build_permission_response(&response_value, session_id, permission_mode)
             {
@@ -374,6 +393,10 @@ pub(super) async fn run_turn_with_runtime<Transport: AppServerRuntimeTransport>(
                 let _ = stream_tx.send(AppServerStreamEvent::ProgressUpdate(progress));
             }
 
+            for activity in stream_parser::extract_activity(&response_value, session_id) {
+                let _ = stream_tx.send(AppServerStreamEvent::Activity(activity));
+            }
+
             if let Some(chunk) =
                 stream_parser::extract_assistant_message_chunk(&response_value, session_id)
             {
@@ -523,6 +546,7 @@ mod tests {
             request_kind: crate::channel::AgentRequestKind::SessionStart,
             session_id: "session-1".to_string(),
             speed_mode: SpeedMode::Normal,
+            tool_approval: None,
         }
     }
 
@@ -869,8 +893,10 @@ mod tests {
         let result = run_turn_with_runtime(
             &mut transport,
             "session-1",
+            Path::new("/tmp/project"),
             PermissionMode::ReadOnly,
             "Inspect the architecture",
+            None,
             stream_tx,
         )
         .await;
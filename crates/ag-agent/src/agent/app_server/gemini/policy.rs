@@ -1,18 +1,155 @@
 //! Gemini ACP permission policy helpers.
 
+use std::path::Path;
+
 use agent_client_protocol::schema::v1::{
     CLIENT_METHOD_NAMES, PermissionOption, PermissionOptionKind, RequestPermissionOutcome,
     RequestPermissionRequest, RequestPermissionResponse, SelectedPermissionOutcome,
 };
 use serde_json::Value;
 
+use super::super::session_path::path_is_session_local;
+use crate::channel::{ToolApprovalDecision, ToolApprovalKind, ToolApprovalRequest};
 use crate::model::permission::PermissionMode;
 
+/// ACP tool kinds that ask-mode turns still approve without user input.
+///
+/// Reads stay automatic so agents can work freely, while shell commands,
+/// deletes, moves, fetches, and unknown tools reach the user. Edits are
+/// automatic only inside the session worktree; see
+/// [`edit_locations_are_session_local`].
+const ASK_MODE_AUTO_APPROVED_TOOL_KINDS: [&str; 3] = ["read", "search", "think"];
+
+/// ACP `session/request_permission` request that waits for a user decision in
+/// [`PermissionMode::Ask`].
+pub(super) struct PendingPermissionApproval {
+    options: Value,
+    request: ToolApprovalRequest,
+    request_id: Value,
+}
+
+impl PendingPermissionApproval {
+    /// Returns the user-facing approval request.
+    pub(super) fn request(&self) -> &ToolApprovalRequest {
+        &self.request
+    }
+
+    /// Builds the ACP response that selects the option matching `decision`.
+    ///
+    /// Denials pick a reject option when the agent offers one and otherwise
+    /// cancel the request.
+    pub(super) fn response(&self, decision: ToolApprovalDecision) -> Value {
+        let preferred_kinds: &[PermissionOptionKind] = match decision {
+            ToolApprovalDecision::AllowOnce => &[PermissionOptionKind::AllowOnce],
+            ToolApprovalDecision::AllowForSession => &[
+                PermissionOptionKind::AllowAlways,
+                PermissionOptionKind::AllowOnce,
+            ],
+            ToolApprovalDecision::Deny => &[PermissionOptionKind::RejectOnce],
+        };
+        let selected_option_id = select_option_id_from_value(&self.options, preferred_kinds);
+
+        build_permission_result_payload(&self.request_id, selected_option_id)
+    }
+}
+
+/// Returns the permission request that must wait for the user, when any.
+///
+/// Only ask-mode turns forward requests, and only for tool kinds outside
+/// [`ASK_MODE_AUTO_APPROVED_TOOL_KINDS`] and edits that touch paths outside
+/// `session_folder`, matching the Codex file-change policy.
+pub(super) fn pending_permission_approval(
+    response_value: &Value,
+    expected_session_id: &str,
+    permission_mode: PermissionMode,
+    session_folder: &Path,
+) -> Option<PendingPermissionApproval> {
+    if !permission_mode.asks_for_approval()
+        || response_value.get("method").and_then(Value::as_str)
+            != Some(CLIENT_METHOD_NAMES.session_request_permission)
+    {
+        return None;
+    }
+
+    let params = response_value.get("params")?;
+    if params.get("sessionId").and_then(Value::as_str)? != expected_session_id {
+        return None;
+    }
+
+    let tool_call = params.get("toolCall").unwrap_or(&Value::Null);
+    let tool_kind = tool_call.get("kind").and_then(Value::as_str);
+    if tool_kind.is_some_and(|tool_kind| ASK_MODE_AUTO_APPROVED_TOOL_KINDS.contains(&tool_kind)) {
+        return None;
+    }
+    if tool_kind == Some("edit") && edit_locations_are_session_local(tool_call, session_folder) {
+        return None;
+    }
+
+    Some(PendingPermissionApproval {
+        options: params.get("options").cloned().unwrap_or(Value::Null),
+        request: tool_approval_request(tool_call, tool_kind),
+        request_id: response_value.get("id")?.clone(),
+    })
+}
+
+/// Returns whether an edit declares at least one location and every
+/// `toolCall.locations[].path` stays under `session_folder`.
+fn edit_locations_are_session_local(tool_call: &Value, session_folder: &Path) -> bool {
+    let paths = tool_call_location_paths(tool_call);
+
+    !paths.is_empty()
+        && paths
+            .iter()
+            .all(|path| path_is_session_local(path, session_folder))
+}
+
+/// Returns the file paths declared in `toolCall.locations`.
+fn tool_call_location_paths(tool_call: &Value) -> Vec<&str> {
+    tool_call
+        .get("locations")
+        .and_then(Value::as_array)
+        .map(|locations| {
+            locations
+                .iter()
+                .filter_map(|location| location.get("path").and_then(Value::as_str))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Builds the user-facing summary for one ACP tool call.
+fn tool_approval_request(tool_call: &Value, tool_kind: Option<&str>) -> ToolApprovalRequest {
+    let kind = match tool_kind {
+        Some("execute") => ToolApprovalKind::Command,
+        Some("delete" | "edit" | "move") => ToolApprovalKind::FileChange,
+        _ => ToolApprovalKind::Tool,
+    };
+    let summary = tool_call
+        .get("title")
+        .and_then(Value::as_str)
+        .or_else(|| {
+            tool_call
+                .pointer("/rawInput/command")
+                .and_then(Value::as_str)
+        })
+        .unwrap_or("Run a tool")
+        .to_string();
+    let locations = tool_call_location_paths(tool_call);
+
+    ToolApprovalRequest {
+        detail: (!locations.is_empty()).then(|| locations.join(", ")),
+        kind,
+        summary,
+    }
+}
+
 /// Builds a `session/request_permission` response for the active session.
 ///
 /// Gemini ACP uses client-selected permission options to unblock tools.
 /// Agentty selects an explicit one-shot allow option for auto-edit turns and
-/// cancels every request for read-only turns.
+/// cancels every request for read-only turns. Ask-mode requests that reach
+/// this point are auto-approved tool kinds or session-local edits, because
+/// [`pending_permission_approval`] forwards the rest to the user first.
 pub(super) fn build_permission_response(
     response_value: &Value,
     expected_session_id: &str,
@@ -119,22 +256,28 @@ fn select_permission_option(options: &[PermissionOption]) -> Option<&PermissionO
 
 /// Selects the preferred allow option identifier from raw ACP choices.
 fn select_permission_option_id_from_value(options: &Value) -> Option<String> {
+    select_option_id_from_value(options, &preferred_allow_option_kinds())
+}
+
+/// Selects the first option identifier matching `preferred_kinds` in order.
+fn select_option_id_from_value(
+    options: &Value,
+    preferred_kinds: &[PermissionOptionKind],
+) -> Option<String> {
     let options = options.as_array()?;
-    preferred_allow_option_kinds()
-        .into_iter()
-        .find_map(|preferred_kind| {
-            let preferred_kind_value = permission_option_kind_value(preferred_kind)?;
-            options.iter().find_map(|option| {
-                if option.get("kind") == Some(&preferred_kind_value) {
-                    option
-                        .get("optionId")
-                        .and_then(Value::as_str)
-                        .map(ToString::to_string)
-                } else {
-                    None
-                }
-            })
+    preferred_kinds.iter().copied().find_map(|preferred_kind| {
+        let preferred_kind_value = permission_option_kind_value(preferred_kind)?;
+        options.iter().find_map(|option| {
+            if option.get("kind") == Some(&preferred_kind_value) {
+                option
+                    .get("optionId")
+                    .and_then(Value::as_str)
+                    .map(ToString::to_string)
+            } else {
+                None
+            }
         })
+    })
 }
 
 /// Returns allow option kinds in Agentty's safety-preserving preference order.
@@ -241,4 +384,148 @@ mod tests {
             Some(&Value::String("allow-always".to_string()))
         );
     }
+
+    #[test]
+    fn ask_mode_forwards_execute_requests_and_auto_approves_session_local_edits() {
+        // Arrange
+        let execute_request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "permission-1",
+            "method": CLIENT_METHOD_NAMES.session_request_permission,
+            "params": {
+                "sessionId": "session-1",
+                "toolCall": {
+                    "toolCallId": "tool-1",
+                    "kind": "execute",
+                    "title": "rm -rf target",
+                    "locations": [{"path": "/tmp/project"}]
+                },
+                "options": [
+                    {"optionId": "allow-once", "name": "Allow once", "kind": "allow_once"},
+                    {"optionId": "allow-always", "name": "Always", "kind": "allow_always"},
+                    {"optionId": "reject-once", "name": "Reject", "kind": "reject_once"}
+                ]
+            }
+        });
+        let edit_request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "permission-2",
+            "method": CLIENT_METHOD_NAMES.session_request_permission,
+            "params": {
+                "sessionId": "session-1",
+                "toolCall": {
+                    "toolCallId": "tool-2",
+                    "kind": "edit",
+                    "locations": [{"path": "/tmp/project/src/lib.rs"}]
+                },
+                "options": [{"optionId": "allow-once", "kind": "allow_once"}]
+            }
+        });
+        let session_folder = Path::new("/tmp/project");
+
+        // Act
+        let pending_execute = pending_permission_approval(
+            &execute_request,
+            "session-1",
+            PermissionMode::Ask,
+            session_folder,
+        )
+        .expect("execute request should wait for the user");
+        let pending_edit = pending_permission_approval(
+            &edit_request,
+            "session-1",
+            PermissionMode::Ask,
+            session_folder,
+        );
+        let edit_response =
+            build_permission_response(&edit_request, "session-1", PermissionMode::Ask)
+                .expect("edit permission response should be generated");
+
+        // Assert
+        assert_eq!(
+            pending_execute.request(),
+            &ToolApprovalRequest {
+                detail: Some("/tmp/project".to_string()),
+                kind: ToolApprovalKind::Command,
+                summary: "rm -rf target".to_string(),
+            }
+        );
+        assert_eq!(
+            pending_execute
+                .response(ToolApprovalDecision::AllowForSession)
+                .pointer("/result/outcome/optionId"),
+            Some(&Value::String("allow-always".to_string()))
+        );
+        assert_eq!(
+            pending_execute
+                .response(ToolApprovalDecision::Deny)
+                .pointer("/result/outcome/optionId"),
+            Some(&Value::String("reject-once".to_string()))
+        );
+        assert!(pending_edit.is_none());
+        assert_eq!(
+            edit_response.pointer("/result/outcome/optionId"),
+            Some(&Value::String("allow-once".to_string()))
+        );
+    }
+
+    #[test]
+    fn ask_mode_forwards_edits_outside_the_session_worktree() {
+        // Arrange
+        let edit_request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "permission-3",
+            "method": CLIENT_METHOD_NAMES.session_request_permission,
+            "params": {
+                "sessionId": "session-1",
+                "toolCall": {
+                    "toolCallId": "tool-3",
+                    "kind": "edit",
+                    "title": "Edit shell profile",
+                    "locations": [
+                        {"path": "/tmp/project/src/lib.rs"},
+                        {"path": "/home/user/.bashrc"}
+                    ]
+                },
+                "options": [{"optionId": "allow-once", "kind": "allow_once"}]
+            }
+        });
+        let unlocated_edit_request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "permission-4",
+            "method": CLIENT_METHOD_NAMES.session_request_permission,
+            "params": {
+                "sessionId": "session-1",
+                "toolCall": {"toolCallId": "tool-4", "kind": "edit"},
+                "options": [{"optionId": "allow-once", "kind": "allow_once"}]
+            }
+        });
+        let session_folder = Path::new("/tmp/project");
+
+        // Act
+        let pending_edit = pending_permission_approval(
+            &edit_request,
+            "session-1",
+            PermissionMode::Ask,
+            session_folder,
+        )
+        .expect("outside edit should wait for the user");
+        let pending_unlocated_edit = pending_permission_approval(
+            &unlocated_edit_request,
+            "session-1",
+            PermissionMode::Ask,
+            session_folder,
+        );
+
+        // Assert
+        assert_eq!(
+            pending_edit.request(),
+            &ToolApprovalRequest {
+                detail: Some("/tmp/project/src/lib.rs, /home/user/.bashrc".to_string()),
+                kind: ToolApprovalKind::FileChange,
+                summary: "Edit shell profile".to_string(),
+            }
+        );
+        assert!(pending_unlocated_edit.is_some());
+    }
 }
@@ -4,6 +4,7 @@ use agent_client_protocol::schema::v1::CLIENT_METHOD_NAMES;
 use serde_json::Value;
 
 use super::usage;
+use crate::model::activity::{TurnActivity, replacement_line_counts};
 
 /// Selects the most reliable final assistant payload for one Gemini turn.
 ///
@@ -86,6 +87,103 @@ pub(super) fn extract_progress_update(
     }
 }
 
+/// Extracts structured activity from ACP `tool_call` and `tool_call_update`
+/// session updates.
+///
+/// New `tool_call` updates report a started tool, or a web fetch for `fetch`
+/// tools. Updates that reach a terminal status report the finished tool plus
+/// one file patch per `diff` content entry.
+pub(super) fn extract_activity(
+    response_value: &Value,
+    expected_session_id: &str,
+) -> Vec<TurnActivity> {
+    let Some(session_update) = extract_session_update_kind(response_value, expected_session_id)
+        .filter(|kind| matches!(*kind, "tool_call" | "tool_call_update"))
+    else {
+        return Vec::new();
+    };
+    let Some(update) = response_value
+        .get("params")
+        .and_then(|params| params.get("update"))
+    else {
+        return Vec::new();
+    };
+    let call_id = update
+        .get("toolCallId")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    let tool = update
+        .get("kind")
+        .and_then(Value::as_str)
+        .unwrap_or("tool")
+        .to_string();
+    let command = update
+        .get("rawInput")
+        .and_then(|raw_input| raw_input.get("command"))
+        .or_else(|| update.get("title"))
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    let status = update.get("status").and_then(Value::as_str);
+    let mut activities = Vec::new();
+
+    if session_update == "tool_call" {
+        if tool == "fetch" {
+            let target = update
+                .get("rawInput")
+                .and_then(|raw_input| raw_input.get("url"))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .or_else(|| command.clone())
+                .unwrap_or_default();
+            activities.push(TurnActivity::WebFetch { target });
+        } else {
+            activities.push(TurnActivity::ToolStarted {
+                call_id: call_id.clone(),
+                command: command.clone(),
+                tool: tool.clone(),
+            });
+        }
+    }
+
+    let Some(status) = status.filter(|status| matches!(*status, "completed" | "failed")) else {
+        return activities;
+    };
+    if status == "completed" {
+        activities.extend(diff_activities(update));
+    }
+    activities.push(TurnActivity::ToolFinished {
+        call_id,
+        command,
+        exit_code: None,
+        succeeded: status == "completed",
+        tool,
+    });
+
+    activities
+}
+
+/// Returns one file patch per ACP `diff` content entry in a tool update.
+fn diff_activities(update: &Value) -> impl Iterator<Item = TurnActivity> + '_ {
+    update
+        .get("content")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|content| content.get("type").and_then(Value::as_str) == Some("diff"))
+        .filter_map(|content| {
+            let path = content.get("path").and_then(Value::as_str)?;
+            let old_text = content.get("oldText").and_then(Value::as_str).unwrap_or("");
+            let new_text = content.get("newText").and_then(Value::as_str).unwrap_or("");
+            let (added_lines, deleted_lines) = replacement_line_counts(old_text, new_text);
+
+            Some(TurnActivity::FilePatched {
+                added_lines,
+                deleted_lines,
+                path: path.to_string(),
+            })
+        })
+}
+
 /// Returns the ACP `sessionUpdate` kind for the matching session update.
 pub(super) fn extract_session_update_kind<'value>(
     response_value: &'value Value,
@@ -107,3 +205,93 @@ pub(super) fn extract_session_update_kind<'value>(
         .and_then(|update| update.get("sessionUpdate"))
         .and_then(Value::as_str)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds one ACP `session/update` notification for `session-1`.
+    fn session_update(update: &Value) -> Value {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": CLIENT_METHOD_NAMES.session_update,
+            "params": {"sessionId": "session-1", "update": update}
+        })
+    }
+
+    #[test]
+    fn extract_activity_reports_tool_start_and_completed_edit() {
+        // Arrange
+        let started = session_update(&serde_json::json!({
+            "sessionUpdate": "tool_call",
+            "toolCallId": "call-1",
+            "title": "Edit src/lib.rs",
+            "kind": "edit",
+            "status": "pending"
+        }));
+        let completed = session_update(&serde_json::json!({
+            "sessionUpdate": "tool_call_update",
+            "toolCallId": "call-1",
+            "status": "completed",
+            "content": [
+                {"type": "diff", "path": "src/lib.rs", "oldText": "old", "newText": "new\nmore"}
+            ]
+        }));
+
+        // Act
+        let started_activity = extract_activity(&started, "session-1");
+        let completed_activity = extract_activity(&completed, "session-1");
+
+        // Assert
+        assert_eq!(
+            started_activity,
+            vec![TurnActivity::ToolStarted {
+                call_id: Some("call-1".to_string()),
+                command: Some("Edit src/lib.rs".to_string()),
+                tool: "edit".to_string(),
+            }]
+        );
+        assert_eq!(
+            completed_activity,
+            vec![
+                TurnActivity::FilePatched {
+                    added_lines: 2,
+                    deleted_lines: 1,
+                    path: "src/lib.rs".to_string(),
+                },
+                TurnActivity::ToolFinished {
+                    call_id: Some("call-1".to_string()),
+                    command: None,
+                    exit_code: None,
+                    succeeded: true,
+                    tool: "tool".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn extract_activity_reports_fetch_and_ignores_other_sessions() {
+        // Arrange
+        let fetch = session_update(&serde_json::json!({
+            "sessionUpdate": "tool_call",
+            "toolCallId": "call-2",
+            "title": "Fetch docs",
+            "kind": "fetch",
+            "rawInput": {"url": "https://example.com/docs"}
+        }));
+
+        // Act
+        let fetch_activity = extract_activity(&fetch, "session-1");
+        let other_session_activity = extract_activity(&fetch, "session-2");
+
+        // Assert
+        assert_eq!(
+            fetch_activity,
+            vec![TurnActivity::WebFetch {
+                target: "https://example.com/docs".to_string(),
+            }]
+        );
+        assert_eq!(other_session_activity, Vec::new());
+    }
+}
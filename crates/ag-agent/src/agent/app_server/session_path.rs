@@ -0,0 +1,28 @@
+//! Session-worktree path checks shared by app-server permission policies.
+
+use std::path::{Component, Path, PathBuf};
+
+/// Returns whether `path_text` resolves inside `session_folder`.
+pub(super) fn path_is_session_local(path_text: &str, session_folder: &Path) -> bool {
+    let candidate_path = PathBuf::from(path_text);
+    if candidate_path.is_absolute() {
+        return candidate_path.starts_with(session_folder);
+    }
+
+    normalize_session_relative_path(session_folder, &candidate_path)
+        .is_some_and(|normalized_path| normalized_path.starts_with(session_folder))
+}
+
+/// Lexically normalizes one relative path under the session worktree.
+fn normalize_session_relative_path(session_folder: &Path, relative_path: &Path) -> Option<PathBuf> {
+    let mut normalized_path = session_folder.to_path_buf();
+    for component in relative_path.components() {
+        match component {
+            Component::Normal(path_component) => normalized_path.push(path_component),
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+
+    Some(normalized_path)
+}
@@ -10,6 +10,7 @@ use std::time::{Duration, Instant};
 
 use semver::Version;
 
+use super::harness;
 use crate::model::agent::{AgentCliInfo, AgentKind};
 
 /// Oldest Antigravity CLI release supported by Agentty's native stream
@@ -79,15 +80,28 @@ pub trait AgentAvailabilityProbe: Send + Sync {
 }
 
 /// Production availability probe backed by `PATH` executable discovery.
+///
+/// The in-process harness needs no executable, so it is reported whenever a
+/// harness provider API key is present in the environment.
 pub struct RealAgentAvailabilityProbe;
 
 impl AgentAvailabilityProbe for RealAgentAvailabilityProbe {
     fn available_agent_kinds(&self) -> Vec<AgentKind> {
-        available_agent_kinds_from_path(env::var_os("PATH").as_deref())
+        let mut agent_kinds = available_agent_kinds_from_path(env::var_os("PATH").as_deref());
+        if harness::is_harness_configured() {
+            agent_kinds.push(AgentKind::Harness);
+        }
+
+        agent_kinds
     }
 
     fn available_agent_clis(&self) -> Vec<AgentCliInfo> {
-        available_agent_clis_from_path(env::var_os("PATH").as_deref())
+        let mut agent_clis = available_agent_clis_from_path(env::var_os("PATH").as_deref());
+        if harness::is_harness_configured() {
+            agent_clis.push(harness_cli_info());
+        }
+
+        agent_clis
     }
 }
 
@@ -109,11 +123,25 @@ pub fn executable_name(agent_kind: AgentKind) -> &'static str {
     agent_kind.executable_name()
 }
 
-/// Returns available agent CLI metadata from one `PATH` value.
-fn available_agent_clis_from_path(path_value: Option<&OsStr>) -> Vec<AgentCliInfo> {
-    let executable_agent_clis = AgentKind::ALL
+/// Returns the built-in harness row, versioned with the Agentty build.
+fn harness_cli_info() -> AgentCliInfo {
+    AgentCliInfo::new(
+        AgentKind::Harness,
+        Some(env!("CARGO_PKG_VERSION").to_string()),
+    )
+}
+
+/// Returns executable-backed agent kinds in display order.
+fn executable_agent_kinds() -> impl Iterator<Item = AgentKind> {
+    AgentKind::ALL
         .iter()
         .copied()
+        .filter(|agent_kind| *agent_kind != AgentKind::Harness)
+}
+
+/// Returns available agent CLI metadata from one `PATH` value.
+fn available_agent_clis_from_path(path_value: Option<&OsStr>) -> Vec<AgentCliInfo> {
+    let executable_agent_clis = executable_agent_kinds()
         .filter_map(|agent_kind| {
             let executable_path = executable_path_on_path(path_value, executable_name(agent_kind))?;
 
@@ -128,9 +156,7 @@ fn available_agent_clis_from_path(path_value: Option<&OsStr>) -> Vec<AgentCliInf
 
 /// Returns agent kinds whose executables are present on one `PATH` value.
 fn available_agent_kinds_from_path(path_value: Option<&OsStr>) -> Vec<AgentKind> {
-    AgentKind::ALL
-        .iter()
-        .copied()
+    executable_agent_kinds()
         .filter(|agent_kind| {
             if *agent_kind == AgentKind::Antigravity {
                 return ensure_antigravity_cli_supported_on_path(path_value).is_ok();
@@ -526,6 +552,7 @@ mod tests {
     use tempfile::tempdir;
 
     use super::*;
+    use crate::model::agent::AgentCliVersion;
 
     /// Serializes tests that update the process-wide Antigravity compatibility
     /// snapshot.
@@ -568,6 +595,31 @@ mod tests {
         assert_eq!(available_agent_kinds, vec![AgentKind::Codex]);
     }
 
+    #[test]
+    /// Ensures the in-process harness is never discovered through `PATH`,
+    /// even when an executable shares its display name.
+    fn test_available_agent_kinds_from_path_skips_in_process_harness() {
+        // Arrange
+        let temp_directory = tempdir().expect("failed to create temp dir");
+        let harness_path = temp_directory.path().join("ag-harness");
+        fs::write(&harness_path, "").expect("failed to create harness executable");
+        fs::set_permissions(&harness_path, fs::Permissions::from_mode(0o755))
+            .expect("failed to mark harness executable");
+        let path_value = env::join_paths([temp_directory.path()]).expect("valid path");
+
+        // Act
+        let available_agent_kinds = available_agent_kinds_from_path(Some(path_value.as_os_str()));
+        let harness_row = harness_cli_info();
+
+        // Assert
+        assert_eq!(available_agent_kinds, Vec::<AgentKind>::new());
+        assert_eq!(harness_row.executable_name, "ag-harness");
+        assert_eq!(
+            harness_row.version,
+            AgentCliVersion::Value(env!("CARGO_PKG_VERSION").to_string())
+        );
+    }
+
     #[test]
     /// Ensures unsupported Antigravity installations are not selectable even
     /// when the executable is present.
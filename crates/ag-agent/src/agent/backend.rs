@@ -17,6 +17,9 @@ pub enum AgentTransport {
     AppServer,
     /// Provider runs as direct CLI subprocess commands.
     Cli,
+    /// Provider runs inside the Agentty process without an external
+    /// executable.
+    InProcess,
 }
 
 impl AgentTransport {
@@ -24,6 +27,11 @@ impl AgentTransport {
     pub fn uses_app_server(self) -> bool {
         matches!(self, Self::AppServer)
     }
+
+    /// Returns whether turns run inside the Agentty process.
+    pub fn runs_in_process(self) -> bool {
+        matches!(self, Self::InProcess)
+    }
 }
 
 /// Prompt delivery mode used by one provider backend.
@@ -147,6 +155,20 @@ mod tests {
         assert!(!result);
     }
 
+    #[test]
+    fn test_agent_transport_in_process_runs_in_process() {
+        // Arrange
+        let transport = AgentTransport::InProcess;
+
+        // Act
+        let runs_in_process = transport.runs_in_process();
+        let uses_app_server = transport.uses_app_server();
+
+        // Assert
+        assert!(runs_in_process);
+        assert!(!uses_app_server);
+    }
+
     #[test]
     fn test_agent_backend_error_setup_displays_message() {
         // Arrange
@@ -0,0 +1,760 @@
+//! In-process `ag-harness` backend for Qwen, Kimi, Muse, and
+//! OpenAI-compatible endpoint models.
+//!
+//! Harness turns run the bounded `ag-harness` tool loop inside the Agentty
+//! process against the session worktree, so no provider CLI is required.
+//! Provider credentials and endpoints come from environment variables, and
+//! the final structured output is validated against the shared
+//! response protocol schema before it is returned.
+
+use std::collections::HashMap;
+use std::env;
+use std::path::Path;
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+
+use ag_harness::{
+    CompletionMetadata, Harness, KimiConfig, LifecycleEvent, LifecycleEventKind, Model,
+    ModelClient, ModelError, ModelRequest, ModelResponse, MuseConfig, OpenAiCompatibleAuth,
+    OpenAiCompatibleConfig, OutputSchema, QwenConfig, StructuredOutputCapability, Tool,
+};
+use ag_protocol::{
+    AgentResponse, ProtocolRequestProfile, SchemaRequiredPolicy, agent_response_output_schema,
+};
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+
+use super::backend::{AgentBackend, AgentBackendError, BuildCommandRequest};
+use crate::channel::TurnEvent;
+use crate::model::activity::TurnActivity;
+use crate::model::agent::{AgentKind, AgentModel};
+use crate::model::permission::PermissionMode;
+
+/// Resolves one provider model identifier into a harness model.
+pub(crate) type HarnessModelResolver =
+    Arc<dyn Fn(&str) -> Result<Arc<dyn Model>, String> + Send + Sync>;
+
+/// Environment variable naming the model id served by the OpenAI-compatible
+/// endpoint.
+const OPENAI_COMPATIBLE_MODEL_ENV: &str = "OPENAI_COMPATIBLE_MODEL";
+
+/// Environment variable declaring the OpenAI-compatible endpoint's
+/// structured-output support.
+const OPENAI_COMPATIBLE_STRUCTURED_OUTPUT_ENV: &str = "OPENAI_COMPATIBLE_STRUCTURED_OUTPUT";
+
+/// Repair requests allowed when the OpenAI-compatible endpoint only follows
+/// prompted structured output.
+const OPENAI_COMPATIBLE_PROMPTED_REPAIR_ATTEMPTS: u8 = 2;
+
+/// Model API provider served through the in-process harness.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum HarnessProvider {
+    Kimi,
+    Muse,
+    OpenAiCompatible,
+    Qwen,
+}
+
+impl HarnessProvider {
+    /// All harness providers, in availability probe order.
+    const ALL: [Self; 4] = [Self::Qwen, Self::Kimi, Self::Muse, Self::OpenAiCompatible];
+
+    /// Returns the provider that serves `model`, based on its id family.
+    fn for_model(model: &str) -> Option<Self> {
+        if model.starts_with("qwen") {
+            return Some(Self::Qwen);
+        }
+        if model.starts_with("kimi") {
+            return Some(Self::Kimi);
+        }
+        if model.starts_with("muse") {
+            return Some(Self::Muse);
+        }
+        if model == AgentModel::OpenAiCompatible.as_str() {
+            return Some(Self::OpenAiCompatible);
+        }
+
+        None
+    }
+
+    /// Returns the environment variable holding the provider API key.
+    fn api_key_env(self) -> &'static str {
+        match self {
+            Self::Kimi => "MOONSHOT_API_KEY",
+            Self::Muse => "MODEL_API_KEY",
+            Self::OpenAiCompatible => "OPENAI_COMPATIBLE_API_KEY",
+            Self::Qwen => "DASHSCOPE_API_KEY",
+        }
+    }
+
+    /// Returns the environment variable overriding the provider base URL.
+    fn base_url_env(self) -> &'static str {
+        match self {
+            Self::Kimi => "MOONSHOT_BASE_URL",
+            Self::Muse => "MODEL_API_BASE_URL",
+            Self::OpenAiCompatible => "OPENAI_COMPATIBLE_BASE_URL",
+            Self::Qwen => "DASHSCOPE_BASE_URL",
+        }
+    }
+
+    /// Returns the official OpenAI-compatible base URL for the provider.
+    ///
+    /// A self-hosted OpenAI-compatible endpoint has no official address.
+    fn default_base_url(self) -> Option<&'static str> {
+        match self {
+            Self::Kimi => Some("https://api.moonshot.ai/v1"),
+            Self::Muse => Some("https://api.meta.ai/v1"),
+            Self::OpenAiCompatible => None,
+            Self::Qwen => Some("https://dashscope-intl.aliyuncs.com/compatible-mode/v1"),
+        }
+    }
+
+    /// Returns the environment variable that must be set to use the
+    /// provider.
+    ///
+    /// Hosted providers need an API key. Local inference servers usually
+    /// need none but have no default address, so the OpenAI-compatible
+    /// provider requires its base URL instead.
+    fn required_env(self) -> &'static str {
+        match self.default_base_url() {
+            Some(_) => self.api_key_env(),
+            None => self.base_url_env(),
+        }
+    }
+
+    /// Builds the provider model client for `model`.
+    fn model_client(
+        self,
+        api_key: Option<String>,
+        base_url: String,
+        model: String,
+        structured_output: StructuredOutputCapability,
+    ) -> Result<ModelClient, String> {
+        let client = match self {
+            Self::Kimi => ModelClient::kimi(KimiConfig {
+                api_key: api_key.unwrap_or_default(),
+                base_url,
+                model,
+            }),
+            Self::Muse => ModelClient::muse(MuseConfig {
+                api_key: api_key.unwrap_or_default(),
+                base_url,
+                model,
+            }),
+            Self::OpenAiCompatible => ModelClient::openai_compatible(OpenAiCompatibleConfig {
+                auth: api_key.map_or(OpenAiCompatibleAuth::None, OpenAiCompatibleAuth::Bearer),
+                base_url,
+                model,
+                structured_output,
+            }),
+            Self::Qwen => ModelClient::qwen(QwenConfig {
+                api_key: api_key.unwrap_or_default(),
+                base_url,
+                model,
+            }),
+        };
+
+        client.map_err(|error| error.to_string())
+    }
+}
+
+/// Placeholder backend for the in-process harness provider.
+///
+/// Harness turns never spawn a subprocess, so command construction always
+/// fails; channel and one-shot routing dispatch harness turns before any
+/// command would be built.
+pub(super) struct HarnessBackend;
+
+impl AgentBackend for HarnessBackend {
+    fn setup(&self, _folder: &Path) -> Result<(), AgentBackendError> {
+        Ok(())
+    }
+
+    fn build_command<'request>(
+        &'request self,
+        _request: BuildCommandRequest<'request>,
+    ) -> Result<Command, AgentBackendError> {
+        Err(AgentBackendError::CommandBuild(
+            "Harness turns run in-process and do not build provider commands".to_string(),
+        ))
+    }
+}
+
+/// Returns whether any harness provider has an API key in the process
+/// environment.
+pub(crate) fn is_harness_configured() -> bool {
+    is_harness_configured_in(|name| env::var(name).ok())
+}
+
+/// Returns whether `environment` provides the required setting for any
+/// harness provider.
+fn is_harness_configured_in(environment: impl Fn(&str) -> Option<String>) -> bool {
+    HarnessProvider::ALL.iter().any(|provider| {
+        environment(provider.required_env()).is_some_and(|value| !value.trim().is_empty())
+    })
+}
+
+/// Returns the production resolver that builds provider clients from the
+/// process environment.
+pub(crate) fn environment_model_resolver() -> HarnessModelResolver {
+    Arc::new(|model| {
+        let client = model_client_from_environment(model, |name| env::var(name).ok())?;
+
+        Ok(Arc::new(client) as Arc<dyn Model>)
+    })
+}
+
+/// Builds one provider client for `model` from environment lookups.
+///
+/// The OpenAI-compatible provider sends the model id named by
+/// `OPENAI_COMPATIBLE_MODEL` instead of Agentty's `openai-compatible`
+/// selection id.
+///
+/// # Errors
+/// Returns an error when `model` belongs to no harness provider, a required
+/// provider setting is unset or invalid, or the model identifier is invalid.
+fn model_client_from_environment(
+    model: &str,
+    environment: impl Fn(&str) -> Option<String>,
+) -> Result<ModelClient, String> {
+    let provider = HarnessProvider::for_model(model)
+        .ok_or_else(|| format!("Model `{model}` is not served by the harness backend"))?;
+    let lookup = |name: &str| environment(name).filter(|value| !value.trim().is_empty());
+    let required_setting = |name: &'static str| {
+        lookup(name)
+            .ok_or_else(|| format!("Set `{name}` to run `{model}` through the harness backend"))
+    };
+    required_setting(provider.required_env())?;
+    let base_url = lookup(provider.base_url_env())
+        .or_else(|| provider.default_base_url().map(str::to_string))
+        .unwrap_or_default();
+    let (served_model, structured_output) = match provider {
+        HarnessProvider::OpenAiCompatible => (
+            required_setting(OPENAI_COMPATIBLE_MODEL_ENV)?,
+            parse_structured_output_capability(
+                lookup(OPENAI_COMPATIBLE_STRUCTURED_OUTPUT_ENV).as_deref(),
+            )?,
+        ),
+        HarnessProvider::Kimi | HarnessProvider::Muse | HarnessProvider::Qwen => {
+            (model.to_string(), StructuredOutputCapability::JsonSchema)
+        }
+    };
+
+    provider.model_client(
+        lookup(provider.api_key_env()),
+        base_url,
+        served_model,
+        structured_output,
+    )
+}
+
+/// Parses the declared structured-output support of an OpenAI-compatible
+/// endpoint, defaulting to enforced JSON schemas.
+///
+/// # Errors
+/// Returns an error when `value` is not `json_schema`, `json_object`, or
+/// `prompted`.
+fn parse_structured_output_capability(
+    value: Option<&str>,
+) -> Result<StructuredOutputCapability, String> {
+    match value.map(str::trim) {
+        None | Some("json_schema") => Ok(StructuredOutputCapability::JsonSchema),
+        Some("json_object") => Ok(StructuredOutputCapability::JsonObject),
+        Some("prompted") => Ok(StructuredOutputCapability::Prompted {
+            repair_attempts: OPENAI_COMPATIBLE_PROMPTED_REPAIR_ATTEMPTS,
+        }),
+        Some(other) => Err(format!(
+            "Unknown `{OPENAI_COMPATIBLE_STRUCTURED_OUTPUT_ENV}` value `{other}`; use \
+             `json_schema`, `json_object`, or `prompted`"
+        )),
+    }
+}
+
+/// Input payload for one in-process harness turn.
+pub(crate) struct HarnessTurnRequest<'a> {
+    /// Session worktree that roots the harness read, write, and bash tools.
+    pub(crate) folder: &'a Path,
+    /// Filesystem policy; read-only turns only receive the read tool, and
+    /// only auto-edit turns receive the bash tool.
+    pub(crate) permission_mode: PermissionMode,
+    /// Fully prepared prompt text including protocol instructions.
+    pub(crate) prompt: String,
+    /// Protocol profile used to normalize the structured response.
+    pub(crate) protocol_profile: ProtocolRequestProfile,
+}
+
+/// Parsed response and token usage from one harness turn.
+#[derive(Debug)]
+pub(crate) struct HarnessTurnOutput {
+    /// Input tokens summed across every model request in the turn.
+    pub(crate) input_tokens: u64,
+    /// Output tokens summed across every model request in the turn.
+    pub(crate) output_tokens: u64,
+    /// Protocol response parsed from the validated structured output.
+    pub(crate) response: AgentResponse,
+}
+
+/// Runs one prompt through the harness tool loop and parses the final
+/// structured output into the shared response protocol.
+///
+/// Tool lifecycle events are forwarded to `events` as
+/// [`TurnEvent::Activity`] values when an event sink is supplied.
+///
+/// # Errors
+/// Returns an error when the output schema cannot be built, the harness turn
+/// fails, or the structured output does not match the response protocol.
+pub(crate) async fn run_harness_turn(
+    model: Arc<dyn Model>,
+    request: HarnessTurnRequest<'_>,
+    events: Option<mpsc::UnboundedSender<TurnEvent>>,
+) -> Result<HarnessTurnOutput, String> {
+    let schema = OutputSchema::new(agent_response_output_schema(
+        SchemaRequiredPolicy::MinimumProtocolKeys,
+    ))
+    .map_err(|error| format!("Harness response schema is invalid: {error}"))?;
+    let observer = Arc::new(HarnessTurnObserver::new(events));
+    let mut harness = Harness::new(SharedModel(model))
+        .repository(request.folder)
+        .allow(Tool::Read)
+        .with_lifecycle_observer(Arc::clone(&observer).observer());
+    // The harness has no approval channel, so `Ask` runs read-only rather
+    // than granting tools the user never got to approve.
+    if request.permission_mode.for_agent_kind(AgentKind::Harness) == PermissionMode::AutoEdit {
+        harness = harness.allow(Tool::Write).allow(Tool::Bash);
+    }
+
+    let output = harness
+        .run(request.prompt, schema)
+        .await
+        .map_err(|error| format!("Harness turn failed: {error}"))?;
+    let response = super::parse_turn_response(
+        AgentKind::Harness,
+        &output.to_string(),
+        request.protocol_profile,
+    )?;
+    let (input_tokens, output_tokens) = observer.token_usage();
+
+    Ok(HarnessTurnOutput {
+        input_tokens,
+        output_tokens,
+        response,
+    })
+}
+
+/// Shares one resolved model with a [`Harness`], which takes ownership of
+/// its model.
+struct SharedModel(Arc<dyn Model>);
+
+#[async_trait]
+impl Model for SharedModel {
+    async fn complete(&self, request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        self.0.complete(request).await
+    }
+
+    async fn complete_with_optional_metadata(
+        &self,
+        request: ModelRequest,
+    ) -> Result<(ModelResponse, Option<CompletionMetadata>), ModelError> {
+        self.0.complete_with_optional_metadata(request).await
+    }
+}
+
+/// Projects harness lifecycle events into turn activity and token usage.
+struct HarnessTurnObserver {
+    events: Option<mpsc::UnboundedSender<TurnEvent>>,
+    state: Mutex<HarnessTurnObserverState>,
+}
+
+/// Mutable tool-name and usage state collected while a harness turn runs.
+#[derive(Default)]
+struct HarnessTurnObserverState {
+    input_tokens: u64,
+    output_tokens: u64,
+    tool_names: HashMap<u64, String>,
+}
+
+impl HarnessTurnObserver {
+    /// Creates an observer that forwards activity to `events`, when present.
+    fn new(events: Option<mpsc::UnboundedSender<TurnEvent>>) -> Self {
+        Self {
+            events,
+            state: Mutex::new(HarnessTurnObserverState::default()),
+        }
+    }
+
+    /// Returns a lifecycle callback sharing this observer.
+    fn observer(self: Arc<Self>) -> impl Fn(LifecycleEvent) + Send + Sync + 'static {
+        move |event| self.observe(&event)
+    }
+
+    /// Returns the summed `(input, output)` token usage observed so far.
+    fn token_usage(&self) -> (u64, u64) {
+        self.state
+            .lock()
+            .map(|state| (state.input_tokens, state.output_tokens))
+            .unwrap_or_default()
+    }
+
+    /// Records one lifecycle event and forwards tool steps as activity.
+    fn observe(&self, event: &LifecycleEvent) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        let activity =
+            match event.kind() {
+                LifecycleEventKind::ModelRequestCompleted {
+                    completion: Some(completion),
+                    ..
+                } => {
+                    if let Some(usage) = completion.usage() {
+                        state.input_tokens += usage.input_tokens().unwrap_or_default();
+                        state.output_tokens += usage.output_tokens().unwrap_or_default();
+                    }
+
+                    None
+                }
+                LifecycleEventKind::ToolRequested {
+                    tool_call_id,
+                    tool_name,
+                    ..
+                } => {
+                    state
+                        .tool_names
+                        .insert(tool_call_id.get(), tool_name.clone());
+
+                    Some(TurnActivity::ToolStarted {
+                        call_id: Some(tool_call_id.get().to_string()),
+                        command: None,
+                        tool: tool_name.clone(),
+                    })
+                }
+                LifecycleEventKind::ToolCompleted { tool_call_id, .. } => {
+                    Some(tool_finished_activity(&mut state, tool_call_id.get(), true))
+                }
+                LifecycleEventKind::ToolDenied { tool_call_id, .. }
+                | LifecycleEventKind::ToolFailed { tool_call_id, .. } => Some(
+                    tool_finished_activity(&mut state, tool_call_id.get(), false),
+                ),
+                _ => None,
+            };
+
+        if let (Some(activity), Some(events)) = (activity, &self.events) {
+            let _ = events.send(TurnEvent::Activity(activity));
+        }
+    }
+}
+
+/// Builds the finish activity for one tool call started earlier in the turn.
+fn tool_finished_activity(
+    state: &mut HarnessTurnObserverState,
+    tool_call_id: u64,
+    succeeded: bool,
+) -> TurnActivity {
+    TurnActivity::ToolFinished {
+        call_id: Some(tool_call_id.to_string()),
+        command: None,
+        exit_code: None,
+        succeeded,
+        tool: state.tool_names.remove(&tool_call_id).unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use serde_json::{Value, json};
+    use tempfile::tempdir;
+
+    use super::*;
+
+    /// Test model that returns one fixed structured output.
+    pub(crate) struct OutputModel(pub(crate) Value);
+
+    #[async_trait]
+    impl Model for OutputModel {
+        async fn complete(&self, _request: ModelRequest) -> Result<ModelResponse, ModelError> {
+            Ok(ModelResponse::Output(self.0.clone()))
+        }
+    }
+
+    /// Test model that records the advertised tool names before returning a
+    /// fixed structured output.
+    struct ToolRecordingModel {
+        output: Value,
+        tool_names: std::sync::Mutex<Vec<&'static str>>,
+    }
+
+    #[async_trait]
+    impl Model for ToolRecordingModel {
+        async fn complete(&self, request: ModelRequest) -> Result<ModelResponse, ModelError> {
+            self.tool_names
+                .lock()
+                .expect("tool recorder should not be poisoned")
+                .extend(request.tools().iter().map(ag_harness::ToolDefinition::name));
+
+            Ok(ModelResponse::Output(self.output.clone()))
+        }
+    }
+
+    /// Builds one environment lookup from fixed `(name, value)` pairs.
+    fn environment(
+        values: &'static [(&'static str, &'static str)],
+    ) -> impl Fn(&str) -> Option<String> {
+        move |name| {
+            values
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (*value).to_string())
+        }
+    }
+
+    #[test]
+    fn test_is_harness_configured_in_requires_non_empty_provider_key() {
+        // Arrange
+        let empty = environment(&[]);
+        let blank = environment(&[("DASHSCOPE_API_KEY", "  ")]);
+        let kimi = environment(&[("MOONSHOT_API_KEY", "secret")]);
+        let local_key_only = environment(&[("OPENAI_COMPATIBLE_API_KEY", "secret")]);
+        let local_endpoint =
+            environment(&[("OPENAI_COMPATIBLE_BASE_URL", "http://127.0.0.1:8080/v1")]);
+
+        // Act / Assert
+        assert!(!is_harness_configured_in(empty));
+        assert!(!is_harness_configured_in(blank));
+        assert!(is_harness_configured_in(kimi));
+        assert!(!is_harness_configured_in(local_key_only));
+        assert!(is_harness_configured_in(local_endpoint));
+    }
+
+    #[test]
+    fn test_model_client_from_environment_serves_openai_compatible_endpoint_model() {
+        // Arrange
+        let lookup = environment(&[
+            ("OPENAI_COMPATIBLE_BASE_URL", "http://127.0.0.1:8080/v1"),
+            ("OPENAI_COMPATIBLE_MODEL", "llama-3.3-8b-instruct"),
+            ("OPENAI_COMPATIBLE_STRUCTURED_OUTPUT", "prompted"),
+        ]);
+
+        // Act
+        let client = model_client_from_environment("openai-compatible", &lookup)
+            .expect("local endpoint should be configured");
+
+        // Assert
+        assert_eq!(client.metadata().provider(), "openai_compatible");
+        assert_eq!(client.metadata().model(), "llama-3.3-8b-instruct");
+    }
+
+    #[test]
+    fn test_model_client_from_environment_reports_incomplete_openai_compatible_settings() {
+        // Arrange
+        let missing_endpoint = environment(&[("OPENAI_COMPATIBLE_MODEL", "llama")]);
+        let missing_model = environment(&[("OPENAI_COMPATIBLE_BASE_URL", "http://127.0.0.1/v1")]);
+        let invalid_capability = environment(&[
+            ("OPENAI_COMPATIBLE_BASE_URL", "http://127.0.0.1/v1"),
+            ("OPENAI_COMPATIBLE_MODEL", "llama"),
+            ("OPENAI_COMPATIBLE_STRUCTURED_OUTPUT", "grammar"),
+        ]);
+
+        // Act
+        let errors = [missing_endpoint, missing_model, invalid_capability].map(|lookup| {
+            model_client_from_environment("openai-compatible", lookup)
+                .err()
+                .unwrap_or_default()
+        });
+
+        // Assert
+        assert_eq!(
+            errors,
+            [
+                "Set `OPENAI_COMPATIBLE_BASE_URL` to run `openai-compatible` through the harness \
+                 backend"
+                    .to_string(),
+                "Set `OPENAI_COMPATIBLE_MODEL` to run `openai-compatible` through the harness \
+                 backend"
+                    .to_string(),
+                "Unknown `OPENAI_COMPATIBLE_STRUCTURED_OUTPUT` value `grammar`; use \
+                 `json_schema`, `json_object`, or `prompted`"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_model_client_from_environment_routes_models_to_providers() {
+        // Arrange
+        let lookup = environment(&[
+            ("DASHSCOPE_API_KEY", "qwen-secret"),
+            ("MOONSHOT_API_KEY", "kimi-secret"),
+            ("MODEL_API_KEY", "muse-secret"),
+        ]);
+
+        // Act
+        let providers = ["qwen-plus", "kimi-k2.6", "muse-spark-1.2"].map(|model| {
+            model_client_from_environment(model, &lookup).map(|client| client.metadata().provider())
+        });
+
+        // Assert
+        assert_eq!(
+            providers,
+            [Ok("alibaba_cloud"), Ok("moonshot_ai"), Ok("meta")]
+        );
+    }
+
+    #[test]
+    fn test_model_client_from_environment_reports_missing_key_and_unknown_model() {
+        // Arrange
+        let lookup = environment(&[]);
+
+        // Act
+        let missing_key = model_client_from_environment("kimi-k2.6", &lookup).err();
+        let unknown_model = model_client_from_environment("gpt-5.6-sol", &lookup).err();
+
+        // Assert
+        assert_eq!(
+            missing_key.as_deref(),
+            Some("Set `MOONSHOT_API_KEY` to run `kimi-k2.6` through the harness backend")
+        );
+        assert_eq!(
+            unknown_model.as_deref(),
+            Some("Model `gpt-5.6-sol` is not served by the harness backend")
+        );
+    }
+
+    #[test]
+    fn test_harness_backend_refuses_to_build_commands() {
+        // Arrange
+        let temp_directory = tempdir().expect("failed to create temp dir");
+        let request_kind = crate::channel::AgentRequestKind::SessionStart;
+
+        // Act
+        let result = HarnessBackend.build_command(BuildCommandRequest {
+            attachments: &[],
+            folder: temp_directory.path(),
+            main_checkout_root: None,
+            model: "qwen-plus",
+            permission_mode: PermissionMode::AutoEdit,
+            personality_prompt: None,
+            prompt: "hello",
+            reasoning_level: crate::model::agent::ReasoningLevel::High,
+            replay_transcript: None,
+            request_kind: &request_kind,
+            speed_mode: crate::model::session::SpeedMode::Normal,
+        });
+
+        // Assert
+        assert!(matches!(result, Err(AgentBackendError::CommandBuild(_))));
+    }
+
+    #[tokio::test]
+    async fn test_run_harness_turn_parses_structured_output_into_protocol_response() {
+        // Arrange
+        let temp_directory = tempdir().expect("failed to create temp dir");
+        let model = Arc::new(OutputModel(json!({
+            "answer": "Done.",
+            "questions": [],
+            "summary": { "session": "Session summary", "turn": "Turn summary" }
+        })));
+
+        // Act
+        let output = run_harness_turn(
+            model,
+            HarnessTurnRequest {
+                folder: temp_directory.path(),
+                permission_mode: PermissionMode::AutoEdit,
+                prompt: "Say done".to_string(),
+                protocol_profile: ProtocolRequestProfile::SessionTurn,
+            },
+            None,
+        )
+        .await
+        .expect("harness turn should succeed");
+
+        // Assert
+        assert_eq!(output.response.answer, "Done.");
+        assert_eq!(
+            output
+                .response
+                .summary
+                .as_ref()
+                .map(|summary| summary.turn.as_str()),
+            Some("Turn summary")
+        );
+        assert_eq!((output.input_tokens, output.output_tokens), (0, 0));
+    }
+
+    #[tokio::test]
+    async fn test_run_harness_turn_rejects_output_outside_response_protocol() {
+        // Arrange
+        let temp_directory = tempdir().expect("failed to create temp dir");
+        let model = Arc::new(OutputModel(json!({ "unexpected": true })));
+
+        // Act
+        let error = run_harness_turn(
+            model,
+            HarnessTurnRequest {
+                folder: temp_directory.path(),
+                permission_mode: PermissionMode::ReadOnly,
+                prompt: "Say done".to_string(),
+                protocol_profile: ProtocolRequestProfile::SessionTurn,
+            },
+            None,
+        )
+        .await
+        .expect_err("protocol mismatch should fail");
+
+        // Assert
+        assert!(
+            error.contains("did not match the required JSON schema from harness"),
+            "{error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_harness_turn_enables_tools_by_permission_mode() {
+        // Arrange
+        let temp_directory = tempdir().expect("failed to create temp dir");
+        let structured_output = json!({
+            "answer": "Done.",
+            "questions": [],
+            "summary": { "session": "Session summary", "turn": "Turn summary" }
+        });
+        let permission_modes = [
+            PermissionMode::AutoEdit,
+            PermissionMode::Ask,
+            PermissionMode::ReadOnly,
+        ];
+
+        // Act
+        let mut advertised_tools = Vec::new();
+        for permission_mode in permission_modes {
+            let recording_model = Arc::new(ToolRecordingModel {
+                output: structured_output.clone(),
+                tool_names: std::sync::Mutex::new(Vec::new()),
+            });
+            run_harness_turn(
+                Arc::clone(&recording_model) as Arc<dyn Model>,
+                HarnessTurnRequest {
+                    folder: temp_directory.path(),
+                    permission_mode,
+                    prompt: "Say done".to_string(),
+                    protocol_profile: ProtocolRequestProfile::SessionTurn,
+                },
+                None,
+            )
+            .await
+            .expect("harness turn should succeed");
+            advertised_tools.push(
+                recording_model
+                    .tool_names
+                    .lock()
+                    .expect("tool recorder should not be poisoned")
+                    .clone(),
+            );
+        }
+
+        // Assert
+        assert_eq!(
+            advertised_tools,
+            vec![vec!["read", "write", "bash"], vec!["read"], vec!["read"],]
+        );
+    }
+}
@@ -16,6 +16,7 @@ use super::backend::{
 use super::prompt;
 use super::response_parser::ParsedResponse;
 use crate::app_server::AppServerClient;
+use crate::model::activity::TurnActivity;
 use crate::model::agent::AgentKind;
 
 /// Factory hook used to build or override provider-specific app-server
@@ -52,6 +53,14 @@ pub(crate) fn parse_stream_output_line(
     (provider_descriptor(kind).parse_stream_output_line)(stdout_line)
 }
 
+/// Parses one stream line into structured turn activity for CLI providers.
+///
+/// App-server providers emit activity from their own stream parsers, so this
+/// returns no activity for them.
+pub(crate) fn parse_stream_activity_line(kind: AgentKind, stdout_line: &str) -> Vec<TurnActivity> {
+    (provider_descriptor(kind).parse_stream_activity_line)(stdout_line)
+}
+
 /// Returns transport mode for the selected provider.
 pub fn transport_mode(kind: AgentKind) -> AgentTransport {
     provider_descriptor(kind).transport
@@ -139,6 +148,7 @@ struct AgentProviderDescriptor {
     app_server_thought_policy: AppServerThoughtPolicy,
     backend_factory: fn() -> Box<dyn AgentBackend>,
     parse_response: fn(&str, &str) -> ParsedResponse,
+    parse_stream_activity_line: fn(&str) -> Vec<TurnActivity>,
     parse_stream_output_line: fn(&str) -> Option<(String, bool)>,
     prompt_transport: AgentPromptTransport,
     protocol_schema_instruction_mode: ProtocolSchemaInstructionMode,
@@ -157,6 +167,7 @@ fn provider_descriptor(kind: AgentKind) -> AgentProviderDescriptor {
             app_server_thought_policy: AppServerThoughtPolicy::None,
             backend_factory: || Box::new(super::antigravity::AntigravityBackend::new()),
             parse_response: super::response_parser::parse_antigravity_response_with_fallback,
+            parse_stream_activity_line: |_stdout_line| Vec::new(),
             parse_stream_output_line: super::response_parser::parse_antigravity_stream_output_line,
             prompt_transport: AgentPromptTransport::Argv,
             protocol_schema_instruction_mode: ProtocolSchemaInstructionMode::TransportSchema,
@@ -172,6 +183,7 @@ fn provider_descriptor(kind: AgentKind) -> AgentProviderDescriptor {
             app_server_thought_policy: AppServerThoughtPolicy::None,
             backend_factory: || Box::new(super::gemini::GeminiBackend),
             parse_response: super::response_parser::parse_gemini_response_with_fallback,
+            parse_stream_activity_line: |_stdout_line| Vec::new(),
             parse_stream_output_line: super::response_parser::parse_gemini_stream_output_line,
             prompt_transport: AgentPromptTransport::Argv,
             protocol_schema_instruction_mode: ProtocolSchemaInstructionMode::PromptSchema,
@@ -182,6 +194,7 @@ fn provider_descriptor(kind: AgentKind) -> AgentProviderDescriptor {
             app_server_thought_policy: AppServerThoughtPolicy::None,
             backend_factory: || Box::new(super::claude::ClaudeBackend),
             parse_response: super::response_parser::parse_claude_response_with_fallback,
+            parse_stream_activity_line: super::response_parser::parse_claude_stream_activity_line,
             parse_stream_output_line: super::response_parser::parse_claude_stream_output_line,
             prompt_transport: AgentPromptTransport::Stdin,
             protocol_schema_instruction_mode: ProtocolSchemaInstructionMode::TransportSchema,
@@ -197,11 +210,23 @@ fn provider_descriptor(kind: AgentKind) -> AgentProviderDescriptor {
             app_server_thought_policy: AppServerThoughtPolicy::PhaseLabel,
             backend_factory: || Box::new(super::codex::CodexBackend),
             parse_response: super::response_parser::parse_codex_response_with_fallback,
+            parse_stream_activity_line: |_stdout_line| Vec::new(),
             parse_stream_output_line: super::response_parser::parse_codex_stream_output_line,
             prompt_transport: AgentPromptTransport::Argv,
             protocol_schema_instruction_mode: ProtocolSchemaInstructionMode::TransportSchema,
             transport: AgentTransport::AppServer,
         },
+        AgentKind::Harness => AgentProviderDescriptor {
+            app_server_client_factory: |_default_client| None,
+            app_server_thought_policy: AppServerThoughtPolicy::None,
+            backend_factory: || Box::new(super::harness::HarnessBackend),
+            parse_response: super::response_parser::parse_harness_response,
+            parse_stream_activity_line: |_stdout_line| Vec::new(),
+            parse_stream_output_line: |_stdout_line| None,
+            prompt_transport: AgentPromptTransport::Argv,
+            protocol_schema_instruction_mode: ProtocolSchemaInstructionMode::TransportSchema,
+            transport: AgentTransport::InProcess,
+        },
     }
 }
 
@@ -231,18 +256,21 @@ mod tests {
         let claude_kind = AgentKind::Claude;
         let codex_kind = AgentKind::Codex;
         let gemini_kind = AgentKind::Gemini;
+        let harness_kind = AgentKind::Harness;
 
         // Act
         let antigravity_transport = transport_mode(antigravity_kind);
         let claude_transport = transport_mode(claude_kind);
         let codex_transport = transport_mode(codex_kind);
         let gemini_transport = transport_mode(gemini_kind);
+        let harness_transport = transport_mode(harness_kind);
 
         // Assert
         assert_eq!(antigravity_transport, AgentTransport::AppServer);
         assert_eq!(claude_transport, AgentTransport::Cli);
         assert_eq!(codex_transport, AgentTransport::AppServer);
         assert_eq!(gemini_transport, AgentTransport::AppServer);
+        assert_eq!(harness_transport, AgentTransport::InProcess);
     }
 
     #[test]
@@ -289,6 +317,10 @@ mod tests {
             protocol_schema_instruction_mode(AgentKind::Codex),
             ProtocolSchemaInstructionMode::TransportSchema
         );
+        assert_eq!(
+            protocol_schema_instruction_mode(AgentKind::Harness),
+            ProtocolSchemaInstructionMode::TransportSchema
+        );
     }
 
     #[test]
@@ -302,6 +334,7 @@ mod tests {
             AgentKind::Claude,
             AgentKind::Codex,
             AgentKind::Gemini,
+            AgentKind::Harness,
         ] {
             // Act
             let error =
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use serde::Deserialize;
 
+use crate::model::activity::{TurnActivity, replacement_line_counts};
 use crate::model::session::{SessionDiffState, SessionStats};
 
 /// Parsed agent response including content text and usage statistics.
@@ -119,6 +120,15 @@ pub(super) fn parse_codex_response_with_fallback(stdout: &str, stderr: &str) ->
     })
 }
 
+/// Returns raw output for the in-process harness, which never produces CLI
+/// stdout; harness turns parse structured output before reaching this path.
+pub(super) fn parse_harness_response(stdout: &str, stderr: &str) -> ParsedResponse {
+    ParsedResponse {
+        content: fallback_response(stdout, stderr),
+        stats: SessionStats::default(),
+    }
+}
+
 /// Parses Antigravity `stream-json` output and falls back to raw output when
 /// the CLI emits an unstructured diagnostic.
 pub(super) fn parse_antigravity_response_with_fallback(
@@ -463,6 +473,137 @@ pub(super) fn parse_claude_stream_output_line(stdout_line: &str) -> Option<(Stri
     Some((progress_message, false))
 }
 
+/// Parses one Claude stream line into structured turn activity.
+///
+/// Assistant `tool_use` blocks report started tools, file edits, and web
+/// fetches; user `tool_result` blocks report the matching tool finish.
+pub(super) fn parse_claude_stream_activity_line(stdout_line: &str) -> Vec<TurnActivity> {
+    let Ok(stream_event) = serde_json::from_str::<serde_json::Value>(stdout_line.trim()) else {
+        return Vec::new();
+    };
+    let event_type = stream_event.get("type").and_then(serde_json::Value::as_str);
+    let content_blocks = stream_event
+        .get("message")
+        .and_then(|message| message.get("content"))
+        .and_then(serde_json::Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    content_blocks
+        .iter()
+        .filter_map(|content_block| match event_type {
+            Some("assistant") => claude_tool_use_activity(content_block),
+            Some("user") => claude_tool_result_activity(content_block),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Maps one Claude `tool_use` content block to structured activity.
+fn claude_tool_use_activity(content_block: &serde_json::Value) -> Option<TurnActivity> {
+    if content_block
+        .get("type")
+        .and_then(serde_json::Value::as_str)
+        != Some("tool_use")
+    {
+        return None;
+    }
+
+    let tool = content_block
+        .get("name")
+        .and_then(serde_json::Value::as_str)?;
+    let input = content_block
+        .get("input")
+        .unwrap_or(&serde_json::Value::Null);
+    let input_text = |field: &str| input.get(field).and_then(serde_json::Value::as_str);
+
+    match tool {
+        "Edit" | "MultiEdit" | "Write" | "NotebookEdit" => {
+            let path = input_text("file_path").or_else(|| input_text("notebook_path"))?;
+            let (added_lines, deleted_lines) = claude_edit_line_counts(input);
+
+            Some(TurnActivity::FilePatched {
+                added_lines,
+                deleted_lines,
+                path: path.to_string(),
+            })
+        }
+        "WebFetch" | "WebSearch" => {
+            let target = input_text("url").or_else(|| input_text("query"))?;
+
+            Some(TurnActivity::WebFetch {
+                target: target.to_string(),
+            })
+        }
+        _ => Some(TurnActivity::ToolStarted {
+            call_id: content_block
+                .get("id")
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_string),
+            command: ["command", "file_path", "pattern", "path", "description"]
+                .into_iter()
+                .find_map(input_text)
+                .map(str::to_string),
+            tool: tool.to_string(),
+        }),
+    }
+}
+
+/// Counts added and deleted lines for one Claude file-edit tool input.
+fn claude_edit_line_counts(input: &serde_json::Value) -> (u64, u64) {
+    if let Some(content) =
+        json_str_field(input, "content").or_else(|| json_str_field(input, "new_source"))
+    {
+        return replacement_line_counts("", content);
+    }
+
+    let edits = input
+        .get("edits")
+        .and_then(serde_json::Value::as_array)
+        .map_or_else(|| vec![input], |edits| edits.iter().collect());
+
+    edits.into_iter().fold((0, 0), |(added, deleted), edit| {
+        let (edit_added, edit_deleted) = replacement_line_counts(
+            json_str_field(edit, "old_string").unwrap_or_default(),
+            json_str_field(edit, "new_string").unwrap_or_default(),
+        );
+
+        (added + edit_added, deleted + edit_deleted)
+    })
+}
+
+/// Returns one string field from a JSON object.
+fn json_str_field<'value>(value: &'value serde_json::Value, field: &str) -> Option<&'value str> {
+    value.get(field).and_then(serde_json::Value::as_str)
+}
+
+/// Maps one Claude `tool_result` content block to a finished-tool activity.
+fn claude_tool_result_activity(content_block: &serde_json::Value) -> Option<TurnActivity> {
+    if content_block
+        .get("type")
+        .and_then(serde_json::Value::as_str)
+        != Some("tool_result")
+    {
+        return None;
+    }
+
+    let call_id = content_block
+        .get("tool_use_id")
+        .and_then(serde_json::Value::as_str)?;
+    let is_error = content_block
+        .get("is_error")
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(false);
+
+    Some(TurnActivity::ToolFinished {
+        call_id: Some(call_id.to_string()),
+        command: None,
+        exit_code: None,
+        succeeded: !is_error,
+        tool: "tool".to_string(),
+    })
+}
+
 /// Parses Gemini JSON/stream output into final response text and usage.
 fn parse_gemini_response(stdout: &str) -> Option<ParsedResponse> {
     let trimmed_stdout = stdout.trim();
@@ -1446,6 +1587,68 @@ mod tests {
         assert_eq!(parsed_line, Some(("Partial answer".to_string(), true)));
     }
 
+    #[test]
+    fn test_parse_claude_stream_activity_line_maps_tool_use_blocks() {
+        // Arrange
+        let stdout_line = concat!(
+            r#"{"type":"assistant","message":{"role":"assistant","content":["#,
+            r#"{"type":"tool_use","id":"toolu_1","name":"Bash","input":{"command":"cargo test"}},"#,
+            r#"{"type":"tool_use","id":"toolu_2","name":"Edit","input":{"file_path":"src/lib.rs","#,
+            r#""old_string":"old","new_string":"new\nmore"}},"#,
+            r#"{"type":"tool_use","id":"toolu_3","name":"WebFetch","input":{"url":"https://example.com"}}"#,
+            r#"]}}"#
+        );
+
+        // Act
+        let activities = parse_claude_stream_activity_line(stdout_line);
+
+        // Assert
+        assert_eq!(
+            activities,
+            vec![
+                TurnActivity::ToolStarted {
+                    call_id: Some("toolu_1".to_string()),
+                    command: Some("cargo test".to_string()),
+                    tool: "Bash".to_string(),
+                },
+                TurnActivity::FilePatched {
+                    added_lines: 2,
+                    deleted_lines: 1,
+                    path: "src/lib.rs".to_string(),
+                },
+                TurnActivity::WebFetch {
+                    target: "https://example.com".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_claude_stream_activity_line_maps_tool_results_to_finishes() {
+        // Arrange
+        let stdout_line = concat!(
+            r#"{"type":"user","message":{"role":"user","content":["#,
+            r#"{"type":"tool_result","tool_use_id":"toolu_1","is_error":true,"content":"boom"}"#,
+            r#"]}}"#
+        );
+
+        // Act
+        let activities = parse_claude_stream_activity_line(stdout_line);
+
+        // Assert
+        assert_eq!(
+            activities,
+            vec![TurnActivity::ToolFinished {
+                call_id: Some("toolu_1".to_string()),
+                command: None,
+                exit_code: None,
+                succeeded: false,
+                tool: "tool".to_string(),
+            }]
+        );
+        assert_eq!(parse_claude_stream_activity_line("not json"), Vec::new());
+    }
+
     #[test]
     fn test_parse_claude_stream_output_line_reads_message_event_content() {
         // Arrange
@@ -9,14 +9,16 @@ use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
 use ag_protocol::{
-    AgentResponse, build_protocol_repair_prompt, format_protocol_parse_debug_details,
-    parse_agent_response_strict,
+    AgentResponse, ProtocolSchemaInstructionMode, build_protocol_repair_prompt,
+    format_protocol_parse_debug_details, parse_agent_response_strict,
 };
 use async_trait::async_trait;
 
 use super::backend::{AgentBackend, BuildCommandRequest};
 use super::cli::error;
 use super::cli::execution::{self, CliExecutionError, CliExecutionObserver, CliExitStatus};
+use super::harness::{self, HarnessModelResolver, HarnessTurnRequest};
+use super::prompt::build_cli_prompt_text;
 use super::{
     ParsedResponse, create_app_server_client, create_backend, parse_response, transport_mode,
 };
@@ -133,8 +135,13 @@ async fn submit_one_shot_with_stats_and_app_server_client(
     app_server_client_override: Option<Arc<dyn AppServerClient>>,
 ) -> Result<OneShotSubmission, String> {
     let backend = create_backend(request.agent_kind);
+    let transport = transport_mode(request.agent_kind);
 
-    if transport_mode(request.agent_kind).uses_app_server() {
+    if transport.runs_in_process() {
+        return submit_one_shot_with_harness(harness::environment_model_resolver(), request).await;
+    }
+
+    if transport.uses_app_server() {
         let app_server_client =
             create_app_server_client(request.agent_kind, app_server_client_override).ok_or_else(
                 || {
@@ -183,6 +190,7 @@ async fn submit_one_shot_with_app_server_client(
         reasoning_level: request.reasoning_level,
         session_id: session_id.clone(),
         speed_mode: request.speed_mode,
+        tool_approval: None,
     };
 
     let turn_result = app_server_client.run_turn(turn_request, stream_tx).await;
@@ -233,6 +241,61 @@ async fn submit_one_shot_with_app_server_client(
     })
 }
 
+/// Executes one isolated prompt through the in-process harness.
+///
+/// The harness validates structured output natively, so no protocol-repair
+/// retry is attempted.
+///
+/// # Errors
+/// Returns an error when the model cannot be resolved, the prompt cannot be
+/// rendered, or the harness turn fails.
+async fn submit_one_shot_with_harness(
+    model_resolver: HarnessModelResolver,
+    request: OneShotRequest,
+) -> Result<OneShotSubmission, String> {
+    let model_id = request.model.provider_model_str();
+    let model = model_resolver(model_id)?;
+    let prompt_payload = ag_protocol::TurnPrompt::from_agent_data(request.prompt.clone());
+    let prompt = build_cli_prompt_text(
+        BuildCommandRequest {
+            attachments: &prompt_payload.attachments,
+            folder: &request.folder,
+            main_checkout_root: None,
+            model: model_id,
+            permission_mode: request.permission_mode,
+            personality_prompt: None,
+            prompt: &request.prompt,
+            reasoning_level: request.reasoning_level,
+            replay_transcript: None,
+            request_kind: &request.request_kind,
+            speed_mode: request.speed_mode,
+        },
+        ProtocolSchemaInstructionMode::TransportSchema,
+        "Harness",
+    )
+    .map_err(|error| format!("Failed to build one-shot harness prompt: {error}"))?;
+    let output = harness::run_harness_turn(
+        model,
+        HarnessTurnRequest {
+            folder: &request.folder,
+            permission_mode: request.permission_mode,
+            prompt,
+            protocol_profile: request.request_kind.protocol_profile(),
+        },
+        None,
+    )
+    .await?;
+
+    Ok(OneShotSubmission {
+        response: output.response,
+        stats: SessionStats {
+            input_tokens: output.input_tokens,
+            output_tokens: output.output_tokens,
+            ..SessionStats::default()
+        },
+    })
+}
+
 /// Executes one isolated prompt using the provided backend.
 ///
 /// This shared helper keeps process execution behind the existing
@@ -335,6 +398,7 @@ async fn attempt_one_shot_app_server_repair(
         reasoning_level: request.reasoning_level,
         session_id: session_id.to_string(),
         speed_mode: request.speed_mode,
+        tool_approval: None,
     };
     let repair_result = app_server_client
         .run_turn(repair_turn_request, repair_stream_tx)
@@ -1276,4 +1340,42 @@ mod tests {
         assert!(error.contains("debug_details:"));
         assert!(error.contains("response:\nplain text"));
     }
+
+    #[tokio::test]
+    /// Verifies harness one-shot prompts resolve the selected model and
+    /// return the structured protocol response without a CLI subprocess.
+    async fn test_submit_one_shot_with_harness_returns_structured_response() {
+        // Arrange
+        let temp_directory = tempdir().expect("failed to create temp dir");
+        let model_resolver: HarnessModelResolver = Arc::new(|model: &str| {
+            assert_eq!(model, "muse-spark-1.2");
+
+            Ok(Arc::new(harness::tests::OutputModel(serde_json::json!({
+                "answer": "Add harness backend",
+                "questions": []
+            }))) as Arc<dyn ag_harness::Model>)
+        });
+
+        // Act
+        let submission = submit_one_shot_with_harness(
+            model_resolver,
+            OneShotRequest {
+                agent_kind: AgentKind::Harness,
+                child_pid: None,
+                folder: temp_directory.path().to_path_buf(),
+                model: AgentModel::MuseSpark12,
+                permission_mode: PermissionMode::ReadOnly,
+                prompt: "Generate title".to_string(),
+                request_kind: AgentRequestKind::UtilityPrompt,
+                reasoning_level: ReasoningLevel::default(),
+                speed_mode: SpeedMode::Normal,
+            },
+        )
+        .await
+        .expect("harness one-shot should succeed");
+
+        // Assert
+        assert_eq!(submission.response.answer, "Add harness backend");
+        assert_eq!(submission.stats, SessionStats::default());
+    }
 }
@@ -9,7 +9,8 @@ use ag_protocol::TurnPrompt;
 use tokio::sync::mpsc;
 
 use crate::app_server::AppServerError;
-use crate::channel::{AgentRequestKind, LiveTranscript, PersonalityPrompt};
+use crate::channel::{AgentRequestKind, LiveTranscript, PersonalityPrompt, ToolApprovalHandler};
+use crate::model::activity::TurnActivity;
 use crate::model::agent::ReasoningLevel;
 use crate::model::permission::PermissionMode;
 use crate::model::session::SpeedMode;
@@ -44,6 +45,8 @@ pub enum AppServerStreamEvent {
     },
     /// An `item/started` event produced a progress description.
     ProgressUpdate(String),
+    /// A provider notification described one structured turn step.
+    Activity(TurnActivity),
 }
 
 /// Input payload for one app-server turn execution.
@@ -87,6 +90,9 @@ pub struct AppServerTurnRequest {
     pub session_id: String,
     /// Response-speed preference for this turn.
     pub speed_mode: SpeedMode,
+    /// Host port that answers provider approval requests in
+    /// [`PermissionMode::Ask`] turns.
+    pub tool_approval: Option<Arc<dyn ToolApprovalHandler>>,
 }
 
 /// Normalized result for one app-server turn.
@@ -139,6 +139,7 @@ mod tests {
             replay_transcript: None,
             session_id: "test-session".to_string(),
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         };
 
         // Act
@@ -166,6 +167,7 @@ mod tests {
             replay_transcript: Some("queued transcript".to_string()),
             session_id: "test-session".to_string(),
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         };
 
         // Act
@@ -193,6 +195,7 @@ mod tests {
             replay_transcript: None,
             session_id: "test-session".to_string(),
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         };
 
         // Act
@@ -350,6 +353,7 @@ mod tests {
             replay_transcript: None,
             session_id: "test-session".to_string(),
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         };
 
         // Act
@@ -520,6 +520,7 @@ mod tests {
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         };
 
         // Act
@@ -547,6 +548,7 @@ mod tests {
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         };
 
         // Act
@@ -574,6 +576,7 @@ mod tests {
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         };
 
         // Act
@@ -601,6 +604,7 @@ mod tests {
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         };
 
         // Act
@@ -629,6 +633,7 @@ mod tests {
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         };
         let captured_retry_prompt = Arc::new(Mutex::new(String::new()));
 
@@ -711,6 +716,7 @@ mod tests {
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         };
         let shutdown_count = Arc::new(AtomicUsize::new(0));
 
@@ -778,6 +784,7 @@ mod tests {
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         };
         let start_count = Arc::new(AtomicUsize::new(0));
         let run_count = Arc::new(AtomicUsize::new(0));
@@ -866,6 +873,7 @@ mod tests {
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         };
         let run_count = Arc::new(AtomicUsize::new(0));
         let shutdown_count = Arc::new(AtomicUsize::new(0));
@@ -943,6 +951,7 @@ mod tests {
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         };
         let captured_prompt = Arc::new(Mutex::new(String::new()));
 
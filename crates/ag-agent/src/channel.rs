@@ -8,13 +8,15 @@ pub(crate) mod app_server;
 pub(crate) mod cli;
 mod contract;
 mod factory;
+pub(crate) mod harness;
 
 #[cfg(any(test, feature = "test-utils"))]
 pub use contract::MockAgentChannel;
 pub(crate) use contract::PersonalityPromptUpdate;
 pub use contract::{
     AgentChannel, AgentError, AgentFuture, AgentRequestKind, LiveTranscript, PersonalityPrompt,
-    SessionRef, StartSessionRequest, TurnContinuation, TurnEvent, TurnRequest, TurnResult,
+    SessionRef, StartSessionRequest, ToolApprovalDecision, ToolApprovalHandler, ToolApprovalKind,
+    ToolApprovalRequest, TurnContinuation, TurnEvent, TurnRequest, TurnResult,
 };
 pub use factory::create_agent_channel;
 #[cfg(any(test, feature = "test-utils"))]
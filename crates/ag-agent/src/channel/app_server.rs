@@ -23,7 +23,7 @@ use crate::model::agent::AgentKind;
 /// Turn execution is delegated to [`AppServerClient::run_turn`].
 /// [`AppServerStreamEvent`]s emitted by the provider are bridged to
 /// [`TurnEvent::ThoughtDelta`] values when transient loader text should be
-/// updated.
+/// updated and to [`TurnEvent::Activity`] for structured turn steps.
 pub(crate) struct AppServerAgentChannel {
     /// Provider-specific app-server client.
     client: Arc<dyn AppServerClient>,
@@ -86,6 +86,7 @@ impl AgentChannel for AppServerAgentChannel {
                 reasoning_level: req.reasoning_level,
                 session_id,
                 speed_mode: req.speed_mode,
+                tool_approval: req.tool_approval,
             };
             let protocol_profile = request.request_kind.protocol_profile();
             let repair_request = request.clone();
@@ -126,6 +127,10 @@ impl AgentChannel for AppServerAgentChannel {
                                 // Fire-and-forget: receiver may be dropped during shutdown.
                                 let _ = events.send(TurnEvent::ThoughtDelta(trimmed.to_string()));
                             }
+                            AppServerStreamEvent::Activity(activity) => {
+                                // Fire-and-forget: receiver may be dropped during shutdown.
+                                let _ = events.send(TurnEvent::Activity(activity));
+                            }
                         }
                     }
                 })
@@ -250,6 +255,7 @@ async fn parse_or_repair_app_server_response(
         reasoning_level: repair_request.reasoning_level,
         session_id: repair_request.session_id,
         speed_mode: repair_request.speed_mode,
+        tool_approval: repair_request.tool_approval,
     };
     let (repair_stream_tx, _repair_stream_rx) = mpsc::unbounded_channel();
     let repair_result = client
@@ -304,6 +310,7 @@ mod tests {
             reasoning_level: ReasoningLevel::default(),
             request_kind: AgentRequestKind::SessionStart,
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         }
     }
 
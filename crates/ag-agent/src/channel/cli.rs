@@ -25,7 +25,9 @@ use crate::model::agent::AgentKind;
 ///
 /// Stdout lines are classified by
 /// [`agent::parse_stream_output_line`] and transient loader updates are
-/// forwarded as [`TurnEvent::ThoughtDelta`]. A kill signal transitions the
+/// forwarded as [`TurnEvent::ThoughtDelta`]. Structured tool and file-edit
+/// steps from [`agent::parse_stream_activity_line`] are forwarded as
+/// [`TurnEvent::Activity`]. A kill signal transitions the
 /// turn to a failed state with a `[Stopped]` banner. A spawn failure is
 /// surfaced through [`AgentError`].
 pub(crate) struct CliAgentChannel {
@@ -61,6 +63,10 @@ impl CliExecutionObserver for CliTurnObserver {
     }
 
     fn stdout_line(&self, line: &str) {
+        for activity in agent::parse_stream_activity_line(self.kind, line) {
+            let _ = self.events.send(TurnEvent::Activity(activity));
+        }
+
         let Some((text, is_response_content)) = agent::parse_stream_output_line(self.kind, line)
         else {
             return;
@@ -361,6 +367,7 @@ mod tests {
             reasoning_level: ReasoningLevel::default(),
             request_kind: AgentRequestKind::SessionStart,
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         }
     }
 
@@ -560,6 +567,7 @@ mod tests {
             reasoning_level: ReasoningLevel::default(),
             request_kind: AgentRequestKind::SessionStart,
             speed_mode: crate::model::session::SpeedMode::default(),
+            tool_approval: None,
         };
         let prompt_text = request.prompt.agent_text();
 
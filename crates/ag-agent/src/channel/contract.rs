@@ -9,6 +9,7 @@ use std::sync::Arc;
 use ag_protocol::{AgentResponse, ProtocolRequestProfile, TurnPrompt};
 use tokio::sync::mpsc;
 
+use crate::model::activity::TurnActivity;
 use crate::model::agent::ReasoningLevel;
 use crate::model::permission::PermissionMode;
 use crate::model::session::SpeedMode;
@@ -22,6 +23,52 @@ pub trait LiveTranscript: fmt::Debug + Send + Sync {
     fn replay_text(&self) -> Option<String>;
 }
 
+/// Provider request category that needs an explicit user decision.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ToolApprovalKind {
+    /// Shell command execution.
+    Command,
+    /// File edit outside the automatically approved session worktree.
+    FileChange,
+    /// Any other provider tool call.
+    Tool,
+}
+
+/// One provider approval request surfaced to the user in
+/// [`PermissionMode::Ask`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ToolApprovalRequest {
+    /// Optional supporting detail, such as the working directory or the
+    /// provider-reported reason.
+    pub detail: Option<String>,
+    /// Provider-reported request category.
+    pub kind: ToolApprovalKind,
+    /// One-line description of the action, such as the command line.
+    pub summary: String,
+}
+
+/// User decision for one [`ToolApprovalRequest`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ToolApprovalDecision {
+    /// Runs this action once.
+    AllowOnce,
+    /// Runs this action and lets the provider skip identical requests for the
+    /// rest of its session.
+    AllowForSession,
+    /// Rejects this action; the agent continues without it.
+    Deny,
+}
+
+/// Host port that answers provider approval requests during ask-mode turns.
+///
+/// Provider turn loops await the returned future, so the turn blocks until the
+/// host answers. Dropping the pending answer must resolve to
+/// [`ToolApprovalDecision::Deny`].
+pub trait ToolApprovalHandler: fmt::Debug + Send + Sync {
+    /// Waits for the user's decision on one provider request.
+    fn request_approval(&self, request: ToolApprovalRequest) -> AgentFuture<ToolApprovalDecision>;
+}
+
 /// Turn initiation mode for [`TurnRequest`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AgentRequestKind {
@@ -272,6 +319,11 @@ pub struct TurnRequest {
     pub request_kind: AgentRequestKind,
     /// Response-speed preference for the turn.
     pub speed_mode: SpeedMode,
+    /// Host port that answers provider approval requests in
+    /// [`PermissionMode::Ask`] turns.
+    ///
+    /// When absent, ask-mode approval requests are denied.
+    pub tool_approval: Option<Arc<dyn ToolApprovalHandler>>,
 }
 
 /// Incremental event emitted during one agent turn.
@@ -284,6 +336,8 @@ pub enum TurnEvent {
     /// A streamed thinking/planning or tool-status fragment shown in the
     /// transient loader.
     ThoughtDelta(String),
+    /// A structured tool, file-edit, or web step for the live activity log.
+    Activity(TurnActivity),
     /// The turn completed successfully with final token counts.
     Completed {
         /// Whether the provider reset its context for this turn.
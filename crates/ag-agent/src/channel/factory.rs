@@ -7,12 +7,14 @@ use crate::app_server::AppServerClient;
 use crate::channel::app_server::AppServerAgentChannel;
 use crate::channel::cli::CliAgentChannel;
 use crate::channel::contract::AgentChannel;
+use crate::channel::harness::HarnessAgentChannel;
 use crate::model::agent::AgentKind;
 
 /// Creates the provider-specific channel for the given agent kind.
 ///
 /// Claude uses [`CliAgentChannel`]; persistent runtime providers
-/// (Antigravity, Gemini, Codex) use [`AppServerAgentChannel`].
+/// (Antigravity, Gemini, Codex) use [`AppServerAgentChannel`]; the in-process
+/// harness uses [`HarnessAgentChannel`].
 pub fn create_agent_channel(
     kind: AgentKind,
     app_server_client_override: Option<Arc<dyn AppServerClient>>,
@@ -20,7 +22,11 @@ pub fn create_agent_channel(
     let backend = agent::create_backend(kind);
     let transport = agent::transport_mode(kind);
 
-    if transport.uses_app_server() {
+    if transport.runs_in_process() {
+        Arc::new(HarnessAgentChannel::new(
+            agent::harness::environment_model_resolver(),
+        ))
+    } else if transport.uses_app_server() {
         match agent::create_app_server_client(kind, app_server_client_override) {
             Some(app_server_client) => {
                 Arc::new(AppServerAgentChannel::new(app_server_client, kind))
@@ -72,6 +78,15 @@ mod tests {
         assert_eq!(Arc::strong_count(&channel), 1);
     }
 
+    #[test]
+    fn create_agent_channel_returns_harness_channel_for_harness() {
+        // Arrange / Act
+        let channel = create_agent_channel(AgentKind::Harness, None);
+
+        // Assert
+        assert_eq!(Arc::strong_count(&channel), 1);
+    }
+
     #[test]
     fn create_agent_channel_returns_app_server_channel_for_gemini() {
         // Arrange / Act
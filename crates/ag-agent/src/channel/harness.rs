@@ -0,0 +1,232 @@
+//! In-process harness [`AgentChannel`] adapter.
+//!
+//! Runs each turn through the `ag-harness` tool loop inside the Agentty
+//! process, rooted at the session worktree, and returns the same structured
+//! [`TurnResult`] produced by CLI and app-server channels.
+
+use ag_protocol::ProtocolSchemaInstructionMode;
+use tokio::sync::mpsc;
+
+use crate::agent::harness::{self, HarnessModelResolver, HarnessTurnRequest};
+use crate::agent::{self, BuildCommandRequest};
+use crate::channel::{
+    AgentChannel, AgentError, AgentFuture, SessionRef, StartSessionRequest, TurnEvent, TurnRequest,
+    TurnResult,
+};
+
+/// [`AgentChannel`] adapter that runs harness turns in-process.
+///
+/// Harness turns are stateless: each follow-up replays the prior transcript
+/// through the shared prompt bootstrap, and tool lifecycle steps stream as
+/// [`TurnEvent::Activity`] values while the turn runs.
+pub(crate) struct HarnessAgentChannel {
+    /// Resolves the selected provider model for each turn.
+    model_resolver: HarnessModelResolver,
+}
+
+impl HarnessAgentChannel {
+    /// Creates a harness channel that resolves models through
+    /// `model_resolver`.
+    pub(crate) fn new(model_resolver: HarnessModelResolver) -> Self {
+        Self { model_resolver }
+    }
+}
+
+/// Renders the full harness prompt, including replay and protocol guidance.
+///
+/// # Errors
+/// Returns an error when attachment or prompt template rendering fails.
+fn build_harness_prompt(request: &TurnRequest) -> Result<String, AgentError> {
+    let prompt_text = request.prompt.agent_text();
+    let build_request = BuildCommandRequest {
+        attachments: &request.prompt.attachments,
+        folder: &request.folder,
+        main_checkout_root: request.main_checkout_root.as_deref(),
+        model: &request.model,
+        permission_mode: request.permission_mode,
+        personality_prompt: request.personality.current(),
+        prompt: &prompt_text,
+        reasoning_level: request.reasoning_level,
+        replay_transcript: request.continuation.replay_transcript(),
+        request_kind: &request.request_kind,
+        speed_mode: request.speed_mode,
+    };
+
+    agent::build_cli_prompt_text(
+        build_request,
+        ProtocolSchemaInstructionMode::TransportSchema,
+        "Harness",
+    )
+    .map_err(|error| AgentError::Backend(error.to_string()))
+}
+
+impl AgentChannel for HarnessAgentChannel {
+    /// Returns a [`SessionRef`] immediately; harness turns are stateless.
+    fn start_session(
+        &self,
+        req: StartSessionRequest,
+    ) -> AgentFuture<Result<SessionRef, AgentError>> {
+        let session_id = req.session_id;
+
+        Box::pin(async move { Ok(SessionRef { session_id }) })
+    }
+
+    /// Runs one harness turn against the session worktree.
+    ///
+    /// # Errors
+    /// Returns [`AgentError::Backend`] when the model cannot be resolved, the
+    /// harness turn fails, or its output does not match the protocol.
+    fn run_turn(
+        &self,
+        _session_id: String,
+        req: TurnRequest,
+        events: mpsc::UnboundedSender<TurnEvent>,
+    ) -> AgentFuture<Result<TurnResult, AgentError>> {
+        let model_resolver = self.model_resolver.clone();
+
+        Box::pin(async move {
+            let model = model_resolver(&req.model).map_err(AgentError::Backend)?;
+            let prompt = build_harness_prompt(&req)?;
+            let output = harness::run_harness_turn(
+                model,
+                HarnessTurnRequest {
+                    folder: &req.folder,
+                    permission_mode: req.permission_mode,
+                    prompt,
+                    protocol_profile: req.request_kind.protocol_profile(),
+                },
+                Some(events),
+            )
+            .await
+            .map_err(AgentError::Backend)?;
+
+            Ok(TurnResult {
+                assistant_message: output.response,
+                context_reset: false,
+                input_tokens: output.input_tokens,
+                output_tokens: output.output_tokens,
+                provider_conversation_id: None,
+            })
+        })
+    }
+
+    /// No-op; harness turns keep no session state between turns.
+    fn shutdown_session(&self, _session_id: String) -> AgentFuture<Result<(), AgentError>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use ag_harness::Model;
+    use ag_protocol::TurnPrompt;
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::agent::harness::tests::OutputModel;
+    use crate::channel::{AgentRequestKind, PersonalityPrompt, TurnContinuation};
+    use crate::model::agent::ReasoningLevel;
+    use crate::model::permission::PermissionMode;
+    use crate::model::session::SpeedMode;
+
+    /// Builds one harness turn request rooted at `folder`.
+    fn turn_request(folder: &std::path::Path, model: &str) -> TurnRequest {
+        TurnRequest {
+            continuation: TurnContinuation::replaying("Earlier turn".to_string()),
+            folder: folder.to_path_buf(),
+            main_checkout_root: None,
+            model: model.to_string(),
+            permission_mode: PermissionMode::AutoEdit,
+            personality: PersonalityPrompt::default(),
+            prompt: TurnPrompt::from_text("Summarize the repository".to_string()),
+            reasoning_level: ReasoningLevel::High,
+            request_kind: AgentRequestKind::SessionResume,
+            speed_mode: SpeedMode::Normal,
+            tool_approval: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_turn_resolves_selected_model_and_returns_protocol_response() {
+        // Arrange
+        let temp_directory = tempdir().expect("failed to create temp dir");
+        let resolved_models = Arc::new(Mutex::new(Vec::new()));
+        let recorded_models = Arc::clone(&resolved_models);
+        let channel = HarnessAgentChannel::new(Arc::new(move |model: &str| {
+            recorded_models
+                .lock()
+                .expect("model lock should be available")
+                .push(model.to_string());
+
+            Ok(Arc::new(OutputModel(
+                json!({ "answer": "All set.", "questions": [] }),
+            )) as Arc<dyn Model>)
+        }));
+        let (events, _events_rx) = mpsc::unbounded_channel();
+
+        // Act
+        let result = channel
+            .run_turn(
+                "session-1".to_string(),
+                turn_request(temp_directory.path(), "kimi-k2.6"),
+                events,
+            )
+            .await
+            .expect("harness turn should succeed");
+
+        // Assert
+        assert_eq!(result.assistant_message.answer, "All set.");
+        assert_eq!(result.provider_conversation_id, None);
+        assert_eq!(
+            *resolved_models
+                .lock()
+                .expect("model lock should be available"),
+            vec!["kimi-k2.6".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_turn_surfaces_model_resolution_failure() {
+        // Arrange
+        let temp_directory = tempdir().expect("failed to create temp dir");
+        let channel = HarnessAgentChannel::new(Arc::new(|_model: &str| {
+            Err(
+                "Set `DASHSCOPE_API_KEY` to run `qwen-plus` through the harness backend"
+                    .to_string(),
+            )
+        }));
+        let (events, _events_rx) = mpsc::unbounded_channel();
+
+        // Act
+        let error = channel
+            .run_turn(
+                "session-1".to_string(),
+                turn_request(temp_directory.path(), "qwen-plus"),
+                events,
+            )
+            .await
+            .expect_err("missing credentials should fail the turn");
+
+        // Assert
+        assert!(
+            matches!(error, AgentError::Backend(message) if message.contains("DASHSCOPE_API_KEY"))
+        );
+    }
+
+    #[test]
+    fn test_build_harness_prompt_replays_prior_transcript() {
+        // Arrange
+        let temp_directory = tempdir().expect("failed to create temp dir");
+        let request = turn_request(temp_directory.path(), "qwen-plus");
+
+        // Act
+        let prompt = build_harness_prompt(&request).expect("prompt should render");
+
+        // Assert
+        assert!(prompt.contains("Earlier turn"));
+        assert!(prompt.contains("Summarize the repository"));
+    }
+}
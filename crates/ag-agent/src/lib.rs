@@ -31,11 +31,13 @@ pub use app_server::{
 };
 pub use channel::{
     AgentChannel, AgentError, AgentFuture, AgentRequestKind, LiveTranscript, PersonalityPrompt,
-    SessionRef, StartSessionRequest, TurnContinuation, TurnEvent, TurnRequest, TurnResult,
+    SessionRef, StartSessionRequest, ToolApprovalDecision, ToolApprovalHandler, ToolApprovalKind,
+    ToolApprovalRequest, TurnContinuation, TurnEvent, TurnRequest, TurnResult,
     create_agent_channel,
 };
 #[cfg(any(test, feature = "test-utils"))]
 pub use channel::{MockAgentChannel, create_cli_agent_channel_with_backend};
+pub use model::activity::TurnActivity;
 pub use model::agent::{
     AgentCliInfo, AgentCliVersion, AgentKind, AgentModel, AgentSelection, AgentSelectionMetadata,
     ReasoningLevel, parse_persisted_session_agent_model, resolve_agent_kind_for_model,
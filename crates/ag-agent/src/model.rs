@@ -1,5 +1,6 @@
 //! Agent-domain models shared by provider transports and Agentty.
 
+pub(crate) mod activity;
 pub(crate) mod agent;
 pub(crate) mod permission;
 pub(crate) mod session;
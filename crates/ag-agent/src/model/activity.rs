@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+
+/// One structured step observed in a provider stream while a turn runs.
+///
+/// Stream parsers emit these alongside transient loader text so hosts can
+/// show and persist a live activity log. The serialized form is stable
+/// because hosts store it with the turn transcript.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum TurnActivity {
+    /// A provider tool or shell command started running.
+    ToolStarted {
+        /// Provider call identifier used to pair the matching finish event.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        call_id: Option<String>,
+        /// Shell command or primary tool argument, when the provider sent one.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        command: Option<String>,
+        /// Provider tool name, such as `Bash` or `command`.
+        tool: String,
+    },
+    /// A provider tool or shell command finished.
+    ToolFinished {
+        /// Provider call identifier matching the earlier start event.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        call_id: Option<String>,
+        /// Shell command or primary tool argument, when the provider sent one.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        command: Option<String>,
+        /// Process exit code, when the provider reports one.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        exit_code: Option<i32>,
+        /// Whether the provider reported the call as successful.
+        succeeded: bool,
+        /// Provider tool name, such as `Bash` or `command`.
+        tool: String,
+    },
+    /// The agent created, edited, or deleted one file.
+    FilePatched {
+        /// Number of added lines in the patch.
+        added_lines: u64,
+        /// Number of deleted lines in the patch.
+        deleted_lines: u64,
+        /// Patched file path as reported by the provider.
+        path: String,
+    },
+    /// The agent fetched a URL or ran a web search.
+    WebFetch {
+        /// Fetched URL or search query.
+        target: String,
+    },
+}
+
+impl TurnActivity {
+    /// Encodes this activity into its persisted JSON form.
+    pub fn encode(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Decodes one persisted activity, returning `None` for unknown shapes.
+    pub fn decode(content: &str) -> Option<Self> {
+        serde_json::from_str(content).ok()
+    }
+}
+
+/// Counts added and deleted lines in one unified diff, skipping file headers.
+pub(crate) fn patch_line_counts(diff: &str) -> (u64, u64) {
+    let mut added_lines = 0;
+    let mut deleted_lines = 0;
+    for line in diff.lines() {
+        if line.starts_with("+++") || line.starts_with("---") {
+            continue;
+        }
+        if line.starts_with('+') {
+            added_lines += 1;
+        } else if line.starts_with('-') {
+            deleted_lines += 1;
+        }
+    }
+
+    (added_lines, deleted_lines)
+}
+
+/// Counts replaced lines for providers that report edits as old/new text.
+pub(crate) fn replacement_line_counts(old_text: &str, new_text: &str) -> (u64, u64) {
+    let line_count = |text: &str| u64::try_from(text.lines().count()).unwrap_or(u64::MAX);
+
+    (line_count(new_text), line_count(old_text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn activity_round_trips_through_persisted_json() {
+        // Arrange
+        let activity = TurnActivity::ToolFinished {
+            call_id: Some("call-1".to_string()),
+            command: Some("cargo test".to_string()),
+            exit_code: Some(101),
+            succeeded: false,
+            tool: "command".to_string(),
+        };
+
+        // Act
+        let encoded = activity.encode();
+        let decoded = TurnActivity::decode(&encoded);
+
+        // Assert
+        assert!(encoded.contains(r#""type":"tool_finished""#));
+        assert_eq!(decoded, Some(activity));
+        assert_eq!(TurnActivity::decode("not json"), None);
+    }
+
+    #[test]
+    fn patch_line_counts_skip_file_headers() {
+        // Arrange
+        let diff =
+            "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,2 +1,3 @@\n-old\n+new\n+more\n same\n";
+
+        // Act
+        let counts = patch_line_counts(diff);
+
+        // Assert
+        assert_eq!(counts, (2, 1));
+        assert_eq!(replacement_line_counts("a\nb", "c"), (1, 2));
+    }
+}
@@ -14,6 +14,9 @@ pub enum AgentKind {
     Claude,
     /// `OpenAI` Codex CLI/backend.
     Codex,
+    /// In-process `ag-harness` model loop for Qwen, Kimi, Muse, and
+    /// OpenAI-compatible endpoint models.
+    Harness,
 }
 
 /// One locally runnable agent CLI and the installed version refreshed at
@@ -108,6 +111,15 @@ pub enum AgentModel {
     ClaudeFable5,
     /// Claude Haiku model backed by `claude-haiku-4-5-20251001`.
     ClaudeHaiku4520251001,
+    /// Qwen model served by Alibaba Cloud Model Studio as `qwen-plus`.
+    QwenPlus,
+    /// Kimi model served by Moonshot AI as `kimi-k2.6`.
+    KimiK26,
+    /// Muse model served by Meta's Model API as `muse-spark-1.2`.
+    MuseSpark12,
+    /// Model served by a local or self-hosted OpenAI-compatible endpoint,
+    /// selected as `openai-compatible`.
+    OpenAiCompatible,
 }
 
 /// Session-level agent selection that keeps provider kind and model together.
@@ -190,7 +202,9 @@ impl AgentSelection {
             AgentKind::Codex if self.model == AgentModel::Gpt53CodexSpark => {
                 Self::new(AgentKind::Codex, AgentModel::Gpt56Sol)
             }
-            AgentKind::Antigravity | AgentKind::Gemini | AgentKind::Codex => self,
+            AgentKind::Antigravity | AgentKind::Gemini | AgentKind::Codex | AgentKind::Harness => {
+                self
+            }
         }
     }
 }
@@ -220,6 +234,10 @@ impl AgentModel {
             Self::ClaudeSonnet5 => "claude-sonnet-5",
             Self::ClaudeFable5 => "claude-fable-5",
             Self::ClaudeHaiku4520251001 => "claude-haiku-4-5-20251001",
+            Self::QwenPlus => "qwen-plus",
+            Self::KimiK26 => "kimi-k2.6",
+            Self::MuseSpark12 => "muse-spark-1.2",
+            Self::OpenAiCompatible => "openai-compatible",
         }
     }
 
@@ -560,6 +578,10 @@ impl FromStr for AgentModel {
             "claude-sonnet-5" => Ok(Self::ClaudeSonnet5),
             "claude-fable-5" => Ok(Self::ClaudeFable5),
             "claude-haiku-4-5-20251001" => Ok(Self::ClaudeHaiku4520251001),
+            "qwen-plus" => Ok(Self::QwenPlus),
+            "kimi-k2.6" => Ok(Self::KimiK26),
+            "muse-spark-1.2" => Ok(Self::MuseSpark12),
+            "openai-compatible" => Ok(Self::OpenAiCompatible),
             other => Err(format!("unknown model: {other}")),
         }
     }
@@ -585,6 +607,12 @@ impl AgentSelectionMetadata for AgentModel {
             Self::ClaudeSonnet5 => "Balanced Claude model for quality and latency.",
             Self::ClaudeFable5 => "Claude Fable model for creative, narrative-heavy tasks.",
             Self::ClaudeHaiku4520251001 => "Fast Claude model for lighter tasks.",
+            Self::QwenPlus => "Balanced Qwen model run in-process through ag-harness.",
+            Self::KimiK26 => "Kimi agentic coding model run in-process through ag-harness.",
+            Self::MuseSpark12 => "Muse Spark model run in-process through ag-harness.",
+            Self::OpenAiCompatible => {
+                "Local or self-hosted OpenAI-compatible endpoint run through ag-harness."
+            }
         }
     }
 }
@@ -596,15 +624,20 @@ impl AgentKind {
         AgentKind::Antigravity,
         AgentKind::Claude,
         AgentKind::Codex,
+        AgentKind::Harness,
     ];
 
     /// Returns the provider CLI executable name.
+    ///
+    /// The harness runs in-process, so its name labels the built-in runtime
+    /// rather than an executable looked up on `PATH`.
     pub fn executable_name(self) -> &'static str {
         match self {
             Self::Antigravity => "agy",
             Self::Gemini => "gemini",
             Self::Claude => "claude",
             Self::Codex => "codex",
+            Self::Harness => "ag-harness",
         }
     }
 
@@ -614,6 +647,7 @@ impl AgentKind {
             Self::Antigravity | Self::Gemini => AgentModel::Gemini31Pro,
             Self::Claude => AgentModel::ClaudeFable5,
             Self::Codex => AgentModel::Gpt56Sol,
+            Self::Harness => AgentModel::QwenPlus,
         }
     }
 
@@ -650,12 +684,19 @@ impl AgentKind {
             AgentModel::Gpt56Luna,
             AgentModel::Gpt53CodexSpark,
         ];
+        const HARNESS_MODELS: &[AgentModel] = &[
+            AgentModel::QwenPlus,
+            AgentModel::KimiK26,
+            AgentModel::MuseSpark12,
+            AgentModel::OpenAiCompatible,
+        ];
 
         match self {
             Self::Antigravity => ANTIGRAVITY_MODELS,
             Self::Gemini => GEMINI_MODELS,
             Self::Claude => CLAUDE_MODELS,
             Self::Codex => CODEX_MODELS,
+            Self::Harness => HARNESS_MODELS,
         }
     }
 
@@ -685,6 +726,19 @@ impl AgentKind {
     pub fn supports_speed_mode(self) -> bool {
         matches!(self, Self::Claude | Self::Codex)
     }
+
+    /// Returns whether this provider routes tool approval requests to the
+    /// user.
+    ///
+    /// Codex and Gemini pause on provider approval requests, so
+    /// [`PermissionMode::Ask`] can wait on the approval overlay. Claude,
+    /// Antigravity, and the in-process harness have no interactive approval
+    /// channel, so `Ask` is not offered for them.
+    ///
+    /// [`PermissionMode::Ask`]: crate::model::permission::PermissionMode::Ask
+    pub fn supports_tool_approval(self) -> bool {
+        matches!(self, Self::Codex | Self::Gemini)
+    }
 }
 
 impl AgentSelectionMetadata for AgentKind {
@@ -694,6 +748,7 @@ impl AgentSelectionMetadata for AgentKind {
             Self::Gemini => "gemini",
             Self::Claude => "claude",
             Self::Codex => "codex",
+            Self::Harness => "harness",
         }
     }
 
@@ -703,6 +758,9 @@ impl AgentSelectionMetadata for AgentKind {
             Self::Gemini => "Google Gemini CLI agent.",
             Self::Claude => "Anthropic Claude Code agent.",
             Self::Codex => "OpenAI Codex CLI agent.",
+            Self::Harness => {
+                "Built-in ag-harness agent for Qwen, Kimi, Muse, and OpenAI-compatible endpoints."
+            }
         }
     }
 }
@@ -732,6 +790,7 @@ impl FromStr for AgentKind {
             "gemini" => Ok(Self::Gemini),
             "claude" => Ok(Self::Claude),
             "codex" => Ok(Self::Codex),
+            "harness" => Ok(Self::Harness),
             other => Err(format!("unknown agent kind: {other}")),
         }
     }
@@ -1,6 +1,8 @@
 use std::fmt;
 use std::str::FromStr;
 
+use super::agent::AgentKind;
+
 /// Supported permission mode values for agent execution workflows.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
 pub enum PermissionMode {
@@ -10,17 +12,25 @@ pub enum PermissionMode {
     /// Restricts the agent to repository inspection without filesystem writes
     /// or mutating command approvals.
     ReadOnly,
+    /// Allows session-local edits but asks the user before each provider
+    /// command or tool approval request.
+    Ask,
 }
 
 impl PermissionMode {
     /// Ordered permission-mode options shown by interactive selectors.
-    pub const ALL: [PermissionMode; 2] = [PermissionMode::AutoEdit, PermissionMode::ReadOnly];
+    pub const ALL: [PermissionMode; 3] = [
+        PermissionMode::AutoEdit,
+        PermissionMode::Ask,
+        PermissionMode::ReadOnly,
+    ];
 
     /// Returns the wire label used for persistence and provider invocation.
     pub fn label(self) -> &'static str {
         match self {
             Self::AutoEdit => "auto_edit",
             Self::ReadOnly => "read_only",
+            Self::Ask => "ask",
         }
     }
 
@@ -29,6 +39,7 @@ impl PermissionMode {
         match self {
             Self::AutoEdit => "Auto Edit",
             Self::ReadOnly => "Read Only",
+            Self::Ask => "Ask",
         }
     }
 
@@ -36,6 +47,34 @@ impl PermissionMode {
     pub fn is_read_only(self) -> bool {
         self == Self::ReadOnly
     }
+
+    /// Returns whether provider approval requests must wait for the user.
+    pub fn asks_for_approval(self) -> bool {
+        self == Self::Ask
+    }
+
+    /// Returns whether this mode can be selected for sessions on `agent_kind`.
+    ///
+    /// `Ask` needs an interactive approval channel, so it is only offered for
+    /// providers that report [`AgentKind::supports_tool_approval`].
+    pub fn is_available_for(self, agent_kind: AgentKind) -> bool {
+        self != Self::Ask || agent_kind.supports_tool_approval()
+    }
+
+    /// Returns the mode `agent_kind` actually runs with for this selection.
+    ///
+    /// A persisted `Ask` falls back to [`PermissionMode::ReadOnly`] on
+    /// providers without an approval channel, for example after `/model`
+    /// switches the session to Claude, so it never grants more access than
+    /// the user chose.
+    #[must_use]
+    pub fn for_agent_kind(self, agent_kind: AgentKind) -> Self {
+        if self.is_available_for(agent_kind) {
+            return self;
+        }
+
+        Self::ReadOnly
+    }
 }
 
 impl fmt::Display for PermissionMode {
@@ -51,6 +90,7 @@ impl FromStr for PermissionMode {
         match s {
             "auto_edit" => Ok(PermissionMode::AutoEdit),
             "read_only" => Ok(PermissionMode::ReadOnly),
+            "ask" => Ok(PermissionMode::Ask),
             _ => Err(format!("Unknown permission mode: {s}")),
         }
     }
@@ -63,7 +103,7 @@ mod tests {
     #[test]
     fn test_from_str_accepts_supported_modes() {
         // Arrange
-        let permission_modes = ["auto_edit", "read_only"];
+        let permission_modes = ["auto_edit", "read_only", "ask"];
 
         // Act
         let parsed_permission_modes = permission_modes.map(PermissionMode::from_str);
@@ -71,7 +111,11 @@ mod tests {
         // Assert
         assert_eq!(
             parsed_permission_modes,
-            [Ok(PermissionMode::AutoEdit), Ok(PermissionMode::ReadOnly)]
+            [
+                Ok(PermissionMode::AutoEdit),
+                Ok(PermissionMode::ReadOnly),
+                Ok(PermissionMode::Ask)
+            ]
         );
     }
 
@@ -102,17 +146,53 @@ mod tests {
     #[test]
     fn test_label_and_display_label_return_persisted_and_user_facing_text() {
         // Arrange
-        let permission_modes = [PermissionMode::AutoEdit, PermissionMode::ReadOnly];
+        let permission_modes = PermissionMode::ALL;
 
         // Act
         let labels = permission_modes.map(PermissionMode::label);
         let display_labels = permission_modes.map(PermissionMode::display_label);
         let read_only = permission_modes.map(PermissionMode::is_read_only);
+        let asks_for_approval = permission_modes.map(PermissionMode::asks_for_approval);
 
         // Assert
-        assert_eq!(labels, ["auto_edit", "read_only"]);
-        assert_eq!(display_labels, ["Auto Edit", "Read Only"]);
-        assert_eq!(read_only, [false, true]);
+        assert_eq!(labels, ["auto_edit", "ask", "read_only"]);
+        assert_eq!(display_labels, ["Auto Edit", "Ask", "Read Only"]);
+        assert_eq!(read_only, [false, false, true]);
+        assert_eq!(asks_for_approval, [false, true, false]);
+    }
+
+    #[test]
+    fn test_ask_falls_back_to_read_only_without_approval_channel() {
+        // Arrange
+        let agent_kinds = AgentKind::ALL;
+
+        // Act
+        let available = agent_kinds
+            .iter()
+            .map(|agent_kind| PermissionMode::Ask.is_available_for(*agent_kind))
+            .collect::<Vec<_>>();
+        let effective_modes = agent_kinds
+            .iter()
+            .map(|agent_kind| PermissionMode::Ask.for_agent_kind(*agent_kind))
+            .collect::<Vec<_>>();
+        let auto_edit_modes = agent_kinds
+            .iter()
+            .map(|agent_kind| PermissionMode::AutoEdit.for_agent_kind(*agent_kind))
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(available, [true, false, false, true, false]);
+        assert_eq!(
+            effective_modes,
+            [
+                PermissionMode::Ask,
+                PermissionMode::ReadOnly,
+                PermissionMode::ReadOnly,
+                PermissionMode::Ask,
+                PermissionMode::ReadOnly,
+            ]
+        );
+        assert_eq!(auto_edit_modes, [PermissionMode::AutoEdit; 5]);
     }
 
     #[test]
@@ -1,9 +1,9 @@
 //! Shared helpers used by forge review-request adapters.
 //!
-//! Each supported forge (GitHub, GitLab) needs the same normalization for
-//! authentication failures, host-resolution failures, status-summary joining,
-//! provider label casing, and spawn-time error mapping. Keeping these in one
-//! module avoids divergence between adapters.
+//! Each supported forge (GitHub, GitLab, Forgejo) needs the same normalization
+//! for authentication failures, host-resolution failures, status-summary
+//! joining, provider label casing, and spawn-time error mapping. Keeping these
+//! in one module avoids divergence between adapters.
 
 use std::sync::Arc;
 
@@ -13,6 +13,12 @@ use super::{
     ReviewRequestSummary, UpdateReviewRequestInput, command_output_detail,
 };
 
+/// Maximum number of trailing log lines kept in one check log excerpt.
+const CHECK_LOG_EXCERPT_LINE_LIMIT: usize = 80;
+
+/// Lowercase title prefixes that GitLab and Forgejo treat as draft markers.
+const DRAFT_TITLE_PREFIXES: &[&str] = &["draft:", "[draft]", "(draft)", "wip:", "[wip]"];
+
 /// Provider-neutral partial edit produced after a best-effort recheck that the
 /// remote fields still match the values used during semantic reconciliation.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -317,6 +323,33 @@ impl ReviewRequestOperations {
         })
     }
 
+    /// Downloads one check run log and trims it to its trailing lines in an
+    /// owned future for adapter trait implementations.
+    pub(crate) fn fetch_check_log_excerpt_future(
+        &self,
+        remote: ForgeRemote,
+        log_id: String,
+        log_command: fn(&ForgeRemote, &str) -> ForgeCommand,
+        operation: &'static str,
+    ) -> ForgeFuture<Result<String, ReviewRequestError>> {
+        let operations = self.clone();
+
+        Box::pin(async move {
+            if log_id.is_empty() || !log_id.chars().all(|character| character.is_ascii_digit()) {
+                return Err(operation_failed(
+                    remote.forge_kind,
+                    format!("invalid check log id `{log_id}`"),
+                ));
+            }
+
+            let output = operations
+                .run_review_command(&remote, log_command(&remote, &log_id), operation)
+                .await?;
+
+            Ok(check_log_excerpt(&output.stdout))
+        })
+    }
+
     /// Maps one spawn-time failure into a normalized review-request error for
     /// the forge owning `remote`.
     fn map_spawn_error(remote: &ForgeRemote, error: ForgeCommandError) -> ReviewRequestError {
@@ -366,14 +399,12 @@ impl ReviewRequestOperations {
 
     /// Returns whether `detail` looks like a forge CLI authentication failure.
     ///
-    /// Parameterized on `forge_kind` so the CLI-specific `{cli} auth login`
-    /// marker stays accurate across forges while the remaining substrings are
-    /// shared.
+    /// Parameterized on `forge_kind` so the CLI-specific login command marker
+    /// stays accurate across forges while the remaining substrings are shared.
     fn looks_like_authentication_failure(detail: &str, forge_kind: ForgeKind) -> bool {
         let normalized_detail = detail.to_ascii_lowercase();
-        let auth_login_marker = format!("{} auth login", forge_kind.cli_name());
 
-        normalized_detail.contains(&auth_login_marker)
+        normalized_detail.contains(forge_kind.auth_login_command())
             || normalized_detail.contains("not logged in")
             || normalized_detail.contains("authentication failed")
             || normalized_detail.contains("authentication required")
@@ -427,6 +458,102 @@ pub(crate) fn status_summary_parts(parts: &[String]) -> Option<String> {
     Some(parts.join(", "))
 }
 
+/// Returns the trailing lines of one CI job log as plain text.
+///
+/// ANSI escape sequences are removed and carriage-return progress updates
+/// collapse to the text written last on each line.
+pub(crate) fn check_log_excerpt(log: &str) -> String {
+    let lines = log
+        .lines()
+        .map(|line| strip_ansi_escapes(line.rsplit('\r').next().unwrap_or_default()))
+        .collect::<Vec<_>>();
+    let trimmed_lines = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(&[][..], |last_line| &lines[..=last_line]);
+    let first_line = trimmed_lines
+        .len()
+        .saturating_sub(CHECK_LOG_EXCERPT_LINE_LIMIT);
+
+    trimmed_lines[first_line..].join("\n")
+}
+
+/// Removes ANSI CSI escape sequences such as color codes from one line.
+fn strip_ansi_escapes(line: &str) -> String {
+    let mut stripped = String::with_capacity(line.len());
+    let mut characters = line.chars().peekable();
+    while let Some(character) = characters.next() {
+        if character != '\u{1b}' {
+            stripped.push(character);
+
+            continue;
+        }
+        if characters.next_if_eq(&'[').is_none() {
+            continue;
+        }
+        for escape_character in characters.by_ref() {
+            if ('\u{40}'..='\u{7e}').contains(&escape_character) {
+                break;
+            }
+        }
+    }
+
+    stripped
+}
+
+/// Returns `title` without its leading draft markers, or `None` when the
+/// title carries no marker.
+pub(crate) fn strip_draft_title_prefix(title: &str) -> Option<String> {
+    let mut remaining = title.trim_start();
+    let mut stripped = false;
+    while let Some(prefix) = DRAFT_TITLE_PREFIXES.iter().find(|prefix| {
+        remaining
+            .get(..prefix.len())
+            .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+    }) {
+        remaining = remaining[prefix.len()..].trim_start();
+        stripped = true;
+    }
+
+    stripped.then(|| remaining.to_string())
+}
+
+/// Returns the id of the milestone titled `title` from `(title, id)` pairs.
+///
+/// # Errors
+/// Returns an error message when no milestone matches `title`.
+pub(crate) fn find_milestone_id(
+    milestones: impl IntoIterator<Item = (String, u64)>,
+    title: &str,
+) -> Result<u64, String> {
+    milestones
+        .into_iter()
+        .find(|(milestone_title, _)| milestone_title == title)
+        .map(|(_, id)| id)
+        .ok_or_else(|| format!("milestone `{title}` was not found"))
+}
+
+/// Prefixes one failure to apply labels, reviewers, or a milestone with the
+/// already created `display_id` so users know the review request exists.
+pub(crate) fn publish_metadata_failed(
+    display_id: &str,
+    error: ReviewRequestError,
+) -> ReviewRequestError {
+    match error {
+        ReviewRequestError::OperationFailed {
+            forge_kind,
+            message,
+        } => operation_failed(
+            forge_kind,
+            format!(
+                "{display_id} was created, but applying labels, reviewers, or milestone failed: \
+                 {message}"
+            ),
+        ),
+        error => error,
+    }
+}
+
 /// Formats one provider enum-like label into sentence case words.
 pub(crate) fn normalize_provider_label(label: &str) -> String {
     let lowercase = label.replace('_', " ").to_ascii_lowercase();
@@ -550,6 +677,19 @@ mod tests {
         assert!(matched);
     }
 
+    #[test]
+    fn looks_like_authentication_failure_matches_tea_login_prompt() {
+        // Arrange
+        let detail = "No gitea login configured. To start using tea, first run\n  tea login add";
+
+        // Act
+        let matched =
+            ReviewRequestOperations::looks_like_authentication_failure(detail, ForgeKind::Forgejo);
+
+        // Assert
+        assert!(matched);
+    }
+
     #[test]
     fn looks_like_authentication_failure_matches_http_401() {
         // Arrange
@@ -681,6 +821,47 @@ mod tests {
         assert_eq!(normalized, String::new());
     }
 
+    #[test]
+    fn strip_draft_title_prefix_removes_known_markers() {
+        // Arrange
+        let titles = ["Draft: Fix parser", "[WIP] wip: Fix parser", "Fix parser"];
+
+        // Act
+        let stripped = titles.map(strip_draft_title_prefix);
+
+        // Assert
+        assert_eq!(
+            stripped,
+            [
+                Some("Fix parser".to_string()),
+                Some("Fix parser".to_string()),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn check_log_excerpt_strips_ansi_and_keeps_trailing_lines() {
+        // Arrange
+        let mut log = (0..100)
+            .map(|index| format!("line {index}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        log.push_str(
+            "\nDownloading 10%\rDownloading 100%\n\u{1b}[31merror\u{1b}[0m: build failed\n\n",
+        );
+
+        // Act
+        let excerpt = check_log_excerpt(&log);
+
+        // Assert
+        let lines = excerpt.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 80);
+        assert_eq!(lines[0], "line 22");
+        assert_eq!(lines[78], "Downloading 100%");
+        assert_eq!(lines[79], "error: build failed");
+    }
+
     #[test]
     fn map_spawn_error_maps_executable_not_found_to_cli_not_installed() {
         // Arrange
@@ -771,6 +952,7 @@ mod tests {
         let host = match forge_kind {
             ForgeKind::GitHub => "github.com",
             ForgeKind::GitLab => "gitlab.example.internal",
+            ForgeKind::Forgejo => "codeberg.org",
         };
         ForgeRemote {
             command_working_directory: None,
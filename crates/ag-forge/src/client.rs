@@ -1,12 +1,15 @@
 //! Public review-request trait boundary and production client wiring.
 
-use std::sync::Arc;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use thiserror::Error;
 
 use super::{
-    CreateReviewRequestInput, ForgeCommandRunner, ForgeFuture, ForgeKind, ForgeRemote,
-    GitHubReviewRequestAdapter, GitLabReviewRequestAdapter, RealForgeCommandRunner,
-    ReviewCommentSnapshot, ReviewRequestError, ReviewRequestMetadata, ReviewRequestSummary,
-    UpdateReviewRequestInput, detect_remote,
+    CreateReviewRequestInput, ForgeBackend, ForgeCommandRunner, ForgeFuture, ForgeHostOverrides,
+    ForgeKind, ForgeRemote, ForgejoReviewRequestAdapter, GitHubReviewRequestAdapter,
+    GitLabReviewRequestAdapter, HttpForgeCommandRunner, RealForgeCommandRunner, ReviewCheckReport,
+    ReviewCommentSnapshot, ReviewRequestError, ReviewRequestMetadata, ReviewRequestPublishChoices,
+    ReviewRequestSummary, UpdateReviewRequestInput, detect_remote_with_overrides,
 };
 
 /// Async boundary used by app orchestration for forge review requests.
@@ -53,6 +56,29 @@ pub trait ReviewRequestClient: Send + Sync {
         display_id: String,
     ) -> ForgeFuture<Result<ReviewRequestSummary, ReviewRequestError>>;
 
+    /// Marks one existing draft review request ready for review and returns
+    /// the refreshed summary.
+    ///
+    /// # Errors
+    /// Returns a provider-specific review-request error when the draft state
+    /// cannot be cleared.
+    fn mark_review_request_ready(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewRequestSummary, ReviewRequestError>>;
+
+    /// Loads the labels, open milestones, and reviewer candidates offered
+    /// for completion when publishing a new review request.
+    ///
+    /// # Errors
+    /// Returns a provider-specific review-request error when any list cannot
+    /// be loaded.
+    fn fetch_review_request_publish_choices(
+        &self,
+        remote: ForgeRemote,
+    ) -> ForgeFuture<Result<ReviewRequestPublishChoices, ReviewRequestError>>;
+
     /// Loads the current title and body of one existing review request.
     ///
     /// # Errors
@@ -105,6 +131,33 @@ pub trait ReviewRequestClient: Send + Sync {
         display_id: String,
     ) -> ForgeFuture<Result<ReviewCommentSnapshot, ReviewRequestError>>;
 
+    /// Fetches the CI check runs or pipeline jobs reported for the head
+    /// commit of one existing review request.
+    ///
+    /// Log excerpts are left empty; callers load them for failed runs through
+    /// [`ReviewRequestClient::fetch_check_log_excerpt`].
+    ///
+    /// # Errors
+    /// Returns a provider-specific review-request error when the checks cannot
+    /// be loaded.
+    fn fetch_review_request_checks(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewCheckReport, ReviewRequestError>>;
+
+    /// Loads the trailing lines of one check run log by its forge-native
+    /// `log_id`.
+    ///
+    /// # Errors
+    /// Returns a provider-specific review-request error when the log cannot
+    /// be downloaded or the forge does not expose job logs.
+    fn fetch_check_log_excerpt(
+        &self,
+        remote: ForgeRemote,
+        log_id: String,
+    ) -> ForgeFuture<Result<String, ReviewRequestError>>;
+
     /// Adds one reply to an existing review thread.
     ///
     /// # Errors
@@ -131,15 +184,92 @@ pub trait ReviewRequestClient: Send + Sync {
     ) -> ForgeFuture<Result<(), ReviewRequestError>>;
 }
 
+/// Shared forge backend choice consulted on every review-request call.
+///
+/// The backend is a per-project setting, so the app publishes the active
+/// project's choice through one cloned handle instead of rebuilding the
+/// client on project switches.
+#[derive(Clone, Debug, Default)]
+pub struct ForgeBackendSelection {
+    backend: Arc<RwLock<ForgeBackend>>,
+}
+
+impl ForgeBackendSelection {
+    /// Replaces the backend used by subsequent review-request calls.
+    pub fn set(&self, backend: ForgeBackend) {
+        *self.backend.write().unwrap_or_else(PoisonError::into_inner) = backend;
+    }
+
+    /// Returns the backend used by review-request calls.
+    pub fn get(&self) -> ForgeBackend {
+        *self.backend.read().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Failures while building the production review-request client.
+#[derive(Debug, Error)]
+pub enum ReviewRequestClientError {
+    /// The HTTP API backend could not build its HTTP client.
+    #[error("failed to build the forge HTTP client: {0}")]
+    HttpClient(#[from] reqwest::Error),
+}
+
 /// Production [`ReviewRequestClient`] that routes to forge-specific adapters.
 pub struct RealReviewRequestClient {
-    command_runner: Arc<dyn ForgeCommandRunner>,
+    backend_selection: ForgeBackendSelection,
+    cli_command_runner: Arc<dyn ForgeCommandRunner>,
+    host_overrides: ForgeHostOverrides,
+    http_command_runner: Arc<dyn ForgeCommandRunner>,
 }
 
 impl RealReviewRequestClient {
-    /// Builds one review-request client from a forge command runner.
+    /// Builds one client backed by the forge CLIs and the HTTP API runner.
+    ///
+    /// # Errors
+    /// Returns [`ReviewRequestClientError::HttpClient`] when the HTTP API
+    /// runner cannot build its HTTP client.
+    pub fn try_new() -> Result<Self, ReviewRequestClientError> {
+        Ok(Self::with_command_runners(
+            Arc::new(RealForgeCommandRunner),
+            Arc::new(HttpForgeCommandRunner::new()?),
+        ))
+    }
+
+    /// Builds one review-request client that routes both backends through
+    /// `command_runner`.
+    #[cfg(test)]
     pub(crate) fn new(command_runner: Arc<dyn ForgeCommandRunner>) -> Self {
-        Self { command_runner }
+        Self::with_command_runners(Arc::clone(&command_runner), command_runner)
+    }
+
+    /// Builds one review-request client from per-backend command runners.
+    pub(crate) fn with_command_runners(
+        cli_command_runner: Arc<dyn ForgeCommandRunner>,
+        http_command_runner: Arc<dyn ForgeCommandRunner>,
+    ) -> Self {
+        Self {
+            backend_selection: ForgeBackendSelection::default(),
+            cli_command_runner,
+            host_overrides: ForgeHostOverrides::default(),
+            http_command_runner,
+        }
+    }
+
+    /// Returns one client that reads its transport from `backend_selection`.
+    #[must_use]
+    pub fn with_backend_selection(mut self, backend_selection: ForgeBackendSelection) -> Self {
+        self.backend_selection = backend_selection;
+
+        self
+    }
+
+    /// Returns one client that consults `host_overrides` during remote
+    /// detection.
+    #[must_use]
+    pub fn with_host_overrides(mut self, host_overrides: ForgeHostOverrides) -> Self {
+        self.host_overrides = host_overrides;
+
+        self
     }
 
     /// Runs `call` on an authenticated adapter selected for `remote`.
@@ -165,28 +295,25 @@ impl RealReviewRequestClient {
         })
     }
 
-    /// Returns one adapter implementation for `forge_kind`.
+    /// Returns one adapter implementation for `forge_kind` on the selected
+    /// backend.
     fn adapter_for(&self, forge_kind: ForgeKind) -> Arc<dyn ReviewRequestAdapter> {
+        let command_runner = match self.backend_selection.get() {
+            ForgeBackend::Cli => Arc::clone(&self.cli_command_runner),
+            ForgeBackend::Http => Arc::clone(&self.http_command_runner),
+        };
+
         match forge_kind {
-            ForgeKind::GitHub => Arc::new(GitHubReviewRequestAdapter::new(Arc::clone(
-                &self.command_runner,
-            ))),
-            ForgeKind::GitLab => Arc::new(GitLabReviewRequestAdapter::new(Arc::clone(
-                &self.command_runner,
-            ))),
+            ForgeKind::GitHub => Arc::new(GitHubReviewRequestAdapter::new(command_runner)),
+            ForgeKind::GitLab => Arc::new(GitLabReviewRequestAdapter::new(command_runner)),
+            ForgeKind::Forgejo => Arc::new(ForgejoReviewRequestAdapter::new(command_runner)),
         }
     }
 }
 
-impl Default for RealReviewRequestClient {
-    fn default() -> Self {
-        Self::new(Arc::new(RealForgeCommandRunner))
-    }
-}
-
 impl ReviewRequestClient for RealReviewRequestClient {
     fn detect_remote(&self, repo_url: String) -> Result<ForgeRemote, ReviewRequestError> {
-        detect_remote(&repo_url)
+        detect_remote_with_overrides(&repo_url, &self.host_overrides)
     }
 
     fn find_by_source_branch(
@@ -254,6 +381,25 @@ impl ReviewRequestClient for RealReviewRequestClient {
         Ok(review_request.web_url.clone())
     }
 
+    fn mark_review_request_ready(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewRequestSummary, ReviewRequestError>> {
+        self.call_with_authenticated_adapter(remote, move |adapter, remote| {
+            adapter.mark_authenticated_review_request_ready(remote, display_id)
+        })
+    }
+
+    fn fetch_review_request_publish_choices(
+        &self,
+        remote: ForgeRemote,
+    ) -> ForgeFuture<Result<ReviewRequestPublishChoices, ReviewRequestError>> {
+        self.call_with_authenticated_adapter(remote, move |adapter, remote| {
+            adapter.fetch_authenticated_review_request_publish_choices(remote)
+        })
+    }
+
     fn fetch_review_comment_snapshot(
         &self,
         remote: ForgeRemote,
@@ -264,6 +410,26 @@ impl ReviewRequestClient for RealReviewRequestClient {
         })
     }
 
+    fn fetch_review_request_checks(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewCheckReport, ReviewRequestError>> {
+        self.call_with_authenticated_adapter(remote, move |adapter, remote| {
+            adapter.fetch_authenticated_review_request_checks(remote, display_id)
+        })
+    }
+
+    fn fetch_check_log_excerpt(
+        &self,
+        remote: ForgeRemote,
+        log_id: String,
+    ) -> ForgeFuture<Result<String, ReviewRequestError>> {
+        self.call_with_authenticated_adapter(remote, move |adapter, remote| {
+            adapter.fetch_authenticated_check_log_excerpt(remote, log_id)
+        })
+    }
+
     fn reply_to_thread(
         &self,
         remote: ForgeRemote,
@@ -327,6 +493,19 @@ pub(crate) trait ReviewRequestAdapter: Send + Sync {
         display_id: String,
     ) -> ForgeFuture<Result<ReviewRequestSummary, ReviewRequestError>>;
 
+    /// Clears the draft state of one review request after authentication.
+    fn mark_authenticated_review_request_ready(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewRequestSummary, ReviewRequestError>>;
+
+    /// Loads publish completion choices after authentication.
+    fn fetch_authenticated_review_request_publish_choices(
+        &self,
+        remote: ForgeRemote,
+    ) -> ForgeFuture<Result<ReviewRequestPublishChoices, ReviewRequestError>>;
+
     /// Loads current review-request metadata after authentication.
     fn authenticated_review_request_metadata(
         &self,
@@ -349,6 +528,20 @@ pub(crate) trait ReviewRequestAdapter: Send + Sync {
         display_id: String,
     ) -> ForgeFuture<Result<ReviewCommentSnapshot, ReviewRequestError>>;
 
+    /// Fetches head-commit CI checks after authentication.
+    fn fetch_authenticated_review_request_checks(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewCheckReport, ReviewRequestError>>;
+
+    /// Loads one check run log excerpt after authentication.
+    fn fetch_authenticated_check_log_excerpt(
+        &self,
+        remote: ForgeRemote,
+        log_id: String,
+    ) -> ForgeFuture<Result<String, ReviewRequestError>>;
+
     /// Adds one reply after authentication.
     fn reply_to_authenticated_thread(
         &self,
@@ -373,12 +566,12 @@ mod tests {
 
     use super::*;
     use crate::command::{ForgeCommand, ForgeCommandOutput, MockForgeCommandRunner};
-    use crate::{ForgeKind, ReviewRequestState};
+    use crate::{ForgeBackend, ForgeKind, ReviewRequestState};
 
     #[test]
     fn review_request_web_url_returns_error_when_summary_is_missing_url() {
         // Arrange
-        let client = RealReviewRequestClient::default();
+        let client = RealReviewRequestClient::try_new().expect("client should build");
         let review_request = ReviewRequestSummary {
             display_id: "#42".to_string(),
             forge_kind: ForgeKind::GitHub,
@@ -408,7 +601,7 @@ mod tests {
     #[test]
     fn review_request_web_url_returns_gitlab_url_without_provider_routing() {
         // Arrange
-        let client = RealReviewRequestClient::default();
+        let client = RealReviewRequestClient::try_new().expect("client should build");
         let review_request = ReviewRequestSummary {
             display_id: "!42".to_string(),
             forge_kind: ForgeKind::GitLab,
@@ -735,6 +928,99 @@ mod tests {
         assert_eq!(review_request.forge_kind, ForgeKind::GitLab);
     }
 
+    #[tokio::test]
+    async fn refresh_review_request_authenticates_before_forgejo_refresh() {
+        // Arrange
+        let remote = forgejo_remote();
+        let mut sequence = Sequence::new();
+        let mut command_runner = MockForgeCommandRunner::new();
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf(|command| command_arguments_are(command, "tea", &["api", "user"]))
+            .returning(|_| Box::pin(async { Ok(success_output(String::new())) }));
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf(|command| {
+                command_arguments_are(
+                    command,
+                    "tea",
+                    &["api", "repos/agentty-xyz/agentty/pulls/42"],
+                )
+            })
+            .returning(|_| Box::pin(async { Ok(success_output(forgejo_view_json())) }));
+        let client = RealReviewRequestClient::new(Arc::new(command_runner));
+
+        // Act
+        let review_request = client
+            .refresh_review_request(remote, "#42".to_string())
+            .await
+            .expect("Forgejo refresh should succeed");
+
+        // Assert
+        assert_eq!(review_request.display_id, "#42");
+        assert_eq!(review_request.forge_kind, ForgeKind::Forgejo);
+    }
+
+    #[tokio::test]
+    async fn refresh_review_request_routes_through_selected_http_backend() {
+        // Arrange
+        let remote = forgejo_remote();
+        let mut cli_command_runner = MockForgeCommandRunner::new();
+        cli_command_runner.expect_run().never();
+        let mut http_command_runner = MockForgeCommandRunner::new();
+        http_command_runner
+            .expect_run()
+            .times(2)
+            .returning(|command| {
+                let stdout = if command.arguments == ["api", "user"] {
+                    String::new()
+                } else {
+                    forgejo_view_json()
+                };
+
+                Box::pin(async move { Ok(success_output(stdout)) })
+            });
+        let backend_selection = ForgeBackendSelection::default();
+        let client = RealReviewRequestClient::with_command_runners(
+            Arc::new(cli_command_runner),
+            Arc::new(http_command_runner),
+        )
+        .with_backend_selection(backend_selection.clone());
+        backend_selection.set(ForgeBackend::Http);
+
+        // Act
+        let review_request = client
+            .refresh_review_request(remote, "#42".to_string())
+            .await
+            .expect("HTTP-backed refresh should succeed");
+
+        // Assert
+        assert_eq!(review_request.display_id, "#42");
+    }
+
+    #[test]
+    fn detect_remote_uses_configured_forgejo_host_overrides() {
+        // Arrange
+        let host_overrides = ForgeHostOverrides::default();
+        let client = RealReviewRequestClient::try_new()
+            .expect("client should build")
+            .with_host_overrides(host_overrides.clone());
+        host_overrides.set_forgejo_hosts(&["git.example.com".to_string()]);
+
+        // Act
+        let remote = client
+            .detect_remote("git@git.example.com:team/project.git".to_string())
+            .expect("configured Forgejo host should be detected");
+
+        // Assert
+        assert_eq!(remote.forge_kind, ForgeKind::Forgejo);
+        assert_eq!(remote.project_path(), "team/project");
+    }
+
     /// Returns whether `command` exactly matches one expected CLI invocation.
     fn command_arguments_are(
         command: &ForgeCommand,
@@ -775,6 +1061,19 @@ mod tests {
         }
     }
 
+    /// Builds one normalized Forgejo remote for client routing tests.
+    fn forgejo_remote() -> ForgeRemote {
+        ForgeRemote {
+            command_working_directory: None,
+            forge_kind: ForgeKind::Forgejo,
+            host: "codeberg.org".to_string(),
+            namespace: "agentty-xyz".to_string(),
+            project: "agentty".to_string(),
+            repo_url: "https://codeberg.org/agentty-xyz/agentty.git".to_string(),
+            web_url: "https://codeberg.org/agentty-xyz/agentty".to_string(),
+        }
+    }
+
     /// Builds one successful command output with `stdout`.
     fn success_output(stdout: String) -> ForgeCommandOutput {
         ForgeCommandOutput {
@@ -827,4 +1126,21 @@ mod tests {
         }"#
         .to_string()
     }
+
+    /// Returns one representative Forgejo pull-request JSON response.
+    fn forgejo_view_json() -> String {
+        r#"{
+            "number": 42,
+            "title": "Add forge review support",
+            "body": "Current description.",
+            "state": "open",
+            "mergeable": true,
+            "merged": false,
+            "merged_at": null,
+            "html_url": "https://codeberg.org/agentty-xyz/agentty/pulls/42",
+            "base": {"ref": "main"},
+            "head": {"ref": "feature/forge"}
+        }"#
+        .to_string()
+    }
 }
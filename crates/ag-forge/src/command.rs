@@ -10,11 +10,14 @@ use tokio::time;
 use super::ForgeFuture;
 
 /// Maximum time one forge CLI command may run before it is canceled.
-const FORGE_COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+pub(crate) const FORGE_COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
 
 /// One forge CLI invocation.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct ForgeCommand {
+    /// Forge host the command targets, used by API-backed runners that do not
+    /// resolve hosts from CLI login state.
+    pub(crate) api_host: Option<String>,
     /// Argument vector passed to the executable.
     pub(crate) arguments: Vec<String>,
     /// Environment variables applied to the spawned process.
@@ -29,6 +32,7 @@ impl ForgeCommand {
     /// Builds one forge CLI command with no extra environment.
     pub(crate) fn new(executable: &'static str, arguments: Vec<String>) -> Self {
         Self {
+            api_host: None,
             arguments,
             environment: Vec::new(),
             executable,
@@ -36,6 +40,13 @@ impl ForgeCommand {
         }
     }
 
+    /// Records the forge host the command targets.
+    pub(crate) fn with_api_host(mut self, api_host: impl Into<String>) -> Self {
+        self.api_host = Some(api_host.into());
+
+        self
+    }
+
     /// Adds one environment variable to the command.
     pub(crate) fn with_environment(mut self, key: &str, value: impl Into<String>) -> Self {
         self.environment.push((key.to_string(), value.into()));
@@ -199,10 +210,12 @@ mod tests {
 
         // Act
         let command = ForgeCommand::new("gh", vec!["pr".to_string(), "view".to_string()])
+            .with_api_host("github.com")
             .with_environment("GH_TOKEN", "secret")
             .with_working_directory(working_directory.clone());
 
         // Assert
+        assert_eq!(command.api_host.as_deref(), Some("github.com"));
         assert_eq!(command.executable, "gh");
         assert_eq!(command.arguments, vec!["pr", "view"]);
         assert_eq!(
@@ -0,0 +1,2038 @@
+//! Forgejo review-request adapter routed through the `tea` CLI.
+//!
+//! Every request goes through `tea api` against the Forgejo/Gitea REST API so
+//! one CLI login covers pull requests, reviews, and comments. The same adapter
+//! serves Gitea instances because both forges share the `/api/v1` surface.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use serde::Deserialize;
+
+use super::{
+    CreateReviewRequestInput, ForgeCommand, ForgeCommandRunner, ForgeFuture, ForgeKind,
+    ForgeRemote, ReviewCheckConclusion, ReviewCheckReport, ReviewCheckRun, ReviewCheckStatus,
+    ReviewComment, ReviewCommentAnchorSide, ReviewCommentSnapshot, ReviewCommentThread,
+    ReviewRequestAdapter, ReviewRequestCreateOptions, ReviewRequestError, ReviewRequestMetadata,
+    ReviewRequestMetadataEdit, ReviewRequestOperations, ReviewRequestPublishChoices,
+    ReviewRequestState, ReviewRequestSummary, SyncReviewRequestMetadataConfig,
+    UpdateReviewRequestInput, find_milestone_id, is_forgejo_host, map_parse_error,
+    operation_failed, parse_remote_url, publish_metadata_failed, status_summary_parts,
+    strip_draft_title_prefix, strip_port,
+};
+
+/// Title prefix Forgejo uses to mark one pull request as a work-in-progress
+/// draft.
+const DRAFT_TITLE_PREFIX: &str = "WIP: ";
+
+/// Maximum page size accepted by default Forgejo and Gitea instances.
+const PAGE_LIMIT: u32 = 50;
+
+/// Forgejo pull-request adapter that normalizes `tea api` output.
+#[derive(Clone)]
+pub(crate) struct ForgejoReviewRequestAdapter {
+    operations: ReviewRequestOperations,
+}
+
+impl ForgejoReviewRequestAdapter {
+    /// Builds one Forgejo adapter from a forge command runner.
+    pub(crate) fn new(command_runner: Arc<dyn ForgeCommandRunner>) -> Self {
+        Self {
+            operations: ReviewRequestOperations::new(command_runner),
+        }
+    }
+
+    /// Returns normalized Forgejo remote metadata when `repo_url` points at a
+    /// well-known Forgejo or Gitea hostname.
+    pub(crate) fn detect_remote(repo_url: &str) -> Option<ForgeRemote> {
+        let parsed_remote = parse_remote_url(repo_url)?;
+        if !is_forgejo_host(strip_port(&parsed_remote.host)) {
+            return None;
+        }
+
+        Some(parsed_remote.into_forge_remote(ForgeKind::Forgejo))
+    }
+}
+
+impl ReviewRequestAdapter for ForgejoReviewRequestAdapter {
+    fn ensure_authenticated(
+        &self,
+        remote: &ForgeRemote,
+    ) -> ForgeFuture<Result<(), ReviewRequestError>> {
+        self.operations
+            .ensure_authenticated_future(remote.clone(), auth_status_command)
+    }
+
+    /// Finds the most recently updated open pull request whose head branch is
+    /// `source_branch`.
+    ///
+    /// Forgejo's list endpoint cannot filter by head branch, so the adapter
+    /// filters one page of open pull requests locally.
+    fn find_authenticated_by_source_branch(
+        &self,
+        remote: ForgeRemote,
+        source_branch: String,
+    ) -> ForgeFuture<Result<Option<ReviewRequestSummary>, ReviewRequestError>> {
+        let operations = self.operations.clone();
+
+        Box::pin(async move {
+            let output = operations
+                .run_review_command(&remote, lookup_command(&remote), "find pull request")
+                .await?;
+
+            map_parse_error(
+                remote.forge_kind,
+                parse_lookup_response(&output.stdout, &source_branch),
+            )
+        })
+    }
+
+    /// Creates one new pull request from `input`, then applies its labels,
+    /// reviewers, and milestone.
+    fn create_authenticated_review_request(
+        &self,
+        remote: ForgeRemote,
+        input: CreateReviewRequestInput,
+    ) -> ForgeFuture<Result<ReviewRequestSummary, ReviewRequestError>> {
+        let operations = self.operations.clone();
+
+        Box::pin(async move {
+            let output = operations
+                .run_review_command(
+                    &remote,
+                    create_command(&remote, &input),
+                    "create pull request",
+                )
+                .await?;
+            let summary = map_parse_error(remote.forge_kind, parse_view_response(&output.stdout))?;
+            if input.options.has_metadata() {
+                let pull_request_number = parse_display_id(&summary.display_id)?;
+                apply_create_options(&operations, &remote, &pull_request_number, &input.options)
+                    .await
+                    .map_err(|error| publish_metadata_failed(&summary.display_id, error))?;
+            }
+
+            Ok(summary)
+        })
+    }
+
+    /// Refreshes one existing pull request by display id.
+    fn refresh_authenticated_review_request(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewRequestSummary, ReviewRequestError>> {
+        self.operations.refresh_review_request_future(
+            remote,
+            display_id,
+            parse_display_id,
+            view_command,
+            "refresh pull request",
+            parse_view_response,
+        )
+    }
+
+    /// Loads current pull-request title/body metadata.
+    fn authenticated_review_request_metadata(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewRequestMetadata, ReviewRequestError>> {
+        self.operations
+            .review_request_metadata_future(remote, display_id, &metadata_sync_config())
+    }
+
+    /// Checks current pull-request metadata and updates fields that still
+    /// match the reconciled input.
+    fn sync_authenticated_review_request_metadata(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+        input: UpdateReviewRequestInput,
+    ) -> ForgeFuture<Result<ReviewRequestSummary, ReviewRequestError>> {
+        let adapter = self.clone();
+
+        self.operations.sync_review_request_metadata_future(
+            remote,
+            display_id,
+            input,
+            &metadata_sync_config(),
+            move |remote, display_id| {
+                adapter.refresh_authenticated_review_request(remote, display_id)
+            },
+        )
+    }
+
+    /// Fetches submitted reviews, their inline comments, and pull-request
+    /// conversation comments, then groups inline comments into threads by
+    /// file and line.
+    fn fetch_authenticated_review_comment_snapshot(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewCommentSnapshot, ReviewRequestError>> {
+        let operations = self.operations.clone();
+
+        Box::pin(async move {
+            let pull_request_number = parse_display_id(&display_id)?;
+            let output = operations
+                .run_review_command(
+                    &remote,
+                    reviews_command(&remote, &pull_request_number),
+                    "fetch pull-request reviews",
+                )
+                .await?;
+            let reviews =
+                map_parse_error(remote.forge_kind, parse_reviews_response(&output.stdout))?;
+
+            let mut review_comments = Vec::new();
+            for review in reviews.iter().filter(|review| review.has_inline_comments()) {
+                let output = operations
+                    .run_review_command(
+                        &remote,
+                        review_comments_command(&remote, &pull_request_number, review.id),
+                        "fetch pull-request review comments",
+                    )
+                    .await?;
+                let comments = map_parse_error(
+                    remote.forge_kind,
+                    parse_review_comments_response(&output.stdout),
+                )?;
+                review_comments.extend(comments.into_iter().map(|comment| (review.stale, comment)));
+            }
+
+            let output = operations
+                .run_review_command(
+                    &remote,
+                    issue_comments_command(&remote, &pull_request_number),
+                    "fetch pull-request comments",
+                )
+                .await?;
+            let issue_comments = map_parse_error(
+                remote.forge_kind,
+                parse_issue_comments_response(&output.stdout),
+            )?;
+
+            Ok(review_comment_snapshot(
+                &reviews,
+                review_comments,
+                issue_comments,
+            ))
+        })
+    }
+
+    /// Fetches the combined commit status of the pull-request head commit,
+    /// which Forgejo Actions and external CI services both report into.
+    fn fetch_authenticated_review_request_checks(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewCheckReport, ReviewRequestError>> {
+        let operations = self.operations.clone();
+
+        Box::pin(async move {
+            let pull_request_number = parse_display_id(&display_id)?;
+            let output = operations
+                .run_review_command(
+                    &remote,
+                    view_command(&remote, &pull_request_number),
+                    "view pull request",
+                )
+                .await?;
+            let head_sha =
+                map_parse_error(remote.forge_kind, parse_head_sha_response(&output.stdout))?;
+            let output = operations
+                .run_review_command(
+                    &remote,
+                    combined_status_command(&remote, &head_sha),
+                    "fetch pull-request commit status",
+                )
+                .await?;
+            let runs = map_parse_error(
+                remote.forge_kind,
+                parse_combined_status_response(&output.stdout),
+            )?;
+
+            Ok(ReviewCheckReport { head_sha, runs })
+        })
+    }
+
+    /// Reports that job logs are unavailable.
+    ///
+    /// Forgejo commit statuses only link to the job page; the REST API does
+    /// not serve Actions job logs.
+    fn fetch_authenticated_check_log_excerpt(
+        &self,
+        _remote: ForgeRemote,
+        _log_id: String,
+    ) -> ForgeFuture<Result<String, ReviewRequestError>> {
+        Box::pin(async move {
+            Err(operation_failed(
+                ForgeKind::Forgejo,
+                "download job log: Forgejo does not expose job logs through its API",
+            ))
+        })
+    }
+
+    /// Replies by submitting one comment-only review anchored to the same
+    /// file and line, which Forgejo renders inside the existing conversation.
+    fn reply_to_authenticated_thread(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+        thread_id: String,
+        body: String,
+    ) -> ForgeFuture<Result<(), ReviewRequestError>> {
+        let operations = self.operations.clone();
+
+        Box::pin(async move {
+            let pull_request_number = parse_display_id(&display_id)?;
+            let anchor = parse_thread_id(&thread_id)?;
+            operations
+                .run_review_command(
+                    &remote,
+                    reply_to_thread_command(&remote, &pull_request_number, &anchor, &body),
+                    "reply to pull-request review conversation",
+                )
+                .await?;
+
+            Ok(())
+        })
+    }
+
+    /// Reports that conversation resolution is unavailable.
+    ///
+    /// Forgejo only resolves review conversations through its web UI; neither
+    /// the REST API nor `tea` exposes the action, so callers surface this
+    /// error instead of silently treating the thread as resolved.
+    fn resolve_authenticated_thread(
+        &self,
+        _remote: ForgeRemote,
+        _display_id: String,
+        thread_id: String,
+    ) -> ForgeFuture<Result<(), ReviewRequestError>> {
+        Box::pin(async move {
+            let anchor = parse_thread_id(&thread_id)?;
+
+            Err(operation_failed(
+                ForgeKind::Forgejo,
+                format!(
+                    "resolve pull-request review conversation: Forgejo does not expose \
+                     conversation resolution through its API; resolve the conversation on `{}` in \
+                     the web UI",
+                    anchor.path
+                ),
+            ))
+        })
+    }
+
+    /// Marks one work-in-progress pull request ready by removing its `WIP: `
+    /// title prefix.
+    fn mark_authenticated_review_request_ready(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewRequestSummary, ReviewRequestError>> {
+        let adapter = self.clone();
+        let operations = self.operations.clone();
+
+        Box::pin(async move {
+            let pull_request_number = parse_display_id(&display_id)?;
+            let metadata = operations
+                .review_request_metadata_future(
+                    remote.clone(),
+                    display_id.clone(),
+                    &metadata_sync_config(),
+                )
+                .await?;
+            if let Some(title) = strip_draft_title_prefix(&metadata.title) {
+                let edit = ReviewRequestMetadataEdit {
+                    body: None,
+                    title: Some(title),
+                };
+                operations
+                    .run_review_command(
+                        &remote,
+                        update_metadata_command(&remote, &pull_request_number, &edit),
+                        "mark pull request ready",
+                    )
+                    .await?;
+            }
+
+            adapter
+                .refresh_authenticated_review_request(remote, display_id)
+                .await
+        })
+    }
+
+    /// Loads repository labels, assignable users, and open milestones.
+    fn fetch_authenticated_review_request_publish_choices(
+        &self,
+        remote: ForgeRemote,
+    ) -> ForgeFuture<Result<ReviewRequestPublishChoices, ReviewRequestError>> {
+        let operations = self.operations.clone();
+
+        Box::pin(async move {
+            let labels_output = operations
+                .run_review_command(
+                    &remote,
+                    repository_list_command(&remote, "labels"),
+                    "list repository labels",
+                )
+                .await?;
+            let reviewers_output = operations
+                .run_review_command(
+                    &remote,
+                    repository_list_command(&remote, "assignees"),
+                    "list assignable users",
+                )
+                .await?;
+            let milestones_output = operations
+                .run_review_command(&remote, milestones_command(&remote), "list open milestones")
+                .await?;
+
+            map_parse_error(
+                ForgeKind::Forgejo,
+                parse_publish_choices(
+                    &labels_output.stdout,
+                    &reviewers_output.stdout,
+                    &milestones_output.stdout,
+                ),
+            )
+        })
+    }
+}
+
+/// Applies the labels, reviewers, and milestone from `options` to one newly
+/// created pull request.
+async fn apply_create_options(
+    operations: &ReviewRequestOperations,
+    remote: &ForgeRemote,
+    pull_request_number: &str,
+    options: &ReviewRequestCreateOptions,
+) -> Result<(), ReviewRequestError> {
+    let issue_endpoint = format!(
+        "repos/{}/issues/{pull_request_number}",
+        remote.project_path()
+    );
+    if !options.labels.is_empty() {
+        operations
+            .run_review_command(
+                remote,
+                api_write_command(
+                    remote,
+                    "POST",
+                    &serde_json::json!({ "labels": options.labels }),
+                    format!("{issue_endpoint}/labels"),
+                ),
+                "add pull-request labels",
+            )
+            .await?;
+    }
+    if !options.reviewers.is_empty() {
+        operations
+            .run_review_command(
+                remote,
+                api_write_command(
+                    remote,
+                    "POST",
+                    &serde_json::json!({ "reviewers": options.reviewers }),
+                    format!(
+                        "{}/requested_reviewers",
+                        pull_request_endpoint(remote, pull_request_number)
+                    ),
+                ),
+                "request pull-request reviewers",
+            )
+            .await?;
+    }
+    if let Some(milestone) = options.milestone.as_deref() {
+        let output = operations
+            .run_review_command(remote, milestones_command(remote), "list open milestones")
+            .await?;
+        let milestone_id = map_parse_error(
+            ForgeKind::Forgejo,
+            parse_milestones_response(&output.stdout)
+                .and_then(|milestones| find_milestone_id(milestones, milestone)),
+        )?;
+        operations
+            .run_review_command(
+                remote,
+                api_write_command(
+                    remote,
+                    "PATCH",
+                    &serde_json::json!({ "milestone": milestone_id }),
+                    issue_endpoint,
+                ),
+                "set pull-request milestone",
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Builds Forgejo-specific metadata view and edit configuration.
+fn metadata_sync_config() -> SyncReviewRequestMetadataConfig {
+    SyncReviewRequestMetadataConfig {
+        edit_metadata_command: update_metadata_command,
+        edit_operation: "update pull-request metadata",
+        parse_display_id,
+        parse_metadata_response,
+        view_metadata_command: view_command,
+        view_operation: "view pull-request metadata",
+    }
+}
+
+/// Builds the `tea api user` command that verifies the active login.
+fn auth_status_command(remote: &ForgeRemote) -> ForgeCommand {
+    forgejo_command(remote, vec!["api".to_string(), "user".to_string()])
+}
+
+/// Builds the `tea api` command that lists recently updated open pull
+/// requests.
+fn lookup_command(remote: &ForgeRemote) -> ForgeCommand {
+    forgejo_command(
+        remote,
+        vec![
+            "api".to_string(),
+            format!(
+                "repos/{}/pulls?state=open&sort=recentupdate&limit={PAGE_LIMIT}",
+                remote.project_path()
+            ),
+        ],
+    )
+}
+
+/// Parses the first open pull request whose head branch is `source_branch`.
+fn parse_lookup_response(
+    stdout: &str,
+    source_branch: &str,
+) -> Result<Option<ReviewRequestSummary>, String> {
+    let pull_requests: Vec<ForgejoPullRequest> = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid Forgejo pull-request lookup response: {error}"))?;
+
+    Ok(pull_requests
+        .into_iter()
+        .find(|pull_request| pull_request.head.branch == source_branch)
+        .map(ForgejoPullRequest::into_summary))
+}
+
+/// Builds the `tea api` command that creates one pull request for `input`.
+///
+/// Forgejo marks drafts through a `WIP: ` title prefix, which is added unless
+/// `input` asks for a ready request, so session-published review requests do
+/// not appear ready for merge before the user chooses to mark them ready.
+fn create_command(remote: &ForgeRemote, input: &CreateReviewRequestInput) -> ForgeCommand {
+    let title = if input.options.draft {
+        format!("{DRAFT_TITLE_PREFIX}{}", input.title)
+    } else {
+        input.title.clone()
+    };
+    let payload = serde_json::json!({
+        "base": input.target_branch,
+        "body": input.body.clone().unwrap_or_default(),
+        "head": input.source_branch,
+        "title": title,
+    });
+
+    forgejo_command(
+        remote,
+        vec![
+            "api".to_string(),
+            "--method".to_string(),
+            "POST".to_string(),
+            "--data".to_string(),
+            payload.to_string(),
+            format!("repos/{}/pulls", remote.project_path()),
+        ],
+    )
+}
+
+/// Parses one Forgejo pull-request display id into the numeric index used by
+/// API paths.
+fn parse_display_id(display_id: &str) -> Result<String, ReviewRequestError> {
+    let trimmed = display_id.trim().trim_start_matches('#');
+    if trimmed.is_empty() || !trimmed.chars().all(|character| character.is_ascii_digit()) {
+        return Err(operation_failed(
+            ForgeKind::Forgejo,
+            format!("invalid Forgejo pull-request display id: `{display_id}`"),
+        ));
+    }
+
+    Ok(trimmed.to_string())
+}
+
+/// Builds the `tea api` command that loads one pull request.
+fn view_command(remote: &ForgeRemote, pull_request_number: &str) -> ForgeCommand {
+    forgejo_command(
+        remote,
+        vec![
+            "api".to_string(),
+            pull_request_endpoint(remote, pull_request_number),
+        ],
+    )
+}
+
+/// Parses one pull-request summary from a Forgejo pull-request response.
+fn parse_view_response(stdout: &str) -> Result<ReviewRequestSummary, String> {
+    let pull_request: ForgejoPullRequest = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid Forgejo pull-request response: {error}"))?;
+
+    Ok(pull_request.into_summary())
+}
+
+/// Parses current pull-request title/body metadata.
+fn parse_metadata_response(stdout: &str) -> Result<ReviewRequestMetadata, String> {
+    let metadata: ForgejoMetadataResponse = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid Forgejo pull-request metadata response: {error}"))?;
+
+    Ok(ReviewRequestMetadata {
+        body: metadata.body.unwrap_or_default(),
+        title: metadata.title,
+    })
+}
+
+/// Builds the `tea api` command that updates one pull-request title/body.
+fn update_metadata_command(
+    remote: &ForgeRemote,
+    pull_request_number: &str,
+    edit: &ReviewRequestMetadataEdit,
+) -> ForgeCommand {
+    let mut payload = serde_json::Map::new();
+    if let Some(title) = edit.title.as_ref() {
+        payload.insert("title".to_string(), title.clone().into());
+    }
+    if let Some(body) = edit.body.as_ref() {
+        payload.insert("body".to_string(), body.clone().into());
+    }
+
+    forgejo_command(
+        remote,
+        vec![
+            "api".to_string(),
+            "--method".to_string(),
+            "PATCH".to_string(),
+            "--data".to_string(),
+            serde_json::Value::Object(payload).to_string(),
+            pull_request_endpoint(remote, pull_request_number),
+        ],
+    )
+}
+
+/// Builds the `tea api` command that lists submitted pull-request reviews.
+fn reviews_command(remote: &ForgeRemote, pull_request_number: &str) -> ForgeCommand {
+    forgejo_command(
+        remote,
+        vec![
+            "api".to_string(),
+            format!(
+                "{}/reviews?limit={PAGE_LIMIT}",
+                pull_request_endpoint(remote, pull_request_number)
+            ),
+        ],
+    )
+}
+
+/// Builds the `tea api` command that lists inline comments of one review.
+fn review_comments_command(
+    remote: &ForgeRemote,
+    pull_request_number: &str,
+    review_id: u64,
+) -> ForgeCommand {
+    forgejo_command(
+        remote,
+        vec![
+            "api".to_string(),
+            format!(
+                "{}/reviews/{review_id}/comments",
+                pull_request_endpoint(remote, pull_request_number)
+            ),
+        ],
+    )
+}
+
+/// Builds the `tea api` command that lists pull-request conversation
+/// comments.
+///
+/// Forgejo stores pull-request conversation comments on the backing issue.
+fn issue_comments_command(remote: &ForgeRemote, pull_request_number: &str) -> ForgeCommand {
+    forgejo_command(
+        remote,
+        vec![
+            "api".to_string(),
+            format!(
+                "repos/{}/issues/{pull_request_number}/comments?limit={PAGE_LIMIT}",
+                remote.project_path()
+            ),
+        ],
+    )
+}
+
+/// Builds the `tea api` command that submits one comment-only review at the
+/// thread anchor.
+fn reply_to_thread_command(
+    remote: &ForgeRemote,
+    pull_request_number: &str,
+    anchor: &ForgejoThreadAnchor,
+    body: &str,
+) -> ForgeCommand {
+    let mut comment = serde_json::Map::new();
+    comment.insert("body".to_string(), body.into());
+    comment.insert("path".to_string(), anchor.path.clone().into());
+    match anchor.side {
+        ReviewCommentAnchorSide::Old => {
+            comment.insert("old_position".to_string(), anchor.line.into());
+        }
+        ReviewCommentAnchorSide::File | ReviewCommentAnchorSide::New => {
+            comment.insert("new_position".to_string(), anchor.line.into());
+        }
+    }
+    let payload = serde_json::json!({
+        "body": "",
+        "comments": [comment],
+        "event": "COMMENT",
+    });
+
+    forgejo_command(
+        remote,
+        vec![
+            "api".to_string(),
+            "--method".to_string(),
+            "POST".to_string(),
+            "--data".to_string(),
+            payload.to_string(),
+            format!(
+                "{}/reviews",
+                pull_request_endpoint(remote, pull_request_number)
+            ),
+        ],
+    )
+}
+
+/// Builds one `tea api` command that sends `payload` to `endpoint`.
+fn api_write_command(
+    remote: &ForgeRemote,
+    method: &str,
+    payload: &serde_json::Value,
+    endpoint: String,
+) -> ForgeCommand {
+    forgejo_command(
+        remote,
+        vec![
+            "api".to_string(),
+            "--method".to_string(),
+            method.to_string(),
+            "--data".to_string(),
+            payload.to_string(),
+            endpoint,
+        ],
+    )
+}
+
+/// Builds the `tea api` command that lists open repository milestones.
+fn milestones_command(remote: &ForgeRemote) -> ForgeCommand {
+    forgejo_command(
+        remote,
+        vec![
+            "api".to_string(),
+            format!(
+                "repos/{}/milestones?state=open&limit={PAGE_LIMIT}",
+                remote.project_path()
+            ),
+        ],
+    )
+}
+
+/// Builds the `tea api` command that lists the first page of one repository
+/// collection such as `labels` or `assignees`.
+fn repository_list_command(remote: &ForgeRemote, collection: &str) -> ForgeCommand {
+    forgejo_command(
+        remote,
+        vec![
+            "api".to_string(),
+            format!(
+                "repos/{}/{collection}?limit={PAGE_LIMIT}",
+                remote.project_path()
+            ),
+        ],
+    )
+}
+
+/// Parses `(title, id)` pairs from one milestones response.
+fn parse_milestones_response(stdout: &str) -> Result<Vec<(String, u64)>, String> {
+    let milestones: Vec<ForgejoMilestone> = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid Forgejo milestones response: {error}"))?;
+
+    Ok(milestones
+        .into_iter()
+        .map(|milestone| (milestone.title, milestone.id))
+        .collect())
+}
+
+/// Parses the labels, assignable users, and milestones offered while
+/// publishing.
+fn parse_publish_choices(
+    labels_stdout: &str,
+    reviewers_stdout: &str,
+    milestones_stdout: &str,
+) -> Result<ReviewRequestPublishChoices, String> {
+    let labels: Vec<ForgejoLabel> = serde_json::from_str(labels_stdout)
+        .map_err(|error| format!("invalid Forgejo labels response: {error}"))?;
+    let reviewers: Vec<ForgejoUser> = serde_json::from_str(reviewers_stdout)
+        .map_err(|error| format!("invalid Forgejo assignees response: {error}"))?;
+
+    Ok(ReviewRequestPublishChoices {
+        labels: labels.into_iter().map(|label| label.name).collect(),
+        milestones: parse_milestones_response(milestones_stdout)?
+            .into_iter()
+            .map(|(title, _)| title)
+            .collect(),
+        reviewers: reviewers.into_iter().map(|user| user.login).collect(),
+    })
+}
+
+/// Returns the API path of one pull request.
+fn pull_request_endpoint(remote: &ForgeRemote, pull_request_number: &str) -> String {
+    format!(
+        "repos/{}/pulls/{pull_request_number}",
+        remote.project_path()
+    )
+}
+
+/// Builds the `tea api` command that reads the combined status of one commit.
+fn combined_status_command(remote: &ForgeRemote, commit_sha: &str) -> ForgeCommand {
+    forgejo_command(
+        remote,
+        vec![
+            "api".to_string(),
+            format!(
+                "repos/{}/commits/{commit_sha}/status?limit={PAGE_LIMIT}",
+                remote.project_path()
+            ),
+        ],
+    )
+}
+
+/// Parses the head commit SHA from one pull-request response.
+fn parse_head_sha_response(stdout: &str) -> Result<String, String> {
+    let pull_request: ForgejoPullRequest = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid Forgejo pull-request response: {error}"))?;
+    let head_sha = pull_request.head.sha;
+    if head_sha.is_empty()
+        || !head_sha
+            .chars()
+            .all(|character| character.is_ascii_hexdigit())
+    {
+        return Err(format!("invalid Forgejo head commit `{head_sha}`"));
+    }
+
+    Ok(head_sha)
+}
+
+/// Parses one combined commit status into normalized check runs.
+fn parse_combined_status_response(stdout: &str) -> Result<Vec<ReviewCheckRun>, String> {
+    let combined_status: ForgejoCombinedStatus = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid Forgejo commit status response: {error}"))?;
+
+    Ok(combined_status
+        .statuses
+        .into_iter()
+        .map(ForgejoCommitStatus::into_check_run)
+        .collect())
+}
+
+/// Parses submitted pull-request reviews.
+fn parse_reviews_response(stdout: &str) -> Result<Vec<ForgejoReview>, String> {
+    serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid Forgejo pull-request reviews response: {error}"))
+}
+
+/// Parses inline comments of one pull-request review.
+fn parse_review_comments_response(stdout: &str) -> Result<Vec<ForgejoReviewComment>, String> {
+    serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid Forgejo review comments response: {error}"))
+}
+
+/// Parses pull-request conversation comments.
+fn parse_issue_comments_response(stdout: &str) -> Result<Vec<ForgejoIssueComment>, String> {
+    serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid Forgejo pull-request comments response: {error}"))
+}
+
+/// Groups inline review comments into anchored threads and merges review
+/// summaries with conversation comments into chronological PR-level comments.
+///
+/// Forgejo has no conversation identifier, so comments sharing one file and
+/// signed line form one thread, matching how its web UI groups them.
+fn review_comment_snapshot(
+    reviews: &[ForgejoReview],
+    review_comments: Vec<(bool, ForgejoReviewComment)>,
+    issue_comments: Vec<ForgejoIssueComment>,
+) -> ReviewCommentSnapshot {
+    let mut grouped_comments: BTreeMap<(String, i64), Vec<(bool, ForgejoReviewComment)>> =
+        BTreeMap::new();
+    for (is_stale, comment) in review_comments {
+        grouped_comments
+            .entry((comment.path.clone(), comment.signed_line()))
+            .or_default()
+            .push((is_stale, comment));
+    }
+
+    let threads = grouped_comments
+        .into_iter()
+        .map(|((path, signed_line), mut comments)| {
+            comments.sort_by_key(|(_, comment)| comment.id);
+            let anchor = ForgejoThreadAnchor::from_signed_line(path, signed_line);
+            let is_outdated = comments.first().map(|(is_stale, _)| *is_stale);
+            let is_resolved = comments
+                .iter()
+                .any(|(_, comment)| comment.resolver.is_some());
+
+            ReviewCommentThread {
+                anchor_side: anchor.side,
+                comments: comments
+                    .into_iter()
+                    .map(|(_, comment)| ReviewComment {
+                        author: user_login(comment.user),
+                        body: comment.body,
+                    })
+                    .collect(),
+                id: anchor.thread_id(),
+                is_outdated,
+                is_resolved,
+                line: (anchor.line > 0).then_some(anchor.line),
+                path: anchor.path,
+                start_line: None,
+            }
+        })
+        .collect();
+
+    let mut timestamped_comments = reviews
+        .iter()
+        .filter(|review| review.is_submitted() && !review.body.trim().is_empty())
+        .map(|review| {
+            (
+                review.submitted_at.clone().unwrap_or_default(),
+                ReviewComment {
+                    author: user_login(review.user.clone()),
+                    body: review.body.clone(),
+                },
+            )
+        })
+        .chain(issue_comments.into_iter().map(|comment| {
+            (
+                comment.created_at,
+                ReviewComment {
+                    author: user_login(comment.user),
+                    body: comment.body,
+                },
+            )
+        }))
+        .collect::<Vec<_>>();
+    timestamped_comments.sort_by(|left, right| left.0.cmp(&right.0));
+
+    ReviewCommentSnapshot {
+        pr_level_comments: timestamped_comments
+            .into_iter()
+            .map(|(_, comment)| comment)
+            .collect(),
+        threads,
+    }
+}
+
+/// Parses one synthesized thread id back into its file and line anchor.
+fn parse_thread_id(thread_id: &str) -> Result<ForgejoThreadAnchor, ReviewRequestError> {
+    thread_id
+        .split_once(':')
+        .and_then(|(signed_line, path)| {
+            let signed_line = signed_line.parse::<i64>().ok()?;
+
+            (!path.is_empty())
+                .then(|| ForgejoThreadAnchor::from_signed_line(path.to_string(), signed_line))
+        })
+        .ok_or_else(|| {
+            operation_failed(
+                ForgeKind::Forgejo,
+                format!("invalid Forgejo review conversation id: `{thread_id}`"),
+            )
+        })
+}
+
+/// Returns the login shown for one optional Forgejo user.
+fn user_login(user: Option<ForgejoUser>) -> String {
+    user.map(|user| user.login).unwrap_or_default()
+}
+
+/// Builds one base `tea` command with deterministic color settings and the
+/// optional session worktree so `tea` selects the login matching the
+/// repository remote.
+fn forgejo_command(remote: &ForgeRemote, arguments: Vec<String>) -> ForgeCommand {
+    ForgeCommand::new("tea", arguments)
+        .with_api_host(remote.host.clone())
+        .with_environment("NO_COLOR", "1")
+        .with_optional_working_directory(remote.command_working_directory.clone())
+}
+
+/// File and line anchor shared by the comments of one Forgejo review
+/// conversation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ForgejoThreadAnchor {
+    line: u32,
+    path: String,
+    side: ReviewCommentAnchorSide,
+}
+
+impl ForgejoThreadAnchor {
+    /// Builds one anchor from Forgejo's signed line convention, where
+    /// negative lines address the old side of the diff.
+    fn from_signed_line(path: String, signed_line: i64) -> Self {
+        let side = match signed_line {
+            0 => ReviewCommentAnchorSide::File,
+            line if line < 0 => ReviewCommentAnchorSide::Old,
+            _ => ReviewCommentAnchorSide::New,
+        };
+
+        Self {
+            line: u32::try_from(signed_line.unsigned_abs()).unwrap_or(u32::MAX),
+            path,
+            side,
+        }
+    }
+
+    /// Returns the opaque `<signed line>:<path>` thread id.
+    fn thread_id(&self) -> String {
+        let signed_line = match self.side {
+            ReviewCommentAnchorSide::Old => -i64::from(self.line),
+            ReviewCommentAnchorSide::File | ReviewCommentAnchorSide::New => i64::from(self.line),
+        };
+
+        format!("{signed_line}:{}", self.path)
+    }
+}
+
+/// Forgejo pull-request payload returned by the pull-request endpoints.
+#[derive(Deserialize)]
+struct ForgejoPullRequest {
+    base: ForgejoBranchRef,
+    #[serde(default)]
+    draft: bool,
+    head: ForgejoBranchRef,
+    html_url: String,
+    #[serde(default)]
+    mergeable: bool,
+    #[serde(default)]
+    merged: bool,
+    merged_at: Option<String>,
+    number: u64,
+    state: String,
+    title: String,
+}
+
+impl ForgejoPullRequest {
+    /// Converts one pull request into the normalized summary.
+    fn into_summary(self) -> ReviewRequestSummary {
+        let state = self.review_request_state();
+        let status_summary = self.status_summary(state);
+
+        ReviewRequestSummary {
+            display_id: format!("#{}", self.number),
+            forge_kind: ForgeKind::Forgejo,
+            source_branch: self.head.branch,
+            state,
+            status_summary,
+            target_branch: self.base.branch,
+            title: self.title,
+            web_url: self.html_url,
+        }
+    }
+
+    /// Maps Forgejo state fields into the normalized review-request state.
+    fn review_request_state(&self) -> ReviewRequestState {
+        if self.merged || self.merged_at.is_some() {
+            return ReviewRequestState::Merged;
+        }
+
+        if self.state.eq_ignore_ascii_case("closed") {
+            return ReviewRequestState::Closed;
+        }
+
+        ReviewRequestState::Open
+    }
+
+    /// Formats the provider-specific status summary for the UI.
+    fn status_summary(&self, state: ReviewRequestState) -> Option<String> {
+        if state != ReviewRequestState::Open {
+            return None;
+        }
+
+        let mut parts = Vec::new();
+        if self.is_draft() {
+            parts.push("Draft".to_string());
+        }
+        parts.push(if self.mergeable {
+            "Mergeable".to_string()
+        } else {
+            "Conflicts".to_string()
+        });
+
+        status_summary_parts(&parts)
+    }
+
+    /// Returns whether Forgejo treats the pull request as a work in progress.
+    fn is_draft(&self) -> bool {
+        let title = self.title.trim_start().to_ascii_lowercase();
+
+        self.draft || title.starts_with("wip:") || title.starts_with("[wip]")
+    }
+}
+
+/// Branch reference nested in one Forgejo pull-request payload.
+#[derive(Deserialize)]
+struct ForgejoBranchRef {
+    #[serde(rename = "ref")]
+    branch: String,
+    #[serde(default)]
+    sha: String,
+}
+
+/// Combined commit status returned by the commit status endpoint.
+#[derive(Deserialize)]
+struct ForgejoCombinedStatus {
+    #[serde(default)]
+    statuses: Vec<ForgejoCommitStatus>,
+}
+
+/// One commit status reported by Forgejo Actions or an external CI service.
+#[derive(Deserialize)]
+struct ForgejoCommitStatus {
+    context: String,
+    status: String,
+    target_url: Option<String>,
+}
+
+impl ForgejoCommitStatus {
+    /// Converts one commit status into a normalized check run without a log
+    /// reference.
+    fn into_check_run(self) -> ReviewCheckRun {
+        let (status, conclusion) = match self.status.as_str() {
+            "success" => (
+                ReviewCheckStatus::Completed,
+                Some(ReviewCheckConclusion::Success),
+            ),
+            "error" | "failure" => (
+                ReviewCheckStatus::Completed,
+                Some(ReviewCheckConclusion::Failure),
+            ),
+            "warning" => (
+                ReviewCheckStatus::Completed,
+                Some(ReviewCheckConclusion::Neutral),
+            ),
+            _ => (ReviewCheckStatus::Queued, None),
+        };
+
+        ReviewCheckRun {
+            conclusion,
+            log_excerpt: None,
+            log_id: None,
+            name: self.context,
+            status,
+            url: self.target_url.filter(|target_url| !target_url.is_empty()),
+        }
+    }
+}
+
+/// Forgejo pull-request title/body payload.
+#[derive(Deserialize)]
+struct ForgejoMetadataResponse {
+    #[serde(default)]
+    body: Option<String>,
+    title: String,
+}
+
+/// One submitted or pending Forgejo pull-request review.
+#[derive(Deserialize)]
+struct ForgejoReview {
+    #[serde(default)]
+    body: String,
+    #[serde(default)]
+    comments_count: u64,
+    id: u64,
+    #[serde(default)]
+    stale: bool,
+    state: String,
+    submitted_at: Option<String>,
+    user: Option<ForgejoUser>,
+}
+
+impl ForgejoReview {
+    /// Returns whether the review was submitted rather than left pending.
+    fn is_submitted(&self) -> bool {
+        !self.state.eq_ignore_ascii_case("PENDING")
+    }
+
+    /// Returns whether the review carries inline comments worth fetching.
+    fn has_inline_comments(&self) -> bool {
+        self.is_submitted() && self.comments_count > 0
+    }
+}
+
+/// One inline comment attached to a Forgejo pull-request review.
+#[derive(Deserialize)]
+struct ForgejoReviewComment {
+    body: String,
+    id: u64,
+    /// Old-side line, set when the comment targets removed lines.
+    #[serde(default)]
+    original_position: u64,
+    path: String,
+    /// New-side line, set when the comment targets added or context lines.
+    #[serde(default)]
+    position: u64,
+    resolver: Option<ForgejoUser>,
+    user: Option<ForgejoUser>,
+}
+
+impl ForgejoReviewComment {
+    /// Returns Forgejo's signed line, negative for old-side anchors.
+    fn signed_line(&self) -> i64 {
+        if self.position > 0 {
+            return i64::try_from(self.position).unwrap_or(i64::MAX);
+        }
+
+        -i64::try_from(self.original_position).unwrap_or(i64::MAX)
+    }
+}
+
+/// One Forgejo pull-request conversation comment.
+#[derive(Deserialize)]
+struct ForgejoIssueComment {
+    body: String,
+    #[serde(default)]
+    created_at: String,
+    user: Option<ForgejoUser>,
+}
+
+/// Repository label returned by the labels API.
+#[derive(Deserialize)]
+struct ForgejoLabel {
+    name: String,
+}
+
+/// Repository milestone returned by the milestones API.
+#[derive(Deserialize)]
+struct ForgejoMilestone {
+    id: u64,
+    title: String,
+}
+
+/// Minimal Forgejo user data shown in session review-comment views.
+#[derive(Clone, Deserialize)]
+struct ForgejoUser {
+    login: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use mockall::Sequence;
+
+    use super::*;
+    use crate::ReviewRequestMetadataFieldUpdate;
+    use crate::command::{ForgeCommandOutput, MockForgeCommandRunner};
+
+    #[tokio::test]
+    async fn find_authenticated_by_source_branch_filters_open_pull_requests_by_head_branch() {
+        // Arrange
+        let remote = forgejo_remote();
+        let mut command_runner = MockForgeCommandRunner::new();
+        command_runner
+            .expect_run()
+            .once()
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &lookup_command(&remote)
+            })
+            .returning(|_| Box::pin(async { Ok(success_output(forgejo_pull_list_json())) }));
+        let adapter = ForgejoReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let review_request = adapter
+            .find_authenticated_by_source_branch(remote, "feature/forge".to_string())
+            .await
+            .expect("Forgejo lookup should succeed");
+
+        // Assert
+        assert_eq!(
+            review_request,
+            Some(ReviewRequestSummary {
+                display_id: "#42".to_string(),
+                forge_kind: ForgeKind::Forgejo,
+                source_branch: "feature/forge".to_string(),
+                state: ReviewRequestState::Open,
+                status_summary: Some("Draft, Mergeable".to_string()),
+                target_branch: "main".to_string(),
+                title: "WIP: Add forge review support".to_string(),
+                web_url: "https://codeberg.org/agentty-xyz/agentty/pulls/42".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn find_authenticated_by_source_branch_returns_none_without_matching_head_branch() {
+        // Arrange
+        let remote = forgejo_remote();
+        let mut command_runner = MockForgeCommandRunner::new();
+        command_runner
+            .expect_run()
+            .once()
+            .returning(|_| Box::pin(async { Ok(success_output(forgejo_pull_list_json())) }));
+        let adapter = ForgejoReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let review_request = adapter
+            .find_authenticated_by_source_branch(remote, "feature/missing".to_string())
+            .await
+            .expect("Forgejo lookup should succeed");
+
+        // Assert
+        assert_eq!(review_request, None);
+    }
+
+    #[tokio::test]
+    async fn create_authenticated_review_request_posts_wip_pull_request() {
+        // Arrange
+        let remote = forgejo_remote();
+        let input = CreateReviewRequestInput {
+            body: Some("Implements the provider adapters.".to_string()),
+            options: ReviewRequestCreateOptions::default(),
+            source_branch: "feature/forge".to_string(),
+            target_branch: "main".to_string(),
+            title: "Add forge review support".to_string(),
+        };
+        let mut command_runner = MockForgeCommandRunner::new();
+        command_runner
+            .expect_run()
+            .once()
+            .withf({
+                let remote = remote.clone();
+                let input = input.clone();
+
+                move |command| command == &create_command(&remote, &input)
+            })
+            .returning(|_| Box::pin(async { Ok(success_output(forgejo_pull_json())) }));
+        let adapter = ForgejoReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let review_request = adapter
+            .create_authenticated_review_request(remote.clone(), input.clone())
+            .await
+            .expect("Forgejo create should succeed");
+        let command = create_command(&remote, &input);
+        let payload: serde_json::Value =
+            serde_json::from_str(&command.arguments[4]).expect("payload should be JSON");
+
+        // Assert
+        assert_eq!(review_request.display_id, "#42");
+        assert_eq!(review_request.forge_kind, ForgeKind::Forgejo);
+        assert_eq!(payload["title"], "WIP: Add forge review support");
+        assert_eq!(payload["head"], "feature/forge");
+        assert_eq!(payload["base"], "main");
+        assert_eq!(
+            command.arguments.last().map(String::as_str),
+            Some("repos/agentty-xyz/agentty/pulls")
+        );
+    }
+
+    #[tokio::test]
+    async fn create_authenticated_review_request_applies_labels_reviewers_and_milestone() {
+        // Arrange
+        let remote = forgejo_remote();
+        let input = CreateReviewRequestInput {
+            body: None,
+            options: ReviewRequestCreateOptions {
+                draft: false,
+                labels: vec!["bug".to_string()],
+                milestone: Some("v1.0".to_string()),
+                reviewers: vec!["alice".to_string()],
+            },
+            source_branch: "feature/forge".to_string(),
+            target_branch: "main".to_string(),
+            title: "Add forge review support".to_string(),
+        };
+        let expected_commands = vec![
+            create_command(&remote, &input),
+            api_write_command(
+                &remote,
+                "POST",
+                &serde_json::json!({ "labels": ["bug"] }),
+                "repos/agentty-xyz/agentty/issues/42/labels".to_string(),
+            ),
+            api_write_command(
+                &remote,
+                "POST",
+                &serde_json::json!({ "reviewers": ["alice"] }),
+                "repos/agentty-xyz/agentty/pulls/42/requested_reviewers".to_string(),
+            ),
+            milestones_command(&remote),
+            api_write_command(
+                &remote,
+                "PATCH",
+                &serde_json::json!({ "milestone": 5 }),
+                "repos/agentty-xyz/agentty/issues/42".to_string(),
+            ),
+        ];
+        let outputs = [
+            forgejo_pull_json(),
+            "[]".to_string(),
+            "{}".to_string(),
+            r#"[{"id":5,"title":"v1.0"}]"#.to_string(),
+            "{}".to_string(),
+        ];
+        let mut sequence = Sequence::new();
+        let mut command_runner = MockForgeCommandRunner::new();
+        for (expected_command, output) in expected_commands.into_iter().zip(outputs) {
+            command_runner
+                .expect_run()
+                .once()
+                .in_sequence(&mut sequence)
+                .withf(move |command| command == &expected_command)
+                .returning(move |_| {
+                    let output = output.clone();
+
+                    Box::pin(async move { Ok(success_output(output)) })
+                });
+        }
+        let adapter = ForgejoReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let create_command = create_command(&remote, &input);
+        let payload: serde_json::Value =
+            serde_json::from_str(&create_command.arguments[4]).expect("payload should be JSON");
+        let review_request = adapter
+            .create_authenticated_review_request(remote, input)
+            .await
+            .expect("Forgejo create should succeed");
+
+        // Assert
+        assert_eq!(review_request.display_id, "#42");
+        assert_eq!(payload["title"], "Add forge review support");
+    }
+
+    #[tokio::test]
+    async fn mark_authenticated_review_request_ready_removes_wip_title_prefix() {
+        // Arrange
+        let remote = forgejo_remote();
+        let ready_edit = ReviewRequestMetadataEdit {
+            body: None,
+            title: Some("Add forge review support".to_string()),
+        };
+        let ready_pull_json = forgejo_pull_json()
+            .replace("WIP: Add forge review support", "Add forge review support");
+        let expected_commands = vec![
+            view_command(&remote, "42"),
+            update_metadata_command(&remote, "42", &ready_edit),
+            view_command(&remote, "42"),
+        ];
+        let outputs = [forgejo_pull_json(), "{}".to_string(), ready_pull_json];
+        let mut sequence = Sequence::new();
+        let mut command_runner = MockForgeCommandRunner::new();
+        for (expected_command, output) in expected_commands.into_iter().zip(outputs) {
+            command_runner
+                .expect_run()
+                .once()
+                .in_sequence(&mut sequence)
+                .withf(move |command| command == &expected_command)
+                .returning(move |_| {
+                    let output = output.clone();
+
+                    Box::pin(async move { Ok(success_output(output)) })
+                });
+        }
+        let adapter = ForgejoReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let review_request = adapter
+            .mark_authenticated_review_request_ready(remote, "#42".to_string())
+            .await
+            .expect("mark ready should succeed");
+
+        // Assert
+        assert!(!review_request.is_draft());
+    }
+
+    #[tokio::test]
+    async fn authenticated_review_request_metadata_loads_current_pull_request() {
+        // Arrange
+        let remote = forgejo_remote();
+        let mut command_runner = MockForgeCommandRunner::new();
+        command_runner
+            .expect_run()
+            .once()
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &view_command(&remote, "42")
+            })
+            .returning(|_| Box::pin(async { Ok(success_output(forgejo_pull_json())) }));
+        let adapter = ForgejoReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let metadata = adapter
+            .authenticated_review_request_metadata(remote, "#42".to_string())
+            .await
+            .expect("Forgejo metadata lookup should succeed");
+
+        // Assert
+        assert_eq!(
+            metadata,
+            ReviewRequestMetadata {
+                body: "Current description.".to_string(),
+                title: "WIP: Add forge review support".to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_authenticated_review_request_metadata_patches_changed_fields() {
+        // Arrange
+        let remote = forgejo_remote();
+        let input = UpdateReviewRequestInput {
+            body: Some(ReviewRequestMetadataFieldUpdate {
+                current: "Current description.".to_string(),
+                desired: "Updated description.".to_string(),
+            }),
+            title: Some(ReviewRequestMetadataFieldUpdate {
+                current: "Earlier title".to_string(),
+                desired: "Ignored title".to_string(),
+            }),
+        };
+        let edit = ReviewRequestMetadataEdit {
+            body: Some("Updated description.".to_string()),
+            title: None,
+        };
+        let mut sequence = Sequence::new();
+        let mut command_runner = MockForgeCommandRunner::new();
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &view_command(&remote, "42")
+            })
+            .returning(|_| Box::pin(async { Ok(success_output(forgejo_pull_json())) }));
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf({
+                let remote = remote.clone();
+                let edit = edit.clone();
+
+                move |command| command == &update_metadata_command(&remote, "42", &edit)
+            })
+            .returning(|_| Box::pin(async { Ok(success_output(forgejo_pull_json())) }));
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &view_command(&remote, "42")
+            })
+            .returning(|_| Box::pin(async { Ok(success_output(forgejo_pull_json())) }));
+        let adapter = ForgejoReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let summary = adapter
+            .sync_authenticated_review_request_metadata(remote.clone(), "#42".to_string(), input)
+            .await
+            .expect("Forgejo metadata sync should succeed");
+        let command = update_metadata_command(&remote, "42", &edit);
+
+        // Assert
+        assert_eq!(summary.display_id, "#42");
+        assert_eq!(command.arguments[4], r#"{"body":"Updated description."}"#);
+    }
+
+    #[test]
+    fn forgejo_pull_request_maps_terminal_states_without_status_summary() {
+        // Arrange
+        let cases = [
+            (true, "closed", ReviewRequestState::Merged),
+            (false, "closed", ReviewRequestState::Closed),
+            (false, "open", ReviewRequestState::Open),
+        ];
+
+        // Act & Assert
+        for (merged, state, expected) in cases {
+            let mut payload: serde_json::Value =
+                serde_json::from_str(&forgejo_pull_json()).expect("fixture should be JSON");
+            payload["merged"] = merged.into();
+            payload["state"] = state.into();
+            let summary = parse_view_response(&payload.to_string()).expect("fixture should parse");
+
+            assert_eq!(summary.state, expected);
+            assert_eq!(
+                summary.status_summary.is_some(),
+                expected == ReviewRequestState::Open
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn fetch_authenticated_review_comment_snapshot_groups_review_comments_by_anchor() {
+        // Arrange
+        let remote = forgejo_remote();
+        let mut sequence = Sequence::new();
+        let mut command_runner = MockForgeCommandRunner::new();
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &reviews_command(&remote, "42")
+            })
+            .returning(|_| Box::pin(async { Ok(success_output(forgejo_reviews_json())) }));
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &review_comments_command(&remote, "42", 7)
+            })
+            .returning(|_| {
+                Box::pin(async { Ok(success_output(forgejo_first_review_comments_json())) })
+            });
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &review_comments_command(&remote, "42", 8)
+            })
+            .returning(|_| {
+                Box::pin(async { Ok(success_output(forgejo_second_review_comments_json())) })
+            });
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &issue_comments_command(&remote, "42")
+            })
+            .returning(|_| Box::pin(async { Ok(success_output(forgejo_issue_comments_json())) }));
+        let adapter = ForgejoReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let snapshot = adapter
+            .fetch_authenticated_review_comment_snapshot(remote, "#42".to_string())
+            .await
+            .expect("Forgejo review snapshot should parse");
+
+        // Assert
+        assert_eq!(snapshot.threads.len(), 2);
+        let new_side_thread = &snapshot.threads[1];
+        assert_eq!(new_side_thread.id, "12:src/main.rs");
+        assert_eq!(new_side_thread.anchor_side, ReviewCommentAnchorSide::New);
+        assert_eq!(new_side_thread.line, Some(12));
+        assert_eq!(new_side_thread.is_outdated, Some(false));
+        assert!(!new_side_thread.is_resolved);
+        assert_eq!(
+            new_side_thread
+                .comments
+                .iter()
+                .map(|comment| comment.author.as_str())
+                .collect::<Vec<_>>(),
+            vec!["alice", "bob"]
+        );
+        let old_side_thread = &snapshot.threads[0];
+        assert_eq!(old_side_thread.id, "-4:src/main.rs");
+        assert_eq!(old_side_thread.anchor_side, ReviewCommentAnchorSide::Old);
+        assert_eq!(old_side_thread.line, Some(4));
+        assert!(old_side_thread.is_resolved);
+        assert_eq!(
+            snapshot
+                .pr_level_comments
+                .iter()
+                .map(|comment| comment.body.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Please take another look.", "Looks good overall."]
+        );
+    }
+
+    #[tokio::test]
+    async fn reply_to_authenticated_thread_submits_review_comment_at_thread_anchor() {
+        // Arrange
+        let remote = forgejo_remote();
+        let anchor = ForgejoThreadAnchor {
+            line: 4,
+            path: "src/main.rs".to_string(),
+            side: ReviewCommentAnchorSide::Old,
+        };
+        let mut command_runner = MockForgeCommandRunner::new();
+        command_runner
+            .expect_run()
+            .once()
+            .withf({
+                let remote = remote.clone();
+                let anchor = anchor.clone();
+
+                move |command| {
+                    command == &reply_to_thread_command(&remote, "42", &anchor, "Addressed.")
+                }
+            })
+            .returning(|_| Box::pin(async { Ok(success_output("{}".to_string())) }));
+        let adapter = ForgejoReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let reply_result = adapter
+            .reply_to_authenticated_thread(
+                remote.clone(),
+                "#42".to_string(),
+                "-4:src/main.rs".to_string(),
+                "Addressed.".to_string(),
+            )
+            .await;
+        let command = reply_to_thread_command(&remote, "42", &anchor, "Addressed.");
+        let payload: serde_json::Value =
+            serde_json::from_str(&command.arguments[4]).expect("payload should be JSON");
+
+        // Assert
+        assert_eq!(reply_result, Ok(()));
+        assert_eq!(payload["event"], "COMMENT");
+        assert_eq!(payload["comments"][0]["path"], "src/main.rs");
+        assert_eq!(payload["comments"][0]["old_position"], 4);
+    }
+
+    #[tokio::test]
+    async fn resolve_authenticated_thread_reports_missing_api_support() {
+        // Arrange
+        let adapter = ForgejoReviewRequestAdapter::new(Arc::new(MockForgeCommandRunner::new()));
+
+        // Act
+        let error = adapter
+            .resolve_authenticated_thread(
+                forgejo_remote(),
+                "#42".to_string(),
+                "12:src/main.rs".to_string(),
+            )
+            .await
+            .expect_err("resolution should be reported as unsupported");
+
+        // Assert
+        assert!(matches!(
+            error,
+            ReviewRequestError::OperationFailed {
+                forge_kind: ForgeKind::Forgejo,
+                ref message,
+            } if message.contains("web UI") && message.contains("src/main.rs")
+        ));
+    }
+
+    #[tokio::test]
+    async fn fetch_authenticated_review_request_checks_reads_head_commit_status() {
+        // Arrange
+        let remote = forgejo_remote();
+        let mut sequence = Sequence::new();
+        let mut command_runner = MockForgeCommandRunner::new();
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &view_command(&remote, "42")
+            })
+            .returning(|_| Box::pin(async { Ok(success_output(forgejo_pull_json())) }));
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &combined_status_command(&remote, "2222222")
+            })
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(success_output(
+                        r#"{"state":"failure","statuses":[
+                            {"context":"ci / test","status":"failure","target_url":"https://codeberg.org/actions/runs/1"},
+                            {"context":"ci / lint","status":"pending","target_url":""}
+                        ]}"#
+                        .to_string(),
+                    ))
+                })
+            });
+        let adapter = ForgejoReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let report = adapter
+            .fetch_authenticated_review_request_checks(remote, "#42".to_string())
+            .await
+            .expect("Forgejo commit status should parse");
+
+        // Assert
+        assert_eq!(report.head_sha, "2222222");
+        assert_eq!(
+            report.runs,
+            vec![
+                ReviewCheckRun {
+                    conclusion: Some(ReviewCheckConclusion::Failure),
+                    log_excerpt: None,
+                    log_id: None,
+                    name: "ci / test".to_string(),
+                    status: ReviewCheckStatus::Completed,
+                    url: Some("https://codeberg.org/actions/runs/1".to_string()),
+                },
+                ReviewCheckRun {
+                    conclusion: None,
+                    log_excerpt: None,
+                    log_id: None,
+                    name: "ci / lint".to_string(),
+                    status: ReviewCheckStatus::Queued,
+                    url: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_thread_id_round_trips_anchor_ids() {
+        // Arrange
+        let thread_ids = ["12:src/main.rs", "-4:src/lib.rs", "0:docs/a:b.md"];
+
+        // Act & Assert
+        for thread_id in thread_ids {
+            let anchor = parse_thread_id(thread_id).expect("thread id should parse");
+
+            assert_eq!(anchor.thread_id(), thread_id);
+        }
+        assert!(parse_thread_id("src/main.rs").is_err());
+        assert!(parse_thread_id("12:").is_err());
+    }
+
+    #[test]
+    fn parse_display_id_rejects_invalid_pull_request_reference() {
+        // Arrange
+        let display_id = "#not-a-number";
+
+        // Act
+        let error = parse_display_id(display_id).expect_err("invalid display id should fail");
+
+        // Assert
+        assert_eq!(
+            error,
+            ReviewRequestError::OperationFailed {
+                forge_kind: ForgeKind::Forgejo,
+                message: "invalid Forgejo pull-request display id: `#not-a-number`".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn detect_remote_supports_well_known_forgejo_hosts() {
+        // Arrange
+        let repo_url = "https://codeberg.org/agentty-xyz/agentty.git";
+
+        // Act
+        let remote =
+            ForgejoReviewRequestAdapter::detect_remote(repo_url).expect("forgejo remote expected");
+
+        // Assert
+        assert_eq!(remote.forge_kind, ForgeKind::Forgejo);
+        assert_eq!(remote.project_path(), "agentty-xyz/agentty");
+        assert_eq!(
+            ForgejoReviewRequestAdapter::detect_remote("https://example.com/team/project.git"),
+            None
+        );
+    }
+
+    #[test]
+    fn forgejo_command_uses_remote_working_directory_for_tea_login_selection() {
+        // Arrange
+        let remote =
+            forgejo_remote().with_command_working_directory(PathBuf::from("/tmp/session-worktree"));
+
+        // Act
+        let command = auth_status_command(&remote);
+
+        // Assert
+        assert_eq!(command.executable, "tea");
+        assert_eq!(
+            command.arguments,
+            vec!["api".to_string(), "user".to_string()]
+        );
+        assert_eq!(
+            command.working_directory,
+            Some(PathBuf::from("/tmp/session-worktree"))
+        );
+    }
+
+    /// Builds one normalized Forgejo remote for command-construction tests.
+    fn forgejo_remote() -> ForgeRemote {
+        ForgeRemote {
+            command_working_directory: None,
+            forge_kind: ForgeKind::Forgejo,
+            host: "codeberg.org".to_string(),
+            namespace: "agentty-xyz".to_string(),
+            project: "agentty".to_string(),
+            repo_url: "https://codeberg.org/agentty-xyz/agentty.git".to_string(),
+            web_url: "https://codeberg.org/agentty-xyz/agentty".to_string(),
+        }
+    }
+
+    /// Builds one successful command output with `stdout`.
+    fn success_output(stdout: String) -> ForgeCommandOutput {
+        ForgeCommandOutput {
+            exit_code: Some(0),
+            stderr: String::new(),
+            stdout,
+        }
+    }
+
+    /// Returns one recorded Forgejo pull-request response.
+    fn forgejo_pull_json() -> String {
+        r#"{
+            "id": 9001,
+            "number": 42,
+            "title": "WIP: Add forge review support",
+            "body": "Current description.",
+            "state": "open",
+            "draft": false,
+            "mergeable": true,
+            "merged": false,
+            "merged_at": null,
+            "html_url": "https://codeberg.org/agentty-xyz/agentty/pulls/42",
+            "base": {"label": "main", "ref": "main", "sha": "1111111"},
+            "head": {"label": "feature/forge", "ref": "feature/forge", "sha": "2222222"},
+            "user": {"id": 1, "login": "alice"}
+        }"#
+        .to_string()
+    }
+
+    /// Returns one recorded Forgejo open pull-request list response.
+    fn forgejo_pull_list_json() -> String {
+        format!(
+            r#"[
+                {{
+                    "number": 43,
+                    "title": "Unrelated change",
+                    "body": "",
+                    "state": "open",
+                    "mergeable": false,
+                    "merged": false,
+                    "merged_at": null,
+                    "html_url": "https://codeberg.org/agentty-xyz/agentty/pulls/43",
+                    "base": {{"ref": "main"}},
+                    "head": {{"ref": "feature/other"}}
+                }},
+                {}
+            ]"#,
+            forgejo_pull_json()
+        )
+    }
+
+    /// Returns one recorded Forgejo pull-request reviews response.
+    fn forgejo_reviews_json() -> String {
+        r#"[
+            {
+                "id": 7,
+                "user": {"login": "alice"},
+                "body": "",
+                "state": "REQUEST_CHANGES",
+                "comments_count": 2,
+                "stale": false,
+                "submitted_at": "2026-07-16T10:00:00Z"
+            },
+            {
+                "id": 8,
+                "user": {"login": "bob"},
+                "body": "Please take another look.",
+                "state": "COMMENT",
+                "comments_count": 1,
+                "stale": true,
+                "submitted_at": "2026-07-16T11:00:00Z"
+            },
+            {
+                "id": 9,
+                "user": {"login": "carol"},
+                "body": "Draft notes",
+                "state": "PENDING",
+                "comments_count": 3,
+                "stale": false,
+                "submitted_at": null
+            }
+        ]"#
+        .to_string()
+    }
+
+    /// Returns recorded inline comments for the first review.
+    fn forgejo_first_review_comments_json() -> String {
+        r#"[
+            {
+                "id": 100,
+                "user": {"login": "alice"},
+                "body": "Please simplify this.",
+                "path": "src/main.rs",
+                "position": 12,
+                "original_position": 0,
+                "resolver": null
+            },
+            {
+                "id": 101,
+                "user": {"login": "alice"},
+                "body": "Why was this removed?",
+                "path": "src/main.rs",
+                "position": 0,
+                "original_position": 4,
+                "resolver": {"login": "alice"}
+            }
+        ]"#
+        .to_string()
+    }
+
+    /// Returns recorded inline comments for the second review.
+    fn forgejo_second_review_comments_json() -> String {
+        r#"[
+            {
+                "id": 102,
+                "user": {"login": "bob"},
+                "body": "Agreed.",
+                "path": "src/main.rs",
+                "position": 12,
+                "original_position": 0,
+                "resolver": null
+            }
+        ]"#
+        .to_string()
+    }
+
+    /// Returns recorded pull-request conversation comments.
+    fn forgejo_issue_comments_json() -> String {
+        r#"[
+            {
+                "id": 200,
+                "user": {"login": "carol"},
+                "body": "Looks good overall.",
+                "created_at": "2026-07-16T12:00:00Z"
+            }
+        ]"#
+        .to_string()
+    }
+}
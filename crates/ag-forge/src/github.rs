@@ -6,11 +6,13 @@ use serde::Deserialize;
 
 use super::{
     CreateReviewRequestInput, ForgeCommand, ForgeCommandRunner, ForgeFuture, ForgeKind,
-    ForgeRemote, ReviewComment, ReviewCommentAnchorSide, ReviewCommentSnapshot,
-    ReviewCommentThread, ReviewRequestAdapter, ReviewRequestError, ReviewRequestMetadata,
-    ReviewRequestMetadataEdit, ReviewRequestOperations, ReviewRequestState, ReviewRequestSummary,
-    SyncReviewRequestMetadataConfig, UpdateReviewRequestInput, map_parse_error,
-    normalize_provider_label, operation_failed, parse_remote_url, status_summary_parts, strip_port,
+    ForgeRemote, ReviewCheckConclusion, ReviewCheckReport, ReviewCheckRun, ReviewCheckStatus,
+    ReviewComment, ReviewCommentAnchorSide, ReviewCommentSnapshot, ReviewCommentThread,
+    ReviewRequestAdapter, ReviewRequestCreateOptions, ReviewRequestError, ReviewRequestMetadata,
+    ReviewRequestMetadataEdit, ReviewRequestOperations, ReviewRequestPublishChoices,
+    ReviewRequestState, ReviewRequestSummary, SyncReviewRequestMetadataConfig,
+    UpdateReviewRequestInput, find_milestone_id, map_parse_error, normalize_provider_label,
+    operation_failed, parse_remote_url, publish_metadata_failed, status_summary_parts, strip_port,
 };
 
 /// Paginated GraphQL query used to fetch review threads for one pull request.
@@ -31,11 +33,24 @@ const THREAD_COMMENTS_QUERY: &str = "query($threadId: ID!, $endCursor: String) {
                                      $threadId) { ... on PullRequestReviewThread { \
                                      comments(first: 100, after: $endCursor) { nodes { author { \
                                      login } body } pageInfo { hasNextPage endCursor } } } } }";
+/// GraphQL query used to fetch the check rollup for a pull-request head commit.
+const CHECK_RUNS_QUERY: &str =
+    "query($owner: String!, $repo: String!, $number: Int!) { repository(owner: $owner, name: \
+     $repo) { pullRequest(number: $number) { headRefOid commits(last: 1) { nodes { commit { \
+     statusCheckRollup { contexts(first: 100) { nodes { __typename ... on CheckRun { databaseId \
+     name status conclusion detailsUrl checkSuite { app { slug } } } ... on StatusContext { \
+     context state targetUrl } } } } } } } } } }";
+/// GitHub App slug whose check runs expose downloadable Actions job logs.
+const GITHUB_ACTIONS_APP_SLUG: &str = "github-actions";
 /// GraphQL mutation used to add one reply to a pull-request review thread.
 const REPLY_TO_THREAD_MUTATION: &str =
     "mutation($threadId: ID!, $body: String!) { addPullRequestReviewThreadReply(input: { \
      pullRequestReviewThreadId: $threadId, body: $body }) { comment { id } } }";
 /// GraphQL mutation used to resolve one pull-request review thread.
+const MARK_READY_MUTATION: &str = "mutation($pullRequestId: ID!) { \
+                                   markPullRequestReadyForReview(input: { pullRequestId: \
+                                   $pullRequestId }) { pullRequest { id } } }";
+
 const RESOLVE_THREAD_MUTATION: &str = "mutation($threadId: ID!) { resolveReviewThread(input: { \
                                        threadId: $threadId }) { thread { id isResolved } } }";
 
@@ -93,7 +108,8 @@ impl ReviewRequestAdapter for GitHubReviewRequestAdapter {
         )
     }
 
-    /// Creates one new draft pull request from `input`.
+    /// Creates one new pull request from `input`, then applies its labels,
+    /// reviewers, and milestone.
     fn create_authenticated_review_request(
         &self,
         remote: ForgeRemote,
@@ -109,15 +125,23 @@ impl ReviewRequestAdapter for GitHubReviewRequestAdapter {
                 .run_review_command(&remote, create_command, "create pull request")
                 .await?;
 
-            adapter
-                .find_authenticated_by_source_branch(remote, source_branch)
+            let summary = adapter
+                .find_authenticated_by_source_branch(remote.clone(), source_branch)
                 .await?
                 .ok_or_else(|| {
                     operation_failed(
                         ForgeKind::GitHub,
                         "GitHub pull request was created but could not be reloaded",
                     )
-                })
+                })?;
+            if input.options.has_metadata() {
+                let pull_request_number = parse_display_id(&summary.display_id)?;
+                apply_create_options(&operations, &remote, &pull_request_number, &input.options)
+                    .await
+                    .map_err(|error| publish_metadata_failed(&summary.display_id, error))?;
+            }
+
+            Ok(summary)
         })
     }
 
@@ -234,6 +258,42 @@ impl ReviewRequestAdapter for GitHubReviewRequestAdapter {
         })
     }
 
+    /// Fetches the check runs and commit statuses reported for the
+    /// pull-request head commit through GitHub's GraphQL check rollup.
+    fn fetch_authenticated_review_request_checks(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewCheckReport, ReviewRequestError>> {
+        let operations = self.operations.clone();
+
+        Box::pin(async move {
+            let pull_request_number = parse_display_id(&display_id)?;
+            let output = operations
+                .run_review_command(
+                    &remote,
+                    check_runs_command(&remote, &pull_request_number),
+                    "fetch pull-request checks",
+                )
+                .await?;
+
+            map_parse_error(ForgeKind::GitHub, parse_check_runs_response(&output.stdout))
+        })
+    }
+
+    fn fetch_authenticated_check_log_excerpt(
+        &self,
+        remote: ForgeRemote,
+        log_id: String,
+    ) -> ForgeFuture<Result<String, ReviewRequestError>> {
+        self.operations.fetch_check_log_excerpt_future(
+            remote,
+            log_id,
+            job_log_command,
+            "download GitHub Actions job log",
+        )
+    }
+
     fn reply_to_authenticated_thread(
         &self,
         remote: ForgeRemote,
@@ -278,6 +338,125 @@ impl ReviewRequestAdapter for GitHubReviewRequestAdapter {
             Ok(())
         })
     }
+
+    /// Marks one draft pull request ready through GitHub's GraphQL API, which
+    /// addresses pull requests by node id.
+    fn mark_authenticated_review_request_ready(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewRequestSummary, ReviewRequestError>> {
+        let adapter = self.clone();
+        let operations = self.operations.clone();
+
+        Box::pin(async move {
+            let pull_request_number = parse_display_id(&display_id)?;
+            let output = operations
+                .run_review_command(
+                    &remote,
+                    pull_request_node_command(&remote, &pull_request_number),
+                    "load pull-request node id",
+                )
+                .await?;
+            let node_id =
+                map_parse_error(ForgeKind::GitHub, parse_node_id_response(&output.stdout))?;
+            operations
+                .run_review_command(
+                    &remote,
+                    mark_ready_command(&remote, &node_id),
+                    "mark pull request ready for review",
+                )
+                .await?;
+
+            adapter
+                .refresh_authenticated_review_request(remote, display_id)
+                .await
+        })
+    }
+
+    /// Loads repository labels, assignable users, and open milestones.
+    fn fetch_authenticated_review_request_publish_choices(
+        &self,
+        remote: ForgeRemote,
+    ) -> ForgeFuture<Result<ReviewRequestPublishChoices, ReviewRequestError>> {
+        let operations = self.operations.clone();
+
+        Box::pin(async move {
+            let labels_output = operations
+                .run_review_command(
+                    &remote,
+                    repository_list_command(&remote, "labels", &[]),
+                    "list repository labels",
+                )
+                .await?;
+            let reviewers_output = operations
+                .run_review_command(
+                    &remote,
+                    repository_list_command(&remote, "assignees", &[]),
+                    "list assignable users",
+                )
+                .await?;
+            let milestones_output = operations
+                .run_review_command(&remote, milestones_command(&remote), "list open milestones")
+                .await?;
+
+            map_parse_error(
+                ForgeKind::GitHub,
+                parse_publish_choices(
+                    &labels_output.stdout,
+                    &reviewers_output.stdout,
+                    &milestones_output.stdout,
+                ),
+            )
+        })
+    }
+}
+
+/// Applies the labels, reviewers, and milestone from `options` to one newly
+/// created pull request.
+async fn apply_create_options(
+    operations: &ReviewRequestOperations,
+    remote: &ForgeRemote,
+    pull_request_number: &str,
+    options: &ReviewRequestCreateOptions,
+) -> Result<(), ReviewRequestError> {
+    if !options.labels.is_empty() {
+        operations
+            .run_review_command(
+                remote,
+                add_labels_command(remote, pull_request_number, &options.labels),
+                "add pull-request labels",
+            )
+            .await?;
+    }
+    if !options.reviewers.is_empty() {
+        operations
+            .run_review_command(
+                remote,
+                request_reviewers_command(remote, pull_request_number, &options.reviewers),
+                "request pull-request reviewers",
+            )
+            .await?;
+    }
+    if let Some(milestone) = options.milestone.as_deref() {
+        let output = operations
+            .run_review_command(remote, milestones_command(remote), "list open milestones")
+            .await?;
+        let milestone_number = map_parse_error(
+            ForgeKind::GitHub,
+            parse_milestones_response(&output.stdout)
+                .and_then(|milestones| find_milestone_id(milestones, milestone)),
+        )?;
+        operations
+            .run_review_command(
+                remote,
+                set_milestone_command(remote, pull_request_number, milestone_number),
+                "set pull-request milestone",
+            )
+            .await?;
+    }
+
+    Ok(())
 }
 
 /// Builds GitHub-specific metadata view and edit configuration.
@@ -343,31 +522,212 @@ fn parse_lookup_display_id(stdout: &str) -> Result<Option<String>, String> {
 
 /// Builds the `gh pr create` command for `input`.
 ///
-/// GitHub pull requests default to draft so session-published review requests
-/// do not appear ready for merge before the user chooses to mark them ready.
+/// Pull requests open as drafts unless `input` asks for a ready request, so
+/// session-published review requests do not appear ready for merge before the
+/// user chooses to mark them ready.
 /// When a session worktree is available, the command runs there so `gh` does
 /// not inherit a stale process cwd and fail when it shells out to `git`.
 fn create_command(remote: &ForgeRemote, input: &CreateReviewRequestInput) -> ForgeCommand {
+    let mut arguments = vec!["pr".to_string(), "create".to_string()];
+    if input.options.draft {
+        arguments.push("--draft".to_string());
+    }
+    arguments.extend([
+        "--repo".to_string(),
+        remote.project_path(),
+        "--head".to_string(),
+        input.source_branch.clone(),
+        "--base".to_string(),
+        input.target_branch.clone(),
+        "--title".to_string(),
+        input.title.clone(),
+        "--body".to_string(),
+        input.body.clone().unwrap_or_default(),
+    ]);
+
+    github_command(remote, arguments)
+}
+
+/// Builds one `gh api` request that adds `labels` to a pull request through
+/// the issues API.
+fn add_labels_command(
+    remote: &ForgeRemote,
+    pull_request_number: &str,
+    labels: &[String],
+) -> ForgeCommand {
+    let mut arguments = github_api_arguments(
+        remote,
+        "POST",
+        format!(
+            "repos/{}/issues/{pull_request_number}/labels",
+            remote.project_path()
+        ),
+    );
+    for label in labels {
+        arguments.extend(["-f".to_string(), format!("labels[]={label}")]);
+    }
+
+    github_command(remote, arguments)
+}
+
+/// Builds one `gh api` request that asks `reviewers` to review a pull
+/// request.
+///
+/// Entries written as `org/team` request a team review by team slug.
+fn request_reviewers_command(
+    remote: &ForgeRemote,
+    pull_request_number: &str,
+    reviewers: &[String],
+) -> ForgeCommand {
+    let mut arguments = github_api_arguments(
+        remote,
+        "POST",
+        format!(
+            "repos/{}/pulls/{pull_request_number}/requested_reviewers",
+            remote.project_path()
+        ),
+    );
+    for reviewer in reviewers {
+        let field = match reviewer.split_once('/') {
+            Some((_, team_slug)) => format!("team_reviewers[]={team_slug}"),
+            None => format!("reviewers[]={reviewer}"),
+        };
+        arguments.extend(["-f".to_string(), field]);
+    }
+
+    github_command(remote, arguments)
+}
+
+/// Builds one `gh api` request that assigns milestone `milestone_number` to a
+/// pull request.
+fn set_milestone_command(
+    remote: &ForgeRemote,
+    pull_request_number: &str,
+    milestone_number: u64,
+) -> ForgeCommand {
+    let mut arguments = github_api_arguments(
+        remote,
+        "PATCH",
+        format!(
+            "repos/{}/issues/{pull_request_number}",
+            remote.project_path()
+        ),
+    );
+    arguments.extend(["-F".to_string(), format!("milestone={milestone_number}")]);
+
+    github_command(remote, arguments)
+}
+
+/// Builds one `gh api` request that lists the open repository milestones.
+fn milestones_command(remote: &ForgeRemote) -> ForgeCommand {
+    repository_list_command(remote, "milestones", &["state=open"])
+}
+
+/// Builds one `gh api` request that lists the first page of one repository
+/// collection such as `labels` or `assignees`.
+fn repository_list_command(
+    remote: &ForgeRemote,
+    collection: &str,
+    filters: &[&str],
+) -> ForgeCommand {
+    let mut arguments = github_api_arguments(
+        remote,
+        "GET",
+        format!("repos/{}/{collection}", remote.project_path()),
+    );
+    for filter in filters.iter().copied().chain(["per_page=100"]) {
+        arguments.extend(["-f".to_string(), filter.to_string()]);
+    }
+
+    github_command(remote, arguments)
+}
+
+/// Builds one `gh api` request that loads the pull-request node id.
+fn pull_request_node_command(remote: &ForgeRemote, pull_request_number: &str) -> ForgeCommand {
+    github_command(
+        remote,
+        github_api_arguments(
+            remote,
+            "GET",
+            format!(
+                "repos/{}/pulls/{pull_request_number}",
+                remote.project_path()
+            ),
+        ),
+    )
+}
+
+/// Builds one `gh api graphql` mutation that marks a draft pull request ready
+/// for review.
+fn mark_ready_command(remote: &ForgeRemote, node_id: &str) -> ForgeCommand {
     github_command(
         remote,
         vec![
-            "pr".to_string(),
-            "create".to_string(),
-            "--draft".to_string(),
-            "--repo".to_string(),
-            remote.project_path(),
-            "--head".to_string(),
-            input.source_branch.clone(),
-            "--base".to_string(),
-            input.target_branch.clone(),
-            "--title".to_string(),
-            input.title.clone(),
-            "--body".to_string(),
-            input.body.clone().unwrap_or_default(),
+            "api".to_string(),
+            "--hostname".to_string(),
+            remote.host.clone(),
+            "graphql".to_string(),
+            "-f".to_string(),
+            format!("query={MARK_READY_MUTATION}"),
+            "-f".to_string(),
+            format!("pullRequestId={node_id}"),
         ],
     )
 }
 
+/// Returns the leading `gh api` arguments for one REST request.
+fn github_api_arguments(remote: &ForgeRemote, method: &str, endpoint: String) -> Vec<String> {
+    vec![
+        "api".to_string(),
+        "--hostname".to_string(),
+        remote.host.clone(),
+        "--method".to_string(),
+        method.to_string(),
+        endpoint,
+    ]
+}
+
+/// Parses the GraphQL node id from one pull-request REST response.
+fn parse_node_id_response(stdout: &str) -> Result<String, String> {
+    let pull_request: GitHubNodeResponse = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid GitHub pull-request response: {error}"))?;
+
+    Ok(pull_request.node_id)
+}
+
+/// Parses `(title, number)` pairs from one milestones response.
+fn parse_milestones_response(stdout: &str) -> Result<Vec<(String, u64)>, String> {
+    let milestones: Vec<GitHubMilestone> = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid GitHub milestones response: {error}"))?;
+
+    Ok(milestones
+        .into_iter()
+        .map(|milestone| (milestone.title, milestone.number))
+        .collect())
+}
+
+/// Parses the labels, assignable users, and milestones offered while
+/// publishing.
+fn parse_publish_choices(
+    labels_stdout: &str,
+    reviewers_stdout: &str,
+    milestones_stdout: &str,
+) -> Result<ReviewRequestPublishChoices, String> {
+    let labels: Vec<GitHubLabel> = serde_json::from_str(labels_stdout)
+        .map_err(|error| format!("invalid GitHub labels response: {error}"))?;
+    let reviewers: Vec<GitHubUser> = serde_json::from_str(reviewers_stdout)
+        .map_err(|error| format!("invalid GitHub assignees response: {error}"))?;
+
+    Ok(ReviewRequestPublishChoices {
+        labels: labels.into_iter().map(|label| label.name).collect(),
+        milestones: parse_milestones_response(milestones_stdout)?
+            .into_iter()
+            .map(|(title, _)| title)
+            .collect(),
+        reviewers: reviewers.into_iter().map(|user| user.login).collect(),
+    })
+}
+
 /// Parses one GitHub pull-request display id into the numeric argument for
 /// `gh`.
 fn parse_display_id(display_id: &str) -> Result<String, ReviewRequestError> {
@@ -528,6 +888,72 @@ fn paginated_graphql_command(
     github_command(remote, arguments)
 }
 
+/// Builds one `gh api graphql` query for the pull-request head-commit check
+/// rollup.
+fn check_runs_command(remote: &ForgeRemote, pull_request_number: &str) -> ForgeCommand {
+    github_command(
+        remote,
+        vec![
+            "api".to_string(),
+            "--hostname".to_string(),
+            remote.host.clone(),
+            "graphql".to_string(),
+            "-f".to_string(),
+            format!("query={CHECK_RUNS_QUERY}"),
+            "-F".to_string(),
+            format!("owner={}", remote.namespace),
+            "-F".to_string(),
+            format!("repo={}", remote.project),
+            "-F".to_string(),
+            format!("number={pull_request_number}"),
+        ],
+    )
+}
+
+/// Builds one `gh api` request that downloads a GitHub Actions job log.
+fn job_log_command(remote: &ForgeRemote, job_id: &str) -> ForgeCommand {
+    github_command(
+        remote,
+        vec![
+            "api".to_string(),
+            "--hostname".to_string(),
+            remote.host.clone(),
+            format!("repos/{}/actions/jobs/{job_id}/logs", remote.project_path()),
+        ],
+    )
+}
+
+/// Parses the head-commit check rollup from a `gh api graphql` response.
+fn parse_check_runs_response(stdout: &str) -> Result<ReviewCheckReport, String> {
+    let response: GitHubCheckRunsEnvelope = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid GitHub check-runs response: {error}"))?;
+    let pull_request = response
+        .data
+        .and_then(|data| data.repository)
+        .and_then(|repository| repository.pull_request)
+        .ok_or_else(|| "GitHub check-runs response is missing the pull request".to_string())?;
+    let runs = pull_request
+        .commits
+        .nodes
+        .into_iter()
+        .next_back()
+        .and_then(|node| node.commit.status_check_rollup)
+        .map(|rollup| {
+            rollup
+                .contexts
+                .nodes
+                .into_iter()
+                .filter_map(GitHubCheckContextNode::into_check_run)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(ReviewCheckReport {
+        head_sha: pull_request.head_ref_oid,
+        runs,
+    })
+}
+
 /// Builds one `gh api graphql` mutation that replies to a review thread.
 fn reply_to_thread_command(remote: &ForgeRemote, thread_id: &str, body: &str) -> ForgeCommand {
     github_command(
@@ -689,6 +1115,7 @@ fn review_comment_from_node(node: GitHubReviewCommentNode) -> ReviewComment {
 /// optional session worktree for repository-aware git fallback commands.
 fn github_command(remote: &ForgeRemote, arguments: Vec<String>) -> ForgeCommand {
     ForgeCommand::new("gh", arguments)
+        .with_api_host(remote.host.clone())
         .with_environment("CLICOLOR", "0")
         .with_environment("NO_COLOR", "1")
         .with_optional_working_directory(remote.command_working_directory.clone())
@@ -700,6 +1127,31 @@ struct GitHubLookupResponse {
     number: u64,
 }
 
+/// Minimal GitHub pull-request payload that carries the GraphQL node id.
+#[derive(Deserialize)]
+struct GitHubNodeResponse {
+    node_id: String,
+}
+
+/// Repository label returned by `GET repos/{owner}/{repo}/labels`.
+#[derive(Deserialize)]
+struct GitHubLabel {
+    name: String,
+}
+
+/// Repository milestone returned by `GET repos/{owner}/{repo}/milestones`.
+#[derive(Deserialize)]
+struct GitHubMilestone {
+    number: u64,
+    title: String,
+}
+
+/// Assignable user returned by `GET repos/{owner}/{repo}/assignees`.
+#[derive(Deserialize)]
+struct GitHubUser {
+    login: String,
+}
+
 /// GraphQL response envelope for review-threads queries.
 #[derive(Deserialize)]
 struct GitHubReviewThreadsEnvelope {
@@ -823,6 +1275,194 @@ struct GitHubReviewCommentAuthor {
     login: String,
 }
 
+/// GraphQL response envelope for the head-commit check rollup query.
+#[derive(Deserialize)]
+struct GitHubCheckRunsEnvelope {
+    data: Option<GitHubCheckRunsData>,
+}
+
+/// GraphQL `data` payload for the head-commit check rollup query.
+#[derive(Deserialize)]
+struct GitHubCheckRunsData {
+    repository: Option<GitHubCheckRunsRepository>,
+}
+
+/// GraphQL repository node carrying the pull-request field.
+#[derive(Deserialize)]
+struct GitHubCheckRunsRepository {
+    #[serde(rename = "pullRequest")]
+    pull_request: Option<GitHubCheckRunsPullRequest>,
+}
+
+/// GraphQL pull-request node carrying its head commit.
+#[derive(Deserialize)]
+struct GitHubCheckRunsPullRequest {
+    commits: GitHubCheckRunsCommitConnection,
+    #[serde(rename = "headRefOid")]
+    head_ref_oid: String,
+}
+
+/// GraphQL `commits(last: 1)` connection.
+#[derive(Deserialize)]
+struct GitHubCheckRunsCommitConnection {
+    nodes: Vec<GitHubCheckRunsCommitNode>,
+}
+
+/// GraphQL pull-request commit node.
+#[derive(Deserialize)]
+struct GitHubCheckRunsCommitNode {
+    commit: GitHubCheckRunsCommit,
+}
+
+/// GraphQL commit node carrying its optional check rollup.
+#[derive(Deserialize)]
+struct GitHubCheckRunsCommit {
+    #[serde(rename = "statusCheckRollup")]
+    status_check_rollup: Option<GitHubStatusCheckRollup>,
+}
+
+/// GraphQL status-check rollup for one commit.
+#[derive(Deserialize)]
+struct GitHubStatusCheckRollup {
+    contexts: GitHubCheckContextConnection,
+}
+
+/// GraphQL `contexts` connection carrying check runs and commit statuses.
+#[derive(Deserialize)]
+struct GitHubCheckContextConnection {
+    nodes: Vec<GitHubCheckContextNode>,
+}
+
+/// One check-rollup context: a GitHub App check run or a legacy commit
+/// status.
+#[derive(Deserialize)]
+#[serde(tag = "__typename")]
+enum GitHubCheckContextNode {
+    CheckRun {
+        #[serde(rename = "checkSuite")]
+        check_suite: Option<GitHubCheckSuite>,
+        conclusion: Option<String>,
+        #[serde(rename = "databaseId")]
+        database_id: Option<u64>,
+        #[serde(rename = "detailsUrl")]
+        details_url: Option<String>,
+        name: String,
+        status: String,
+    },
+    StatusContext {
+        context: String,
+        state: String,
+        #[serde(rename = "targetUrl")]
+        target_url: Option<String>,
+    },
+    #[serde(other)]
+    Unsupported,
+}
+
+impl GitHubCheckContextNode {
+    /// Converts one rollup context into a normalized check run.
+    ///
+    /// Only GitHub Actions check runs carry a `log_id`, because other apps do
+    /// not expose job logs through the Actions API.
+    fn into_check_run(self) -> Option<ReviewCheckRun> {
+        match self {
+            Self::CheckRun {
+                check_suite,
+                conclusion,
+                database_id,
+                details_url,
+                name,
+                status,
+            } => {
+                let is_actions_run = check_suite
+                    .and_then(|check_suite| check_suite.app)
+                    .is_some_and(|app| app.slug == GITHUB_ACTIONS_APP_SLUG);
+                let status = Self::check_run_status(&status);
+
+                Some(ReviewCheckRun {
+                    conclusion: (status == ReviewCheckStatus::Completed)
+                        .then(|| Self::check_run_conclusion(conclusion.as_deref()))
+                        .flatten(),
+                    log_excerpt: None,
+                    log_id: database_id
+                        .filter(|_| is_actions_run)
+                        .map(|database_id| database_id.to_string()),
+                    name,
+                    status,
+                    url: details_url,
+                })
+            }
+            Self::StatusContext {
+                context,
+                state,
+                target_url,
+            } => {
+                let (status, conclusion) = Self::status_context_result(&state);
+
+                Some(ReviewCheckRun {
+                    conclusion,
+                    log_excerpt: None,
+                    log_id: None,
+                    name: context,
+                    status,
+                    url: target_url,
+                })
+            }
+            Self::Unsupported => None,
+        }
+    }
+
+    /// Maps one GitHub `CheckStatusState` into a normalized status.
+    fn check_run_status(status: &str) -> ReviewCheckStatus {
+        match status {
+            "COMPLETED" => ReviewCheckStatus::Completed,
+            "IN_PROGRESS" => ReviewCheckStatus::InProgress,
+            _ => ReviewCheckStatus::Queued,
+        }
+    }
+
+    /// Maps one GitHub `CheckConclusionState` into a normalized conclusion.
+    fn check_run_conclusion(conclusion: Option<&str>) -> Option<ReviewCheckConclusion> {
+        match conclusion? {
+            "SUCCESS" => Some(ReviewCheckConclusion::Success),
+            "FAILURE" | "TIMED_OUT" | "ACTION_REQUIRED" | "STARTUP_FAILURE" => {
+                Some(ReviewCheckConclusion::Failure)
+            }
+            "CANCELLED" => Some(ReviewCheckConclusion::Cancelled),
+            "SKIPPED" => Some(ReviewCheckConclusion::Skipped),
+            _ => Some(ReviewCheckConclusion::Neutral),
+        }
+    }
+
+    /// Maps one GitHub commit `StatusState` into a normalized status and
+    /// conclusion.
+    fn status_context_result(state: &str) -> (ReviewCheckStatus, Option<ReviewCheckConclusion>) {
+        match state {
+            "SUCCESS" => (
+                ReviewCheckStatus::Completed,
+                Some(ReviewCheckConclusion::Success),
+            ),
+            "ERROR" | "FAILURE" => (
+                ReviewCheckStatus::Completed,
+                Some(ReviewCheckConclusion::Failure),
+            ),
+            _ => (ReviewCheckStatus::Queued, None),
+        }
+    }
+}
+
+/// GraphQL check-suite node identifying the app that created a check run.
+#[derive(Deserialize)]
+struct GitHubCheckSuite {
+    app: Option<GitHubCheckSuiteApp>,
+}
+
+/// GraphQL GitHub App node.
+#[derive(Deserialize)]
+struct GitHubCheckSuiteApp {
+    slug: String,
+}
+
 /// GitHub pull-request JSON payload returned by `gh pr view --json`.
 #[derive(Deserialize)]
 struct GitHubViewResponse {
@@ -992,6 +1632,7 @@ mod tests {
         let remote = github_remote();
         let input = CreateReviewRequestInput {
             body: Some("Implements the provider adapters.".to_string()),
+            options: ReviewRequestCreateOptions::default(),
             source_branch: "feature/forge".to_string(),
             target_branch: "main".to_string(),
             title: "Add forge review support".to_string(),
@@ -1274,6 +1915,7 @@ mod tests {
         let remote = github_remote();
         let input = CreateReviewRequestInput {
             body: Some("Implements the provider adapters.".to_string()),
+            options: ReviewRequestCreateOptions::default(),
             source_branch: "feature/forge".to_string(),
             target_branch: "main".to_string(),
             title: "Add forge review support".to_string(),
@@ -1292,6 +1934,192 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn create_authenticated_review_request_applies_ready_state_labels_reviewers_and_milestone()
+     {
+        // Arrange
+        let remote = github_remote();
+        let input = CreateReviewRequestInput {
+            body: None,
+            options: ReviewRequestCreateOptions {
+                draft: false,
+                labels: vec!["bug".to_string()],
+                milestone: Some("v1.0".to_string()),
+                reviewers: vec!["octocat".to_string(), "agentty-xyz/core".to_string()],
+            },
+            source_branch: "feature/forge".to_string(),
+            target_branch: "main".to_string(),
+            title: "Add forge review support".to_string(),
+        };
+        let expected_commands = vec![
+            create_command(&remote, &input),
+            lookup_command(&remote, "feature/forge"),
+            view_command(&remote, "42"),
+            add_labels_command(&remote, "42", &input.options.labels),
+            request_reviewers_command(&remote, "42", &input.options.reviewers),
+            milestones_command(&remote),
+            set_milestone_command(&remote, "42", 3),
+        ];
+        let outputs = [
+            String::new(),
+            r#"[{"number":42}]"#.to_string(),
+            github_view_json(),
+            "[]".to_string(),
+            "{}".to_string(),
+            r#"[{"number":2,"title":"v0.9"},{"number":3,"title":"v1.0"}]"#.to_string(),
+            "{}".to_string(),
+        ];
+        let mut sequence = Sequence::new();
+        let mut command_runner = MockForgeCommandRunner::new();
+        for (expected_command, output) in expected_commands.into_iter().zip(outputs) {
+            command_runner
+                .expect_run()
+                .once()
+                .in_sequence(&mut sequence)
+                .withf(move |command| command == &expected_command)
+                .returning(move |_| {
+                    let output = output.clone();
+
+                    Box::pin(async move { Ok(success_output(output)) })
+                });
+        }
+        let adapter = GitHubReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let create_command = create_command(&remote, &input);
+        let review_request = adapter
+            .create_authenticated_review_request(remote.clone(), input)
+            .await
+            .expect("GitHub create should succeed");
+        let reviewers_command =
+            request_reviewers_command(&remote, "42", &["agentty-xyz/core".to_string()]);
+
+        // Assert
+        assert_eq!(review_request.display_id, "#42");
+        assert!(!create_command.arguments.contains(&"--draft".to_string()));
+        assert!(
+            reviewers_command
+                .arguments
+                .contains(&"team_reviewers[]=core".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn create_authenticated_review_request_reports_missing_milestone_after_creation() {
+        // Arrange
+        let remote = github_remote();
+        let input = CreateReviewRequestInput {
+            body: None,
+            options: ReviewRequestCreateOptions {
+                milestone: Some("v2.0".to_string()),
+                ..ReviewRequestCreateOptions::default()
+            },
+            source_branch: "feature/forge".to_string(),
+            target_branch: "main".to_string(),
+            title: "Add forge review support".to_string(),
+        };
+        let outputs = [
+            String::new(),
+            r#"[{"number":42}]"#.to_string(),
+            github_view_json(),
+            r#"[{"number":3,"title":"v1.0"}]"#.to_string(),
+        ];
+        let mut sequence = Sequence::new();
+        let mut command_runner = MockForgeCommandRunner::new();
+        for output in outputs {
+            command_runner
+                .expect_run()
+                .once()
+                .in_sequence(&mut sequence)
+                .returning(move |_| {
+                    let output = output.clone();
+
+                    Box::pin(async move { Ok(success_output(output)) })
+                });
+        }
+        let adapter = GitHubReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let error = adapter
+            .create_authenticated_review_request(remote, input)
+            .await
+            .expect_err("unknown milestone should fail");
+
+        // Assert
+        assert_eq!(
+            error,
+            ReviewRequestError::OperationFailed {
+                forge_kind: ForgeKind::GitHub,
+                message: "#42 was created, but applying labels, reviewers, or milestone failed: \
+                          milestone `v2.0` was not found"
+                    .to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn mark_authenticated_review_request_ready_runs_graphql_mutation_by_node_id() {
+        // Arrange
+        let remote = github_remote();
+        let expected_commands = vec![
+            pull_request_node_command(&remote, "42"),
+            mark_ready_command(&remote, "PR_node42"),
+            view_command(&remote, "42"),
+        ];
+        let outputs = [
+            r#"{"node_id":"PR_node42"}"#.to_string(),
+            "{}".to_string(),
+            github_view_json(),
+        ];
+        let mut sequence = Sequence::new();
+        let mut command_runner = MockForgeCommandRunner::new();
+        for (expected_command, output) in expected_commands.into_iter().zip(outputs) {
+            command_runner
+                .expect_run()
+                .once()
+                .in_sequence(&mut sequence)
+                .withf(move |command| command == &expected_command)
+                .returning(move |_| {
+                    let output = output.clone();
+
+                    Box::pin(async move { Ok(success_output(output)) })
+                });
+        }
+        let adapter = GitHubReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let review_request = adapter
+            .mark_authenticated_review_request_ready(remote, "#42".to_string())
+            .await
+            .expect("mark ready should succeed");
+
+        // Assert
+        assert_eq!(review_request.display_id, "#42");
+        assert!(!review_request.is_draft());
+    }
+
+    #[test]
+    fn parse_publish_choices_reads_labels_assignees_and_milestones() {
+        // Arrange
+        let labels = r#"[{"name":"bug"},{"name":"docs"}]"#;
+        let assignees = r#"[{"login":"octocat"}]"#;
+        let milestones = r#"[{"number":3,"title":"v1.0"}]"#;
+
+        // Act
+        let choices = parse_publish_choices(labels, assignees, milestones)
+            .expect("publish choices should parse");
+
+        // Assert
+        assert_eq!(
+            choices,
+            ReviewRequestPublishChoices {
+                labels: vec!["bug".to_string(), "docs".to_string()],
+                milestones: vec!["v1.0".to_string()],
+                reviewers: vec!["octocat".to_string()],
+            }
+        );
+    }
+
     #[test]
     fn github_commands_use_remote_working_directory_for_git_context() {
         // Arrange
@@ -1299,6 +2127,7 @@ mod tests {
             github_remote().with_command_working_directory(PathBuf::from("/tmp/session-worktree"));
         let input = CreateReviewRequestInput {
             body: Some("Implements the provider adapters.".to_string()),
+            options: ReviewRequestCreateOptions::default(),
             source_branch: "feature/forge".to_string(),
             target_branch: "main".to_string(),
             title: "Add forge review support".to_string(),
@@ -1628,6 +2457,92 @@ mod tests {
         assert_eq!(resolution_result, Ok(()));
     }
 
+    #[tokio::test]
+    async fn fetch_authenticated_review_request_checks_maps_check_runs_and_statuses() {
+        // Arrange
+        let remote = github_remote();
+        let mut command_runner = MockForgeCommandRunner::new();
+        command_runner
+            .expect_run()
+            .once()
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &check_runs_command(&remote, "42")
+            })
+            .returning(|_| Box::pin(async { Ok(success_output(github_check_runs_json())) }));
+        let adapter = GitHubReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let report = adapter
+            .fetch_authenticated_review_request_checks(remote, "#42".to_string())
+            .await
+            .expect("GitHub check rollup should parse");
+
+        // Assert
+        assert_eq!(report.head_sha, "abc123");
+        assert_eq!(
+            report.runs,
+            vec![
+                ReviewCheckRun {
+                    conclusion: Some(ReviewCheckConclusion::Failure),
+                    log_excerpt: None,
+                    log_id: Some("901".to_string()),
+                    name: "test".to_string(),
+                    status: ReviewCheckStatus::Completed,
+                    url: Some("https://github.com/agentty-xyz/agentty/actions/runs/1".to_string()),
+                },
+                ReviewCheckRun {
+                    conclusion: None,
+                    log_excerpt: None,
+                    log_id: None,
+                    name: "codecov".to_string(),
+                    status: ReviewCheckStatus::InProgress,
+                    url: None,
+                },
+                ReviewCheckRun {
+                    conclusion: None,
+                    log_excerpt: None,
+                    log_id: None,
+                    name: "ci/legacy".to_string(),
+                    status: ReviewCheckStatus::Queued,
+                    url: Some("https://ci.example.com/build/7".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_authenticated_check_log_excerpt_downloads_actions_job_log() {
+        // Arrange
+        let remote = github_remote();
+        let mut command_runner = MockForgeCommandRunner::new();
+        command_runner
+            .expect_run()
+            .once()
+            .withf({
+                let remote = remote.clone();
+
+                move |command| {
+                    command == &job_log_command(&remote, "901")
+                        && command.arguments.last().map(String::as_str)
+                            == Some("repos/agentty-xyz/agentty/actions/jobs/901/logs")
+                }
+            })
+            .returning(|_| {
+                Box::pin(async { Ok(success_output("cargo test\nerror: 1 failed\n".to_string())) })
+            });
+        let adapter = GitHubReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let excerpt = adapter
+            .fetch_authenticated_check_log_excerpt(remote, "901".to_string())
+            .await;
+
+        // Assert
+        assert_eq!(excerpt, Ok("cargo test\nerror: 1 failed".to_string()));
+    }
+
     #[test]
     fn parse_review_thread_pages_rejects_missing_data() {
         // Arrange
@@ -2024,6 +2939,55 @@ mod tests {
         })
     }
 
+    fn github_check_runs_json() -> String {
+        serde_json::json!({
+            "data": {
+                "repository": {
+                    "pullRequest": {
+                        "headRefOid": "abc123",
+                        "commits": {
+                            "nodes": [{
+                                "commit": {
+                                    "statusCheckRollup": {
+                                        "contexts": {
+                                            "nodes": [
+                                                {
+                                                    "__typename": "CheckRun",
+                                                    "checkSuite": { "app": { "slug": "github-actions" } },
+                                                    "conclusion": "TIMED_OUT",
+                                                    "databaseId": 901,
+                                                    "detailsUrl": "https://github.com/agentty-xyz/agentty/actions/runs/1",
+                                                    "name": "test",
+                                                    "status": "COMPLETED"
+                                                },
+                                                {
+                                                    "__typename": "CheckRun",
+                                                    "checkSuite": { "app": { "slug": "codecov" } },
+                                                    "conclusion": null,
+                                                    "databaseId": 902,
+                                                    "detailsUrl": null,
+                                                    "name": "codecov",
+                                                    "status": "IN_PROGRESS"
+                                                },
+                                                {
+                                                    "__typename": "StatusContext",
+                                                    "context": "ci/legacy",
+                                                    "state": "PENDING",
+                                                    "targetUrl": "https://ci.example.com/build/7"
+                                                }
+                                            ]
+                                        }
+                                    }
+                                }
+                            }]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    }
+
     fn github_view_json() -> String {
         r#"{
             "number": 42,
@@ -7,11 +7,14 @@ use url::{Url, form_urlencoded};
 
 use super::{
     CreateReviewRequestInput, ForgeCommand, ForgeCommandRunner, ForgeFuture, ForgeKind,
-    ForgeRemote, ReviewComment, ReviewCommentAnchorSide, ReviewCommentSnapshot,
-    ReviewCommentThread, ReviewRequestAdapter, ReviewRequestError, ReviewRequestMetadata,
-    ReviewRequestMetadataEdit, ReviewRequestOperations, ReviewRequestState, ReviewRequestSummary,
-    SyncReviewRequestMetadataConfig, UpdateReviewRequestInput, is_gitlab_host, map_parse_error,
-    normalize_provider_label, parse_remote_url, status_summary_parts, strip_port,
+    ForgeRemote, ReviewCheckConclusion, ReviewCheckReport, ReviewCheckRun, ReviewCheckStatus,
+    ReviewComment, ReviewCommentAnchorSide, ReviewCommentSnapshot, ReviewCommentThread,
+    ReviewRequestAdapter, ReviewRequestCreateOptions, ReviewRequestError, ReviewRequestMetadata,
+    ReviewRequestMetadataEdit, ReviewRequestOperations, ReviewRequestPublishChoices,
+    ReviewRequestState, ReviewRequestSummary, SyncReviewRequestMetadataConfig,
+    UpdateReviewRequestInput, find_milestone_id, is_gitlab_host, map_parse_error,
+    normalize_provider_label, parse_remote_url, publish_metadata_failed, status_summary_parts,
+    strip_draft_title_prefix, strip_port,
 };
 
 /// GitLab merge-request adapter that normalizes `glab` command output.
@@ -68,7 +71,8 @@ impl ReviewRequestAdapter for GitLabReviewRequestAdapter {
         )
     }
 
-    /// Creates one new draft merge request from `input`.
+    /// Creates one new merge request from `input`, then applies its labels,
+    /// reviewers, and milestone.
     fn create_authenticated_review_request(
         &self,
         remote: ForgeRemote,
@@ -84,6 +88,12 @@ impl ReviewRequestAdapter for GitLabReviewRequestAdapter {
                 .await?;
             let display_id =
                 map_parse_error(remote.forge_kind, parse_create_display_id(&output.stdout))?;
+            if input.options.has_metadata() {
+                let merge_request_iid = parse_display_id(&display_id)?;
+                apply_create_options(&operations, &remote, &merge_request_iid, &input.options)
+                    .await
+                    .map_err(|error| publish_metadata_failed(&display_id, error))?;
+            }
 
             adapter
                 .refresh_authenticated_review_request(remote, display_id)
@@ -156,6 +166,67 @@ impl ReviewRequestAdapter for GitLabReviewRequestAdapter {
         )
     }
 
+    /// Fetches the jobs of the merge-request head pipeline through GitLab's
+    /// REST API.
+    fn fetch_authenticated_review_request_checks(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewCheckReport, ReviewRequestError>> {
+        let operations = self.operations.clone();
+
+        Box::pin(async move {
+            let merge_request_iid = parse_display_id(&display_id)?;
+            let merge_request_output = operations
+                .run_review_command(
+                    &remote,
+                    merge_request_pipeline_command(&remote, &merge_request_iid),
+                    "fetch merge-request head pipeline",
+                )
+                .await?;
+            let merge_request = map_parse_error(
+                ForgeKind::GitLab,
+                parse_merge_request_pipeline_response(&merge_request_output.stdout),
+            )?;
+            let Some(pipeline) = merge_request.head_pipeline else {
+                return Ok(ReviewCheckReport {
+                    head_sha: merge_request.sha,
+                    runs: Vec::new(),
+                });
+            };
+
+            let jobs_output = operations
+                .run_review_command(
+                    &remote,
+                    pipeline_jobs_command(&remote, pipeline.id),
+                    "fetch merge-request pipeline jobs",
+                )
+                .await?;
+            let runs = map_parse_error(
+                ForgeKind::GitLab,
+                parse_pipeline_jobs_response(&jobs_output.stdout),
+            )?;
+
+            Ok(ReviewCheckReport {
+                head_sha: merge_request.sha,
+                runs,
+            })
+        })
+    }
+
+    fn fetch_authenticated_check_log_excerpt(
+        &self,
+        remote: ForgeRemote,
+        log_id: String,
+    ) -> ForgeFuture<Result<String, ReviewRequestError>> {
+        self.operations.fetch_check_log_excerpt_future(
+            remote,
+            log_id,
+            job_trace_command,
+            "download GitLab job log",
+        )
+    }
+
     fn reply_to_authenticated_thread(
         &self,
         remote: ForgeRemote,
@@ -200,6 +271,142 @@ impl ReviewRequestAdapter for GitLabReviewRequestAdapter {
             Ok(())
         })
     }
+
+    /// Marks one draft merge request ready by removing its draft title
+    /// prefix, which is how GitLab stores the draft state.
+    fn mark_authenticated_review_request_ready(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ReviewRequestSummary, ReviewRequestError>> {
+        let adapter = self.clone();
+        let operations = self.operations.clone();
+
+        Box::pin(async move {
+            let merge_request_iid = parse_display_id(&display_id)?;
+            let metadata = operations
+                .review_request_metadata_future(
+                    remote.clone(),
+                    display_id.clone(),
+                    &metadata_sync_config(),
+                )
+                .await?;
+            if let Some(title) = strip_draft_title_prefix(&metadata.title) {
+                let edit = ReviewRequestMetadataEdit {
+                    body: None,
+                    title: Some(title),
+                };
+                operations
+                    .run_review_command(
+                        &remote,
+                        update_metadata_command(&remote, &merge_request_iid, &edit),
+                        "mark merge request ready",
+                    )
+                    .await?;
+            }
+
+            adapter
+                .refresh_authenticated_review_request(remote, display_id)
+                .await
+        })
+    }
+
+    /// Loads project labels, members, and active milestones.
+    fn fetch_authenticated_review_request_publish_choices(
+        &self,
+        remote: ForgeRemote,
+    ) -> ForgeFuture<Result<ReviewRequestPublishChoices, ReviewRequestError>> {
+        let operations = self.operations.clone();
+
+        Box::pin(async move {
+            let labels_output = operations
+                .run_review_command(
+                    &remote,
+                    project_list_command(&remote, "labels"),
+                    "list project labels",
+                )
+                .await?;
+            let reviewers_output = operations
+                .run_review_command(
+                    &remote,
+                    project_list_command(&remote, "members/all"),
+                    "list project members",
+                )
+                .await?;
+            let milestones_output = operations
+                .run_review_command(
+                    &remote,
+                    milestones_command(&remote),
+                    "list active milestones",
+                )
+                .await?;
+
+            map_parse_error(
+                ForgeKind::GitLab,
+                parse_publish_choices(
+                    &labels_output.stdout,
+                    &reviewers_output.stdout,
+                    &milestones_output.stdout,
+                ),
+            )
+        })
+    }
+}
+
+/// Applies the labels, reviewers, and milestone from `options` to one newly
+/// created merge request in a single update.
+///
+/// GitLab assigns reviewers by user id, so each username is resolved first.
+async fn apply_create_options(
+    operations: &ReviewRequestOperations,
+    remote: &ForgeRemote,
+    merge_request_iid: &str,
+    options: &ReviewRequestCreateOptions,
+) -> Result<(), ReviewRequestError> {
+    let mut reviewer_ids = Vec::with_capacity(options.reviewers.len());
+    for reviewer in &options.reviewers {
+        let output = operations
+            .run_review_command(
+                remote,
+                user_lookup_command(remote, reviewer),
+                "look up reviewer",
+            )
+            .await?;
+        reviewer_ids.push(map_parse_error(
+            ForgeKind::GitLab,
+            parse_user_id_response(&output.stdout, reviewer),
+        )?);
+    }
+    let milestone_id = match options.milestone.as_deref() {
+        Some(milestone) => {
+            let output = operations
+                .run_review_command(remote, milestones_command(remote), "list active milestones")
+                .await?;
+
+            Some(map_parse_error(
+                ForgeKind::GitLab,
+                parse_milestones_response(&output.stdout)
+                    .and_then(|milestones| find_milestone_id(milestones, milestone)),
+            )?)
+        }
+        None => None,
+    };
+
+    operations
+        .run_review_command(
+            remote,
+            apply_create_options_command(
+                remote,
+                merge_request_iid,
+                &options.labels,
+                &reviewer_ids,
+                milestone_id,
+            ),
+            "update merge-request labels, reviewers, and milestone",
+        )
+        .await?;
+
+    Ok(())
 }
 
 /// Builds GitLab-specific metadata view and edit configuration.
@@ -265,31 +472,156 @@ fn parse_lookup_display_id(stdout: &str) -> Result<Option<String>, String> {
 
 /// Builds the `glab mr create` command for `input`.
 ///
-/// GitLab merge requests default to draft so session-published review requests
-/// do not appear ready for merge before the user chooses to mark them ready.
+/// Merge requests open as drafts unless `input` asks for a ready request, so
+/// session-published review requests do not appear ready for merge before the
+/// user chooses to mark them ready.
 fn create_command(remote: &ForgeRemote, input: &CreateReviewRequestInput) -> ForgeCommand {
-    gitlab_command(
+    let mut arguments = vec![
+        "mr".to_string(),
+        "create".to_string(),
+        "--repo".to_string(),
+        remote.web_url.clone(),
+    ];
+    if input.options.draft {
+        arguments.push("--draft".to_string());
+    }
+    arguments.extend([
+        "--source-branch".to_string(),
+        input.source_branch.clone(),
+        "--target-branch".to_string(),
+        input.target_branch.clone(),
+        "--title".to_string(),
+        input.title.clone(),
+        "--description".to_string(),
+        input.body.clone().unwrap_or_default(),
+        "--yes".to_string(),
+    ]);
+
+    gitlab_command(remote, "glab", arguments)
+}
+
+/// Builds one `glab api` request that sets labels, reviewers, and milestone
+/// on a merge request.
+fn apply_create_options_command(
+    remote: &ForgeRemote,
+    merge_request_iid: &str,
+    labels: &[String],
+    reviewer_ids: &[u64],
+    milestone_id: Option<u64>,
+) -> ForgeCommand {
+    let mut arguments = vec![
+        "api".to_string(),
+        "--hostname".to_string(),
+        remote.host.clone(),
+        "--method".to_string(),
+        "PUT".to_string(),
+    ];
+    if !labels.is_empty() {
+        arguments.extend([
+            "--raw-field".to_string(),
+            format!("labels={}", labels.join(",")),
+        ]);
+    }
+    for reviewer_id in reviewer_ids {
+        arguments.extend([
+            "--field".to_string(),
+            format!("reviewer_ids[]={reviewer_id}"),
+        ]);
+    }
+    if let Some(milestone_id) = milestone_id {
+        arguments.extend([
+            "--field".to_string(),
+            format!("milestone_id={milestone_id}"),
+        ]);
+    }
+    arguments.push(format!(
+        "{}/merge_requests/{merge_request_iid}",
+        project_endpoint(remote)
+    ));
+
+    gitlab_command(remote, "glab", arguments)
+}
+
+/// Builds the `glab api` request that finds one user by username.
+fn user_lookup_command(remote: &ForgeRemote, username: &str) -> ForgeCommand {
+    let encoded_username: String = form_urlencoded::byte_serialize(username.as_bytes()).collect();
+
+    gitlab_api_command(remote, format!("/users?username={encoded_username}"))
+}
+
+/// Builds the `glab api` request that lists active project milestones.
+fn milestones_command(remote: &ForgeRemote) -> ForgeCommand {
+    gitlab_api_command(
         remote,
-        "glab",
-        vec![
-            "mr".to_string(),
-            "create".to_string(),
-            "--repo".to_string(),
-            remote.web_url.clone(),
-            "--draft".to_string(),
-            "--source-branch".to_string(),
-            input.source_branch.clone(),
-            "--target-branch".to_string(),
-            input.target_branch.clone(),
-            "--title".to_string(),
-            input.title.clone(),
-            "--description".to_string(),
-            input.body.clone().unwrap_or_default(),
-            "--yes".to_string(),
-        ],
+        format!(
+            "{}/milestones?state=active&per_page=100",
+            project_endpoint(remote)
+        ),
+    )
+}
+
+/// Builds the `glab api` request that lists the first page of one project
+/// collection such as `labels` or `members/all`.
+fn project_list_command(remote: &ForgeRemote, collection: &str) -> ForgeCommand {
+    gitlab_api_command(
+        remote,
+        format!("{}/{collection}?per_page=100", project_endpoint(remote)),
     )
 }
 
+/// Returns the API path of the project behind `remote`.
+fn project_endpoint(remote: &ForgeRemote) -> String {
+    let encoded_project_path: String =
+        form_urlencoded::byte_serialize(remote.project_path().as_bytes()).collect();
+
+    format!("/projects/{encoded_project_path}")
+}
+
+/// Parses the id of `username` from one user lookup response.
+fn parse_user_id_response(stdout: &str, username: &str) -> Result<u64, String> {
+    let users: Vec<GitLabUser> = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid GitLab users response: {error}"))?;
+
+    users
+        .into_iter()
+        .find(|user| user.username.eq_ignore_ascii_case(username))
+        .map(|user| user.id)
+        .ok_or_else(|| format!("reviewer `{username}` was not found"))
+}
+
+/// Parses `(title, id)` pairs from one milestones response.
+fn parse_milestones_response(stdout: &str) -> Result<Vec<(String, u64)>, String> {
+    let milestones: Vec<GitLabMilestone> = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid GitLab milestones response: {error}"))?;
+
+    Ok(milestones
+        .into_iter()
+        .map(|milestone| (milestone.title, milestone.id))
+        .collect())
+}
+
+/// Parses the labels, project members, and milestones offered while
+/// publishing.
+fn parse_publish_choices(
+    labels_stdout: &str,
+    reviewers_stdout: &str,
+    milestones_stdout: &str,
+) -> Result<ReviewRequestPublishChoices, String> {
+    let labels: Vec<GitLabLabel> = serde_json::from_str(labels_stdout)
+        .map_err(|error| format!("invalid GitLab labels response: {error}"))?;
+    let reviewers: Vec<GitLabUser> = serde_json::from_str(reviewers_stdout)
+        .map_err(|error| format!("invalid GitLab members response: {error}"))?;
+
+    Ok(ReviewRequestPublishChoices {
+        labels: labels.into_iter().map(|label| label.name).collect(),
+        milestones: parse_milestones_response(milestones_stdout)?
+            .into_iter()
+            .map(|(title, _)| title)
+            .collect(),
+        reviewers: reviewers.into_iter().map(|user| user.username).collect(),
+    })
+}
+
 /// Parses one merge-request display id from `glab mr create` stdout.
 fn parse_create_display_id(stdout: &str) -> Result<String, String> {
     let created_url = stdout
@@ -482,6 +814,72 @@ fn resolve_thread_command(
     )
 }
 
+/// Builds the `glab api` request that reads the merge-request head pipeline.
+fn merge_request_pipeline_command(remote: &ForgeRemote, merge_request_iid: &str) -> ForgeCommand {
+    let encoded_project_path: String =
+        form_urlencoded::byte_serialize(remote.project_path().as_bytes()).collect();
+
+    gitlab_api_command(
+        remote,
+        format!("/projects/{encoded_project_path}/merge_requests/{merge_request_iid}"),
+    )
+}
+
+/// Builds the `glab api` request that lists the jobs of one pipeline.
+fn pipeline_jobs_command(remote: &ForgeRemote, pipeline_id: u64) -> ForgeCommand {
+    let encoded_project_path: String =
+        form_urlencoded::byte_serialize(remote.project_path().as_bytes()).collect();
+
+    gitlab_api_command(
+        remote,
+        format!("/projects/{encoded_project_path}/pipelines/{pipeline_id}/jobs?per_page=100"),
+    )
+}
+
+/// Builds the `glab api` request that downloads one job trace.
+fn job_trace_command(remote: &ForgeRemote, job_id: &str) -> ForgeCommand {
+    let encoded_project_path: String =
+        form_urlencoded::byte_serialize(remote.project_path().as_bytes()).collect();
+
+    gitlab_api_command(
+        remote,
+        format!("/projects/{encoded_project_path}/jobs/{job_id}/trace"),
+    )
+}
+
+/// Builds one read-only `glab api` request for `endpoint`.
+fn gitlab_api_command(remote: &ForgeRemote, endpoint: String) -> ForgeCommand {
+    gitlab_command(
+        remote,
+        "glab",
+        vec![
+            "api".to_string(),
+            "--hostname".to_string(),
+            remote.host.clone(),
+            endpoint,
+        ],
+    )
+}
+
+/// Parses the head commit and head pipeline from one merge-request response.
+fn parse_merge_request_pipeline_response(
+    stdout: &str,
+) -> Result<GitLabMergeRequestPipelineResponse, String> {
+    serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid GitLab merge-request pipeline response: {error}"))
+}
+
+/// Parses pipeline jobs into normalized check runs.
+fn parse_pipeline_jobs_response(stdout: &str) -> Result<Vec<ReviewCheckRun>, String> {
+    let jobs: Vec<GitLabPipelineJob> = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid GitLab pipeline jobs response: {error}"))?;
+
+    Ok(jobs
+        .into_iter()
+        .map(GitLabPipelineJob::into_check_run)
+        .collect())
+}
+
 /// Returns the encoded GitLab discussion endpoint for one optional child
 /// resource.
 fn discussion_endpoint(
@@ -622,6 +1020,7 @@ fn gitlab_command(
     arguments: Vec<String>,
 ) -> ForgeCommand {
     ForgeCommand::new(executable, arguments)
+        .with_api_host(remote.host.clone())
         .with_environment("CLICOLOR", "0")
         .with_environment("NO_COLOR", "1")
         .with_environment("GITLAB_HOST", remote.host.clone())
@@ -707,6 +1106,73 @@ impl GitLabViewResponse {
     }
 }
 
+/// GitLab merge-request payload fields that identify the head pipeline.
+#[derive(Deserialize)]
+struct GitLabMergeRequestPipelineResponse {
+    head_pipeline: Option<GitLabHeadPipeline>,
+    #[serde(default)]
+    sha: String,
+}
+
+/// GitLab head pipeline reference embedded in a merge-request payload.
+#[derive(Deserialize)]
+struct GitLabHeadPipeline {
+    id: u64,
+}
+
+/// One GitLab pipeline job returned by the pipeline jobs API.
+#[derive(Deserialize)]
+struct GitLabPipelineJob {
+    #[serde(default)]
+    allow_failure: bool,
+    id: u64,
+    name: String,
+    status: String,
+    web_url: Option<String>,
+}
+
+impl GitLabPipelineJob {
+    /// Converts one pipeline job into a normalized check run.
+    ///
+    /// Failed jobs marked `allow_failure` finish as neutral so they do not
+    /// turn the merge-request badge red.
+    fn into_check_run(self) -> ReviewCheckRun {
+        let (status, conclusion) = match self.status.as_str() {
+            "success" => (
+                ReviewCheckStatus::Completed,
+                Some(ReviewCheckConclusion::Success),
+            ),
+            "failed" if self.allow_failure => (
+                ReviewCheckStatus::Completed,
+                Some(ReviewCheckConclusion::Neutral),
+            ),
+            "failed" => (
+                ReviewCheckStatus::Completed,
+                Some(ReviewCheckConclusion::Failure),
+            ),
+            "canceled" => (
+                ReviewCheckStatus::Completed,
+                Some(ReviewCheckConclusion::Cancelled),
+            ),
+            "skipped" | "manual" => (
+                ReviewCheckStatus::Completed,
+                Some(ReviewCheckConclusion::Skipped),
+            ),
+            "running" => (ReviewCheckStatus::InProgress, None),
+            _ => (ReviewCheckStatus::Queued, None),
+        };
+
+        ReviewCheckRun {
+            conclusion,
+            log_excerpt: None,
+            log_id: Some(self.id.to_string()),
+            name: self.name,
+            status,
+            url: self.web_url,
+        }
+    }
+}
+
 /// GitLab merge-request title/description payload returned by
 /// `glab mr view --output json`.
 #[derive(Deserialize)]
@@ -716,6 +1182,26 @@ struct GitLabMetadataResponse {
     title: String,
 }
 
+/// Project label returned by the labels API.
+#[derive(Deserialize)]
+struct GitLabLabel {
+    name: String,
+}
+
+/// Project milestone returned by the milestones API.
+#[derive(Deserialize)]
+struct GitLabMilestone {
+    id: u64,
+    title: String,
+}
+
+/// User returned by the users and project members APIs.
+#[derive(Deserialize)]
+struct GitLabUser {
+    id: u64,
+    username: String,
+}
+
 /// GitLab merge-request discussion returned by the discussions API.
 #[derive(Clone, Deserialize)]
 struct GitLabDiscussion {
@@ -867,6 +1353,7 @@ mod tests {
         let remote = gitlab_remote();
         let input = CreateReviewRequestInput {
             body: Some("Implements the provider adapters.".to_string()),
+            options: ReviewRequestCreateOptions::default(),
             source_branch: "feature/forge".to_string(),
             target_branch: "main".to_string(),
             title: "Add forge review support".to_string(),
@@ -917,6 +1404,132 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn create_authenticated_review_request_updates_labels_reviewers_and_milestone() {
+        // Arrange
+        let remote = gitlab_remote();
+        let input = CreateReviewRequestInput {
+            body: None,
+            options: ReviewRequestCreateOptions {
+                draft: false,
+                labels: vec!["bug".to_string(), "backend".to_string()],
+                milestone: Some("v1.0".to_string()),
+                reviewers: vec!["alice".to_string()],
+            },
+            source_branch: "feature/forge".to_string(),
+            target_branch: "main".to_string(),
+            title: "Add forge review support".to_string(),
+        };
+        let expected_commands = vec![
+            create_command(&remote, &input),
+            user_lookup_command(&remote, "alice"),
+            milestones_command(&remote),
+            apply_create_options_command(&remote, "42", &input.options.labels, &[7], Some(11)),
+            view_command(&remote, "42"),
+        ];
+        let outputs = [
+            "https://gitlab.com/agentty-xyz/agentty/-/merge_requests/42\n".to_string(),
+            r#"[{"id":7,"username":"alice"}]"#.to_string(),
+            r#"[{"id":11,"title":"v1.0"}]"#.to_string(),
+            "{}".to_string(),
+            gitlab_view_json(),
+        ];
+        let mut sequence = Sequence::new();
+        let mut command_runner = MockForgeCommandRunner::new();
+        for (expected_command, output) in expected_commands.into_iter().zip(outputs) {
+            command_runner
+                .expect_run()
+                .once()
+                .in_sequence(&mut sequence)
+                .withf(move |command| command == &expected_command)
+                .returning(move |_| {
+                    let output = output.clone();
+
+                    Box::pin(async move { Ok(success_output(output)) })
+                });
+        }
+        let adapter = GitLabReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let create_command = create_command(&remote, &input);
+        let update_command =
+            apply_create_options_command(&remote, "42", &input.options.labels, &[7], Some(11));
+        let review_request = adapter
+            .create_authenticated_review_request(remote, input)
+            .await
+            .expect("GitLab create should succeed");
+
+        // Assert
+        assert_eq!(review_request.display_id, "!42");
+        assert!(!create_command.arguments.contains(&"--draft".to_string()));
+        assert!(
+            update_command
+                .arguments
+                .contains(&"labels=bug,backend".to_string())
+        );
+        assert!(
+            update_command
+                .arguments
+                .contains(&"reviewer_ids[]=7".to_string())
+        );
+        assert!(
+            update_command
+                .arguments
+                .contains(&"milestone_id=11".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn mark_authenticated_review_request_ready_removes_draft_title_prefix() {
+        // Arrange
+        let remote = gitlab_remote();
+        let draft_view_json = serde_json::json!({
+            "description": "Current description.",
+            "draft": true,
+            "iid": 42,
+            "source_branch": "feature/forge",
+            "state": "opened",
+            "target_branch": "main",
+            "title": "Draft: Add forge review support",
+            "web_url": "https://gitlab.com/agentty-xyz/agentty/-/merge_requests/42"
+        })
+        .to_string();
+        let ready_edit = ReviewRequestMetadataEdit {
+            body: None,
+            title: Some("Add forge review support".to_string()),
+        };
+        let expected_commands = vec![
+            view_command(&remote, "42"),
+            update_metadata_command(&remote, "42", &ready_edit),
+            view_command(&remote, "42"),
+        ];
+        let outputs = [draft_view_json, "{}".to_string(), gitlab_view_json()];
+        let mut sequence = Sequence::new();
+        let mut command_runner = MockForgeCommandRunner::new();
+        for (expected_command, output) in expected_commands.into_iter().zip(outputs) {
+            command_runner
+                .expect_run()
+                .once()
+                .in_sequence(&mut sequence)
+                .withf(move |command| command == &expected_command)
+                .returning(move |_| {
+                    let output = output.clone();
+
+                    Box::pin(async move { Ok(success_output(output)) })
+                });
+        }
+        let adapter = GitLabReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let review_request = adapter
+            .mark_authenticated_review_request_ready(remote, "!42".to_string())
+            .await
+            .expect("mark ready should succeed");
+
+        // Assert
+        assert_eq!(review_request.display_id, "!42");
+    }
+
     #[tokio::test]
     async fn authenticated_review_request_metadata_loads_current_merge_request() {
         // Arrange
@@ -1238,6 +1851,109 @@ mod tests {
         assert_eq!(snapshot.pr_level_comments[0].author, "carol");
     }
 
+    #[tokio::test]
+    async fn fetch_authenticated_review_request_checks_loads_head_pipeline_jobs() {
+        // Arrange
+        let remote = gitlab_remote();
+        let mut sequence = Sequence::new();
+        let mut command_runner = MockForgeCommandRunner::new();
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &merge_request_pipeline_command(&remote, "42")
+            })
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(success_output(
+                        r#"{"sha":"def456","head_pipeline":{"id":77}}"#.to_string(),
+                    ))
+                })
+            });
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &pipeline_jobs_command(&remote, 77)
+            })
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(success_output(
+                        r#"[
+                            {"id":1,"name":"lint","status":"failed","allow_failure":true,"web_url":null},
+                            {"id":2,"name":"test","status":"failed","web_url":"https://gitlab.com/jobs/2"},
+                            {"id":3,"name":"deploy","status":"manual","web_url":null}
+                        ]"#
+                        .to_string(),
+                    ))
+                })
+            });
+        let adapter = GitLabReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let report = adapter
+            .fetch_authenticated_review_request_checks(remote, "!42".to_string())
+            .await
+            .expect("GitLab pipeline jobs should parse");
+
+        // Assert
+        assert_eq!(report.head_sha, "def456");
+        assert_eq!(
+            report
+                .runs
+                .iter()
+                .map(|run| (run.name.as_str(), run.conclusion, run.log_id.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("lint", Some(ReviewCheckConclusion::Neutral), Some("1")),
+                ("test", Some(ReviewCheckConclusion::Failure), Some("2")),
+                ("deploy", Some(ReviewCheckConclusion::Skipped), Some("3")),
+            ]
+        );
+        assert_eq!(
+            report
+                .failed_runs()
+                .map(|run| run.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["test"]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_authenticated_review_request_checks_returns_empty_report_without_pipeline() {
+        // Arrange
+        let remote = gitlab_remote();
+        let mut command_runner = MockForgeCommandRunner::new();
+        command_runner.expect_run().once().returning(|_| {
+            Box::pin(async {
+                Ok(success_output(
+                    r#"{"sha":"def456","head_pipeline":null}"#.to_string(),
+                ))
+            })
+        });
+        let adapter = GitLabReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let report = adapter
+            .fetch_authenticated_review_request_checks(remote, "!42".to_string())
+            .await;
+
+        // Assert
+        assert_eq!(
+            report,
+            Ok(ReviewCheckReport {
+                head_sha: "def456".to_string(),
+                runs: Vec::new(),
+            })
+        );
+    }
+
     #[tokio::test]
     async fn review_thread_reply_and_resolution_run_discussion_requests() {
         // Arrange
@@ -1357,6 +2073,7 @@ mod tests {
             gitlab_remote().with_command_working_directory(PathBuf::from("/tmp/session-worktree"));
         let input = CreateReviewRequestInput {
             body: Some("Implements the provider adapters.".to_string()),
+            options: ReviewRequestCreateOptions::default(),
             source_branch: "feature/forge".to_string(),
             target_branch: "main".to_string(),
             title: "Add forge review support".to_string(),
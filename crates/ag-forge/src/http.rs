@@ -0,0 +1,1772 @@
+//! HTTP forge backend that serves adapter commands through forge REST and
+//! GraphQL APIs instead of spawning `gh`, `glab`, or `tea`.
+//!
+//! Adapters build the same [`ForgeCommand`] values for both backends.
+//! [`HttpForgeCommandRunner`] translates the CLI invocations adapters issue
+//! into API requests and renders CLI-shaped stdout, so response parsing stays
+//! shared between backends.
+
+use std::collections::{HashMap, VecDeque};
+use std::env;
+use std::error::Error as _;
+use std::process::Stdio;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::{ACCEPT, AUTHORIZATION, HeaderMap, LINK, RETRY_AFTER};
+use reqwest::{Method, RequestBuilder, StatusCode};
+use serde_json::{Map, Value, json};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tokio::time;
+use url::{Url, form_urlencoded};
+
+use super::{
+    FORGE_COMMAND_TIMEOUT, ForgeCommand, ForgeCommandError, ForgeCommandOutput, ForgeCommandRunner,
+    ForgeFuture,
+};
+
+/// Maximum time one `git credential fill` lookup may run.
+const CREDENTIAL_HELPER_TIMEOUT: Duration = Duration::from_secs(10);
+/// Maximum number of pages one paginated request follows.
+const MAX_PAGES: usize = 100;
+/// Maximum number of retries after rate-limited responses for one request.
+const MAX_RATE_LIMIT_RETRIES: usize = 2;
+/// Longest rate-limit reset the runner waits for before failing the request.
+const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(60);
+/// CLI options that consume the following argument as their value.
+const VALUE_OPTIONS: &[&str] = &[
+    "--base",
+    "--body",
+    "--data",
+    "--description",
+    "--field",
+    "--head",
+    "--hostname",
+    "--json",
+    "--method",
+    "--order",
+    "--output",
+    "--per-page",
+    "--raw-field",
+    "--repo",
+    "--sort",
+    "--source-branch",
+    "--target-branch",
+    "--title",
+    "-F",
+    "-f",
+];
+/// GraphQL field path selected from `gh pr view` replacement queries.
+const PULL_REQUEST_RESULT_PATH: &[&str] = &["data", "repository", "pullRequest"];
+/// User agent sent with every API request; GitHub rejects anonymous agents.
+const USER_AGENT: &str = concat!("agentty/", env!("CARGO_PKG_VERSION"));
+
+/// [`ForgeCommandRunner`] that talks to forge HTTP APIs with token
+/// authentication instead of spawning forge CLIs.
+///
+/// Tokens come from forge-specific environment variables first and
+/// `git credential fill` second, and are cached per host until one request
+/// is rejected with HTTP 401.
+#[derive(Clone)]
+pub(crate) struct HttpForgeCommandRunner {
+    api_origin_override: Option<String>,
+    client: reqwest::Client,
+    tokens: Arc<ForgeTokenStore>,
+}
+
+impl HttpForgeCommandRunner {
+    /// Builds one runner with the shared forge request timeout.
+    ///
+    /// # Errors
+    /// Returns the `reqwest` error when the TLS backend or system
+    /// configuration prevents building the HTTP client.
+    pub(crate) fn new() -> Result<Self, reqwest::Error> {
+        let client = reqwest::Client::builder()
+            .timeout(FORGE_COMMAND_TIMEOUT)
+            .user_agent(USER_AGENT)
+            .build()?;
+
+        Ok(Self {
+            api_origin_override: None,
+            client,
+            tokens: Arc::new(ForgeTokenStore::default()),
+        })
+    }
+
+    /// Routes every API request to `origin` instead of the forge host.
+    #[cfg(test)]
+    fn with_api_origin(mut self, origin: &str) -> Self {
+        self.api_origin_override = Some(origin.trim_end_matches('/').to_string());
+
+        self
+    }
+
+    /// Seeds the token cache so tests do not consult the environment.
+    #[cfg(test)]
+    fn with_token(self, host: &str, token: &str) -> Self {
+        self.tokens.remember(host, token.to_string());
+
+        self
+    }
+
+    /// Translates and runs one forge command through the HTTP API.
+    async fn run_command(
+        &self,
+        command: ForgeCommand,
+    ) -> Result<ForgeCommandOutput, ForgeCommandError> {
+        let executable = command.executable;
+        let request =
+            translate_command(&command).map_err(|message| ForgeCommandError::SpawnFailed {
+                executable: executable.to_string(),
+                message,
+            })?;
+        let Some(token) = self.tokens.token(request.api, &request.host).await else {
+            return Ok(missing_token_output(request.api, &request.host));
+        };
+        let origin = self
+            .api_origin_override
+            .clone()
+            .unwrap_or_else(|| request.api.api_origin(&request.host));
+        let session = ApiSession {
+            api: request.api,
+            executable,
+            host: &request.host,
+            token,
+        };
+
+        match request.operation {
+            HttpForgeOperation::GraphQl(graphql_request) => {
+                let url =
+                    parse_api_url(executable, &request.api.graphql_url(&origin, &request.host))?;
+
+                self.run_graphql(&session, url, graphql_request).await
+            }
+            HttpForgeOperation::Rest(rest_request) => {
+                let url = rest_url(
+                    &request.api.rest_base_url(&origin, &request.host),
+                    &rest_request.endpoint,
+                    &rest_request.query,
+                )
+                .map_err(|message| ForgeCommandError::SpawnFailed {
+                    executable: executable.to_string(),
+                    message,
+                })?;
+
+                self.run_rest(&session, url, rest_request).await
+            }
+        }
+    }
+
+    /// Runs one REST request, following `Link: rel="next"` pages when asked.
+    async fn run_rest(
+        &self,
+        session: &ApiSession<'_>,
+        mut url: Url,
+        request: RestRequest,
+    ) -> Result<ForgeCommandOutput, ForgeCommandError> {
+        let mut pages = Vec::new();
+        loop {
+            let response = self
+                .send(session, request.method.clone(), url, request.body.as_ref())
+                .await?;
+            if !response.status.is_success() {
+                return Ok(response.failure_output());
+            }
+            if !request.paginate {
+                return Ok(success_output(request.output.render(response.body)));
+            }
+
+            let next_page = response.next_page;
+            pages.push(response.body);
+            match next_page {
+                Some(next_page) if pages.len() < MAX_PAGES => url = next_page,
+                _ => break,
+            }
+        }
+
+        Ok(match merge_rest_pages(pages) {
+            Ok(stdout) => success_output(stdout),
+            Err(message) => failure_output(message),
+        })
+    }
+
+    /// Runs one GraphQL request, following the outermost `pageInfo` cursor
+    /// through the `$endCursor` variable when asked.
+    async fn run_graphql(
+        &self,
+        session: &ApiSession<'_>,
+        url: Url,
+        request: GraphQlRequest,
+    ) -> Result<ForgeCommandOutput, ForgeCommandError> {
+        let mut variables = request.variables;
+        let mut pages = Vec::new();
+        loop {
+            let payload = json!({"query": request.query, "variables": variables});
+            let response = self
+                .send(session, Method::POST, url.clone(), Some(&payload))
+                .await?;
+            if !response.status.is_success() {
+                return Ok(response.failure_output());
+            }
+
+            let page = match serde_json::from_str::<Value>(&response.body) {
+                Ok(page) => page,
+                Err(error) => {
+                    return Ok(failure_output(format!("invalid GraphQL response: {error}")));
+                }
+            };
+            if let Some(message) = graphql_error_message(&page) {
+                return Ok(failure_output(format!("GraphQL: {message}")));
+            }
+
+            let end_cursor = if request.paginate {
+                next_graphql_cursor(&page)
+            } else {
+                None
+            };
+            pages.push(page);
+            match end_cursor {
+                Some(end_cursor) if pages.len() < MAX_PAGES => {
+                    variables.insert("endCursor".to_string(), Value::String(end_cursor));
+                }
+                _ => break,
+            }
+        }
+
+        if request.paginate {
+            return Ok(success_output(Value::Array(pages).to_string()));
+        }
+
+        let page = pages.pop().unwrap_or_default();
+        let Some(result_path) = request.result_path else {
+            return Ok(success_output(page.to_string()));
+        };
+
+        Ok(
+            match result_path
+                .iter()
+                .try_fold(&page, |value, key| value.get(key))
+                .filter(|value| !value.is_null())
+            {
+                Some(result) => success_output(result.to_string()),
+                None => failure_output(format!(
+                    "GraphQL response is missing `{}`",
+                    result_path.join(".")
+                )),
+            },
+        )
+    }
+
+    /// Sends one authenticated request, waiting out short rate-limit resets.
+    async fn send(
+        &self,
+        session: &ApiSession<'_>,
+        method: Method,
+        url: Url,
+        body: Option<&Value>,
+    ) -> Result<HttpResponse, ForgeCommandError> {
+        let mut retries = 0;
+        loop {
+            let mut request = session.api.authorize(
+                self.client.request(method.clone(), url.clone()),
+                &session.token,
+            );
+            if let Some(body) = body {
+                request = request.json(body);
+            }
+
+            let response = request
+                .send()
+                .await
+                .map_err(|error| transport_error(session.executable, &error))?;
+            let status = response.status();
+            let rate_limit_reset = rate_limit_delay(status, response.headers(), SystemTime::now());
+            if let Some(delay) = rate_limit_reset
+                && delay <= MAX_RATE_LIMIT_WAIT
+                && retries < MAX_RATE_LIMIT_RETRIES
+            {
+                retries += 1;
+                time::sleep(delay).await;
+
+                continue;
+            }
+            if status == StatusCode::UNAUTHORIZED {
+                self.tokens.forget(session.host);
+            }
+
+            let next_page = next_page_url(response.headers())
+                .filter(|next_page| next_page.origin() == url.origin());
+            let body = response
+                .text()
+                .await
+                .map_err(|error| transport_error(session.executable, &error))?;
+
+            return Ok(HttpResponse {
+                body,
+                next_page,
+                rate_limit_reset,
+                status,
+            });
+        }
+    }
+}
+
+impl ForgeCommandRunner for HttpForgeCommandRunner {
+    fn run(
+        &self,
+        command: ForgeCommand,
+    ) -> ForgeFuture<Result<ForgeCommandOutput, ForgeCommandError>> {
+        let runner = self.clone();
+
+        Box::pin(async move { runner.run_command(command).await })
+    }
+}
+
+/// Forge API family addressed by one translated command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ForgeApi {
+    Forgejo,
+    GitHub,
+    GitLab,
+}
+
+impl ForgeApi {
+    /// Returns the API family served by one forge CLI executable.
+    fn from_executable(executable: &str) -> Option<Self> {
+        match executable {
+            "gh" => Some(Self::GitHub),
+            "glab" => Some(Self::GitLab),
+            "tea" => Some(Self::Forgejo),
+            _ => None,
+        }
+    }
+
+    /// Returns the scheme and authority that serve API requests for `host`.
+    fn api_origin(self, host: &str) -> String {
+        if self == Self::GitHub && host == "github.com" {
+            return "https://api.github.com".to_string();
+        }
+
+        format!("https://{host}")
+    }
+
+    /// Returns the REST base URL below `origin`.
+    ///
+    /// GitHub Enterprise Server mounts the REST API below `/api/v3`, while
+    /// `github.com` serves it from the root of `api.github.com`.
+    fn rest_base_url(self, origin: &str, host: &str) -> String {
+        match self {
+            Self::GitHub if host == "github.com" => origin.to_string(),
+            Self::GitHub => format!("{origin}/api/v3"),
+            Self::GitLab => format!("{origin}/api/v4"),
+            Self::Forgejo => format!("{origin}/api/v1"),
+        }
+    }
+
+    /// Returns the GraphQL endpoint below `origin`.
+    fn graphql_url(self, origin: &str, host: &str) -> String {
+        if self == Self::GitHub && host == "github.com" {
+            return format!("{origin}/graphql");
+        }
+
+        format!("{origin}/api/graphql")
+    }
+
+    /// Returns the environment variables that may hold a token for `host`,
+    /// in lookup order.
+    fn token_variables(self, host: &str) -> &'static [&'static str] {
+        match self {
+            Self::GitHub if host == "github.com" => &["GH_TOKEN", "GITHUB_TOKEN"],
+            Self::GitHub => &["GH_ENTERPRISE_TOKEN", "GITHUB_ENTERPRISE_TOKEN"],
+            Self::GitLab => &["GITLAB_TOKEN", "GITLAB_ACCESS_TOKEN"],
+            Self::Forgejo => &["FORGEJO_TOKEN", "GITEA_TOKEN"],
+        }
+    }
+
+    /// Adds the forge-specific authentication and media-type headers.
+    fn authorize(self, request: RequestBuilder, token: &str) -> RequestBuilder {
+        match self {
+            Self::GitHub => request
+                .bearer_auth(token)
+                .header(ACCEPT, "application/vnd.github+json"),
+            Self::GitLab => request.bearer_auth(token),
+            Self::Forgejo => request.header(AUTHORIZATION, format!("token {token}")),
+        }
+    }
+}
+
+/// Authenticated API context shared by the requests of one command.
+struct ApiSession<'a> {
+    api: ForgeApi,
+    executable: &'static str,
+    host: &'a str,
+    token: String,
+}
+
+/// One API request translated from a forge CLI invocation.
+#[derive(Debug, PartialEq)]
+struct HttpForgeRequest {
+    api: ForgeApi,
+    host: String,
+    operation: HttpForgeOperation,
+}
+
+/// Request shape of one translated forge command.
+#[derive(Debug, PartialEq)]
+enum HttpForgeOperation {
+    /// GitHub GraphQL request.
+    GraphQl(GraphQlRequest),
+    /// REST request below the forge API base URL.
+    Rest(RestRequest),
+}
+
+/// One GitHub GraphQL request.
+#[derive(Debug, PartialEq)]
+struct GraphQlRequest {
+    /// Whether `pageInfo` cursors are followed and pages emitted as an array.
+    paginate: bool,
+    /// GraphQL document sent as the `query` payload field.
+    query: String,
+    /// Object path selected from the response instead of the whole payload.
+    result_path: Option<&'static [&'static str]>,
+    /// GraphQL variables sent with the query.
+    variables: Map<String, Value>,
+}
+
+/// One REST request below the forge API base URL.
+#[derive(Debug, PartialEq)]
+struct RestRequest {
+    /// JSON request body for non-`GET` requests.
+    body: Option<Value>,
+    /// Endpoint path, optionally with a query string.
+    endpoint: String,
+    /// HTTP method.
+    method: Method,
+    /// How the response body is rendered to stdout.
+    output: RestOutput,
+    /// Whether `Link: rel="next"` pages are followed and merged.
+    paginate: bool,
+    /// Extra query parameters appended to the endpoint.
+    query: Vec<(String, String)>,
+}
+
+/// Stdout rendering for one successful REST response.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum RestOutput {
+    /// Prints the raw response body, like `gh api`.
+    Body,
+    /// Prints one string field, like the URL line from `gh pr create`.
+    Field(&'static str),
+}
+
+impl RestOutput {
+    /// Renders one successful response body.
+    fn render(self, body: String) -> String {
+        let Self::Field(field) = self else {
+            return body;
+        };
+
+        serde_json::from_str::<Value>(&body)
+            .ok()
+            .and_then(|value| value.get(field).and_then(Value::as_str).map(str::to_string))
+            .map_or(body, |value| format!("{value}\n"))
+    }
+}
+
+/// Captured response of one API request.
+struct HttpResponse {
+    body: String,
+    next_page: Option<Url>,
+    rate_limit_reset: Option<Duration>,
+    status: StatusCode,
+}
+
+impl HttpResponse {
+    /// Renders one non-success response as failed CLI output.
+    ///
+    /// The `HTTP <status>` prefix keeps authentication detection shared with
+    /// the CLI backend, whose tools report API failures the same way.
+    fn failure_output(&self) -> ForgeCommandOutput {
+        let message = api_error_message(&self.body)
+            .or_else(|| self.status.canonical_reason().map(str::to_string))
+            .unwrap_or_default();
+        let detail = format!("HTTP {}: {message}", self.status.as_u16());
+        let Some(reset) = self.rate_limit_reset else {
+            return failure_output(detail);
+        };
+
+        failure_output(format!(
+            "{detail} (API rate limit exceeded; retry in {}s)",
+            reset.as_secs()
+        ))
+    }
+}
+
+/// Positional arguments, option values, and boolean flags of one CLI
+/// invocation.
+#[derive(Debug, Default)]
+struct CommandArguments {
+    flags: Vec<String>,
+    options: Vec<(String, String)>,
+    positionals: Vec<String>,
+}
+
+impl CommandArguments {
+    /// Splits `arguments` using the option names in [`VALUE_OPTIONS`].
+    fn parse(arguments: &[String]) -> Result<Self, String> {
+        let mut parsed = Self::default();
+        let mut arguments = arguments.iter();
+        while let Some(argument) = arguments.next() {
+            if VALUE_OPTIONS.contains(&argument.as_str()) {
+                let value = arguments
+                    .next()
+                    .ok_or_else(|| format!("missing value for `{argument}`"))?;
+                parsed.options.push((argument.clone(), value.clone()));
+            } else if argument.starts_with('-') {
+                parsed.flags.push(argument.clone());
+            } else {
+                parsed.positionals.push(argument.clone());
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Returns whether one boolean flag was passed.
+    fn has_flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+    }
+
+    /// Returns the last value passed for option `name`.
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(option, _)| option == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the value passed for option `name`, or an error when missing.
+    fn required_option(&self, name: &str) -> Result<&str, String> {
+        self.option(name)
+            .ok_or_else(|| format!("missing required option `{name}`"))
+    }
+
+    /// Returns one positional argument.
+    fn positional(&self, index: usize) -> Option<&str> {
+        self.positionals.get(index).map(String::as_str)
+    }
+
+    /// Returns `key=value` fields in order, typing values passed through
+    /// `typed_options` the way `gh api -F` does.
+    ///
+    /// Repeated `key[]=value` fields collect into one JSON array under `key`.
+    fn fields(
+        &self,
+        raw_options: &[&str],
+        typed_options: &[&str],
+    ) -> Result<Map<String, Value>, String> {
+        let mut fields = Map::new();
+        for (option, field) in &self.options {
+            let is_typed = typed_options.contains(&option.as_str());
+            if !is_typed && !raw_options.contains(&option.as_str()) {
+                continue;
+            }
+
+            let (key, value) = field
+                .split_once('=')
+                .ok_or_else(|| format!("invalid field `{field}`: expected `key=value`"))?;
+            let value = if is_typed {
+                typed_field_value(value)
+            } else {
+                Value::String(value.to_string())
+            };
+            if let Some(array_key) = key.strip_suffix("[]") {
+                let entry = fields
+                    .entry(array_key.to_string())
+                    .or_insert_with(|| Value::Array(Vec::new()));
+                match entry {
+                    Value::Array(values) => values.push(value),
+                    _ => return Err(format!("field `{array_key}` mixes array and scalar values")),
+                }
+
+                continue;
+            }
+            fields.insert(key.to_string(), value);
+        }
+
+        Ok(fields)
+    }
+
+    /// Returns a short `executable subcommand` label for error messages.
+    fn command_label(&self, executable: &str) -> String {
+        let subcommand = self
+            .positionals
+            .iter()
+            .take(2)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        format!("{executable} {subcommand}")
+    }
+}
+
+/// Translates one adapter-built forge CLI command into an API request.
+fn translate_command(command: &ForgeCommand) -> Result<HttpForgeRequest, String> {
+    let api = ForgeApi::from_executable(command.executable).ok_or_else(|| {
+        format!(
+            "`{}` commands are not supported by the HTTP forge backend",
+            command.executable
+        )
+    })?;
+    let host = command
+        .api_host
+        .clone()
+        .ok_or_else(|| format!("`{}` command is missing its forge host", command.executable))?;
+    let arguments = CommandArguments::parse(&command.arguments)?;
+    let operation = match api {
+        ForgeApi::Forgejo => translate_forgejo_command(&arguments),
+        ForgeApi::GitHub => translate_github_command(&arguments),
+        ForgeApi::GitLab => translate_gitlab_command(&arguments),
+    }?;
+
+    Ok(HttpForgeRequest {
+        api,
+        host,
+        operation,
+    })
+}
+
+/// Translates one `gh` invocation.
+fn translate_github_command(arguments: &CommandArguments) -> Result<HttpForgeOperation, String> {
+    match (arguments.positional(0), arguments.positional(1)) {
+        (Some("auth"), Some("status")) => Ok(authenticated_user_request()),
+        (Some("api"), Some("graphql")) => {
+            let mut variables = arguments.fields(&["-f"], &["-F"])?;
+            let Some(Value::String(query)) = variables.remove("query") else {
+                return Err("missing GraphQL `query` field".to_string());
+            };
+
+            Ok(HttpForgeOperation::GraphQl(GraphQlRequest {
+                paginate: arguments.has_flag("--paginate"),
+                query,
+                result_path: None,
+                variables,
+            }))
+        }
+        (Some("api"), Some(endpoint)) => Ok(HttpForgeOperation::Rest(rest_request_with_fields(
+            arguments,
+            endpoint,
+            arguments.fields(&["-f"], &["-F"])?,
+        )?)),
+        (Some("pr"), Some("create")) => {
+            let repository = arguments.required_option("--repo")?;
+
+            Ok(HttpForgeOperation::Rest(RestRequest {
+                body: Some(json!({
+                    "base": arguments.required_option("--base")?,
+                    "body": arguments.option("--body").unwrap_or_default(),
+                    "draft": arguments.has_flag("--draft"),
+                    "head": arguments.required_option("--head")?,
+                    "title": arguments.required_option("--title")?,
+                })),
+                endpoint: format!("repos/{repository}/pulls"),
+                method: Method::POST,
+                output: RestOutput::Field("html_url"),
+                paginate: false,
+                query: Vec::new(),
+            }))
+        }
+        (Some("pr"), Some("edit")) => {
+            let number = review_request_number(arguments)?;
+            let repository = arguments.required_option("--repo")?;
+
+            Ok(HttpForgeOperation::Rest(RestRequest {
+                body: Some(optional_fields_body(&[
+                    ("body", arguments.option("--body")),
+                    ("title", arguments.option("--title")),
+                ])),
+                endpoint: format!("repos/{repository}/pulls/{number}"),
+                method: Method::PATCH,
+                output: RestOutput::Body,
+                paginate: false,
+                query: Vec::new(),
+            }))
+        }
+        (Some("pr"), Some("view")) => github_pull_request_view_request(arguments),
+        _ => Err(unsupported_command_message(arguments, "gh")),
+    }
+}
+
+/// Translates `gh pr view --json` into one GraphQL pull-request query.
+///
+/// `gh` JSON field names match the GraphQL `PullRequest` fields, so the
+/// requested fields are selected directly.
+fn github_pull_request_view_request(
+    arguments: &CommandArguments,
+) -> Result<HttpForgeOperation, String> {
+    let number = review_request_number(arguments)?;
+    let (owner, repository) = arguments
+        .required_option("--repo")?
+        .split_once('/')
+        .ok_or_else(|| "expected `--repo` in `owner/name` form".to_string())?;
+    let fields = arguments
+        .required_option("--json")?
+        .split(',')
+        .map(str::trim)
+        .filter(|field| !field.is_empty())
+        .collect::<Vec<_>>();
+    if fields.is_empty()
+        || fields.iter().any(|field| {
+            !field
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric())
+        })
+    {
+        return Err("invalid `--json` field list".to_string());
+    }
+
+    let query = format!(
+        "query($owner: String!, $repo: String!, $number: Int!) {{ repository(owner: $owner, name: \
+         $repo) {{ pullRequest(number: $number) {{ {} }} }} }}",
+        fields.join(" ")
+    );
+    let mut variables = Map::new();
+    variables.insert("number".to_string(), Value::from(number));
+    variables.insert("owner".to_string(), Value::from(owner));
+    variables.insert("repo".to_string(), Value::from(repository));
+
+    Ok(HttpForgeOperation::GraphQl(GraphQlRequest {
+        paginate: false,
+        query,
+        result_path: Some(PULL_REQUEST_RESULT_PATH),
+        variables,
+    }))
+}
+
+/// Translates one `glab` invocation.
+fn translate_gitlab_command(arguments: &CommandArguments) -> Result<HttpForgeOperation, String> {
+    match (arguments.positional(0), arguments.positional(1)) {
+        (Some("auth"), Some("status")) => Ok(authenticated_user_request()),
+        (Some("api"), Some(endpoint)) => Ok(HttpForgeOperation::Rest(rest_request_with_fields(
+            arguments,
+            endpoint,
+            arguments.fields(&["--raw-field", "-f"], &["--field", "-F"])?,
+        )?)),
+        (Some("mr"), Some("create")) => {
+            let project = gitlab_project_id(arguments.required_option("--repo")?);
+            let title = arguments.required_option("--title")?;
+            let title = if arguments.has_flag("--draft") {
+                format!("Draft: {title}")
+            } else {
+                title.to_string()
+            };
+
+            Ok(HttpForgeOperation::Rest(RestRequest {
+                body: Some(json!({
+                    "description": arguments.option("--description").unwrap_or_default(),
+                    "source_branch": arguments.required_option("--source-branch")?,
+                    "target_branch": arguments.required_option("--target-branch")?,
+                    "title": title,
+                })),
+                endpoint: format!("projects/{project}/merge_requests"),
+                method: Method::POST,
+                output: RestOutput::Field("web_url"),
+                paginate: false,
+                query: Vec::new(),
+            }))
+        }
+        (Some("mr"), Some("list")) => {
+            let project = gitlab_project_id(arguments.required_option("--repo")?);
+            let mut query = vec![("state".to_string(), "opened".to_string())];
+            for (option, parameter) in [
+                ("--source-branch", "source_branch"),
+                ("--order", "order_by"),
+                ("--sort", "sort"),
+                ("--per-page", "per_page"),
+            ] {
+                if let Some(value) = arguments.option(option) {
+                    query.push((parameter.to_string(), value.to_string()));
+                }
+            }
+
+            Ok(HttpForgeOperation::Rest(RestRequest {
+                body: None,
+                endpoint: format!("projects/{project}/merge_requests"),
+                method: Method::GET,
+                output: RestOutput::Body,
+                paginate: false,
+                query,
+            }))
+        }
+        (Some("mr"), Some("update")) => {
+            let number = review_request_number(arguments)?;
+            let project = gitlab_project_id(arguments.required_option("--repo")?);
+
+            Ok(HttpForgeOperation::Rest(RestRequest {
+                body: Some(optional_fields_body(&[
+                    ("description", arguments.option("--description")),
+                    ("title", arguments.option("--title")),
+                ])),
+                endpoint: format!("projects/{project}/merge_requests/{number}"),
+                method: Method::PUT,
+                output: RestOutput::Body,
+                paginate: false,
+                query: Vec::new(),
+            }))
+        }
+        (Some("mr"), Some("view")) => {
+            let number = review_request_number(arguments)?;
+            let project = gitlab_project_id(arguments.required_option("--repo")?);
+
+            Ok(HttpForgeOperation::Rest(RestRequest {
+                body: None,
+                endpoint: format!("projects/{project}/merge_requests/{number}"),
+                method: Method::GET,
+                output: RestOutput::Body,
+                paginate: false,
+                query: Vec::new(),
+            }))
+        }
+        _ => Err(unsupported_command_message(arguments, "glab")),
+    }
+}
+
+/// Translates one `tea` invocation.
+fn translate_forgejo_command(arguments: &CommandArguments) -> Result<HttpForgeOperation, String> {
+    let (Some("api"), Some(endpoint)) = (arguments.positional(0), arguments.positional(1)) else {
+        return Err(unsupported_command_message(arguments, "tea"));
+    };
+    let body = arguments
+        .option("--data")
+        .map(|data| {
+            serde_json::from_str::<Value>(data)
+                .map_err(|error| format!("invalid `--data` JSON: {error}"))
+        })
+        .transpose()?;
+
+    Ok(HttpForgeOperation::Rest(RestRequest {
+        body,
+        endpoint: endpoint.to_string(),
+        method: request_method(arguments, Method::GET)?,
+        output: RestOutput::Body,
+        paginate: false,
+        query: Vec::new(),
+    }))
+}
+
+/// Builds the `GET /user` request that replaces CLI auth-status checks.
+fn authenticated_user_request() -> HttpForgeOperation {
+    HttpForgeOperation::Rest(RestRequest {
+        body: None,
+        endpoint: "user".to_string(),
+        method: Method::GET,
+        output: RestOutput::Body,
+        paginate: false,
+        query: Vec::new(),
+    })
+}
+
+/// Builds one `api` REST request that sends `fields` as query parameters for
+/// `GET` and as a JSON body otherwise.
+fn rest_request_with_fields(
+    arguments: &CommandArguments,
+    endpoint: &str,
+    fields: Map<String, Value>,
+) -> Result<RestRequest, String> {
+    let default_method = if fields.is_empty() {
+        Method::GET
+    } else {
+        Method::POST
+    };
+    let method = request_method(arguments, default_method)?;
+    let (body, query) = if method == Method::GET {
+        let query = fields
+            .into_iter()
+            .flat_map(|(key, value)| match value {
+                Value::Array(values) => values
+                    .into_iter()
+                    .map(|value| (format!("{key}[]"), query_value(value)))
+                    .collect::<Vec<_>>(),
+                value => vec![(key, query_value(value))],
+            })
+            .collect();
+
+        (None, query)
+    } else {
+        (Some(Value::Object(fields)), Vec::new())
+    };
+
+    Ok(RestRequest {
+        body,
+        endpoint: endpoint.to_string(),
+        method,
+        output: RestOutput::Body,
+        paginate: arguments.has_flag("--paginate"),
+        query,
+    })
+}
+
+/// Renders one field value as a query-string parameter.
+fn query_value(value: Value) -> String {
+    match value {
+        Value::String(value) => value,
+        value => value.to_string(),
+    }
+}
+
+/// Returns the `--method` option, or `default_method` when it is missing.
+fn request_method(arguments: &CommandArguments, default_method: Method) -> Result<Method, String> {
+    arguments
+        .option("--method")
+        .map_or(Ok(default_method), |method| {
+            Method::from_bytes(method.to_ascii_uppercase().as_bytes())
+                .map_err(|_| format!("invalid HTTP method `{method}`"))
+        })
+}
+
+/// Returns the numeric review-request argument that follows the subcommand.
+fn review_request_number(arguments: &CommandArguments) -> Result<u64, String> {
+    let number = arguments
+        .positional(2)
+        .ok_or_else(|| "missing review-request number".to_string())?;
+
+    number
+        .parse()
+        .map_err(|_| format!("invalid review-request number `{number}`"))
+}
+
+/// Builds one JSON object from the fields that have values.
+fn optional_fields_body(fields: &[(&str, Option<&str>)]) -> Value {
+    Value::Object(
+        fields
+            .iter()
+            .filter_map(|(key, value)| value.map(|value| ((*key).to_string(), Value::from(value))))
+            .collect(),
+    )
+}
+
+/// Returns the URL-encoded GitLab project path for one `--repo` value.
+fn gitlab_project_id(repository: &str) -> String {
+    let project_path = Url::parse(repository).map_or_else(
+        |_| repository.trim_matches('/').to_string(),
+        |url| url.path().trim_matches('/').to_string(),
+    );
+    let project_path = project_path.trim_end_matches(".git");
+
+    form_urlencoded::byte_serialize(project_path.as_bytes()).collect()
+}
+
+/// Converts one `-F`/`--field` value into JSON the way `gh api` does.
+fn typed_field_value(value: &str) -> Value {
+    match value {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        "null" => Value::Null,
+        _ => value
+            .parse::<i64>()
+            .map_or_else(|_| Value::String(value.to_string()), Value::from),
+    }
+}
+
+/// Returns the error for one CLI invocation the backend cannot translate.
+fn unsupported_command_message(arguments: &CommandArguments, executable: &str) -> String {
+    format!(
+        "`{}` is not supported by the HTTP forge backend",
+        arguments.command_label(executable)
+    )
+}
+
+/// Joins `endpoint` onto `base_url` and appends `query` parameters.
+fn rest_url(base_url: &str, endpoint: &str, query: &[(String, String)]) -> Result<Url, String> {
+    let mut url = Url::parse(&format!(
+        "{}/{}",
+        base_url.trim_end_matches('/'),
+        endpoint.trim_start_matches('/')
+    ))
+    .map_err(|error| format!("invalid forge API URL: {error}"))?;
+    if !query.is_empty() {
+        url.query_pairs_mut().extend_pairs(query);
+    }
+
+    Ok(url)
+}
+
+/// Parses one absolute API URL.
+fn parse_api_url(executable: &str, url: &str) -> Result<Url, ForgeCommandError> {
+    Url::parse(url).map_err(|error| ForgeCommandError::SpawnFailed {
+        executable: executable.to_string(),
+        message: format!("invalid forge API URL: {error}"),
+    })
+}
+
+/// Combines the JSON array pages of one paginated REST response.
+fn merge_rest_pages(pages: Vec<String>) -> Result<String, String> {
+    let mut items = Vec::new();
+    for page in pages {
+        match serde_json::from_str::<Value>(&page) {
+            Ok(Value::Array(page_items)) => items.extend(page_items),
+            Ok(_) => return Err("paginated forge API response is not a JSON array".to_string()),
+            Err(error) => return Err(format!("invalid paginated forge API response: {error}")),
+        }
+    }
+
+    Ok(Value::Array(items).to_string())
+}
+
+/// Returns the `rel="next"` URL from one `Link` response header.
+fn next_page_url(headers: &HeaderMap) -> Option<Url> {
+    let link = headers.get(LINK)?.to_str().ok()?;
+
+    link.split(',').find_map(|entry| {
+        let (target, parameters) = entry.split_once(';')?;
+        let is_next = parameters
+            .split(';')
+            .any(|parameter| parameter.trim() == "rel=\"next\"");
+        if !is_next {
+            return None;
+        }
+
+        Url::parse(target.trim().trim_start_matches('<').trim_end_matches('>')).ok()
+    })
+}
+
+/// Returns the cursor of the shallowest `pageInfo` that has a next page.
+///
+/// The shallowest `pageInfo` belongs to the collection paginated through
+/// `$endCursor`; nested connections such as per-thread comments are fetched
+/// separately by the adapters.
+fn next_graphql_cursor(page: &Value) -> Option<String> {
+    let mut pending = VecDeque::from([page]);
+    while let Some(value) = pending.pop_front() {
+        match value {
+            Value::Object(object) => {
+                if let Some(page_info) = object.get("pageInfo") {
+                    let has_next_page = page_info
+                        .get("hasNextPage")
+                        .and_then(Value::as_bool)
+                        .unwrap_or(false);
+
+                    return page_info
+                        .get("endCursor")
+                        .and_then(Value::as_str)
+                        .filter(|_| has_next_page)
+                        .map(str::to_string);
+                }
+
+                pending.extend(object.values());
+            }
+            Value::Array(items) => pending.extend(items),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Returns the joined messages of one GraphQL `errors` array.
+fn graphql_error_message(page: &Value) -> Option<String> {
+    let errors = page.get("errors")?.as_array()?;
+    if errors.is_empty() {
+        return None;
+    }
+
+    Some(
+        errors
+            .iter()
+            .map(|error| {
+                error
+                    .get("message")
+                    .and_then(Value::as_str)
+                    .map_or_else(|| error.to_string(), str::to_string)
+            })
+            .collect::<Vec<_>>()
+            .join("; "),
+    )
+}
+
+/// Returns the `message` or `error` text of one JSON API error body.
+fn api_error_message(body: &str) -> Option<String> {
+    let value = serde_json::from_str::<Value>(body).ok()?;
+    let message = value.get("message").or_else(|| value.get("error"))?;
+
+    Some(
+        message
+            .as_str()
+            .map_or_else(|| message.to_string(), str::to_string),
+    )
+}
+
+/// Returns how long to wait before retrying one rate-limited response.
+///
+/// `Retry-After` wins over the GitHub (`X-RateLimit-*`) and GitLab
+/// (`RateLimit-*`) reset headers, which carry Unix timestamps.
+fn rate_limit_delay(status: StatusCode, headers: &HeaderMap, now: SystemTime) -> Option<Duration> {
+    if status != StatusCode::FORBIDDEN && status != StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+
+    let header_number = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+    };
+    if let Some(retry_after) = header_number(RETRY_AFTER.as_str()) {
+        return Some(Duration::from_secs(retry_after));
+    }
+
+    let now = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    [
+        ("x-ratelimit-remaining", "x-ratelimit-reset"),
+        ("ratelimit-remaining", "ratelimit-reset"),
+    ]
+    .into_iter()
+    .find(|(remaining, _)| header_number(remaining) == Some(0))
+    .and_then(|(_, reset)| header_number(reset))
+    .map(|reset| Duration::from_secs(reset.saturating_sub(now)))
+}
+
+/// Maps one `reqwest` transport failure into the shared command error.
+fn transport_error(executable: &str, error: &reqwest::Error) -> ForgeCommandError {
+    if error.is_timeout() {
+        return ForgeCommandError::TimedOut {
+            executable: executable.to_string(),
+            timeout: FORGE_COMMAND_TIMEOUT,
+        };
+    }
+
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+
+    ForgeCommandError::SpawnFailed {
+        executable: executable.to_string(),
+        message,
+    }
+}
+
+/// Returns successful CLI-shaped output.
+fn success_output(stdout: String) -> ForgeCommandOutput {
+    ForgeCommandOutput {
+        exit_code: Some(0),
+        stderr: String::new(),
+        stdout,
+    }
+}
+
+/// Returns failed CLI-shaped output with `stderr` detail.
+fn failure_output(stderr: String) -> ForgeCommandOutput {
+    ForgeCommandOutput {
+        exit_code: Some(1),
+        stderr,
+        stdout: String::new(),
+    }
+}
+
+/// Returns the failed output reported when no token is available for `host`.
+fn missing_token_output(api: ForgeApi, host: &str) -> ForgeCommandOutput {
+    failure_output(format!(
+        "authentication required for `{host}`: set {} or store a token for `https://{host}` with \
+         `git credential approve`",
+        api.token_variables(host)
+            .iter()
+            .map(|variable| format!("`{variable}`"))
+            .collect::<Vec<_>>()
+            .join(" or ")
+    ))
+}
+
+/// Per-host API tokens resolved from the environment or a git credential
+/// helper.
+#[derive(Default)]
+struct ForgeTokenStore {
+    tokens: Mutex<HashMap<String, String>>,
+}
+
+impl ForgeTokenStore {
+    /// Returns the cached token for `host`, resolving and caching it first
+    /// when needed.
+    async fn token(&self, api: ForgeApi, host: &str) -> Option<String> {
+        if let Some(token) = self.cached(host) {
+            return Some(token);
+        }
+
+        let token = match token_from_environment(api, host, |name| env::var(name).ok()) {
+            Some(token) => token,
+            None => credential_helper_token(host).await?,
+        };
+        self.remember(host, token.clone());
+
+        Some(token)
+    }
+
+    /// Returns the cached token for `host`.
+    fn cached(&self, host: &str) -> Option<String> {
+        self.tokens
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(host)
+            .cloned()
+    }
+
+    /// Caches `token` for `host`.
+    fn remember(&self, host: &str, token: String) {
+        self.tokens
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(host.to_string(), token);
+    }
+
+    /// Drops the cached token for `host` after the forge rejected it.
+    fn forget(&self, host: &str) {
+        self.tokens
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(host);
+    }
+}
+
+/// Returns the first non-empty token variable for `api` and `host`.
+fn token_from_environment(
+    api: ForgeApi,
+    host: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Option<String> {
+    api.token_variables(host).iter().find_map(|variable| {
+        lookup(variable)
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty())
+    })
+}
+
+/// Reads the stored password for `https://<host>` through
+/// `git credential fill` without prompting.
+async fn credential_helper_token(host: &str) -> Option<String> {
+    let mut process = Command::new("git");
+    process
+        .args(["credential", "fill"])
+        .env("GCM_INTERACTIVE", "never")
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .kill_on_drop(true)
+        .stderr(Stdio::null())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped());
+    let mut child = process.spawn().ok()?;
+    let mut stdin = child.stdin.take()?;
+    stdin
+        .write_all(format!("protocol=https\nhost={host}\n\n").as_bytes())
+        .await
+        .ok()?;
+    drop(stdin);
+
+    let output = time::timeout(CREDENTIAL_HELPER_TIMEOUT, child.wait_with_output())
+        .await
+        .ok()?
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    parse_credential_password(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Returns the non-empty `password` attribute of `git credential fill`
+/// output.
+fn parse_credential_password(output: &str) -> Option<String> {
+    output
+        .lines()
+        .find_map(|line| line.strip_prefix("password="))
+        .filter(|password| !password.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+    use wiremock::matchers::{body_json, body_partial_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    fn command(executable: &'static str, host: &str, arguments: &[&str]) -> ForgeCommand {
+        ForgeCommand::new(
+            executable,
+            arguments
+                .iter()
+                .map(|argument| (*argument).to_string())
+                .collect(),
+        )
+        .with_api_host(host)
+    }
+
+    fn runner(server: &MockServer, host: &str) -> HttpForgeCommandRunner {
+        HttpForgeCommandRunner::new()
+            .expect("HTTP client should build")
+            .with_api_origin(&server.uri())
+            .with_token(host, "secret-token")
+    }
+
+    #[test]
+    fn translate_command_maps_pr_view_fields_to_graphql_selection() {
+        // Arrange
+        let command = command(
+            "gh",
+            "github.com",
+            &[
+                "pr",
+                "view",
+                "42",
+                "--repo",
+                "agentty-xyz/agentty",
+                "--json",
+                "number,title,mergedAt",
+            ],
+        );
+
+        // Act
+        let request = translate_command(&command).expect("pr view should translate");
+
+        // Assert
+        let mut variables = Map::new();
+        variables.insert("number".to_string(), json!(42));
+        variables.insert("owner".to_string(), json!("agentty-xyz"));
+        variables.insert("repo".to_string(), json!("agentty"));
+        assert_eq!(
+            request.operation,
+            HttpForgeOperation::GraphQl(GraphQlRequest {
+                paginate: false,
+                query: "query($owner: String!, $repo: String!, $number: Int!) { repository(owner: \
+                        $owner, name: $repo) { pullRequest(number: $number) { number title \
+                        mergedAt } } }"
+                    .to_string(),
+                result_path: Some(PULL_REQUEST_RESULT_PATH),
+                variables,
+            })
+        );
+    }
+
+    #[test]
+    fn translate_command_maps_mr_list_to_encoded_project_query() {
+        // Arrange
+        let command = command(
+            "glab",
+            "gitlab.com",
+            &[
+                "mr",
+                "list",
+                "--repo",
+                "https://gitlab.com/group/agentty",
+                "--source-branch",
+                "feature/forge",
+                "--order",
+                "created_at",
+                "--sort",
+                "desc",
+                "--per-page",
+                "1",
+                "--output",
+                "json",
+            ],
+        );
+
+        // Act
+        let request = translate_command(&command).expect("mr list should translate");
+
+        // Assert
+        assert_eq!(request.api, ForgeApi::GitLab);
+        assert_eq!(
+            request.operation,
+            HttpForgeOperation::Rest(RestRequest {
+                body: None,
+                endpoint: "projects/group%2Fagentty/merge_requests".to_string(),
+                method: Method::GET,
+                output: RestOutput::Body,
+                paginate: false,
+                query: vec![
+                    ("state".to_string(), "opened".to_string()),
+                    ("source_branch".to_string(), "feature/forge".to_string()),
+                    ("order_by".to_string(), "created_at".to_string()),
+                    ("sort".to_string(), "desc".to_string()),
+                    ("per_page".to_string(), "1".to_string()),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn translate_command_collects_array_fields_into_json_arrays() {
+        // Arrange
+        let command = command(
+            "gh",
+            "github.com",
+            &[
+                "api",
+                "--hostname",
+                "github.com",
+                "--method",
+                "POST",
+                "repos/agentty-xyz/agentty/pulls/42/requested_reviewers",
+                "-f",
+                "reviewers[]=octocat",
+                "-f",
+                "reviewers[]=hubot",
+                "-f",
+                "team_reviewers[]=core",
+            ],
+        );
+
+        // Act
+        let request = translate_command(&command).expect("api call should translate");
+
+        // Assert
+        assert_eq!(
+            request.operation,
+            HttpForgeOperation::Rest(RestRequest {
+                body: Some(json!({
+                    "reviewers": ["octocat", "hubot"],
+                    "team_reviewers": ["core"],
+                })),
+                endpoint: "repos/agentty-xyz/agentty/pulls/42/requested_reviewers".to_string(),
+                method: Method::POST,
+                output: RestOutput::Body,
+                paginate: false,
+                query: Vec::new(),
+            })
+        );
+    }
+
+    #[test]
+    fn translate_command_rejects_unsupported_subcommands() {
+        // Arrange
+        let command = command("gh", "github.com", &["repo", "clone", "agentty"]);
+
+        // Act
+        let error = translate_command(&command).expect_err("repo clone is not translated");
+
+        // Assert
+        assert_eq!(
+            error,
+            "`gh repo clone` is not supported by the HTTP forge backend"
+        );
+    }
+
+    #[tokio::test]
+    async fn run_creates_draft_pull_request_and_prints_web_url() {
+        // Arrange
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/agentty-xyz/agentty/pulls"))
+            .and(header("authorization", "Bearer secret-token"))
+            .and(body_json(json!({
+                "base": "main",
+                "body": "Body",
+                "draft": true,
+                "head": "feature/forge",
+                "title": "Add forge backend",
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "html_url": "https://github.com/agentty-xyz/agentty/pull/42",
+                "number": 42,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let command = command(
+            "gh",
+            "github.com",
+            &[
+                "pr",
+                "create",
+                "--draft",
+                "--repo",
+                "agentty-xyz/agentty",
+                "--head",
+                "feature/forge",
+                "--base",
+                "main",
+                "--title",
+                "Add forge backend",
+                "--body",
+                "Body",
+            ],
+        );
+
+        // Act
+        let output = runner(&server, "github.com")
+            .run(command)
+            .await
+            .expect("request should complete");
+
+        // Assert
+        assert!(output.success());
+        assert_eq!(
+            output.stdout,
+            "https://github.com/agentty-xyz/agentty/pull/42\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn run_follows_graphql_cursor_and_slurps_pages() {
+        // Arrange
+        let server = MockServer::start().await;
+        let first_page = json!({"data": {"node": {"comments": {
+            "nodes": [{"body": "first"}],
+            "pageInfo": {"hasNextPage": true, "endCursor": "cursor-1"},
+        }}}});
+        let second_page = json!({"data": {"node": {"comments": {
+            "nodes": [{"body": "second"}],
+            "pageInfo": {"hasNextPage": false, "endCursor": null},
+        }}}});
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_partial_json(
+                json!({"variables": {"endCursor": "cursor-1"}}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(second_page.clone()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_partial_json(
+                json!({"variables": {"threadId": "thread-1"}}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(first_page.clone()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let command = command(
+            "gh",
+            "github.com",
+            &[
+                "api",
+                "--hostname",
+                "github.com",
+                "graphql",
+                "--paginate",
+                "--slurp",
+                "-f",
+                "query=query($threadId: ID!, $endCursor: String) { node(id: $threadId) { id } }",
+                "-F",
+                "threadId=thread-1",
+            ],
+        );
+
+        // Act
+        let output = runner(&server, "github.com")
+            .run(command)
+            .await
+            .expect("request should complete");
+
+        // Assert
+        assert!(output.success());
+        let pages: Value = serde_json::from_str(&output.stdout).expect("stdout should be JSON");
+        assert_eq!(pages, json!([first_page, second_page]));
+    }
+
+    #[tokio::test]
+    async fn run_merges_paginated_gitlab_rest_pages() {
+        // Arrange
+        let server = MockServer::start().await;
+        let endpoint = "/api/v4/projects/group%2Fagentty/merge_requests/7/discussions";
+        Mock::given(method("GET"))
+            .and(path(endpoint))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([{"id": "b"}])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(endpoint))
+            .and(query_param("per_page", "100"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!([{"id": "a"}]))
+                    .insert_header(
+                        "link",
+                        format!(
+                            "<{}{endpoint}?per_page=100&page=2>; rel=\"next\"",
+                            server.uri()
+                        )
+                        .as_str(),
+                    ),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let command = command(
+            "glab",
+            "gitlab.com",
+            &[
+                "api",
+                "--hostname",
+                "gitlab.com",
+                "--paginate",
+                "/projects/group%2Fagentty/merge_requests/7/discussions?per_page=100",
+            ],
+        );
+
+        // Act
+        let output = runner(&server, "gitlab.com")
+            .run(command)
+            .await
+            .expect("request should complete");
+
+        // Assert
+        assert!(output.success());
+        let discussions: Value =
+            serde_json::from_str(&output.stdout).expect("stdout should be JSON");
+        assert_eq!(discussions, json!([{"id": "a"}, {"id": "b"}]));
+    }
+
+    #[tokio::test]
+    async fn run_sends_forgejo_data_body_with_token_header() {
+        // Arrange
+        let server = MockServer::start().await;
+        Mock::given(method("PATCH"))
+            .and(path("/api/v1/repos/team/agentty/pulls/3"))
+            .and(header("authorization", "token secret-token"))
+            .and(body_json(json!({"title": "Updated"})))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({"number": 3})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let command = command(
+            "tea",
+            "codeberg.org",
+            &[
+                "api",
+                "--method",
+                "PATCH",
+                "--data",
+                r#"{"title":"Updated"}"#,
+                "repos/team/agentty/pulls/3",
+            ],
+        );
+
+        // Act
+        let output = runner(&server, "codeberg.org")
+            .run(command)
+            .await
+            .expect("request should complete");
+
+        // Assert
+        assert!(output.success());
+        assert_eq!(output.stdout, r#"{"number":3}"#);
+    }
+
+    #[tokio::test]
+    async fn run_reports_unauthorized_response_as_http_401_failure() {
+        // Arrange
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(
+                ResponseTemplate::new(401).set_body_json(json!({"message": "Bad credentials"})),
+            )
+            .mount(&server)
+            .await;
+        let runner = runner(&server, "github.com");
+        let command = command(
+            "gh",
+            "github.com",
+            &["auth", "status", "--hostname", "github.com"],
+        );
+
+        // Act
+        let output = runner.run(command).await.expect("request should complete");
+
+        // Assert
+        assert!(!output.success());
+        assert_eq!(output.stderr, "HTTP 401: Bad credentials");
+        assert_eq!(runner.tokens.cached("github.com"), None);
+    }
+
+    #[tokio::test]
+    async fn run_retries_after_short_rate_limit_reset() {
+        // Arrange
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"login": "agentty"})))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        let command = command(
+            "gh",
+            "github.com",
+            &["auth", "status", "--hostname", "github.com"],
+        );
+
+        // Act
+        let output = runner(&server, "github.com")
+            .run(command)
+            .await
+            .expect("request should complete");
+
+        // Assert
+        assert!(output.success());
+        assert_eq!(output.stdout, r#"{"login":"agentty"}"#);
+    }
+
+    #[test]
+    fn rate_limit_delay_reads_github_reset_timestamp() {
+        // Arrange
+        let now = UNIX_EPOCH + Duration::from_secs(1_000);
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("1090"));
+
+        // Act
+        let forbidden_delay = rate_limit_delay(StatusCode::FORBIDDEN, &headers, now);
+        let not_found_delay = rate_limit_delay(StatusCode::NOT_FOUND, &headers, now);
+
+        // Assert
+        assert_eq!(forbidden_delay, Some(Duration::from_secs(90)));
+        assert_eq!(not_found_delay, None);
+    }
+
+    #[test]
+    fn token_from_environment_uses_enterprise_variables_for_other_github_hosts() {
+        // Arrange
+        let lookup = |name: &str| match name {
+            "GH_TOKEN" => Some("public-token".to_string()),
+            "GITHUB_ENTERPRISE_TOKEN" => Some("enterprise-token".to_string()),
+            _ => None,
+        };
+
+        // Act
+        let public_token = token_from_environment(ForgeApi::GitHub, "github.com", lookup);
+        let enterprise_token =
+            token_from_environment(ForgeApi::GitHub, "github.example.com", lookup);
+
+        // Assert
+        assert_eq!(public_token.as_deref(), Some("public-token"));
+        assert_eq!(enterprise_token.as_deref(), Some("enterprise-token"));
+    }
+
+    #[test]
+    fn parse_credential_password_reads_password_attribute() {
+        // Arrange
+        let output = "protocol=https\nhost=gitlab.com\nusername=oauth2\npassword=glpat-123\n";
+
+        // Act
+        let password = parse_credential_password(output);
+
+        // Assert
+        assert_eq!(password.as_deref(), Some("glpat-123"));
+    }
+}
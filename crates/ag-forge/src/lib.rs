@@ -3,30 +3,39 @@
 mod adapter_common;
 mod client;
 mod command;
+mod forgejo;
 mod github;
 mod gitlab;
+mod http;
 mod model;
 mod remote;
 
 pub(crate) use adapter_common::{
     ReviewRequestMetadataEdit, ReviewRequestOperations, SyncReviewRequestMetadataConfig,
-    map_parse_error, normalize_provider_label, operation_failed, status_summary_parts,
+    find_milestone_id, map_parse_error, normalize_provider_label, operation_failed,
+    publish_metadata_failed, status_summary_parts, strip_draft_title_prefix,
 };
 #[cfg(any(test, feature = "test-utils"))]
 pub use client::MockReviewRequestClient;
 pub(crate) use client::ReviewRequestAdapter;
-pub use client::{RealReviewRequestClient, ReviewRequestClient};
+pub use client::{
+    ForgeBackendSelection, RealReviewRequestClient, ReviewRequestClient, ReviewRequestClientError,
+};
 pub(crate) use command::{
-    ForgeCommand, ForgeCommandError, ForgeCommandOutput, ForgeCommandRunner,
+    FORGE_COMMAND_TIMEOUT, ForgeCommand, ForgeCommandError, ForgeCommandOutput, ForgeCommandRunner,
     RealForgeCommandRunner, command_output_detail,
 };
+pub(crate) use forgejo::ForgejoReviewRequestAdapter;
 pub(crate) use github::GitHubReviewRequestAdapter;
 pub(crate) use gitlab::GitLabReviewRequestAdapter;
+pub(crate) use http::HttpForgeCommandRunner;
 pub use model::{
-    CreateReviewRequestInput, ForgeFuture, ForgeKind, ForgeRemote, ReviewComment,
-    ReviewCommentAnchorSide, ReviewCommentSnapshot, ReviewCommentThread, ReviewRequestError,
-    ReviewRequestMetadata, ReviewRequestMetadataFieldUpdate, ReviewRequestState,
-    ReviewRequestSummary, UpdateReviewRequestInput, is_gitlab_host,
+    CreateReviewRequestInput, ForgeBackend, ForgeFuture, ForgeKind, ForgeRemote,
+    ReviewCheckConclusion, ReviewCheckOutcome, ReviewCheckReport, ReviewCheckRun,
+    ReviewCheckStatus, ReviewComment, ReviewCommentAnchorSide, ReviewCommentSnapshot,
+    ReviewCommentThread, ReviewRequestCreateOptions, ReviewRequestError, ReviewRequestMetadata,
+    ReviewRequestMetadataFieldUpdate, ReviewRequestPublishChoices, ReviewRequestState,
+    ReviewRequestSummary, UpdateReviewRequestInput, is_forgejo_host, is_gitlab_host,
 };
-pub use remote::detect_remote;
+pub use remote::{ForgeHostOverrides, detect_remote, detect_remote_with_overrides};
 pub(crate) use remote::{parse_remote_url, strip_port};
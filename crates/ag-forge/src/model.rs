@@ -16,6 +16,8 @@ pub enum ForgeKind {
     GitHub,
     /// GitLab-hosted merge requests.
     GitLab,
+    /// Forgejo- or Gitea-hosted pull requests.
+    Forgejo,
 }
 
 impl ForgeKind {
@@ -24,6 +26,7 @@ impl ForgeKind {
         match self {
             Self::GitHub => "GitHub",
             Self::GitLab => "GitLab",
+            Self::Forgejo => "Forgejo",
         }
     }
 
@@ -32,6 +35,7 @@ impl ForgeKind {
         match self {
             Self::GitHub => "gh",
             Self::GitLab => "glab",
+            Self::Forgejo => "tea",
         }
     }
 
@@ -40,6 +44,7 @@ impl ForgeKind {
         match self {
             Self::GitHub => "gh auth login",
             Self::GitLab => "glab auth login",
+            Self::Forgejo => "tea login add",
         }
     }
 
@@ -48,13 +53,14 @@ impl ForgeKind {
         match self {
             Self::GitHub => "GitHub",
             Self::GitLab => "GitLab",
+            Self::Forgejo => "Forgejo",
         }
     }
 
     /// Returns the forge-native review-request noun shown in user-facing copy.
     pub fn review_request_name(self) -> &'static str {
         match self {
-            Self::GitHub => "pull request",
+            Self::GitHub | Self::Forgejo => "pull request",
             Self::GitLab => "merge request",
         }
     }
@@ -67,10 +73,28 @@ impl ForgeKind {
     /// Returns the short UI indicator label for one review request.
     pub fn review_request_short_name(self) -> &'static str {
         match self {
-            Self::GitHub => "PR",
+            Self::GitHub | Self::Forgejo => "PR",
             Self::GitLab => "MR",
         }
     }
+
+    /// Returns the forge-native display id for review request `number`, such
+    /// as `#42` or `!42`.
+    pub fn review_request_display_id(self, number: u64) -> String {
+        match self {
+            Self::GitHub | Self::Forgejo => format!("#{number}"),
+            Self::GitLab => format!("!{number}"),
+        }
+    }
+
+    /// Returns the forge-maintained git ref that tracks the head commit of
+    /// review request `number`, including requests opened from forks.
+    pub fn review_request_head_ref(self, number: u64) -> String {
+        match self {
+            Self::GitHub | Self::Forgejo => format!("refs/pull/{number}/head"),
+            Self::GitLab => format!("refs/merge-requests/{number}/head"),
+        }
+    }
 }
 
 impl fmt::Display for ForgeKind {
@@ -86,6 +110,7 @@ impl FromStr for ForgeKind {
         match value {
             "GitHub" => Ok(Self::GitHub),
             "GitLab" => Ok(Self::GitLab),
+            "Forgejo" => Ok(Self::Forgejo),
             _ => Err(format!("Unknown review-request forge: {value}")),
         }
     }
@@ -99,6 +124,68 @@ pub fn is_gitlab_host(host: &str) -> bool {
         || host.contains(".gitlab.")
 }
 
+/// Returns whether `host` looks like one Forgejo or Gitea instance hostname.
+///
+/// Self-hosted instances with arbitrary hostnames are recognized through
+/// [`crate::ForgeHostOverrides`] instead.
+pub fn is_forgejo_host(host: &str) -> bool {
+    host == "codeberg.org"
+        || host == "gitea.com"
+        || host.starts_with("forgejo.")
+        || host.starts_with("gitea.")
+        || host.contains(".forgejo.")
+        || host.contains(".gitea.")
+}
+
+/// Transport used to reach forge review-request APIs for one project.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ForgeBackend {
+    /// Shell out to the forge CLI (`gh`, `glab`, or `tea`).
+    #[default]
+    Cli,
+    /// Call forge REST and GraphQL APIs directly with token authentication.
+    Http,
+}
+
+impl ForgeBackend {
+    /// Every selectable backend in settings order.
+    pub const ALL: [Self; 2] = [Self::Cli, Self::Http];
+
+    /// Returns the persisted string representation for this backend.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cli => "Cli",
+            Self::Http => "Http",
+        }
+    }
+
+    /// Returns the user-facing backend label.
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::Cli => "CLI",
+            Self::Http => "HTTP API",
+        }
+    }
+}
+
+impl fmt::Display for ForgeBackend {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ForgeBackend {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "Cli" => Ok(Self::Cli),
+            "Http" => Ok(Self::Http),
+            _ => Err(format!("Unknown forge backend: {value}")),
+        }
+    }
+}
+
 /// Normalized remote lifecycle state for one linked review request.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ReviewRequestState {
@@ -167,6 +254,20 @@ pub struct ReviewRequestSummary {
     pub web_url: String,
 }
 
+impl ReviewRequestSummary {
+    /// Returns whether the forge reports this review request as a draft.
+    ///
+    /// Every adapter leads the status summary with `Draft` for draft
+    /// requests, so the flag is derived instead of stored separately.
+    pub fn is_draft(&self) -> bool {
+        self.state == ReviewRequestState::Open
+            && self
+                .status_summary
+                .as_deref()
+                .is_some_and(|summary| summary.split(", ").any(|part| part == "Draft"))
+    }
+}
+
 /// Boxed async result used by review-request trait methods.
 pub type ForgeFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
 
@@ -208,6 +309,44 @@ impl ForgeRemote {
         format!("{}/{}", self.namespace, self.project)
     }
 
+    /// Parses one user-supplied review-request reference for this repository
+    /// and returns its number.
+    ///
+    /// Accepts a bare number, a display id such as `#42` or `!42`, or a
+    /// browser URL below [`Self::web_url`] such as `.../pull/42`,
+    /// `.../pulls/42`, or `.../-/merge_requests/42`. Returns `None` for
+    /// malformed input and for URLs that point at another repository.
+    pub fn review_request_number(&self, reference: &str) -> Option<u64> {
+        let reference = reference.trim();
+        let Some(url_path) = strip_url_scheme(reference) else {
+            return parse_review_request_number(reference.trim_start_matches(['#', '!']));
+        };
+        let repository_path = strip_url_scheme(&self.web_url)?.trim_end_matches('/');
+        if !url_path
+            .to_ascii_lowercase()
+            .starts_with(&repository_path.to_ascii_lowercase())
+        {
+            return None;
+        }
+
+        let mut segments = url_path[repository_path.len()..]
+            .trim_start_matches('/')
+            .split('/')
+            .skip_while(|segment| *segment == "-");
+        let kind = segments.next()?;
+        let number = segments.next()?;
+        let expected_kind = match self.forge_kind {
+            ForgeKind::GitHub => "pull",
+            ForgeKind::GitLab => "merge_requests",
+            ForgeKind::Forgejo => "pulls",
+        };
+        if kind != expected_kind {
+            return None;
+        }
+
+        parse_review_request_number(number.split(['?', '#']).next().unwrap_or_default())
+    }
+
     /// Returns the browser-openable URL that starts one new pull request or
     /// review request for `source_branch` into `target_branch`.
     ///
@@ -227,10 +366,29 @@ impl ForgeRemote {
             ForgeKind::GitLab => {
                 gitlab_review_request_creation_url(self, source_branch, target_branch)
             }
+            ForgeKind::Forgejo => {
+                forgejo_review_request_creation_url(self, source_branch, target_branch)
+            }
         }
     }
 }
 
+/// Returns `url` without its `http://` or `https://` scheme, or `None` when
+/// `url` is not an HTTP URL.
+fn strip_url_scheme(url: &str) -> Option<&str> {
+    url.strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+}
+
+/// Parses one positive review-request number.
+fn parse_review_request_number(value: &str) -> Option<u64> {
+    if value.is_empty() || !value.chars().all(|character| character.is_ascii_digit()) {
+        return None;
+    }
+
+    value.parse().ok().filter(|number| *number > 0)
+}
+
 /// One inline review comment emitted by a reviewer on a forge review thread.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ReviewComment {
@@ -303,11 +461,170 @@ pub struct ReviewCommentSnapshot {
     pub threads: Vec<ReviewCommentThread>,
 }
 
+/// Lifecycle stage of one CI check run or pipeline job.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReviewCheckStatus {
+    /// The check is waiting for a runner or an upstream stage.
+    Queued,
+    /// The check is currently running.
+    InProgress,
+    /// The check finished and carries a conclusion.
+    Completed,
+}
+
+/// Final result of one completed CI check run or pipeline job.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReviewCheckConclusion {
+    /// The check succeeded.
+    Success,
+    /// The check failed, errored, or timed out.
+    Failure,
+    /// The check was canceled before it finished.
+    Cancelled,
+    /// The check was skipped.
+    Skipped,
+    /// The check finished without a pass or fail verdict.
+    Neutral,
+}
+
+/// Aggregate CI outcome shown as one badge for a review request.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReviewCheckOutcome {
+    /// At least one check is still queued or running and none failed.
+    Pending,
+    /// Every check finished without failing.
+    Passed,
+    /// At least one check failed or was canceled.
+    Failed,
+}
+
+/// One CI check run or pipeline job reported for a review request head
+/// commit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewCheckRun {
+    /// Final result, present once `status` is
+    /// [`ReviewCheckStatus::Completed`].
+    pub conclusion: Option<ReviewCheckConclusion>,
+    /// Trailing lines of the job log, loaded for failed runs whose forge
+    /// exposes logs.
+    pub log_excerpt: Option<String>,
+    /// Forge-native job identifier used to download the job log, when the
+    /// forge exposes logs for this check.
+    pub log_id: Option<String>,
+    /// Check or job name shown by the forge.
+    pub name: String,
+    /// Current lifecycle stage.
+    pub status: ReviewCheckStatus,
+    /// Browser-openable check details URL, when the forge provides one.
+    pub url: Option<String>,
+}
+
+impl ReviewCheckRun {
+    /// Returns whether this check finished with a failing conclusion.
+    pub fn is_failed(&self) -> bool {
+        matches!(
+            self.conclusion,
+            Some(ReviewCheckConclusion::Failure | ReviewCheckConclusion::Cancelled)
+        )
+    }
+
+    /// Returns whether this check has not finished yet.
+    pub fn is_pending(&self) -> bool {
+        self.status != ReviewCheckStatus::Completed
+    }
+}
+
+/// CI checks reported for the head commit of one review request.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReviewCheckReport {
+    /// Head commit the checks ran against.
+    pub head_sha: String,
+    /// Check runs or pipeline jobs in forge order.
+    pub runs: Vec<ReviewCheckRun>,
+}
+
+impl ReviewCheckReport {
+    /// Returns the aggregate outcome, or `None` when the head commit has no
+    /// checks.
+    ///
+    /// Failures win over pending checks so a broken build is visible before
+    /// slower jobs finish.
+    pub fn outcome(&self) -> Option<ReviewCheckOutcome> {
+        if self.runs.is_empty() {
+            return None;
+        }
+        if self.runs.iter().any(ReviewCheckRun::is_failed) {
+            return Some(ReviewCheckOutcome::Failed);
+        }
+        if self.runs.iter().any(ReviewCheckRun::is_pending) {
+            return Some(ReviewCheckOutcome::Pending);
+        }
+
+        Some(ReviewCheckOutcome::Passed)
+    }
+
+    /// Returns the failed check runs in forge order.
+    pub fn failed_runs(&self) -> impl Iterator<Item = &ReviewCheckRun> {
+        self.runs.iter().filter(|run| run.is_failed())
+    }
+}
+
+/// User-selected fields applied when publishing creates a new review
+/// request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewRequestCreateOptions {
+    /// Whether the review request opens as a draft.
+    pub draft: bool,
+    /// Label names added to the review request.
+    pub labels: Vec<String>,
+    /// Milestone title assigned to the review request, when any.
+    pub milestone: Option<String>,
+    /// Usernames requested as reviewers.
+    ///
+    /// GitHub team reviewers use the `org/team-slug` form.
+    pub reviewers: Vec<String>,
+}
+
+impl Default for ReviewRequestCreateOptions {
+    /// Opens review requests as drafts so session-published work does not
+    /// appear ready for merge before the user chooses to mark it ready.
+    fn default() -> Self {
+        Self {
+            draft: true,
+            labels: Vec::new(),
+            milestone: None,
+            reviewers: Vec::new(),
+        }
+    }
+}
+
+impl ReviewRequestCreateOptions {
+    /// Returns whether any label, milestone, or reviewer must be applied
+    /// after the review request is created.
+    pub fn has_metadata(&self) -> bool {
+        !self.labels.is_empty() || self.milestone.is_some() || !self.reviewers.is_empty()
+    }
+}
+
+/// Labels, milestones, and reviewers a forge offers for completion in the
+/// publish popup.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReviewRequestPublishChoices {
+    /// Repository label names.
+    pub labels: Vec<String>,
+    /// Open milestone titles.
+    pub milestones: Vec<String>,
+    /// Usernames that can be requested as reviewers.
+    pub reviewers: Vec<String>,
+}
+
 /// Input required to create a review request on one forge.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CreateReviewRequestInput {
     /// Optional body or description submitted with the review request.
     pub body: Option<String>,
+    /// Draft state, labels, milestone, and reviewers chosen for the request.
+    pub options: ReviewRequestCreateOptions,
     /// Source branch that should be reviewed.
     pub source_branch: String,
     /// Target branch that receives the review request.
@@ -404,8 +721,9 @@ impl ReviewRequestError {
                 forge_kind.display_name(),
             ),
             Self::UnsupportedRemote { repo_url } => format!(
-                "Review requests are only supported for GitHub and GitLab remotes.\nThis \
-                 repository remote is not supported: `{repo_url}`."
+                "Review requests are only supported for GitHub, GitLab, and Forgejo \
+                 remotes.\nThis repository remote is not supported: `{repo_url}`.\nAdd its host \
+                 to the project's Forgejo Hosts setting if it runs Forgejo or Gitea."
             ),
             Self::OperationFailed {
                 forge_kind,
@@ -470,6 +788,26 @@ fn gitlab_review_request_creation_url(
     Ok(url.into())
 }
 
+/// Builds one Forgejo compare URL that opens the new pull-request flow.
+fn forgejo_review_request_creation_url(
+    remote: &ForgeRemote,
+    source_branch: &str,
+    target_branch: &str,
+) -> Result<String, ReviewRequestError> {
+    let mut url = parsed_remote_web_url(remote)?;
+
+    {
+        let mut path_segments = url
+            .path_segments_mut()
+            .map_err(|()| invalid_web_url_error(remote))?;
+        path_segments.pop_if_empty();
+        path_segments.push("compare");
+        path_segments.push(&format!("{target_branch}...{source_branch}"));
+    }
+
+    Ok(url.into())
+}
+
 /// Parses the stored repository web URL for one forge remote.
 fn parsed_remote_web_url(remote: &ForgeRemote) -> Result<Url, ReviewRequestError> {
     Url::parse(&remote.web_url).map_err(|_| invalid_web_url_error(remote))
@@ -540,6 +878,78 @@ mod tests {
         }
     }
 
+    fn forge_remote(forge_kind: ForgeKind, web_url: &str) -> ForgeRemote {
+        ForgeRemote {
+            command_working_directory: None,
+            forge_kind,
+            host: "example.com".to_string(),
+            namespace: "team".to_string(),
+            project: "app".to_string(),
+            repo_url: format!("{web_url}.git"),
+            web_url: web_url.to_string(),
+        }
+    }
+
+    #[test]
+    fn review_request_number_accepts_numbers_display_ids_and_repository_urls() {
+        // Arrange
+        let github = forge_remote(ForgeKind::GitHub, "https://github.com/team/app");
+        let gitlab = forge_remote(ForgeKind::GitLab, "https://gitlab.com/team/app");
+        let forgejo = forge_remote(ForgeKind::Forgejo, "https://codeberg.org/team/app");
+
+        // Act, Assert
+        assert_eq!(github.review_request_number(" 42 "), Some(42));
+        assert_eq!(github.review_request_number("#42"), Some(42));
+        assert_eq!(gitlab.review_request_number("!7"), Some(7));
+        assert_eq!(
+            github.review_request_number("https://github.com/team/app/pull/42/files"),
+            Some(42)
+        );
+        assert_eq!(
+            gitlab
+                .review_request_number("https://gitlab.com/team/app/-/merge_requests/7?tab=diffs"),
+            Some(7)
+        );
+        assert_eq!(
+            forgejo.review_request_number("https://codeberg.org/team/app/pulls/3"),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn review_request_number_rejects_foreign_repositories_and_malformed_input() {
+        // Arrange
+        let github = forge_remote(ForgeKind::GitHub, "https://github.com/team/app");
+
+        // Act, Assert
+        assert_eq!(
+            github.review_request_number("https://github.com/team/other/pull/42"),
+            None
+        );
+        assert_eq!(
+            github.review_request_number("https://github.com/team/app/issues/42"),
+            None
+        );
+        assert_eq!(github.review_request_number("#abc"), None);
+        assert_eq!(github.review_request_number("0"), None);
+        assert_eq!(github.review_request_number(""), None);
+    }
+
+    #[test]
+    fn review_request_head_ref_and_display_id_use_forge_native_formats() {
+        // Arrange, Act, Assert
+        assert_eq!(
+            ForgeKind::GitHub.review_request_head_ref(42),
+            "refs/pull/42/head"
+        );
+        assert_eq!(
+            ForgeKind::GitLab.review_request_head_ref(7),
+            "refs/merge-requests/7/head"
+        );
+        assert_eq!(ForgeKind::Forgejo.review_request_display_id(3), "#3");
+        assert_eq!(ForgeKind::GitLab.review_request_display_id(7), "!7");
+    }
+
     #[test]
     fn review_comment_thread_is_actionable_when_unresolved_even_if_outdated() {
         // Arrange
@@ -555,6 +965,70 @@ mod tests {
         assert!(outdated.is_actionable());
     }
 
+    #[test]
+    fn review_request_summary_is_draft_reads_open_draft_status() {
+        // Arrange
+        let summary = |state, status_summary: Option<&str>| ReviewRequestSummary {
+            display_id: "#1".to_string(),
+            forge_kind: ForgeKind::GitHub,
+            source_branch: "feature".to_string(),
+            state,
+            status_summary: status_summary.map(str::to_string),
+            target_branch: "main".to_string(),
+            title: "Feature".to_string(),
+            web_url: String::new(),
+        };
+
+        // Act, Assert
+        assert!(summary(ReviewRequestState::Open, Some("Draft, Mergeable")).is_draft());
+        assert!(!summary(ReviewRequestState::Open, Some("Approved")).is_draft());
+        assert!(!summary(ReviewRequestState::Open, None).is_draft());
+        assert!(!summary(ReviewRequestState::Closed, Some("Draft")).is_draft());
+    }
+
+    #[test]
+    fn review_check_report_outcome_prefers_failures_over_pending_checks() {
+        // Arrange
+        let check_run = |status, conclusion| ReviewCheckRun {
+            conclusion,
+            log_excerpt: None,
+            log_id: None,
+            name: "build".to_string(),
+            status,
+            url: None,
+        };
+        let passed = check_run(
+            ReviewCheckStatus::Completed,
+            Some(ReviewCheckConclusion::Success),
+        );
+        let skipped = check_run(
+            ReviewCheckStatus::Completed,
+            Some(ReviewCheckConclusion::Skipped),
+        );
+        let running = check_run(ReviewCheckStatus::InProgress, None);
+        let failed = check_run(
+            ReviewCheckStatus::Completed,
+            Some(ReviewCheckConclusion::Failure),
+        );
+        let report = |runs: Vec<ReviewCheckRun>| ReviewCheckReport {
+            head_sha: "abc123".to_string(),
+            runs,
+        };
+
+        // Act
+        let empty_outcome = report(Vec::new()).outcome();
+        let passed_outcome = report(vec![passed.clone(), skipped]).outcome();
+        let pending_outcome = report(vec![passed.clone(), running.clone()]).outcome();
+        let failed_outcome = report(vec![passed, running, failed.clone()]).outcome();
+
+        // Assert
+        assert_eq!(empty_outcome, None);
+        assert_eq!(passed_outcome, Some(ReviewCheckOutcome::Passed));
+        assert_eq!(pending_outcome, Some(ReviewCheckOutcome::Pending));
+        assert_eq!(failed_outcome, Some(ReviewCheckOutcome::Failed));
+        assert!(failed.is_failed());
+    }
+
     #[test]
     fn forge_kind_from_str_gitlab() {
         // Arrange
@@ -572,6 +1046,43 @@ mod tests {
         assert_eq!(forge_kind.review_request_short_name(), "MR");
     }
 
+    #[test]
+    fn forge_kind_from_str_forgejo() {
+        // Arrange
+        let raw_forge_kind = "Forgejo";
+
+        // Act
+        let forge_kind = raw_forge_kind
+            .parse::<ForgeKind>()
+            .expect("forgejo forge kind should parse");
+
+        // Assert
+        assert_eq!(forge_kind, ForgeKind::Forgejo);
+        assert_eq!(forge_kind.cli_name(), "tea");
+        assert_eq!(forge_kind.auth_login_command(), "tea login add");
+        assert_eq!(forge_kind.review_request_name(), "pull request");
+        assert_eq!(forge_kind.review_request_short_name(), "PR");
+    }
+
+    #[test]
+    fn is_forgejo_host_matches_well_known_instance_names() {
+        // Arrange
+        let forgejo_hosts = [
+            "codeberg.org",
+            "forgejo.example.org",
+            "git.gitea.example.com",
+        ];
+        let other_hosts = ["github.com", "gitlab.com", "git.example.com"];
+
+        // Act, Assert
+        for host in forgejo_hosts {
+            assert!(is_forgejo_host(host), "expected `{host}` to match");
+        }
+        for host in other_hosts {
+            assert!(!is_forgejo_host(host), "expected `{host}` not to match");
+        }
+    }
+
     #[test]
     fn authentication_required_message_includes_original_cli_error_detail() {
         // Arrange
@@ -686,4 +1197,29 @@ mod tests {
             "https://gitlab.com/agentty-xyz/agentty/-/merge_requests/new?merge_request%5Bsource_branch%5D=review%2Fcustom-branch&merge_request%5Btarget_branch%5D=main"
         );
     }
+
+    #[test]
+    fn review_request_creation_url_returns_forgejo_compare_link() {
+        // Arrange
+        let remote = ForgeRemote {
+            command_working_directory: None,
+            forge_kind: ForgeKind::Forgejo,
+            host: "codeberg.org".to_string(),
+            namespace: "agentty-xyz".to_string(),
+            project: "agentty".to_string(),
+            repo_url: "git@codeberg.org:agentty-xyz/agentty.git".to_string(),
+            web_url: "https://codeberg.org/agentty-xyz/agentty".to_string(),
+        };
+
+        // Act
+        let url = remote
+            .review_request_creation_url("review/custom-branch", "main")
+            .expect("forgejo compare URL should be created");
+
+        // Assert
+        assert_eq!(
+            url,
+            "https://codeberg.org/agentty-xyz/agentty/compare/main...review%2Fcustom-branch"
+        );
+    }
 }
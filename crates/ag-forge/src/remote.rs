@@ -1,10 +1,67 @@
 //! Forge remote detection helpers shared across provider adapters.
 
+use std::sync::{Arc, PoisonError, RwLock};
+
 use super::{
-    ForgeKind, ForgeRemote, GitHubReviewRequestAdapter, GitLabReviewRequestAdapter,
-    ReviewRequestError,
+    ForgeKind, ForgeRemote, ForgejoReviewRequestAdapter, GitHubReviewRequestAdapter,
+    GitLabReviewRequestAdapter, ReviewRequestError,
 };
 
+/// Shared user-configured forge hosts consulted before the built-in hostname
+/// heuristics.
+///
+/// Forgejo and Gitea instances run on arbitrary hostnames, so the app
+/// publishes the active project's configured hosts through one cloned handle
+/// that the production review-request client reads during detection.
+#[derive(Clone, Debug, Default)]
+pub struct ForgeHostOverrides {
+    forgejo_hosts: Arc<RwLock<Vec<String>>>,
+}
+
+impl ForgeHostOverrides {
+    /// Replaces the hosts that should be treated as Forgejo instances.
+    ///
+    /// Entries are trimmed, lowercased, and stripped of any URL scheme or
+    /// trailing slash so users can paste either a hostname or a web URL.
+    pub fn set_forgejo_hosts(&self, hosts: &[String]) {
+        let hosts = hosts
+            .iter()
+            .map(|host| normalize_host_entry(host))
+            .filter(|host| !host.is_empty())
+            .collect();
+
+        *self
+            .forgejo_hosts
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = hosts;
+    }
+
+    /// Returns whether `host` was configured as one Forgejo instance.
+    ///
+    /// Configured entries without a port match every port on that host.
+    pub fn is_forgejo_host(&self, host: &str) -> bool {
+        let forgejo_hosts = self
+            .forgejo_hosts
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        forgejo_hosts
+            .iter()
+            .any(|forgejo_host| forgejo_host == host || forgejo_host == strip_port(host))
+    }
+}
+
+/// Normalizes one user-entered host or URL into a lowercase `host[:port]`.
+fn normalize_host_entry(host: &str) -> String {
+    let host = host.trim();
+    let host = host
+        .split_once("://")
+        .map_or(host, |(_, scheme_rest)| scheme_rest);
+    let host = host.split('/').next().unwrap_or(host);
+
+    strip_userinfo(host).to_ascii_lowercase()
+}
+
 /// Parsed remote components extracted from one git remote URL.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct ParsedRemote {
@@ -44,6 +101,26 @@ impl ParsedRemote {
 /// Returns [`ReviewRequestError::UnsupportedRemote`] when the repository
 /// remote does not map to a supported forge.
 pub fn detect_remote(repo_url: &str) -> Result<ForgeRemote, ReviewRequestError> {
+    detect_remote_with_overrides(repo_url, &ForgeHostOverrides::default())
+}
+
+/// Detects one supported forge remote from `repo_url`, treating hosts listed
+/// in `host_overrides` as Forgejo instances before applying the built-in
+/// hostname heuristics.
+///
+/// # Errors
+/// Returns [`ReviewRequestError::UnsupportedRemote`] when the repository
+/// remote does not map to a supported forge.
+pub fn detect_remote_with_overrides(
+    repo_url: &str,
+    host_overrides: &ForgeHostOverrides,
+) -> Result<ForgeRemote, ReviewRequestError> {
+    if let Some(parsed_remote) = parse_remote_url(repo_url)
+        && host_overrides.is_forgejo_host(&parsed_remote.host)
+    {
+        return Ok(parsed_remote.into_forge_remote(ForgeKind::Forgejo));
+    }
+
     if let Some(remote) = GitHubReviewRequestAdapter::detect_remote(repo_url) {
         return Ok(remote);
     }
@@ -52,6 +129,10 @@ pub fn detect_remote(repo_url: &str) -> Result<ForgeRemote, ReviewRequestError>
         return Ok(remote);
     }
 
+    if let Some(remote) = ForgejoReviewRequestAdapter::detect_remote(repo_url) {
+        return Ok(remote);
+    }
+
     Err(ReviewRequestError::UnsupportedRemote {
         repo_url: display_safe_remote_url(repo_url),
     })
@@ -292,7 +373,11 @@ mod tests {
                 repo_url: repo_url.to_string(),
             }
         );
-        assert!(error.detail_message().contains("GitHub and GitLab remotes"));
+        assert!(
+            error
+                .detail_message()
+                .contains("GitHub, GitLab, and Forgejo remotes")
+        );
         assert!(error.detail_message().contains("example.com"));
     }
 
@@ -333,4 +418,58 @@ mod tests {
         assert_eq!(remote.project_path(), "team/agentty");
         assert_eq!(remote.web_url, "https://gitlab.company.org/team/agentty");
     }
+
+    #[test]
+    fn detect_remote_returns_forgejo_remote_for_codeberg_origin() {
+        // Arrange
+        let repo_url = "git@codeberg.org:agentty-xyz/agentty.git";
+
+        // Act
+        let remote = detect_remote(repo_url).expect("codeberg remote should be supported");
+
+        // Assert
+        assert_eq!(remote.forge_kind, ForgeKind::Forgejo);
+        assert_eq!(remote.host, "codeberg.org");
+        assert_eq!(remote.web_url, "https://codeberg.org/agentty-xyz/agentty");
+    }
+
+    #[test]
+    fn detect_remote_with_overrides_treats_configured_hosts_as_forgejo() {
+        // Arrange
+        let repo_url = "ssh://git@git.example.com:2222/team/agentty.git";
+        let host_overrides = ForgeHostOverrides::default();
+        host_overrides.set_forgejo_hosts(&[" https://Git.Example.com/ ".to_string()]);
+
+        // Act
+        let remote = detect_remote_with_overrides(repo_url, &host_overrides)
+            .expect("configured forgejo host should be supported");
+        let default_error = detect_remote(repo_url).expect_err("unconfigured host should fail");
+
+        // Assert
+        assert_eq!(remote.forge_kind, ForgeKind::Forgejo);
+        assert_eq!(remote.host, "git.example.com");
+        assert_eq!(remote.project_path(), "team/agentty");
+        assert!(matches!(
+            default_error,
+            ReviewRequestError::UnsupportedRemote { .. }
+        ));
+    }
+
+    #[test]
+    fn forge_host_overrides_match_configured_host_on_any_port() {
+        // Arrange
+        let host_overrides = ForgeHostOverrides::default();
+        host_overrides.set_forgejo_hosts(&[
+            "git.example.com".to_string(),
+            "code.example.org:3000".to_string(),
+            "  ".to_string(),
+        ]);
+
+        // Act, Assert
+        assert!(host_overrides.is_forgejo_host("git.example.com"));
+        assert!(host_overrides.is_forgejo_host("git.example.com:8443"));
+        assert!(host_overrides.is_forgejo_host("code.example.org:3000"));
+        assert!(!host_overrides.is_forgejo_host("code.example.org"));
+        assert!(!host_overrides.is_forgejo_host(""));
+    }
 }
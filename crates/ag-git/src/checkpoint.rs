@@ -0,0 +1,290 @@
+use std::path::PathBuf;
+
+use super::error::GitError;
+use super::repo::run_git_command;
+
+/// Ref namespace that stores hidden per-session turn checkpoints.
+const CHECKPOINT_REF_ROOT: &str = "refs/agentty";
+
+/// One hidden turn checkpoint recorded for a session branch.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TurnCheckpoint {
+    /// Full commit hash the checkpoint ref points at.
+    pub commit_hash: String,
+    /// One-based turn number encoded in the ref name.
+    pub turn: u32,
+}
+
+/// Returns the hidden ref name for one session turn checkpoint.
+pub(crate) fn turn_checkpoint_ref(session_id: &str, turn: u32) -> String {
+    format!("{CHECKPOINT_REF_ROOT}/{session_id}/turn-{turn}")
+}
+
+/// Points `refs/agentty/<session>/turn-<turn>` at the current `HEAD`.
+///
+/// Recording the same turn again moves the ref, so a retried turn keeps only
+/// its latest snapshot.
+///
+/// # Errors
+/// Returns a [`GitError`] when `HEAD` cannot be resolved or the ref update is
+/// rejected.
+pub(crate) async fn record_turn_checkpoint(
+    repo_path: PathBuf,
+    session_id: String,
+    turn: u32,
+) -> Result<String, GitError> {
+    let commit_hash = super::head_hash(repo_path.clone()).await?;
+    run_git_command(
+        repo_path,
+        vec![
+            "update-ref".to_string(),
+            turn_checkpoint_ref(&session_id, turn),
+            commit_hash.clone(),
+        ],
+        format!("Failed to record checkpoint for turn {turn}"),
+    )
+    .await?;
+
+    Ok(commit_hash)
+}
+
+/// Lists recorded turn checkpoints for one session ordered by turn number.
+///
+/// Refs whose name does not end in a `turn-<n>` suffix are ignored.
+///
+/// # Errors
+/// Returns a [`GitError`] when refs cannot be listed.
+pub(crate) async fn list_turn_checkpoints(
+    repo_path: PathBuf,
+    session_id: String,
+) -> Result<Vec<TurnCheckpoint>, GitError> {
+    let output = run_git_command(
+        repo_path,
+        vec![
+            "for-each-ref".to_string(),
+            "--format=%(objectname) %(refname)".to_string(),
+            format!("{CHECKPOINT_REF_ROOT}/{session_id}/"),
+        ],
+        "Failed to list turn checkpoints".to_string(),
+    )
+    .await?;
+
+    Ok(parse_turn_checkpoints(&output))
+}
+
+/// Restores the session worktree to one recorded turn checkpoint.
+///
+/// Resets `HEAD`, the index, and tracked files to the checkpoint commit,
+/// removes untracked files created after it (ignored files are kept), and
+/// deletes the now-orphaned checkpoints of later turns.
+///
+/// # Errors
+/// Returns a [`GitError`] when the checkpoint is missing or any reset, clean,
+/// or ref deletion step fails.
+pub(crate) async fn restore_turn_checkpoint(
+    repo_path: PathBuf,
+    session_id: String,
+    turn: u32,
+) -> Result<(), GitError> {
+    let checkpoints = list_turn_checkpoints(repo_path.clone(), session_id.clone()).await?;
+    let Some(checkpoint) = checkpoints
+        .iter()
+        .find(|checkpoint| checkpoint.turn == turn)
+    else {
+        return Err(GitError::CheckpointNotFound { turn });
+    };
+
+    run_git_command(
+        repo_path.clone(),
+        vec![
+            "reset".to_string(),
+            "--hard".to_string(),
+            checkpoint.commit_hash.clone(),
+        ],
+        format!("Failed to restore checkpoint for turn {turn}"),
+    )
+    .await?;
+    run_git_command(
+        repo_path.clone(),
+        vec!["clean".to_string(), "-fd".to_string()],
+        format!("Failed to remove untracked files after restoring turn {turn}"),
+    )
+    .await?;
+
+    for later_checkpoint in checkpoints
+        .iter()
+        .filter(|checkpoint| checkpoint.turn > turn)
+    {
+        delete_ref(
+            repo_path.clone(),
+            turn_checkpoint_ref(&session_id, later_checkpoint.turn),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Deletes every recorded turn checkpoint for one session.
+///
+/// # Errors
+/// Returns a [`GitError`] when refs cannot be listed or deleted.
+pub(crate) async fn delete_turn_checkpoints(
+    repo_path: PathBuf,
+    session_id: String,
+) -> Result<(), GitError> {
+    let checkpoints = list_turn_checkpoints(repo_path.clone(), session_id.clone()).await?;
+    for checkpoint in checkpoints {
+        delete_ref(
+            repo_path.clone(),
+            turn_checkpoint_ref(&session_id, checkpoint.turn),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Deletes one ref with `git update-ref -d`.
+async fn delete_ref(repo_path: PathBuf, reference: String) -> Result<(), GitError> {
+    run_git_command(
+        repo_path,
+        vec![
+            "update-ref".to_string(),
+            "-d".to_string(),
+            reference.clone(),
+        ],
+        format!("Failed to delete `{reference}`"),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Parses `for-each-ref` output lines of `<hash> <refname>` into checkpoints.
+fn parse_turn_checkpoints(output: &str) -> Vec<TurnCheckpoint> {
+    let mut checkpoints = output
+        .lines()
+        .filter_map(|line| {
+            let (commit_hash, reference) = line.trim().split_once(' ')?;
+            let turn = reference.rsplit_once("/turn-")?.1.parse::<u32>().ok()?;
+
+            Some(TurnCheckpoint {
+                commit_hash: commit_hash.to_string(),
+                turn,
+            })
+        })
+        .collect::<Vec<_>>();
+    checkpoints.sort_by_key(|checkpoint| checkpoint.turn);
+
+    checkpoints
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use std::process::Command;
+
+    use tempfile::tempdir;
+
+    use super::*;
+
+    fn run_git(repo_path: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(repo_path)
+            .output()
+            .expect("failed to run git command");
+
+        assert!(
+            output.status.success(),
+            "git command {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    fn setup_session_repo(repo_path: &Path) {
+        run_git(repo_path, &["init", "-b", "main"]);
+        run_git(repo_path, &["config", "user.name", "Test User"]);
+        run_git(repo_path, &["config", "user.email", "test@example.com"]);
+        fs::write(repo_path.join("README.md"), "base").expect("failed to write file");
+        run_git(repo_path, &["add", "README.md"]);
+        run_git(repo_path, &["commit", "-m", "Initial commit"]);
+    }
+
+    fn commit_file(repo_path: &Path, content: &str) {
+        fs::write(repo_path.join("README.md"), content).expect("failed to write file");
+        run_git(repo_path, &["commit", "-am", content]);
+    }
+
+    #[test]
+    fn parse_turn_checkpoints_orders_by_turn_and_skips_foreign_refs() {
+        // Arrange
+        let output = "bbb refs/agentty/sess/turn-10\naaa refs/agentty/sess/turn-2\nccc \
+                      refs/agentty/sess/other\n";
+
+        // Act
+        let checkpoints = parse_turn_checkpoints(output);
+
+        // Assert
+        assert_eq!(
+            checkpoints,
+            vec![
+                TurnCheckpoint {
+                    commit_hash: "aaa".to_string(),
+                    turn: 2,
+                },
+                TurnCheckpoint {
+                    commit_hash: "bbb".to_string(),
+                    turn: 10,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn restore_turn_checkpoint_resets_worktree_and_drops_later_turns() {
+        // Arrange
+        let dir = tempdir().expect("failed to create temp dir");
+        let repo_path = dir.path().to_path_buf();
+        setup_session_repo(&repo_path);
+        commit_file(&repo_path, "turn one");
+        let turn_one_hash = record_turn_checkpoint(repo_path.clone(), "sess".to_string(), 1)
+            .await
+            .expect("failed to record turn 1");
+        commit_file(&repo_path, "turn two");
+        record_turn_checkpoint(repo_path.clone(), "sess".to_string(), 2)
+            .await
+            .expect("failed to record turn 2");
+        fs::write(repo_path.join("scratch.txt"), "untracked").expect("failed to write file");
+
+        // Act
+        let result = restore_turn_checkpoint(repo_path.clone(), "sess".to_string(), 1).await;
+
+        // Assert
+        assert!(result.is_ok(), "restore should succeed: {result:?}");
+        assert_eq!(run_git(&repo_path, &["rev-parse", "HEAD"]), turn_one_hash);
+        assert_eq!(
+            fs::read_to_string(repo_path.join("README.md")).expect("failed to read file"),
+            "turn one"
+        );
+        assert!(!repo_path.join("scratch.txt").exists());
+        assert_eq!(
+            list_turn_checkpoints(repo_path.clone(), "sess".to_string())
+                .await
+                .expect("failed to list checkpoints"),
+            vec![TurnCheckpoint {
+                commit_hash: turn_one_hash,
+                turn: 1,
+            }]
+        );
+        assert!(matches!(
+            restore_turn_checkpoint(repo_path, "sess".to_string(), 2).await,
+            Err(GitError::CheckpointNotFound { turn: 2 })
+        ));
+    }
+}
@@ -4,6 +4,7 @@ use std::future::Future;
 use std::path::PathBuf;
 use std::pin::Pin;
 
+use super::checkpoint::TurnCheckpoint;
 use super::error::GitError;
 use super::merge::SquashMergeOutcome;
 use super::rebase::{InProgressGitOperation, RebaseStepResult};
@@ -13,15 +14,16 @@ use super::sync::{
 use super::{
     abort_rebase, branch_tracking_statuses, check_pre_commit_hook_ready, commit_all,
     commit_all_preserving_single_commit, create_worktree, current_upstream_reference,
-    delete_branch, detect_git_info, diff, diff_changed_files, fetch_remote, find_git_repo_root,
-    get_ahead_behind, get_ref_ahead_behind, has_commits_since, has_merge_conflicts,
-    has_unmerged_paths, head_commit_message, head_hash, head_short_hash, in_progress_operation,
-    is_rebase_in_progress, is_worktree_clean, list_conflicted_files, list_local_commit_titles,
-    list_staged_conflict_marker_files, list_upstream_commit_titles, main_checkout_working_tree,
-    main_repo_root, pull_rebase, push_current_branch, push_current_branch_to_remote_branch, rebase,
-    rebase_continue, rebase_onto_start, rebase_start, ref_hash, remote_branch_exists,
-    remove_worktree, repo_url, squash_merge, squash_merge_diff, stage_all, sync,
-    tracked_worktree_status, worktree_status,
+    delete_branch, delete_turn_checkpoints, detect_git_info, diff, diff_changed_files,
+    fetch_remote, fetch_remote_ref, find_git_repo_root, get_ahead_behind, get_ref_ahead_behind,
+    has_commits_since, has_merge_conflicts, has_unmerged_paths, head_commit_message, head_hash,
+    head_short_hash, in_progress_operation, is_rebase_in_progress, is_worktree_clean,
+    list_conflicted_files, list_local_commit_titles, list_staged_conflict_marker_files,
+    list_turn_checkpoints, list_upstream_commit_titles, main_checkout_working_tree, main_repo_root,
+    pull_rebase, push_current_branch, push_current_branch_to_remote_branch, rebase,
+    rebase_continue, rebase_onto_start, rebase_start, record_turn_checkpoint, ref_hash,
+    remote_branch_exists, remove_worktree, repo_url, restore_turn_checkpoint, reverse_apply_patch,
+    squash_merge, squash_merge_diff, stage_all, sync, tracked_worktree_status, worktree_status,
 };
 
 /// Boxed async result used by [`GitClient`] trait methods.
@@ -234,6 +236,52 @@ pub trait GitClient: Send + Sync {
         reference: String,
     ) -> GitFuture<Result<String, GitError>>;
 
+    /// Points the hidden `refs/agentty/<session>/turn-<turn>` checkpoint ref
+    /// at the current `HEAD` of `repo_path` and returns the recorded hash.
+    ///
+    /// # Errors
+    /// Returns an error when `HEAD` cannot be resolved or the ref update
+    /// fails.
+    fn record_turn_checkpoint(
+        &self,
+        repo_path: PathBuf,
+        session_id: String,
+        turn: u32,
+    ) -> GitFuture<Result<String, GitError>>;
+
+    /// Lists recorded turn checkpoints for one session ordered by turn.
+    ///
+    /// # Errors
+    /// Returns an error when refs cannot be listed.
+    fn list_turn_checkpoints(
+        &self,
+        repo_path: PathBuf,
+        session_id: String,
+    ) -> GitFuture<Result<Vec<TurnCheckpoint>, GitError>>;
+
+    /// Hard-resets the worktree in `repo_path` to one turn checkpoint,
+    /// removes untracked files, and deletes checkpoints of later turns.
+    ///
+    /// # Errors
+    /// Returns an error when the checkpoint is missing or the reset, clean,
+    /// or ref cleanup fails.
+    fn restore_turn_checkpoint(
+        &self,
+        repo_path: PathBuf,
+        session_id: String,
+        turn: u32,
+    ) -> GitFuture<Result<(), GitError>>;
+
+    /// Deletes every recorded turn checkpoint for one session.
+    ///
+    /// # Errors
+    /// Returns an error when refs cannot be listed or deleted.
+    fn delete_turn_checkpoints(
+        &self,
+        repo_path: PathBuf,
+        session_id: String,
+    ) -> GitFuture<Result<(), GitError>>;
+
     /// Returns the full `HEAD` commit message for `repo_path`, or `None` when
     /// no commits exist.
     ///
@@ -284,6 +332,17 @@ pub trait GitClient: Send + Sync {
         relative_path: String,
     ) -> GitFuture<Result<WorktreeFileContent, GitError>>;
 
+    /// Reverse-applies one unified `patch` to the worktree in `repo_path`,
+    /// discarding the changes it describes without touching the index.
+    ///
+    /// # Errors
+    /// Returns an error when the patch no longer applies to the worktree.
+    fn reverse_apply_patch(
+        &self,
+        repo_path: PathBuf,
+        patch: String,
+    ) -> GitFuture<Result<(), GitError>>;
+
     /// Returns whether the worktree in `repo_path` has no local changes.
     ///
     /// # Errors
@@ -363,6 +422,17 @@ pub trait GitClient: Send + Sync {
     /// Returns an error when fetch fails.
     fn fetch_remote(&self, repo_path: PathBuf) -> GitFuture<Result<(), GitError>>;
 
+    /// Fetches one explicit `remote_ref` from `origin` and returns the
+    /// fetched commit hash.
+    ///
+    /// # Errors
+    /// Returns an error when fetch fails or the fetched ref is not a commit.
+    fn fetch_remote_ref(
+        &self,
+        repo_path: PathBuf,
+        remote_ref: String,
+    ) -> GitFuture<Result<String, GitError>>;
+
     /// Reads ahead/behind commit counts for `repo_path`.
     ///
     /// # Errors
@@ -616,6 +686,40 @@ impl GitClient for RealGitClient {
         Box::pin(async move { ref_hash(repo_path, reference).await })
     }
 
+    fn record_turn_checkpoint(
+        &self,
+        repo_path: PathBuf,
+        session_id: String,
+        turn: u32,
+    ) -> GitFuture<Result<String, GitError>> {
+        Box::pin(async move { record_turn_checkpoint(repo_path, session_id, turn).await })
+    }
+
+    fn list_turn_checkpoints(
+        &self,
+        repo_path: PathBuf,
+        session_id: String,
+    ) -> GitFuture<Result<Vec<TurnCheckpoint>, GitError>> {
+        Box::pin(async move { list_turn_checkpoints(repo_path, session_id).await })
+    }
+
+    fn restore_turn_checkpoint(
+        &self,
+        repo_path: PathBuf,
+        session_id: String,
+        turn: u32,
+    ) -> GitFuture<Result<(), GitError>> {
+        Box::pin(async move { restore_turn_checkpoint(repo_path, session_id, turn).await })
+    }
+
+    fn delete_turn_checkpoints(
+        &self,
+        repo_path: PathBuf,
+        session_id: String,
+    ) -> GitFuture<Result<(), GitError>> {
+        Box::pin(async move { delete_turn_checkpoints(repo_path, session_id).await })
+    }
+
     fn head_commit_message(
         &self,
         repo_path: PathBuf,
@@ -651,6 +755,14 @@ impl GitClient for RealGitClient {
         Box::pin(async move { sync::read_worktree_file(repo_path, relative_path).await })
     }
 
+    fn reverse_apply_patch(
+        &self,
+        repo_path: PathBuf,
+        patch: String,
+    ) -> GitFuture<Result<(), GitError>> {
+        Box::pin(async move { reverse_apply_patch(repo_path, patch).await })
+    }
+
     fn is_worktree_clean(&self, repo_path: PathBuf) -> GitFuture<Result<bool, GitError>> {
         Box::pin(async move { is_worktree_clean(repo_path).await })
     }
@@ -708,6 +820,14 @@ impl GitClient for RealGitClient {
         Box::pin(async move { fetch_remote(repo_path).await })
     }
 
+    fn fetch_remote_ref(
+        &self,
+        repo_path: PathBuf,
+        remote_ref: String,
+    ) -> GitFuture<Result<String, GitError>> {
+        Box::pin(async move { fetch_remote_ref(repo_path, remote_ref).await })
+    }
+
     fn get_ahead_behind(&self, repo_path: PathBuf) -> GitFuture<Result<(u32, u32), GitError>> {
         Box::pin(async move { get_ahead_behind(repo_path).await })
     }
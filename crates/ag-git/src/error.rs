@@ -28,6 +28,13 @@ pub enum GitError {
     #[error("{0}")]
     OutputParse(String),
 
+    /// No hidden checkpoint ref is recorded for the requested session turn.
+    #[error("No checkpoint recorded for turn {turn}")]
+    CheckpointNotFound {
+        /// One-based turn number that has no checkpoint.
+        turn: u32,
+    },
+
     /// The requested repository or worktree is no longer available.
     #[error("{detail}")]
     RepositoryUnavailable {
@@ -117,6 +124,18 @@ mod tests {
         assert_eq!(error.to_string(), "unexpected rev-parse output");
     }
 
+    #[test]
+    fn checkpoint_not_found_display_includes_turn() {
+        // Arrange
+        let error = GitError::CheckpointNotFound { turn: 3 };
+
+        // Act
+        let display = error.to_string();
+
+        // Assert
+        assert_eq!(display, "No checkpoint recorded for turn 3");
+    }
+
     #[test]
     fn repository_unavailable_display_shows_original_detail() {
         // Arrange
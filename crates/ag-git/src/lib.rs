@@ -1,5 +1,7 @@
 //! Reusable git, worktree, sync, rebase, and squash-merge orchestration.
 
+/// Hidden per-turn checkpoint refs for session branches.
+mod checkpoint;
 /// Client boundary and production adapter implementations.
 mod client;
 /// Typed error types for git infrastructure operations.
@@ -17,6 +19,10 @@ mod sync;
 /// Worktree and branch-detection workflows.
 mod worktree;
 
+pub use checkpoint::TurnCheckpoint;
+pub(crate) use checkpoint::{
+    delete_turn_checkpoints, list_turn_checkpoints, record_turn_checkpoint, restore_turn_checkpoint,
+};
 #[cfg(any(test, feature = "test-utils"))]
 pub use client::MockGitClient;
 pub use client::{GitClient, GitFuture, RealGitClient};
@@ -40,10 +46,10 @@ pub use sync::{
 pub(crate) use sync::{
     branch_tracking_statuses, check_pre_commit_hook_ready, commit_all,
     commit_all_preserving_single_commit, current_upstream_reference, delete_branch, diff,
-    diff_changed_files, fetch_remote, get_ahead_behind, get_ref_ahead_behind, has_commits_since,
-    head_commit_message, head_hash, head_short_hash, is_worktree_clean, list_local_commit_titles,
-    list_upstream_commit_titles, pull_rebase, push_current_branch,
-    push_current_branch_to_remote_branch, ref_hash, remote_branch_exists, stage_all,
-    tracked_worktree_status, worktree_status,
+    diff_changed_files, fetch_remote, fetch_remote_ref, get_ahead_behind, get_ref_ahead_behind,
+    has_commits_since, head_commit_message, head_hash, head_short_hash, is_worktree_clean,
+    list_local_commit_titles, list_upstream_commit_titles, pull_rebase, push_current_branch,
+    push_current_branch_to_remote_branch, ref_hash, remote_branch_exists, reverse_apply_patch,
+    stage_all, tracked_worktree_status, worktree_status,
 };
 pub(crate) use worktree::{create_worktree, detect_git_info, find_git_repo_root, remove_worktree};
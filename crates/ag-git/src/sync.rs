@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use std::io::Read;
+use std::io::{Read, Write};
 use std::path::{Component, Path, PathBuf};
 use std::process::Output;
 use std::time::Duration;
@@ -477,6 +477,43 @@ fn read_worktree_file_sync(
     Ok(worktree_file_content(bytes))
 }
 
+/// Reverse-applies one unified patch to the worktree in `repo_path`.
+///
+/// Only worktree files change; the index and `HEAD` are left for the caller
+/// to commit. The patch is written to a temporary file so arbitrary patch
+/// bytes never pass through command-line arguments.
+///
+/// # Errors
+/// Returns a [`GitError`] when the temporary patch cannot be written or the
+/// patch no longer applies to the current worktree.
+pub(crate) async fn reverse_apply_patch(repo_path: PathBuf, patch: String) -> Result<(), GitError> {
+    spawn_blocking(move || reverse_apply_patch_sync(&repo_path, &patch)).await?
+}
+
+/// Writes `patch` to a temporary file and runs `git apply -R` against it.
+fn reverse_apply_patch_sync(repo_path: &Path, patch: &str) -> Result<(), GitError> {
+    let mut patch_file =
+        tempfile::NamedTempFile::new().map_err(|error| GitError::CommandFailed {
+            command: "create temporary patch file".to_string(),
+            stderr: error.to_string(),
+        })?;
+    patch_file
+        .write_all(patch.as_bytes())
+        .map_err(|error| GitError::CommandFailed {
+            command: "write temporary patch file".to_string(),
+            stderr: error.to_string(),
+        })?;
+    let patch_path = patch_file.path().to_string_lossy().into_owned();
+
+    run_git_command_sync(
+        repo_path,
+        &["apply", "-R", "--whitespace=nowarn", patch_path.as_str()],
+        "Failed to revert diff changes",
+    )?;
+
+    Ok(())
+}
+
 /// Classifies bytes read through the bounded worktree-file reader.
 fn worktree_file_content(bytes: Vec<u8>) -> WorktreeFileContent {
     if bytes.len() > MAX_WORKTREE_FILE_BYTE_COUNT {
@@ -1061,6 +1098,33 @@ pub(crate) async fn fetch_remote(repo_path: PathBuf) -> Result<(), GitError> {
     Ok(())
 }
 
+/// Fetches one explicit ref from `origin` and returns the fetched commit hash.
+///
+/// Used for refs outside the default fetch refspec, such as forge-maintained
+/// `refs/pull/<number>/head` review-request heads.
+///
+/// # Errors
+/// Returns a [`GitError`] if `git fetch` fails or `FETCH_HEAD` cannot be
+/// resolved to a commit.
+pub(crate) async fn fetch_remote_ref(
+    repo_path: PathBuf,
+    remote_ref: String,
+) -> Result<String, GitError> {
+    run_git_command(
+        repo_path.clone(),
+        vec![
+            "fetch".to_string(),
+            "--no-tags".to_string(),
+            "origin".to_string(),
+            remote_ref.clone(),
+        ],
+        format!("Git fetch of `{remote_ref}` failed"),
+    )
+    .await?;
+
+    ref_hash(repo_path, "FETCH_HEAD".to_string()).await
+}
+
 /// Returns the number of commits ahead and behind the upstream branch.
 ///
 /// # Arguments
@@ -1912,6 +1976,65 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn reverse_apply_patch_discards_described_worktree_changes() {
+        // Arrange
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        setup_test_git_repo(temp_dir.path());
+        fs::write(temp_dir.path().join("README.md"), "base\nkept\ndiscarded\n")
+            .expect("failed to write worktree change");
+        let patch = concat!(
+            "diff --git a/README.md b/README.md\n",
+            "--- a/README.md\n",
+            "+++ b/README.md\n",
+            "@@ -1,2 +1,3 @@\n",
+            " base\n",
+            " kept\n",
+            "+discarded\n",
+        );
+
+        // Act
+        let result = reverse_apply_patch(temp_dir.path().to_path_buf(), patch.to_string()).await;
+
+        // Assert
+        assert!(result.is_ok(), "reverse apply should succeed: {result:?}");
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("README.md"))
+                .expect("failed to read worktree file"),
+            "base\nkept\n"
+        );
+        assert_eq!(
+            git_command_stdout(temp_dir.path(), &["diff", "--cached", "--name-only"]),
+            ""
+        );
+    }
+
+    #[tokio::test]
+    async fn reverse_apply_patch_rejects_stale_patches() {
+        // Arrange
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        setup_test_git_repo(temp_dir.path());
+        let patch = concat!(
+            "diff --git a/README.md b/README.md\n",
+            "--- a/README.md\n",
+            "+++ b/README.md\n",
+            "@@ -1 +1,2 @@\n",
+            " base\n",
+            "+missing\n",
+        );
+
+        // Act
+        let result = reverse_apply_patch(temp_dir.path().to_path_buf(), patch.to_string()).await;
+
+        // Assert
+        assert!(matches!(result, Err(GitError::CommandFailed { .. })));
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("README.md"))
+                .expect("failed to read worktree file"),
+            "base\n"
+        );
+    }
+
     #[tokio::test]
     async fn read_worktree_file_returns_text_for_safe_nested_path() {
         // Arrange
@@ -2600,6 +2723,35 @@ mod tests {
         assert!(exists);
     }
 
+    #[tokio::test]
+    async fn fetch_remote_ref_returns_commit_for_non_branch_ref() {
+        // Arrange
+        let temp_dir = tempdir().expect("failed to create temp dir");
+        let remote_dir = tempdir().expect("failed to create remote temp dir");
+        setup_test_git_repo(temp_dir.path());
+        run_git_command(remote_dir.path(), &["init", "--bare"]);
+        let remote_path = remote_dir.path().to_string_lossy().to_string();
+        run_git_command(temp_dir.path(), &["remote", "add", "origin", &remote_path]);
+        run_git_command(
+            temp_dir.path(),
+            &["push", "origin", "main:refs/pull/7/head"],
+        );
+        let expected_hash = ref_hash(temp_dir.path().to_path_buf(), "main".to_string())
+            .await
+            .expect("failed to resolve main hash");
+
+        // Act
+        let fetched_hash = fetch_remote_ref(
+            temp_dir.path().to_path_buf(),
+            "refs/pull/7/head".to_string(),
+        )
+        .await
+        .expect("review-request head fetch should succeed");
+
+        // Assert
+        assert_eq!(fetched_hash, expected_hash);
+    }
+
     #[tokio::test]
     async fn remote_branch_lookup_preserves_remote_config_failure() {
         // Arrange
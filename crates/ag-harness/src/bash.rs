@@ -0,0 +1,519 @@
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+use std::{env, io};
+
+use rustix::process::{self, Pid, Signal};
+use serde::Serialize;
+use thiserror::Error;
+use tokio::io::{AsyncRead, AsyncReadExt as _};
+
+use crate::file_system::FileSystem;
+use crate::tool::BashArguments;
+
+const DEFAULT_OUTPUT_LIMIT_BYTES: usize = 32 * 1024;
+const DEFAULT_TIMEOUT: Duration = Duration::from_mins(2);
+const DEFAULT_ENVIRONMENT: [&str; 7] = ["HOME", "LANG", "LC_ALL", "PATH", "TERM", "TMPDIR", "USER"];
+const MAX_TIMEOUT: Duration = Duration::from_mins(10);
+const READ_CHUNK_BYTES: usize = 8 * 1024;
+const SHELL: &str = "bash";
+
+/// Execution limits applied to every `bash` tool call.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BashConfig {
+    /// Timeout used when the model does not request one.
+    pub default_timeout: Duration,
+    /// Environment variable names copied from the harness process; every
+    /// other variable is cleared before the command starts.
+    pub environment: Vec<String>,
+    /// Upper bound applied to model-requested timeouts.
+    pub max_timeout: Duration,
+    /// Maximum bytes kept from each of stdout and stderr.
+    ///
+    /// Longer streams keep their first and last halves around a truncation
+    /// marker.
+    pub output_limit_bytes: usize,
+}
+
+impl Default for BashConfig {
+    fn default() -> Self {
+        Self {
+            default_timeout: DEFAULT_TIMEOUT,
+            environment: DEFAULT_ENVIRONMENT.map(str::to_string).to_vec(),
+            max_timeout: MAX_TIMEOUT,
+            output_limit_bytes: DEFAULT_OUTPUT_LIMIT_BYTES,
+        }
+    }
+}
+
+/// Bounded result returned by one `bash` execution.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct BashOutput {
+    exit_code: Option<i32>,
+    stderr: String,
+    stdout: String,
+    timed_out: bool,
+    truncated: bool,
+}
+
+impl BashOutput {
+    /// Returns the process exit code, or `None` when a signal ended it.
+    pub fn exit_code(&self) -> Option<i32> {
+        self.exit_code
+    }
+
+    /// Returns captured standard error, possibly truncated.
+    pub fn stderr(&self) -> &str {
+        &self.stderr
+    }
+
+    /// Returns captured standard output, possibly truncated.
+    pub fn stdout(&self) -> &str {
+        &self.stdout
+    }
+
+    /// Returns whether the command was killed after its timeout elapsed.
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /// Returns whether either stream exceeded the configured output limit.
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    pub(crate) fn to_tool_result(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
+/// Failure while safely executing one `bash` command.
+#[derive(Debug, Error)]
+pub enum BashError {
+    /// The repository root could not be resolved.
+    #[error("failed to resolve repository root: {source}")]
+    RepositoryRoot {
+        /// Underlying filesystem failure.
+        #[source]
+        source: io::Error,
+    },
+    /// The requested working directory could not be resolved.
+    #[error("failed to resolve working directory `{path}`: {source}")]
+    ResolveDirectory {
+        /// Repository-relative requested directory.
+        path: String,
+        /// Underlying filesystem failure.
+        #[source]
+        source: io::Error,
+    },
+    /// The canonical working directory escapes the canonical repository root.
+    #[error("working directory `{path}` resolves outside the repository")]
+    OutsideRepository {
+        /// Repository-relative requested directory.
+        path: String,
+    },
+    /// The shell process could not be started.
+    #[error("failed to start `{SHELL}`: {source}")]
+    Spawn {
+        /// Underlying process failure.
+        #[source]
+        source: io::Error,
+    },
+    /// Command output could not be captured.
+    #[error("failed to capture command output: {source}")]
+    Capture {
+        /// Underlying pipe failure.
+        #[source]
+        source: io::Error,
+    },
+    /// The shell process status could not be collected.
+    #[error("failed to wait for `{SHELL}`: {source}")]
+    Wait {
+        /// Underlying process failure.
+        #[source]
+        source: io::Error,
+    },
+    /// The successful result could not be encoded for the model.
+    #[error("failed to encode bash result: {0}")]
+    Encode(#[from] serde_json::Error),
+}
+
+pub(crate) struct BashTool {
+    config: BashConfig,
+    file_system: Arc<dyn FileSystem>,
+    repository_root: PathBuf,
+}
+
+impl BashTool {
+    pub(crate) fn new(
+        config: BashConfig,
+        file_system: Arc<dyn FileSystem>,
+        repository_root: PathBuf,
+    ) -> Self {
+        Self {
+            config,
+            file_system,
+            repository_root,
+        }
+    }
+
+    pub(crate) async fn execute(&self, arguments: &BashArguments) -> Result<BashOutput, BashError> {
+        let working_directory = self.working_directory(arguments).await?;
+        let timeout = arguments
+            .timeout_seconds()
+            .map_or(self.config.default_timeout, Duration::from_secs)
+            .min(self.config.max_timeout);
+        let mut command = tokio::process::Command::new(SHELL);
+        command
+            .arg("-c")
+            .arg(arguments.command())
+            .current_dir(working_directory)
+            .env_clear()
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .process_group(0);
+        for name in &self.config.environment {
+            if let Some(value) = env::var_os(name) {
+                command.env(name, value);
+            }
+        }
+
+        let mut child = command
+            .spawn()
+            .map_err(|source| BashError::Spawn { source })?;
+        let process_group = ProcessGroup::new(child.id());
+        let stdout = child.stdout.take();
+        let stderr = child.stderr.take();
+        let mut stdout_buffer = OutputBuffer::new(self.config.output_limit_bytes);
+        let mut stderr_buffer = OutputBuffer::new(self.config.output_limit_bytes);
+        let outcome = tokio::time::timeout(timeout, async {
+            let wait = async {
+                let status = child.wait().await;
+                // Background descendants would otherwise hold the pipes open.
+                process_group.kill();
+
+                status
+            };
+            let (status, stdout_result, stderr_result) = tokio::join!(
+                wait,
+                capture(stdout, &mut stdout_buffer),
+                capture(stderr, &mut stderr_buffer),
+            );
+            stdout_result.map_err(|source| BashError::Capture { source })?;
+            stderr_result.map_err(|source| BashError::Capture { source })?;
+
+            status.map_err(|source| BashError::Wait { source })
+        })
+        .await;
+        let (exit_code, timed_out) = if let Ok(status) = outcome {
+            (status?.code(), false)
+        } else {
+            process_group.kill();
+            (None, true)
+        };
+        let truncated = stdout_buffer.is_truncated() || stderr_buffer.is_truncated();
+
+        Ok(BashOutput {
+            exit_code,
+            stderr: stderr_buffer.into_text(),
+            stdout: stdout_buffer.into_text(),
+            timed_out,
+            truncated,
+        })
+    }
+
+    async fn working_directory(&self, arguments: &BashArguments) -> Result<PathBuf, BashError> {
+        let root = self
+            .file_system
+            .canonicalize(&self.repository_root)
+            .await
+            .map_err(|source| BashError::RepositoryRoot { source })?;
+        let Some(path) = arguments.working_directory() else {
+            return Ok(root);
+        };
+        let candidate = root.join(Path::new(path));
+        let canonical_path = self
+            .file_system
+            .canonicalize(&candidate)
+            .await
+            .map_err(|source| BashError::ResolveDirectory {
+                path: path.to_string(),
+                source,
+            })?;
+        if !canonical_path.starts_with(&root) {
+            return Err(BashError::OutsideRepository {
+                path: path.to_string(),
+            });
+        }
+
+        Ok(canonical_path)
+    }
+}
+
+/// Unix process group that owns the shell and every descendant it spawns.
+struct ProcessGroup {
+    id: Option<Pid>,
+}
+
+impl ProcessGroup {
+    fn new(child_id: Option<u32>) -> Self {
+        let id = child_id
+            .and_then(|pid| i32::try_from(pid).ok())
+            .and_then(Pid::from_raw);
+
+        Self { id }
+    }
+
+    fn kill(&self) {
+        if let Some(id) = self.id {
+            let _ = process::kill_process_group(id, Signal::KILL);
+        }
+    }
+}
+
+impl Drop for ProcessGroup {
+    fn drop(&mut self) {
+        self.kill();
+    }
+}
+
+/// Stream capture that keeps the first and last bytes of long output.
+struct OutputBuffer {
+    head: Vec<u8>,
+    head_limit: usize,
+    omitted_bytes: usize,
+    tail: VecDeque<u8>,
+    tail_limit: usize,
+}
+
+impl OutputBuffer {
+    fn new(limit: usize) -> Self {
+        let head_limit = limit / 2;
+
+        Self {
+            head: Vec::new(),
+            head_limit,
+            omitted_bytes: 0,
+            tail: VecDeque::new(),
+            tail_limit: limit - head_limit,
+        }
+    }
+
+    fn push(&mut self, mut bytes: &[u8]) {
+        let head_bytes = bytes.len().min(self.head_limit - self.head.len());
+        self.head.extend_from_slice(&bytes[..head_bytes]);
+        bytes = &bytes[head_bytes..];
+        if bytes.len() > self.tail_limit {
+            let skipped = bytes.len() - self.tail_limit;
+            self.omitted_bytes += skipped + self.tail.len();
+            self.tail.clear();
+            bytes = &bytes[skipped..];
+        }
+        let overflow = (self.tail.len() + bytes.len()).saturating_sub(self.tail_limit);
+        self.omitted_bytes += overflow;
+        self.tail.drain(..overflow);
+        self.tail.extend(bytes);
+    }
+
+    fn is_truncated(&self) -> bool {
+        self.omitted_bytes > 0
+    }
+
+    fn into_text(self) -> String {
+        let mut text = String::from_utf8_lossy(&self.head).into_owned();
+        if self.omitted_bytes > 0 {
+            let _ = write!(text, "\n[... {} bytes truncated ...]\n", self.omitted_bytes);
+        }
+        let tail = Vec::from(self.tail);
+        text.push_str(&String::from_utf8_lossy(&tail));
+
+        text
+    }
+}
+
+async fn capture(
+    stream: Option<impl AsyncRead + Unpin>,
+    buffer: &mut OutputBuffer,
+) -> io::Result<()> {
+    let Some(mut stream) = stream else {
+        return Ok(());
+    };
+    let mut chunk = vec![0_u8; READ_CHUNK_BYTES];
+    loop {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(());
+        }
+        buffer.push(&chunk[..read]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::file_system::LocalFileSystem;
+
+    fn arguments(value: serde_json::Value) -> BashArguments {
+        serde_json::from_value(value).expect("bash arguments should be valid")
+    }
+
+    fn bash_tool(repository_root: &Path, config: BashConfig) -> BashTool {
+        BashTool::new(
+            config,
+            Arc::new(LocalFileSystem),
+            repository_root.to_path_buf(),
+        )
+    }
+
+    #[tokio::test]
+    async fn runs_from_repository_root_and_requested_subdirectory() {
+        // Arrange
+        let repository = tempfile::tempdir().expect("repository fixture should be created");
+        fs::create_dir(repository.path().join("crates")).expect("subdirectory should be created");
+        let tool = bash_tool(repository.path(), BashConfig::default());
+
+        // Act
+        let root_output = tool
+            .execute(&arguments(
+                json!({ "command": "basename \"$PWD\"; exit 3" }),
+            ))
+            .await
+            .expect("root command should run");
+        let nested_output = tool
+            .execute(&arguments(json!({
+                "command": "basename \"$PWD\" >&2",
+                "working_directory": "crates"
+            })))
+            .await
+            .expect("nested command should run");
+
+        // Assert
+        let root_name = repository
+            .path()
+            .file_name()
+            .expect("tempdir should have a name")
+            .to_string_lossy();
+        assert_eq!(root_output.stdout(), format!("{root_name}\n"));
+        assert_eq!(root_output.exit_code(), Some(3));
+        assert_eq!(nested_output.stderr(), "crates\n");
+        assert_eq!(nested_output.exit_code(), Some(0));
+        assert!(!nested_output.timed_out());
+    }
+
+    #[tokio::test]
+    async fn rejects_working_directory_outside_repository() {
+        // Arrange
+        let outside = tempfile::tempdir().expect("outside fixture should be created");
+        let repository = tempfile::tempdir().expect("repository fixture should be created");
+        std::os::unix::fs::symlink(outside.path(), repository.path().join("escape"))
+            .expect("symlink should be created");
+        let tool = bash_tool(repository.path(), BashConfig::default());
+
+        // Act
+        let error = tool
+            .execute(&arguments(json!({
+                "command": "pwd",
+                "working_directory": "escape"
+            })))
+            .await
+            .expect_err("escaping directory should be rejected");
+
+        // Assert
+        assert!(matches!(error, BashError::OutsideRepository { path } if path == "escape"));
+    }
+
+    #[tokio::test]
+    async fn kills_command_after_timeout_and_keeps_partial_output() {
+        // Arrange
+        let repository = tempfile::tempdir().expect("repository fixture should be created");
+        let config = BashConfig {
+            max_timeout: Duration::from_secs(1),
+            ..BashConfig::default()
+        };
+        let tool = bash_tool(repository.path(), config);
+
+        // Act
+        let output = tool
+            .execute(&arguments(json!({
+                "command": "echo started; sleep 30",
+                "timeout_seconds": 60
+            })))
+            .await
+            .expect("timed out command should still report output");
+
+        // Assert
+        assert!(output.timed_out());
+        assert_eq!(output.exit_code(), None);
+        assert_eq!(output.stdout(), "started\n");
+    }
+
+    #[tokio::test]
+    async fn passes_only_allow_listed_environment() {
+        // Arrange
+        let repository = tempfile::tempdir().expect("repository fixture should be created");
+        let config = BashConfig {
+            environment: vec!["CARGO_PKG_NAME".to_string()],
+            ..BashConfig::default()
+        };
+        let tool = bash_tool(repository.path(), config);
+
+        // Act
+        let output = tool
+            .execute(&arguments(json!({
+                "command": "echo \"${CARGO_PKG_NAME:-unset}\"; echo \"${CARGO_MANIFEST_DIR:-unset}\""
+            })))
+            .await
+            .expect("environment command should run");
+
+        // Assert
+        let package_name = env::var("CARGO_PKG_NAME").unwrap_or_else(|_| "unset".to_string());
+        assert_eq!(output.stdout(), format!("{package_name}\nunset\n"));
+    }
+
+    #[tokio::test]
+    async fn truncates_long_output_between_head_and_tail() {
+        // Arrange
+        let repository = tempfile::tempdir().expect("repository fixture should be created");
+        let config = BashConfig {
+            output_limit_bytes: 8,
+            ..BashConfig::default()
+        };
+        let tool = bash_tool(repository.path(), config);
+
+        // Act
+        let output = tool
+            .execute(&arguments(
+                json!({ "command": "printf 'abcdefghijklmnopqrstuvwxyz'" }),
+            ))
+            .await
+            .expect("long command should run");
+
+        // Assert
+        assert!(output.truncated());
+        assert_eq!(output.stdout(), "abcd\n[... 18 bytes truncated ...]\nwxyz");
+    }
+
+    #[test]
+    fn output_buffer_keeps_tail_across_small_chunks() {
+        // Arrange
+        let mut buffer = OutputBuffer::new(4);
+
+        // Act
+        for chunk in [b"ab".as_slice(), b"cd", b"ef", b"g"] {
+            buffer.push(chunk);
+        }
+
+        // Assert
+        assert!(buffer.is_truncated());
+        assert_eq!(buffer.into_text(), "ab\n[... 3 bytes truncated ...]\nfg");
+    }
+}
@@ -22,6 +22,14 @@ pub(crate) const STRUCTURED_OUTPUT_INSTRUCTION: &str = concat!(
     "Return only one JSON object. The object must validate against this JSON Schema. ",
     "Do not include Markdown fences or any other text.\n\nJSON Schema:\n",
 );
+pub(crate) const STRUCTURED_OUTPUT_REPAIR_INSTRUCTION: &str = "Your previous reply was rejected: ";
+
+/// Authentication attached to every Chat Completions request.
+pub(crate) enum ChatCompletionAuth {
+    Bearer(String),
+    Header { name: String, value: String },
+    None,
+}
 
 /// Structured-output representation selected by one Chat Completions provider.
 #[derive(Clone, Copy)]
@@ -31,6 +39,9 @@ pub(crate) enum StructuredOutputMode {
         tool_result_name: bool,
     },
     JsonSchema,
+    Prompted {
+        repair_attempts: u8,
+    },
 }
 
 impl StructuredOutputMode {
@@ -44,6 +55,17 @@ impl StructuredOutputMode {
         )
     }
 
+    fn repair_attempts(self) -> u8 {
+        match self {
+            Self::Prompted { repair_attempts } => repair_attempts,
+            Self::JsonObject { .. } | Self::JsonSchema => 0,
+        }
+    }
+
+    fn schema_instruction(self) -> bool {
+        matches!(self, Self::JsonObject { .. } | Self::Prompted { .. })
+    }
+
     fn tool_result_name(self) -> bool {
         matches!(
             self,
@@ -89,7 +111,7 @@ impl GeneratedResponse {
 /// Shared structured-output backend for OpenAI-compatible Chat Completions
 /// APIs.
 pub(crate) struct ChatCompletionBackend {
-    api_key: String,
+    auth: ChatCompletionAuth,
     base_url: String,
     client: Arc<dyn ChatCompletionClient>,
     model: String,
@@ -99,12 +121,12 @@ pub(crate) struct ChatCompletionBackend {
 impl ChatCompletionBackend {
     /// Creates a structured-output backend with the production HTTP client.
     pub(crate) fn new(
-        api_key: String,
+        auth: ChatCompletionAuth,
         base_url: String,
         model: String,
         policy: ChatCompletionProviderPolicy,
     ) -> Self {
-        Self::with_client(api_key, base_url, model, policy, default_client())
+        Self::with_client(auth, base_url, model, policy, default_client())
     }
 
     /// Returns the backend's telemetry identity.
@@ -113,6 +135,9 @@ impl ChatCompletionBackend {
     }
 
     /// Generates raw structured output through the shared wire lifecycle.
+    ///
+    /// Prompted providers validate terminal output locally and resend the
+    /// rejected reply with its diagnostic up to the policy's repair budget.
     pub(crate) async fn generate(
         &self,
         request: &model::ModelRequest,
@@ -122,8 +147,50 @@ impl ChatCompletionBackend {
                 reason: self.policy.unsupported_schema_reason.to_string(),
             });
         }
+        let mut messages = self.messages(request)?;
+        let mut repair_attempts = self.policy.structured_output.repair_attempts();
+        loop {
+            let response = self.generate_once(request, &messages).await?;
+            let GeneratedResponse::Output { metadata, output } = response else {
+                return Ok(response);
+            };
+            if !matches!(
+                self.policy.structured_output,
+                StructuredOutputMode::Prompted { .. }
+            ) {
+                return Ok(GeneratedResponse::Output { metadata, output });
+            }
+            let output = strip_markdown_fence(&output).to_string();
+            let error = match request.schema().parse_and_validate(&output) {
+                Ok(_) => return Ok(GeneratedResponse::Output { metadata, output }),
+                Err(error) => model::ModelError::from(error),
+            };
+            if repair_attempts == 0 {
+                return Ok(GeneratedResponse::Output { metadata, output });
+            }
+            repair_attempts -= 1;
+            messages.push(ChatCompletionMessagePayload::Text {
+                content: output,
+                role: "assistant",
+            });
+            let instruction = format!(
+                "{STRUCTURED_OUTPUT_INSTRUCTION}{}",
+                request.schema().value()
+            );
+            messages.push(ChatCompletionMessagePayload::Text {
+                content: format!("{STRUCTURED_OUTPUT_REPAIR_INSTRUCTION}{error}.\n\n{instruction}"),
+                role: "user",
+            });
+        }
+    }
+
+    async fn generate_once(
+        &self,
+        request: &model::ModelRequest,
+        messages: &[ChatCompletionMessagePayload],
+    ) -> Result<GeneratedResponse, model::ModelError> {
         let payload = ChatCompletionPayload {
-            messages: self.messages(request)?,
+            messages,
             model: &self.model,
             response_format: self.response_format(request.schema()),
             tools: request
@@ -136,7 +203,7 @@ impl ChatCompletionBackend {
         let completion = self
             .client
             .complete(ChatCompletionRequest::new(
-                &self.api_key,
+                &self.auth,
                 endpoint(&self.base_url),
                 payload,
             ))
@@ -177,14 +244,14 @@ impl ChatCompletionBackend {
 
     /// Creates a structured-output backend with an injected transport client.
     pub(crate) fn with_client(
-        api_key: String,
+        auth: ChatCompletionAuth,
         base_url: String,
         model: String,
         policy: ChatCompletionProviderPolicy,
         client: Arc<dyn ChatCompletionClient>,
     ) -> Self {
         Self {
-            api_key,
+            auth,
             base_url,
             client,
             model,
@@ -197,10 +264,7 @@ impl ChatCompletionBackend {
         request: &model::ModelRequest,
     ) -> Result<Vec<ChatCompletionMessagePayload>, model::ModelError> {
         let mut messages = Vec::with_capacity(request.messages().len() + 1);
-        if matches!(
-            self.policy.structured_output,
-            StructuredOutputMode::JsonObject { .. }
-        ) {
+        if self.policy.structured_output.schema_instruction() {
             messages.push(ChatCompletionMessagePayload::Text {
                 content: format!(
                     "{STRUCTURED_OUTPUT_INSTRUCTION}{}",
@@ -217,6 +281,12 @@ impl ChatCompletionBackend {
                         role: "user",
                     });
                 }
+                model::ModelMessage::Assistant(content) => {
+                    messages.push(ChatCompletionMessagePayload::Text {
+                        content: content.clone(),
+                        role: "assistant",
+                    });
+                }
                 model::ModelMessage::AssistantToolCall(call) => {
                     messages.push(ChatCompletionMessagePayload::AssistantToolCall {
                         content: None,
@@ -261,19 +331,23 @@ impl ChatCompletionBackend {
         Ok(messages)
     }
 
-    fn response_format<'a>(&self, schema: &'a schema_contract::OutputSchema) -> ResponseFormat<'a> {
+    fn response_format<'a>(
+        &self,
+        schema: &'a schema_contract::OutputSchema,
+    ) -> Option<ResponseFormat<'a>> {
         match self.policy.structured_output {
-            StructuredOutputMode::JsonObject { .. } => ResponseFormat {
+            StructuredOutputMode::JsonObject { .. } => Some(ResponseFormat {
                 json_schema: None,
                 kind: "json_object",
-            },
-            StructuredOutputMode::JsonSchema => ResponseFormat {
+            }),
+            StructuredOutputMode::JsonSchema => Some(ResponseFormat {
                 json_schema: Some(JsonSchemaResponseFormat {
                     name: "ag_harness_output",
                     schema: schema.value(),
                 }),
                 kind: "json_schema",
-            },
+            }),
+            StructuredOutputMode::Prompted { .. } => None,
         }
     }
 
@@ -347,21 +421,15 @@ impl ChatCompletionBackend {
             schema_contract::ensure_content_size(reasoning_content)
                 .map_err(model::ModelError::from)?;
         }
-        let call = if function.name == "write" {
-            let arguments = serde_json::from_str::<tool::WriteArguments>(&function.arguments)
-                .map_err(|error| model::ModelError::InvalidToolArguments {
-                    reason: schema_contract::bounded_diagnostic(error),
-                })?;
-
-            tool::ToolCall::write(call.id, arguments, reasoning_content)
-        } else {
-            let arguments = serde_json::from_str::<tool::ReadArguments>(&function.arguments)
-                .map_err(|error| model::ModelError::InvalidToolArguments {
-                    reason: schema_contract::bounded_diagnostic(error),
-                })?;
-
-            tool::ToolCall::read(call.id, arguments, reasoning_content)
-        };
+        let call = tool::ToolCall::decode(
+            call.id,
+            &function.name,
+            &function.arguments,
+            reasoning_content,
+        )
+        .map_err(|error| model::ModelError::InvalidToolArguments {
+            reason: schema_contract::bounded_diagnostic(error),
+        })?;
 
         Ok(call)
     }
@@ -369,24 +437,24 @@ impl ChatCompletionBackend {
 
 /// One provider-authenticated request using the Chat Completions wire API.
 pub(crate) struct ChatCompletionRequest<'a> {
-    api_key: &'a str,
+    auth: &'a ChatCompletionAuth,
     endpoint: String,
     payload: Value,
 }
 
 impl<'a> ChatCompletionRequest<'a> {
     /// Creates a request from provider-owned authentication and payload data.
-    pub(crate) fn new(api_key: &'a str, endpoint: String, payload: Value) -> Self {
+    pub(crate) fn new(auth: &'a ChatCompletionAuth, endpoint: String, payload: Value) -> Self {
         Self {
-            api_key,
+            auth,
             endpoint,
             payload,
         }
     }
 
     /// Consumes the request into values usable by a client implementation.
-    pub(crate) fn into_parts(self) -> (&'a str, String, Value) {
-        (self.api_key, self.endpoint, self.payload)
+    pub(crate) fn into_parts(self) -> (&'a ChatCompletionAuth, String, Value) {
+        (self.auth, self.endpoint, self.payload)
     }
 }
 
@@ -437,6 +505,29 @@ pub(crate) trait ChatCompletionClient: Send + Sync {
     ) -> Result<Option<ChatCompletion>, ChatCompletionError>;
 }
 
+/// Returns `output` without one surrounding Markdown code fence.
+///
+/// Prompted providers are asked for bare JSON, but local models commonly
+/// wrap it in a fence anyway.
+fn strip_markdown_fence(output: &str) -> &str {
+    let trimmed = output.trim();
+    let Some(fenced) = trimmed
+        .strip_prefix("```")
+        .and_then(|fenced| fenced.strip_suffix("```"))
+    else {
+        return output;
+    };
+    let body = fenced.split_once('\n').map_or(fenced, |(language, body)| {
+        if language.trim().chars().all(char::is_alphanumeric) {
+            body
+        } else {
+            fenced
+        }
+    });
+
+    body.trim()
+}
+
 /// Builds the Chat Completions endpoint for a provider base URL.
 pub(crate) fn endpoint(base_url: &str) -> String {
     format!("{}/chat/completions", base_url.trim_end_matches('/'))
@@ -459,11 +550,14 @@ impl ChatCompletionClient for ReqwestChatCompletionClient {
         &self,
         request: ChatCompletionRequest<'_>,
     ) -> Result<Option<ChatCompletion>, ChatCompletionError> {
-        let (api_key, endpoint, payload) = request.into_parts();
-        let mut response = self
-            .client
-            .post(endpoint)
-            .bearer_auth(api_key)
+        let (auth, endpoint, payload) = request.into_parts();
+        let builder = self.client.post(endpoint);
+        let builder = match auth {
+            ChatCompletionAuth::Bearer(api_key) => builder.bearer_auth(api_key),
+            ChatCompletionAuth::Header { name, value } => builder.header(name, value),
+            ChatCompletionAuth::None => builder,
+        };
+        let mut response = builder
             .timeout(REQUEST_TIMEOUT)
             .json(&payload)
             .send()
@@ -593,9 +687,10 @@ impl ChatCompletionError {
 
 #[derive(Serialize)]
 struct ChatCompletionPayload<'a> {
-    messages: Vec<ChatCompletionMessagePayload>,
+    messages: &'a [ChatCompletionMessagePayload],
     model: &'a str,
-    response_format: ResponseFormat<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<ResponseFormat<'a>>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     tools: Vec<ChatCompletionTool<'a>>,
 }
@@ -825,7 +920,7 @@ mod tests {
     fn serializes_tool_history_for_native_json_schema_provider() {
         // Arrange
         let backend = ChatCompletionBackend::with_client(
-            "test-key".to_string(),
+            ChatCompletionAuth::Bearer("test-key".to_string()),
             "https://example.com/v1".to_string(),
             "native-schema-model".to_string(),
             ChatCompletionProviderPolicy {
@@ -1117,7 +1212,7 @@ mod tests {
     fn normalizes_transport_error_classification() {
         // Arrange
         let backend = ChatCompletionBackend::with_client(
-            "test-key".to_string(),
+            ChatCompletionAuth::Bearer("test-key".to_string()),
             "https://example.com/v1".to_string(),
             "model".to_string(),
             ChatCompletionProviderPolicy {
@@ -1174,11 +1269,9 @@ mod tests {
         let client = ReqwestChatCompletionClient {
             client: reqwest::Client::new(),
         };
-        let request = ChatCompletionRequest::new(
-            "test-key",
-            format!("http://{address}"),
-            serde_json::json!({}),
-        );
+        let auth = ChatCompletionAuth::Bearer("test-key".to_string());
+        let request =
+            ChatCompletionRequest::new(&auth, format!("http://{address}"), serde_json::json!({}));
 
         // Act
         let result = client.complete(request).await;
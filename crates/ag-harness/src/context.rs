@@ -0,0 +1,161 @@
+use serde_json::json;
+
+use crate::model::ModelMessage;
+
+const APPROXIMATE_BYTES_PER_TOKEN: usize = 4;
+
+/// Returns a provider-neutral token estimate for one request history.
+///
+/// The estimate counts message bytes at roughly four bytes per token, which is
+/// deliberately conservative for English text and code across tokenizers.
+pub(crate) fn estimate_tokens(messages: &[ModelMessage]) -> usize {
+    messages
+        .iter()
+        .map(message_bytes)
+        .sum::<usize>()
+        .div_ceil(APPROXIMATE_BYTES_PER_TOKEN)
+}
+
+/// Replaces the oldest tool results with an omission marker until the
+/// estimated history fits `max_tokens`.
+///
+/// The most recent tool result is always kept so the model can act on it.
+/// Returns the number of compacted results; the history may still exceed the
+/// budget when prompts and tool calls alone are larger than it.
+pub(crate) fn compact_tool_results(messages: &mut [ModelMessage], max_tokens: usize) -> usize {
+    let mut estimated_tokens = estimate_tokens(messages);
+    if estimated_tokens <= max_tokens {
+        return 0;
+    }
+    let Some(latest_result) = messages
+        .iter()
+        .rposition(|message| matches!(message, ModelMessage::ToolResult { .. }))
+    else {
+        return 0;
+    };
+
+    let mut compacted = 0;
+    for message in &mut messages[..latest_result] {
+        if estimated_tokens <= max_tokens {
+            break;
+        }
+        let ModelMessage::ToolResult { content, .. } = message else {
+            continue;
+        };
+        let marker = omitted_tool_result(content.len());
+        if marker.len() >= content.len() {
+            continue;
+        }
+        let saved_tokens = (content.len() - marker.len()) / APPROXIMATE_BYTES_PER_TOKEN;
+        *content = marker;
+        estimated_tokens = estimated_tokens.saturating_sub(saved_tokens);
+        compacted += 1;
+    }
+
+    compacted
+}
+
+fn omitted_tool_result(original_bytes: usize) -> String {
+    json!({
+        "omitted": true,
+        "original_bytes": original_bytes,
+        "reason": "older tool result removed to fit the context window"
+    })
+    .to_string()
+}
+
+fn message_bytes(message: &ModelMessage) -> usize {
+    match message {
+        ModelMessage::User(content) | ModelMessage::Assistant(content) => content.len(),
+        ModelMessage::AssistantToolCall(call) => {
+            call.name().len()
+                + call.arguments_json().map_or(0, |arguments| arguments.len())
+                + call.reasoning_content().map_or(0, str::len)
+        }
+        ModelMessage::ToolResult { content, name, .. } => name.len() + content.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+    use crate::tool::{ReadArguments, ToolCall};
+
+    fn tool_round(id: &str, content: &str) -> [ModelMessage; 2] {
+        let arguments = serde_json::from_value::<ReadArguments>(json!({ "path": "Cargo.toml" }))
+            .expect("read arguments should be valid");
+
+        [
+            ModelMessage::AssistantToolCall(ToolCall::read(id.to_string(), arguments, None)),
+            ModelMessage::ToolResult {
+                call_id: id.to_string(),
+                content: content.to_string(),
+                name: "read".to_string(),
+            },
+        ]
+    }
+
+    fn tool_result_content(message: &ModelMessage) -> &str {
+        match message {
+            ModelMessage::ToolResult { content, .. } => content,
+            _ => "",
+        }
+    }
+
+    #[test]
+    fn estimate_tokens_rounds_message_bytes_up() {
+        // Arrange
+        let messages = [
+            ModelMessage::User("12345".to_string()),
+            ModelMessage::Assistant("678".to_string()),
+        ];
+
+        // Act
+        let estimate = estimate_tokens(&messages);
+
+        // Assert
+        assert_eq!(estimate, 2);
+    }
+
+    #[test]
+    fn compact_tool_results_replaces_oldest_results_and_keeps_latest() {
+        // Arrange
+        let large = "x".repeat(4_000);
+        let mut messages = vec![ModelMessage::User("inspect".to_string())];
+        messages.extend(tool_round("call_1", &large));
+        messages.extend(tool_round("call_2", &large));
+        messages.extend(tool_round("call_3", &large));
+
+        // Act
+        let compacted = compact_tool_results(&mut messages, 1_500);
+
+        // Assert
+        assert_eq!(compacted, 2);
+        let first: Value =
+            serde_json::from_str(tool_result_content(&messages[2])).expect("marker should be JSON");
+        assert_eq!(first["omitted"], true);
+        assert_eq!(first["original_bytes"], 4_000);
+        assert_eq!(tool_result_content(&messages[6]), large);
+    }
+
+    #[test]
+    fn compact_tool_results_stops_once_history_fits() {
+        // Arrange
+        let large = "x".repeat(4_000);
+        let mut messages = vec![ModelMessage::User("inspect".to_string())];
+        messages.extend(tool_round("call_1", &large));
+        messages.extend(tool_round("call_2", &large));
+        messages.extend(tool_round("call_3", &large));
+
+        // Act
+        let compacted = compact_tool_results(&mut messages, 2_500);
+        let unchanged = compact_tool_results(&mut messages, usize::MAX);
+
+        // Assert
+        assert_eq!(compacted, 1);
+        assert_eq!(unchanged, 0);
+        assert_eq!(tool_result_content(&messages[4]), large);
+    }
+}
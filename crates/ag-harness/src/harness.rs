@@ -2,23 +2,27 @@ use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use serde_json::Value;
+use serde_json::{Value, json};
 use thiserror::Error;
 
+use crate::bash::{BashConfig, BashError, BashTool};
+use crate::context;
 use crate::file_system::{FileSystem, LocalFileSystem};
 use crate::lifecycle::{
     LifecycleEmitter, LifecycleId, LifecycleObserver, ToolErrorType, TurnErrorType, TurnLifecycle,
 };
-use crate::model::{Model, ModelError, ModelRequest, ModelResponse};
-use crate::policy::Policy;
+use crate::model::{Model, ModelError, ModelMessage, ModelRequest, ModelResponse};
+use crate::policy::{Policy, ToolApprover};
 use crate::read::{ReadError, ReadTool};
 use crate::schema_contract::OutputSchema;
+use crate::session::HarnessSession;
 use crate::tool::{
-    ReadArguments, Tool, ToolCall, ToolCallArguments, ToolDefinition, WriteArguments,
+    BashArguments, ReadArguments, Tool, ToolCall, ToolCallArguments, ToolDefinition, WriteArguments,
 };
 use crate::write::{WriteError, WriteTool};
 
 const DEFAULT_MAX_TOOL_CALLS: usize = 8;
+const TOOL_APPROVAL_DENIED_REASON: &str = "the tool call was not approved";
 
 /// Application-facing harness for one complete model turn.
 ///
@@ -26,6 +30,9 @@ const DEFAULT_MAX_TOOL_CALLS: usize = 8;
 /// returns tool results to the model, and finishes with locally validated
 /// structured output.
 pub struct Harness {
+    approver: Option<Arc<dyn ToolApprover>>,
+    bash_config: BashConfig,
+    context_window_tokens: Option<usize>,
     file_system: Arc<dyn FileSystem>,
     lifecycle: LifecycleEmitter,
     max_tool_calls: usize,
@@ -38,6 +45,9 @@ impl Harness {
     /// Creates a deny-by-default harness backed by the local filesystem.
     pub fn new(model: impl Model + 'static) -> Self {
         Self {
+            approver: None,
+            bash_config: BashConfig::default(),
+            context_window_tokens: None,
             file_system: Arc::new(LocalFileSystem),
             lifecycle: LifecycleEmitter::default(),
             max_tool_calls: DEFAULT_MAX_TOOL_CALLS,
@@ -63,6 +73,26 @@ impl Harness {
         self
     }
 
+    /// Replaces the timeout, environment, and output limits used by
+    /// [`Tool::Bash`].
+    #[must_use]
+    pub fn bash_config(mut self, bash_config: BashConfig) -> Self {
+        self.bash_config = bash_config;
+
+        self
+    }
+
+    /// Consults `approver` before executing each policy-allowed tool call.
+    ///
+    /// A rejected call is returned to the model as a denied tool result
+    /// instead of failing the turn.
+    #[must_use]
+    pub fn with_tool_approver(mut self, approver: impl ToolApprover + 'static) -> Self {
+        self.approver = Some(Arc::new(approver));
+
+        self
+    }
+
     /// Replaces the local filesystem implementation.
     #[must_use]
     pub fn file_system(mut self, file_system: impl FileSystem + 'static) -> Self {
@@ -89,6 +119,18 @@ impl Harness {
         self
     }
 
+    /// Bounds the estimated request history to `tokens`.
+    ///
+    /// Before each model request, the oldest tool results are replaced with a
+    /// short omission marker until the estimate fits. The most recent tool
+    /// result is always kept.
+    #[must_use]
+    pub fn context_window_tokens(mut self, tokens: NonZeroUsize) -> Self {
+        self.context_window_tokens = Some(tokens.get());
+
+        self
+    }
+
     /// Runs one prompt through tool execution to terminal structured output.
     ///
     /// # Errors
@@ -100,9 +142,43 @@ impl Harness {
         prompt: impl Into<String>,
         schema: OutputSchema,
     ) -> Result<Value, TurnError> {
+        self.run_with_history(Vec::new(), prompt.into(), schema)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// Runs one prompt as the next turn of `session`.
+    ///
+    /// The model sees the session's earlier prompts, tool calls, tool
+    /// results, and outputs. The session records the turn only when it
+    /// succeeds, so a failed turn can be retried from the same history.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TurnError`] under the same conditions as [`Self::run`].
+    pub async fn run_in_session(
+        &self,
+        session: &mut HarnessSession,
+        prompt: impl Into<String>,
+        schema: OutputSchema,
+    ) -> Result<Value, TurnError> {
+        let (output, messages) = self
+            .run_with_history(session.messages().to_vec(), prompt.into(), schema)
+            .await?;
+        session.record_turn(messages, &output);
+
+        Ok(output)
+    }
+
+    async fn run_with_history(
+        &self,
+        history: Vec<ModelMessage>,
+        prompt: String,
+        schema: OutputSchema,
+    ) -> Result<(Value, Vec<ModelMessage>), TurnError> {
         let turn = self.lifecycle.start_turn();
         let turn_id = turn.as_ref().map(TurnLifecycle::id);
-        let result = self.run_turn(prompt.into(), schema, turn_id).await;
+        let result = self.run_turn(history, prompt, schema, turn_id).await;
 
         if let Some(turn) = turn {
             match &result {
@@ -116,31 +192,29 @@ impl Harness {
 
     async fn run_turn(
         &self,
+        history: Vec<ModelMessage>,
         prompt: String,
         schema: OutputSchema,
         turn_id: Option<LifecycleId>,
-    ) -> Result<Value, TurnError> {
-        let (mut request, read_tool, write_tool) = self.prepare_request(prompt, schema)?;
+    ) -> Result<(Value, Vec<ModelMessage>), TurnError> {
+        let (mut request, tools) = self.prepare_request(history, prompt, schema)?;
         let mut completed_tool_calls = 0_usize;
         let mut model_request_index = 0_u64;
 
         loop {
+            if let Some(max_tokens) = self.context_window_tokens {
+                context::compact_tool_results(request.messages_mut(), max_tokens);
+            }
             let response = self
                 .complete_model_request(&request, model_request_index, turn_id)
                 .await?;
             model_request_index += 1;
 
             match response {
-                ModelResponse::Output(output) => return Ok(output),
+                ModelResponse::Output(output) => return Ok((output, request.into_messages())),
                 ModelResponse::ToolCall(call) => {
                     let result = self
-                        .execute_tool_call(
-                            &call,
-                            read_tool.as_ref(),
-                            write_tool.as_ref(),
-                            completed_tool_calls,
-                            turn_id,
-                        )
+                        .execute_tool_call(&call, &tools, completed_tool_calls, turn_id)
                         .await?;
                     request.record_tool_result(call, result);
                     completed_tool_calls += 1;
@@ -151,17 +225,19 @@ impl Harness {
 
     fn prepare_request(
         &self,
+        history: Vec<ModelMessage>,
         prompt: String,
         schema: OutputSchema,
-    ) -> Result<(ModelRequest, Option<ReadTool>, Option<WriteTool>), TurnError> {
-        let mut request = ModelRequest::new(prompt, schema);
+    ) -> Result<(ModelRequest, ToolSet), TurnError> {
+        let mut request = ModelRequest::with_history(history, prompt, schema);
         if self.lifecycle.is_enabled() {
             request.mark_lifecycle_observed();
         }
         let read_allowed = self.policy.allows(Tool::Read);
         let write_allowed = self.policy.allows(Tool::Write);
-        if !read_allowed && !write_allowed {
-            return Ok((request, None, None));
+        let bash_allowed = self.policy.allows(Tool::Bash);
+        if !read_allowed && !write_allowed && !bash_allowed {
+            return Ok((request, ToolSet::default()));
         }
         let repository_root = self
             .repository_root
@@ -175,8 +251,21 @@ impl Harness {
             request = request.clone().with_tool(ToolDefinition::write());
             WriteTool::new(self.file_system.clone(), repository_root.clone())
         });
+        let bash_tool = bash_allowed.then(|| {
+            request = request.clone().with_tool(ToolDefinition::bash());
+            BashTool::new(
+                self.bash_config.clone(),
+                self.file_system.clone(),
+                repository_root.clone(),
+            )
+        });
+        let tools = ToolSet {
+            bash: bash_tool,
+            read: read_tool,
+            write: write_tool,
+        };
 
-        Ok((request, read_tool, write_tool))
+        Ok((request, tools))
     }
 
     async fn complete_model_request(
@@ -212,8 +301,7 @@ impl Harness {
     async fn execute_tool_call(
         &self,
         call: &ToolCall,
-        read_tool: Option<&ReadTool>,
-        write_tool: Option<&WriteTool>,
+        tools: &ToolSet,
         completed_tool_calls: usize,
         turn_id: Option<LifecycleId>,
     ) -> Result<String, TurnError> {
@@ -221,12 +309,18 @@ impl Harness {
             .lifecycle
             .request_tool(call.name().to_string(), turn_id);
         let execution = match call.arguments() {
-            ToolCallArguments::Read(arguments) => {
-                read_tool.map(|tool| ToolExecution::Read(tool, arguments))
-            }
-            ToolCallArguments::Write(arguments) => {
-                write_tool.map(|tool| ToolExecution::Write(tool, arguments))
-            }
+            ToolCallArguments::Read(arguments) => tools
+                .read
+                .as_ref()
+                .map(|tool| ToolExecution::Read(tool, arguments)),
+            ToolCallArguments::Write(arguments) => tools
+                .write
+                .as_ref()
+                .map(|tool| ToolExecution::Write(tool, arguments)),
+            ToolCallArguments::Bash(arguments) => tools
+                .bash
+                .as_ref()
+                .map(|tool| ToolExecution::Bash(tool, arguments)),
         };
         let Some(execution) = execution else {
             if let Some(tool_lifecycle) = tool_lifecycle {
@@ -246,17 +340,35 @@ impl Harness {
                 limit: self.max_tool_calls,
             });
         }
+        if self
+            .approver
+            .as_ref()
+            .is_some_and(|approver| !approver.approve(call))
+        {
+            if let Some(tool_lifecycle) = tool_lifecycle {
+                tool_lifecycle.denied();
+            }
+
+            return Ok(denied_tool_result());
+        }
         if let Some(tool_lifecycle) = tool_lifecycle.as_mut() {
             tool_lifecycle.started();
         }
         match execute_tool(execution).await {
-            Ok(result) => {
+            Ok(ToolResult::Completed(result)) => {
                 if let Some(tool_lifecycle) = tool_lifecycle {
                     tool_lifecycle.completed();
                 }
 
                 Ok(result)
             }
+            Ok(ToolResult::TimedOut(result)) => {
+                if let Some(tool_lifecycle) = tool_lifecycle {
+                    tool_lifecycle.failed(ToolErrorType::Timeout);
+                }
+
+                Ok(result)
+            }
             Err(error) => {
                 if let Some(tool_lifecycle) = tool_lifecycle {
                     tool_lifecycle.failed(ToolErrorType::Execution);
@@ -289,6 +401,9 @@ pub enum TurnError {
     /// A repository write failed.
     #[error(transparent)]
     Write(#[from] WriteError),
+    /// A shell command could not be executed.
+    #[error(transparent)]
+    Bash(#[from] BashError),
     /// The model exceeded the bounded number of calls in one turn.
     #[error("model exceeded the per-turn tool call limit of {limit}")]
     ToolCallLimit {
@@ -303,33 +418,67 @@ impl TurnError {
         match self {
             Self::Model(error) => TurnErrorType::Model(error.error_type()),
             Self::ToolDenied { .. } => TurnErrorType::ToolDenied,
-            Self::Read(_) | Self::Write(_) => TurnErrorType::Tool,
+            Self::Read(_) | Self::Write(_) | Self::Bash(_) => TurnErrorType::Tool,
             Self::RepositoryRequired => TurnErrorType::RepositoryRequired,
             Self::ToolCallLimit { .. } => TurnErrorType::ToolCallLimit,
         }
     }
 }
 
+/// Repository-scoped tools enabled for one turn.
+#[derive(Default)]
+struct ToolSet {
+    bash: Option<BashTool>,
+    read: Option<ReadTool>,
+    write: Option<WriteTool>,
+}
+
 enum ToolExecution<'a> {
+    Bash(&'a BashTool, &'a BashArguments),
     Read(&'a ReadTool, &'a ReadArguments),
     Write(&'a WriteTool, &'a WriteArguments),
 }
 
-async fn execute_tool(execution: ToolExecution<'_>) -> Result<String, TurnError> {
+/// Encoded tool result returned to the model.
+enum ToolResult {
+    Completed(String),
+    /// The tool reached its timeout; the partial result still reaches the
+    /// model so it can retry with a narrower command.
+    TimedOut(String),
+}
+
+fn denied_tool_result() -> String {
+    json!({ "denied": true, "reason": TOOL_APPROVAL_DENIED_REASON }).to_string()
+}
+
+async fn execute_tool(execution: ToolExecution<'_>) -> Result<ToolResult, TurnError> {
     match execution {
+        ToolExecution::Bash(bash_tool, arguments) => {
+            let output = bash_tool.execute(arguments).await?;
+            let result = output.to_tool_result().map_err(BashError::from)?;
+
+            Ok(if output.timed_out() {
+                ToolResult::TimedOut(result)
+            } else {
+                ToolResult::Completed(result)
+            })
+        }
         ToolExecution::Read(read_tool, arguments) => read_tool
             .execute(arguments)
             .await?
             .to_tool_result()
+            .map(ToolResult::Completed)
             .map_err(ReadError::from)
             .map_err(TurnError::from),
         ToolExecution::Write(write_tool, arguments) => match write_tool.execute(arguments).await {
             Ok(output) => output
                 .to_tool_result()
+                .map(ToolResult::Completed)
                 .map_err(WriteError::from)
                 .map_err(TurnError::from),
             Err(error) if error.is_model_correctable() => error
                 .to_tool_result(arguments.path())
+                .map(ToolResult::Completed)
                 .map_err(WriteError::from)
                 .map_err(TurnError::from),
             Err(error) => Err(error.into()),
@@ -386,6 +535,13 @@ mod tests {
         ToolCall::write(id.to_string(), arguments, None)
     }
 
+    fn bash_call(id: &str, arguments: Value) -> ToolCall {
+        let arguments = serde_json::from_value::<BashArguments>(arguments)
+            .expect("bash arguments should be valid");
+
+        ToolCall::bash(id.to_string(), arguments, None)
+    }
+
     fn readable_file_system() -> MockFileSystem {
         let mut file_system = MockFileSystem::new();
         let mut sequence = Sequence::new();
@@ -808,6 +964,315 @@ mod tests {
         assert_eq!(error.error_type(), TurnErrorType::Tool);
     }
 
+    #[tokio::test]
+    async fn completes_bash_tool_round_trip() {
+        // Arrange
+        let repository = tempfile::tempdir().expect("repository fixture should be created");
+        let mut model = MockModel::new();
+        let call_count = Arc::new(AtomicUsize::new(0));
+        model
+            .expect_complete_with_optional_metadata()
+            .times(2)
+            .returning(move |request| {
+                if call_count.fetch_add(1, Ordering::SeqCst) == 0 {
+                    assert_eq!(request.tools(), &[ToolDefinition::bash()]);
+
+                    return Ok(response_without_metadata(ModelResponse::ToolCall(
+                        bash_call("call_bash", json!({ "command": "echo ok; exit 1" })),
+                    )));
+                }
+                assert!(matches!(
+                    &request.messages()[2],
+                    ModelMessage::ToolResult { content, name, .. }
+                        if name == "bash"
+                            && serde_json::from_str::<Value>(content).is_ok_and(|value| {
+                                value["stdout"] == "ok\n" && value["exit_code"] == 1
+                            })
+                ));
+
+                Ok(response_without_metadata(ModelResponse::Output(
+                    json!({ "summary": "failed" }),
+                )))
+            });
+        let harness = Harness::new(model)
+            .repository(repository.path())
+            .allow(Tool::Bash);
+
+        // Act
+        let output = harness
+            .run("run the check", object_schema())
+            .await
+            .expect("bash round trip should succeed");
+
+        // Assert
+        assert_eq!(output, json!({ "summary": "failed" }));
+    }
+
+    #[tokio::test]
+    async fn reports_bash_timeout_to_lifecycle_and_model() {
+        // Arrange
+        let repository = tempfile::tempdir().expect("repository fixture should be created");
+        let mut model = MockModel::new();
+        let call_count = Arc::new(AtomicUsize::new(0));
+        model
+            .expect_complete_with_optional_metadata()
+            .times(2)
+            .returning(move |request| {
+                if call_count.fetch_add(1, Ordering::SeqCst) == 0 {
+                    return Ok(response_without_metadata(ModelResponse::ToolCall(
+                        bash_call("call_bash", json!({ "command": "sleep 30" })),
+                    )));
+                }
+                assert!(matches!(
+                    &request.messages()[2],
+                    ModelMessage::ToolResult { content, .. }
+                        if serde_json::from_str::<Value>(content)
+                            .is_ok_and(|value| value["timed_out"] == true)
+                ));
+
+                Ok(response_without_metadata(ModelResponse::Output(
+                    json!({ "summary": "timed out" }),
+                )))
+            });
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let observed_events = Arc::clone(&events);
+        let harness = Harness::new(model)
+            .repository(repository.path())
+            .allow(Tool::Bash)
+            .bash_config(BashConfig {
+                default_timeout: std::time::Duration::from_millis(200),
+                ..BashConfig::default()
+            })
+            .with_lifecycle_observer(move |event| {
+                observed_events
+                    .lock()
+                    .expect("event recorder should not be poisoned")
+                    .push(event);
+            });
+
+        // Act
+        let output = harness
+            .run("run the check", object_schema())
+            .await
+            .expect("timed out command should not fail the turn");
+
+        // Assert
+        assert_eq!(output, json!({ "summary": "timed out" }));
+        let events = events
+            .lock()
+            .expect("event recorder should not be poisoned");
+        assert!(events.iter().any(|event| matches!(
+            event.kind(),
+            crate::LifecycleEventKind::ToolFailed {
+                error_type: ToolErrorType::Timeout,
+                ..
+            }
+        )));
+    }
+
+    #[tokio::test]
+    async fn returns_unapproved_tool_call_to_model_as_denied() {
+        // Arrange
+        let mut model = MockModel::new();
+        let call_count = Arc::new(AtomicUsize::new(0));
+        model
+            .expect_complete_with_optional_metadata()
+            .times(2)
+            .returning(move |request| {
+                if call_count.fetch_add(1, Ordering::SeqCst) == 0 {
+                    return Ok(response_without_metadata(ModelResponse::ToolCall(
+                        bash_call("call_bash", json!({ "command": "rm -rf target" })),
+                    )));
+                }
+                assert!(matches!(
+                    &request.messages()[2],
+                    ModelMessage::ToolResult { content, .. }
+                        if serde_json::from_str::<Value>(content)
+                            .is_ok_and(|value| value["denied"] == true)
+                ));
+
+                Ok(response_without_metadata(ModelResponse::Output(
+                    json!({ "summary": "denied" }),
+                )))
+            });
+        let mut file_system = MockFileSystem::new();
+        file_system.expect_canonicalize().times(0);
+        let approved_commands = Arc::new(Mutex::new(Vec::new()));
+        let observed_commands = Arc::clone(&approved_commands);
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let observed_events = Arc::clone(&events);
+        let harness = Harness::new(model)
+            .file_system(file_system)
+            .repository("repo")
+            .allow(Tool::Bash)
+            .with_tool_approver(move |call: &ToolCall| {
+                if let Some(arguments) = call.bash_arguments() {
+                    observed_commands
+                        .lock()
+                        .expect("command recorder should not be poisoned")
+                        .push(arguments.command().to_string());
+                }
+
+                false
+            })
+            .with_lifecycle_observer(move |event| {
+                observed_events
+                    .lock()
+                    .expect("event recorder should not be poisoned")
+                    .push(event);
+            });
+
+        // Act
+        let output = harness
+            .run("clean up", object_schema())
+            .await
+            .expect("unapproved call should not fail the turn");
+
+        // Assert
+        assert_eq!(output, json!({ "summary": "denied" }));
+        assert_eq!(
+            *approved_commands
+                .lock()
+                .expect("command recorder should not be poisoned"),
+            vec!["rm -rf target".to_string()]
+        );
+        let events = events
+            .lock()
+            .expect("event recorder should not be poisoned");
+        assert!(
+            events
+                .iter()
+                .any(|event| matches!(event.kind(), crate::LifecycleEventKind::ToolDenied { .. }))
+        );
+    }
+
+    #[tokio::test]
+    async fn session_turns_continue_from_reloaded_history() {
+        // Arrange
+        let mut model = MockModel::new();
+        let call_count = Arc::new(AtomicUsize::new(0));
+        model
+            .expect_complete_with_optional_metadata()
+            .times(3)
+            .returning(move |request| match call_count.fetch_add(1, Ordering::SeqCst) {
+                0 => Ok(response_without_metadata(ModelResponse::ToolCall(
+                    read_call("call_read"),
+                ))),
+                1 => Ok(response_without_metadata(ModelResponse::Output(
+                    json!({ "summary": "workspace" }),
+                ))),
+                _ => {
+                    assert_eq!(request.messages().len(), 5);
+                    assert!(matches!(
+                        &request.messages()[2],
+                        ModelMessage::ToolResult { call_id, .. } if call_id == "call_read"
+                    ));
+                    assert!(matches!(
+                        &request.messages()[3],
+                        ModelMessage::Assistant(output) if output == r#"{"summary":"workspace"}"#
+                    ));
+                    assert!(matches!(
+                        &request.messages()[4],
+                        ModelMessage::User(prompt) if prompt == "summarize again"
+                    ));
+
+                    Ok(response_without_metadata(ModelResponse::Output(
+                        json!({ "summary": "still workspace" }),
+                    )))
+                }
+            });
+        let harness = Harness::new(model)
+            .file_system(readable_file_system())
+            .repository("repo")
+            .allow(Tool::Read);
+        let mut session = HarnessSession::new();
+        harness
+            .run_in_session(&mut session, "inspect the manifest", object_schema())
+            .await
+            .expect("first session turn should succeed");
+        let document = session.to_json().expect("session should encode");
+        let mut reloaded = HarnessSession::from_json(&document).expect("session should decode");
+
+        // Act
+        let output = harness
+            .run_in_session(&mut reloaded, "summarize again", object_schema())
+            .await
+            .expect("second session turn should succeed");
+
+        // Assert
+        assert_eq!(output, json!({ "summary": "still workspace" }));
+        assert_eq!(reloaded.turn_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn failed_session_turn_leaves_history_unchanged() {
+        // Arrange
+        let mut model = MockModel::new();
+        model
+            .expect_complete_with_optional_metadata()
+            .times(1)
+            .returning(|_| {
+                Ok(response_without_metadata(ModelResponse::ToolCall(
+                    read_call("call_denied"),
+                )))
+            });
+        let harness = Harness::new(model);
+        let mut session = HarnessSession::new();
+
+        // Act
+        let error = harness
+            .run_in_session(&mut session, "inspect", object_schema())
+            .await
+            .expect_err("denied tool should fail the turn");
+
+        // Assert
+        assert!(matches!(error, TurnError::ToolDenied { .. }));
+        assert!(session.is_empty());
+    }
+
+    #[tokio::test]
+    async fn compacts_older_tool_results_to_context_window() {
+        // Arrange
+        let mut model = MockModel::new();
+        let call_count = Arc::new(AtomicUsize::new(0));
+        model
+            .expect_complete_with_optional_metadata()
+            .times(3)
+            .returning(move |request| {
+                if call_count.fetch_add(1, Ordering::SeqCst) < 2 {
+                    return Ok(response_without_metadata(ModelResponse::ToolCall(
+                        bash_call("call_bash", json!({ "command": "printf '%0500d' 0" })),
+                    )));
+                }
+                assert!(matches!(
+                    &request.messages()[2],
+                    ModelMessage::ToolResult { content, .. } if content.contains("\"omitted\":true")
+                ));
+                assert!(matches!(
+                    &request.messages()[4],
+                    ModelMessage::ToolResult { content, .. } if content.contains("00000")
+                ));
+
+                Ok(response_without_metadata(ModelResponse::Output(
+                    json!({ "summary": "compacted" }),
+                )))
+            });
+        let repository = tempfile::tempdir().expect("repository fixture should be created");
+        let harness = Harness::new(model)
+            .repository(repository.path())
+            .allow(Tool::Bash)
+            .context_window_tokens(NonZeroUsize::new(200).expect("budget should be non-zero"));
+
+        // Act
+        let output = harness
+            .run("inspect", object_schema())
+            .await
+            .expect("compacted turn should succeed");
+
+        // Assert
+        assert_eq!(output, json!({ "summary": "compacted" }));
+    }
+
     #[tokio::test]
     async fn rejects_write_call_when_policy_denies_write() {
         // Arrange
@@ -1,11 +1,13 @@
 //! Lightweight, Rust-native LLM harness for application-facing agent workflows.
 //!
 //! The crate provides a provider-neutral model loop, normalized completion
-//! metadata, validated structured output, and deny-by-default repository read
-//! and patch tools. Provider and local filesystem implementations remain
-//! behind injectable boundaries.
+//! metadata, validated structured output, and deny-by-default repository read,
+//! patch, and bounded shell tools. Provider and local filesystem
+//! implementations remain behind injectable boundaries.
 
+mod bash;
 mod chat_completion;
+mod context;
 mod file_system;
 mod harness;
 mod lifecycle;
@@ -14,10 +16,13 @@ mod policy;
 mod provider;
 mod read;
 mod schema_contract;
+mod session;
 mod telemetry;
 mod tool;
+mod trace;
 mod write;
 
+pub use bash::{BashConfig, BashError, BashOutput};
 pub use file_system::{FileSystem, LocalFileSystem};
 pub use harness::{Harness, TurnError};
 pub use lifecycle::{
@@ -29,11 +34,17 @@ pub use model::{
     ModelErrorType, ModelMetadata, ModelMetadataError, ModelRequest, ModelResponse,
     ModelWithMetadata,
 };
+pub use policy::ToolApprover;
 pub use provider::{
-    KimiConfig, MUSE_SPARK_1_2, MUSE_SPARK_1_2_CONTRIBUTOR, Muse, MuseConfig, MuseError, QwenConfig,
+    KimiConfig, MUSE_SPARK_1_2, MUSE_SPARK_1_2_CONTRIBUTOR, Muse, MuseConfig, MuseError,
+    OpenAiCompatibleAuth, OpenAiCompatibleConfig, QwenConfig, StructuredOutputCapability,
 };
 pub use read::{ReadError, ReadOutput};
 pub use schema_contract::{OutputSchema, OutputSchemaError};
+pub use session::{HarnessSession, SessionError};
 pub use telemetry::LifecycleMetrics;
-pub use tool::{ReadArguments, Tool, ToolCall, ToolCallArguments, ToolDefinition, WriteArguments};
+pub use tool::{
+    BashArguments, ReadArguments, Tool, ToolCall, ToolCallArguments, ToolDefinition, WriteArguments,
+};
+pub use trace::LifecycleTraces;
 pub use write::{WriteError, WriteOutput};
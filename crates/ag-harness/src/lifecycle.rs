@@ -205,6 +205,8 @@ pub enum ToolErrorType {
     CallLimit,
     /// The allowed tool failed while executing or encoding its result.
     Execution,
+    /// The allowed tool exceeded its execution timeout.
+    Timeout,
 }
 
 impl ToolErrorType {
@@ -214,6 +216,7 @@ impl ToolErrorType {
             Self::Cancelled => crate::telemetry::ERROR_CANCELLED,
             Self::CallLimit => crate::telemetry::ERROR_TOOL_CALL_LIMIT,
             Self::Execution => crate::telemetry::ERROR_TOOL_EXECUTION,
+            Self::Timeout => crate::telemetry::ERROR_TOOL_TIMEOUT,
         }
     }
 }
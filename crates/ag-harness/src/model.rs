@@ -5,7 +5,7 @@ use serde_json::Value;
 use thiserror::Error;
 
 use crate::lifecycle::{LifecycleEmitter, LifecycleObserver, ModelResponseType};
-use crate::provider::{self, KimiConfig, MuseConfig, QwenConfig};
+use crate::provider::{self, KimiConfig, MuseConfig, OpenAiCompatibleConfig, QwenConfig};
 use crate::schema_contract::{OutputSchema, OutputValidationError};
 use crate::{chat_completion, telemetry, tool};
 
@@ -100,7 +100,7 @@ impl ModelClient {
     /// empty or contains only whitespace.
     pub fn kimi(config: KimiConfig) -> Result<Self, ModelMetadataError> {
         Self::chat_completion(
-            config.api_key,
+            chat_completion::ChatCompletionAuth::Bearer(config.api_key),
             config.base_url,
             config.model,
             provider::KIMI_POLICY,
@@ -115,13 +115,29 @@ impl ModelClient {
     /// empty or contains only whitespace.
     pub fn muse(config: MuseConfig) -> Result<Self, ModelMetadataError> {
         Self::chat_completion(
-            config.api_key,
+            chat_completion::ChatCompletionAuth::Bearer(config.api_key),
             config.base_url,
             config.model,
             provider::MUSE_POLICY,
         )
     }
 
+    /// Creates a client backed by any OpenAI-compatible Chat Completions
+    /// endpoint, such as a local llama.cpp, vLLM, or Ollama server.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelMetadataError`] when the configured model identifier is
+    /// empty or contains only whitespace.
+    pub fn openai_compatible(config: OpenAiCompatibleConfig) -> Result<Self, ModelMetadataError> {
+        Self::chat_completion(
+            config.auth.into(),
+            config.base_url,
+            config.model,
+            provider::openai_compatible_policy(config.structured_output),
+        )
+    }
+
     /// Creates a client backed by Alibaba Cloud Model Studio's Qwen API.
     ///
     /// # Errors
@@ -130,7 +146,7 @@ impl ModelClient {
     /// empty or contains only whitespace.
     pub fn qwen(config: QwenConfig) -> Result<Self, ModelMetadataError> {
         Self::chat_completion(
-            config.api_key,
+            chat_completion::ChatCompletionAuth::Bearer(config.api_key),
             config.base_url,
             config.model,
             provider::QWEN_POLICY,
@@ -226,12 +242,12 @@ impl ModelClient {
     }
 
     fn chat_completion(
-        api_key: String,
+        auth: chat_completion::ChatCompletionAuth,
         base_url: String,
         model: String,
         policy: chat_completion::ChatCompletionProviderPolicy,
     ) -> Result<Self, ModelMetadataError> {
-        let backend = chat_completion::ChatCompletionBackend::new(api_key, base_url, model, policy);
+        let backend = chat_completion::ChatCompletionBackend::new(auth, base_url, model, policy);
         let (provider, model) = backend.identity();
         let metadata = ModelMetadata::new(provider, model)?;
 
@@ -317,11 +333,21 @@ pub struct ModelRequest {
 impl ModelRequest {
     /// Creates a model request whose response must match `schema`.
     pub fn new(prompt: impl Into<String>, schema: OutputSchema) -> Self {
+        Self::with_history(Vec::new(), prompt, schema)
+    }
+
+    /// Creates a request that continues `history` with one new user prompt.
+    pub(crate) fn with_history(
+        mut history: Vec<ModelMessage>,
+        prompt: impl Into<String>,
+        schema: OutputSchema,
+    ) -> Self {
         let prompt = prompt.into();
+        history.push(ModelMessage::User(prompt.clone()));
 
         Self {
             lifecycle_observed: false,
-            messages: vec![ModelMessage::User(prompt.clone())],
+            messages: history,
             prompt,
             schema,
             tools: Vec::new(),
@@ -361,6 +387,14 @@ impl ModelRequest {
         &self.messages
     }
 
+    pub(crate) fn messages_mut(&mut self) -> &mut [ModelMessage] {
+        &mut self.messages
+    }
+
+    pub(crate) fn into_messages(self) -> Vec<ModelMessage> {
+        self.messages
+    }
+
     pub(crate) fn lifecycle_observed(&self) -> bool {
         self.lifecycle_observed
     }
@@ -384,6 +418,8 @@ impl ModelRequest {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) enum ModelMessage {
     User(String),
+    /// Terminal structured output from an earlier session turn.
+    Assistant(String),
     AssistantToolCall(tool::ToolCall),
     ToolResult {
         call_id: String,
@@ -1,8 +1,9 @@
-use crate::tool::Tool;
+use crate::tool::{Tool, ToolCall};
 
 /// Default-deny permissions for built-in harness tools.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub(crate) struct Policy {
+    bash: bool,
     read: bool,
     write: bool,
 }
@@ -10,6 +11,7 @@ pub(crate) struct Policy {
 impl Policy {
     pub(crate) fn allow(&mut self, tool: Tool) {
         match tool {
+            Tool::Bash => self.bash = true,
             Tool::Read => self.read = true,
             Tool::Write => self.write = true,
         }
@@ -17,12 +19,32 @@ impl Policy {
 
     pub(crate) fn allows(self, tool: Tool) -> bool {
         match tool {
+            Tool::Bash => self.bash,
             Tool::Read => self.read,
             Tool::Write => self.write,
         }
     }
 }
 
+/// Per-call approval hook consulted after the static tool policy allows a
+/// call and before the harness executes it.
+///
+/// A rejected call is reported to the model as a denied tool result so the
+/// turn can continue with a different approach.
+pub trait ToolApprover: Send + Sync {
+    /// Returns whether the decoded tool call may run.
+    fn approve(&self, call: &ToolCall) -> bool;
+}
+
+impl<F> ToolApprover for F
+where
+    F: Fn(&ToolCall) -> bool + Send + Sync,
+{
+    fn approve(&self, call: &ToolCall) -> bool {
+        self(call)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -33,14 +55,18 @@ mod tests {
         let mut policy = Policy::default();
 
         // Act
+        let bash_denied_by_default = policy.allows(Tool::Bash);
         let read_denied_by_default = policy.allows(Tool::Read);
         let write_denied_by_default = policy.allows(Tool::Write);
+        policy.allow(Tool::Bash);
         policy.allow(Tool::Read);
         policy.allow(Tool::Write);
 
         // Assert
+        assert!(!bash_denied_by_default);
         assert!(!read_denied_by_default);
         assert!(!write_denied_by_default);
+        assert!(policy.allows(Tool::Bash));
         assert!(policy.allows(Tool::Read));
         assert!(policy.allows(Tool::Write));
     }
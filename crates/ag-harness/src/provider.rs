@@ -2,11 +2,16 @@
 
 mod kimi;
 mod muse;
+mod openai_compatible;
 mod qwen;
 
 pub use kimi::KimiConfig;
 pub(crate) use kimi::POLICY as KIMI_POLICY;
 pub(crate) use muse::POLICY as MUSE_POLICY;
 pub use muse::{MUSE_SPARK_1_2, MUSE_SPARK_1_2_CONTRIBUTOR, Muse, MuseConfig, MuseError};
+pub(crate) use openai_compatible::policy as openai_compatible_policy;
+pub use openai_compatible::{
+    OpenAiCompatibleAuth, OpenAiCompatibleConfig, StructuredOutputCapability,
+};
 pub(crate) use qwen::POLICY as QWEN_POLICY;
 pub use qwen::QwenConfig;
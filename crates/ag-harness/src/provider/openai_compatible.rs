@@ -0,0 +1,365 @@
+use crate::{chat_completion, telemetry};
+
+const DISPLAY_NAME: &str = "OpenAI-compatible endpoint";
+const UNSUPPORTED_SCHEMA_REASON: &str =
+    "OpenAI-compatible structured output requires an explicit object root schema";
+
+/// Configuration for a model served by any OpenAI-compatible Chat
+/// Completions endpoint, such as a llama.cpp server, vLLM, or Ollama's `/v1`
+/// API.
+pub struct OpenAiCompatibleConfig {
+    /// Authentication attached to every request.
+    pub auth: OpenAiCompatibleAuth,
+    /// API base URL ending in the OpenAI-compatible version path.
+    pub base_url: String,
+    /// Model identifier sent with each request.
+    pub model: String,
+    /// Structured-output support declared for the served model.
+    pub structured_output: StructuredOutputCapability,
+}
+
+/// Authentication scheme used by an OpenAI-compatible endpoint.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OpenAiCompatibleAuth {
+    /// Sends no authentication, as most local inference servers expect.
+    None,
+    /// Sends the key as an `Authorization: Bearer` token.
+    Bearer(String),
+    /// Sends the value in a custom header such as `api-key`.
+    Header {
+        /// Header name.
+        name: String,
+        /// Header value.
+        value: String,
+    },
+}
+
+/// Structured-output support declared by an OpenAI-compatible endpoint.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StructuredOutputCapability {
+    /// The endpoint enforces `response_format` of type `json_schema`.
+    JsonSchema,
+    /// The endpoint only enforces `response_format` of type `json_object`;
+    /// the schema is sent as a system instruction.
+    JsonObject,
+    /// The endpoint has no structured-output mode; the schema is sent as a
+    /// system instruction and invalid replies are returned to the model with
+    /// their validation error up to `repair_attempts` times.
+    Prompted {
+        /// Maximum number of repair requests after the first reply.
+        repair_attempts: u8,
+    },
+}
+
+impl From<OpenAiCompatibleAuth> for chat_completion::ChatCompletionAuth {
+    fn from(auth: OpenAiCompatibleAuth) -> Self {
+        match auth {
+            OpenAiCompatibleAuth::None => Self::None,
+            OpenAiCompatibleAuth::Bearer(api_key) => Self::Bearer(api_key),
+            OpenAiCompatibleAuth::Header { name, value } => Self::Header { name, value },
+        }
+    }
+}
+
+/// Returns the shared backend policy for one declared capability.
+pub(crate) fn policy(
+    capability: StructuredOutputCapability,
+) -> chat_completion::ChatCompletionProviderPolicy {
+    let structured_output = match capability {
+        StructuredOutputCapability::JsonSchema => chat_completion::StructuredOutputMode::JsonSchema,
+        StructuredOutputCapability::JsonObject => {
+            chat_completion::StructuredOutputMode::JsonObject {
+                assistant_reasoning_content: false,
+                tool_result_name: false,
+            }
+        }
+        StructuredOutputCapability::Prompted { repair_attempts } => {
+            chat_completion::StructuredOutputMode::Prompted { repair_attempts }
+        }
+    };
+
+    chat_completion::ChatCompletionProviderPolicy {
+        display_name: DISPLAY_NAME,
+        structured_output,
+        telemetry_name: telemetry::PROVIDER_OPENAI_COMPATIBLE,
+        unsupported_schema_reason: UNSUPPORTED_SCHEMA_REASON,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+    use wiremock::matchers::{bearer_token, body_json, body_string_contains, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::chat_completion::{
+        STRUCTURED_OUTPUT_INSTRUCTION, STRUCTURED_OUTPUT_REPAIR_INSTRUCTION,
+    };
+    use crate::model;
+
+    fn person_schema_value() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" }
+            },
+            "required": ["name"],
+            "additionalProperties": false
+        })
+    }
+
+    fn request(prompt: &str) -> model::ModelRequest {
+        model::ModelRequest::new(
+            prompt,
+            crate::OutputSchema::new(person_schema_value()).expect("schema should be valid"),
+        )
+    }
+
+    fn schema_instruction() -> String {
+        format!("{STRUCTURED_OUTPUT_INSTRUCTION}{}", person_schema_value())
+    }
+
+    fn local_model(
+        server: &MockServer,
+        auth: OpenAiCompatibleAuth,
+        structured_output: StructuredOutputCapability,
+    ) -> model::ModelClient {
+        model::ModelClient::openai_compatible(OpenAiCompatibleConfig {
+            auth,
+            base_url: format!("{}/v1/", server.uri()),
+            model: "llama-3.3-8b-instruct".to_string(),
+            structured_output,
+        })
+        .expect("fixture configuration should be valid")
+    }
+
+    fn stop_response(content: &str) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{
+                "finish_reason": "stop",
+                "message": {"content": content}
+            }]
+        }))
+    }
+
+    #[test]
+    fn metadata_reports_openai_compatible_provider() {
+        // Arrange
+        let config = OpenAiCompatibleConfig {
+            auth: OpenAiCompatibleAuth::None,
+            base_url: "http://127.0.0.1:8080/v1".to_string(),
+            model: "qwen2.5-coder:7b".to_string(),
+            structured_output: StructuredOutputCapability::JsonObject,
+        };
+
+        // Act
+        let client =
+            model::ModelClient::openai_compatible(config).expect("configuration should be valid");
+
+        // Assert
+        assert_eq!(client.metadata().provider(), "openai_compatible");
+        assert_eq!(client.metadata().model(), "qwen2.5-coder:7b");
+    }
+
+    #[test]
+    fn rejects_empty_model_during_construction() {
+        // Arrange
+        let config = OpenAiCompatibleConfig {
+            auth: OpenAiCompatibleAuth::None,
+            base_url: "http://127.0.0.1:8080/v1".to_string(),
+            model: " ".to_string(),
+            structured_output: StructuredOutputCapability::JsonSchema,
+        };
+
+        // Act
+        let error = model::ModelClient::openai_compatible(config)
+            .err()
+            .expect("empty model configuration should be rejected");
+
+        // Assert
+        assert_eq!(error, model::ModelMetadataError::EmptyModel);
+    }
+
+    #[tokio::test]
+    async fn sends_native_json_schema_without_authentication() {
+        // Arrange
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(body_json(json!({
+                "messages": [{"content": "extract the name", "role": "user"}],
+                "model": "llama-3.3-8b-instruct",
+                "response_format": {
+                    "type": "json_schema",
+                    "json_schema": {
+                        "name": "ag_harness_output",
+                        "schema": person_schema_value()
+                    }
+                }
+            })))
+            .respond_with(stop_response(r#"{"name":"Ada"}"#))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let model = local_model(
+            &server,
+            OpenAiCompatibleAuth::None,
+            StructuredOutputCapability::JsonSchema,
+        );
+
+        // Act
+        let response = model
+            .complete(request("extract the name"))
+            .await
+            .expect("local request should succeed");
+
+        // Assert
+        assert_eq!(response.output(), Some(&json!({ "name": "Ada" })));
+        let received = server
+            .received_requests()
+            .await
+            .expect("request recording should be enabled");
+        assert!(!received[0].headers.contains_key("authorization"));
+    }
+
+    #[tokio::test]
+    async fn sends_custom_auth_header_with_json_object_instruction() {
+        // Arrange
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(header("api-key", "local-secret"))
+            .and(body_json(json!({
+                "messages": [
+                    {"content": schema_instruction(), "role": "system"},
+                    {"content": "extract the name", "role": "user"}
+                ],
+                "model": "llama-3.3-8b-instruct",
+                "response_format": {"type": "json_object"}
+            })))
+            .respond_with(stop_response(r#"{"name":"Ada"}"#))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let model = local_model(
+            &server,
+            OpenAiCompatibleAuth::Header {
+                name: "api-key".to_string(),
+                value: "local-secret".to_string(),
+            },
+            StructuredOutputCapability::JsonObject,
+        );
+
+        // Act
+        let response = model
+            .complete(request("extract the name"))
+            .await
+            .expect("header-authenticated request should succeed");
+
+        // Assert
+        assert_eq!(response.output(), Some(&json!({ "name": "Ada" })));
+    }
+
+    #[tokio::test]
+    async fn prompted_output_strips_markdown_fence() {
+        // Arrange
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(bearer_token("test-key"))
+            .and(body_json(json!({
+                "messages": [
+                    {"content": schema_instruction(), "role": "system"},
+                    {"content": "extract the name", "role": "user"}
+                ],
+                "model": "llama-3.3-8b-instruct"
+            })))
+            .respond_with(stop_response("```json\n{\"name\":\"Ada\"}\n```"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let model = local_model(
+            &server,
+            OpenAiCompatibleAuth::Bearer("test-key".to_string()),
+            StructuredOutputCapability::Prompted { repair_attempts: 0 },
+        );
+
+        // Act
+        let response = model
+            .complete(request("extract the name"))
+            .await
+            .expect("fenced output should validate");
+
+        // Assert
+        assert_eq!(response.output(), Some(&json!({ "name": "Ada" })));
+    }
+
+    #[tokio::test]
+    async fn prompted_output_repairs_invalid_reply() {
+        // Arrange
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(body_json(json!({
+                "messages": [
+                    {"content": schema_instruction(), "role": "system"},
+                    {"content": "extract the name", "role": "user"}
+                ],
+                "model": "llama-3.3-8b-instruct"
+            })))
+            .respond_with(stop_response(r#"{"name":42}"#))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(body_string_contains(STRUCTURED_OUTPUT_REPAIR_INSTRUCTION))
+            .and(body_string_contains(r#"{\"name\":42}"#))
+            .and(body_string_contains(r".\n\nReturn only one JSON object."))
+            .respond_with(stop_response(r#"{"name":"Ada"}"#))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let model = local_model(
+            &server,
+            OpenAiCompatibleAuth::None,
+            StructuredOutputCapability::Prompted { repair_attempts: 1 },
+        );
+
+        // Act
+        let response = model
+            .complete(request("extract the name"))
+            .await
+            .expect("repaired output should validate");
+
+        // Assert
+        assert_eq!(response.output(), Some(&json!({ "name": "Ada" })));
+    }
+
+    #[tokio::test]
+    async fn prompted_output_fails_after_repair_budget() {
+        // Arrange
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .respond_with(stop_response("The name is Ada."))
+            .expect(3)
+            .mount(&server)
+            .await;
+        let model = local_model(
+            &server,
+            OpenAiCompatibleAuth::None,
+            StructuredOutputCapability::Prompted { repair_attempts: 2 },
+        );
+
+        // Act
+        let error = model
+            .complete(request("extract the name"))
+            .await
+            .expect_err("unrepaired output should fail");
+
+        // Assert
+        assert!(matches!(error, model::ModelError::InvalidJson { .. }));
+    }
+}
@@ -33,9 +33,9 @@ mod tests {
 
     use super::*;
     use crate::chat_completion::{
-        ChatCompletion, ChatCompletionBackend, ChatCompletionClient, ChatCompletionError,
-        ChatCompletionRequest, ERROR_BODY_LIMIT_BYTES, RESPONSE_ENVELOPE_LIMIT_BYTES,
-        STRUCTURED_OUTPUT_INSTRUCTION, SUCCESS_BODY_LIMIT_BYTES,
+        ChatCompletion, ChatCompletionAuth, ChatCompletionBackend, ChatCompletionClient,
+        ChatCompletionError, ChatCompletionRequest, ERROR_BODY_LIMIT_BYTES,
+        RESPONSE_ENVELOPE_LIMIT_BYTES, STRUCTURED_OUTPUT_INSTRUCTION, SUCCESS_BODY_LIMIT_BYTES,
     };
     use crate::{model, schema_contract, tool};
 
@@ -47,8 +47,8 @@ mod tests {
             &self,
             request: ChatCompletionRequest<'_>,
         ) -> Result<Option<ChatCompletion>, ChatCompletionError> {
-            let (api_key, endpoint, payload) = request.into_parts();
-            assert_eq!(api_key, "stub-key");
+            let (auth, endpoint, payload) = request.into_parts();
+            assert!(matches!(auth, ChatCompletionAuth::Bearer(api_key) if api_key == "stub-key"));
             assert_eq!(endpoint, "https://stub.example/v1/chat/completions");
             assert_eq!(payload["model"], "qwen-stub");
             assert_eq!(payload["response_format"]["type"], "json_object");
@@ -549,7 +549,7 @@ mod tests {
     async fn completes_through_injected_client() {
         // Arrange
         let model = ChatCompletionBackend::with_client(
-            "stub-key".to_string(),
+            ChatCompletionAuth::Bearer("stub-key".to_string()),
             "https://stub.example/v1/".to_string(),
             "qwen-stub".to_string(),
             POLICY,
@@ -0,0 +1,396 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::model::ModelMessage;
+use crate::tool::ToolCall;
+
+const SESSION_FORMAT_VERSION: u32 = 1;
+
+/// Conversation history carried across sequential harness turns.
+///
+/// Pass a session to [`crate::Harness::run_in_session`] so each turn sees the
+/// prompts, tool calls, tool results, and terminal outputs of earlier turns.
+/// Sessions serialize to a versioned JSON document and can be reloaded after
+/// a process restart to continue the conversation.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HarnessSession {
+    messages: Vec<ModelMessage>,
+}
+
+impl HarnessSession {
+    /// Creates an empty session.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether no turn has completed in this session.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Returns the number of completed turns in this session.
+    pub fn turn_count(&self) -> usize {
+        self.messages
+            .iter()
+            .filter(|message| matches!(message, ModelMessage::Assistant(_)))
+            .count()
+    }
+
+    /// Decodes a session from its versioned JSON document.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SessionError`] when the document is malformed, uses an
+    /// unsupported format version, or contains an invalid tool call.
+    pub fn from_json(document: &str) -> Result<Self, SessionError> {
+        let version = serde_json::from_str::<StoredVersion>(document)
+            .map_err(|source| SessionError::Decode { source })?
+            .version;
+        if version != SESSION_FORMAT_VERSION {
+            return Err(SessionError::UnsupportedVersion { version });
+        }
+        let stored = serde_json::from_str::<StoredSession>(document)
+            .map_err(|source| SessionError::Decode { source })?;
+        let messages = stored
+            .messages
+            .into_iter()
+            .map(StoredMessage::into_message)
+            .collect::<Result<_, _>>()
+            .map_err(|source| SessionError::Decode { source })?;
+
+        Ok(Self { messages })
+    }
+
+    /// Encodes this session as a versioned JSON document.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SessionError::Encode`] when a tool call cannot be encoded.
+    pub fn to_json(&self) -> Result<String, SessionError> {
+        let messages = self
+            .messages
+            .iter()
+            .map(StoredMessage::from_message)
+            .collect::<Result<_, _>>()
+            .map_err(|source| SessionError::Encode { source })?;
+        let stored = StoredSession {
+            messages,
+            version: SESSION_FORMAT_VERSION,
+        };
+
+        serde_json::to_string(&stored).map_err(|source| SessionError::Encode { source })
+    }
+
+    /// Loads a session previously written by [`Self::save`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SessionError`] when the file cannot be read or decoded.
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self, SessionError> {
+        let path = path.as_ref();
+        let document =
+            tokio::fs::read_to_string(path)
+                .await
+                .map_err(|source| SessionError::Read {
+                    path: path.to_path_buf(),
+                    source,
+                })?;
+
+        Self::from_json(&document)
+    }
+
+    /// Writes this session to `path`, replacing any earlier copy atomically.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SessionError`] when the session cannot be encoded or the
+    /// file cannot be written.
+    pub async fn save(&self, path: impl AsRef<Path>) -> Result<(), SessionError> {
+        let path = path.as_ref();
+        let document = self.to_json()?;
+        let staging_path = staging_path(path);
+        let write_error = |source| SessionError::Write {
+            path: path.to_path_buf(),
+            source,
+        };
+        tokio::fs::write(&staging_path, document)
+            .await
+            .map_err(write_error)?;
+        if let Err(error) = tokio::fs::rename(&staging_path, path).await {
+            let _ = tokio::fs::remove_file(&staging_path).await;
+
+            return Err(write_error(error));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn messages(&self) -> &[ModelMessage] {
+        &self.messages
+    }
+
+    /// Replaces the history with one completed turn's messages and output.
+    pub(crate) fn record_turn(&mut self, mut messages: Vec<ModelMessage>, output: &Value) {
+        messages.push(ModelMessage::Assistant(output.to_string()));
+        self.messages = messages;
+    }
+}
+
+/// Failure while encoding, decoding, loading, or saving a [`HarnessSession`].
+#[derive(Debug, Error)]
+pub enum SessionError {
+    /// The session file could not be read.
+    #[error("failed to read session `{}`: {source}", path.display())]
+    Read {
+        /// Session file path.
+        path: PathBuf,
+        /// Underlying filesystem failure.
+        #[source]
+        source: io::Error,
+    },
+    /// The session file could not be written.
+    #[error("failed to write session `{}`: {source}", path.display())]
+    Write {
+        /// Session file path.
+        path: PathBuf,
+        /// Underlying filesystem failure.
+        #[source]
+        source: io::Error,
+    },
+    /// The session document is malformed.
+    #[error("failed to decode session: {source}")]
+    Decode {
+        /// Underlying JSON failure.
+        #[source]
+        source: serde_json::Error,
+    },
+    /// The session could not be encoded.
+    #[error("failed to encode session: {source}")]
+    Encode {
+        /// Underlying JSON failure.
+        #[source]
+        source: serde_json::Error,
+    },
+    /// The session document was written by an incompatible format version.
+    #[error("unsupported session format version {version}")]
+    UnsupportedVersion {
+        /// Version recorded in the document.
+        version: u32,
+    },
+}
+
+#[derive(Deserialize)]
+struct StoredVersion {
+    version: u32,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct StoredSession {
+    messages: Vec<StoredMessage>,
+    version: u32,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case", tag = "type")]
+enum StoredMessage {
+    User {
+        content: String,
+    },
+    Assistant {
+        content: String,
+    },
+    ToolCall {
+        arguments: String,
+        id: String,
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reasoning_content: Option<String>,
+    },
+    ToolResult {
+        call_id: String,
+        content: String,
+        name: String,
+    },
+}
+
+impl StoredMessage {
+    fn from_message(message: &ModelMessage) -> Result<Self, serde_json::Error> {
+        Ok(match message {
+            ModelMessage::User(content) => Self::User {
+                content: content.clone(),
+            },
+            ModelMessage::Assistant(content) => Self::Assistant {
+                content: content.clone(),
+            },
+            ModelMessage::AssistantToolCall(call) => Self::ToolCall {
+                arguments: call.arguments_json()?,
+                id: call.id().to_string(),
+                name: call.name().to_string(),
+                reasoning_content: call.reasoning_content().map(str::to_string),
+            },
+            ModelMessage::ToolResult {
+                call_id,
+                content,
+                name,
+            } => Self::ToolResult {
+                call_id: call_id.clone(),
+                content: content.clone(),
+                name: name.clone(),
+            },
+        })
+    }
+
+    fn into_message(self) -> Result<ModelMessage, serde_json::Error> {
+        Ok(match self {
+            Self::User { content } => ModelMessage::User(content),
+            Self::Assistant { content } => ModelMessage::Assistant(content),
+            Self::ToolCall {
+                arguments,
+                id,
+                name,
+                reasoning_content,
+            } => ModelMessage::AssistantToolCall(ToolCall::decode(
+                id,
+                &name,
+                &arguments,
+                reasoning_content,
+            )?),
+            Self::ToolResult {
+                call_id,
+                content,
+                name,
+            } => ModelMessage::ToolResult {
+                call_id,
+                content,
+                name,
+            },
+        })
+    }
+}
+
+fn staging_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".tmp");
+
+    path.with_file_name(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::tool::ReadArguments;
+
+    fn completed_session() -> HarnessSession {
+        let arguments = serde_json::from_value::<ReadArguments>(json!({ "path": "Cargo.toml" }))
+            .expect("read arguments should be valid");
+        let mut session = HarnessSession::new();
+        session.record_turn(
+            vec![
+                ModelMessage::User("inspect".to_string()),
+                ModelMessage::AssistantToolCall(ToolCall::read(
+                    "call_read".to_string(),
+                    arguments,
+                    Some("thinking".to_string()),
+                )),
+                ModelMessage::ToolResult {
+                    call_id: "call_read".to_string(),
+                    content: "{\"content\":\"[workspace]\"}".to_string(),
+                    name: "read".to_string(),
+                },
+            ],
+            &json!({ "summary": "workspace" }),
+        );
+
+        session
+    }
+
+    #[test]
+    fn json_round_trip_preserves_history() {
+        // Arrange
+        let session = completed_session();
+
+        // Act
+        let document = session.to_json().expect("session should encode");
+        let decoded = HarnessSession::from_json(&document).expect("session should decode");
+
+        // Assert
+        assert_eq!(decoded, session);
+        assert_eq!(decoded.turn_count(), 1);
+        let value: Value = serde_json::from_str(&document).expect("document should be JSON");
+        assert_eq!(value["version"], 1);
+        assert_eq!(value["messages"][1]["type"], "tool_call");
+        assert_eq!(
+            value["messages"][3]["content"],
+            "{\"summary\":\"workspace\"}"
+        );
+    }
+
+    #[test]
+    fn from_json_rejects_unsupported_version_and_unknown_tool() {
+        // Arrange
+        let future_version = json!({ "version": 2, "messages": [] }).to_string();
+        let unknown_tool = json!({
+            "version": 1,
+            "messages": [{
+                "type": "tool_call",
+                "arguments": "{}",
+                "id": "call",
+                "name": "browse"
+            }]
+        })
+        .to_string();
+
+        // Act
+        let version_error = HarnessSession::from_json(&future_version)
+            .expect_err("future version should be rejected");
+        let tool_error =
+            HarnessSession::from_json(&unknown_tool).expect_err("unknown tool should be rejected");
+
+        // Assert
+        assert!(matches!(
+            version_error,
+            SessionError::UnsupportedVersion { version: 2 }
+        ));
+        assert!(matches!(tool_error, SessionError::Decode { .. }));
+    }
+
+    #[tokio::test]
+    async fn save_and_load_round_trip_through_file() {
+        // Arrange
+        let directory = tempfile::tempdir().expect("session directory should be created");
+        let path = directory.path().join("session.json");
+        let session = completed_session();
+
+        // Act
+        session.save(&path).await.expect("session should save");
+        let loaded = HarnessSession::load(&path)
+            .await
+            .expect("session should load");
+
+        // Assert
+        assert_eq!(loaded, session);
+        assert!(!staging_path(&path).exists());
+    }
+
+    #[tokio::test]
+    async fn load_reports_missing_file() {
+        // Arrange
+        let directory = tempfile::tempdir().expect("session directory should be created");
+        let path = directory.path().join("missing.json");
+
+        // Act
+        let error = HarnessSession::load(&path)
+            .await
+            .expect_err("missing session should fail");
+
+        // Assert
+        assert!(matches!(error, SessionError::Read { path: error_path, .. } if error_path == path));
+    }
+}
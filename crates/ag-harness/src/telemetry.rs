@@ -26,6 +26,7 @@ pub(crate) const ERROR_REPOSITORY_REQUIRED: &str = "repository_required";
 pub(crate) const ERROR_TOOL_CALL_LIMIT: &str = "tool_call_limit";
 pub(crate) const ERROR_TOOL_DENIED: &str = "tool_denied";
 pub(crate) const ERROR_TOOL_EXECUTION: &str = "tool_execution_error";
+pub(crate) const ERROR_TOOL_TIMEOUT: &str = "tool_timeout";
 pub(crate) const ERROR_INVALID_OUTPUT: &str = "invalid_output";
 pub(crate) const ERROR_INVALID_PROVIDER_RESPONSE: &str = "invalid_provider_response";
 pub(crate) const ERROR_INVALID_RESPONSE: &str = "invalid_response";
@@ -40,6 +41,7 @@ pub(crate) const OPERATION_CHAT: &str = "chat";
 pub(crate) const PROVIDER_ALIBABA_CLOUD: &str = "alibaba_cloud";
 pub(crate) const PROVIDER_META: &str = "meta";
 pub(crate) const PROVIDER_MOONSHOT_AI: &str = "moonshot_ai";
+pub(crate) const PROVIDER_OPENAI_COMPATIBLE: &str = "openai_compatible";
 pub(crate) const TOKEN_BOUNDARIES: [f64; 14] = [
     1.0,
     4.0,
@@ -61,6 +63,7 @@ pub(crate) const TOKEN_METRIC: &str = "gen_ai.client.token.usage";
 pub(crate) const TOKEN_TYPE_INPUT: &str = "input";
 pub(crate) const TOKEN_TYPE_OUTPUT: &str = "output";
 pub(crate) const TOKEN_UNIT: &str = "{token}";
+pub(crate) const TOOL_TYPE_FUNCTION: &str = "function";
 const AGENT_CALL_BOUNDARIES: [f64; 8] = [1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0, 128.0];
 const AGENT_DURATION_BOUNDARIES_SECONDS: [f64; 13] = [
     0.1, 0.2, 0.4, 0.8, 1.6, 3.2, 6.4, 12.8, 25.6, 51.2, 102.4, 204.8, 409.6,
@@ -80,7 +83,6 @@ const AGENT_TOOL_CALLS_METRIC: &str = "gen_ai.invoke_agent.tool_calls";
 const AGENT_TOOL_CALLS_UNIT: &str = "{tool_call}";
 const TOOL_DURATION_DESCRIPTION: &str = "The duration of a single tool execution.";
 const TOOL_DURATION_METRIC: &str = "gen_ai.execute_tool.duration";
-const TOOL_TYPE_FUNCTION: &str = "function";
 
 /// OpenTelemetry metric projection over ordered harness lifecycle events.
 ///
@@ -4,6 +4,12 @@ use std::num::NonZeroU64;
 use serde::{Deserialize, Deserializer, Serialize, de};
 use serde_json::{Number, Value, json};
 
+const BASH_DESCRIPTION: &str = concat!(
+    "Run one bash command from a repository directory and return its exit status with ",
+    "bounded stdout and stderr.",
+);
+const BASH_NAME: &str = "bash";
+const MAX_COMMAND_BYTES: usize = 16 * 1024;
 const READ_DESCRIPTION: &str =
     "Read a repository-relative file, optionally selecting a line range.";
 const READ_NAME: &str = "read";
@@ -19,6 +25,8 @@ pub enum Tool {
     Read,
     /// Repository-relative patch writes.
     Write,
+    /// Bounded shell commands run beneath the repository root.
+    Bash,
 }
 
 /// Provider-neutral definition of a native model tool.
@@ -80,6 +88,32 @@ impl ToolDefinition {
         }
     }
 
+    /// Defines the native `bash` function tool.
+    pub fn bash() -> Self {
+        Self {
+            description: BASH_DESCRIPTION,
+            name: BASH_NAME,
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "command": {
+                        "type": "string",
+                        "minLength": 1,
+                        "maxLength": MAX_COMMAND_BYTES
+                    },
+                    "timeout_seconds": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": u64::MAX
+                    },
+                    "working_directory": repository_path_schema()
+                },
+                "required": ["command"],
+                "additionalProperties": false
+            }),
+        }
+    }
+
     /// Returns the description sent with the native function definition.
     pub fn description(&self) -> &'static str {
         self.description
@@ -134,6 +168,7 @@ impl ToolCall {
         match &self.arguments {
             ToolArguments::Read(arguments) => ToolCallArguments::Read(arguments),
             ToolArguments::Write(arguments) => ToolCallArguments::Write(arguments),
+            ToolArguments::Bash(arguments) => ToolCallArguments::Bash(arguments),
         }
     }
 
@@ -141,15 +176,23 @@ impl ToolCall {
     pub fn read_arguments(&self) -> Option<&ReadArguments> {
         match &self.arguments {
             ToolArguments::Read(arguments) => Some(arguments),
-            ToolArguments::Write(_) => None,
+            ToolArguments::Write(_) | ToolArguments::Bash(_) => None,
         }
     }
 
     /// Returns typed `write` arguments when this is a `write` call.
     pub fn write_arguments(&self) -> Option<&WriteArguments> {
         match &self.arguments {
-            ToolArguments::Read(_) => None,
             ToolArguments::Write(arguments) => Some(arguments),
+            ToolArguments::Read(_) | ToolArguments::Bash(_) => None,
+        }
+    }
+
+    /// Returns typed `bash` arguments when this is a `bash` call.
+    pub fn bash_arguments(&self) -> Option<&BashArguments> {
+        match &self.arguments {
+            ToolArguments::Bash(arguments) => Some(arguments),
+            ToolArguments::Read(_) | ToolArguments::Write(_) => None,
         }
     }
 
@@ -163,6 +206,7 @@ impl ToolCall {
         match self.arguments {
             ToolArguments::Read(_) => READ_NAME,
             ToolArguments::Write(_) => WRITE_NAME,
+            ToolArguments::Bash(_) => BASH_NAME,
         }
     }
 
@@ -190,10 +234,50 @@ impl ToolCall {
         }
     }
 
+    pub(crate) fn bash(
+        id: String,
+        arguments: BashArguments,
+        reasoning_content: Option<String>,
+    ) -> Self {
+        Self {
+            arguments: ToolArguments::Bash(arguments),
+            id,
+            reasoning_content,
+        }
+    }
+
+    /// Decodes one call from its native function name and JSON arguments.
+    pub(crate) fn decode(
+        id: String,
+        name: &str,
+        arguments: &str,
+        reasoning_content: Option<String>,
+    ) -> Result<Self, serde_json::Error> {
+        match name {
+            BASH_NAME => Ok(Self::bash(
+                id,
+                serde_json::from_str(arguments)?,
+                reasoning_content,
+            )),
+            READ_NAME => Ok(Self::read(
+                id,
+                serde_json::from_str(arguments)?,
+                reasoning_content,
+            )),
+            WRITE_NAME => Ok(Self::write(
+                id,
+                serde_json::from_str(arguments)?,
+                reasoning_content,
+            )),
+            _ => Err(de::Error::custom(format!("unknown tool `{name}`"))),
+        }
+    }
+
     pub(crate) fn arguments_json(&self) -> Result<String, serde_json::Error> {
         match &self.arguments {
             ToolArguments::Read(arguments) => serde_json::to_string(arguments),
             ToolArguments::Write(arguments) => serde_json::to_string(arguments),
+            ToolArguments::Bash(arguments) => serde_json::to_string(arguments),
         }
     }
 
@@ -206,6 +290,7 @@ impl ToolCall {
 enum ToolArguments {
     Read(ReadArguments),
     Write(WriteArguments),
+    Bash(BashArguments),
 }
 
 /// Borrowed typed arguments for one native tool call.
@@ -215,6 +300,8 @@ pub enum ToolCallArguments<'a> {
     Read(&'a ReadArguments),
     /// Arguments for a repository patch write.
     Write(&'a WriteArguments),
+    /// Arguments for a shell command.
+    Bash(&'a BashArguments),
 }
 
 /// Validated arguments for the native `read` function.
@@ -283,6 +370,73 @@ impl WriteArguments {
     }
 }
 
+/// Validated arguments for the native `bash` function.
+///
+/// `command` is a non-empty script run with `bash -c`. `working_directory`,
+/// when present, is a repository-relative directory, and `timeout_seconds`,
+/// when present, is a positive timeout that the harness caps at its
+/// configured maximum.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct BashArguments {
+    #[serde(deserialize_with = "deserialize_bounded_command")]
+    command: String,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_positive_integer",
+        skip_serializing_if = "Option::is_none"
+    )]
+    timeout_seconds: Option<NonZeroU64>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_repository_path",
+        skip_serializing_if = "Option::is_none"
+    )]
+    working_directory: Option<String>,
+}
+
+impl BashArguments {
+    /// Returns the shell command supplied by the model.
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// Returns the optional requested timeout in seconds.
+    pub fn timeout_seconds(&self) -> Option<u64> {
+        self.timeout_seconds.map(NonZeroU64::get)
+    }
+
+    /// Returns the optional repository-relative working directory.
+    pub fn working_directory(&self) -> Option<&str> {
+        self.working_directory.as_deref()
+    }
+}
+
+fn deserialize_bounded_command<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let command = String::deserialize(deserializer)?;
+    if command.trim().is_empty() {
+        return Err(de::Error::custom("command must not be empty"));
+    }
+    if command.len() > MAX_COMMAND_BYTES {
+        return Err(de::Error::custom("command exceeds the byte limit"));
+    }
+    if command.contains('\0') {
+        return Err(de::Error::custom("command must not contain NUL"));
+    }
+
+    Ok(command)
+}
+
+fn deserialize_optional_repository_path<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_repository_path(deserializer).map(Some)
+}
+
 fn deserialize_bounded_patch<'de, D>(deserializer: D) -> Result<String, D::Error>
 where
     D: Deserializer<'de>,
@@ -494,6 +648,79 @@ mod tests {
         assert!(decode_results.into_iter().all(|result| result.is_err()));
     }
 
+    #[test]
+    fn bash_definition_exposes_native_function_contract() {
+        // Arrange and Act
+        let definition = ToolDefinition::bash();
+        let validator =
+            Validator::new(definition.parameters()).expect("bash argument schema should compile");
+
+        // Assert
+        assert_eq!(definition.name(), "bash");
+        assert!(validator.is_valid(&json!({ "command": "cargo test" })));
+        assert!(validator.is_valid(&json!({
+            "command": "cargo test",
+            "timeout_seconds": 30,
+            "working_directory": "crates/ag-harness"
+        })));
+    }
+
+    #[test]
+    fn bash_definition_and_arguments_reject_invalid_input() {
+        // Arrange
+        let definition = ToolDefinition::bash();
+        let validator =
+            Validator::new(definition.parameters()).expect("bash argument schema should compile");
+        let values = [
+            json!({}),
+            json!({ "command": "" }),
+            json!({ "command": "x".repeat(MAX_COMMAND_BYTES + 1) }),
+            json!({ "command": "ls", "timeout_seconds": 0 }),
+            json!({ "command": "ls", "timeout_seconds": null }),
+            json!({ "command": "ls", "working_directory": "../outside" }),
+            json!({ "command": "ls", "working_directory": "/tmp" }),
+            json!({ "command": "ls", "env": {} }),
+        ];
+
+        // Act
+        let schema_results = values.clone().map(|value| validator.is_valid(&value));
+        let decode_results = values.map(serde_json::from_value::<BashArguments>);
+
+        // Assert
+        assert!(schema_results.into_iter().all(|valid| !valid));
+        assert!(decode_results.into_iter().all(|result| result.is_err()));
+    }
+
+    #[test]
+    fn bash_tool_call_exposes_typed_arguments_and_serialization() {
+        // Arrange
+        let arguments = serde_json::from_value(json!({
+            "command": "cargo test",
+            "working_directory": "crates/ag-harness"
+        }))
+        .expect("bash arguments should decode");
+        let bash = ToolCall::bash("bash-id".to_string(), arguments, None);
+
+        // Act
+        let bash_json = bash.arguments_json().expect("bash arguments should encode");
+
+        // Assert
+        assert_eq!(bash.name(), "bash");
+        assert!(bash.read_arguments().is_none());
+        assert!(bash.write_arguments().is_none());
+        let arguments = bash
+            .bash_arguments()
+            .expect("bash arguments should be exposed");
+        assert_eq!(arguments.command(), "cargo test");
+        assert_eq!(arguments.timeout_seconds(), None);
+        assert_eq!(arguments.working_directory(), Some("crates/ag-harness"));
+        assert_eq!(
+            bash_json,
+            r#"{"command":"cargo test","working_directory":"crates/ag-harness"}"#
+        );
+        assert!(matches!(bash.arguments(), ToolCallArguments::Bash(_)));
+    }
+
     #[test]
     fn tool_call_exposes_matching_typed_arguments_and_serialization() {
         // Arrange
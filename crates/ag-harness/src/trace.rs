@@ -0,0 +1,574 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+
+use opentelemetry::trace::{Span as _, SpanKind, Status, TraceContextExt, Tracer};
+use opentelemetry::{Array, Context, KeyValue, StringValue, Value, global};
+
+use crate::lifecycle::{LifecycleEvent, LifecycleEventKind, LifecycleId, LifecycleObserver};
+use crate::model::{CompletionMetadata, ModelMetadata};
+use crate::telemetry::{
+    ATTRIBUTE_ERROR_TYPE, ATTRIBUTE_OPERATION_NAME, ATTRIBUTE_PROVIDER_NAME,
+    ATTRIBUTE_REQUEST_MODEL, ATTRIBUTE_TOOL_NAME, ATTRIBUTE_TOOL_TYPE, ERROR_CANCELLED,
+    ERROR_TOOL_DENIED, INSTRUMENTATION_SCOPE, OPERATION_CHAT, TOOL_TYPE_FUNCTION,
+};
+
+const ATTRIBUTE_RESPONSE_FINISH_REASONS: &str = "gen_ai.response.finish_reasons";
+const ATTRIBUTE_RESPONSE_MODEL: &str = "gen_ai.response.model";
+const ATTRIBUTE_USAGE_INPUT_TOKENS: &str = "gen_ai.usage.input_tokens";
+const ATTRIBUTE_USAGE_OUTPUT_TOKENS: &str = "gen_ai.usage.output_tokens";
+const OPERATION_EXECUTE_TOOL: &str = "execute_tool";
+const OPERATION_INVOKE_AGENT: &str = "invoke_agent";
+
+/// OpenTelemetry trace projection over ordered harness lifecycle events.
+///
+/// Each turn becomes an `invoke_agent` span whose children are one `chat`
+/// span per model request and one `execute_tool` span per requested tool.
+/// Failed, denied, and cancelled operations end with an error status and an
+/// `error.type` attribute. Standalone model requests become root `chat`
+/// spans.
+///
+/// Applications install an OpenTelemetry tracer provider before constructing
+/// this observer and retain ownership of export, flushing, and shutdown.
+pub struct LifecycleTraces {
+    state: Mutex<LifecycleTraceState>,
+    tracer: global::BoxedTracer,
+}
+
+impl LifecycleTraces {
+    /// Creates a lifecycle observer backed by the global tracer provider.
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(LifecycleTraceState::default()),
+            tracer: global::tracer(INSTRUMENTATION_SCOPE),
+        }
+    }
+}
+
+impl Default for LifecycleTraces {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LifecycleObserver for LifecycleTraces {
+    fn observe(&self, event: LifecycleEvent) {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .observe(&self.tracer, &event);
+    }
+}
+
+#[derive(Default)]
+struct LifecycleTraceState {
+    model_requests: HashMap<LifecycleId, PendingSpan>,
+    tools: HashMap<LifecycleId, PendingSpan>,
+    turns: HashMap<LifecycleId, Context>,
+}
+
+impl LifecycleTraceState {
+    fn observe(&mut self, tracer: &global::BoxedTracer, event: &LifecycleEvent) {
+        match event.kind() {
+            LifecycleEventKind::TurnStarted { turn_id } => {
+                let parent = Context::current();
+                let span = tracer
+                    .span_builder(OPERATION_INVOKE_AGENT)
+                    .with_kind(SpanKind::Internal)
+                    .with_attributes([KeyValue::new(
+                        ATTRIBUTE_OPERATION_NAME,
+                        OPERATION_INVOKE_AGENT,
+                    )])
+                    .start_with_context(tracer, &parent);
+                self.turns.insert(*turn_id, parent.with_span(span));
+            }
+            LifecycleEventKind::TurnCompleted { turn_id, .. } => {
+                self.end_turn(*turn_id, None);
+            }
+            LifecycleEventKind::TurnFailed {
+                error_type,
+                turn_id,
+                ..
+            } => self.end_turn(*turn_id, Some(error_type.as_str())),
+            LifecycleEventKind::ModelRequestStarted {
+                model,
+                model_call_id,
+                turn_id,
+                ..
+            } => {
+                let span = tracer
+                    .span_builder(chat_span_name(model.as_ref()))
+                    .with_kind(SpanKind::Client)
+                    .with_attributes(chat_attributes(model.as_ref()))
+                    .start_with_context(tracer, &self.parent_context(*turn_id));
+                self.model_requests
+                    .insert(*model_call_id, PendingSpan::new(span, *turn_id));
+            }
+            LifecycleEventKind::ModelRequestCompleted {
+                completion,
+                model_call_id,
+                ..
+            } => {
+                if let Some(mut pending) = self.model_requests.remove(model_call_id) {
+                    if let Some(completion) = completion {
+                        pending
+                            .span
+                            .set_attributes(completion_attributes(completion));
+                    }
+                    pending.end(None);
+                }
+            }
+            LifecycleEventKind::ModelRequestFailed {
+                error_type,
+                model_call_id,
+                ..
+            } => {
+                if let Some(pending) = self.model_requests.remove(model_call_id) {
+                    pending.end(Some(error_type.as_str()));
+                }
+            }
+            LifecycleEventKind::ModelRequestCancelled { model_call_id, .. } => {
+                if let Some(pending) = self.model_requests.remove(model_call_id) {
+                    pending.end(Some(ERROR_CANCELLED));
+                }
+            }
+            LifecycleEventKind::ToolRequested {
+                tool_call_id,
+                tool_name,
+                turn_id,
+            } => {
+                let span = tracer
+                    .span_builder(format!("{OPERATION_EXECUTE_TOOL} {tool_name}"))
+                    .with_kind(SpanKind::Internal)
+                    .with_attributes([
+                        KeyValue::new(ATTRIBUTE_OPERATION_NAME, OPERATION_EXECUTE_TOOL),
+                        KeyValue::new(ATTRIBUTE_TOOL_NAME, tool_name.clone()),
+                        KeyValue::new(ATTRIBUTE_TOOL_TYPE, TOOL_TYPE_FUNCTION),
+                    ])
+                    .start_with_context(tracer, &self.parent_context(Some(*turn_id)));
+                self.tools
+                    .insert(*tool_call_id, PendingSpan::new(span, Some(*turn_id)));
+            }
+            LifecycleEventKind::ToolCompleted { tool_call_id, .. } => {
+                self.end_tool(*tool_call_id, None);
+            }
+            LifecycleEventKind::ToolDenied { tool_call_id, .. } => {
+                self.end_tool(*tool_call_id, Some(ERROR_TOOL_DENIED));
+            }
+            LifecycleEventKind::ToolFailed {
+                error_type,
+                tool_call_id,
+                ..
+            } => self.end_tool(*tool_call_id, Some(error_type.as_str())),
+            LifecycleEventKind::ToolStarted { .. } => {}
+        }
+    }
+
+    fn parent_context(&self, turn_id: Option<LifecycleId>) -> Context {
+        turn_id
+            .and_then(|turn_id| self.turns.get(&turn_id))
+            .cloned()
+            .unwrap_or_else(Context::new)
+    }
+
+    fn end_tool(&mut self, tool_call_id: LifecycleId, error_type: Option<&'static str>) {
+        if let Some(pending) = self.tools.remove(&tool_call_id) {
+            pending.end(error_type);
+        }
+    }
+
+    fn end_turn(&mut self, turn_id: LifecycleId, error_type: Option<&'static str>) {
+        let Some(context) = self.turns.remove(&turn_id) else {
+            return;
+        };
+        let mut orphaned = take_turn_spans(&mut self.model_requests, turn_id);
+        orphaned.extend(take_turn_spans(&mut self.tools, turn_id));
+        for pending in orphaned {
+            pending.end(Some(ERROR_CANCELLED));
+        }
+
+        let span = context.span();
+        if let Some(error_type) = error_type {
+            span.set_attribute(KeyValue::new(ATTRIBUTE_ERROR_TYPE, error_type));
+            span.set_status(Status::error(error_type));
+        }
+        span.end();
+    }
+}
+
+struct PendingSpan {
+    span: global::BoxedSpan,
+    turn_id: Option<LifecycleId>,
+}
+
+impl PendingSpan {
+    fn new(span: global::BoxedSpan, turn_id: Option<LifecycleId>) -> Self {
+        Self { span, turn_id }
+    }
+
+    fn end(mut self, error_type: Option<&'static str>) {
+        if let Some(error_type) = error_type {
+            self.span
+                .set_attribute(KeyValue::new(ATTRIBUTE_ERROR_TYPE, error_type));
+            self.span.set_status(Status::error(error_type));
+        }
+        self.span.end();
+    }
+}
+
+/// Removes child spans still open when their owning turn ends.
+fn take_turn_spans(
+    spans: &mut HashMap<LifecycleId, PendingSpan>,
+    turn_id: LifecycleId,
+) -> Vec<PendingSpan> {
+    let ids = spans
+        .iter()
+        .filter(|(_, pending)| pending.turn_id == Some(turn_id))
+        .map(|(id, _)| *id)
+        .collect::<Vec<_>>();
+
+    ids.into_iter().filter_map(|id| spans.remove(&id)).collect()
+}
+
+fn chat_span_name(model: Option<&ModelMetadata>) -> String {
+    match model {
+        Some(model) => format!("{OPERATION_CHAT} {}", model.model()),
+        None => OPERATION_CHAT.to_string(),
+    }
+}
+
+fn chat_attributes(model: Option<&ModelMetadata>) -> Vec<KeyValue> {
+    let mut attributes = vec![KeyValue::new(ATTRIBUTE_OPERATION_NAME, OPERATION_CHAT)];
+    if let Some(model) = model {
+        attributes.push(KeyValue::new(ATTRIBUTE_PROVIDER_NAME, model.provider()));
+        attributes.push(KeyValue::new(
+            ATTRIBUTE_REQUEST_MODEL,
+            model.model().to_string(),
+        ));
+    }
+
+    attributes
+}
+
+fn completion_attributes(completion: &CompletionMetadata) -> Vec<KeyValue> {
+    let mut attributes = vec![KeyValue::new(
+        ATTRIBUTE_RESPONSE_FINISH_REASONS,
+        Value::Array(Array::String(vec![StringValue::from(
+            completion.finish_reason().to_string(),
+        )])),
+    )];
+    if let Some(response_model) = completion.response_model() {
+        attributes.push(KeyValue::new(
+            ATTRIBUTE_RESPONSE_MODEL,
+            response_model.to_string(),
+        ));
+    }
+    let usage = completion.usage();
+    let token_attributes = [
+        (
+            ATTRIBUTE_USAGE_INPUT_TOKENS,
+            usage.and_then(|usage| usage.input_tokens()),
+        ),
+        (
+            ATTRIBUTE_USAGE_OUTPUT_TOKENS,
+            usage.and_then(|usage| usage.output_tokens()),
+        ),
+    ];
+    for (key, tokens) in token_attributes {
+        if let Some(tokens) = tokens.and_then(|tokens| i64::try_from(tokens).ok()) {
+            attributes.push(KeyValue::new(key, tokens));
+        }
+    }
+
+    attributes
+}
+
+#[cfg(test)]
+mod tests {
+    use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+    use opentelemetry_proto::tonic::common::v1::any_value;
+    use opentelemetry_proto::tonic::trace::v1::{Span, span, status};
+    use opentelemetry_proto::transform::common::tonic::ResourceAttributesWithSchema;
+    use opentelemetry_proto::transform::trace::tonic::group_spans_by_resource_and_scope;
+    use opentelemetry_sdk::Resource;
+    use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider};
+    use prost::Message as _;
+
+    use super::*;
+    use crate::lifecycle::{LifecycleEmitter, ModelResponseType, TurnErrorType};
+    use crate::model::{CompletionUsage, ModelErrorType};
+
+    type SpanTree = Vec<(String, Option<String>, Vec<(String, Option<String>)>)>;
+
+    fn record_lifecycle_fixtures(lifecycle: &LifecycleEmitter) {
+        let metadata = ModelMetadata::new("test_provider", "standalone-model")
+            .expect("fixture metadata should be valid");
+        lifecycle
+            .start_model_request(Some(metadata), 0, None)
+            .expect("observer should start a standalone model request")
+            .completed(None, ModelResponseType::Output);
+
+        let successful_turn = lifecycle
+            .start_turn()
+            .expect("observer should start a turn");
+        let successful_turn_id = successful_turn.id();
+        let usage = CompletionUsage::new(None, None, Some(23), Some(7), None, Some(30));
+        lifecycle
+            .start_model_request(None, 0, Some(successful_turn_id))
+            .expect("observer should start a model request")
+            .completed(
+                Some(CompletionMetadata::new(
+                    "tool_calls".to_string(),
+                    Some("sensitive-response-id".to_string()),
+                    Some("returned-model".to_string()),
+                    None,
+                    Some(usage),
+                )),
+                ModelResponseType::ToolCall,
+            );
+        let mut read_tool = lifecycle
+            .request_tool("read".to_string(), Some(successful_turn_id))
+            .expect("observer should request a tool");
+        read_tool.started();
+        read_tool.completed();
+        lifecycle
+            .start_model_request(None, 1, Some(successful_turn_id))
+            .expect("observer should start a model request")
+            .completed(None, ModelResponseType::Output);
+        successful_turn.completed();
+
+        let denied_turn = lifecycle
+            .start_turn()
+            .expect("observer should start a turn");
+        lifecycle
+            .request_tool("write".to_string(), Some(denied_turn.id()))
+            .expect("observer should request a denied tool")
+            .denied();
+        denied_turn.failed(TurnErrorType::ToolDenied);
+
+        let failed_turn = lifecycle
+            .start_turn()
+            .expect("observer should start a turn");
+        lifecycle
+            .start_model_request(None, 0, Some(failed_turn.id()))
+            .expect("observer should start a model request")
+            .failed(ModelErrorType::InvalidOutput);
+        failed_turn.failed(TurnErrorType::Model(ModelErrorType::InvalidOutput));
+
+        let cancelled_turn = lifecycle
+            .start_turn()
+            .expect("observer should start a turn");
+        let cancelled_turn_id = cancelled_turn.id();
+        let mut cancelled_tool = lifecycle
+            .request_tool("bash".to_string(), Some(cancelled_turn_id))
+            .expect("observer should request a tool");
+        cancelled_tool.started();
+        drop(cancelled_tool);
+        drop(
+            lifecycle
+                .start_model_request(None, 1, Some(cancelled_turn_id))
+                .expect("observer should start a model request"),
+        );
+        drop(cancelled_turn);
+    }
+
+    /// Encodes finished spans as an OTLP trace export and decodes them back.
+    fn decode_otlp_export(exporter: &InMemorySpanExporter) -> Vec<Span> {
+        let spans = exporter
+            .get_finished_spans()
+            .expect("spans should be exported");
+        let resource = ResourceAttributesWithSchema::from(&Resource::builder().build());
+        let encoded = ExportTraceServiceRequest {
+            resource_spans: group_spans_by_resource_and_scope(spans, &resource),
+        }
+        .encode_to_vec();
+
+        ExportTraceServiceRequest::decode(encoded.as_slice())
+            .expect("OTLP export should decode")
+            .resource_spans
+            .into_iter()
+            .flat_map(|resource_spans| resource_spans.scope_spans)
+            .inspect(|scope_spans| {
+                assert_eq!(
+                    scope_spans.scope.as_ref().map(|scope| scope.name.as_str()),
+                    Some(INSTRUMENTATION_SCOPE)
+                );
+            })
+            .flat_map(|scope_spans| scope_spans.spans)
+            .collect()
+    }
+
+    fn attribute(span: &Span, key: &str) -> Option<String> {
+        let value = span
+            .attributes
+            .iter()
+            .find(|attribute| attribute.key == key)?
+            .value
+            .as_ref()?
+            .value
+            .as_ref()?;
+
+        Some(match value {
+            any_value::Value::StringValue(value) => value.clone(),
+            any_value::Value::IntValue(value) => value.to_string(),
+            any_value::Value::ArrayValue(values) => values
+                .values
+                .iter()
+                .filter_map(|value| match value.value.as_ref()? {
+                    any_value::Value::StringValue(value) => Some(value.as_str()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join(","),
+            other => format!("{other:?}"),
+        })
+    }
+
+    fn span_tree(spans: &[Span]) -> SpanTree {
+        let mut tree = spans
+            .iter()
+            .filter(|span| span.parent_span_id.is_empty())
+            .map(|root| {
+                let mut children = spans
+                    .iter()
+                    .filter(|child| child.parent_span_id == root.span_id)
+                    .inspect(|child| assert_eq!(child.trace_id, root.trace_id))
+                    .map(|child| (child.name.clone(), attribute(child, ATTRIBUTE_ERROR_TYPE)))
+                    .collect::<Vec<_>>();
+                children.sort_unstable();
+
+                (
+                    root.name.clone(),
+                    attribute(root, ATTRIBUTE_ERROR_TYPE),
+                    children,
+                )
+            })
+            .collect::<Vec<_>>();
+        tree.sort_unstable();
+
+        tree
+    }
+
+    fn named<'spans>(spans: &'spans [Span], name: &str) -> &'spans Span {
+        spans
+            .iter()
+            .find(|span| span.name == name)
+            .expect("span should be exported")
+    }
+
+    fn assert_span_attributes(spans: &[Span]) {
+        let standalone = named(spans, "chat standalone-model");
+        assert_eq!(standalone.kind, span::SpanKind::Client as i32);
+        assert_eq!(
+            attribute(standalone, ATTRIBUTE_PROVIDER_NAME).as_deref(),
+            Some("test_provider")
+        );
+        assert_eq!(
+            attribute(standalone, ATTRIBUTE_REQUEST_MODEL).as_deref(),
+            Some("standalone-model")
+        );
+        let tool_call_chat = spans
+            .iter()
+            .find(|span| attribute(span, ATTRIBUTE_RESPONSE_MODEL).is_some())
+            .expect("completion attributes should be exported");
+        assert_eq!(
+            attribute(tool_call_chat, ATTRIBUTE_OPERATION_NAME).as_deref(),
+            Some("chat")
+        );
+        assert_eq!(
+            attribute(tool_call_chat, ATTRIBUTE_RESPONSE_MODEL).as_deref(),
+            Some("returned-model")
+        );
+        assert_eq!(
+            attribute(tool_call_chat, ATTRIBUTE_RESPONSE_FINISH_REASONS).as_deref(),
+            Some("tool_calls")
+        );
+        assert_eq!(
+            attribute(tool_call_chat, ATTRIBUTE_USAGE_INPUT_TOKENS).as_deref(),
+            Some("23")
+        );
+        assert_eq!(
+            attribute(tool_call_chat, ATTRIBUTE_USAGE_OUTPUT_TOKENS).as_deref(),
+            Some("7")
+        );
+        let read = named(spans, "execute_tool read");
+        assert_eq!(read.kind, span::SpanKind::Internal as i32);
+        assert_eq!(
+            attribute(read, ATTRIBUTE_OPERATION_NAME).as_deref(),
+            Some("execute_tool")
+        );
+        assert_eq!(
+            attribute(read, ATTRIBUTE_TOOL_NAME).as_deref(),
+            Some("read")
+        );
+        assert_eq!(
+            attribute(read, ATTRIBUTE_TOOL_TYPE).as_deref(),
+            Some("function")
+        );
+        assert!(
+            spans
+                .iter()
+                .all(|span| attribute(span, "gen_ai.response.id").is_none())
+        );
+    }
+
+    #[test]
+    fn projects_turns_into_otlp_span_tree() {
+        // Arrange
+        let exporter = InMemorySpanExporter::default();
+        let tracer_provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        global::set_tracer_provider(tracer_provider.clone());
+        let lifecycle = LifecycleEmitter::new(LifecycleTraces::default());
+
+        // Act
+        record_lifecycle_fixtures(&lifecycle);
+        tracer_provider.force_flush().expect("spans should flush");
+
+        // Assert
+        let spans = decode_otlp_export(&exporter);
+        assert_eq!(spans.len(), 12);
+        let error = |error_type: &str| Some(error_type.to_string());
+        assert_eq!(
+            span_tree(&spans),
+            [
+                ("chat standalone-model".to_string(), None, vec![]),
+                (
+                    "invoke_agent".to_string(),
+                    None,
+                    vec![
+                        ("chat".to_string(), None),
+                        ("chat".to_string(), None),
+                        ("execute_tool read".to_string(), None),
+                    ],
+                ),
+                (
+                    "invoke_agent".to_string(),
+                    error("cancelled"),
+                    vec![
+                        ("chat".to_string(), error("cancelled")),
+                        ("execute_tool bash".to_string(), error("cancelled")),
+                    ],
+                ),
+                (
+                    "invoke_agent".to_string(),
+                    error("invalid_output"),
+                    vec![("chat".to_string(), error("invalid_output"))],
+                ),
+                (
+                    "invoke_agent".to_string(),
+                    error("tool_denied"),
+                    vec![("execute_tool write".to_string(), error("tool_denied"))],
+                ),
+            ]
+        );
+        for span in &spans {
+            let is_error = span.status.as_ref().map(|status| status.code)
+                == Some(status::StatusCode::Error as i32);
+            assert_eq!(is_error, attribute(span, ATTRIBUTE_ERROR_TYPE).is_some());
+            assert!(span.end_time_unix_nano >= span.start_time_unix_nano);
+        }
+
+        assert_span_attributes(&spans);
+    }
+}
@@ -0,0 +1,99 @@
+//! Offline coverage for the harness loop against a local OpenAI-compatible
+//! stand-in server.
+#![cfg(test)]
+
+use std::fs;
+
+use ag_harness::{
+    Harness, ModelClient, OpenAiCompatibleAuth, OpenAiCompatibleConfig, OutputSchema,
+    StructuredOutputCapability, Tool,
+};
+use serde_json::json;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn version_schema() -> OutputSchema {
+    OutputSchema::new(json!({
+        "type": "object",
+        "properties": {"version": {"type": "string"}},
+        "required": ["version"],
+        "additionalProperties": false
+    }))
+    .expect("fixture schema should be valid")
+}
+
+#[tokio::test]
+async fn harness_reads_repository_and_repairs_output_through_local_endpoint() {
+    // Arrange
+    let repository = tempfile::tempdir().expect("repository fixture should be created");
+    fs::write(
+        repository.path().join("Cargo.toml"),
+        "[package]\nversion = \"1.2.3\"\n",
+    )
+    .expect("manifest fixture should be written");
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .and(body_string_contains("Your previous reply was rejected"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{
+                "finish_reason": "stop",
+                "message": {"content": "```json\n{\"version\":\"1.2.3\"}\n```"}
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .and(body_string_contains("call_local_read"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{
+                "finish_reason": "stop",
+                "message": {"content": "The version is 1.2.3."}
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{
+                "finish_reason": "tool_calls",
+                "message": {
+                    "content": null,
+                    "tool_calls": [{
+                        "id": "call_local_read",
+                        "type": "function",
+                        "function": {
+                            "name": "read",
+                            "arguments": r#"{"path":"Cargo.toml"}"#
+                        }
+                    }]
+                }
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let model = ModelClient::openai_compatible(OpenAiCompatibleConfig {
+        auth: OpenAiCompatibleAuth::None,
+        base_url: format!("{}/v1", server.uri()),
+        model: "local-coder".to_string(),
+        structured_output: StructuredOutputCapability::Prompted { repair_attempts: 1 },
+    })
+    .expect("fixture configuration should be valid");
+    let harness = Harness::new(model)
+        .repository(repository.path())
+        .allow(Tool::Read);
+
+    // Act
+    let output = harness
+        .run("Report the package version.", version_schema())
+        .await
+        .expect("local harness turn should succeed");
+
+    // Assert
+    assert_eq!(output, json!({ "version": "1.2.3" }));
+}
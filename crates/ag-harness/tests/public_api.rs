@@ -5,8 +5,8 @@ use std::sync::{Arc, Mutex};
 
 use ag_harness::{
     CompletionMetadata, CompletionUsage, Harness, LifecycleEventKind, LifecycleMetrics,
-    LifecycleObserverSet, Model, ModelCompletion, ModelError, ModelRequest, ModelResponse,
-    ModelWithMetadata, OutputSchema, OutputSchemaError,
+    LifecycleObserverSet, LifecycleTraces, Model, ModelCompletion, ModelError, ModelRequest,
+    ModelResponse, ModelWithMetadata, OutputSchema, OutputSchemaError,
 };
 use async_trait::async_trait;
 use serde_json::json;
@@ -156,7 +156,8 @@ async fn external_observer_set_fans_out_lifecycle_events() -> Result<(), Box<dyn
             .expect("second event recorder should not be poisoned")
             .push(event);
     })
-    .with_observer(LifecycleMetrics::new());
+    .with_observer(LifecycleMetrics::new())
+    .with_observer(LifecycleTraces::new());
     let harness = Harness::new(ExternalMetadataModel).with_lifecycle_observer(observers);
 
     // Act
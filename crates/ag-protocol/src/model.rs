@@ -555,6 +555,7 @@ mod tests {
 
         // Assert
         assert_eq!(subtask.kind, crate::SubtaskKind::Implementation);
+        assert_eq!(subtask.depends_on, [] as [std::string::String; 0]);
         assert_eq!(subtask.touched_areas, [] as [std::string::String; 0]);
     }
 
@@ -579,6 +580,7 @@ mod tests {
         assert!(normalized_description.contains("Ordinary session and utility turns"));
         assert!(normalized_description.contains("unattended in its own worktree"));
         assert!(normalized_description.contains("independently completable"));
+        assert!(normalized_description.contains("List prerequisite task keys in `depends_on`"));
         assert!(normalized_description.contains("without wildcards"));
         assert!(description.contains("Areas may overlap"));
         assert!(normalized_description.contains("fewer than two independent subtasks"));
@@ -618,6 +620,7 @@ mod tests {
     fn test_subtask(index: usize) -> SubtaskItem {
         SubtaskItem {
             acceptance_criteria: vec![format!("Work item {index} is complete")],
+            depends_on: Vec::new(),
             kind: crate::SubtaskKind::Implementation,
             prompt: format!("Complete work item {index}"),
             task_key: format!("task-{index}"),
@@ -19,16 +19,20 @@ pub enum SubtaskKind {
 /// One proposed child session in an orchestrator decomposition plan.
 ///
 /// Each subtask is executed unattended by its own child session in its own
-/// worktree, branched from the same base branch as its siblings. Children never
-/// coordinate with each other while running, so each prompt must be
-/// self-contained. `touched_areas` provides best-effort planning context rather
-/// than an exclusive ownership boundary.
+/// worktree. Subtasks without `depends_on` branch from the campaign base
+/// branch and run concurrently; a dependent subtask starts only after every
+/// listed prerequisite passed verification, and an implementation dependent
+/// branches from its implementation prerequisite. Children never coordinate
+/// with each other while running, so each prompt must be self-contained.
+/// `touched_areas` provides best-effort planning context rather than an
+/// exclusive ownership boundary.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[schemars(
     title = "SubtaskItem",
     description = "One proposed child session in an orchestrator decomposition plan. Each subtask \
-                   runs unattended in its own worktree branched from the same base branch, so it \
-                   must be completable without coordinating with its siblings."
+                   runs unattended in its own worktree, so it must be completable without \
+                   coordinating with its siblings. Use `depends_on` when it needs another \
+                   subtask's verified result first."
 )]
 pub struct SubtaskItem {
     /// Observable conditions the worker must satisfy before the controller
@@ -41,6 +45,18 @@ pub struct SubtaskItem {
                        against the same list."
     )]
     pub acceptance_criteria: Vec<String>,
+    /// Task keys that must pass verification before this task starts.
+    #[serde(default)]
+    #[schemars(
+        title = "depends_on",
+        description = "Task keys from this plan, or from the running campaign, that must pass \
+                       verification before this subtask starts. An implementation subtask may \
+                       depend on at most one implementation subtask and then starts from that \
+                       subtask's branch; research prerequisites contribute their reports. \
+                       Research subtasks may depend only on research subtasks. Dependencies must \
+                       not form a cycle. Defaults to an empty list when omitted."
+    )]
+    pub depends_on: Vec<String>,
     /// Whether this task implements repository changes or only reports
     /// read-only findings.
     #[serde(default)]
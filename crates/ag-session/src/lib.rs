@@ -24,13 +24,14 @@ pub use message::{
 };
 pub use model::{
     ForgeKind, PermissionMode, ReviewRequest, ReviewRequestState, ReviewRequestSummary, Session,
-    SessionId, SessionRole, SessionSettings, SessionStatus, SpeedMode,
+    SessionId, SessionRole, SessionSettings, SessionStatus, SessionSummary, SpeedMode,
     activity_day_key_with_offset,
 };
 pub use orchestration::{
     IntegrationApproach, MAX_AUTOMATED_REVIEW_ITERATIONS, OrchestrationPlanTask,
     OrchestrationPolicy, OrchestrationScheduleDecision, OrchestrationStatus, OrchestrationTaskKind,
-    OrchestrationTaskStatus, validate_subtasks,
+    OrchestrationTaskObservation, OrchestrationTaskStatus, PrerequisiteState, dependency_waves,
+    validate_subtasks,
 };
 pub use personality::{
     PERSONALITY_PROMPT_MAX_BYTES, Personality, PersonalityParseError, PersonalitySummary,
@@ -46,7 +47,9 @@ pub use service::{
     CreateSessionMode, CreateSessionRequest, QuestionAnswer, SessionBackend, SessionService,
 };
 pub use setting::{
-    DEFAULT_AUTO_APPROVE_ORCHESTRATION_RESEARCH, DEFAULT_ORCHESTRATION_PARALLELISM,
-    MAX_ORCHESTRATION_PARALLELISM, SettingName,
+    DEFAULT_AUTO_APPROVE_ORCHESTRATION_RESEARCH, DEFAULT_MERGE_GATE_TIMEOUT_SECONDS,
+    DEFAULT_ORCHESTRATION_PARALLELISM, DEFAULT_POST_TURN_CHECK_TIMEOUT_SECONDS,
+    MAX_ORCHESTRATION_PARALLELISM, MAX_POST_TURN_CHECK_FIX_RETRIES,
+    MERGE_GATE_TIMEOUT_OPTIONS_SECONDS, POST_TURN_CHECK_TIMEOUT_OPTIONS_SECONDS, SettingName,
 };
 pub use transcript_notice::TranscriptNotice;
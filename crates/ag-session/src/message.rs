@@ -19,6 +19,11 @@ pub enum SessionMessageKind {
     AssistantAnswer,
     /// Generic workflow notice emitted by Agentty session workflows.
     WorkflowNotice,
+    /// Serialized structured tool, file-edit, or web step from one turn.
+    ///
+    /// Activity rows back the live activity log and are hidden from plain
+    /// transcript text and provider replay.
+    TurnActivity,
 }
 
 impl SessionMessageKind {
@@ -29,6 +34,7 @@ impl SessionMessageKind {
             Self::AgentPrompt => "agent_prompt",
             Self::AssistantAnswer => "assistant_answer",
             Self::WorkflowNotice => "workflow_notice",
+            Self::TurnActivity => "turn_activity",
         }
     }
 
@@ -62,6 +68,7 @@ impl FromStr for SessionMessageKind {
             "agent_prompt" => Ok(Self::AgentPrompt),
             "assistant_answer" => Ok(Self::AssistantAnswer),
             "workflow_notice" => Ok(Self::WorkflowNotice),
+            "turn_activity" => Ok(Self::TurnActivity),
             _ => Err(SessionMessageKindParseError {
                 value: value.to_string(),
             }),
@@ -180,6 +187,60 @@ impl SessionTranscript {
         self.content_hash = transcript_content_hash(&self.messages);
     }
 
+    /// Returns how many turns the transcript contains, counting one turn per
+    /// user or generated prompt message.
+    pub fn turn_count(&self) -> u32 {
+        let prompt_count = self
+            .messages
+            .iter()
+            .filter(|message| message.kind.is_prompt())
+            .count();
+
+        u32::try_from(prompt_count).unwrap_or(u32::MAX)
+    }
+
+    /// Returns the prompt text that started one-based `turn`, when present.
+    pub fn turn_prompt(&self, turn: u32) -> Option<&str> {
+        let turn_index = usize::try_from(turn.checked_sub(1)?).ok()?;
+
+        self.messages
+            .iter()
+            .filter(|message| message.kind.is_prompt())
+            .nth(turn_index)
+            .map(|message| message.content.as_str())
+    }
+
+    /// Returns the position of the first message recorded after one-based
+    /// `turn`, or `None` when no later turn has started.
+    ///
+    /// Notices and activity rows that follow a turn's prompt belong to that
+    /// turn, so the boundary is the prompt that starts turn `turn + 1`.
+    pub fn position_after_turn(&self, turn: u32) -> Option<i64> {
+        let next_turn_index = usize::try_from(turn).ok()?;
+
+        self.messages
+            .iter()
+            .filter(|message| message.kind.is_prompt())
+            .nth(next_turn_index)
+            .map(|message| message.position)
+    }
+
+    /// Drops every message recorded after one-based `turn`.
+    pub fn truncate_after_turn(&mut self, turn: u32) {
+        let Some(boundary_position) = self.position_after_turn(turn) else {
+            return;
+        };
+
+        self.messages
+            .retain(|message| message.position < boundary_position);
+        self.total_content_len = self
+            .messages
+            .iter()
+            .map(|message| message.content.len())
+            .sum();
+        self.content_hash = transcript_content_hash(&self.messages);
+    }
+
     /// Returns formatted transcript text for replay when content exists.
     ///
     /// User and assistant rows store raw content, so replay injects the prompt
@@ -252,14 +313,17 @@ fn transcript_content_hash(messages: &[SessionMessage]) -> u64 {
 /// User prompts preserve leading horizontal whitespace so pasted indentation
 /// survives persistence while outer line breaks and trailing whitespace are
 /// normalized. Assistant rows remove outer whitespace, while workflow notices
-/// preserve exact content so status blocks keep their spacing.
+/// and serialized turn activity preserve exact content so status blocks keep
+/// their spacing.
 pub fn stored_message_content(kind: SessionMessageKind, content: &str) -> String {
     match kind {
         SessionMessageKind::UserPrompt | SessionMessageKind::AgentPrompt => {
             normalized_user_prompt_content(content)
         }
         SessionMessageKind::AssistantAnswer => normalized_message_content(content),
-        SessionMessageKind::WorkflowNotice => content.to_string(),
+        SessionMessageKind::WorkflowNotice | SessionMessageKind::TurnActivity => {
+            content.to_string()
+        }
     }
 }
 
@@ -274,6 +338,7 @@ impl SessionMessage {
                 append_assistant_answer_display_text(output, &self.content);
             }
             SessionMessageKind::WorkflowNotice => output.push_str(&self.content),
+            SessionMessageKind::TurnActivity => {}
         }
     }
 }
@@ -365,6 +430,7 @@ mod tests {
             SessionMessageKind::AgentPrompt,
             SessionMessageKind::AssistantAnswer,
             SessionMessageKind::WorkflowNotice,
+            SessionMessageKind::TurnActivity,
         ];
 
         // Act
@@ -540,6 +606,61 @@ mod tests {
         assert!(!conversation_text.contains("[Commit]"));
     }
 
+    #[test]
+    fn test_session_transcript_replay_text_hides_turn_activity() {
+        // Arrange
+        let transcript = SessionTranscript::new(vec![
+            SessionMessage::conversation(0, SessionMessageKind::UserPrompt, "prompt"),
+            SessionMessage::new(
+                1,
+                SessionMessageKind::TurnActivity,
+                r#"{"type":"web_fetch","target":"https://example.com"}"#,
+            ),
+            SessionMessage::conversation(2, SessionMessageKind::AssistantAnswer, "answer"),
+        ]);
+
+        // Act
+        let replay_text = transcript.replay_text();
+
+        // Assert
+        assert_eq!(
+            replay_text.as_deref(),
+            Some(
+                " › prompt
+
+answer
+
+"
+            )
+        );
+        assert!(!SessionMessageKind::TurnActivity.is_conversation_message());
+    }
+
+    #[test]
+    fn test_session_transcript_truncate_after_turn_keeps_turn_notices() {
+        // Arrange
+        let mut transcript = SessionTranscript::new(vec![
+            SessionMessage::conversation(0, SessionMessageKind::UserPrompt, "first"),
+            SessionMessage::conversation(1, SessionMessageKind::AssistantAnswer, "one"),
+            SessionMessage::new(2, SessionMessageKind::WorkflowNotice, "[Commit] done"),
+            SessionMessage::conversation(3, SessionMessageKind::UserPrompt, "second"),
+            SessionMessage::conversation(4, SessionMessageKind::AssistantAnswer, "two"),
+        ]);
+        let expected = SessionTranscript::new(transcript.messages()[..3].to_vec());
+
+        // Act
+        let boundary = transcript.position_after_turn(1);
+        transcript.truncate_after_turn(1);
+
+        // Assert
+        assert_eq!(boundary, Some(3));
+        assert_eq!(transcript, expected);
+        assert_eq!(transcript.turn_count(), 1);
+        assert_eq!(transcript.turn_prompt(1), Some("first"));
+        assert_eq!(transcript.turn_prompt(2), None);
+        assert_eq!(transcript.position_after_turn(1), None);
+    }
+
     #[test]
     fn test_session_transcript_conversation_replay_text_ignores_notice_only_transcript() {
         // Arrange
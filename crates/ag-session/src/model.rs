@@ -175,6 +175,18 @@ impl SessionRole {
         !matches!(self, SessionRole::Orchestrator)
     }
 
+    /// Returns whether providers must run this role under the read-only
+    /// permission policy regardless of the persisted permission mode.
+    ///
+    /// Controllers plan and verify while researchers report; neither may edit
+    /// the repository, so both receive provider-native read-only enforcement.
+    pub fn enforces_read_only(self) -> bool {
+        matches!(
+            self,
+            SessionRole::Orchestrator | SessionRole::OrchestrationResearcher
+        )
+    }
+
     /// Returns whether an end user may submit turns or branch mutations
     /// directly to this session.
     pub fn accepts_user_turns(self) -> bool {
@@ -315,6 +327,19 @@ impl SessionStatus {
         )
     }
 
+    /// Returns whether the host is still running a turn, review, or branch
+    /// action for this status.
+    pub fn is_busy(self) -> bool {
+        matches!(
+            self,
+            SessionStatus::InProgress
+                | SessionStatus::AgentReview
+                | SessionStatus::Queued
+                | SessionStatus::Rebasing
+                | SessionStatus::Merging
+        )
+    }
+
     /// Returns whether a transition to `next` is valid.
     pub fn can_transition_to(self, next: SessionStatus) -> bool {
         if self == next {
@@ -469,6 +494,21 @@ pub struct Session {
     pub updated_at: i64,
 }
 
+/// Lightweight session-list projection returned without transcript detail.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionSummary {
+    /// Session creation timestamp in Unix seconds.
+    pub created_at: i64,
+    /// Stable session identifier.
+    pub id: SessionId,
+    /// Current lifecycle status.
+    pub status: SessionStatus,
+    /// Optional user-visible session title.
+    pub title: Option<String>,
+    /// Last update timestamp in Unix seconds.
+    pub updated_at: i64,
+}
+
 /// Converts Unix timestamp seconds to a day key after applying a UTC offset.
 pub fn activity_day_key_with_offset(timestamp_seconds: i64, utc_offset_seconds: i64) -> i64 {
     timestamp_seconds
@@ -536,6 +576,27 @@ mod tests {
         assert!("Unknown".parse::<SessionStatus>().is_err());
     }
 
+    #[test]
+    fn session_status_busy_covers_running_background_work() {
+        // Arrange / Act
+        let busy_statuses = SessionStatus::ALL
+            .into_iter()
+            .filter(|status| status.is_busy())
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(
+            busy_statuses,
+            [
+                SessionStatus::InProgress,
+                SessionStatus::AgentReview,
+                SessionStatus::Queued,
+                SessionStatus::Rebasing,
+                SessionStatus::Merging,
+            ]
+        );
+    }
+
     #[test]
     fn session_role_round_trips_persisted_values() {
         // Arrange
@@ -574,6 +635,10 @@ mod tests {
         assert!(!SessionRole::OrchestrationResearcher.accepts_user_turns());
         assert!(SessionRole::OrchestrationWorker.is_managed());
         assert!(SessionRole::OrchestrationResearcher.is_managed());
+        assert!(!SessionRole::Worker.enforces_read_only());
+        assert!(!SessionRole::OrchestrationWorker.enforces_read_only());
+        assert!(SessionRole::OrchestrationResearcher.enforces_read_only());
+        assert!(SessionRole::Orchestrator.enforces_read_only());
     }
 
     #[test]
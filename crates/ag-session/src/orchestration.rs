@@ -5,7 +5,7 @@
 //! user's approval, actively fanning out, or settled; each task row tracks one
 //! child session through creation, execution, and settlement.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::str::FromStr;
 
@@ -371,12 +371,103 @@ impl OrchestrationTaskStatus {
     }
 }
 
+/// Combined verification state of one task's prerequisites.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PrerequisiteState {
+    /// Every prerequisite passed verification, or the task has none.
+    #[default]
+    Satisfied,
+    /// At least one prerequisite is still running or awaiting verification.
+    Pending,
+    /// At least one prerequisite ended without passing verification.
+    Failed,
+}
+
+impl PrerequisiteState {
+    /// Classifies one prerequisite task from its kind, status, and whether
+    /// its latest verification verdict passed.
+    ///
+    /// Implementation prerequisites are satisfied once the controller admitted
+    /// them to integration. Research prerequisites are satisfied once their
+    /// report passed verification.
+    pub fn of_prerequisite(
+        kind: OrchestrationTaskKind,
+        status: Option<OrchestrationTaskStatus>,
+        passed_verification: bool,
+    ) -> Self {
+        match (kind, status) {
+            (OrchestrationTaskKind::Research, Some(OrchestrationTaskStatus::Reported))
+                if passed_verification =>
+            {
+                Self::Satisfied
+            }
+            (
+                OrchestrationTaskKind::Implementation,
+                Some(
+                    OrchestrationTaskStatus::AwaitingIntegration
+                    | OrchestrationTaskStatus::Merging
+                    | OrchestrationTaskStatus::ReviewRequested
+                    | OrchestrationTaskStatus::Integrated,
+                ),
+            ) => Self::Satisfied,
+            (
+                _,
+                None
+                | Some(
+                    OrchestrationTaskStatus::Failed
+                    | OrchestrationTaskStatus::Canceled
+                    | OrchestrationTaskStatus::Detached
+                    | OrchestrationTaskStatus::IntegrationFailed,
+                ),
+            ) => Self::Failed,
+            _ => Self::Pending,
+        }
+    }
+}
+
+impl FromIterator<PrerequisiteState> for PrerequisiteState {
+    fn from_iter<I: IntoIterator<Item = PrerequisiteState>>(states: I) -> Self {
+        states
+            .into_iter()
+            .fold(Self::Satisfied, |combined, state| match (combined, state) {
+                (Self::Failed, _) | (_, Self::Failed) => Self::Failed,
+                (Self::Pending, _) | (_, Self::Pending) => Self::Pending,
+                _ => Self::Satisfied,
+            })
+    }
+}
+
+/// Typed scheduling observation for one orchestration task.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OrchestrationTaskObservation {
+    /// Combined verification state of the task's prerequisites.
+    pub prerequisites: PrerequisiteState,
+    /// Persisted task status, when it parsed.
+    pub status: Option<OrchestrationTaskStatus>,
+}
+
+impl OrchestrationTaskObservation {
+    /// Observes a task without prerequisites.
+    pub fn independent(status: Option<OrchestrationTaskStatus>) -> Self {
+        Self {
+            prerequisites: PrerequisiteState::Satisfied,
+            status,
+        }
+    }
+
+    /// Returns whether the task is planned but still waits on prerequisites.
+    pub fn is_blocked(self) -> bool {
+        self.status == Some(OrchestrationTaskStatus::Planned)
+            && self.prerequisites != PrerequisiteState::Satisfied
+    }
+}
+
 /// Pure scheduling decision derived from one orchestration task snapshot.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct OrchestrationScheduleDecision {
-    /// Number of planned tasks that may claim a parallelism slot.
+    /// Number of unblocked planned tasks that may claim a parallelism slot.
     pub spawn_count: usize,
-    /// Whether every non-empty task has settled and roll-up can be claimed.
+    /// Whether every startable task has settled and roll-up can be claimed.
     pub should_submit: bool,
 }
 
@@ -386,25 +477,40 @@ pub struct OrchestrationPolicy;
 impl OrchestrationPolicy {
     /// Decides fan-out capacity and roll-up readiness without persistence or
     /// runtime dependencies.
+    ///
+    /// Planned tasks blocked on prerequisites neither spawn nor hold back the
+    /// roll-up, and tasks already admitted to integration by an earlier
+    /// verification wave do not need to settle again.
     pub fn schedule(
         max_parallelism: usize,
-        task_statuses: &[Option<OrchestrationTaskStatus>],
+        tasks: &[OrchestrationTaskObservation],
     ) -> OrchestrationScheduleDecision {
-        let occupied_slots = task_statuses
+        let occupied_slots = tasks
             .iter()
-            .filter(|status| status.is_some_and(OrchestrationTaskStatus::occupies_parallelism_slot))
+            .filter(|task| {
+                task.status
+                    .is_some_and(OrchestrationTaskStatus::occupies_parallelism_slot)
+            })
             .count();
-        let planned_tasks = task_statuses
+        let startable_tasks = tasks
             .iter()
-            .filter(|status| **status == Some(OrchestrationTaskStatus::Planned))
+            .filter(|task| {
+                task.status == Some(OrchestrationTaskStatus::Planned) && !task.is_blocked()
+            })
             .count();
         let spawn_count = max_parallelism
             .saturating_sub(occupied_slots)
-            .min(planned_tasks);
-        let should_submit = !task_statuses.is_empty()
-            && task_statuses
-                .iter()
-                .all(|status| status.is_some_and(OrchestrationTaskStatus::is_settled));
+            .min(startable_tasks);
+        let should_submit = tasks
+            .iter()
+            .any(|task| task.status.is_some_and(OrchestrationTaskStatus::is_settled))
+            && tasks.iter().all(|task| {
+                task.is_blocked()
+                    || task.status.is_some_and(|status| {
+                        status.is_settled()
+                            || status == OrchestrationTaskStatus::AwaitingIntegration
+                    })
+            });
 
         OrchestrationScheduleDecision {
             spawn_count,
@@ -419,6 +525,8 @@ impl OrchestrationPolicy {
 pub struct OrchestrationPlanTask {
     /// Observable conditions checked during settlement verification.
     pub acceptance_criteria: Vec<String>,
+    /// Task keys that must pass verification before this task starts.
+    pub depends_on: Vec<String>,
     /// Whether the task implements changes or returns research findings.
     pub kind: OrchestrationTaskKind,
     /// Standalone task prompt delivered to the child session.
@@ -433,24 +541,24 @@ pub struct OrchestrationPlanTask {
 
 /// Validates one proposed subtask set before application code persists it.
 ///
+/// `existing_tasks` holds the tasks already persisted for the campaign, so
+/// follow-up and retry proposals may depend on earlier work. A proposed task
+/// replaces the existing task with the same key.
+///
 /// # Errors
 ///
-/// Returns a user-facing reason when the plan is too small, incomplete, or uses
-/// invalid task keys or planning paths.
-pub fn validate_subtasks(subtasks: &[OrchestrationPlanTask], is_retry: bool) -> Result<(), String> {
-    let is_research_wave = !subtasks.is_empty()
-        && subtasks
-            .iter()
-            .all(|subtask| subtask.kind == OrchestrationTaskKind::Research);
-    let has_research = subtasks
+/// Returns a user-facing reason when the plan is too small, incomplete, uses
+/// invalid task keys or planning paths, or declares unusable dependencies.
+pub fn validate_subtasks(
+    subtasks: &[OrchestrationPlanTask],
+    existing_tasks: &[OrchestrationPlanTask],
+    is_retry: bool,
+) -> Result<(), String> {
+    let implementation_count = subtasks
         .iter()
-        .any(|subtask| subtask.kind == OrchestrationTaskKind::Research);
-    if has_research && !is_research_wave {
-        return Err(
-            "research and implementation tasks must be proposed in separate waves.".to_string(),
-        );
-    }
-    if subtasks.len() < 2 && !is_retry && !is_research_wave {
+        .filter(|subtask| subtask.kind == OrchestrationTaskKind::Implementation)
+        .count();
+    if !is_retry && (subtasks.is_empty() || implementation_count == 1) {
         return Err("a meaningful orchestration requires at least two subtasks.".to_string());
     }
     let mut task_keys = HashSet::new();
@@ -486,9 +594,132 @@ pub fn validate_subtasks(subtasks: &[OrchestrationPlanTask], is_retry: bool) ->
         }
     }
 
+    validate_dependencies(subtasks, &task_graph(subtasks, existing_tasks))
+}
+
+/// Returns the dependency wave of every proposed subtask, in input order.
+///
+/// A task without prerequisites belongs to wave `0`; every other task belongs
+/// to the wave after its latest prerequisite. Waves are computed over the
+/// proposal merged with `existing_tasks`, so follow-up work lands after the
+/// campaign tasks it depends on. Call [`validate_subtasks`] first; unknown or
+/// cyclic dependencies resolve to wave `0`.
+pub fn dependency_waves(
+    subtasks: &[OrchestrationPlanTask],
+    existing_tasks: &[OrchestrationPlanTask],
+) -> Vec<i64> {
+    let waves = task_waves(&task_graph(subtasks, existing_tasks)).unwrap_or_default();
+
+    subtasks
+        .iter()
+        .map(|subtask| {
+            waves
+                .get(subtask.task_key.as_str())
+                .copied()
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn task_graph<'a>(
+    subtasks: &'a [OrchestrationPlanTask],
+    existing_tasks: &'a [OrchestrationPlanTask],
+) -> HashMap<&'a str, &'a OrchestrationPlanTask> {
+    existing_tasks
+        .iter()
+        .chain(subtasks)
+        .map(|task| (task.task_key.as_str(), task))
+        .collect()
+}
+
+fn validate_dependencies(
+    subtasks: &[OrchestrationPlanTask],
+    graph: &HashMap<&str, &OrchestrationPlanTask>,
+) -> Result<(), String> {
+    for subtask in subtasks {
+        let mut dependencies = HashSet::new();
+        let mut implementation_dependencies = 0_usize;
+        for dependency in &subtask.depends_on {
+            if *dependency == subtask.task_key {
+                return Err(format!(
+                    "subtask `{}` cannot depend on itself.",
+                    subtask.task_key
+                ));
+            }
+            let Some(prerequisite) = graph.get(dependency.as_str()) else {
+                return Err(format!(
+                    "subtask `{}` depends on unknown task `{dependency}`.",
+                    subtask.task_key
+                ));
+            };
+            if !dependencies.insert(dependency.as_str()) {
+                return Err(format!(
+                    "subtask `{}` lists dependency `{dependency}` more than once.",
+                    subtask.task_key
+                ));
+            }
+            if prerequisite.kind == OrchestrationTaskKind::Implementation {
+                implementation_dependencies += 1;
+            }
+        }
+        if subtask.kind == OrchestrationTaskKind::Research && implementation_dependencies > 0 {
+            return Err(format!(
+                "research subtask `{}` can only depend on research tasks.",
+                subtask.task_key
+            ));
+        }
+        if implementation_dependencies > 1 {
+            return Err(format!(
+                "subtask `{}` can depend on at most one implementation task because its worktree \
+                 starts from that task's branch.",
+                subtask.task_key
+            ));
+        }
+    }
+    if task_waves(graph).is_none() {
+        return Err("subtask dependencies must not form a cycle.".to_string());
+    }
+
     Ok(())
 }
 
+/// Resolves dependency depth for every task, or `None` when the graph has a
+/// cycle. Dependencies outside the graph are ignored.
+fn task_waves<'a>(
+    graph: &HashMap<&'a str, &'a OrchestrationPlanTask>,
+) -> Option<HashMap<&'a str, i64>> {
+    let mut waves = HashMap::with_capacity(graph.len());
+    while waves.len() < graph.len() {
+        let mut resolved_any = false;
+        for (task_key, task) in graph {
+            if waves.contains_key(task_key) {
+                continue;
+            }
+            let mut wave = Some(0_i64);
+            for dependency in task
+                .depends_on
+                .iter()
+                .filter(|dependency| graph.contains_key(dependency.as_str()))
+            {
+                wave = wave.zip(waves.get(dependency.as_str()).copied()).map(
+                    |(wave, dependency_wave): (i64, i64)| {
+                        wave.max(dependency_wave.saturating_add(1))
+                    },
+                );
+            }
+            if let Some(wave) = wave {
+                waves.insert(*task_key, wave);
+                resolved_any = true;
+            }
+        }
+        if !resolved_any {
+            return None;
+        }
+    }
+
+    Some(waves)
+}
+
 fn is_kebab_case_task_key(task_key: &str) -> bool {
     !task_key.is_empty()
         && task_key.split('-').all(|segment| {
@@ -849,6 +1080,7 @@ mod tests {
         // Arrange
         let plan = [OrchestrationPlanTask {
             acceptance_criteria: vec!["Architecture questions are answered".to_string()],
+            depends_on: Vec::new(),
             kind: OrchestrationTaskKind::Research,
             prompt: "Inspect the architecture".to_string(),
             task_key: "architecture".to_string(),
@@ -857,7 +1089,7 @@ mod tests {
         }];
 
         // Act
-        let result = validate_subtasks(&plan, false);
+        let result = validate_subtasks(&plan, &[], false);
 
         // Assert
         assert_eq!(result, Ok(()));
@@ -868,6 +1100,7 @@ mod tests {
         // Arrange
         let single = [OrchestrationPlanTask {
             acceptance_criteria: vec!["Feature is complete".to_string()],
+            depends_on: Vec::new(),
             kind: OrchestrationTaskKind::Implementation,
             prompt: "Implement the feature".to_string(),
             task_key: "feature".to_string(),
@@ -885,22 +1118,10 @@ mod tests {
                 ..single[0].clone()
             },
         ];
-        let mixed = [
-            OrchestrationPlanTask {
-                kind: OrchestrationTaskKind::Research,
-                ..single[0].clone()
-            },
-            OrchestrationPlanTask {
-                task_key: "implementation".to_string(),
-                ..single[0].clone()
-            },
-        ];
-
         // Act
-        let single_result = validate_subtasks(&single, false);
-        let empty_result = validate_subtasks(&[], false);
-        let scope_result = validate_subtasks(&invalid_scope, false);
-        let mixed_result = validate_subtasks(&mixed, false);
+        let single_result = validate_subtasks(&single, &[], false);
+        let empty_result = validate_subtasks(&[], &[], false);
+        let scope_result = validate_subtasks(&invalid_scope, &[], false);
 
         // Assert
         assert_eq!(
@@ -912,30 +1133,207 @@ mod tests {
             Err("a meaningful orchestration requires at least two subtasks.".to_string())
         );
         assert!(scope_result.is_err_and(|reason| reason.contains("wildcard patterns")));
+    }
+
+    /// Builds one valid plan task with explicit prerequisites.
+    fn dependent_task(
+        task_key: &str,
+        kind: OrchestrationTaskKind,
+        depends_on: &[&str],
+    ) -> OrchestrationPlanTask {
+        OrchestrationPlanTask {
+            acceptance_criteria: vec![format!("{task_key} is complete")],
+            depends_on: depends_on.iter().map(ToString::to_string).collect(),
+            kind,
+            prompt: format!("Complete {task_key}"),
+            task_key: task_key.to_string(),
+            title: task_key.to_string(),
+            touched_areas: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn validation_accepts_mixed_dependency_waves_and_computes_depth() {
+        // Arrange
+        let plan = [
+            dependent_task("survey", OrchestrationTaskKind::Research, &[]),
+            dependent_task("schema", OrchestrationTaskKind::Implementation, &["survey"]),
+            dependent_task(
+                "callers",
+                OrchestrationTaskKind::Implementation,
+                &["schema"],
+            ),
+            dependent_task("docs", OrchestrationTaskKind::Implementation, &[]),
+        ];
+        let existing = [dependent_task(
+            "schema",
+            OrchestrationTaskKind::Implementation,
+            &[],
+        )];
+        let follow_up = [dependent_task(
+            "tests",
+            OrchestrationTaskKind::Implementation,
+            &["schema"],
+        )];
+
+        // Act
+        let result = validate_subtasks(&plan, &[], false);
+        let follow_up_result = validate_subtasks(&follow_up, &existing, true);
+        let waves = dependency_waves(&plan, &[]);
+        let follow_up_waves = dependency_waves(&follow_up, &existing);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(follow_up_result, Ok(()));
+        assert_eq!(waves, vec![0, 1, 2, 0]);
+        assert_eq!(follow_up_waves, vec![1]);
+    }
+
+    #[test]
+    fn validation_rejects_unusable_dependencies() {
+        // Arrange
+        let implementation = OrchestrationTaskKind::Implementation;
+        let research = OrchestrationTaskKind::Research;
+        let cases = [
+            (
+                vec![
+                    dependent_task("one", implementation, &["one"]),
+                    dependent_task("two", implementation, &[]),
+                ],
+                "subtask `one` cannot depend on itself.",
+            ),
+            (
+                vec![
+                    dependent_task("one", implementation, &["missing"]),
+                    dependent_task("two", implementation, &[]),
+                ],
+                "subtask `one` depends on unknown task `missing`.",
+            ),
+            (
+                vec![
+                    dependent_task("one", implementation, &["two", "two"]),
+                    dependent_task("two", implementation, &[]),
+                ],
+                "subtask `one` lists dependency `two` more than once.",
+            ),
+            (
+                vec![
+                    dependent_task("one", implementation, &[]),
+                    dependent_task("two", implementation, &[]),
+                    dependent_task("notes", research, &["one"]),
+                ],
+                "research subtask `notes` can only depend on research tasks.",
+            ),
+            (
+                vec![
+                    dependent_task("one", implementation, &[]),
+                    dependent_task("two", implementation, &[]),
+                    dependent_task("three", implementation, &["one", "two"]),
+                ],
+                "subtask `three` can depend on at most one implementation task because its \
+                 worktree starts from that task's branch.",
+            ),
+            (
+                vec![
+                    dependent_task("one", implementation, &["two"]),
+                    dependent_task("two", implementation, &["one"]),
+                ],
+                "subtask dependencies must not form a cycle.",
+            ),
+        ];
+
+        for (plan, expected_reason) in cases {
+            // Act
+            let result = validate_subtasks(&plan, &[], false);
+
+            // Assert
+            assert_eq!(result, Err(expected_reason.to_string()));
+        }
+    }
+
+    #[test]
+    fn prerequisite_state_requires_a_verified_prerequisite() {
+        // Arrange
+        let implementation = OrchestrationTaskKind::Implementation;
+        let research = OrchestrationTaskKind::Research;
+
+        // Act
+        let states = [
+            PrerequisiteState::of_prerequisite(
+                implementation,
+                Some(OrchestrationTaskStatus::AwaitingIntegration),
+                false,
+            ),
+            PrerequisiteState::of_prerequisite(
+                implementation,
+                Some(OrchestrationTaskStatus::Ready),
+                true,
+            ),
+            PrerequisiteState::of_prerequisite(
+                implementation,
+                Some(OrchestrationTaskStatus::IntegrationFailed),
+                true,
+            ),
+            PrerequisiteState::of_prerequisite(
+                research,
+                Some(OrchestrationTaskStatus::Reported),
+                true,
+            ),
+            PrerequisiteState::of_prerequisite(
+                research,
+                Some(OrchestrationTaskStatus::Reported),
+                false,
+            ),
+            PrerequisiteState::of_prerequisite(research, None, false),
+        ];
+        let combined_pending = [PrerequisiteState::Satisfied, PrerequisiteState::Pending]
+            .into_iter()
+            .collect::<PrerequisiteState>();
+        let combined_failed = [PrerequisiteState::Pending, PrerequisiteState::Failed]
+            .into_iter()
+            .collect::<PrerequisiteState>();
+        let combined_empty = std::iter::empty().collect::<PrerequisiteState>();
+
+        // Assert
         assert_eq!(
-            mixed_result,
-            Err(
-                "research and implementation tasks must be proposed in separate waves.".to_string()
-            )
+            states,
+            [
+                PrerequisiteState::Satisfied,
+                PrerequisiteState::Pending,
+                PrerequisiteState::Failed,
+                PrerequisiteState::Satisfied,
+                PrerequisiteState::Pending,
+                PrerequisiteState::Failed,
+            ]
         );
+        assert_eq!(combined_pending, PrerequisiteState::Pending);
+        assert_eq!(combined_failed, PrerequisiteState::Failed);
+        assert_eq!(combined_empty, PrerequisiteState::Satisfied);
     }
 
     #[test]
     /// Derives fan-out capacity and roll-up readiness from typed task states.
     fn test_orchestration_policy_schedules_available_slots_and_settlement() {
         // Arrange
-        let active_statuses = [
+        let observe = |statuses: &[Option<OrchestrationTaskStatus>]| {
+            statuses
+                .iter()
+                .copied()
+                .map(OrchestrationTaskObservation::independent)
+                .collect::<Vec<_>>()
+        };
+        let active_statuses = observe(&[
             Some(OrchestrationTaskStatus::Running),
             Some(OrchestrationTaskStatus::WaitingForInput),
             Some(OrchestrationTaskStatus::Planned),
             Some(OrchestrationTaskStatus::Planned),
-        ];
-        let settled_statuses = [
+        ]);
+        let settled_statuses = observe(&[
             Some(OrchestrationTaskStatus::Ready),
             Some(OrchestrationTaskStatus::Failed),
             Some(OrchestrationTaskStatus::Canceled),
-        ];
-        let invalid_statuses = [Some(OrchestrationTaskStatus::Ready), None];
+        ]);
+        let invalid_statuses = observe(&[Some(OrchestrationTaskStatus::Ready), None]);
 
         // Act
         let active_decision = OrchestrationPolicy::schedule(3, &active_statuses);
@@ -968,6 +1366,57 @@ mod tests {
         assert!(!invalid_decision.should_submit);
     }
 
+    #[test]
+    fn orchestration_policy_holds_blocked_tasks_and_verifies_each_wave() {
+        // Arrange
+        let blocked = OrchestrationTaskObservation {
+            prerequisites: PrerequisiteState::Pending,
+            status: Some(OrchestrationTaskStatus::Planned),
+        };
+        let unblocked = OrchestrationTaskObservation {
+            prerequisites: PrerequisiteState::Satisfied,
+            status: Some(OrchestrationTaskStatus::Planned),
+        };
+        let first_wave = [
+            OrchestrationTaskObservation::independent(Some(OrchestrationTaskStatus::Ready)),
+            blocked,
+        ];
+        let second_wave = [
+            OrchestrationTaskObservation::independent(Some(
+                OrchestrationTaskStatus::AwaitingIntegration,
+            )),
+            unblocked,
+        ];
+        let verified_second_wave = [
+            OrchestrationTaskObservation::independent(Some(
+                OrchestrationTaskStatus::AwaitingIntegration,
+            )),
+            OrchestrationTaskObservation::independent(Some(OrchestrationTaskStatus::Ready)),
+        ];
+
+        // Act
+        let first_decision = OrchestrationPolicy::schedule(3, &first_wave);
+        let second_decision = OrchestrationPolicy::schedule(3, &second_wave);
+        let verified_decision = OrchestrationPolicy::schedule(3, &verified_second_wave);
+
+        // Assert
+        assert_eq!(
+            first_decision,
+            OrchestrationScheduleDecision {
+                spawn_count: 0,
+                should_submit: true,
+            }
+        );
+        assert_eq!(
+            second_decision,
+            OrchestrationScheduleDecision {
+                spawn_count: 1,
+                should_submit: false,
+            }
+        );
+        assert!(verified_decision.should_submit);
+    }
+
     #[test]
     /// Maps every child-session lifecycle family into orchestration policy.
     fn test_task_status_from_child_status_covers_session_lifecycle() {
@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
-use crate::{ReviewRequest, Session, SessionError, SessionId};
+use crate::{ReviewRequest, Session, SessionError, SessionId, SessionSummary};
 
 /// Creation strategy for a new session.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -20,6 +20,9 @@ pub enum CreateSessionMode {
     OrchestrationChild {
         /// Durable task row used to re-link the child after restart.
         task_id: i64,
+        /// Verified prerequisite worker whose branch becomes the worktree
+        /// start point, when the task depends on unintegrated work.
+        prerequisite_session_id: Option<SessionId>,
     },
     /// Creates one temporary read-only researcher owned by an orchestration
     /// task.
@@ -32,6 +35,12 @@ pub enum CreateSessionMode {
         /// Review-ready parent session whose branch becomes the stack base.
         parent_session_id: SessionId,
     },
+    /// Creates a session on the head of an existing forge review request and
+    /// starts it on the request's unresolved review threads.
+    ReviewRequestImport {
+        /// Review-request number, display id such as `#42`, or browser URL.
+        reference: String,
+    },
 }
 
 /// Explicit input for creating one session.
@@ -99,6 +108,9 @@ pub trait SessionBackend: Send + Sync {
     /// Loads one complete session aggregate, including settings and messages.
     async fn get_session(&self, session_id: &SessionId) -> Result<Option<Session>, SessionError>;
 
+    /// Lists lightweight summaries for the sessions owned by one project.
+    async fn list_sessions(&self, project_id: i64) -> Result<Vec<SessionSummary>, SessionError>;
+
     /// Sends one text message, starting, resuming, or queueing as appropriate.
     async fn send_message(
         &self,
@@ -169,6 +181,19 @@ impl SessionService {
         self.backend.get_session(session_id).await
     }
 
+    /// Lists lightweight summaries for the sessions owned by one project,
+    /// ordered by most recent update.
+    ///
+    /// # Errors
+    /// Returns an error when the project is unavailable or persisted data
+    /// cannot be loaded or decoded.
+    pub async fn list_sessions(
+        &self,
+        project_id: i64,
+    ) -> Result<Vec<SessionSummary>, SessionError> {
+        self.backend.list_sessions(project_id).await
+    }
+
     /// Sends a text message to one session.
     ///
     /// # Errors
@@ -278,6 +303,7 @@ mod tests {
         calls: Vec<String>,
         create_results: VecDeque<Result<SessionId, SessionError>>,
         get_result: Option<Result<Option<Session>, SessionError>>,
+        list_result: Option<Result<Vec<SessionSummary>, SessionError>>,
         review_result: Option<Result<ReviewRequest, SessionError>>,
         unit_results: VecDeque<Result<(), SessionError>>,
     }
@@ -312,6 +338,22 @@ mod tests {
                 .unwrap_or_else(|| Err(SessionError::Operation("missing result".to_string())))
         }
 
+        async fn list_sessions(
+            &self,
+            project_id: i64,
+        ) -> Result<Vec<SessionSummary>, SessionError> {
+            let mut state = self
+                .state
+                .lock()
+                .expect("fake backend state should remain available");
+            state.calls.push(format!("list:{project_id}"));
+
+            state
+                .list_result
+                .clone()
+                .unwrap_or_else(|| Err(SessionError::Operation("missing result".to_string())))
+        }
+
         async fn send_message(
             &self,
             session_id: &SessionId,
@@ -464,9 +506,17 @@ mod tests {
     async fn service_delegates_create_and_get() {
         // Arrange
         let expected_session = session_fixture();
+        let expected_summary = SessionSummary {
+            created_at: expected_session.created_at,
+            id: expected_session.id.clone(),
+            status: expected_session.status,
+            title: expected_session.title.clone(),
+            updated_at: expected_session.updated_at,
+        };
         let backend = Arc::new(FakeBackend::from_state(FakeBackendState {
             create_results: VecDeque::from([Ok(SessionId::from("session-1"))]),
             get_result: Some(Ok(Some(expected_session.clone()))),
+            list_result: Some(Ok(vec![expected_summary.clone()])),
             ..FakeBackendState::default()
         }));
         let service = SessionService::new(backend.clone());
@@ -484,10 +534,15 @@ mod tests {
             .get_session(&session_id)
             .await
             .expect("session should load");
+        let listed_sessions = service
+            .list_sessions(7)
+            .await
+            .expect("sessions should list");
 
         // Assert
         assert_eq!(loaded_session, Some(expected_session));
-        assert_eq!(backend.calls(), ["create:Regular"]);
+        assert_eq!(listed_sessions, [expected_summary]);
+        assert_eq!(backend.calls(), ["create:Regular", "list:7"]);
     }
 
     #[tokio::test]
@@ -601,6 +656,10 @@ mod tests {
             .get_session(&session_id)
             .await
             .expect_err("get should require a result");
+        let list_error = service
+            .list_sessions(7)
+            .await
+            .expect_err("list should require a result");
         let send_error = service
             .send_message(&session_id, "continue")
             .await
@@ -640,6 +699,7 @@ mod tests {
         let errors = [
             create_error,
             get_error,
+            list_error,
             send_error,
             coordinator_error,
             answer_error,
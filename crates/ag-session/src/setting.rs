@@ -8,6 +8,17 @@ pub const DEFAULT_AUTO_APPROVE_ORCHESTRATION_RESEARCH: bool = true;
 pub const DEFAULT_ORCHESTRATION_PARALLELISM: u8 = 3;
 /// Maximum orchestration concurrency exposed by the settings selector.
 pub const MAX_ORCHESTRATION_PARALLELISM: u8 = 8;
+/// Default time budget for one merge-gate command, in seconds.
+pub const DEFAULT_MERGE_GATE_TIMEOUT_SECONDS: u64 = 1800;
+/// Merge-gate timeouts exposed by the settings selector, in seconds.
+pub const MERGE_GATE_TIMEOUT_OPTIONS_SECONDS: [u64; 4] = [300, 900, 1800, 3600];
+/// Maximum automatic fix turns requested for consecutive failed post-turn
+/// checks.
+pub const MAX_POST_TURN_CHECK_FIX_RETRIES: u8 = 5;
+/// Default time budget for one post-turn check command, in seconds.
+pub const DEFAULT_POST_TURN_CHECK_TIMEOUT_SECONDS: u64 = 300;
+/// Post-turn check timeouts exposed by the settings selector, in seconds.
+pub const POST_TURN_CHECK_TIMEOUT_OPTIONS_SECONDS: [u64; 4] = [60, 300, 600, 1800];
 
 /// Stable keys used in the `setting` and `project_setting` tables.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -42,6 +53,12 @@ pub enum SettingName {
     DefaultSmartReasoningLevel,
     /// Persists the response speed paired with the smart-model default.
     DefaultSmartSpeedMode,
+    /// Persists whether project review requests use forge CLIs or direct
+    /// forge HTTP APIs.
+    ForgeBackend,
+    /// Persists the project hosts treated as Forgejo or Gitea instances during
+    /// review-request remote detection.
+    ForgejoHosts,
     /// Persists whether generated session commits append the Agentty coauthor
     /// trailer.
     IncludeCoauthoredByAgentty,
@@ -49,8 +66,23 @@ pub enum SettingName {
     LaunchConfiguration,
     /// Persists whether the last used model should become the default.
     LastUsedModelAsDefault,
+    /// Persists the newline-separated commands that must pass before a
+    /// project session squash-merges.
+    MergeGateCommands,
+    /// Persists whether a failed merge gate starts an agent fix turn.
+    MergeGateFixTurn,
+    /// Persists the per-command merge-gate timeout in seconds.
+    MergeGateTimeoutSeconds,
     /// Persists how many orchestration children may run at once.
     OrchestrationParallelism,
+    /// Persists the newline-separated commands run after each project
+    /// session turn.
+    PostTurnCheckCommands,
+    /// Persists how many consecutive failed post-turn checks start an agent
+    /// fix turn.
+    PostTurnCheckFixRetries,
+    /// Persists the per-command post-turn check timeout in seconds.
+    PostTurnCheckTimeoutSeconds,
     /// Persists the active terminal color theme.
     Theme,
 }
@@ -74,10 +106,18 @@ impl SettingName {
             Self::DefaultSmartModel => "DefaultSmartModel",
             Self::DefaultSmartReasoningLevel => "DefaultSmartReasoningLevel",
             Self::DefaultSmartSpeedMode => "DefaultSmartSpeedMode",
+            Self::ForgeBackend => "ForgeBackend",
+            Self::ForgejoHosts => "ForgejoHosts",
             Self::IncludeCoauthoredByAgentty => "IncludeCoauthoredByAgentty",
             Self::LaunchConfiguration => "LaunchConfiguration",
             Self::LastUsedModelAsDefault => "LastUsedModelAsDefault",
+            Self::MergeGateCommands => "MergeGateCommands",
+            Self::MergeGateFixTurn => "MergeGateFixTurn",
+            Self::MergeGateTimeoutSeconds => "MergeGateTimeoutSeconds",
             Self::OrchestrationParallelism => "OrchestrationParallelism",
+            Self::PostTurnCheckCommands => "PostTurnCheckCommands",
+            Self::PostTurnCheckFixRetries => "PostTurnCheckFixRetries",
+            Self::PostTurnCheckTimeoutSeconds => "PostTurnCheckTimeoutSeconds",
             Self::Theme => "Theme",
         }
     }
@@ -128,6 +168,8 @@ mod tests {
                 "DefaultSmartReasoningLevel",
             ),
             (SettingName::DefaultSmartSpeedMode, "DefaultSmartSpeedMode"),
+            (SettingName::ForgeBackend, "ForgeBackend"),
+            (SettingName::ForgejoHosts, "ForgejoHosts"),
             (
                 SettingName::IncludeCoauthoredByAgentty,
                 "IncludeCoauthoredByAgentty",
@@ -137,10 +179,25 @@ mod tests {
                 SettingName::LastUsedModelAsDefault,
                 "LastUsedModelAsDefault",
             ),
+            (SettingName::MergeGateCommands, "MergeGateCommands"),
+            (SettingName::MergeGateFixTurn, "MergeGateFixTurn"),
+            (
+                SettingName::MergeGateTimeoutSeconds,
+                "MergeGateTimeoutSeconds",
+            ),
             (
                 SettingName::OrchestrationParallelism,
                 "OrchestrationParallelism",
             ),
+            (SettingName::PostTurnCheckCommands, "PostTurnCheckCommands"),
+            (
+                SettingName::PostTurnCheckFixRetries,
+                "PostTurnCheckFixRetries",
+            ),
+            (
+                SettingName::PostTurnCheckTimeoutSeconds,
+                "PostTurnCheckTimeoutSeconds",
+            ),
             (SettingName::Theme, "Theme"),
         ];
 
@@ -170,10 +227,18 @@ mod tests {
             SettingName::DefaultSmartModel,
             SettingName::DefaultSmartReasoningLevel,
             SettingName::DefaultSmartSpeedMode,
+            SettingName::ForgeBackend,
+            SettingName::ForgejoHosts,
             SettingName::IncludeCoauthoredByAgentty,
             SettingName::LaunchConfiguration,
             SettingName::LastUsedModelAsDefault,
+            SettingName::MergeGateCommands,
+            SettingName::MergeGateFixTurn,
+            SettingName::MergeGateTimeoutSeconds,
             SettingName::OrchestrationParallelism,
+            SettingName::PostTurnCheckCommands,
+            SettingName::PostTurnCheckFixRetries,
+            SettingName::PostTurnCheckTimeoutSeconds,
             SettingName::Theme,
         ];
 
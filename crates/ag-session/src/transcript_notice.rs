@@ -14,6 +14,10 @@ pub enum TranscriptNotice {
     BranchPush,
     /// Automatic published-branch push failure.
     BranchPushError,
+    /// Post-turn check command progress.
+    Checks,
+    /// Post-turn check command failure.
+    ChecksError,
     /// Session auto-commit result.
     Commit,
     /// Agent-assisted auto-commit recovery attempt.
@@ -24,6 +28,8 @@ pub enum TranscriptNotice {
     CommitWarning,
     /// Follow-on session creation failure.
     ContinueError,
+    /// Read-only controller changed its worktree during a provider turn.
+    ControllerEditWarning,
     /// Generic prompt submission failure.
     Error,
     /// Session fork creation failure.
@@ -34,6 +40,10 @@ pub enum TranscriptNotice {
     Merge,
     /// Merge workflow failure.
     MergeError,
+    /// Merge-gate command progress.
+    MergeGate,
+    /// Merge-gate command failure.
+    MergeGateError,
     /// Main checkout changed during a provider turn.
     MainCheckoutWarning,
     /// Prompt image-paste failure.
@@ -50,6 +60,14 @@ pub enum TranscriptNotice {
     RebaseError,
     /// Reply submission failure.
     ReplyError,
+    /// Diff-view revert of selected changed lines or hunks.
+    Revert,
+    /// Diff-view revert failure.
+    RevertError,
+    /// Session rewind to an earlier turn checkpoint.
+    Rewind,
+    /// Session rewind failure.
+    RewindError,
     /// Review-request creation result.
     ReviewRequest,
     /// Successful forge review-thread replies and resolution.
@@ -71,16 +89,21 @@ impl TranscriptNotice {
             Self::Apply => "[Apply]",
             Self::BranchPush => "[Branch Push]",
             Self::BranchPushError => "[Branch Push Error]",
+            Self::Checks => "[Checks]",
+            Self::ChecksError => "[Checks Error]",
             Self::Commit => "[Commit]",
             Self::CommitAssist => "[Commit Assist]",
             Self::CommitError => "[Commit Error]",
             Self::CommitWarning => "[Commit Warning]",
             Self::ContinueError => "[Continue Error]",
+            Self::ControllerEditWarning => "[Controller Edit Warning]",
             Self::Error => "[Error]",
             Self::ForkError => "[Fork Error]",
             Self::FollowUpTaskError => "[Follow-Up Task Error]",
             Self::Merge => "[Merge]",
             Self::MergeError => "[Merge Error]",
+            Self::MergeGate => "[Merge Gate]",
+            Self::MergeGateError => "[Merge Gate Error]",
             Self::MainCheckoutWarning => "[Main Checkout Warning]",
             Self::PasteImageError => "[Paste Image Error]",
             Self::Personality => "[Personality]",
@@ -89,6 +112,10 @@ impl TranscriptNotice {
             Self::RebaseAssist => "[Sync Assist]",
             Self::RebaseError => "[Sync Error]",
             Self::ReplyError => "[Reply Error]",
+            Self::Revert => "[Revert]",
+            Self::RevertError => "[Revert Error]",
+            Self::Rewind => "[Rewind]",
+            Self::RewindError => "[Rewind Error]",
             Self::ReviewRequest => "[Review Request]",
             Self::ReviewComments => "[Review Comments]",
             Self::ReviewCommentsWarning => "[Review Comments Warning]",
@@ -121,11 +148,17 @@ mod tests {
             (TranscriptNotice::Apply, "[Apply]"),
             (TranscriptNotice::BranchPush, "[Branch Push]"),
             (TranscriptNotice::BranchPushError, "[Branch Push Error]"),
+            (TranscriptNotice::Checks, "[Checks]"),
+            (TranscriptNotice::ChecksError, "[Checks Error]"),
             (TranscriptNotice::Commit, "[Commit]"),
             (TranscriptNotice::CommitAssist, "[Commit Assist]"),
             (TranscriptNotice::CommitError, "[Commit Error]"),
             (TranscriptNotice::CommitWarning, "[Commit Warning]"),
             (TranscriptNotice::ContinueError, "[Continue Error]"),
+            (
+                TranscriptNotice::ControllerEditWarning,
+                "[Controller Edit Warning]",
+            ),
             (TranscriptNotice::Error, "[Error]"),
             (TranscriptNotice::ForkError, "[Fork Error]"),
             (
@@ -134,6 +167,8 @@ mod tests {
             ),
             (TranscriptNotice::Merge, "[Merge]"),
             (TranscriptNotice::MergeError, "[Merge Error]"),
+            (TranscriptNotice::MergeGate, "[Merge Gate]"),
+            (TranscriptNotice::MergeGateError, "[Merge Gate Error]"),
             (
                 TranscriptNotice::MainCheckoutWarning,
                 "[Main Checkout Warning]",
@@ -145,6 +180,10 @@ mod tests {
             (TranscriptNotice::RebaseAssist, "[Sync Assist]"),
             (TranscriptNotice::RebaseError, "[Sync Error]"),
             (TranscriptNotice::ReplyError, "[Reply Error]"),
+            (TranscriptNotice::Revert, "[Revert]"),
+            (TranscriptNotice::RevertError, "[Revert Error]"),
+            (TranscriptNotice::Rewind, "[Rewind]"),
+            (TranscriptNotice::RewindError, "[Rewind Error]"),
             (TranscriptNotice::ReviewRequest, "[Review Request]"),
             (TranscriptNotice::ReviewComments, "[Review Comments]"),
             (
@@ -0,0 +1,5 @@
+-- Persist the prerequisite task keys and derived dependency wave of each
+-- orchestration task so dependent workers start only after verified
+-- prerequisites.
+ALTER TABLE session_orchestration_task ADD COLUMN depends_on TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE session_orchestration_task ADD COLUMN wave INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,7 @@
+-- Persist pending and active merge-queue entries so queued merges survive an
+-- application restart in their original FIFO order.
+CREATE TABLE session_merge_queue (
+    position INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id TEXT NOT NULL UNIQUE REFERENCES session(id) ON DELETE CASCADE,
+    queued_at INTEGER NOT NULL
+);
@@ -3,6 +3,7 @@
 mod activity;
 mod connection;
 mod error;
+mod merge_queue;
 mod operation;
 mod orchestration;
 mod project;
@@ -21,14 +22,16 @@ pub(crate) use activity::SqliteActivityRepository;
 pub use connection::Database;
 pub use error::DbError;
 pub(crate) use error::DbResultExt;
+pub(crate) use merge_queue::SqliteMergeQueueRepository;
+pub use merge_queue::{MergeQueueRepository, MergeQueueRow};
 pub(crate) use operation::SqliteOperationRepository;
 pub use operation::{OperationRepository, SessionOperationRow};
 #[cfg(any(test, feature = "test-utils"))]
 pub use orchestration::MockOrchestrationRepository;
 pub(crate) use orchestration::SqliteOrchestrationRepository;
 pub use orchestration::{
-    OrchestrationRepository, PersistedOrchestrationTask, SessionOrchestrationMetadataRow,
-    SessionOrchestrationRow, SessionOrchestrationTaskRow,
+    OrchestrationRepository, OrchestrationTaskEdit, PersistedOrchestrationTask,
+    SessionOrchestrationMetadataRow, SessionOrchestrationRow, SessionOrchestrationTaskRow,
 };
 pub(crate) use project::SqliteProjectRepository;
 pub use project::{ProjectListRow, ProjectRepository, ProjectRow};
@@ -0,0 +1,250 @@
+//! Merge-queue persistence adapters and query helpers.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::timestamp::TimestampSource;
+use crate::{DbError, DbResultExt};
+
+/// Persisted merge-queue entry joined with its session lifecycle status.
+pub struct MergeQueueRow {
+    /// Session waiting for or running its squash merge.
+    pub session_id: String,
+    /// Persisted session lifecycle status.
+    pub status: String,
+}
+
+/// Merge-queue persistence boundary used by app orchestration and tests.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait MergeQueueRepository: Send + Sync {
+    /// Appends one session to the persisted FIFO merge queue.
+    ///
+    /// Sessions that already own a queue entry keep their original position.
+    async fn enqueue_merge_session(&self, session_id: &str) -> Result<(), DbError>;
+
+    /// Loads every persisted merge-queue entry in FIFO order.
+    async fn load_merge_queue(&self) -> Result<Vec<MergeQueueRow>, DbError>;
+
+    /// Loads persisted merge-queue session ids for one project in FIFO order.
+    async fn load_project_merge_queue_session_ids(
+        &self,
+        project_id: i64,
+    ) -> Result<Vec<String>, DbError>;
+
+    /// Removes one session from the persisted merge queue.
+    async fn remove_merge_session(&self, session_id: &str) -> Result<(), DbError>;
+}
+
+/// `SQLite` implementation of [`MergeQueueRepository`].
+#[derive(Clone)]
+pub(crate) struct SqliteMergeQueueRepository {
+    pool: SqlitePool,
+    timestamp_source: Arc<dyn TimestampSource>,
+}
+
+impl SqliteMergeQueueRepository {
+    /// Creates a merge-queue repository backed by the provided pool.
+    pub(crate) fn new(pool: SqlitePool, timestamp_source: Arc<dyn TimestampSource>) -> Self {
+        Self {
+            pool,
+            timestamp_source,
+        }
+    }
+}
+
+/// Row returned when loading one queued session id.
+struct SessionIdRow {
+    session_id: String,
+}
+
+#[async_trait]
+impl MergeQueueRepository for SqliteMergeQueueRepository {
+    async fn enqueue_merge_session(&self, session_id: &str) -> Result<(), DbError> {
+        let now = self.timestamp_source.now_timestamp_seconds();
+
+        sqlx::query!(
+            r"
+INSERT OR IGNORE INTO session_merge_queue (session_id, queued_at)
+VALUES (?, ?)
+",
+            session_id,
+            now
+        )
+        .execute(&self.pool)
+        .await
+        .db_context("enqueue merge session")?;
+
+        Ok(())
+    }
+
+    async fn load_merge_queue(&self) -> Result<Vec<MergeQueueRow>, DbError> {
+        let rows = sqlx::query_as!(
+            MergeQueueRow,
+            r#"
+SELECT session_merge_queue.session_id AS "session_id!", session.status AS "status!"
+FROM session_merge_queue
+JOIN session ON session.id = session_merge_queue.session_id
+ORDER BY session_merge_queue.position
+"#
+        )
+        .fetch_all(&self.pool)
+        .await
+        .db_context("load merge queue")?;
+
+        Ok(rows)
+    }
+
+    async fn load_project_merge_queue_session_ids(
+        &self,
+        project_id: i64,
+    ) -> Result<Vec<String>, DbError> {
+        let rows = sqlx::query_as!(
+            SessionIdRow,
+            r#"
+SELECT session_merge_queue.session_id AS "session_id!"
+FROM session_merge_queue
+JOIN session ON session.id = session_merge_queue.session_id
+WHERE session.project_id = ?
+ORDER BY session_merge_queue.position
+"#,
+            project_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .db_context("load project merge queue")?;
+
+        Ok(rows.into_iter().map(|row| row.session_id).collect())
+    }
+
+    async fn remove_merge_session(&self, session_id: &str) -> Result<(), DbError> {
+        sqlx::query!(
+            r"
+DELETE FROM session_merge_queue
+WHERE session_id = ?
+",
+            session_id
+        )
+        .execute(&self.pool)
+        .await
+        .db_context("remove merge session")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AppRepositories;
+
+    #[tokio::test]
+    async fn merge_queue_keeps_fifo_order_per_project_and_ignores_duplicates() {
+        // Arrange
+        let database = AppRepositories::in_memory().await.expect("db should open");
+        let project_id = database
+            .projects()
+            .upsert_project("/tmp/merge-queue-project", Some("main".to_string()))
+            .await
+            .expect("failed to insert project");
+        let other_project_id = database
+            .projects()
+            .upsert_project("/tmp/merge-queue-other", Some("main".to_string()))
+            .await
+            .expect("failed to insert other project");
+        for (session_id, status, session_project_id) in [
+            ("session-b", "Merging", project_id),
+            ("session-a", "Queued", project_id),
+            ("session-other", "Queued", other_project_id),
+        ] {
+            database
+                .sessions()
+                .insert_session(
+                    session_id,
+                    "gpt-5.6-sol",
+                    "main",
+                    status,
+                    session_project_id,
+                )
+                .await
+                .expect("failed to insert session");
+        }
+
+        // Act
+        for session_id in ["session-b", "session-a", "session-other", "session-b"] {
+            database
+                .merge_queue()
+                .enqueue_merge_session(session_id)
+                .await
+                .expect("failed to enqueue merge");
+        }
+        database
+            .merge_queue()
+            .remove_merge_session("session-other")
+            .await
+            .expect("failed to remove merge");
+        let queue = database
+            .merge_queue()
+            .load_merge_queue()
+            .await
+            .expect("failed to load merge queue");
+        let project_queue = database
+            .merge_queue()
+            .load_project_merge_queue_session_ids(project_id)
+            .await
+            .expect("failed to load project merge queue");
+
+        // Assert
+        assert_eq!(
+            queue
+                .iter()
+                .map(|row| (row.session_id.as_str(), row.status.as_str()))
+                .collect::<Vec<_>>(),
+            [("session-b", "Merging"), ("session-a", "Queued")]
+        );
+        assert_eq!(project_queue, ["session-b", "session-a"]);
+    }
+
+    #[tokio::test]
+    async fn deleting_session_removes_its_merge_queue_entry() {
+        // Arrange
+        let database = AppRepositories::in_memory().await.expect("db should open");
+        let project_id = database
+            .projects()
+            .upsert_project("/tmp/merge-queue-delete", Some("main".to_string()))
+            .await
+            .expect("failed to insert project");
+        database
+            .sessions()
+            .insert_session("session-a", "gpt-5.6-sol", "main", "Queued", project_id)
+            .await
+            .expect("failed to insert session");
+        database
+            .merge_queue()
+            .enqueue_merge_session("session-a")
+            .await
+            .expect("failed to enqueue merge");
+
+        // Act
+        database
+            .sessions()
+            .delete_session("session-a")
+            .await
+            .expect("failed to delete session");
+        let queue = database
+            .merge_queue()
+            .load_merge_queue()
+            .await
+            .expect("failed to load merge queue");
+
+        // Assert
+        assert_eq!(
+            queue
+                .iter()
+                .map(|row| row.session_id.as_str())
+                .collect::<Vec<_>>(),
+            [] as [&str; 0]
+        );
+    }
+}
@@ -75,6 +75,9 @@ pub struct SessionOrchestrationTaskRow {
     pub continuation_generation: i64,
     /// Feedback prompt waiting to resume the existing managed child.
     pub continuation_prompt: Option<String>,
+    /// Serialized task keys that must pass verification before this task
+    /// starts.
+    pub depends_on: String,
     /// Stable database identifier.
     pub id: i64,
     /// Number of bounded automatic spawn retries already consumed.
@@ -105,6 +108,8 @@ pub struct SessionOrchestrationTaskRow {
     pub verification_reason: Option<String>,
     /// Latest controller verdict for this task.
     pub verification_verdict: Option<String>,
+    /// Dependency depth used to group tasks into execution waves.
+    pub wave: i64,
 }
 
 /// Task scope and child base needed to compute verification evidence.
@@ -143,6 +148,9 @@ pub struct SessionOrchestrationMetadataRow {
 pub struct PersistedOrchestrationTask {
     /// Serialized acceptance criteria checked during verification.
     pub acceptance_criteria: String,
+    /// Serialized task keys that must pass verification before this task
+    /// starts.
+    pub depends_on: String,
     /// Persisted execution behavior.
     pub kind: String,
     /// Stable integration order selected on the approval board.
@@ -157,6 +165,23 @@ pub struct PersistedOrchestrationTask {
     pub title: String,
     /// Serialized repository areas this task expects to touch.
     pub touched_areas: String,
+    /// Dependency depth used to group tasks into execution waves.
+    pub wave: i64,
+}
+
+/// User-edited plan fields for one proposed orchestration task.
+///
+/// Owns its fields for the same mock-friendliness reason as
+/// [`PersistedOrchestrationTask`].
+pub struct OrchestrationTaskEdit {
+    /// Serialized acceptance criteria checked during verification.
+    pub acceptance_criteria: String,
+    /// Standalone prompt handed to the child session.
+    pub prompt: String,
+    /// Short human-readable task title.
+    pub title: String,
+    /// Serialized repository areas this task expects to touch.
+    pub touched_areas: String,
 }
 
 /// Orchestration persistence boundary used by the coordinator and tests.
@@ -288,6 +313,40 @@ pub trait OrchestrationRepository: Send + Sync {
         max_parallelism: i64,
     ) -> Result<(), DbError>;
 
+    /// Replaces the plan fields of one task still awaiting approval.
+    async fn update_proposed_orchestration_task(
+        &self,
+        id: i64,
+        edit: OrchestrationTaskEdit,
+    ) -> Result<bool, DbError>;
+
+    /// Removes one task from a plan that is still awaiting approval.
+    async fn delete_proposed_orchestration_task(&self, id: i64) -> Result<bool, DbError>;
+
+    /// Rewrites integration order to follow `task_ids` before integration
+    /// begins.
+    async fn reorder_orchestration_tasks(
+        &self,
+        session_orchestration_id: i64,
+        task_ids: Vec<i64>,
+    ) -> Result<bool, DbError>;
+
+    /// Returns failed or canceled tasks to `Planned` so fan-out starts fresh
+    /// children for them, releasing any prior child link.
+    async fn retry_orchestration_tasks(
+        &self,
+        session_orchestration_id: i64,
+        task_ids: Vec<i64>,
+    ) -> Result<bool, DbError>;
+
+    /// Settles one `IntegrationFailed` task as `Canceled` so integration can
+    /// finish without it.
+    async fn skip_orchestration_task_integration(
+        &self,
+        id: i64,
+        reason: &str,
+    ) -> Result<bool, DbError>;
+
     /// Routes feedback to a live managed child without replacing its branch.
     async fn queue_orchestration_continuation(
         &self,
@@ -333,6 +392,10 @@ pub trait OrchestrationRepository: Send + Sync {
         child_session_id: &str,
     ) -> Result<bool, DbError>;
 
+    /// Moves one dependent task's child from its prerequisite branch onto the
+    /// controller's base branch and returns whether the base changed.
+    async fn retarget_orchestration_task_child(&self, id: i64) -> Result<bool, DbError>;
+
     /// Updates one task's status and failure detail.
     async fn update_orchestration_task_status(
         &self,
@@ -480,6 +543,7 @@ RETURNING id AS "id!: i64"
     ) -> Result<i64, DbError> {
         let PersistedOrchestrationTask {
             acceptance_criteria,
+            depends_on,
             kind,
             merge_position,
             prompt,
@@ -487,6 +551,7 @@ RETURNING id AS "id!: i64"
             task_key,
             title,
             touched_areas,
+            wave,
         } = task;
         kind.parse::<ag_session::OrchestrationTaskKind>()
             .map_err(|_| DbError::InvalidData {
@@ -509,20 +574,24 @@ INSERT INTO session_orchestration_task (
     prompt,
     touched_areas,
     acceptance_criteria,
+    depends_on,
     kind,
     merge_position,
+    wave,
     status,
     created_at,
     updated_at
 )
-VALUES (?, ?, ?, ?, ?, ?, ?, ?, 'Planned', ?, ?)
+VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'Planned', ?, ?)
 ON CONFLICT(session_orchestration_id, task_key) DO UPDATE
 SET title = excluded.title,
     prompt = excluded.prompt,
     kind = excluded.kind,
     touched_areas = excluded.touched_areas,
     acceptance_criteria = excluded.acceptance_criteria,
+    depends_on = excluded.depends_on,
     merge_position = excluded.merge_position,
+    wave = excluded.wave,
     status = 'Planned',
     child_session_id = NULL,
     continuation_prompt = NULL,
@@ -541,8 +610,10 @@ RETURNING id AS "id!: i64"
             prompt,
             touched_areas,
             acceptance_criteria,
+            depends_on,
             kind,
             merge_position,
+            wave,
             now,
             now
         )
@@ -783,6 +854,7 @@ SELECT task.id AS "id!: i64",
        child.summary AS child_summary,
        task.continuation_generation,
        task.continuation_prompt,
+       task.depends_on,
        task.infrastructure_retry_count,
        task.kind,
        task.last_error,
@@ -796,7 +868,8 @@ SELECT task.id AS "id!: i64",
        task.touched_areas,
        task.title,
        task.verification_reason,
-       task.verification_verdict
+       task.verification_verdict,
+       task.wave
 FROM session_orchestration_task AS task
 LEFT JOIN session AS child
 ON child.id = task.child_session_id
@@ -1303,6 +1376,218 @@ WHERE id = (
         Ok(result.rows_affected() == 1)
     }
 
+    async fn update_proposed_orchestration_task(
+        &self,
+        id: i64,
+        edit: OrchestrationTaskEdit,
+    ) -> Result<bool, DbError> {
+        let OrchestrationTaskEdit {
+            acceptance_criteria,
+            prompt,
+            title,
+            touched_areas,
+        } = edit;
+        let now = self.now();
+
+        let result = sqlx::query!(
+            r"
+UPDATE session_orchestration_task
+SET title = ?,
+    prompt = ?,
+    acceptance_criteria = ?,
+    touched_areas = ?,
+    updated_at = ?
+WHERE id = ?
+  AND status = 'Proposed'
+",
+            title,
+            prompt,
+            acceptance_criteria,
+            touched_areas,
+            now,
+            id
+        )
+        .execute(&self.0)
+        .await
+        .db_context("update proposed orchestration task")?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn delete_proposed_orchestration_task(&self, id: i64) -> Result<bool, DbError> {
+        let result = sqlx::query!(
+            r"
+DELETE FROM session_orchestration_task
+WHERE id = ?
+  AND status = 'Proposed'
+",
+            id
+        )
+        .execute(&self.0)
+        .await
+        .db_context("delete proposed orchestration task")?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn reorder_orchestration_tasks(
+        &self,
+        session_orchestration_id: i64,
+        task_ids: Vec<i64>,
+    ) -> Result<bool, DbError> {
+        let now = self.now();
+        let mut transaction = self
+            .0
+            .begin()
+            .await
+            .db_context("reorder orchestration tasks")?;
+        let orchestration = sqlx::query!(
+            r"
+SELECT id
+FROM session_orchestration
+WHERE id = ?
+  AND status IN ('AwaitingApproval', 'Running', 'Verifying', 'AwaitingIntegration')
+",
+            session_orchestration_id
+        )
+        .fetch_optional(&mut *transaction)
+        .await
+        .db_context("reorder orchestration tasks")?;
+        if orchestration.is_none() {
+            return Ok(false);
+        }
+        for (merge_position, task_id) in (0_i64..).zip(task_ids) {
+            sqlx::query!(
+                r"
+UPDATE session_orchestration_task
+SET merge_position = ?,
+    updated_at = ?
+WHERE id = ?
+  AND session_orchestration_id = ?
+",
+                merge_position,
+                now,
+                task_id,
+                session_orchestration_id
+            )
+            .execute(&mut *transaction)
+            .await
+            .db_context("reorder orchestration tasks")?;
+        }
+        transaction
+            .commit()
+            .await
+            .db_context("reorder orchestration tasks")?;
+
+        Ok(true)
+    }
+
+    async fn retry_orchestration_tasks(
+        &self,
+        session_orchestration_id: i64,
+        task_ids: Vec<i64>,
+    ) -> Result<bool, DbError> {
+        let now = self.now();
+        let mut transaction = self
+            .0
+            .begin()
+            .await
+            .db_context("retry orchestration tasks")?;
+        let orchestration = sqlx::query!(
+            r"
+SELECT id
+FROM session_orchestration
+WHERE id = ?
+  AND status IN ('Running', 'AwaitingIntegration')
+",
+            session_orchestration_id
+        )
+        .fetch_optional(&mut *transaction)
+        .await
+        .db_context("retry orchestration tasks")?;
+        if orchestration.is_none() {
+            return Ok(false);
+        }
+        let mut retried = false;
+        for task_id in task_ids {
+            let result = sqlx::query!(
+                r"
+UPDATE session_orchestration_task
+SET status = 'Planned',
+    child_session_id = NULL,
+    continuation_prompt = NULL,
+    infrastructure_retry_count = 0,
+    review_iteration = 0,
+    research_report = NULL,
+    result_summary = NULL,
+    verification_reason = NULL,
+    verification_verdict = NULL,
+    last_error = NULL,
+    updated_at = ?
+WHERE id = ?
+  AND session_orchestration_id = ?
+  AND status IN ('Failed', 'Canceled')
+",
+                now,
+                task_id,
+                session_orchestration_id
+            )
+            .execute(&mut *transaction)
+            .await
+            .db_context("retry orchestration tasks")?;
+            if result.rows_affected() == 0 {
+                continue;
+            }
+            retried = true;
+            sqlx::query!(
+                r"
+UPDATE session
+SET orchestration_task_id = NULL,
+    updated_at = ?
+WHERE orchestration_task_id = ?
+",
+                now,
+                task_id
+            )
+            .execute(&mut *transaction)
+            .await
+            .db_context("retry orchestration tasks")?;
+        }
+        transaction
+            .commit()
+            .await
+            .db_context("retry orchestration tasks")?;
+
+        Ok(retried)
+    }
+
+    async fn skip_orchestration_task_integration(
+        &self,
+        id: i64,
+        reason: &str,
+    ) -> Result<bool, DbError> {
+        let now = self.now();
+
+        let result = sqlx::query!(
+            r"
+UPDATE session_orchestration_task
+SET status = 'Canceled',
+    last_error = ?,
+    updated_at = ?
+WHERE id = ?
+  AND status = 'IntegrationFailed'
+",
+            reason,
+            now,
+            id
+        )
+        .execute(&self.0)
+        .await
+        .db_context("skip orchestration task integration")?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
     async fn reset_orchestration_verification(&self, id: i64) -> Result<(), DbError> {
         let now = self.now();
 
@@ -1612,6 +1897,37 @@ WHERE id = ?
         Ok(result.rows_affected() == 1)
     }
 
+    async fn retarget_orchestration_task_child(&self, id: i64) -> Result<bool, DbError> {
+        let now = self.now();
+
+        let result = sqlx::query!(
+            r"
+WITH campaign_base AS (
+    SELECT task.child_session_id,
+           controller.base_branch
+    FROM session_orchestration_task AS task
+    INNER JOIN session_orchestration AS orchestration
+    ON orchestration.id = task.session_orchestration_id
+    INNER JOIN session AS controller
+    ON controller.id = orchestration.controller_session_id
+    WHERE task.id = ?
+)
+UPDATE session
+SET base_branch = (SELECT base_branch FROM campaign_base),
+    updated_at = ?
+WHERE id = (SELECT child_session_id FROM campaign_base)
+  AND base_branch <> (SELECT base_branch FROM campaign_base)
+",
+            id,
+            now
+        )
+        .execute(&self.0)
+        .await
+        .db_context("retarget orchestration task child")?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
     async fn update_orchestration_task_status(
         &self,
         id: i64,
@@ -1855,6 +2171,7 @@ mod tests {
     fn planned_task(session_orchestration_id: i64, task_key: &str) -> PersistedOrchestrationTask {
         PersistedOrchestrationTask {
             acceptance_criteria: format!(r#"["Complete {task_key}"]"#),
+            depends_on: "[]".to_string(),
             kind: "Implementation".to_string(),
             merge_position: 0,
             prompt: format!("Complete {task_key}"),
@@ -1862,6 +2179,7 @@ mod tests {
             task_key: task_key.to_string(),
             title: format!("Task {task_key}"),
             touched_areas: format!(r#"["crates/{task_key}/"]"#),
+            wave: 0,
         }
     }
 
@@ -2035,6 +2353,89 @@ mod tests {
         assert!(tasks[0].research_report.is_none());
     }
 
+    #[tokio::test]
+    async fn dependent_task_round_trips_dependencies_and_retargets_child_base() {
+        // Arrange
+        let database = controller_fixture().await;
+        let orchestration_id = database
+            .orchestrations()
+            .insert_orchestration("controller", &OrchestrationStatus::Running.to_string(), 2)
+            .await
+            .expect("failed to insert orchestration");
+        let task_id = database
+            .orchestrations()
+            .upsert_orchestration_task(PersistedOrchestrationTask {
+                depends_on: r#"["alpha"]"#.to_string(),
+                wave: 1,
+                ..planned_task(orchestration_id, "beta")
+            })
+            .await
+            .expect("failed to insert dependent task");
+        assert!(
+            database
+                .orchestrations()
+                .claim_orchestration_task(task_id)
+                .await
+                .expect("failed to claim dependent task")
+        );
+        database
+            .sessions()
+            .insert_session_with_agent(PersistedSessionCreation {
+                agent: "codex",
+                base_branch: "agentty/alpha",
+                id: "beta-child",
+                is_draft: false,
+                model: AgentKind::Codex.default_model().as_str(),
+                orchestration_task_id: Some(task_id),
+                parent_session_id: None,
+                permission_mode: ag_agent::PermissionMode::AutoEdit,
+                personality_id: None,
+                project_id: 1,
+                reasoning_level: ReasoningLevel::default(),
+                role: Some("OrchestrationWorker"),
+                speed_mode: SpeedMode::Normal,
+                status: "Review",
+            })
+            .await
+            .expect("failed to insert dependent child");
+        assert!(
+            database
+                .orchestrations()
+                .link_orchestration_task_child(task_id, "beta-child")
+                .await
+                .expect("failed to link dependent child")
+        );
+
+        // Act
+        let tasks = database
+            .orchestrations()
+            .load_orchestration_tasks(orchestration_id)
+            .await
+            .expect("failed to load tasks");
+        let first_retarget = database
+            .orchestrations()
+            .retarget_orchestration_task_child(task_id)
+            .await
+            .expect("failed to retarget child");
+        let second_retarget = database
+            .orchestrations()
+            .retarget_orchestration_task_child(task_id)
+            .await
+            .expect("failed to repeat retarget");
+        let base_branch = database
+            .sessions()
+            .get_session_base_branch("beta-child")
+            .await
+            .expect("failed to load child base branch");
+
+        // Assert
+        assert_eq!(tasks[0].depends_on, r#"["alpha"]"#);
+        assert_eq!(tasks[0].wave, 1);
+        assert!(first_retarget);
+        assert!(!second_retarget);
+        assert_eq!(base_branch.as_deref(), Some("main"));
+    }
+
     #[tokio::test]
     async fn research_task_round_trips_report_and_latest_child_answer_without_scope() {
         // Arrange
@@ -2128,6 +2529,194 @@ mod tests {
         assert!(scope.is_none());
     }
 
+    #[tokio::test]
+    async fn proposed_tasks_accept_board_edits_only_before_approval() {
+        // Arrange
+        let database = controller_fixture().await;
+        let orchestration_id = database
+            .orchestrations()
+            .insert_orchestration(
+                "controller",
+                &OrchestrationStatus::AwaitingApproval.to_string(),
+                2,
+            )
+            .await
+            .expect("failed to insert orchestration");
+        let mut task_ids = Vec::new();
+        for task_key in ["alpha", "beta", "gamma"] {
+            let task_id = database
+                .orchestrations()
+                .upsert_orchestration_task(planned_task(orchestration_id, task_key))
+                .await
+                .expect("failed to insert task");
+            database
+                .orchestrations()
+                .update_orchestration_task_status(
+                    task_id,
+                    &OrchestrationTaskStatus::Proposed.to_string(),
+                    None,
+                )
+                .await
+                .expect("failed to propose task");
+            task_ids.push(task_id);
+        }
+        let edit = || OrchestrationTaskEdit {
+            acceptance_criteria: r#"["Edited criterion"]"#.to_string(),
+            prompt: "Edited prompt".to_string(),
+            title: "Edited alpha".to_string(),
+            touched_areas: r#"["crates/edited/"]"#.to_string(),
+        };
+
+        // Act
+        let edited = database
+            .orchestrations()
+            .update_proposed_orchestration_task(task_ids[0], edit())
+            .await
+            .expect("failed to edit proposed task");
+        let deleted = database
+            .orchestrations()
+            .delete_proposed_orchestration_task(task_ids[1])
+            .await
+            .expect("failed to delete proposed task");
+        let reordered = database
+            .orchestrations()
+            .reorder_orchestration_tasks(orchestration_id, vec![task_ids[2], task_ids[0]])
+            .await
+            .expect("failed to reorder tasks");
+        database
+            .orchestrations()
+            .approve_orchestration_plan(orchestration_id)
+            .await
+            .expect("failed to approve plan");
+        let late_edit = database
+            .orchestrations()
+            .update_proposed_orchestration_task(task_ids[0], edit())
+            .await
+            .expect("failed to attempt late edit");
+        let late_delete = database
+            .orchestrations()
+            .delete_proposed_orchestration_task(task_ids[2])
+            .await
+            .expect("failed to attempt late delete");
+        let tasks = database
+            .orchestrations()
+            .load_orchestration_tasks(orchestration_id)
+            .await
+            .expect("failed to load tasks");
+
+        // Assert
+        assert!(edited && deleted && reordered);
+        assert!(!late_edit && !late_delete);
+        assert_eq!(
+            tasks
+                .iter()
+                .map(|task| (task.task_key.as_str(), task.merge_position))
+                .collect::<Vec<_>>(),
+            [("gamma", 0), ("alpha", 1)]
+        );
+        assert_eq!(tasks[1].title, "Edited alpha");
+        assert_eq!(tasks[1].prompt, "Edited prompt");
+        assert_eq!(tasks[1].acceptance_criteria, r#"["Edited criterion"]"#);
+        assert_eq!(tasks[1].touched_areas, r#"["crates/edited/"]"#);
+    }
+
+    #[tokio::test]
+    async fn failed_tasks_retry_and_integration_failures_skip() {
+        // Arrange
+        let (database, pool) = controller_fixture_with_pool().await;
+        let project_id = database
+            .projects()
+            .upsert_project("/tmp/project", None)
+            .await
+            .expect("failed to load project");
+        let orchestration_id = database
+            .orchestrations()
+            .insert_orchestration(
+                "controller",
+                &OrchestrationStatus::AwaitingIntegration.to_string(),
+                2,
+            )
+            .await
+            .expect("failed to insert orchestration");
+        let failed_id = database
+            .orchestrations()
+            .upsert_orchestration_task(planned_task(orchestration_id, "alpha"))
+            .await
+            .expect("failed to insert failed task");
+        let integration_failed_id = database
+            .orchestrations()
+            .upsert_orchestration_task(planned_task(orchestration_id, "beta"))
+            .await
+            .expect("failed to insert integration task");
+        insert_orchestration_child(&database, project_id, "child-alpha", failed_id).await;
+        database
+            .orchestrations()
+            .link_orchestration_task_child(failed_id, "child-alpha")
+            .await
+            .expect("failed to link failed child");
+        for (task_id, status) in [
+            (failed_id, OrchestrationTaskStatus::Failed),
+            (
+                integration_failed_id,
+                OrchestrationTaskStatus::IntegrationFailed,
+            ),
+        ] {
+            database
+                .orchestrations()
+                .update_orchestration_task_status(
+                    task_id,
+                    &status.to_string(),
+                    Some("boom".to_string()),
+                )
+                .await
+                .expect("failed to settle task");
+        }
+
+        // Act
+        let retried = database
+            .orchestrations()
+            .retry_orchestration_tasks(orchestration_id, vec![failed_id, integration_failed_id])
+            .await
+            .expect("failed to retry tasks");
+        let skipped = database
+            .orchestrations()
+            .skip_orchestration_task_integration(integration_failed_id, "Skipped by user")
+            .await
+            .expect("failed to skip integration");
+        let repeated_skip = database
+            .orchestrations()
+            .skip_orchestration_task_integration(integration_failed_id, "Skipped by user")
+            .await
+            .expect("failed to repeat skip");
+        let tasks = database
+            .orchestrations()
+            .load_orchestration_tasks(orchestration_id)
+            .await
+            .expect("failed to load tasks");
+        let child_link = sqlx::query_scalar::<_, Option<i64>>(
+            "SELECT orchestration_task_id FROM session WHERE id = 'child-alpha'",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("failed to load child link");
+
+        // Assert
+        assert!(retried && skipped);
+        assert!(!repeated_skip);
+        assert_eq!(
+            tasks[0].status,
+            OrchestrationTaskStatus::Planned.to_string()
+        );
+        assert_eq!(tasks[0].child_session_id, None);
+        assert_eq!(tasks[0].last_error, None);
+        assert_eq!(child_link, None);
+        assert_eq!(
+            tasks[1].status,
+            OrchestrationTaskStatus::Canceled.to_string()
+        );
+        assert_eq!(tasks[1].last_error.as_deref(), Some("Skipped by user"));
+    }
+
     #[tokio::test]
     async fn orchestration_task_kind_validation_rejects_unknown_writes_and_hydration() {
         // Arrange
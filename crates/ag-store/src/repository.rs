@@ -7,11 +7,11 @@ use sqlx::SqlitePool;
 #[cfg(any(test, feature = "test-utils"))]
 use super::connection::open_in_memory_pool;
 use super::{
-    ActivityRepository, OperationRepository, OrchestrationRepository, ProjectRepository,
-    ReviewRepository, SessionRepository, SettingRepository, SqliteActivityRepository,
-    SqliteOperationRepository, SqliteOrchestrationRepository, SqliteProjectRepository,
-    SqliteReviewRepository, SqliteSessionRepository, SqliteSettingRepository,
-    SqliteUsageRepository, UsageRepository,
+    ActivityRepository, MergeQueueRepository, OperationRepository, OrchestrationRepository,
+    ProjectRepository, ReviewRepository, SessionRepository, SettingRepository,
+    SqliteActivityRepository, SqliteMergeQueueRepository, SqliteOperationRepository,
+    SqliteOrchestrationRepository, SqliteProjectRepository, SqliteReviewRepository,
+    SqliteSessionRepository, SqliteSettingRepository, SqliteUsageRepository, UsageRepository,
 };
 use crate::timestamp::TimestampSource;
 #[cfg(any(test, feature = "test-utils"))]
@@ -21,6 +21,7 @@ use crate::timestamp::system_timestamp_source;
 #[derive(Clone)]
 pub struct AppRepositories {
     activity: Arc<dyn ActivityRepository>,
+    merge_queue: Arc<dyn MergeQueueRepository>,
     operation: Arc<dyn OperationRepository>,
     orchestration: Arc<dyn OrchestrationRepository>,
     project: Arc<dyn ProjectRepository>,
@@ -44,6 +45,10 @@ impl AppRepositories {
     ) -> Self {
         Self::from_parts(AppRepositoryParts {
             activity: Arc::new(SqliteActivityRepository::new(pool.clone())),
+            merge_queue: Arc::new(SqliteMergeQueueRepository::new(
+                pool.clone(),
+                Arc::clone(&timestamp_source),
+            )),
             operation: Arc::new(SqliteOperationRepository::new(
                 pool.clone(),
                 Arc::clone(&timestamp_source),
@@ -70,6 +75,7 @@ impl AppRepositories {
     pub(crate) fn from_parts(parts: AppRepositoryParts) -> Self {
         let AppRepositoryParts {
             activity,
+            merge_queue,
             operation,
             orchestration,
             project,
@@ -81,6 +87,7 @@ impl AppRepositories {
 
         Self {
             activity,
+            merge_queue,
             operation,
             orchestration,
             project,
@@ -130,6 +137,11 @@ impl AppRepositories {
         self.activity.as_ref()
     }
 
+    /// Returns the persisted merge-queue repository.
+    pub fn merge_queue(&self) -> &dyn MergeQueueRepository {
+        self.merge_queue.as_ref()
+    }
+
     /// Returns the session-operation repository.
     pub fn operations(&self) -> &dyn OperationRepository {
         self.operation.as_ref()
@@ -161,6 +173,12 @@ impl AppRepositories {
         self.session.as_ref()
     }
 
+    /// Returns a cloneable session repository for background change
+    /// observation.
+    pub fn session_repository(&self) -> Arc<dyn SessionRepository> {
+        Arc::clone(&self.session)
+    }
+
     /// Returns the settings repository.
     pub fn settings(&self) -> &dyn SettingRepository {
         self.setting.as_ref()
@@ -178,6 +196,7 @@ impl AppRepositories {
 /// to a production adapter when a focused fake was intended.
 pub(crate) struct AppRepositoryParts {
     pub(crate) activity: Arc<dyn ActivityRepository>,
+    pub(crate) merge_queue: Arc<dyn MergeQueueRepository>,
     pub(crate) operation: Arc<dyn OperationRepository>,
     pub(crate) orchestration: Arc<dyn OrchestrationRepository>,
     pub(crate) project: Arc<dyn ProjectRepository>,
@@ -398,6 +417,7 @@ WHERE session_orchestration.id = ?
             .returning(|_| Ok(true));
         let repositories = AppRepositories::from_parts(AppRepositoryParts {
             activity: Arc::clone(&baseline.activity),
+            merge_queue: Arc::clone(&baseline.merge_queue),
             operation: Arc::new(operation),
             orchestration: Arc::clone(&baseline.orchestration),
             project: Arc::clone(&baseline.project),
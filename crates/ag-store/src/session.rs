@@ -375,7 +375,6 @@ pub trait SessionRepository: Send + Sync {
     /// projects.
     async fn load_active_session_agent_models(&self) -> Result<Vec<SessionAgentModelRow>, DbError>;
 
-    #[cfg(any(test, feature = "test-utils"))]
     /// Loads all sessions ordered by most recent update.
     async fn load_sessions(&self) -> Result<Vec<SessionRow>, DbError>;
 
@@ -398,6 +397,10 @@ pub trait SessionRepository: Send + Sync {
         session_id: &str,
     ) -> Result<Vec<SessionMessageRow>, DbError>;
 
+    /// Loads the newest transcript position of every session that has at
+    /// least one message.
+    async fn load_last_message_positions(&self) -> Result<Vec<(String, i64)>, DbError>;
+
     /// Loads persisted focused-review cache rows for one project.
     async fn load_session_focused_reviews_for_project(
         &self,
@@ -493,6 +496,10 @@ pub trait SessionRepository: Send + Sync {
     /// known size and line counts.
     async fn mark_session_diff_unknown(&self, id: &str) -> Result<(), DbError>;
 
+    /// Deletes transcript messages at or after `from_position` and refreshes
+    /// session ordering metadata.
+    async fn truncate_session_messages(&self, id: &str, from_position: i64) -> Result<(), DbError>;
+
     /// Updates persisted diff-derived presence, size, and line-count fields
     /// for a session row.
     async fn update_session_diff_stats(
@@ -1471,7 +1478,6 @@ ORDER BY id
         Ok(rows)
     }
 
-    #[cfg(any(test, feature = "test-utils"))]
     async fn load_sessions(&self) -> Result<Vec<SessionRow>, DbError> {
         let rows = sqlx::query_as!(
             SessionJoinRow,
@@ -1638,6 +1644,21 @@ ORDER BY position, id
         Ok(rows)
     }
 
+    async fn load_last_message_positions(&self) -> Result<Vec<(String, i64)>, DbError> {
+        let rows = sqlx::query_as::<_, (String, i64)>(
+            r"
+SELECT session_id,
+       MAX(position)
+FROM session_message
+GROUP BY session_id
+",
+        )
+        .fetch_all(&self.0)
+        .await?;
+
+        Ok(rows)
+    }
+
     async fn load_session_focused_reviews_for_project(
         &self,
         project_id: i64,
@@ -2091,6 +2112,10 @@ WHERE id = ?
         Ok(())
     }
 
+    async fn truncate_session_messages(&self, id: &str, from_position: i64) -> Result<(), DbError> {
+        self.2.truncate_from(id, from_position).await
+    }
+
     async fn update_session_instruction_conversation_id(
         &self,
         id: &str,
@@ -9,6 +9,7 @@ use crate::timestamp::TimestampSource;
 use crate::{DbError, DbResultExt};
 
 const APPEND_SESSION_MESSAGE: &str = "append session message";
+const TRUNCATE_SESSION_MESSAGES: &str = "truncate session messages";
 
 /// Internal store that owns transcript ordering and its session timestamp.
 #[derive(Clone)]
@@ -87,6 +88,48 @@ WHERE session_id = ?
 
         Ok(())
     }
+
+    /// Deletes messages at or after `from_position` and updates the owning
+    /// session atomically.
+    pub(super) async fn truncate_from(&self, id: &str, from_position: i64) -> Result<(), DbError> {
+        let now = self.timestamp_source.now_timestamp_seconds();
+        let mut transaction = self
+            .pool
+            .begin()
+            .await
+            .db_context(TRUNCATE_SESSION_MESSAGES)?;
+        sqlx::query(
+            r"
+DELETE FROM session_message
+WHERE session_id = ?
+  AND position >= ?
+",
+        )
+        .bind(id)
+        .bind(from_position)
+        .execute(&mut *transaction)
+        .await
+        .db_context(TRUNCATE_SESSION_MESSAGES)?;
+        sqlx::query(
+            r"
+UPDATE session
+SET updated_at = ?
+WHERE id = ?
+",
+        )
+        .bind(now)
+        .bind(id)
+        .execute(&mut *transaction)
+        .await
+        .db_context(TRUNCATE_SESSION_MESSAGES)?;
+
+        transaction
+            .commit()
+            .await
+            .db_context(TRUNCATE_SESSION_MESSAGES)?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -115,6 +158,57 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn truncate_from_drops_messages_at_and_after_position() {
+        // Arrange
+        let pool = open_in_memory_pool(1)
+            .await
+            .expect("failed to open in-memory db");
+        let repositories = AppRepositories::from_pool(pool);
+        let project_id = repositories
+            .projects()
+            .upsert_project("/tmp/message-truncate", Some("main".to_string()))
+            .await
+            .expect("failed to insert project");
+        repositories
+            .sessions()
+            .insert_session("session-a", "gpt-5.6-sol", "main", "Review", project_id)
+            .await
+            .expect("failed to insert session");
+        for (kind, content) in [
+            (SessionMessageKind::UserPrompt, "first"),
+            (SessionMessageKind::AssistantAnswer, "one"),
+            (SessionMessageKind::UserPrompt, "second"),
+        ] {
+            repositories
+                .sessions()
+                .append_session_message("session-a", kind, content)
+                .await
+                .expect("failed to append message");
+        }
+
+        // Act
+        repositories
+            .sessions()
+            .truncate_session_messages("session-a", 2)
+            .await
+            .expect("failed to truncate messages");
+        let messages = repositories
+            .sessions()
+            .load_session_messages("session-a")
+            .await
+            .expect("failed to load messages");
+
+        // Assert
+        assert_eq!(
+            messages
+                .iter()
+                .map(|message| message.content.as_str())
+                .collect::<Vec<_>>(),
+            vec!["first", "one"]
+        );
+    }
+
     #[tokio::test]
     async fn append_failure_reports_semantic_operation_context() {
         // Arrange
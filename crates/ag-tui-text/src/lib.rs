@@ -7,6 +7,8 @@ pub mod markdown;
 /// Bounded Mermaid parsing and terminal diagram rendering.
 pub mod mermaid;
 mod style;
+/// Language detection and scope-based syntax highlighting for code.
+pub mod syntax;
 /// Terminal-width wrapping, truncation, borrowing, and compact formatting.
 pub mod text_util;
 
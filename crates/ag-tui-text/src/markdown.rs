@@ -9,6 +9,7 @@ use rustc_hash::FxHasher;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::style::TextRenderSettings;
+use crate::syntax::{self, SyntaxHighlighter, SyntaxLanguage};
 use crate::text_util::wrap_styled_line;
 use crate::{mermaid, style};
 
@@ -335,6 +336,16 @@ fn render_markdown_input_line(
         return next_line_index;
     }
 
+    if let Some(next_line_index) = render_highlighted_code_block_line(
+        raw_lines,
+        line_index,
+        width,
+        *block_state,
+        rendered_lines,
+    ) {
+        return next_line_index;
+    }
+
     if update_fence_state(raw_line, block_state) {
         return line_index + 1;
     }
@@ -369,6 +380,44 @@ fn render_mermaid_block_line(
     Some(next_line_index)
 }
 
+/// Renders a fenced code block with syntax highlighting when the current
+/// paragraph line opens a fence whose info string names a known language.
+///
+/// Returns the index just past the closing fence. Unclosed fences highlight
+/// through the end of the input, matching plain fenced-code rendering.
+fn render_highlighted_code_block_line(
+    raw_lines: &[&str],
+    line_index: usize,
+    width: usize,
+    block_state: BlockState,
+    rendered_lines: &mut Vec<Line<'static>>,
+) -> Option<usize> {
+    let raw_line = raw_lines[line_index];
+    if !matches!(block_state, BlockState::Paragraph)
+        || !is_fence_delimiter(raw_line)
+        || is_stats_fence(raw_line)
+    {
+        return None;
+    }
+    let fence_info = raw_line.trim().trim_start_matches('`');
+    let language = SyntaxLanguage::from_fence_info(fence_info)?;
+    let mut highlighter = SyntaxHighlighter::new(language);
+    let palette = style::active_palette();
+
+    for (code_line_index, code_line) in raw_lines.iter().enumerate().skip(line_index + 1) {
+        if is_fence_delimiter(code_line) {
+            return Some(code_line_index + 1);
+        }
+
+        let syntax_spans = highlighter.highlight_line(code_line);
+        let spans =
+            syntax::highlighted_spans(code_line, &syntax_spans, code_block_style(), &palette);
+        rendered_lines.extend(wrap_verbatim_spans_with_word_boundaries(spans, width));
+    }
+
+    Some(raw_lines.len())
+}
+
 /// Toggles fenced block state and returns whether the line was consumed.
 fn update_fence_state(raw_line: &str, block_state: &mut BlockState) -> bool {
     if !is_fence_delimiter(raw_line) {
@@ -2471,7 +2520,62 @@ mod tests {
         // Assert
         assert_eq!(lines.len(), 1);
         assert_eq!(lines[0].to_string(), "let value = **raw**;");
-        assert_eq!(lines[0].spans[0].style, code_block_style());
+        assert!(lines[0].spans.iter().all(|span| {
+            span.style.bg == code_block_style().bg
+                && !span.style.add_modifier.contains(Modifier::BOLD)
+        }));
+    }
+
+    #[test]
+    fn test_render_markdown_highlights_fenced_code_for_known_language() {
+        // Arrange
+        let input = "```rust
+fn main() {
+    /* note
+    still note */
+}
+```
+after";
+        let palette = crate::TextPalette::DEFAULT;
+
+        // Act
+        let lines = render_markdown(input, 80);
+
+        // Assert
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0].spans[0].content, "fn");
+        assert_eq!(
+            lines[0].spans[0].style,
+            code_block_style().fg(palette.accent)
+        );
+        assert!(lines[2].spans.iter().any(|span| {
+            span.content.contains("still note")
+                && span.style.fg == Some(palette.text_subtle)
+                && span.style.add_modifier.contains(Modifier::ITALIC)
+        }));
+        assert_eq!(lines[4].to_string(), "after");
+    }
+
+    #[test]
+    fn test_render_markdown_highlighted_code_uses_injected_palette() {
+        // Arrange
+        let input = "```rust
+let value = 1;
+```";
+        let settings = TextRenderSettings {
+            cache_version: 7,
+            palette: crate::TextPalette {
+                accent: Color::Rgb(1, 2, 3),
+                ..crate::TextPalette::DEFAULT
+            },
+        };
+
+        // Act
+        let lines = render_markdown_with_settings(input, 80, settings);
+
+        // Assert
+        assert_eq!(lines[0].spans[0].content, "let");
+        assert_eq!(lines[0].spans[0].style.fg, Some(Color::Rgb(1, 2, 3)));
     }
 
     #[test]
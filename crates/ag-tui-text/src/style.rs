@@ -171,7 +171,7 @@ pub(crate) fn active_theme_cache_version() -> u64 {
     active_settings().cache_version
 }
 
-fn active_palette() -> TextPalette {
+pub(crate) fn active_palette() -> TextPalette {
     active_settings().palette
 }
 
@@ -0,0 +1,437 @@
+//! Language-aware syntax highlighting for code rendered in the terminal.
+//!
+//! Grammars come from the bundled Sublime syntax set. Parsed scopes collapse
+//! into a small set of semantic [`SyntaxTokenKind`] values so host themes can
+//! color code with their existing palette instead of shipping editor themes.
+
+use std::ops::Range;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Span;
+use syntect::parsing::{ParseState, Scope, ScopeStack, SyntaxReference, SyntaxSet};
+
+use crate::style::TextPalette;
+
+/// Longest line, in bytes, that is parsed for highlighting.
+///
+/// Longer lines, such as minified bundles, render with the caller's base style
+/// so one pathological line cannot stall a frame.
+const MAX_HIGHLIGHT_LINE_BYTES: usize = 4096;
+
+/// Name of the bundled fallback grammar that carries no highlighting.
+const PLAIN_TEXT_SYNTAX_NAME: &str = "Plain Text";
+
+/// Scope prefixes checked from the innermost scope outward.
+///
+/// `None` stops the search without coloring, which keeps operators and
+/// punctuation in the surrounding base style.
+const SCOPE_TOKEN_KINDS: &[(&str, Option<SyntaxTokenKind>)] = &[
+    ("comment", Some(SyntaxTokenKind::Comment)),
+    ("string", Some(SyntaxTokenKind::String)),
+    ("constant.character", Some(SyntaxTokenKind::String)),
+    ("constant", Some(SyntaxTokenKind::Constant)),
+    ("keyword.operator", None),
+    ("keyword", Some(SyntaxTokenKind::Keyword)),
+    ("storage", Some(SyntaxTokenKind::Keyword)),
+    ("entity.name.function", Some(SyntaxTokenKind::Function)),
+    ("support.function", Some(SyntaxTokenKind::Function)),
+    ("variable.function", Some(SyntaxTokenKind::Function)),
+    ("support.macro", Some(SyntaxTokenKind::Function)),
+    ("entity.name.tag", Some(SyntaxTokenKind::Attribute)),
+    (
+        "entity.other.attribute-name",
+        Some(SyntaxTokenKind::Attribute),
+    ),
+    ("meta.annotation", Some(SyntaxTokenKind::Attribute)),
+    ("entity.name", Some(SyntaxTokenKind::Type)),
+    ("support.type", Some(SyntaxTokenKind::Type)),
+    ("support.class", Some(SyntaxTokenKind::Type)),
+    ("entity.other.inherited-class", Some(SyntaxTokenKind::Type)),
+];
+
+/// Semantic class of one highlighted code token.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SyntaxTokenKind {
+    /// Attributes, annotations, and markup tags.
+    Attribute,
+    /// Line and block comments.
+    Comment,
+    /// Numeric, boolean, and other language constants.
+    Constant,
+    /// Function and macro names.
+    Function,
+    /// Language keywords and storage modifiers.
+    Keyword,
+    /// String and character literals.
+    String,
+    /// Type, class, and other named entity declarations.
+    Type,
+}
+
+impl SyntaxTokenKind {
+    /// Returns the palette color used for this token kind.
+    #[must_use]
+    pub fn color(self, palette: &TextPalette) -> Color {
+        match self {
+            Self::Attribute | Self::Type => palette.info,
+            Self::Comment => palette.text_subtle,
+            Self::Constant => palette.warning,
+            Self::Function => palette.warning_soft,
+            Self::Keyword => palette.accent,
+            Self::String => palette.success,
+        }
+    }
+
+    /// Applies this token's color, and italics for comments, on top of
+    /// `base_style` so backgrounds from diff rows or code blocks survive.
+    #[must_use]
+    pub fn style(self, base_style: Style, palette: &TextPalette) -> Style {
+        let style = base_style.fg(self.color(palette));
+        if self == Self::Comment {
+            return style.add_modifier(Modifier::ITALIC);
+        }
+
+        style
+    }
+}
+
+/// Highlighted byte range inside one source line.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SyntaxSpan {
+    /// Token class for the range.
+    pub kind: SyntaxTokenKind,
+    /// Byte range within the highlighted line.
+    pub range: Range<usize>,
+}
+
+/// Grammar selected for one file or fenced code block.
+#[derive(Clone, Copy, Debug)]
+pub struct SyntaxLanguage {
+    syntax: &'static SyntaxReference,
+}
+
+impl SyntaxLanguage {
+    /// Detects the grammar for a repository path from its file name or
+    /// extension.
+    #[must_use]
+    pub fn from_path(path: &str) -> Option<Self> {
+        let path = Path::new(path);
+        let syntax_set = syntax_set();
+        let file_name = path.file_name()?.to_str()?;
+        let syntax = syntax_set.find_syntax_by_extension(file_name).or_else(|| {
+            let extension = path.extension()?.to_str()?;
+
+            syntax_set.find_syntax_by_extension(extension)
+        })?;
+
+        Self::highlighted(syntax)
+    }
+
+    /// Detects the grammar named by a Markdown fence info string such as
+    /// `rust` or `py title="example"`.
+    #[must_use]
+    pub fn from_fence_info(info: &str) -> Option<Self> {
+        let token = info
+            .split(|character: char| character.is_whitespace() || character == ',')
+            .next()?
+            .trim_start_matches('.')
+            .trim_matches(['{', '}']);
+        if token.is_empty() {
+            return None;
+        }
+        let syntax = syntax_set().find_syntax_by_token(token)?;
+
+        Self::highlighted(syntax)
+    }
+
+    /// Returns the grammar display name.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        &self.syntax.name
+    }
+
+    /// Wraps grammars that produce meaningful highlighting.
+    fn highlighted(syntax: &'static SyntaxReference) -> Option<Self> {
+        if syntax.name == PLAIN_TEXT_SYNTAX_NAME {
+            return None;
+        }
+
+        Some(Self { syntax })
+    }
+}
+
+/// Incremental highlighter that carries grammar state across lines.
+///
+/// Feed lines in source order so multi-line comments and strings keep their
+/// classification on continuation lines.
+pub struct SyntaxHighlighter {
+    language: SyntaxLanguage,
+    parse_state: ParseState,
+    scope_stack: ScopeStack,
+}
+
+impl SyntaxHighlighter {
+    /// Creates a highlighter positioned at the start of a source file.
+    #[must_use]
+    pub fn new(language: SyntaxLanguage) -> Self {
+        Self {
+            language,
+            parse_state: ParseState::new(language.syntax),
+            scope_stack: ScopeStack::new(),
+        }
+    }
+
+    /// Discards carried grammar state, for example at a new diff hunk.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.language);
+    }
+
+    /// Returns highlighted ranges for the next source line.
+    ///
+    /// Ranges are sorted, non-overlapping, and omit text that keeps the
+    /// caller's base style. Grammar errors reset the highlighter and return no
+    /// ranges for the line.
+    pub fn highlight_line(&mut self, line: &str) -> Vec<SyntaxSpan> {
+        if line.len() > MAX_HIGHLIGHT_LINE_BYTES {
+            return Vec::new();
+        }
+
+        let line_with_newline = format!("{line}\n");
+        let Ok(operations) = self
+            .parse_state
+            .parse_line(&line_with_newline, syntax_set())
+        else {
+            self.reset();
+
+            return Vec::new();
+        };
+        let mut spans = Vec::new();
+        let mut range_start = 0;
+        for (offset, operation) in operations {
+            let range_end = offset.min(line.len());
+            push_syntax_span(
+                &mut spans,
+                range_start..range_end,
+                token_kind(&self.scope_stack),
+            );
+            range_start = range_end;
+            if self.scope_stack.apply(&operation).is_err() {
+                self.reset();
+
+                return Vec::new();
+            }
+        }
+        push_syntax_span(
+            &mut spans,
+            range_start..line.len(),
+            token_kind(&self.scope_stack),
+        );
+
+        spans
+    }
+}
+
+/// Splits `line` into styled spans, applying `syntax_spans` over
+/// `base_style`.
+#[must_use]
+pub fn highlighted_spans(
+    line: &str,
+    syntax_spans: &[SyntaxSpan],
+    base_style: Style,
+    palette: &TextPalette,
+) -> Vec<Span<'static>> {
+    let mut spans = Vec::with_capacity(syntax_spans.len().saturating_mul(2).saturating_add(1));
+    let mut cursor = 0;
+    for syntax_span in syntax_spans {
+        let Some(text) = line.get(syntax_span.range.clone()) else {
+            continue;
+        };
+        if syntax_span.range.start > cursor
+            && let Some(plain_text) = line.get(cursor..syntax_span.range.start)
+        {
+            spans.push(Span::styled(plain_text.to_string(), base_style));
+        }
+        spans.push(Span::styled(
+            text.to_string(),
+            syntax_span.kind.style(base_style, palette),
+        ));
+        cursor = syntax_span.range.end;
+    }
+    if cursor < line.len() || spans.is_empty() {
+        spans.push(Span::styled(
+            line.get(cursor..).unwrap_or_default().to_string(),
+            base_style,
+        ));
+    }
+
+    spans
+}
+
+/// Appends one classified range, merging it with an adjacent range of the
+/// same kind.
+fn push_syntax_span(
+    spans: &mut Vec<SyntaxSpan>,
+    range: Range<usize>,
+    kind: Option<SyntaxTokenKind>,
+) {
+    let Some(kind) = kind else {
+        return;
+    };
+    if range.is_empty() {
+        return;
+    }
+    if let Some(last_span) = spans.last_mut()
+        && last_span.kind == kind
+        && last_span.range.end == range.start
+    {
+        last_span.range.end = range.end;
+
+        return;
+    }
+
+    spans.push(SyntaxSpan { kind, range });
+}
+
+/// Classifies the innermost scope that maps to a token kind.
+fn token_kind(scope_stack: &ScopeStack) -> Option<SyntaxTokenKind> {
+    let scope_token_kinds = scope_token_kinds();
+
+    scope_stack
+        .as_slice()
+        .iter()
+        .rev()
+        .find_map(|scope| {
+            scope_token_kinds
+                .iter()
+                .find(|(prefix, _)| prefix.is_prefix_of(*scope))
+                .map(|(_, kind)| *kind)
+        })
+        .flatten()
+}
+
+/// Returns the lazily parsed scope-prefix classification table.
+fn scope_token_kinds() -> &'static [(Scope, Option<SyntaxTokenKind>)] {
+    static SCOPES: OnceLock<Vec<(Scope, Option<SyntaxTokenKind>)>> = OnceLock::new();
+
+    SCOPES.get_or_init(|| {
+        SCOPE_TOKEN_KINDS
+            .iter()
+            .filter_map(|(prefix, kind)| Some((Scope::new(prefix).ok()?, *kind)))
+            .collect()
+    })
+}
+
+/// Returns the lazily loaded bundled grammar set.
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+
+    SYNTAX_SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the highlighted text and token kind for each span in `line`.
+    fn highlighted_tokens(line: &str, spans: &[SyntaxSpan]) -> Vec<(String, SyntaxTokenKind)> {
+        spans
+            .iter()
+            .map(|span| (line[span.range.clone()].to_string(), span.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_language_from_path_detects_extension_and_skips_plain_text() {
+        // Arrange
+        let rust_path = "crates/ag-tui-text/src/syntax.rs";
+        let text_path = "notes/todo.txt";
+
+        // Act
+        let rust_language = SyntaxLanguage::from_path(rust_path);
+        let text_language = SyntaxLanguage::from_path(text_path);
+        let unknown_language = SyntaxLanguage::from_path("data/blob.unknownext");
+
+        // Assert
+        assert_eq!(rust_language.map(SyntaxLanguage::name), Some("Rust"));
+        assert!(text_language.is_none());
+        assert!(unknown_language.is_none());
+    }
+
+    #[test]
+    fn test_language_from_fence_info_uses_first_token() {
+        // Arrange
+        let info = "py title=\"example\"";
+
+        // Act
+        let language = SyntaxLanguage::from_fence_info(info);
+        let empty_language = SyntaxLanguage::from_fence_info("");
+
+        // Assert
+        assert_eq!(language.map(SyntaxLanguage::name), Some("Python"));
+        assert!(empty_language.is_none());
+    }
+
+    #[test]
+    fn test_highlight_line_classifies_rust_tokens() {
+        // Arrange
+        let language = SyntaxLanguage::from_fence_info("rust").expect("rust grammar is bundled");
+        let mut highlighter = SyntaxHighlighter::new(language);
+        let line = "let name = \"agentty\"; // greet";
+
+        // Act
+        let spans = highlighter.highlight_line(line);
+        let tokens = highlighted_tokens(line, &spans);
+
+        // Assert
+        assert!(tokens.contains(&("let".to_string(), SyntaxTokenKind::Keyword)));
+        assert!(tokens.contains(&("\"agentty\"".to_string(), SyntaxTokenKind::String)));
+        assert!(tokens.contains(&("// greet".to_string(), SyntaxTokenKind::Comment)));
+        assert!(!tokens.iter().any(|(text, _)| text.contains('=')));
+    }
+
+    #[test]
+    fn test_highlight_line_carries_block_comment_state_until_reset() {
+        // Arrange
+        let language = SyntaxLanguage::from_fence_info("rust").expect("rust grammar is bundled");
+        let mut highlighter = SyntaxHighlighter::new(language);
+        highlighter.highlight_line("/* open");
+
+        // Act
+        let continued_spans = highlighter.highlight_line("still comment");
+        highlighter.reset();
+        let reset_spans = highlighter.highlight_line("still comment");
+
+        // Assert
+        assert_eq!(
+            continued_spans,
+            [SyntaxSpan {
+                kind: SyntaxTokenKind::Comment,
+                range: 0..13,
+            }]
+        );
+        assert_eq!(reset_spans, [] as [SyntaxSpan; 0]);
+    }
+
+    #[test]
+    fn test_highlighted_spans_layers_token_colors_over_base_style() {
+        // Arrange
+        let palette = TextPalette::DEFAULT;
+        let base_style = Style::default().bg(Color::Black);
+        let syntax_spans = [SyntaxSpan {
+            kind: SyntaxTokenKind::Keyword,
+            range: 0..2,
+        }];
+
+        // Act
+        let spans = highlighted_spans("fn main", &syntax_spans, base_style, &palette);
+
+        // Assert
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].content, "fn");
+        assert_eq!(spans[0].style, base_style.fg(palette.accent));
+        assert_eq!(spans[1].content, " main");
+        assert_eq!(spans[1].style, base_style);
+    }
+}
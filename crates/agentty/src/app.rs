@@ -15,10 +15,12 @@ pub(crate) mod prompt_intent;
 mod reducer;
 mod review;
 mod review_request;
+mod review_request_import;
 mod service;
 pub(crate) mod session;
 mod session_api;
 mod session_diff;
+mod session_event;
 mod session_runtime;
 pub mod session_state;
 pub(crate) mod setting;
@@ -32,11 +34,12 @@ mod view;
 #[cfg(test)]
 pub(crate) use core::AppClients;
 pub use core::{AGENTTY_WT_DIR, App, UpdateStatus, agentty_home};
-pub(crate) use core::{AppEvent, AppRuntimeEvent};
+pub(crate) use core::{AppEvent, AppRuntimeEvent, ReviewRequestForgeUpdate};
 
 pub use error::AppError;
 pub(crate) use orchestration::{
-    OrchestrationApprovalOutcome, OrchestrationCoordinator, OrchestrationSchedule,
+    OrchestrationApprovalOutcome, OrchestrationBoardAction, OrchestrationCoordinator,
+    OrchestrationSchedule,
 };
 pub use project::ProjectManager;
 pub(crate) use review::ReviewCacheEntry;
@@ -49,6 +52,7 @@ pub use session::{SessionError, SessionManager, SessionState};
 #[cfg(test)]
 pub(crate) use session::{SyncMainOutcome, SyncSessionStartError};
 pub(crate) use session_diff::SessionDiffUpdate;
+pub(crate) use session_event::{SessionChangeEvent, SessionChangeFeed};
 pub(crate) use session_runtime::{
     SessionRuntimeAccess, SessionRuntimeCommand, SessionRuntimeHandle,
 };
@@ -4,7 +4,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use ag_forge as forge;
-use ag_git::GitClient;
+use ag_git::{GitClient, WorktreeFileContent};
 
 use super::session::{self, unix_timestamp_from_system_time};
 use crate::app::review_request;
@@ -28,6 +28,9 @@ pub(crate) struct BranchPublishTaskSession {
     pub(crate) published_upstream_ref: Option<String>,
     /// Persisted linked review request, when the session already tracks one.
     pub(crate) review_request: Option<ReviewRequest>,
+    /// Draft state, labels, reviewers, and milestone applied when publishing
+    /// creates a new review request.
+    pub(crate) review_request_options: forge::ReviewRequestCreateOptions,
     /// Current session lifecycle state checked before push.
     pub(crate) status: Status,
 }
@@ -36,7 +39,8 @@ impl BranchPublishTaskSession {
     /// Builds one background-task snapshot from a live session row.
     ///
     /// The app layer may override `base_branch` with a stacked parent publish
-    /// target before moving this snapshot into the background task.
+    /// target and `review_request_options` with the publish popup choices
+    /// before moving this snapshot into the background task.
     pub(crate) fn from_session(session: &Session) -> Self {
         Self {
             base_branch: session.base_branch.clone(),
@@ -44,6 +48,7 @@ impl BranchPublishTaskSession {
             id: session.id.clone(),
             published_upstream_ref: session.published_upstream_ref.clone(),
             review_request: session.review_request.clone(),
+            review_request_options: forge::ReviewRequestCreateOptions::default(),
             status: session.status,
         }
     }
@@ -360,6 +365,10 @@ pub(crate) fn git_push_authentication_message(
             "Git push requires authentication for this repository.\nAuthorize git access, then \
              {retry_action}.\nRun `glab auth login`, or configure credentials with a PAT/SSH key."
         ),
+        Some(forge::ForgeKind::Forgejo) => format!(
+            "Git push requires authentication for this repository.\nAuthorize git access, then \
+             {retry_action}.\nRun `tea login add`, or configure credentials with a PAT/SSH key."
+        ),
         None => format!(
             "Git push requires authentication for this repository.\nAuthorize git access, then \
              {retry_action}.\nConfigure Git credentials with a PAT/SSH key or credential helper."
@@ -726,7 +735,8 @@ async fn create_or_refresh_review_request(
 }
 
 /// Builds one normalized create-request payload from branch-publish session
-/// commit message.
+/// commit message, the repository review-request template, and the publish
+/// popup options.
 async fn load_review_request_create_input(
     branch_publish_session: &BranchPublishTaskSession,
     git_client: Arc<dyn GitClient>,
@@ -757,14 +767,43 @@ async fn load_review_request_create_input(
             )
         })?;
 
+    let template = load_review_request_template(branch_publish_session, git_client).await;
+
     Ok(forge::CreateReviewRequestInput {
-        body: review_request_commit_message.body,
+        body: review_request::review_request_body_with_template(
+            review_request_commit_message.body,
+            template.as_deref(),
+        ),
+        options: branch_publish_session.review_request_options.clone(),
         source_branch,
         target_branch: branch_publish_session.base_branch.clone(),
         title: review_request_commit_message.title,
     })
 }
 
+/// Returns the first repository review-request template found in the
+/// session worktree.
+///
+/// Templates are optional, so unreadable or non-text files are skipped.
+async fn load_review_request_template(
+    branch_publish_session: &BranchPublishTaskSession,
+    git_client: Arc<dyn GitClient>,
+) -> Option<String> {
+    for template_path in review_request::REVIEW_REQUEST_TEMPLATE_PATHS {
+        let content = git_client
+            .read_worktree_file(
+                branch_publish_session.folder.clone(),
+                (*template_path).to_string(),
+            )
+            .await;
+        if let Ok(WorktreeFileContent::Text(template)) = content {
+            return Some(template);
+        }
+    }
+
+    None
+}
+
 /// Returns one forge-native review-request creation helper for a pushed
 /// session.
 async fn branch_review_request_creation_info(
@@ -827,6 +866,10 @@ fn detected_forge_kind_from_push_auth_url(detail_message: &str) -> Option<forge:
         return Some(forge::ForgeKind::GitLab);
     }
 
+    if forge::is_forgejo_host(host) {
+        return Some(forge::ForgeKind::Forgejo);
+    }
+
     None
 }
 
@@ -851,6 +894,10 @@ fn detected_forge_kind_from_text(detail_message: &str) -> Option<forge::ForgeKin
         if forge::is_gitlab_host(normalized_host) {
             return Some(forge::ForgeKind::GitLab);
         }
+
+        if forge::is_forgejo_host(normalized_host) {
+            return Some(forge::ForgeKind::Forgejo);
+        }
     }
 
     None
@@ -976,6 +1023,7 @@ mod tests {
             id: "session-id".into(),
             published_upstream_ref: None,
             review_request: None,
+            review_request_options: forge::ReviewRequestCreateOptions::default(),
             status: Status::Review,
         };
         let mut mock_git_client = git::MockGitClient::new();
@@ -9,7 +9,7 @@ mod events;
 mod new;
 mod state;
 
-pub(crate) use events::{AppEvent, AppRuntimeEvent};
+pub(crate) use events::{AppEvent, AppRuntimeEvent, ReviewRequestForgeUpdate};
 #[cfg(test)]
 pub(crate) use state::AppClients;
 pub(crate) use state::SyncReviewRequestTaskResult;
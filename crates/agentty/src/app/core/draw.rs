@@ -56,6 +56,7 @@ impl App {
         match &self.mode {
             AppMode::List
             | AppMode::SessionCreation { .. }
+            | AppMode::ReviewRequestImportInput { .. }
             | AppMode::PreCommitHookWarning { .. }
             | AppMode::ProjectSwitcher { .. }
             | AppMode::Confirmation { .. }
@@ -85,7 +86,9 @@ impl App {
                 restore_view,
                 ..
             } => *is_loading || self.session_has_tick_driven_ui(&restore_view.session_id),
-            AppMode::DiffLoading { .. } | AppMode::Diff { .. } => false,
+            AppMode::DiffLoading { .. }
+            | AppMode::Diff { .. }
+            | AppMode::OrchestrationBoard { .. } => false,
             AppMode::Help { context, .. } => self.help_overlay_has_tick_driven_ui(context),
         }
     }
@@ -23,7 +23,8 @@ use tracing::warn;
 
 use super::state::{App, SyncPopupContext, SyncReviewRequestTaskResult, UpdateStatus};
 use crate::app::session::{
-    SessionTaskService, StatusTransition, SyncMainOutcome, SyncSessionStartError, TurnAppliedState,
+    PostTurnCheckRun, SessionTaskService, StatusTransition, SyncMainOutcome, SyncSessionStartError,
+    TurnAppliedState,
 };
 use crate::app::session_state::SessionGitStatus;
 use crate::app::{self, SessionRuntimeCommand, sync_message};
@@ -32,9 +33,10 @@ use crate::domain::file_entry::{FileEntry, at_mention_lookup_root};
 use crate::domain::input::InputState;
 use crate::domain::question::default_option_index;
 use crate::domain::session::{
-    PublishBranchAction, PublishedBranchSyncStatus, Session, SessionDiffStats, SessionHandles,
-    SessionId, Status,
+    PostTurnCheckOutcome, PostTurnCheckReport, PublishBranchAction, PublishedBranchSyncStatus,
+    Session, SessionDiffStats, SessionHandles, SessionId, Status,
 };
+use crate::domain::theme::CustomThemeCatalog;
 use crate::domain::transcript_notice::TranscriptNotice;
 use crate::domain::transient_message::TransientMessageBody;
 use crate::infra::db::DbError;
@@ -100,6 +102,8 @@ pub(crate) enum AppEvent {
     },
     /// Indicates locally available agent CLI versions finished loading.
     AgentCliVersionsUpdated { agent_clis: Vec<AgentCliInfo> },
+    /// Indicates the user theme files under the themes directory changed.
+    CustomThemesUpdated { catalog: CustomThemeCatalog },
     /// Indicates progress of the background auto-update.
     UpdateStatusChanged { update_status: UpdateStatus },
     /// Indicates a session agent/model selection has been persisted.
@@ -144,6 +148,8 @@ pub(crate) enum AppEvent {
         /// Session whose comments were requested.
         session_id: SessionId,
     },
+    /// Indicates completion of one user-triggered review-request forge call.
+    ReviewRequestForgeLoaded(ReviewRequestForgeUpdate),
     /// Indicates compact live thinking text for an in-progress session.
     SessionProgressUpdated {
         progress_message: Option<String>,
@@ -223,6 +229,20 @@ pub(crate) enum AppEvent {
         notice: String,
         session_id: SessionId,
     },
+    /// Requests one agent fix turn after a failed merge gate returned the
+    /// session to review.
+    MergeGateFixTurnRequested {
+        prompt: String,
+        session_id: SessionId,
+    },
+    /// Reports the outcome of the project post-turn checks after one turn.
+    ///
+    /// A failed run with a fix prompt starts an automatic fix turn only while
+    /// fewer than its `max_fix_attempts` consecutive fix turns were requested.
+    PostTurnChecksCompleted {
+        run: PostTurnCheckRun,
+        session_id: SessionId,
+    },
     /// Indicates that the replaceable child-status loader changed for an
     /// orchestrator.
     SessionOrchestrationProgressUpdated {
@@ -248,12 +268,34 @@ pub(crate) enum AppEvent {
     },
 }
 
+/// Result of one user-triggered review-request forge call outside the
+/// background sync loop.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ReviewRequestForgeUpdate {
+    /// Mark-ready-for-review call finished.
+    MarkedReady {
+        /// Refreshed review-request summary, or the forge error message.
+        result: Result<ag_forge::ReviewRequestSummary, String>,
+        /// Session linked to the review request.
+        session_id: SessionId,
+    },
+    /// Forge choice lookup for the publish-branch overlay's review-request
+    /// fields finished.
+    PublishChoicesLoaded {
+        /// Labels, milestones, and reviewers, or the forge error message.
+        result: Result<ag_forge::ReviewRequestPublishChoices, String>,
+        /// Session whose publish overlay requested the choices.
+        session_id: SessionId,
+    },
+}
+
 /// Reduced representation of all app events currently queued for one tick.
 #[derive(Default)]
 pub(super) struct AppEventBatch {
     pub(super) applied_turns: HashMap<SessionId, TurnAppliedState>,
     pub(super) agent_cli_updates: Option<Vec<AgentCliInfo>>,
     pub(super) at_mention_entries_updates: HashMap<SessionId, Vec<FileEntry>>,
+    pub(super) custom_theme_catalog_update: Option<CustomThemeCatalog>,
     pub(super) branch_publish_action_updates: Vec<BranchPublishActionUpdate>,
     pub(super) branch_publish_resolved_session_ids: HashSet<SessionId>,
     pub(super) branch_publish_started_session_ids: HashSet<SessionId>,
@@ -279,6 +321,7 @@ pub(super) struct AppEventBatch {
     pub(super) session_speed_mode_updates: HashMap<SessionId, crate::domain::agent::SpeedMode>,
     pub(super) session_progress_updates: HashMap<SessionId, Option<String>>,
     pub(super) session_review_comment_snapshots: Vec<SessionReviewCommentSnapshotUpdate>,
+    pub(super) review_request_forge_updates: Vec<ReviewRequestForgeUpdate>,
     pub(super) session_diff_stats_updates: HashMap<SessionId, SessionDiffStats>,
     pub(super) session_diff_updates: Vec<crate::app::SessionDiffUpdate>,
     pub(super) stacked_parent_merge_child_rebases: HashSet<SessionId>,
@@ -286,6 +329,8 @@ pub(super) struct AppEventBatch {
     pub(super) stacked_parent_turns_completed: HashSet<SessionId>,
     pub(super) session_title_generation_finished: HashMap<SessionId, u64>,
     pub(super) session_workflow_notice_updates: HashMap<SessionId, Vec<String>>,
+    pub(super) merge_gate_fix_turns: HashMap<SessionId, String>,
+    pub(super) post_turn_check_runs: HashMap<SessionId, PostTurnCheckRun>,
     pub(super) should_refresh_git_status: bool,
     /// Whether this batch should reload project list snapshots from
     /// persistence.
@@ -389,6 +434,7 @@ impl AppEventBatch {
         let changes_observable_state = self.should_reload_sessions
             || self.should_reload_projects
             || self.agent_cli_updates.is_some()
+            || self.custom_theme_catalog_update.is_some()
             || self.git_status_update.is_some()
             || self.latest_available_version_update.is_some()
             || self.update_status.is_some()
@@ -408,12 +454,15 @@ impl AppEventBatch {
             || !self.session_progress_updates.is_empty()
             || !self.session_queued_sync_resolved_ids.is_empty()
             || !self.session_review_comment_snapshots.is_empty()
+            || !self.review_request_forge_updates.is_empty()
             || !self.session_reasoning_level_updates.is_empty()
             || !self.session_speed_mode_updates.is_empty()
             || !self.session_diff_stats_updates.is_empty()
             || !self.session_diff_updates.is_empty()
             || !self.session_title_generation_finished.is_empty()
             || !self.session_workflow_notice_updates.is_empty()
+            || !self.merge_gate_fix_turns.is_empty()
+            || !self.post_turn_check_runs.is_empty()
             || !self.stacked_parent_merge_child_rebases.is_empty()
             || !self.stacked_parent_syncs_completed.is_empty()
             || !self.stacked_parent_turns_completed.is_empty()
@@ -459,6 +508,9 @@ impl AppEventBatch {
             AppEvent::AgentCliVersionsUpdated { agent_clis } => {
                 self.agent_cli_updates = Some(agent_clis);
             }
+            AppEvent::CustomThemesUpdated { catalog } => {
+                self.custom_theme_catalog_update = Some(catalog);
+            }
             AppEvent::UpdateStatusChanged { update_status } => {
                 self.update_status = Some(update_status);
             }
@@ -498,6 +550,7 @@ impl AppEventBatch {
             | AppEvent::SessionDiffLoaded { .. }
             | AppEvent::SessionModelUpdated { .. }
             | AppEvent::SessionReviewCommentSnapshotLoaded { .. }
+            | AppEvent::ReviewRequestForgeLoaded(_)
             | AppEvent::SessionProgressUpdated { .. }
             | AppEvent::SyncMainCompleted { .. }
             | AppEvent::SyncMainConflictResolutionStarted { .. }
@@ -517,6 +570,8 @@ impl AppEventBatch {
             | AppEvent::StackedParentSyncCompleted { .. }
             | AppEvent::StackedParentMergeCompleted { .. }
             | AppEvent::SessionWorkflowNoticeUpdated { .. }
+            | AppEvent::MergeGateFixTurnRequested { .. }
+            | AppEvent::PostTurnChecksCompleted { .. }
             | AppEvent::SessionOrchestrationProgressUpdated { .. }
             | AppEvent::PublishedBranchSyncUpdated { .. }
             | AppEvent::ReviewRequestStatusUpdated { .. }) => self.collect_runtime_event(event),
@@ -566,13 +621,9 @@ impl AppEventBatch {
                 request_id,
                 result,
                 session_id,
-            } => {
-                self.session_review_comment_snapshots
-                    .push(SessionReviewCommentSnapshotUpdate {
-                        request_id,
-                        result,
-                        session_id,
-                    });
+            } => self.collect_review_comment_snapshot_loaded(request_id, result, session_id),
+            AppEvent::ReviewRequestForgeLoaded(update) => {
+                self.review_request_forge_updates.push(update);
             }
             AppEvent::SyncMainCompleted { result } => self.collect_sync_main_completed(result),
             AppEvent::SyncMainConflictResolutionStarted { conflicted_files } => {
@@ -606,12 +657,15 @@ impl AppEventBatch {
             | AppEvent::StackedParentSyncCompleted { .. }
             | AppEvent::StackedParentMergeCompleted { .. }
             | AppEvent::SessionWorkflowNoticeUpdated { .. }
+            | AppEvent::MergeGateFixTurnRequested { .. }
+            | AppEvent::PostTurnChecksCompleted { .. }
             | AppEvent::SessionOrchestrationProgressUpdated { .. }
             | AppEvent::PublishedBranchSyncUpdated { .. }
             | AppEvent::ReviewRequestStatusUpdated { .. }) => self.collect_workflow_event(event),
             AppEvent::GitStatusUpdated { .. }
             | AppEvent::VersionAvailabilityUpdated { .. }
             | AppEvent::AgentCliVersionsUpdated { .. }
+            | AppEvent::CustomThemesUpdated { .. }
             | AppEvent::UpdateStatusChanged { .. }
             | AppEvent::SessionPersonalityUpdated { .. }
             | AppEvent::SessionPermissionModeUpdated { .. }
@@ -625,6 +679,22 @@ impl AppEventBatch {
         }
     }
 
+    /// Collects one linked review-comment load completion for foreground
+    /// reduction.
+    fn collect_review_comment_snapshot_loaded(
+        &mut self,
+        request_id: u64,
+        result: Result<ag_forge::ReviewCommentSnapshot, String>,
+        session_id: SessionId,
+    ) {
+        self.session_review_comment_snapshots
+            .push(SessionReviewCommentSnapshotUpdate {
+                request_id,
+                result,
+                session_id,
+            });
+    }
+
     /// Collects one background full-diff completion for foreground reduction.
     fn collect_session_diff_loaded(
         &mut self,
@@ -691,6 +761,8 @@ impl AppEventBatch {
             AppEvent::SessionWorkflowNoticeUpdated { notice, session_id } => {
                 self.collect_session_workflow_notice_updated(session_id, notice);
             }
+            event @ (AppEvent::MergeGateFixTurnRequested { .. }
+            | AppEvent::PostTurnChecksCompleted { .. }) => self.collect_check_result_event(event),
             AppEvent::SessionOrchestrationProgressUpdated {
                 progress,
                 session_id,
@@ -720,6 +792,7 @@ impl AppEventBatch {
             | AppEvent::GitStatusUpdated { .. }
             | AppEvent::VersionAvailabilityUpdated { .. }
             | AppEvent::AgentCliVersionsUpdated { .. }
+            | AppEvent::CustomThemesUpdated { .. }
             | AppEvent::UpdateStatusChanged { .. }
             | AppEvent::SessionModelUpdated { .. }
             | AppEvent::SessionPersonalityUpdated { .. }
@@ -730,6 +803,7 @@ impl AppEventBatch {
             | AppEvent::RefreshProjects
             | AppEvent::RefreshGitStatus
             | AppEvent::SessionReviewCommentSnapshotLoaded { .. }
+            | AppEvent::ReviewRequestForgeLoaded(_)
             | AppEvent::SessionProgressUpdated { .. }
             | AppEvent::SyncMainCompleted { .. }
             | AppEvent::SyncMainConflictResolutionStarted { .. }
@@ -740,6 +814,20 @@ impl AppEventBatch {
         }
     }
 
+    /// Stores merge-gate fix requests and post-turn check results, keeping
+    /// the latest payload per session.
+    fn collect_check_result_event(&mut self, event: AppEvent) {
+        match event {
+            AppEvent::MergeGateFixTurnRequested { prompt, session_id } => {
+                self.merge_gate_fix_turns.insert(session_id, prompt);
+            }
+            AppEvent::PostTurnChecksCompleted { run, session_id } => {
+                self.post_turn_check_runs.insert(session_id, run);
+            }
+            _ => {}
+        }
+    }
+
     /// Stores a workflow notice update and marks its session as touched.
     fn collect_session_workflow_notice_updated(&mut self, session_id: SessionId, notice: String) {
         self.session_ids.insert(session_id.clone());
@@ -1004,6 +1092,10 @@ impl App {
         self.apply_session_review_comment_snapshot_updates(std::mem::take(
             &mut event_batch.session_review_comment_snapshots,
         ));
+        self.apply_review_request_forge_updates(std::mem::take(
+            &mut event_batch.review_request_forge_updates,
+        ))
+        .await;
         let completed_turn_session_ids = event_batch.applied_turns.keys().cloned().collect();
         let completed_review_session_ids =
             Self::completed_review_session_ids(&event_batch.applied_turns);
@@ -1025,12 +1117,10 @@ impl App {
         }
 
         self.sync_touched_sessions(&event_batch.session_ids);
-        for (session_id, progress) in
-            std::mem::take(&mut event_batch.session_orchestration_progress_updates)
-        {
-            self.sessions
-                .update_orchestration_progress(&session_id, progress);
-        }
+        self.apply_orchestration_progress_updates(std::mem::take(
+            &mut event_batch.session_orchestration_progress_updates,
+        ))
+        .await;
         for (session_id, notices) in
             std::mem::take(&mut event_batch.session_workflow_notice_updates)
         {
@@ -1063,6 +1153,10 @@ impl App {
 
         self.handle_merge_queue_progress(&event_batch.session_ids, &previous_session_states)
             .await;
+        self.start_merge_gate_fix_turns(std::mem::take(&mut event_batch.merge_gate_fix_turns))
+            .await;
+        self.apply_post_turn_check_runs(std::mem::take(&mut event_batch.post_turn_check_runs))
+            .await;
         self.retain_valid_session_progress_messages();
         self.sessions.retain_active_prompt_outputs();
 
@@ -1149,6 +1243,78 @@ impl App {
         }
     }
 
+    /// Applies user-triggered review-request forge results: mark-ready
+    /// summaries are persisted and reported in the session transcript, and
+    /// publish choices fill the matching open publish-branch overlay.
+    async fn apply_review_request_forge_updates(&mut self, updates: Vec<ReviewRequestForgeUpdate>) {
+        for update in updates {
+            match update {
+                ReviewRequestForgeUpdate::MarkedReady { result, session_id } => {
+                    self.apply_review_request_marked_ready(&session_id, result)
+                        .await;
+                }
+                ReviewRequestForgeUpdate::PublishChoicesLoaded { result, session_id } => {
+                    self.apply_review_request_publish_choices(&session_id, result);
+                }
+            }
+        }
+    }
+
+    /// Persists the refreshed summary of a review request marked ready for
+    /// review and reports the outcome in the session transcript.
+    async fn apply_review_request_marked_ready(
+        &mut self,
+        session_id: &SessionId,
+        result: Result<ag_forge::ReviewRequestSummary, String>,
+    ) {
+        let notice = match result {
+            Ok(summary) => {
+                let notice = TranscriptNotice::ReviewRequest
+                    .format(format!("Marked {} ready for review.", summary.display_id));
+                let _ = self
+                    .sessions
+                    .store_review_request_summary(&self.services, session_id, summary)
+                    .await;
+
+                notice
+            }
+            Err(error) => TranscriptNotice::ReviewRequestSyncWarning
+                .format(format!("Failed to mark review request ready: {error}")),
+        };
+        self.append_output_for_session(session_id, &notice).await;
+    }
+
+    /// Stores loaded forge publish choices on the matching open
+    /// publish-branch overlay, dropping results for overlays already closed.
+    fn apply_review_request_publish_choices(
+        &mut self,
+        session_id: &SessionId,
+        result: Result<ag_forge::ReviewRequestPublishChoices, String>,
+    ) {
+        let AppMode::PublishBranchInput {
+            restore_view,
+            review_request_form: Some(form),
+            ..
+        } = &mut self.mode
+        else {
+            return;
+        };
+        if restore_view.session_id != *session_id {
+            return;
+        }
+
+        match result {
+            Ok(choices) => {
+                form.choices = Some(choices);
+                form.choices_error = None;
+            }
+            Err(error) => {
+                form.choices = None;
+                form.choices_error = Some(format!("Failed to load forge choices: {error}"));
+            }
+        }
+    }
+
     /// Applies completed linked-session comment loads only while the matching
     /// diff workspace remains visible.
     fn apply_session_review_comment_snapshot_updates(
@@ -1218,6 +1384,59 @@ impl App {
         }
     }
 
+    /// Sends merge-gate fix prompts to sessions whose gate failed after the
+    /// failure restored them to review.
+    async fn start_merge_gate_fix_turns(&mut self, fix_turns: HashMap<SessionId, String>) {
+        for (session_id, prompt) in fix_turns {
+            if !self.reply(session_id.as_str(), prompt).await {
+                warn!(
+                    session_id = session_id.as_str(),
+                    "failed to start merge gate fix turn"
+                );
+            }
+        }
+    }
+
+    /// Records post-turn check results and starts automatic fix turns for
+    /// failed runs while the project's retry budget allows.
+    ///
+    /// Fix attempts count consecutive automatic fix turns: a passing run or a
+    /// user-submitted reply restores the full budget, while an exhausted
+    /// budget leaves the failure visible for the user to handle.
+    async fn apply_post_turn_check_runs(&mut self, runs: HashMap<SessionId, PostTurnCheckRun>) {
+        for (session_id, run) in runs {
+            let previous_fix_attempts = self
+                .sessions
+                .post_turn_check_report(&session_id)
+                .map_or(0, |report| report.fix_attempts);
+            let mut fix_attempts = match run.outcome {
+                PostTurnCheckOutcome::Passed { .. } => 0,
+                PostTurnCheckOutcome::Failed { .. } => previous_fix_attempts,
+            };
+            if let Some(fix_prompt) = run.fix_prompt
+                && fix_attempts < run.max_fix_attempts
+            {
+                if self.reply(session_id.as_str(), fix_prompt).await {
+                    fix_attempts += 1;
+                } else {
+                    warn!(
+                        session_id = session_id.as_str(),
+                        "failed to start post-turn checks fix turn"
+                    );
+                }
+            }
+
+            self.sessions.record_post_turn_check_report(
+                &session_id,
+                PostTurnCheckReport {
+                    fix_attempts,
+                    max_fix_attempts: run.max_fix_attempts,
+                    outcome: run.outcome,
+                },
+            );
+        }
+    }
+
     /// Starts automatic sync rebases for stacked children after their parent
     /// has returned to a review-ready state.
     async fn start_stacked_child_rebases_after_parent_turns(
@@ -1293,6 +1512,10 @@ impl App {
             self.services.replace_available_agent_clis(agent_clis);
         }
 
+        if let Some(catalog) = event_batch.custom_theme_catalog_update.take() {
+            self.settings.set_custom_themes(catalog);
+        }
+
         if let Some(git_status_update) = &event_batch.git_status_update
             && git_status_update.generation == self.sync_handle.current_generation()
         {
@@ -1600,6 +1823,26 @@ impl App {
         }
     }
 
+    /// Stores campaign progress snapshots and reloads a visible campaign
+    /// board whose controller reported progress.
+    async fn apply_orchestration_progress_updates(
+        &mut self,
+        progress_updates: HashMap<SessionId, Option<String>>,
+    ) {
+        let mut reloads_orchestration_board = false;
+        for (session_id, progress) in progress_updates {
+            reloads_orchestration_board |= matches!(
+                &self.mode,
+                AppMode::OrchestrationBoard { session_id: board_id, .. } if *board_id == session_id
+            );
+            self.sessions
+                .update_orchestration_progress(&session_id, progress);
+        }
+        if reloads_orchestration_board {
+            self.reload_orchestration_board().await;
+        }
+    }
+
     /// Returns whether the active UI mode currently shows the provided
     /// session.
     fn is_viewing_session(&self, session_id: &str) -> bool {
@@ -1650,10 +1893,12 @@ impl App {
             } => view_id == session_id,
             AppMode::List
             | AppMode::SessionCreation { .. }
+            | AppMode::ReviewRequestImportInput { .. }
             | AppMode::PreCommitHookWarning { .. }
             | AppMode::ProjectSwitcher { .. }
             | AppMode::Confirmation { .. }
             | AppMode::SyncBlockedPopup { .. }
+            | AppMode::OrchestrationBoard { .. }
             | AppMode::Help { .. } => false,
         }
     }
@@ -2068,6 +2313,8 @@ impl App {
                 .store_review_request_summary(&self.services, &session_id, summary)
                 .await;
         }
+        self.sessions
+            .store_review_checks(&session_id, task_result.checks);
 
         match task_result.outcome {
             crate::app::session::SyncReviewRequestOutcome::Merged {
@@ -2346,6 +2593,7 @@ impl App {
         let cleanup_task = tokio::spawn(async move {
             if let Err(error) =
                 crate::app::session::SessionManager::cleanup_merged_session_worktree(
+                    session_id.as_str(),
                     folder,
                     fs_client,
                     git_client,
@@ -2575,7 +2823,7 @@ impl App {
         )
     }
 
-    /// Returns one brief pull/push sentence fragment for sync completion.
+    /// Returns one brief pull/push sentence fragment for sync run.
     fn sync_commit_summary(direction: &str, commit_count: Option<u32>) -> String {
         match commit_count {
             Some(1) => format!("1 commit {direction}"),
@@ -2585,7 +2833,7 @@ impl App {
     }
 
     /// Returns one brief conflict-resolution sentence fragment for sync
-    /// completion.
+    /// run.
     fn sync_conflict_summary(resolved_conflict_files: &[String]) -> String {
         if resolved_conflict_files.is_empty() {
             return "no conflicts fixed".to_string();
@@ -2607,7 +2855,7 @@ mod tests {
     use crate::domain::session::{
         ForgeKind, ReviewRequest, ReviewRequestState, ReviewRequestSummary,
     };
-    use crate::presentation::app_mode::{DiffFocus, DiffLineComments};
+    use crate::presentation::app_mode::{DiffFocus, DiffLayoutMode, DiffLineComments};
 
     #[tokio::test]
     async fn merged_branch_eligibility_rejects_incomplete_session_context() {
@@ -2867,6 +3115,7 @@ mod tests {
                 diff: String::new(),
                 file_explorer_selected_index: 0,
                 focus: DiffFocus::Files,
+                layout_mode: DiffLayoutMode::default(),
                 line_comments: DiffLineComments::default(),
                 selected_diff_line_index: 0,
                 preview: DiffPreview::default(),
@@ -2927,6 +3176,7 @@ mod tests {
             diff: String::new(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -3392,6 +3642,7 @@ mod tests {
                 diff: "diff --git a/README.md b/README.md\n+preview".to_string(),
                 file_explorer_selected_index: 0,
                 focus: DiffFocus::Files,
+                layout_mode: DiffLayoutMode::default(),
                 line_comments: DiffLineComments::default(),
                 selected_diff_line_index: 0,
                 preview: DiffPreview::Loading {
@@ -3481,6 +3732,7 @@ mod tests {
             diff: "diff".to_string(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             preview,
             review_comments: None,
@@ -3543,6 +3795,7 @@ mod tests {
                 diff: "diff --git a/README.md b/README.md\n+preview".to_string(),
                 file_explorer_selected_index: 0,
                 focus: DiffFocus::Files,
+                layout_mode: DiffLayoutMode::default(),
                 line_comments: DiffLineComments::default(),
                 selected_diff_line_index: 0,
                 preview: DiffPreview::Loading {
@@ -29,9 +29,9 @@ impl App {
     /// runs automatically after detecting a newer version.
     ///
     /// # Errors
-    /// Returns an error if startup project metadata cannot be persisted,
-    /// required startup state cannot be loaded from the database, or restart
-    /// recovery cannot complete.
+    /// Returns an error if the external clients cannot be built, startup
+    /// project metadata cannot be persisted, required startup state cannot be
+    /// loaded from the database, or restart recovery cannot complete.
     pub async fn new(
         auto_update: bool,
         base_path: PathBuf,
@@ -39,7 +39,7 @@ impl App {
         git_branch: Option<String>,
         repositories: impl Into<AppRepositories>,
     ) -> Result<Self, AppError> {
-        let clients = AppClients::new();
+        let clients = AppClients::new()?;
 
         let app = Self::new_with_options(
             auto_update,
@@ -139,6 +139,12 @@ impl App {
             startup_working_dir.clone(),
         );
         let settings = Self::load_settings(&repositories, &services, active_project_id).await;
+        clients
+            .forge_backend_selection
+            .set(settings.forge_backend());
+        clients
+            .forge_host_overrides
+            .set_forgejo_hosts(&settings.forgejo_hosts());
         let mut sessions = Self::load_and_restack_startup_sessions(
             &services,
             active_project_id,
@@ -157,6 +163,7 @@ impl App {
             .unwrap_or_else(|| sync_handle.sync_main_runner());
         let mut app = Self {
             mode: crate::presentation::app_mode::AppMode::List,
+            keymap: crate::presentation::keymap::Keymap::default(),
             needs_redraw: true,
             settings,
             settings_presentation:
@@ -172,6 +179,8 @@ impl App {
             question_progress: std::collections::HashMap::new(),
             question_reconcile_reload_attempted: None,
             event_rx,
+            forge_backend_selection: clients.forge_backend_selection,
+            forge_host_overrides: clients.forge_host_overrides,
             is_tmux_session: clients.is_tmux_session,
             review_cache,
             latest_available_version: None,
@@ -184,6 +193,7 @@ impl App {
             tmux_client: clients.tmux_client,
         };
         app.recover_startup_focused_reviews(recoverable_focused_review_session_ids);
+        app.resume_persisted_merge_queue().await;
 
         Ok(app)
     }
@@ -7,7 +7,10 @@ use std::sync::Arc;
 use ag_agent::{AgentAvailabilityProbe, AppServerClient, RealAgentAvailabilityProbe};
 #[cfg(test)]
 use ag_forge as forge;
-use ag_forge::{RealReviewRequestClient, ReviewRequestClient};
+use ag_forge::{
+    ForgeBackendSelection, ForgeHostOverrides, RealReviewRequestClient, ReviewCheckReport,
+    ReviewRequestClient,
+};
 use ag_git::{GitClient, GitError, RealGitClient};
 #[cfg(test)]
 use app::branch_publish::detected_forge_kind_from_git_push_error;
@@ -69,6 +72,7 @@ use crate::infra::tmux::{self, RealTmuxClient, TmuxClient};
 use crate::presentation::app_mode::{
     AppMode, ChatFocus, ConfirmationViewMode, DiffReviewComments, PromptModeSnapshot,
 };
+use crate::presentation::keymap::Keymap;
 use crate::presentation::settings::SettingsPresentationState;
 
 /// Relative directory name used for session git worktrees within the
@@ -117,6 +121,9 @@ struct TerminalContinuationDraft {
 /// persistence alongside the UI outcome.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct SyncReviewRequestTaskResult {
+    /// Head-commit CI checks loaded for open review requests, or `None` when
+    /// the review request is not open or its checks could not be loaded.
+    pub(crate) checks: Option<ReviewCheckReport>,
     pub(crate) outcome: session::SyncReviewRequestOutcome,
     /// Normalized summary to persist when a review request was found or
     /// refreshed.
@@ -129,6 +136,12 @@ pub(crate) struct AppClients {
     /// Whether startup should spawn background CLI version detection.
     pub(super) agent_cli_version_task_enabled: bool,
     pub(super) app_server_client_override: Option<Arc<dyn AppServerClient>>,
+    /// Project forge backend shared with the production review-request
+    /// client.
+    pub(super) forge_backend_selection: ForgeBackendSelection,
+    /// Configured Forgejo hosts shared with the production review-request
+    /// client.
+    pub(super) forge_host_overrides: ForgeHostOverrides,
     pub(super) fs_client: Arc<dyn FsClient>,
     pub(super) git_client: Arc<dyn GitClient>,
     pub(super) is_tmux_session: bool,
@@ -142,20 +155,35 @@ pub(crate) struct AppClients {
 impl AppClients {
     /// Builds one client bundle with real implementations for each external
     /// boundary.
-    pub(crate) fn new() -> Self {
-        Self {
+    ///
+    /// # Errors
+    /// Returns an error when the forge review-request client cannot build its
+    /// HTTP client.
+    pub(crate) fn new() -> Result<Self, AppError> {
+        let forge_backend_selection = ForgeBackendSelection::default();
+        let forge_host_overrides = ForgeHostOverrides::default();
+        let review_request_client = RealReviewRequestClient::try_new()
+            .map_err(|error| AppError::Workflow(error.to_string()))?;
+
+        Ok(Self {
             agent_availability_probe: Arc::new(RealAgentAvailabilityProbe),
             agent_cli_version_task_enabled: !cfg!(test),
             app_server_client_override: None,
+            forge_backend_selection: forge_backend_selection.clone(),
+            forge_host_overrides: forge_host_overrides.clone(),
             fs_client: Arc::new(RealFsClient),
             git_client: Arc::new(RealGitClient),
             is_tmux_session: tmux::is_tmux_session(),
             personality_catalog_client: Arc::new(RealPersonalityCatalogClient),
             project_discovery_client: Arc::new(RealProjectDiscoveryClient),
-            review_request_client: Arc::new(RealReviewRequestClient::default()),
+            review_request_client: Arc::new(
+                review_request_client
+                    .with_backend_selection(forge_backend_selection)
+                    .with_host_overrides(forge_host_overrides),
+            ),
             sync_main_runner: None,
             tmux_client: Arc::new(RealTmuxClient),
-        }
+        })
     }
 
     /// Replaces the startup agent-availability boundary while preserving the
@@ -244,6 +272,9 @@ impl AppClients {
 pub struct App {
     /// Tracks the currently active UI mode and its transient state.
     pub mode: AppMode,
+    /// Resolves mode shortcuts and help labels, including user overrides
+    /// loaded from `keymap.toml` at terminal startup.
+    pub(crate) keymap: Keymap,
     /// Tracks whether the foreground runtime should render a fresh frame.
     pub(crate) needs_redraw: bool,
     /// Stores persisted and in-memory application settings for the active
@@ -297,6 +328,12 @@ pub struct App {
     pub(crate) sync_handle: sync::SyncHandle,
     /// Receives app events emitted by background tasks and workflows.
     pub(super) event_rx: mpsc::UnboundedReceiver<AppEvent>,
+    /// Publishes the active project's forge backend to the review-request
+    /// client.
+    pub(super) forge_backend_selection: ForgeBackendSelection,
+    /// Publishes the active project's Forgejo hosts to review-request remote
+    /// detection.
+    pub(super) forge_host_overrides: ForgeHostOverrides,
     /// Whether Agentty was launched from inside a `tmux` session.
     pub(super) is_tmux_session: bool,
     /// Stores the latest available stable `agentty` version when one is
@@ -359,6 +396,16 @@ impl App {
         self.is_tmux_session
     }
 
+    /// Publishes the active project's forge settings to the review-request
+    /// client: configured Forgejo hosts for remote detection and the selected
+    /// CLI or HTTP backend.
+    pub(crate) fn sync_forge_settings(&self) {
+        self.forge_backend_selection
+            .set(self.settings.forge_backend());
+        self.forge_host_overrides
+            .set_forgejo_hosts(&self.settings.forgejo_hosts());
+    }
+
     /// Cycles the active list tab forward.
     pub fn next_tab(&mut self) {
         self.tabs.next();
@@ -396,7 +443,7 @@ impl App {
         let working_dir = session.folder.clone();
 
         let request_id = task::TaskService::spawn_session_review_comment_snapshot_task(
-            task::SessionReviewCommentSnapshotTask {
+            task::LinkedReviewRequestTask {
                 display_id,
                 fallback_repo_url,
                 session_id: session_id.clone(),
@@ -410,6 +457,55 @@ impl App {
         Some(DiffReviewComments::loading(request_id))
     }
 
+    /// Starts taking the session's linked draft review request out of draft
+    /// state on the forge.
+    pub(crate) fn mark_session_review_request_ready(&self, session_id: &SessionId) {
+        let Some(session) = self
+            .sessions
+            .sessions()
+            .iter()
+            .find(|session| session.id == *session_id)
+        else {
+            return;
+        };
+        let Some(review_request) = session.review_request.as_ref() else {
+            return;
+        };
+
+        task::TaskService::spawn_mark_review_request_ready_task(
+            task::LinkedReviewRequestTask {
+                display_id: review_request.summary.display_id.clone(),
+                fallback_repo_url: SessionManager::review_request_repo_url(review_request),
+                session_id: session_id.clone(),
+                working_dir: session.folder.clone(),
+            },
+            self.services.event_sender(),
+            self.services.git_client(),
+            self.services.review_request_client(),
+        );
+    }
+
+    /// Starts loading forge labels, milestones, and reviewers offered by the
+    /// publish-branch overlay's review-request fields.
+    pub(crate) fn start_review_request_publish_choices_load(&self, session_id: &SessionId) {
+        let Some(session) = self
+            .sessions
+            .sessions()
+            .iter()
+            .find(|session| session.id == *session_id)
+        else {
+            return;
+        };
+
+        task::TaskService::spawn_review_request_publish_choices_task(
+            session_id.clone(),
+            session.folder.clone(),
+            self.services.event_sender(),
+            self.services.git_client(),
+            self.services.review_request_client(),
+        );
+    }
+
     /// Moves selection to the next session in the list.
     pub fn next(&mut self) {
         self.sessions.next();
@@ -502,12 +598,15 @@ impl App {
             git_upstream_ref,
             project.path,
         );
+        let custom_themes = self.settings.custom_themes().clone();
         self.settings = SettingsManager::from_repositories(
             self.services.db().clone(),
             self.services.available_agent_kinds(),
             project.id,
         )
         .await;
+        self.settings.set_custom_themes(custom_themes);
+        self.sync_forge_settings();
         self.settings_presentation = SettingsPresentationState::default();
         let default_session_model = SessionManager::load_default_session_model(
             &self.services,
@@ -527,6 +626,7 @@ impl App {
         self.reload_projects().await;
         self.refresh_sessions_now().await;
         self.resume_deferred_auto_reviews(recoverable_focused_review_session_ids);
+        self.resume_persisted_merge_queue().await;
 
         Ok(())
     }
@@ -820,8 +920,9 @@ impl App {
     ///
     /// Starting a new turn clears cached and persisted focused-review output
     /// for that session so review text does not persist past prompt
-    /// submission. Returns `true` when the reply command was enqueued on the
-    /// session worker.
+    /// submission, and restores the automatic post-turn checks fix budget.
+    /// Returns `true` when the reply command was enqueued on the session
+    /// worker.
     pub async fn reply(&mut self, session_id: &str, prompt: impl Into<TurnPrompt>) -> bool {
         if self
             .sessions
@@ -832,6 +933,7 @@ impl App {
         }
 
         self.clear_review_output(session_id);
+        self.sessions.reset_post_turn_check_fix_attempts(session_id);
         let _ = self
             .services
             .db()
@@ -1046,6 +1148,56 @@ impl App {
         Ok(())
     }
 
+    /// Lists restorable turn checkpoints for a session, newest turn first.
+    ///
+    /// # Errors
+    /// Returns an error if checkpoint refs or transcript rows cannot be loaded.
+    pub async fn list_session_turn_checkpoints(
+        &self,
+        session_id: &str,
+    ) -> Result<Vec<crate::domain::session::SessionTurnCheckpoint>, AppError> {
+        Ok(self
+            .sessions
+            .list_session_turn_checkpoints(&self.services, session_id)
+            .await?)
+    }
+
+    /// Restores a session worktree and transcript to one turn checkpoint.
+    ///
+    /// # Errors
+    /// Returns an error if the session is busy or the restore fails.
+    pub async fn rewind_session_to_turn(
+        &mut self,
+        session_id: &str,
+        turn: u32,
+    ) -> Result<(), AppError> {
+        self.sessions
+            .rewind_session_to_turn(&self.services, session_id, turn)
+            .await?;
+        self.process_pending_app_events().await;
+
+        Ok(())
+    }
+
+    /// Discards diff-view selected changes from one session worktree.
+    ///
+    /// # Errors
+    /// Returns an error if the session cannot be reverted or the patch no
+    /// longer applies to its worktree.
+    pub async fn revert_session_diff_changes(
+        &mut self,
+        session_id: &str,
+        patch: String,
+        summary: Vec<String>,
+    ) -> Result<(), AppError> {
+        self.sessions
+            .revert_session_diff_changes(&self.services, session_id, patch, summary)
+            .await?;
+        self.process_pending_app_events().await;
+
+        Ok(())
+    }
+
     /// Returns the currently selected session, if any.
     pub fn selected_session(&self) -> Option<&Session> {
         self.sessions.selected_session()
@@ -1296,14 +1448,18 @@ impl App {
     }
 
     /// Starts the session-view branch-publish action flow for one session.
+    ///
+    /// `review_request_options` only apply when the action creates a new
+    /// review request; refreshes of an existing link ignore them.
     pub(crate) async fn start_publish_branch_action(
         &mut self,
         restore_view: ConfirmationViewMode,
         session_id: &str,
         publish_branch_action: PublishBranchAction,
         remote_branch_name: Option<String>,
+        review_request_options: ag_forge::ReviewRequestCreateOptions,
     ) {
-        let Some(branch_publish_context) = self.branch_publish_task_context(session_id) else {
+        let Some(mut branch_publish_context) = self.branch_publish_task_context(session_id) else {
             self.mode = Self::view_info_popup_mode(
                 "Branch push failed".to_string(),
                 "Session is no longer available.".to_string(),
@@ -1316,6 +1472,7 @@ impl App {
         };
 
         if publish_branch_action == PublishBranchAction::PublishPullRequest {
+            branch_publish_context.session.review_request_options = review_request_options;
             let branch_operation_lock = Arc::clone(&branch_publish_context.branch_operation_lock);
             // Reserve an idle branch before persistence. An existing owner
             // already serializes worker execution, so the UI never waits here.
@@ -1407,17 +1564,25 @@ impl App {
 
     /// Starts squash-merge workflow for a review-ready session.
     ///
+    /// Queue entries are written through to persistence so pending merges
+    /// survive an app restart.
+    ///
     /// # Errors
-    /// Returns an error if session is not mergeable, queueing fails, or
-    /// immediate merge start fails while the queue is idle.
+    /// Returns an error if session is not mergeable, queued status cannot
+    /// be persisted, or immediate merge start fails while the queue is idle.
     pub async fn merge_session(&mut self, session_id: &str) -> Result<(), AppError> {
         if self.merge_queue.is_queued_or_active(session_id) {
             return Ok(());
         }
 
         self.validate_merge_request(session_id)?;
+        self.persist_merge_queue_entry(session_id).await;
         if self.merge_queue.has_active() {
-            self.mark_session_as_queued_for_merge(session_id).await?;
+            if let Err(error) = self.mark_session_as_queued_for_merge(session_id).await {
+                self.remove_persisted_merge_queue_entry(session_id).await;
+
+                return Err(error);
+            }
             self.merge_queue.enqueue(SessionId::from(session_id));
 
             return Ok(());
@@ -1428,6 +1593,65 @@ impl App {
         self.start_next_merge_from_queue(true).await
     }
 
+    /// Re-enqueues persisted merge-queue entries for the active project.
+    ///
+    /// Startup recovery already returned interrupted merges to `Queued`, so
+    /// this only rebuilds the in-memory FIFO and starts its head.
+    pub(crate) async fn resume_persisted_merge_queue(&mut self) {
+        let project_id = self.projects.active_project_id();
+        let session_ids = match self
+            .services
+            .db()
+            .merge_queue()
+            .load_project_merge_queue_session_ids(project_id)
+            .await
+        {
+            Ok(session_ids) => session_ids,
+            Err(error) => {
+                warn!(project_id, error = %error, "failed to load persisted merge queue");
+
+                return;
+            }
+        };
+        for session_id in session_ids {
+            if !self.merge_queue.is_queued_or_active(&session_id) {
+                self.merge_queue.enqueue(SessionId::from(session_id));
+            }
+        }
+
+        // Best-effort: failed entries are reported on their own transcripts.
+        let _ = self.start_next_merge_from_queue(false).await;
+    }
+
+    /// Appends one session to the persisted merge queue.
+    ///
+    /// Persistence is best-effort: a failed write only loses restart
+    /// recovery for this entry, so the in-memory merge still proceeds.
+    async fn persist_merge_queue_entry(&self, session_id: &str) {
+        if let Err(error) = self
+            .services
+            .db()
+            .merge_queue()
+            .enqueue_merge_session(session_id)
+            .await
+        {
+            warn!(session_id, error = %error, "failed to persist merge queue entry");
+        }
+    }
+
+    /// Removes one session from the persisted merge queue.
+    async fn remove_persisted_merge_queue_entry(&self, session_id: &str) {
+        if let Err(error) = self
+            .services
+            .db()
+            .merge_queue()
+            .remove_merge_session(session_id)
+            .await
+        {
+            warn!(session_id, error = %error, "failed to remove persisted merge queue entry");
+        }
+    }
+
     /// Starts or queues a session branch rebase onto its base branch.
     ///
     /// If the session is currently generating focused review output, starting
@@ -1965,6 +2189,11 @@ impl App {
                 "Session must be in review or queued status".to_string(),
             ));
         }
+        if session.is_review_request_import() {
+            return Err(AppError::Workflow(
+                session::IMPORTED_REVIEW_REQUEST_MERGE_ERROR.to_string(),
+            ));
+        }
         if !self.sessions.can_merge_session_branch_in_stack(session_id) {
             return Err(AppError::Workflow(
                 "Merge cannot run for linked review requests or while another stack session is \
@@ -2028,6 +2257,12 @@ impl App {
         }
 
         while let Some(next_session_id) = self.merge_queue.pop_next() {
+            // Entries for sessions outside the loaded project stay persisted
+            // and resume when that project becomes active again.
+            if self.sessions.session_or_err(&next_session_id).is_err() {
+                continue;
+            }
+
             match self
                 .sessions
                 .merge_session(&next_session_id, &self.projects, &self.services)
@@ -2039,6 +2274,8 @@ impl App {
                     return Ok(());
                 }
                 Err(error) => {
+                    self.remove_persisted_merge_queue_entry(&next_session_id)
+                        .await;
                     self.restore_queued_session_to_review(&next_session_id)
                         .await;
 
@@ -2059,12 +2296,14 @@ impl App {
     /// Advances queue state after reducer-applied status changes.
     ///
     /// The queue advances when the active merge session transitions away from
-    /// `Merging` or disappears from the refreshed session list.
+    /// `Merging` or disappears from the refreshed session list. The finished
+    /// merge's persisted queue entry is removed before the next one starts.
     pub(super) async fn handle_merge_queue_progress(
         &mut self,
         session_ids: &HashSet<SessionId>,
         previous_session_states: &HashMap<SessionId, Status>,
     ) {
+        let active_session_id = self.merge_queue.active_session_id().map(SessionId::from);
         let current_status = self
             .merge_queue
             .active_session_id()
@@ -2081,6 +2320,10 @@ impl App {
             previous_session_states,
         );
         if progress == MergeQueueProgress::StartNext {
+            if let Some(active_session_id) = active_session_id {
+                self.remove_persisted_merge_queue_entry(&active_session_id)
+                    .await;
+            }
             // Best-effort: merge queue progression failure is handled by status events.
             let _ = self.start_next_merge_from_queue(false).await;
         }
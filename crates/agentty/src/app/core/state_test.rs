@@ -21,6 +21,7 @@ use super::{
 };
 use crate::app::branch_publish::{BranchPublishActionUpdate, BranchPublishTaskSuccess};
 use crate::app::review::ReviewUpdate;
+use crate::app::session::PostTurnCheckRun;
 use crate::app::session_state::SessionGitStatus;
 use crate::app::{AppServiceDeps, Tab, diff_content_hash};
 use crate::domain::agent::{AgentModel, ReasoningLevel, SpeedMode};
@@ -28,9 +29,10 @@ use crate::domain::composer::PromptAttachment;
 use crate::domain::file_entry::FileEntry;
 use crate::domain::question::QuestionItem;
 use crate::domain::session::{
-    ForgeKind, PublishedBranchSyncStatus, QueuedMessage, ReviewRequest, ReviewRequestState,
-    ReviewRequestSummary, SESSION_DATA_DIR, SessionDiffState, SessionDiffStats,
-    SessionFollowUpTask, SessionHandles, SessionRole, SessionSize, SessionStats, Status,
+    ForgeKind, PostTurnCheckOutcome, PostTurnCheckReport, PublishedBranchSyncStatus, QueuedMessage,
+    ReviewRequest, ReviewRequestState, ReviewRequestSummary, SESSION_DATA_DIR, SessionDiffState,
+    SessionDiffStats, SessionFollowUpTask, SessionHandles, SessionRole, SessionSize, SessionStats,
+    Status,
 };
 use crate::domain::session_message::{SessionMessageKind, SessionTranscript};
 use crate::domain::setting::SettingName;
@@ -42,7 +44,9 @@ use crate::domain::turn_prompt::TurnPrompt;
 use crate::infra::db::AppRepositories;
 use crate::infra::project_discovery::{HOME_PROJECT_SCAN_MAX_RESULTS, RealProjectDiscoveryClient};
 use crate::infra::tmux::{MockTmuxClient, TmuxClient};
-use crate::presentation::app_mode::{DiffFocus, DiffLineComments, DiffPreview, DiffSidebarFocus};
+use crate::presentation::app_mode::{
+    DiffFocus, DiffLayoutMode, DiffLineComments, DiffPreview, DiffSidebarFocus,
+};
 use crate::presentation::prompt::{PromptAttachmentState, PromptHistoryState, PromptSlashState};
 use crate::presentation::settings::SettingsAction;
 use crate::runtime::mode::diff;
@@ -254,6 +258,7 @@ async fn diff_comments_have_no_tick_driven_ui() {
         diff: String::new(),
         file_explorer_selected_index: 0,
         focus: DiffFocus::Files,
+        layout_mode: DiffLayoutMode::default(),
         line_comments: DiffLineComments::default(),
         selected_diff_line_index: 0,
         preview: DiffPreview::default(),
@@ -1652,6 +1657,7 @@ async fn review_request_enqueue_does_not_wait_for_existing_branch_operation() {
             &session_id,
             PublishBranchAction::PublishPullRequest,
             None,
+            ag_forge::ReviewRequestCreateOptions::default(),
         ),
     )
     .await;
@@ -1696,6 +1702,7 @@ async fn running_review_request_action_queues_on_existing_worker() {
         &session_id,
         PublishBranchAction::PublishPullRequest,
         None,
+        ag_forge::ReviewRequestCreateOptions::default(),
     )
     .await;
     crate::test_support::set_session_status_for_test(&mut app, &session_id, Status::InProgress);
@@ -1709,6 +1716,7 @@ async fn running_review_request_action_queues_on_existing_worker() {
         &session_id,
         PublishBranchAction::PublishPullRequest,
         None,
+        ag_forge::ReviewRequestCreateOptions::default(),
     )
     .await;
     let publish_body = app.sessions.state().sessions()[0]
@@ -1752,6 +1760,7 @@ async fn review_request_enqueue_failure_replaces_queued_status_with_error() {
         &session_id,
         PublishBranchAction::PublishPullRequest,
         None,
+        ag_forge::ReviewRequestCreateOptions::default(),
     )
     .await;
     let publish_body = app
@@ -1811,8 +1820,14 @@ async fn push_action_still_dispatches_through_background_publish_path() {
     ));
 
     // Act
-    app.start_publish_branch_action(restore_view, &session_id, PublishBranchAction::Push, None)
-        .await;
+    app.start_publish_branch_action(
+        restore_view,
+        &session_id,
+        PublishBranchAction::Push,
+        None,
+        ag_forge::ReviewRequestCreateOptions::default(),
+    )
+    .await;
     let completion_event = tokio::time::timeout(Duration::from_secs(1), app.next_app_event())
         .await
         .expect("background branch publish should complete")
@@ -2598,6 +2613,44 @@ async fn configured_launch_configurations_returns_trimmed_non_empty_entries() {
     );
 }
 
+#[tokio::test]
+async fn sync_forge_settings_publishes_project_forgejo_hosts_and_backend() {
+    // Arrange
+    let mut app = crate::test_support::new_test_app_with_tmux_client_without_retained_base_dir(
+        Arc::new(MockTmuxClient::new()),
+    )
+    .await;
+    app.settings
+        .apply_operation(
+            crate::presentation::settings::SettingsOperation::ForgejoHosts(
+                "git.example.com\ncode.example.org".to_string(),
+            ),
+        )
+        .await;
+    app.settings
+        .apply_operation(
+            crate::presentation::settings::SettingsOperation::ForgeBackend(
+                ag_forge::ForgeBackend::Http,
+            ),
+        )
+        .await;
+
+    // Act
+    app.sync_forge_settings();
+
+    // Assert
+    assert!(app.forge_host_overrides.is_forgejo_host("git.example.com"));
+    assert!(
+        app.forge_host_overrides
+            .is_forgejo_host("code.example.org:3000")
+    );
+    assert!(!app.forge_host_overrides.is_forgejo_host("github.com"));
+    assert_eq!(
+        app.forge_backend_selection.get(),
+        ag_forge::ForgeBackend::Http
+    );
+}
+
 #[tokio::test]
 async fn open_session_worktree_in_tmux_runs_configured_launch_configuration_when_window_opens() {
     // Arrange
@@ -3769,6 +3822,7 @@ async fn apply_app_events_review_request_status_updated_ignores_stale_generation
     app.apply_app_events(AppEvent::ReviewRequestStatusUpdated {
         generation: stale_generation,
         result: Ok(SyncReviewRequestTaskResult {
+            checks: None,
             outcome: session::SyncReviewRequestOutcome::Closed {
                 display_id: "#42".to_string(),
             },
@@ -3824,6 +3878,7 @@ async fn apply_app_events_review_request_status_transition_updates_session() {
     app.apply_app_events(AppEvent::ReviewRequestStatusUpdated {
         generation,
         result: Ok(SyncReviewRequestTaskResult {
+            checks: None,
             outcome: session::SyncReviewRequestOutcome::Closed {
                 display_id: "#42".to_string(),
             },
@@ -3896,6 +3951,7 @@ async fn apply_app_events_review_request_status_survives_same_batch_sync_refresh
     app.apply_app_events(AppEvent::ReviewRequestStatusUpdated {
         generation,
         result: Ok(SyncReviewRequestTaskResult {
+            checks: None,
             outcome: session::SyncReviewRequestOutcome::Closed {
                 display_id: "#42".to_string(),
             },
@@ -6594,6 +6650,7 @@ fn merged_review_request_status_update(
     ReviewRequestStatusUpdate {
         generation: 0,
         result: Ok(SyncReviewRequestTaskResult {
+            checks: None,
             outcome: session::SyncReviewRequestOutcome::Merged {
                 display_id: display_id.to_string(),
                 session_head_hash: Some(session_head_hash.to_string()),
@@ -6857,6 +6914,7 @@ async fn test_apply_review_request_status_update_persists_summary() {
 
     let summary = test_review_request_summary("#5", ReviewRequestState::Open);
     let task_result = SyncReviewRequestTaskResult {
+        checks: None,
         outcome: session::SyncReviewRequestOutcome::Open {
             display_id: "#5".to_string(),
             status_summary: None,
@@ -6914,6 +6972,7 @@ async fn test_apply_review_request_status_update_closed_cancels_session() {
     app.refresh_sessions_now().await;
 
     let task_result = SyncReviewRequestTaskResult {
+        checks: None,
         outcome: session::SyncReviewRequestOutcome::Closed {
             display_id: "#7".to_string(),
         },
@@ -6986,6 +7045,7 @@ async fn test_apply_review_request_status_update_closed_cancels_stacked_child()
     app.refresh_sessions_now().await;
 
     let task_result = SyncReviewRequestTaskResult {
+        checks: None,
         outcome: session::SyncReviewRequestOutcome::Closed {
             display_id: "#7".to_string(),
         },
@@ -7412,3 +7472,143 @@ async fn manual_sync_defers_stranded_child_when_restack_marker_cannot_load() {
         .expect("expected durable marker warning");
     assert!(workflow_output.contains("Durable restack marker load failed"));
 }
+
+#[tokio::test]
+async fn merge_session_drops_persisted_queue_entry_when_merge_start_fails() {
+    // Arrange
+    let (mut app, _base_dir) = crate::test_support::new_test_app().await;
+    let project_id = app.projects.active_project_id();
+    app.services
+        .db()
+        .sessions()
+        .insert_session("session-id", "gpt-5.6-sol", "main", "Review", project_id)
+        .await
+        .expect("failed to insert session");
+    app.sessions.push_session(
+        crate::test_support::SessionFixtureBuilder::new()
+            .role(SessionRole::Orchestrator)
+            .status(Status::Review)
+            .build(),
+    );
+
+    // Act
+    let result = app.merge_session("session-id").await;
+    let persisted_queue = app
+        .services
+        .db()
+        .merge_queue()
+        .load_project_merge_queue_session_ids(project_id)
+        .await
+        .expect("failed to load merge queue");
+
+    // Assert
+    let error = result.expect_err("orchestrator merge should fail to start");
+    assert_eq!(
+        error.to_string(),
+        "Orchestrator sessions do not own branch changes"
+    );
+    assert_eq!(persisted_queue, [] as [String; 0]);
+    assert!(!app.merge_queue.is_queued_or_active("session-id"));
+}
+
+#[tokio::test]
+async fn resume_persisted_merge_queue_keeps_entries_for_unloaded_sessions() {
+    // Arrange
+    let (mut app, _base_dir) = crate::test_support::new_test_app().await;
+    let project_id = app.projects.active_project_id();
+    app.services
+        .db()
+        .sessions()
+        .insert_session("unloaded", "gpt-5.6-sol", "main", "Queued", project_id)
+        .await
+        .expect("failed to insert session");
+    app.services
+        .db()
+        .merge_queue()
+        .enqueue_merge_session("unloaded")
+        .await
+        .expect("failed to enqueue merge");
+
+    // Act
+    app.resume_persisted_merge_queue().await;
+    let persisted_queue = app
+        .services
+        .db()
+        .merge_queue()
+        .load_project_merge_queue_session_ids(project_id)
+        .await
+        .expect("failed to load merge queue");
+
+    // Assert
+    assert_eq!(persisted_queue, ["unloaded"]);
+    assert!(!app.merge_queue.is_queued_or_active("unloaded"));
+}
+
+#[tokio::test]
+async fn post_turn_check_runs_stop_fix_turns_at_budget_and_reset_after_pass() {
+    // Arrange
+    let (mut app, _base_dir) = crate::test_support::new_test_app().await;
+    app.sessions.push_session(
+        crate::test_support::SessionFixtureBuilder::new()
+            .id("session-1")
+            .status(Status::Review)
+            .build(),
+    );
+    app.sessions
+        .session_handles_mut()
+        .insert("session-1".into(), SessionHandles::new(Status::Review));
+    let failed_outcome = PostTurnCheckOutcome::Failed {
+        command: "cargo test".to_string(),
+        output: "assertion failed".to_string(),
+        timed_out: false,
+    };
+    app.sessions.record_post_turn_check_report(
+        "session-1",
+        PostTurnCheckReport {
+            fix_attempts: 2,
+            max_fix_attempts: 2,
+            outcome: failed_outcome.clone(),
+        },
+    );
+
+    // Act
+    app.apply_app_events(AppEvent::PostTurnChecksCompleted {
+        run: PostTurnCheckRun {
+            fix_prompt: Some("fix the checks".to_string()),
+            max_fix_attempts: 2,
+            outcome: failed_outcome.clone(),
+        },
+        session_id: "session-1".into(),
+    })
+    .await;
+    let exhausted_report = app.sessions.sessions()[0].post_turn_checks.clone();
+    app.apply_app_events(AppEvent::PostTurnChecksCompleted {
+        run: PostTurnCheckRun {
+            fix_prompt: None,
+            max_fix_attempts: 2,
+            outcome: PostTurnCheckOutcome::Passed { command_count: 1 },
+        },
+        session_id: "session-1".into(),
+    })
+    .await;
+    let passed_report = app.sessions.sessions()[0].post_turn_checks.clone();
+
+    // Assert
+    assert_eq!(
+        exhausted_report,
+        Some(PostTurnCheckReport {
+            fix_attempts: 2,
+            max_fix_attempts: 2,
+            outcome: failed_outcome,
+        })
+    );
+    assert_eq!(app.sessions.sessions()[0].status, Status::Review);
+    assert_eq!(
+        passed_report,
+        Some(PostTurnCheckReport {
+            fix_attempts: 0,
+            max_fix_attempts: 2,
+            outcome: PostTurnCheckOutcome::Passed { command_count: 1 },
+        })
+    );
+}
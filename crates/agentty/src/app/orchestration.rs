@@ -27,8 +27,10 @@ use crate::app::AppEvent;
 use crate::app::prompt_intent::build_apply_review_prompt;
 use crate::app::session::session_branch;
 use crate::domain::orchestration::{
-    IntegrationApproach, MAX_AUTOMATED_REVIEW_ITERATIONS, OrchestrationPlanTask,
-    OrchestrationPolicy, OrchestrationStatus, OrchestrationTaskKind, OrchestrationTaskStatus,
+    IntegrationApproach, MAX_AUTOMATED_REVIEW_ITERATIONS, OrchestrationBoard,
+    OrchestrationBoardTask, OrchestrationPlanTask, OrchestrationPolicy, OrchestrationStatus,
+    OrchestrationTaskDraft, OrchestrationTaskKind, OrchestrationTaskObservation,
+    OrchestrationTaskStatus, PrerequisiteState, dependency_waves,
     validate_subtasks as validate_orchestration_plan,
 };
 use crate::domain::review::{self, FocusedReviewStatus};
@@ -37,17 +39,23 @@ use crate::domain::setting::{
     MAX_ORCHESTRATION_PARALLELISM, SettingName,
 };
 use crate::infra::db::{
-    AppRepositories, DbError, OrchestrationRepository, PersistedOrchestrationTask,
-    SessionOrchestrationMetadataRow, SessionOrchestrationRow, SessionOrchestrationTaskRow,
+    AppRepositories, DbError, OrchestrationRepository, OrchestrationTaskEdit,
+    PersistedOrchestrationTask, SessionOrchestrationMetadataRow, SessionOrchestrationRow,
+    SessionOrchestrationTaskRow,
 };
 
 /// Maximum child summary length persisted into a roll-up.
 const RESULT_SUMMARY_MAX_CHARS: usize = 800;
 /// Maximum research report length persisted into a controller roll-up.
 const RESEARCH_REPORT_MAX_CHARS: usize = 32_768;
+/// Roll-up note for tasks whose verdict an earlier wave already recorded.
+const EARLIER_WAVE_VERIFICATION: &str =
+    "Verification: passed in an earlier wave; no verdict needed";
 /// Durable warning recorded when a research child attempted repository edits.
 const RESEARCH_EDIT_WARNING: &str =
     "Research child modified its temporary worktree; those changes were discarded";
+/// Failure detail recorded when the user skips a task that could not integrate.
+const SKIPPED_INTEGRATION_ERROR: &str = "Integration skipped on the campaign board";
 /// Number of identical infrastructure failures retried without user input.
 const INFRASTRUCTURE_RETRY_LIMIT: i64 = 2;
 /// Maximum recurring controller snapshot size in Unicode scalar values.
@@ -72,6 +80,40 @@ pub(crate) enum OrchestrationApprovalOutcome {
     Unavailable,
 }
 
+/// One user edit applied from the full-screen campaign board.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum OrchestrationBoardAction {
+    /// Replaces the plan fields of a proposed task.
+    Edit {
+        /// Replacement plan fields.
+        draft: OrchestrationTaskDraft,
+        /// Edited task.
+        task_id: i64,
+    },
+    /// Removes a proposed task from the plan.
+    Delete {
+        /// Removed task.
+        task_id: i64,
+    },
+    /// Moves a task one step in integration order.
+    Move {
+        /// Whether the task moves toward the front of the order.
+        earlier: bool,
+        /// Moved task.
+        task_id: i64,
+    },
+    /// Starts a failed task, and the dependents it canceled, over.
+    Retry {
+        /// Failed task.
+        task_id: i64,
+    },
+    /// Lets integration finish without a task that failed to integrate.
+    SkipIntegration {
+        /// Task whose integration failed.
+        task_id: i64,
+    },
+}
+
 /// Askama view model for controller turns.
 #[derive(Template)]
 #[template(path = "orchestrator_controller_prompt.md", escape = "none")]
@@ -85,6 +127,7 @@ struct OrchestratorControllerPromptTemplate<'a> {
 #[template(path = "orchestration_child_prompt.md", escape = "none")]
 struct OrchestrationChildPromptTemplate<'a> {
     acceptance_criteria: &'a str,
+    prerequisites: &'a str,
     prompt: &'a str,
     task_key: &'a str,
     title: &'a str,
@@ -96,6 +139,7 @@ struct OrchestrationChildPromptTemplate<'a> {
 #[template(path = "orchestration_research_prompt.md", escape = "none")]
 struct OrchestrationResearchPromptTemplate<'a> {
     acceptance_criteria: &'a str,
+    prerequisites: &'a str,
     prompt: &'a str,
     task_key: &'a str,
     title: &'a str,
@@ -184,7 +228,17 @@ pub(crate) async fn persist_controller_plan(
     let auto_approve_research = should_auto_approve_research(db, &subtasks).await;
     let retry_orchestration_id =
         reusable_retry_orchestration_id(db, existing.as_ref(), &subtasks).await?;
-    if let Err(reason) = validate_subtasks(&subtasks, retry_orchestration_id.is_some()) {
+    let retry_tasks = match retry_orchestration_id {
+        Some(retry_orchestration_id) => {
+            db.orchestrations()
+                .load_orchestration_tasks(retry_orchestration_id)
+                .await?
+        }
+        None => Vec::new(),
+    };
+    if let Err(reason) =
+        validate_subtasks(&subtasks, &retry_tasks, retry_orchestration_id.is_some())
+    {
         response.subtasks.clear();
         response.questions = vec![QuestionItem::with_options(
             format!("The orchestration plan cannot run yet: {reason} Revise the plan?"),
@@ -223,11 +277,13 @@ pub(crate) async fn persist_controller_plan(
         )
         .await?;
 
-    for (merge_position, subtask) in subtasks.into_iter().enumerate() {
+    let waves = subtask_waves(&subtasks, &retry_tasks);
+    for (merge_position, (subtask, wave)) in subtasks.into_iter().zip(waves).enumerate() {
         persist_proposed_subtask(
             db,
             orchestration_id,
             i64::try_from(merge_position).unwrap_or(i64::MAX),
+            wave,
             subtask,
         )
         .await?;
@@ -292,10 +348,21 @@ async fn persist_controller_verdicts(
     if orchestration.status != OrchestrationStatus::Verifying.to_string() {
         return Ok(());
     }
+    let admitted_task_keys = db
+        .orchestrations()
+        .load_orchestration_tasks(orchestration.id)
+        .await?
+        .into_iter()
+        .filter(|task| task_status(task) == Some(OrchestrationTaskStatus::AwaitingIntegration))
+        .map(|task| task.task_key)
+        .collect::<HashSet<_>>();
     let mut recorded_task_keys = HashSet::new();
     for verdict in response.verification_verdict_items() {
         let task_key = verdict.task_key.trim();
-        if task_key.is_empty() || !recorded_task_keys.insert(task_key.to_string()) {
+        if task_key.is_empty()
+            || admitted_task_keys.contains(task_key)
+            || !recorded_task_keys.insert(task_key.to_string())
+        {
             continue;
         }
         let recorded = db
@@ -377,6 +444,7 @@ async fn route_active_subtasks(
         .map(|task| (task.task_key.as_str(), task))
         .collect::<HashMap<_, _>>();
     let auto_approve_research = should_auto_approve_research(db, &subtasks).await;
+    let waves = subtask_waves(&subtasks, &tasks);
 
     let mut has_routed_work = false;
     let mut proposed_count = 0_i64;
@@ -386,13 +454,14 @@ async fn route_active_subtasks(
         .max()
         .unwrap_or(-1)
         .saturating_add(1);
-    for subtask in subtasks {
+    for (subtask, wave) in subtasks.into_iter().zip(waves) {
         if let Some(task) = existing_by_key.get(subtask.task_key.as_str()) {
             let existing_subtask_router = ExistingSubtaskRouter {
                 db,
                 orchestration_id: orchestration.id,
                 response,
                 task,
+                wave,
             };
             let (blocked, routed, proposed_increment) =
                 existing_subtask_router.route(subtask).await?;
@@ -409,6 +478,7 @@ async fn route_active_subtasks(
             db,
             orchestration.id,
             next_merge_position.saturating_add(proposed_count),
+            wave,
             subtask,
         )
         .await?;
@@ -454,6 +524,7 @@ struct ExistingSubtaskRouter<'a> {
     orchestration_id: i64,
     response: &'a mut AgentResponse,
     task: &'a SessionOrchestrationTaskRow,
+    wave: i64,
 }
 
 impl ExistingSubtaskRouter<'_> {
@@ -466,6 +537,7 @@ impl ExistingSubtaskRouter<'_> {
                 self.db,
                 self.orchestration_id,
                 self.task.merge_position,
+                self.wave,
                 subtask,
             )
             .await?;
@@ -500,6 +572,7 @@ async fn persist_proposed_subtask(
     db: &AppRepositories,
     orchestration_id: i64,
     merge_position: i64,
+    wave: i64,
     subtask: SubtaskItem,
 ) -> Result<(), DbError> {
     let touched_areas = serde_json::to_string(&subtask_touched_areas(&subtask))
@@ -509,6 +582,8 @@ async fn persist_proposed_subtask(
         .upsert_orchestration_task(PersistedOrchestrationTask {
             acceptance_criteria: serde_json::to_string(&subtask.acceptance_criteria)
                 .unwrap_or_else(|_| "[]".to_string()),
+            depends_on: serde_json::to_string(&subtask.depends_on)
+                .unwrap_or_else(|_| "[]".to_string()),
             kind: orchestration_task_kind(subtask.kind).to_string(),
             merge_position,
             prompt: subtask.prompt,
@@ -516,6 +591,7 @@ async fn persist_proposed_subtask(
             task_key: subtask.task_key,
             title: subtask.title,
             touched_areas,
+            wave,
         })
         .await?;
     db.orchestrations()
@@ -542,7 +618,7 @@ fn active_subtask_validation_question(
     tasks: &[SessionOrchestrationTaskRow],
     subtasks: &[SubtaskItem],
 ) -> Option<QuestionItem> {
-    if let Err(reason) = validate_subtasks(subtasks, true) {
+    if let Err(reason) = validate_subtasks(subtasks, tasks, true) {
         return Some(QuestionItem::with_options(
             format!("The follow-up work cannot run yet: {reason} Revise it?"),
             vec![
@@ -623,6 +699,7 @@ fn continuation_routing_question(task_key: &str) -> QuestionItem {
 fn task_as_subtask(task: &SessionOrchestrationTaskRow) -> Option<SubtaskItem> {
     Some(SubtaskItem {
         acceptance_criteria: serde_json::from_str(&task.acceptance_criteria).ok()?,
+        depends_on: serde_json::from_str(&task.depends_on).ok()?,
         kind: subtask_kind(task_kind(task)?),
         prompt: task.prompt.clone(),
         task_key: task.task_key.clone(),
@@ -716,6 +793,256 @@ pub(crate) async fn detach_managed_child(
         .await
 }
 
+/// Loads the full campaign board for one controller, when it owns a campaign.
+pub(crate) async fn load_orchestration_board(
+    db: &AppRepositories,
+    controller_session_id: &str,
+) -> Result<Option<OrchestrationBoard>, DbError> {
+    let Some(orchestration) = db
+        .orchestrations()
+        .load_orchestration_for_controller(controller_session_id)
+        .await?
+    else {
+        return Ok(None);
+    };
+    let Ok(status) = orchestration.status.parse::<OrchestrationStatus>() else {
+        return Ok(None);
+    };
+    let tasks = db
+        .orchestrations()
+        .load_orchestration_tasks(orchestration.id)
+        .await?;
+
+    Ok(Some(OrchestrationBoard {
+        status,
+        tasks: tasks.iter().filter_map(board_task).collect(),
+    }))
+}
+
+/// Applies one campaign-board edit through the orchestration repository.
+///
+/// Edits land in the persisted plan, so the coordinator, controller snapshot,
+/// and next verification envelope all observe them.
+///
+/// # Errors
+///
+/// Returns a user-facing reason when the task no longer allows the edit or
+/// the edited plan is invalid.
+pub(crate) async fn apply_orchestration_board_action(
+    db: &AppRepositories,
+    controller_session_id: &str,
+    action: OrchestrationBoardAction,
+) -> Result<(), String> {
+    let orchestration = db
+        .orchestrations()
+        .load_orchestration_for_controller(controller_session_id)
+        .await
+        .map_err(|error| error.to_string())?
+        .ok_or_else(|| "This campaign is no longer available.".to_string())?;
+    let tasks = db
+        .orchestrations()
+        .load_orchestration_tasks(orchestration.id)
+        .await
+        .map_err(|error| error.to_string())?;
+    let task_id = match &action {
+        OrchestrationBoardAction::Edit { task_id, .. }
+        | OrchestrationBoardAction::Delete { task_id }
+        | OrchestrationBoardAction::Move { task_id, .. }
+        | OrchestrationBoardAction::Retry { task_id }
+        | OrchestrationBoardAction::SkipIntegration { task_id } => *task_id,
+    };
+    let task = tasks
+        .iter()
+        .find(|task| task.id == task_id)
+        .ok_or_else(|| "The selected task no longer exists.".to_string())?;
+    let applied = match action {
+        OrchestrationBoardAction::Edit { draft, .. } => {
+            edit_proposed_task(db, task, &tasks, draft).await?
+        }
+        OrchestrationBoardAction::Delete { .. } => delete_proposed_task(db, task, &tasks).await?,
+        OrchestrationBoardAction::Move { earlier, .. } => {
+            move_task(db, orchestration.id, task, &tasks, earlier).await?
+        }
+        OrchestrationBoardAction::Retry { .. } => {
+            retry_failed_task(db, orchestration.id, task, &tasks).await?
+        }
+        OrchestrationBoardAction::SkipIntegration { .. } => {
+            if task_status(task) != Some(OrchestrationTaskStatus::IntegrationFailed) {
+                return Err("Only tasks that failed to integrate can be skipped.".to_string());
+            }
+            db.orchestrations()
+                .skip_orchestration_task_integration(task.id, SKIPPED_INTEGRATION_ERROR)
+                .await
+                .map_err(|error| error.to_string())?
+        }
+    };
+    if !applied {
+        return Err("The campaign moved on before the change was saved.".to_string());
+    }
+
+    Ok(())
+}
+
+/// Validates and persists replacement plan fields for one proposed task.
+async fn edit_proposed_task(
+    db: &AppRepositories,
+    task: &SessionOrchestrationTaskRow,
+    tasks: &[SessionOrchestrationTaskRow],
+    draft: OrchestrationTaskDraft,
+) -> Result<bool, String> {
+    if task_status(task) != Some(OrchestrationTaskStatus::Proposed) {
+        return Err("Only proposed tasks can be edited.".to_string());
+    }
+    let kind = task_kind(task).unwrap_or(OrchestrationTaskKind::Implementation);
+    let OrchestrationTaskDraft {
+        acceptance_criteria,
+        prompt,
+        title,
+        touched_areas,
+    } = draft;
+    let edited = OrchestrationPlanTask {
+        acceptance_criteria,
+        depends_on: task_dependencies(task),
+        kind,
+        prompt,
+        task_key: task.task_key.clone(),
+        title,
+        touched_areas: if kind == OrchestrationTaskKind::Research {
+            Vec::new()
+        } else {
+            touched_areas
+        },
+    };
+    validate_orchestration_plan(std::slice::from_ref(&edited), &existing_plan(tasks), true)?;
+    let edit = OrchestrationTaskEdit {
+        acceptance_criteria: serde_json::to_string(&edited.acceptance_criteria)
+            .map_err(|error| error.to_string())?,
+        prompt: edited.prompt,
+        title: edited.title,
+        touched_areas: serde_json::to_string(&edited.touched_areas)
+            .map_err(|error| error.to_string())?,
+    };
+
+    db.orchestrations()
+        .update_proposed_orchestration_task(task.id, edit)
+        .await
+        .map_err(|error| error.to_string())
+}
+
+/// Removes one proposed task that no other task depends on.
+async fn delete_proposed_task(
+    db: &AppRepositories,
+    task: &SessionOrchestrationTaskRow,
+    tasks: &[SessionOrchestrationTaskRow],
+) -> Result<bool, String> {
+    if task_status(task) != Some(OrchestrationTaskStatus::Proposed) {
+        return Err("Only proposed tasks can be deleted.".to_string());
+    }
+    if let Some(dependent) = tasks
+        .iter()
+        .find(|candidate| task_dependencies(candidate).contains(&task.task_key))
+    {
+        return Err(format!(
+            "Task `{}` depends on `{}`; delete it first.",
+            dependent.task_key, task.task_key
+        ));
+    }
+    if !tasks.iter().any(|candidate| {
+        candidate.id != task.id && task_status(candidate) == Some(OrchestrationTaskStatus::Proposed)
+    }) {
+        return Err("Cancel the campaign instead of deleting its last proposed task.".to_string());
+    }
+
+    db.orchestrations()
+        .delete_proposed_orchestration_task(task.id)
+        .await
+        .map_err(|error| error.to_string())
+}
+
+/// Swaps one task with its neighbor in persisted integration order.
+async fn move_task(
+    db: &AppRepositories,
+    orchestration_id: i64,
+    task: &SessionOrchestrationTaskRow,
+    tasks: &[SessionOrchestrationTaskRow],
+    earlier: bool,
+) -> Result<bool, String> {
+    let mut task_ids = tasks.iter().map(|task| task.id).collect::<Vec<_>>();
+    let Some(index) = task_ids.iter().position(|task_id| *task_id == task.id) else {
+        return Ok(true);
+    };
+    let neighbor = if earlier {
+        index.checked_sub(1)
+    } else {
+        Some(index + 1).filter(|neighbor| *neighbor < task_ids.len())
+    };
+    let Some(neighbor) = neighbor else {
+        return Ok(true);
+    };
+    task_ids.swap(index, neighbor);
+
+    db.orchestrations()
+        .reorder_orchestration_tasks(orchestration_id, task_ids)
+        .await
+        .map_err(|error| error.to_string())
+}
+
+/// Requeues one failed task together with every dependent that was canceled
+/// because of it.
+async fn retry_failed_task(
+    db: &AppRepositories,
+    orchestration_id: i64,
+    task: &SessionOrchestrationTaskRow,
+    tasks: &[SessionOrchestrationTaskRow],
+) -> Result<bool, String> {
+    if task_status(task) != Some(OrchestrationTaskStatus::Failed) {
+        return Err("Only failed tasks can be retried.".to_string());
+    }
+    let mut retried = vec![task];
+    let mut index = 0;
+    while let Some(prerequisite) = retried.get(index) {
+        let cancellation = unreachable_prerequisite_error(&prerequisite.task_key);
+        let dependents = tasks
+            .iter()
+            .filter(|candidate| {
+                task_status(candidate) == Some(OrchestrationTaskStatus::Canceled)
+                    && candidate.last_error.as_deref() == Some(cancellation.as_str())
+                    && !retried.iter().any(|retried| retried.id == candidate.id)
+            })
+            .collect::<Vec<_>>();
+        retried.extend(dependents);
+        index += 1;
+    }
+
+    db.orchestrations()
+        .retry_orchestration_tasks(
+            orchestration_id,
+            retried.iter().map(|task| task.id).collect(),
+        )
+        .await
+        .map_err(|error| error.to_string())
+}
+
+/// Projects one persisted task row onto the campaign board.
+fn board_task(task: &SessionOrchestrationTaskRow) -> Option<OrchestrationBoardTask> {
+    Some(OrchestrationBoardTask {
+        acceptance_criteria: serde_json::from_str(&task.acceptance_criteria).unwrap_or_default(),
+        child_session_id: task.child_session_id.as_deref().map(SessionId::from),
+        depends_on: task_dependencies(task),
+        id: task.id,
+        kind: task_kind(task)?,
+        last_error: task.last_error.clone(),
+        prompt: task.prompt.clone(),
+        status: task_status(task)?,
+        task_key: task.task_key.clone(),
+        title: task.title.clone(),
+        touched_areas: serde_json::from_str(&task.touched_areas).unwrap_or_default(),
+        verification_reason: task.verification_reason.clone(),
+        verification_verdict: task.verification_verdict.clone(),
+        wave: task.wave,
+    })
+}
+
 /// Bulk-loads controller-child adjacency and controller progress for one
 /// project's session-list refresh.
 pub(crate) async fn session_metadata_for_project(
@@ -893,11 +1220,28 @@ impl OrchestrationCoordinator {
         &self,
         orchestration: &SessionOrchestrationRow,
     ) -> Result<(), String> {
-        let tasks = self
+        let mut tasks = self
             .repository
             .load_orchestration_tasks(orchestration.id)
             .await
             .map_err(|error| error.to_string())?;
+        self.cancel_unreachable_tasks(&mut tasks).await?;
+        let has_next_wave = task_observations(&tasks).iter().any(|observation| {
+            observation.status == Some(OrchestrationTaskStatus::Planned)
+                && !observation.is_blocked()
+        });
+        if has_next_wave {
+            self.repository
+                .update_orchestration_status(
+                    orchestration.id,
+                    &OrchestrationStatus::Running.to_string(),
+                )
+                .await
+                .map_err(|error| error.to_string())?;
+            self.emit_live_status(orchestration, &tasks);
+
+            return Ok(());
+        }
         if tasks.iter().all(task_is_integration_settled) {
             self.complete_campaign(orchestration).await?;
         } else {
@@ -978,21 +1322,38 @@ impl OrchestrationCoordinator {
             .await
             .map_err(|error| error.to_string())?;
         for task in &mut tasks {
+            // Tasks verified in an earlier wave keep their verdict while
+            // their dependents run.
+            if task_status(task) == Some(OrchestrationTaskStatus::AwaitingIntegration) {
+                continue;
+            }
             self.reconcile_task(task).await?;
         }
         self.surface_child_questions(orchestration, &tasks).await?;
+        self.cancel_unreachable_tasks(&mut tasks).await?;
 
-        let task_statuses = tasks.iter().map(task_status).collect::<Vec<_>>();
+        let observations = task_observations(&tasks);
         let decision = OrchestrationPolicy::schedule(
             usize::try_from(orchestration.max_parallelism).unwrap_or_default(),
-            &task_statuses,
+            &observations,
         );
-        for task in tasks
-            .iter_mut()
-            .filter(|task| task_status(task) == Some(OrchestrationTaskStatus::Planned))
+        let spawn_indexes = observations
+            .iter()
+            .enumerate()
+            .filter(|(_, observation)| {
+                observation.status == Some(OrchestrationTaskStatus::Planned)
+                    && !observation.is_blocked()
+            })
+            .map(|(index, _)| index)
             .take(decision.spawn_count)
-        {
-            self.spawn_task(orchestration, task).await?;
+            .collect::<Vec<_>>();
+        for index in spawn_indexes {
+            let prerequisites = task_prerequisites(&tasks[index], &tasks)
+                .into_iter()
+                .cloned()
+                .collect::<Vec<_>>();
+            self.spawn_task(orchestration, &mut tasks[index], &prerequisites)
+                .await?;
         }
 
         let refreshed = self
@@ -1000,10 +1361,9 @@ impl OrchestrationCoordinator {
             .load_orchestration_tasks(orchestration.id)
             .await
             .map_err(|error| error.to_string())?;
-        let refreshed_statuses = refreshed.iter().map(task_status).collect::<Vec<_>>();
         let refreshed_decision = OrchestrationPolicy::schedule(
             usize::try_from(orchestration.max_parallelism).unwrap_or_default(),
-            &refreshed_statuses,
+            &task_observations(&refreshed),
         );
         if refreshed_decision.should_submit {
             self.clear_live_status(orchestration);
@@ -1117,11 +1477,9 @@ impl OrchestrationCoordinator {
             return Ok(());
         }
 
-        if let Some(task) = tasks
-            .iter_mut()
-            .find(|task| task_status(task) == Some(OrchestrationTaskStatus::AwaitingIntegration))
-        {
-            self.integrate_task(task, integration_approach).await?;
+        if let Some(index) = self.next_integration_task(&mut tasks).await? {
+            self.integrate_task(&mut tasks[index], integration_approach)
+                .await?;
             self.emit_live_status(orchestration, &tasks);
 
             return Ok(());
@@ -1136,6 +1494,62 @@ impl OrchestrationCoordinator {
         Ok(())
     }
 
+    /// Returns the next verified task whose implementation prerequisite has
+    /// already landed, in dependency-wave order.
+    ///
+    /// A dependent whose prerequisite landed is moved onto the controller's
+    /// base branch first; one whose prerequisite can no longer land fails.
+    async fn next_integration_task(
+        &self,
+        tasks: &mut [SessionOrchestrationTaskRow],
+    ) -> Result<Option<usize>, String> {
+        let mut candidates = tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, task)| {
+                task_status(task) == Some(OrchestrationTaskStatus::AwaitingIntegration)
+            })
+            .map(|(index, task)| (task.wave, index))
+            .collect::<Vec<_>>();
+        candidates.sort_unstable();
+        for (_, index) in candidates {
+            let prerequisite_status = implementation_prerequisite(&tasks[index], tasks)
+                .map(|prerequisite| (prerequisite.task_key.clone(), task_status(prerequisite)));
+            match prerequisite_status {
+                None => return Ok(Some(index)),
+                Some((_, Some(OrchestrationTaskStatus::Integrated))) => {
+                    self.repository
+                        .retarget_orchestration_task_child(tasks[index].id)
+                        .await
+                        .map_err(|error| error.to_string())?;
+
+                    return Ok(Some(index));
+                }
+                Some((
+                    prerequisite_key,
+                    Some(
+                        OrchestrationTaskStatus::IntegrationFailed
+                        | OrchestrationTaskStatus::Detached
+                        | OrchestrationTaskStatus::Canceled,
+                    )
+                    | None,
+                )) => {
+                    self.update_task_status(
+                        &mut tasks[index],
+                        OrchestrationTaskStatus::IntegrationFailed,
+                        Some(format!(
+                            "Prerequisite `{prerequisite_key}` was not integrated"
+                        )),
+                    )
+                    .await?;
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(None)
+    }
+
     async fn reconcile_review_requested_task(
         &self,
         task: &mut SessionOrchestrationTaskRow,
@@ -1687,6 +2101,31 @@ impl OrchestrationCoordinator {
         Ok(())
     }
 
+    /// Cancels planned tasks whose prerequisites can no longer pass
+    /// verification, including tasks that transitively depend on them.
+    async fn cancel_unreachable_tasks(
+        &self,
+        tasks: &mut [SessionOrchestrationTaskRow],
+    ) -> Result<(), String> {
+        while let Some((index, prerequisite_key)) =
+            tasks.iter().enumerate().find_map(|(index, task)| {
+                (task_status(task) == Some(OrchestrationTaskStatus::Planned))
+                    .then(|| failed_prerequisite_key(task, tasks))
+                    .flatten()
+                    .map(|prerequisite_key| (index, prerequisite_key))
+            })
+        {
+            self.update_task_status(
+                &mut tasks[index],
+                OrchestrationTaskStatus::Canceled,
+                Some(unreachable_prerequisite_error(&prerequisite_key)),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
     async fn update_task_status(
         &self,
         task: &mut SessionOrchestrationTaskRow,
@@ -1711,6 +2150,7 @@ impl OrchestrationCoordinator {
         &self,
         orchestration: &SessionOrchestrationRow,
         task: &mut SessionOrchestrationTaskRow,
+        prerequisites: &[SessionOrchestrationTaskRow],
     ) -> Result<(), String> {
         let claimed = self
             .repository
@@ -1727,7 +2167,18 @@ impl OrchestrationCoordinator {
             Some(OrchestrationTaskKind::Research) => {
                 CreateSessionMode::OrchestrationResearch { task_id: task.id }
             }
-            _ => CreateSessionMode::OrchestrationChild { task_id: task.id },
+            _ => CreateSessionMode::OrchestrationChild {
+                task_id: task.id,
+                prerequisite_session_id: prerequisites
+                    .iter()
+                    .find(|prerequisite| {
+                        task_kind(prerequisite) == Some(OrchestrationTaskKind::Implementation)
+                            && task_status(prerequisite)
+                                != Some(OrchestrationTaskStatus::Integrated)
+                    })
+                    .and_then(|prerequisite| prerequisite.child_session_id.clone())
+                    .map(SessionId::from),
+            },
         };
         let child_session_id = match self
             .session_service
@@ -1758,7 +2209,7 @@ impl OrchestrationCoordinator {
         }
         task.child_session_id = Some(child_session_id.as_str().to_string());
         task.status = OrchestrationTaskStatus::Running.to_string();
-        let prompt = child_prompt(task);
+        let prompt = child_prompt(task, prerequisites);
         if let Err(error) = self
             .session_service
             .send_message(&child_session_id, prompt)
@@ -1865,20 +2316,44 @@ impl OrchestrationCoordinator {
     }
 }
 
-fn validate_subtasks(subtasks: &[SubtaskItem], is_retry: bool) -> Result<(), String> {
-    let plan = subtasks
+fn validate_subtasks(
+    subtasks: &[SubtaskItem],
+    existing_tasks: &[SessionOrchestrationTaskRow],
+    is_retry: bool,
+) -> Result<(), String> {
+    let plan = subtasks.iter().map(plan_task).collect::<Vec<_>>();
+
+    validate_orchestration_plan(&plan, &existing_plan(existing_tasks), is_retry)
+}
+
+/// Returns the dependency wave persisted for each proposed subtask.
+fn subtask_waves(
+    subtasks: &[SubtaskItem],
+    existing_tasks: &[SessionOrchestrationTaskRow],
+) -> Vec<i64> {
+    let plan = subtasks.iter().map(plan_task).collect::<Vec<_>>();
+
+    dependency_waves(&plan, &existing_plan(existing_tasks))
+}
+
+fn existing_plan(tasks: &[SessionOrchestrationTaskRow]) -> Vec<OrchestrationPlanTask> {
+    tasks
         .iter()
-        .map(|subtask| OrchestrationPlanTask {
-            acceptance_criteria: subtask.acceptance_criteria.clone(),
-            kind: orchestration_task_kind(subtask.kind),
-            prompt: subtask.prompt.clone(),
-            task_key: subtask.task_key.clone(),
-            title: subtask.title.clone(),
-            touched_areas: subtask.touched_areas.clone(),
-        })
-        .collect::<Vec<_>>();
+        .filter_map(task_as_subtask)
+        .map(|subtask| plan_task(&subtask))
+        .collect()
+}
 
-    validate_orchestration_plan(&plan, is_retry)
+fn plan_task(subtask: &SubtaskItem) -> OrchestrationPlanTask {
+    OrchestrationPlanTask {
+        acceptance_criteria: subtask.acceptance_criteria.clone(),
+        depends_on: subtask.depends_on.clone(),
+        kind: orchestration_task_kind(subtask.kind),
+        prompt: subtask.prompt.clone(),
+        task_key: subtask.task_key.clone(),
+        title: subtask.title.clone(),
+        touched_areas: subtask.touched_areas.clone(),
+    }
 }
 
 fn orchestration_task_kind(kind: SubtaskKind) -> OrchestrationTaskKind {
@@ -2010,11 +2485,107 @@ fn task_blocks_integration_approval(task: &SessionOrchestrationTaskRow) -> bool
         (Some(OrchestrationTaskKind::Research), Some(OrchestrationTaskStatus::Reported)) => {
             task.verification_verdict.as_deref() != Some("Pass")
         }
+        (_, Some(OrchestrationTaskStatus::Planned)) => !task_dependencies(task).is_empty(),
         (_, Some(OrchestrationTaskStatus::Ready)) => true,
         _ => false,
     }
 }
 
+/// Returns the failure detail recorded on a task canceled because its
+/// prerequisite failed.
+fn unreachable_prerequisite_error(prerequisite_key: &str) -> String {
+    format!("Prerequisite `{prerequisite_key}` did not pass verification")
+}
+
+/// Parses the prerequisite task keys persisted for one task.
+fn task_dependencies(task: &SessionOrchestrationTaskRow) -> Vec<String> {
+    serde_json::from_str(&task.depends_on).unwrap_or_default()
+}
+
+/// Returns the persisted prerequisite rows of one task, skipping unknown keys.
+fn task_prerequisites<'a>(
+    task: &SessionOrchestrationTaskRow,
+    tasks: &'a [SessionOrchestrationTaskRow],
+) -> Vec<&'a SessionOrchestrationTaskRow> {
+    task_dependencies(task)
+        .iter()
+        .filter_map(|task_key| {
+            tasks
+                .iter()
+                .find(|candidate| candidate.task_key == *task_key)
+        })
+        .collect()
+}
+
+/// Returns the implementation task whose branch a dependent worker starts
+/// from, when it has one.
+fn implementation_prerequisite<'a>(
+    task: &SessionOrchestrationTaskRow,
+    tasks: &'a [SessionOrchestrationTaskRow],
+) -> Option<&'a SessionOrchestrationTaskRow> {
+    task_prerequisites(task, tasks)
+        .into_iter()
+        .find(|prerequisite| task_kind(prerequisite) != Some(OrchestrationTaskKind::Research))
+}
+
+fn prerequisite_states(
+    task: &SessionOrchestrationTaskRow,
+    tasks: &[SessionOrchestrationTaskRow],
+) -> Vec<(String, PrerequisiteState)> {
+    task_dependencies(task)
+        .into_iter()
+        .map(|task_key| {
+            let state = tasks
+                .iter()
+                .find(|candidate| candidate.task_key == task_key)
+                .and_then(|prerequisite| {
+                    Some(PrerequisiteState::of_prerequisite(
+                        task_kind(prerequisite)?,
+                        task_status(prerequisite),
+                        prerequisite.verification_verdict.as_deref() == Some("Pass"),
+                    ))
+                })
+                .unwrap_or(PrerequisiteState::Failed);
+
+            (task_key, state)
+        })
+        .collect()
+}
+
+fn failed_prerequisite_key(
+    task: &SessionOrchestrationTaskRow,
+    tasks: &[SessionOrchestrationTaskRow],
+) -> Option<String> {
+    prerequisite_states(task, tasks)
+        .into_iter()
+        .find(|(_, state)| *state == PrerequisiteState::Failed)
+        .map(|(task_key, _)| task_key)
+}
+
+fn pending_prerequisite_keys(
+    task: &SessionOrchestrationTaskRow,
+    tasks: &[SessionOrchestrationTaskRow],
+) -> Vec<String> {
+    prerequisite_states(task, tasks)
+        .into_iter()
+        .filter(|(_, state)| *state != PrerequisiteState::Satisfied)
+        .map(|(task_key, _)| task_key)
+        .collect()
+}
+
+fn task_observations(tasks: &[SessionOrchestrationTaskRow]) -> Vec<OrchestrationTaskObservation> {
+    tasks
+        .iter()
+        .map(|task| OrchestrationTaskObservation {
+            prerequisites: prerequisite_states(task, tasks)
+                .into_iter()
+                .map(|(_, state)| state)
+                .collect(),
+            status: task_status(task),
+        })
+        .collect()
+}
+
 fn task_is_integration_settled(task: &SessionOrchestrationTaskRow) -> bool {
     match (task_kind(task), task_status(task)) {
         (Some(OrchestrationTaskKind::Research), Some(OrchestrationTaskStatus::Reported)) => {
@@ -2037,10 +2608,15 @@ pub(crate) fn child_session_is_stopped(status: Option<&str>) -> bool {
         })
 }
 
-fn child_prompt(task: &SessionOrchestrationTaskRow) -> String {
+fn child_prompt(
+    task: &SessionOrchestrationTaskRow,
+    prerequisites: &[SessionOrchestrationTaskRow],
+) -> String {
+    let prerequisites = prerequisite_context(prerequisites);
     if task_kind(task) == Some(OrchestrationTaskKind::Research) {
         return OrchestrationResearchPromptTemplate {
             acceptance_criteria: &task.acceptance_criteria,
+            prerequisites: &prerequisites,
             prompt: &task.prompt,
             task_key: &task.task_key,
             title: &task.title,
@@ -2051,6 +2627,7 @@ fn child_prompt(task: &SessionOrchestrationTaskRow) -> String {
 
     OrchestrationChildPromptTemplate {
         acceptance_criteria: &task.acceptance_criteria,
+        prerequisites: &prerequisites,
         prompt: &task.prompt,
         task_key: &task.task_key,
         title: &task.title,
@@ -2060,6 +2637,35 @@ fn child_prompt(task: &SessionOrchestrationTaskRow) -> String {
     .unwrap_or_else(|_| task.prompt.clone())
 }
 
+/// Renders verified prerequisite results for a dependent child's first turn.
+fn prerequisite_context(prerequisites: &[SessionOrchestrationTaskRow]) -> String {
+    prerequisites
+        .iter()
+        .map(|prerequisite| {
+            if task_kind(prerequisite) == Some(OrchestrationTaskKind::Research) {
+                return format!(
+                    "Research task `{}`:\n<research_report>\n{}\n</research_report>",
+                    prerequisite.task_key,
+                    prerequisite
+                        .research_report
+                        .as_deref()
+                        .unwrap_or("No research report available")
+                );
+            }
+
+            format!(
+                "Implementation task `{}`: your worktree starts from its branch. Summary: {}",
+                prerequisite.task_key,
+                prerequisite
+                    .result_summary
+                    .as_deref()
+                    .unwrap_or("No summary available")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
 fn bounded_goal(answer: &str) -> String {
     let first_paragraph = answer.split("\n\n").next().unwrap_or(answer).trim();
     let mut characters = first_paragraph.chars();
@@ -2115,15 +2721,23 @@ fn campaign_status_message(
     lines.extend(tasks.iter().map(|task| {
         let status = task_status(task).map_or("unknown", OrchestrationTaskStatus::campaign_label);
         let evidence = campaign_task_evidence(task);
-
-        let kind_label = if task_kind(task) == Some(OrchestrationTaskKind::Research) {
+        let pending_prerequisites = pending_prerequisite_keys(task, tasks);
+        let waiting = if task_status(task) == Some(OrchestrationTaskStatus::Planned)
+            && !pending_prerequisites.is_empty()
+        {
+            format!("; waiting on {}", pending_prerequisites.join(", "))
+        } else {
+            String::new()
+        };
+
+        let kind_label = if task_kind(task) == Some(OrchestrationTaskKind::Research) {
             "[Research] "
         } else {
             ""
         };
 
         format!(
-            "- {kind_label}{} [{}]: {status}{evidence}",
+            "- {kind_label}{} [{}]: {status}{waiting}{evidence}",
             task.title, task.task_key
         )
     }));
@@ -2186,11 +2800,13 @@ fn controller_task_snapshot(task: &SessionOrchestrationTaskRow) -> serde_json::V
             || touched_area_truncated
             || omitted_touched_area_count > 0,
         "omitted_touched_area_count": omitted_touched_area_count,
+        "depends_on": task_dependencies(task),
         "kind": &task.kind,
         "status": task_status(task)
             .map_or_else(|| "unknown".to_string(), |status| status.to_string()),
         "task_key": task_key,
         "touched_areas": touched_areas,
+        "wave": task.wave,
     })
 }
 
@@ -2315,24 +2931,7 @@ fn rollup_message(goal_statement: &str, tasks: &[SessionOrchestrationTaskRow]) -
         output_tokens = output_tokens
             .saturating_add(u64::try_from(task.child_output_tokens).unwrap_or_default());
         if task_kind(task) == Some(OrchestrationTaskKind::Research) {
-            lines.extend([
-                format!("Research task `{}` — {}", task.task_key, task.status),
-                format!("Acceptance criteria: {}", task.acceptance_criteria),
-                format!(
-                    "Temporary worktree: {}",
-                    if task.child_has_diff == Some(true) {
-                        "edits were detected and discarded"
-                    } else {
-                        "no edits detected"
-                    }
-                ),
-                "<research_report>".to_string(),
-                task.research_report
-                    .clone()
-                    .unwrap_or_else(|| "No research report available".to_string()),
-                "</research_report>".to_string(),
-                String::new(),
-            ]);
+            lines.extend(research_rollup_lines(task));
 
             continue;
         }
@@ -2342,11 +2941,20 @@ fn rollup_message(goal_statement: &str, tasks: &[SessionOrchestrationTaskRow]) -
             .child_session_id
             .as_deref()
             .map_or_else(|| "none".to_string(), session_branch);
-        if task_status(task) == Some(OrchestrationTaskStatus::Ready) {
-            merge_order.push(branch.clone());
+        if matches!(
+            task_status(task),
+            Some(OrchestrationTaskStatus::Ready | OrchestrationTaskStatus::AwaitingIntegration)
+        ) {
+            merge_order.push((task.wave, branch.clone()));
+        }
+        lines.push(format!("Task `{}` — {}", task.task_key, task.status));
+        if task_was_verified_earlier(task) {
+            lines.push(EARLIER_WAVE_VERIFICATION.to_string());
+        }
+        if let Some(prerequisite) = implementation_prerequisite(task, tasks) {
+            lines.push(format!("Builds on task: `{}`", prerequisite.task_key));
         }
         lines.extend([
-            format!("Task `{}` — {}", task.task_key, task.status),
             format!("Branch: `{branch}`"),
             format!("Acceptance criteria: {}", task.acceptance_criteria),
             format!("Expected areas: {}", expected_areas_evidence(area_hints)),
@@ -2378,16 +2986,56 @@ fn rollup_message(goal_statement: &str, tasks: &[SessionOrchestrationTaskRow]) -
         "Total child token usage: {input_tokens} input, {output_tokens} output."
     ));
     lines.push("Integration order:".to_string());
+    merge_order.sort_by_key(|(wave, _)| *wave);
     lines.extend(
         merge_order
             .into_iter()
             .enumerate()
-            .map(|(index, branch)| format!("{}. `{branch}`", index + 1)),
+            .map(|(index, (_, branch))| format!("{}. `{branch}`", index + 1)),
     );
 
     lines.join("\n")
 }
 
+fn research_rollup_lines(task: &SessionOrchestrationTaskRow) -> Vec<String> {
+    let mut lines = vec![format!(
+        "Research task `{}` — {}",
+        task.task_key, task.status
+    )];
+    if task_was_verified_earlier(task) {
+        lines.push(EARLIER_WAVE_VERIFICATION.to_string());
+    }
+    lines.extend([
+        format!("Acceptance criteria: {}", task.acceptance_criteria),
+        format!(
+            "Temporary worktree: {}",
+            if task.child_has_diff == Some(true) {
+                "edits were detected and discarded"
+            } else {
+                "no edits detected"
+            }
+        ),
+        "<research_report>".to_string(),
+        task.research_report
+            .clone()
+            .unwrap_or_else(|| "No research report available".to_string()),
+        "</research_report>".to_string(),
+        String::new(),
+    ]);
+
+    lines
+}
+
+/// Returns whether an earlier verification wave already passed this task.
+fn task_was_verified_earlier(task: &SessionOrchestrationTaskRow) -> bool {
+    match (task_kind(task), task_status(task)) {
+        (Some(OrchestrationTaskKind::Research), Some(OrchestrationTaskStatus::Reported)) => {
+            task.verification_verdict.as_deref() == Some("Pass")
+        }
+        (_, status) => status == Some(OrchestrationTaskStatus::AwaitingIntegration),
+    }
+}
+
 fn rollup_review_evidence(task: &SessionOrchestrationTaskRow) -> String {
     let review_status = task
         .child_focused_review_status
@@ -2497,7 +3145,7 @@ mod tests {
     use ag_protocol::VerificationVerdictItem;
     use ag_session::{
         AnswerQuestionsRequest, ForgeKind, ReviewRequest, ReviewRequestState, ReviewRequestSummary,
-        Session, SessionBackend, SessionError,
+        Session, SessionBackend, SessionError, SessionSummary,
     };
     use async_trait::async_trait;
 
@@ -2600,6 +3248,13 @@ mod tests {
             Ok(None)
         }
 
+        async fn list_sessions(
+            &self,
+            _project_id: i64,
+        ) -> Result<Vec<SessionSummary>, SessionError> {
+            Ok(Vec::new())
+        }
+
         async fn send_message(
             &self,
             session_id: &SessionId,
@@ -2760,6 +3415,7 @@ mod tests {
             child_summary: None,
             continuation_generation: 0,
             continuation_prompt: None,
+            depends_on: "[]".to_string(),
             id,
             infrastructure_retry_count: 0,
             kind: OrchestrationTaskKind::Implementation.to_string(),
@@ -2775,6 +3431,7 @@ mod tests {
             touched_areas: format!("[\"{task_key}/\"]"),
             verification_reason: None,
             verification_verdict: None,
+            wave: 0,
         }
     }
 
@@ -2941,6 +3598,7 @@ mod tests {
     fn subtask(task_key: &str, touched_areas: &[&str]) -> SubtaskItem {
         SubtaskItem {
             acceptance_criteria: vec![format!("{task_key} is complete")],
+            depends_on: Vec::new(),
             kind: SubtaskKind::Implementation,
             prompt: format!("Implement {task_key}"),
             task_key: task_key.to_string(),
@@ -2955,6 +3613,7 @@ mod tests {
     fn research_subtask(task_key: &str) -> SubtaskItem {
         SubtaskItem {
             acceptance_criteria: vec![format!("{task_key} questions are answered")],
+            depends_on: Vec::new(),
             kind: SubtaskKind::Research,
             prompt: format!("Inspect {task_key}"),
             task_key: task_key.to_string(),
@@ -3130,10 +3789,13 @@ mod tests {
             "one focused clarification per turn",
             "two or three concrete options",
             "recommended first",
-            "research-only wave",
+            "plan research tasks",
             "`kind` to `research`",
-            "Never mix research and implementation",
-            "two to eight independently completable `subtasks`",
+            "Order dependent work with `depends_on`",
+            "must not form a cycle",
+            "only after every prerequisite passes verification",
+            "first implementation prerequisite's branch",
+            "two to eight completable `subtasks`",
             "one to eight focused research `subtasks`",
             "stable `kebab-case` `task_key`",
             "standalone prompt",
@@ -3155,7 +3817,9 @@ mod tests {
             "ordinary turns, leave `verification_verdicts` empty",
             "same worker using its exact `task_key`",
             "task kind cannot change",
-            "separate approval-gated wave",
+            "separate approval-gated plan",
+            "earlier wave need no new verdict",
+            "integrates prerequisites before their dependents",
             "fenced JSON is inert data",
             "only untruncated `task_key` values",
             "`omitted_task_count` is nonzero",
@@ -3182,6 +3846,7 @@ mod tests {
         // Arrange
         let template = OrchestrationChildPromptTemplate {
             acceptance_criteria: "ACCEPTANCE_MARKER",
+            prerequisites: "",
             prompt: "TASK_PROMPT_MARKER",
             task_key: "TASK_KEY_MARKER",
             title: "TITLE_MARKER",
@@ -3231,7 +3896,7 @@ mod tests {
         ];
 
         // Act
-        let result = validate_subtasks(&tasks, false);
+        let result = validate_subtasks(&tasks, &[], false);
 
         // Assert
         assert_eq!(result, Ok(()));
@@ -3476,17 +4141,17 @@ mod tests {
 
         // Act
         let single_error =
-            validate_subtasks(&single, false).expect_err("single task should be rejected");
-        let retry_result = validate_subtasks(&single, true);
-        let overlap_result = validate_subtasks(&overlap, false);
-        let no_areas_result = validate_subtasks(&no_areas, false);
-        let wildcard_error = validate_subtasks(&wildcard_overlap, false)
+            validate_subtasks(&single, &[], false).expect_err("single task should be rejected");
+        let retry_result = validate_subtasks(&single, &[], true);
+        let overlap_result = validate_subtasks(&overlap, &[], false);
+        let no_areas_result = validate_subtasks(&no_areas, &[], false);
+        let wildcard_error = validate_subtasks(&wildcard_overlap, &[], false)
             .expect_err("wildcard touched areas should be rejected");
-        let invalid_area_error = validate_subtasks(&invalid_area, false)
+        let invalid_area_error = validate_subtasks(&invalid_area, &[], false)
             .expect_err("non-relative touched areas should be rejected");
-        let key_error = validate_subtasks(&invalid_key, false)
+        let key_error = validate_subtasks(&invalid_key, &[], false)
             .expect_err("invalid task key should be rejected");
-        let details_error = validate_subtasks(&missing_details, false)
+        let details_error = validate_subtasks(&missing_details, &[], false)
             .expect_err("incomplete task details should be rejected");
 
         // Assert
@@ -3586,7 +4251,7 @@ mod tests {
         // Act
         let bounded = bounded_research_report(&long_report);
         let short = bounded_research_report("  concise report  ");
-        let prompt = child_prompt(&research);
+        let prompt = child_prompt(&research, &[]);
         let rollup = rollup_message(
             "Understand the project",
             &[research.clone(), clean_research.clone()],
@@ -5138,6 +5803,272 @@ mod tests {
         }));
     }
 
+    /// Marks a fixture task as depending on `depends_on` in dependency `wave`.
+    fn depending_on(
+        mut task: SessionOrchestrationTaskRow,
+        depends_on: &[&str],
+        wave: i64,
+    ) -> SessionOrchestrationTaskRow {
+        task.depends_on = serde_json::json!(depends_on).to_string();
+        task.wave = wave;
+
+        task
+    }
+
+    #[tokio::test]
+    async fn reconciliation_starts_dependents_from_verified_prerequisite_branches() {
+        // Arrange
+        let backend = TestSessionBackend::default();
+        backend.push_create_result("child-callers");
+        let mut schema = task(
+            1,
+            "schema",
+            OrchestrationTaskStatus::AwaitingIntegration,
+            Some("child-schema"),
+        );
+        schema.result_summary = Some("Added the schema column".to_string());
+        schema.verification_verdict = Some("Pass".to_string());
+        let callers = depending_on(
+            task(2, "callers", OrchestrationTaskStatus::Planned, None),
+            &["schema"],
+            1,
+        );
+        let cleanup = depending_on(
+            task(3, "cleanup", OrchestrationTaskStatus::Planned, None),
+            &["callers"],
+            2,
+        );
+        let mut running_callers = depending_on(
+            task(
+                2,
+                "callers",
+                OrchestrationTaskStatus::Running,
+                Some("child-callers"),
+            ),
+            &["schema"],
+            1,
+        );
+        running_callers.child_status = Some(SessionStatus::InProgress.to_string());
+        let mut repository = MockOrchestrationRepository::new();
+        repository
+            .expect_load_active_orchestrations()
+            .once()
+            .returning(|| Ok(vec![orchestration(2)]));
+        mock_task_snapshots(
+            &mut repository,
+            vec![
+                vec![schema.clone(), callers, cleanup.clone()],
+                vec![schema, running_callers, cleanup],
+            ],
+        );
+        repository
+            .expect_claim_orchestration_task()
+            .withf(|id| *id == 2)
+            .once()
+            .returning(|_| Ok(true));
+        repository
+            .expect_link_orchestration_task_child()
+            .withf(|id, child_session_id| *id == 2 && child_session_id == "child-callers")
+            .once()
+            .returning(|_, _| Ok(true));
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+        let coordinator =
+            OrchestrationCoordinator::new(event_tx, Arc::new(repository), backend.service());
+
+        // Act
+        coordinator
+            .reconcile_once()
+            .await
+            .expect("reconciliation should succeed");
+
+        // Assert
+        let calls = backend.calls();
+        assert_eq!(
+            calls
+                .iter()
+                .filter(|call| call.starts_with("create:"))
+                .collect::<Vec<_>>(),
+            [
+                "create:OrchestrationChild { task_id: 2, prerequisite_session_id: \
+                 Some(SessionId(\"child-schema\")) }"
+            ]
+        );
+        assert!(calls.iter().any(|call| {
+            call.starts_with("send:child-callers:")
+                && call
+                    .contains("Implementation task `schema`: your worktree starts from its branch")
+                && call.contains("Added the schema column")
+        }));
+    }
+
+    #[tokio::test]
+    async fn integration_lands_prerequisites_before_retargeting_dependents() {
+        // Arrange
+        let backend = TestSessionBackend::default();
+        let callers = depending_on(
+            task(
+                1,
+                "callers",
+                OrchestrationTaskStatus::AwaitingIntegration,
+                Some("child-callers"),
+            ),
+            &["schema"],
+            1,
+        );
+        let schema = task(
+            2,
+            "schema",
+            OrchestrationTaskStatus::AwaitingIntegration,
+            Some("child-schema"),
+        );
+        let integrated_schema = task(
+            2,
+            "schema",
+            OrchestrationTaskStatus::Integrated,
+            Some("child-schema"),
+        );
+        let failed_schema = task(
+            2,
+            "schema",
+            OrchestrationTaskStatus::IntegrationFailed,
+            Some("child-schema"),
+        );
+        let mut repository = MockOrchestrationRepository::new();
+        mock_task_snapshots(
+            &mut repository,
+            vec![
+                vec![callers.clone(), schema],
+                vec![callers.clone(), integrated_schema],
+                vec![callers, failed_schema],
+            ],
+        );
+        repository
+            .expect_load_orchestration_integration_approach()
+            .times(3)
+            .returning(|_| Ok(IntegrationApproach::LocalMerge.to_string()));
+        repository
+            .expect_retarget_orchestration_task_child()
+            .withf(|id| *id == 1)
+            .once()
+            .returning(|_| Ok(true));
+        let updates: TaskStatusUpdates = Arc::new(Mutex::new(Vec::new()));
+        repository
+            .expect_update_orchestration_task_status()
+            .times(3)
+            .returning({
+                let updates = Arc::clone(&updates);
+
+                move |id, status, error| {
+                    updates
+                        .lock()
+                        .expect("status updates should remain available")
+                        .push((id, status.to_string(), error));
+
+                    Ok(())
+                }
+            });
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+        let coordinator =
+            OrchestrationCoordinator::new(event_tx, Arc::new(repository), backend.service());
+        let mut campaign = orchestration(2);
+        campaign.status = OrchestrationStatus::Integrating.to_string();
+
+        // Act
+        for _ in 0..3 {
+            coordinator
+                .reconcile_integration(&campaign)
+                .await
+                .expect("integration snapshot should reconcile");
+        }
+
+        // Assert
+        let calls = backend.calls();
+        assert_eq!(
+            calls
+                .iter()
+                .filter(|call| call.starts_with("merge:"))
+                .collect::<Vec<_>>(),
+            ["merge:child-schema", "merge:child-callers"]
+        );
+        assert_eq!(
+            *updates
+                .lock()
+                .expect("status updates should remain available"),
+            [
+                (2, "Merging".to_string(), None),
+                (1, "Merging".to_string(), None),
+                (
+                    1,
+                    "IntegrationFailed".to_string(),
+                    Some("Prerequisite `schema` was not integrated".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn awaiting_integration_resumes_the_next_wave_and_cancels_unreachable_dependents() {
+        // Arrange
+        let backend = TestSessionBackend::default();
+        let mut survey = task(1, "survey", OrchestrationTaskStatus::Failed, None);
+        survey.kind = OrchestrationTaskKind::Research.to_string();
+        let tasks = vec![
+            survey,
+            task(
+                2,
+                "schema",
+                OrchestrationTaskStatus::AwaitingIntegration,
+                Some("child-schema"),
+            ),
+            depending_on(
+                task(3, "callers", OrchestrationTaskStatus::Planned, None),
+                &["schema"],
+                1,
+            ),
+            depending_on(
+                task(4, "docs", OrchestrationTaskStatus::Planned, None),
+                &["survey"],
+                1,
+            ),
+        ];
+        let mut repository = MockOrchestrationRepository::new();
+        mock_task_snapshots(&mut repository, vec![tasks]);
+        repository
+            .expect_update_orchestration_task_status()
+            .withf(|id, status, error| {
+                *id == 4
+                    && status == "Canceled"
+                    && error.as_deref() == Some("Prerequisite `survey` did not pass verification")
+            })
+            .once()
+            .returning(|_, _, _| Ok(()));
+        repository
+            .expect_update_orchestration_status()
+            .withf(|id, status| *id == 1 && status == "Running")
+            .once()
+            .returning(|_, _| Ok(()));
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let coordinator =
+            OrchestrationCoordinator::new(event_tx, Arc::new(repository), backend.service());
+        let mut campaign = orchestration(2);
+        campaign.status = OrchestrationStatus::AwaitingIntegration.to_string();
+
+        // Act
+        coordinator
+            .reconcile_awaiting_integration(&campaign)
+            .await
+            .expect("awaiting integration snapshot should reconcile");
+
+        // Assert
+        let progress = match event_rx.try_recv() {
+            Ok(AppEvent::SessionOrchestrationProgressUpdated { progress, .. }) => progress,
+            _ => None,
+        }
+        .expect("the resumed campaign should emit a live status");
+        assert!(progress.contains("- callers [callers]: waiting\n"));
+        assert!(progress.ends_with("- docs [docs]: canceled"));
+    }
+
     #[tokio::test]
     async fn failed_child_creation_queues_an_infrastructure_retry() {
         // Arrange
@@ -5162,7 +6093,7 @@ mod tests {
 
         // Act
         coordinator
-            .spawn_task(&orchestration(2), &mut planned_task)
+            .spawn_task(&orchestration(2), &mut planned_task, &[])
             .await
             .expect("failed creation should settle the task");
 
@@ -5203,7 +6134,7 @@ mod tests {
 
         // Act
         coordinator
-            .spawn_task(&orchestration(2), &mut planned_task)
+            .spawn_task(&orchestration(2), &mut planned_task, &[])
             .await
             .expect("research child should spawn");
 
@@ -5426,7 +6357,7 @@ mod tests {
 
         // Act
         coordinator
-            .spawn_task(&orchestration(2), &mut planned_task)
+            .spawn_task(&orchestration(2), &mut planned_task, &[])
             .await
             .expect("failed prompt delivery should settle the task");
 
@@ -5457,7 +6388,7 @@ mod tests {
 
         // Act
         coordinator
-            .spawn_task(&orchestration(2), &mut planned_task)
+            .spawn_task(&orchestration(2), &mut planned_task, &[])
             .await
             .expect("a lost fan-out claim should be harmless");
 
@@ -5492,7 +6423,7 @@ mod tests {
 
         // Act
         coordinator
-            .spawn_task(&orchestration(2), &mut planned_task)
+            .spawn_task(&orchestration(2), &mut planned_task, &[])
             .await
             .expect("the unclaimed child should be canceled");
 
@@ -5500,7 +6431,8 @@ mod tests {
         assert_eq!(
             backend.calls(),
             vec![
-                "create:OrchestrationChild { task_id: 1 }".to_string(),
+                "create:OrchestrationChild { task_id: 1, prerequisite_session_id: None }"
+                    .to_string(),
                 "cancel:child-1".to_string(),
             ]
         );
@@ -6479,6 +7411,200 @@ mod tests {
         );
     }
 
+    /// Returns the campaign board for the fixture controller.
+    async fn board(database: &AppRepositories) -> OrchestrationBoard {
+        load_orchestration_board(database, "controller")
+            .await
+            .expect("board should load")
+            .expect("controller should own a campaign")
+    }
+
+    /// Returns board task keys in integration order.
+    fn board_task_keys(board: &OrchestrationBoard) -> Vec<&str> {
+        board
+            .tasks
+            .iter()
+            .map(|task| task.task_key.as_str())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn campaign_board_edits_deletes_and_reorders_proposed_tasks() {
+        // Arrange
+        let (database, _) = controller_database().await;
+        let mut docs = subtask("docs", &["docs/site/content/docs/"]);
+        docs.depends_on = vec!["ui".to_string()];
+        let mut response = AgentResponse::plain("Plan");
+        response.subtasks = vec![
+            subtask("protocol", &["crates/ag-protocol/"]),
+            subtask("ui", &["crates/agentty/src/ui/"]),
+            docs,
+        ];
+        persist_controller_plan(&database, "controller", &mut response)
+            .await
+            .expect("plan should persist");
+        let ids = board(&database)
+            .await
+            .tasks
+            .iter()
+            .map(|task| task.id)
+            .collect::<Vec<_>>();
+        let draft = OrchestrationTaskDraft {
+            acceptance_criteria: vec!["Protocol round-trips".to_string()],
+            prompt: "Extend the protocol schema".to_string(),
+            title: "Protocol schema".to_string(),
+            touched_areas: vec!["crates/ag-protocol/src/".to_string()],
+        };
+        let mut empty_title = draft.clone();
+        empty_title.title = String::new();
+
+        // Act
+        let empty_title_result = apply_orchestration_board_action(
+            &database,
+            "controller",
+            OrchestrationBoardAction::Edit {
+                draft: empty_title,
+                task_id: ids[0],
+            },
+        )
+        .await;
+        let edit_result = apply_orchestration_board_action(
+            &database,
+            "controller",
+            OrchestrationBoardAction::Edit {
+                draft,
+                task_id: ids[0],
+            },
+        )
+        .await;
+        let prerequisite_delete_result = apply_orchestration_board_action(
+            &database,
+            "controller",
+            OrchestrationBoardAction::Delete { task_id: ids[1] },
+        )
+        .await;
+        let dependent_delete_result = apply_orchestration_board_action(
+            &database,
+            "controller",
+            OrchestrationBoardAction::Delete { task_id: ids[2] },
+        )
+        .await;
+        let move_result = apply_orchestration_board_action(
+            &database,
+            "controller",
+            OrchestrationBoardAction::Move {
+                earlier: false,
+                task_id: ids[0],
+            },
+        )
+        .await;
+        let board = board(&database).await;
+
+        // Assert
+        assert!(empty_title_result.is_err());
+        assert_eq!(edit_result, Ok(()));
+        assert_eq!(
+            prerequisite_delete_result,
+            Err("Task `docs` depends on `ui`; delete it first.".to_string())
+        );
+        assert_eq!(dependent_delete_result, Ok(()));
+        assert_eq!(move_result, Ok(()));
+        assert_eq!(board.status, OrchestrationStatus::AwaitingApproval);
+        assert_eq!(board_task_keys(&board), ["ui", "protocol"]);
+        assert_eq!(board.tasks[1].title, "Protocol schema");
+        assert_eq!(board.tasks[1].prompt, "Extend the protocol schema");
+        assert_eq!(board.tasks[1].acceptance_criteria, ["Protocol round-trips"]);
+        assert_eq!(board.tasks[1].touched_areas, ["crates/ag-protocol/src/"]);
+    }
+
+    #[tokio::test]
+    async fn campaign_board_retries_failed_tasks_and_skips_integration_failures() {
+        // Arrange
+        let (database, _) = controller_database().await;
+        let (_, tasks, _, _) = persist_approved_plan(
+            &database,
+            vec![
+                subtask("protocol", &["crates/ag-protocol/"]),
+                subtask("ui", &["crates/agentty/src/ui/"]),
+                subtask("docs", &["docs/site/content/docs/"]),
+            ],
+        )
+        .await;
+        for (task, status, error) in [
+            (&tasks[0], OrchestrationTaskStatus::Failed, "Child failed"),
+            (
+                &tasks[1],
+                OrchestrationTaskStatus::Canceled,
+                "Prerequisite `protocol` did not pass verification",
+            ),
+            (
+                &tasks[2],
+                OrchestrationTaskStatus::IntegrationFailed,
+                "Merge conflict",
+            ),
+        ] {
+            database
+                .orchestrations()
+                .update_orchestration_task_status(
+                    task.id,
+                    &status.to_string(),
+                    Some(error.to_string()),
+                )
+                .await
+                .expect("failed to seed task status");
+        }
+
+        // Act
+        let canceled_retry_result = apply_orchestration_board_action(
+            &database,
+            "controller",
+            OrchestrationBoardAction::Retry {
+                task_id: tasks[1].id,
+            },
+        )
+        .await;
+        let retry_result = apply_orchestration_board_action(
+            &database,
+            "controller",
+            OrchestrationBoardAction::Retry {
+                task_id: tasks[0].id,
+            },
+        )
+        .await;
+        let skip_result = apply_orchestration_board_action(
+            &database,
+            "controller",
+            OrchestrationBoardAction::SkipIntegration {
+                task_id: tasks[2].id,
+            },
+        )
+        .await;
+        let board = board(&database).await;
+
+        // Assert
+        assert_eq!(
+            canceled_retry_result,
+            Err("Only failed tasks can be retried.".to_string())
+        );
+        assert_eq!(retry_result, Ok(()));
+        assert_eq!(skip_result, Ok(()));
+        assert_eq!(
+            board
+                .tasks
+                .iter()
+                .map(|task| (task.status, task.last_error.as_deref()))
+                .collect::<Vec<_>>(),
+            [
+                (OrchestrationTaskStatus::Planned, None),
+                (OrchestrationTaskStatus::Planned, None),
+                (
+                    OrchestrationTaskStatus::Canceled,
+                    Some(SKIPPED_INTEGRATION_ERROR)
+                ),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn running_orchestration_discards_repeated_plan_output() {
         // Arrange
@@ -6735,8 +7861,7 @@ mod tests {
             .load_orchestration_tasks(orchestration.id)
             .await
             .expect("failed to reload settled tasks");
-        let decision =
-            OrchestrationPolicy::schedule(2, &routed.iter().map(task_status).collect::<Vec<_>>());
+        let decision = OrchestrationPolicy::schedule(2, &task_observations(&routed));
 
         // Assert
         assert_eq!(campaign.status, OrchestrationStatus::Running.to_string());
@@ -7191,6 +8316,7 @@ mod tests {
             .orchestrations()
             .upsert_orchestration_task(PersistedOrchestrationTask {
                 acceptance_criteria: r#"["Protocol is implemented"]"#.to_string(),
+                depends_on: "[]".to_string(),
                 kind: OrchestrationTaskKind::Implementation.to_string(),
                 merge_position: 0,
                 prompt: "Implement protocol".to_string(),
@@ -7198,6 +8324,7 @@ mod tests {
                 task_key: "protocol".to_string(),
                 title: "Protocol".to_string(),
                 touched_areas: r#"["crates/ag-protocol/"]"#.to_string(),
+                wave: 0,
             })
             .await
             .expect("task should persist");
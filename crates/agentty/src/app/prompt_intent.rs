@@ -2,7 +2,7 @@ use std::fmt::Write as _;
 use std::path::PathBuf;
 
 use ag_agent as agent;
-use ag_forge::{ReviewCommentSnapshot, ReviewCommentThread};
+use ag_forge::{ReviewCheckReport, ReviewCheckRun, ReviewCommentSnapshot, ReviewCommentThread};
 use tracing::warn;
 
 #[cfg(test)]
@@ -15,7 +15,7 @@ use crate::domain::composer::PromptAttachment;
 use crate::domain::permission::PermissionMode;
 use crate::domain::personality::PersonalitySummary;
 use crate::domain::review;
-use crate::domain::session::{SessionId, Status};
+use crate::domain::session::{SessionId, SessionTurnCheckpoint, Status};
 use crate::domain::transcript_notice::TranscriptNotice;
 use crate::domain::transient_message::{
     TransientMessage, TransientMessageAnchor, TransientMessageBody, TransientMessageLifecycle,
@@ -30,6 +30,9 @@ const APPLY_REVIEW_PROMPT_TEMPLATE: &str = include_str!("template/apply_review_p
 /// Checked-in prompt template submitted from the review-comments page.
 const RESOLVE_REVIEW_COMMENT_PROMPT_TEMPLATE: &str =
     include_str!("template/resolve_review_comment_prompt.md");
+/// Checked-in prompt template submitted by the fix-CI session action.
+const FIX_REVIEW_CHECKS_PROMPT_TEMPLATE: &str =
+    include_str!("template/fix_review_checks_prompt.md");
 
 /// Presentation navigation requested after a review-comment resolution attempt.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -168,6 +171,25 @@ impl App {
         }
     }
 
+    /// Submits one agent turn that asks the session to fix its failing CI
+    /// checks, using the cached job log tails.
+    ///
+    /// Returns whether the session accepted the fix-up turn.
+    pub(crate) async fn fix_session_review_checks(&mut self, session_id: &SessionId) -> bool {
+        let Some(prompt) = self
+            .sessions
+            .sessions()
+            .iter()
+            .find(|session| session.id == *session_id)
+            .and_then(|session| session.review_checks.as_ref())
+            .and_then(build_fix_review_checks_prompt)
+        else {
+            return false;
+        };
+
+        self.reply(session_id.as_str(), prompt).await
+    }
+
     /// Routes one presentation-owned prompt submission through the matching
     /// session workflow and returns the requested navigation effect.
     pub(crate) async fn submit_prompt(
@@ -360,6 +382,48 @@ impl App {
             .await;
     }
 
+    /// Loads `/rewind` picker entries for the targeted session.
+    pub(crate) async fn list_prompt_turn_checkpoints(
+        &self,
+        session_id: &SessionId,
+    ) -> Vec<SessionTurnCheckpoint> {
+        match self.list_session_turn_checkpoints(session_id).await {
+            Ok(checkpoints) => checkpoints,
+            Err(error) => {
+                warn!(
+                    session_id = %session_id,
+                    error = %error,
+                    "failed to list session turn checkpoints"
+                );
+
+                Vec::new()
+            }
+        }
+    }
+
+    /// Rewinds one session to a slash-selected turn checkpoint and appends
+    /// visible feedback.
+    pub(crate) async fn rewind_prompt_session(&mut self, session_id: &SessionId, turn: u32) {
+        match self.rewind_session_to_turn(session_id, turn).await {
+            Ok(()) => {
+                self.append_prompt_status_line(
+                    session_id,
+                    TranscriptNotice::Rewind,
+                    &format!("Rewound to the end of turn {turn}."),
+                )
+                .await;
+            }
+            Err(error) => {
+                self.append_prompt_status_line(
+                    session_id,
+                    TranscriptNotice::RewindError,
+                    &error.to_string(),
+                )
+                .await;
+            }
+        }
+    }
+
     /// Persists one slash-selected reasoning level and logs any failure with
     /// session context.
     pub(crate) async fn update_prompt_session_reasoning_level(
@@ -641,6 +705,70 @@ pub(crate) fn build_resolve_review_comment_prompt(
     Some((TurnPrompt::from_agent_data(prompt), thread_ids))
 }
 
+/// Builds the review-comment prompt for every actionable thread in
+/// `snapshot`, used when a session adopts an existing review request.
+pub(crate) fn build_unresolved_review_comment_prompt(
+    snapshot: &ReviewCommentSnapshot,
+) -> Option<(TurnPrompt, Vec<String>)> {
+    let selections = snapshot
+        .threads
+        .iter()
+        .filter(|thread| thread.is_actionable())
+        .map(|thread| ReviewCommentSelection {
+            thread_id: thread.id.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    build_resolve_review_comment_prompt(snapshot, &selections)
+}
+
+/// Builds the agent-facing fix-CI prompt from the failed runs of one check
+/// report.
+///
+/// Returns `None` when the report has no failed runs.
+pub(crate) fn build_fix_review_checks_prompt(report: &ReviewCheckReport) -> Option<TurnPrompt> {
+    let mut check_logs = String::new();
+    for run in report.failed_runs() {
+        append_failed_check_prompt(&mut check_logs, run);
+    }
+    if check_logs.is_empty() {
+        return None;
+    }
+
+    let check_logs = format!(
+        "Head commit: {}\n\n{}",
+        report.head_sha,
+        check_logs.trim_end()
+    );
+    let fence = agent::diff_fence(&check_logs);
+    let fenced_check_logs = format!("{fence}text\n{check_logs}\n{fence}");
+    let prompt = FIX_REVIEW_CHECKS_PROMPT_TEMPLATE
+        .trim_end()
+        .replace("{{ fenced_check_logs }}", &fenced_check_logs);
+
+    Some(TurnPrompt::from_agent_data(prompt))
+}
+
+/// Appends one failed check's name, details URL, and log tail.
+fn append_failed_check_prompt(check_logs: &mut String, run: &ReviewCheckRun) {
+    let _ = writeln!(check_logs, "Job: {}", run.name);
+    if let Some(url) = &run.url {
+        let _ = writeln!(check_logs, "URL: {url}");
+    }
+    match run.log_excerpt.as_deref().map(str::trim_end) {
+        Some(log_excerpt) if !log_excerpt.is_empty() => {
+            let _ = writeln!(check_logs, "Log (last lines):\n{log_excerpt}");
+        }
+        _ => {
+            let _ = writeln!(
+                check_logs,
+                "Log: unavailable; inspect the job configuration for this check"
+            );
+        }
+    }
+    check_logs.push('\n');
+}
+
 /// Returns the actionable inline threads selected for a turn.
 fn selected_review_comment_threads<'a>(
     snapshot: &'a ReviewCommentSnapshot,
@@ -939,6 +1067,71 @@ mod tests {
         assert_eq!(prompt.text_source, TurnPromptTextSource::UserPrompt);
     }
 
+    #[test]
+    fn test_build_fix_review_checks_prompt_includes_failed_job_logs() {
+        // Arrange
+        let report = ReviewCheckReport {
+            head_sha: "abc123".to_string(),
+            runs: vec![
+                ReviewCheckRun {
+                    conclusion: Some(ag_forge::ReviewCheckConclusion::Success),
+                    log_excerpt: None,
+                    log_id: None,
+                    name: "lint".to_string(),
+                    status: ag_forge::ReviewCheckStatus::Completed,
+                    url: None,
+                },
+                ReviewCheckRun {
+                    conclusion: Some(ag_forge::ReviewCheckConclusion::Failure),
+                    log_excerpt: Some("error[E0308]: mismatched types".to_string()),
+                    log_id: Some("7".to_string()),
+                    name: "test".to_string(),
+                    status: ag_forge::ReviewCheckStatus::Completed,
+                    url: Some("https://ci.example/jobs/7".to_string()),
+                },
+            ],
+        };
+
+        // Act
+        let prompt =
+            build_fix_review_checks_prompt(&report).expect("failed runs should build a prompt");
+
+        // Assert
+        assert!(
+            prompt
+                .text
+                .starts_with("CI checks failed on the published branch")
+        );
+        assert!(prompt.text.contains(
+            "```text\nHead commit: abc123\n\nJob: test\nURL: https://ci.example/jobs/7\nLog (last \
+             lines):\nerror[E0308]: mismatched types\n```"
+        ));
+        assert!(!prompt.text.contains("Job: lint"));
+        assert_eq!(prompt.text_source, TurnPromptTextSource::AgentData);
+    }
+
+    #[test]
+    fn test_build_fix_review_checks_prompt_skips_reports_without_failures() {
+        // Arrange
+        let report = ReviewCheckReport {
+            head_sha: "abc123".to_string(),
+            runs: vec![ReviewCheckRun {
+                conclusion: None,
+                log_excerpt: None,
+                log_id: None,
+                name: "test".to_string(),
+                status: ag_forge::ReviewCheckStatus::Queued,
+                url: None,
+            }],
+        };
+
+        // Act
+        let prompt = build_fix_review_checks_prompt(&report);
+
+        // Assert
+        assert_eq!(prompt, None);
+    }
+
     /// Ensures `/apply` widens the suggestions fence when review text already
     /// contains a Markdown code fence.
     #[test]
@@ -1084,6 +1277,24 @@ mod tests {
         assert!(empty.is_none());
     }
 
+    /// Ensures imported review requests start on every actionable thread.
+    #[test]
+    fn test_build_unresolved_review_comment_prompt_selects_all_actionable_threads() {
+        // Arrange
+        let snapshot = review_comment_snapshot();
+
+        // Act
+        let (prompt, thread_ids) = build_unresolved_review_comment_prompt(&snapshot)
+            .expect("snapshot should contain actionable comments");
+
+        // Assert
+        assert_eq!(
+            thread_ids,
+            vec!["thread-current".to_string(), "thread-outdated".to_string()]
+        );
+        assert!(!prompt.text.contains("thread-resolved"));
+    }
+
     /// Ensures review data containing a Markdown fence is wrapped in a wider
     /// fence before it reaches the agent.
     #[test]
@@ -1,5 +1,17 @@
 //! Shared review-request helpers used by app workflows.
 
+/// Repository-relative review-request description templates, checked in
+/// order when publishing a new review request.
+pub(crate) const REVIEW_REQUEST_TEMPLATE_PATHS: &[&str] = &[
+    ".github/pull_request_template.md",
+    ".github/PULL_REQUEST_TEMPLATE.md",
+    "PULL_REQUEST_TEMPLATE.md",
+    "docs/pull_request_template.md",
+    ".gitlab/merge_request_templates/Default.md",
+    ".gitea/pull_request_template.md",
+    ".forgejo/pull_request_template.md",
+];
+
 /// Parsed commit-message metadata used to populate a new review request.
 #[derive(Debug, Eq, PartialEq)]
 pub(crate) struct ReviewRequestCommitMessage {
@@ -27,6 +39,23 @@ pub(crate) fn parse_review_request_commit_message(
     })
 }
 
+/// Appends one repository review-request `template` after the commit-message
+/// body so authors fill in the checklist their repository expects.
+pub(crate) fn review_request_body_with_template(
+    body: Option<String>,
+    template: Option<&str>,
+) -> Option<String> {
+    let template = template
+        .map(str::trim)
+        .filter(|template| !template.is_empty());
+
+    match (body, template) {
+        (Some(body), Some(template)) => Some(format!("{body}\n\n{template}")),
+        (None, Some(template)) => Some(template.to_string()),
+        (body, None) => body,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,4 +112,34 @@ mod tests {
         // Assert
         assert_eq!(parsed_commit_message, None);
     }
+
+    /// Verifies the repository template follows the commit body.
+    #[test]
+    fn review_request_body_with_template_appends_template_after_body() {
+        // Arrange
+        let body = Some("- Keep title in sync".to_string());
+        let template = Some("## Checklist\n- [ ] Tests\n");
+
+        // Act
+        let combined_body = review_request_body_with_template(body, template);
+
+        // Assert
+        assert_eq!(
+            combined_body,
+            Some("- Keep title in sync\n\n## Checklist\n- [ ] Tests".to_string())
+        );
+    }
+
+    /// Verifies a blank template leaves the commit body unchanged.
+    #[test]
+    fn review_request_body_with_template_ignores_blank_template() {
+        // Arrange
+        let template = Some("  \n");
+
+        // Act
+        let combined_body = review_request_body_with_template(None, template);
+
+        // Assert
+        assert_eq!(combined_body, None);
+    }
 }
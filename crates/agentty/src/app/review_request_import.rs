@@ -0,0 +1,233 @@
+//! Session creation from an existing forge review request.
+
+use std::path::PathBuf;
+
+use ag_forge::{ForgeRemote, ReviewRequestState, ReviewRequestSummary};
+
+use crate::app::prompt_intent::build_unresolved_review_comment_prompt;
+use crate::app::session::{SessionCreationKind, SessionCreationSettings};
+use crate::app::{App, AppError, task};
+use crate::domain::transcript_notice::TranscriptNotice;
+
+/// Remote name used for review-request head fetches and adopted upstreams.
+const ORIGIN_REMOTE: &str = "origin";
+
+impl App {
+    /// Creates one session on the head commit of an existing review request,
+    /// links the request, and starts the agent on its unresolved threads.
+    ///
+    /// `reference` accepts a review-request number, display id, or browser URL
+    /// for the active project repository. The fetched head commit becomes the
+    /// session base branch so auto-commits stack on top of the author's
+    /// commits instead of amending them; it also leaves no local branch to
+    /// merge into or sync from, so merge and sync reject imported sessions.
+    /// When the head branch lives on `origin`, the session adopts it as its
+    /// published upstream so each turn pushes back to the review request.
+    ///
+    /// # Errors
+    /// Returns an error when the reference does not belong to the project
+    /// repository, the forge lookup fails, the review request is no longer
+    /// open, or its head commit cannot be fetched into a new worktree.
+    pub(super) async fn create_review_request_import_session(
+        &mut self,
+        project_id: i64,
+        creation_settings: Option<SessionCreationSettings>,
+        reference: &str,
+    ) -> Result<String, AppError> {
+        let working_dir = self.projects.working_dir().to_path_buf();
+        let git_client = self.services.git_client();
+        let review_request_client = self.services.review_request_client();
+        let remote = task::review_request_remote(
+            working_dir.clone(),
+            git_client.as_ref(),
+            review_request_client.as_ref(),
+        )
+        .await
+        .map_err(AppError::Workflow)?;
+        let review_request_name = remote.forge_kind.review_request_name();
+        let number = remote.review_request_number(reference).ok_or_else(|| {
+            AppError::Workflow(format!(
+                "`{}` is not a {review_request_name} number or URL for {}",
+                reference.trim(),
+                remote.web_url
+            ))
+        })?;
+        let display_id = remote.forge_kind.review_request_display_id(number);
+        let summary = review_request_client
+            .refresh_review_request(remote.clone(), display_id.clone())
+            .await
+            .map_err(|error| AppError::Workflow(error.detail_message()))?;
+        if summary.state != ReviewRequestState::Open {
+            return Err(AppError::Workflow(format!(
+                "Only open review requests can be imported; {display_id} is {}",
+                summary.state.as_str().to_lowercase()
+            )));
+        }
+
+        let repo_root = git_client
+            .find_git_repo_root(working_dir.clone())
+            .await
+            .ok_or_else(|| AppError::Workflow("Failed to find git repository root".to_string()))?;
+        let head_hash = git_client
+            .fetch_remote_ref(
+                repo_root.clone(),
+                remote.forge_kind.review_request_head_ref(number),
+            )
+            .await?;
+        let session_id = self
+            .sessions
+            .create_session_for_project(
+                &self.services,
+                project_id,
+                &head_hash,
+                working_dir,
+                creation_settings,
+                SessionCreationKind::Worker,
+            )
+            .await?;
+        self.finish_api_session_creation(&session_id).await;
+
+        let upstream_reference = self
+            .origin_head_branch_upstream(repo_root, &summary, &head_hash)
+            .await;
+        self.link_imported_review_request(&session_id, summary, upstream_reference)
+            .await;
+        self.start_imported_review_request_session(&session_id, remote, display_id)
+            .await;
+
+        Ok(session_id)
+    }
+
+    /// Returns the `origin/<branch>` upstream for the review-request head
+    /// branch when `origin` hosts it at the imported head commit.
+    ///
+    /// Requests opened from forks return `None` so session pushes never land
+    /// on an unrelated `origin` branch that happens to share the head name.
+    async fn origin_head_branch_upstream(
+        &self,
+        repo_root: PathBuf,
+        summary: &ReviewRequestSummary,
+        head_hash: &str,
+    ) -> Option<String> {
+        let origin_head_hash = self
+            .services
+            .git_client()
+            .fetch_remote_ref(repo_root, format!("refs/heads/{}", summary.source_branch))
+            .await
+            .ok()?;
+
+        (origin_head_hash == head_hash)
+            .then(|| format!("{ORIGIN_REMOTE}/{}", summary.source_branch))
+    }
+
+    /// Persists the review-request link and optional pushed upstream for one
+    /// imported session, reporting failures in its transcript.
+    async fn link_imported_review_request(
+        &mut self,
+        session_id: &str,
+        summary: ReviewRequestSummary,
+        upstream_reference: Option<String>,
+    ) {
+        let display_id = summary.display_id.clone();
+        let source_branch = summary.source_branch.clone();
+        if let Err(error) = self
+            .sessions
+            .store_review_request_summary(&self.services, session_id, summary)
+            .await
+        {
+            self.append_output_for_session(
+                session_id,
+                &TranscriptNotice::ReviewRequestSyncWarning
+                    .format(format!("Failed to link {display_id}: {error}")),
+            )
+            .await;
+
+            return;
+        }
+
+        let Some(upstream_reference) = upstream_reference else {
+            self.append_output_for_session(
+                session_id,
+                &TranscriptNotice::ReviewRequest.format(format!(
+                    "Imported {display_id}. Its head branch `{source_branch}` is not on \
+                     `{ORIGIN_REMOTE}`, so session changes stay local until you publish them."
+                )),
+            )
+            .await;
+
+            return;
+        };
+        if let Err(error) = self
+            .services
+            .db()
+            .sessions()
+            .update_session_published_upstream_ref(session_id, Some(upstream_reference.clone()))
+            .await
+        {
+            self.append_output_for_session(
+                session_id,
+                &TranscriptNotice::ReviewRequestSyncWarning
+                    .format(format!("Failed to track `{upstream_reference}`: {error}")),
+            )
+            .await;
+
+            return;
+        }
+        self.sessions
+            .apply_published_upstream_ref(session_id, upstream_reference.clone());
+        self.append_output_for_session(
+            session_id,
+            &TranscriptNotice::ReviewRequest.format(format!(
+                "Imported {display_id}. Session changes push to `{upstream_reference}`."
+            )),
+        )
+        .await;
+    }
+
+    /// Loads the imported request's review threads and starts the first turn
+    /// on the unresolved ones.
+    ///
+    /// Without unresolved threads the session stays idle so users can write
+    /// the first prompt themselves.
+    async fn start_imported_review_request_session(
+        &mut self,
+        session_id: &str,
+        remote: ForgeRemote,
+        display_id: String,
+    ) {
+        let review_request_client = self.services.review_request_client();
+        let snapshot = match task::load_review_comment_snapshot(
+            remote,
+            display_id.clone(),
+            review_request_client.as_ref(),
+        )
+        .await
+        {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                self.append_output_for_session(
+                    session_id,
+                    &TranscriptNotice::ReviewCommentsWarning
+                        .format(format!("Failed to load review comments: {error}")),
+                )
+                .await;
+
+                return;
+            }
+        };
+        let Some((prompt, thread_ids)) = build_unresolved_review_comment_prompt(&snapshot) else {
+            self.append_output_for_session(
+                session_id,
+                &TranscriptNotice::ReviewComments
+                    .format(format!("{display_id} has no unresolved review threads.")),
+            )
+            .await;
+
+            return;
+        };
+
+        self.sessions
+            .reply_to_review_comments(&self.services, session_id, prompt, thread_ids)
+            .await;
+    }
+}
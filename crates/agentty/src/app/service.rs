@@ -314,6 +314,7 @@ fn app_event_label(event: &AppEvent) -> &'static str {
         AppEvent::GitStatusUpdated { .. } => "GitStatusUpdated",
         AppEvent::VersionAvailabilityUpdated { .. } => "VersionAvailabilityUpdated",
         AppEvent::AgentCliVersionsUpdated { .. } => "AgentCliVersionsUpdated",
+        AppEvent::CustomThemesUpdated { .. } => "CustomThemesUpdated",
         AppEvent::UpdateStatusChanged { .. } => "UpdateStatusChanged",
         AppEvent::SessionModelUpdated { .. } => "SessionModelUpdated",
         AppEvent::SessionPersonalityUpdated { .. } => "SessionPersonalityUpdated",
@@ -324,6 +325,7 @@ fn app_event_label(event: &AppEvent) -> &'static str {
         AppEvent::RefreshProjects => "RefreshProjects",
         AppEvent::RefreshGitStatus => "RefreshGitStatus",
         AppEvent::SessionReviewCommentSnapshotLoaded { .. } => "SessionReviewCommentSnapshotLoaded",
+        AppEvent::ReviewRequestForgeLoaded(_) => "ReviewRequestForgeLoaded",
         AppEvent::SessionProgressUpdated { .. } => "SessionProgressUpdated",
         AppEvent::SyncMainCompleted { .. } => "SyncMainCompleted",
         AppEvent::SyncMainConflictResolutionStarted { .. } => "SyncMainConflictResolutionStarted",
@@ -343,6 +345,8 @@ fn app_event_label(event: &AppEvent) -> &'static str {
         AppEvent::StackedParentSyncCompleted { .. } => "StackedParentSyncCompleted",
         AppEvent::StackedParentMergeCompleted { .. } => "StackedParentMergeCompleted",
         AppEvent::SessionWorkflowNoticeUpdated { .. } => "SessionWorkflowNoticeUpdated",
+        AppEvent::MergeGateFixTurnRequested { .. } => "MergeGateFixTurnRequested",
+        AppEvent::PostTurnChecksCompleted { .. } => "PostTurnChecksCompleted",
         AppEvent::SessionOrchestrationProgressUpdated { .. } => {
             "SessionOrchestrationProgressUpdated"
         }
@@ -22,4 +22,6 @@ pub use state::SessionState;
 pub(crate) use workflow::load::{
     SessionLoadInput, migrate_active_sessions_off_retired_models, migrate_session_off_retired_model,
 };
+pub(crate) use workflow::merge::IMPORTED_REVIEW_REQUEST_MERGE_ERROR;
+pub(crate) use workflow::post_turn_checks::PostTurnCheckRun;
 pub(crate) use workflow::refresh::SyncReviewRequestOutcome;
@@ -179,6 +179,7 @@ pub struct SessionManager {
 /// Live bookkeeping shared by session lifecycle workflows.
 pub(super) struct SessionWorkflowState {
     pub(super) pending_history_replay: HashSet<SessionId>,
+    pub(super) pending_revert_notes: HashMap<SessionId, Vec<String>>,
     pub(super) published_branch_sync_operations: HashMap<SessionId, String>,
     pub(super) title_generation_tasks: HashMap<SessionId, TitleGenerationTask>,
 }
@@ -220,6 +221,7 @@ impl SessionManager {
             stats_activity,
             workflow_state: SessionWorkflowState {
                 pending_history_replay,
+                pending_revert_notes: HashMap::new(),
                 published_branch_sync_operations: HashMap::new(),
                 title_generation_tasks: HashMap::new(),
             },
@@ -404,6 +406,62 @@ impl SessionManager {
         self.state.sync_session_from_handle(session_id);
     }
 
+    /// Answers the oldest approval request blocking one session's turn and
+    /// refreshes that session snapshot so the overlay advances immediately.
+    pub(crate) fn resolve_session_tool_approval(
+        &mut self,
+        session_id: &str,
+        decision: crate::domain::permission::ToolApprovalDecision,
+    ) -> bool {
+        let was_resolved = self
+            .state
+            .handles()
+            .get(session_id)
+            .is_some_and(|handles| handles.resolve_tool_approval(decision));
+        self.state.sync_session_from_handle(session_id);
+
+        was_resolved
+    }
+
+    /// Returns the latest post-turn check result recorded for one session.
+    pub(crate) fn post_turn_check_report(
+        &self,
+        session_id: &str,
+    ) -> Option<crate::domain::session::PostTurnCheckReport> {
+        self.state
+            .handles()
+            .get(session_id)
+            .and_then(crate::domain::session::SessionHandles::post_turn_check_report)
+    }
+
+    /// Records one post-turn check result and refreshes that session snapshot
+    /// so the header status row updates immediately.
+    pub(crate) fn record_post_turn_check_report(
+        &mut self,
+        session_id: &str,
+        report: crate::domain::session::PostTurnCheckReport,
+    ) {
+        if let Some(handles) = self.state.handles().get(session_id) {
+            handles.set_post_turn_check_report(report);
+        }
+        self.state.sync_session_from_handle(session_id);
+    }
+
+    /// Restores the automatic post-turn check fix budget for one session.
+    pub(crate) fn reset_post_turn_check_fix_attempts(&mut self, session_id: &str) {
+        if let Some(handles) = self.state.handles().get(session_id) {
+            handles.reset_post_turn_check_fix_attempts();
+        }
+        self.state.sync_session_from_handle(session_id);
+    }
+
+    /// Flips whether one session's transcript activity logs list every entry.
+    pub(crate) fn toggle_session_activity_log(&mut self, session_id: &str) {
+        if let Some(session) = self.state.session_mut_for_id(session_id) {
+            session.activity_log_expanded = !session.activity_log_expanded;
+        }
+    }
+
     /// Applies recomputed diff metadata to one loaded session.
     pub(crate) fn apply_session_diff_stats_updated(
         &mut self,
@@ -641,6 +699,16 @@ impl SessionManager {
             .resolve_queued_action(session_id, TransientMessageSlot::SyncQueue);
     }
 
+    /// Stores the CI checks refreshed for one linked review request, clearing
+    /// them when the review request is no longer open.
+    pub(crate) fn store_review_checks(
+        &mut self,
+        session_id: &str,
+        report: Option<ag_forge::ReviewCheckReport>,
+    ) {
+        self.state.replace_review_checks(session_id, report);
+    }
+
     /// Replaces manual branch-publish progress with its inline final result.
     ///
     /// When the owning project is not loaded, appends the result to the live
@@ -50,7 +50,8 @@ use crate::infra::clock::{Clock, RealClock};
 use crate::infra::db::AppRepositories;
 use crate::infra::fs::{self as fs, FsClient};
 use crate::presentation::app_mode::{
-    AppMode, DiffFocus, DiffLineComments, DiffPreview, HelpContext, ReviewCommentSelection,
+    AppMode, DiffFocus, DiffLayoutMode, DiffLineComments, DiffPreview, HelpContext,
+    ReviewCommentSelection,
 };
 
 /// Builds one loading focused-review entry with a stable test profile.
@@ -184,6 +185,14 @@ fn expect_pre_commit_hook_ready(mock: &mut git::MockGitClient) {
         .returning(|_| Box::pin(async { Ok(()) }));
 }
 
+/// Expects one post-turn checkpoint for the given one-based turn.
+fn expect_turn_checkpoint(mock: &mut git::MockGitClient, expected_turn: u32) {
+    mock.expect_record_turn_checkpoint()
+        .once()
+        .withf(move |_, _, turn| *turn == expected_turn)
+        .returning(|_, _, _| Box::pin(async { Ok("abc1234".to_string()) }));
+}
+
 fn allow_detect_git_info_with_head_hash(mock: &mut git::MockGitClient, allow_head_hash: bool) {
     mock.expect_detect_git_info().times(0..).returning(|path| {
         let branch_name = path
@@ -277,6 +286,9 @@ fn create_mock_git_client_for_successful_noop_merges(
                 Ok(())
             })
         });
+    mock.expect_delete_turn_checkpoints()
+        .times(expected_merge_count)
+        .returning(|_, _| Box::pin(async { Ok(()) }));
     mock.expect_delete_branch()
         .times(expected_merge_count)
         .returning(|_, _| Box::pin(async { Ok(()) }));
@@ -555,6 +567,12 @@ fn setup_mock_commit_and_branch_expectations(mock: &mut git::MockGitClient) {
     mock.expect_delete_branch()
         .times(0..)
         .returning(|_, _| Box::pin(async { Ok(()) }));
+    mock.expect_record_turn_checkpoint()
+        .times(0..)
+        .returning(|_, _, _| Box::pin(async { Ok("checkpoint-tip".to_string()) }));
+    mock.expect_delete_turn_checkpoints()
+        .times(0..)
+        .returning(|_, _| Box::pin(async { Ok(()) }));
     mock.expect_diff().times(0..).returning(|folder, _| {
         Box::pin(async move { Ok(synthetic_diff_from_session_folder(&folder).await) })
     });
@@ -748,6 +766,7 @@ fn add_manual_session_with_status(
         reasoning_level_override: None,
         published_upstream_ref: None,
         questions: Vec::new(),
+        review_checks: None,
         review_request: None,
         size: SessionSize::Xs,
         speed_mode: crate::domain::agent::SpeedMode::default(),
@@ -758,6 +777,9 @@ fn add_manual_session_with_status(
         transcript: None,
         updated_at: 0,
         transient_messages: TransientMessageStore::default(),
+        pending_tool_approval: None,
+        post_turn_checks: None,
+        activity_log_expanded: false,
     });
     if app.sessions.selected_session_index().is_none() {
         app.sessions.select_session_index(Some(0));
@@ -813,6 +835,7 @@ fn test_session_manager_with_clock(
             reasoning_level_override,
             published_upstream_ref: None,
             questions: Vec::new(),
+            review_checks: None,
             review_request: None,
             size: SessionSize::Xs,
             speed_mode: crate::domain::agent::SpeedMode::default(),
@@ -823,6 +846,9 @@ fn test_session_manager_with_clock(
             transcript: None,
             updated_at: 0,
             transient_messages: TransientMessageStore::default(),
+            pending_tool_approval: None,
+            post_turn_checks: None,
+            activity_log_expanded: false,
         }],
         crate::domain::selection::SelectionState::default(),
         clock,
@@ -3977,6 +4003,7 @@ async fn test_refresh_sessions_loads_diff_help_detail_when_another_session_is_se
             diff: String::new(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -4812,6 +4839,7 @@ async fn test_spawn_session_task_auto_commits_changes() {
         .expect_head_short_hash()
         .times(1)
         .returning(|_| Box::pin(async { Ok("abc1234".to_string()) }));
+    expect_turn_checkpoint(&mut mock_git_client, 1);
     expect_pre_commit_hook_ready(&mut mock_git_client);
     mock_git_client
         .expect_diff()
@@ -5026,6 +5054,7 @@ async fn test_spawn_session_task_skips_commit_when_nothing_to_commit() {
         .expect_get_ref_ahead_behind()
         .times(0..)
         .returning(|_, _, _| Box::pin(async { Ok((0, 0)) }));
+    expect_turn_checkpoint(&mut mock_git_client, 1);
     install_mock_git_client(&mut app, mock_git_client);
 
     // Agent that produces no file changes
@@ -6527,6 +6556,11 @@ async fn test_cleanup_merged_session_worktree_without_repo_hint() {
                 Ok(())
             })
         });
+    mock_git_client
+        .expect_delete_turn_checkpoints()
+        .times(1)
+        .withf(|_, session_id| session_id == "cleanup123")
+        .returning(|_, _| Box::pin(async { Ok(()) }));
     mock_git_client
         .expect_delete_branch()
         .times(1)
@@ -6535,6 +6569,7 @@ async fn test_cleanup_merged_session_worktree_without_repo_hint() {
 
     // Act
     let result = SessionManager::cleanup_merged_session_worktree(
+        "cleanup123",
         worktree_folder.clone(),
         Arc::new(create_passthrough_mock_fs_client()),
         Arc::new(mock_git_client),
@@ -42,6 +42,15 @@ pub enum SessionError {
     #[error("{0}")]
     Workflow(String),
 
+    /// A project merge-gate command failed in the rebased session worktree.
+    #[error("Merge gate command `{command}` failed:\n{output}")]
+    MergeGateFailed {
+        /// Gate command that exited unsuccessfully.
+        command: String,
+        /// Trailing combined stdout and stderr captured from the command.
+        output: String,
+    },
+
     /// The user explicitly stopped the active turn before the agent finished.
     #[error("{0}")]
     StoppedByUser(String),
@@ -2,6 +2,8 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::{Instant, SystemTime};
 
+use ag_forge::ReviewCheckReport;
+
 use crate::app::session::{Clock, SESSION_REFRESH_INTERVAL};
 use crate::domain::selection::SelectionState;
 use crate::domain::session::{Session, SessionDiffStats, SessionHandles, SessionId, Status};
@@ -180,7 +182,8 @@ impl SessionState {
     /// Database refreshes can observe intermediate workflow persistence, such
     /// as a published upstream branch before its review-request URL is ready.
     /// Carrying transient output across that refresh keeps active loaders
-    /// visible until their owning reducer resolves them.
+    /// visible until their owning reducer resolves them. The view-only
+    /// activity-log expansion toggle is carried for the same reason.
     pub(crate) fn replace_sessions(&mut self, mut sessions: Vec<Session>) {
         let transient_state_by_session_id: HashMap<SessionId, _> = self
             .sessions
@@ -188,15 +191,20 @@ impl SessionState {
             .map(|session| {
                 (
                     session.id.clone(),
-                    (session.status, session.transient_messages.clone()),
+                    (
+                        session.status,
+                        session.transient_messages.clone(),
+                        session.activity_log_expanded,
+                    ),
                 )
             })
             .collect();
         for session in &mut sessions {
-            if let Some((previous_status, transient_messages)) =
+            if let Some((previous_status, transient_messages, activity_log_expanded)) =
                 transient_state_by_session_id.get(&session.id)
             {
                 session.transient_messages.clone_from(transient_messages);
+                session.activity_log_expanded = *activity_log_expanded;
                 session.reconcile_status_transition(*previous_status);
             }
         }
@@ -269,6 +277,22 @@ impl SessionState {
         }
     }
 
+    /// Mirrors the latest linked review-request CI checks into live handles
+    /// and the active render snapshot when that project is currently loaded.
+    pub(crate) fn replace_review_checks(
+        &mut self,
+        session_id: &str,
+        report: Option<ReviewCheckReport>,
+    ) {
+        if let Some(session_handles) = self.runtime.handle(session_id) {
+            session_handles.set_review_check_report(report.clone());
+        }
+
+        if let Some(session) = self.session_mut_for_id(session_id) {
+            session.review_checks = report;
+        }
+    }
+
     /// Copies current values from runtime handles into plain `Session` fields.
     ///
     /// The runtime uses targeted `sync_session_from_handle()` calls for
@@ -474,6 +498,9 @@ impl SessionState {
         }
 
         session.queued_messages = session_handles.queued_message_snapshot();
+        session.pending_tool_approval = session_handles.pending_tool_approval();
+        session.post_turn_checks = session_handles.post_turn_check_report();
+        session.review_checks = session_handles.review_check_report();
         for queued_action in session_handles.queued_action_snapshot() {
             session.transient_messages.upsert(queued_action);
         }
@@ -14,10 +14,15 @@ pub(super) mod isolation;
 pub(super) mod lifecycle;
 pub(super) mod load;
 pub(super) mod merge;
+pub(super) mod merge_gate;
 pub(super) mod post_turn;
+pub(super) mod post_turn_checks;
+pub(super) mod project_commands;
 pub(super) mod published_branch;
 pub(super) mod refresh;
+pub(super) mod revert;
 pub(super) mod review;
+pub(super) mod rewind;
 pub(super) mod task;
 pub(super) mod turn;
 pub(super) mod worker;
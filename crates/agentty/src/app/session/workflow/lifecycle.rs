@@ -2105,6 +2105,14 @@ impl SessionManager {
             None
         };
 
+        if cleanup.has_git_branch {
+            Self::delete_session_turn_checkpoints(
+                git_client.as_ref(),
+                repo_root.clone(),
+                &cleanup.session_id,
+            )
+            .await;
+        }
         let cleanup_errors = Self::cleanup_session_worktree_resources(
             fs_client.clone(),
             git_client,
@@ -2261,11 +2269,13 @@ impl SessionManager {
             .ok()
             .and_then(|session| session.published_upstream_ref.clone());
         let idempotent = operation_id.is_some();
+        let (agent_prompt, revert_notes) =
+            self.take_pending_revert_notes(&persisted_session_id, &prompt);
 
         let command = Self::build_session_command(BuildSessionCommandInput {
             is_first_message,
             operation_id,
-            prompt: prompt.clone(),
+            prompt: agent_prompt,
             published_upstream_ref,
             replay_transcript,
             review_comment_thread_ids,
@@ -2285,6 +2295,9 @@ impl SessionManager {
                 },
             )
             .await;
+        if enqueued == ReplyEnqueueOutcome::Failed {
+            self.restore_pending_revert_notes(&persisted_session_id, revert_notes);
+        }
         if enqueued == ReplyEnqueueOutcome::Enqueued
             && defer_prompt_until_enqueued
             && persist_prompt
@@ -2364,9 +2377,9 @@ impl SessionManager {
             title_to_save = Some(title);
         }
 
+        let transport = agent::transport_mode(session.agent.kind());
         let replay_transcript = if !is_first_message
-            && (should_replay_history
-                || agent::transport_mode(session.agent.kind()).uses_app_server())
+            && (should_replay_history || transport.uses_app_server() || transport.runs_in_process())
         {
             session
                 .transcript
@@ -3453,6 +3466,12 @@ impl SessionManager {
         let cleanup_task_handle = tokio::spawn(async move {
             if has_worktree {
                 let repo_root = git_client.main_repo_root(folder.clone()).await.ok();
+                Self::delete_session_turn_checkpoints(
+                    git_client.as_ref(),
+                    repo_root.clone(),
+                    &session_id,
+                )
+                .await;
                 let cleanup_errors = Self::cleanup_session_worktree_resources(
                     Arc::clone(&fs_client),
                     Arc::clone(&git_client),
@@ -3544,6 +3563,31 @@ impl SessionManager {
         cleanup_errors
     }
 
+    /// Deletes the hidden per-turn checkpoint refs of one removed session.
+    ///
+    /// Checkpoint refs live in the shared repository, so they outlive the
+    /// worktree unless removed explicitly. Failures are only logged.
+    pub(super) async fn delete_session_turn_checkpoints(
+        git_client: &dyn git::GitClient,
+        repo_root: Option<PathBuf>,
+        session_id: &str,
+    ) {
+        let Some(repo_root) = repo_root else {
+            return;
+        };
+
+        if let Err(error) = git_client
+            .delete_turn_checkpoints(repo_root, session_id.to_string())
+            .await
+        {
+            warn!(
+                session_id = session_id,
+                error = %error,
+                "failed to delete session turn checkpoints"
+            );
+        }
+    }
+
     /// Emits debug-visible warnings for best-effort cleanup failures.
     fn warn_cleanup_errors(session_id: &str, cleanup_errors: &[String]) {
         for cleanup_error in cleanup_errors {
@@ -501,6 +501,7 @@ impl SessionManager {
             reasoning_level_override: input.reasoning_level_override,
             published_upstream_ref: input.row.published_upstream_ref,
             questions: input.session_questions,
+            review_checks: None,
             review_request: input.review_request,
             role: input.role,
             size: input.size,
@@ -522,6 +523,9 @@ impl SessionManager {
             transcript: input.session_transcript,
             updated_at: input.row.updated_at,
             transient_messages: TransientMessageStore::default(),
+            pending_tool_approval: None,
+            post_turn_checks: None,
+            activity_log_expanded: false,
         };
         for queued_action in input.session_queued_actions {
             session.transient_messages.upsert(queued_action);
@@ -628,7 +632,7 @@ fn insert_loaded_session_handle(
 }
 
 /// Loads ordered session messages into the render transcript snapshot.
-async fn load_session_transcript(
+pub(super) async fn load_session_transcript(
     db: &AppRepositories,
     session_id: &str,
 ) -> Result<SessionTranscript, DbError> {
@@ -15,6 +15,7 @@ use askama::Template;
 use tokio::sync::{OwnedMutexGuard, mpsc};
 use tracing::warn;
 
+use super::merge_gate::{self, MergeGateSettings};
 use super::published_branch::{self, PublishedBranchAutoPushInput};
 use super::worker::{SessionCommand, has_unfinished_rebase_operation};
 use super::{SessionTaskService, StatusTransition, session_branch};
@@ -26,12 +27,22 @@ use crate::app::service::SessionUpdateVersionMap;
 use crate::app::session::{Clock, SessionError};
 use crate::app::{AppEvent, AppServices, ProjectManager, SessionManager};
 use crate::domain::agent::{AgentKind, AgentModel, AgentSelection, ReasoningLevel};
-use crate::domain::session::{PublishedBranchSyncStatus, SessionId, Status};
+use crate::domain::session::{PublishedBranchSyncStatus, Session, SessionId, Status};
 use crate::domain::session_message::SessionTranscript;
 use crate::domain::transcript_notice::TranscriptNotice;
 use crate::infra::db::{AppRepositories, DbError};
 use crate::infra::fs::{self as fs, FsClient};
 
+/// Merge rejection shown for sessions imported from a review request.
+pub(crate) const IMPORTED_REVIEW_REQUEST_MERGE_ERROR: &str = "Imported review-request sessions \
+                                                              cannot merge locally; changes land \
+                                                              through the review request";
+
+/// Sync rejection shown for sessions imported from a review request.
+const IMPORTED_REVIEW_REQUEST_SYNC_ERROR: &str = "Imported review-request sessions cannot sync; \
+                                                  they are based on the imported head commit, not \
+                                                  a local branch";
+
 const REBASE_ASSIST_POLICY: AssistPolicy = AssistPolicy {
     max_attempts: 3,
     // Allow up to 3 consecutive identical-content observations before
@@ -628,19 +639,16 @@ impl SyncSessionStartError {
 }
 
 impl SessionMergeService {
-    /// Starts a squash merge for a review-ready or queued session branch in
-    /// the background.
+    /// Loads one session and validates that it may start a squash merge.
     ///
     /// # Errors
-    /// Returns an error if the session is invalid for merge, required git
-    /// metadata is missing, or the status transition to `Merging` fails.
-    async fn merge_session(
-        &self,
-        manager: &SessionManager,
+    /// Returns an error when the session is missing, owns no branch changes,
+    /// is not review-ready or queued, was imported from a review request, or
+    /// is blocked by its stack.
+    fn load_merge_start_session<'a>(
+        manager: &'a SessionManager,
         session_id: &str,
-        projects: &ProjectManager,
-        services: &AppServices,
-    ) -> Result<(), SessionError> {
+    ) -> Result<&'a Session, SessionError> {
         let session = manager
             .session_or_err(session_id)
             .map_err(|_| SessionError::NotFound)?;
@@ -654,6 +662,11 @@ impl SessionMergeService {
                 "Session must be in review or queued status".to_string(),
             ));
         }
+        if session.is_review_request_import() {
+            return Err(SessionError::Workflow(
+                IMPORTED_REVIEW_REQUEST_MERGE_ERROR.to_string(),
+            ));
+        }
         if !manager.can_merge_session_branch_in_stack(session_id) {
             return Err(SessionError::Workflow(
                 "Merge cannot run for linked review requests or while another stack session is \
@@ -662,6 +675,23 @@ impl SessionMergeService {
             ));
         }
 
+        Ok(session)
+    }
+
+    /// Starts a squash merge for a review-ready or queued session branch in
+    /// the background.
+    ///
+    /// # Errors
+    /// Returns an error if the session is invalid for merge, required git
+    /// metadata is missing, or the status transition to `Merging` fails.
+    async fn merge_session(
+        &self,
+        manager: &SessionManager,
+        session_id: &str,
+        projects: &ProjectManager,
+        services: &AppServices,
+    ) -> Result<(), SessionError> {
+        let session = Self::load_merge_start_session(manager, session_id)?;
         let (archive_diff, db, folder, id, session_agent) = (
             session.is_managed(),
             services.db().clone(),
@@ -1098,6 +1128,11 @@ impl SessionMergeService {
                 "Session must be in review status or in progress".to_string(),
             ));
         }
+        if session.is_review_request_import() {
+            return Err(SessionError::Workflow(
+                IMPORTED_REVIEW_REQUEST_SYNC_ERROR.to_string(),
+            ));
+        }
         if !manager.can_rebase_session_branch_in_stack(session_id) {
             return Err(SessionError::Workflow(
                 "Stacked sync can only run when no other stack session is active".to_string(),
@@ -1168,9 +1203,10 @@ impl SessionManager {
     /// Executes the merge workflow for one session branch.
     ///
     /// # Errors
-    /// Returns an error when the rebase step fails, the canonical session
-    /// commit message cannot be loaded, squash-merge git commands fail, status
-    /// transitions are invalid, or worktree cleanup fails.
+    /// Returns an error when the rebase step fails, a merge-gate command
+    /// fails, the canonical session commit message cannot be loaded,
+    /// squash-merge git commands fail, status transitions are invalid, or
+    /// worktree cleanup fails.
     async fn execute_merge_workflow(input: MergeTaskInput) -> Result<String, SessionError> {
         let rebase_input = Self::merge_rebase_input(&input);
         let MergeTaskInput {
@@ -1198,6 +1234,9 @@ impl SessionManager {
                 "Merge failed during rebase step: {error}"
             )));
         }
+        // Verify the rebased tree before it reaches the base branch so two
+        // individually green sessions cannot combine into a broken target.
+        merge_gate::run_merge_gate(&app_event_tx, &db, &folder, &id).await?;
         let parent_commit_hash = git_client.head_hash(folder.clone()).await?;
 
         let squash_diff = Self::load_squash_diff(
@@ -1223,22 +1262,19 @@ impl SessionManager {
                 .await?,
             )
         };
-        let merge_outcome = if let Some(commit_message) = authoritative_commit_message.as_ref() {
-            let repo_root = repo_root.clone();
-            let source_branch = source_branch.clone();
-            let base_branch = base_branch.clone();
-            let commit_message = commit_message.clone();
-
-            git_client
-                .squash_merge(repo_root, source_branch, base_branch, commit_message)
-                .await?
-        } else {
-            git::SquashMergeOutcome::AlreadyPresentInTarget
-        };
+        let merge_outcome = Self::squash_merge_session_branch(
+            git_client.as_ref(),
+            repo_root.clone(),
+            source_branch.clone(),
+            base_branch.clone(),
+            authoritative_commit_message.clone(),
+        )
+        .await?;
         let merged_commit_hash =
             Self::load_merged_commit_hash(git_client.as_ref(), repo_root.clone(), merge_outcome)
                 .await?;
         Self::cleanup_merged_session_worktree(
+            &id,
             folder.clone(),
             Arc::clone(&fs_client),
             Arc::clone(&git_client),
@@ -1502,7 +1538,11 @@ impl SessionManager {
                 SessionTaskService::request_git_status_refresh(app_event_tx);
             }
             Err(error) => {
-                let merge_error = TranscriptNotice::MergeError.format(error);
+                let merge_error = if matches!(error, SessionError::MergeGateFailed { .. }) {
+                    TranscriptNotice::MergeGateError.format(&error)
+                } else {
+                    TranscriptNotice::MergeError.format(&error)
+                };
                 SessionTaskService::append_workflow_notice(
                     transcript,
                     db,
@@ -1519,6 +1559,39 @@ impl SessionManager {
                          status was already current"
                     );
                 }
+                if let SessionError::MergeGateFailed { command, output } = &error {
+                    Self::request_merge_gate_fix_turn(app_event_tx, db, id, command, output).await;
+                }
+            }
+        }
+    }
+
+    /// Hands a failed merge gate to the agent when the project enables
+    /// merge-gate fix turns.
+    ///
+    /// Runs after the session is restored to `Review` so the follow-up reply
+    /// is accepted by the reducer.
+    async fn request_merge_gate_fix_turn(
+        app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+        db: &AppRepositories,
+        id: &str,
+        command: &str,
+        output: &str,
+    ) {
+        if !MergeGateSettings::load(db, id).await.fix_turn {
+            return;
+        }
+
+        match merge_gate::merge_gate_fix_prompt(command, output) {
+            Ok(prompt) => {
+                SessionTaskService::request_merge_gate_fix_turn(app_event_tx, id, prompt);
+            }
+            Err(error) => {
+                warn!(
+                    session_id = id,
+                    error = %error,
+                    "failed to render merge gate fix prompt"
+                );
             }
         }
     }
@@ -3022,14 +3095,41 @@ impl SessionManager {
         }
     }
 
-    /// Removes a merged session worktree and deletes its source branch.
+    /// Squash-merges the session branch into its base branch.
+    ///
+    /// A missing `commit_message` means the session diff is empty, so the
+    /// branch content is already present in the target and nothing is
+    /// merged.
+    ///
+    /// # Errors
+    /// Returns an error when the squash-merge git commands fail.
+    async fn squash_merge_session_branch(
+        git_client: &dyn GitClient,
+        repo_root: PathBuf,
+        source_branch: String,
+        base_branch: String,
+        commit_message: Option<String>,
+    ) -> Result<git::SquashMergeOutcome, SessionError> {
+        let Some(commit_message) = commit_message else {
+            return Ok(git::SquashMergeOutcome::AlreadyPresentInTarget);
+        };
+
+        Ok(git_client
+            .squash_merge(repo_root, source_branch, base_branch, commit_message)
+            .await?)
+    }
+
+    /// Removes a merged session worktree and deletes its source branch and
+    /// hidden turn checkpoint refs.
     ///
     /// When `repo_root` is not provided, this resolves the shared repository
     /// root through `git rev-parse` via `GitClient`.
     ///
     /// # Errors
-    /// Returns an error if worktree or branch cleanup fails.
+    /// Returns an error if worktree or branch cleanup fails. Checkpoint ref
+    /// cleanup failures are only logged.
     pub(crate) async fn cleanup_merged_session_worktree(
+        session_id: &str,
         folder: PathBuf,
         fs_client: Arc<dyn FsClient>,
         git_client: Arc<dyn GitClient>,
@@ -3042,6 +3142,8 @@ impl SessionManager {
         };
 
         git_client.remove_worktree(folder.clone()).await?;
+        Self::delete_session_turn_checkpoints(git_client.as_ref(), repo_root.clone(), session_id)
+            .await;
 
         if let Some(repo_root) = repo_root {
             git_client.delete_branch(repo_root, source_branch).await?;
@@ -3168,6 +3270,41 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_merge_and_rebase_reject_imported_review_request_session() {
+        // Arrange
+        let mut app = crate::test_support::new_test_app_without_retained_base_dir().await;
+        app.sessions.push_session(
+            crate::test_support::SessionFixtureBuilder::new()
+                .base_branch("0123456789abcdef0123456789abcdef01234567")
+                .build(),
+        );
+
+        // Act
+        let merge_result = app
+            .sessions
+            .merge_session("session-id", &app.projects, &app.services)
+            .await;
+        let rebase_result = app
+            .sessions
+            .rebase_session(&app.services, "session-id")
+            .await;
+
+        // Assert
+        assert_eq!(
+            merge_result
+                .expect_err("imported session should not merge")
+                .to_string(),
+            IMPORTED_REVIEW_REQUEST_MERGE_ERROR
+        );
+        assert_eq!(
+            rebase_result
+                .expect_err("imported session should not sync")
+                .to_string(),
+            IMPORTED_REVIEW_REQUEST_SYNC_ERROR
+        );
+    }
+
     #[tokio::test]
     async fn test_merge_session_rejects_orchestrator_before_workflow_start() {
         // Arrange
@@ -3869,6 +4006,11 @@ mod tests {
             .times(1)
             .in_sequence(&mut sequence)
             .returning(|_| Box::pin(async { Ok(()) }));
+        mock_git_client
+            .expect_delete_turn_checkpoints()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
         mock_git_client
             .expect_delete_branch()
             .times(1)
@@ -3900,6 +4042,69 @@ mod tests {
         assert_managed_merge_metadata(&db, expected_merged_commit_hash).await;
     }
 
+    #[tokio::test]
+    async fn test_execute_merge_workflow_stops_before_squash_when_merge_gate_fails() {
+        // Arrange
+        let mut mock_git_client = git::MockGitClient::new();
+        let mut sequence = Sequence::new();
+        mock_git_client
+            .expect_is_worktree_clean()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Box::pin(async { Ok(true) }));
+        mock_git_client
+            .expect_is_rebase_in_progress()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Box::pin(async { Ok(false) }));
+        mock_git_client
+            .expect_rebase_start()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Box::pin(async { Ok(git::RebaseStepResult::Completed) }));
+        mock_git_client.expect_head_hash().times(0);
+        mock_git_client.expect_squash_merge_diff().times(0);
+        mock_git_client.expect_squash_merge().times(0);
+        mock_git_client.expect_remove_worktree().times(0);
+        let (_temp_dir, input) = build_merge_task_input_for_test(Arc::new(mock_git_client)).await;
+        std::fs::create_dir_all(&input.folder).expect("failed to create session worktree");
+        let project_id = input
+            .db
+            .projects()
+            .upsert_project("/tmp/project", Some("main".to_string()))
+            .await
+            .expect("failed to insert project");
+        input
+            .db
+            .sessions()
+            .insert_session("session-123", "gpt-5.6-sol", "main", "Merging", project_id)
+            .await
+            .expect("failed to insert merge session row");
+        input
+            .db
+            .settings()
+            .upsert_project_setting(
+                project_id,
+                crate::domain::setting::SettingName::MergeGateCommands,
+                "echo gate broke; exit 3",
+            )
+            .await
+            .expect("failed to save merge gate commands");
+
+        // Act
+        let result = SessionManager::execute_merge_workflow(input).await;
+
+        // Assert
+        let error = result.expect_err("failing merge gate should stop the merge");
+        let gate_failure = match error {
+            SessionError::MergeGateFailed { command, output } => Some((command, output)),
+            _ => None,
+        }
+        .expect("expected merge gate failure");
+        assert_eq!(gate_failure.0, "echo gate broke; exit 3");
+        assert!(gate_failure.1.contains("gate broke"));
+    }
+
     #[tokio::test]
     async fn test_execute_merge_workflow_skips_commit_creation_for_empty_squash_diff() {
         // Arrange
@@ -3937,6 +4142,11 @@ mod tests {
             .times(1)
             .in_sequence(&mut sequence)
             .returning(|_| Box::pin(async { Ok(()) }));
+        mock_git_client
+            .expect_delete_turn_checkpoints()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
         mock_git_client
             .expect_delete_branch()
             .times(1)
@@ -4607,6 +4817,54 @@ mod tests {
         );
     }
 
+    /// Verifies merged-session cleanup deletes the session's hidden turn
+    /// checkpoint refs from the shared repository and only logs failures.
+    #[tokio::test]
+    async fn test_cleanup_merged_session_worktree_deletes_turn_checkpoints() {
+        // Arrange
+        let temp_dir = tempdir().expect("failed to create temporary test directory");
+        let folder = temp_dir.path().join("session-worktree");
+        let repo_root = temp_dir.path().join("repo-root");
+        let expected_repo_root = repo_root.clone();
+        let mut mock_git_client = git::MockGitClient::new();
+        let mut mock_fs_client = fs::MockFsClient::new();
+        mock_git_client
+            .expect_remove_worktree()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_git_client
+            .expect_delete_turn_checkpoints()
+            .times(1)
+            .withf(move |checkpoint_repo_root, session_id| {
+                checkpoint_repo_root == &expected_repo_root && session_id == "session-123"
+            })
+            .returning(|_, _| {
+                Box::pin(async { Err(GitError::OutputParse("ref delete failed".to_string())) })
+            });
+        mock_git_client
+            .expect_delete_branch()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_fs_client
+            .expect_remove_dir_all()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        // Act
+        let result = SessionManager::cleanup_merged_session_worktree(
+            "session-123",
+            folder,
+            Arc::new(mock_fs_client),
+            Arc::new(mock_git_client),
+            "wt/session-123".to_string(),
+            Some(repo_root),
+        )
+        .await;
+
+        // Assert
+        assert!(result.is_ok(), "cleanup should succeed: {result:?}");
+    }
+
     /// Verifies merged-session cleanup surfaces branch deletion failures after
     /// the worktree itself has already been removed.
     #[tokio::test]
@@ -4622,6 +4880,10 @@ mod tests {
             .expect_remove_worktree()
             .times(1)
             .returning(|_| Box::pin(async { Ok(()) }));
+        mock_git_client
+            .expect_delete_turn_checkpoints()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
         mock_git_client
             .expect_delete_branch()
             .times(1)
@@ -4632,6 +4894,7 @@ mod tests {
 
         // Act
         let result = SessionManager::cleanup_merged_session_worktree(
+            "session-123",
             folder,
             Arc::new(mock_fs_client),
             Arc::new(mock_git_client),
@@ -0,0 +1,295 @@
+//! Project merge-gate commands run between the merge rebase and squash.
+
+use std::path::Path;
+use std::time::Duration;
+
+use askama::Template;
+use tokio::sync::mpsc;
+
+use super::{SessionTaskService, project_commands};
+use crate::app::AppEvent;
+use crate::app::session::SessionError;
+use crate::domain::setting::{DEFAULT_MERGE_GATE_TIMEOUT_SECONDS, SettingName};
+use crate::domain::transcript_notice::TranscriptNotice;
+use crate::infra::db::AppRepositories;
+use crate::infra::shell::{self, TimedShellCommandOutput};
+
+/// Maximum number of trailing output lines kept from one failed gate command.
+const MERGE_GATE_OUTPUT_MAX_LINES: usize = 80;
+
+/// Askama view model for rendering the merge-gate fix-turn prompt.
+#[derive(Template)]
+#[template(path = "merge_gate_fix_prompt.md", escape = "none")]
+struct MergeGateFixPromptTemplate<'a> {
+    command: &'a str,
+    output: &'a str,
+}
+
+/// Project-scoped merge-gate configuration for one session.
+#[derive(Debug, Eq, PartialEq)]
+pub(super) struct MergeGateSettings {
+    /// Commands that must all succeed before the squash merge, in order.
+    pub(super) commands: Vec<String>,
+    /// Whether a failed gate hands its output to the agent for a fix turn.
+    pub(super) fix_turn: bool,
+    /// Time budget for each gate command.
+    pub(super) timeout: Duration,
+}
+
+impl Default for MergeGateSettings {
+    fn default() -> Self {
+        Self {
+            commands: Vec::new(),
+            fix_turn: false,
+            timeout: Duration::from_secs(DEFAULT_MERGE_GATE_TIMEOUT_SECONDS),
+        }
+    }
+}
+
+impl MergeGateSettings {
+    /// Loads the merge-gate settings of the project that owns `session_id`.
+    ///
+    /// Missing projects and unreadable settings fall back to an empty gate so
+    /// the merge keeps its pre-gate behavior.
+    pub(super) async fn load(db: &AppRepositories, session_id: &str) -> Self {
+        let Some(project_id) = project_commands::load_session_project_id(db, session_id).await
+        else {
+            return Self::default();
+        };
+        let commands = project_commands::parse_command_list(
+            project_commands::load_project_setting(db, project_id, SettingName::MergeGateCommands)
+                .await,
+        );
+        let fix_turn =
+            project_commands::load_project_setting(db, project_id, SettingName::MergeGateFixTurn)
+                .await
+                .and_then(|value| value.parse::<bool>().ok())
+                .unwrap_or(false);
+        let timeout_seconds = project_commands::load_project_setting(
+            db,
+            project_id,
+            SettingName::MergeGateTimeoutSeconds,
+        )
+        .await
+        .and_then(|value| value.parse::<u64>().ok())
+        .filter(|value| *value > 0)
+        .unwrap_or(DEFAULT_MERGE_GATE_TIMEOUT_SECONDS);
+
+        Self {
+            commands,
+            fix_turn,
+            timeout: Duration::from_secs(timeout_seconds),
+        }
+    }
+}
+
+/// Runs the session project's merge-gate commands inside `folder`.
+///
+/// # Errors
+/// Returns the first failure reported by [`run_merge_gate_commands`].
+pub(super) async fn run_merge_gate(
+    app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+    db: &AppRepositories,
+    folder: &Path,
+    id: &str,
+) -> Result<(), SessionError> {
+    let settings = MergeGateSettings::load(db, id).await;
+
+    run_merge_gate_commands(
+        app_event_tx,
+        folder,
+        id,
+        &settings.commands,
+        settings.timeout,
+    )
+    .await
+}
+
+/// Runs every configured merge-gate command in order inside `folder`.
+///
+/// # Errors
+/// Returns [`SessionError::MergeGateFailed`] with the trailing captured
+/// output for the first command that exits unsuccessfully or outlives
+/// `timeout`, or a workflow error when a command cannot be spawned.
+async fn run_merge_gate_commands(
+    app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+    folder: &Path,
+    id: &str,
+    commands: &[String],
+    timeout: Duration,
+) -> Result<(), SessionError> {
+    for command in commands {
+        SessionTaskService::emit_session_workflow_notice(
+            app_event_tx,
+            id,
+            TranscriptNotice::MergeGate.format_line(format!("Running `{command}`")),
+        );
+        let output = shell::run_shell_command_with_timeout(folder, command, timeout)
+            .await
+            .map_err(|error| {
+                SessionError::Workflow(format!("Merge gate could not run `{command}`: {error}"))
+            })?;
+        let failure_output = match output {
+            TimedShellCommandOutput::Completed(output) if output.success => continue,
+            TimedShellCommandOutput::Completed(output) => {
+                shell::output_tail(&output.output, MERGE_GATE_OUTPUT_MAX_LINES)
+            }
+            TimedShellCommandOutput::TimedOut { output } => {
+                let timeout_line = format!("Timed out after {} seconds.", timeout.as_secs());
+                let tail = shell::output_tail(&output, MERGE_GATE_OUTPUT_MAX_LINES);
+                if tail.is_empty() {
+                    timeout_line
+                } else {
+                    format!("{tail}\n{timeout_line}")
+                }
+            }
+        };
+
+        return Err(SessionError::MergeGateFailed {
+            command: command.clone(),
+            output: failure_output,
+        });
+    }
+
+    Ok(())
+}
+
+/// Renders the follow-up prompt that asks the agent to fix a failed gate.
+///
+/// # Errors
+/// Returns an error when the prompt template cannot be rendered.
+pub(super) fn merge_gate_fix_prompt(command: &str, output: &str) -> Result<String, SessionError> {
+    MergeGateFixPromptTemplate { command, output }
+        .render()
+        .map_err(|error| {
+            SessionError::Workflow(format!(
+                "Failed to render `merge_gate_fix_prompt.md`: {error}"
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn merge_gate_stops_at_first_failing_command_with_its_output() {
+        // Arrange
+        let folder = tempfile::tempdir().expect("failed to create temp dir");
+        let (app_event_tx, mut app_event_rx) = mpsc::unbounded_channel();
+        let commands = [
+            "true".to_string(),
+            "echo broken test; exit 1".to_string(),
+            "touch should-not-run".to_string(),
+        ];
+
+        // Act
+        let error = run_merge_gate_commands(
+            &app_event_tx,
+            folder.path(),
+            "session-a",
+            &commands,
+            Duration::from_secs(5),
+        )
+        .await
+        .expect_err("second gate command should fail");
+        let mut notices = Vec::new();
+        while let Ok(event) = app_event_rx.try_recv() {
+            if let AppEvent::SessionWorkflowNoticeUpdated { notice, .. } = event {
+                notices.push(notice);
+            }
+        }
+
+        // Assert
+        assert!(matches!(
+            &error,
+            SessionError::MergeGateFailed { command, output }
+                if command == "echo broken test; exit 1" && output == "broken test"
+        ));
+        assert_eq!(
+            notices,
+            [
+                "[Merge Gate] Running `true`",
+                "[Merge Gate] Running `echo broken test; exit 1`",
+            ]
+        );
+        assert!(!folder.path().join("should-not-run").exists());
+    }
+
+    #[tokio::test]
+    async fn merge_gate_fails_timed_out_command_with_its_output_tail() {
+        // Arrange
+        let folder = tempfile::tempdir().expect("failed to create temp dir");
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let commands = [
+            "echo compiling; sleep 5".to_string(),
+            "touch should-not-run".to_string(),
+        ];
+
+        // Act
+        let error = run_merge_gate_commands(
+            &app_event_tx,
+            folder.path(),
+            "session-a",
+            &commands,
+            Duration::from_millis(500),
+        )
+        .await
+        .expect_err("hanging gate command should time out");
+
+        // Assert
+        assert!(matches!(
+            &error,
+            SessionError::MergeGateFailed { command, output }
+                if command == "echo compiling; sleep 5"
+                    && output == "compiling\nTimed out after 0 seconds."
+        ));
+        assert!(!folder.path().join("should-not-run").exists());
+    }
+
+    #[tokio::test]
+    async fn merge_gate_settings_load_project_commands_fix_turn_and_timeout() {
+        // Arrange
+        let db = AppRepositories::in_memory().await.expect("db should open");
+        let project_id = db
+            .projects()
+            .upsert_project("/tmp/merge-gate", Some("main".to_string()))
+            .await
+            .expect("failed to insert project");
+        db.sessions()
+            .insert_session("session-a", "gpt-5.6-sol", "main", "Review", project_id)
+            .await
+            .expect("failed to insert session");
+        db.settings()
+            .upsert_project_setting(
+                project_id,
+                SettingName::MergeGateCommands,
+                " cargo fmt --check \n\ncargo test",
+            )
+            .await
+            .expect("failed to save gate commands");
+        db.settings()
+            .upsert_project_setting(project_id, SettingName::MergeGateFixTurn, "true")
+            .await
+            .expect("failed to save fix-turn setting");
+        db.settings()
+            .upsert_project_setting(project_id, SettingName::MergeGateTimeoutSeconds, "600")
+            .await
+            .expect("failed to save gate timeout");
+
+        // Act
+        let settings = MergeGateSettings::load(&db, "session-a").await;
+        let missing_settings = MergeGateSettings::load(&db, "missing").await;
+
+        // Assert
+        assert_eq!(
+            settings,
+            MergeGateSettings {
+                commands: vec!["cargo fmt --check".to_string(), "cargo test".to_string()],
+                fix_turn: true,
+                timeout: Duration::from_secs(600),
+            }
+        );
+        assert_eq!(missing_settings, MergeGateSettings::default());
+    }
+}
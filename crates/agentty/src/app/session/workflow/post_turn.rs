@@ -16,7 +16,7 @@ use uuid::Uuid;
 
 use super::task::{AutoCommitOutcome, SessionTranscriptMessageAppend};
 use super::worker::{SessionWorkerContext, TurnMetadata, has_unfinished_branch_operation};
-use super::{SessionTaskService, StatusTransition, published_branch, turn};
+use super::{SessionTaskService, StatusTransition, post_turn_checks, published_branch, turn};
 use crate::app::assist::AssistContext;
 use crate::app::service::SessionUpdateVersionMap;
 use crate::app::session::{Clock, SessionError, TurnAppliedState};
@@ -148,6 +148,8 @@ pub(super) struct TurnFinalizerContext {
     pub(super) fs_client: Arc<dyn FsClient>,
     /// Git boundary used by diff-stat refresh.
     pub(super) git_client: Arc<dyn GitClient>,
+    /// In-memory queue checked before running post-turn checks.
+    pub(super) queued_messages: Arc<Mutex<VecDeque<QueuedMessage>>>,
     /// Per-app session update versions shared with the main runtime.
     pub(super) session_update_versions: SessionUpdateVersionMap,
     /// Session identifier whose final state is being refreshed.
@@ -166,11 +168,22 @@ impl TurnFinalizerContext {
             folder: context.folder.clone(),
             fs_client: Arc::clone(&context.fs_client),
             git_client: Arc::clone(&context.git_client),
+            queued_messages: Arc::clone(&context.queued_messages),
             session_update_versions: context.session_update_versions.clone(),
             session_id: context.session_id.clone(),
             status: Arc::clone(&context.status),
         }
     }
+
+    /// Returns whether follow-up prompts are waiting for inline drainage.
+    ///
+    /// Queued prompts supersede post-turn checks because the next turn will
+    /// change the worktree again; a poisoned queue lock counts as non-empty.
+    fn has_queued_messages(&self) -> bool {
+        self.queued_messages
+            .lock()
+            .map_or(true, |guard| !guard.is_empty())
+    }
 }
 
 /// Applies one successful turn result to persistence and returns the
@@ -286,6 +299,11 @@ pub(super) async fn apply_turn_result(
 }
 
 /// Refreshes durable session projections and status after a turn result.
+///
+/// Review-ready turns of branch-owning sessions first run the project's
+/// post-turn checks unless follow-up prompts are already queued; the check
+/// result is reported after the status transition so an automatic fix turn
+/// starts from `Review`.
 pub(super) async fn finalize_channel_turn(
     context: &TurnFinalizerContext,
     result: &Result<Status, SessionError>,
@@ -327,6 +345,22 @@ pub(super) async fn finalize_channel_turn(
     if session_role == SessionRole::OrchestrationResearcher {
         archive_research_diff(context).await;
     }
+    // Checks run while the session is still `InProgress` so merge and review
+    // actions cannot start against a worktree whose checks are still running.
+    let post_turn_check_run = if matches!(result, Ok(Status::Review))
+        && session_role.owns_branch_changes()
+        && !context.has_queued_messages()
+    {
+        post_turn_checks::run_post_turn_checks(
+            &context.app_event_tx,
+            &context.db,
+            &context.folder,
+            &context.session_id,
+        )
+        .await
+    } else {
+        None
+    };
 
     if let Some(target_status) = status_update_after_turn_result(result) {
         // Best-effort: status transition failure is non-critical.
@@ -340,6 +374,13 @@ pub(super) async fn finalize_channel_turn(
         );
         let _ = status_transition.apply(target_status).await;
     }
+    if let Some(post_turn_check_run) = post_turn_check_run {
+        SessionTaskService::complete_post_turn_checks(
+            &context.app_event_tx,
+            &context.session_id,
+            post_turn_check_run,
+        );
+    }
 }
 
 /// Archives any observed researcher diff before its temporary worktree is
@@ -541,6 +582,7 @@ async fn apply_successful_turn_result(
         .as_ref()
         .map(|summary| summary.session.clone());
     if owns_branch_changes && can_auto_push {
+        record_turn_checkpoint(context).await;
         start_published_branch_auto_push(
             context,
             turn_metadata,
@@ -636,6 +678,36 @@ async fn append_review_comment_persistence_failure_notice(context: &PostTurnCont
     .await;
 }
 
+/// Snapshots the committed session branch to the hidden checkpoint ref for
+/// the turn that just finished.
+///
+/// Checkpoint failures only disable `/rewind` for this turn, so they are
+/// logged instead of failing the turn.
+async fn record_turn_checkpoint(context: &PostTurnContext) {
+    let Some(turn) = context
+        .transcript
+        .lock()
+        .ok()
+        .map(|transcript| transcript.turn_count())
+        .filter(|turn| *turn > 0)
+    else {
+        return;
+    };
+
+    if let Err(error) = context
+        .git_client
+        .record_turn_checkpoint(context.folder.clone(), context.session_id.to_string(), turn)
+        .await
+    {
+        warn!(
+            session_id = %context.session_id,
+            turn,
+            error = %error,
+            "failed to record turn checkpoint"
+        );
+    }
+}
+
 async fn session_owns_branch_changes(db: &AppRepositories, session_id: &str) -> bool {
     turn::load_session_role(db, session_id)
         .await
@@ -1009,6 +1081,7 @@ mod tests {
             folder,
             fs_client: Arc::new(fs_client),
             git_client: Arc::new(git_client),
+            queued_messages: Arc::new(Mutex::new(VecDeque::new())),
             session_update_versions: Arc::default(),
             session_id: session_id.into(),
             status: Arc::clone(&status),
@@ -1362,6 +1435,7 @@ mod tests {
             folder: PathBuf::new(),
             fs_client: Arc::new(crate::infra::fs::MockFsClient::new()),
             git_client: Arc::new(MockGitClient::new()),
+            queued_messages: Arc::new(Mutex::new(VecDeque::new())),
             session_update_versions: Arc::default(),
             session_id: "session-id".into(),
             status: Arc::clone(&status),
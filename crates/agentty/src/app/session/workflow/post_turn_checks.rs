@@ -0,0 +1,400 @@
+//! Project post-turn checks run after each successful session turn.
+
+use std::path::Path;
+use std::time::Duration;
+
+use askama::Template;
+use tokio::sync::mpsc;
+
+use super::{SessionTaskService, project_commands};
+use crate::app::AppEvent;
+use crate::domain::session::PostTurnCheckOutcome;
+use crate::domain::setting::{
+    DEFAULT_POST_TURN_CHECK_TIMEOUT_SECONDS, MAX_POST_TURN_CHECK_FIX_RETRIES, SettingName,
+};
+use crate::domain::transcript_notice::TranscriptNotice;
+use crate::infra::db::AppRepositories;
+use crate::infra::shell::{self, TimedShellCommandOutput};
+
+/// Maximum number of trailing output lines kept from one failed check.
+const POST_TURN_CHECK_OUTPUT_MAX_LINES: usize = 60;
+
+/// Progress label shown while post-turn checks run.
+const POST_TURN_CHECK_PROGRESS_LABEL: &str = "Running checks...";
+
+/// Askama view model for rendering the post-turn checks fix-turn prompt.
+#[derive(Template)]
+#[template(path = "post_turn_checks_fix_prompt.md", escape = "none")]
+struct PostTurnChecksFixPromptTemplate<'a> {
+    command: &'a str,
+    output: &'a str,
+    timed_out: bool,
+    timeout_seconds: u64,
+}
+
+/// Project-scoped post-turn check configuration for one session.
+#[derive(Debug, Eq, PartialEq)]
+pub(super) struct PostTurnCheckSettings {
+    /// Commands run in order after each successful turn.
+    pub(super) commands: Vec<String>,
+    /// Maximum automatic fix turns for consecutive failed check runs.
+    pub(super) fix_retries: u8,
+    /// Time budget for each check command.
+    pub(super) timeout: Duration,
+}
+
+impl Default for PostTurnCheckSettings {
+    fn default() -> Self {
+        Self {
+            commands: Vec::new(),
+            fix_retries: 0,
+            timeout: Duration::from_secs(DEFAULT_POST_TURN_CHECK_TIMEOUT_SECONDS),
+        }
+    }
+}
+
+impl PostTurnCheckSettings {
+    /// Loads the post-turn check settings of the project that owns
+    /// `session_id`.
+    ///
+    /// Missing projects and unreadable settings fall back to running no
+    /// checks so turns keep their pre-check behavior.
+    pub(super) async fn load(db: &AppRepositories, session_id: &str) -> Self {
+        let Some(project_id) = project_commands::load_session_project_id(db, session_id).await
+        else {
+            return Self::default();
+        };
+        let commands = project_commands::parse_command_list(
+            project_commands::load_project_setting(
+                db,
+                project_id,
+                SettingName::PostTurnCheckCommands,
+            )
+            .await,
+        );
+        let fix_retries = project_commands::load_project_setting(
+            db,
+            project_id,
+            SettingName::PostTurnCheckFixRetries,
+        )
+        .await
+        .and_then(|value| value.parse::<u8>().ok())
+        .map_or(0, |value| value.min(MAX_POST_TURN_CHECK_FIX_RETRIES));
+        let timeout_seconds = project_commands::load_project_setting(
+            db,
+            project_id,
+            SettingName::PostTurnCheckTimeoutSeconds,
+        )
+        .await
+        .and_then(|value| value.parse::<u64>().ok())
+        .filter(|value| *value > 0)
+        .unwrap_or(DEFAULT_POST_TURN_CHECK_TIMEOUT_SECONDS);
+
+        Self {
+            commands,
+            fix_retries,
+            timeout: Duration::from_secs(timeout_seconds),
+        }
+    }
+}
+
+/// Completed post-turn check run reported to the app reducer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct PostTurnCheckRun {
+    /// Rendered fix-turn prompt when the failed run may start a fix turn.
+    pub(crate) fix_prompt: Option<String>,
+    /// Maximum consecutive automatic fix turns allowed by the project.
+    pub(crate) max_fix_attempts: u8,
+    /// Outcome of the check run.
+    pub(crate) outcome: PostTurnCheckOutcome,
+}
+
+/// Runs the session project's post-turn checks inside `folder`.
+///
+/// Returns `None` when the project has no configured checks. A failed run
+/// carries a rendered fix-turn prompt when the project allows automatic fix
+/// turns.
+pub(super) async fn run_post_turn_checks(
+    app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+    db: &AppRepositories,
+    folder: &Path,
+    id: &str,
+) -> Option<PostTurnCheckRun> {
+    let settings = PostTurnCheckSettings::load(db, id).await;
+    if settings.commands.is_empty() {
+        return None;
+    }
+
+    SessionTaskService::set_session_progress(
+        app_event_tx,
+        id,
+        Some(POST_TURN_CHECK_PROGRESS_LABEL.to_string()),
+    );
+    let outcome = run_post_turn_check_commands(
+        app_event_tx,
+        folder,
+        id,
+        &settings.commands,
+        settings.timeout,
+    )
+    .await;
+    SessionTaskService::clear_session_progress(app_event_tx, id);
+    let fix_prompt = match &outcome {
+        PostTurnCheckOutcome::Failed {
+            command,
+            output,
+            timed_out,
+        } if settings.fix_retries > 0 => {
+            post_turn_checks_fix_prompt(command, output, *timed_out, settings.timeout)
+        }
+        _ => None,
+    };
+
+    Some(PostTurnCheckRun {
+        fix_prompt,
+        max_fix_attempts: settings.fix_retries,
+        outcome,
+    })
+}
+
+/// Runs every configured check command in order inside `folder`, stopping at
+/// the first failure.
+async fn run_post_turn_check_commands(
+    app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+    folder: &Path,
+    id: &str,
+    commands: &[String],
+    timeout: Duration,
+) -> PostTurnCheckOutcome {
+    for command in commands {
+        SessionTaskService::emit_session_workflow_notice(
+            app_event_tx,
+            id,
+            TranscriptNotice::Checks.format_line(format!("Running `{command}`")),
+        );
+        let (output, timed_out) =
+            match shell::run_shell_command_with_timeout(folder, command, timeout).await {
+                Ok(TimedShellCommandOutput::Completed(output)) if output.success => continue,
+                Ok(TimedShellCommandOutput::Completed(output)) => (
+                    shell::output_tail(&output.output, POST_TURN_CHECK_OUTPUT_MAX_LINES),
+                    false,
+                ),
+                Ok(TimedShellCommandOutput::TimedOut { .. }) => (
+                    format!("Timed out after {} seconds.", timeout.as_secs()),
+                    true,
+                ),
+                Err(error) => (format!("Could not run the command: {error}"), false),
+            };
+        SessionTaskService::emit_session_workflow_notice(
+            app_event_tx,
+            id,
+            TranscriptNotice::ChecksError.format_line(format!("`{command}` failed")),
+        );
+
+        return PostTurnCheckOutcome::Failed {
+            command: command.clone(),
+            output,
+            timed_out,
+        };
+    }
+
+    PostTurnCheckOutcome::Passed {
+        command_count: commands.len(),
+    }
+}
+
+/// Renders the follow-up prompt that asks the agent to fix failed checks.
+///
+/// Rendering failures are logged and skip the automatic fix turn.
+fn post_turn_checks_fix_prompt(
+    command: &str,
+    output: &str,
+    timed_out: bool,
+    timeout: Duration,
+) -> Option<String> {
+    PostTurnChecksFixPromptTemplate {
+        command,
+        output,
+        timed_out,
+        timeout_seconds: timeout.as_secs(),
+    }
+    .render()
+    .inspect_err(|error| {
+        tracing::warn!(
+            error = %error,
+            "failed to render `post_turn_checks_fix_prompt.md`"
+        );
+    })
+    .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Drains transcript notices emitted on `app_event_rx`.
+    fn drain_notices(app_event_rx: &mut mpsc::UnboundedReceiver<AppEvent>) -> Vec<String> {
+        let mut notices = Vec::new();
+        while let Ok(event) = app_event_rx.try_recv() {
+            if let AppEvent::SessionWorkflowNoticeUpdated { notice, .. } = event {
+                notices.push(notice);
+            }
+        }
+
+        notices
+    }
+
+    #[tokio::test]
+    async fn post_turn_checks_stop_at_first_failing_command_with_its_output() {
+        // Arrange
+        let folder = tempfile::tempdir().expect("failed to create temp dir");
+        let (app_event_tx, mut app_event_rx) = mpsc::unbounded_channel();
+        let commands = [
+            "true".to_string(),
+            "echo lint failed; exit 1".to_string(),
+            "touch should-not-run".to_string(),
+        ];
+
+        // Act
+        let outcome = run_post_turn_check_commands(
+            &app_event_tx,
+            folder.path(),
+            "session-a",
+            &commands,
+            Duration::from_secs(10),
+        )
+        .await;
+        let notices = drain_notices(&mut app_event_rx);
+
+        // Assert
+        assert_eq!(
+            outcome,
+            PostTurnCheckOutcome::Failed {
+                command: "echo lint failed; exit 1".to_string(),
+                output: "lint failed".to_string(),
+                timed_out: false,
+            }
+        );
+        assert_eq!(
+            notices,
+            [
+                "[Checks] Running `true`",
+                "[Checks] Running `echo lint failed; exit 1`",
+                "[Checks Error] `echo lint failed; exit 1` failed",
+            ]
+        );
+        assert!(!folder.path().join("should-not-run").exists());
+    }
+
+    #[tokio::test]
+    async fn post_turn_checks_report_timed_out_command() {
+        // Arrange
+        let folder = tempfile::tempdir().expect("failed to create temp dir");
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let commands = ["sleep 5".to_string()];
+
+        // Act
+        let outcome = run_post_turn_check_commands(
+            &app_event_tx,
+            folder.path(),
+            "session-a",
+            &commands,
+            Duration::from_millis(100),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(
+            outcome,
+            PostTurnCheckOutcome::Failed {
+                command: "sleep 5".to_string(),
+                output: "Timed out after 0 seconds.".to_string(),
+                timed_out: true,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn post_turn_checks_pass_when_every_command_succeeds() {
+        // Arrange
+        let folder = tempfile::tempdir().expect("failed to create temp dir");
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let commands = ["true".to_string(), "echo ok".to_string()];
+
+        // Act
+        let outcome = run_post_turn_check_commands(
+            &app_event_tx,
+            folder.path(),
+            "session-a",
+            &commands,
+            Duration::from_secs(10),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(outcome, PostTurnCheckOutcome::Passed { command_count: 2 });
+    }
+
+    #[tokio::test]
+    async fn post_turn_check_settings_load_project_commands_retries_and_timeout() {
+        // Arrange
+        let db = AppRepositories::in_memory().await.expect("db should open");
+        let project_id = db
+            .projects()
+            .upsert_project("/tmp/post-turn-checks", Some("main".to_string()))
+            .await
+            .expect("failed to insert project");
+        db.sessions()
+            .insert_session("session-a", "gpt-5.6-sol", "main", "Review", project_id)
+            .await
+            .expect("failed to insert session");
+        for (setting_name, value) in [
+            (
+                SettingName::PostTurnCheckCommands,
+                " cargo clippy \n\ncargo test",
+            ),
+            (SettingName::PostTurnCheckFixRetries, "9"),
+            (SettingName::PostTurnCheckTimeoutSeconds, "600"),
+        ] {
+            db.settings()
+                .upsert_project_setting(project_id, setting_name, value)
+                .await
+                .expect("failed to save post-turn check setting");
+        }
+
+        // Act
+        let settings = PostTurnCheckSettings::load(&db, "session-a").await;
+        let missing_settings = PostTurnCheckSettings::load(&db, "missing").await;
+
+        // Assert
+        assert_eq!(
+            settings,
+            PostTurnCheckSettings {
+                commands: vec!["cargo clippy".to_string(), "cargo test".to_string()],
+                fix_retries: MAX_POST_TURN_CHECK_FIX_RETRIES,
+                timeout: Duration::from_secs(600),
+            }
+        );
+        assert_eq!(missing_settings, PostTurnCheckSettings::default());
+    }
+
+    #[test]
+    fn post_turn_checks_fix_prompt_includes_command_output_and_timeout() {
+        // Arrange
+        let timeout = Duration::from_secs(60);
+
+        // Act
+        let failed_prompt =
+            post_turn_checks_fix_prompt("cargo test", "assertion failed", false, timeout)
+                .expect("prompt should render");
+        let timed_out_prompt =
+            post_turn_checks_fix_prompt("cargo test", "Timed out after 60 seconds.", true, timeout)
+                .expect("prompt should render");
+
+        // Assert
+        assert!(failed_prompt.contains("`cargo test`"));
+        assert!(failed_prompt.contains("assertion failed"));
+        assert!(!failed_prompt.contains("60 seconds"));
+        assert!(timed_out_prompt.contains("60 seconds"));
+    }
+}
@@ -0,0 +1,58 @@
+//! Project-scoped shell command settings shared by session workflows.
+
+use tracing::warn;
+
+use crate::domain::setting::SettingName;
+use crate::infra::db::AppRepositories;
+
+/// Loads the project identifier that owns `session_id`.
+///
+/// Missing sessions and read failures return `None` so callers fall back to
+/// running no project commands.
+pub(super) async fn load_session_project_id(db: &AppRepositories, session_id: &str) -> Option<i64> {
+    match db.sessions().load_session_project_id(session_id).await {
+        Ok(project_id) => project_id,
+        Err(error) => {
+            warn!(
+                session_id,
+                error = %error,
+                "failed to load session project while reading project commands"
+            );
+
+            None
+        }
+    }
+}
+
+/// Loads one project setting value, logging and ignoring read failures.
+pub(super) async fn load_project_setting(
+    db: &AppRepositories,
+    project_id: i64,
+    setting_name: SettingName,
+) -> Option<String> {
+    db.settings()
+        .get_project_setting(project_id, setting_name)
+        .await
+        .unwrap_or_else(|error| {
+            warn!(
+                project_id,
+                setting = %setting_name,
+                error = %error,
+                "failed to load project command setting"
+            );
+
+            None
+        })
+}
+
+/// Splits one persisted newline-separated command list into trimmed,
+/// non-empty command lines.
+pub(super) fn parse_command_list(value: Option<String>) -> Vec<String> {
+    value
+        .unwrap_or_default()
+        .lines()
+        .map(str::trim)
+        .filter(|command| !command.is_empty())
+        .map(ToString::to_string)
+        .collect()
+}
@@ -282,6 +282,9 @@ impl SessionManager {
                 .split_once("/-/merge_requests/")
                 .or_else(|| web_url.split_once("/merge_requests/"))
                 .map(|(repo_url, _)| repo_url.to_string()),
+            ForgeKind::Forgejo => web_url
+                .split_once("/pulls/")
+                .map(|(repo_url, _)| repo_url.to_string()),
         }
     }
 
@@ -386,7 +389,7 @@ mod tests {
     use crate::infra::db::AppRepositories;
     use crate::infra::fs;
     use crate::presentation::app_mode::{
-        DiffFocus, DiffLineComments, DiffPreview, DiffSidebarFocus,
+        DiffFocus, DiffLayoutMode, DiffLineComments, DiffPreview, DiffSidebarFocus,
     };
     use crate::presentation::help_action::ViewSessionState;
 
@@ -849,7 +852,9 @@ mod tests {
         // Arrange
         let mode = AppMode::Help {
             context: HelpContext::View {
+                can_fix_review_checks: false,
                 can_fork_session: false,
+                can_mark_review_ready: false,
                 can_merge_session_branch: false,
                 can_mutate_session_branch: false,
                 can_open_worktree: false,
@@ -888,6 +893,7 @@ mod tests {
                 diff: String::new(),
                 file_explorer_selected_index: 0,
                 focus: DiffFocus::Files,
+                layout_mode: DiffLayoutMode::default(),
                 line_comments: DiffLineComments::default(),
                 selected_diff_line_index: 0,
                 preview: DiffPreview::default(),
@@ -914,6 +920,7 @@ mod tests {
                     scroll_offset: None,
                     session_id: "publish-session".into(),
                 },
+                review_request_form: None,
             },
         ];
 
@@ -946,6 +953,7 @@ mod tests {
             diff: String::new(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -0,0 +1,269 @@
+//! Diff-view revert workflow that discards selected worktree changes.
+
+use std::sync::Arc;
+
+use super::{SessionManager, SessionTaskService};
+use crate::app::assist::AssistContext;
+use crate::app::session::SessionError;
+use crate::app::{AppEvent, AppServices};
+use crate::domain::session::SessionId;
+use crate::domain::transcript_notice::TranscriptNotice;
+use crate::domain::turn_prompt::TurnPrompt;
+
+/// Agent-facing heading placed before pending diff-view revert notes.
+const REVERT_NOTES_HEADING: &str = "Since your last turn, the user discarded these changes from \
+                                    the diff view. Do not reapply them unless asked:";
+
+impl SessionManager {
+    /// Discards the changed rows described by `patch` from one session
+    /// worktree.
+    ///
+    /// Reverse-applies the patch, records a `[Revert]` workflow notice,
+    /// queues `summary` for the next agent prompt, and amends the session
+    /// commit in the background like a completed turn.
+    ///
+    /// # Errors
+    /// Returns an error if the session is missing, cannot be reverted in its
+    /// current status, or the patch no longer matches the worktree.
+    pub async fn revert_session_diff_changes(
+        &mut self,
+        services: &AppServices,
+        session_id: &str,
+        patch: String,
+        summary: Vec<String>,
+    ) -> Result<(), SessionError> {
+        let (folder, session_agent) = {
+            let session = self.session_or_err(session_id)?;
+            if !session.allows_diff_revert_action() {
+                return Err(SessionError::Workflow(
+                    "Session must be in review status to revert diff changes".to_string(),
+                ));
+            }
+
+            (session.folder.clone(), session.agent)
+        };
+
+        services
+            .git_client()
+            .reverse_apply_patch(folder.clone(), patch)
+            .await?;
+        let notice = TranscriptNotice::Revert
+            .format(format!("Discarded diff changes: {}", summary.join("; ")));
+        self.append_output_for_session(services, session_id, &notice)
+            .await;
+        self.workflow_state
+            .pending_revert_notes
+            .entry(SessionId::from(session_id))
+            .or_default()
+            .extend(summary);
+
+        let handles = self.session_handles_or_err(session_id)?;
+        let app_event_tx = services.event_sender();
+        let context = AssistContext {
+            app_event_tx: app_event_tx.clone(),
+            child_pid: Arc::clone(&handles.child_pid),
+            db: services.db().clone(),
+            folder: folder.clone(),
+            git_client: services.git_client(),
+            id: session_id.to_string(),
+            one_shot_client: services.one_shot_client(),
+            session_agent,
+            session_update_versions: services.session_update_versions(),
+            transcript: Arc::clone(&handles.transcript),
+        };
+        let (db, fs_client, git_client, id) = (
+            services.db().clone(),
+            services.fs_client(),
+            services.git_client(),
+            SessionId::from(session_id),
+        );
+        tokio::spawn(async move {
+            SessionTaskService::handle_auto_commit(context).await;
+            if let Some(diff_stats) = SessionTaskService::refresh_persisted_session_diff_stats(
+                &db,
+                fs_client.as_ref(),
+                git_client.as_ref(),
+                id.as_str(),
+                &folder,
+            )
+            .await
+            {
+                let _ = app_event_tx.send(AppEvent::SessionDiffStatsUpdated {
+                    diff_stats,
+                    session_id: id,
+                });
+            }
+            let _ = app_event_tx.send(AppEvent::RefreshGitStatus);
+        });
+
+        Ok(())
+    }
+
+    /// Drains pending diff-view revert notes into the agent-facing copy of
+    /// `prompt`.
+    ///
+    /// Returns the drained notes alongside the prompt so a reply that fails
+    /// to enqueue can restore them with [`Self::restore_pending_revert_notes`].
+    pub(super) fn take_pending_revert_notes(
+        &mut self,
+        session_id: &str,
+        prompt: &TurnPrompt,
+    ) -> (TurnPrompt, Vec<String>) {
+        let notes = self
+            .workflow_state
+            .pending_revert_notes
+            .remove(session_id)
+            .unwrap_or_default();
+        let mut agent_prompt = prompt.clone();
+        if !notes.is_empty() {
+            let note_lines = notes
+                .iter()
+                .map(|note| format!("- {note}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            agent_prompt.text = format!(
+                "{REVERT_NOTES_HEADING}\n{note_lines}\n\n{}",
+                agent_prompt.text
+            );
+        }
+
+        (agent_prompt, notes)
+    }
+
+    /// Puts drained revert notes back ahead of any recorded since.
+    pub(super) fn restore_pending_revert_notes(&mut self, session_id: &str, notes: Vec<String>) {
+        if notes.is_empty() {
+            return;
+        }
+        let pending_notes = self
+            .workflow_state
+            .pending_revert_notes
+            .entry(SessionId::from(session_id))
+            .or_default();
+        pending_notes.splice(0..0, notes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use std::process::Command;
+
+    use crate::domain::session::Status;
+    use crate::domain::session_message::SessionMessageKind;
+    use crate::domain::turn_prompt::TurnPrompt;
+
+    /// Runs one Git command inside `folder` and asserts that it succeeds.
+    fn run_git(folder: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(folder)
+            .output()
+            .expect("failed to run git");
+
+        assert!(
+            output.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_revert_session_diff_changes_discards_rows_and_notes_next_prompt() {
+        // Arrange
+        let (mut app, _base_dir) = crate::test_support::new_git_test_app().await;
+        let session_id = app
+            .create_session()
+            .await
+            .expect("failed to create session");
+        let folder = app.sessions.sessions()[0].folder.clone();
+        fs::write(folder.join("notes.txt"), "keep\n").expect("failed to write file");
+        run_git(&folder, &["add", "notes.txt"]);
+        run_git(&folder, &["commit", "-m", "Add notes"]);
+        fs::write(folder.join("notes.txt"), "keep\nadded\n").expect("failed to write file");
+        *app.sessions.session_handles()[session_id.as_str()]
+            .status
+            .lock()
+            .expect("status lock should be available") = Status::Review;
+        app.sessions.sync_session_from_handle(&session_id);
+        let patch = concat!(
+            "diff --git a/notes.txt b/notes.txt\n",
+            "--- a/notes.txt\n",
+            "+++ b/notes.txt\n",
+            "@@ -1,1 +1,2 @@\n",
+            " keep\n",
+            "+added\n",
+        );
+        let summary = vec!["notes.txt: discarded 1 added line (new line 2)".to_string()];
+
+        // Act
+        let result = app
+            .sessions
+            .revert_session_diff_changes(&app.services, &session_id, patch.to_string(), summary)
+            .await;
+        let (agent_prompt, notes) = app
+            .sessions
+            .take_pending_revert_notes(&session_id, &TurnPrompt::from_text("Next".to_string()));
+        let (drained_prompt, drained_notes) = app
+            .sessions
+            .take_pending_revert_notes(&session_id, &TurnPrompt::from_text("Next".to_string()));
+
+        // Assert
+        assert!(result.is_ok(), "revert should succeed: {result:?}");
+        assert_eq!(
+            fs::read_to_string(folder.join("notes.txt")).expect("failed to read file"),
+            "keep\n"
+        );
+        assert_eq!(
+            notes,
+            vec!["notes.txt: discarded 1 added line (new line 2)".to_string()]
+        );
+        assert!(agent_prompt.text.starts_with(super::REVERT_NOTES_HEADING));
+        assert!(
+            agent_prompt
+                .text
+                .ends_with("- notes.txt: discarded 1 added line (new line 2)\n\nNext")
+        );
+        assert_eq!(drained_prompt.text, "Next");
+        assert_eq!(drained_notes, Vec::<String>::new());
+        let revert_notices = app
+            .services
+            .db()
+            .sessions()
+            .load_session_messages(&session_id)
+            .await
+            .expect("failed to load messages")
+            .into_iter()
+            .filter(|message| {
+                message.kind == SessionMessageKind::WorkflowNotice.as_str()
+                    && message.content.contains("[Revert] Discarded diff changes")
+            })
+            .count();
+        assert_eq!(revert_notices, 1);
+    }
+
+    #[tokio::test]
+    async fn test_revert_session_diff_changes_rejects_active_session() {
+        // Arrange
+        let (mut app, _base_dir) = crate::test_support::new_git_test_app().await;
+        let session_id = app
+            .create_session()
+            .await
+            .expect("failed to create session");
+
+        // Act
+        let result = app
+            .sessions
+            .revert_session_diff_changes(&app.services, &session_id, String::new(), Vec::new())
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+        let (agent_prompt, notes) = app
+            .sessions
+            .take_pending_revert_notes(&session_id, &TurnPrompt::from_text("Next".to_string()));
+        assert_eq!(agent_prompt.text, "Next");
+        assert_eq!(notes, Vec::<String>::new());
+    }
+}
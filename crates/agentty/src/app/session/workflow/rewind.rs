@@ -0,0 +1,236 @@
+//! Per-turn checkpoint listing and `/rewind` restoration workflows.
+
+use super::{SessionManager, SessionTaskService, load};
+use crate::app::session::SessionError;
+use crate::app::{AppEvent, AppServices};
+use crate::domain::session::{SessionId, SessionTurnCheckpoint};
+
+impl SessionManager {
+    /// Lists restorable turn checkpoints for one session, newest turn first.
+    ///
+    /// Checkpoints are only offered for turns that still exist in the
+    /// persisted transcript, so each entry carries the prompt that started it.
+    ///
+    /// # Errors
+    /// Returns an error if the session is missing or checkpoint refs or
+    /// transcript rows cannot be loaded.
+    pub async fn list_session_turn_checkpoints(
+        &self,
+        services: &AppServices,
+        session_id: &str,
+    ) -> Result<Vec<SessionTurnCheckpoint>, SessionError> {
+        let folder = self.session_or_err(session_id)?.folder.clone();
+        let checkpoints = services
+            .git_client()
+            .list_turn_checkpoints(folder, session_id.to_string())
+            .await?;
+        let transcript = load::load_session_transcript(services.db(), session_id).await?;
+
+        Ok(checkpoints
+            .into_iter()
+            .rev()
+            .filter_map(|checkpoint| {
+                let prompt = transcript.turn_prompt(checkpoint.turn)?;
+
+                Some(SessionTurnCheckpoint {
+                    prompt: prompt.to_string(),
+                    turn: checkpoint.turn,
+                })
+            })
+            .collect())
+    }
+
+    /// Restores one session to the state recorded after `turn`.
+    ///
+    /// Resets the session worktree to the turn checkpoint, drops transcript
+    /// rows from later turns, and forgets the provider conversation so the
+    /// next reply starts fresh from a replay of the truncated transcript.
+    ///
+    /// # Errors
+    /// Returns an error if the session is missing, busy, has no checkpoint
+    /// for `turn`, or any restore or persistence step fails.
+    pub async fn rewind_session_to_turn(
+        &mut self,
+        services: &AppServices,
+        session_id: &str,
+        turn: u32,
+    ) -> Result<(), SessionError> {
+        let folder = {
+            let session = self.session_or_err(session_id)?;
+            if !session.status.allows_review_actions() {
+                return Err(SessionError::Workflow(
+                    "Session must be in review status to rewind".to_string(),
+                ));
+            }
+
+            session.folder.clone()
+        };
+
+        services
+            .git_client()
+            .restore_turn_checkpoint(folder.clone(), session_id.to_string(), turn)
+            .await?;
+
+        let mut transcript = load::load_session_transcript(services.db(), session_id).await?;
+        if let Some(boundary_position) = transcript.position_after_turn(turn) {
+            services
+                .db()
+                .sessions()
+                .truncate_session_messages(session_id, boundary_position)
+                .await?;
+            transcript.truncate_after_turn(turn);
+        }
+        if let Some(handles) = self.session_handles().get(session_id) {
+            handles.replace_transcript(transcript);
+        }
+        self.sync_session_from_handle(session_id);
+
+        services
+            .db()
+            .sessions()
+            .update_session_provider_conversation_id(session_id, None)
+            .await?;
+        services
+            .db()
+            .sessions()
+            .update_session_instruction_conversation_id(session_id, None)
+            .await?;
+        self.clear_session_worker(session_id);
+        self.mark_history_replay_pending(session_id);
+
+        if let Some(diff_stats) = SessionTaskService::refresh_persisted_session_diff_stats(
+            services.db(),
+            services.fs_client().as_ref(),
+            services.git_client().as_ref(),
+            session_id,
+            &folder,
+        )
+        .await
+        {
+            services.emit_app_event(AppEvent::SessionDiffStatsUpdated {
+                diff_stats,
+                session_id: SessionId::from(session_id),
+            });
+        }
+        services.emit_app_event(AppEvent::RefreshGitStatus);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use std::process::Command;
+
+    use crate::domain::session::{SessionTurnCheckpoint, Status};
+    use crate::domain::session_message::{SessionMessageKind, SessionTranscript};
+
+    /// Commits one new `README.md` body inside a session worktree.
+    fn commit_worktree_readme(folder: &Path, content: &str) {
+        fs::write(folder.join("README.md"), content).expect("failed to write file");
+        let output = Command::new("git")
+            .args(["commit", "-am", content])
+            .current_dir(folder)
+            .output()
+            .expect("failed to run git commit");
+
+        assert!(
+            output.status.success(),
+            "git commit failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rewind_session_to_turn_restores_worktree_and_truncates_transcript() {
+        // Arrange
+        let (mut app, _base_dir) = crate::test_support::new_git_test_app().await;
+        let session_id = app
+            .create_session()
+            .await
+            .expect("failed to create session");
+        let folder = app.sessions.sessions()[0].folder.clone();
+        let db = app.services.db().clone();
+        for (turn, prompt, answer) in [(1, "first", "turn one"), (2, "second", "turn two")] {
+            db.sessions()
+                .append_session_message(&session_id, SessionMessageKind::UserPrompt, prompt)
+                .await
+                .expect("failed to append prompt");
+            db.sessions()
+                .append_session_message(&session_id, SessionMessageKind::AssistantAnswer, answer)
+                .await
+                .expect("failed to append answer");
+            commit_worktree_readme(&folder, answer);
+            app.services
+                .git_client()
+                .record_turn_checkpoint(folder.clone(), session_id.clone(), turn)
+                .await
+                .expect("failed to record checkpoint");
+        }
+        db.sessions()
+            .update_session_provider_conversation_id(&session_id, Some("thread-1".to_string()))
+            .await
+            .expect("failed to store provider conversation");
+        *app.sessions.session_handles()[session_id.as_str()]
+            .status
+            .lock()
+            .expect("status lock should be available") = Status::Review;
+        app.sessions.sync_session_from_handle(&session_id);
+
+        // Act
+        let checkpoints = app
+            .list_session_turn_checkpoints(&session_id)
+            .await
+            .expect("failed to list checkpoints");
+        let result = app.rewind_session_to_turn(&session_id, 1).await;
+
+        // Assert
+        assert!(result.is_ok(), "rewind should succeed: {result:?}");
+        assert_eq!(
+            checkpoints,
+            vec![
+                SessionTurnCheckpoint {
+                    prompt: "second".to_string(),
+                    turn: 2,
+                },
+                SessionTurnCheckpoint {
+                    prompt: "first".to_string(),
+                    turn: 1,
+                },
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(folder.join("README.md")).expect("failed to read file"),
+            "turn one"
+        );
+        let remaining_contents = db
+            .sessions()
+            .load_session_messages(&session_id)
+            .await
+            .expect("failed to load messages")
+            .into_iter()
+            .map(|message| message.content)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            remaining_contents,
+            vec!["first".to_string(), "turn one".to_string()]
+        );
+        assert_eq!(
+            app.sessions.sessions()[0]
+                .transcript
+                .as_ref()
+                .map(SessionTranscript::turn_count),
+            Some(1)
+        );
+        assert_eq!(
+            db.sessions()
+                .get_session_provider_conversation_id(&session_id)
+                .await
+                .expect("failed to load provider conversation"),
+            None
+        );
+        assert!(app.rewind_session_to_turn(&session_id, 2).await.is_err());
+    }
+}
@@ -18,7 +18,7 @@ use crate::app::assist::{
     run_agent_assist,
 };
 use crate::app::service::{AppServices, SessionUpdateVersionMap};
-use crate::app::session::{Clock, SessionError, unix_timestamp_from_system_time};
+use crate::app::session::{Clock, PostTurnCheckRun, SessionError, unix_timestamp_from_system_time};
 use crate::app::{AppEvent, SessionManager, setting};
 use crate::domain::agent::{AgentKind, AgentSelection, ReasoningLevel};
 #[cfg(test)]
@@ -26,7 +26,7 @@ use crate::domain::agent::{AgentModel, AgentSelectionMetadata};
 use crate::domain::session::{
     COMMITTING_PROGRESS_LABEL, SessionDiffStats, SessionHandles, SessionId, Status,
 };
-use crate::domain::session_message::{SessionMessageKind, SessionTranscript};
+use crate::domain::session_message::{SessionMessageKind, SessionTranscript, TurnActivity};
 use crate::domain::setting::SettingName;
 use crate::domain::transcript_notice::TranscriptNotice;
 use crate::infra::db::{AppRepositories, DbError};
@@ -476,6 +476,40 @@ impl SessionTaskService {
         );
     }
 
+    /// Requests one agent fix turn for a session whose merge gate failed.
+    pub(super) fn request_merge_gate_fix_turn(
+        app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+        id: &str,
+        prompt: String,
+    ) {
+        Self::send_app_event(
+            app_event_tx,
+            AppEvent::MergeGateFixTurnRequested {
+                prompt,
+                session_id: SessionId::from(id),
+            },
+            Some(id),
+            "MergeGateFixTurnRequested",
+        );
+    }
+
+    /// Reports one completed post-turn check run to the app reducer.
+    pub(super) fn complete_post_turn_checks(
+        app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+        id: &str,
+        run: PostTurnCheckRun,
+    ) {
+        Self::send_app_event(
+            app_event_tx,
+            AppEvent::PostTurnChecksCompleted {
+                run,
+                session_id: SessionId::from(id),
+            },
+            Some(id),
+            "PostTurnChecksCompleted",
+        );
+    }
+
     /// Loads the project-scoped toggle that controls whether generated session
     /// commit messages include the Agentty coauthor trailer.
     ///
@@ -1232,6 +1266,30 @@ impl SessionTaskService {
         Self::emit_session_updated(app_event_tx, session_update_versions, id);
     }
 
+    /// Appends one structured turn activity to the in-memory transcript and
+    /// durable message store.
+    pub(crate) async fn append_turn_activity(
+        transcript: &Arc<Mutex<SessionTranscript>>,
+        db: &AppRepositories,
+        app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+        session_update_versions: &SessionUpdateVersionMap,
+        id: &str,
+        activity: &TurnActivity,
+    ) {
+        let content = activity.encode();
+        Self::append_live_and_persist_transcript_message(
+            transcript,
+            id,
+            SessionMessageKind::TurnActivity,
+            &content,
+            db.sessions()
+                .append_session_message(id, SessionMessageKind::TurnActivity, &content),
+            "failed to persist turn activity",
+        )
+        .await;
+        Self::emit_session_updated(app_event_tx, session_update_versions, id);
+    }
+
     /// Persists one workflow notice without exposing it to the live transcript.
     ///
     /// Reducer events use this when a transient status and its durable result
@@ -5,8 +5,8 @@ use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use ag_agent::{
-    AgentError, AgentRequestKind, LiveTranscript, OneShotClient, PersonalityPrompt,
-    TurnContinuation, TurnEvent, TurnRequest, TurnResult,
+    AgentError, AgentFuture, AgentRequestKind, LiveTranscript, OneShotClient, PersonalityPrompt,
+    ToolApprovalHandler, TurnContinuation, TurnEvent, TurnRequest, TurnResult,
 };
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
@@ -15,13 +15,15 @@ use tracing::{debug, warn};
 use super::lifecycle::SessionTitleGenerationTaskInput;
 use super::worker::{SessionWorkerContext, TurnMetadata};
 use super::{SessionTaskService, StatusTransition, isolation, post_turn};
+use crate::app::service::SessionUpdateVersionMap;
 use crate::app::session::SessionError;
 use crate::app::{AppEvent, SessionManager, setting};
 use crate::domain::agent::{AgentKind, AgentSelection, ReasoningLevel};
-use crate::domain::permission::PermissionMode;
+use crate::domain::permission::{PermissionMode, ToolApprovalDecision, ToolApprovalRequest};
 use crate::domain::session::{SessionId, SessionRole, Status};
-use crate::domain::session_message::SessionTranscript;
+use crate::domain::session_message::{SessionTranscript, TurnActivity};
 use crate::domain::setting::SettingName;
+use crate::domain::tool_approval::ToolApprovalQueue;
 use crate::domain::transcript_notice::TranscriptNotice;
 use crate::domain::turn_prompt::{TurnPrompt, TurnPromptTextSource};
 use crate::infra::db::AppRepositories;
@@ -60,6 +62,59 @@ pub(super) fn live_transcript_source(
     })
 }
 
+/// Approval handler that parks provider requests in the session approval
+/// queue until the user answers the overlay.
+#[derive(Debug)]
+struct SessionToolApproval {
+    app_event_tx: mpsc::UnboundedSender<AppEvent>,
+    session_id: SessionId,
+    session_update_versions: SessionUpdateVersionMap,
+    tool_approvals: Arc<Mutex<ToolApprovalQueue>>,
+}
+
+impl ToolApprovalHandler for SessionToolApproval {
+    fn request_approval(&self, request: ToolApprovalRequest) -> AgentFuture<ToolApprovalDecision> {
+        let receiver = self
+            .tool_approvals
+            .lock()
+            .ok()
+            .map(|mut tool_approvals| tool_approvals.push(request));
+        SessionTaskService::emit_session_updated(
+            &self.app_event_tx,
+            &self.session_update_versions,
+            &self.session_id,
+        );
+        let app_event_tx = self.app_event_tx.clone();
+        let session_id = self.session_id.clone();
+        let session_update_versions = Arc::clone(&self.session_update_versions);
+
+        Box::pin(async move {
+            let decision = match receiver {
+                Some(receiver) => receiver.await.unwrap_or(ToolApprovalDecision::Deny),
+                None => ToolApprovalDecision::Deny,
+            };
+            SessionTaskService::emit_session_updated(
+                &app_event_tx,
+                &session_update_versions,
+                &session_id,
+            );
+
+            decision
+        })
+    }
+}
+
+/// Builds the approval handler that routes `Ask` requests to the session
+/// view.
+fn tool_approval_source(context: &SessionWorkerContext) -> Arc<dyn ToolApprovalHandler> {
+    Arc::new(SessionToolApproval {
+        app_event_tx: context.app_event_tx.clone(),
+        session_id: context.session_id.clone(),
+        session_update_versions: Arc::clone(&context.session_update_versions),
+        tool_approvals: Arc::clone(&context.tool_approvals),
+    })
+}
+
 /// Main-checkout tracked-file status captured before one provider turn.
 struct MainCheckoutSnapshot {
     main_repo_root: PathBuf,
@@ -181,7 +236,7 @@ pub(super) async fn run_channel_turn(
     let session_project_id = load_session_project_id(&context.db, &context.session_id).await;
     let permission_mode = match load_session_permission_mode(&context.db, &context.session_id).await
     {
-        Ok(permission_mode) => permission_mode,
+        Ok(permission_mode) => permission_mode.for_agent_kind(turn_metadata.session_agent.kind()),
         Err(error) => {
             return finalize_turn_setup_failure(
                 context,
@@ -219,6 +274,9 @@ pub(super) async fn run_channel_turn(
         reasoning_level,
         request_kind: request_kind.clone(),
         speed_mode,
+        tool_approval: permission_mode
+            .asks_for_approval()
+            .then(|| tool_approval_source(context)),
     };
 
     let (event_tx, event_rx) = mpsc::unbounded_channel::<TurnEvent>();
@@ -227,6 +285,7 @@ pub(super) async fn run_channel_turn(
         context.app_event_tx.clone(),
         context.session_id.clone(),
         Arc::clone(&context.child_pid),
+        Some(TurnActivityLog::from_context(context)),
     ));
 
     spawn_turn_title_generation(
@@ -247,6 +306,7 @@ pub(super) async fn run_channel_turn(
 
     let _ = consumer.await;
 
+    archive_controller_edits(context).await;
     let turn_result =
         add_main_checkout_warning(context, main_checkout_snapshot.as_ref(), turn_result).await;
     let finalizer_context = post_turn::TurnFinalizerContext::from_worker(context);
@@ -374,7 +434,7 @@ pub(super) async fn load_session_permission_mode(
             reason,
         })?
         .unwrap_or_default();
-    if role == SessionRole::OrchestrationResearcher {
+    if role.enforces_read_only() {
         return Ok(PermissionMode::ReadOnly);
     }
 
@@ -626,10 +686,35 @@ pub(super) async fn load_session_speed_mode(
         .unwrap_or_default()
 }
 
+/// Transcript and storage handles that record one session turn's activity.
+pub(super) struct TurnActivityLog {
+    /// Repository used to persist activity rows alongside the turn.
+    pub(super) db: AppRepositories,
+    /// Per-session render versions bumped after each recorded activity.
+    pub(super) session_update_versions: SessionUpdateVersionMap,
+    /// Live transcript buffer rendered while the turn is still running.
+    pub(super) transcript: Arc<Mutex<SessionTranscript>>,
+}
+
+impl TurnActivityLog {
+    /// Builds an activity log that writes into the worker's session
+    /// transcript.
+    pub(super) fn from_context(context: &SessionWorkerContext) -> Self {
+        Self {
+            db: context.db.clone(),
+            session_update_versions: context.session_update_versions.clone(),
+            transcript: Arc::clone(&context.transcript),
+        }
+    }
+}
+
 /// Consumes [`TurnEvent`]s from `event_rx` and applies their side effects.
 ///
 /// - [`TurnEvent::ThoughtDelta`]: coalesces immediately ready thought bursts
 ///   and updates the transient thinking loader text with the latest message.
+/// - [`TurnEvent::Activity`]: appends and persists one transcript activity row
+///   when `activity_log` is present; utility prompts pass `None` because they
+///   do not own a visible turn.
 /// - [`TurnEvent::PidUpdate`]: writes the new PID into `child_pid`.
 /// - [`TurnEvent::Completed`] / [`TurnEvent::Failed`]: reserved; ignored here
 ///   because completion is signalled by `run_turn`'s return value.
@@ -638,8 +723,10 @@ pub(super) async fn consume_turn_events(
     app_event_tx: mpsc::UnboundedSender<AppEvent>,
     session_id: SessionId,
     child_pid: Arc<Mutex<Option<u32>>>,
+    activity_log: Option<TurnActivityLog>,
 ) {
     let mut active_progress: Option<String> = None;
+    let mut coalesced_activities = Vec::new();
 
     while let Some(event) = event_rx.recv().await {
         match event {
@@ -652,13 +739,30 @@ pub(super) async fn consume_turn_events(
                     child_pid.as_ref(),
                     thought,
                     &session_id,
+                    &mut coalesced_activities,
                 );
-                if active_progress.as_deref() == Some(thought.as_str()) {
-                    continue;
+                if active_progress.as_deref() != Some(thought.as_str()) {
+                    active_progress = Some(thought.clone());
+                    SessionTaskService::set_session_progress(
+                        &app_event_tx,
+                        &session_id,
+                        Some(thought),
+                    );
                 }
 
-                active_progress = Some(thought.clone());
-                SessionTaskService::set_session_progress(&app_event_tx, &session_id, Some(thought));
+                for activity in coalesced_activities.drain(..) {
+                    record_turn_activity(
+                        activity_log.as_ref(),
+                        &app_event_tx,
+                        &session_id,
+                        &activity,
+                    )
+                    .await;
+                }
+            }
+            TurnEvent::Activity(activity) => {
+                record_turn_activity(activity_log.as_ref(), &app_event_tx, &session_id, &activity)
+                    .await;
             }
             TurnEvent::PidUpdate(pid) => {
                 set_child_pid(child_pid.as_ref(), pid);
@@ -678,14 +782,16 @@ pub(super) async fn consume_turn_events(
 /// Coalesces immediately ready turn progress events before app-event enqueue.
 ///
 /// PID updates are still applied as they are encountered, while repeated
-/// thought deltas collapse to the newest normalized message. Completion events
-/// remain ignored here because turn completion is handled by the channel
-/// result.
+/// thought deltas collapse to the newest normalized message. Activity events
+/// are never collapsed; they are collected into `activities` in arrival order
+/// so the caller can record each one. Completion events remain ignored here
+/// because turn completion is handled by the channel result.
 fn coalesce_ready_turn_progress_events(
     event_rx: &mut mpsc::UnboundedReceiver<TurnEvent>,
     child_pid: &Mutex<Option<u32>>,
     initial_thought: String,
     session_id: &SessionId,
+    activities: &mut Vec<TurnActivity>,
 ) -> String {
     let mut latest_thought = initial_thought;
     let mut coalesced_events = 0;
@@ -702,6 +808,7 @@ fn coalesce_ready_turn_progress_events(
                     latest_thought = thought;
                 }
             }
+            TurnEvent::Activity(activity) => activities.push(activity),
             TurnEvent::PidUpdate(pid) => set_child_pid(child_pid, pid),
             TurnEvent::Completed { .. } | TurnEvent::Failed(_) => {}
         }
@@ -721,6 +828,29 @@ fn coalesce_ready_turn_progress_events(
     latest_thought
 }
 
+/// Appends one structured activity to the turn transcript when the consumer
+/// owns a visible turn.
+async fn record_turn_activity(
+    activity_log: Option<&TurnActivityLog>,
+    app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+    session_id: &SessionId,
+    activity: &TurnActivity,
+) {
+    let Some(activity_log) = activity_log else {
+        return;
+    };
+
+    SessionTaskService::append_turn_activity(
+        &activity_log.transcript,
+        &activity_log.db,
+        app_event_tx,
+        &activity_log.session_update_versions,
+        session_id,
+        activity,
+    )
+    .await;
+}
+
 /// Records the latest child process id observed from a provider turn stream.
 fn set_child_pid(child_pid: &Mutex<Option<u32>>, pid: Option<u32>) {
     // Sync critical section (single assignment, no `.await`);
@@ -746,7 +876,7 @@ async fn add_main_checkout_warning(
     };
     match main_checkout_snapshot.dirty_warning(context).await {
         Ok(Some(warning)) => {
-            append_main_checkout_warning(context, warning).await;
+            append_workflow_warning(context, warning).await;
 
             Ok(result)
         }
@@ -792,8 +922,8 @@ fn fresh_turn_cancel_token(
     Ok(guard.clone())
 }
 
-/// Appends one main-checkout warning to the live and persisted transcript.
-async fn append_main_checkout_warning(context: &SessionWorkerContext, warning: String) {
+/// Appends one workflow warning to the live and persisted transcript.
+async fn append_workflow_warning(context: &SessionWorkerContext, warning: String) {
     SessionTaskService::append_workflow_notice(
         &context.transcript,
         &context.db,
@@ -805,6 +935,89 @@ async fn append_main_checkout_warning(context: &SessionWorkerContext, warning: S
     .await;
 }
 
+/// Archives controller worktree mutations as evidence after one provider
+/// turn.
+///
+/// Controllers run under the read-only permission policy, so a non-empty
+/// diff against the base branch means the provider bypassed enforcement. The
+/// diff is persisted as the session's archived diff and a transcript warning
+/// is appended whenever the evidence changes. Controllers never auto-commit,
+/// so the mutation stays visible in the worktree for inspection. Failures are
+/// logged and never fail the turn.
+async fn archive_controller_edits(context: &SessionWorkerContext) {
+    if load_session_role(&context.db, &context.session_id).await != SessionRole::Orchestrator {
+        return;
+    }
+    let base_branch = match context
+        .db
+        .sessions()
+        .get_session_base_branch(&context.session_id)
+        .await
+    {
+        Ok(Some(base_branch)) => base_branch,
+        Ok(None) => return,
+        Err(error) => {
+            warn!(
+                session_id = %context.session_id,
+                %error,
+                "Failed to load controller base branch before mutation check"
+            );
+
+            return;
+        }
+    };
+    let diff = match context
+        .git_client
+        .diff(context.folder.clone(), base_branch)
+        .await
+    {
+        Ok(diff) => diff,
+        Err(error) => {
+            warn!(
+                session_id = %context.session_id,
+                %error,
+                "Failed to inspect controller worktree for mutations"
+            );
+
+            return;
+        }
+    };
+    if diff.trim().is_empty() {
+        return;
+    }
+    let previous_diff = context
+        .db
+        .sessions()
+        .load_session_archived_diff(&context.session_id)
+        .await
+        .ok()
+        .flatten();
+    if previous_diff.as_deref() == Some(diff.as_str()) {
+        return;
+    }
+    if let Err(error) = context
+        .db
+        .sessions()
+        .update_session_archived_diff(&context.session_id, Some(diff))
+        .await
+    {
+        warn!(
+            session_id = %context.session_id,
+            %error,
+            "Failed to persist controller mutation evidence"
+        );
+    }
+
+    append_workflow_warning(
+        context,
+        TranscriptNotice::ControllerEditWarning.format(
+            "The read-only controller changed its worktree during this turn. The diff was \
+             archived as evidence and the changes were not committed.",
+        ),
+    )
+    .await;
+}
+
 /// Spawns read-only title generation while a session still has its
 /// provisional title.
 async fn spawn_turn_title_generation(
@@ -879,8 +1092,83 @@ fn normalize_thinking_stream_text(text: &str) -> Option<String> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::VecDeque;
+
+    use ag_agent::MockAgentChannel;
+    use ag_git::MockGitClient;
+
     use super::*;
+    use crate::domain::agent::{AgentModel, SpeedMode};
     use crate::infra::db::{DbError, PersistedSessionCreation};
+    use crate::infra::fs::MockFsClient;
+    use crate::infra::personality::RealPersonalityCatalogClient;
+
+    /// Inserts one managed session with `role` under a fresh project.
+    async fn insert_role_session(repositories: &AppRepositories, session_id: &str, role: &str) {
+        let project_id = repositories
+            .projects()
+            .upsert_project("/tmp/project", Some("main".to_string()))
+            .await
+            .expect("failed to upsert project");
+        repositories
+            .sessions()
+            .insert_session_with_agent(PersistedSessionCreation {
+                agent: "codex",
+                base_branch: "main",
+                id: session_id,
+                is_draft: false,
+                model: "gpt-5.6-sol",
+                orchestration_task_id: None,
+                parent_session_id: None,
+                permission_mode: PermissionMode::AutoEdit,
+                personality_id: None,
+                project_id,
+                reasoning_level: ReasoningLevel::default(),
+                role: Some(role),
+                speed_mode: SpeedMode::Normal,
+                status: "InProgress",
+            })
+            .await
+            .expect("failed to insert role session");
+    }
+
+    /// Builds a worker context whose only live boundary is `git_client`.
+    fn controller_worker_context(
+        db: AppRepositories,
+        git_client: MockGitClient,
+        session_id: &str,
+    ) -> SessionWorkerContext {
+        SessionWorkerContext {
+            app_event_tx: mpsc::unbounded_channel().0,
+            branch_operation_lock: Arc::new(tokio::sync::Mutex::new(())),
+            cancel_token: Arc::new(Mutex::new(CancellationToken::new())),
+            channel: Arc::new(MockAgentChannel::new()),
+            child_pid: Arc::new(Mutex::new(None)),
+            clock: Arc::new(crate::infra::clock::RealClock),
+            db,
+            folder: PathBuf::from("/tmp/controller"),
+            fs_client: Arc::new(MockFsClient::new()),
+            git_client: Arc::new(git_client),
+            personality_catalog_client: Arc::new(RealPersonalityCatalogClient),
+            queued_messages: Arc::new(Mutex::new(VecDeque::new())),
+            review_request_client: Arc::new(ag_forge::MockReviewRequestClient::new()),
+            session_update_versions: Arc::default(),
+            session_id: session_id.into(),
+            session_agent: AgentSelection::new(AgentKind::Codex, AgentModel::Gpt56Sol),
+            status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
+            transcript: Arc::new(Mutex::new(SessionTranscript::default())),
+        }
+    }
+
+    fn transcript_text(context: &SessionWorkerContext) -> String {
+        context
+            .transcript
+            .lock()
+            .ok()
+            .and_then(|transcript| transcript.replay_text())
+            .unwrap_or_default()
+    }
 
     #[test]
     fn read_only_chat_prompt_redirects_write_access_requests_to_mode_shortcut() {
@@ -893,7 +1181,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn persisted_research_role_selects_read_only_permission_mode() {
+    async fn persisted_read_only_roles_select_read_only_permission_mode() {
         // Arrange
         let repositories = AppRepositories::in_memory().await.expect("db should open");
         let project_id = repositories
@@ -942,6 +1230,7 @@ mod tests {
             })
             .await
             .expect("failed to insert research session");
+        insert_role_session(&repositories, "controller", "Orchestrator").await;
 
         // Act
         let worker_mode = load_session_permission_mode(&repositories, "worker")
@@ -953,6 +1242,9 @@ mod tests {
         let research_mode = load_session_permission_mode(&repositories, "researcher")
             .await
             .expect("research mode should load");
+        let controller_mode = load_session_permission_mode(&repositories, "controller")
+            .await
+            .expect("controller mode should load");
         let missing_error = load_session_permission_mode(&repositories, "missing")
             .await
             .expect_err("missing session should fail");
@@ -961,6 +1253,7 @@ mod tests {
         assert_eq!(worker_mode, PermissionMode::AutoEdit);
         assert_eq!(read_only_worker_mode, PermissionMode::ReadOnly);
         assert_eq!(research_mode, PermissionMode::ReadOnly);
+        assert_eq!(controller_mode, PermissionMode::ReadOnly);
         assert!(matches!(missing_error, SessionError::NotFound));
     }
 
@@ -1042,4 +1335,69 @@ mod tests {
         // Assert
         assert_eq!(reasoning_level, ReasoningLevel::High);
     }
+
+    #[tokio::test]
+    async fn controller_edits_are_archived_once_with_transcript_warning() {
+        // Arrange
+        let db = AppRepositories::in_memory().await.expect("db should open");
+        insert_role_session(&db, "controller", "Orchestrator").await;
+        let mut git_client = MockGitClient::new();
+        git_client.expect_diff().times(2).returning(|folder, base| {
+            assert_eq!(folder, PathBuf::from("/tmp/controller"));
+            assert_eq!(base, "main");
+
+            Box::pin(async { Ok("diff --git a/plan.md b/plan.md\n+edited\n".to_string()) })
+        });
+        let context = controller_worker_context(db.clone(), git_client, "controller");
+
+        // Act
+        archive_controller_edits(&context).await;
+        archive_controller_edits(&context).await;
+        let archived_diff = db
+            .sessions()
+            .load_session_archived_diff("controller")
+            .await
+            .expect("failed to load archived diff");
+
+        // Assert
+        assert_eq!(
+            archived_diff.as_deref(),
+            Some("diff --git a/plan.md b/plan.md\n+edited\n")
+        );
+        assert_eq!(
+            transcript_text(&context)
+                .matches("[Controller Edit Warning]")
+                .count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn controller_edit_check_skips_clean_controllers_and_other_roles() {
+        // Arrange
+        let db = AppRepositories::in_memory().await.expect("db should open");
+        insert_role_session(&db, "controller", "Orchestrator").await;
+        insert_role_session(&db, "worker", "OrchestrationWorker").await;
+        let mut git_client = MockGitClient::new();
+        git_client
+            .expect_diff()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(String::new()) }));
+        let controller = controller_worker_context(db.clone(), git_client, "controller");
+        let worker = controller_worker_context(db.clone(), MockGitClient::new(), "worker");
+
+        // Act
+        archive_controller_edits(&controller).await;
+        archive_controller_edits(&worker).await;
+        let controller_diff = db
+            .sessions()
+            .load_session_archived_diff("controller")
+            .await
+            .expect("failed to load controller archived diff");
+
+        // Assert
+        assert_eq!(controller_diff, None);
+        assert!(!transcript_text(&controller).contains("[Controller Edit Warning]"));
+        assert!(!transcript_text(&worker).contains("[Controller Edit Warning]"));
+    }
 }
@@ -39,6 +39,7 @@ use crate::domain::session::{
     PublishBranchAction, QueuedMessage, ReviewRequest, SessionId, SessionStats, Status,
 };
 use crate::domain::session_message::{SessionMessageKind, SessionTranscript};
+use crate::domain::tool_approval::ToolApprovalQueue;
 use crate::domain::transcript_notice::TranscriptNotice;
 use crate::domain::turn_prompt::TurnPrompt;
 use crate::infra::db::{AppRepositories, OperationRepository, SessionOperationRow};
@@ -89,8 +90,9 @@ pub(super) enum SessionCommand {
     /// Publishes the session branch and creates or refreshes its forge review
     /// request after earlier work on this worker has completed.
     CreateReviewRequest {
-        /// Session snapshot captured when the action was accepted.
-        branch_publish_session: BranchPublishTaskSession,
+        /// Session snapshot captured when the action was accepted, boxed to
+        /// keep queued commands small.
+        branch_publish_session: Box<BranchPublishTaskSession>,
         /// Persisted operation identifier.
         operation_id: String,
         /// Optional user-selected remote branch name.
@@ -265,6 +267,9 @@ pub(super) struct SessionWorkerContext {
     /// Agent provider and model selected for this session.
     pub(super) session_agent: AgentSelection,
     pub(super) status: Arc<Mutex<Status>>,
+    /// Shared with [`SessionHandles`] so `Ask` turns can block on the
+    /// approval overlay.
+    pub(super) tool_approvals: Arc<Mutex<ToolApprovalQueue>>,
     pub(super) transcript: Arc<Mutex<SessionTranscript>>,
 }
 
@@ -406,6 +411,7 @@ impl SessionWorkerRebaseAssistClient {
             reasoning_level,
             request_kind: AgentRequestKind::UtilityPrompt,
             speed_mode,
+            tool_approval: None,
         };
         let (event_tx, event_rx) = mpsc::unbounded_channel::<TurnEvent>();
         let consumer = tokio::spawn(turn::consume_turn_events(
@@ -413,6 +419,7 @@ impl SessionWorkerRebaseAssistClient {
             self.app_event_tx.clone(),
             self.session_id.clone(),
             Arc::clone(&self.child_pid),
+            None,
         ));
 
         let turn_result = self
@@ -611,6 +618,7 @@ pub(super) struct SessionWorkerRuntime {
     session_agent: AgentSelection,
     session_id: SessionId,
     status: Arc<Mutex<Status>>,
+    tool_approvals: Arc<Mutex<ToolApprovalQueue>>,
     transcript: Arc<Mutex<SessionTranscript>>,
 }
 
@@ -638,6 +646,9 @@ impl SessionWorkerService {
     /// Marks unfinished operations from previous process runs as failed and
     /// closes any open active-work timing window at `timestamp_seconds`.
     ///
+    /// Persisted merge-queue entries are returned to `Queued` so the app can
+    /// resume them once their project loads.
+    ///
     /// # Errors
     /// Returns an error when loading operations, cleaning interrupted rebases,
     /// reconciling session status, or recording interrupted operations fails.
@@ -647,6 +658,13 @@ impl SessionWorkerService {
         git_client: Arc<dyn GitClient>,
         timestamp_seconds: i64,
     ) -> Result<(), SessionError> {
+        Self::requeue_merges_from_previous_run(
+            db,
+            base_path,
+            git_client.as_ref(),
+            timestamp_seconds,
+        )
+        .await?;
         let unfinished_operations = db.operations().load_unfinished_session_operations().await?;
         Self::abort_rebase_operations_from_previous_run(
             base_path,
@@ -677,10 +695,55 @@ impl SessionWorkerService {
         Ok(())
     }
 
+    /// Returns persisted merge-queue sessions to `Queued` after a restart.
+    ///
+    /// Merges interrupted mid-flight have their preparatory rebase aborted so
+    /// the resumed merge starts from a clean worktree. Entries whose session
+    /// already left the queue, for example after a crash between the squash
+    /// merge and queue cleanup, are dropped.
+    ///
+    /// # Errors
+    /// Returns an error when loading or updating queue state fails, or Git
+    /// cannot inspect or abort interrupted rebase state.
+    async fn requeue_merges_from_previous_run(
+        db: &AppRepositories,
+        base_path: &Path,
+        git_client: &dyn GitClient,
+        timestamp_seconds: i64,
+    ) -> Result<(), SessionError> {
+        let queued_status = Status::Queued.to_string();
+        for row in db.merge_queue().load_merge_queue().await? {
+            if row.status == queued_status {
+                continue;
+            }
+            if row.status != Status::Merging.to_string() {
+                db.merge_queue()
+                    .remove_merge_session(&row.session_id)
+                    .await?;
+
+                continue;
+            }
+
+            let folder = session_folder(base_path, &row.session_id);
+            if git_client.is_rebase_in_progress(folder.clone()).await? {
+                git_client.abort_rebase(folder).await?;
+            }
+            db.sessions()
+                .update_session_status_with_timing_at(
+                    &row.session_id,
+                    &queued_status,
+                    timestamp_seconds,
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
     /// Aborts stale git rebase state left by interrupted worker operations.
     ///
-    /// Only worker-backed rebase operations are handled here because merge
-    /// tasks are not yet persisted in `session_operation`.
+    /// Only worker-backed rebase operations are handled here; interrupted
+    /// merges are recovered from the persisted merge queue.
     ///
     /// # Errors
     /// Returns an error when Git cannot inspect or abort interrupted rebase
@@ -850,6 +913,7 @@ impl SessionWorkerService {
             session_id: runtime.session_id.clone(),
             session_agent: runtime.session_agent,
             status: Arc::clone(&runtime.status),
+            tool_approvals: Arc::clone(&runtime.tool_approvals),
             transcript: Arc::clone(&runtime.transcript),
         };
         let (sender, receiver) = mpsc::unbounded_channel();
@@ -1173,7 +1237,7 @@ impl SessionWorkerService {
             } => {
                 Self::run_create_review_request_command(
                     context,
-                    branch_publish_session,
+                    *branch_publish_session,
                     remote_branch_name,
                     response,
                 )
@@ -1445,7 +1509,7 @@ impl SessionManager {
         let status = branch_publish_session.status;
         let response = response_tx.map(|response_tx| Arc::new(Mutex::new(Some(response_tx))));
         let command = SessionCommand::CreateReviewRequest {
-            branch_publish_session,
+            branch_publish_session: Box::new(branch_publish_session),
             operation_id: Uuid::new_v4().to_string(),
             remote_branch_name,
             response: response.clone(),
@@ -1525,6 +1589,7 @@ impl SessionManager {
             session_id: session.id.clone(),
             session_agent: session.agent,
             status: Arc::clone(&handles.status),
+            tool_approvals: handles.tool_approval_queue(),
             transcript: Arc::clone(&handles.transcript),
         })
     }
@@ -1568,7 +1633,7 @@ mod tests {
         status_update_after_turn_result,
     };
     use super::super::turn::{
-        consume_turn_events, resolve_turn_personality, run_channel_turn,
+        TurnActivityLog, consume_turn_events, resolve_turn_personality, run_channel_turn,
         run_turn_with_cancellation, terminate_child_process,
     };
     use super::*;
@@ -1576,6 +1641,7 @@ mod tests {
     use crate::domain::personality::Personality;
     use crate::domain::question::QuestionItem;
     use crate::domain::session::{PublishedBranchSyncStatus, ReviewRequest, ReviewRequestState};
+    use crate::domain::session_message::TurnActivity;
     use crate::infra::db::{AppRepositories, PersistedSessionCreation, SessionTurnMetadata};
     use crate::infra::fs;
     use crate::infra::personality::{MockPersonalityCatalogClient, RealPersonalityCatalogClient};
@@ -1872,14 +1938,15 @@ mod tests {
     fn test_session_command_kind_values() {
         // Arrange
         let review_request_command = SessionCommand::CreateReviewRequest {
-            branch_publish_session: BranchPublishTaskSession {
+            branch_publish_session: Box::new(BranchPublishTaskSession {
                 base_branch: "main".to_string(),
                 folder: PathBuf::new(),
                 id: "sess1".into(),
                 published_upstream_ref: None,
                 review_request: None,
+                review_request_options: forge::ReviewRequestCreateOptions::default(),
                 status: Status::Review,
-            },
+            }),
             operation_id: "op-review-request".to_string(),
             remote_branch_name: None,
             response: None,
@@ -1975,14 +2042,15 @@ mod tests {
         context.app_event_tx = app_event_tx;
         let (response_tx, response_rx) = oneshot::channel();
         let command = SessionCommand::CreateReviewRequest {
-            branch_publish_session: BranchPublishTaskSession {
+            branch_publish_session: Box::new(BranchPublishTaskSession {
                 base_branch: "main".to_string(),
                 folder: context.folder.clone(),
                 id: context.session_id.clone(),
                 published_upstream_ref: None,
                 review_request: None,
+                review_request_options: forge::ReviewRequestCreateOptions::default(),
                 status: Status::InProgress,
-            },
+            }),
             operation_id: "op-review-request".to_string(),
             remote_branch_name: None,
             response: Some(Arc::new(Mutex::new(Some(response_tx)))),
@@ -2051,14 +2119,15 @@ mod tests {
         let (app_event_tx, mut app_event_rx) = mpsc::unbounded_channel();
         context.app_event_tx = app_event_tx;
         let command = SessionCommand::CreateReviewRequest {
-            branch_publish_session: BranchPublishTaskSession {
+            branch_publish_session: Box::new(BranchPublishTaskSession {
                 base_branch: "main".to_string(),
                 folder: context.folder.clone(),
                 id: context.session_id.clone(),
                 published_upstream_ref: None,
                 review_request: None,
+                review_request_options: forge::ReviewRequestCreateOptions::default(),
                 status: Status::Review,
-            },
+            }),
             operation_id: "op-review-request".to_string(),
             remote_branch_name: None,
             response: Some(Arc::new(Mutex::new(Some(response_tx)))),
@@ -2397,6 +2466,7 @@ mod tests {
             session_id: "sess1".into(),
             session_agent: AgentSelection::new(AgentKind::Antigravity, AgentModel::Gemini37Flash),
             status: Arc::clone(&status),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
         let prompt = turn_prompt_with_attachment(attachment_path);
 
@@ -2451,6 +2521,7 @@ mod tests {
             app_event_tx,
             "session-1".into(),
             Arc::clone(&child_pid),
+            None,
         )
         .await;
 
@@ -2518,6 +2589,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         cancel_token_after_short_delay(Arc::clone(&cancel_token));
@@ -2658,6 +2730,7 @@ mod tests {
             session_id: "sess1".into(),
             session_agent,
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         // Act — the turn should complete normally because
@@ -2761,6 +2834,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         // Act
@@ -2865,6 +2939,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         // Act
@@ -2956,6 +3031,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         // Act
@@ -3053,6 +3129,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         // Act
@@ -3359,6 +3436,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         let req = TurnRequest {
@@ -3372,6 +3450,7 @@ mod tests {
             reasoning_level: ReasoningLevel::default(),
             request_kind: AgentRequestKind::SessionStart,
             speed_mode: crate::domain::agent::SpeedMode::default(),
+            tool_approval: None,
         };
 
         // Act — pass the pre-cancelled token directly.
@@ -3435,6 +3514,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         let req = TurnRequest {
@@ -3448,6 +3528,7 @@ mod tests {
             reasoning_level: ReasoningLevel::default(),
             request_kind: AgentRequestKind::SessionStart,
             speed_mode: crate::domain::agent::SpeedMode::default(),
+            tool_approval: None,
         };
 
         // Spawn a task that cancels the token after a small delay so the
@@ -3508,6 +3589,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         // Act
@@ -3554,6 +3636,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         // Act — should not panic or error.
@@ -3581,7 +3664,7 @@ mod tests {
         drop(event_tx);
 
         // Act
-        consume_turn_events(event_rx, app_event_tx, "session-1".into(), child_pid).await;
+        consume_turn_events(event_rx, app_event_tx, "session-1".into(), child_pid, None).await;
 
         let events = std::iter::from_fn(|| app_event_rx.try_recv().ok()).collect::<Vec<_>>();
 
@@ -3618,7 +3701,7 @@ mod tests {
         drop(event_tx);
 
         // Act
-        consume_turn_events(event_rx, app_event_tx, "session-1".into(), child_pid).await;
+        consume_turn_events(event_rx, app_event_tx, "session-1".into(), child_pid, None).await;
 
         let events = std::iter::from_fn(|| app_event_rx.try_recv().ok()).collect::<Vec<_>>();
 
@@ -3638,6 +3721,75 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    /// Verifies activity events, including ones drained while coalescing a
+    /// thought burst, reach the live transcript and the message store.
+    async fn test_consume_turn_events_records_activity_during_thought_bursts() {
+        // Arrange
+        let db = AppRepositories::in_memory().await.expect("db should open");
+        insert_in_progress_research_session(&db).await;
+        let transcript = Arc::new(Mutex::new(SessionTranscript::default()));
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (app_event_tx, _app_event_rx) = mpsc::unbounded_channel();
+        let started = TurnActivity::ToolStarted {
+            call_id: Some("call-1".to_string()),
+            command: Some("cargo test".to_string()),
+            tool: "command".to_string(),
+        };
+        let patched = TurnActivity::FilePatched {
+            added_lines: 2,
+            deleted_lines: 1,
+            path: "src/lib.rs".to_string(),
+        };
+        event_tx
+            .send(TurnEvent::ThoughtDelta("Running tests".to_string()))
+            .expect("failed to send thought delta");
+        event_tx
+            .send(TurnEvent::Activity(started.clone()))
+            .expect("failed to send activity");
+        event_tx
+            .send(TurnEvent::ThoughtDelta("Editing".to_string()))
+            .expect("failed to send thought delta");
+        event_tx
+            .send(TurnEvent::Activity(patched.clone()))
+            .expect("failed to send activity");
+        drop(event_tx);
+
+        // Act
+        consume_turn_events(
+            event_rx,
+            app_event_tx,
+            "sess1".into(),
+            Arc::new(Mutex::new(None)),
+            Some(TurnActivityLog {
+                db: db.clone(),
+                session_update_versions: SessionUpdateVersionMap::default(),
+                transcript: Arc::clone(&transcript),
+            }),
+        )
+        .await;
+
+        // Assert
+        let live_activities = transcript
+            .lock()
+            .expect("transcript lock poisoned")
+            .messages()
+            .iter()
+            .filter(|message| message.kind == SessionMessageKind::TurnActivity)
+            .filter_map(|message| TurnActivity::decode(&message.content))
+            .collect::<Vec<_>>();
+        let persisted_kinds = db
+            .sessions()
+            .load_session_messages("sess1")
+            .await
+            .expect("failed to load session messages")
+            .into_iter()
+            .map(|row| row.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(live_activities, vec![started, patched]);
+        assert_eq!(persisted_kinds, vec!["turn_activity", "turn_activity"]);
+    }
+
     #[tokio::test]
     /// Verifies turn summaries are persisted to the database when the agent
     /// returns them.
@@ -3693,6 +3845,7 @@ mod tests {
             session_id: "sess1".into(),
             session_agent,
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
         let turn_result = Ok(TurnResult {
             assistant_message: AgentResponse {
@@ -3788,6 +3941,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
         let mut turn_result = successful_turn_result("Implemented the change.");
         turn_result.assistant_message.summary = Some(AgentResponseSummary {
@@ -3874,6 +4028,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         // Act
@@ -4410,6 +4565,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
         let turn_result = Ok(successful_turn_result("Implemented the change."));
 
@@ -4485,6 +4641,7 @@ mod tests {
             session_id: "sess1".into(),
             session_agent,
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
         let turn_result = fixed_review_turn_result();
 
@@ -4572,6 +4729,7 @@ mod tests {
             session_id: "sess1".into(),
             session_agent,
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
         let turn_result = fixed_review_turn_result();
 
@@ -4680,6 +4838,7 @@ mod tests {
             session_id: "sess1".into(),
             session_agent,
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         // Act
@@ -4748,6 +4907,7 @@ mod tests {
             session_id: "sess1".into(),
             session_agent,
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
         let turn_result = TurnResult {
             assistant_message: AgentResponse {
@@ -4845,6 +5005,7 @@ mod tests {
             session_id: "sess1".into(),
             session_agent,
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
         let turn_result = TurnResult {
             assistant_message: AgentResponse {
@@ -4950,6 +5111,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
         let turn_result = Ok(TurnResult {
             assistant_message: AgentResponse {
@@ -5048,6 +5210,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
         let turn_result = Ok(TurnResult {
             assistant_message: AgentResponse {
@@ -5151,6 +5314,7 @@ mod tests {
             session_id: "sess1".into(),
             session_agent,
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
         let turn_result = Ok(successful_turn_result("Implemented the change."));
 
@@ -5249,6 +5413,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
         let turn_result = Ok(TurnResult {
             assistant_message: AgentResponse {
@@ -5358,6 +5523,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
         let turn_result = Ok(TurnResult {
             assistant_message: AgentResponse {
@@ -5447,6 +5613,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
         let turn_result = Ok(TurnResult {
             assistant_message: AgentResponse {
@@ -5705,6 +5872,7 @@ mod tests {
                 AgentModel::Gpt56Sol,
             ),
             status: Arc::clone(&status),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         RebaseAssistWorkerHarness {
@@ -5814,6 +5982,74 @@ mod tests {
         assert_eq!(*context.status.lock().expect("status lock"), Status::Review);
     }
 
+    #[tokio::test]
+    /// Verifies restart recovery requeues interrupted merges, aborts their
+    /// stale rebase, and drops queue entries whose session already left the
+    /// queue.
+    async fn test_fail_unfinished_operations_from_previous_run_requeues_persisted_merges() {
+        // Arrange
+        let base_dir = tempdir().expect("failed to create temp dir");
+        let db = AppRepositories::in_memory().await.expect("db should open");
+        let project_id = db
+            .projects()
+            .upsert_project("/tmp/project", Some("main".to_string()))
+            .await
+            .expect("failed to upsert project");
+        for (session_id, status) in [
+            ("merging", Status::Merging),
+            ("queued", Status::Queued),
+            ("done", Status::Done),
+        ] {
+            db.sessions()
+                .insert_session(
+                    session_id,
+                    "gemini-3.7-flash",
+                    "main",
+                    &status.to_string(),
+                    project_id,
+                )
+                .await
+                .expect("failed to insert session");
+            db.merge_queue()
+                .enqueue_merge_session(session_id)
+                .await
+                .expect("failed to enqueue merge");
+        }
+        let mut mock_git_client = MockGitClient::new();
+        mock_git_client
+            .expect_is_rebase_in_progress()
+            .once()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        mock_git_client
+            .expect_abort_rebase()
+            .once()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        // Act
+        let result = SessionWorkerService::fail_unfinished_operations_from_previous_run_at(
+            &db,
+            base_dir.path(),
+            Arc::new(mock_git_client),
+            300,
+        )
+        .await;
+        let queue = db
+            .merge_queue()
+            .load_merge_queue()
+            .await
+            .expect("failed to load merge queue");
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(
+            queue
+                .iter()
+                .map(|row| (row.session_id.as_str(), row.status.as_str()))
+                .collect::<Vec<_>>(),
+            [("merging", "Queued"), ("queued", "Queued")]
+        );
+    }
+
     #[tokio::test]
     /// Verifies recovery stops immediately when unfinished operations cannot
     /// be loaded from storage.
@@ -6195,6 +6431,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         // Act
@@ -6269,6 +6506,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         // Act
@@ -6357,6 +6595,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         // Act — the new operation should proceed despite the old
@@ -6466,6 +6705,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(status)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         };
 
         (context, db, queue_handle, base_dir)
@@ -6499,6 +6739,7 @@ mod tests {
                 AgentModel::Gemini37Flash,
             ),
             status: Arc::new(Mutex::new(Status::InProgress)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
         }
     }
 
@@ -6750,6 +6991,10 @@ mod tests {
         mock_git_client
             .expect_diff()
             .returning(|_, _| Box::pin(async { Ok(String::new()) }));
+        mock_git_client
+            .expect_record_turn_checkpoint()
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok("abc1234".to_string()) }));
         mock_git_client
             .expect_push_current_branch_to_remote_branch()
             .times(1)
@@ -10,7 +10,7 @@ use ag_session::{
     AnswerQuestionsRequest, CoordinatorMessageRequest, CoordinatorMessageVisibility,
     CreateSessionMode, CreateSessionRequest, QuestionAnswer, ReviewRequest, ReviewRequestState,
     SessionBackend, SessionError as ApiSessionError, SessionId, SessionMessage, SessionMessageKind,
-    SessionRole, SessionService, SessionSettings, SessionStatus,
+    SessionRole, SessionService, SessionSettings, SessionStatus, SessionSummary,
 };
 use async_trait::async_trait;
 use tokio::sync::oneshot;
@@ -18,20 +18,24 @@ use tokio::sync::oneshot;
 #[cfg(test)]
 use crate::app::branch_publish::{BranchPublishTaskSuccess, review_request_from_publish_result};
 use crate::app::branch_publish::{branch_publish_loading_label, review_request_queued_label};
-use crate::app::orchestration::{OrchestrationApprovalOutcome, child_session_is_stopped};
+use crate::app::orchestration::{
+    OrchestrationApprovalOutcome, OrchestrationBoardAction, child_session_is_stopped,
+};
 use crate::app::session::{
     SessionCreationKind, SessionCreationSettings, migrate_session_off_retired_model,
+    session_branch, session_folder,
 };
 use crate::app::{
     App, AppError, AppEvent, SessionError, SessionRuntimeAccess, SessionRuntimeCommand,
     SessionRuntimeHandle,
 };
 use crate::domain::orchestration::{
-    IntegrationApproach, OrchestrationStatus, OrchestrationTaskStatus,
+    IntegrationApproach, OrchestrationBoard, OrchestrationStatus, OrchestrationTaskStatus,
 };
 use crate::domain::session::{PublishBranchAction, Session};
 use crate::domain::turn_prompt::TurnPrompt;
-use crate::infra::db::{SessionMessageRow, SessionReviewRequestRow, SessionRow};
+use crate::infra::db::{SessionListRow, SessionMessageRow, SessionReviewRequestRow, SessionRow};
+use crate::presentation::app_mode::AppMode;
 
 #[async_trait]
 impl SessionBackend for SessionRuntimeHandle {
@@ -49,6 +53,10 @@ impl SessionBackend for SessionRuntimeHandle {
         SessionRuntimeHandle::get_session(self, session_id).await
     }
 
+    async fn list_sessions(&self, project_id: i64) -> Result<Vec<SessionSummary>, ApiSessionError> {
+        SessionRuntimeHandle::list_sessions(self, project_id).await
+    }
+
     async fn send_message(
         &self,
         session_id: &SessionId,
@@ -134,6 +142,78 @@ impl App {
         detached
     }
 
+    /// Loads the full campaign board for one orchestrator session.
+    pub(crate) async fn load_orchestration_board(
+        &self,
+        controller_session_id: &str,
+    ) -> Option<OrchestrationBoard> {
+        crate::app::orchestration::load_orchestration_board(
+            self.services.db(),
+            controller_session_id,
+        )
+        .await
+        .ok()
+        .flatten()
+    }
+
+    /// Applies one campaign-board edit and schedules a session-list refresh.
+    ///
+    /// # Errors
+    ///
+    /// Returns a user-facing reason when the edit is rejected.
+    pub(crate) async fn apply_orchestration_board_action(
+        &self,
+        controller_session_id: &str,
+        action: OrchestrationBoardAction,
+    ) -> Result<(), String> {
+        crate::app::orchestration::apply_orchestration_board_action(
+            self.services.db(),
+            controller_session_id,
+            action,
+        )
+        .await?;
+        self.services.emit_app_event(AppEvent::RefreshSessions);
+
+        Ok(())
+    }
+
+    /// Reloads the visible campaign board while keeping the selected task.
+    ///
+    /// Returns to session view when the campaign no longer exists.
+    pub(crate) async fn reload_orchestration_board(&mut self) {
+        let AppMode::OrchestrationBoard {
+            board,
+            session_id,
+            selected_index,
+            ..
+        } = &self.mode
+        else {
+            return;
+        };
+        let session_id = session_id.clone();
+        let selected_task_id = board.tasks.get(*selected_index).map(|task| task.id);
+        let Some(reloaded) = self.load_orchestration_board(&session_id).await else {
+            self.mode = AppMode::View {
+                scroll_offset: None,
+                session_id,
+            };
+
+            return;
+        };
+        if let AppMode::OrchestrationBoard {
+            board,
+            selected_index,
+            ..
+        } = &mut self.mode
+        {
+            *selected_index = selected_task_id
+                .and_then(|task_id| reloaded.tasks.iter().position(|task| task.id == task_id))
+                .unwrap_or(*selected_index)
+                .min(reloaded.tasks.len().saturating_sub(1));
+            *board = reloaded;
+        }
+    }
+
     /// Drives one local API request while processing the actor commands ahead
     /// of it.
     ///
@@ -176,6 +256,12 @@ impl App {
             } => {
                 let _ = response_tx.send(self.get_api_session(&session_id).await);
             }
+            SessionRuntimeCommand::List {
+                project_id,
+                response_tx,
+            } => {
+                let _ = response_tx.send(self.list_api_sessions(project_id).await);
+            }
             SessionRuntimeCommand::SendMessage {
                 access,
                 message,
@@ -354,12 +440,16 @@ impl App {
                 )
                 .await
             }
-            CreateSessionMode::OrchestrationChild { task_id } => {
-                self.create_api_materialized_session(
+            CreateSessionMode::OrchestrationChild {
+                task_id,
+                prerequisite_session_id,
+            } => {
+                self.create_api_orchestration_child(
                     request.project_id,
                     base_branch_override,
                     creation_settings,
-                    SessionCreationKind::OrchestrationChild { task_id },
+                    task_id,
+                    prerequisite_session_id,
                 )
                 .await
             }
@@ -386,6 +476,14 @@ impl App {
                     self.create_stacked_draft_session(&parent_session_id).await
                 }
             }
+            CreateSessionMode::ReviewRequestImport { reference } => {
+                self.create_review_request_import_session(
+                    request.project_id,
+                    creation_settings,
+                    &reference,
+                )
+                .await
+            }
         }
         .map_err(api_error_from_app)?;
 
@@ -444,6 +542,57 @@ impl App {
             .map_err(AppError::from)
     }
 
+    /// Creates one orchestration worker, starting from a verified
+    /// prerequisite worker's branch when the task depends on one.
+    ///
+    /// A dependent worker records its starting commit as the stack base so
+    /// integration replays only its own commits once the prerequisite lands
+    /// on the controller's base branch.
+    async fn create_api_orchestration_child(
+        &mut self,
+        project_id: i64,
+        base_branch_override: Option<String>,
+        creation_settings: Option<SessionCreationSettings>,
+        task_id: i64,
+        prerequisite_session_id: Option<SessionId>,
+    ) -> Result<String, AppError> {
+        let base_branch_override = match prerequisite_session_id.as_ref() {
+            Some(prerequisite_session_id) => Some(
+                self.sessions
+                    .session_branch_name(prerequisite_session_id.as_str())
+                    .map_or_else(
+                        || session_branch(prerequisite_session_id.as_str()),
+                        str::to_string,
+                    ),
+            ),
+            None => base_branch_override,
+        };
+        let session_id = self
+            .create_api_materialized_session(
+                project_id,
+                base_branch_override,
+                creation_settings,
+                SessionCreationKind::OrchestrationChild { task_id },
+            )
+            .await?;
+        if prerequisite_session_id.is_some() {
+            let stack_base_commit_hash = self
+                .services
+                .git_client()
+                .head_hash(session_folder(self.services.base_path(), &session_id))
+                .await
+                .map_err(|error| AppError::Workflow(error.to_string()))?;
+            self.services
+                .db()
+                .sessions()
+                .update_session_stack_base_commit_hash(&session_id, Some(stack_base_commit_hash))
+                .await
+                .map_err(|error| AppError::Workflow(error.to_string()))?;
+        }
+
+        Ok(session_id)
+    }
+
     /// Loads one complete session aggregate from persistence plus live queue
     /// state.
     async fn get_api_session(
@@ -494,6 +643,23 @@ impl App {
         build_api_session(row, message_rows, queued_messages).map(Some)
     }
 
+    /// Loads summaries for every listed session in one project from one
+    /// metadata query, ordered by most recent update.
+    async fn list_api_sessions(
+        &self,
+        project_id: i64,
+    ) -> Result<Vec<SessionSummary>, ApiSessionError> {
+        self.services
+            .db()
+            .sessions()
+            .load_sessions_for_project(project_id)
+            .await
+            .map_err(|error| ApiSessionError::Operation(error.to_string()))?
+            .into_iter()
+            .map(build_api_session_summary)
+            .collect()
+    }
+
     /// Sends one validated API message through the existing session workflow.
     async fn send_api_message(
         &mut self,
@@ -943,7 +1109,7 @@ impl App {
     /// Attempts to register a newly persisted active-project session before
     /// acknowledging creation, scheduling a refresh retry when loading is
     /// temporarily unavailable.
-    async fn finish_api_session_creation(&mut self, session_id: &str) {
+    pub(super) async fn finish_api_session_creation(&mut self, session_id: &str) {
         if self
             .sessions
             .sessions()
@@ -1109,6 +1275,22 @@ fn build_api_session(
     })
 }
 
+/// Converts one persisted session-list row into its shared summary model.
+fn build_api_session_summary(row: SessionListRow) -> Result<SessionSummary, ApiSessionError> {
+    let status = row
+        .status
+        .parse::<SessionStatus>()
+        .map_err(|error| ApiSessionError::InvalidData(format!("session `{}`: {error}", row.id)))?;
+
+    Ok(SessionSummary {
+        created_at: row.created_at,
+        id: SessionId::from(row.id),
+        status,
+        title: row.title,
+        updated_at: row.updated_at,
+    })
+}
+
 /// Converts one persisted transcript row into its shared typed model.
 fn api_message_from_row(row: SessionMessageRow) -> Result<SessionMessage, ApiSessionError> {
     let kind = row.kind.parse::<SessionMessageKind>().map_err(|error| {
@@ -1331,6 +1513,27 @@ mod tests {
             .await
     }
 
+    /// Builds one planned fixture task named after `name`.
+    fn orchestration_fixture_task(
+        orchestration_id: i64,
+        name: &str,
+        kind: OrchestrationTaskKind,
+        merge_position: i64,
+    ) -> PersistedOrchestrationTask {
+        PersistedOrchestrationTask {
+            acceptance_criteria: format!(r#"["{name} task is implemented"]"#),
+            depends_on: "[]".to_string(),
+            kind: kind.to_string(),
+            merge_position,
+            prompt: format!("Implement the {name} task"),
+            session_orchestration_id: orchestration_id,
+            task_key: format!("{name}-task"),
+            title: format!("{name} task"),
+            touched_areas: format!(r#"["crates/{name}/"]"#),
+            wave: 0,
+        }
+    }
+
     async fn seed_active_orchestration_session(
         app: &mut App,
         link_child: bool,
@@ -1362,31 +1565,23 @@ mod tests {
             .services
             .db()
             .orchestrations()
-            .upsert_orchestration_task(PersistedOrchestrationTask {
-                acceptance_criteria: r#"["Worker task is implemented"]"#.to_string(),
-                kind: task_kind.to_string(),
-                merge_position: 0,
-                prompt: "Implement the worker task".to_string(),
-                session_orchestration_id: orchestration_id,
-                task_key: "worker-task".to_string(),
-                title: "Worker task".to_string(),
-                touched_areas: r#"["crates/worker/"]"#.to_string(),
-            })
+            .upsert_orchestration_task(orchestration_fixture_task(
+                orchestration_id,
+                "worker",
+                task_kind,
+                0,
+            ))
             .await
             .expect("orchestration task should persist");
         app.services
             .db()
             .orchestrations()
-            .upsert_orchestration_task(PersistedOrchestrationTask {
-                acceptance_criteria: r#"["Unlinked task is implemented"]"#.to_string(),
-                kind: "Implementation".to_string(),
-                merge_position: 1,
-                prompt: "Implement the unlinked task".to_string(),
-                session_orchestration_id: orchestration_id,
-                task_key: "unlinked-task".to_string(),
-                title: "Unlinked task".to_string(),
-                touched_areas: r#"["crates/unlinked/"]"#.to_string(),
-            })
+            .upsert_orchestration_task(orchestration_fixture_task(
+                orchestration_id,
+                "unlinked",
+                OrchestrationTaskKind::Implementation,
+                1,
+            ))
             .await
             .expect("unlinked orchestration task should persist");
         let claimed = app
@@ -1403,7 +1598,10 @@ mod tests {
                 inherit_from_session_id: Some(controller_session_id.clone()),
                 mode: match task_kind {
                     OrchestrationTaskKind::Implementation => {
-                        CreateSessionMode::OrchestrationChild { task_id }
+                        CreateSessionMode::OrchestrationChild {
+                            task_id,
+                            prerequisite_session_id: None,
+                        }
                     }
                     OrchestrationTaskKind::Research => {
                         CreateSessionMode::OrchestrationResearch { task_id }
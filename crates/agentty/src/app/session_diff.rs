@@ -5,18 +5,20 @@ use std::collections::hash_map::Entry;
 
 use tracing::warn;
 
-use crate::app::App;
 use crate::app::review::{self, FocusedReviewPersistence, ReviewCacheEntry};
 use crate::app::task::{SessionDiffTaskInput, SessionDiffTaskSource, TaskService};
+use crate::app::{App, AppError};
 use crate::domain::review::FocusedReviewStatus;
-use crate::domain::session::{SessionId, SessionRole, Status};
+use crate::domain::session::{Session, SessionId, SessionRole, Status};
+use crate::domain::transcript_notice::TranscriptNotice;
 use crate::domain::transient_message::{
     TransientMessage, TransientMessageAnchor, TransientMessageBody, TransientMessageLifecycle,
     TransientMessageSlot,
 };
 use crate::infra::db::DbError;
 use crate::presentation::app_mode::{
-    AppMode, DiffFocus, DiffLineComments, DiffPreview, DiffRestoreTarget, DiffSidebarFocus,
+    AppMode, DiffFocus, DiffLayoutMode, DiffLineComments, DiffPreview, DiffRestoreTarget,
+    DiffSidebarFocus,
 };
 
 /// Maximum number of delayed persistence attempts after an automatic-review
@@ -158,6 +160,57 @@ impl App {
         );
     }
 
+    /// Reverts diff-view selected changes and refreshes the open diff in
+    /// place.
+    ///
+    /// Failures, including patches made stale by newer worktree edits, are
+    /// reported as a `[Revert Error]` transcript notice and leave the visible
+    /// diff unchanged.
+    pub(crate) async fn revert_diff_changes(
+        &mut self,
+        session_id: &SessionId,
+        patch: String,
+        summary: Vec<String>,
+    ) {
+        if let Err(error) = self
+            .revert_session_diff_changes(session_id, patch, summary)
+            .await
+        {
+            self.append_prompt_status_line(
+                session_id,
+                TranscriptNotice::RevertError,
+                &error.to_string(),
+            )
+            .await;
+
+            return;
+        }
+        let refreshed_diff = match self.load_session_diff(session_id).await {
+            Ok(Some(refreshed_diff)) => refreshed_diff,
+            Ok(None) => return,
+            Err(error) => {
+                warn!(
+                    session_id = %session_id,
+                    error = %error,
+                    "failed to reload diff after revert"
+                );
+
+                return;
+            }
+        };
+        if let AppMode::Diff {
+            diff,
+            scroll_cache,
+            session_id: diff_session_id,
+            ..
+        } = &mut self.mode
+            && diff_session_id == session_id
+        {
+            *diff = refreshed_diff;
+            *scroll_cache = None;
+        }
+    }
+
     /// Discards every diff continuation and deferred automatic-review trigger
     /// owned by a deleted session so detached task completions remain stale.
     pub(crate) fn discard_deleted_session_diff_state(&mut self, session_id: &SessionId) {
@@ -433,6 +486,79 @@ impl App {
         });
     }
 
+    /// Loads the current full diff for one session in the foreground.
+    ///
+    /// Headless frontends use this instead of the background request path
+    /// because they print one result and exit. Returns `None` when the session
+    /// is unknown or its worktree was reclaimed without an archived diff.
+    ///
+    /// # Errors
+    /// Returns an error when Git or archived-diff persistence fails.
+    pub(crate) async fn load_session_diff(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<String>, AppError> {
+        let Some(session) = self.sessions.session_for_id(session_id) else {
+            return Ok(None);
+        };
+        let (base_branch, git_client) = match self.session_diff_source(session) {
+            SessionDiffTaskSource::Archived { .. } => {
+                return self.load_session_archived_diff(session_id).await;
+            }
+            SessionDiffTaskSource::Worktree {
+                base_branch,
+                git_client,
+                ..
+            } => (base_branch, git_client),
+        };
+
+        match git_client.diff(session.folder.clone(), base_branch).await {
+            Ok(diff) => Ok(Some(diff)),
+            Err(ag_git::GitError::RepositoryUnavailable { .. }) => {
+                self.load_session_archived_diff(session_id).await
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Loads the retained diff persisted when a managed worktree was
+    /// reclaimed.
+    async fn load_session_archived_diff(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<String>, AppError> {
+        Ok(self
+            .services
+            .db()
+            .sessions()
+            .load_session_archived_diff(session_id)
+            .await?)
+    }
+
+    /// Selects where one session's diff comes from.
+    ///
+    /// Managed sessions whose worktree is reclaimed on completion read their
+    /// archived diff; every other session diffs its worktree, with managed
+    /// merges falling back to the archive when cleanup wins the load race.
+    fn session_diff_source(&self, session: &Session) -> SessionDiffTaskSource {
+        let uses_archived_diff = session.is_managed()
+            && (session.status == Status::Done
+                || (session.role == SessionRole::OrchestrationResearcher
+                    && session.status == Status::Canceled));
+        if uses_archived_diff {
+            return SessionDiffTaskSource::Archived {
+                repositories: self.services.db().clone(),
+            };
+        }
+
+        SessionDiffTaskSource::Worktree {
+            archived_fallback: (session.is_managed() && session.status == Status::Merging)
+                .then(|| self.services.db().clone()),
+            base_branch: session.base_branch.clone(),
+            git_client: self.services.git_client(),
+        }
+    }
+
     /// Spawns the appropriate archive or worktree diff source and registers
     /// the continuation before the foreground task yields again.
     fn spawn_session_diff_request(
@@ -441,22 +567,7 @@ impl App {
         purpose: SessionDiffPurpose,
     ) -> Option<u64> {
         let session = self.sessions.session_for_id(session_id)?;
-        let source = if session.is_managed()
-            && (session.status == Status::Done
-                || (session.role == SessionRole::OrchestrationResearcher
-                    && session.status == Status::Canceled))
-        {
-            SessionDiffTaskSource::Archived {
-                repositories: self.services.db().clone(),
-            }
-        } else {
-            SessionDiffTaskSource::Worktree {
-                archived_fallback: (session.is_managed() && session.status == Status::Merging)
-                    .then(|| self.services.db().clone()),
-                base_branch: session.base_branch.clone(),
-                git_client: self.services.git_client(),
-            }
-        };
+        let source = self.session_diff_source(session);
         let input = SessionDiffTaskInput {
             app_event_tx: self.services.event_sender(),
             folder: session.folder.clone(),
@@ -595,6 +706,7 @@ impl App {
             diff,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             preview: DiffPreview::default(),
             review_comments,
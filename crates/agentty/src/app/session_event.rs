@@ -0,0 +1,265 @@
+//! Persisted session change feed for external observers.
+//!
+//! The feed compares successive persisted session snapshots instead of hooking
+//! individual workflows, so status and transcript changes written by any
+//! session worker are observed the same way. Owners run a feed only while
+//! someone is listening, because each poll reloads session rows and busy
+//! transcripts.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use ag_session::SessionStatus;
+
+use crate::infra::db::{DbError, SessionRepository, SessionRow};
+
+/// One observable change to a persisted session.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum SessionChangeEvent {
+    /// A transcript message was appended to one session.
+    MessageAdded {
+        /// Canonical transcript text.
+        content: String,
+        /// Stable message-kind string.
+        kind: String,
+        /// Monotonic position within the session transcript.
+        position: i64,
+        /// Owning session identifier.
+        session_id: String,
+    },
+    /// One session appeared or moved to a new lifecycle status.
+    StatusChanged {
+        /// Previously observed status, or `None` for newly created sessions.
+        previous_status: Option<String>,
+        /// Owning session identifier.
+        session_id: String,
+        /// Current persisted status.
+        status: String,
+    },
+}
+
+impl SessionChangeEvent {
+    /// Returns the session this change belongs to.
+    pub(crate) fn session_id(&self) -> &str {
+        match self {
+            Self::MessageAdded { session_id, .. } | Self::StatusChanged { session_id, .. } => {
+                session_id
+            }
+        }
+    }
+}
+
+/// Last persisted state observed for one session.
+struct ObservedSession {
+    last_message_position: Option<i64>,
+    status: String,
+    updated_at: i64,
+}
+
+/// Diffs persisted session snapshots into ordered change events.
+pub(crate) struct SessionChangeFeed {
+    observed: HashMap<String, ObservedSession>,
+    repository: Arc<dyn SessionRepository>,
+}
+
+impl SessionChangeFeed {
+    /// Creates a feed whose baseline is the current persisted state, so
+    /// existing history is not replayed as new changes.
+    ///
+    /// The baseline costs one session query plus one aggregate transcript
+    /// query, independent of how many sessions exist.
+    ///
+    /// # Errors
+    /// Returns an error when sessions or transcript positions cannot be
+    /// loaded.
+    pub(crate) async fn new(repository: Arc<dyn SessionRepository>) -> Result<Self, DbError> {
+        let mut last_message_positions = repository
+            .load_last_message_positions()
+            .await?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        let observed = repository
+            .load_sessions()
+            .await?
+            .into_iter()
+            .map(|row| {
+                let observed_session = ObservedSession {
+                    last_message_position: last_message_positions.remove(&row.id),
+                    status: row.status,
+                    updated_at: row.updated_at,
+                };
+
+                (row.id, observed_session)
+            })
+            .collect();
+
+        Ok(Self {
+            observed,
+            repository,
+        })
+    }
+
+    /// Loads one persisted snapshot and returns changes since the last poll.
+    ///
+    /// Transcripts are reloaded for sessions whose row changed and for busy
+    /// sessions, whose appends can share a second-granularity `updated_at`.
+    ///
+    /// # Errors
+    /// Returns an error when sessions or transcripts cannot be loaded.
+    pub(crate) async fn poll(&mut self) -> Result<Vec<SessionChangeEvent>, DbError> {
+        let mut events = Vec::new();
+        for row in self.repository.load_sessions().await? {
+            let previous = self.observed.remove(&row.id);
+            let previous_status = previous.as_ref().map(|observed| observed.status.clone());
+            if previous_status.as_deref() != Some(row.status.as_str()) {
+                events.push(SessionChangeEvent::StatusChanged {
+                    previous_status,
+                    session_id: row.id.clone(),
+                    status: row.status.clone(),
+                });
+            }
+
+            let mut last_message_position = previous
+                .as_ref()
+                .and_then(|observed| observed.last_message_position);
+            if should_reload_messages(previous.as_ref(), &row) {
+                for message in self.repository.load_session_messages(&row.id).await? {
+                    if last_message_position.is_some_and(|position| message.position <= position) {
+                        continue;
+                    }
+
+                    last_message_position = Some(message.position);
+                    events.push(SessionChangeEvent::MessageAdded {
+                        content: message.content,
+                        kind: message.kind,
+                        position: message.position,
+                        session_id: row.id.clone(),
+                    });
+                }
+            }
+
+            self.observed.insert(
+                row.id,
+                ObservedSession {
+                    last_message_position,
+                    status: row.status,
+                    updated_at: row.updated_at,
+                },
+            );
+        }
+
+        Ok(events)
+    }
+}
+
+/// Returns whether one session row may have new transcript messages.
+fn should_reload_messages(previous: Option<&ObservedSession>, row: &SessionRow) -> bool {
+    let Some(previous) = previous else {
+        return true;
+    };
+    let is_busy = row
+        .status
+        .parse::<SessionStatus>()
+        .is_ok_and(SessionStatus::is_busy);
+
+    is_busy || previous.status != row.status || previous.updated_at != row.updated_at
+}
+
+#[cfg(test)]
+mod tests {
+    use ag_session::SessionMessageKind;
+
+    use super::*;
+    use crate::infra::db::AppRepositories;
+
+    /// Opens an in-memory repository bundle with one project.
+    async fn repositories_with_project() -> (AppRepositories, i64) {
+        let repositories = AppRepositories::in_memory()
+            .await
+            .expect("in-memory database should open");
+        let project_id = repositories
+            .projects()
+            .upsert_project("/tmp/project", None)
+            .await
+            .expect("project should be inserted");
+
+        (repositories, project_id)
+    }
+
+    #[tokio::test]
+    async fn feed_skips_history_present_at_startup() {
+        // Arrange
+        let (repositories, project_id) = repositories_with_project().await;
+        let sessions = repositories.session_repository();
+        sessions
+            .insert_session("session-1", "gpt-5.6-sol", "main", "Review", project_id)
+            .await
+            .expect("session should be inserted");
+        sessions
+            .append_session_message("session-1", SessionMessageKind::UserPrompt, "old prompt")
+            .await
+            .expect("message should be appended");
+        let mut feed = SessionChangeFeed::new(Arc::clone(&sessions))
+            .await
+            .expect("feed should start");
+
+        // Act
+        let events = feed.poll().await.expect("poll should succeed");
+
+        // Assert
+        assert_eq!(events, Vec::new());
+    }
+
+    #[tokio::test]
+    async fn feed_reports_new_sessions_statuses_and_messages_in_order() {
+        // Arrange
+        let (repositories, project_id) = repositories_with_project().await;
+        let sessions = repositories.session_repository();
+        let mut feed = SessionChangeFeed::new(Arc::clone(&sessions))
+            .await
+            .expect("feed should start");
+        sessions
+            .insert_session("session-1", "gpt-5.6-sol", "main", "Draft", project_id)
+            .await
+            .expect("session should be inserted");
+        let created_events = feed.poll().await.expect("poll should succeed");
+        sessions
+            .update_session_status_with_timing_at("session-1", "InProgress", 0)
+            .await
+            .expect("status should update");
+        sessions
+            .append_session_message("session-1", SessionMessageKind::UserPrompt, "build it")
+            .await
+            .expect("message should be appended");
+
+        // Act
+        let events = feed.poll().await.expect("poll should succeed");
+        let repeated_events = feed.poll().await.expect("poll should succeed");
+
+        // Assert
+        assert_eq!(
+            created_events,
+            vec![SessionChangeEvent::StatusChanged {
+                previous_status: None,
+                session_id: "session-1".to_string(),
+                status: "Draft".to_string(),
+            }]
+        );
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events[0],
+            SessionChangeEvent::StatusChanged {
+                previous_status: Some("Draft".to_string()),
+                session_id: "session-1".to_string(),
+                status: "InProgress".to_string(),
+            }
+        );
+        assert!(matches!(
+            &events[1],
+            SessionChangeEvent::MessageAdded { content, kind, .. }
+                if content == "build it" && kind == "user_prompt"
+        ));
+        assert_eq!(events[1].session_id(), "session-1");
+        assert_eq!(repeated_events, Vec::new());
+    }
+}
@@ -15,7 +15,7 @@ use std::task::Poll;
 
 use ag_session::{
     AnswerQuestionsRequest, CoordinatorMessageRequest, CreateSessionRequest, ReviewRequest,
-    Session, SessionError, SessionId,
+    Session, SessionError, SessionId, SessionSummary,
 };
 use tokio::sync::{mpsc, oneshot, watch};
 
@@ -38,6 +38,11 @@ pub(crate) enum SessionRuntimeCommand {
         response_tx: oneshot::Sender<Result<Option<Session>, SessionError>>,
         session_id: SessionId,
     },
+    /// Lists lightweight summaries for sessions owned by one project.
+    List {
+        project_id: i64,
+        response_tx: oneshot::Sender<Result<Vec<SessionSummary>, SessionError>>,
+    },
     /// Sends one user message.
     SendMessage {
         access: SessionRuntimeAccess,
@@ -123,6 +128,18 @@ impl SessionRuntimeHandle {
         .await
     }
 
+    /// Lists project session summaries through the runtime actor.
+    pub(crate) async fn list_sessions(
+        &self,
+        project_id: i64,
+    ) -> Result<Vec<SessionSummary>, SessionError> {
+        self.request(|response_tx| SessionRuntimeCommand::List {
+            project_id,
+            response_tx,
+        })
+        .await
+    }
+
     /// Sends one message through the runtime actor.
     pub(crate) async fn send_message(
         &self,
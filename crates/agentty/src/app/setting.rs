@@ -1,3 +1,4 @@
+use ag_forge::ForgeBackend;
 use tracing::warn;
 
 use crate::app::AppServices;
@@ -6,11 +7,13 @@ use crate::domain::agent::{
 };
 use crate::domain::setting::{
     DEFAULT_AUTO_APPROVE_ORCHESTRATION_RESEARCH, DEFAULT_ORCHESTRATION_PARALLELISM,
-    MAX_ORCHESTRATION_PARALLELISM, SettingName,
+    MAX_ORCHESTRATION_PARALLELISM, MAX_POST_TURN_CHECK_FIX_RETRIES, SettingName,
 };
-use crate::domain::theme::ColorTheme;
+use crate::domain::theme::{ColorTheme, CustomTheme, CustomThemeCatalog};
 use crate::infra::db::AppRepositories;
-use crate::presentation::settings::{SettingsOperation, SettingsView};
+use crate::presentation::settings::{
+    MergeGateSettingsView, PostTurnChecksSettingsView, SettingsOperation, SettingsView,
+};
 
 /// Loads the persisted smart-model default used for new sessions.
 ///
@@ -219,13 +222,24 @@ pub struct SettingsManager {
     pub default_smart_selection: AgentSelection,
     /// Default response speed used when creating new sessions.
     pub default_smart_speed_mode: SpeedMode,
+    /// Transport used for the active project's review-request calls.
+    forge_backend: ForgeBackend,
+    /// Newline- or comma-separated hosts treated as Forgejo or Gitea
+    /// instances for the active project.
+    forgejo_hosts: String,
     /// Optional command run in tmux when opening a session worktree.
     pub launch_configuration: String,
+    /// Project merge-gate commands and fix-turn toggle.
+    merge_gate: MergeGateSettingsView,
     /// Maximum number of orchestration child sessions run concurrently.
     pub orchestration_parallelism: u8,
+    /// Project post-turn check commands, timeout, and fix-turn budget.
+    post_turn_checks: PostTurnChecksSettingsView,
     /// Active terminal color theme for the whole application.
     pub theme: ColorTheme,
     available_agent_kinds: Vec<AgentKind>,
+    /// User theme files loaded from the themes directory.
+    custom_themes: CustomThemeCatalog,
     /// Whether generated session commit messages append the Agentty coauthor
     /// trailer for the active project.
     ///
@@ -301,12 +315,8 @@ impl SettingsManager {
         )
         .await;
 
-        let launch_configuration = repositories
-            .settings()
-            .get_project_setting(project_id, SettingName::LaunchConfiguration)
-            .await
-            .unwrap_or(None)
-            .unwrap_or_default();
+        let (forgejo_hosts, launch_configuration, merge_gate, post_turn_checks) =
+            load_project_list_settings(&repositories, project_id).await;
 
         let include_coauthored_by_agentty = load_project_bool_setting_from_repositories(
             &repositories,
@@ -339,9 +349,14 @@ impl SettingsManager {
             default_smart_reasoning_level: default_smart.reasoning_level,
             default_smart_selection: default_smart.selection,
             default_smart_speed_mode: default_smart.speed_mode,
+            forge_backend: load_forge_backend_setting(&repositories, project_id).await,
+            forgejo_hosts,
             launch_configuration,
+            merge_gate,
+            post_turn_checks,
             theme,
             available_agent_kinds,
+            custom_themes: CustomThemeCatalog::default(),
             include_coauthored_by_agentty,
             orchestration_parallelism,
             project_id,
@@ -358,6 +373,35 @@ impl SettingsManager {
         parse_launch_configurations(self.launch_configuration.as_str())
     }
 
+    /// Returns the configured Forgejo hosts in persisted order.
+    ///
+    /// Entries are split by newlines, commas, or whitespace and trimmed.
+    pub(crate) fn forgejo_hosts(&self) -> Vec<String> {
+        parse_forgejo_hosts(self.forgejo_hosts.as_str())
+    }
+
+    /// Returns the transport used for the active project's review-request
+    /// calls.
+    pub(crate) fn forge_backend(&self) -> ForgeBackend {
+        self.forge_backend
+    }
+
+    /// Returns the user theme files loaded from the themes directory.
+    pub(crate) fn custom_themes(&self) -> &CustomThemeCatalog {
+        &self.custom_themes
+    }
+
+    /// Replaces the loaded user theme files after the themes directory
+    /// changes.
+    pub(crate) fn set_custom_themes(&mut self, custom_themes: CustomThemeCatalog) {
+        self.custom_themes = custom_themes;
+    }
+
+    /// Returns the loaded user theme backing the selected theme, if any.
+    pub(crate) fn active_custom_theme(&self) -> Option<&CustomTheme> {
+        self.custom_themes.find(&self.theme)
+    }
+
     /// Returns an immutable projection for the settings screen.
     pub(crate) fn view(&self) -> SettingsView {
         SettingsView {
@@ -375,14 +419,35 @@ impl SettingsManager {
             default_smart_reasoning_level: self.default_smart_reasoning_level,
             default_smart_selection: self.default_smart_selection,
             default_smart_speed_mode: self.default_smart_speed_mode,
+            forge_backend: self.forge_backend,
+            forgejo_hosts: self.forgejo_hosts.clone(),
             include_coauthored_by_agentty: self.include_coauthored_by_agentty,
             launch_configuration: self.launch_configuration.clone(),
+            merge_gate: self.merge_gate.clone(),
             orchestration_parallelism: self.orchestration_parallelism,
-            theme: self.theme,
+            post_turn_checks: self.post_turn_checks.clone(),
+            theme: self.theme.clone(),
+            theme_notice: self.custom_themes.notice(&self.theme),
+            theme_options: self.theme_options(),
             use_last_used_model_as_default: self.use_last_used_model_as_default,
         }
     }
 
+    /// Returns built-in themes followed by loaded user themes, with the
+    /// labels shown by the settings selector.
+    fn theme_options(&self) -> Vec<(ColorTheme, String)> {
+        let builtin_options = ColorTheme::ALL
+            .into_iter()
+            .map(|theme| (theme.clone(), theme.label().to_string()));
+        let custom_options = self
+            .custom_themes
+            .themes
+            .iter()
+            .map(|theme| (ColorTheme::Custom(theme.id.clone()), theme.name.clone()));
+
+        builtin_options.chain(custom_options).collect()
+    }
+
     /// Applies and persists one value change requested by the settings screen.
     pub(crate) async fn apply_operation(&mut self, operation: SettingsOperation) {
         match operation {
@@ -426,6 +491,14 @@ impl SettingsManager {
                 self.use_last_used_model_as_default = use_last_used_model_as_default;
                 self.persist_default_smart_model_settings().await;
             }
+            SettingsOperation::ForgeBackend(value) => {
+                self.forge_backend = value;
+                self.persist_forge_backend_setting().await;
+            }
+            SettingsOperation::ForgejoHosts(value) => {
+                self.forgejo_hosts = value;
+                self.persist_forgejo_hosts_setting().await;
+            }
             SettingsOperation::IncludeCoauthoredByAgentty(value) => {
                 self.include_coauthored_by_agentty = value;
                 self.persist_include_coauthored_by_agentty_setting().await;
@@ -434,10 +507,27 @@ impl SettingsManager {
                 self.launch_configuration = value;
                 self.persist_launch_configuration_setting().await;
             }
+            SettingsOperation::MergeGateCommands(value) => {
+                self.merge_gate.commands = value;
+                self.persist_merge_gate_commands_setting().await;
+            }
+            SettingsOperation::MergeGateFixTurn(value) => {
+                self.merge_gate.fix_turn = value;
+                self.persist_merge_gate_fix_turn_setting().await;
+            }
+            SettingsOperation::MergeGateTimeoutSeconds(value) => {
+                self.merge_gate.timeout_seconds = value;
+                self.persist_merge_gate_timeout_setting().await;
+            }
             SettingsOperation::OrchestrationParallelism(value) => {
                 self.orchestration_parallelism = value.clamp(1, MAX_ORCHESTRATION_PARALLELISM);
                 self.persist_orchestration_parallelism_setting().await;
             }
+            SettingsOperation::PostTurnCheckCommands(_)
+            | SettingsOperation::PostTurnCheckFixRetries(_)
+            | SettingsOperation::PostTurnCheckTimeoutSeconds(_) => {
+                self.apply_post_turn_checks_operation(operation).await;
+            }
             SettingsOperation::Theme(value) => {
                 self.theme = value;
                 self.persist_theme_setting().await;
@@ -445,6 +535,38 @@ impl SettingsManager {
         }
     }
 
+    /// Applies and persists one project post-turn checks setting change.
+    async fn apply_post_turn_checks_operation(&mut self, operation: SettingsOperation) {
+        let (setting_name, value) = match operation {
+            SettingsOperation::PostTurnCheckCommands(value) => {
+                self.post_turn_checks.commands.clone_from(&value);
+
+                (SettingName::PostTurnCheckCommands, value)
+            }
+            SettingsOperation::PostTurnCheckFixRetries(value) => {
+                self.post_turn_checks.fix_retries = value.min(MAX_POST_TURN_CHECK_FIX_RETRIES);
+
+                (
+                    SettingName::PostTurnCheckFixRetries,
+                    self.post_turn_checks.fix_retries.to_string(),
+                )
+            }
+            SettingsOperation::PostTurnCheckTimeoutSeconds(value) => {
+                self.post_turn_checks.timeout_seconds = value;
+
+                (SettingName::PostTurnCheckTimeoutSeconds, value.to_string())
+            }
+            _ => return,
+        };
+
+        // Best-effort: settings persistence failure is non-critical.
+        let _ = self
+            .repositories
+            .settings()
+            .upsert_project_setting(self.project_id, setting_name, &value)
+            .await;
+    }
+
     /// Persists the current `LaunchConfiguration` setting value.
     async fn persist_launch_configuration_setting(&self) {
         let _ = self
@@ -458,6 +580,80 @@ impl SettingsManager {
             .await;
     }
 
+    /// Persists the current `ForgeBackend` setting value.
+    async fn persist_forge_backend_setting(&self) {
+        // Best-effort: settings persistence failure is non-critical.
+        let _ = self
+            .repositories
+            .settings()
+            .upsert_project_setting(
+                self.project_id,
+                SettingName::ForgeBackend,
+                self.forge_backend.as_str(),
+            )
+            .await;
+    }
+
+    /// Persists the current `ForgejoHosts` setting value.
+    async fn persist_forgejo_hosts_setting(&self) {
+        // Best-effort: settings persistence failure is non-critical.
+        let _ = self
+            .repositories
+            .settings()
+            .upsert_project_setting(
+                self.project_id,
+                SettingName::ForgejoHosts,
+                &self.forgejo_hosts,
+            )
+            .await;
+    }
+
+    /// Persists the current `MergeGateCommands` setting value.
+    async fn persist_merge_gate_commands_setting(&self) {
+        // Best-effort: settings persistence failure is non-critical.
+        let _ = self
+            .repositories
+            .settings()
+            .upsert_project_setting(
+                self.project_id,
+                SettingName::MergeGateCommands,
+                &self.merge_gate.commands,
+            )
+            .await;
+    }
+
+    /// Persists whether failed merge gates start an agent fix turn.
+    async fn persist_merge_gate_fix_turn_setting(&self) {
+        let merge_gate_fix_turn = self.merge_gate.fix_turn.to_string();
+
+        // Best-effort: settings persistence failure is non-critical.
+        let _ = self
+            .repositories
+            .settings()
+            .upsert_project_setting(
+                self.project_id,
+                SettingName::MergeGateFixTurn,
+                &merge_gate_fix_turn,
+            )
+            .await;
+    }
+
+    /// Persists the per-command merge-gate timeout.
+    async fn persist_merge_gate_timeout_setting(&self) {
+        let merge_gate_timeout_seconds = self.merge_gate.timeout_seconds.to_string();
+
+        // Best-effort: settings persistence failure is non-critical.
+        let _ = self
+            .repositories
+            .settings()
+            .upsert_project_setting(
+                self.project_id,
+                SettingName::MergeGateTimeoutSeconds,
+                &merge_gate_timeout_seconds,
+            )
+            .await;
+    }
+
     /// Persists whether research-only orchestration waves start immediately.
     async fn persist_auto_approve_orchestration_research_setting(&self) {
         let value = self.auto_approve_orchestration_research.to_string();
@@ -625,7 +821,7 @@ impl SettingsManager {
         let _ = self
             .repositories
             .settings()
-            .upsert_setting(SettingName::Theme, self.theme.as_str())
+            .upsert_setting(SettingName::Theme, &self.theme.persisted_value())
             .await;
     }
 }
@@ -669,6 +865,137 @@ fn parse_launch_configurations(launch_configuration_setting: &str) -> Vec<String
         .collect()
 }
 
+/// Parses the persisted settings value into Forgejo host entries.
+fn parse_forgejo_hosts(forgejo_hosts_setting: &str) -> Vec<String> {
+    forgejo_hosts_setting
+        .split(|character: char| character == ',' || character.is_whitespace())
+        .filter(|host| !host.is_empty())
+        .map(std::string::ToString::to_string)
+        .collect()
+}
+
+/// Loads the project list-valued settings: Forgejo hosts, launch
+/// configurations, the merge gate, and post-turn checks.
+async fn load_project_list_settings(
+    repositories: &AppRepositories,
+    project_id: i64,
+) -> (
+    String,
+    String,
+    MergeGateSettingsView,
+    PostTurnChecksSettingsView,
+) {
+    let forgejo_hosts =
+        load_project_string_setting(repositories, project_id, SettingName::ForgejoHosts).await;
+    let launch_configuration =
+        load_project_string_setting(repositories, project_id, SettingName::LaunchConfiguration)
+            .await;
+
+    (
+        forgejo_hosts,
+        launch_configuration,
+        load_merge_gate_settings_from_repositories(repositories, project_id).await,
+        load_post_turn_checks_from_repositories(repositories, project_id).await,
+    )
+}
+
+/// Loads the project forge backend, falling back to the CLI backend when the
+/// setting is missing or unrecognized.
+async fn load_forge_backend_setting(
+    repositories: &AppRepositories,
+    project_id: i64,
+) -> ForgeBackend {
+    load_project_string_setting(repositories, project_id, SettingName::ForgeBackend)
+        .await
+        .parse()
+        .unwrap_or_default()
+}
+
+/// Loads the project merge-gate commands, timeout, and fix-turn toggle.
+async fn load_merge_gate_settings_from_repositories(
+    repositories: &AppRepositories,
+    project_id: i64,
+) -> MergeGateSettingsView {
+    let defaults = MergeGateSettingsView::default();
+    let commands =
+        load_project_string_setting(repositories, project_id, SettingName::MergeGateCommands).await;
+    let fix_turn = load_project_bool_setting_from_repositories(
+        repositories,
+        Some(project_id),
+        SettingName::MergeGateFixTurn,
+        defaults.fix_turn,
+    )
+    .await;
+    let timeout_seconds = load_project_string_setting(
+        repositories,
+        project_id,
+        SettingName::MergeGateTimeoutSeconds,
+    )
+    .await
+    .parse::<u64>()
+    .ok()
+    .filter(|value| *value > 0)
+    .unwrap_or(defaults.timeout_seconds);
+
+    MergeGateSettingsView {
+        commands,
+        fix_turn,
+        timeout_seconds,
+    }
+}
+
+/// Loads the project post-turn check commands, timeout, and fix-turn budget.
+async fn load_post_turn_checks_from_repositories(
+    repositories: &AppRepositories,
+    project_id: i64,
+) -> PostTurnChecksSettingsView {
+    let defaults = PostTurnChecksSettingsView::default();
+    let commands =
+        load_project_string_setting(repositories, project_id, SettingName::PostTurnCheckCommands)
+            .await;
+    let fix_retries = load_project_string_setting(
+        repositories,
+        project_id,
+        SettingName::PostTurnCheckFixRetries,
+    )
+    .await
+    .parse::<u8>()
+    .map_or(defaults.fix_retries, |value| {
+        value.min(MAX_POST_TURN_CHECK_FIX_RETRIES)
+    });
+    let timeout_seconds = load_project_string_setting(
+        repositories,
+        project_id,
+        SettingName::PostTurnCheckTimeoutSeconds,
+    )
+    .await
+    .parse::<u64>()
+    .ok()
+    .filter(|value| *value > 0)
+    .unwrap_or(defaults.timeout_seconds);
+
+    PostTurnChecksSettingsView {
+        commands,
+        fix_retries,
+        timeout_seconds,
+    }
+}
+
+/// Loads one project-scoped text setting, treating missing or unreadable
+/// values as empty.
+async fn load_project_string_setting(
+    repositories: &AppRepositories,
+    project_id: i64,
+    setting_name: SettingName,
+) -> String {
+    repositories
+        .settings()
+        .get_project_setting(project_id, setting_name)
+        .await
+        .unwrap_or(None)
+        .unwrap_or_default()
+}
+
 /// Loads one project-scoped boolean setting through the narrow repository
 /// dependency used by [`SettingsManager`].
 async fn load_project_bool_setting_from_repositories(
@@ -969,10 +1296,19 @@ mod tests {
                     default_smart_reasoning_level: ReasoningLevel::High,
                     default_smart_selection: default_selection,
                     default_smart_speed_mode: SpeedMode::Normal,
+                    forge_backend: ForgeBackend::default(),
+                    forgejo_hosts: String::new(),
                     include_coauthored_by_agentty: false,
                     launch_configuration: String::new(),
+                    merge_gate: MergeGateSettingsView::default(),
                     orchestration_parallelism: DEFAULT_ORCHESTRATION_PARALLELISM,
+                    post_turn_checks: PostTurnChecksSettingsView::default(),
                     theme: ColorTheme::Current,
+                    theme_notice: None,
+                    theme_options: ColorTheme::ALL
+                        .into_iter()
+                        .map(|theme| (theme.clone(), theme.label().to_string()))
+                        .collect(),
                     use_last_used_model_as_default: false,
                 },
             }
@@ -1589,7 +1925,7 @@ mod tests {
         services
             .db()
             .settings()
-            .upsert_setting(SettingName::Theme, ColorTheme::Green.as_str())
+            .upsert_setting(SettingName::Theme, &ColorTheme::Green.persisted_value())
             .await
             .expect("failed to persist theme setting");
         services
@@ -1846,7 +2182,10 @@ mod tests {
         services
             .db()
             .settings()
-            .upsert_setting(SettingName::Theme, ColorTheme::DarkHorizon.as_str())
+            .upsert_setting(
+                SettingName::Theme,
+                &ColorTheme::DarkHorizon.persisted_value(),
+            )
             .await
             .expect("failed to persist theme setting");
 
@@ -1857,6 +2196,51 @@ mod tests {
         assert_eq!(manager.settings().theme, ColorTheme::DarkHorizon);
     }
 
+    #[tokio::test]
+    async fn settings_manager_view_lists_custom_themes_and_missing_key_notice() {
+        // Arrange
+        let (services, project_id) = test_services().await;
+        let paper_theme = ColorTheme::Custom("paper".to_string());
+        services
+            .db()
+            .settings()
+            .upsert_setting(SettingName::Theme, &paper_theme.persisted_value())
+            .await
+            .expect("failed to persist theme setting");
+        let mut manager = SettingsManager::from_repositories(
+            services.db().clone(),
+            services.available_agent_kinds(),
+            project_id,
+        )
+        .await;
+        let custom_theme = crate::domain::theme::parse_custom_theme(
+            "paper",
+            "name = \"Paper\"\n[colors]\ntext = \"black\"\n",
+        )
+        .expect("theme should parse");
+
+        // Act
+        manager.set_custom_themes(CustomThemeCatalog {
+            errors: std::collections::BTreeMap::new(),
+            themes: vec![custom_theme],
+        });
+        let harness = SettingsTestHarness::from_manager(manager);
+        let rows = harness.settings_rows();
+
+        // Assert
+        assert_eq!(
+            harness.view.theme_options.last(),
+            Some(&(paper_theme, "Paper".to_string()))
+        );
+        assert!(harness.settings().active_custom_theme().is_some());
+        assert!(
+            rows[0]
+                .1
+                .starts_with("Paper (missing accent, accent_soft, border and ")
+        );
+        assert!(rows[0].1.ends_with("; using Agentty Default colors)"));
+    }
+
     #[tokio::test]
     async fn apply_operation_persists_role_model_reasoning_and_speed_settings() {
         // Arrange
@@ -1945,7 +2329,7 @@ mod tests {
     }
 
     #[test]
-    fn previous_wraps_to_launch_configurations_row_from_theme_row() {
+    fn previous_wraps_to_forge_backend_row_from_theme_row() {
         // Arrange
         let mut manager = new_settings_manager();
 
@@ -1958,7 +2342,7 @@ mod tests {
                 .presentation
                 .snapshot(&manager.view)
                 .selected_row_index,
-            Some(7)
+            Some(15)
         );
     }
 
@@ -1983,7 +2367,7 @@ mod tests {
         let rows = manager.settings_rows();
 
         // Assert
-        assert_eq!(rows.len(), 8);
+        assert_eq!(rows.len(), 16);
         assert_eq!(rows[0].0, "Theme");
         assert_eq!(rows[1].0, "Orchestrator Parallelism");
         assert_eq!(rows[2].0, "Auto-approve Research");
@@ -1993,6 +2377,19 @@ mod tests {
         assert_eq!(rows[5].0, "Default Review Model");
         assert_eq!(rows[6].0, "Coauthored by Agentty");
         assert_eq!(rows[7].0, "Launch Configurations");
+        assert_eq!(rows[8].0, "Merge Gate Commands");
+        assert_eq!(rows[9].0, "Merge Gate Timeout");
+        assert_eq!(rows[9].1, "30 min");
+        assert_eq!(rows[10].0, "Merge Gate Fix Turn");
+        assert_eq!(rows[10].1, "Disabled");
+        assert_eq!(rows[11].0, "Post-turn Checks");
+        assert_eq!(rows[12].0, "Post-turn Check Timeout");
+        assert_eq!(rows[12].1, "5 min");
+        assert_eq!(rows[13].0, "Post-turn Check Fix Retries");
+        assert_eq!(rows[13].1, "Disabled");
+        assert_eq!(rows[14].0, "Forgejo Hosts");
+        assert_eq!(rows[15].0, "Forge Backend");
+        assert_eq!(rows[15].1, "CLI");
     }
 
     #[test]
@@ -2009,12 +2406,20 @@ mod tests {
         assert_eq!(global_rows[0].0, "Theme");
         assert_eq!(global_rows[1].0, "Orchestrator Parallelism");
         assert_eq!(global_rows[2].0, "Auto-approve Research");
-        assert_eq!(project_rows.len(), 5);
+        assert_eq!(project_rows.len(), 13);
         assert_eq!(project_rows[0].0, "Default Smart Model");
         assert_eq!(project_rows[1].0, "Default Fast Model");
         assert_eq!(project_rows[2].0, "Default Review Model");
         assert_eq!(project_rows[3].0, "Coauthored by Agentty");
         assert_eq!(project_rows[4].0, "Launch Configurations");
+        assert_eq!(project_rows[5].0, "Merge Gate Commands");
+        assert_eq!(project_rows[6].0, "Merge Gate Timeout");
+        assert_eq!(project_rows[7].0, "Merge Gate Fix Turn");
+        assert_eq!(project_rows[8].0, "Post-turn Checks");
+        assert_eq!(project_rows[9].0, "Post-turn Check Timeout");
+        assert_eq!(project_rows[10].0, "Post-turn Check Fix Retries");
+        assert_eq!(project_rows[11].0, "Forgejo Hosts");
+        assert_eq!(project_rows[12].0, "Forge Backend");
     }
 
     #[test]
@@ -2364,6 +2769,40 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn forgejo_hosts_editor_persists_hosts_for_remote_detection() {
+        // Arrange
+        let (services, project_id) = test_services().await;
+        let mut manager = settings_manager(&services, project_id).await;
+        select_row(&mut manager, 14);
+        manager.handle_enter();
+        manager.start_adding_launch_configuration();
+
+        // Act
+        manager.apply_launch_configuration_input_command(InputCommand::InsertText(
+            "git.example.com, code.example.org:3000".to_string(),
+        ));
+        manager.confirm_launch_configuration_input().await;
+
+        // Assert
+        assert_eq!(
+            manager.settings().forgejo_hosts(),
+            vec![
+                "git.example.com".to_string(),
+                "code.example.org:3000".to_string()
+            ]
+        );
+        assert_eq!(
+            services
+                .db()
+                .settings()
+                .get_project_setting(project_id, SettingName::ForgejoHosts)
+                .await
+                .expect("failed to load Forgejo hosts"),
+            Some("git.example.com, code.example.org:3000".to_string())
+        );
+    }
+
     #[tokio::test]
     async fn confirm_launch_configuration_input_edits_selected_command_and_persists_value() {
         // Arrange
@@ -2534,6 +2973,31 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn selector_dropdown_selects_http_forge_backend_and_persists_value() {
+        // Arrange
+        let (services, project_id) = test_services().await;
+        let mut manager = settings_manager(&services, project_id).await;
+        select_row(&mut manager, 15);
+
+        // Act
+        manager.handle_enter();
+        manager.next_selector_dropdown_option();
+        manager.select_selector_dropdown_option().await;
+
+        // Assert
+        assert_eq!(manager.settings().forge_backend(), ForgeBackend::Http);
+        assert_eq!(
+            services
+                .db()
+                .settings()
+                .get_project_setting(project_id, SettingName::ForgeBackend)
+                .await
+                .expect("failed to load forge backend setting"),
+            Some("Http".to_string())
+        );
+    }
+
     #[tokio::test]
     async fn selector_dropdown_selects_theme_setting_and_persists_value() {
         // Arrange
@@ -2554,7 +3018,7 @@ mod tests {
 
         manager.next_selector_dropdown_option();
         manager.select_selector_dropdown_option().await;
-        let selected_theme = manager.settings().theme;
+        let selected_theme = manager.settings().theme.clone();
         let persisted_theme = services
             .db()
             .settings()
@@ -2564,10 +3028,7 @@ mod tests {
 
         // Assert
         assert_eq!(selected_theme, ColorTheme::Green);
-        assert_eq!(
-            persisted_theme,
-            Some(ColorTheme::Green.as_str().to_string())
-        );
+        assert_eq!(persisted_theme, Some(ColorTheme::Green.persisted_value()));
     }
 
     #[tokio::test]
@@ -2626,6 +3087,57 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn selector_dropdown_persists_post_turn_check_fix_retries() {
+        // Arrange
+        let (services, project_id) = test_services().await;
+        let mut manager = settings_manager(&services, project_id).await;
+        select_row(&mut manager, 13);
+
+        // Act
+        manager.handle_enter();
+        manager.next_selector_dropdown_option();
+        manager.next_selector_dropdown_option();
+        manager.select_selector_dropdown_option().await;
+
+        // Assert
+        assert_eq!(manager.settings().view().post_turn_checks.fix_retries, 2);
+        assert_eq!(
+            services
+                .db()
+                .settings()
+                .get_project_setting(project_id, SettingName::PostTurnCheckFixRetries)
+                .await
+                .expect("failed to load post-turn check fix retries"),
+            Some("2".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn selector_dropdown_persists_merge_gate_timeout() {
+        // Arrange
+        let (services, project_id) = test_services().await;
+        let mut manager = settings_manager(&services, project_id).await;
+        select_row(&mut manager, 9);
+
+        // Act
+        manager.handle_enter();
+        manager.next_selector_dropdown_option();
+        manager.select_selector_dropdown_option().await;
+
+        // Assert
+        assert_eq!(manager.settings().view().merge_gate.timeout_seconds, 3600);
+        assert_eq!(
+            services
+                .db()
+                .settings()
+                .get_project_setting(project_id, SettingName::MergeGateTimeoutSeconds)
+                .await
+                .expect("failed to load merge gate timeout"),
+            Some("3600".to_string())
+        );
+    }
+
     #[tokio::test]
     async fn launch_configuration_editor_apis_are_noops_without_open_editor() {
         // Arrange
@@ -298,6 +298,7 @@ impl AppStartup {
             );
         }
         task::TaskService::spawn_version_check_task(event_tx, auto_update);
+        task::TaskService::spawn_custom_theme_watch_task(event_tx);
     }
 
     /// Loads project list entries for the projects tab.
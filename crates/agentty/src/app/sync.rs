@@ -17,7 +17,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
-use ag_forge::ReviewRequestClient;
+use ag_forge::{ReviewCheckReport, ReviewRequestClient};
 use ag_git::GitClient;
 use tokio::sync::{mpsc, watch};
 use tokio::time::MissedTickBehavior;
@@ -39,6 +39,9 @@ const REVIEW_SYNC_FAILURE_NOTICE_THRESHOLD: u32 = 3;
 /// Upper bound of review passes one failing target is skipped between
 /// retries.
 const REVIEW_SYNC_MAX_BACKOFF_PASSES: u64 = 7;
+/// Maximum number of uncached failed-check logs downloaded for one target per
+/// review pass, so a broken matrix build does not stall the pass.
+const CHECK_LOG_FETCHES_PER_PASS: usize = 3;
 
 /// Starts project sync work and emits completion events for list-mode popups.
 #[cfg_attr(test, mockall::automock)]
@@ -319,6 +322,9 @@ fn review_target_polling_keys(
 /// the periodic passes through the same queue.
 pub(crate) struct SyncOrchestrator {
     app_event_tx: mpsc::UnboundedSender<AppEvent>,
+    /// Failed-check log excerpts keyed by session and forge log id, reused
+    /// across passes so each failed job log is downloaded once.
+    check_log_excerpts: HashMap<SessionId, HashMap<String, String>>,
     command_rx: mpsc::UnboundedReceiver<SyncCommand>,
     context_rx: watch::Receiver<SyncContext>,
     review_pass_index: u64,
@@ -338,6 +344,7 @@ impl SyncOrchestrator {
     ) {
         let orchestrator = Self {
             app_event_tx,
+            check_log_excerpts: HashMap::new(),
             command_rx,
             context_rx,
             review_pass_index: 0,
@@ -479,14 +486,24 @@ impl SyncOrchestrator {
                 continue;
             }
 
+            let cached_log_excerpts = self
+                .check_log_excerpts
+                .remove(&review_request_sync_target.session_id)
+                .unwrap_or_default();
             let result = sync_review_request_status(
                 review_request_sync_target.folder.clone(),
                 context.git_client.as_ref(),
                 review_request_sync_target.linked_review_request.clone(),
                 review_request_sync_target.published_upstream_ref.clone(),
                 context.review_request_client.as_ref(),
+                &cached_log_excerpts,
             )
             .await;
+            self.record_check_log_excerpts(
+                &review_request_sync_target.session_id,
+                &result,
+                cached_log_excerpts,
+            );
 
             if self.context_is_stale(context) {
                 return updates;
@@ -499,7 +516,7 @@ impl SyncOrchestrator {
             });
         }
 
-        self.retain_active_failure_states(&context.review_request_sync_targets);
+        self.retain_active_target_states(&context.review_request_sync_targets);
 
         updates
     }
@@ -623,16 +640,44 @@ impl SyncOrchestrator {
         }
     }
 
-    /// Drops failure tracking for sessions no longer in the target list.
-    fn retain_active_failure_states(
+    /// Keeps the log excerpts of the failed checks reported by one
+    /// successful sync, or the previous cache when the sync failed.
+    fn record_check_log_excerpts(
+        &mut self,
+        session_id: &SessionId,
+        result: &Result<SyncReviewRequestTaskResult, String>,
+        cached_log_excerpts: HashMap<String, String>,
+    ) {
+        let check_log_excerpts = match result {
+            Ok(task_result) => task_result
+                .checks
+                .iter()
+                .flat_map(ReviewCheckReport::failed_runs)
+                .filter_map(|run| Some((run.log_id.clone()?, run.log_excerpt.clone()?)))
+                .collect(),
+            Err(_) => cached_log_excerpts,
+        };
+        if !check_log_excerpts.is_empty() {
+            self.check_log_excerpts
+                .insert(session_id.clone(), check_log_excerpts);
+        }
+    }
+
+    /// Drops failure tracking and cached check logs for sessions no longer in
+    /// the target list.
+    fn retain_active_target_states(
         &mut self,
         review_request_sync_targets: &[ReviewRequestSyncTarget],
     ) {
-        self.review_sync_failures.retain(|session_id, _| {
+        let is_active_target = |session_id: &SessionId| {
             review_request_sync_targets
                 .iter()
                 .any(|target| &target.session_id == session_id)
-        });
+        };
+        self.review_sync_failures
+            .retain(|session_id, _| is_active_target(session_id));
+        self.check_log_excerpts
+            .retain(|session_id, _| is_active_target(session_id));
     }
 }
 
@@ -717,13 +762,16 @@ async fn session_git_statuses(
 ///
 /// When the session has a linked review request, this refreshes it by display
 /// id. Otherwise, when the branch was published, this searches for an
-/// externally created review request by source branch name.
+/// externally created review request by source branch name. Open review
+/// requests also load their head-commit CI checks, reusing
+/// `cached_log_excerpts` for failed jobs seen in earlier passes.
 async fn sync_review_request_status(
     folder: PathBuf,
     git_client: &dyn GitClient,
     linked_review_request: Option<crate::domain::session::ReviewRequest>,
     published_upstream_ref: Option<String>,
     review_request_client: &dyn ReviewRequestClient,
+    cached_log_excerpts: &HashMap<String, String>,
 ) -> Result<SyncReviewRequestTaskResult, String> {
     let remote = review_request_remote(
         folder.clone(),
@@ -735,23 +783,30 @@ async fn sync_review_request_status(
 
     if let Some(review_request) = linked_review_request {
         let refreshed_summary = review_request_client
-            .refresh_review_request(remote, review_request.summary.display_id)
+            .refresh_review_request(remote.clone(), review_request.summary.display_id)
             .await
             .map_err(|error| error.detail_message())?;
         let session_head_hash =
             session_head_hash_for_summary(git_client, &folder, &refreshed_summary).await;
+        let checks = review_check_report(
+            remote,
+            &refreshed_summary,
+            review_request_client,
+            cached_log_excerpts,
+        )
+        .await;
 
-        return Ok(sync_task_result_from_summary(
-            refreshed_summary,
-            session_head_hash,
-        ));
+        return Ok(SyncReviewRequestTaskResult {
+            checks,
+            ..sync_task_result_from_summary(refreshed_summary, session_head_hash)
+        });
     }
 
     let upstream_ref = published_upstream_ref
         .ok_or_else(|| "Session branch has not been published yet".to_string())?;
     let source_branch = session::remote_branch_name_from_upstream_ref(&upstream_ref);
     let found_summary = review_request_client
-        .find_by_source_branch(remote, source_branch)
+        .find_by_source_branch(remote.clone(), source_branch)
         .await
         .map_err(|error| error.detail_message())?;
 
@@ -759,15 +814,76 @@ async fn sync_review_request_status(
         Some(summary) => {
             let session_head_hash =
                 session_head_hash_for_summary(git_client, &folder, &summary).await;
-            Ok(sync_task_result_from_summary(summary, session_head_hash))
+            let checks =
+                review_check_report(remote, &summary, review_request_client, cached_log_excerpts)
+                    .await;
+
+            Ok(SyncReviewRequestTaskResult {
+                checks,
+                ..sync_task_result_from_summary(summary, session_head_hash)
+            })
         }
         None => Ok(SyncReviewRequestTaskResult {
+            checks: None,
             outcome: session::SyncReviewRequestOutcome::NoReviewRequest,
             summary: None,
         }),
     }
 }
 
+/// Loads head-commit CI checks for one open review request.
+///
+/// Best-effort: check failures are logged and reported as `None` so forge
+/// CI outages never fail the review-request refresh itself. Log excerpts
+/// come from `cached_log_excerpts` when available; at most
+/// [`CHECK_LOG_FETCHES_PER_PASS`] uncached failed-job logs are downloaded.
+async fn review_check_report(
+    remote: ag_forge::ForgeRemote,
+    summary: &crate::domain::session::ReviewRequestSummary,
+    review_request_client: &dyn ReviewRequestClient,
+    cached_log_excerpts: &HashMap<String, String>,
+) -> Option<ReviewCheckReport> {
+    if summary.state != ReviewRequestState::Open {
+        return None;
+    }
+
+    let mut report = review_request_client
+        .fetch_review_request_checks(remote.clone(), summary.display_id.clone())
+        .await
+        .inspect_err(|error| {
+            tracing::debug!(
+                "review request check sync failed: {}",
+                error.detail_message()
+            );
+        })
+        .ok()?;
+    let mut remaining_log_fetches = CHECK_LOG_FETCHES_PER_PASS;
+    for run in &mut report.runs {
+        if !run.is_failed() {
+            continue;
+        }
+        let Some(log_id) = run.log_id.clone() else {
+            continue;
+        };
+        if let Some(log_excerpt) = cached_log_excerpts.get(&log_id) {
+            run.log_excerpt = Some(log_excerpt.clone());
+
+            continue;
+        }
+        if remaining_log_fetches == 0 {
+            continue;
+        }
+
+        remaining_log_fetches -= 1;
+        run.log_excerpt = review_request_client
+            .fetch_check_log_excerpt(remote.clone(), log_id)
+            .await
+            .ok();
+    }
+
+    Some(report)
+}
+
 /// Resolves the forge remote used for one background review-request refresh.
 ///
 /// Active sessions prefer the live worktree remote so forge CLI commands
@@ -812,6 +928,9 @@ fn review_request_repo_url(
             .split_once("/-/merge_requests/")
             .or_else(|| web_url.split_once("/merge_requests/"))
             .map(|(repo_url, _)| repo_url.to_string()),
+        ag_forge::ForgeKind::Forgejo => web_url
+            .split_once("/pulls/")
+            .map(|(repo_url, _)| repo_url.to_string()),
     }
 }
 
@@ -855,6 +974,7 @@ fn sync_task_result_from_summary(
     };
 
     SyncReviewRequestTaskResult {
+        checks: None,
         outcome,
         summary: Some(summary),
     }
@@ -1037,6 +1157,7 @@ mod tests {
         let (_command_tx, command_rx) = mpsc::unbounded_channel();
         let mut orchestrator = SyncOrchestrator {
             app_event_tx,
+            check_log_excerpts: HashMap::new(),
             command_rx,
             context_rx,
             review_pass_index: 0,
@@ -1082,6 +1203,7 @@ mod tests {
         let (_command_tx, command_rx) = mpsc::unbounded_channel();
         let mut orchestrator = SyncOrchestrator {
             app_event_tx,
+            check_log_excerpts: HashMap::new(),
             command_rx,
             context_rx,
             review_pass_index: 0,
@@ -1091,6 +1213,7 @@ mod tests {
         let target = review_request_sync_target("session-1", None);
         let failure: Result<SyncReviewRequestTaskResult, String> = Err("gh exploded".to_string());
         let success = Ok(SyncReviewRequestTaskResult {
+            checks: None,
             outcome: session::SyncReviewRequestOutcome::NoReviewRequest,
             summary: None,
         });
@@ -1367,6 +1490,7 @@ mod tests {
             Some(linked),
             None,
             &mock_review_request_client,
+            &HashMap::new(),
         )
         .await
         .expect("sync should succeed");
@@ -1462,6 +1586,7 @@ mod tests {
             Some(linked),
             None,
             &mock_review_request_client,
+            &HashMap::new(),
         )
         .await
         .expect("sync should use the linked review-request URL fallback");
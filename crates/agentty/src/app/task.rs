@@ -20,17 +20,21 @@ use tracing::warn;
 use crate::app::error::AppError;
 use crate::app::review::FocusedReviewPersistenceRetry;
 use crate::app::session_diff::DeferredAutoReviewPersistenceRetry;
-use crate::app::{AppEvent, UpdateStatus, at_mention_task};
+use crate::app::{AppEvent, ReviewRequestForgeUpdate, UpdateStatus, at_mention_task};
 use crate::domain::agent::{AgentCliInfo, AgentKind, AgentSelection, ReasoningLevel};
 use crate::domain::file_entry::FileEntry;
 use crate::domain::session::SessionId;
-use crate::infra::{file_index, version};
+use crate::domain::theme::CustomThemeCatalog;
+use crate::infra::{file_index, theme, version};
 
 /// Delay applied before a fresh `@`-mention filesystem walk starts.
 const AT_MENTION_LOAD_DEBOUNCE: Duration = Duration::from_millis(75);
 /// Delay before a failed focused-review persistence write is retried through
 /// the foreground event reducer.
 const FOCUSED_REVIEW_PERSISTENCE_RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+/// Interval between user theme directory scans used for hot reload.
+#[cfg(not(test))]
+const CUSTOM_THEME_POLL_INTERVAL: Duration = Duration::from_secs(2);
 /// Monotonic counter used to distinguish stale and current at-mention loads.
 static NEXT_AT_MENTION_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
 /// Monotonic counter used to distinguish stale review-comment loads.
@@ -42,13 +46,14 @@ static NEXT_SESSION_DIFF_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
 /// session execution.
 pub(crate) struct TaskService;
 
-/// Payload needed to load comments for a linked session review request.
-pub(super) struct SessionReviewCommentSnapshotTask {
+/// Payload needed to reach a linked session review request from a background
+/// forge task.
+pub(super) struct LinkedReviewRequestTask {
     /// Provider display id such as GitHub `#123` or GitLab `!123`.
     pub(super) display_id: String,
     /// Repository URL reconstructed from the persisted review-request link.
     pub(super) fallback_repo_url: Option<String>,
-    /// Session whose comments should receive the completed snapshot.
+    /// Session that receives the completed forge result.
     pub(super) session_id: SessionId,
     /// Session worktree used for remote detection and forge CLI context.
     pub(super) working_dir: PathBuf,
@@ -260,10 +265,56 @@ impl TaskService {
         });
     }
 
+    /// Spawns the user theme watcher that hot-reloads `~/.agentty/themes/`.
+    ///
+    /// In tests, it does nothing so test runs never read the user's home
+    /// directory.
+    pub(super) fn spawn_custom_theme_watch_task(app_event_tx: &mpsc::UnboundedSender<AppEvent>) {
+        #[cfg(test)]
+        let _ = app_event_tx;
+
+        #[cfg(not(test))]
+        {
+            let app_event_tx = app_event_tx.clone();
+            let themes_dir = crate::app::agentty_home().join(theme::THEMES_DIR);
+            tokio::spawn(async move {
+                Self::watch_custom_themes(themes_dir, app_event_tx, CUSTOM_THEME_POLL_INTERVAL)
+                    .await;
+            });
+        }
+    }
+
+    /// Rescans `themes_dir` every `poll_interval` and emits the catalog
+    /// whenever it differs from the last emitted one.
+    ///
+    /// The first scan emits only when it finds theme files or errors, and
+    /// the loop ends once the app event receiver is dropped.
+    async fn watch_custom_themes(
+        themes_dir: PathBuf,
+        app_event_tx: mpsc::UnboundedSender<AppEvent>,
+        poll_interval: Duration,
+    ) {
+        let mut emitted_catalog = CustomThemeCatalog::default();
+        loop {
+            let catalog = theme::load_custom_theme_catalog(&themes_dir).await;
+            if catalog != emitted_catalog {
+                emitted_catalog = catalog.clone();
+                if app_event_tx
+                    .send(AppEvent::CustomThemesUpdated { catalog })
+                    .is_err()
+                {
+                    return;
+                }
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
     /// Spawns one linked session review-comment load without blocking terminal
     /// input or redraws and returns its stale-completion request generation.
     pub(super) fn spawn_session_review_comment_snapshot_task(
-        task: SessionReviewCommentSnapshotTask,
+        task: LinkedReviewRequestTask,
         app_event_tx: mpsc::UnboundedSender<AppEvent>,
         git_client: Arc<dyn GitClient>,
         review_request_client: Arc<dyn ReviewRequestClient>,
@@ -298,23 +349,78 @@ impl TaskService {
         git_client: &dyn GitClient,
         review_request_client: &dyn ReviewRequestClient,
     ) -> Result<ReviewCommentSnapshot, String> {
-        let remote =
-            match review_request_remote(working_dir, git_client, review_request_client).await {
-                Ok(remote) => remote,
-                Err(working_dir_error) => {
-                    let Some(repo_url) = fallback_repo_url else {
-                        return Err(working_dir_error);
-                    };
-
-                    review_request_client
-                        .detect_remote(repo_url)
-                        .map_err(|error| error.detail_message())?
-                }
-            };
+        let remote = linked_review_request_remote(
+            working_dir,
+            fallback_repo_url,
+            git_client,
+            review_request_client,
+        )
+        .await?;
 
         load_review_comment_snapshot(remote, display_id, review_request_client).await
     }
 
+    /// Spawns one forge call that takes a linked draft review request out of
+    /// draft state.
+    pub(super) fn spawn_mark_review_request_ready_task(
+        task: LinkedReviewRequestTask,
+        app_event_tx: mpsc::UnboundedSender<AppEvent>,
+        git_client: Arc<dyn GitClient>,
+        review_request_client: Arc<dyn ReviewRequestClient>,
+    ) {
+        tokio::spawn(async move {
+            let result = match linked_review_request_remote(
+                task.working_dir,
+                task.fallback_repo_url,
+                git_client.as_ref(),
+                review_request_client.as_ref(),
+            )
+            .await
+            {
+                Ok(remote) => review_request_client
+                    .mark_review_request_ready(remote, task.display_id)
+                    .await
+                    .map_err(|error| error.detail_message()),
+                Err(error) => Err(error),
+            };
+            let _ = app_event_tx.send(AppEvent::ReviewRequestForgeLoaded(
+                ReviewRequestForgeUpdate::MarkedReady {
+                    result,
+                    session_id: task.session_id,
+                },
+            ));
+        });
+    }
+
+    /// Spawns one forge lookup of the labels, milestones, and reviewers
+    /// offered while composing a new review request.
+    pub(super) fn spawn_review_request_publish_choices_task(
+        session_id: SessionId,
+        working_dir: PathBuf,
+        app_event_tx: mpsc::UnboundedSender<AppEvent>,
+        git_client: Arc<dyn GitClient>,
+        review_request_client: Arc<dyn ReviewRequestClient>,
+    ) {
+        tokio::spawn(async move {
+            let result = match review_request_remote(
+                working_dir,
+                git_client.as_ref(),
+                review_request_client.as_ref(),
+            )
+            .await
+            {
+                Ok(remote) => review_request_client
+                    .fetch_review_request_publish_choices(remote)
+                    .await
+                    .map_err(|error| error.detail_message()),
+                Err(error) => Err(error),
+            };
+            let _ = app_event_tx.send(AppEvent::ReviewRequestForgeLoaded(
+                ReviewRequestForgeUpdate::PublishChoicesLoaded { result, session_id },
+            ));
+        });
+    }
+
     /// Spawns a one-shot background check for newer `agentty` versions on
     /// npmjs, optionally followed by an automatic `npm i -g agentty@latest`
     /// update.
@@ -584,8 +690,9 @@ impl TaskService {
     }
 }
 
-/// Resolves the active project remote for session review-comment loading.
-async fn review_request_remote(
+/// Resolves the active project remote for session review-comment and
+/// publish-choice loading.
+pub(super) async fn review_request_remote(
     working_dir: PathBuf,
     git_client: &dyn GitClient,
     review_request_client: &dyn ReviewRequestClient,
@@ -601,9 +708,31 @@ async fn review_request_remote(
         .map_err(|error| error.detail_message())
 }
 
+/// Resolves the remote of a linked review request, falling back to its
+/// persisted forge URL when terminal-session cleanup removed the worktree.
+async fn linked_review_request_remote(
+    working_dir: PathBuf,
+    fallback_repo_url: Option<String>,
+    git_client: &dyn GitClient,
+    review_request_client: &dyn ReviewRequestClient,
+) -> Result<ForgeRemote, String> {
+    match review_request_remote(working_dir, git_client, review_request_client).await {
+        Ok(remote) => Ok(remote),
+        Err(working_dir_error) => {
+            let Some(repo_url) = fallback_repo_url else {
+                return Err(working_dir_error);
+            };
+
+            review_request_client
+                .detect_remote(repo_url)
+                .map_err(|error| error.detail_message())
+        }
+    }
+}
+
 /// Fetches and normalizes one review-comment snapshot from an already
 /// resolved forge remote.
-async fn load_review_comment_snapshot(
+pub(super) async fn load_review_comment_snapshot(
     remote: ForgeRemote,
     display_id: String,
     review_request_client: &dyn ReviewRequestClient,
@@ -973,6 +1102,44 @@ mod tests {
         assert!(matches!(result, Err(error) if error.contains("session worktree was removed")));
     }
 
+    #[tokio::test]
+    /// Ensures the theme watcher emits the initial catalog and each later
+    /// change to a theme file.
+    async fn watch_custom_themes_emits_catalog_when_theme_files_change() {
+        // Arrange
+        let themes_dir = tempfile::tempdir().expect("failed to create temp dir");
+        let theme_path = themes_dir.path().join("paper.toml");
+        std::fs::write(&theme_path, "name = \"Paper\"\n").expect("failed to write theme");
+        let (app_event_tx, mut app_event_rx) = mpsc::unbounded_channel();
+        let watch_task = tokio::spawn(TaskService::watch_custom_themes(
+            themes_dir.path().to_path_buf(),
+            app_event_tx,
+            Duration::from_millis(10),
+        ));
+
+        // Act
+        let initial_event = tokio::time::timeout(Duration::from_secs(1), app_event_rx.recv())
+            .await
+            .expect("timed out waiting for initial theme catalog")
+            .expect("theme watcher should emit the initial catalog");
+        std::fs::write(&theme_path, "name = \"Paper Light\"\n").expect("failed to write theme");
+        let changed_event = tokio::time::timeout(Duration::from_secs(1), app_event_rx.recv())
+            .await
+            .expect("timed out waiting for changed theme catalog")
+            .expect("theme watcher should emit the changed catalog");
+        watch_task.abort();
+
+        // Assert
+        assert!(matches!(
+            initial_event,
+            AppEvent::CustomThemesUpdated { catalog } if catalog.themes[0].name == "Paper"
+        ));
+        assert!(matches!(
+            changed_event,
+            AppEvent::CustomThemesUpdated { catalog } if catalog.themes[0].name == "Paper Light"
+        ));
+    }
+
     #[tokio::test]
     /// Ensures test-mode version checks still emit one reducer event without
     /// touching the network.
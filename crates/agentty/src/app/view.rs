@@ -8,10 +8,11 @@ use crate::app::{App, Tab, UpdateStatus, session};
 use crate::domain::agent::{AgentCliInfo, ReasoningLevel};
 use crate::domain::project::ProjectListItem;
 use crate::domain::session::{DailyActivity, Session, SessionId};
-use crate::domain::theme::ColorTheme;
+use crate::domain::theme::{ColorTheme, CustomTheme};
 use crate::infra::clock;
 use crate::presentation::app_mode::{AppMode, HelpContext};
 use crate::presentation::frame_time::FrameTime;
+use crate::presentation::keymap::Keymap;
 use crate::presentation::settings::SettingsScreenSnapshot;
 
 /// Focused-review display state for the visible session.
@@ -26,12 +27,14 @@ pub(crate) struct AppViewSnapshot<'a> {
     pub(crate) active_prompt_outputs: &'a HashMap<SessionId, String>,
     pub(crate) available_agent_clis: Vec<AgentCliInfo>,
     pub(crate) current_tab: Tab,
+    pub(crate) custom_theme: Option<&'a CustomTheme>,
     pub(crate) default_reasoning_level: ReasoningLevel,
     pub(crate) frame_time: FrameTime,
     pub(crate) git_branch: Option<&'a str>,
     pub(crate) git_status: Option<(u32, u32)>,
     pub(crate) git_upstream_ref: Option<&'a str>,
     pub(crate) is_tmux_session: bool,
+    pub(crate) keymap: &'a Keymap,
     pub(crate) latest_available_version: Option<&'a str>,
     pub(crate) mode: &'a AppMode,
     pub(crate) mru_project_order: &'a [usize],
@@ -49,7 +52,7 @@ pub(crate) struct AppViewSnapshot<'a> {
     pub(crate) settings_screen: Option<SettingsScreenSnapshot>,
     pub(crate) stats_activity: &'a [DailyActivity],
     pub(crate) status_bar_fyi_rotation_index: u64,
-    pub(crate) theme: ColorTheme,
+    pub(crate) theme: &'a ColorTheme,
     pub(crate) update_status: Option<&'a UpdateStatus>,
     pub(crate) working_dir: &'a Path,
 }
@@ -84,12 +87,14 @@ impl App {
             active_prompt_outputs: sessions.active_prompt_outputs,
             available_agent_clis: self.services.available_agent_clis(),
             current_tab,
+            custom_theme: self.settings.active_custom_theme(),
             default_reasoning_level: self.settings.default_smart_reasoning_level,
             frame_time,
             git_branch: project.git_branch,
             git_status: project.git_status,
             git_upstream_ref: project.git_upstream_ref,
             is_tmux_session: self.is_tmux_session(),
+            keymap: &self.keymap,
             latest_available_version: self.latest_available_version.as_deref(),
             mode: &self.mode,
             mru_project_order: project.mru_project_order,
@@ -107,7 +112,7 @@ impl App {
             settings_screen,
             stats_activity: sessions.stats_activity,
             status_bar_fyi_rotation_index,
-            theme: self.settings.theme,
+            theme: &self.settings.theme,
             update_status: self.update_status.as_ref(),
             working_dir: project.working_dir,
         }
@@ -135,10 +140,12 @@ fn visible_review_session_id(mode: &AppMode) -> Option<&str> {
         | AppMode::ViewInfoPopup { restore_view, .. } => Some(&restore_view.session_id),
         AppMode::List
         | AppMode::SessionCreation { .. }
+        | AppMode::ReviewRequestImportInput { .. }
         | AppMode::PreCommitHookWarning { .. }
         | AppMode::ProjectSwitcher { .. }
         | AppMode::Confirmation { .. }
         | AppMode::SyncBlockedPopup { .. }
+        | AppMode::OrchestrationBoard { .. }
         | AppMode::Help { .. } => None,
     }
 }
@@ -146,7 +153,7 @@ fn visible_review_session_id(mode: &AppMode) -> Option<&str> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::presentation::app_mode::{DiffFocus, DiffLineComments};
+    use crate::presentation::app_mode::{DiffFocus, DiffLayoutMode, DiffLineComments};
 
     #[test]
     fn visible_review_session_id_includes_diff_comments() {
@@ -155,6 +162,7 @@ mod tests {
             diff: String::new(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: crate::presentation::app_mode::DiffPreview::default(),
@@ -20,6 +20,7 @@ pub mod session_message;
 pub mod session_order;
 pub mod setting;
 pub mod theme;
+pub(crate) mod tool_approval;
 pub(crate) mod transcript_notice;
 pub(crate) mod transient_message;
 
@@ -34,9 +35,11 @@ pub mod agent {
     };
 }
 
-/// Agent permission mode metadata.
+/// Agent permission mode and tool-approval metadata.
 pub mod permission {
-    pub use ag_agent::PermissionMode;
+    pub use ag_agent::{
+        PermissionMode, ToolApprovalDecision, ToolApprovalKind, ToolApprovalRequest,
+    };
 }
 
 /// Canonical turn prompt payload types.
@@ -10,6 +10,7 @@ use crate::domain::agent::{
 };
 use crate::domain::input::InputState;
 use crate::domain::personality::PersonalitySummary;
+use crate::domain::session::SessionTurnCheckpoint;
 
 /// One selectable row in the prompt slash-command menu.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -48,6 +49,8 @@ pub enum PromptSuggestionSelection {
     Personality(Option<PersonalitySummary>),
     /// Session-scoped reasoning selection chosen during `/reasoning`.
     Reasoning(ReasoningLevel),
+    /// One-based turn checkpoint chosen during `/rewind`.
+    Rewind(u32),
     /// Session-scoped response-speed selection chosen during `/speed`.
     Speed(SpeedMode),
 }
@@ -370,6 +373,8 @@ pub enum PromptSlashStage {
     Personality,
     /// Selecting a session-specific reasoning level override.
     Reasoning,
+    /// Selecting a turn checkpoint to restore.
+    Rewind,
     /// Selecting a session-specific response-speed preference.
     Speed,
 }
@@ -379,6 +384,8 @@ pub enum PromptSlashStage {
 pub struct PromptSlashState {
     /// Agent kinds currently runnable on this machine for `/model`.
     pub available_agent_kinds: Vec<AgentKind>,
+    /// Turn checkpoints loaded when `/rewind` was accepted, newest first.
+    pub checkpoints: Vec<SessionTurnCheckpoint>,
     /// Workspace personalities loaded when `/personality` was accepted.
     pub personalities: Vec<PersonalitySummary>,
     /// Agent selected for the current slash workflow, when applicable.
@@ -396,6 +403,7 @@ impl PromptSlashState {
     pub fn with_available_agent_kinds(available_agent_kinds: Vec<AgentKind>) -> Self {
         Self {
             available_agent_kinds,
+            checkpoints: Vec::new(),
             personalities: Vec::new(),
             selected_agent: None,
             selected_index: 0,
@@ -424,6 +432,7 @@ impl PromptSlashState {
     /// Resets slash state back to command selection.
     pub fn reset(&mut self) {
         self.selected_agent = None;
+        self.checkpoints.clear();
         self.personalities.clear();
         self.selected_index = 0;
         self.stage = PromptSlashStage::Command;
@@ -596,26 +605,12 @@ impl Default for PromptComposerState {
 #[must_use]
 pub fn prompt_slash_option_count(
     input: &str,
-    stage: PromptSlashStage,
-    selected_agent: Option<AgentKind>,
-    available_agent_kinds: &[AgentKind],
-    personalities: &[PersonalitySummary],
+    slash_state: &PromptSlashState,
     session_agent_kind: AgentKind,
     allow_apply_command: bool,
 ) -> usize {
-    build_prompt_slash_suggestion_list(
-        input,
-        &PromptSlashState {
-            available_agent_kinds: available_agent_kinds.to_vec(),
-            personalities: personalities.to_vec(),
-            selected_agent,
-            selected_index: 0,
-            stage,
-        },
-        session_agent_kind,
-        allow_apply_command,
-    )
-    .map_or(0, |suggestion_list| suggestion_list.items.len())
+    build_prompt_slash_suggestion_list(input, slash_state, session_agent_kind, allow_apply_command)
+        .map_or(0, |suggestion_list| suggestion_list.items.len())
 }
 
 /// Returns the character range deleted by one current-line delete action.
@@ -892,6 +887,10 @@ fn build_slash_suggestion_list(
             "/reasoning Level (j/k move, Enter select)",
             reasoning_suggestion_items(),
         ),
+        PromptSlashStage::Rewind => (
+            "/rewind Turn (j/k move, Enter restore)",
+            rewind_suggestion_items(&slash_state.checkpoints),
+        ),
         PromptSlashStage::Speed => (
             "/speed Mode (j/k move, Enter select)",
             speed_suggestion_items(),
@@ -986,6 +985,13 @@ fn selected_slash_action(
 
             Some(PromptSuggestionSelection::Reasoning(selected_reasoning))
         }
+        PromptSlashStage::Rewind => slash_state
+            .checkpoints
+            .get(clamp_selected_index(
+                slash_state.selected_index,
+                slash_state.checkpoints.len(),
+            ))
+            .map(|checkpoint| PromptSuggestionSelection::Rewind(checkpoint.turn)),
         PromptSlashStage::Speed => {
             let options = SpeedMode::ALL;
             let selected_speed_mode = options
@@ -1028,6 +1034,7 @@ fn command_description(command: &str) -> &'static str {
         "/model" => "Choose an agent and model for this session.",
         "/personality" => "List: .agents/agents/. Choose a personality for this session.",
         "/reasoning" => "Override the reasoning level for this session.",
+        "/rewind" => "Restore the worktree and transcript to an earlier turn.",
         "/speed" => "Choose normal or fast responses for this session.",
         _ => "Prompt slash command.",
     }
@@ -1040,7 +1047,14 @@ fn prompt_slash_commands(
     allow_apply_command: bool,
 ) -> Vec<&'static str> {
     let lowered = input.to_lowercase();
-    let mut commands = vec!["/apply", "/model", "/personality", "/reasoning", "/speed"];
+    let mut commands = vec![
+        "/apply",
+        "/model",
+        "/personality",
+        "/reasoning",
+        "/rewind",
+        "/speed",
+    ];
     if !allow_apply_command {
         commands.retain(|command| *command != "/apply");
     }
@@ -1164,6 +1178,36 @@ fn personality_suggestion_items(personalities: &[PersonalitySummary]) -> Vec<Pro
     .collect()
 }
 
+/// Returns render-ready rows for `/rewind`, newest turn first.
+fn rewind_suggestion_items(checkpoints: &[SessionTurnCheckpoint]) -> Vec<PromptSuggestionItem> {
+    if checkpoints.is_empty() {
+        return vec![PromptSuggestionItem {
+            badge: None,
+            detail: None,
+            label: "No turn checkpoints recorded yet.".to_string(),
+            metadata: None,
+        }];
+    }
+
+    checkpoints
+        .iter()
+        .map(|checkpoint| PromptSuggestionItem {
+            badge: Some(format!("#{}", checkpoint.turn)),
+            detail: Some(
+                checkpoint
+                    .prompt
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string(),
+            ),
+            label: format!("After turn {}", checkpoint.turn),
+            metadata: None,
+        })
+        .collect()
+}
+
 /// Returns the exclusive end index for an `[Image #n]` placeholder token that
 /// starts at `start_index`.
 fn image_token_end_index(characters: &[char], start_index: usize) -> Option<usize> {
@@ -1590,6 +1634,50 @@ mod tests {
         assert_eq!(selection, None);
     }
 
+    #[test]
+    fn test_rewind_slash_stage_lists_checkpoints_and_selects_turn() {
+        // Arrange
+        let mut slash_state = PromptSlashState {
+            checkpoints: vec![
+                SessionTurnCheckpoint {
+                    prompt: "Add tests\nwith details".to_string(),
+                    turn: 2,
+                },
+                SessionTurnCheckpoint {
+                    prompt: "Implement parser".to_string(),
+                    turn: 1,
+                },
+            ],
+            stage: PromptSlashStage::Rewind,
+            ..PromptSlashState::default()
+        };
+        slash_state.selected_index = 1;
+
+        // Act
+        let suggestion_list =
+            build_prompt_slash_suggestion_list("/rewind", &slash_state, AgentKind::Codex, false)
+                .expect("rewind suggestions should render");
+        let selection =
+            resolve_prompt_slash_selection("/rewind", &slash_state, AgentKind::Codex, false);
+        slash_state.checkpoints.clear();
+        let empty_selection =
+            resolve_prompt_slash_selection("/rewind", &slash_state, AgentKind::Codex, false);
+
+        // Assert
+        assert_eq!(
+            suggestion_list.items[0],
+            PromptSuggestionItem {
+                badge: Some("#2".to_string()),
+                detail: Some("Add tests".to_string()),
+                label: "After turn 2".to_string(),
+                metadata: None,
+            }
+        );
+        assert_eq!(suggestion_list.selected_index, 1);
+        assert_eq!(selection, Some(PromptSuggestionSelection::Rewind(1)));
+        assert_eq!(empty_selection, None);
+    }
+
     #[test]
     fn test_selected_slash_action_uses_fuzzy_matched_command() {
         // Arrange
@@ -1976,7 +2064,7 @@ mod tests {
         // Assert
         assert_eq!(
             labels,
-            vec!["/model", "/personality", "/reasoning", "/speed"]
+            vec!["/model", "/personality", "/reasoning", "/rewind", "/speed"]
         );
         assert_eq!(suggestion_list.selected_index, 0);
     }
@@ -1998,7 +2086,7 @@ mod tests {
                 .iter()
                 .map(|item| item.label.as_str())
                 .collect::<Vec<_>>(),
-            vec!["/model", "/personality", "/reasoning"]
+            vec!["/model", "/personality", "/reasoning", "/rewind"]
         );
     }
 
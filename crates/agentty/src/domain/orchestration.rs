@@ -1,7 +1,95 @@
-//! Compatibility exports for frontend-neutral orchestration models.
+//! Frontend-neutral orchestration models and the campaign board snapshot.
 
 pub use ag_session::{
     IntegrationApproach, MAX_AUTOMATED_REVIEW_ITERATIONS, OrchestrationPlanTask,
     OrchestrationPolicy, OrchestrationScheduleDecision, OrchestrationStatus, OrchestrationTaskKind,
-    OrchestrationTaskStatus, validate_subtasks,
+    OrchestrationTaskObservation, OrchestrationTaskStatus, PrerequisiteState, dependency_waves,
+    validate_subtasks,
 };
+
+use crate::domain::session::SessionId;
+
+/// Persisted campaign state shown on the full-screen plan board.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrchestrationBoard {
+    /// Campaign lifecycle phase.
+    pub status: OrchestrationStatus,
+    /// Tasks in integration order.
+    pub tasks: Vec<OrchestrationBoardTask>,
+}
+
+impl OrchestrationBoard {
+    /// Returns whether integration order may still change.
+    pub fn can_reorder(&self) -> bool {
+        matches!(
+            self.status,
+            OrchestrationStatus::AwaitingApproval
+                | OrchestrationStatus::Running
+                | OrchestrationStatus::Verifying
+                | OrchestrationStatus::AwaitingIntegration
+        )
+    }
+}
+
+/// One campaign task with the plan details hidden by the compact monitor.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrchestrationBoardTask {
+    /// Observable conditions checked during verification.
+    pub acceptance_criteria: Vec<String>,
+    /// Managed child currently linked to the task.
+    pub child_session_id: Option<SessionId>,
+    /// Task keys that must pass verification first.
+    pub depends_on: Vec<String>,
+    /// Stable persistence identifier.
+    pub id: i64,
+    /// Implementation or research behavior.
+    pub kind: OrchestrationTaskKind,
+    /// Most recent failure detail.
+    pub last_error: Option<String>,
+    /// Standalone prompt handed to the child session.
+    pub prompt: String,
+    /// Task lifecycle state.
+    pub status: OrchestrationTaskStatus,
+    /// Stable key unique within the campaign.
+    pub task_key: String,
+    /// Short human-readable title.
+    pub title: String,
+    /// Best-effort repository-relative planning areas.
+    pub touched_areas: Vec<String>,
+    /// Controller explanation for the latest verdict.
+    pub verification_reason: Option<String>,
+    /// Latest controller verdict.
+    pub verification_verdict: Option<String>,
+    /// Dependency wave the task runs in.
+    pub wave: i64,
+}
+
+impl OrchestrationBoardTask {
+    /// Returns whether the plan fields can still be edited or deleted.
+    pub fn is_editable(&self) -> bool {
+        self.status == OrchestrationTaskStatus::Proposed
+    }
+
+    /// Returns whether the task can start over with a fresh child.
+    pub fn can_retry(&self) -> bool {
+        self.status == OrchestrationTaskStatus::Failed
+    }
+
+    /// Returns whether integration can continue without this task.
+    pub fn can_skip_integration(&self) -> bool {
+        self.status == OrchestrationTaskStatus::IntegrationFailed
+    }
+}
+
+/// User-entered replacement plan fields for one proposed task.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrchestrationTaskDraft {
+    /// Observable conditions checked during verification.
+    pub acceptance_criteria: Vec<String>,
+    /// Standalone prompt handed to the child session.
+    pub prompt: String,
+    /// Short human-readable title.
+    pub title: String,
+    /// Best-effort repository-relative planning areas.
+    pub touched_areas: Vec<String>,
+}
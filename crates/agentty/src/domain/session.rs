@@ -6,6 +6,7 @@ use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 
 pub use ag_agent::{SessionDiffState, SessionStats, SpeedMode};
+use ag_forge::{ReviewCheckOutcome, ReviewCheckReport};
 pub use ag_session::{
     ForgeKind, ReviewRequest, ReviewRequestState, ReviewRequestSummary, SessionId, SessionRole,
     SessionStatus as Status, activity_day_key_with_offset,
@@ -15,7 +16,9 @@ use tokio_util::sync::CancellationToken;
 
 use super::agent::{AgentSelection, ReasoningLevel};
 use super::session_message::SessionTranscript;
+use crate::domain::permission::{ToolApprovalDecision, ToolApprovalRequest};
 use crate::domain::question::QuestionItem;
+use crate::domain::tool_approval::ToolApprovalQueue;
 use crate::domain::transient_message::{
     TransientMessage, TransientMessageAnchor, TransientMessageBody, TransientMessageLifecycle,
     TransientMessageSlot, TransientMessageStore,
@@ -234,9 +237,21 @@ impl SessionFollowUpTask {
         FollowUpTaskAction::Launch
     }
 }
+/// One restorable turn checkpoint offered by `/rewind`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionTurnCheckpoint {
+    /// Prompt text that started the checkpointed turn.
+    pub prompt: String,
+    /// One-based turn number the checkpoint restores.
+    pub turn: u32,
+}
+
 /// In-memory snapshot of one persisted session row used by the UI and app
 /// orchestration layers.
 pub struct Session {
+    /// Whether transcript activity logs list every entry instead of the
+    /// collapsed summary; view-only state that is never persisted.
+    pub activity_log_expanded: bool,
     /// Agent provider and model selected for this session.
     pub agent: AgentSelection,
     /// Base branch used to create the session worktree.
@@ -268,10 +283,16 @@ pub struct Session {
     /// Parent session this stacked session is based on while its parent branch
     /// remains active.
     pub parent_session_id: Option<SessionId>,
+    /// Oldest provider approval request blocking the active `Ask` turn,
+    /// mirrored from [`SessionHandles`] for the approval overlay.
+    pub pending_tool_approval: Option<ToolApprovalRequest>,
     /// Provider permission mode selected through the prompt shortcut.
     pub permission_mode: crate::domain::permission::PermissionMode,
     /// Workspace personality selected for future turns, when present.
     pub personality_id: Option<String>,
+    /// Latest post-turn check result, mirrored from [`SessionHandles`] for the
+    /// session header status row.
+    pub post_turn_checks: Option<PostTurnCheckReport>,
     /// Human-readable project name associated with the session.
     pub project_name: String,
     /// Initial user prompt used to create the session.
@@ -288,6 +309,9 @@ pub struct Session {
     pub published_upstream_ref: Option<String>,
     /// Model clarification questions emitted by the agent.
     pub questions: Vec<QuestionItem>,
+    /// Latest CI checks reported for the linked review request head commit,
+    /// mirrored from [`SessionHandles`] for the list badge and header row.
+    pub review_checks: Option<ReviewCheckReport>,
     /// Persisted forge review-request link for this session, when available.
     pub review_request: Option<ReviewRequest>,
     /// Role this session plays in multi-session orchestration.
@@ -443,6 +467,14 @@ impl Session {
             && (self.status.allows_review_actions() || self.status == Status::Question)
     }
 
+    /// Returns whether diff-view reverts may discard changes from the session
+    /// worktree.
+    pub fn allows_diff_revert_action(&self) -> bool {
+        self.accepts_user_turns()
+            && self.role.owns_branch_changes()
+            && self.status.allows_review_actions()
+    }
+
     /// Returns whether the session lifecycle and ownership role permit opening
     /// its materialized worktree.
     ///
@@ -470,6 +502,19 @@ impl Session {
         self.role.is_managed()
     }
 
+    /// Returns the permission mode providers enforce for the next turn.
+    ///
+    /// Read-only roles override the persisted mode, which is kept so the
+    /// user's choice is restored if the role ever changes. `Ask` shows as
+    /// read-only on providers without an approval channel.
+    pub fn effective_permission_mode(&self) -> crate::domain::permission::PermissionMode {
+        if self.role.enforces_read_only() {
+            return crate::domain::permission::PermissionMode::ReadOnly;
+        }
+
+        self.permission_mode.for_agent_kind(self.agent.kind())
+    }
+
     /// Returns whether this session belongs to a one-level stack beneath a
     /// parent session branch.
     pub fn is_stacked_child(&self) -> bool {
@@ -569,11 +614,42 @@ impl Session {
         String::new()
     }
 
+    /// Returns whether this session was imported from a review request and
+    /// is based on the request's fetched head commit.
+    ///
+    /// Imported sessions persist that commit hash as their base so session
+    /// commits stack on the author's commits. The base is therefore no local
+    /// branch that a merge could land on or a sync could pick up new commits
+    /// from.
+    pub fn is_review_request_import(&self) -> bool {
+        matches!(self.base_branch.len(), 40 | 64)
+            && self
+                .base_branch
+                .chars()
+                .all(|character| character.is_ascii_hexdigit())
+    }
+
     /// Returns whether this session has a linked forge review request.
     pub fn has_review_request(&self) -> bool {
         self.review_request.is_some()
     }
 
+    /// Returns whether the latest CI checks for the linked review request
+    /// include a failed run.
+    pub fn has_failed_review_checks(&self) -> bool {
+        self.review_checks
+            .as_ref()
+            .is_some_and(|report| report.outcome() == Some(ReviewCheckOutcome::Failed))
+    }
+
+    /// Returns whether the linked forge review request is still an open draft
+    /// that may be marked ready for review.
+    pub fn has_draft_review_request(&self) -> bool {
+        self.review_request
+            .as_ref()
+            .is_some_and(|review_request| review_request.summary.is_draft())
+    }
+
     /// Returns whether this session can trigger a forge review request sync.
     ///
     /// Sync is available when the session has a published branch or a linked
@@ -697,8 +773,9 @@ pub(crate) fn can_mutate_session_branch_in_stack(sessions: &[Session], session_i
 /// Returns whether a session can enter the merge queue while preserving stack
 /// consistency.
 ///
-/// A linked forge review request disables local merge queueing so the remote
-/// review remains the only merge path. Otherwise, merging a parent with idle
+/// A linked forge review request or an imported review-request base disables
+/// local merge queueing so the remote review remains the only merge path.
+/// Otherwise, merging a parent with idle
 /// materialized children is allowed because the successful parent merge
 /// retargets and syncs the children afterward. Active stack members still
 /// block the request so the stack does not run competing branch work.
@@ -708,13 +785,15 @@ pub(crate) fn can_merge_session_branch_in_stack(sessions: &[Session], session_id
     };
 
     stack.requested_session.review_request.is_none()
+        && !stack.requested_session.is_review_request_import()
         && !stack.has_branch_mutating_member_except(session_id)
 }
 
 /// Returns whether a session can start session sync while preserving stack
 /// consistency.
 ///
-/// Like merge, syncing a parent with idle materialized children is allowed
+/// Imported review-request sessions have no base branch to sync from. Like
+/// merge, syncing a parent with idle materialized children is allowed
 /// because the successful parent sync fans out child syncs afterward. Active
 /// stack members still block the request so the stack does not run competing
 /// branch work.
@@ -723,7 +802,8 @@ pub(crate) fn can_rebase_session_branch_in_stack(sessions: &[Session], session_i
         return false;
     };
 
-    !stack.has_branch_mutating_member_except(session_id)
+    !stack.requested_session.is_review_request_import()
+        && !stack.has_branch_mutating_member_except(session_id)
 }
 
 /// Returns whether a session can accept a chat reply under one-level stack
@@ -868,6 +948,37 @@ impl QueuedMessage {
     }
 }
 
+/// Result of the project post-turn checks run after one completed turn.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PostTurnCheckOutcome {
+    /// Every configured check command exited successfully.
+    Passed {
+        /// Number of commands that ran.
+        command_count: usize,
+    },
+    /// One check command failed or exceeded its timeout; later commands
+    /// were skipped.
+    Failed {
+        /// Command line that failed.
+        command: String,
+        /// Trailing captured output of the failed command.
+        output: String,
+        /// Whether the command was stopped at the configured timeout.
+        timed_out: bool,
+    },
+}
+
+/// Latest post-turn check result rendered as the session header status row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PostTurnCheckReport {
+    /// Automatic fix turns already requested for consecutive failures.
+    pub fix_attempts: u8,
+    /// Maximum automatic fix turns allowed by the project settings.
+    pub max_fix_attempts: u8,
+    /// Outcome of the most recent check run.
+    pub outcome: PostTurnCheckOutcome,
+}
+
 /// Shared runtime handles for one active session worker.
 pub struct SessionHandles {
     /// Serializes branch-publish ownership with queued branch operations.
@@ -896,8 +1007,14 @@ pub struct SessionHandles {
     pub status: Arc<Mutex<Status>>,
     /// Shared typed transcript snapshot mirrored to the render layer.
     pub transcript: Arc<Mutex<SessionTranscript>>,
+    /// Latest post-turn check result mirrored into the session header.
+    post_turn_checks: Mutex<Option<PostTurnCheckReport>>,
     /// Queued workflow rows that must survive active-project snapshot reloads.
     queued_actions: Arc<Mutex<TransientMessageStore>>,
+    /// Latest linked review-request CI checks refreshed by background sync.
+    review_checks: Mutex<Option<ReviewCheckReport>>,
+    /// Provider approval requests waiting for a user decision in `Ask` mode.
+    tool_approvals: Arc<Mutex<ToolApprovalQueue>>,
     /// Whether [`Self::transcript`] contains the complete persisted history.
     ///
     /// Lazy session-list handles start unhydrated so background workflow
@@ -912,10 +1029,13 @@ impl SessionHandles {
             branch_operation_lock: Arc::new(AsyncMutex::new(())),
             cancel_token: Arc::new(Mutex::new(CancellationToken::new())),
             child_pid: Arc::new(Mutex::new(None)),
+            post_turn_checks: Mutex::new(None),
             queued_actions: Arc::new(Mutex::new(TransientMessageStore::default())),
             queued_messages: Arc::new(Mutex::new(VecDeque::new())),
             queued_work_sequence: Arc::new(AtomicU64::new(0)),
+            review_checks: Mutex::new(None),
             status: Arc::new(Mutex::new(status)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
             transcript: Arc::new(Mutex::new(SessionTranscript::default())),
             transcript_is_hydrated: AtomicBool::new(true),
         }
@@ -927,10 +1047,13 @@ impl SessionHandles {
             branch_operation_lock: Arc::new(AsyncMutex::new(())),
             cancel_token: Arc::new(Mutex::new(CancellationToken::new())),
             child_pid: Arc::new(Mutex::new(None)),
+            post_turn_checks: Mutex::new(None),
             queued_actions: Arc::new(Mutex::new(TransientMessageStore::default())),
             queued_messages: Arc::new(Mutex::new(VecDeque::new())),
             queued_work_sequence: Arc::new(AtomicU64::new(0)),
+            review_checks: Mutex::new(None),
             status: Arc::new(Mutex::new(status)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
             transcript: Arc::new(Mutex::new(SessionTranscript::default())),
             transcript_is_hydrated: AtomicBool::new(false),
         }
@@ -942,10 +1065,13 @@ impl SessionHandles {
             branch_operation_lock: Arc::new(AsyncMutex::new(())),
             cancel_token: Arc::new(Mutex::new(CancellationToken::new())),
             child_pid: Arc::new(Mutex::new(None)),
+            post_turn_checks: Mutex::new(None),
             queued_actions: Arc::new(Mutex::new(TransientMessageStore::default())),
             queued_messages: Arc::new(Mutex::new(VecDeque::new())),
             queued_work_sequence: Arc::new(AtomicU64::new(0)),
+            review_checks: Mutex::new(None),
             status: Arc::new(Mutex::new(status)),
+            tool_approvals: Arc::new(Mutex::new(ToolApprovalQueue::default())),
             transcript: Arc::new(Mutex::new(transcript)),
             transcript_is_hydrated: AtomicBool::new(true),
         }
@@ -973,6 +1099,15 @@ impl SessionHandles {
         Some(transcript.clone())
     }
 
+    /// Replaces the live transcript with a complete persisted snapshot, such
+    /// as the history left after `/rewind` truncation.
+    pub(crate) fn replace_transcript(&self, transcript: SessionTranscript) {
+        if let Ok(mut live_transcript) = self.transcript.lock() {
+            *live_transcript = transcript;
+            self.transcript_is_hydrated.store(true, Ordering::Release);
+        }
+    }
+
     /// Reserves the next shared submission order for queued session work.
     pub(crate) fn next_queued_work_order(&self) -> u64 {
         self.queued_work_sequence.fetch_add(1, Ordering::Relaxed)
@@ -990,6 +1125,46 @@ impl SessionHandles {
             .unwrap_or_default()
     }
 
+    /// Returns the latest post-turn check result, when checks have run.
+    pub(crate) fn post_turn_check_report(&self) -> Option<PostTurnCheckReport> {
+        self.post_turn_checks
+            .lock()
+            .ok()
+            .and_then(|report| report.clone())
+    }
+
+    /// Replaces the latest post-turn check result.
+    pub(crate) fn set_post_turn_check_report(&self, report: PostTurnCheckReport) {
+        if let Ok(mut current_report) = self.post_turn_checks.lock() {
+            *current_report = Some(report);
+        }
+    }
+
+    /// Returns the latest linked review-request CI checks, when loaded.
+    pub(crate) fn review_check_report(&self) -> Option<ReviewCheckReport> {
+        self.review_checks
+            .lock()
+            .ok()
+            .and_then(|report| report.clone())
+    }
+
+    /// Replaces the latest linked review-request CI checks.
+    pub(crate) fn set_review_check_report(&self, report: Option<ReviewCheckReport>) {
+        if let Ok(mut current_report) = self.review_checks.lock() {
+            *current_report = report;
+        }
+    }
+
+    /// Restores the full automatic fix budget after a user-submitted turn
+    /// while keeping the latest check result visible.
+    pub(crate) fn reset_post_turn_check_fix_attempts(&self) {
+        if let Ok(mut current_report) = self.post_turn_checks.lock()
+            && let Some(report) = current_report.as_mut()
+        {
+            report.fix_attempts = 0;
+        }
+    }
+
     /// Stores one queued workflow row beside the worker-owned queue state.
     pub(crate) fn upsert_queued_action(&self, message: TransientMessage) {
         debug_assert!(matches!(&message.body, TransientMessageBody::Queued(_)));
@@ -1020,6 +1195,27 @@ impl SessionHandles {
             .unwrap_or_default()
     }
 
+    /// Returns the shared approval queue consumed by the session worker.
+    pub(crate) fn tool_approval_queue(&self) -> Arc<Mutex<ToolApprovalQueue>> {
+        Arc::clone(&self.tool_approvals)
+    }
+
+    /// Returns the oldest approval request still blocking the active turn.
+    pub(crate) fn pending_tool_approval(&self) -> Option<ToolApprovalRequest> {
+        self.tool_approvals
+            .lock()
+            .ok()
+            .and_then(|mut tool_approvals| tool_approvals.current())
+    }
+
+    /// Answers the oldest pending approval request and returns whether a
+    /// waiting turn received the decision.
+    pub(crate) fn resolve_tool_approval(&self, decision: ToolApprovalDecision) -> bool {
+        self.tool_approvals
+            .lock()
+            .is_ok_and(|mut tool_approvals| tool_approvals.resolve(decision))
+    }
+
     /// Merges messages appended while persistence was in flight into a
     /// database snapshot, deduplicating exact matches and retaining conflicts.
     fn merge_unloaded_transcript(
@@ -1281,6 +1477,34 @@ pub(crate) mod tests {
         assert!(!managed_allows_reply);
     }
 
+    #[test]
+    fn test_allows_diff_revert_action_requires_settled_user_owned_session() {
+        // Arrange
+        let statuses = [
+            Status::Review,
+            Status::AgentReview,
+            Status::InProgress,
+            Status::Merged,
+        ];
+        let managed_session = SessionFixtureBuilder::new()
+            .role(SessionRole::OrchestrationWorker)
+            .status(Status::Review)
+            .build();
+
+        // Act
+        let revert_permissions = statuses.map(|status| {
+            SessionFixtureBuilder::new()
+                .status(status)
+                .build()
+                .allows_diff_revert_action()
+        });
+        let managed_allows_revert = managed_session.allows_diff_revert_action();
+
+        // Assert
+        assert_eq!(revert_permissions, [true, true, false, false]);
+        assert!(!managed_allows_revert);
+    }
+
     #[test]
     fn test_allows_worktree_open_action_accepts_managed_worker_only_in_review() {
         // Arrange
@@ -1512,6 +1736,34 @@ pub(crate) mod tests {
         assert!(!can_merge_session);
     }
 
+    #[test]
+    fn test_merge_and_rebase_stack_gates_block_imported_review_request_base() {
+        // Arrange
+        let imported_session = SessionFixtureBuilder::new()
+            .id("imported-session")
+            .base_branch("0123456789abcdef0123456789abcdef01234567")
+            .status(Status::Review)
+            .build();
+        let branch_session = SessionFixtureBuilder::new()
+            .id("branch-session")
+            .base_branch("deadbeef")
+            .status(Status::Review)
+            .build();
+        let sessions = vec![imported_session, branch_session];
+
+        // Act
+        let can_merge_imported = can_merge_session_branch_in_stack(&sessions, "imported-session");
+        let can_rebase_imported = can_rebase_session_branch_in_stack(&sessions, "imported-session");
+        let can_merge_branch = can_merge_session_branch_in_stack(&sessions, "branch-session");
+        let can_rebase_branch = can_rebase_session_branch_in_stack(&sessions, "branch-session");
+
+        // Assert
+        assert!(!can_merge_imported);
+        assert!(!can_rebase_imported);
+        assert!(can_merge_branch);
+        assert!(can_rebase_branch);
+    }
+
     #[test]
     fn test_can_mutate_session_branch_in_stack_blocks_concurrent_stack_member() {
         // Arrange
@@ -2239,6 +2491,7 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             reasoning_level_override: None,
             published_upstream_ref: None,
             questions: Vec::new(),
+            review_checks: None,
             review_request: None,
             size: SessionSize::Xs,
             speed_mode: SpeedMode::default(),
@@ -2249,6 +2502,9 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             transcript: None,
             updated_at: 0,
             transient_messages: TransientMessageStore::default(),
+            pending_tool_approval: None,
+            post_turn_checks: None,
+            activity_log_expanded: false,
         };
 
         // Act
@@ -2314,6 +2570,7 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             reasoning_level_override: None,
             published_upstream_ref: Some("origin/wt/session-id".to_string()),
             questions: Vec::new(),
+            review_checks: None,
             review_request: None,
             size: SessionSize::Xs,
             speed_mode: SpeedMode::default(),
@@ -2324,6 +2581,9 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             transcript: None,
             updated_at: 0,
             transient_messages: TransientMessageStore::default(),
+            pending_tool_approval: None,
+            post_turn_checks: None,
+            activity_log_expanded: false,
         };
 
         // Act
@@ -2362,6 +2622,7 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             reasoning_level_override: None,
             published_upstream_ref: Some("origin/wt/session-id".to_string()),
             questions: Vec::new(),
+            review_checks: None,
             review_request: None,
             size: SessionSize::Xs,
             speed_mode: SpeedMode::default(),
@@ -2372,6 +2633,9 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             transcript: None,
             updated_at: 0,
             transient_messages: TransientMessageStore::default(),
+            pending_tool_approval: None,
+            post_turn_checks: None,
+            activity_log_expanded: false,
         };
 
         // Act
@@ -2410,6 +2674,7 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             reasoning_level_override: None,
             published_upstream_ref: None,
             questions: Vec::new(),
+            review_checks: None,
             review_request: None,
             size: SessionSize::Xs,
             speed_mode: SpeedMode::default(),
@@ -2420,6 +2685,9 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             transcript: None,
             updated_at: 0,
             transient_messages: TransientMessageStore::default(),
+            pending_tool_approval: None,
+            post_turn_checks: None,
+            activity_log_expanded: false,
         };
 
         // Act
@@ -2458,6 +2726,7 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             reasoning_level_override: None,
             published_upstream_ref: None,
             questions: Vec::new(),
+            review_checks: None,
             review_request: None,
             size: SessionSize::Xs,
             speed_mode: SpeedMode::default(),
@@ -2468,6 +2737,9 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             transcript: None,
             updated_at: 0,
             transient_messages: TransientMessageStore::default(),
+            pending_tool_approval: None,
+            post_turn_checks: None,
+            activity_log_expanded: false,
         };
 
         // Act
@@ -1,5 +1,7 @@
-//! Compatibility exports for session transcript models owned by `ag-session`.
+//! Compatibility exports for session transcript models owned by `ag-session`
+//! and the structured turn activity payload stored in transcript rows.
 
+pub use ag_agent::TurnActivity;
 pub use ag_session::{
     SessionMessage, SessionMessageKind, SessionMessageKindParseError, SessionTranscript,
     normalized_message_content, stored_message_content,
@@ -1,6 +1,8 @@
 //! Compatibility exports for frontend-neutral persisted setting keys.
 
 pub(crate) use ag_session::{
-    DEFAULT_AUTO_APPROVE_ORCHESTRATION_RESEARCH, DEFAULT_ORCHESTRATION_PARALLELISM,
-    MAX_ORCHESTRATION_PARALLELISM, SettingName,
+    DEFAULT_AUTO_APPROVE_ORCHESTRATION_RESEARCH, DEFAULT_MERGE_GATE_TIMEOUT_SECONDS,
+    DEFAULT_ORCHESTRATION_PARALLELISM, DEFAULT_POST_TURN_CHECK_TIMEOUT_SECONDS,
+    MAX_ORCHESTRATION_PARALLELISM, MAX_POST_TURN_CHECK_FIX_RETRIES,
+    MERGE_GATE_TIMEOUT_OPTIONS_SECONDS, POST_TURN_CHECK_TIMEOUT_OPTIONS_SECONDS, SettingName,
 };
@@ -1,9 +1,22 @@
 //! Domain model for user-selectable terminal color themes.
+//!
+//! Built-in themes are enum variants; user themes are TOML files under
+//! `~/.agentty/themes/` whose `[colors]` section assigns one color per
+//! [`ThemeColorKey`].
 
+use std::collections::BTreeMap;
 use std::fmt;
 
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Persisted-value prefix that marks a user theme file stem.
+const CUSTOM_THEME_PREFIX: &str = "custom:";
+/// Missing color keys named in a theme notice before the rest are counted.
+const MISSING_KEY_NOTICE_LIMIT: usize = 3;
+
 /// Terminal color themes available in settings.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Default)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Default)]
 pub enum ColorTheme {
     /// The default Agentty terminal palette shown as `Agentty Default`.
     #[default]
@@ -12,29 +25,36 @@ pub enum ColorTheme {
     Green,
     /// A warm dark palette inspired by the Horizon editor theme.
     DarkHorizon,
+    /// A user theme file identified by its file stem.
+    Custom(String),
 }
 
 impl ColorTheme {
-    /// All selectable color themes in settings display order.
+    /// Built-in color themes in settings display order.
     pub const ALL: [Self; 3] = [Self::Current, Self::Green, Self::DarkHorizon];
 
     /// Returns the persisted wire value for this theme.
     #[must_use]
-    pub fn as_str(self) -> &'static str {
+    pub fn persisted_value(&self) -> String {
         match self {
-            Self::Current => "current",
-            Self::Green => "green",
-            Self::DarkHorizon => "dark_horizon",
+            Self::Current => "current".to_string(),
+            Self::Green => "green".to_string(),
+            Self::DarkHorizon => "dark_horizon".to_string(),
+            Self::Custom(id) => format!("{CUSTOM_THEME_PREFIX}{id}"),
         }
     }
 
     /// Returns the human-readable theme name shown in the settings page.
+    ///
+    /// User themes show their file stem; the settings projection prefers the
+    /// `name` declared inside a loaded theme file.
     #[must_use]
-    pub fn label(self) -> &'static str {
+    pub fn label(&self) -> &str {
         match self {
             Self::Current => "Agentty Default",
             Self::Green => "Agentty Green",
             Self::DarkHorizon => "Dark Horizon",
+            Self::Custom(id) => id,
         }
     }
 
@@ -48,20 +68,22 @@ impl ColorTheme {
             "current" => Some(Self::Current),
             "green" => Some(Self::Green),
             "dark_horizon" => Some(Self::DarkHorizon),
-            _ => None,
+            _ => value
+                .strip_prefix(CUSTOM_THEME_PREFIX)
+                .filter(|id| !id.is_empty())
+                .map(|id| Self::Custom(id.to_string())),
         }
     }
 
-    /// Returns the next theme in settings selector order.
+    /// Returns the next built-in theme in settings selector order.
+    ///
+    /// User themes advance to the first built-in theme.
     #[must_use]
-    pub fn next(self) -> Self {
-        let current_index = Self::ALL
-            .iter()
-            .position(|theme| *theme == self)
-            .unwrap_or(0);
-        let next_index = (current_index + 1) % Self::ALL.len();
+    pub fn next(&self) -> Self {
+        let current_index = Self::ALL.iter().position(|theme| theme == self);
+        let next_index = current_index.map_or(0, |index| (index + 1) % Self::ALL.len());
 
-        Self::ALL[next_index]
+        Self::ALL[next_index].clone()
     }
 }
 
@@ -71,6 +93,356 @@ impl fmt::Display for ColorTheme {
     }
 }
 
+/// Semantic color slot that a user theme file can assign.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ThemeColorKey {
+    /// Primary accent for focused elements, titles, and keywords.
+    Accent,
+    /// Secondary accent for queued sessions and published branches.
+    AccentSoft,
+    /// Panel borders and separators.
+    Border,
+    /// Destructive and failed states, including removed diff text.
+    Danger,
+    /// Softer danger tone for graded severity scales.
+    DangerSoft,
+    /// Review states, types, and secondary headings.
+    Info,
+    /// Question-status emphasis.
+    Question,
+    /// Base surface for bars and table headers.
+    Surface,
+    /// Surface behind clarification prompt blocks.
+    SurfaceClarification,
+    /// Surface behind removed diff lines.
+    SurfaceDanger,
+    /// Elevated surface for table headers and code blocks.
+    SurfaceElevated,
+    /// Surface behind selected rows and list entries.
+    SurfaceSelection,
+    /// Surface behind added diff lines.
+    SurfaceSuccess,
+    /// Surface that dims content behind modal overlays.
+    SurfaceOverlay,
+    /// Surface behind user prompt transcript blocks.
+    SurfacePrompt,
+    /// Primary readable text.
+    Text,
+    /// Secondary text.
+    TextMuted,
+    /// Placeholders, hints, and comments.
+    TextSubtle,
+    /// Positive states, merged sessions, and added diff text.
+    Success,
+    /// Softer success tone for graded severity scales.
+    SuccessSoft,
+    /// In-progress and caution states, constants, and inline code.
+    Warning,
+    /// Softer warning tone for clarification headings and functions.
+    WarningSoft,
+    /// Activity heatmap cell without activity.
+    HeatmapEmpty,
+    /// Activity heatmap lowest intensity.
+    HeatmapLevel1,
+    /// Activity heatmap second intensity.
+    HeatmapLevel2,
+    /// Activity heatmap third intensity.
+    HeatmapLevel3,
+    /// Activity heatmap highest intensity.
+    HeatmapLevel4,
+}
+
+impl ThemeColorKey {
+    /// Every assignable color slot in theme-file documentation order.
+    pub const ALL: [Self; 27] = [
+        Self::Accent,
+        Self::AccentSoft,
+        Self::Border,
+        Self::Danger,
+        Self::DangerSoft,
+        Self::Info,
+        Self::Question,
+        Self::Surface,
+        Self::SurfaceClarification,
+        Self::SurfaceDanger,
+        Self::SurfaceElevated,
+        Self::SurfaceSelection,
+        Self::SurfaceSuccess,
+        Self::SurfaceOverlay,
+        Self::SurfacePrompt,
+        Self::Text,
+        Self::TextMuted,
+        Self::TextSubtle,
+        Self::Success,
+        Self::SuccessSoft,
+        Self::Warning,
+        Self::WarningSoft,
+        Self::HeatmapEmpty,
+        Self::HeatmapLevel1,
+        Self::HeatmapLevel2,
+        Self::HeatmapLevel3,
+        Self::HeatmapLevel4,
+    ];
+
+    /// Returns the key used inside the theme file `[colors]` section.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Accent => "accent",
+            Self::AccentSoft => "accent_soft",
+            Self::Border => "border",
+            Self::Danger => "danger",
+            Self::DangerSoft => "danger_soft",
+            Self::Info => "info",
+            Self::Question => "question",
+            Self::Surface => "surface",
+            Self::SurfaceClarification => "surface_clarification",
+            Self::SurfaceDanger => "surface_danger",
+            Self::SurfaceElevated => "surface_elevated",
+            Self::SurfaceSelection => "surface_selection",
+            Self::SurfaceSuccess => "surface_success",
+            Self::SurfaceOverlay => "surface_overlay",
+            Self::SurfacePrompt => "surface_prompt",
+            Self::Text => "text",
+            Self::TextMuted => "text_muted",
+            Self::TextSubtle => "text_subtle",
+            Self::Success => "success",
+            Self::SuccessSoft => "success_soft",
+            Self::Warning => "warning",
+            Self::WarningSoft => "warning_soft",
+            Self::HeatmapEmpty => "heatmap_empty",
+            Self::HeatmapLevel1 => "heatmap_level_1",
+            Self::HeatmapLevel2 => "heatmap_level_2",
+            Self::HeatmapLevel3 => "heatmap_level_3",
+            Self::HeatmapLevel4 => "heatmap_level_4",
+        }
+    }
+}
+
+/// Standard ANSI color names accepted in theme files, by palette index.
+const ANSI_COLOR_NAMES: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "gray",
+    "dark_gray",
+    "light_red",
+    "light_green",
+    "light_yellow",
+    "light_blue",
+    "light_magenta",
+    "light_cyan",
+    "white",
+];
+
+/// One color value assigned by a user theme file.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ThemeColor {
+    /// The terminal's own default foreground or background.
+    Default,
+    /// Terminal palette index; `0..=15` are the standard ANSI colors.
+    Indexed(u8),
+    /// 24-bit color.
+    Rgb(u8, u8, u8),
+}
+
+impl ThemeColor {
+    /// Parses `#rrggbb`, an ANSI color name such as `light_blue`, a palette
+    /// index `0`..`255`, or `default`.
+    ///
+    /// # Errors
+    /// Returns a description when `text` is not a supported color.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let normalized = text.trim().to_ascii_lowercase().replace(['-', ' '], "_");
+        if normalized == "default" || normalized == "reset" {
+            return Ok(Self::Default);
+        }
+        if let Some(hex) = normalized.strip_prefix('#') {
+            let channel = |range: std::ops::Range<usize>| {
+                hex.get(range)
+                    .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+            };
+            if hex.len() == 6
+                && let (Some(red), Some(green), Some(blue)) =
+                    (channel(0..2), channel(2..4), channel(4..6))
+            {
+                return Ok(Self::Rgb(red, green, blue));
+            }
+
+            return Err(format!("`{text}` is not a `#rrggbb` color"));
+        }
+        if let Ok(index) = normalized.parse::<u8>() {
+            return Ok(Self::Indexed(index));
+        }
+        let ansi_name = if normalized == "grey" {
+            "gray"
+        } else if normalized == "dark_grey" {
+            "dark_gray"
+        } else {
+            normalized.as_str()
+        };
+
+        ANSI_COLOR_NAMES
+            .iter()
+            .position(|name| *name == ansi_name)
+            .and_then(|index| u8::try_from(index).ok())
+            .map(Self::Indexed)
+            .ok_or_else(|| format!("unknown color `{text}`"))
+    }
+}
+
+/// One parsed user theme file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomTheme {
+    /// Colors assigned by the file; unassigned keys use the default palette.
+    pub colors: BTreeMap<ThemeColorKey, ThemeColor>,
+    /// File stem used as the persisted theme identifier.
+    pub id: String,
+    /// Display name from the file's `name` key, or the file stem.
+    pub name: String,
+}
+
+impl CustomTheme {
+    /// Returns the color slots the file leaves unassigned.
+    #[must_use]
+    pub fn missing_keys(&self) -> Vec<ThemeColorKey> {
+        ThemeColorKey::ALL
+            .into_iter()
+            .filter(|key| !self.colors.contains_key(key))
+            .collect()
+    }
+}
+
+/// Invalid user theme file content.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum CustomThemeError {
+    /// The file is not valid TOML, has an unknown top-level key, or assigns
+    /// a non-string value.
+    #[error("{0}")]
+    Syntax(String),
+    /// A color key or color value is not recognized.
+    #[error("{0}")]
+    Invalid(String),
+}
+
+/// Deserialized user theme file before color keys and values are validated.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CustomThemeFile {
+    /// Optional display name shown in the settings theme dropdown.
+    #[serde(default)]
+    name: Option<String>,
+    /// `[colors]` entries keyed by [`ThemeColorKey`] name.
+    #[serde(default)]
+    colors: BTreeMap<String, String>,
+}
+
+/// Parses one user theme file identified by its file stem.
+///
+/// The root `name` key sets the display name; the `[colors]` section assigns
+/// [`ThemeColorKey`] values.
+///
+/// # Errors
+/// Returns a [`CustomThemeError`] for syntax errors, unknown keys or
+/// sections, non-string values, and unsupported colors.
+pub fn parse_custom_theme(id: &str, text: &str) -> Result<CustomTheme, CustomThemeError> {
+    let file: CustomThemeFile = toml::from_str(text)
+        .map_err(|error| CustomThemeError::Syntax(syntax_message(text, &error)))?;
+    let mut colors = BTreeMap::new();
+
+    for (key, value) in &file.colors {
+        let color_key = ThemeColorKey::ALL
+            .into_iter()
+            .find(|color_key| color_key.as_str() == key)
+            .ok_or_else(|| CustomThemeError::Invalid(format!("unknown color key `{key}`")))?;
+        let color = ThemeColor::parse(value)
+            .map_err(|error| CustomThemeError::Invalid(format!("{key}: {error}")))?;
+        colors.insert(color_key, color);
+    }
+
+    Ok(CustomTheme {
+        colors,
+        id: id.to_string(),
+        name: file.name.unwrap_or_else(|| id.to_string()),
+    })
+}
+
+/// Formats a TOML error as one `line N: message` row for the settings page.
+fn syntax_message(text: &str, error: &toml::de::Error) -> String {
+    let message = error.message().trim_end();
+    let Some(span) = error.span() else {
+        return message.to_string();
+    };
+    let line = text
+        .get(..span.start)
+        .map_or(1, |prefix| prefix.matches('\n').count() + 1);
+
+    format!("line {line}: {message}")
+}
+
+/// User theme files discovered in the themes directory.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CustomThemeCatalog {
+    /// File stems whose content failed to parse, with the reason.
+    pub errors: BTreeMap<String, String>,
+    /// Valid themes ordered by file stem.
+    pub themes: Vec<CustomTheme>,
+}
+
+impl CustomThemeCatalog {
+    /// Returns the loaded user theme selected by `theme`.
+    #[must_use]
+    pub fn find(&self, theme: &ColorTheme) -> Option<&CustomTheme> {
+        let ColorTheme::Custom(id) = theme else {
+            return None;
+        };
+
+        self.themes
+            .iter()
+            .find(|custom_theme| custom_theme.id == *id)
+    }
+
+    /// Returns the warning shown beside the selected theme when its file is
+    /// missing, invalid, or leaves colors unassigned.
+    #[must_use]
+    pub fn notice(&self, theme: &ColorTheme) -> Option<String> {
+        let ColorTheme::Custom(id) = theme else {
+            return None;
+        };
+        if let Some(error) = self.errors.get(id) {
+            return Some(format!("invalid; using Agentty Default: {error}"));
+        }
+        let Some(custom_theme) = self.find(theme) else {
+            return Some("file not found; using Agentty Default".to_string());
+        };
+        let missing_keys = custom_theme.missing_keys();
+        if missing_keys.is_empty() {
+            return None;
+        }
+
+        let missing_names = missing_keys
+            .iter()
+            .take(MISSING_KEY_NOTICE_LIMIT)
+            .map(|key| key.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let hidden_count = missing_keys.len().saturating_sub(MISSING_KEY_NOTICE_LIMIT);
+        if hidden_count > 0 {
+            return Some(format!(
+                "missing {missing_names} and {hidden_count} more; using Agentty Default colors"
+            ));
+        }
+
+        Some(format!(
+            "missing {missing_names}; using Agentty Default colors"
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,4 +512,147 @@ mod tests {
         // Assert
         assert_eq!(theme, Some(ColorTheme::DarkHorizon));
     }
+
+    #[test]
+    fn parse_persisted_round_trips_custom_theme() {
+        // Arrange
+        let theme = ColorTheme::Custom("solarized-light".to_string());
+
+        // Act
+        let persisted_value = theme.persisted_value();
+        let parsed_theme = ColorTheme::parse_persisted(&persisted_value);
+
+        // Assert
+        assert_eq!(persisted_value, "custom:solarized-light");
+        assert_eq!(parsed_theme, Some(theme));
+        assert_eq!(ColorTheme::parse_persisted("custom:"), None);
+    }
+
+    #[test]
+    fn theme_color_parse_accepts_hex_names_indexes_and_default() {
+        // Arrange
+        let values = ["#268BD2", "light-blue", "Dark Grey", "236", "default"];
+
+        // Act
+        let colors = values.map(ThemeColor::parse);
+
+        // Assert
+        assert_eq!(
+            colors,
+            [
+                Ok(ThemeColor::Rgb(38, 139, 210)),
+                Ok(ThemeColor::Indexed(12)),
+                Ok(ThemeColor::Indexed(8)),
+                Ok(ThemeColor::Indexed(236)),
+                Ok(ThemeColor::Default),
+            ]
+        );
+        assert!(ThemeColor::parse("#12345").is_err());
+        assert!(ThemeColor::parse("teal").is_err());
+    }
+
+    #[test]
+    fn parse_custom_theme_reads_name_and_colors() {
+        // Arrange
+        let text =
+            "name = \"Paper\"\n\n[colors]\ntext = \"black\"\nsurface_selection = \"#eee8d5\"\n";
+
+        // Act
+        let theme = parse_custom_theme("paper", text).expect("theme should parse");
+
+        // Assert
+        assert_eq!(theme.id, "paper");
+        assert_eq!(theme.name, "Paper");
+        assert_eq!(
+            theme.colors.get(&ThemeColorKey::SurfaceSelection),
+            Some(&ThemeColor::Rgb(238, 232, 213))
+        );
+        assert_eq!(theme.missing_keys().len(), ThemeColorKey::ALL.len() - 2);
+    }
+
+    #[test]
+    fn parse_custom_theme_rejects_unknown_keys_and_colors() {
+        // Arrange
+        let unknown_key = "[colors]\nbackground = \"black\"\n";
+        let invalid_color = "[colors]\ntext = \"#zzzzzz\"\n";
+
+        // Act
+        let unknown_key_error = parse_custom_theme("paper", unknown_key);
+        let invalid_color_error = parse_custom_theme("paper", invalid_color);
+
+        // Assert
+        assert_eq!(
+            unknown_key_error,
+            Err(CustomThemeError::Invalid(
+                "unknown color key `background`".to_string()
+            ))
+        );
+        assert_eq!(
+            invalid_color_error,
+            Err(CustomThemeError::Invalid(
+                "text: `#zzzzzz` is not a `#rrggbb` color".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_custom_theme_reports_syntax_and_shape_errors_on_one_line() {
+        // Arrange
+        let bare_value = "name = \"Paper\"\n[colors]\ntext = black\n";
+        let root_color = "accent = \"black\"\n";
+
+        // Act
+        let bare_value_error = parse_custom_theme("paper", bare_value);
+        let root_color_error = parse_custom_theme("paper", root_color);
+
+        // Assert
+        assert!(matches!(
+            bare_value_error,
+            Err(CustomThemeError::Syntax(ref message))
+                if message.starts_with("line 3: ") && !message.contains('\n')
+        ));
+        assert!(matches!(
+            root_color_error,
+            Err(CustomThemeError::Syntax(ref message))
+                if message.starts_with("line 1: ") && message.contains("accent")
+        ));
+    }
+
+    #[test]
+    fn catalog_notice_reports_missing_keys_invalid_files_and_absent_themes() {
+        // Arrange
+        let mut colors = BTreeMap::new();
+        for key in ThemeColorKey::ALL.into_iter().skip(4) {
+            colors.insert(key, ThemeColor::Default);
+        }
+        let catalog = CustomThemeCatalog {
+            errors: BTreeMap::from([("broken".to_string(), "line 2: bad".to_string())]),
+            themes: vec![CustomTheme {
+                colors,
+                id: "paper".to_string(),
+                name: "Paper".to_string(),
+            }],
+        };
+
+        // Act
+        let paper_notice = catalog.notice(&ColorTheme::Custom("paper".to_string()));
+        let broken_notice = catalog.notice(&ColorTheme::Custom("broken".to_string()));
+        let absent_notice = catalog.notice(&ColorTheme::Custom("gone".to_string()));
+        let built_in_notice = catalog.notice(&ColorTheme::Green);
+
+        // Assert
+        assert_eq!(
+            paper_notice.as_deref(),
+            Some("missing accent, accent_soft, border and 1 more; using Agentty Default colors")
+        );
+        assert_eq!(
+            broken_notice.as_deref(),
+            Some("invalid; using Agentty Default: line 2: bad")
+        );
+        assert_eq!(
+            absent_notice.as_deref(),
+            Some("file not found; using Agentty Default")
+        );
+        assert_eq!(built_in_notice, None);
+    }
 }
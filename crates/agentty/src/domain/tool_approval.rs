@@ -0,0 +1,120 @@
+//! Pending provider approval requests awaiting a user decision.
+
+use std::collections::VecDeque;
+
+use tokio::sync::oneshot;
+
+use crate::domain::permission::{ToolApprovalDecision, ToolApprovalRequest};
+
+/// One approval request paired with the channel that unblocks its turn.
+#[derive(Debug)]
+struct PendingToolApproval {
+    request: ToolApprovalRequest,
+    responder: oneshot::Sender<ToolApprovalDecision>,
+}
+
+/// FIFO queue of approval requests raised by one session's active turn.
+///
+/// The worker pushes a request and awaits the returned receiver; the UI shows
+/// the oldest still-open request and resolves it with the user's decision.
+#[derive(Debug, Default)]
+pub(crate) struct ToolApprovalQueue {
+    pending: VecDeque<PendingToolApproval>,
+}
+
+impl ToolApprovalQueue {
+    /// Enqueues one request and returns the receiver that yields its decision.
+    pub(crate) fn push(
+        &mut self,
+        request: ToolApprovalRequest,
+    ) -> oneshot::Receiver<ToolApprovalDecision> {
+        let (responder, receiver) = oneshot::channel();
+        self.pending
+            .push_back(PendingToolApproval { request, responder });
+
+        receiver
+    }
+
+    /// Returns the oldest request whose turn is still waiting for an answer.
+    ///
+    /// Requests abandoned by a cancelled turn are pruned so the overlay never
+    /// asks about work that can no longer run.
+    pub(crate) fn current(&mut self) -> Option<ToolApprovalRequest> {
+        self.prune_closed();
+
+        self.pending.front().map(|pending| pending.request.clone())
+    }
+
+    /// Answers the oldest open request and returns whether one was resolved.
+    pub(crate) fn resolve(&mut self, decision: ToolApprovalDecision) -> bool {
+        self.prune_closed();
+        let Some(pending) = self.pending.pop_front() else {
+            return false;
+        };
+
+        pending.responder.send(decision).is_ok()
+    }
+
+    /// Drops leading requests whose receivers were dropped.
+    fn prune_closed(&mut self) {
+        while self
+            .pending
+            .front()
+            .is_some_and(|pending| pending.responder.is_closed())
+        {
+            self.pending.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::permission::ToolApprovalKind;
+
+    /// Builds one command approval request for queue tests.
+    fn command_request(command: &str) -> ToolApprovalRequest {
+        ToolApprovalRequest {
+            detail: None,
+            kind: ToolApprovalKind::Command,
+            summary: command.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_answers_oldest_request_first() {
+        // Arrange
+        let mut queue = ToolApprovalQueue::default();
+        let first_receiver = queue.push(command_request("cargo publish"));
+        let _second_receiver = queue.push(command_request("rm -rf target"));
+
+        // Act
+        let current_request = queue.current();
+        let was_resolved = queue.resolve(ToolApprovalDecision::Deny);
+        let next_request = queue.current();
+
+        // Assert
+        assert_eq!(current_request, Some(command_request("cargo publish")));
+        assert!(was_resolved);
+        assert_eq!(
+            first_receiver.await.expect("decision should be delivered"),
+            ToolApprovalDecision::Deny
+        );
+        assert_eq!(next_request, Some(command_request("rm -rf target")));
+    }
+
+    #[test]
+    fn test_current_skips_requests_abandoned_by_cancelled_turns() {
+        // Arrange
+        let mut queue = ToolApprovalQueue::default();
+        drop(queue.push(command_request("cargo publish")));
+
+        // Act
+        let current_request = queue.current();
+        let was_resolved = queue.resolve(ToolApprovalDecision::AllowOnce);
+
+        // Assert
+        assert_eq!(current_request, None);
+        assert!(!was_resolved);
+    }
+}
@@ -16,6 +16,10 @@ pub mod personality;
 pub(crate) mod process;
 /// Startup project-discovery boundary for home-directory repository scans.
 pub mod project_discovery;
+/// Shell execution for project-configured verification commands.
+pub(crate) mod shell;
+/// User color-theme file discovery.
+pub mod theme;
 /// Tmux process boundary used by app orchestration.
 pub mod tmux;
 pub mod version;
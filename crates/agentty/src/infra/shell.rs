@@ -0,0 +1,195 @@
+//! Shell execution for project-configured verification commands.
+
+use std::io;
+use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::process::Command;
+
+/// Captured result of one shell command run inside a session worktree.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct ShellCommandOutput {
+    /// Combined stdout followed by stderr, lossily decoded as UTF-8.
+    pub(crate) output: String,
+    /// Whether the command exited successfully.
+    pub(crate) success: bool,
+}
+
+/// Capacity reserved before each read from a child output pipe.
+const PIPE_READ_CHUNK_BYTES: usize = 8192;
+
+/// Result of one shell command run against a deadline.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) enum TimedShellCommandOutput {
+    /// The command exited before the deadline.
+    Completed(ShellCommandOutput),
+    /// The command was still running at the deadline and was killed.
+    TimedOut {
+        /// Combined stdout followed by stderr captured before the deadline.
+        output: String,
+    },
+}
+
+/// Runs `command` through `sh -c` inside `working_dir`, giving up after
+/// `timeout`.
+///
+/// Stdin is closed so interactive commands fail fast instead of blocking the
+/// calling workflow. Output is captured incrementally so a command still
+/// running at the deadline reports what it printed so far; the timed-out
+/// child is killed when it is dropped.
+///
+/// # Errors
+/// Returns an error when the shell process cannot be spawned or awaited.
+pub(crate) async fn run_shell_command_with_timeout(
+    working_dir: &Path,
+    command: &str,
+    timeout: Duration,
+) -> io::Result<TimedShellCommandOutput> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .current_dir(working_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()?;
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+    let mut stdout_bytes = Vec::new();
+    let mut stderr_bytes = Vec::new();
+    let run = async {
+        let (status, (), ()) = tokio::try_join!(
+            child.wait(),
+            read_pipe(stdout, &mut stdout_bytes),
+            read_pipe(stderr, &mut stderr_bytes),
+        )?;
+
+        io::Result::Ok(status)
+    };
+    let status = tokio::time::timeout(timeout, run).await;
+    let mut combined_output = String::from_utf8_lossy(&stdout_bytes).into_owned();
+    combined_output.push_str(&String::from_utf8_lossy(&stderr_bytes));
+
+    match status {
+        Ok(status) => Ok(TimedShellCommandOutput::Completed(ShellCommandOutput {
+            output: combined_output,
+            success: status?.success(),
+        })),
+        Err(_) => Ok(TimedShellCommandOutput::TimedOut {
+            output: combined_output,
+        }),
+    }
+}
+
+/// Appends everything read from one child pipe to `bytes` until EOF.
+async fn read_pipe(pipe: Option<impl AsyncRead + Unpin>, bytes: &mut Vec<u8>) -> io::Result<()> {
+    let Some(mut pipe) = pipe else {
+        return Ok(());
+    };
+    loop {
+        bytes.reserve(PIPE_READ_CHUNK_BYTES);
+        if pipe.read_buf(bytes).await? == 0 {
+            return Ok(());
+        }
+    }
+}
+
+/// Returns the last `max_lines` lines of captured command output, prefixed
+/// with a count of the omitted earlier lines when any were dropped.
+pub(crate) fn output_tail(output: &str, max_lines: usize) -> String {
+    let lines = output.trim_end().lines().collect::<Vec<_>>();
+    let skipped_line_count = lines.len().saturating_sub(max_lines);
+    let tail = lines[skipped_line_count..].join("\n");
+    if skipped_line_count == 0 {
+        return tail;
+    }
+
+    format!("... ({skipped_line_count} earlier lines omitted)\n{tail}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn run_shell_command_with_timeout_captures_output_and_exit_status() {
+        // Arrange
+        let working_dir = tempfile::tempdir().expect("failed to create temp dir");
+        std::fs::write(working_dir.path().join("marker.txt"), "present")
+            .expect("failed to write marker");
+
+        // Act
+        let passed = run_shell_command_with_timeout(
+            working_dir.path(),
+            "cat marker.txt",
+            Duration::from_secs(5),
+        )
+        .await
+        .expect("shell should spawn");
+        let failed = run_shell_command_with_timeout(
+            working_dir.path(),
+            "echo out; echo err >&2; exit 3",
+            Duration::from_secs(5),
+        )
+        .await
+        .expect("shell should spawn");
+
+        // Assert
+        assert_eq!(
+            passed,
+            TimedShellCommandOutput::Completed(ShellCommandOutput {
+                output: "present".to_string(),
+                success: true,
+            })
+        );
+        assert_eq!(
+            failed,
+            TimedShellCommandOutput::Completed(ShellCommandOutput {
+                output: "out\nerr\n".to_string(),
+                success: false,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn run_shell_command_with_timeout_reports_partial_output_past_deadline() {
+        // Arrange
+        let working_dir = tempfile::tempdir().expect("failed to create temp dir");
+
+        // Act
+        let timed_out = run_shell_command_with_timeout(
+            working_dir.path(),
+            "echo started; echo warming >&2; sleep 5",
+            Duration::from_millis(500),
+        )
+        .await
+        .expect("shell should spawn");
+
+        // Assert
+        assert_eq!(
+            timed_out,
+            TimedShellCommandOutput::TimedOut {
+                output: "started\nwarming\n".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn output_tail_keeps_last_lines_and_counts_omitted_ones() {
+        // Arrange
+        let output = (1..=85)
+            .map(|line| format!("line {line}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // Act
+        let tail = output_tail(&output, 80);
+
+        // Assert
+        assert!(tail.starts_with("... (5 earlier lines omitted)\nline 6\n"));
+        assert!(tail.ends_with("line 85"));
+    }
+}
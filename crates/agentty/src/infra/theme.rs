@@ -0,0 +1,121 @@
+//! User color-theme discovery under the Agentty home directory.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tracing::warn;
+
+use crate::domain::theme::{CustomThemeCatalog, parse_custom_theme};
+
+/// Directory name below the Agentty home that holds user theme files.
+pub const THEMES_DIR: &str = "themes";
+
+/// File extension of user theme files.
+const THEME_FILE_EXTENSION: &str = "toml";
+
+/// Loads every `*.toml` theme file directly inside `themes_dir`.
+///
+/// A missing directory yields an empty catalog. Files that cannot be read or
+/// parsed are recorded in [`CustomThemeCatalog::errors`] under their file
+/// stem so the settings page can explain why the theme is unavailable.
+pub async fn load_custom_theme_catalog(themes_dir: &Path) -> CustomThemeCatalog {
+    let mut catalog = CustomThemeCatalog::default();
+    let theme_paths = match list_theme_paths(themes_dir).await {
+        Ok(theme_paths) => theme_paths,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return catalog,
+        Err(error) => {
+            let themes_dir_display = themes_dir.display().to_string();
+            warn!(
+                themes_dir = %themes_dir_display,
+                %error,
+                "failed to list user theme files"
+            );
+
+            return catalog;
+        }
+    };
+
+    for (id, path) in theme_paths {
+        let theme = match tokio::fs::read_to_string(&path).await {
+            Ok(text) => parse_custom_theme(&id, &text).map_err(|error| error.to_string()),
+            Err(error) => Err(error.to_string()),
+        };
+        match theme {
+            Ok(theme) => catalog.themes.push(theme),
+            Err(error) => {
+                catalog.errors.insert(id, error);
+            }
+        }
+    }
+
+    catalog
+}
+
+/// Returns theme file stems and paths in stem order.
+async fn list_theme_paths(themes_dir: &Path) -> io::Result<BTreeMap<String, PathBuf>> {
+    let mut theme_paths = BTreeMap::new();
+    let mut entries = tokio::fs::read_dir(themes_dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        if path.extension().and_then(|extension| extension.to_str()) != Some(THEME_FILE_EXTENSION)
+            || !entry
+                .file_type()
+                .await
+                .is_ok_and(|file_type| file_type.is_file())
+        {
+            continue;
+        }
+        let Some(id) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        theme_paths.insert(id.to_string(), path);
+    }
+
+    Ok(theme_paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::theme::{ThemeColor, ThemeColorKey};
+
+    #[tokio::test]
+    async fn load_custom_theme_catalog_reads_valid_files_and_records_errors() {
+        // Arrange
+        let temp_dir = tempfile::tempdir().expect("failed to create temp dir");
+        let themes_dir = temp_dir.path();
+        std::fs::write(
+            themes_dir.join("paper.toml"),
+            "name = \"Paper\"\n[colors]\ntext = \"black\"\n",
+        )
+        .expect("failed to write theme");
+        std::fs::write(themes_dir.join("broken.toml"), "[colors]\ntext = black\n")
+            .expect("failed to write theme");
+        std::fs::write(themes_dir.join("notes.txt"), "ignored").expect("failed to write note");
+
+        // Act
+        let catalog = load_custom_theme_catalog(themes_dir).await;
+
+        // Assert
+        assert_eq!(catalog.themes.len(), 1);
+        assert_eq!(catalog.themes[0].name, "Paper");
+        assert_eq!(
+            catalog.themes[0].colors.get(&ThemeColorKey::Text),
+            Some(&ThemeColor::Indexed(0))
+        );
+        assert_eq!(catalog.errors.keys().collect::<Vec<_>>(), vec!["broken"]);
+    }
+
+    #[tokio::test]
+    async fn load_custom_theme_catalog_returns_empty_catalog_for_missing_directory() {
+        // Arrange
+        let temp_dir = tempfile::tempdir().expect("failed to create temp dir");
+
+        // Act
+        let catalog = load_custom_theme_catalog(&temp_dir.path().join(THEMES_DIR)).await;
+
+        // Assert
+        assert_eq!(catalog, CustomThemeCatalog::default());
+    }
+}
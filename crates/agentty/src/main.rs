@@ -9,7 +9,8 @@ use agentty::app::{AGENTTY_WT_DIR, App, AppError, agentty_home};
 use agentty::infra::db::{
     DB_DIR, DB_FILE, Database, timestamp_source_from_environment as environment_timestamp_source,
 };
-use clap::Parser;
+use agentty::runtime::SessionArgs;
+use clap::{Parser, Subcommand};
 
 /// Command-line options for launching Agentty.
 #[derive(Debug, Parser)]
@@ -18,6 +19,16 @@ struct Cli {
     /// Disables automatic application updates.
     #[arg(long)]
     no_update: bool,
+    /// Headless command to run instead of the terminal UI.
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// Non-interactive commands available from the command line.
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Scripts sessions without the terminal UI.
+    Session(SessionArgs),
 }
 
 /// Runs the `agentty` application runtime using the configured workspace and
@@ -37,7 +48,8 @@ async fn main() -> ExitCode {
     }
 }
 
-/// Builds startup dependencies, then launches the `agentty` runtime.
+/// Builds startup dependencies, then launches the terminal runtime or the
+/// requested headless command.
 ///
 /// # Errors
 /// Returns an error if database startup, app construction, or runtime
@@ -45,14 +57,28 @@ async fn main() -> ExitCode {
 async fn run(cli: Cli) -> Result<(), AppError> {
     let home = agentty_home();
     let base_path = home.join(AGENTTY_WT_DIR);
-    let working_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("/"));
+    let project_dir = match &cli.command {
+        Some(Command::Session(args)) => args.project.clone(),
+        None => None,
+    };
+    let working_dir = project_dir
+        .or_else(|| std::env::current_dir().ok())
+        .unwrap_or_else(|| PathBuf::from("/"));
     let git_client = RealGitClient;
     let git_branch = git_client.detect_git_info(working_dir.clone()).await;
 
     let db_path = home.join(DB_DIR).join(DB_FILE);
     let db = Database::open_with_timestamp_source(&db_path, environment_timestamp_source()).await?;
 
-    let mut app = App::new(!cli.no_update, base_path, working_dir, git_branch, db).await?;
+    // Headless commands never self-update so scripted runs stay reproducible.
+    let auto_update = !cli.no_update && cli.command.is_none();
+    let mut app = App::new(auto_update, base_path, working_dir, git_branch, db).await?;
+
+    if let Some(Command::Session(args)) = cli.command {
+        let mut stdout = io::stdout().lock();
+
+        return agentty::runtime::run_session_command(&mut app, args, &mut stdout).await;
+    }
 
     agentty::runtime::run(&mut app)
         .await
@@ -88,6 +114,38 @@ mod tests {
         assert!(cli.no_update);
     }
 
+    #[test]
+    fn cli_parses_headless_session_command() {
+        // Arrange / Act
+        let cli = Cli::try_parse_from([
+            "agentty",
+            "session",
+            "--project",
+            "/tmp/project",
+            "wait",
+            "session-1",
+            "--timeout",
+            "30",
+            "--json",
+        ])
+        .expect("session command should parse");
+
+        // Assert
+        let args = cli
+            .command
+            .map(|Command::Session(args)| args)
+            .expect("session command should be selected");
+        assert!(args.json);
+        assert_eq!(args.project, Some(PathBuf::from("/tmp/project")));
+        assert_eq!(
+            args.command,
+            agentty::runtime::SessionCommand::Wait {
+                session_id: "session-1".to_string(),
+                timeout: Some(30),
+            }
+        );
+    }
+
     #[test]
     fn cli_rejects_unknown_arguments() {
         // Arrange / Act
@@ -102,7 +160,10 @@ mod tests {
     async fn run_reports_database_parent_creation_failure() {
         if env::var_os(DATABASE_FAILURE_CHILD_ENV).is_some() {
             // Arrange
-            let cli = Cli { no_update: false };
+            let cli = Cli {
+                command: None,
+                no_update: false,
+            };
 
             // Act
             let error = run(cli)
@@ -6,8 +6,12 @@ pub mod app_mode;
 pub(crate) mod frame_time;
 /// Context-sensitive help actions and keybinding projections.
 pub mod help_action;
+/// Named shortcut actions and user overrides loaded from `keymap.toml`.
+pub mod keymap;
 /// Prompt composer history, attachment, and suggestion state.
 pub mod prompt;
+/// Review-request metadata fields collected by the publish-branch overlay.
+pub mod publish_form;
 /// Stable selection projection for grouped review-comment snapshots.
 pub(crate) mod review_comment;
 pub(crate) mod settings;
@@ -5,10 +5,15 @@ use ag_forge::ReviewCommentSnapshot;
 use super::help_action::{
     self, HelpAction, ViewActionAvailability, ViewHelpState, ViewSessionState,
 };
+use super::keymap::{Keymap, KeymapMode};
 use super::prompt::{
     PromptAtMentionState, PromptAttachmentState, PromptHistoryState, PromptSlashState,
 };
+use super::publish_form::ReviewRequestPublishForm;
 use crate::domain::input::InputState;
+use crate::domain::orchestration::{
+    OrchestrationBoard, OrchestrationBoardTask, OrchestrationTaskDraft,
+};
 use crate::domain::question::QuestionItem;
 use crate::domain::session::{
     PublishBranchAction, Session, SessionId, Status, can_reply_to_session_in_stack,
@@ -162,6 +167,15 @@ pub struct DiffLineComment {
     pub(crate) target: DiffLineCommentTarget,
 }
 
+/// Amount of changed diff content discarded by one revert request.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DiffRevertScope {
+    /// Only the selected changed row or `Shift+V` row range.
+    Lines,
+    /// Every changed row in each hunk that contains the selection.
+    Hunk,
+}
+
 /// Inline comments accumulated while the unified diff remains open.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct DiffLineComments {
@@ -173,6 +187,8 @@ pub struct DiffLineComments {
     pub(crate) selection_anchor_index: Option<usize>,
     /// Inline comment currently selected for navigation, when any.
     pub(crate) selected_comment_index: Option<usize>,
+    /// Revert awaiting a confirming second key press, when any.
+    pub(crate) pending_revert: Option<DiffRevertScope>,
 }
 
 impl DiffLineComments {
@@ -353,6 +369,27 @@ pub enum DiffFocus {
     Content,
 }
 
+/// Column arrangement used to render the diff panel.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DiffLayoutMode {
+    /// One column with deletions and additions interleaved.
+    #[default]
+    Unified,
+    /// Old and new file columns aligned row by row within each hunk.
+    Split,
+}
+
+impl DiffLayoutMode {
+    /// Returns the other layout mode.
+    #[must_use]
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Unified => Self::Split,
+            Self::Split => Self::Unified,
+        }
+    }
+}
+
 /// Semantic intent for a `Confirmation` overlay interaction.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ConfirmationIntent {
@@ -662,6 +699,124 @@ pub enum ChatFocus {
     Chat,
 }
 
+/// Plan field focused in the campaign-board task editor.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OrchestrationTaskField {
+    /// Short human-readable title.
+    Title,
+    /// Standalone prompt handed to the child session.
+    Prompt,
+    /// Acceptance criteria, one per line.
+    AcceptanceCriteria,
+    /// Planning areas, one per line.
+    TouchedAreas,
+}
+
+impl OrchestrationTaskField {
+    /// Returns the field that receives focus after this one.
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            OrchestrationTaskField::Title => OrchestrationTaskField::Prompt,
+            OrchestrationTaskField::Prompt => OrchestrationTaskField::AcceptanceCriteria,
+            OrchestrationTaskField::AcceptanceCriteria => OrchestrationTaskField::TouchedAreas,
+            OrchestrationTaskField::TouchedAreas => OrchestrationTaskField::Title,
+        }
+    }
+
+    /// Returns the field label rendered above its input.
+    pub fn label(self) -> &'static str {
+        match self {
+            OrchestrationTaskField::Title => "Title",
+            OrchestrationTaskField::Prompt => "Prompt",
+            OrchestrationTaskField::AcceptanceCriteria => "Acceptance criteria (one per line)",
+            OrchestrationTaskField::TouchedAreas => "Touched areas (one per line)",
+        }
+    }
+}
+
+/// In-progress edit of one proposed campaign task.
+pub struct OrchestrationTaskEditor {
+    /// Editable acceptance criteria, one per line.
+    pub acceptance_criteria: InputState,
+    /// Field currently receiving key input.
+    pub focus: OrchestrationTaskField,
+    /// Editable child prompt.
+    pub prompt: InputState,
+    /// Edited task.
+    pub task_id: i64,
+    /// Editable task title.
+    pub title: InputState,
+    /// Editable planning areas, one per line.
+    pub touched_areas: InputState,
+}
+
+impl OrchestrationTaskEditor {
+    /// Starts editing the current plan fields of one task.
+    #[must_use]
+    pub fn from_task(task: &OrchestrationBoardTask) -> Self {
+        Self {
+            acceptance_criteria: InputState::with_text(task.acceptance_criteria.join("\n")),
+            focus: OrchestrationTaskField::Title,
+            prompt: InputState::with_text(task.prompt.clone()),
+            task_id: task.id,
+            title: InputState::with_text(task.title.clone()),
+            touched_areas: InputState::with_text(task.touched_areas.join("\n")),
+        }
+    }
+
+    /// Returns the input for one field.
+    pub fn input(&self, field: OrchestrationTaskField) -> &InputState {
+        match field {
+            OrchestrationTaskField::Title => &self.title,
+            OrchestrationTaskField::Prompt => &self.prompt,
+            OrchestrationTaskField::AcceptanceCriteria => &self.acceptance_criteria,
+            OrchestrationTaskField::TouchedAreas => &self.touched_areas,
+        }
+    }
+
+    /// Returns the input receiving key events.
+    pub fn focused_input_mut(&mut self) -> &mut InputState {
+        match self.focus {
+            OrchestrationTaskField::Title => &mut self.title,
+            OrchestrationTaskField::Prompt => &mut self.prompt,
+            OrchestrationTaskField::AcceptanceCriteria => &mut self.acceptance_criteria,
+            OrchestrationTaskField::TouchedAreas => &mut self.touched_areas,
+        }
+    }
+
+    /// Returns the trimmed replacement plan fields.
+    #[must_use]
+    pub fn draft(&self) -> OrchestrationTaskDraft {
+        OrchestrationTaskDraft {
+            acceptance_criteria: non_empty_lines(self.acceptance_criteria.text()),
+            prompt: self.prompt.text().trim().to_string(),
+            title: self.title.text().trim().to_string(),
+            touched_areas: non_empty_lines(self.touched_areas.text()),
+        }
+    }
+}
+
+/// Pending confirmation before deleting one proposed campaign task.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrchestrationTaskDeleteConfirmation {
+    /// Highlighted confirmation option index.
+    pub selected_confirmation_index: usize,
+    /// Task removed when the deletion is confirmed.
+    pub task_id: i64,
+    /// Task title shown in the confirmation message.
+    pub task_title: String,
+}
+
+/// Splits multiline editor text into trimmed, non-empty entries.
+fn non_empty_lines(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 /// Represents the active UI mode for the application.
 pub enum AppMode {
     /// Displays the active top-level list tab.
@@ -671,6 +826,12 @@ pub enum AppMode {
         /// Highlighted session creation option.
         selected_option_index: usize,
     },
+    /// Displays the review-request reference input opened from the session
+    /// creation selector's import option.
+    ReviewRequestImportInput {
+        /// Editable review-request number, display id, or browser URL.
+        input: InputState,
+    },
     /// Displays an advisory before opening the session creation selector.
     PreCommitHookWarning {
         /// Full warning text, installation commands, and future-enforcement
@@ -756,6 +917,9 @@ pub enum AppMode {
         publish_branch_action: PublishBranchAction,
         /// View state restored after publish or cancel.
         restore_view: ConfirmationViewMode,
+        /// Draft, label, reviewer, and milestone fields shown only when the
+        /// action will create a new review request.
+        review_request_form: Option<Box<ReviewRequestPublishForm>>,
     },
     /// Session chat composer for the first prompt or a follow-up reply.
     Prompt {
@@ -809,6 +973,8 @@ pub enum AppMode {
         file_explorer_selected_index: usize,
         /// Panel currently receiving changed-file navigation input.
         focus: DiffFocus,
+        /// Unified or side-by-side arrangement of the right-hand panel.
+        layout_mode: DiffLayoutMode,
         /// Inline changed-line comments accumulated for the next turn.
         line_comments: DiffLineComments,
         /// Sticky rendered-markdown preview state for the selected file.
@@ -853,6 +1019,25 @@ pub enum AppMode {
         selected_option_index: Option<usize>,
     },
 
+    /// Full-screen campaign board for reviewing and editing an
+    /// orchestrator's task plan.
+    OrchestrationBoard {
+        /// Persisted campaign snapshot, reloaded after every change.
+        board: OrchestrationBoard,
+        /// Pending task deletion awaiting a yes/no confirmation.
+        delete_confirmation: Option<OrchestrationTaskDeleteConfirmation>,
+        /// Active task editor, when a proposed task is being edited.
+        editor: Option<OrchestrationTaskEditor>,
+        /// Whether the selected task shows its full plan details.
+        expanded: bool,
+        /// Outcome of the latest rejected board action.
+        notice: Option<String>,
+        /// Highlighted task index in integration order.
+        selected_index: usize,
+        /// Orchestrator session that owns the campaign.
+        session_id: SessionId,
+    },
+
     /// Displays context-sensitive keybindings above the originating page.
     Help {
         /// Originating page state used for help content and restoration.
@@ -871,8 +1056,12 @@ pub enum HelpContext {
     },
     /// Session-view help context and action availability.
     View {
+        /// Whether a fix-up turn may be started for failing CI checks.
+        can_fix_review_checks: bool,
         /// Whether the session may be forked.
         can_fork_session: bool,
+        /// Whether the linked draft review request may be marked ready.
+        can_mark_review_ready: bool,
         /// Whether the session branch may enter the merge queue.
         can_merge_session_branch: bool,
         /// Whether any session-branch mutation may begin.
@@ -908,6 +1097,8 @@ pub enum HelpContext {
         file_explorer_selected_index: usize,
         /// Panel that held keyboard focus before help opened.
         focus: DiffFocus,
+        /// Unified or side-by-side arrangement to restore.
+        layout_mode: DiffLayoutMode,
         /// Inline changed-line comments accumulated before help opened.
         line_comments: DiffLineComments,
         /// Rendered-markdown preview state to restore.
@@ -928,11 +1119,14 @@ pub enum HelpContext {
 }
 
 impl HelpContext {
-    /// Returns projected keybinding entries for the originating page.
-    pub fn keybindings(&self) -> Vec<HelpAction> {
+    /// Returns projected keybinding entries for the originating page, with
+    /// key labels relabeled by `keymap` overrides.
+    pub fn keybindings(&self, keymap: &Keymap) -> Vec<HelpAction> {
         match self {
             HelpContext::View {
+                can_fix_review_checks,
                 can_fork_session,
+                can_mark_review_ready,
                 can_merge_session_branch,
                 can_mutate_session_branch,
                 can_open_worktree,
@@ -944,31 +1138,38 @@ impl HelpContext {
                 publish_pull_request_action,
                 session_state,
                 ..
-            } => help_action::view_actions_with_review_comments(
-                ViewHelpState {
-                    can_fork_session: ViewActionAvailability::from_bool(*can_fork_session),
-                    can_merge_session_branch: ViewActionAvailability::from_bool(
-                        *can_merge_session_branch,
-                    ),
-                    can_mutate_session_branch: ViewActionAvailability::from_bool(
-                        *can_mutate_session_branch,
-                    ),
-                    can_open_worktree: ViewActionAvailability::from_bool(*can_open_worktree),
-                    can_rebase_session_branch: ViewActionAvailability::from_bool(
-                        *can_rebase_session_branch,
-                    ),
-                    can_show_diff: ViewActionAvailability::from_bool(*can_show_diff),
-                    reply_to_session: ViewActionAvailability::from_bool(*can_reply_to_session),
-                    can_start_staged_session: ViewActionAvailability::from_bool(
-                        *can_start_staged_session,
-                    ),
-                    publish_pull_request_action: *publish_pull_request_action,
-                    session_state: *session_state,
-                },
-                *can_view_review_comments,
+            } => keymap.remap_help_actions(
+                KeymapMode::View,
+                help_action::view_actions_with_review_comments(
+                    ViewHelpState {
+                        can_fork_session: ViewActionAvailability::from_bool(*can_fork_session),
+                        can_merge_session_branch: ViewActionAvailability::from_bool(
+                            *can_merge_session_branch,
+                        ),
+                        can_mutate_session_branch: ViewActionAvailability::from_bool(
+                            *can_mutate_session_branch,
+                        ),
+                        can_open_worktree: ViewActionAvailability::from_bool(*can_open_worktree),
+                        can_rebase_session_branch: ViewActionAvailability::from_bool(
+                            *can_rebase_session_branch,
+                        ),
+                        can_show_diff: ViewActionAvailability::from_bool(*can_show_diff),
+                        reply_to_session: ViewActionAvailability::from_bool(*can_reply_to_session),
+                        can_start_staged_session: ViewActionAvailability::from_bool(
+                            *can_start_staged_session,
+                        ),
+                        publish_pull_request_action: *publish_pull_request_action,
+                        session_state: *session_state,
+                    },
+                    *can_view_review_comments,
+                    *can_fix_review_checks,
+                    *can_mark_review_ready,
+                ),
             ),
             HelpContext::List { keybindings } => keybindings.clone(),
-            HelpContext::Diff { can_comment, .. } => help_action::diff_actions(*can_comment),
+            HelpContext::Diff { can_comment, .. } => {
+                keymap.remap_help_actions(KeymapMode::Diff, help_action::diff_actions(*can_comment))
+            }
         }
     }
 
@@ -990,6 +1191,7 @@ impl HelpContext {
                 diff,
                 file_explorer_selected_index,
                 focus,
+                layout_mode,
                 line_comments,
                 preview,
                 review_comments,
@@ -1001,6 +1203,7 @@ impl HelpContext {
                 diff,
                 file_explorer_selected_index,
                 focus,
+                layout_mode,
                 line_comments,
                 preview,
                 review_comments: review_comments.map(|review_comments| *review_comments),
@@ -1228,7 +1431,9 @@ mod tests {
     fn test_help_context_view_keybindings_for_in_progress_show_sync_and_hide_edit_actions() {
         // Arrange
         let context = HelpContext::View {
+            can_fix_review_checks: false,
             can_fork_session: true,
+            can_mark_review_ready: false,
             can_merge_session_branch: true,
             can_mutate_session_branch: true,
             can_open_worktree: true,
@@ -1244,7 +1449,7 @@ mod tests {
         };
 
         // Act
-        let bindings = context.keybindings();
+        let bindings = context.keybindings(&Keymap::default());
 
         // Assert
         assert!(bindings.iter().any(|binding| binding.key == "q"));
@@ -1262,7 +1467,9 @@ mod tests {
     fn test_help_context_restore_mode_ignores_help_only_view_fields() {
         // Arrange
         let context = HelpContext::View {
+            can_fix_review_checks: false,
             can_fork_session: true,
+            can_mark_review_ready: false,
             can_merge_session_branch: true,
             can_mutate_session_branch: true,
             can_open_worktree: true,
@@ -1295,7 +1502,9 @@ mod tests {
     fn test_help_context_view_keybindings_include_publish_pull_request_action() {
         // Arrange
         let context = HelpContext::View {
+            can_fix_review_checks: false,
             can_fork_session: true,
+            can_mark_review_ready: false,
             can_merge_session_branch: true,
             can_mutate_session_branch: true,
             can_open_worktree: true,
@@ -1311,7 +1520,7 @@ mod tests {
         };
 
         // Act
-        let bindings = context.keybindings();
+        let bindings = context.keybindings(&Keymap::default());
 
         // Assert
         assert!(bindings.iter().any(|binding| binding.key == "p"));
@@ -1327,7 +1536,7 @@ mod tests {
         let context = HelpContext::List { keybindings };
 
         // Act
-        let bindings = context.keybindings();
+        let bindings = context.keybindings(&Keymap::default());
 
         // Assert
         assert_eq!(bindings.len(), 2);
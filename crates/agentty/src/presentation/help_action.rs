@@ -1,5 +1,7 @@
+use std::borrow::Cow;
+
 use crate::domain::session::{PublishBranchAction, Session, Status};
-use crate::presentation::app_mode::{DiffFocus, DiffSidebarFocus};
+use crate::presentation::app_mode::{DiffFocus, DiffRevertScope, DiffSidebarFocus};
 
 /// Footer shortcut label for prompt image paste.
 ///
@@ -8,10 +10,13 @@ pub(crate) const PROMPT_IMAGE_PASTE_SHORTCUT_LABEL: &str = "Ctrl+V/Alt+V";
 
 /// One user-visible shortcut entry that can be rendered in the footer and
 /// in the help popup.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+///
+/// The key label is borrowed for built-in shortcuts and owned once a user
+/// keymap rewrites it.
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct HelpAction {
     pub(crate) footer_label: &'static str,
-    pub(crate) key: &'static str,
+    pub(crate) key: Cow<'static, str>,
     pub(crate) popup_label: &'static str,
 }
 
@@ -24,7 +29,7 @@ impl HelpAction {
     ) -> Self {
         Self {
             footer_label,
-            key,
+            key: Cow::Borrowed(key),
             popup_label,
         }
     }
@@ -430,18 +435,29 @@ pub(crate) fn view_actions(state: ViewHelpState) -> Vec<HelpAction> {
 
     append_view_continue_action(&mut actions, action_set);
     actions.extend(VIEW_OUTPUT_SCROLL_ACTIONS);
+    actions.push(HelpAction::new(
+        "activity",
+        "e",
+        "Expand or collapse activity log",
+    ));
     actions.push(HelpAction::new("help", "?", "Help"));
 
     actions
 }
 
 /// Returns full session-view help actions with linked review comments when
-/// available outside sessions that support terminal continuation.
+/// available outside sessions that support terminal continuation, plus the
+/// fix-CI action when the linked review request has failing checks and the
+/// mark-ready action while it is still a draft.
 pub(crate) fn view_actions_with_review_comments(
     state: ViewHelpState,
     can_view_review_comments: bool,
+    can_fix_review_checks: bool,
+    can_mark_review_ready: bool,
 ) -> Vec<HelpAction> {
     let mut actions = view_actions(state);
+    append_mark_review_ready_action(&mut actions, can_mark_review_ready);
+    append_fix_review_checks_action(&mut actions, can_fix_review_checks);
     append_review_comment_action(
         &mut actions,
         can_append_review_comments(state.session_state, can_view_review_comments),
@@ -502,6 +518,32 @@ fn append_review_comment_action(actions: &mut Vec<HelpAction>, is_available: boo
     );
 }
 
+/// Adds the fix-CI shortcut before trailing navigation actions.
+fn append_fix_review_checks_action(actions: &mut Vec<HelpAction>, is_available: bool) {
+    if !is_available {
+        return;
+    }
+
+    let insertion_index = 1.min(actions.len());
+    actions.insert(
+        insertion_index,
+        HelpAction::new("fix CI", "x", "Fix failing CI checks"),
+    );
+}
+
+/// Adds the mark-ready shortcut before trailing navigation actions.
+fn append_mark_review_ready_action(actions: &mut Vec<HelpAction>, is_available: bool) {
+    if !is_available {
+        return;
+    }
+
+    let insertion_index = 1.min(actions.len());
+    actions.insert(
+        insertion_index,
+        HelpAction::new("mark ready", "R", "Mark draft review request ready"),
+    );
+}
+
 /// Appends the stop action shared by full help and compact footer rows.
 fn append_view_stop_action(actions: &mut Vec<HelpAction>, action_set: ViewActionSet) {
     if action_set.stop_session.is_enabled() {
@@ -529,6 +571,7 @@ fn append_orchestration_actions(actions: &mut Vec<HelpAction>, session_state: Vi
         }
         ViewSessionState::Orchestrator => {
             actions.push(HelpAction::new("approve", "a", "Approve campaign step"));
+            actions.push(HelpAction::new("board", "b", "Open campaign board"));
         }
         _ => {}
     }
@@ -772,6 +815,7 @@ pub(crate) fn diff_actions(can_comment: bool) -> Vec<HelpAction> {
         HelpAction::new("files", "f/Esc/Left", "Focus changed files"),
         HelpAction::new("comments", "c", "Focus review comments"),
         HelpAction::new("preview", "p", "Toggle selected markdown preview"),
+        HelpAction::new("layout", "t", "Toggle unified or side-by-side diff layout"),
         HelpAction::new(
             "scroll/select row",
             "J/K/Up/Down",
@@ -796,6 +840,16 @@ pub(crate) fn diff_actions(can_comment: bool) -> Vec<HelpAction> {
         ));
     }
     actions.extend([
+        HelpAction::new(
+            "revert lines",
+            "x x",
+            "Discard the selected changed rows from the worktree",
+        ),
+        HelpAction::new(
+            "revert hunk",
+            "X X",
+            "Discard each hunk containing the selection from the worktree",
+        ),
         HelpAction::new("select", "Space", "Toggle comment selection"),
         HelpAction::new("submit", "Enter", "Submit selected comments to the agent"),
         HelpAction::new("help", "?", "Help"),
@@ -817,6 +871,8 @@ pub(crate) struct DiffFooterContext {
     pub(crate) has_review_comments: bool,
     /// Inline changed-line comment state for footer editing actions.
     pub(crate) line_comment_state: DiffLineCommentFooterState,
+    /// Diff-view revert availability and confirmation state.
+    pub(crate) revert_state: DiffRevertFooterState,
     /// Sidebar section that currently controls the right pane.
     pub(crate) sidebar_focus: DiffSidebarFocus,
 }
@@ -837,8 +893,23 @@ pub(crate) enum DiffLineCommentFooterState {
     },
 }
 
+/// Revert state that changes the compact Diff footer actions.
+#[derive(Clone, Copy)]
+pub(crate) enum DiffRevertFooterState {
+    /// The visible session cannot discard worktree changes.
+    Unavailable,
+    /// Selected changed rows may be reverted.
+    Ready,
+    /// One revert awaits its confirming second key press.
+    Confirming(DiffRevertScope),
+}
+
 /// Returns compact diff footer actions for the page-level hint line.
 pub(crate) fn diff_footer_actions(context: DiffFooterContext) -> Vec<HelpAction> {
+    if let DiffRevertFooterState::Confirming(scope) = context.revert_state {
+        return revert_confirmation_actions(scope);
+    }
+    let can_revert = matches!(context.revert_state, DiffRevertFooterState::Ready);
     if matches!(
         context.line_comment_state,
         DiffLineCommentFooterState::Editing
@@ -853,12 +924,7 @@ pub(crate) fn diff_footer_actions(context: DiffFooterContext) -> Vec<HelpAction>
         context.line_comment_state,
         DiffLineCommentFooterState::Selecting
     ) {
-        return vec![
-            HelpAction::new("back", "q", "Back to session"),
-            HelpAction::new("cancel", "Esc", "Cancel row selection"),
-            HelpAction::new("extend", "j/k", "Extend row selection"),
-            HelpAction::new("comment", "Enter", "Comment on selected rows"),
-        ];
+        return row_selection_actions(can_revert);
     }
 
     let mut actions = vec![HelpAction::new("back", "q/Esc", "Back to session")];
@@ -871,6 +937,7 @@ pub(crate) fn diff_footer_actions(context: DiffFooterContext) -> Vec<HelpAction>
                 "Focus the selected file's changed lines",
             ));
             actions.push(HelpAction::new("preview", "p", "Toggle markdown preview"));
+            actions.push(HelpAction::new("layout", "t", "Toggle side-by-side diff"));
             if context.has_review_comments {
                 actions.push(HelpAction::new("comments", "c", "Focus review comments"));
             }
@@ -893,6 +960,13 @@ pub(crate) fn diff_footer_actions(context: DiffFooterContext) -> Vec<HelpAction>
                     "Edit the selected line's or inline comment's feedback",
                 ));
             }
+            if can_revert {
+                actions.push(HelpAction::new(
+                    "revert",
+                    "x/X",
+                    "Revert selected line or hunk",
+                ));
+            }
             if matches!(
                 context.line_comment_state,
                 DiffLineCommentFooterState::Ready { comment_count } if comment_count > 0
@@ -936,6 +1010,42 @@ pub(crate) fn diff_footer_actions(context: DiffFooterContext) -> Vec<HelpAction>
     actions
 }
 
+/// Returns the footer shown while `Shift+V` row selection is active.
+fn row_selection_actions(can_revert: bool) -> Vec<HelpAction> {
+    let mut actions = vec![
+        HelpAction::new("back", "q", "Back to session"),
+        HelpAction::new("cancel", "Esc", "Cancel row selection"),
+        HelpAction::new("extend", "j/k", "Extend row selection"),
+        HelpAction::new("comment", "Enter", "Comment on selected rows"),
+    ];
+    if can_revert {
+        actions.push(HelpAction::new(
+            "revert",
+            "x/X",
+            "Revert selected rows or hunks",
+        ));
+    }
+
+    actions
+}
+
+/// Returns the footer shown while one diff-view revert awaits confirmation.
+fn revert_confirmation_actions(scope: DiffRevertScope) -> Vec<HelpAction> {
+    let confirm_action = match scope {
+        DiffRevertScope::Lines => {
+            HelpAction::new("confirm revert", "x", "Discard the selected rows")
+        }
+        DiffRevertScope::Hunk => {
+            HelpAction::new("confirm revert", "X", "Discard the selected hunks")
+        }
+    };
+
+    vec![
+        confirm_action,
+        HelpAction::new("cancel", "Esc", "Keep the changes"),
+    ]
+}
+
 /// Returns list-mode actions shared by all tabs.
 fn list_base_actions() -> Vec<HelpAction> {
     Vec::from(LIST_BASE_ACTIONS)
@@ -1723,7 +1833,10 @@ mod tests {
 
         // Act
         let actions = view_footer_actions(state);
-        let ordered_keys = actions.iter().map(|action| action.key).collect::<Vec<_>>();
+        let ordered_keys = actions
+            .iter()
+            .map(|action| action.key.as_ref())
+            .collect::<Vec<_>>();
 
         // Assert
         assert_eq!(
@@ -1757,7 +1870,10 @@ mod tests {
 
         // Act
         let actions = view_footer_actions(state);
-        let ordered_keys = actions.iter().map(|action| action.key).collect::<Vec<_>>();
+        let ordered_keys = actions
+            .iter()
+            .map(|action| action.key.as_ref())
+            .collect::<Vec<_>>();
 
         // Assert
         assert_eq!(
@@ -1816,7 +1932,10 @@ mod tests {
 
         // Act
         let actions = view_footer_actions(state);
-        let ordered_keys = actions.iter().map(|action| action.key).collect::<Vec<_>>();
+        let ordered_keys = actions
+            .iter()
+            .map(|action| action.key.as_ref())
+            .collect::<Vec<_>>();
 
         // Assert
         assert_eq!(&ordered_keys[..4], ["q", "r", "Ctrl+c", "j/k"]);
@@ -1871,7 +1990,10 @@ mod tests {
 
         // Act
         let actions = view_footer_actions(state);
-        let ordered_keys = actions.iter().map(|action| action.key).collect::<Vec<_>>();
+        let ordered_keys = actions
+            .iter()
+            .map(|action| action.key.as_ref())
+            .collect::<Vec<_>>();
 
         // Assert
         assert_eq!(&ordered_keys[..4], ["q", "c", "j/k", "?"]);
@@ -1897,7 +2019,7 @@ mod tests {
             };
 
             // Act
-            let full_actions = view_actions_with_review_comments(state, true);
+            let full_actions = view_actions_with_review_comments(state, true, false, false);
 
             // Assert
             assert_eq!(
@@ -1935,8 +2057,8 @@ mod tests {
         };
 
         // Act
-        let available_actions = view_actions_with_review_comments(state, true);
-        let unavailable_actions = view_actions_with_review_comments(state, false);
+        let available_actions = view_actions_with_review_comments(state, true, false, false);
+        let unavailable_actions = view_actions_with_review_comments(state, false, false, false);
 
         // Assert
         assert!(
@@ -1951,6 +2073,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_view_actions_fix_ci_follows_failed_check_availability() {
+        // Arrange
+        let state = ViewHelpState {
+            can_fork_session: ViewActionAvailability::Enabled,
+            can_merge_session_branch: ViewActionAvailability::Enabled,
+            can_mutate_session_branch: ViewActionAvailability::Enabled,
+            can_rebase_session_branch: ViewActionAvailability::Enabled,
+            can_show_diff: ViewActionAvailability::Enabled,
+            can_open_worktree: ViewActionAvailability::Enabled,
+            reply_to_session: ViewActionAvailability::Enabled,
+            can_start_staged_session: ViewActionAvailability::Disabled,
+            publish_pull_request_action: None,
+            session_state: ViewSessionState::Review,
+        };
+
+        // Act
+        let available_actions = view_actions_with_review_comments(state, true, true, false);
+        let unavailable_actions = view_actions_with_review_comments(state, true, false, false);
+
+        // Assert
+        assert_eq!(available_actions[2].popup_label, "Fix failing CI checks");
+        assert_eq!(available_actions[2].key, "x");
+        assert!(
+            !unavailable_actions
+                .iter()
+                .any(|action| action.popup_label == "Fix failing CI checks")
+        );
+    }
+
+    #[test]
+    fn test_view_actions_mark_ready_follows_draft_availability() {
+        // Arrange
+        let state = ViewHelpState {
+            can_fork_session: ViewActionAvailability::Enabled,
+            can_merge_session_branch: ViewActionAvailability::Enabled,
+            can_mutate_session_branch: ViewActionAvailability::Enabled,
+            can_rebase_session_branch: ViewActionAvailability::Enabled,
+            can_show_diff: ViewActionAvailability::Enabled,
+            can_open_worktree: ViewActionAvailability::Enabled,
+            reply_to_session: ViewActionAvailability::Enabled,
+            can_start_staged_session: ViewActionAvailability::Disabled,
+            publish_pull_request_action: None,
+            session_state: ViewSessionState::Review,
+        };
+
+        // Act
+        let available_actions = view_actions_with_review_comments(state, false, false, true);
+        let unavailable_actions = view_actions_with_review_comments(state, false, false, false);
+
+        // Assert
+        assert_eq!(
+            available_actions[1].popup_label,
+            "Mark draft review request ready"
+        );
+        assert_eq!(available_actions[1].key, "R");
+        assert!(
+            !unavailable_actions
+                .iter()
+                .any(|action| action.popup_label == "Mark draft review request ready")
+        );
+    }
+
     #[test]
     fn test_view_footer_actions_canceled_shows_continue_before_scroll() {
         // Arrange
@@ -1969,7 +2154,10 @@ mod tests {
 
         // Act
         let actions = view_footer_actions(state);
-        let ordered_keys = actions.iter().map(|action| action.key).collect::<Vec<_>>();
+        let ordered_keys = actions
+            .iter()
+            .map(|action| action.key.as_ref())
+            .collect::<Vec<_>>();
 
         // Assert
         assert_eq!(&ordered_keys[..4], ["q", "c", "j/k", "?"]);
@@ -2097,32 +2285,33 @@ mod tests {
 
         // Act
         let managed_keys = view_actions(state)
-            .iter()
+            .into_iter()
             .map(|action| action.key)
             .collect::<Vec<_>>();
         state.session_state = ViewSessionState::ManagedResearch;
         let research_keys = view_actions(state)
-            .iter()
+            .into_iter()
             .map(|action| action.key)
             .collect::<Vec<_>>();
         state.session_state = ViewSessionState::Orchestrator;
         state.reply_to_session = ViewActionAvailability::Enabled;
         let controller_keys = view_actions(state)
-            .iter()
+            .into_iter()
             .map(|action| action.key)
             .collect::<Vec<_>>();
 
         // Assert
-        assert!(managed_keys.contains(&"d"));
-        assert!(managed_keys.contains(&"D"));
-        assert!(!managed_keys.contains(&"o"));
-        assert!(!managed_keys.contains(&"Enter"));
-        assert!(research_keys.contains(&"d"));
-        assert!(!research_keys.contains(&"D"));
-        assert!(!research_keys.contains(&"o"));
-        assert!(controller_keys.contains(&"a"));
-        assert!(controller_keys.contains(&"Enter"));
-        assert!(!controller_keys.contains(&"m"));
+        assert!(managed_keys.contains(&"d".into()));
+        assert!(managed_keys.contains(&"D".into()));
+        assert!(!managed_keys.contains(&"o".into()));
+        assert!(!managed_keys.contains(&"Enter".into()));
+        assert!(research_keys.contains(&"d".into()));
+        assert!(!research_keys.contains(&"D".into()));
+        assert!(!research_keys.contains(&"o".into()));
+        assert!(controller_keys.contains(&"a".into()));
+        assert!(controller_keys.contains(&"b".into()));
+        assert!(controller_keys.contains(&"Enter".into()));
+        assert!(!controller_keys.contains(&"m".into()));
     }
 
     #[test]
@@ -2143,21 +2332,21 @@ mod tests {
 
         // Act
         let managed_keys = view_actions(state)
-            .iter()
+            .into_iter()
             .map(|action| action.key)
             .collect::<Vec<_>>();
 
         // Assert
-        assert!(managed_keys.contains(&"o"));
-        assert!(!managed_keys.contains(&"Enter"));
-        assert!(!managed_keys.contains(&"m"));
+        assert!(managed_keys.contains(&"o".into()));
+        assert!(!managed_keys.contains(&"Enter".into()));
+        assert!(!managed_keys.contains(&"m".into()));
     }
 
     #[test]
     fn test_read_only_detail_and_diff_action_groups_expose_expected_keys() {
         // Arrange, Act
         let diff_keys = diff_actions(true)
-            .iter()
+            .into_iter()
             .map(|action| action.key)
             .collect::<Vec<_>>();
         let file_footer_keys = diff_footer_actions(DiffFooterContext {
@@ -2166,9 +2355,10 @@ mod tests {
             focus: DiffFocus::Files,
             has_review_comments: true,
             line_comment_state: DiffLineCommentFooterState::Ready { comment_count: 0 },
+            revert_state: DiffRevertFooterState::Unavailable,
             sidebar_focus: DiffSidebarFocus::Files,
         })
-        .iter()
+        .into_iter()
         .map(|action| action.key)
         .collect::<Vec<_>>();
 
@@ -2182,16 +2372,22 @@ mod tests {
                 "f/Esc/Left",
                 "c",
                 "p",
+                "t",
                 "J/K/Up/Down",
                 "Shift+V",
                 "Enter/Esc",
                 "s",
+                "x x",
+                "X X",
                 "Space",
                 "Enter",
                 "?"
             ]
         );
-        assert_eq!(file_footer_keys, ["q/Esc", "j/k", "Enter/l", "p", "c", "?"]);
+        assert_eq!(
+            file_footer_keys,
+            ["q/Esc", "j/k", "Enter/l", "p", "t", "c", "?"]
+        );
     }
 
     #[test]
@@ -2203,9 +2399,10 @@ mod tests {
             focus: DiffFocus::Content,
             has_review_comments: true,
             line_comment_state: DiffLineCommentFooterState::Ready { comment_count: 2 },
+            revert_state: DiffRevertFooterState::Unavailable,
             sidebar_focus: DiffSidebarFocus::Files,
         })
-        .iter()
+        .into_iter()
         .map(|action| action.key)
         .collect::<Vec<_>>();
 
@@ -2229,9 +2426,10 @@ mod tests {
             focus: DiffFocus::Content,
             has_review_comments: false,
             line_comment_state: DiffLineCommentFooterState::ReadOnly,
+            revert_state: DiffRevertFooterState::Unavailable,
             sidebar_focus: DiffSidebarFocus::Files,
         })
-        .iter()
+        .into_iter()
         .map(|action| action.key)
         .collect::<Vec<_>>();
 
@@ -2251,9 +2449,10 @@ mod tests {
             focus: DiffFocus::Content,
             has_review_comments: false,
             line_comment_state: DiffLineCommentFooterState::Editing,
+            revert_state: DiffRevertFooterState::Unavailable,
             sidebar_focus: DiffSidebarFocus::Files,
         })
-        .iter()
+        .into_iter()
         .map(|action| action.key)
         .collect::<Vec<_>>();
 
@@ -2270,9 +2469,10 @@ mod tests {
             focus: DiffFocus::Content,
             has_review_comments: false,
             line_comment_state: DiffLineCommentFooterState::Selecting,
+            revert_state: DiffRevertFooterState::Unavailable,
             sidebar_focus: DiffSidebarFocus::Files,
         })
-        .iter()
+        .into_iter()
         .map(|action| action.key)
         .collect::<Vec<_>>();
 
@@ -2280,6 +2480,37 @@ mod tests {
         assert_eq!(selecting_keys, ["q", "Esc", "j/k", "Enter"]);
     }
 
+    #[test]
+    fn test_diff_content_footer_shows_revert_and_its_confirmation() {
+        // Arrange
+        let context = DiffFooterContext {
+            can_mark_selected: false,
+            can_submit: false,
+            focus: DiffFocus::Content,
+            has_review_comments: false,
+            line_comment_state: DiffLineCommentFooterState::Ready { comment_count: 0 },
+            revert_state: DiffRevertFooterState::Ready,
+            sidebar_focus: DiffSidebarFocus::Files,
+        };
+
+        // Act
+        let ready_keys = diff_footer_actions(context)
+            .into_iter()
+            .map(|action| action.key)
+            .collect::<Vec<_>>();
+        let confirming_keys = diff_footer_actions(DiffFooterContext {
+            revert_state: DiffRevertFooterState::Confirming(DiffRevertScope::Hunk),
+            ..context
+        })
+        .into_iter()
+        .map(|action| action.key)
+        .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(ready_keys, ["q", "Esc/Left", "j/k", "Enter", "x/X", "?"]);
+        assert_eq!(confirming_keys, ["X", "Esc"]);
+    }
+
     #[test]
     fn test_review_comment_actions_include_selection_and_submit_keys() {
         // Arrange, Act
@@ -2289,9 +2520,13 @@ mod tests {
             focus: DiffFocus::Files,
             has_review_comments: true,
             line_comment_state: DiffLineCommentFooterState::Ready { comment_count: 0 },
+            revert_state: DiffRevertFooterState::Unavailable,
             sidebar_focus: DiffSidebarFocus::Comments,
         });
-        let comment_keys = actions.iter().map(|action| action.key).collect::<Vec<_>>();
+        let comment_keys = actions
+            .iter()
+            .map(|action| action.key.as_ref())
+            .collect::<Vec<_>>();
 
         // Assert
         assert_eq!(
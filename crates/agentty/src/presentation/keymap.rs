@@ -0,0 +1,1167 @@
+//! Named per-mode shortcut actions and user overrides from `keymap.toml`.
+//!
+//! Every remappable shortcut is declared once in [`ACTIONS`] with its
+//! built-in chords. A [`Keymap`] layers user overrides on top of that table;
+//! mode handlers resolve pressed chords into the per-mode action enums, and
+//! help projections rewrite their key labels from the same table so hints
+//! always match dispatch.
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::presentation::help_action::HelpAction;
+
+/// File name of the user keymap inside the Agentty home directory.
+pub const KEYMAP_FILE: &str = "keymap.toml";
+
+/// Key-handling surface that owns one `keymap.toml` section.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum KeymapMode {
+    /// Sessions, projects, and settings tabs.
+    List,
+    /// Session transcript without an active composer.
+    View,
+    /// Full-screen session diff.
+    Diff,
+    /// Prompt composer and its transcript focus.
+    Prompt,
+    /// Clarification-question panel and its transcript focus.
+    Question,
+}
+
+impl KeymapMode {
+    /// Returns the `keymap.toml` section name for this mode.
+    pub fn section_name(self) -> &'static str {
+        match self {
+            Self::List => "list",
+            Self::View => "view",
+            Self::Diff => "diff",
+            Self::Prompt => "prompt",
+            Self::Question => "question",
+        }
+    }
+
+    /// Returns whether the mode hosts a text input whose typed characters
+    /// must never be rebound.
+    fn hosts_text_input(self) -> bool {
+        matches!(self, Self::Prompt | Self::Question)
+    }
+}
+
+/// Physical key of one chord, independent of the terminal backend.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum KeyName {
+    /// Printable character, with letters already folded for `Shift`.
+    Char(char),
+    /// `Enter`.
+    Enter,
+    /// `Esc`.
+    Esc,
+    /// `Tab`.
+    Tab,
+    /// `Shift+Tab`.
+    BackTab,
+    /// `Backspace`.
+    Backspace,
+    /// `Delete`.
+    Delete,
+    /// Up arrow.
+    Up,
+    /// Down arrow.
+    Down,
+    /// Left arrow.
+    Left,
+    /// Right arrow.
+    Right,
+    /// `Home`.
+    Home,
+    /// `End`.
+    End,
+    /// `PageUp`.
+    PageUp,
+    /// `PageDown`.
+    PageDown,
+    /// Function key `F1`..`F12`.
+    F(u8),
+}
+
+/// Named special keys accepted in chord text, in display spelling.
+const NAMED_KEYS: [(&str, KeyName); 15] = [
+    ("Enter", KeyName::Enter),
+    ("Esc", KeyName::Esc),
+    ("Tab", KeyName::Tab),
+    ("BackTab", KeyName::BackTab),
+    ("Backspace", KeyName::Backspace),
+    ("Delete", KeyName::Delete),
+    ("Up", KeyName::Up),
+    ("Down", KeyName::Down),
+    ("Left", KeyName::Left),
+    ("Right", KeyName::Right),
+    ("Home", KeyName::Home),
+    ("End", KeyName::End),
+    ("PageUp", KeyName::PageUp),
+    ("PageDown", KeyName::PageDown),
+    ("Space", KeyName::Char(' ')),
+];
+
+/// One normalized key plus modifiers.
+///
+/// Unmodified letters fold `Shift` into the uppercase character, so `J` and
+/// `Shift+j` are the same chord. With `Ctrl` or `Alt`, letters are stored in
+/// lowercase and `Shift` stays explicit.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct KeyChord {
+    /// Physical key.
+    pub key: KeyName,
+    /// Whether `Ctrl` is held.
+    pub ctrl: bool,
+    /// Whether `Alt` is held.
+    pub alt: bool,
+    /// Whether `Shift` is held for keys that do not fold it into a character.
+    pub shift: bool,
+}
+
+impl KeyChord {
+    /// Builds one normalized chord from raw key and modifier state.
+    pub fn new(key: KeyName, ctrl: bool, alt: bool, shift: bool) -> Self {
+        match key {
+            KeyName::Char(character) if character.is_ascii_alphabetic() => {
+                if ctrl || alt {
+                    return Self {
+                        key: KeyName::Char(character.to_ascii_lowercase()),
+                        ctrl,
+                        alt,
+                        shift: shift || character.is_ascii_uppercase(),
+                    };
+                }
+                let character = if shift {
+                    character.to_ascii_uppercase()
+                } else {
+                    character
+                };
+
+                Self {
+                    key: KeyName::Char(character),
+                    ctrl,
+                    alt,
+                    shift: false,
+                }
+            }
+            KeyName::Char(_) | KeyName::BackTab => Self {
+                key,
+                ctrl,
+                alt,
+                shift: false,
+            },
+            KeyName::Tab if shift => Self {
+                key: KeyName::BackTab,
+                ctrl,
+                alt,
+                shift: false,
+            },
+            _ => Self {
+                key,
+                ctrl,
+                alt,
+                shift,
+            },
+        }
+    }
+
+    /// Parses chord text such as `j`, `Shift+V`, `Ctrl+d`, or `Alt+Enter`.
+    ///
+    /// # Errors
+    /// Returns a description when a modifier or key name is not recognized.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut ctrl = false;
+        let mut alt = false;
+        let mut shift = false;
+        let mut rest = text;
+        while let Some((modifier, key)) = rest.split_once('+').filter(|(_, key)| !key.is_empty()) {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => ctrl = true,
+                "alt" | "option" => alt = true,
+                "shift" => shift = true,
+                _ => return Err(format!("unknown modifier `{modifier}` in `{text}`")),
+            }
+            rest = key;
+        }
+
+        let mut characters = rest.chars();
+        let key = match (characters.next(), characters.next()) {
+            (Some(character), None) => KeyName::Char(character),
+            _ => NAMED_KEYS
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(rest))
+                .map(|(_, key)| *key)
+                .or_else(|| {
+                    rest.strip_prefix(['F', 'f'])
+                        .and_then(|number| number.parse::<u8>().ok())
+                        .filter(|number| (1..=12).contains(number))
+                        .map(KeyName::F)
+                })
+                .ok_or_else(|| format!("unknown key `{rest}` in `{text}`"))?,
+        };
+        let key = match key {
+            KeyName::Char(character) if (ctrl || alt) && !shift => {
+                KeyName::Char(character.to_ascii_lowercase())
+            }
+            _ => key,
+        };
+
+        Ok(Self::new(key, ctrl, alt, shift))
+    }
+
+    /// Returns whether this chord types one character into a text input.
+    pub fn is_text_character(self) -> bool {
+        matches!(self.key, KeyName::Char(_)) && !self.ctrl && !self.alt
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            formatter.write_str("Ctrl+")?;
+        }
+        if self.alt {
+            formatter.write_str("Alt+")?;
+        }
+        if self.shift {
+            formatter.write_str("Shift+")?;
+        }
+        match self.key {
+            KeyName::Char(' ') => formatter.write_str("Space"),
+            KeyName::Char(character) => write!(formatter, "{character}"),
+            KeyName::BackTab => formatter.write_str("Shift+Tab"),
+            KeyName::F(number) => write!(formatter, "F{number}"),
+            key => {
+                let name = NAMED_KEYS
+                    .iter()
+                    .find(|(_, named_key)| *named_key == key)
+                    .map_or("?", |(name, _)| *name);
+
+                formatter.write_str(name)
+            }
+        }
+    }
+}
+
+/// Remappable `[list]` actions for the sessions, projects, and settings tabs.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ListAction {
+    /// Opens the quit confirmation.
+    Quit,
+    /// Moves to the next tab.
+    NextTab,
+    /// Moves to the previous tab.
+    PreviousTab,
+    /// Starts the new-session flow.
+    NewSession,
+    /// Opens the project switcher.
+    SwitchProject,
+    /// Selects the next row.
+    SelectNext,
+    /// Selects the previous row.
+    SelectPrevious,
+    /// Opens the selected row.
+    Open,
+    /// Confirms cancelling the selected session.
+    CancelSession,
+    /// Syncs the active list context.
+    Sync,
+    /// Opens the help overlay.
+    Help,
+}
+
+/// Remappable `[view]` actions for the session transcript.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ViewAction {
+    /// Returns to the sessions list.
+    Back,
+    /// Opens the prompt composer.
+    Reply,
+    /// Opens the composer with a slash command.
+    Command,
+    /// Starts a staged draft session.
+    Start,
+    /// Opens the session worktree.
+    OpenWorktree,
+    /// Launches or opens the selected follow-up task.
+    FollowUpTask,
+    /// Selects the previous follow-up task.
+    PreviousFollowUpTask,
+    /// Selects the next follow-up task.
+    NextFollowUpTask,
+    /// Opens review comments, or continues a finished session.
+    Comments,
+    /// Asks the agent to fix failing review checks.
+    FixCi,
+    /// Marks the review request ready.
+    MarkReady,
+    /// Approves the orchestration plan.
+    ApprovePlan,
+    /// Opens the campaign board.
+    CampaignBoard,
+    /// Detaches a managed worker from its campaign.
+    Detach,
+    /// Opens the session diff.
+    Diff,
+    /// Publishes the session branch.
+    Publish,
+    /// Forks the session.
+    Fork,
+    /// Opens or regenerates the focused review.
+    Review,
+    /// Confirms merging the session.
+    Merge,
+    /// Syncs the session branch with its base.
+    Sync,
+    /// Ends the in-progress turn.
+    EndTurn,
+    /// Toggles the activity log.
+    ActivityLog,
+    /// Scrolls the transcript down one line.
+    ScrollDown,
+    /// Scrolls the transcript up one line.
+    ScrollUp,
+    /// Jumps to the oldest output.
+    ScrollTop,
+    /// Jumps to the newest output.
+    ScrollBottom,
+    /// Scrolls down half a page.
+    HalfPageDown,
+    /// Scrolls up half a page.
+    HalfPageUp,
+    /// Opens the help overlay.
+    Help,
+}
+
+/// Remappable `[diff]` actions for the session diff.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DiffAction {
+    /// Leaves the diff.
+    Back,
+    /// Selects the next file, row, or comment.
+    SelectNext,
+    /// Selects the previous file, row, or comment.
+    SelectPrevious,
+    /// Scrolls the patch down.
+    ScrollDown,
+    /// Scrolls the patch up.
+    ScrollUp,
+    /// Focuses the selected file's changes.
+    Open,
+    /// Returns focus to the file list.
+    Files,
+    /// Focuses review comments.
+    Comments,
+    /// Toggles the file preview.
+    Preview,
+    /// Toggles unified and side-by-side layouts.
+    Layout,
+    /// Starts selecting a row range.
+    SelectRows,
+    /// Sends completed inline comments.
+    SubmitComments,
+    /// Reverts the selected changed rows.
+    RevertLines,
+    /// Reverts each hunk containing the selection.
+    RevertHunk,
+    /// Toggles the selected review comment for the next batch.
+    ToggleComment,
+    /// Opens the help overlay.
+    Help,
+}
+
+/// Remappable `[prompt]` actions for the prompt composer.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PromptAction {
+    /// Switches focus between the composer and the transcript.
+    Focus,
+    /// Sends the prompt.
+    Send,
+    /// Inserts a newline.
+    Newline,
+    /// Cancels the composer.
+    Cancel,
+    /// Toggles the permission mode.
+    SwitchMode,
+    /// Scrolls the focused transcript down.
+    ScrollDown,
+    /// Scrolls the focused transcript up.
+    ScrollUp,
+    /// Jumps to the oldest output.
+    ScrollTop,
+    /// Jumps to the newest output.
+    ScrollBottom,
+    /// Opens the session diff from the transcript.
+    Diff,
+    /// Returns to the sessions list from the transcript.
+    Sessions,
+}
+
+/// Remappable `[question]` actions for the clarification-question panel.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum QuestionAction {
+    /// Switches focus between the answer panel and the transcript.
+    Focus,
+    /// Submits the answer.
+    Send,
+    /// Ends the turn without answering.
+    EndTurn,
+    /// Selects the next option or scrolls the transcript down.
+    Down,
+    /// Selects the previous option or scrolls the transcript up.
+    Up,
+    /// Jumps to the oldest output.
+    ScrollTop,
+    /// Jumps to the newest output.
+    ScrollBottom,
+    /// Opens the session diff from the transcript.
+    Diff,
+    /// Returns to the sessions list.
+    Sessions,
+}
+
+/// One remappable action in any mode.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum KeyAction {
+    /// `[list]` action.
+    List(ListAction),
+    /// `[view]` action.
+    View(ViewAction),
+    /// `[diff]` action.
+    Diff(DiffAction),
+    /// `[prompt]` action.
+    Prompt(PromptAction),
+    /// `[question]` action.
+    Question(QuestionAction),
+}
+
+impl KeyAction {
+    /// Returns the mode whose handler owns the action.
+    pub fn mode(self) -> KeymapMode {
+        match self {
+            Self::List(_) => KeymapMode::List,
+            Self::View(_) => KeymapMode::View,
+            Self::Diff(_) => KeymapMode::Diff,
+            Self::Prompt(_) => KeymapMode::Prompt,
+            Self::Question(_) => KeymapMode::Question,
+        }
+    }
+
+    /// Returns the `keymap.toml` key of the action.
+    fn name(self) -> &'static str {
+        ACTIONS
+            .iter()
+            .find(|spec| spec.action == self)
+            .map_or("?", |spec| spec.name)
+    }
+}
+
+/// Per-mode action enum that one mode handler matches on.
+pub trait ModeAction: Copy {
+    /// Section whose chords resolve to this action type.
+    const MODE: KeymapMode;
+
+    /// Narrows a table action to this mode, or `None` for another mode.
+    fn from_key_action(action: KeyAction) -> Option<Self>;
+}
+
+impl ModeAction for ListAction {
+    const MODE: KeymapMode = KeymapMode::List;
+
+    fn from_key_action(action: KeyAction) -> Option<Self> {
+        match action {
+            KeyAction::List(action) => Some(action),
+            _ => None,
+        }
+    }
+}
+
+impl ModeAction for ViewAction {
+    const MODE: KeymapMode = KeymapMode::View;
+
+    fn from_key_action(action: KeyAction) -> Option<Self> {
+        match action {
+            KeyAction::View(action) => Some(action),
+            _ => None,
+        }
+    }
+}
+
+impl ModeAction for DiffAction {
+    const MODE: KeymapMode = KeymapMode::Diff;
+
+    fn from_key_action(action: KeyAction) -> Option<Self> {
+        match action {
+            KeyAction::Diff(action) => Some(action),
+            _ => None,
+        }
+    }
+}
+
+impl ModeAction for PromptAction {
+    const MODE: KeymapMode = KeymapMode::Prompt;
+
+    fn from_key_action(action: KeyAction) -> Option<Self> {
+        match action {
+            KeyAction::Prompt(action) => Some(action),
+            _ => None,
+        }
+    }
+}
+
+impl ModeAction for QuestionAction {
+    const MODE: KeymapMode = KeymapMode::Question;
+
+    fn from_key_action(action: KeyAction) -> Option<Self> {
+        match action {
+            KeyAction::Question(action) => Some(action),
+            _ => None,
+        }
+    }
+}
+
+/// One remappable shortcut and its built-in chords.
+struct ActionSpec {
+    /// Action dispatched to the owning mode handler.
+    action: KeyAction,
+    /// Built-in chords; the first one is shown in help.
+    defaults: &'static [&'static str],
+    /// `keymap.toml` key inside the mode section.
+    name: &'static str,
+}
+
+impl ActionSpec {
+    /// Declares one action for `ACTIONS`.
+    const fn new(action: KeyAction, name: &'static str, defaults: &'static [&'static str]) -> Self {
+        Self {
+            action,
+            defaults,
+            name,
+        }
+    }
+}
+
+/// Declares one `[list]` action.
+const fn list(
+    action: ListAction,
+    name: &'static str,
+    defaults: &'static [&'static str],
+) -> ActionSpec {
+    ActionSpec::new(KeyAction::List(action), name, defaults)
+}
+
+/// Declares one `[view]` action.
+const fn view(
+    action: ViewAction,
+    name: &'static str,
+    defaults: &'static [&'static str],
+) -> ActionSpec {
+    ActionSpec::new(KeyAction::View(action), name, defaults)
+}
+
+/// Declares one `[diff]` action.
+const fn diff(
+    action: DiffAction,
+    name: &'static str,
+    defaults: &'static [&'static str],
+) -> ActionSpec {
+    ActionSpec::new(KeyAction::Diff(action), name, defaults)
+}
+
+/// Declares one `[prompt]` action.
+const fn prompt(
+    action: PromptAction,
+    name: &'static str,
+    defaults: &'static [&'static str],
+) -> ActionSpec {
+    ActionSpec::new(KeyAction::Prompt(action), name, defaults)
+}
+
+/// Declares one `[question]` action.
+const fn question(
+    action: QuestionAction,
+    name: &'static str,
+    defaults: &'static [&'static str],
+) -> ActionSpec {
+    ActionSpec::new(KeyAction::Question(action), name, defaults)
+}
+
+/// Every remappable shortcut, grouped by mode.
+const ACTIONS: &[ActionSpec] = &[
+    list(ListAction::Quit, "quit", &["q"]),
+    list(ListAction::NextTab, "next_tab", &["Tab"]),
+    list(ListAction::PreviousTab, "previous_tab", &["Shift+Tab"]),
+    list(ListAction::NewSession, "new_session", &["a"]),
+    list(ListAction::SwitchProject, "switch_project", &["p"]),
+    list(ListAction::SelectNext, "select_next", &["j"]),
+    list(ListAction::SelectPrevious, "select_previous", &["k"]),
+    list(ListAction::Open, "open", &["Enter"]),
+    list(ListAction::CancelSession, "cancel_session", &["c"]),
+    list(ListAction::Sync, "sync", &["s", "S"]),
+    list(ListAction::Help, "help", &["?"]),
+    view(ViewAction::Back, "back", &["q"]),
+    view(ViewAction::Reply, "reply", &["Enter"]),
+    view(ViewAction::Command, "command", &["/"]),
+    view(ViewAction::Start, "start", &["s"]),
+    view(ViewAction::OpenWorktree, "open_worktree", &["o"]),
+    view(ViewAction::FollowUpTask, "follow_up_task", &["l"]),
+    view(
+        ViewAction::PreviousFollowUpTask,
+        "previous_follow_up_task",
+        &["["],
+    ),
+    view(ViewAction::NextFollowUpTask, "next_follow_up_task", &["]"]),
+    view(ViewAction::Comments, "comments", &["c"]),
+    view(ViewAction::FixCi, "fix_ci", &["x"]),
+    view(ViewAction::MarkReady, "mark_ready", &["R"]),
+    view(ViewAction::ApprovePlan, "approve_plan", &["a"]),
+    view(ViewAction::CampaignBoard, "campaign_board", &["b"]),
+    view(ViewAction::Detach, "detach", &["D"]),
+    view(ViewAction::Diff, "diff", &["d"]),
+    view(ViewAction::Publish, "publish", &["p", "P"]),
+    view(ViewAction::Fork, "fork", &["F"]),
+    view(ViewAction::Review, "review", &["f"]),
+    view(ViewAction::Merge, "merge", &["m"]),
+    view(ViewAction::Sync, "sync", &["r"]),
+    view(ViewAction::EndTurn, "end_turn", &["Ctrl+c"]),
+    view(ViewAction::ActivityLog, "activity_log", &["e"]),
+    view(ViewAction::ScrollDown, "scroll_down", &["j"]),
+    view(ViewAction::ScrollUp, "scroll_up", &["k"]),
+    view(ViewAction::ScrollTop, "scroll_top", &["g"]),
+    view(ViewAction::ScrollBottom, "scroll_bottom", &["G"]),
+    view(ViewAction::HalfPageDown, "half_page_down", &["Ctrl+d"]),
+    view(ViewAction::HalfPageUp, "half_page_up", &["Ctrl+u"]),
+    view(ViewAction::Help, "help", &["?"]),
+    diff(DiffAction::Back, "back", &["q"]),
+    diff(DiffAction::SelectNext, "select_next", &["j"]),
+    diff(DiffAction::SelectPrevious, "select_previous", &["k"]),
+    diff(DiffAction::ScrollDown, "scroll_down", &["J"]),
+    diff(DiffAction::ScrollUp, "scroll_up", &["K"]),
+    diff(DiffAction::Open, "open", &["l"]),
+    diff(DiffAction::Files, "files", &["f", "h"]),
+    diff(DiffAction::Comments, "comments", &["c"]),
+    diff(DiffAction::Preview, "preview", &["p"]),
+    diff(DiffAction::Layout, "layout", &["t"]),
+    diff(DiffAction::SelectRows, "select_rows", &["Shift+V"]),
+    diff(DiffAction::SubmitComments, "submit_comments", &["s"]),
+    diff(DiffAction::RevertLines, "revert_lines", &["x"]),
+    diff(DiffAction::RevertHunk, "revert_hunk", &["X"]),
+    diff(DiffAction::ToggleComment, "toggle_comment", &["Space"]),
+    diff(DiffAction::Help, "help", &["?"]),
+    prompt(PromptAction::Focus, "focus", &["Tab"]),
+    prompt(PromptAction::Send, "send", &["Enter"]),
+    prompt(
+        PromptAction::Newline,
+        "newline",
+        &["Alt+Enter", "Shift+Enter"],
+    ),
+    prompt(PromptAction::Cancel, "cancel", &["Esc", "Ctrl+c"]),
+    prompt(PromptAction::SwitchMode, "switch_mode", &["Shift+Tab"]),
+    prompt(PromptAction::ScrollDown, "scroll_down", &["j"]),
+    prompt(PromptAction::ScrollUp, "scroll_up", &["k"]),
+    prompt(PromptAction::ScrollTop, "scroll_top", &["g"]),
+    prompt(PromptAction::ScrollBottom, "scroll_bottom", &["G"]),
+    prompt(PromptAction::Diff, "diff", &["d"]),
+    prompt(PromptAction::Sessions, "sessions", &["q"]),
+    question(QuestionAction::Focus, "focus", &["Tab"]),
+    question(QuestionAction::Send, "send", &["Enter"]),
+    question(QuestionAction::EndTurn, "end_turn", &["Ctrl+c"]),
+    question(QuestionAction::Down, "down", &["j"]),
+    question(QuestionAction::Up, "up", &["k"]),
+    question(QuestionAction::ScrollTop, "scroll_top", &["g"]),
+    question(QuestionAction::ScrollBottom, "scroll_bottom", &["G"]),
+    question(QuestionAction::Diff, "diff", &["d"]),
+    question(QuestionAction::Sessions, "sessions", &["q"]),
+];
+
+/// Invalid `keymap.toml` content detected at startup.
+#[derive(Debug, Error)]
+pub enum KeymapError {
+    /// The file is not valid TOML or has an unknown section or value shape.
+    #[error("{0}")]
+    Parse(#[from] toml::de::Error),
+    /// An action or chord is unknown or conflicts with another.
+    #[error("{0}")]
+    Invalid(String),
+}
+
+/// Deserialized `keymap.toml` with one optional table per mode.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct KeymapFile {
+    /// `[list]` overrides keyed by action name.
+    list: BTreeMap<String, ChordList>,
+    /// `[view]` overrides keyed by action name.
+    view: BTreeMap<String, ChordList>,
+    /// `[diff]` overrides keyed by action name.
+    diff: BTreeMap<String, ChordList>,
+    /// `[prompt]` overrides keyed by action name.
+    prompt: BTreeMap<String, ChordList>,
+    /// `[question]` overrides keyed by action name.
+    question: BTreeMap<String, ChordList>,
+}
+
+impl KeymapFile {
+    /// Returns each mode with its override table in section order.
+    fn sections(&self) -> [(KeymapMode, &BTreeMap<String, ChordList>); 5] {
+        [
+            (KeymapMode::List, &self.list),
+            (KeymapMode::View, &self.view),
+            (KeymapMode::Diff, &self.diff),
+            (KeymapMode::Prompt, &self.prompt),
+            (KeymapMode::Question, &self.question),
+        ]
+    }
+}
+
+/// One action override: a single chord or an array of chords.
+#[derive(Debug, Deserialize)]
+#[serde(
+    untagged,
+    expecting = "a key chord string or an array of key chord strings"
+)]
+enum ChordList {
+    /// `action = "j"`.
+    One(String),
+    /// `action = ["j", "Down"]`.
+    Many(Vec<String>),
+}
+
+impl ChordList {
+    /// Returns the chord strings in file order.
+    fn texts(&self) -> &[String] {
+        match self {
+            Self::One(text) => std::slice::from_ref(text),
+            Self::Many(texts) => texts,
+        }
+    }
+}
+
+/// Chord table keyed by mode and chord.
+type BindingTable = HashMap<(KeymapMode, KeyChord), KeyAction>;
+
+/// Built-in shortcuts plus validated user overrides.
+#[derive(Debug)]
+pub struct Keymap {
+    /// Effective chords after user overrides, mapped to their actions.
+    bindings: BindingTable,
+    /// Built-in chords mapped to their actions, for surfaces that bypass
+    /// overrides.
+    built_in: BindingTable,
+    /// Built-in chords of overridden actions mapped to the user's first
+    /// chord, for help labels.
+    help_replacements: HashMap<(KeymapMode, KeyChord), KeyChord>,
+    /// Built-in chords released by overrides and not rebound.
+    released: HashSet<(KeymapMode, KeyChord)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let built_in = ACTIONS
+            .iter()
+            .flat_map(|spec| {
+                default_chords(spec)
+                    .into_iter()
+                    .map(|chord| ((spec.action.mode(), chord), spec.action))
+            })
+            .collect::<BindingTable>();
+
+        Self {
+            bindings: built_in.clone(),
+            built_in,
+            help_replacements: HashMap::new(),
+            released: HashSet::new(),
+        }
+    }
+}
+
+impl Keymap {
+    /// Parses and validates `keymap.toml` content.
+    ///
+    /// Each section names a mode and each key names one action; values are
+    /// one chord string or an array of chords that replace the built-in
+    /// chords. Within one mode, every chord may trigger only one action, and
+    /// prompt or question actions cannot move onto plain typed characters.
+    ///
+    /// # Errors
+    /// Returns a [`KeymapError`] for syntax errors, unknown sections, actions,
+    /// or keys, and conflicting bindings.
+    pub fn from_toml(text: &str) -> Result<Self, KeymapError> {
+        let file: KeymapFile = toml::from_str(text)?;
+        let mut user_chords: HashMap<KeyAction, Vec<KeyChord>> = HashMap::new();
+
+        for (mode, section) in file.sections() {
+            let section_name = mode.section_name();
+            for (action_name, chord_list) in section {
+                let spec = ACTIONS
+                    .iter()
+                    .find(|spec| spec.action.mode() == mode && spec.name == action_name)
+                    .ok_or_else(|| {
+                        KeymapError::Invalid(format!(
+                            "unknown action `{section_name}.{action_name}`"
+                        ))
+                    })?;
+                let chords = chord_list
+                    .texts()
+                    .iter()
+                    .map(|chord_text| {
+                        KeyChord::parse(chord_text).map_err(|error| {
+                            KeymapError::Invalid(format!("{section_name}.{action_name}: {error}"))
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if chords.is_empty() {
+                    return Err(KeymapError::Invalid(format!(
+                        "{section_name}.{action_name}: at least one key is required"
+                    )));
+                }
+                validate_text_input_binding(spec, &chords)?;
+                user_chords.insert(spec.action, chords);
+            }
+        }
+
+        Self::from_user_chords(&user_chords)
+    }
+
+    /// Builds lookup tables from validated per-action user chords.
+    fn from_user_chords(
+        user_chords: &HashMap<KeyAction, Vec<KeyChord>>,
+    ) -> Result<Self, KeymapError> {
+        let mut keymap = Self::default();
+        keymap.bindings.clear();
+
+        for spec in ACTIONS {
+            let mode = spec.action.mode();
+            let defaults = default_chords(spec);
+            let chords = user_chords.get(&spec.action);
+            for chord in chords.unwrap_or(&defaults) {
+                if let Some(owner) = keymap.bindings.insert((mode, *chord), spec.action)
+                    && owner != spec.action
+                {
+                    return Err(KeymapError::Invalid(format!(
+                        "conflict in [{}]: `{chord}` is bound to both `{}` and `{}`",
+                        mode.section_name(),
+                        owner.name(),
+                        spec.name
+                    )));
+                }
+            }
+            let Some(first_chord) = chords.and_then(|chords| chords.first()) else {
+                continue;
+            };
+            for default_chord in defaults {
+                keymap
+                    .help_replacements
+                    .insert((mode, default_chord), *first_chord);
+                keymap.released.insert((mode, default_chord));
+            }
+        }
+        let bindings = &keymap.bindings;
+        keymap
+            .released
+            .retain(|mode_chord| !bindings.contains_key(mode_chord));
+
+        Ok(keymap)
+    }
+
+    /// Resolves one pressed chord into an action of `A`'s mode.
+    ///
+    /// While `accepts_text` is set, plain typed characters keep their
+    /// built-in meaning so the focused input still receives them.
+    pub fn action<A: ModeAction>(&self, chord: KeyChord, accepts_text: bool) -> Option<A> {
+        let bindings = if accepts_text && chord.is_text_character() {
+            &self.built_in
+        } else {
+            &self.bindings
+        };
+
+        bindings
+            .get(&(A::MODE, chord))
+            .copied()
+            .and_then(A::from_key_action)
+    }
+
+    /// Resolves one pressed chord through the built-in table only, for
+    /// inline editors and dropdowns that keep their fixed keys.
+    pub fn built_in_action<A: ModeAction>(&self, chord: KeyChord) -> Option<A> {
+        self.built_in
+            .get(&(A::MODE, chord))
+            .copied()
+            .and_then(A::from_key_action)
+    }
+
+    /// Returns whether `chord` lost its built-in action to an override and
+    /// should be ignored in `mode`.
+    pub fn is_released(&self, mode: KeymapMode, chord: KeyChord, accepts_text: bool) -> bool {
+        !(accepts_text && chord.is_text_character()) && self.released.contains(&(mode, chord))
+    }
+
+    /// Rewrites the key label of each help action for `mode` overrides.
+    pub fn remap_help_actions(
+        &self,
+        mode: KeymapMode,
+        mut actions: Vec<HelpAction>,
+    ) -> Vec<HelpAction> {
+        if self.help_replacements.is_empty() {
+            return actions;
+        }
+        for action in &mut actions {
+            if let Some(label) = self.help_label(mode, &action.key) {
+                action.key = Cow::Owned(label);
+            }
+        }
+
+        actions
+    }
+
+    /// Returns `key` with every overridden chord token replaced, or `None`
+    /// when no token is overridden.
+    ///
+    /// A label that is one chord, such as `/`, is replaced whole; other
+    /// labels such as `j/k` or `x x` are split on `/` and spaces, and tokens
+    /// that are not chords stay as written.
+    fn help_label(&self, mode: KeymapMode, key: &str) -> Option<String> {
+        let replacement = |token: &str| {
+            KeyChord::parse(token)
+                .ok()
+                .and_then(|chord| self.help_replacements.get(&(mode, chord)))
+        };
+        if let Some(chord) = replacement(key) {
+            return Some(chord.to_string());
+        }
+
+        let mut is_rewritten = false;
+        let mut label = String::new();
+        let mut token_start = 0;
+        for (index, separator) in key
+            .match_indices(['/', ' '])
+            .chain(std::iter::once((key.len(), "")))
+        {
+            let token = &key[token_start..index];
+            match replacement(token) {
+                Some(chord) => {
+                    is_rewritten = true;
+                    label.push_str(&chord.to_string());
+                }
+                None => label.push_str(token),
+            }
+            label.push_str(separator);
+            token_start = index.saturating_add(separator.len());
+        }
+
+        is_rewritten.then_some(label)
+    }
+}
+
+/// Parses the built-in chords of one action.
+fn default_chords(spec: &ActionSpec) -> Vec<KeyChord> {
+    spec.defaults
+        .iter()
+        .filter_map(|chord_text| KeyChord::parse(chord_text).ok())
+        .collect()
+}
+
+/// Rejects moving a non-character prompt or question action onto a plain
+/// character, which the focused input would consume as text.
+fn validate_text_input_binding(spec: &ActionSpec, chords: &[KeyChord]) -> Result<(), KeymapError> {
+    let mode = spec.action.mode();
+    if !mode.hosts_text_input()
+        || default_chords(spec)
+            .iter()
+            .any(|chord| chord.is_text_character())
+    {
+        return Ok(());
+    }
+    if let Some(chord) = chords.iter().find(|chord| chord.is_text_character()) {
+        return Err(KeymapError::Invalid(format!(
+            "{}.{}: `{chord}` would be typed into the input; use a modifier or special key",
+            mode.section_name(),
+            spec.name
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses one chord for assertions.
+    fn chord(text: &str) -> KeyChord {
+        KeyChord::parse(text).expect("chord should parse")
+    }
+
+    #[test]
+    fn test_built_in_actions_parse_without_conflicts() {
+        // Arrange
+        let user_chords = HashMap::new();
+
+        // Act
+        let keymap = Keymap::from_user_chords(&user_chords);
+        let unparsable_defaults = ACTIONS
+            .iter()
+            .flat_map(|spec| spec.defaults.iter())
+            .filter(|chord_text| KeyChord::parse(chord_text).is_err())
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert!(keymap.is_ok(), "built-in actions conflict: {keymap:?}");
+        assert_eq!(unparsable_defaults, Vec::<&&str>::new());
+    }
+
+    #[test]
+    fn test_key_chord_parse_normalizes_shift_and_modifiers() {
+        // Arrange
+        let cases = [
+            ("J", "Shift+j"),
+            ("Ctrl+d", "ctrl+D"),
+            ("Shift+Tab", "BackTab"),
+            ("Space", " "),
+        ];
+
+        // Act
+        let pairs = cases.map(|(left, right)| (chord(left), chord(right)));
+
+        // Assert
+        for (left, right) in pairs {
+            assert_eq!(left, right);
+        }
+        assert_eq!(chord("Shift+V").to_string(), "V");
+        assert_eq!(chord("Ctrl+Shift+V").to_string(), "Ctrl+Shift+v");
+        assert_eq!(chord("Alt+Enter").to_string(), "Alt+Enter");
+    }
+
+    #[test]
+    fn test_from_toml_resolves_overrides_and_releases_old_chords() {
+        // Arrange
+        let text = "[list]\nselect_next = \"n\"\nselect_previous = [\"e\", \"Up\"]\n";
+
+        // Act
+        let keymap = Keymap::from_toml(text).expect("keymap should load");
+
+        // Assert
+        assert_eq!(
+            keymap.action::<ListAction>(chord("n"), false),
+            Some(ListAction::SelectNext)
+        );
+        assert_eq!(
+            keymap.action::<ListAction>(chord("Up"), false),
+            Some(ListAction::SelectPrevious)
+        );
+        assert_eq!(keymap.action::<ListAction>(chord("j"), false), None);
+        assert!(keymap.is_released(KeymapMode::List, chord("j"), false));
+        assert_eq!(
+            keymap.built_in_action::<ListAction>(chord("j")),
+            Some(ListAction::SelectNext)
+        );
+        assert_eq!(keymap.action::<DiffAction>(chord("n"), false), None);
+    }
+
+    #[test]
+    fn test_from_toml_supports_swapping_two_actions() {
+        // Arrange
+        let text = "[diff]\nselect_next = \"k\"\nselect_previous = \"j\"\n";
+
+        // Act
+        let keymap = Keymap::from_toml(text).expect("keymap should load");
+
+        // Assert
+        assert_eq!(
+            keymap.action::<DiffAction>(chord("j"), false),
+            Some(DiffAction::SelectPrevious)
+        );
+        assert_eq!(
+            keymap.action::<DiffAction>(chord("k"), false),
+            Some(DiffAction::SelectNext)
+        );
+        assert!(!keymap.is_released(KeymapMode::Diff, chord("j"), false));
+    }
+
+    #[test]
+    fn test_from_toml_rejects_conflicting_bindings() {
+        // Arrange
+        let text = "[view]\nmerge = \"r\"\n";
+
+        // Act
+        let error = Keymap::from_toml(text).expect_err("conflict should fail");
+
+        // Assert
+        assert_eq!(
+            error.to_string(),
+            "conflict in [view]: `r` is bound to both `merge` and `sync`"
+        );
+    }
+
+    #[test]
+    fn test_from_toml_rejects_unknown_names_and_typed_prompt_keys() {
+        // Arrange
+        let cases = [
+            ("[lists]\nquit = \"x\"\n", "unknown field `lists`"),
+            ("[list]\nexit = \"x\"\n", "unknown action `list.exit`"),
+            ("[list]\nquit = \"Hyper+x\"\n", "unknown modifier `Hyper`"),
+            ("[list]\nquit = 1\n", "a key chord string"),
+            ("[prompt]\nsend = \"s\"\n", "would be typed into the input"),
+        ];
+
+        // Act
+        let errors = cases.map(|(text, _)| {
+            Keymap::from_toml(text)
+                .expect_err("invalid keymap should fail")
+                .to_string()
+        });
+
+        // Assert
+        for (error, (_, expected)) in errors.iter().zip(cases) {
+            assert!(
+                error.contains(expected),
+                "`{error}` should mention `{expected}`"
+            );
+        }
+    }
+
+    #[test]
+    fn test_action_keeps_typed_characters_for_text_input() {
+        // Arrange
+        let keymap = Keymap::from_toml("[question]\ndown = \"n\"\n").expect("keymap should load");
+
+        // Act
+        let typing = keymap.action::<QuestionAction>(chord("n"), true);
+        let navigating = keymap.action::<QuestionAction>(chord("n"), false);
+
+        // Assert
+        assert_eq!(typing, None);
+        assert_eq!(navigating, Some(QuestionAction::Down));
+        assert!(!keymap.is_released(KeymapMode::Question, chord("j"), true));
+    }
+
+    #[test]
+    fn test_remap_help_actions_rewrites_overridden_tokens() {
+        // Arrange
+        let keymap = Keymap::from_toml("[diff]\nselect_next = \"n\"\nrevert_hunk = \"Ctrl+x\"\n")
+            .expect("keymap should load");
+        let actions = vec![
+            HelpAction::new("select", "j/k", "Select"),
+            HelpAction::new("revert hunk", "X X", "Revert hunk"),
+            HelpAction::new("help", "?", "Help"),
+        ];
+
+        // Act
+        let keys = keymap
+            .remap_help_actions(KeymapMode::Diff, actions)
+            .into_iter()
+            .map(|action| action.key)
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(keys, ["n/k", "Ctrl+x Ctrl+x", "?"]);
+        assert!(matches!(keys[2], Cow::Borrowed("?")));
+    }
+}
@@ -0,0 +1,315 @@
+use ag_forge::{ReviewRequestCreateOptions, ReviewRequestPublishChoices};
+
+use crate::domain::input::{InputCommand, InputState};
+
+/// Maximum number of completion candidates shown below the focused field.
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Separator inserted after each completed list value.
+const LIST_SEPARATOR: &str = ", ";
+
+/// Focusable rows in the publish-branch overlay when it also creates a review
+/// request.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PublishFormField {
+    /// Remote branch name input owned by the publish-branch mode itself.
+    #[default]
+    Branch,
+    /// Draft versus ready-for-review toggle.
+    Draft,
+    /// Comma-separated forge label names.
+    Labels,
+    /// Comma-separated reviewer usernames or `org/team` slugs.
+    Reviewers,
+    /// Single milestone title.
+    Milestone,
+}
+
+impl PublishFormField {
+    /// Focus order from top to bottom.
+    const ORDER: [Self; 5] = [
+        Self::Branch,
+        Self::Draft,
+        Self::Labels,
+        Self::Reviewers,
+        Self::Milestone,
+    ];
+
+    /// Returns the next field, wrapping to the branch input.
+    fn next(self) -> Self {
+        let index = Self::ORDER
+            .iter()
+            .position(|field| *field == self)
+            .unwrap_or(0);
+
+        Self::ORDER[(index + 1) % Self::ORDER.len()]
+    }
+
+    /// Returns the previous field, wrapping to the milestone input.
+    fn previous(self) -> Self {
+        let index = Self::ORDER
+            .iter()
+            .position(|field| *field == self)
+            .unwrap_or(0);
+
+        Self::ORDER[(index + Self::ORDER.len() - 1) % Self::ORDER.len()]
+    }
+}
+
+/// Review-request metadata collected by the publish-branch overlay before the
+/// forge review request is created.
+///
+/// Forge choices load asynchronously; until they arrive, fields stay
+/// editable as free text and completion simply offers nothing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewRequestPublishForm {
+    /// Labels, milestones, and reviewers offered by the forge, once loaded.
+    pub choices: Option<ReviewRequestPublishChoices>,
+    /// Forge error raised while loading completion choices.
+    pub choices_error: Option<String>,
+    /// Whether the review request is opened as a draft.
+    pub draft: bool,
+    /// Currently focused overlay row.
+    pub focus: PublishFormField,
+    /// Comma-separated label names.
+    pub labels: InputState,
+    /// Milestone title.
+    pub milestone: InputState,
+    /// Comma-separated reviewer usernames or team slugs.
+    pub reviewers: InputState,
+}
+
+impl Default for ReviewRequestPublishForm {
+    fn default() -> Self {
+        Self {
+            choices: None,
+            choices_error: None,
+            draft: true,
+            focus: PublishFormField::Branch,
+            labels: InputState::default(),
+            milestone: InputState::default(),
+            reviewers: InputState::default(),
+        }
+    }
+}
+
+impl ReviewRequestPublishForm {
+    /// Moves focus to the next overlay row.
+    pub fn focus_next(&mut self) {
+        self.focus = self.focus.next();
+    }
+
+    /// Moves focus to the previous overlay row.
+    pub fn focus_previous(&mut self) {
+        self.focus = self.focus.previous();
+    }
+
+    /// Flips the draft toggle.
+    pub fn toggle_draft(&mut self) {
+        self.draft = !self.draft;
+    }
+
+    /// Returns the focused metadata input, or `None` for the branch and draft
+    /// rows.
+    pub fn focused_input_mut(&mut self) -> Option<&mut InputState> {
+        match self.focus {
+            PublishFormField::Branch | PublishFormField::Draft => None,
+            PublishFormField::Labels => Some(&mut self.labels),
+            PublishFormField::Reviewers => Some(&mut self.reviewers),
+            PublishFormField::Milestone => Some(&mut self.milestone),
+        }
+    }
+
+    /// Returns forge values matching the token currently being typed in the
+    /// focused field.
+    pub fn suggestions(&self) -> Vec<&str> {
+        let Some((input, candidates)) = self.focused_completion_source() else {
+            return Vec::new();
+        };
+        let (chosen, partial) = split_completion_token(input.text(), self.is_list_focus());
+        let partial = partial.to_lowercase();
+
+        candidates
+            .iter()
+            .map(String::as_str)
+            .filter(|candidate| candidate.to_lowercase().starts_with(&partial))
+            .filter(|candidate| !chosen.contains(candidate))
+            .take(MAX_SUGGESTIONS)
+            .collect()
+    }
+
+    /// Replaces the token being typed with the first suggestion.
+    ///
+    /// Returns `false` when no suggestion matches so callers can fall back
+    /// to moving focus.
+    pub fn complete_focused_field(&mut self) -> bool {
+        let Some(suggestion) = self.suggestions().first().map(|value| (*value).to_string()) else {
+            return false;
+        };
+        let is_list_focus = self.is_list_focus();
+        let Some(input) = self.focused_input_mut() else {
+            return false;
+        };
+        let (chosen, _) = split_completion_token(input.text(), is_list_focus);
+        let mut values = chosen;
+        values.push(&suggestion);
+        let mut text = values.join(LIST_SEPARATOR);
+        if is_list_focus {
+            text.push_str(LIST_SEPARATOR);
+        }
+        input.apply(InputCommand::ReplaceRange {
+            end: input.text().chars().count(),
+            start: 0,
+            text,
+        });
+
+        true
+    }
+
+    /// Converts the collected fields into forge create options.
+    pub fn create_options(&self) -> ReviewRequestCreateOptions {
+        let milestone = self.milestone.text().trim();
+
+        ReviewRequestCreateOptions {
+            draft: self.draft,
+            labels: split_list_values(self.labels.text()),
+            milestone: (!milestone.is_empty()).then(|| milestone.to_string()),
+            reviewers: split_list_values(self.reviewers.text()),
+        }
+    }
+
+    /// Returns the focused input together with its completion candidates.
+    fn focused_completion_source(&self) -> Option<(&InputState, &[String])> {
+        let choices = self.choices.as_ref()?;
+
+        match self.focus {
+            PublishFormField::Branch | PublishFormField::Draft => None,
+            PublishFormField::Labels => Some((&self.labels, choices.labels.as_slice())),
+            PublishFormField::Reviewers => Some((&self.reviewers, choices.reviewers.as_slice())),
+            PublishFormField::Milestone => Some((&self.milestone, choices.milestones.as_slice())),
+        }
+    }
+
+    /// Returns whether the focused field accepts comma-separated values.
+    fn is_list_focus(&self) -> bool {
+        matches!(
+            self.focus,
+            PublishFormField::Labels | PublishFormField::Reviewers
+        )
+    }
+}
+
+/// Splits list input into already chosen values and the trailing token being
+/// typed.
+fn split_completion_token(text: &str, is_list: bool) -> (Vec<&str>, &str) {
+    if !is_list {
+        return (Vec::new(), text.trim());
+    }
+
+    let mut values: Vec<&str> = text.split(',').map(str::trim).collect();
+    let partial = values.pop().unwrap_or_default();
+    values.retain(|value| !value.is_empty());
+
+    (values, partial)
+}
+
+/// Splits comma-separated input into trimmed non-empty values.
+fn split_list_values(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn form_with_choices() -> ReviewRequestPublishForm {
+        ReviewRequestPublishForm {
+            choices: Some(ReviewRequestPublishChoices {
+                labels: vec!["bug".to_string(), "Backend".to_string(), "docs".to_string()],
+                milestones: vec!["v1.0".to_string()],
+                reviewers: vec!["alice".to_string(), "bob".to_string()],
+            }),
+            ..ReviewRequestPublishForm::default()
+        }
+    }
+
+    #[test]
+    fn test_focus_cycles_through_all_fields() {
+        // Arrange
+        let mut form = ReviewRequestPublishForm::default();
+
+        // Act
+        form.focus_previous();
+        let wrapped_focus = form.focus;
+        form.focus_next();
+
+        // Assert
+        assert_eq!(wrapped_focus, PublishFormField::Milestone);
+        assert_eq!(form.focus, PublishFormField::Branch);
+    }
+
+    #[test]
+    fn test_complete_focused_field_appends_case_insensitive_match() {
+        // Arrange
+        let mut form = form_with_choices();
+        form.focus = PublishFormField::Labels;
+        form.labels = InputState::with_text("bug, b".to_string());
+
+        // Act
+        let suggestions: Vec<String> = form
+            .suggestions()
+            .into_iter()
+            .map(ToString::to_string)
+            .collect();
+        let completed = form.complete_focused_field();
+
+        // Assert
+        assert_eq!(suggestions, vec!["Backend"]);
+        assert!(completed);
+        assert_eq!(form.labels.text(), "bug, Backend, ");
+    }
+
+    #[test]
+    fn test_complete_focused_field_returns_false_without_choices() {
+        // Arrange
+        let mut form = ReviewRequestPublishForm {
+            focus: PublishFormField::Reviewers,
+            ..ReviewRequestPublishForm::default()
+        };
+
+        // Act
+        let completed = form.complete_focused_field();
+
+        // Assert
+        assert!(!completed);
+        assert_eq!(form.reviewers.text(), "");
+    }
+
+    #[test]
+    fn test_create_options_trims_list_values_and_blank_milestone() {
+        // Arrange
+        let mut form = form_with_choices();
+        form.toggle_draft();
+        form.labels = InputState::with_text(" bug ,, docs, ".to_string());
+        form.reviewers = InputState::with_text("alice, org/team".to_string());
+        form.milestone = InputState::with_text("  ".to_string());
+
+        // Act
+        let options = form.create_options();
+
+        // Assert
+        assert_eq!(
+            options,
+            ReviewRequestCreateOptions {
+                draft: false,
+                labels: vec!["bug".to_string(), "docs".to_string()],
+                milestone: None,
+                reviewers: vec!["alice".to_string(), "org/team".to_string()],
+            }
+        );
+    }
+}
@@ -1,9 +1,14 @@
 //! Presentation-owned settings screen state and input translation.
 
+use ag_forge::ForgeBackend;
+
 use crate::domain::agent::{AgentSelection, ReasoningLevel, SpeedMode};
 use crate::domain::input::{InputCommand, InputState};
 use crate::domain::selection::SelectionState;
-use crate::domain::setting::MAX_ORCHESTRATION_PARALLELISM;
+use crate::domain::setting::{
+    MAX_ORCHESTRATION_PARALLELISM, MAX_POST_TURN_CHECK_FIX_RETRIES,
+    MERGE_GATE_TIMEOUT_OPTIONS_SECONDS, POST_TURN_CHECK_TIMEOUT_OPTIONS_SECONDS,
+};
 use crate::domain::theme::ColorTheme;
 
 /// Immutable setting values and available choices required by the settings
@@ -21,13 +26,70 @@ pub(crate) struct SettingsView {
     pub(crate) default_smart_reasoning_level: ReasoningLevel,
     pub(crate) default_smart_selection: AgentSelection,
     pub(crate) default_smart_speed_mode: SpeedMode,
+    /// Transport used for the active project's review-request calls.
+    pub(crate) forge_backend: ForgeBackend,
+    /// Newline- or comma-separated hosts treated as Forgejo instances.
+    pub(crate) forgejo_hosts: String,
     pub(crate) include_coauthored_by_agentty: bool,
     pub(crate) launch_configuration: String,
+    pub(crate) merge_gate: MergeGateSettingsView,
     pub(crate) orchestration_parallelism: u8,
+    pub(crate) post_turn_checks: PostTurnChecksSettingsView,
     pub(crate) theme: ColorTheme,
+    /// Notice explaining why the selected user theme falls back to default
+    /// colors.
+    pub(crate) theme_notice: Option<String>,
+    /// Selectable themes with display labels: built-ins, then user themes.
+    pub(crate) theme_options: Vec<(ColorTheme, String)>,
     pub(crate) use_last_used_model_as_default: bool,
 }
 
+/// Project merge-gate configuration shown by the settings screen.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct MergeGateSettingsView {
+    /// Newline-separated commands that must pass in the rebased session
+    /// worktree before a squash merge.
+    pub(crate) commands: String,
+    /// Whether a failed merge gate hands its output to the agent for a fix
+    /// turn.
+    pub(crate) fix_turn: bool,
+    /// Time budget for each merge-gate command, in seconds.
+    pub(crate) timeout_seconds: u64,
+}
+
+impl Default for MergeGateSettingsView {
+    fn default() -> Self {
+        Self {
+            commands: String::new(),
+            fix_turn: false,
+            timeout_seconds: crate::domain::setting::DEFAULT_MERGE_GATE_TIMEOUT_SECONDS,
+        }
+    }
+}
+
+/// Project post-turn check configuration shown by the settings screen.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct PostTurnChecksSettingsView {
+    /// Newline-separated commands run in the session worktree after each
+    /// turn.
+    pub(crate) commands: String,
+    /// Consecutive failed check runs that may start an automatic fix turn;
+    /// zero disables fix turns.
+    pub(crate) fix_retries: u8,
+    /// Time budget for each check command, in seconds.
+    pub(crate) timeout_seconds: u64,
+}
+
+impl Default for PostTurnChecksSettingsView {
+    fn default() -> Self {
+        Self {
+            commands: String::new(),
+            fix_retries: 0,
+            timeout_seconds: crate::domain::setting::DEFAULT_POST_TURN_CHECK_TIMEOUT_SECONDS,
+        }
+    }
+}
+
 /// One persistence operation requested by the settings screen.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) enum SettingsOperation {
@@ -48,9 +110,17 @@ pub(crate) enum SettingsOperation {
         speed_mode: SpeedMode,
         use_last_used_model_as_default: bool,
     },
+    ForgeBackend(ForgeBackend),
+    ForgejoHosts(String),
     IncludeCoauthoredByAgentty(bool),
     LaunchConfiguration(String),
+    MergeGateCommands(String),
+    MergeGateFixTurn(bool),
+    MergeGateTimeoutSeconds(u64),
     OrchestrationParallelism(u8),
+    PostTurnCheckCommands(String),
+    PostTurnCheckFixRetries(u8),
+    PostTurnCheckTimeoutSeconds(u64),
     Theme(ColorTheme),
 }
 
@@ -96,7 +166,7 @@ pub(crate) struct SettingsSelectorDropdown {
     pub title: &'static str,
 }
 
-/// Active interaction mode for the `Launch Configurations` list editor.
+/// Active interaction mode for the project command-list editor.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum LaunchConfigurationListEditorMode {
     Add,
@@ -104,13 +174,14 @@ pub enum LaunchConfigurationListEditorMode {
     Edit,
 }
 
-/// Render-ready snapshot for the `Launch Configurations` list editor overlay.
+/// Render-ready snapshot for the project command-list editor overlay.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LaunchConfigurationListEditorSnapshot {
     pub commands: Vec<String>,
     pub input: Option<InputState>,
     pub mode: LaunchConfigurationListEditorMode,
     pub selected_index: usize,
+    pub title: &'static str,
 }
 
 /// Immutable data required to render one settings screen frame.
@@ -312,11 +383,12 @@ impl SettingsPresentationState {
 
         match self.selected_row().control() {
             SettingControl::CommandList => {
-                self.launch_configuration_list_editor = Some(
-                    LaunchConfigurationListEditorState::from_launch_configuration(
-                        view.launch_configuration.as_str(),
-                    ),
-                );
+                let row = self.selected_row();
+                self.launch_configuration_list_editor =
+                    Some(LaunchConfigurationListEditorState::from_commands(
+                        row,
+                        command_list_value(view, row),
+                    ));
             }
             SettingControl::Selector => self.open_selector_dropdown(view, self.selected_row()),
         }
@@ -364,9 +436,7 @@ impl SettingsPresentationState {
         editor.commands.remove(editor.selected_index());
         editor.clamp_selected_index();
 
-        Some(SettingsOperation::LaunchConfiguration(
-            join_launch_configurations(&editor.commands),
-        ))
+        Some(command_list_operation(editor.row, &editor.commands))
     }
 
     fn edit_launch_configuration(&mut self) -> Option<SettingsOperation> {
@@ -406,6 +476,7 @@ impl SettingsPresentationState {
             input: editor.is_input_mode().then(|| editor.input.clone()),
             mode: editor.mode,
             selected_index: editor.selected_index(),
+            title: editor.row.label(),
         })
     }
 
@@ -431,9 +502,7 @@ impl SettingsPresentationState {
         editor.commands.swap(selected_index, next_index);
         editor.selected_index = next_index;
 
-        Some(SettingsOperation::LaunchConfiguration(
-            join_launch_configurations(&editor.commands),
-        ))
+        Some(command_list_operation(editor.row, &editor.commands))
     }
 
     fn next(&mut self, view: &SettingsView) {
@@ -521,7 +590,8 @@ impl SettingsPresentationState {
                             .selected_index
                             .min(options.len().saturating_sub(1)),
                     )?
-                    .value;
+                    .value
+                    .clone();
 
                 match value {
                     SettingSelectorValue::ModelSelection(selection) => {
@@ -691,12 +761,11 @@ impl SettingsPresentationState {
     }
 
     fn footer_hint(&self) -> &'static str {
-        if self.is_launch_configuration_list_editor_input_active() {
-            "Launch Configurations: type a command, Enter save, Esc cancel"
-        } else if self.is_launch_configuration_list_editor_open() {
-            "Launch Configurations: j/k move, a add, e/Enter edit, d delete, J/K reorder, Esc/q \
-             close"
-        } else if let Some(selector_dropdown) = self.selector_dropdown {
+        if let Some(editor) = &self.launch_configuration_list_editor {
+            return editor.row.command_list_footer_hint(editor.is_input_mode());
+        }
+
+        if let Some(selector_dropdown) = self.selector_dropdown {
             selector_dropdown.footer_hint()
         } else {
             "Settings: Enter opens selectors or command editor"
@@ -740,12 +809,27 @@ fn settings_operation_for_primary_selector(
         (SettingRow::AutoApproveOrchestrationResearch, SettingSelectorValue::Bool(value)) => {
             Some(SettingsOperation::AutoApproveOrchestrationResearch(value))
         }
+        (SettingRow::ForgeBackend, SettingSelectorValue::ForgeBackend(value)) => {
+            Some(SettingsOperation::ForgeBackend(value))
+        }
         (SettingRow::IncludeCoauthoredByAgentty, SettingSelectorValue::Bool(value)) => {
             Some(SettingsOperation::IncludeCoauthoredByAgentty(value))
         }
+        (SettingRow::MergeGateFixTurn, SettingSelectorValue::Bool(value)) => {
+            Some(SettingsOperation::MergeGateFixTurn(value))
+        }
+        (SettingRow::MergeGateTimeout, SettingSelectorValue::TimeoutSeconds(value)) => {
+            Some(SettingsOperation::MergeGateTimeoutSeconds(value))
+        }
         (SettingRow::OrchestrationParallelism, SettingSelectorValue::Parallelism(value)) => {
             Some(SettingsOperation::OrchestrationParallelism(value))
         }
+        (SettingRow::PostTurnCheckFixRetries, SettingSelectorValue::FixRetries(value)) => {
+            Some(SettingsOperation::PostTurnCheckFixRetries(value))
+        }
+        (SettingRow::PostTurnCheckTimeout, SettingSelectorValue::TimeoutSeconds(value)) => {
+            Some(SettingsOperation::PostTurnCheckTimeoutSeconds(value))
+        }
         (SettingRow::Theme, SettingSelectorValue::Theme(value)) => {
             Some(SettingsOperation::Theme(value))
         }
@@ -765,14 +849,22 @@ enum SettingRow {
     DefaultSmartModel,
     DefaultFastModel,
     DefaultReviewModel,
+    ForgeBackend,
+    ForgejoHosts,
     IncludeCoauthoredByAgentty,
     LaunchConfiguration,
+    MergeGateCommands,
+    MergeGateFixTurn,
+    MergeGateTimeout,
     OrchestrationParallelism,
+    PostTurnCheckCommands,
+    PostTurnCheckFixRetries,
+    PostTurnCheckTimeout,
     Theme,
 }
 
 impl SettingRow {
-    const ALL: [Self; 8] = [
+    const ALL: [Self; 16] = [
         Self::Theme,
         Self::OrchestrationParallelism,
         Self::AutoApproveOrchestrationResearch,
@@ -781,18 +873,34 @@ impl SettingRow {
         Self::DefaultReviewModel,
         Self::IncludeCoauthoredByAgentty,
         Self::LaunchConfiguration,
+        Self::MergeGateCommands,
+        Self::MergeGateTimeout,
+        Self::MergeGateFixTurn,
+        Self::PostTurnCheckCommands,
+        Self::PostTurnCheckTimeout,
+        Self::PostTurnCheckFixRetries,
+        Self::ForgejoHosts,
+        Self::ForgeBackend,
     ];
     const GLOBAL: [Self; 3] = [
         Self::Theme,
         Self::OrchestrationParallelism,
         Self::AutoApproveOrchestrationResearch,
     ];
-    const PROJECT: [Self; 5] = [
+    const PROJECT: [Self; 13] = [
         Self::DefaultSmartModel,
         Self::DefaultFastModel,
         Self::DefaultReviewModel,
         Self::IncludeCoauthoredByAgentty,
         Self::LaunchConfiguration,
+        Self::MergeGateCommands,
+        Self::MergeGateTimeout,
+        Self::MergeGateFixTurn,
+        Self::PostTurnCheckCommands,
+        Self::PostTurnCheckTimeout,
+        Self::PostTurnCheckFixRetries,
+        Self::ForgejoHosts,
+        Self::ForgeBackend,
     ];
     const ROW_COUNT: usize = Self::ALL.len();
 
@@ -805,20 +913,60 @@ impl SettingRow {
 
     fn control(self) -> SettingControl {
         match self {
-            Self::LaunchConfiguration => SettingControl::CommandList,
+            Self::ForgejoHosts
+            | Self::LaunchConfiguration
+            | Self::MergeGateCommands
+            | Self::PostTurnCheckCommands => SettingControl::CommandList,
             _ => SettingControl::Selector,
         }
     }
 
+    /// Returns the footer hint for this row's open command-list editor.
+    fn command_list_footer_hint(self, is_input_mode: bool) -> &'static str {
+        match (self, is_input_mode) {
+            (Self::ForgejoHosts, true) => "Forgejo Hosts: type a host, Enter save, Esc cancel",
+            (Self::ForgejoHosts, false) => {
+                "Forgejo Hosts: j/k move, a add, e/Enter edit, d delete, J/K reorder, Esc/q close"
+            }
+            (Self::MergeGateCommands, true) => {
+                "Merge Gate Commands: type a command, Enter save, Esc cancel"
+            }
+            (Self::MergeGateCommands, false) => {
+                "Merge Gate Commands: j/k move, a add, e/Enter edit, d delete, J/K reorder, Esc/q \
+                 close"
+            }
+            (Self::PostTurnCheckCommands, true) => {
+                "Post-turn Checks: type a command, Enter save, Esc cancel"
+            }
+            (Self::PostTurnCheckCommands, false) => {
+                "Post-turn Checks: j/k move, a add, e/Enter edit, d delete, J/K reorder, Esc/q \
+                 close"
+            }
+            (_, true) => "Launch Configurations: type a command, Enter save, Esc cancel",
+            (_, false) => {
+                "Launch Configurations: j/k move, a add, e/Enter edit, d delete, J/K reorder, \
+                 Esc/q close"
+            }
+        }
+    }
+
     fn label(self) -> &'static str {
         match self {
             Self::AutoApproveOrchestrationResearch => "Auto-approve Research",
             Self::DefaultSmartModel => "Default Smart Model",
             Self::DefaultFastModel => "Default Fast Model",
             Self::DefaultReviewModel => "Default Review Model",
+            Self::ForgeBackend => "Forge Backend",
+            Self::ForgejoHosts => "Forgejo Hosts",
             Self::IncludeCoauthoredByAgentty => "Coauthored by Agentty",
             Self::LaunchConfiguration => "Launch Configurations",
+            Self::MergeGateCommands => "Merge Gate Commands",
+            Self::MergeGateFixTurn => "Merge Gate Fix Turn",
+            Self::MergeGateTimeout => "Merge Gate Timeout",
             Self::OrchestrationParallelism => "Orchestrator Parallelism",
+            Self::PostTurnCheckCommands => "Post-turn Checks",
+            Self::PostTurnCheckFixRetries => "Post-turn Check Fix Retries",
+            Self::PostTurnCheckTimeout => "Post-turn Check Timeout",
             Self::Theme => "Theme",
         }
     }
@@ -934,15 +1082,17 @@ struct LaunchConfigurationListEditorState {
     commands: Vec<String>,
     input: InputState,
     mode: LaunchConfigurationListEditorMode,
+    row: SettingRow,
     selected_index: usize,
 }
 
 impl LaunchConfigurationListEditorState {
-    fn from_launch_configuration(launch_configuration: &str) -> Self {
+    fn from_commands(row: SettingRow, commands: &str) -> Self {
         Self {
-            commands: parse_launch_configurations(launch_configuration),
+            commands: parse_launch_configurations(commands),
             input: InputState::default(),
             mode: LaunchConfigurationListEditorMode::Browse,
+            row,
             selected_index: 0,
         }
     }
@@ -978,7 +1128,7 @@ struct SettingSelectorOption {
 
 impl SettingSelectorOption {
     fn is_current_for(&self, view: &SettingsView, row: SettingRow) -> bool {
-        match (row, self.value) {
+        match (row, self.value.clone()) {
             (SettingRow::AutoApproveOrchestrationResearch, SettingSelectorValue::Bool(value)) => {
                 view.auto_approve_orchestration_research == value
             }
@@ -994,25 +1144,43 @@ impl SettingSelectorOption {
             (SettingRow::DefaultReviewModel, SettingSelectorValue::ModelSelection(selection)) => {
                 view.default_review_selection == selection
             }
+            (SettingRow::ForgeBackend, SettingSelectorValue::ForgeBackend(value)) => {
+                view.forge_backend == value
+            }
             (SettingRow::IncludeCoauthoredByAgentty, SettingSelectorValue::Bool(value)) => {
                 view.include_coauthored_by_agentty == value
             }
+            (SettingRow::MergeGateFixTurn, SettingSelectorValue::Bool(value)) => {
+                view.merge_gate.fix_turn == value
+            }
+            (SettingRow::MergeGateTimeout, SettingSelectorValue::TimeoutSeconds(value)) => {
+                view.merge_gate.timeout_seconds == value
+            }
             (SettingRow::OrchestrationParallelism, SettingSelectorValue::Parallelism(value)) => {
                 view.orchestration_parallelism == value
             }
+            (SettingRow::PostTurnCheckFixRetries, SettingSelectorValue::FixRetries(value)) => {
+                view.post_turn_checks.fix_retries == value
+            }
+            (SettingRow::PostTurnCheckTimeout, SettingSelectorValue::TimeoutSeconds(value)) => {
+                view.post_turn_checks.timeout_seconds == value
+            }
             (SettingRow::Theme, SettingSelectorValue::Theme(value)) => view.theme == value,
             _ => false,
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 enum SettingSelectorValue {
     Bool(bool),
+    FixRetries(u8),
+    ForgeBackend(ForgeBackend),
     LastUsedModel,
     ModelSelection(AgentSelection),
     Parallelism(u8),
     Theme(ColorTheme),
+    TimeoutSeconds(u64),
 }
 
 fn apply_launch_configuration_input(
@@ -1044,7 +1212,28 @@ fn apply_launch_configuration_input(
     editor.input = InputState::default();
     editor.mode = LaunchConfigurationListEditorMode::Browse;
 
-    SettingsOperation::LaunchConfiguration(join_launch_configurations(&editor.commands))
+    command_list_operation(editor.row, &editor.commands)
+}
+
+/// Returns the persisted newline-joined value edited by one command-list row.
+fn command_list_value(view: &SettingsView, row: SettingRow) -> &str {
+    match row {
+        SettingRow::ForgejoHosts => view.forgejo_hosts.as_str(),
+        SettingRow::MergeGateCommands => view.merge_gate.commands.as_str(),
+        SettingRow::PostTurnCheckCommands => view.post_turn_checks.commands.as_str(),
+        _ => view.launch_configuration.as_str(),
+    }
+}
+
+/// Builds the persistence request for one edited command-list row.
+fn command_list_operation(row: SettingRow, commands: &[String]) -> SettingsOperation {
+    let value = join_launch_configurations(commands);
+    match row {
+        SettingRow::ForgejoHosts => SettingsOperation::ForgejoHosts(value),
+        SettingRow::MergeGateCommands => SettingsOperation::MergeGateCommands(value),
+        SettingRow::PostTurnCheckCommands => SettingsOperation::PostTurnCheckCommands(value),
+        _ => SettingsOperation::LaunchConfiguration(value),
+    }
 }
 
 fn move_launch_configuration_list_editor_selection(
@@ -1067,9 +1256,9 @@ fn move_launch_configuration_list_editor_selection(
 
 fn selector_options_for_row(view: &SettingsView, row: SettingRow) -> Vec<SettingSelectorOption> {
     match row {
-        SettingRow::AutoApproveOrchestrationResearch | SettingRow::IncludeCoauthoredByAgentty => {
-            bool_selector_options()
-        }
+        SettingRow::AutoApproveOrchestrationResearch
+        | SettingRow::IncludeCoauthoredByAgentty
+        | SettingRow::MergeGateFixTurn => bool_selector_options(),
         SettingRow::DefaultSmartModel => {
             let mut options = model_selector_options(view);
             options.push(SettingSelectorOption {
@@ -1082,19 +1271,41 @@ fn selector_options_for_row(view: &SettingsView, row: SettingRow) -> Vec<Setting
         SettingRow::DefaultFastModel | SettingRow::DefaultReviewModel => {
             model_selector_options(view)
         }
-        SettingRow::LaunchConfiguration => Vec::new(),
+        SettingRow::ForgeBackend => ForgeBackend::ALL
+            .into_iter()
+            .map(|value| SettingSelectorOption {
+                label: value.display_name().to_string(),
+                value: SettingSelectorValue::ForgeBackend(value),
+            })
+            .collect(),
+        SettingRow::ForgejoHosts
+        | SettingRow::LaunchConfiguration
+        | SettingRow::MergeGateCommands
+        | SettingRow::PostTurnCheckCommands => Vec::new(),
+        SettingRow::MergeGateTimeout => {
+            timeout_selector_options(MERGE_GATE_TIMEOUT_OPTIONS_SECONDS)
+        }
         SettingRow::OrchestrationParallelism => (1..=MAX_ORCHESTRATION_PARALLELISM)
             .map(|value| SettingSelectorOption {
                 label: value.to_string(),
                 value: SettingSelectorValue::Parallelism(value),
             })
             .collect(),
-        SettingRow::Theme => ColorTheme::ALL
-            .iter()
-            .copied()
+        SettingRow::PostTurnCheckFixRetries => (0..=MAX_POST_TURN_CHECK_FIX_RETRIES)
             .map(|value| SettingSelectorOption {
-                label: value.label().to_string(),
-                value: SettingSelectorValue::Theme(value),
+                label: display_fix_retries(value),
+                value: SettingSelectorValue::FixRetries(value),
+            })
+            .collect(),
+        SettingRow::PostTurnCheckTimeout => {
+            timeout_selector_options(POST_TURN_CHECK_TIMEOUT_OPTIONS_SECONDS)
+        }
+        SettingRow::Theme => view
+            .theme_options
+            .iter()
+            .map(|(value, label)| SettingSelectorOption {
+                label: label.clone(),
+                value: SettingSelectorValue::Theme(value.clone()),
             })
             .collect(),
     }
@@ -1110,6 +1321,16 @@ fn bool_selector_options() -> Vec<SettingSelectorOption> {
         .collect()
 }
 
+fn timeout_selector_options(options_seconds: [u64; 4]) -> Vec<SettingSelectorOption> {
+    options_seconds
+        .into_iter()
+        .map(|value| SettingSelectorOption {
+            label: display_timeout_seconds(value),
+            value: SettingSelectorValue::TimeoutSeconds(value),
+        })
+        .collect()
+}
+
 fn model_selector_options(view: &SettingsView) -> Vec<SettingSelectorOption> {
     view.available_model_selections
         .iter()
@@ -1162,17 +1383,63 @@ fn display_value_for_row(view: &SettingsView, row: SettingRow) -> String {
             view.default_review_reasoning_level,
             view.default_review_speed_mode,
         ),
+        SettingRow::ForgeBackend => view.forge_backend.display_name().to_string(),
+        SettingRow::ForgejoHosts => display_launch_configuration_summary(&view.forgejo_hosts),
         SettingRow::IncludeCoauthoredByAgentty => {
             bool_setting_display(view.include_coauthored_by_agentty)
         }
         SettingRow::LaunchConfiguration => {
             display_launch_configuration_summary(&view.launch_configuration)
         }
+        SettingRow::MergeGateCommands => {
+            display_launch_configuration_summary(&view.merge_gate.commands)
+        }
+        SettingRow::MergeGateFixTurn => bool_setting_display(view.merge_gate.fix_turn),
+        SettingRow::MergeGateTimeout => display_timeout_seconds(view.merge_gate.timeout_seconds),
         SettingRow::OrchestrationParallelism => view.orchestration_parallelism.to_string(),
-        SettingRow::Theme => view.theme.label().to_string(),
+        SettingRow::PostTurnCheckCommands => {
+            display_launch_configuration_summary(&view.post_turn_checks.commands)
+        }
+        SettingRow::PostTurnCheckFixRetries => {
+            display_fix_retries(view.post_turn_checks.fix_retries)
+        }
+        SettingRow::PostTurnCheckTimeout => {
+            display_timeout_seconds(view.post_turn_checks.timeout_seconds)
+        }
+        SettingRow::Theme => display_theme(view),
     }
 }
 
+/// Returns the selected theme's display label with any fallback notice.
+fn display_theme(view: &SettingsView) -> String {
+    let label = view
+        .theme_options
+        .iter()
+        .find(|(theme, _)| *theme == view.theme)
+        .map_or_else(|| view.theme.label(), |(_, label)| label.as_str());
+
+    match &view.theme_notice {
+        Some(notice) => format!("{label} ({notice})"),
+        None => label.to_string(),
+    }
+}
+
+fn display_fix_retries(value: u8) -> String {
+    if value == 0 {
+        return "Disabled".to_string();
+    }
+
+    value.to_string()
+}
+
+fn display_timeout_seconds(value: u64) -> String {
+    if value.is_multiple_of(60) {
+        return format!("{} min", value / 60);
+    }
+
+    format!("{value} s")
+}
+
 fn bool_setting_display(value: bool) -> String {
     if value {
         "Enabled".to_string()
@@ -1278,10 +1545,19 @@ mod tests {
             default_smart_reasoning_level: ReasoningLevel::High,
             default_smart_selection: smart_selection,
             default_smart_speed_mode: SpeedMode::Normal,
+            forge_backend: ForgeBackend::default(),
+            forgejo_hosts: String::new(),
             include_coauthored_by_agentty: false,
             launch_configuration: launch_configuration.to_string(),
+            merge_gate: MergeGateSettingsView::default(),
             orchestration_parallelism: 3,
+            post_turn_checks: PostTurnChecksSettingsView::default(),
             theme: ColorTheme::Current,
+            theme_notice: None,
+            theme_options: ColorTheme::ALL
+                .into_iter()
+                .map(|theme| (theme.clone(), theme.label().to_string()))
+                .collect(),
             use_last_used_model_as_default: false,
         }
     }
@@ -1655,12 +1931,14 @@ mod tests {
             commands: Vec::new(),
             input: InputState::with_text("nvim".to_string()),
             mode: LaunchConfigurationListEditorMode::Edit,
+            row: SettingRow::LaunchConfiguration,
             selected_index: 0,
         };
         let mut empty_add = LaunchConfigurationListEditorState {
             commands: Vec::new(),
             input: InputState::default(),
             mode: LaunchConfigurationListEditorMode::Add,
+            row: SettingRow::LaunchConfiguration,
             selected_index: 0,
         };
 
@@ -1679,6 +1957,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn merge_gate_list_editor_input_persists_merge_gate_commands() {
+        // Arrange
+        let mut editor = LaunchConfigurationListEditorState::from_commands(
+            SettingRow::MergeGateCommands,
+            "cargo test",
+        );
+        editor.mode = LaunchConfigurationListEditorMode::Add;
+        editor.input = InputState::with_text("cargo clippy".to_string());
+
+        // Act
+        let operation = apply_launch_configuration_input(&mut editor);
+
+        // Assert
+        assert_eq!(
+            operation,
+            SettingsOperation::MergeGateCommands("cargo test\ncargo clippy".to_string())
+        );
+    }
+
     #[test]
     fn previous_launch_selection_wraps_and_all_row_options_are_available() {
         // Arrange
@@ -1687,6 +1985,7 @@ mod tests {
             commands: vec!["cargo test".to_string(), "npm run dev".to_string()],
             input: InputState::default(),
             mode: LaunchConfigurationListEditorMode::Browse,
+            row: SettingRow::LaunchConfiguration,
             selected_index: 0,
         };
 
@@ -3,16 +3,22 @@
 //! This parent module intentionally exposes child modules and re-exports
 //! runtime entry APIs.
 
+#[cfg(unix)]
+mod control_server;
 mod core;
 mod event;
+mod headless;
 mod key_handler;
+mod keymap;
 pub mod mode;
 mod presentation;
+mod session_json;
 mod terminal;
 mod timing;
 
 pub(crate) use core::{EventResult, TuiTerminal, backend_err};
 pub use core::{run, run_with_backend};
 
+pub use headless::{SessionArgs, SessionCommand, run_session_command};
 pub(crate) use presentation::PresentationState;
 pub(crate) use timing::FRAME_INTERVAL;
@@ -0,0 +1,1131 @@
+//! Local JSON-RPC 2.0 control server owned by the terminal runtime.
+//!
+//! The server listens on a user-only Unix-domain socket and exposes the
+//! [`SessionService`] operations plus a subscription stream of session status
+//! and transcript changes. Messages are newline-delimited JSON objects; batch
+//! requests are not supported.
+
+use std::io;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use ag_session::{
+    AnswerQuestionsRequest, CreateSessionMode, CreateSessionRequest, QuestionAnswer, ReviewRequest,
+    SessionError, SessionId, SessionService,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio::task::{JoinHandle, JoinSet};
+use tracing::warn;
+
+use crate::app::{SessionChangeEvent, SessionChangeFeed};
+use crate::infra::db::SessionRepository;
+use crate::runtime::session_json::{SessionDetailView, SessionSummaryView};
+
+/// Socket filename created under the Agentty home directory.
+pub(crate) const CONTROL_SOCKET_FILE: &str = "control.sock";
+
+/// Cadence used to diff persisted sessions into subscription events.
+const SESSION_EVENT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Buffered change events kept for slow subscribers before they lag.
+const SESSION_EVENT_CAPACITY: usize = 1024;
+
+/// Longest accepted request line in bytes; longer lines close the client.
+const MAX_REQUEST_LINE_BYTES: usize = 1024 * 1024;
+
+/// JSON-RPC 2.0 protocol version string.
+const JSONRPC_VERSION: &str = "2.0";
+
+/// Standard JSON-RPC error code for malformed JSON.
+const PARSE_ERROR: i64 = -32700;
+/// Standard JSON-RPC error code for structurally invalid requests.
+const INVALID_REQUEST: i64 = -32600;
+/// Standard JSON-RPC error code for unknown methods.
+const METHOD_NOT_FOUND: i64 = -32601;
+/// Standard JSON-RPC error code for malformed method parameters.
+const INVALID_PARAMS: i64 = -32602;
+/// Server-defined error code for rejected or failed session operations.
+const SESSION_OPERATION_FAILED: i64 = -32000;
+/// Server-defined error code for unknown sessions.
+const SESSION_NOT_FOUND: i64 = -32001;
+
+/// Running control-server tasks and the socket they own.
+pub(crate) struct ControlServerTask {
+    accept_task: JoinHandle<()>,
+    feed_task: JoinHandle<()>,
+    socket_path: PathBuf,
+}
+
+impl ControlServerTask {
+    /// Binds the control socket and starts serving `session_service`.
+    ///
+    /// Stale sockets left by a crashed process are replaced, while a socket
+    /// still accepting connections is reported as in use.
+    ///
+    /// # Errors
+    /// Returns an error when another process owns the socket or the socket
+    /// cannot be bound.
+    pub(crate) async fn start(
+        socket_path: PathBuf,
+        session_service: SessionService,
+        session_repository: Arc<dyn SessionRepository>,
+        default_project_id: i64,
+    ) -> io::Result<Self> {
+        let listener = bind_control_socket(&socket_path).await?;
+        let (event_tx, _) = broadcast::channel(SESSION_EVENT_CAPACITY);
+        let (feed_start_tx, feed_start_rx) = mpsc::unbounded_channel();
+        let context = Arc::new(ControlContext {
+            default_project_id,
+            event_tx: event_tx.clone(),
+            feed_start_tx,
+            session_service,
+        });
+
+        Ok(Self {
+            accept_task: tokio::spawn(accept_connections(listener, context)),
+            feed_task: tokio::spawn(publish_session_changes(
+                session_repository,
+                event_tx,
+                feed_start_rx,
+            )),
+            socket_path,
+        })
+    }
+
+    /// Stops accepting connections, closes open clients, and removes the
+    /// socket file.
+    pub(crate) async fn stop(self) {
+        self.accept_task.abort();
+        self.feed_task.abort();
+        let _ = self.accept_task.await;
+        let _ = self.feed_task.await;
+        // Best-effort: the socket may already be gone.
+        let _ = tokio::fs::remove_file(&self.socket_path).await;
+    }
+}
+
+/// Binds `socket_path` with user-only permissions.
+///
+/// The socket is bound inside a fresh `0700` staging directory, restricted to
+/// `0600`, and only then renamed into place, so it is never reachable with
+/// default permissions.
+async fn bind_control_socket(socket_path: &Path) -> io::Result<UnixListener> {
+    let parent = socket_path.parent().unwrap_or_else(|| Path::new("."));
+    tokio::fs::create_dir_all(parent).await?;
+    if tokio::fs::try_exists(socket_path).await? {
+        if UnixStream::connect(socket_path).await.is_ok() {
+            return Err(io::Error::new(
+                io::ErrorKind::AddrInUse,
+                format!(
+                    "control socket `{}` is owned by another Agentty process",
+                    socket_path.display()
+                ),
+            ));
+        }
+
+        tokio::fs::remove_file(socket_path).await?;
+    }
+
+    let staging_dir = parent.join(format!(".{CONTROL_SOCKET_FILE}.{}", std::process::id()));
+    // Best-effort: a crashed process may have left its staging directory.
+    let _ = tokio::fs::remove_dir_all(&staging_dir).await;
+    tokio::fs::DirBuilder::new()
+        .mode(0o700)
+        .create(&staging_dir)
+        .await?;
+    let bind_result = bind_staged_control_socket(&staging_dir, socket_path).await;
+    // Best-effort: the staging directory is empty once the socket moved.
+    let _ = tokio::fs::remove_dir_all(&staging_dir).await;
+
+    bind_result
+}
+
+/// Binds the socket inside `staging_dir`, restricts it, and moves it to
+/// `socket_path`.
+async fn bind_staged_control_socket(
+    staging_dir: &Path,
+    socket_path: &Path,
+) -> io::Result<UnixListener> {
+    let staged_socket_path = staging_dir.join(CONTROL_SOCKET_FILE);
+    let listener = UnixListener::bind(&staged_socket_path)?;
+    tokio::fs::set_permissions(&staged_socket_path, std::fs::Permissions::from_mode(0o600)).await?;
+    tokio::fs::rename(&staged_socket_path, socket_path).await?;
+
+    Ok(listener)
+}
+
+/// Accepts clients until the task is aborted.
+///
+/// Connection tasks live in a `JoinSet`, so aborting this task also closes
+/// every open client.
+async fn accept_connections(listener: UnixListener, context: Arc<ControlContext>) {
+    let mut connections = JoinSet::new();
+
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    connections.spawn(serve_connection(stream, Arc::clone(&context)));
+                }
+                Err(error) => warn!(%error, "control server failed to accept a client"),
+            },
+            Some(_) = connections.join_next(), if !connections.is_empty() => {}
+        }
+    }
+}
+
+/// Polls persisted sessions and broadcasts every observed change while at
+/// least one client is subscribed.
+///
+/// Each subscription first asks for a baseline through `feed_start_rx`, and
+/// the feed is dropped once the last subscriber leaves, so an idle socket
+/// issues no session queries.
+async fn publish_session_changes(
+    session_repository: Arc<dyn SessionRepository>,
+    event_tx: broadcast::Sender<SessionChangeEvent>,
+    mut feed_start_rx: mpsc::UnboundedReceiver<FeedStartAck>,
+) {
+    let mut feed: Option<SessionChangeFeed> = None;
+    let mut interval = tokio::time::interval(SESSION_EVENT_POLL_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        tokio::select! {
+            Some(ack_tx) = feed_start_rx.recv() => {
+                let start_result = if feed.is_some() {
+                    Ok(())
+                } else {
+                    SessionChangeFeed::new(Arc::clone(&session_repository))
+                        .await
+                        .map(|started_feed| {
+                            feed = Some(started_feed);
+                            interval.reset();
+                        })
+                        .map_err(|error| error.to_string())
+                };
+                // Fire-and-forget: the subscribing client may have left.
+                let _ = ack_tx.send(start_result);
+            }
+            _ = interval.tick(), if feed.is_some() => {
+                if event_tx.receiver_count() == 0 {
+                    feed = None;
+
+                    continue;
+                }
+                let Some(active_feed) = feed.as_mut() else {
+                    continue;
+                };
+                match active_feed.poll().await {
+                    Ok(events) => {
+                        for event in events {
+                            // Fire-and-forget: subscribers may leave between polls.
+                            let _ = event_tx.send(event);
+                        }
+                    }
+                    Err(error) => warn!(%error, "control server failed to poll session changes"),
+                }
+            }
+            else => break,
+        }
+    }
+}
+
+/// Reply channel used to confirm that the change feed has a baseline.
+type FeedStartAck = oneshot::Sender<Result<(), String>>;
+
+/// Shared request-handling state for every client connection.
+struct ControlContext {
+    default_project_id: i64,
+    event_tx: broadcast::Sender<SessionChangeEvent>,
+    feed_start_tx: mpsc::UnboundedSender<FeedStartAck>,
+    session_service: SessionService,
+}
+
+/// Serves newline-delimited JSON-RPC messages for one client.
+async fn serve_connection(stream: UnixStream, context: Arc<ControlContext>) {
+    let (reader, mut writer) = stream.into_split();
+    let (outgoing_tx, mut outgoing_rx) = mpsc::unbounded_channel::<String>();
+    let writer_task = tokio::spawn(async move {
+        while let Some(mut line) = outgoing_rx.recv().await {
+            line.push('\n');
+            if writer.write_all(line.as_bytes()).await.is_err() {
+                break;
+            }
+        }
+    });
+    let mut connection = ControlConnection {
+        context,
+        outgoing_tx,
+        subscription: None,
+    };
+
+    let mut reader = BufReader::new(reader);
+    loop {
+        let line = match read_request_line(&mut reader).await {
+            Ok(Some(line)) => line,
+            Ok(None) => break,
+            Err(error) => {
+                if error.kind() == io::ErrorKind::InvalidData {
+                    let response = error_response(
+                        &Value::Null,
+                        &RpcError::new(INVALID_REQUEST, error.to_string()),
+                    );
+                    // Fire-and-forget: the connection closes right after.
+                    let _ = connection.outgoing_tx.send(response);
+                }
+
+                break;
+            }
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = connection.handle_line(&line).await
+            && connection.outgoing_tx.send(response).is_err()
+        {
+            break;
+        }
+    }
+
+    connection.unsubscribe();
+    drop(connection);
+    let _ = writer_task.await;
+}
+
+/// Reads one newline-delimited request of at most
+/// [`MAX_REQUEST_LINE_BYTES`].
+///
+/// Returns `Ok(None)` at end of stream.
+///
+/// # Errors
+/// Returns an [`io::ErrorKind::InvalidData`] error when the line is too long
+/// or not UTF-8, and propagates read failures.
+async fn read_request_line<R>(reader: &mut R) -> io::Result<Option<String>>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let read_limit = u64::try_from(MAX_REQUEST_LINE_BYTES)
+        .unwrap_or(u64::MAX)
+        .saturating_add(1);
+    let mut line = Vec::new();
+    if reader.take(read_limit).read_until(b'\n', &mut line).await? == 0 {
+        return Ok(None);
+    }
+
+    if line.last() == Some(&b'\n') {
+        line.pop();
+    } else if line.len() > MAX_REQUEST_LINE_BYTES {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Request exceeds {MAX_REQUEST_LINE_BYTES} bytes"),
+        ));
+    }
+
+    String::from_utf8(line)
+        .map(Some)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
+
+/// Per-client outgoing channel and active subscription.
+struct ControlConnection {
+    context: Arc<ControlContext>,
+    outgoing_tx: mpsc::UnboundedSender<String>,
+    subscription: Option<JoinHandle<()>>,
+}
+
+impl ControlConnection {
+    /// Handles one raw message and returns the serialized response, if the
+    /// message was a request rather than a notification.
+    async fn handle_line(&mut self, line: &str) -> Option<String> {
+        let value = match serde_json::from_str::<Value>(line) {
+            Ok(value) => value,
+            Err(error) => {
+                return Some(error_response(
+                    &Value::Null,
+                    &RpcError::new(PARSE_ERROR, format!("Parse error: {error}")),
+                ));
+            }
+        };
+        let request = match serde_json::from_value::<RpcRequest>(value) {
+            Ok(request) if request.jsonrpc == JSONRPC_VERSION => request,
+            Ok(request) => {
+                return Some(error_response(
+                    &request.id.unwrap_or(Value::Null),
+                    &RpcError::new(INVALID_REQUEST, "Invalid request: `jsonrpc` must be `2.0`"),
+                ));
+            }
+            Err(error) => {
+                return Some(error_response(
+                    &Value::Null,
+                    &RpcError::new(INVALID_REQUEST, format!("Invalid request: {error}")),
+                ));
+            }
+        };
+
+        let result = self.dispatch(&request.method, request.params).await;
+        let id = request.id?;
+
+        Some(match result {
+            Ok(result) => serialize_message(&json!({
+                "id": id,
+                "jsonrpc": JSONRPC_VERSION,
+                "result": result,
+            })),
+            Err(error) => error_response(&id, &error),
+        })
+    }
+
+    /// Runs one method and returns its JSON result.
+    async fn dispatch(&mut self, method: &str, params: Value) -> Result<Value, RpcError> {
+        let service = &self.context.session_service;
+
+        match method {
+            "session.create" => self.create_session(parse_params(params)?).await,
+            "session.get" => {
+                let params: SessionParams = parse_params(params)?;
+                let session = service
+                    .get_session(&SessionId::from(params.session_id))
+                    .await?
+                    .ok_or(SessionError::NotFound)?;
+
+                to_result(&SessionDetailView::new(&session, None))
+            }
+            "session.list" => {
+                let params: ListParams = parse_params(params)?;
+                let sessions = service
+                    .list_sessions(params.project_id.unwrap_or(self.context.default_project_id))
+                    .await?;
+
+                to_result(
+                    &sessions
+                        .iter()
+                        .map(SessionSummaryView::new)
+                        .collect::<Vec<_>>(),
+                )
+            }
+            "session.send_message" => {
+                let params: SendMessageParams = parse_params(params)?;
+                service
+                    .send_message(&SessionId::from(params.session_id), params.message)
+                    .await?;
+
+                Ok(Value::Null)
+            }
+            "session.answer_questions" => {
+                let params: AnswerQuestionsParams = parse_params(params)?;
+                let (session_id, request) = params.into_parts();
+                service.answer_questions(&session_id, request).await?;
+
+                Ok(Value::Null)
+            }
+            "session.cancel" => {
+                let params: SessionParams = parse_params(params)?;
+                service
+                    .cancel_session(&SessionId::from(params.session_id))
+                    .await?;
+
+                Ok(Value::Null)
+            }
+            "session.merge" => {
+                let params: SessionParams = parse_params(params)?;
+                service
+                    .merge_session(&SessionId::from(params.session_id))
+                    .await?;
+
+                Ok(Value::Null)
+            }
+            "session.create_review_request" => {
+                let params: SessionParams = parse_params(params)?;
+                let review_request = service
+                    .create_review_request(&SessionId::from(params.session_id))
+                    .await?;
+
+                to_result(&ReviewRequestView::new(&review_request))
+            }
+            "session.subscribe" => {
+                let params: SubscribeParams = parse_params(params)?;
+                self.subscribe(params.session_id).await?;
+
+                Ok(json!({ "subscribed": true }))
+            }
+            "session.unsubscribe" => {
+                self.unsubscribe();
+
+                Ok(json!({ "subscribed": false }))
+            }
+            _ => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Method not found: `{method}`"),
+            )),
+        }
+    }
+
+    /// Creates one session in the requested or launch-time project.
+    async fn create_session(&self, params: CreateParams) -> Result<Value, RpcError> {
+        let mode = match params.mode {
+            CreateModeParam::Regular => CreateSessionMode::Regular,
+            CreateModeParam::Draft => CreateSessionMode::Draft,
+        };
+        let session_id = self
+            .context
+            .session_service
+            .create_session(CreateSessionRequest {
+                inherit_from_session_id: params.inherit_from_session_id.map(SessionId::from),
+                mode,
+                project_id: params.project_id.unwrap_or(self.context.default_project_id),
+            })
+            .await?;
+
+        Ok(json!({ "session_id": session_id }))
+    }
+
+    /// Replaces the active subscription with one optionally filtered to a
+    /// single session.
+    ///
+    /// Returns once the change feed has a baseline, so every change persisted
+    /// after the response is delivered.
+    async fn subscribe(&mut self, session_id: Option<String>) -> Result<(), RpcError> {
+        self.unsubscribe();
+        let mut event_rx = self.context.event_tx.subscribe();
+        let (ack_tx, ack_rx) = oneshot::channel();
+        let feed_started = if self.context.feed_start_tx.send(ack_tx).is_ok() {
+            ack_rx
+                .await
+                .unwrap_or_else(|_| Err("Session change feed stopped".to_string()))
+        } else {
+            Err("Session change feed stopped".to_string())
+        };
+        feed_started.map_err(|message| {
+            RpcError::new(
+                SESSION_OPERATION_FAILED,
+                format!("Failed to start session change feed: {message}"),
+            )
+        })?;
+        let outgoing_tx = self.outgoing_tx.clone();
+
+        self.subscription = Some(tokio::spawn(async move {
+            loop {
+                let notification = match event_rx.recv().await {
+                    Ok(event) => {
+                        if session_id
+                            .as_deref()
+                            .is_some_and(|session_id| session_id != event.session_id())
+                        {
+                            continue;
+                        }
+
+                        change_notification(&event)
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        notification("session.events_lagged", &json!({ "skipped": skipped }))
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                if outgoing_tx.send(notification).is_err() {
+                    break;
+                }
+            }
+        }));
+
+        Ok(())
+    }
+
+    /// Stops forwarding change notifications to this client.
+    fn unsubscribe(&mut self) {
+        if let Some(subscription) = self.subscription.take() {
+            subscription.abort();
+        }
+    }
+}
+
+/// Serializes one session change as a JSON-RPC notification.
+fn change_notification(event: &SessionChangeEvent) -> String {
+    match event {
+        SessionChangeEvent::MessageAdded {
+            content,
+            kind,
+            position,
+            session_id,
+        } => notification(
+            "session.message_added",
+            &json!({
+                "content": content,
+                "kind": kind,
+                "position": position,
+                "session_id": session_id,
+            }),
+        ),
+        SessionChangeEvent::StatusChanged {
+            previous_status,
+            session_id,
+            status,
+        } => notification(
+            "session.status_changed",
+            &json!({
+                "previous_status": previous_status,
+                "session_id": session_id,
+                "status": status,
+            }),
+        ),
+    }
+}
+
+/// Serializes one server-to-client notification.
+fn notification(method: &str, params: &Value) -> String {
+    serialize_message(&json!({
+        "jsonrpc": JSONRPC_VERSION,
+        "method": method,
+        "params": params,
+    }))
+}
+
+/// Serializes one error response.
+fn error_response(id: &Value, error: &RpcError) -> String {
+    serialize_message(&json!({
+        "error": { "code": error.code, "message": error.message },
+        "id": id,
+        "jsonrpc": JSONRPC_VERSION,
+    }))
+}
+
+/// Serializes one already valid JSON value onto a single line.
+fn serialize_message(message: &Value) -> String {
+    message.to_string()
+}
+
+/// Decodes method parameters, treating omitted params as an empty object.
+fn parse_params<T: for<'de> Deserialize<'de>>(params: Value) -> Result<T, RpcError> {
+    let params = if params.is_null() { json!({}) } else { params };
+
+    serde_json::from_value(params)
+        .map_err(|error| RpcError::new(INVALID_PARAMS, format!("Invalid params: {error}")))
+}
+
+/// Encodes one method result.
+fn to_result(value: &impl Serialize) -> Result<Value, RpcError> {
+    serde_json::to_value(value)
+        .map_err(|error| RpcError::new(SESSION_OPERATION_FAILED, error.to_string()))
+}
+
+/// One incoming JSON-RPC request or notification.
+#[derive(Deserialize)]
+struct RpcRequest {
+    /// Request id; absent for notifications.
+    #[serde(default)]
+    id: Option<Value>,
+    jsonrpc: String,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// JSON-RPC error object returned to clients.
+#[derive(Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    /// Creates one error with a protocol or server-defined code.
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<SessionError> for RpcError {
+    fn from(error: SessionError) -> Self {
+        let code = match error {
+            SessionError::NotFound => SESSION_NOT_FOUND,
+            SessionError::InvalidData(_) | SessionError::Operation(_) => SESSION_OPERATION_FAILED,
+        };
+
+        Self::new(code, error.to_string())
+    }
+}
+
+/// Parameters naming one target session.
+#[derive(Deserialize)]
+struct SessionParams {
+    session_id: String,
+}
+
+/// Creation strategies available to control clients.
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum CreateModeParam {
+    /// Regular session with an eagerly materialized worktree.
+    #[default]
+    Regular,
+    /// Draft whose worktree is materialized on first send.
+    Draft,
+}
+
+/// Parameters for `session.create`.
+#[derive(Deserialize)]
+struct CreateParams {
+    #[serde(default)]
+    inherit_from_session_id: Option<String>,
+    #[serde(default)]
+    mode: CreateModeParam,
+    #[serde(default)]
+    project_id: Option<i64>,
+}
+
+/// Parameters for `session.list`.
+#[derive(Deserialize)]
+struct ListParams {
+    #[serde(default)]
+    project_id: Option<i64>,
+}
+
+/// Parameters for `session.send_message`.
+#[derive(Deserialize)]
+struct SendMessageParams {
+    message: String,
+    session_id: String,
+}
+
+/// One answer in `session.answer_questions`.
+#[derive(Deserialize)]
+struct AnswerParam {
+    answer: String,
+    question: String,
+}
+
+/// Parameters for `session.answer_questions`.
+#[derive(Deserialize)]
+struct AnswerQuestionsParams {
+    answers: Vec<AnswerParam>,
+    session_id: String,
+}
+
+impl AnswerQuestionsParams {
+    /// Splits wire parameters into the target session and API request.
+    fn into_parts(self) -> (SessionId, AnswerQuestionsRequest) {
+        let request = AnswerQuestionsRequest {
+            answers: self
+                .answers
+                .into_iter()
+                .map(|answer| QuestionAnswer {
+                    answer: answer.answer,
+                    question: answer.question,
+                })
+                .collect(),
+        };
+
+        (SessionId::from(self.session_id), request)
+    }
+}
+
+/// Parameters for `session.subscribe`.
+#[derive(Deserialize)]
+struct SubscribeParams {
+    #[serde(default)]
+    session_id: Option<String>,
+}
+
+/// Result projection for `session.create_review_request`.
+#[derive(Serialize)]
+struct ReviewRequestView {
+    display_id: String,
+    source_branch: String,
+    state: &'static str,
+    target_branch: String,
+    title: String,
+    url: String,
+}
+
+impl ReviewRequestView {
+    /// Projects the remote summary of one linked review request.
+    fn new(review_request: &ReviewRequest) -> Self {
+        let summary = &review_request.summary;
+
+        Self {
+            display_id: summary.display_id.clone(),
+            source_branch: summary.source_branch.clone(),
+            state: summary.state.as_str(),
+            target_branch: summary.target_branch.clone(),
+            title: summary.title.clone(),
+            url: summary.web_url.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use ag_session::{
+        CoordinatorMessageRequest, Session, SessionBackend, SessionError as BackendError,
+        SessionSummary,
+    };
+    use async_trait::async_trait;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+
+    use super::*;
+    use crate::infra::db::AppRepositories;
+
+    /// Session backend that records calls and rejects unknown sessions.
+    #[derive(Default)]
+    struct RecordingBackend {
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl RecordingBackend {
+        fn record(&self, call: String) {
+            self.calls.lock().expect("calls lock").push(call);
+        }
+    }
+
+    #[async_trait]
+    impl SessionBackend for RecordingBackend {
+        async fn create_session(
+            &self,
+            request: CreateSessionRequest,
+        ) -> Result<SessionId, BackendError> {
+            self.record(format!("create:{:?}:{}", request.mode, request.project_id));
+
+            Ok(SessionId::from("session-1"))
+        }
+
+        async fn get_session(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Option<Session>, BackendError> {
+            Ok(None)
+        }
+
+        async fn list_sessions(
+            &self,
+            _project_id: i64,
+        ) -> Result<Vec<SessionSummary>, BackendError> {
+            Ok(Vec::new())
+        }
+
+        async fn send_message(
+            &self,
+            session_id: &SessionId,
+            message: String,
+        ) -> Result<(), BackendError> {
+            self.record(format!("send:{session_id}:{message}"));
+
+            Ok(())
+        }
+
+        async fn submit_coordinator_message(
+            &self,
+            _session_id: &SessionId,
+            _request: CoordinatorMessageRequest,
+        ) -> Result<(), BackendError> {
+            Err(BackendError::Operation("unsupported".to_string()))
+        }
+
+        async fn answer_questions(
+            &self,
+            session_id: &SessionId,
+            request: AnswerQuestionsRequest,
+        ) -> Result<(), BackendError> {
+            self.record(format!("answer:{session_id}:{}", request.answers.len()));
+
+            Ok(())
+        }
+
+        async fn cancel_session(&self, _session_id: &SessionId) -> Result<(), BackendError> {
+            Err(BackendError::NotFound)
+        }
+
+        async fn merge_session(&self, session_id: &SessionId) -> Result<(), BackendError> {
+            self.record(format!("merge:{session_id}"));
+
+            Ok(())
+        }
+
+        async fn create_review_request(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<ReviewRequest, BackendError> {
+            Err(BackendError::Operation("no forge remote".to_string()))
+        }
+    }
+
+    /// Connected test client with line-oriented request helpers.
+    struct TestClient {
+        lines: tokio::io::Lines<BufReader<tokio::net::unix::OwnedReadHalf>>,
+        writer: tokio::net::unix::OwnedWriteHalf,
+    }
+
+    impl TestClient {
+        async fn connect(socket_path: &Path) -> Self {
+            let stream = UnixStream::connect(socket_path)
+                .await
+                .expect("client should connect");
+            let (reader, writer) = stream.into_split();
+
+            Self {
+                lines: BufReader::new(reader).lines(),
+                writer,
+            }
+        }
+
+        async fn send(&mut self, message: &str) {
+            self.writer
+                .write_all(format!("{message}\n").as_bytes())
+                .await
+                .expect("request should be written");
+        }
+
+        async fn receive(&mut self) -> Value {
+            let line = tokio::time::timeout(Duration::from_secs(5), self.lines.next_line())
+                .await
+                .expect("response should arrive")
+                .expect("response should be readable")
+                .expect("connection should stay open");
+
+            serde_json::from_str(&line).expect("response should be JSON")
+        }
+
+        async fn request(&mut self, message: &str) -> Value {
+            self.send(message).await;
+
+            self.receive().await
+        }
+    }
+
+    /// Starts a server over a recording backend and in-memory repositories.
+    async fn start_server() -> (
+        ControlServerTask,
+        Arc<RecordingBackend>,
+        AppRepositories,
+        tempfile::TempDir,
+    ) {
+        let temp_dir = tempfile::tempdir().expect("temp dir should be created");
+        let repositories = AppRepositories::in_memory()
+            .await
+            .expect("in-memory database should open");
+        let backend = Arc::new(RecordingBackend::default());
+        let server = ControlServerTask::start(
+            temp_dir.path().join(CONTROL_SOCKET_FILE),
+            SessionService::new(Arc::clone(&backend) as Arc<dyn SessionBackend>),
+            repositories.session_repository(),
+            7,
+        )
+        .await
+        .expect("server should start");
+
+        (server, backend, repositories, temp_dir)
+    }
+
+    #[tokio::test]
+    async fn server_dispatches_session_operations() {
+        // Arrange
+        let (server, backend, _repositories, temp_dir) = start_server().await;
+        let mut client = TestClient::connect(&temp_dir.path().join(CONTROL_SOCKET_FILE)).await;
+
+        // Act
+        let created = client
+            .request(
+                r#"{"jsonrpc":"2.0","id":1,"method":"session.create","params":{"mode":"draft"}}"#,
+            )
+            .await;
+        let sent = client
+            .request(r#"{"jsonrpc":"2.0","id":"send","method":"session.send_message","params":{"session_id":"session-1","message":"hi"}}"#)
+            .await;
+        let answered = client
+            .request(r#"{"jsonrpc":"2.0","id":3,"method":"session.answer_questions","params":{"session_id":"session-1","answers":[{"question":"Q?","answer":"A"}]}}"#)
+            .await;
+        client
+            .send(
+                r#"{"jsonrpc":"2.0","method":"session.merge","params":{"session_id":"session-1"}}"#,
+            )
+            .await;
+        let canceled = client
+            .request(r#"{"jsonrpc":"2.0","id":4,"method":"session.cancel","params":{"session_id":"missing"}}"#)
+            .await;
+        server.stop().await;
+
+        // Assert
+        assert_eq!(created["id"], 1);
+        assert_eq!(created["result"]["session_id"], "session-1");
+        assert_eq!(sent["id"], "send");
+        assert_eq!(sent["result"], Value::Null);
+        assert_eq!(answered["result"], Value::Null);
+        assert_eq!(canceled["error"]["code"], SESSION_NOT_FOUND);
+        assert_eq!(
+            *backend.calls.lock().expect("calls lock"),
+            [
+                "create:Draft:7",
+                "send:session-1:hi",
+                "answer:session-1:1",
+                "merge:session-1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn server_reports_protocol_errors() {
+        // Arrange
+        let (server, _backend, _repositories, temp_dir) = start_server().await;
+        let mut client = TestClient::connect(&temp_dir.path().join(CONTROL_SOCKET_FILE)).await;
+
+        // Act
+        let parse_error = client.request("{not json").await;
+        let invalid_version = client
+            .request(r#"{"jsonrpc":"1.0","id":1,"method":"session.list"}"#)
+            .await;
+        let unknown_method = client
+            .request(r#"{"jsonrpc":"2.0","id":2,"method":"session.explode"}"#)
+            .await;
+        let invalid_params = client
+            .request(r#"{"jsonrpc":"2.0","id":3,"method":"session.send_message","params":{}}"#)
+            .await;
+        let review_error = client
+            .request(r#"{"jsonrpc":"2.0","id":4,"method":"session.create_review_request","params":{"session_id":"session-1"}}"#)
+            .await;
+        server.stop().await;
+
+        // Assert
+        assert_eq!(parse_error["error"]["code"], PARSE_ERROR);
+        assert_eq!(parse_error["id"], Value::Null);
+        assert_eq!(invalid_version["error"]["code"], INVALID_REQUEST);
+        assert_eq!(invalid_version["id"], 1);
+        assert_eq!(unknown_method["error"]["code"], METHOD_NOT_FOUND);
+        assert_eq!(invalid_params["error"]["code"], INVALID_PARAMS);
+        assert_eq!(review_error["error"]["code"], SESSION_OPERATION_FAILED);
+        assert_eq!(review_error["error"]["message"], "no forge remote");
+    }
+
+    #[tokio::test]
+    async fn server_rejects_oversized_request_line_and_closes_connection() {
+        // Arrange
+        let (server, _backend, _repositories, temp_dir) = start_server().await;
+        let mut client = TestClient::connect(&temp_dir.path().join(CONTROL_SOCKET_FILE)).await;
+        let oversized_request = "x".repeat(MAX_REQUEST_LINE_BYTES + 1);
+
+        // Act
+        let rejected = client.request(&oversized_request).await;
+        let next_line = tokio::time::timeout(Duration::from_secs(5), client.lines.next_line())
+            .await
+            .expect("connection should close");
+        server.stop().await;
+
+        // Assert
+        assert_eq!(rejected["error"]["code"], INVALID_REQUEST);
+        assert_eq!(rejected["id"], Value::Null);
+        assert!(matches!(next_line, Ok(None) | Err(_)));
+    }
+
+    #[tokio::test]
+    async fn subscription_streams_filtered_status_and_message_changes() {
+        // Arrange
+        let (server, _backend, repositories, temp_dir) = start_server().await;
+        let project_id = repositories
+            .projects()
+            .upsert_project("/tmp/project", None)
+            .await
+            .expect("project should be inserted");
+        let mut client = TestClient::connect(&temp_dir.path().join(CONTROL_SOCKET_FILE)).await;
+        let subscribed = client
+            .request(r#"{"jsonrpc":"2.0","id":1,"method":"session.subscribe","params":{"session_id":"watched"}}"#)
+            .await;
+        let sessions = repositories.sessions();
+        sessions
+            .insert_session("ignored", "gpt-5.6-sol", "main", "Draft", project_id)
+            .await
+            .expect("session should be inserted");
+        sessions
+            .insert_session("watched", "gpt-5.6-sol", "main", "InProgress", project_id)
+            .await
+            .expect("session should be inserted");
+        sessions
+            .append_session_message(
+                "watched",
+                ag_session::SessionMessageKind::UserPrompt,
+                "build it",
+            )
+            .await
+            .expect("message should be appended");
+
+        // Act
+        let status_changed = client.receive().await;
+        let message_added = client.receive().await;
+        server.stop().await;
+
+        // Assert
+        assert_eq!(subscribed["result"]["subscribed"], true);
+        assert_eq!(status_changed["method"], "session.status_changed");
+        assert_eq!(status_changed["params"]["session_id"], "watched");
+        assert_eq!(status_changed["params"]["previous_status"], Value::Null);
+        assert_eq!(status_changed["params"]["status"], "InProgress");
+        assert_eq!(message_added["method"], "session.message_added");
+        assert_eq!(message_added["params"]["content"], "build it");
+        assert_eq!(message_added["params"]["kind"], "user_prompt");
+    }
+
+    #[tokio::test]
+    async fn start_rejects_socket_owned_by_a_live_server() {
+        // Arrange
+        let (server, _backend, repositories, temp_dir) = start_server().await;
+        let socket_path = temp_dir.path().join(CONTROL_SOCKET_FILE);
+
+        // Act
+        let error = ControlServerTask::start(
+            socket_path.clone(),
+            SessionService::new(Arc::new(RecordingBackend::default())),
+            repositories.session_repository(),
+            7,
+        )
+        .await
+        .err()
+        .expect("second server should be rejected");
+        server.stop().await;
+        let socket_exists_after_stop = socket_path.exists();
+
+        // Assert
+        assert_eq!(error.kind(), io::ErrorKind::AddrInUse);
+        assert!(!socket_exists_after_stop);
+    }
+
+    #[tokio::test]
+    async fn start_replaces_stale_socket_with_user_only_permissions() {
+        // Arrange
+        let temp_dir = tempfile::tempdir().expect("temp dir should be created");
+        let socket_path = temp_dir.path().join(CONTROL_SOCKET_FILE);
+        drop(std::os::unix::net::UnixListener::bind(&socket_path).expect("stale socket"));
+        let repositories = AppRepositories::in_memory()
+            .await
+            .expect("in-memory database should open");
+
+        // Act
+        let server = ControlServerTask::start(
+            socket_path.clone(),
+            SessionService::new(Arc::new(RecordingBackend::default())),
+            repositories.session_repository(),
+            7,
+        )
+        .await
+        .expect("stale socket should be replaced");
+        let mode = std::fs::metadata(&socket_path)
+            .expect("socket should exist")
+            .permissions()
+            .mode();
+        let directory_entries = std::fs::read_dir(temp_dir.path())
+            .expect("socket directory should be readable")
+            .map(|entry| entry.expect("entry should be readable").file_name())
+            .collect::<Vec<_>>();
+        server.stop().await;
+
+        // Assert
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(directory_entries, [CONTROL_SOCKET_FILE]);
+    }
+}
@@ -12,10 +12,16 @@ use async_trait::async_trait;
 use ratatui::Terminal;
 use ratatui::backend::{Backend, ClearType, CrosstermBackend};
 use tokio::sync::mpsc;
+#[cfg(unix)]
+use tracing::warn;
 
+#[cfg(unix)]
+use crate::app::agentty_home;
 use crate::app::{App, OrchestrationCoordinator, OrchestrationSchedule};
 use crate::infra::clock::Clock;
-use crate::runtime::{FRAME_INTERVAL, PresentationState, event, terminal};
+#[cfg(unix)]
+use crate::runtime::control_server::{CONTROL_SOCKET_FILE, ControlServerTask};
+use crate::runtime::{FRAME_INTERVAL, PresentationState, event, keymap, terminal};
 
 /// Fallback redraw cadence for visible spinner and timer UI when no new
 /// events arrive.
@@ -106,8 +112,10 @@ impl EventReaderTask {
 /// Runs the TUI event/render loop until the user exits.
 ///
 /// # Errors
-/// Returns an error if terminal setup, rendering, or event processing fails.
+/// Returns an error if `keymap.toml` is invalid, or if terminal setup,
+/// rendering, or event processing fails.
 pub async fn run(app: &mut App) -> io::Result<()> {
+    app.keymap = keymap::load_user_keymap()?;
     let terminal_guard = terminal::TerminalGuard::new();
     let mut terminal = terminal::setup_terminal(&terminal_guard)?;
 
@@ -119,7 +127,13 @@ pub async fn run(app: &mut App) -> io::Result<()> {
     let mut tick = tokio::time::interval(FRAME_INTERVAL);
     tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
+    #[cfg(unix)]
+    let control_server = start_control_server(app).await;
     let run_result = run_main_loop(app, &mut terminal, &mut event_rx, &mut tick).await;
+    #[cfg(unix)]
+    if let Some(control_server) = control_server {
+        control_server.stop().await;
+    }
     let reader_shutdown_result = event_reader_task.shutdown().await;
     app.wait_for_background_cleanup_tasks().await;
     let cursor_result = terminal.show_cursor().map_err(backend_err);
@@ -127,6 +141,25 @@ pub async fn run(app: &mut App) -> io::Result<()> {
     run_result.and(reader_shutdown_result).and(cursor_result)
 }
 
+/// Starts the local control server for the production terminal runtime.
+///
+/// Failures are logged and leave the terminal UI running without the socket.
+#[cfg(unix)]
+async fn start_control_server(app: &App) -> Option<ControlServerTask> {
+    let socket_path = agentty_home().join(CONTROL_SOCKET_FILE);
+    let control_server = ControlServerTask::start(
+        socket_path,
+        app.session_service(),
+        app.services.db().session_repository(),
+        app.active_project_id(),
+    )
+    .await;
+
+    control_server
+        .inspect_err(|error| warn!(%error, "failed to start control server"))
+        .ok()
+}
+
 /// Runs the TUI event/render loop with an externally provided backend and
 /// event channel.
 ///
@@ -15,6 +15,7 @@ use tracing::debug;
 use crate::app::{App, AppRuntimeEvent};
 use crate::domain::input::InputCommand;
 use crate::presentation::app_mode::AppMode;
+use crate::presentation::publish_form::PublishFormField;
 use crate::runtime::{EventResult, FRAME_INTERVAL, PresentationState, key_handler, mode};
 
 /// Maximum terminal input events processed in one foreground cycle.
@@ -330,10 +331,22 @@ async fn process_paste_event(app: &mut App, pasted_text: &str) {
 
     if let AppMode::PublishBranchInput {
         input,
-        locked_upstream_ref: None,
+        locked_upstream_ref,
+        review_request_form,
         ..
     } = &mut app.mode
     {
+        let focused_input = match review_request_form.as_deref_mut() {
+            Some(form) if form.focus != PublishFormField::Branch => form.focused_input_mut(),
+            _ => locked_upstream_ref.is_none().then_some(input),
+        };
+        if let Some(focused_input) = focused_input {
+            let text = mode::input_key::normalize_single_line_pasted_text(pasted_text);
+            focused_input.apply(InputCommand::InsertText(text));
+        }
+    }
+
+    if let AppMode::ReviewRequestImportInput { input } = &mut app.mode {
         let text = mode::input_key::normalize_single_line_pasted_text(pasted_text);
         input.apply(InputCommand::InsertText(text));
     }
@@ -362,8 +375,8 @@ mod tests {
     use crate::domain::session::{Session, SessionRole, SessionSize, SessionStats, Status};
     use crate::domain::transient_message::TransientMessageStore;
     use crate::presentation::app_mode::{
-        AppMode, ChatFocus, DiffFocus, DiffLineCommentAnchor, DiffLineCommentTarget,
-        DiffLineComments, DiffLineSide, DiffPreview,
+        AppMode, ChatFocus, DiffFocus, DiffLayoutMode, DiffLineCommentAnchor,
+        DiffLineCommentTarget, DiffLineComments, DiffLineSide, DiffPreview,
     };
     use crate::presentation::prompt::{
         PromptAttachmentState, PromptHistoryState, PromptSlashState,
@@ -693,6 +706,7 @@ mod tests {
             reasoning_level_override: None,
             published_upstream_ref: None,
             questions: Vec::new(),
+            review_checks: None,
             review_request: None,
             size: SessionSize::Xs,
             speed_mode: crate::domain::agent::SpeedMode::default(),
@@ -703,6 +717,9 @@ mod tests {
             transcript: None,
             updated_at: 0,
             transient_messages: TransientMessageStore::default(),
+            pending_tool_approval: None,
+            post_turn_checks: None,
+            activity_log_expanded: false,
         });
         app.mode = AppMode::Prompt {
             at_mention_state: None,
@@ -790,6 +807,7 @@ mod tests {
             diff: "diff --git a/src/main.rs b/src/main.rs\n+review();\n".to_string(),
             file_explorer_selected_index: 1,
             focus: DiffFocus::Content,
+            layout_mode: DiffLayoutMode::default(),
             line_comments,
             preview: DiffPreview::default(),
             review_comments: None,
@@ -843,6 +861,7 @@ mod tests {
                 scroll_offset: None,
                 session_id: "session-1".into(),
             },
+            review_request_form: None,
         };
 
         // Act
@@ -856,6 +875,25 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_process_paste_event_updates_review_request_import_input() {
+        // Arrange
+        let mut app = crate::test_support::new_test_app_without_retained_base_dir().await;
+        app.mode = AppMode::ReviewRequestImportInput {
+            input: InputState::default(),
+        };
+
+        // Act
+        process_paste_event(&mut app, "https://github.com/agentty-xyz/agentty/pull/42\n").await;
+
+        // Assert
+        assert!(matches!(
+            &app.mode,
+            AppMode::ReviewRequestImportInput { input }
+                if input.text() == "https://github.com/agentty-xyz/agentty/pull/42"
+        ));
+    }
+
     #[tokio::test]
     async fn test_process_paste_event_updates_launch_configuration_input() {
         // Arrange
@@ -0,0 +1,572 @@
+//! Headless session commands that drive the application without a terminal.
+//!
+//! Each command owns the same [`App`] and [`SessionService`] facade used by
+//! the terminal runtime, so agent turns and merges started from scripts run
+//! through the regular session workflows. Because the process hosts the agent
+//! runtime, commands that start background work keep driving the app until
+//! that work settles before printing their result.
+
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use ag_session::{
+    CreateSessionMode, CreateSessionRequest, Session, SessionId, SessionService, SessionStatus,
+    SessionSummary,
+};
+use clap::{Args, Subcommand};
+use serde::Serialize;
+
+use crate::app::{App, AppError, AppRuntimeEvent};
+use crate::runtime::session_json::{SessionDetailView, SessionSummaryView};
+
+/// Cadence used to re-read persisted session status while a command waits.
+const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Options shared by every headless `session` subcommand.
+#[derive(Debug, Args)]
+pub struct SessionArgs {
+    /// Prints machine-readable JSON instead of plain text.
+    #[arg(long, global = true)]
+    pub json: bool,
+    /// Project directory used instead of the current working directory.
+    #[arg(long, global = true, value_name = "PATH")]
+    pub project: Option<PathBuf>,
+    /// Selected session operation.
+    #[command(subcommand)]
+    pub command: SessionCommand,
+}
+
+/// Scriptable session operations available without the terminal UI.
+#[derive(Clone, Debug, Eq, PartialEq, Subcommand)]
+pub enum SessionCommand {
+    /// Lists sessions owned by the active project.
+    List,
+    /// Creates a session, optionally sending its first prompt.
+    New {
+        /// First prompt; the command waits until the turn settles.
+        #[arg(long)]
+        prompt: Option<String>,
+        /// Maximum number of seconds to wait for the first turn.
+        #[arg(long, requires = "prompt", value_name = "SECONDS")]
+        timeout: Option<u64>,
+    },
+    /// Sends one message and waits until the resulting turn settles.
+    Send {
+        /// Target session identifier.
+        session_id: String,
+        /// Message delivered to the session agent.
+        message: String,
+        /// Maximum number of seconds to wait for the turn.
+        #[arg(long, value_name = "SECONDS")]
+        timeout: Option<u64>,
+    },
+    /// Prints the current status, transcript, and diff of one session.
+    Status {
+        /// Target session identifier.
+        session_id: String,
+    },
+    /// Waits until one session is no longer running background work.
+    Wait {
+        /// Target session identifier.
+        session_id: String,
+        /// Maximum number of seconds to wait.
+        #[arg(long, value_name = "SECONDS")]
+        timeout: Option<u64>,
+    },
+    /// Merges one review-ready session and waits for the merge to finish.
+    Merge {
+        /// Target session identifier.
+        session_id: String,
+        /// Maximum number of seconds to wait for the merge.
+        #[arg(long, value_name = "SECONDS")]
+        timeout: Option<u64>,
+    },
+}
+
+/// Runs one headless session command and writes its result to `output`.
+///
+/// # Errors
+/// Returns an error when the session workflow rejects the command, a wait
+/// times out, a merge does not complete, or output cannot be written.
+pub async fn run_session_command(
+    app: &mut App,
+    args: SessionArgs,
+    output: &mut dyn Write,
+) -> Result<(), AppError> {
+    let _session_runtime_consumer = app.sessions.foreground_consumer();
+    let service = app.session_service();
+    let result = HeadlessSession {
+        app: &mut *app,
+        json: args.json,
+        service,
+    }
+    .run(args.command, output)
+    .await;
+    app.wait_for_background_cleanup_tasks().await;
+
+    result
+}
+
+/// Borrowed application state for one headless command.
+struct HeadlessSession<'a> {
+    app: &'a mut App,
+    json: bool,
+    service: SessionService,
+}
+
+impl HeadlessSession<'_> {
+    /// Dispatches one command to its workflow.
+    async fn run(
+        &mut self,
+        command: SessionCommand,
+        output: &mut dyn Write,
+    ) -> Result<(), AppError> {
+        match command {
+            SessionCommand::List => {
+                let project_id = self.app.active_project_id();
+                let service = self.service.clone();
+                let sessions = self
+                    .app
+                    .drive_session_request(async move { service.list_sessions(project_id).await })
+                    .await
+                    .map_err(|error| api_error(&error))?;
+
+                self.write_session_list(&sessions, output)
+            }
+            SessionCommand::New { prompt, timeout } => {
+                let request = CreateSessionRequest {
+                    inherit_from_session_id: None,
+                    mode: CreateSessionMode::Regular,
+                    project_id: self.app.active_project_id(),
+                };
+                let service = self.service.clone();
+                let session_id = self
+                    .app
+                    .drive_session_request(async move { service.create_session(request).await })
+                    .await
+                    .map_err(|error| api_error(&error))?;
+                if let Some(prompt) = prompt {
+                    self.send_message(&session_id, prompt).await?;
+                    self.wait_until_idle(&session_id, timeout).await?;
+                }
+
+                self.write_session_detail(&session_id, output).await
+            }
+            SessionCommand::Send {
+                session_id,
+                message,
+                timeout,
+            } => {
+                let session_id = SessionId::from(session_id);
+                self.send_message(&session_id, message).await?;
+                self.wait_until_idle(&session_id, timeout).await?;
+
+                self.write_session_detail(&session_id, output).await
+            }
+            SessionCommand::Status { session_id } => {
+                self.write_session_detail(&SessionId::from(session_id), output)
+                    .await
+            }
+            SessionCommand::Wait {
+                session_id,
+                timeout,
+            } => {
+                let session_id = SessionId::from(session_id);
+                self.wait_until_idle(&session_id, timeout).await?;
+
+                self.write_session_detail(&session_id, output).await
+            }
+            SessionCommand::Merge {
+                session_id,
+                timeout,
+            } => {
+                let session_id = SessionId::from(session_id);
+                let service = self.service.clone();
+                let merge_session_id = session_id.clone();
+                self.app
+                    .drive_session_request(
+                        async move { service.merge_session(&merge_session_id).await },
+                    )
+                    .await
+                    .map_err(|error| api_error(&error))?;
+                let session = self.wait_until_idle(&session_id, timeout).await?;
+                self.write_session_detail(&session_id, output).await?;
+                if session.status != SessionStatus::Done {
+                    return Err(AppError::Workflow(format!(
+                        "Merge of session `{session_id}` did not complete; status is `{}`",
+                        session.status
+                    )));
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Sends one message through the programmatic session facade.
+    async fn send_message(
+        &mut self,
+        session_id: &SessionId,
+        message: String,
+    ) -> Result<(), AppError> {
+        let service = self.service.clone();
+        let session_id = session_id.clone();
+
+        self.app
+            .drive_session_request(async move { service.send_message(&session_id, message).await })
+            .await
+            .map_err(|error| api_error(&error))
+    }
+
+    /// Loads one complete session aggregate or reports it as missing.
+    async fn load_session(&mut self, session_id: &SessionId) -> Result<Session, AppError> {
+        let service = self.service.clone();
+        let lookup_session_id = session_id.clone();
+
+        self.app
+            .drive_session_request(async move { service.get_session(&lookup_session_id).await })
+            .await
+            .map_err(|error| api_error(&error))?
+            .ok_or_else(|| AppError::Workflow(format!("Session `{session_id}` not found")))
+    }
+
+    /// Drives the app until the session stops running background work.
+    ///
+    /// # Errors
+    /// Returns an error when the session cannot be loaded or `timeout_seconds`
+    /// elapses first.
+    async fn wait_until_idle(
+        &mut self,
+        session_id: &SessionId,
+        timeout_seconds: Option<u64>,
+    ) -> Result<Session, AppError> {
+        let wait = async {
+            loop {
+                let session = self.load_session(session_id).await?;
+                if !session.status.is_busy() {
+                    return Ok(session);
+                }
+
+                drive_runtime_for(self.app, STATUS_POLL_INTERVAL).await;
+            }
+        };
+
+        match timeout_seconds {
+            Some(timeout_seconds) => {
+                tokio::time::timeout(Duration::from_secs(timeout_seconds), wait)
+                    .await
+                    .map_err(|_| {
+                        AppError::Workflow(format!(
+                            "Timed out after {timeout_seconds}s waiting for session `{session_id}`"
+                        ))
+                    })?
+            }
+            None => wait.await,
+        }
+    }
+
+    /// Writes one session with its transcript and current diff.
+    async fn write_session_detail(
+        &mut self,
+        session_id: &SessionId,
+        output: &mut dyn Write,
+    ) -> Result<(), AppError> {
+        let session = self.load_session(session_id).await?;
+        let diff = self.app.load_session_diff(session_id.as_str()).await?;
+        let view = SessionDetailView::new(&session, diff);
+        if self.json {
+            return write_json(output, &view);
+        }
+
+        write_text(output, &view.to_text())
+    }
+
+    /// Writes one summary row per session.
+    fn write_session_list(
+        &self,
+        sessions: &[SessionSummary],
+        output: &mut dyn Write,
+    ) -> Result<(), AppError> {
+        let views = sessions
+            .iter()
+            .map(SessionSummaryView::new)
+            .collect::<Vec<_>>();
+        if self.json {
+            return write_json(output, &views);
+        }
+
+        let text = views
+            .iter()
+            .map(SessionSummaryView::to_text)
+            .collect::<String>();
+
+        write_text(output, &text)
+    }
+}
+
+/// Applies runtime events for `duration`, then refreshes persisted sessions.
+///
+/// Each selected event is applied to completion; only the wait between
+/// events is bounded.
+async fn drive_runtime_for(app: &mut App, duration: Duration) {
+    let deadline = tokio::time::sleep(duration);
+    tokio::pin!(deadline);
+
+    loop {
+        tokio::select! {
+            biased;
+            () = &mut deadline => break,
+            runtime_event = app.next_runtime_event() => match runtime_event {
+                AppRuntimeEvent::App(event) => app.apply_app_events(*event).await,
+                AppRuntimeEvent::Session(command) => {
+                    app.apply_session_runtime_command(command).await;
+                }
+            },
+        }
+    }
+
+    app.refresh_sessions_if_needed().await;
+}
+
+/// Converts a programmatic session error into the app error surface.
+fn api_error(error: &ag_session::SessionError) -> AppError {
+    AppError::Workflow(error.to_string())
+}
+
+/// Serializes one value as pretty JSON followed by a newline.
+fn write_json(output: &mut dyn Write, value: &impl Serialize) -> Result<(), AppError> {
+    let json = serde_json::to_string_pretty(value)
+        .map_err(|error| AppError::Workflow(format!("Failed to encode JSON output: {error}")))?;
+
+    write_text(output, &format!("{json}\n"))
+}
+
+/// Writes already formatted text to the command output.
+fn write_text(output: &mut dyn Write, text: &str) -> Result<(), AppError> {
+    output
+        .write_all(text.as_bytes())
+        .and_then(|()| output.flush())
+        .map_err(|error| output_error(&error))
+}
+
+/// Wraps output failures, such as a closed pipe, with context.
+fn output_error(error: &io::Error) -> AppError {
+    AppError::Workflow(format!("Failed to write command output: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use ag_agent::{
+        AgentKind, AgentModel, AgentSelection, AppServerTurnResponse, MockAppServerClient,
+    };
+    use clap::Parser;
+
+    use super::*;
+
+    /// Minimal parser wrapper used to exercise subcommand parsing.
+    #[derive(Debug, Parser)]
+    struct TestCli {
+        #[command(flatten)]
+        session: SessionArgs,
+    }
+
+    /// Builds an app whose agent turns answer through a mocked app server.
+    async fn app_with_answering_agent() -> (App, tempfile::TempDir) {
+        let mut app_server = MockAppServerClient::new();
+        app_server.expect_run_turn().returning(|_, _| {
+            Box::pin(async {
+                Ok(AppServerTurnResponse {
+                    assistant_message: r#"{"answer":"done","questions":[],"summary":null}"#
+                        .to_string(),
+                    context_reset: false,
+                    input_tokens: 0,
+                    output_tokens: 0,
+                    pid: None,
+                    provider_conversation_id: None,
+                })
+            })
+        });
+        app_server
+            .expect_shutdown_session()
+            .times(0..)
+            .returning(|_| Box::pin(async {}));
+        let clients = crate::test_support::test_app_clients()
+            .with_app_server_client_override(Arc::new(app_server));
+
+        crate::test_support::new_git_test_app_with_clients(clients).await
+    }
+
+    #[test]
+    fn session_args_parse_global_json_flag_after_subcommand() {
+        // Arrange / Act
+        let cli = TestCli::try_parse_from(["agentty", "send", "session-1", "fix it", "--json"])
+            .expect("send arguments should parse");
+
+        // Assert
+        assert!(cli.session.json);
+        assert_eq!(
+            cli.session.command,
+            SessionCommand::Send {
+                message: "fix it".to_string(),
+                session_id: "session-1".to_string(),
+                timeout: None,
+            }
+        );
+    }
+
+    #[test]
+    fn session_args_reject_new_timeout_without_prompt() {
+        // Arrange / Act
+        let error = TestCli::try_parse_from(["agentty", "new", "--timeout", "30"])
+            .expect_err("timeout without a prompt should be rejected");
+
+        // Assert
+        assert_eq!(
+            error.kind(),
+            clap::error::ErrorKind::MissingRequiredArgument
+        );
+    }
+
+    #[tokio::test]
+    async fn send_waits_for_review_and_prints_json_transcript() {
+        // Arrange
+        let (mut app, _temp_dir) = app_with_answering_agent().await;
+        let mut created_output = Vec::new();
+        run_session_command(
+            &mut app,
+            SessionArgs {
+                command: SessionCommand::New {
+                    prompt: None,
+                    timeout: None,
+                },
+                json: true,
+                project: None,
+            },
+            &mut created_output,
+        )
+        .await
+        .expect("session should be created");
+        let created: serde_json::Value =
+            serde_json::from_slice(&created_output).expect("output should be JSON");
+        let session_id = created["id"]
+            .as_str()
+            .expect("id should be a string")
+            .to_string();
+        app.set_session_model(
+            &session_id,
+            AgentSelection::new(AgentKind::Codex, AgentModel::Gpt56Sol),
+        )
+        .await
+        .expect("session model should update");
+        let mut output = Vec::new();
+
+        // Act
+        run_session_command(
+            &mut app,
+            SessionArgs {
+                command: SessionCommand::Send {
+                    message: "add a readme".to_string(),
+                    session_id,
+                    timeout: Some(10),
+                },
+                json: true,
+                project: None,
+            },
+            &mut output,
+        )
+        .await
+        .expect("headless turn should complete");
+        let view: serde_json::Value =
+            serde_json::from_slice(&output).expect("output should be JSON");
+
+        // Assert
+        assert_eq!(view["status"], "Review");
+        assert_eq!(view["messages"][0]["kind"], "user_prompt");
+        assert_eq!(view["messages"][0]["content"], "add a readme");
+        assert!(
+            view["messages"]
+                .as_array()
+                .expect("messages should be an array")
+                .iter()
+                .any(
+                    |message| message["kind"] == "assistant_answer" && message["content"] == "done"
+                )
+        );
+    }
+
+    #[tokio::test]
+    async fn list_prints_one_text_row_per_project_session() {
+        // Arrange
+        let (mut app, _temp_dir) = crate::test_support::new_git_test_app().await;
+        let mut created_output = Vec::new();
+        run_session_command(
+            &mut app,
+            SessionArgs {
+                command: SessionCommand::New {
+                    prompt: None,
+                    timeout: None,
+                },
+                json: true,
+                project: None,
+            },
+            &mut created_output,
+        )
+        .await
+        .expect("session should be created");
+        let created: serde_json::Value =
+            serde_json::from_slice(&created_output).expect("output should be JSON");
+        let mut output = Vec::new();
+
+        // Act
+        run_session_command(
+            &mut app,
+            SessionArgs {
+                command: SessionCommand::List,
+                json: false,
+                project: None,
+            },
+            &mut output,
+        )
+        .await
+        .expect("sessions should list");
+        let text = String::from_utf8(output).expect("output should be UTF-8");
+
+        // Assert
+        assert_eq!(
+            text,
+            format!(
+                "{}\tDraft\t\n",
+                created["id"].as_str().expect("id should be a string")
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn status_reports_missing_sessions() {
+        // Arrange
+        let (mut app, _temp_dir) = crate::test_support::new_git_test_app().await;
+        let mut output = Vec::new();
+
+        // Act
+        let error = run_session_command(
+            &mut app,
+            SessionArgs {
+                command: SessionCommand::Status {
+                    session_id: "missing".to_string(),
+                },
+                json: false,
+                project: None,
+            },
+            &mut output,
+        )
+        .await
+        .expect_err("missing session should fail");
+
+        // Assert
+        assert_eq!(error.to_string(), "Session `missing` not found");
+        assert_eq!(output, Vec::<u8>::new());
+    }
+}
@@ -10,14 +10,17 @@ use tracing::warn;
 use crate::app::App;
 #[cfg(test)]
 use crate::app::ReviewCacheEntry;
+use crate::domain::input::InputState;
 use crate::domain::orchestration::IntegrationApproach;
 use crate::domain::session::SessionId;
 use crate::domain::transcript_notice::TranscriptNotice;
 use crate::presentation::app_mode::{
     AppMode, ConfirmationIntent, ConfirmationViewMode, DiffSidebarFocus,
 };
+use crate::presentation::keymap::DiffAction;
+use crate::presentation::publish_form::{PublishFormField, ReviewRequestPublishForm};
 use crate::runtime::mode::confirmation::ConfirmationDecision;
-use crate::runtime::{EventResult, PresentationState, backend_err, mode};
+use crate::runtime::{EventResult, PresentationState, backend_err, keymap, mode};
 
 /// Routes key events to the active mode handler and returns the next runtime
 /// action.
@@ -49,12 +52,21 @@ where
         handle_launch_configuration_selector_key(app, key).await
     } else if matches!(app.mode, AppMode::PublishBranchInput { .. }) {
         Ok(handle_publish_branch_input_key(app, key).await)
-    } else {
+    } else if matches!(app.mode, AppMode::ReviewRequestImportInput { .. }) {
+        Ok(handle_review_request_import_key(app, key).await)
+    } else if let Some(event_result) = mode::tool_approval::pending_session_id(app)
+        .and_then(|session_id| mode::tool_approval::handle(app, &session_id, key))
+    {
+        Ok(event_result)
+    } else if !keymap::is_released_key(app, key) {
         match &app.mode {
             AppMode::List => mode::list::handle(app, key).await,
             AppMode::SessionCreation { .. } => {
                 unreachable!("session creation mode is handled before dispatch matching")
             }
+            AppMode::ReviewRequestImportInput { .. } => {
+                unreachable!("review-request import input mode is handled before dispatch matching")
+            }
             AppMode::PreCommitHookWarning { .. } => {
                 Ok(handle_pre_commit_hook_warning_key(app, key))
             }
@@ -90,29 +102,18 @@ where
                 review_comments: Some(review_comments),
                 ..
             } if review_comments.sidebar_focus == DiffSidebarFocus::Comments
-                && !matches!(key.code, KeyCode::Char('?' | 'q')) =>
+                && !matches!(
+                    keymap::resolve_action(app, key),
+                    Some(DiffAction::Help | DiffAction::Back)
+                ) =>
             {
                 handle_review_comment_key(app, presentation, terminal, key).await
             }
-            AppMode::Diff { .. } => {
-                let size = terminal.size().map_err(backend_err)?;
-                let terminal_rect = Rect::new(0, 0, size.width, size.height);
-                let content_area = content_area_for_terminal(terminal_rect);
-                let submit_line_comments = mode::diff::should_submit_line_comments(app, key);
-
-                let result = mode::diff::handle_with_cache(
-                    app,
-                    presentation.render_cache_store(),
-                    content_area,
-                    key,
-                );
-                if submit_line_comments && matches!(app.mode, AppMode::Prompt { .. }) {
-                    mode::prompt::submit_current_text_prompt(app).await;
-                }
-
-                Ok(result)
-            }
+            AppMode::Diff { .. } => handle_diff_key(app, presentation, terminal, key).await,
             AppMode::Help { .. } => Ok(mode::help::handle(app, key)),
+            AppMode::OrchestrationBoard { .. } => {
+                Ok(mode::orchestration_board::handle(app, key).await)
+            }
             AppMode::LaunchConfigurationSelector { .. } => {
                 unreachable!(
                     "launch-configuration selector mode is handled before dispatch matching"
@@ -122,6 +123,8 @@ where
                 unreachable!("publish-branch input mode is handled before dispatch matching")
             }
         }
+    } else {
+        Ok(EventResult::Continue)
     };
 
     if result.is_ok() {
@@ -154,6 +157,45 @@ where
     .await)
 }
 
+/// Routes one diff-mode key through revert shortcuts before regular diff
+/// navigation and inline-comment submission.
+async fn handle_diff_key<B: Backend>(
+    app: &mut App,
+    presentation: &PresentationState,
+    terminal: &mut Terminal<B>,
+    key: KeyEvent,
+) -> io::Result<EventResult>
+where
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
+    match mode::diff::handle_revert_key(app, presentation.render_cache_store(), key) {
+        mode::diff::DiffRevertKeyOutcome::Unhandled => {}
+        mode::diff::DiffRevertKeyOutcome::Handled => return Ok(EventResult::Continue),
+        mode::diff::DiffRevertKeyOutcome::Confirmed {
+            revert_patch,
+            session_id,
+        } => {
+            app.revert_diff_changes(&session_id, revert_patch.patch, revert_patch.summary)
+                .await;
+
+            return Ok(EventResult::Continue);
+        }
+    }
+
+    let size = terminal.size().map_err(backend_err)?;
+    let terminal_rect = Rect::new(0, 0, size.width, size.height);
+    let content_area = content_area_for_terminal(terminal_rect);
+    let submit_line_comments = mode::diff::should_submit_line_comments(app, key);
+
+    let result =
+        mode::diff::handle_with_cache(app, presentation.render_cache_store(), content_area, key);
+    if submit_line_comments && matches!(app.mode, AppMode::Prompt { .. }) {
+        mode::prompt::submit_current_text_prompt(app).await;
+    }
+
+    Ok(result)
+}
+
 /// Resolves the page content area and routes one review-comment key event.
 async fn handle_review_comment_key<B: Backend>(
     app: &mut App,
@@ -221,9 +263,9 @@ async fn handle_session_creation_key(app: &mut App, key: KeyEvent) -> io::Result
 /// Updates the highlighted option in the session creation selector.
 fn update_session_creation_selection(app: &mut App, selected_option_index: usize) {
     let max_option_index = if selected_stacked_parent_session_id(app).is_some() {
-        3
+        4
     } else {
-        2
+        3
     };
 
     if let AppMode::SessionCreation {
@@ -242,6 +284,13 @@ async fn create_selected_session(app: &mut App) -> io::Result<()> {
         1 => CreateSessionMode::Draft,
         2 => CreateSessionMode::Orchestrator,
         3 => {
+            app.mode = AppMode::ReviewRequestImportInput {
+                input: InputState::default(),
+            };
+
+            return Ok(());
+        }
+        4 => {
             let Some(parent_session_id) = selected_stacked_parent_session_id(app) else {
                 return Ok(());
             };
@@ -266,6 +315,66 @@ async fn create_selected_session(app: &mut App) -> io::Result<()> {
     Ok(())
 }
 
+/// Handles key input while the review-request import popup is visible.
+///
+/// `Enter` imports the typed reference into a new session and opens it;
+/// import failures are shown in the blocking info popup.
+async fn handle_review_request_import_key(app: &mut App, key: KeyEvent) -> EventResult {
+    let AppMode::ReviewRequestImportInput { mut input } =
+        std::mem::replace(&mut app.mode, AppMode::List)
+    else {
+        return EventResult::Continue;
+    };
+
+    match key.code {
+        KeyCode::Esc => {}
+        KeyCode::Enter => {
+            let reference = input.text().trim().to_string();
+            if reference.is_empty() {
+                app.mode = AppMode::ReviewRequestImportInput { input };
+
+                return EventResult::Continue;
+            }
+
+            let project_id = app.active_project_id();
+            let service = app.session_service();
+            let request = service.create_session(CreateSessionRequest {
+                inherit_from_session_id: None,
+                mode: CreateSessionMode::ReviewRequestImport { reference },
+                project_id,
+            });
+            match app.drive_session_request(request).await {
+                Ok(session_id) => {
+                    app.mode = AppMode::View {
+                        session_id,
+                        scroll_offset: None,
+                    };
+                }
+                Err(error) => {
+                    app.mode = AppMode::SyncBlockedPopup {
+                        default_branch: None,
+                        is_loading: false,
+                        message: error.to_string(),
+                        project_name: None,
+                        title: "Review request import failed".to_string(),
+                    };
+                }
+            }
+        }
+        _ => {
+            if let Some(command) = mode::input_key::command_for_key(
+                key,
+                mode::input_key::InputCapabilities::SINGLE_LINE,
+            ) {
+                input.apply(command);
+            }
+            app.mode = AppMode::ReviewRequestImportInput { input };
+        }
+    }
+
+    EventResult::Continue
+}
+
 /// Handles the advisory shown before session-type selection.
 fn handle_pre_commit_hook_warning_key(app: &mut App, key: KeyEvent) -> EventResult {
     match key.code {
@@ -427,24 +536,31 @@ fn handle_view_info_popup_key(app: &mut App, key: KeyEvent) -> EventResult {
 /// Handles key input while the publish-branch input overlay is visible.
 ///
 /// Only `Esc` cancels the overlay. Plain character keys continue to edit the
-/// branch name so session-view shortcuts like `q` and `p` do not leak through
-/// while the text field has focus.
+/// focused field so session-view shortcuts like `q` and `p` do not leak
+/// through while the text field has focus. When the overlay also creates a
+/// review request, `Up`/`Down` move between rows, `Space` toggles the draft
+/// row, and `Tab` completes forge choices before falling back to moving focus.
 async fn handle_publish_branch_input_key(app: &mut App, key: KeyEvent) -> EventResult {
-    let publish_branch_input =
+    let mut publish_branch_input =
         PublishBranchInputModeState::from_mode(std::mem::replace(&mut app.mode, AppMode::List));
-    let input_locked = publish_branch_input.locked_upstream_ref.is_some();
 
     match key.code {
         KeyCode::Esc => {
             app.mode = publish_branch_input.restore_view.into_view_mode();
         }
         KeyCode::Enter => {
+            let input_locked = publish_branch_input.locked_upstream_ref.is_some();
             let remote_branch_name = if input_locked {
                 Some(publish_branch_input.input.text().trim().to_string())
             } else {
                 (!publish_branch_input.input.text().trim().is_empty())
                     .then(|| publish_branch_input.input.text().trim().to_string())
             };
+            let review_request_options = publish_branch_input
+                .review_request_form
+                .as_ref()
+                .map(|form| form.create_options())
+                .unwrap_or_default();
             let session_id = publish_branch_input.restore_view.session_id.clone();
 
             app.start_publish_branch_action(
@@ -452,37 +568,53 @@ async fn handle_publish_branch_input_key(app: &mut App, key: KeyEvent) -> EventR
                 &session_id,
                 publish_branch_input.publish_branch_action,
                 remote_branch_name,
+                review_request_options,
             )
             .await;
         }
-        _ if !input_locked => {
-            app.mode = if let Some(command) = mode::input_key::command_for_key(
-                key,
-                mode::input_key::InputCapabilities::SINGLE_LINE,
-            ) {
-                publish_branch_input.apply_input_edit(|input| {
-                    input.apply(command);
-                })
-            } else {
-                publish_branch_input.into_mode()
-            };
-        }
         _ => {
-            app.mode = publish_branch_input.into_mode();
+            if let Some(form) = publish_branch_input.review_request_form.as_deref_mut()
+                && handle_review_request_form_key(form, key)
+            {
+                app.mode = publish_branch_input.into_mode();
+
+                return EventResult::Continue;
+            }
+
+            app.mode = publish_branch_input.apply_key_edit(key);
         }
     }
 
     EventResult::Continue
 }
 
+/// Applies review-request form navigation keys, returning whether `key` was
+/// consumed by the form rather than the focused text input.
+fn handle_review_request_form_key(form: &mut ReviewRequestPublishForm, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Up | KeyCode::BackTab => form.focus_previous(),
+        KeyCode::Down => form.focus_next(),
+        KeyCode::Tab => {
+            if !form.complete_focused_field() {
+                form.focus_next();
+            }
+        }
+        KeyCode::Char(' ') if form.focus == PublishFormField::Draft => form.toggle_draft(),
+        _ => return form.focus == PublishFormField::Draft,
+    }
+
+    true
+}
+
 /// Captures `AppMode::PublishBranchInput` fields so key handlers can rebuild
 /// the overlay consistently after input edits.
 struct PublishBranchInputModeState {
     default_branch_name: String,
-    input: crate::domain::input::InputState,
+    input: InputState,
     locked_upstream_ref: Option<String>,
     publish_branch_action: crate::domain::session::PublishBranchAction,
     restore_view: ConfirmationViewMode,
+    review_request_form: Option<Box<ReviewRequestPublishForm>>,
 }
 
 impl PublishBranchInputModeState {
@@ -494,6 +626,7 @@ impl PublishBranchInputModeState {
             locked_upstream_ref,
             publish_branch_action,
             restore_view,
+            review_request_form,
         } = mode
         else {
             unreachable!("mode must be publish-branch input in this handler");
@@ -505,15 +638,30 @@ impl PublishBranchInputModeState {
             locked_upstream_ref,
             publish_branch_action,
             restore_view,
+            review_request_form,
         }
     }
 
-    /// Applies one input edit and rebuilds the publish-branch overlay mode.
-    fn apply_input_edit(
-        mut self,
-        edit: impl FnOnce(&mut crate::domain::input::InputState),
-    ) -> AppMode {
-        edit(&mut self.input);
+    /// Applies one text-editing key to the focused field and rebuilds the
+    /// publish-branch overlay mode.
+    ///
+    /// A locked branch input ignores edits while its upstream is fixed.
+    fn apply_key_edit(mut self, key: KeyEvent) -> AppMode {
+        let Some(command) =
+            mode::input_key::command_for_key(key, mode::input_key::InputCapabilities::SINGLE_LINE)
+        else {
+            return self.into_mode();
+        };
+
+        if let Some(input) = self
+            .review_request_form
+            .as_deref_mut()
+            .and_then(ReviewRequestPublishForm::focused_input_mut)
+        {
+            input.apply(command);
+        } else if self.locked_upstream_ref.is_none() {
+            self.input.apply(command);
+        }
 
         self.into_mode()
     }
@@ -526,6 +674,7 @@ impl PublishBranchInputModeState {
             locked_upstream_ref: self.locked_upstream_ref,
             publish_branch_action: self.publish_branch_action,
             restore_view: self.restore_view,
+            review_request_form: self.review_request_form,
         }
     }
 }
@@ -933,9 +1082,9 @@ mod tests {
     use crate::domain::session_message::SessionTranscript;
     use crate::infra::tmux::MockTmuxClient;
     use crate::presentation::app_mode::{
-        ConfirmationViewMode, DiffFocus, DiffLineCommentAnchor, DiffLineCommentTarget,
-        DiffLineComments, DiffLineSide, DiffPreview, DiffRestoreTarget, DiffReviewComments,
-        PromptModeSnapshot,
+        ConfirmationViewMode, DiffFocus, DiffLayoutMode, DiffLineCommentAnchor,
+        DiffLineCommentTarget, DiffLineComments, DiffLineSide, DiffPreview, DiffRestoreTarget,
+        DiffReviewComments, PromptModeSnapshot,
     };
     use crate::presentation::prompt::{
         PromptAttachmentState, PromptHistoryState, PromptSlashState,
@@ -1059,7 +1208,7 @@ mod tests {
         );
         app.sessions.select_session_index(Some(0));
         app.mode = AppMode::SessionCreation {
-            selected_option_index: 2,
+            selected_option_index: 3,
         };
 
         // Act
@@ -1094,6 +1243,62 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_handle_session_creation_key_import_opens_reference_input() {
+        // Arrange
+        let (mut app, _base_dir) =
+            crate::test_support::new_git_test_app_with_mock_tmux_client().await;
+        app.mode = AppMode::SessionCreation {
+            selected_option_index: 2,
+        };
+
+        // Act
+        handle_session_creation_key(&mut app, KeyEvent::new(KeyCode::Down, KeyModifiers::NONE))
+            .await
+            .expect("failed to select import option");
+        let result = handle_session_creation_key(
+            &mut app,
+            KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Ok(EventResult::Continue)));
+        assert!(app.sessions.sessions().is_empty());
+        assert!(matches!(
+            app.mode,
+            AppMode::ReviewRequestImportInput { ref input } if input.text().is_empty()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_review_request_import_key_edits_and_cancels_input() {
+        // Arrange
+        let (mut app, _base_dir) =
+            crate::test_support::new_git_test_app_with_mock_tmux_client().await;
+        app.mode = AppMode::ReviewRequestImportInput {
+            input: InputState::default(),
+        };
+
+        // Act
+        handle_review_request_import_key(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('7'), KeyModifiers::NONE),
+        )
+        .await;
+        let typed_text = match &app.mode {
+            AppMode::ReviewRequestImportInput { input } => input.text().to_string(),
+            _ => String::new(),
+        };
+        handle_review_request_import_key(&mut app, KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+            .await;
+
+        // Assert
+        assert_eq!(typed_text, "7");
+        assert!(app.sessions.sessions().is_empty());
+        assert!(matches!(app.mode, AppMode::List));
+    }
+
     #[tokio::test]
     async fn test_handle_session_creation_key_escape_returns_to_list() {
         // Arrange
@@ -1693,7 +1898,7 @@ mod tests {
             at_mention_state: None,
             current_index: 0,
             focus: crate::presentation::app_mode::ChatFocus::Input,
-            input: crate::domain::input::InputState::default(),
+            input: InputState::default(),
             questions: vec![crate::domain::question::QuestionItem::new("Which branch?")],
             responses: Vec::new(),
             scroll_offset: None,
@@ -1762,6 +1967,7 @@ mod tests {
             diff: String::new(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -1828,6 +2034,7 @@ mod tests {
             diff: "diff --git a/src/main.rs b/src/main.rs\n+review();\n".to_string(),
             file_explorer_selected_index: 1,
             focus: DiffFocus::Content,
+            layout_mode: DiffLayoutMode::default(),
             line_comments,
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -1836,7 +2043,7 @@ mod tests {
                 at_mention_state: None,
                 attachment_state: PromptAttachmentState::default(),
                 history_state: PromptHistoryState::default(),
-                input: crate::domain::input::InputState::with_text("/keep draft".to_string()),
+                input: InputState::with_text("/keep draft".to_string()),
                 scroll_offset: None,
                 session_id: "session-id".into(),
                 slash_state: PromptSlashState::default(),
@@ -1866,6 +2073,7 @@ mod tests {
             diff: "diff --git a/src/main.rs b/src/main.rs\n+review();\n".to_string(),
             file_explorer_selected_index: 1,
             focus: DiffFocus::Content,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -2170,13 +2378,14 @@ mod tests {
         let (mut app, _base_dir) = crate::test_support::new_test_app_with_mock_tmux_client().await;
         app.mode = AppMode::PublishBranchInput {
             default_branch_name: "wt/session".to_string(),
-            input: crate::domain::input::InputState::with_text("review/custom".to_string()),
+            input: InputState::with_text("review/custom".to_string()),
             locked_upstream_ref: None,
             publish_branch_action: crate::domain::session::PublishBranchAction::Push,
             restore_view: ConfirmationViewMode {
                 scroll_offset: Some(7),
                 session_id: "session-id".into(),
             },
+            review_request_form: None,
         };
         let backend = ratatui::backend::TestBackend::new(120, 30);
         let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
@@ -2201,19 +2410,71 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_handle_publish_branch_input_key_edits_review_request_form_fields() {
+        // Arrange
+        let (mut app, _base_dir) = crate::test_support::new_test_app_with_mock_tmux_client().await;
+        let form = ReviewRequestPublishForm {
+            choices: Some(ag_forge::ReviewRequestPublishChoices {
+                labels: vec!["bug".to_string()],
+                milestones: Vec::new(),
+                reviewers: Vec::new(),
+            }),
+            ..ReviewRequestPublishForm::default()
+        };
+        app.mode = AppMode::PublishBranchInput {
+            default_branch_name: "wt/session".to_string(),
+            input: InputState::default(),
+            locked_upstream_ref: None,
+            publish_branch_action: crate::domain::session::PublishBranchAction::PublishPullRequest,
+            restore_view: ConfirmationViewMode {
+                scroll_offset: None,
+                session_id: "session-id".into(),
+            },
+            review_request_form: Some(Box::new(form)),
+        };
+
+        // Act
+        for key_code in [
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char('b'),
+            KeyCode::Tab,
+        ] {
+            handle_publish_branch_input_key(&mut app, KeyEvent::new(key_code, KeyModifiers::NONE))
+                .await;
+        }
+
+        // Assert
+        let AppMode::PublishBranchInput {
+            input,
+            review_request_form: Some(form),
+            ..
+        } = &app.mode
+        else {
+            unreachable!("mode should remain publish-branch input with a review-request form");
+        };
+        assert_eq!(input.text(), "");
+        assert_eq!(form.focus, PublishFormField::Labels);
+        assert!(!form.draft);
+        assert_eq!(form.labels.text(), "bug, ");
+    }
+
     #[tokio::test]
     async fn test_handle_publish_branch_input_key_escape_restores_view_mode() {
         // Arrange
         let (mut app, _base_dir) = crate::test_support::new_test_app_with_mock_tmux_client().await;
         app.mode = AppMode::PublishBranchInput {
             default_branch_name: "wt/session".to_string(),
-            input: crate::domain::input::InputState::with_text("review/custom".to_string()),
+            input: InputState::with_text("review/custom".to_string()),
             locked_upstream_ref: None,
             publish_branch_action: crate::domain::session::PublishBranchAction::Push,
             restore_view: ConfirmationViewMode {
                 scroll_offset: Some(7),
                 session_id: "session-id".into(),
             },
+            review_request_form: None,
         };
 
         // Act
@@ -2250,13 +2511,14 @@ mod tests {
         );
         app.mode = AppMode::PublishBranchInput {
             default_branch_name: "wt/session".to_string(),
-            input: crate::domain::input::InputState::with_text("review/custom".to_string()),
+            input: InputState::with_text("review/custom".to_string()),
             locked_upstream_ref: None,
             publish_branch_action: crate::domain::session::PublishBranchAction::PublishPullRequest,
             restore_view: ConfirmationViewMode {
                 scroll_offset: Some(4),
                 session_id: session_id.clone().into(),
             },
+            review_request_form: None,
         };
 
         // Act
@@ -2294,13 +2556,14 @@ mod tests {
         let (mut app, _base_dir) = crate::test_support::new_test_app_with_mock_tmux_client().await;
         app.mode = AppMode::PublishBranchInput {
             default_branch_name: "wt/session".to_string(),
-            input: crate::domain::input::InputState::default(),
+            input: InputState::default(),
             locked_upstream_ref: None,
             publish_branch_action: crate::domain::session::PublishBranchAction::Push,
             restore_view: ConfirmationViewMode {
                 scroll_offset: None,
                 session_id: "session-id".into(),
             },
+            review_request_form: None,
         };
 
         // Act
@@ -2334,13 +2597,14 @@ mod tests {
         for character in typed_shortcut_characters {
             app.mode = AppMode::PublishBranchInput {
                 default_branch_name: "wt/session".to_string(),
-                input: crate::domain::input::InputState::default(),
+                input: InputState::default(),
                 locked_upstream_ref: None,
                 publish_branch_action: crate::domain::session::PublishBranchAction::Push,
                 restore_view: ConfirmationViewMode {
                     scroll_offset: None,
                     session_id: "session-id".into(),
                 },
+                review_request_form: None,
             };
             let modifiers = if character.is_ascii_uppercase() || character == '?' {
                 KeyModifiers::SHIFT
@@ -2373,13 +2637,14 @@ mod tests {
         let (mut app, _base_dir) = crate::test_support::new_test_app_with_mock_tmux_client().await;
         app.mode = AppMode::PublishBranchInput {
             default_branch_name: "wt/session".to_string(),
-            input: crate::domain::input::InputState::with_text("review/custom".to_string()),
+            input: InputState::with_text("review/custom".to_string()),
             locked_upstream_ref: None,
             publish_branch_action: crate::domain::session::PublishBranchAction::Push,
             restore_view: ConfirmationViewMode {
                 scroll_offset: None,
                 session_id: "session-id".into(),
             },
+            review_request_form: None,
         };
 
         // Act
@@ -2404,13 +2669,14 @@ mod tests {
         let (mut app, _base_dir) = crate::test_support::new_test_app_with_mock_tmux_client().await;
         app.mode = AppMode::PublishBranchInput {
             default_branch_name: "wt/session".to_string(),
-            input: crate::domain::input::InputState::with_text("review custom".to_string()),
+            input: InputState::with_text("review custom".to_string()),
             locked_upstream_ref: None,
             publish_branch_action: crate::domain::session::PublishBranchAction::Push,
             restore_view: ConfirmationViewMode {
                 scroll_offset: None,
                 session_id: "session-id".into(),
             },
+            review_request_form: None,
         };
 
         // Act
@@ -2447,13 +2713,14 @@ mod tests {
         let (mut app, _base_dir) = crate::test_support::new_test_app_with_mock_tmux_client().await;
         app.mode = AppMode::PublishBranchInput {
             default_branch_name: "wt/session".to_string(),
-            input: crate::domain::input::InputState::with_text("review/custom".to_string()),
+            input: InputState::with_text("review/custom".to_string()),
             locked_upstream_ref: None,
             publish_branch_action: crate::domain::session::PublishBranchAction::Push,
             restore_view: ConfirmationViewMode {
                 scroll_offset: None,
                 session_id: "session-id".into(),
             },
+            review_request_form: None,
         };
 
         // Act
@@ -2477,13 +2744,14 @@ mod tests {
         let (mut app, _base_dir) = crate::test_support::new_test_app_with_mock_tmux_client().await;
         app.mode = AppMode::PublishBranchInput {
             default_branch_name: "wt/session".to_string(),
-            input: crate::domain::input::InputState::with_text("review/custom".to_string()),
+            input: InputState::with_text("review/custom".to_string()),
             locked_upstream_ref: Some("origin/review/custom".to_string()),
             publish_branch_action: crate::domain::session::PublishBranchAction::Push,
             restore_view: ConfirmationViewMode {
                 scroll_offset: None,
                 session_id: "session-id".into(),
             },
+            review_request_form: None,
         };
 
         // Act
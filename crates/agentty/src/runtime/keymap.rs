@@ -0,0 +1,184 @@
+//! Crossterm bridge for the user keymap loaded from `keymap.toml`.
+
+use std::path::Path;
+use std::{fs, io};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::{App, Tab, agentty_home};
+use crate::domain::input::InputState;
+use crate::presentation::app_mode::{AppMode, ChatFocus};
+use crate::presentation::keymap::{KEYMAP_FILE, KeyChord, KeyName, Keymap, KeymapMode, ModeAction};
+
+/// Loads `~/.agentty/keymap.toml` for dispatch and help.
+///
+/// A missing file keeps the built-in shortcuts.
+///
+/// # Errors
+/// Returns an error when the file cannot be read or fails validation, so
+/// startup stops before the terminal switches to the alternate screen.
+pub(crate) fn load_user_keymap() -> io::Result<Keymap> {
+    read_keymap(&agentty_home().join(KEYMAP_FILE))
+}
+
+/// Reads and validates one keymap file, defaulting when it does not exist.
+fn read_keymap(path: &Path) -> io::Result<Keymap> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Keymap::default()),
+        Err(error) => return Err(error),
+    };
+
+    Keymap::from_toml(&text).map_err(|error| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Invalid keymap {}: {error}", path.display()),
+        )
+    })
+}
+
+/// Resolves `key` into one action of `A`'s mode through the app keymap.
+///
+/// Inline editors, dropdowns, and slash-command input resolve through the
+/// built-in table so their fixed keys keep working after overrides.
+pub(crate) fn resolve_action<A: ModeAction>(app: &App, key: KeyEvent) -> Option<A> {
+    let chord = key_chord(key)?;
+
+    match keymap_scope(app) {
+        Some((mode, accepts_text)) if mode == A::MODE => app.keymap.action(chord, accepts_text),
+        _ => app.keymap.built_in_action(chord),
+    }
+}
+
+/// Returns whether `key` lost its built-in action to a keymap override in
+/// the current mode, so the press should be ignored.
+pub(crate) fn is_released_key(app: &App, key: KeyEvent) -> bool {
+    let Some((mode, accepts_text)) = keymap_scope(app) else {
+        return false;
+    };
+
+    key_chord(key).is_some_and(|chord| app.keymap.is_released(mode, chord, accepts_text))
+}
+
+/// Returns the keymap section for the current mode and whether its focused
+/// input consumes typed characters.
+///
+/// Inline editors, dropdowns, and slash-command input keep their fixed keys
+/// and bypass the keymap.
+fn keymap_scope(app: &App) -> Option<(KeymapMode, bool)> {
+    match &app.mode {
+        AppMode::List => {
+            let is_settings_editor_open = app.tabs.current() == Tab::Settings
+                && (app
+                    .settings_presentation
+                    .is_launch_configuration_list_editor_open()
+                    || app.settings_presentation.is_selector_dropdown_open());
+
+            (!is_settings_editor_open).then_some((KeymapMode::List, false))
+        }
+        AppMode::View { .. } => Some((KeymapMode::View, false)),
+        AppMode::Diff { line_comments, .. } => {
+            (!line_comments.is_editing()).then_some((KeymapMode::Diff, false))
+        }
+        AppMode::Prompt {
+            at_mention_state,
+            focus,
+            input,
+            ..
+        } => (at_mention_state.is_none() && !is_slash_command(input))
+            .then_some((KeymapMode::Prompt, *focus == ChatFocus::Input)),
+        AppMode::Question {
+            at_mention_state,
+            focus,
+            selected_option_index,
+            ..
+        } => at_mention_state.is_none().then_some((
+            KeymapMode::Question,
+            *focus == ChatFocus::Input && selected_option_index.is_none(),
+        )),
+        _ => None,
+    }
+}
+
+/// Returns whether the prompt input is completing a slash command.
+fn is_slash_command(input: &InputState) -> bool {
+    input.text().starts_with('/')
+}
+
+/// Converts one crossterm key event into a backend-neutral chord.
+fn key_chord(key: KeyEvent) -> Option<KeyChord> {
+    let key_name = match key.code {
+        KeyCode::Char(character) => KeyName::Char(character),
+        KeyCode::Enter => KeyName::Enter,
+        KeyCode::Esc => KeyName::Esc,
+        KeyCode::Tab => KeyName::Tab,
+        KeyCode::BackTab => KeyName::BackTab,
+        KeyCode::Backspace => KeyName::Backspace,
+        KeyCode::Delete => KeyName::Delete,
+        KeyCode::Up => KeyName::Up,
+        KeyCode::Down => KeyName::Down,
+        KeyCode::Left => KeyName::Left,
+        KeyCode::Right => KeyName::Right,
+        KeyCode::Home => KeyName::Home,
+        KeyCode::End => KeyName::End,
+        KeyCode::PageUp => KeyName::PageUp,
+        KeyCode::PageDown => KeyName::PageDown,
+        KeyCode::F(number) => KeyName::F(number),
+        _ => return None,
+    };
+
+    Some(KeyChord::new(
+        key_name,
+        key.modifiers.contains(KeyModifiers::CONTROL),
+        key.modifiers.contains(KeyModifiers::ALT),
+        key.modifiers.contains(KeyModifiers::SHIFT),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_chord_normalizes_shifted_letters_and_back_tab() {
+        // Arrange
+        let shifted_letter = KeyEvent::new(KeyCode::Char('V'), KeyModifiers::SHIFT);
+        let back_tab = KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT);
+
+        // Act
+        let shifted_letter_chord = key_chord(shifted_letter).expect("letter should convert");
+        let back_tab_chord = key_chord(back_tab).expect("back tab should convert");
+
+        // Assert
+        assert_eq!(
+            shifted_letter_chord,
+            KeyChord::parse("Shift+V").expect("chord should parse")
+        );
+        assert_eq!(
+            back_tab_chord,
+            KeyChord::parse("Shift+Tab").expect("chord should parse")
+        );
+    }
+
+    #[test]
+    fn test_read_keymap_defaults_when_file_is_missing_and_reports_invalid_file() {
+        // Arrange
+        let temp_dir = tempfile::tempdir().expect("failed to create temp dir");
+        let missing_path = temp_dir.path().join(KEYMAP_FILE);
+        let invalid_path = temp_dir.path().join("invalid.toml");
+        fs::write(&invalid_path, "[diff]\nselect_next = \"k\"\n").expect("write keymap");
+
+        // Act
+        let missing_result = read_keymap(&missing_path);
+        let invalid_error = read_keymap(&invalid_path).expect_err("conflict should fail");
+
+        // Assert
+        assert!(missing_result.is_ok());
+        assert_eq!(invalid_error.kind(), io::ErrorKind::InvalidData);
+        assert!(
+            invalid_error
+                .to_string()
+                .contains("`k` is bound to both `select_next` and `select_previous`")
+        );
+    }
+}
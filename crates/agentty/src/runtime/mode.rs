@@ -7,9 +7,11 @@ pub(crate) mod diff;
 pub(crate) mod help;
 pub(crate) mod input_key;
 pub(crate) mod list;
+pub(crate) mod orchestration_board;
 pub(crate) mod prompt;
 pub(crate) mod question;
 pub(crate) mod review_comment;
 pub(crate) mod session_output_metric;
 pub(crate) mod session_view;
 pub(crate) mod sync_blocked;
+pub(crate) mod tool_approval;
@@ -367,6 +367,7 @@ mod tests {
                 reasoning_level_override: None,
                 published_upstream_ref: None,
                 questions: Vec::new(),
+                review_checks: None,
                 review_request: None,
                 size: SessionSize::Xs,
                 speed_mode: crate::domain::agent::SpeedMode::default(),
@@ -377,6 +378,9 @@ mod tests {
                 transcript: None,
                 updated_at: 0,
                 transient_messages: TransientMessageStore::default(),
+                pending_tool_approval: None,
+                post_turn_checks: None,
+                activity_log_expanded: false,
             }],
             SelectionState::default(),
             Arc::new(RealClock),
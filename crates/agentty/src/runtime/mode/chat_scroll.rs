@@ -35,7 +35,7 @@ pub(crate) enum ChatFocusAction {
     /// Opens the session diff preview when the active mode allows it.
     OpenDiff,
     /// Moves the transcript viewport.
-    Scroll,
+    Scroll(ScrollStep),
     /// Switches focus between the transcript and the bottom input panel.
     ToggleFocus,
     /// Consumes a key that has no chat-focused behavior.
@@ -130,33 +130,25 @@ impl ChatScrollMetrics {
     }
 }
 
-/// Classifies `key` into a shared chat-page action for the current focus.
+/// Classifies one resolved chat shortcut for the current focus.
 ///
-/// `Tab` switches focus from either panel. When the transcript is focused,
-/// `d` requests a diff preview, recognized scroll keys move the transcript,
-/// and all remaining keys are swallowed to preserve the bottom-panel draft.
-/// Keys from the bottom input panel return `None` so its mode can handle them.
+/// The focus toggle switches focus from either panel. When the transcript is
+/// focused, diff and scroll shortcuts apply and all remaining keys are
+/// swallowed to preserve the bottom-panel draft. Keys from the bottom input
+/// panel return `None` so its mode can handle them.
 pub(crate) fn classify_chat_focus_action(
     focus: ChatFocus,
-    key: KeyEvent,
+    shortcut: Option<ChatFocusAction>,
 ) -> Option<ChatFocusAction> {
-    if key.code == KeyCode::Tab {
-        return Some(ChatFocusAction::ToggleFocus);
+    if shortcut == Some(ChatFocusAction::ToggleFocus) {
+        return shortcut;
     }
 
     if focus == ChatFocus::Input {
         return None;
     }
 
-    if is_diff_preview_key(key) {
-        return Some(ChatFocusAction::OpenDiff);
-    }
-
-    if is_scroll_key(key) {
-        return Some(ChatFocusAction::Scroll);
-    }
-
-    Some(ChatFocusAction::Swallow)
+    Some(shortcut.unwrap_or(ChatFocusAction::Swallow))
 }
 
 /// Switches focus between the chat transcript and its bottom input panel.
@@ -167,34 +159,14 @@ pub(crate) fn toggle_chat_focus(focus: &mut ChatFocus) {
     };
 }
 
-/// Returns whether `key` scrolls the session transcript.
-///
-/// Handlers that swallow every other key check this before building
-/// [`ChatScrollMetrics`], because those metrics lay out and fingerprint the
-/// whole transcript and would otherwise run on each ignored keystroke.
-pub(crate) fn is_scroll_key(key: KeyEvent) -> bool {
-    ScrollStep::from_key(key).is_some()
-}
-
-/// Returns whether `key` requests the session diff preview from chat focus.
-fn is_diff_preview_key(key: KeyEvent) -> bool {
-    matches!(key.code, KeyCode::Char('d')) && !key.modifiers.contains(KeyModifiers::CONTROL)
-}
-
-/// Applies one transcript scroll key to `scroll_offset`.
+/// Applies one transcript scroll step to `scroll_offset`.
 ///
-/// Handles `j`/`k`/`Down`/`Up` line steps, `g`/`G` jumps, and `Ctrl+d`/`Ctrl+u`
-/// half-page steps. A `None` offset keeps the transcript pinned to the newest
-/// output. Returns `true` when the key was consumed as a scroll action.
-pub(crate) fn apply_scroll_key(
+/// A `None` offset keeps the transcript pinned to the newest output.
+pub(crate) fn apply_scroll_step(
     scroll_offset: &mut Option<u16>,
     metrics: ChatScrollMetrics,
-    key: KeyEvent,
-) -> bool {
-    let Some(scroll_step) = ScrollStep::from_key(key) else {
-        return false;
-    };
-
+    scroll_step: ScrollStep,
+) {
     match scroll_step {
         ScrollStep::LineDown => {
             *scroll_offset = scroll_offset_down(*scroll_offset, metrics, 1);
@@ -215,13 +187,11 @@ pub(crate) fn apply_scroll_key(
             ));
         }
     }
-
-    true
 }
 
-/// One transcript movement requested by a scroll key.
-#[derive(Clone, Copy, Eq, PartialEq)]
-enum ScrollStep {
+/// One transcript movement requested by a scroll shortcut.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ScrollStep {
     /// Jump to the newest output and keep following it.
     Bottom,
     /// Move down by half a viewport.
@@ -237,13 +207,12 @@ enum ScrollStep {
 }
 
 impl ScrollStep {
-    /// Returns the transcript movement `key` requests, if it is a scroll key.
-    fn from_key(key: KeyEvent) -> Option<Self> {
+    /// Returns the movement for the arrow and half-page keys that chat pages
+    /// accept outside their keymap section.
+    pub(crate) fn from_fixed_key(key: KeyEvent) -> Option<Self> {
         match key.code {
-            KeyCode::Char('j') | KeyCode::Down => Some(Self::LineDown),
-            KeyCode::Char('k') | KeyCode::Up => Some(Self::LineUp),
-            KeyCode::Char('g') => Some(Self::Top),
-            KeyCode::Char('G') => Some(Self::Bottom),
+            KeyCode::Down => Some(Self::LineDown),
+            KeyCode::Up => Some(Self::LineUp),
             KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 Some(Self::HalfPageDown)
             }
@@ -303,65 +272,73 @@ mod tests {
     }
 
     #[test]
-    fn test_is_scroll_key_accepts_scroll_keys_and_rejects_other_keys() {
+    fn test_scroll_step_from_fixed_key_accepts_arrows_and_half_page_keys() {
         // Arrange
         let scroll_keys = [
-            plain_key(KeyCode::Char('j')),
-            plain_key(KeyCode::Up),
-            plain_key(KeyCode::Char('G')),
-            KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL),
+            (plain_key(KeyCode::Down), ScrollStep::LineDown),
+            (plain_key(KeyCode::Up), ScrollStep::LineUp),
+            (
+                KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL),
+                ScrollStep::HalfPageDown,
+            ),
+            (
+                KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL),
+                ScrollStep::HalfPageUp,
+            ),
         ];
         let other_keys = [
-            plain_key(KeyCode::Char('x')),
+            plain_key(KeyCode::Char('j')),
             plain_key(KeyCode::Char('d')),
             plain_key(KeyCode::Enter),
         ];
 
         // Act, Assert
-        assert!(scroll_keys.into_iter().all(is_scroll_key));
-        assert!(!other_keys.into_iter().any(is_scroll_key));
+        for (key, step) in scroll_keys {
+            assert_eq!(ScrollStep::from_fixed_key(key), Some(step));
+        }
+        assert!(
+            other_keys
+                .into_iter()
+                .all(|key| ScrollStep::from_fixed_key(key).is_none())
+        );
     }
 
     #[test]
-    fn test_classify_chat_focus_action_distinguishes_input_and_chat_keys() {
+    fn test_classify_chat_focus_action_distinguishes_input_and_chat_focus() {
         // Arrange
-        let input_key = plain_key(KeyCode::Char('q'));
-        let chat_keys = [
-            (plain_key(KeyCode::Tab), ChatFocusAction::ToggleFocus),
-            (plain_key(KeyCode::Char('d')), ChatFocusAction::OpenDiff),
-            (plain_key(KeyCode::Char('j')), ChatFocusAction::Scroll),
-            (plain_key(KeyCode::Esc), ChatFocusAction::Swallow),
+        let chat_shortcuts = [
+            (
+                Some(ChatFocusAction::ToggleFocus),
+                ChatFocusAction::ToggleFocus,
+            ),
+            (Some(ChatFocusAction::OpenDiff), ChatFocusAction::OpenDiff),
+            (
+                Some(ChatFocusAction::Scroll(ScrollStep::LineDown)),
+                ChatFocusAction::Scroll(ScrollStep::LineDown),
+            ),
+            (None, ChatFocusAction::Swallow),
         ];
 
         // Act, Assert
         assert_eq!(
-            classify_chat_focus_action(ChatFocus::Input, input_key),
+            classify_chat_focus_action(
+                ChatFocus::Input,
+                Some(ChatFocusAction::Scroll(ScrollStep::Top))
+            ),
             None
         );
         assert_eq!(
-            classify_chat_focus_action(ChatFocus::Input, plain_key(KeyCode::Tab)),
+            classify_chat_focus_action(ChatFocus::Input, Some(ChatFocusAction::ToggleFocus)),
             Some(ChatFocusAction::ToggleFocus)
         );
-        for (key, action) in chat_keys {
+        for (shortcut, action) in chat_shortcuts {
             assert_eq!(
-                classify_chat_focus_action(ChatFocus::Chat, key),
+                classify_chat_focus_action(ChatFocus::Chat, shortcut),
                 Some(action)
             );
         }
     }
 
-    #[test]
-    fn test_classify_chat_focus_action_keeps_ctrl_d_as_scroll() {
-        // Arrange
-        let key = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL);
-
-        // Act
-        let action = classify_chat_focus_action(ChatFocus::Chat, key);
-
-        // Assert
-        assert_eq!(action, Some(ChatFocusAction::Scroll));
-    }
-
     #[test]
     fn test_toggle_chat_focus_switches_between_panels() {
         // Arrange
@@ -378,25 +355,7 @@ mod tests {
     }
 
     #[test]
-    fn test_apply_scroll_key_steps_down_one_line() {
-        // Arrange
-        let metrics = ChatScrollMetrics {
-            total_lines: 30,
-            view_height: 10,
-        };
-        let mut scroll_offset = Some(0);
-
-        // Act
-        let is_consumed =
-            apply_scroll_key(&mut scroll_offset, metrics, plain_key(KeyCode::Char('j')));
-
-        // Assert
-        assert!(is_consumed);
-        assert_eq!(scroll_offset, Some(1));
-    }
-
-    #[test]
-    fn test_apply_scroll_key_steps_up_one_line() {
+    fn test_apply_scroll_step_moves_one_line() {
         // Arrange
         let metrics = ChatScrollMetrics {
             total_lines: 30,
@@ -405,16 +364,18 @@ mod tests {
         let mut scroll_offset = Some(5);
 
         // Act
-        let is_consumed =
-            apply_scroll_key(&mut scroll_offset, metrics, plain_key(KeyCode::Char('k')));
+        apply_scroll_step(&mut scroll_offset, metrics, ScrollStep::LineDown);
+        let down_offset = scroll_offset;
+        apply_scroll_step(&mut scroll_offset, metrics, ScrollStep::LineUp);
+        apply_scroll_step(&mut scroll_offset, metrics, ScrollStep::LineUp);
 
         // Assert
-        assert!(is_consumed);
+        assert_eq!(down_offset, Some(6));
         assert_eq!(scroll_offset, Some(4));
     }
 
     #[test]
-    fn test_apply_scroll_key_jumps_to_top_and_bottom() {
+    fn test_apply_scroll_step_jumps_to_top_and_bottom() {
         // Arrange
         let metrics = ChatScrollMetrics {
             total_lines: 30,
@@ -423,9 +384,9 @@ mod tests {
         let mut scroll_offset = None;
 
         // Act
-        apply_scroll_key(&mut scroll_offset, metrics, plain_key(KeyCode::Char('g')));
+        apply_scroll_step(&mut scroll_offset, metrics, ScrollStep::Top);
         let top_offset = scroll_offset;
-        apply_scroll_key(&mut scroll_offset, metrics, plain_key(KeyCode::Char('G')));
+        apply_scroll_step(&mut scroll_offset, metrics, ScrollStep::Bottom);
 
         // Assert
         assert_eq!(top_offset, Some(0));
@@ -433,7 +394,7 @@ mod tests {
     }
 
     #[test]
-    fn test_apply_scroll_key_scrolls_half_page_up_from_bottom() {
+    fn test_apply_scroll_step_scrolls_half_pages() {
         // Arrange
         let metrics = ChatScrollMetrics {
             total_lines: 30,
@@ -442,54 +403,13 @@ mod tests {
         let mut scroll_offset = None;
 
         // Act
-        let is_consumed = apply_scroll_key(
-            &mut scroll_offset,
-            metrics,
-            KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL),
-        );
+        apply_scroll_step(&mut scroll_offset, metrics, ScrollStep::HalfPageUp);
+        let up_offset = scroll_offset;
+        apply_scroll_step(&mut scroll_offset, metrics, ScrollStep::HalfPageDown);
 
         // Assert
-        assert!(is_consumed);
-        assert_eq!(scroll_offset, Some(15));
-    }
-
-    #[test]
-    fn test_apply_scroll_key_scrolls_half_page_down() {
-        // Arrange
-        let metrics = ChatScrollMetrics {
-            total_lines: 30,
-            view_height: 10,
-        };
-        let mut scroll_offset = Some(5);
-
-        // Act
-        let is_consumed = apply_scroll_key(
-            &mut scroll_offset,
-            metrics,
-            KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL),
-        );
-
-        // Assert
-        assert!(is_consumed);
-        assert_eq!(scroll_offset, Some(10));
-    }
-
-    #[test]
-    fn test_apply_scroll_key_ignores_unrelated_keys() {
-        // Arrange
-        let metrics = ChatScrollMetrics {
-            total_lines: 30,
-            view_height: 10,
-        };
-        let mut scroll_offset = Some(3);
-
-        // Act
-        let is_consumed =
-            apply_scroll_key(&mut scroll_offset, metrics, plain_key(KeyCode::Char('x')));
-
-        // Assert
-        assert!(!is_consumed);
-        assert_eq!(scroll_offset, Some(3));
+        assert_eq!(up_offset, Some(15));
+        assert_eq!(scroll_offset, None);
     }
 
     #[test]
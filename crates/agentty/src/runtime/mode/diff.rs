@@ -5,20 +5,22 @@ use crate::app::{App, AppEvent};
 use crate::domain::input::InputState;
 use crate::domain::session::SessionId;
 use crate::presentation::app_mode::{
-    AppMode, DiffFocus, DiffLineCommentTarget, DiffLineComments, DiffPreview,
-    DiffPreviewUnavailableReason, DiffRestoreTarget, DiffReviewComments, DiffScrollCache,
-    DiffSidebarFocus, HelpContext, PromptModeSnapshot, ViewportRect,
-    allows_diff_line_comment_reply,
+    AppMode, DiffFocus, DiffLayoutMode, DiffLineCommentTarget, DiffLineComments, DiffLineSide,
+    DiffPreview, DiffPreviewUnavailableReason, DiffRestoreTarget, DiffRevertScope,
+    DiffReviewComments, DiffScrollCache, DiffSidebarFocus, HelpContext, PromptModeSnapshot,
+    ViewportRect, allows_diff_line_comment_reply,
 };
+use crate::presentation::keymap::DiffAction;
 use crate::presentation::prompt::{PromptAttachmentState, PromptHistoryState};
-use crate::runtime::EventResult;
 use crate::runtime::mode::input_key;
+use crate::runtime::{EventResult, keymap};
 use crate::ui::component::file_explorer::FileExplorer;
 use crate::ui::{RenderCacheStore, diff_util, page};
 
 /// Handles key input while the app is in `AppMode::Diff`.
 ///
-/// File selection via `j`/`k` wraps around between the first and last file
+/// Keys resolve through the `[diff]` keymap section. File selection via
+/// `select_next`/`select_previous` wraps around between the first and last file
 /// explorer entries. Leaving diff mode restores the prior composer or question
 /// snapshot when present; otherwise it rebuilds session view with any cached
 /// focused review output for the same session.
@@ -32,15 +34,16 @@ pub(crate) fn handle_with_cache(
         return EventResult::Continue;
     }
 
-    if handle_help_key(app, key) {
+    let action = keymap::resolve_action(app, key);
+    if handle_help_key(app, action) {
         return EventResult::Continue;
     }
 
-    if handle_exit_key(app, key) {
+    if handle_exit_key(app, action, key) {
         return EventResult::Continue;
     }
 
-    handle_navigation_key(app, render_cache_store, content_area, key);
+    handle_navigation_key(app, render_cache_store, content_area, action, key);
 
     EventResult::Continue
 }
@@ -81,6 +84,7 @@ pub(crate) fn enter_diff_mode(
         diff,
         file_explorer_selected_index: 0,
         focus: DiffFocus::Files,
+        layout_mode: DiffLayoutMode::default(),
         line_comments: DiffLineComments::default(),
         preview: DiffPreview::default(),
         review_comments,
@@ -93,8 +97,8 @@ pub(crate) fn enter_diff_mode(
 }
 
 /// Opens diff help while preserving the current diff-mode snapshot.
-fn handle_help_key(app: &mut App, key: KeyEvent) -> bool {
-    if key.code != KeyCode::Char('?') {
+fn handle_help_key(app: &mut App, action: Option<DiffAction>) -> bool {
+    if action != Some(DiffAction::Help) {
         return false;
     }
 
@@ -104,6 +108,7 @@ fn handle_help_key(app: &mut App, key: KeyEvent) -> bool {
         diff,
         file_explorer_selected_index,
         focus,
+        layout_mode,
         line_comments,
         preview,
         review_comments,
@@ -120,6 +125,7 @@ fn handle_help_key(app: &mut App, key: KeyEvent) -> bool {
                 diff,
                 file_explorer_selected_index,
                 focus,
+                layout_mode,
                 line_comments,
                 preview,
                 review_comments: review_comments.map(Box::new),
@@ -138,10 +144,12 @@ fn handle_help_key(app: &mut App, key: KeyEvent) -> bool {
 }
 
 /// Leaves diff mode and restores the originating view or question state.
-fn handle_exit_key(app: &mut App, key: KeyEvent) -> bool {
-    let should_exit = match key.code {
-        KeyCode::Char('q') => true,
-        KeyCode::Esc => !matches!(
+///
+/// `Esc` also leaves diff mode unless the right-hand content pane holds focus.
+fn handle_exit_key(app: &mut App, action: Option<DiffAction>, key: KeyEvent) -> bool {
+    let should_exit = match (action, key.code) {
+        (Some(DiffAction::Back), _) => true,
+        (None, KeyCode::Esc) => !matches!(
             app.mode,
             AppMode::Diff {
                 focus: DiffFocus::Content,
@@ -181,6 +189,7 @@ fn handle_navigation_key(
     app: &mut App,
     render_cache_store: &RenderCacheStore,
     content_area: Rect,
+    action: Option<DiffAction>,
     key: KeyEvent,
 ) {
     let can_reply_with_line_comments = can_reply_with_line_comments(app);
@@ -189,6 +198,7 @@ fn handle_navigation_key(
         diff,
         mut file_explorer_selected_index,
         mut focus,
+        mut layout_mode,
         mut line_comments,
         mut preview,
         mut review_comments,
@@ -208,6 +218,7 @@ fn handle_navigation_key(
         diff: &diff,
         file_explorer_selected_index: &mut file_explorer_selected_index,
         focus: &mut focus,
+        layout_mode: &mut layout_mode,
         line_comments: &mut line_comments,
         preview: &mut preview,
         review_comments: &mut review_comments,
@@ -218,6 +229,7 @@ fn handle_navigation_key(
     };
     let row_selection_key_handled = handle_row_selection_key(
         render_cache_store,
+        action,
         key,
         &mut navigation,
         can_reply_with_line_comments,
@@ -233,7 +245,14 @@ fn handle_navigation_key(
         })
         .flatten();
     let selection_changed = !row_selection_key_handled
-        && apply_navigation_key(app, render_cache_store, content_area, key, &mut navigation);
+        && apply_navigation_key(
+            app,
+            render_cache_store,
+            content_area,
+            action,
+            key,
+            &mut navigation,
+        );
 
     if selection_changed && preview.is_enabled() {
         refresh_selected_preview(
@@ -250,6 +269,7 @@ fn handle_navigation_key(
         diff,
         file_explorer_selected_index,
         focus,
+        layout_mode,
         line_comments,
         preview,
         review_comments,
@@ -266,7 +286,8 @@ fn handle_navigation_key(
     }
 }
 
-/// Returns whether `s` requests submission of all completed inline comments.
+/// Returns whether `submit_comments` requests submission of all completed
+/// inline comments.
 pub(crate) fn should_submit_line_comments(app: &App, key: KeyEvent) -> bool {
     let AppMode::Diff {
         focus,
@@ -278,16 +299,157 @@ pub(crate) fn should_submit_line_comments(app: &App, key: KeyEvent) -> bool {
     };
 
     can_reply_with_line_comments(app)
-        && is_plain_char_key(key, 's')
+        && keymap::resolve_action(app, key) == Some(DiffAction::SubmitComments)
         && *focus == DiffFocus::Content
         && !line_comments.is_editing()
         && !line_comments.is_selecting()
         && !line_comments.comments.is_empty()
 }
 
-/// Handles `Shift+V` row-selection entry and `Esc` cancellation.
+/// Result of routing one key through the diff-view revert shortcuts.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) enum DiffRevertKeyOutcome {
+    /// The key is not a revert shortcut and continues to diff navigation.
+    Unhandled,
+    /// The key armed or cancelled a revert awaiting confirmation.
+    Handled,
+    /// The key confirmed a revert that should now run for `session_id`.
+    Confirmed {
+        /// Patch that discards the selected rows when reverse-applied.
+        revert_patch: diff_util::DiffRevertPatch,
+        /// Session whose worktree owns the selected rows.
+        session_id: SessionId,
+    },
+}
+
+/// Handles `revert_lines` and `revert_hunk` shortcuts for the selected
+/// changed rows.
+///
+/// The first press arms the revert and the same action confirms it; any other
+/// key cancels a pending revert before normal diff handling. `revert_lines`
+/// (`x`) discards the selected row or `select_rows` range, while
+/// `revert_hunk` (`X`) discards each whole hunk that contains the selection.
+pub(crate) fn handle_revert_key(
+    app: &mut App,
+    render_cache_store: &RenderCacheStore,
+    key: KeyEvent,
+) -> DiffRevertKeyOutcome {
+    let can_revert = can_revert_diff_changes(app);
+    let revert_scope = revert_scope_for_action(keymap::resolve_action(app, key));
+    let AppMode::Diff {
+        diff,
+        file_explorer_selected_index,
+        focus,
+        line_comments,
+        preview,
+        review_comments,
+        selected_diff_line_index,
+        session_id,
+        ..
+    } = &mut app.mode
+    else {
+        return DiffRevertKeyOutcome::Unhandled;
+    };
+    if line_comments.is_editing() {
+        return DiffRevertKeyOutcome::Unhandled;
+    }
+    let pending_revert = line_comments.pending_revert.take();
+    let Some(scope) = revert_scope else {
+        return if pending_revert.is_some() && key.code == KeyCode::Esc {
+            DiffRevertKeyOutcome::Handled
+        } else {
+            DiffRevertKeyOutcome::Unhandled
+        };
+    };
+    let review_comments_are_focused = review_comments
+        .as_ref()
+        .is_some_and(|review_comments| review_comments.sidebar_focus == DiffSidebarFocus::Comments);
+    if !can_revert
+        || *focus != DiffFocus::Content
+        || review_comments_are_focused
+        || selected_preview_is_visible(
+            diff,
+            *file_explorer_selected_index,
+            render_cache_store.diff_layout_cache(),
+            preview,
+        )
+    {
+        return DiffRevertKeyOutcome::Unhandled;
+    }
+    if pending_revert != Some(scope) {
+        line_comments.pending_revert = Some(scope);
+
+        return DiffRevertKeyOutcome::Handled;
+    }
+
+    let content = render_cache_store.diff_layout_cache().content(diff);
+    let (start_changed_line_index, end_changed_line_index) =
+        line_comments.selected_row_bounds(*selected_diff_line_index);
+    let anchors = content.selected_changed_lines(
+        *file_explorer_selected_index,
+        start_changed_line_index,
+        end_changed_line_index,
+    );
+    let revert_patch =
+        diff_util::diff_revert_patch(diff, scope == DiffRevertScope::Hunk, |path, diff_line| {
+            anchors.iter().any(|anchor| {
+                anchor.path == path
+                    && match anchor.side {
+                        DiffLineSide::New => {
+                            diff_line.kind == diff_util::DiffLineKind::Addition
+                                && diff_line.new_line == Some(anchor.line)
+                        }
+                        DiffLineSide::Old => {
+                            diff_line.kind == diff_util::DiffLineKind::Deletion
+                                && diff_line.old_line == Some(anchor.line)
+                        }
+                    }
+            })
+        });
+    line_comments.cancel_selection();
+    let Some(revert_patch) = revert_patch else {
+        return DiffRevertKeyOutcome::Handled;
+    };
+
+    DiffRevertKeyOutcome::Confirmed {
+        revert_patch,
+        session_id: session_id.clone(),
+    }
+}
+
+/// Maps `revert_lines` to a line revert and `revert_hunk` to a whole-hunk
+/// revert.
+fn revert_scope_for_action(action: Option<DiffAction>) -> Option<DiffRevertScope> {
+    match action? {
+        DiffAction::RevertLines => Some(DiffRevertScope::Lines),
+        DiffAction::RevertHunk => Some(DiffRevertScope::Hunk),
+        _ => None,
+    }
+}
+
+/// Returns whether the active diff session may discard worktree changes.
+fn can_revert_diff_changes(app: &App) -> bool {
+    let AppMode::Diff {
+        restore,
+        session_id,
+        ..
+    } = &app.mode
+    else {
+        return false;
+    };
+    if matches!(restore.as_deref(), Some(DiffRestoreTarget::Question(_))) {
+        return false;
+    }
+
+    app.sessions
+        .session_for_id(session_id.as_str())
+        .is_some_and(crate::domain::session::Session::allows_diff_revert_action)
+}
+
+/// Handles `select_rows` row-selection entry and `Esc` cancellation.
 fn handle_row_selection_key(
     render_cache_store: &RenderCacheStore,
+    action: Option<DiffAction>,
     key: KeyEvent,
     navigation: &mut DiffKeyNavigation<'_>,
     can_reply_with_line_comments: bool,
@@ -302,7 +464,7 @@ fn handle_row_selection_key(
         .as_ref()
         .is_some_and(|review_comments| review_comments.sidebar_focus == DiffSidebarFocus::Comments);
     if !can_reply_with_line_comments
-        || !is_shift_char_key(key, 'v')
+        || action != Some(DiffAction::SelectRows)
         || *navigation.focus != DiffFocus::Content
         || review_comments_are_focused
         || selected_preview_is_visible(
@@ -385,6 +547,7 @@ fn start_line_comment_edit(
     let AppMode::Diff {
         diff,
         file_explorer_selected_index,
+        layout_mode,
         line_comments,
         scroll_cache,
         scroll_offset,
@@ -404,7 +567,7 @@ fn start_line_comment_edit(
     *scroll_cache = None;
     let layout = page::diff::diff_changed_line_layout(
         diff,
-        line_comments,
+        (line_comments, *layout_mode),
         *file_explorer_selected_index,
         content_area,
         render_cache_store.diff_layout_cache(),
@@ -569,6 +732,7 @@ struct DiffKeyNavigation<'a> {
     diff: &'a str,
     file_explorer_selected_index: &'a mut usize,
     focus: &'a mut DiffFocus,
+    layout_mode: &'a mut DiffLayoutMode,
     line_comments: &'a mut DiffLineComments,
     preview: &'a mut DiffPreview,
     review_comments: &'a mut Option<DiffReviewComments>,
@@ -585,6 +749,7 @@ impl DiffKeyNavigation<'_> {
             diff: self.diff,
             file_explorer_selected_index: *self.file_explorer_selected_index,
             focus: self.focus,
+            layout_mode: *self.layout_mode,
             line_comments: self.line_comments,
             preview: self.preview,
             review_comments_are_visible: self.review_comments.is_some(),
@@ -595,50 +760,56 @@ impl DiffKeyNavigation<'_> {
     }
 }
 
-/// Applies one file-tree or right-pane navigation key.
+/// Switches between unified and side-by-side layouts without losing the
+/// selected changed line.
+fn toggle_layout_mode(
+    navigation: &mut DiffKeyNavigation<'_>,
+    content_area: Rect,
+    render_cache_store: &RenderCacheStore,
+) {
+    *navigation.layout_mode = navigation.layout_mode.toggled();
+    let mut content_navigation = navigation.content_navigation();
+    keep_selection_visible_after_layout_change(
+        &mut content_navigation,
+        content_area,
+        render_cache_store,
+    );
+}
+
+/// Applies one file-tree or right-pane navigation action.
+///
+/// Arrow keys, `Enter`, `Esc`, and `Left` keep their fixed navigation roles
+/// when they are not bound to another action.
 fn apply_navigation_key(
     app: &App,
     render_cache_store: &RenderCacheStore,
     content_area: Rect,
+    action: Option<DiffAction>,
     key: KeyEvent,
     navigation: &mut DiffKeyNavigation<'_>,
 ) -> bool {
-    if apply_unfocused_scroll_key(render_cache_store, content_area, key, navigation) {
+    if apply_unfocused_scroll_key(render_cache_store, content_area, action, key, navigation) {
         return false;
     }
 
-    match key.code {
-        KeyCode::Char(character @ ('j' | 'k'))
-            if *navigation.focus == DiffFocus::Files && is_plain_char_key(key, character) =>
-        {
-            let content = render_cache_store
-                .diff_layout_cache()
-                .content(navigation.diff);
-            let new_index = selected_index_after_key(
-                character,
-                *navigation.file_explorer_selected_index,
-                content.item_count(),
+    let is_files_focused = *navigation.focus == DiffFocus::Files;
+    match (action, key.code) {
+        (Some(DiffAction::SelectNext), _) if is_files_focused => {
+            return select_file(render_cache_store, navigation, DiffContentDirection::Next);
+        }
+        (Some(DiffAction::SelectPrevious), _) if is_files_focused => {
+            return select_file(
+                render_cache_store,
+                navigation,
+                DiffContentDirection::Previous,
             );
-            if *navigation.file_explorer_selected_index != new_index {
-                *navigation.file_explorer_selected_index = new_index;
-                navigation.line_comments.clear_comment_selection();
-                *navigation.scroll_cache = None;
-                *navigation.scroll_offset = 0;
-                *navigation.selected_diff_line_index = 0;
-
-                return true;
-            }
         }
-        KeyCode::Enter | KeyCode::Char('l')
-            if *navigation.focus == DiffFocus::Files
-                && (key.code == KeyCode::Enter || is_plain_char_key(key, 'l')) =>
-        {
+        (Some(DiffAction::Open), _) | (None, KeyCode::Enter) if is_files_focused => {
             let mut content_navigation = navigation.content_navigation();
             focus_selected_file_changes(&mut content_navigation, content_area, render_cache_store);
         }
-        KeyCode::Down | KeyCode::Char('J' | 'j')
-            if *navigation.focus == DiffFocus::Content
-                && is_content_navigation_key(key, KeyCode::Down, 'j') =>
+        (Some(DiffAction::SelectNext | DiffAction::ScrollDown), _) | (None, KeyCode::Down)
+            if !is_files_focused =>
         {
             let mut content_navigation = navigation.content_navigation();
             move_content_selection(
@@ -648,9 +819,8 @@ fn apply_navigation_key(
                 DiffContentDirection::Next,
             );
         }
-        KeyCode::Up | KeyCode::Char('K' | 'k')
-            if *navigation.focus == DiffFocus::Content
-                && is_content_navigation_key(key, KeyCode::Up, 'k') =>
+        (Some(DiffAction::SelectPrevious | DiffAction::ScrollUp), _) | (None, KeyCode::Up)
+            if !is_files_focused =>
         {
             let mut content_navigation = navigation.content_navigation();
             move_content_selection(
@@ -660,19 +830,13 @@ fn apply_navigation_key(
                 DiffContentDirection::Previous,
             );
         }
-        KeyCode::Esc | KeyCode::Left if *navigation.focus == DiffFocus::Content => {
-            navigation.line_comments.cancel_selection();
-            *navigation.focus = DiffFocus::Files;
-        }
-        KeyCode::Char(character @ ('f' | 'h'))
-            if *navigation.focus == DiffFocus::Content && is_plain_char_key(key, character) =>
+        (Some(DiffAction::Files), _) | (None, KeyCode::Esc | KeyCode::Left)
+            if !is_files_focused =>
         {
             navigation.line_comments.cancel_selection();
             *navigation.focus = DiffFocus::Files;
         }
-        KeyCode::Char('p')
-            if *navigation.focus == DiffFocus::Files && is_plain_char_key(key, 'p') =>
-        {
+        (Some(DiffAction::Preview), _) if is_files_focused => {
             if let Some(updated_preview) = toggle_selected_preview(
                 app,
                 render_cache_store.diff_layout_cache(),
@@ -686,12 +850,12 @@ fn apply_navigation_key(
                 *navigation.scroll_offset = 0;
             }
         }
-        KeyCode::Char('c')
-            if *navigation.focus == DiffFocus::Files
-                && is_plain_char_key(key, 'c')
-                && navigation.review_comments.is_some() =>
+        (Some(DiffAction::Layout), _) => {
+            toggle_layout_mode(navigation, content_area, render_cache_store);
+        }
+        (Some(DiffAction::Comments), _)
+            if is_files_focused && navigation.review_comments.is_some() =>
         {
-            *navigation.focus = DiffFocus::Files;
             focus_review_comments(
                 navigation.review_comments,
                 navigation.scroll_cache,
@@ -704,27 +868,49 @@ fn apply_navigation_key(
     false
 }
 
-/// Applies a row-scroll key without moving focus out of the Files pane.
+/// Moves the file-explorer selection one row, wrapping at either end.
+///
+/// Returns `true` when the selected file changed.
+fn select_file(
+    render_cache_store: &RenderCacheStore,
+    navigation: &mut DiffKeyNavigation<'_>,
+    direction: DiffContentDirection,
+) -> bool {
+    let content = render_cache_store
+        .diff_layout_cache()
+        .content(navigation.diff);
+    let new_index = selected_index_after_move(
+        direction,
+        *navigation.file_explorer_selected_index,
+        content.item_count(),
+    );
+    if *navigation.file_explorer_selected_index == new_index {
+        return false;
+    }
+
+    *navigation.file_explorer_selected_index = new_index;
+    navigation.line_comments.clear_comment_selection();
+    *navigation.scroll_cache = None;
+    *navigation.scroll_offset = 0;
+    *navigation.selected_diff_line_index = 0;
+
+    true
+}
+
+/// Applies a row-scroll action without moving focus out of the Files pane.
 fn apply_unfocused_scroll_key(
     render_cache_store: &RenderCacheStore,
     content_area: Rect,
+    action: Option<DiffAction>,
     key: KeyEvent,
     navigation: &mut DiffKeyNavigation<'_>,
 ) -> bool {
     if *navigation.focus != DiffFocus::Files {
         return false;
     }
-    let direction = match key.code {
-        KeyCode::Down | KeyCode::Char('J' | 'j')
-            if is_unfocused_scroll_key(key, KeyCode::Down, 'j') =>
-        {
-            DiffContentDirection::Next
-        }
-        KeyCode::Up | KeyCode::Char('K' | 'k')
-            if is_unfocused_scroll_key(key, KeyCode::Up, 'k') =>
-        {
-            DiffContentDirection::Previous
-        }
+    let direction = match (action, key.code) {
+        (Some(DiffAction::ScrollDown), _) | (None, KeyCode::Down) => DiffContentDirection::Next,
+        (Some(DiffAction::ScrollUp), _) | (None, KeyCode::Up) => DiffContentDirection::Previous,
         _ => return false,
     };
     let mut content_navigation = navigation.content_navigation();
@@ -738,28 +924,12 @@ fn apply_unfocused_scroll_key(
     true
 }
 
-/// Returns whether a key scrolls the right pane while Files stays focused.
-fn is_unfocused_scroll_key(key: KeyEvent, arrow_key: KeyCode, character: char) -> bool {
-    key.code == arrow_key || is_shift_char_key(key, character)
-}
-
-/// Returns whether a key moves through the active right-hand content pane.
-fn is_content_navigation_key(key: KeyEvent, arrow_key: KeyCode, character: char) -> bool {
-    if key.code == arrow_key {
-        return true;
-    }
-    if is_plain_char_key(key, character) {
-        return true;
-    }
-
-    is_shift_char_key(key, character)
-}
-
 /// Mutable diff-pane navigation values shared by focus and cursor movement.
 struct DiffContentNavigation<'a> {
     diff: &'a str,
     file_explorer_selected_index: usize,
     focus: &'a mut DiffFocus,
+    layout_mode: DiffLayoutMode,
     line_comments: &'a mut DiffLineComments,
     preview: &'a DiffPreview,
     review_comments_are_visible: bool,
@@ -803,7 +973,7 @@ fn focus_selected_file_changes(
     }
     let changed_line_layout = page::diff::diff_changed_line_layout(
         navigation.diff,
-        navigation.line_comments,
+        (navigation.line_comments, navigation.layout_mode),
         navigation.file_explorer_selected_index,
         content_area,
         render_cache_store.diff_layout_cache(),
@@ -856,7 +1026,7 @@ fn move_content_selection(
 
     let changed_line_layout = page::diff::diff_changed_line_layout(
         navigation.diff,
-        navigation.line_comments,
+        (navigation.line_comments, navigation.layout_mode),
         navigation.file_explorer_selected_index,
         content_area,
         render_cache_store.diff_layout_cache(),
@@ -902,6 +1072,46 @@ fn move_content_selection(
         .unwrap_or(*navigation.scroll_offset);
 }
 
+/// Re-anchors the viewport after switching between unified and split layout.
+///
+/// The changed-line cursor keeps its index because both layouts order changed
+/// lines identically; only its rendered rows move.
+fn keep_selection_visible_after_layout_change(
+    navigation: &mut DiffContentNavigation<'_>,
+    content_area: Rect,
+    render_cache_store: &RenderCacheStore,
+) {
+    if selected_preview_is_visible(
+        navigation.diff,
+        navigation.file_explorer_selected_index,
+        render_cache_store.diff_layout_cache(),
+        navigation.preview,
+    ) {
+        return;
+    }
+    *navigation.scroll_cache = None;
+    if *navigation.focus != DiffFocus::Content {
+        *navigation.scroll_offset = 0;
+
+        return;
+    }
+
+    let changed_line_layout = page::diff::diff_changed_line_layout(
+        navigation.diff,
+        (navigation.line_comments, navigation.layout_mode),
+        navigation.file_explorer_selected_index,
+        content_area,
+        render_cache_store.diff_layout_cache(),
+    );
+    *navigation.scroll_offset = changed_line_layout
+        .content_selection_scroll_offset(
+            *navigation.selected_diff_line_index,
+            navigation.line_comments.selected_comment_index(),
+            0,
+        )
+        .unwrap_or_default();
+}
+
 /// Scrolls the selected file or preview by one rendered row without changing
 /// pane focus or the selected changed-line cursor.
 fn scroll_content_by_row(
@@ -915,6 +1125,7 @@ fn scroll_content_by_row(
             content_area,
             diff: navigation.diff,
             diff_layout_cache: render_cache_store.diff_layout_cache(),
+            layout_mode: navigation.layout_mode,
             line_comments: navigation.line_comments,
             markdown_render_cache: render_cache_store.markdown_render_cache(),
             preview: navigation.preview,
@@ -979,13 +1190,18 @@ fn refresh_selected_preview(
     );
 }
 
-/// Returns the wrapped explorer selection for a plain `j` or `k` key.
-fn selected_index_after_key(character: char, current_index: usize, item_count: usize) -> usize {
-    if character == 'j' {
-        return FileExplorer::next_selected_index(current_index, item_count);
+/// Returns the wrapped explorer selection after one move in `direction`.
+fn selected_index_after_move(
+    direction: DiffContentDirection,
+    current_index: usize,
+    item_count: usize,
+) -> usize {
+    match direction {
+        DiffContentDirection::Next => FileExplorer::next_selected_index(current_index, item_count),
+        DiffContentDirection::Previous => {
+            FileExplorer::previous_selected_index(current_index, item_count)
+        }
     }
-
-    FileExplorer::previous_selected_index(current_index, item_count)
 }
 
 /// Toggles preview for the selected row, ignoring unsupported toggle-on keys.
@@ -1073,31 +1289,12 @@ fn start_selected_preview_load(
     DiffPreview::Loading { path, request_id }
 }
 
-/// Returns true when the key event is a plain character key with no
-/// modifiers.
-fn is_plain_char_key(key: KeyEvent, character: char) -> bool {
-    key.code == KeyCode::Char(character) && key.modifiers == KeyModifiers::NONE
-}
-
-/// Returns true when the key event is a shifted character key, accepting both
-/// uppercase and lowercase char payloads emitted by terminals.
-fn is_shift_char_key(key: KeyEvent, character: char) -> bool {
-    let lowercase_character = character.to_ascii_lowercase();
-    let uppercase_character = character.to_ascii_uppercase();
-
-    key.modifiers == KeyModifiers::SHIFT
-        && matches!(
-            key.code,
-            KeyCode::Char(pressed)
-                if pressed == lowercase_character || pressed == uppercase_character
-        )
-}
-
 /// Inputs used to resolve and cache the active diff scroll limit.
 struct DiffScrollLimitInput<'a> {
     content_area: Rect,
     diff: &'a str,
     diff_layout_cache: &'a page::diff::DiffLayoutCache,
+    layout_mode: DiffLayoutMode,
     line_comments: &'a DiffLineComments,
     markdown_render_cache: &'a crate::ui::markdown::MarkdownRenderCache,
     preview: &'a DiffPreview,
@@ -1118,7 +1315,7 @@ fn diff_max_scroll_offset(
 
     let max_scroll_offset = page::diff::diff_view_max_scroll_offset(
         input.diff,
-        input.line_comments,
+        (input.line_comments, input.layout_mode),
         input.selected_index,
         input.content_area,
         input.diff_layout_cache,
@@ -1230,6 +1427,7 @@ mod tests {
             diff: diff.to_string(),
             file_explorer_selected_index,
             focus,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             preview,
             review_comments: None,
@@ -1345,6 +1543,7 @@ mod tests {
             scroll_offset: 7,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -1389,6 +1588,7 @@ mod tests {
             scroll_offset: 7,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -1426,6 +1626,7 @@ mod tests {
             scroll_offset: 0,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Content,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -1495,6 +1696,7 @@ mod tests {
                 diff: scrollable_diff_fixture(),
                 file_explorer_selected_index: 1,
                 focus: DiffFocus::Files,
+                layout_mode: DiffLayoutMode::default(),
                 line_comments: DiffLineComments::default(),
                 preview: DiffPreview::default(),
                 review_comments: None,
@@ -1536,6 +1738,7 @@ mod tests {
             diff: diff.clone(),
             file_explorer_selected_index: 7,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             preview: DiffPreview::default(),
             review_comments: None,
@@ -1578,6 +1781,7 @@ mod tests {
             diff: scrollable_diff_fixture(),
             file_explorer_selected_index: 1,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             preview: DiffPreview::default(),
             review_comments: None,
@@ -1695,6 +1899,7 @@ mod tests {
             diff: "diff --git a/src/main.rs b/src/main.rs\n+review();\n".to_string(),
             file_explorer_selected_index: 1,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -1777,6 +1982,7 @@ mod tests {
             diff: scrollable_diff_fixture(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Content,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             preview: DiffPreview::default(),
             review_comments: None,
@@ -1848,6 +2054,7 @@ mod tests {
             scroll_offset: 3,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Content,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 2,
             preview: DiffPreview::default(),
@@ -1875,6 +2082,70 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_handle_t_toggles_split_layout_and_keeps_selected_line_visible() {
+        // Arrange
+        let (mut app, _base_dir) = crate::test_support::new_test_app().await;
+        app.mode = AppMode::Diff {
+            session_id: "session-id".into(),
+            diff: scrollable_diff_fixture(),
+            scroll_offset: 0,
+            file_explorer_selected_index: 0,
+            focus: DiffFocus::Content,
+            layout_mode: DiffLayoutMode::Unified,
+            line_comments: DiffLineComments::default(),
+            selected_diff_line_index: 30,
+            preview: DiffPreview::default(),
+            review_comments: None,
+            restore: None,
+            scroll_cache: None,
+        };
+        let toggle_key = KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE);
+
+        // Act
+        handle(&mut app, TEST_TERMINAL_SIZE, toggle_key);
+        let AppMode::Diff {
+            diff,
+            layout_mode: split_layout_mode,
+            scroll_offset,
+            selected_diff_line_index,
+            ..
+        } = &app.mode
+        else {
+            unreachable!("diff mode should stay active");
+        };
+        let split_layout = page::diff::diff_changed_line_layout(
+            diff,
+            (&DiffLineComments::default(), *split_layout_mode),
+            0,
+            TEST_TERMINAL_SIZE,
+            &page::diff::DiffLayoutCache::default(),
+        );
+        let selected_range = split_layout.changed_line_ranges[30].clone();
+        let viewport_end =
+            usize::from(*scroll_offset) + usize::from(split_layout.render_layout.viewport_height);
+        let (split_layout_mode, scroll_offset, selected_diff_line_index) = (
+            *split_layout_mode,
+            *scroll_offset,
+            *selected_diff_line_index,
+        );
+        handle(&mut app, TEST_TERMINAL_SIZE, toggle_key);
+
+        // Assert
+        assert_eq!(split_layout_mode, DiffLayoutMode::Split);
+        assert_eq!(selected_diff_line_index, 30);
+        assert!(usize::from(scroll_offset) <= selected_range.start);
+        assert!(selected_range.end <= viewport_end);
+        assert!(matches!(
+            app.mode,
+            AppMode::Diff {
+                layout_mode: DiffLayoutMode::Unified,
+                selected_diff_line_index: 30,
+                ..
+            }
+        ));
+    }
+
     #[tokio::test]
     async fn test_handle_file_focus_scrolls_with_arrows_and_shift_j_k() {
         // Arrange
@@ -1885,6 +2156,7 @@ mod tests {
             scroll_offset: 0,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 7,
             preview: DiffPreview::default(),
@@ -1926,6 +2198,7 @@ mod tests {
             scroll_offset: 0,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Content,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -1963,6 +2236,7 @@ mod tests {
             scroll_offset: 0,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Content,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -2019,6 +2293,7 @@ mod tests {
             scroll_offset: 10,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -2054,6 +2329,7 @@ mod tests {
             scroll_offset: 10,
             file_explorer_selected_index: 1,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -2090,6 +2366,7 @@ mod tests {
             scroll_offset: 10,
             file_explorer_selected_index: 1,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -2126,6 +2403,7 @@ mod tests {
             scroll_offset: 10,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -2162,6 +2440,7 @@ mod tests {
             scroll_offset: 5,
             file_explorer_selected_index: 3,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::Ready {
@@ -2212,6 +2491,7 @@ mod tests {
             scroll_offset: u16::MAX,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Content,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 39,
             preview: DiffPreview::default(),
@@ -2250,6 +2530,7 @@ mod tests {
             scroll_offset: u16::MAX,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Content,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 39,
             preview: DiffPreview::default(),
@@ -2291,6 +2572,7 @@ mod tests {
             scroll_offset: 0,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -2360,6 +2642,7 @@ mod tests {
             scroll_offset: 0,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -2407,6 +2690,7 @@ mod tests {
             scroll_offset: 0,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -2438,6 +2722,7 @@ mod tests {
             scroll_offset: 3,
             file_explorer_selected_index: 1,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -2527,6 +2812,7 @@ mod tests {
             scroll_offset: 3,
             file_explorer_selected_index: 1,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -2634,6 +2920,7 @@ mod tests {
             scroll_offset: 4,
             file_explorer_selected_index: 2,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -2669,6 +2956,7 @@ mod tests {
             diff: "diff output".to_string(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -2705,6 +2993,99 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_handle_revert_key_confirms_selected_line_on_second_press() {
+        // Arrange
+        let (mut app, _base_dir) = preview_test_app(ag_git::MockGitClient::new()).await;
+        let diff = concat!(
+            "diff --git a/src/main.rs b/src/main.rs\n",
+            "--- a/src/main.rs\n",
+            "+++ b/src/main.rs\n",
+            "@@ -1 +1,3 @@\n",
+            " fn main() {}\n",
+            "+keep();\n",
+            "+drop();\n",
+        );
+        app.mode = diff_mode_fixture(diff, 1, DiffFocus::Content, DiffPreview::default());
+        if let AppMode::Diff {
+            selected_diff_line_index,
+            ..
+        } = &mut app.mode
+        {
+            *selected_diff_line_index = 1;
+        }
+        let render_cache_store = RenderCacheStore::default();
+        let revert_key = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE);
+
+        // Act
+        let first_outcome = handle_revert_key(&mut app, &render_cache_store, revert_key);
+        let second_outcome = handle_revert_key(&mut app, &render_cache_store, revert_key);
+
+        // Assert
+        assert_eq!(first_outcome, DiffRevertKeyOutcome::Handled);
+        assert_eq!(
+            second_outcome,
+            DiffRevertKeyOutcome::Confirmed {
+                revert_patch: diff_util::DiffRevertPatch {
+                    patch: concat!(
+                        "diff --git a/src/main.rs b/src/main.rs\n",
+                        "--- a/src/main.rs\n",
+                        "+++ b/src/main.rs\n",
+                        "@@ -1,2 +1,3 @@\n",
+                        " fn main() {}\n",
+                        " keep();\n",
+                        "+drop();\n",
+                    )
+                    .to_string(),
+                    summary: vec!["src/main.rs: discarded 1 added line (new line 3)".to_string()],
+                },
+                session_id: "session-id".into(),
+            }
+        );
+        assert!(matches!(
+            &app.mode,
+            AppMode::Diff { line_comments, .. } if line_comments.pending_revert.is_none()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_revert_key_cancels_pending_revert_on_other_key() {
+        // Arrange
+        let (mut app, _base_dir) = preview_test_app(ag_git::MockGitClient::new()).await;
+        let diff = concat!(
+            "diff --git a/src/main.rs b/src/main.rs\n",
+            "@@ -1 +1,2 @@\n",
+            " fn main() {}\n",
+            "+drop();\n",
+        );
+        app.mode = diff_mode_fixture(diff, 1, DiffFocus::Content, DiffPreview::default());
+        let render_cache_store = RenderCacheStore::default();
+
+        // Act
+        let armed_outcome = handle_revert_key(
+            &mut app,
+            &render_cache_store,
+            KeyEvent::new(KeyCode::Char('X'), KeyModifiers::SHIFT),
+        );
+        let cancel_outcome = handle_revert_key(
+            &mut app,
+            &render_cache_store,
+            KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE),
+        );
+
+        // Assert
+        assert_eq!(armed_outcome, DiffRevertKeyOutcome::Handled);
+        assert_eq!(cancel_outcome, DiffRevertKeyOutcome::Handled);
+        assert!(matches!(
+            &app.mode,
+            AppMode::Diff {
+                focus: DiffFocus::Content,
+                line_comments,
+                ..
+            } if line_comments.pending_revert.is_none()
+        ));
+    }
+
     #[tokio::test]
     async fn test_handle_collects_inline_comments_before_building_next_turn() {
         // Arrange
@@ -3398,6 +3779,7 @@ mod tests {
             diff: "diff --git a/README.md b/README.md\n+preview".to_string(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -3454,6 +3836,7 @@ mod tests {
             diff: "diff --git a/docs/README.md b/docs/README.md\n+preview".to_string(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -3490,6 +3873,7 @@ mod tests {
             diff: "diff --git a/docs/README.md b/docs/README.md\n+preview".to_string(),
             file_explorer_selected_index: 1,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::Ready {
@@ -3545,6 +3929,7 @@ mod tests {
             .to_string(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::Ready {
@@ -3596,6 +3981,7 @@ mod tests {
             diff: "diff --git a/README.md b/README.md\n+preview".to_string(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -3647,6 +4033,7 @@ mod tests {
                 content_area: TEST_TERMINAL_SIZE,
                 diff: &diff,
                 diff_layout_cache: &diff_layout_cache,
+                layout_mode: DiffLayoutMode::default(),
                 line_comments: &line_comments,
                 markdown_render_cache: &markdown_render_cache,
                 preview: &preview,
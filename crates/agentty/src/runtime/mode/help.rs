@@ -36,7 +36,7 @@ mod tests {
     use crossterm::event::KeyModifiers;
 
     use super::*;
-    use crate::presentation::app_mode::{DiffFocus, DiffLineComments, HelpContext};
+    use crate::presentation::app_mode::{DiffFocus, DiffLayoutMode, DiffLineComments, HelpContext};
     use crate::presentation::help_action::{HelpAction, ViewSessionState};
 
     #[tokio::test]
@@ -67,7 +67,9 @@ mod tests {
         let (mut app, _base_dir) = crate::test_support::new_test_app().await;
         app.mode = AppMode::Help {
             context: HelpContext::View {
+                can_fix_review_checks: false,
                 can_fork_session: true,
+                can_mark_review_ready: false,
                 can_merge_session_branch: true,
                 can_mutate_session_branch: true,
                 can_open_worktree: true,
@@ -177,6 +179,7 @@ mod tests {
                 session_id: "s1".into(),
                 diff: "diff content".to_string(),
                 focus: DiffFocus::Content,
+                layout_mode: DiffLayoutMode::default(),
                 line_comments: DiffLineComments::default(),
                 preview: crate::presentation::app_mode::DiffPreview::default(),
                 review_comments: None,
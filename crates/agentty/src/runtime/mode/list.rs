@@ -1,7 +1,7 @@
 use std::io;
 
 use ag_tui_text::text_util::inline_text;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent};
 
 use crate::app::{App, Tab};
 use crate::domain::input::{InputCommand, InputState};
@@ -10,11 +10,12 @@ use crate::presentation::app_mode::{AppMode, ChatFocus, ConfirmationIntent, Help
 use crate::presentation::help_action::{
     HelpAction, project_list_actions, session_list_actions, settings_actions,
 };
+use crate::presentation::keymap::{KeymapMode, ListAction};
 use crate::presentation::prompt::{PromptAttachmentState, PromptHistoryState};
 use crate::presentation::settings::{SettingsAction, SettingsInput};
-use crate::runtime::EventResult;
 use crate::runtime::mode::confirmation::DEFAULT_OPTION_INDEX;
 use crate::runtime::mode::input_key;
+use crate::runtime::{EventResult, keymap};
 
 /// Handles key input while the app is in list mode.
 ///
@@ -40,8 +41,8 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> io::Result<EventResu
         return Ok(EventResult::Continue);
     }
 
-    match key.code {
-        KeyCode::Char('q') => {
+    match (keymap::resolve_action(app, key), key.code) {
+        (Some(ListAction::Quit), _) => {
             app.mode = AppMode::Confirmation {
                 confirmation_intent: ConfirmationIntent::Quit,
                 confirmation_message: "Quit agentty?".to_string(),
@@ -53,38 +54,34 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> io::Result<EventResu
 
             return Ok(EventResult::Continue);
         }
-        KeyCode::Tab => {
+        (Some(ListAction::NextTab), _) => {
             app.next_tab();
             app.persist_current_tab().await;
         }
-        KeyCode::BackTab => {
+        (Some(ListAction::PreviousTab), _) => {
             app.previous_tab();
             app.persist_current_tab().await;
         }
-        KeyCode::Char('a')
-            if app.tabs.current() == Tab::Sessions && key.modifiers == KeyModifiers::NONE =>
-        {
+        (Some(ListAction::NewSession), _) if app.tabs.current() == Tab::Sessions => {
             open_session_creation_flow(app).await;
         }
-        KeyCode::Char('p')
-            if app.tabs.current() == Tab::Sessions && key.modifiers == KeyModifiers::NONE =>
-        {
+        (Some(ListAction::SwitchProject), _) if app.tabs.current() == Tab::Sessions => {
             app.mode = AppMode::ProjectSwitcher {
                 selected_option_index: 0,
             };
         }
-        KeyCode::Char('j') | KeyCode::Down => match app.tabs.current() {
+        (Some(ListAction::SelectNext), _) | (None, KeyCode::Down) => match app.tabs.current() {
             Tab::Projects => app.next_project(),
             Tab::Sessions => app.next(),
             Tab::Settings => apply_settings_action(app, SettingsAction::Next).await,
         },
-        KeyCode::Char('k') | KeyCode::Up => match app.tabs.current() {
+        (Some(ListAction::SelectPrevious), _) | (None, KeyCode::Up) => match app.tabs.current() {
             Tab::Projects => app.previous_project(),
             Tab::Sessions => app.previous(),
             Tab::Settings => apply_settings_action(app, SettingsAction::Previous).await,
         },
-        KeyCode::Enter => return handle_enter_key(app).await,
-        KeyCode::Char('c') if app.tabs.current() == Tab::Sessions => {
+        (Some(ListAction::Open), _) => return handle_enter_key(app).await,
+        (Some(ListAction::CancelSession), _) if app.tabs.current() == Tab::Sessions => {
             let selected_session = app.selected_session().and_then(|session| {
                 session
                     .allows_cancel_action()
@@ -104,8 +101,8 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> io::Result<EventResu
                 };
             }
         }
-        KeyCode::Char(character) if character.eq_ignore_ascii_case(&'s') => sync_list_context(app),
-        KeyCode::Char('?') => {
+        (Some(ListAction::Sync), _) => sync_list_context(app),
+        (Some(ListAction::Help), _) => {
             open_list_help_overlay(app);
         }
         _ => {}
@@ -217,6 +214,7 @@ async fn apply_settings_action(app: &mut App, action: SettingsAction) {
 
     if let Some(operation) = operation {
         app.settings.apply_operation(operation).await;
+        app.sync_forge_settings();
     }
 }
 
@@ -235,8 +233,15 @@ fn open_list_help_overlay(app: &mut App) {
     };
 }
 
-/// Projects current list-mode action availability into keybinding entries.
+/// Projects current list-mode action availability into keybinding entries
+/// labeled with the app keymap.
 fn list_keybindings(app: &App) -> Vec<HelpAction> {
+    app.keymap
+        .remap_help_actions(KeymapMode::List, built_in_list_keybindings(app))
+}
+
+/// Projects current list-mode action availability with built-in key labels.
+fn built_in_list_keybindings(app: &App) -> Vec<HelpAction> {
     if app.tabs.current() == Tab::Projects {
         return project_list_actions();
     }
@@ -935,7 +940,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_settings_previous_key_wraps_to_launch_configuration_row() {
+    async fn test_settings_previous_key_wraps_to_forge_backend_row() {
         // Arrange
         let (mut app, _base_dir) = crate::test_support::new_test_app().await;
         app.tabs.set(Tab::Settings);
@@ -954,7 +959,7 @@ mod tests {
             app.settings_presentation
                 .snapshot(&app.settings.view())
                 .selected_row_index,
-            Some(7)
+            Some(15)
         );
     }
 
@@ -0,0 +1,406 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{App, OrchestrationBoardAction};
+use crate::domain::session::SessionId;
+use crate::presentation::app_mode::{
+    AppMode, OrchestrationTaskDeleteConfirmation, OrchestrationTaskEditor, OrchestrationTaskField,
+};
+use crate::runtime::EventResult;
+use crate::runtime::mode::confirmation::{self, ConfirmationDecision, DEFAULT_OPTION_INDEX};
+use crate::runtime::mode::input_key::{self, InputCapabilities};
+
+/// Opens the full-screen campaign board for one orchestrator session.
+///
+/// Leaves the current mode untouched when the session owns no campaign yet.
+pub(crate) async fn open(app: &mut App, session_id: &SessionId) {
+    let Some(board) = app.load_orchestration_board(session_id).await else {
+        return;
+    };
+
+    app.mode = AppMode::OrchestrationBoard {
+        board,
+        delete_confirmation: None,
+        editor: None,
+        expanded: false,
+        notice: None,
+        selected_index: 0,
+        session_id: session_id.clone(),
+    };
+}
+
+/// Handles key input while the campaign board is visible.
+pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> EventResult {
+    let AppMode::OrchestrationBoard {
+        delete_confirmation,
+        editor,
+        notice,
+        ..
+    } = &mut app.mode
+    else {
+        return EventResult::Continue;
+    };
+    *notice = None;
+
+    if delete_confirmation.is_some() {
+        handle_delete_confirmation_key(app, key).await;
+    } else if editor.is_some() {
+        handle_editor_key(app, key).await;
+    } else {
+        handle_board_key(app, key).await;
+    }
+
+    EventResult::Continue
+}
+
+/// Applies navigation and plan-edit keys while no task editor is open.
+async fn handle_board_key(app: &mut App, key: KeyEvent) {
+    let AppMode::OrchestrationBoard {
+        board,
+        delete_confirmation,
+        editor,
+        expanded,
+        notice,
+        selected_index,
+        session_id,
+    } = &mut app.mode
+    else {
+        return;
+    };
+    let selected_task = board.tasks.get(*selected_index);
+    let action = match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.mode = AppMode::View {
+                scroll_offset: None,
+                session_id: session_id.clone(),
+            };
+
+            return;
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            *selected_index = (*selected_index + 1).min(board.tasks.len().saturating_sub(1));
+
+            return;
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            *selected_index = selected_index.saturating_sub(1);
+
+            return;
+        }
+        KeyCode::Enter => {
+            *expanded = !*expanded;
+
+            return;
+        }
+        KeyCode::Char('e') => {
+            match selected_task {
+                Some(task) if task.is_editable() => {
+                    *editor = Some(OrchestrationTaskEditor::from_task(task));
+                }
+                Some(_) => *notice = Some("Only proposed tasks can be edited.".to_string()),
+                None => {}
+            }
+
+            return;
+        }
+        KeyCode::Char('d') => {
+            match selected_task {
+                Some(task) if task.is_editable() => {
+                    *delete_confirmation = Some(OrchestrationTaskDeleteConfirmation {
+                        selected_confirmation_index: DEFAULT_OPTION_INDEX,
+                        task_id: task.id,
+                        task_title: task.title.clone(),
+                    });
+                }
+                Some(_) => *notice = Some("Only proposed tasks can be deleted.".to_string()),
+                None => {}
+            }
+
+            return;
+        }
+        KeyCode::Char('K' | 'J') => selected_task.map(|task| OrchestrationBoardAction::Move {
+            earlier: key.code == KeyCode::Char('K'),
+            task_id: task.id,
+        }),
+        KeyCode::Char('r') => {
+            selected_task.map(|task| OrchestrationBoardAction::Retry { task_id: task.id })
+        }
+        KeyCode::Char('s') => {
+            selected_task.map(|task| OrchestrationBoardAction::SkipIntegration { task_id: task.id })
+        }
+        _ => None,
+    };
+    let Some(action) = action else {
+        return;
+    };
+    let session_id = session_id.clone();
+
+    apply_action(app, &session_id, action).await;
+}
+
+/// Applies yes/no keys while a task deletion awaits confirmation.
+///
+/// Deletes the task only after an explicit confirmation; any rejection or
+/// cancellation keeps the task and returns to the board.
+async fn handle_delete_confirmation_key(app: &mut App, key: KeyEvent) {
+    let AppMode::OrchestrationBoard {
+        delete_confirmation,
+        session_id,
+        ..
+    } = &mut app.mode
+    else {
+        return;
+    };
+    let Some(pending_confirmation) = delete_confirmation.as_mut() else {
+        return;
+    };
+
+    match confirmation::handle(&mut pending_confirmation.selected_confirmation_index, key) {
+        ConfirmationDecision::Confirm => {
+            let action = OrchestrationBoardAction::Delete {
+                task_id: pending_confirmation.task_id,
+            };
+            let session_id = session_id.clone();
+            *delete_confirmation = None;
+
+            apply_action(app, &session_id, action).await;
+        }
+        ConfirmationDecision::Reject | ConfirmationDecision::Cancel => {
+            *delete_confirmation = None;
+        }
+        ConfirmationDecision::Continue => {}
+    }
+}
+
+/// Applies field editing keys while a proposed task is being edited.
+async fn handle_editor_key(app: &mut App, key: KeyEvent) {
+    let AppMode::OrchestrationBoard {
+        editor, session_id, ..
+    } = &mut app.mode
+    else {
+        return;
+    };
+    if key.code == KeyCode::Esc {
+        *editor = None;
+
+        return;
+    }
+    let Some(task_editor) = editor.as_mut() else {
+        return;
+    };
+
+    match key.code {
+        KeyCode::Tab => task_editor.focus = task_editor.focus.next(),
+        KeyCode::Enter if !input_key::should_insert_newline(key) => {
+            let action = OrchestrationBoardAction::Edit {
+                draft: task_editor.draft(),
+                task_id: task_editor.task_id,
+            };
+            let session_id = session_id.clone();
+            if apply_action(app, &session_id, action).await
+                && let AppMode::OrchestrationBoard { editor, .. } = &mut app.mode
+            {
+                *editor = None;
+            }
+        }
+        _ => {
+            let capabilities = if task_editor.focus == OrchestrationTaskField::Title {
+                InputCapabilities::SINGLE_LINE
+            } else {
+                InputCapabilities::MULTILINE
+            };
+            if let Some(command) = input_key::command_for_key(key, capabilities) {
+                task_editor.focused_input_mut().apply(command);
+            }
+        }
+    }
+}
+
+/// Persists one board action, reloads the board, and surfaces rejections.
+///
+/// Returns whether the action was saved.
+async fn apply_action(
+    app: &mut App,
+    session_id: &SessionId,
+    action: OrchestrationBoardAction,
+) -> bool {
+    let result = app
+        .apply_orchestration_board_action(session_id, action)
+        .await;
+    app.reload_orchestration_board().await;
+    let Err(error) = result else {
+        return true;
+    };
+    if let AppMode::OrchestrationBoard { notice, .. } = &mut app.mode {
+        *notice = Some(error);
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyModifiers;
+
+    use super::*;
+    use crate::domain::orchestration::{
+        OrchestrationBoard, OrchestrationBoardTask, OrchestrationStatus, OrchestrationTaskKind,
+        OrchestrationTaskStatus,
+    };
+
+    fn board_task(
+        id: i64,
+        task_key: &str,
+        status: OrchestrationTaskStatus,
+    ) -> OrchestrationBoardTask {
+        OrchestrationBoardTask {
+            acceptance_criteria: vec![format!("{task_key} works")],
+            child_session_id: None,
+            depends_on: Vec::new(),
+            id,
+            kind: OrchestrationTaskKind::Implementation,
+            last_error: None,
+            prompt: format!("Implement {task_key}"),
+            status,
+            task_key: task_key.to_string(),
+            title: task_key.to_string(),
+            touched_areas: vec![format!("crates/{task_key}/")],
+            verification_reason: None,
+            verification_verdict: None,
+            wave: 0,
+        }
+    }
+
+    fn board_mode() -> AppMode {
+        AppMode::OrchestrationBoard {
+            board: OrchestrationBoard {
+                status: OrchestrationStatus::Running,
+                tasks: vec![
+                    board_task(1, "api", OrchestrationTaskStatus::Running),
+                    board_task(2, "ui", OrchestrationTaskStatus::Proposed),
+                ],
+            },
+            delete_confirmation: None,
+            editor: None,
+            expanded: false,
+            notice: None,
+            selected_index: 0,
+            session_id: "controller".into(),
+        }
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[tokio::test]
+    async fn board_keys_select_expand_and_reject_editing_started_tasks() {
+        // Arrange
+        let (mut app, _base_dir) = crate::test_support::new_test_app().await;
+        app.mode = board_mode();
+
+        // Act
+        handle(&mut app, key(KeyCode::Char('e'))).await;
+        let started_task_notice = match &app.mode {
+            AppMode::OrchestrationBoard { notice, .. } => notice.clone(),
+            _ => None,
+        };
+        handle(&mut app, key(KeyCode::Char('j'))).await;
+        handle(&mut app, key(KeyCode::Char('j'))).await;
+        handle(&mut app, key(KeyCode::Enter)).await;
+
+        // Assert
+        assert_eq!(
+            started_task_notice.as_deref(),
+            Some("Only proposed tasks can be edited.")
+        );
+        assert!(matches!(
+            app.mode,
+            AppMode::OrchestrationBoard {
+                expanded: true,
+                notice: None,
+                selected_index: 1,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn editor_keys_edit_focused_fields_and_cancel_without_saving() {
+        // Arrange
+        let (mut app, _base_dir) = crate::test_support::new_test_app().await;
+        app.mode = board_mode();
+        handle(&mut app, key(KeyCode::Down)).await;
+        handle(&mut app, key(KeyCode::Char('e'))).await;
+
+        // Act
+        handle(&mut app, key(KeyCode::Char('s'))).await;
+        handle(&mut app, key(KeyCode::Tab)).await;
+        handle(&mut app, key(KeyCode::Char('!'))).await;
+        let draft = match &app.mode {
+            AppMode::OrchestrationBoard {
+                editor: Some(editor),
+                ..
+            } => Some(editor.draft()),
+            _ => None,
+        }
+        .expect("editor should stay open while typing");
+        handle(&mut app, key(KeyCode::Esc)).await;
+        handle(&mut app, key(KeyCode::Char('q'))).await;
+
+        // Assert
+        assert_eq!(draft.title, "uis");
+        assert_eq!(draft.prompt, "Implement ui!");
+        assert_eq!(draft.acceptance_criteria, ["ui works"]);
+        assert!(matches!(
+            app.mode,
+            AppMode::View { ref session_id, .. } if session_id == "controller"
+        ));
+    }
+
+    #[tokio::test]
+    async fn delete_key_asks_for_confirmation_before_deleting_proposed_task() {
+        // Arrange
+        let (mut app, _base_dir) = crate::test_support::new_test_app().await;
+        app.mode = board_mode();
+        handle(&mut app, key(KeyCode::Char('d'))).await;
+        let started_task_notice = match &app.mode {
+            AppMode::OrchestrationBoard { notice, .. } => notice.clone(),
+            _ => None,
+        };
+        handle(&mut app, key(KeyCode::Down)).await;
+
+        // Act
+        handle(&mut app, key(KeyCode::Char('d'))).await;
+        let pending_confirmation = match &app.mode {
+            AppMode::OrchestrationBoard {
+                delete_confirmation,
+                ..
+            } => delete_confirmation.clone(),
+            _ => None,
+        };
+        handle(&mut app, key(KeyCode::Char('n'))).await;
+
+        // Assert
+        assert_eq!(
+            started_task_notice.as_deref(),
+            Some("Only proposed tasks can be deleted.")
+        );
+        assert_eq!(
+            pending_confirmation,
+            Some(OrchestrationTaskDeleteConfirmation {
+                selected_confirmation_index: DEFAULT_OPTION_INDEX,
+                task_id: 2,
+                task_title: "ui".to_string(),
+            })
+        );
+        assert!(matches!(
+            app.mode,
+            AppMode::OrchestrationBoard {
+                ref board,
+                delete_confirmation: None,
+                selected_index: 1,
+                ..
+            } if board.tasks.len() == 2
+        ));
+    }
+}
@@ -21,6 +21,7 @@ use crate::domain::turn_prompt::{TurnPrompt, TurnPromptAttachment, TurnPromptTex
 use crate::presentation::app_mode::{
     AppMode, ChatFocus, DiffRestoreTarget, DiffSidebarFocus, PromptModeSnapshot,
 };
+use crate::presentation::keymap::PromptAction;
 use crate::presentation::prompt::{
     PromptAtMentionState, PromptSlashStage, PromptSuggestionSelection,
     apply_prompt_delete_range as apply_prompt_delete_range_components,
@@ -28,9 +29,9 @@ use crate::presentation::prompt::{
     insert_prompt_local_image, insert_prompt_text, prompt_slash_option_count,
     resolve_prompt_slash_selection,
 };
-use crate::runtime::EventResult;
-use crate::runtime::mode::chat_scroll::{self, ChatScrollMetrics};
+use crate::runtime::mode::chat_scroll::{self, ChatScrollMetrics, ScrollStep};
 use crate::runtime::mode::{at_mention, input_key};
+use crate::runtime::{EventResult, keymap};
 use crate::ui::RenderCacheStore;
 use crate::ui::input_layout::{move_input_cursor_down, move_input_cursor_up};
 
@@ -72,12 +73,14 @@ enum PromptInputMode {
 
 /// Handles key input while the app is in `AppMode::Prompt`.
 ///
-/// `Tab` moves focus between the composer and the chat transcript above it,
-/// unless the `@`-mention dropdown is open and claims the key for completion.
-/// `Shift+Tab` toggles the session permission mode while the composer is
-/// focused. While the transcript holds focus, scroll keys navigate it and the
-/// composer text stays untouched. Pressing `q` from transcript focus returns to
-/// the sessions list and saves the complete composer for the next reopen.
+/// Keys resolve through the `[prompt]` keymap section. `focus` (`Tab` by
+/// default) moves focus between the composer and the chat transcript above
+/// it, unless the `@`-mention dropdown is open and claims the key for
+/// completion. `switch_mode` toggles the session permission mode while the
+/// composer is focused. While the transcript holds focus, scroll actions
+/// navigate it and the composer text stays untouched. `sessions` from
+/// transcript focus returns to the sessions list and saves the complete
+/// composer for the next reopen.
 pub(crate) async fn handle_with_cache<B: Backend>(
     app: &mut App,
     render_cache_store: &RenderCacheStore,
@@ -99,7 +102,9 @@ where
         return Ok(EventResult::Continue);
     }
 
-    if is_plain_char_key(key, 'q') && prompt_chat_is_focused(app) {
+    if keymap::resolve_action(app, key) == Some(PromptAction::Sessions)
+        && prompt_chat_is_focused(app)
+    {
         exit_to_list_saving_progress(app);
 
         return Ok(EventResult::Continue);
@@ -134,12 +139,12 @@ fn exit_to_list_saving_progress(app: &mut App) {
 
 /// Handles keys while the chat transcript above the composer holds focus.
 ///
-/// The shared chat-focus classifier handles `Tab`, transcript navigation, and
-/// unsupported keys. `d` opens the diff preview for the session, mirroring
-/// question mode. Every other key — including `Ctrl+C` and `Esc` — is swallowed
-/// so the typed draft and the prompt itself cannot change while the user reads
-/// back the conversation. Swallowed keys skip scroll-metric construction, which
-/// lays out the transcript.
+/// The shared chat-focus classifier handles the focus toggle, transcript
+/// navigation, and unsupported keys. The `diff` action opens the diff preview
+/// for the session, mirroring question mode. Every other key — including
+/// `Ctrl+C` and `Esc` — is swallowed so the typed draft and the prompt itself
+/// cannot change while the user reads back the conversation. Swallowed keys
+/// skip scroll-metric construction, which lays out the transcript.
 ///
 /// Returns `true` when the key was consumed by the focused transcript.
 fn handle_chat_focus_key<B: Backend>(
@@ -156,7 +161,9 @@ where
         return Ok(false);
     };
 
-    match chat_scroll::classify_chat_focus_action(*focus, key) {
+    let shortcut = chat_focus_shortcut(keymap::resolve_action(app, key), key);
+
+    match chat_scroll::classify_chat_focus_action(*focus, shortcut) {
         None => Ok(false),
         Some(chat_scroll::ChatFocusAction::ToggleFocus) => {
             if let AppMode::Prompt { focus, .. } = &mut app.mode {
@@ -170,7 +177,7 @@ where
 
             Ok(true)
         }
-        Some(chat_scroll::ChatFocusAction::Scroll) => {
+        Some(chat_scroll::ChatFocusAction::Scroll(scroll_step)) => {
             let terminal_size = terminal.size().map_err(crate::runtime::backend_err)?;
             let metrics = ChatScrollMetrics::new(
                 app,
@@ -181,7 +188,7 @@ where
             );
 
             if let AppMode::Prompt { scroll_offset, .. } = &mut app.mode {
-                chat_scroll::apply_scroll_key(scroll_offset, metrics, key);
+                chat_scroll::apply_scroll_step(scroll_offset, metrics, scroll_step);
             }
 
             Ok(true)
@@ -190,6 +197,26 @@ where
     }
 }
 
+/// Maps one resolved prompt action, or a fixed arrow or half-page key, onto
+/// the shared chat-focus shortcuts.
+fn chat_focus_shortcut(
+    action: Option<PromptAction>,
+    key: KeyEvent,
+) -> Option<chat_scroll::ChatFocusAction> {
+    let scroll_step = match action {
+        Some(PromptAction::Focus) => return Some(chat_scroll::ChatFocusAction::ToggleFocus),
+        Some(PromptAction::Diff) => return Some(chat_scroll::ChatFocusAction::OpenDiff),
+        Some(PromptAction::ScrollDown) => ScrollStep::LineDown,
+        Some(PromptAction::ScrollUp) => ScrollStep::LineUp,
+        Some(PromptAction::ScrollTop) => ScrollStep::Top,
+        Some(PromptAction::ScrollBottom) => ScrollStep::Bottom,
+        Some(_) => return None,
+        None => ScrollStep::from_fixed_key(key)?,
+    };
+
+    Some(chat_scroll::ChatFocusAction::Scroll(scroll_step))
+}
+
 /// Opens the diff preview from prompt mode.
 ///
 /// Snapshots the current composer state so that exiting the diff view restores
@@ -266,31 +293,41 @@ async fn handle_editing_key<B: Backend>(
 where
     B::Error: std::error::Error + Send + Sync + 'static,
 {
-    match key.code {
-        KeyCode::BackTab => {
+    match (keymap::resolve_action(app, key), key.code) {
+        (Some(PromptAction::SwitchMode), _) => {
             toggle_prompt_permission_mode(app, prompt_context).await;
         }
-        KeyCode::Enter | KeyCode::Char('\r' | '\n') if !input_key::should_insert_newline(key) => {
+        (Some(PromptAction::Send), _) => {
             handle_prompt_submit_key(app, prompt_context).await;
         }
-        KeyCode::Esc | KeyCode::Char('c') if is_prompt_cancel_key(key) => {
+        (None, KeyCode::Char('\r' | '\n')) if !input_key::should_insert_newline(key) => {
+            handle_prompt_submit_key(app, prompt_context).await;
+        }
+        (Some(PromptAction::Newline), _) => {
+            apply_prompt_input_command(app, InputCommand::InsertNewline).await;
+        }
+        (Some(PromptAction::Cancel), _) => {
             handle_prompt_cancel_key(app, prompt_context).await;
         }
-        KeyCode::Up => handle_prompt_up_key(app, terminal, prompt_context)?,
-        KeyCode::Down => handle_prompt_down_key(app, terminal, prompt_context)?,
-        KeyCode::Char('k') if prompt_context.is_slash_command() && is_plain_char_key(key, 'k') => {
+        (_, KeyCode::Up) => handle_prompt_up_key(app, terminal, prompt_context)?,
+        (_, KeyCode::Down) => handle_prompt_down_key(app, terminal, prompt_context)?,
+        (_, KeyCode::Char('k'))
+            if prompt_context.is_slash_command() && is_plain_char_key(key, 'k') =>
+        {
             handle_prompt_up_key(app, terminal, prompt_context)?;
         }
-        KeyCode::Char('j') if prompt_context.is_slash_command() && is_plain_char_key(key, 'j') => {
+        (_, KeyCode::Char('j'))
+            if prompt_context.is_slash_command() && is_plain_char_key(key, 'j') =>
+        {
             handle_prompt_down_key(app, terminal, prompt_context)?;
         }
-        KeyCode::Char('v' | 'V') if is_prompt_image_paste_key(key) => {
+        (_, KeyCode::Char('v' | 'V')) if is_prompt_image_paste_key(key) => {
             handle_prompt_image_paste(app, prompt_context).await;
         }
-        KeyCode::Char('p') if input_key::is_control_key(key) => {
+        (_, KeyCode::Char('p')) if input_key::is_control_key(key) => {
             handle_prompt_up_key(app, terminal, prompt_context)?;
         }
-        KeyCode::Char('n') if input_key::is_control_key(key) => {
+        (_, KeyCode::Char('n')) if input_key::is_control_key(key) => {
             handle_prompt_down_key(app, terminal, prompt_context)?;
         }
         _ => {
@@ -548,10 +585,6 @@ fn reset_prompt_slash_state(app: &mut App) {
     }
 }
 
-fn is_prompt_cancel_key(key: KeyEvent) -> bool {
-    key.code == KeyCode::Esc || key.modifiers.contains(event::KeyModifiers::CONTROL)
-}
-
 fn is_plain_char_key(key: KeyEvent, character: char) -> bool {
     key.code == KeyCode::Char(character) && key.modifiers == event::KeyModifiers::NONE
 }
@@ -693,43 +726,28 @@ fn navigate_prompt_history_down(app: &mut App) {
 }
 
 fn move_prompt_slash_selection(app: &mut App, is_next: bool) {
-    let (
-        available_agent_kinds,
-        input_text,
-        personalities,
-        selected_agent,
-        selected_index,
-        session_agent_kind,
-        session_id,
-        stage,
-    ) = match &app.mode {
+    let (input_text, session_agent_kind, session_id, slash_state) = match &app.mode {
         AppMode::Prompt {
             input,
             session_id,
             slash_state,
             ..
         } => (
-            slash_state.available_agent_kinds.clone(),
             input.text().to_string(),
-            slash_state.personalities.clone(),
-            slash_state.selected_agent,
-            slash_state.selected_index,
             app.selected_session()
                 .map_or(AgentKind::Codex, |session| session.agent.kind()),
             Some(session_id.clone()),
-            slash_state.stage,
+            slash_state.clone(),
         ),
         _ => return,
     };
+    let selected_index = slash_state.selected_index;
     let allow_apply_command = session_id
         .is_some_and(|session_id| app.prompt_apply_command_is_available_for_session(&session_id));
 
     let option_count = prompt_slash_option_count(
         &input_text,
-        stage,
-        selected_agent,
-        &available_agent_kinds,
-        &personalities,
+        &slash_state,
         session_agent_kind,
         allow_apply_command,
     );
@@ -845,6 +863,9 @@ async fn handle_prompt_slash_submit(app: &mut App, prompt_context: &PromptContex
         Some(PromptSuggestionSelection::Command("/speed")) => {
             open_prompt_speed_stage(app, prompt_context.session_index);
         }
+        Some(PromptSuggestionSelection::Command("/rewind")) => {
+            open_prompt_rewind_stage(app, &prompt_context.session_id).await;
+        }
         Some(PromptSuggestionSelection::Command("/personality")) => {
             let personalities = app
                 .list_prompt_personalities(&prompt_context.session_id)
@@ -896,6 +917,11 @@ async fn handle_prompt_slash_submit(app: &mut App, prompt_context: &PromptContex
             app.update_prompt_session_reasoning_level(&prompt_context.session_id, reasoning_level)
                 .await;
         }
+        Some(PromptSuggestionSelection::Rewind(turn)) => {
+            clear_prompt_slash_input(app).await;
+            app.rewind_prompt_session(&prompt_context.session_id, turn)
+                .await;
+        }
         Some(PromptSuggestionSelection::Speed(speed_mode)) => {
             clear_prompt_slash_input(app).await;
             app.update_prompt_session_speed_mode(&prompt_context.session_id, speed_mode)
@@ -905,14 +931,39 @@ async fn handle_prompt_slash_submit(app: &mut App, prompt_context: &PromptContex
     }
 }
 
-/// Toggles and persists the permission mode without changing the composer.
+/// Loads the session's turn checkpoints and opens the `/rewind` picker with
+/// the newest turn highlighted.
+async fn open_prompt_rewind_stage(app: &mut App, session_id: &SessionId) {
+    let checkpoints = app.list_prompt_turn_checkpoints(session_id).await;
+
+    if let AppMode::Prompt { slash_state, .. } = &mut app.mode {
+        slash_state.checkpoints = checkpoints;
+        slash_state.stage = PromptSlashStage::Rewind;
+        slash_state.selected_agent = None;
+        slash_state.selected_index = 0;
+    }
+}
+
+/// Cycles and persists the permission mode without changing the composer.
+///
+/// The cycle is `Auto Edit` → `Read Only` → `Ask` so the long-standing
+/// single-press switch to read-only keeps working. `Ask` is skipped for
+/// providers without an approval channel. Roles that are always read-only,
+/// such as orchestration controllers, ignore the shortcut.
 async fn toggle_prompt_permission_mode(app: &mut App, prompt_context: &PromptContext) {
-    let current_permission_mode = app
-        .session_at(prompt_context.session_index)
-        .map_or_else(PermissionMode::default, |session| session.permission_mode);
+    let session = app.session_at(prompt_context.session_index);
+    if session.is_some_and(|session| session.role.enforces_read_only()) {
+        return;
+    }
+    let current_permission_mode = session.map_or_else(PermissionMode::default, |session| {
+        session.effective_permission_mode()
+    });
+    let ask_available =
+        session.is_none_or(|session| PermissionMode::Ask.is_available_for(session.agent.kind()));
     let permission_mode = match current_permission_mode {
         PermissionMode::AutoEdit => PermissionMode::ReadOnly,
-        PermissionMode::ReadOnly => PermissionMode::AutoEdit,
+        PermissionMode::ReadOnly if ask_available => PermissionMode::Ask,
+        PermissionMode::ReadOnly | PermissionMode::Ask => PermissionMode::AutoEdit,
     };
 
     if let Err(error) = app
@@ -2406,7 +2457,14 @@ mod tests {
         // Assert
         assert_eq!(
             commands,
-            vec!["/apply", "/model", "/personality", "/reasoning", "/speed"]
+            vec![
+                "/apply",
+                "/model",
+                "/personality",
+                "/reasoning",
+                "/rewind",
+                "/speed",
+            ]
         );
     }
 
@@ -2429,10 +2487,10 @@ mod tests {
         // Arrange & Act
         let count = prompt_slash_option_count(
             "/model",
-            PromptSlashStage::Agent,
-            None,
-            AgentKind::ALL,
-            &[],
+            &PromptSlashState {
+                stage: PromptSlashStage::Agent,
+                ..PromptSlashState::default()
+            },
             AgentKind::Codex,
             true,
         );
@@ -2446,10 +2504,11 @@ mod tests {
         // Arrange & Act
         let count = prompt_slash_option_count(
             "/model",
-            PromptSlashStage::Model,
-            Some(AgentKind::Claude),
-            AgentKind::ALL,
-            &[],
+            &PromptSlashState {
+                selected_agent: Some(AgentKind::Claude),
+                stage: PromptSlashStage::Model,
+                ..PromptSlashState::default()
+            },
             AgentKind::Codex,
             true,
         );
@@ -2461,18 +2520,13 @@ mod tests {
     #[test]
     fn test_prompt_slash_option_count_for_agent_stage_uses_available_agent_kinds() {
         // Arrange
-        let available_agent_kinds = [AgentKind::Codex];
+        let slash_state = PromptSlashState {
+            stage: PromptSlashStage::Agent,
+            ..PromptSlashState::with_available_agent_kinds(vec![AgentKind::Codex])
+        };
 
         // Act
-        let count = prompt_slash_option_count(
-            "/model",
-            PromptSlashStage::Agent,
-            None,
-            &available_agent_kinds,
-            &[],
-            AgentKind::Codex,
-            true,
-        );
+        let count = prompt_slash_option_count("/model", &slash_state, AgentKind::Codex, true);
 
         // Assert
         assert_eq!(count, 1);
@@ -2831,7 +2885,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_backtab_toggles_permission_mode_and_preserves_input() {
+    async fn test_backtab_cycles_permission_mode_and_preserves_input() {
         // Arrange
         let (mut app, _base_dir) = new_test_prompt_app("draft text", None).await;
 
@@ -2839,6 +2893,8 @@ mod tests {
         press_prompt_key(&mut app, KeyCode::BackTab).await;
         let read_only_mode = app.sessions.sessions()[0].permission_mode;
         press_prompt_key(&mut app, KeyCode::BackTab).await;
+        let ask_mode = app.sessions.sessions()[0].permission_mode;
+        press_prompt_key(&mut app, KeyCode::BackTab).await;
 
         // Assert
         assert!(matches!(
@@ -2846,6 +2902,33 @@ mod tests {
             AppMode::Prompt { input, .. } if input.text() == "draft text"
         ));
         assert_eq!(read_only_mode, PermissionMode::ReadOnly);
+        assert_eq!(ask_mode, PermissionMode::Ask);
+        assert_eq!(
+            app.sessions.sessions()[0].permission_mode,
+            PermissionMode::AutoEdit
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backtab_skips_ask_for_agent_without_approval_channel() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_prompt_app("draft text", None).await;
+        if let AppMode::Prompt { slash_state, .. } = &mut app.mode {
+            slash_state.stage = PromptSlashStage::Model;
+            slash_state.selected_agent = Some(AgentKind::Claude);
+            slash_state.selected_index = 0;
+        }
+        let prompt_context = prompt_context(&mut app).expect("expected prompt context");
+        handle_prompt_slash_submit(&mut app, &prompt_context).await;
+
+        // Act
+        press_prompt_key(&mut app, KeyCode::BackTab).await;
+        let read_only_mode = app.sessions.sessions()[0].permission_mode;
+        press_prompt_key(&mut app, KeyCode::BackTab).await;
+
+        // Assert
+        assert_eq!(app.sessions.sessions()[0].agent.kind(), AgentKind::Claude);
+        assert_eq!(read_only_mode, PermissionMode::ReadOnly);
         assert_eq!(
             app.sessions.sessions()[0].permission_mode,
             PermissionMode::AutoEdit
@@ -11,10 +11,11 @@ use crate::domain::session::{SessionId, Status};
 use crate::presentation::app_mode::{
     AppMode, ChatFocus, DiffRestoreTarget, DiffSidebarFocus, QuestionModeSnapshot,
 };
+use crate::presentation::keymap::QuestionAction;
 use crate::presentation::prompt::PromptAtMentionState;
-use crate::runtime::EventResult;
-use crate::runtime::mode::chat_scroll::{self, ChatScrollMetrics};
+use crate::runtime::mode::chat_scroll::{self, ChatScrollMetrics, ScrollStep};
 use crate::runtime::mode::{at_mention, input_key};
+use crate::runtime::{EventResult, keymap};
 use crate::ui::RenderCacheStore;
 
 /// Default response stored when users skip one model question.
@@ -22,22 +23,26 @@ const NO_ANSWER: &str = "no answer";
 
 /// Applies one key event in question-answer mode.
 ///
-/// `Tab` toggles focus between the question panel and the chat output for
-/// scrolling. When chat is focused, scroll keys (`j`/`k`/`Up`/`Down`/`g`/`G`/
-/// `Ctrl+d`/`Ctrl+u`) navigate the session transcript. `Enter` submits the
-/// typed answer (or `no answer` when blank), `Ctrl+C` ends the entire turn
-/// without sending a reply while the answer input is focused, `Esc` dismisses
-/// an open at-mention dropdown without ending the turn, and `q` returns to the
-/// sessions list while saving already-submitted answers for the next visit
-/// (skipped while the user is actively typing a free-text answer so the
-/// character can still be inserted into the response).
+/// Keys resolve through the `[question]` keymap section. `focus` (`Tab` by
+/// default) toggles focus between the question panel and the chat output for
+/// scrolling. When chat is focused, `down`/`up`/`scroll_top`/`scroll_bottom`
+/// plus the arrow and `Ctrl+d`/`Ctrl+u` keys navigate the session transcript.
+/// `send` submits the typed answer (or `no answer` when blank), `end_turn`
+/// ends the entire turn without sending a reply while the answer input is
+/// focused, `Esc` dismisses an open at-mention dropdown without ending the
+/// turn, and `sessions` returns to the sessions list while saving
+/// already-submitted answers for the next visit (skipped while the user is
+/// actively typing a free-text answer so the character can still be inserted
+/// into the response).
 pub(crate) async fn handle_with_cache(
     app: &mut App,
     render_cache_store: &RenderCacheStore,
     terminal_size: Rect,
     key: KeyEvent,
 ) -> EventResult {
-    if is_plain_q(key) && should_exit_to_list_on_q(app) {
+    let key_action = keymap::resolve_action(app, key);
+
+    if key_action == Some(QuestionAction::Sessions) && should_exit_to_list_on_q(app) {
         exit_to_list_saving_progress(app);
 
         return EventResult::Continue;
@@ -47,7 +52,7 @@ pub(crate) async fn handle_with_cache(
         return EventResult::Continue;
     }
 
-    if is_ctrl_c(key) {
+    if key_action == Some(QuestionAction::EndTurn) || (key_action.is_none() && is_ctrl_c(key)) {
         if is_answer_input_focused(app) {
             end_turn_no_answer(app).await;
         }
@@ -64,8 +69,8 @@ pub(crate) async fn handle_with_cache(
     };
 
     match action {
-        QuestionAction::Submit(response) => submit_response(app, response).await,
-        QuestionAction::Continue => sync_question_at_mention_state(app),
+        QuestionKeyOutcome::Submit(response) => submit_response(app, response).await,
+        QuestionKeyOutcome::Continue => sync_question_at_mention_state(app),
     }
 
     EventResult::Continue
@@ -76,7 +81,8 @@ async fn handle(app: &mut App, terminal_size: Rect, key: KeyEvent) -> EventResul
     handle_with_cache(app, &RenderCacheStore::default(), terminal_size, key).await
 }
 
-/// Returns whether `key` is a plain `Ctrl+C` press.
+/// Returns whether `key` is a `Ctrl+C` press, including the shifted form
+/// some terminals report.
 fn is_ctrl_c(key: KeyEvent) -> bool {
     matches!(key.code, KeyCode::Char('c' | 'C')) && key.modifiers.contains(KeyModifiers::CONTROL)
 }
@@ -92,18 +98,14 @@ fn is_answer_input_focused(app: &App) -> bool {
     )
 }
 
-/// Returns whether `key` is a plain `q` press without modifiers.
-fn is_plain_q(key: KeyEvent) -> bool {
-    matches!(key.code, KeyCode::Char('q')) && key.modifiers.is_empty()
-}
-
-/// Returns whether a plain `q` press should exit question mode to the sessions
-/// list.
+/// Returns whether the `sessions` action should exit question mode to the
+/// sessions list.
 ///
-/// `q` exits while reading the chat transcript (`ChatFocus::Chat`) or while
-/// navigating predefined options (`selected_option_index` is `Some`). It is
-/// preserved as a free-text character whenever the answer input is focused and
-/// the user is past the option list, so answers can still contain the letter.
+/// The action exits while reading the chat transcript (`ChatFocus::Chat`) or
+/// while navigating predefined options (`selected_option_index` is `Some`). It
+/// is preserved as a free-text character whenever the answer input is focused
+/// and the user is past the option list, so answers can still contain the
+/// letter.
 fn should_exit_to_list_on_q(app: &App) -> bool {
     let AppMode::Question {
         focus,
@@ -165,7 +167,9 @@ fn handle_chat_focus_key(
     };
     let focus = *focus;
 
-    match chat_scroll::classify_chat_focus_action(focus, key) {
+    let shortcut = chat_focus_shortcut(keymap::resolve_action(app, key), key);
+
+    match chat_scroll::classify_chat_focus_action(focus, shortcut) {
         None => false,
         Some(chat_scroll::ChatFocusAction::ToggleFocus) => {
             if let AppMode::Question { focus, .. } = &mut app.mode {
@@ -181,11 +185,11 @@ fn handle_chat_focus_key(
 
             true
         }
-        Some(chat_scroll::ChatFocusAction::Scroll) => {
+        Some(chat_scroll::ChatFocusAction::Scroll(scroll_step)) => {
             if let Some(metrics) = question_scroll_metrics(app, render_cache_store, terminal_size)
                 && let AppMode::Question { scroll_offset, .. } = &mut app.mode
             {
-                chat_scroll::apply_scroll_key(scroll_offset, metrics, key);
+                chat_scroll::apply_scroll_step(scroll_offset, metrics, scroll_step);
             }
 
             true
@@ -194,6 +198,26 @@ fn handle_chat_focus_key(
     }
 }
 
+/// Maps one resolved question action, or a fixed arrow or half-page key, onto
+/// the shared chat-focus shortcuts.
+fn chat_focus_shortcut(
+    action: Option<QuestionAction>,
+    key: KeyEvent,
+) -> Option<chat_scroll::ChatFocusAction> {
+    let scroll_step = match action {
+        Some(QuestionAction::Focus) => return Some(chat_scroll::ChatFocusAction::ToggleFocus),
+        Some(QuestionAction::Diff) => return Some(chat_scroll::ChatFocusAction::OpenDiff),
+        Some(QuestionAction::Down) => ScrollStep::LineDown,
+        Some(QuestionAction::Up) => ScrollStep::LineUp,
+        Some(QuestionAction::ScrollTop) => ScrollStep::Top,
+        Some(QuestionAction::ScrollBottom) => ScrollStep::Bottom,
+        Some(_) => return None,
+        None => ScrollStep::from_fixed_key(key)?,
+    };
+
+    Some(chat_scroll::ChatFocusAction::Scroll(scroll_step))
+}
+
 /// Returns transcript scroll metrics while question mode focuses the chat.
 ///
 /// Returns `None` when the answer input holds focus, so scroll keys stay
@@ -310,8 +334,8 @@ pub(crate) fn handle_paste(app: &mut App, pasted_text: &str) {
     sync_question_at_mention_state(app);
 }
 
-/// Semantic action emitted by one question-mode key event.
-enum QuestionAction {
+/// Outcome of applying one question-mode key event to the answer input.
+enum QuestionKeyOutcome {
     Submit(String),
     Continue,
 }
@@ -323,7 +347,8 @@ enum QuestionAction {
 /// first) option automatically enters free-text mode where the text input is
 /// visible. In free-text mode, `Up` returns to the last predefined option
 /// and `Down` wraps to the first.
-fn resolve_question_action(app: &mut App, key: KeyEvent) -> Option<QuestionAction> {
+fn resolve_question_action(app: &mut App, key: KeyEvent) -> Option<QuestionKeyOutcome> {
+    let key_action = keymap::resolve_action(app, key);
     let action = {
         let AppMode::Question {
             current_index,
@@ -341,47 +366,47 @@ fn resolve_question_action(app: &mut App, key: KeyEvent) -> Option<QuestionActio
             .map_or(0, |item| item.options.len());
         let is_navigating_options = selected_option_index.is_some();
 
-        match key.code {
-            KeyCode::Enter | KeyCode::Char('\r' | '\n')
+        match (key_action, key.code) {
+            (_, KeyCode::Enter | KeyCode::Char('\r' | '\n'))
                 if !is_navigating_options && input_key::should_insert_newline(key) =>
             {
                 input.insert_newline();
 
-                QuestionAction::Continue
+                QuestionKeyOutcome::Continue
             }
-            KeyCode::Enter => {
+            (Some(QuestionAction::Send), _) | (None, KeyCode::Enter) => {
                 resolve_enter_action(input, questions, *current_index, selected_option_index)
             }
-            KeyCode::Up | KeyCode::Char('k') if is_navigating_options => {
+            (Some(QuestionAction::Up), _) | (None, KeyCode::Up) if is_navigating_options => {
                 navigate_option_up(selected_option_index);
 
-                QuestionAction::Continue
+                QuestionKeyOutcome::Continue
             }
-            KeyCode::Down | KeyCode::Char('j') if is_navigating_options => {
+            (Some(QuestionAction::Down), _) | (None, KeyCode::Down) if is_navigating_options => {
                 navigate_option_down(selected_option_index, option_count);
 
-                QuestionAction::Continue
+                QuestionKeyOutcome::Continue
             }
-            KeyCode::Up
+            (None, KeyCode::Up)
                 if !is_navigating_options
                     && option_count > 0
                     && input_key::is_cursor_on_first_line(input) =>
             {
                 *selected_option_index = Some(option_count - 1);
 
-                QuestionAction::Continue
+                QuestionKeyOutcome::Continue
             }
-            KeyCode::Down
+            (None, KeyCode::Down)
                 if !is_navigating_options
                     && option_count > 0
                     && input_key::is_cursor_on_last_line(input) =>
             {
                 *selected_option_index = Some(0);
 
-                QuestionAction::Continue
+                QuestionKeyOutcome::Continue
             }
             _ if !is_navigating_options => resolve_free_text_key(input, key),
-            _ => QuestionAction::Continue,
+            _ => QuestionKeyOutcome::Continue,
         }
     };
 
@@ -399,7 +424,7 @@ fn resolve_enter_action(
     questions: &[QuestionItem],
     current_index: usize,
     selected_option_index: &mut Option<usize>,
-) -> QuestionAction {
+) -> QuestionKeyOutcome {
     if let Some(option_index) = *selected_option_index {
         let selected_text = questions
             .get(current_index)
@@ -407,11 +432,11 @@ fn resolve_enter_action(
             .cloned()
             .unwrap_or_default();
 
-        QuestionAction::Submit(normalize_response_text(&selected_text))
+        QuestionKeyOutcome::Submit(normalize_response_text(&selected_text))
     } else {
         let response_text = input.take_text();
 
-        QuestionAction::Submit(normalize_response_text(&response_text))
+        QuestionKeyOutcome::Submit(normalize_response_text(&response_text))
     }
 }
 
@@ -450,13 +475,13 @@ fn navigate_option_down(selected_option_index: &mut Option<usize>, option_count:
 }
 
 /// Resolves a key event in free-text input mode (no option selected).
-fn resolve_free_text_key(input: &mut InputState, key: KeyEvent) -> QuestionAction {
+fn resolve_free_text_key(input: &mut InputState, key: KeyEvent) -> QuestionKeyOutcome {
     if let Some(command) = input_key::command_for_key(key, input_key::InputCapabilities::MULTILINE)
     {
         input.apply(command);
     }
 
-    QuestionAction::Continue
+    QuestionKeyOutcome::Continue
 }
 
 /// Returns whether the question-mode at-mention dropdown is currently visible.
@@ -1374,6 +1399,7 @@ mod tests {
             reasoning_level_override: None,
             published_upstream_ref: None,
             questions: Vec::new(),
+            review_checks: None,
             review_request: None,
             size: SessionSize::Xs,
             speed_mode: crate::domain::agent::SpeedMode::default(),
@@ -1384,6 +1410,9 @@ mod tests {
             transcript: None,
             updated_at: 0,
             transient_messages: TransientMessageStore::default(),
+            pending_tool_approval: None,
+            post_turn_checks: None,
+            activity_log_expanded: false,
         });
         app.mode = AppMode::Question {
             at_mention_state: None,
@@ -1455,6 +1484,7 @@ mod tests {
             reasoning_level_override: None,
             published_upstream_ref: None,
             questions: Vec::new(),
+            review_checks: None,
             review_request: None,
             size: SessionSize::Xs,
             speed_mode: crate::domain::agent::SpeedMode::default(),
@@ -1465,6 +1495,9 @@ mod tests {
             transcript: None,
             updated_at: 0,
             transient_messages: TransientMessageStore::default(),
+            pending_tool_approval: None,
+            post_turn_checks: None,
+            activity_log_expanded: false,
         });
         app.sessions.session_handles_mut().insert(
             session_id.to_string().into(),
@@ -3195,6 +3228,7 @@ mod tests {
             reasoning_level_override: None,
             published_upstream_ref: None,
             questions: Vec::new(),
+            review_checks: None,
             review_request: None,
             size: SessionSize::Xs,
             speed_mode: crate::domain::agent::SpeedMode::default(),
@@ -3205,6 +3239,9 @@ mod tests {
             transcript: None,
             updated_at: 0,
             transient_messages: TransientMessageStore::default(),
+            pending_tool_approval: None,
+            post_turn_checks: None,
+            activity_log_expanded: false,
         });
 
         app.mode = AppMode::Question {
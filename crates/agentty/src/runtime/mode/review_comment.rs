@@ -7,8 +7,9 @@ use crate::app::prompt_intent::ReviewCommentResolutionOutcome;
 use crate::presentation::app_mode::{
     AppMode, DiffReviewComments, DiffScrollCache, DiffSidebarFocus, ReviewCommentSelection,
 };
+use crate::presentation::keymap::DiffAction;
 use crate::presentation::review_comment;
-use crate::runtime::EventResult;
+use crate::runtime::{EventResult, keymap};
 use crate::ui::{RenderCacheStore, page};
 
 /// Handles agent-resolution, selection, and detail scrolling while the
@@ -19,11 +20,13 @@ pub(crate) async fn handle_with_cache(
     content_area: Rect,
     key: KeyEvent,
 ) -> EventResult {
+    let action = keymap::resolve_action(app, key);
     let mode = std::mem::replace(&mut app.mode, AppMode::List);
     let AppMode::Diff {
         diff,
         file_explorer_selected_index,
         focus,
+        layout_mode,
         line_comments,
         preview,
         review_comments: Some(mut review_comments),
@@ -52,6 +55,7 @@ pub(crate) async fn handle_with_cache(
             diff,
             file_explorer_selected_index,
             focus,
+            layout_mode,
             line_comments,
             preview,
             review_comments: Some(review_comments),
@@ -77,6 +81,7 @@ pub(crate) async fn handle_with_cache(
             item_count,
             render_cache_store,
         },
+        action,
         key,
         &mut review_comments,
         &mut scroll_cache,
@@ -87,6 +92,7 @@ pub(crate) async fn handle_with_cache(
         diff,
         file_explorer_selected_index,
         focus,
+        layout_mode,
         line_comments,
         preview,
         review_comments: Some(review_comments),
@@ -110,8 +116,12 @@ struct ReviewCommentNavigationInput<'a> {
 }
 
 /// Applies comment selection, marking, focus, and detail-scroll keys.
+///
+/// Selection, marking, and focus resolve through the `[diff]` keymap section;
+/// the arrow keys always scroll the selected comment's detail.
 fn handle_review_comment_navigation(
     input: &ReviewCommentNavigationInput<'_>,
+    action: Option<DiffAction>,
     key: KeyEvent,
     review_comments: &mut DiffReviewComments,
     scroll_cache: &mut Option<DiffScrollCache>,
@@ -119,14 +129,14 @@ fn handle_review_comment_navigation(
 ) {
     if input.can_reply {
         toggle_selected_comment(
-            &key,
+            action,
             review_comments.comment_snapshot.as_ref(),
             review_comments.selected_comment_index,
             &mut review_comments.selected_comments,
         );
     }
-    match key.code {
-        KeyCode::Char('j') if key.modifiers == KeyModifiers::NONE => {
+    match (action, key.code) {
+        (Some(DiffAction::SelectNext), _) => {
             let next_index =
                 next_selected_index(review_comments.selected_comment_index, input.item_count);
             if next_index != review_comments.selected_comment_index {
@@ -134,7 +144,7 @@ fn handle_review_comment_navigation(
                 *scroll_offset = 0;
             }
         }
-        KeyCode::Char('k') if key.modifiers == KeyModifiers::NONE => {
+        (Some(DiffAction::SelectPrevious), _) => {
             let previous_index =
                 previous_selected_index(review_comments.selected_comment_index, input.item_count);
             if previous_index != review_comments.selected_comment_index {
@@ -142,7 +152,7 @@ fn handle_review_comment_navigation(
                 *scroll_offset = 0;
             }
         }
-        KeyCode::Down => {
+        (None, KeyCode::Down) => {
             let max_scroll_offset = review_comment_max_scroll_offset(
                 input.render_cache_store,
                 input.content_area,
@@ -154,13 +164,10 @@ fn handle_review_comment_navigation(
             );
             *scroll_offset = increment_scroll_offset(*scroll_offset, max_scroll_offset);
         }
-        KeyCode::Up => {
+        (None, KeyCode::Up) => {
             *scroll_offset = scroll_offset.saturating_sub(1);
         }
-        KeyCode::Esc => {
-            focus_files(review_comments, scroll_cache, scroll_offset);
-        }
-        KeyCode::Char('f') if key.modifiers == KeyModifiers::NONE => {
+        (Some(DiffAction::Files), _) | (None, KeyCode::Esc) => {
             focus_files(review_comments, scroll_cache, scroll_offset);
         }
         _ => {}
@@ -220,12 +227,12 @@ fn session_allows_review_comment_reply(app: &App, session_id: &str) -> bool {
 
 /// Toggles the selected actionable thread in the next agent batch.
 fn toggle_selected_comment(
-    key: &KeyEvent,
+    action: Option<DiffAction>,
     comment_snapshot: Option<&ReviewCommentSnapshot>,
     selected_comment_index: usize,
     selected_comments: &mut Vec<ReviewCommentSelection>,
 ) {
-    if key.code != KeyCode::Char(' ') || key.modifiers != KeyModifiers::NONE {
+    if action != Some(DiffAction::ToggleComment) {
         return;
     }
     let Some(thread_id) = comment_snapshot.and_then(|snapshot| {
@@ -281,7 +288,7 @@ mod tests {
     use super::*;
     use crate::domain::session::{SessionId, SessionRole, Status};
     use crate::presentation::app_mode::{
-        DiffFocus, DiffLineComments, DiffPreview, DiffReviewComments,
+        DiffFocus, DiffLayoutMode, DiffLineComments, DiffPreview, DiffReviewComments,
     };
     use crate::test_support::SessionFixtureBuilder;
 
@@ -318,6 +325,7 @@ mod tests {
             diff: String::new(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
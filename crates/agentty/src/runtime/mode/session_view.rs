@@ -1,6 +1,6 @@
 use std::io;
 
-use crossterm::event::{self, KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::Terminal;
 use ratatui::backend::Backend;
 use ratatui::layout::Rect;
@@ -16,12 +16,13 @@ use crate::presentation::app_mode::{
     AppMode, ChatFocus, ConfirmationIntent, ConfirmationViewMode, DiffSidebarFocus, HelpContext,
 };
 use crate::presentation::help_action::{self, ViewSessionState};
+use crate::presentation::keymap::ViewAction;
 use crate::presentation::prompt::{PromptAttachmentState, PromptHistoryState};
-use crate::runtime::EventResult;
-use crate::runtime::mode::chat_scroll::{self, ChatScrollMetrics};
+use crate::presentation::publish_form::ReviewRequestPublishForm;
+use crate::runtime::mode::chat_scroll::{self, ChatScrollMetrics, ScrollStep};
 use crate::runtime::mode::confirmation::DEFAULT_OPTION_INDEX;
-use crate::runtime::mode::input_key::is_insertable_char_key;
-use crate::runtime::mode::prompt;
+use crate::runtime::mode::{orchestration_board, prompt};
+use crate::runtime::{EventResult, keymap};
 use crate::ui::RenderCacheStore;
 
 #[derive(Clone)]
@@ -80,11 +81,13 @@ impl ViewActionState {
 struct ViewSessionSnapshot {
     branch_actions: ViewActionState,
     continue_terminal_session: ViewActionState,
+    fix_review_checks: ViewActionState,
     fork_session: ViewActionState,
     follow_up_task_action: Option<FollowUpTaskAction>,
     inspect_diff: ViewActionState,
     is_managed: bool,
     is_orchestrator: bool,
+    mark_review_ready: ViewActionState,
     merge_session_branch: ViewActionState,
     mutate_session_branch: ViewActionState,
     open_worktree: ViewActionState,
@@ -163,6 +166,20 @@ impl ViewSessionSnapshot {
         self.review_comments.is_enabled()
     }
 
+    /// Returns whether a fix-up turn can be sent for failing CI checks on the
+    /// linked review request.
+    fn can_fix_review_checks(&self) -> bool {
+        self.fix_review_checks.is_enabled()
+            && self.reply_to_session.is_enabled()
+            && self.session_status.allows_review_actions()
+    }
+
+    /// Returns whether the linked draft review request can be marked ready
+    /// for review.
+    fn can_mark_review_ready(&self) -> bool {
+        self.mark_review_ready.is_enabled() && self.session_status.allows_review_actions()
+    }
+
     /// Returns whether this staged draft can start its first live turn.
     fn can_start_staged_session(&self) -> bool {
         self.start_staged_session.is_enabled()
@@ -265,9 +282,11 @@ async fn handle_view_key(
     let view_context = view_key_context.context;
     let view_metrics = view_key_context.metrics;
     let view_session_snapshot = view_key_context.session_snapshot;
+    let action = keymap::resolve_action(app, key);
 
     if let Some(should_apply_pending_update) = handle_primary_view_key(
         app,
+        action,
         key,
         view_context,
         view_session_snapshot,
@@ -278,13 +297,23 @@ async fn handle_view_key(
         return should_apply_pending_update;
     }
 
-    if chat_scroll::apply_scroll_key(&mut pending_update.scroll_offset, view_metrics, key) {
+    if let Some(scroll_step) = view_scroll_step(action, key) {
+        chat_scroll::apply_scroll_step(
+            &mut pending_update.scroll_offset,
+            view_metrics,
+            scroll_step,
+        );
+
+        return true;
+    }
+
+    if handle_activity_log_key(app, action, view_context) {
         return true;
     }
 
     if let Some(should_apply_pending_update) = handle_workflow_view_key(
         app,
-        key,
+        action,
         view_context,
         view_session_snapshot,
         pending_update,
@@ -297,32 +326,67 @@ async fn handle_view_key(
     true
 }
 
+/// Toggles the activity-log expansion for the viewed session when `action`
+/// is the activity-log shortcut.
+fn handle_activity_log_key(
+    app: &mut App,
+    action: Option<ViewAction>,
+    view_context: &ViewContext,
+) -> bool {
+    if action != Some(ViewAction::ActivityLog) {
+        return false;
+    }
+
+    app.sessions
+        .toggle_session_activity_log(&view_context.session_id);
+
+    true
+}
+
+/// Returns the transcript movement for one resolved scroll action, or for the
+/// arrow keys that always scroll the session view.
+fn view_scroll_step(action: Option<ViewAction>, key: KeyEvent) -> Option<ScrollStep> {
+    match action {
+        Some(ViewAction::ScrollDown) => Some(ScrollStep::LineDown),
+        Some(ViewAction::ScrollUp) => Some(ScrollStep::LineUp),
+        Some(ViewAction::ScrollTop) => Some(ScrollStep::Top),
+        Some(ViewAction::ScrollBottom) => Some(ScrollStep::Bottom),
+        Some(ViewAction::HalfPageDown) => Some(ScrollStep::HalfPageDown),
+        Some(ViewAction::HalfPageUp) => Some(ScrollStep::HalfPageUp),
+        Some(_) => None,
+        None => ScrollStep::from_fixed_key(key),
+    }
+}
+
 /// Handles primary session-view actions that do not need diff/review routing.
 async fn handle_primary_view_key(
     app: &mut App,
+    action: Option<ViewAction>,
     key: KeyEvent,
     view_context: &ViewContext,
     view_session_snapshot: &ViewSessionSnapshot,
     pending_update: &ViewPendingUpdate,
 ) -> Option<bool> {
     if view_session_snapshot.is_orchestrator
-        && handle_orchestration_view_key(app, key, view_context).await
+        && handle_orchestration_view_key(app, action, view_context).await
     {
         return Some(true);
     }
     let accepts_managed_keys = view_session_snapshot.accepts_managed_keys();
-    if accepts_managed_keys && handle_managed_view_key(app, key, view_context) {
+    if accepts_managed_keys && handle_managed_view_key(app, action, view_context) {
         return Some(false);
     }
 
-    match key.code {
-        KeyCode::Char('q') => {
+    match (action, key.code) {
+        (Some(ViewAction::Back), _) => {
             app.mode = AppMode::List;
         }
-        KeyCode::Char('o') if view_session_snapshot.can_open_worktree() => {
+        (Some(ViewAction::OpenWorktree), _) if view_session_snapshot.can_open_worktree() => {
             return Some(handle_open_worktree_key(app, view_context, view_session_snapshot).await);
         }
-        KeyCode::Char('l') if view_session_snapshot.follow_up_task_action.is_some() => {
+        (Some(ViewAction::FollowUpTask), _)
+            if view_session_snapshot.follow_up_task_action.is_some() =>
+        {
             if let Err(error) = app
                 .launch_or_open_selected_follow_up_task(&view_context.session_id)
                 .await
@@ -336,7 +400,7 @@ async fn handle_primary_view_key(
 
             return Some(false);
         }
-        KeyCode::Char('s') if view_session_snapshot.can_start_staged_session() => {
+        (Some(ViewAction::Start), _) if view_session_snapshot.can_start_staged_session() => {
             if let Err(error) = app.start_staged_session(&view_context.session_id).await {
                 app.append_output_for_session(
                     &view_context.session_id,
@@ -347,7 +411,7 @@ async fn handle_primary_view_key(
 
             return Some(false);
         }
-        KeyCode::Char('v' | 'V')
+        (_, KeyCode::Char('v' | 'V'))
             if prompt::is_prompt_image_paste_key(key)
                 && view_session_snapshot.can_paste_image_into_draft_composer() =>
         {
@@ -356,29 +420,29 @@ async fn handle_primary_view_key(
 
             return Some(false);
         }
-        KeyCode::Char('c')
-            if key.modifiers == event::KeyModifiers::NONE
-                && view_session_snapshot.can_open_review_comments() =>
-        {
+        (Some(ViewAction::Comments), _) if view_session_snapshot.can_open_review_comments() => {
             open_review_comments_in_diff(app, view_context);
 
             return Some(false);
         }
-        KeyCode::Char('c')
-            if key.modifiers == event::KeyModifiers::NONE
-                && view_session_snapshot.can_continue_terminal_session() =>
+        (Some(ViewAction::Comments), _)
+            if view_session_snapshot.can_continue_terminal_session() =>
         {
             open_continue_confirmation(app, view_context);
 
             return Some(false);
         }
-        KeyCode::Char('[') if app.has_multiple_follow_up_tasks(&view_context.session_id) => {
+        (Some(ViewAction::PreviousFollowUpTask), _)
+            if app.has_multiple_follow_up_tasks(&view_context.session_id) =>
+        {
             app.select_previous_follow_up_task(&view_context.session_id);
         }
-        KeyCode::Char(']') if app.has_multiple_follow_up_tasks(&view_context.session_id) => {
+        (Some(ViewAction::NextFollowUpTask), _)
+            if app.has_multiple_follow_up_tasks(&view_context.session_id) =>
+        {
             app.select_next_follow_up_task(&view_context.session_id);
         }
-        KeyCode::Enter if view_session_snapshot.can_open_prompt_composer() => {
+        (Some(ViewAction::Reply), _) if view_session_snapshot.can_open_prompt_composer() => {
             switch_view_to_prompt(
                 app,
                 view_context,
@@ -390,9 +454,8 @@ async fn handle_primary_view_key(
             )
             .await;
         }
-        KeyCode::Char('/')
-            if view_session_snapshot.can_launch_configuration_composer()
-                && is_insertable_char_key(key) =>
+        (Some(ViewAction::Command), _)
+            if view_session_snapshot.can_launch_configuration_composer() =>
         {
             switch_view_to_prompt(
                 app,
@@ -448,11 +511,11 @@ async fn handle_open_worktree_key(
 /// Applies campaign-board controls owned by an orchestrator session.
 async fn handle_orchestration_view_key(
     app: &mut App,
-    key: KeyEvent,
+    action: Option<ViewAction>,
     view_context: &ViewContext,
 ) -> bool {
-    match key.code {
-        KeyCode::Char('a') => {
+    match action {
+        Some(ViewAction::ApprovePlan) => {
             let outcome = app
                 .approve_orchestration(&view_context.session_id, None)
                 .await;
@@ -468,6 +531,9 @@ async fn handle_orchestration_view_key(
                 };
             }
         }
+        Some(ViewAction::CampaignBoard) => {
+            orchestration_board::open(app, &view_context.session_id).await;
+        }
         _ => return false,
     }
 
@@ -475,11 +541,17 @@ async fn handle_orchestration_view_key(
 }
 
 /// Opens the one-way ownership-transfer confirmation for a managed worker.
-fn handle_managed_view_key(app: &mut App, key: KeyEvent, view_context: &ViewContext) -> bool {
-    if key.code == KeyCode::Char('c') && key.modifiers.contains(event::KeyModifiers::CONTROL) {
+///
+/// Managed workers swallow `end_turn` because their campaign owns the turn.
+fn handle_managed_view_key(
+    app: &mut App,
+    action: Option<ViewAction>,
+    view_context: &ViewContext,
+) -> bool {
+    if action == Some(ViewAction::EndTurn) {
         return true;
     }
-    if key.code != KeyCode::Char('D') {
+    if action != Some(ViewAction::Detach) {
         return false;
     }
     app.mode = AppMode::Confirmation {
@@ -499,23 +571,16 @@ fn handle_managed_view_key(app: &mut App, key: KeyEvent, view_context: &ViewCont
 /// merge, session sync, cancellation, and help.
 async fn handle_workflow_view_key(
     app: &mut App,
-    key: KeyEvent,
+    action: Option<ViewAction>,
     view_context: &ViewContext,
     view_session_snapshot: &ViewSessionSnapshot,
     pending_update: &mut ViewPendingUpdate,
 ) -> Option<bool> {
-    match key.code {
-        KeyCode::Char('d')
-            if !key.modifiers.contains(event::KeyModifiers::CONTROL)
-                && view_session_snapshot.inspect_diff.is_enabled() =>
-        {
+    match action? {
+        ViewAction::Diff if view_session_snapshot.inspect_diff.is_enabled() => {
             show_diff_for_view_session(app, view_context);
         }
-        KeyCode::Char(character)
-            if character.eq_ignore_ascii_case(&'p')
-                && !key.modifiers.contains(event::KeyModifiers::CONTROL)
-                && view_session_snapshot.publish_pull_request_action.is_some() =>
-        {
+        ViewAction::Publish if view_session_snapshot.publish_pull_request_action.is_some() => {
             let Some(publish_pull_request_action) =
                 view_session_snapshot.publish_pull_request_action
             else {
@@ -525,36 +590,36 @@ async fn handle_workflow_view_key(
 
             return Some(false);
         }
-        KeyCode::Char('F')
-            if !key.modifiers.contains(event::KeyModifiers::CONTROL)
-                && view_session_snapshot.can_fork_session() =>
-        {
+        ViewAction::Fork if view_session_snapshot.can_fork_session() => {
             open_fork_confirmation(app, view_context);
 
             return Some(false);
         }
-        KeyCode::Char('f')
-            if !key.modifiers.contains(event::KeyModifiers::CONTROL)
-                && view_session_snapshot.branch_actions.is_enabled()
+        ViewAction::Review
+            if view_session_snapshot.branch_actions.is_enabled()
                 && view_session_snapshot.session_status.allows_review_actions() =>
         {
             open_or_regenerate_review(app, view_context, pending_update);
         }
-        KeyCode::Char('m') if view_session_snapshot.can_merge_session() => {
+        ViewAction::FixCi if view_session_snapshot.can_fix_review_checks() => {
+            app.fix_session_review_checks(&view_context.session_id)
+                .await;
+        }
+        ViewAction::MarkReady if view_session_snapshot.can_mark_review_ready() => {
+            app.mark_session_review_request_ready(&view_context.session_id);
+        }
+        ViewAction::Merge if view_session_snapshot.can_merge_session() => {
             open_merge_confirmation(app, view_context);
         }
-        KeyCode::Char('r') if view_session_snapshot.can_rebase_session() => {
+        ViewAction::Sync if view_session_snapshot.can_rebase_session() => {
             rebase_view_session(app, &view_context.session_id).await;
         }
-        KeyCode::Char('c')
-            if key.modifiers.contains(event::KeyModifiers::CONTROL)
-                && view_session_snapshot.session_status == Status::InProgress =>
-        {
+        ViewAction::EndTurn if view_session_snapshot.session_status == Status::InProgress => {
             end_in_progress_turn(app, &view_context.session_id).await;
 
             return Some(false);
         }
-        KeyCode::Char('?') => {
+        ViewAction::Help => {
             open_view_help_overlay(app, view_context, view_session_snapshot);
             return Some(false);
         }
@@ -715,6 +780,7 @@ fn view_session_snapshot(app: &App, view_context: &ViewContext) -> Option<ViewSe
         continue_terminal_session: ViewActionState::from_bool(
             session.allows_terminal_continuation(),
         ),
+        fix_review_checks: ViewActionState::from_bool(session.has_failed_review_checks()),
         fork_session: ViewActionState::from_bool(session.allows_fork_action()),
         follow_up_task_action: app.selected_follow_up_task_action(&view_context.session_id),
         inspect_diff: ViewActionState::from_bool(
@@ -724,6 +790,7 @@ fn view_session_snapshot(app: &App, view_context: &ViewContext) -> Option<ViewSe
         ),
         is_managed: session.is_managed(),
         is_orchestrator: session.role == crate::domain::session::SessionRole::Orchestrator,
+        mark_review_ready: ViewActionState::from_bool(session.has_draft_review_request()),
         merge_session_branch: ViewActionState::from_bool(
             session.owns_branch_changes()
                 && app
@@ -1014,7 +1081,9 @@ fn open_view_help_overlay(
 ) {
     app.mode = AppMode::Help {
         context: HelpContext::View {
+            can_fix_review_checks: view_session_snapshot.can_fix_review_checks(),
             can_fork_session: view_session_snapshot.can_fork_session(),
+            can_mark_review_ready: view_session_snapshot.can_mark_review_ready(),
             can_merge_session_branch: view_session_snapshot.can_merge_session_branch(),
             can_mutate_session_branch: view_session_snapshot.can_mutate_session_branch(),
             can_open_worktree: view_session_snapshot.can_open_worktree(),
@@ -1049,6 +1118,11 @@ fn open_publish_branch_input(
         .map(remote_branch_name_from_upstream_ref)
         .map(InputState::with_text)
         .unwrap_or_default();
+    let creates_review_request = publish_branch_action == PublishBranchAction::PublishPullRequest
+        && session.review_request.is_none();
+    let session_id = session.id.clone();
+    let review_request_form =
+        creates_review_request.then(|| Box::new(ReviewRequestPublishForm::default()));
 
     app.mode = AppMode::PublishBranchInput {
         default_branch_name,
@@ -1056,7 +1130,11 @@ fn open_publish_branch_input(
         locked_upstream_ref,
         publish_branch_action,
         restore_view: confirmation_view_mode(view_context),
+        review_request_form,
     };
+    if creates_review_request {
+        app.start_review_request_publish_choices_load(&session_id);
+    }
 }
 
 fn view_context(app: &mut App) -> Option<ViewContext> {
@@ -1219,6 +1297,28 @@ mod tests {
     use crate::ui::component::session_output::SessionOutputLineContext;
     use crate::ui::page::session_chat::SessionChatPage;
 
+    /// Resolves `key` against the app keymap and forwards it to
+    /// `handle_primary_view_key()` like `handle_view_key()` does.
+    async fn dispatch_primary_view_key(
+        app: &mut App,
+        key: KeyEvent,
+        view_context: &ViewContext,
+        view_session_snapshot: &ViewSessionSnapshot,
+        pending_update: &ViewPendingUpdate,
+    ) -> Option<bool> {
+        let action = keymap::resolve_action(app, key);
+
+        handle_primary_view_key(
+            app,
+            action,
+            key,
+            view_context,
+            view_session_snapshot,
+            pending_update,
+        )
+        .await
+    }
+
     fn queued_message(order: u64, text: &str) -> QueuedMessage {
         QueuedMessage::new(order, TurnPrompt::from_text(text.to_string()))
     }
@@ -1290,10 +1390,12 @@ mod tests {
         ViewSessionSnapshot {
             branch_actions: ViewActionState::Enabled,
             continue_terminal_session: ViewActionState::Disabled,
+            fix_review_checks: ViewActionState::Disabled,
             fork_session: ViewActionState::Enabled,
             inspect_diff: ViewActionState::Enabled,
             is_managed: false,
             is_orchestrator: false,
+            mark_review_ready: ViewActionState::Disabled,
             merge_session_branch: ViewActionState::Enabled,
             mutate_session_branch: ViewActionState::Enabled,
             rebase_session_branch: ViewActionState::Enabled,
@@ -1429,6 +1531,34 @@ mod tests {
         assert_eq!(context.session_index, 0);
     }
 
+    #[tokio::test]
+    async fn test_handle_activity_log_key_toggles_expansion_for_view_session() {
+        // Arrange
+        let (mut app, _base_dir, session_id) = new_test_app_with_session().await;
+        app.mode = AppMode::View {
+            session_id: session_id.into(),
+            scroll_offset: None,
+        };
+        let context = view_context(&mut app).expect("expected view context");
+
+        // Act
+        let expand_action =
+            keymap::resolve_action(&app, KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE));
+        let expand_handled = handle_activity_log_key(&mut app, expand_action, &context);
+        let expanded = app.sessions.sessions()[0].activity_log_expanded;
+        let control_action = keymap::resolve_action(
+            &app,
+            KeyEvent::new(KeyCode::Char('e'), KeyModifiers::CONTROL),
+        );
+        let control_handled = handle_activity_log_key(&mut app, control_action, &context);
+
+        // Assert
+        assert!(expand_handled);
+        assert!(expanded);
+        assert!(!control_handled);
+        assert!(app.sessions.sessions()[0].activity_log_expanded);
+    }
+
     #[tokio::test]
     async fn test_view_session_snapshot_disables_actions_for_done_session() {
         // Arrange
@@ -2527,10 +2657,12 @@ mod tests {
         let view_session_snapshot = ViewSessionSnapshot {
             branch_actions: ViewActionState::Enabled,
             continue_terminal_session: ViewActionState::Disabled,
+            fix_review_checks: ViewActionState::Disabled,
             fork_session: ViewActionState::Enabled,
             inspect_diff: ViewActionState::Enabled,
             is_managed: false,
             is_orchestrator: false,
+            mark_review_ready: ViewActionState::Disabled,
             merge_session_branch: ViewActionState::Enabled,
             mutate_session_branch: ViewActionState::Enabled,
             rebase_session_branch: ViewActionState::Enabled,
@@ -2552,7 +2684,9 @@ mod tests {
             app.mode,
             AppMode::Help {
                 context: HelpContext::View {
+                    can_fix_review_checks: false,
                     can_fork_session: true,
+                    can_mark_review_ready: false,
                     can_merge_session_branch: true,
                     can_mutate_session_branch: true,
                     can_open_worktree: true,
@@ -2598,6 +2732,7 @@ mod tests {
                         session_id: ref restored_session_id,
             scroll_offset: Some(5),
                     },
+                review_request_form: Some(_),
             } if default_branch_name == &crate::app::session::session_branch(&session_id)
                 && input_state.cursor == 0
                 && input_state.text().is_empty()
@@ -2865,7 +3000,7 @@ mod tests {
         // Act
         let diff_result = handle_workflow_view_key(
             &mut app,
-            KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE),
+            Some(ViewAction::Diff),
             &view_context,
             &view_session_snapshot,
             &mut pending_update,
@@ -2875,7 +3010,7 @@ mod tests {
         app.cancel_diff_view_load();
         let review_result = handle_workflow_view_key(
             &mut app,
-            KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE),
+            Some(ViewAction::Review),
             &view_context,
             &view_session_snapshot,
             &mut pending_update,
@@ -2922,10 +3057,12 @@ mod tests {
         let view_session_snapshot = ViewSessionSnapshot {
             branch_actions: ViewActionState::Enabled,
             continue_terminal_session: ViewActionState::Disabled,
+            fix_review_checks: ViewActionState::Disabled,
             fork_session: ViewActionState::Disabled,
             inspect_diff: ViewActionState::Disabled,
             is_managed: false,
             is_orchestrator: false,
+            mark_review_ready: ViewActionState::Disabled,
             merge_session_branch: ViewActionState::Enabled,
             mutate_session_branch: ViewActionState::Enabled,
             rebase_session_branch: ViewActionState::Enabled,
@@ -2983,10 +3120,12 @@ mod tests {
         let view_session_snapshot = ViewSessionSnapshot {
             branch_actions: ViewActionState::Enabled,
             continue_terminal_session: ViewActionState::Disabled,
+            fix_review_checks: ViewActionState::Disabled,
             fork_session: ViewActionState::Disabled,
             inspect_diff: ViewActionState::Enabled,
             is_managed: false,
             is_orchestrator: false,
+            mark_review_ready: ViewActionState::Disabled,
             merge_session_branch: ViewActionState::Enabled,
             mutate_session_branch: ViewActionState::Enabled,
             rebase_session_branch: ViewActionState::Enabled,
@@ -3150,7 +3289,7 @@ mod tests {
             view_session_snapshot(&app, &view_context).expect("expected session snapshot");
 
         // Act
-        let uppercase_result = handle_primary_view_key(
+        let uppercase_result = dispatch_primary_view_key(
             &mut app,
             KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT),
             &view_context,
@@ -3165,7 +3304,7 @@ mod tests {
         assert!(matches!(app.mode, AppMode::View { .. }));
 
         // Act
-        let continue_result = handle_primary_view_key(
+        let continue_result = dispatch_primary_view_key(
             &mut app,
             KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE),
             &view_context,
@@ -3207,7 +3346,7 @@ mod tests {
             view_session_snapshot(&app, &view_context).expect("expected session snapshot");
 
         // Act
-        let result = handle_primary_view_key(
+        let result = dispatch_primary_view_key(
             &mut app,
             KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE),
             &view_context,
@@ -3246,7 +3385,7 @@ mod tests {
         let mut results = Vec::new();
         for key in campaign_keys {
             results.push(
-                handle_primary_view_key(
+                dispatch_primary_view_key(
                     &mut app,
                     KeyEvent::new(KeyCode::Char(key), KeyModifiers::NONE),
                     &view_context,
@@ -3256,7 +3395,7 @@ mod tests {
                 .await,
             );
         }
-        let unknown_campaign_key = handle_primary_view_key(
+        let unknown_campaign_key = dispatch_primary_view_key(
             &mut app,
             KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE),
             &view_context,
@@ -3266,7 +3405,7 @@ mod tests {
         .await;
         snapshot.is_orchestrator = false;
         snapshot.is_managed = true;
-        let unrelated = handle_primary_view_key(
+        let unrelated = dispatch_primary_view_key(
             &mut app,
             KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE),
             &view_context,
@@ -3274,7 +3413,7 @@ mod tests {
             &pending_update,
         )
         .await;
-        let direct_cancel = handle_primary_view_key(
+        let direct_cancel = dispatch_primary_view_key(
             &mut app,
             KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
             &view_context,
@@ -3282,7 +3421,7 @@ mod tests {
             &pending_update,
         )
         .await;
-        let detach = handle_primary_view_key(
+        let detach = dispatch_primary_view_key(
             &mut app,
             KeyEvent::new(KeyCode::Char('D'), KeyModifiers::SHIFT),
             &view_context,
@@ -3320,7 +3459,7 @@ mod tests {
         snapshot.is_managed = true;
 
         // Act
-        let result = handle_primary_view_key(
+        let result = dispatch_primary_view_key(
             &mut app,
             KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE),
             &view_context,
@@ -3405,7 +3544,7 @@ mod tests {
         snapshot.is_orchestrator = true;
 
         // Act
-        let result = handle_primary_view_key(
+        let result = dispatch_primary_view_key(
             &mut app,
             KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE),
             &view_context,
@@ -3453,7 +3592,7 @@ mod tests {
             view_session_snapshot(&app, &view_context).expect("expected session snapshot");
 
         // Act
-        let continue_result = handle_primary_view_key(
+        let continue_result = dispatch_primary_view_key(
             &mut app,
             KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE),
             &view_context,
@@ -3776,10 +3915,12 @@ mod tests {
         let view_session_snapshot = ViewSessionSnapshot {
             branch_actions: ViewActionState::Enabled,
             continue_terminal_session: ViewActionState::Disabled,
+            fix_review_checks: ViewActionState::Disabled,
             fork_session: ViewActionState::Enabled,
             inspect_diff: ViewActionState::Enabled,
             is_managed: false,
             is_orchestrator: false,
+            mark_review_ready: ViewActionState::Disabled,
             merge_session_branch: ViewActionState::Enabled,
             mutate_session_branch: ViewActionState::Enabled,
             rebase_session_branch: ViewActionState::Enabled,
@@ -3835,10 +3976,12 @@ mod tests {
         let view_session_snapshot = ViewSessionSnapshot {
             branch_actions: ViewActionState::Enabled,
             continue_terminal_session: ViewActionState::Disabled,
+            fix_review_checks: ViewActionState::Disabled,
             fork_session: ViewActionState::Enabled,
             inspect_diff: ViewActionState::Enabled,
             is_managed: false,
             is_orchestrator: false,
+            mark_review_ready: ViewActionState::Disabled,
             merge_session_branch: ViewActionState::Enabled,
             mutate_session_branch: ViewActionState::Enabled,
             rebase_session_branch: ViewActionState::Enabled,
@@ -3912,10 +4055,12 @@ mod tests {
             let view_session_snapshot = ViewSessionSnapshot {
                 branch_actions: ViewActionState::Enabled,
                 continue_terminal_session: ViewActionState::Disabled,
+                fix_review_checks: ViewActionState::Disabled,
                 fork_session: ViewActionState::Disabled,
                 inspect_diff: ViewActionState::Disabled,
                 is_managed: false,
                 is_orchestrator: false,
+                mark_review_ready: ViewActionState::Disabled,
                 merge_session_branch: ViewActionState::Enabled,
                 mutate_session_branch: ViewActionState::Enabled,
                 rebase_session_branch: ViewActionState::Enabled,
@@ -0,0 +1,144 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+use crate::domain::permission::ToolApprovalDecision;
+use crate::domain::session::SessionId;
+use crate::presentation::app_mode::AppMode;
+use crate::runtime::EventResult;
+
+/// Returns the visible session whose active turn is waiting for approval.
+///
+/// Only session view and prompt modes show the approval overlay, so other
+/// modes keep their own key handling while a request is pending.
+pub(crate) fn pending_session_id(app: &App) -> Option<SessionId> {
+    let (AppMode::View { session_id, .. } | AppMode::Prompt { session_id, .. }) = &app.mode else {
+        return None;
+    };
+
+    app.sessions
+        .session_for_id(session_id)
+        .filter(|session| session.pending_tool_approval.is_some())
+        .map(|session| session.id.clone())
+}
+
+/// Handles key input while the tool approval overlay is visible.
+///
+/// Returns `None` for control-modified keys so shortcuts such as `Ctrl+c`
+/// still reach the underlying mode and can cancel the blocked turn.
+pub(crate) fn handle(app: &mut App, session_id: &str, key: KeyEvent) -> Option<EventResult> {
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        return None;
+    }
+
+    if let Some(decision) = decision_for_key(key) {
+        app.sessions
+            .resolve_session_tool_approval(session_id, decision);
+    }
+
+    Some(EventResult::Continue)
+}
+
+/// Maps one overlay key to the approval decision it selects.
+fn decision_for_key(key: KeyEvent) -> Option<ToolApprovalDecision> {
+    match key.code {
+        KeyCode::Char('y') | KeyCode::Enter => Some(ToolApprovalDecision::AllowOnce),
+        KeyCode::Char('a') => Some(ToolApprovalDecision::AllowForSession),
+        KeyCode::Char('n') | KeyCode::Esc => Some(ToolApprovalDecision::Deny),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::permission::{ToolApprovalKind, ToolApprovalRequest};
+
+    /// Builds a test app viewing one session whose turn awaits approval.
+    async fn new_test_app_with_pending_approval() -> (
+        App,
+        tempfile::TempDir,
+        SessionId,
+        tokio::sync::oneshot::Receiver<ToolApprovalDecision>,
+    ) {
+        let (mut app, base_dir) = crate::test_support::new_git_test_app().await;
+        let session_id = SessionId::from(
+            app.create_session()
+                .await
+                .expect("failed to create session"),
+        );
+        let receiver = app.sessions.session_handles()[&session_id]
+            .tool_approval_queue()
+            .lock()
+            .expect("approval queue lock should be available")
+            .push(ToolApprovalRequest {
+                detail: Some("in /tmp/worktree".to_string()),
+                kind: ToolApprovalKind::Command,
+                summary: "cargo publish".to_string(),
+            });
+        app.sessions.sync_session_from_handle(&session_id);
+        app.mode = AppMode::View {
+            scroll_offset: None,
+            session_id: session_id.clone(),
+        };
+
+        (app, base_dir, session_id, receiver)
+    }
+
+    #[tokio::test]
+    async fn test_handle_allow_for_session_key_unblocks_turn_and_clears_overlay() {
+        // Arrange
+        let (mut app, _base_dir, session_id, receiver) = new_test_app_with_pending_approval().await;
+        let pending_before_key = pending_session_id(&app);
+
+        // Act
+        let event_result = handle(
+            &mut app,
+            &session_id,
+            KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE),
+        );
+
+        // Assert
+        assert_eq!(pending_before_key, Some(session_id));
+        assert!(matches!(event_result, Some(EventResult::Continue)));
+        assert_eq!(
+            receiver.await.expect("decision should be delivered"),
+            ToolApprovalDecision::AllowForSession
+        );
+        assert_eq!(pending_session_id(&app), None);
+    }
+
+    #[tokio::test]
+    async fn test_handle_passes_control_keys_through_to_session_mode() {
+        // Arrange
+        let (mut app, _base_dir, session_id, _receiver) =
+            new_test_app_with_pending_approval().await;
+
+        // Act
+        let event_result = handle(
+            &mut app,
+            &session_id,
+            KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
+        );
+
+        // Assert
+        assert!(event_result.is_none());
+        assert_eq!(pending_session_id(&app), Some(session_id));
+    }
+
+    #[test]
+    fn test_decision_for_key_maps_overlay_shortcuts() {
+        // Arrange
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+        // Act / Assert
+        assert_eq!(
+            decision_for_key(key(KeyCode::Char('y'))),
+            Some(ToolApprovalDecision::AllowOnce)
+        );
+        assert_eq!(
+            decision_for_key(key(KeyCode::Esc)),
+            Some(ToolApprovalDecision::Deny)
+        );
+        assert_eq!(decision_for_key(key(KeyCode::Char('x'))), None);
+    }
+}
@@ -0,0 +1,165 @@
+//! Serializable session projections shared by external frontends.
+//!
+//! Headless commands and the control server expose the same field names so
+//! scripts can move between them without remapping session payloads.
+
+use ag_session::{QuestionItem, Session, SessionMessageKind, SessionSummary};
+use serde::Serialize;
+
+/// Compact list projection for one session.
+#[derive(Debug, Serialize)]
+pub(crate) struct SessionSummaryView {
+    id: String,
+    status: String,
+    title: Option<String>,
+    updated_at: i64,
+}
+
+impl SessionSummaryView {
+    /// Projects the listed fields from one session summary.
+    pub(crate) fn new(session: &SessionSummary) -> Self {
+        Self {
+            id: session.id.to_string(),
+            status: session.status.to_string(),
+            title: session.title.clone(),
+            updated_at: session.updated_at,
+        }
+    }
+
+    /// Formats one tab-separated text row.
+    pub(crate) fn to_text(&self) -> String {
+        format!(
+            "{}\t{}\t{}\n",
+            self.id,
+            self.status,
+            self.title.as_deref().unwrap_or("")
+        )
+    }
+}
+
+/// Complete external projection for one session.
+#[derive(Debug, Serialize)]
+pub(crate) struct SessionDetailView {
+    agent: String,
+    base_branch: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diff: Option<String>,
+    id: String,
+    messages: Vec<SessionMessageView>,
+    model: String,
+    questions: Vec<QuestionItem>,
+    review_request_url: Option<String>,
+    status: String,
+    summary: Option<String>,
+    title: Option<String>,
+    updated_at: i64,
+}
+
+impl SessionDetailView {
+    /// Projects one session aggregate and its optional diff.
+    pub(crate) fn new(session: &Session, diff: Option<String>) -> Self {
+        Self {
+            agent: session.settings.agent.kind().to_string(),
+            base_branch: session.settings.base_branch.clone(),
+            diff: diff.filter(|diff| !diff.trim().is_empty()),
+            id: session.id.to_string(),
+            messages: session
+                .messages
+                .iter()
+                .map(|message| SessionMessageView {
+                    content: message.content.clone(),
+                    kind: message.kind.as_str(),
+                })
+                .collect(),
+            model: session.settings.agent.model().as_str().to_string(),
+            questions: session.questions.clone(),
+            review_request_url: session
+                .review_request
+                .as_ref()
+                .map(|review_request| review_request.summary.web_url.clone()),
+            status: session.status.to_string(),
+            summary: session.summary.clone(),
+            title: session.title.clone(),
+            updated_at: session.updated_at,
+        }
+    }
+
+    /// Formats a readable header, visible transcript, questions, and diff.
+    pub(crate) fn to_text(&self) -> String {
+        let mut sections = vec![format!(
+            "{}\t{}\t{}",
+            self.id,
+            self.status,
+            self.title.as_deref().unwrap_or("")
+        )];
+        sections.extend(self.summary.clone());
+        sections.extend(
+            self.messages
+                .iter()
+                .filter(|message| message.kind != SessionMessageKind::AgentPrompt.as_str())
+                .map(|message| format!("[{}]\n{}", message.kind, message.content)),
+        );
+        sections.extend(
+            self.questions
+                .iter()
+                .map(|question| format!("[question]\n{}", question.text)),
+        );
+        sections.extend(
+            self.diff
+                .as_deref()
+                .map(|diff| diff.trim_end_matches('\n').to_string()),
+        );
+
+        format!("{}\n", sections.join("\n\n"))
+    }
+}
+
+/// Output projection for one transcript message.
+#[derive(Debug, Serialize)]
+pub(crate) struct SessionMessageView {
+    content: String,
+    kind: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detail_text_hides_agent_prompts_and_appends_diff() {
+        // Arrange
+        let view = SessionDetailView {
+            agent: "codex".to_string(),
+            base_branch: "main".to_string(),
+            diff: Some("diff --git a/a b/a".to_string()),
+            id: "session-1".to_string(),
+            messages: vec![
+                SessionMessageView {
+                    content: "hidden".to_string(),
+                    kind: SessionMessageKind::AgentPrompt.as_str(),
+                },
+                SessionMessageView {
+                    content: "shown".to_string(),
+                    kind: SessionMessageKind::UserPrompt.as_str(),
+                },
+            ],
+            model: "gpt-5.6-sol".to_string(),
+            questions: vec![QuestionItem::new("Which branch?")],
+            review_request_url: None,
+            status: "Question".to_string(),
+            summary: None,
+            title: Some("Fix".to_string()),
+            updated_at: 0,
+        };
+
+        // Act
+        let text = view.to_text();
+
+        // Assert
+        assert_eq!(
+            text,
+            "session-1\tQuestion\tFix\n\n[user_prompt]\nshown\n\n[question]\nWhich \
+             branch?\n\ndiff --git a/a b/a\n"
+        );
+    }
+}
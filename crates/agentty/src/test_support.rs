@@ -259,6 +259,7 @@ impl SessionFixtureBuilder {
                 reasoning_level_override: None,
                 published_upstream_ref: None,
                 questions: Vec::new(),
+                review_checks: None,
                 review_request: None,
                 size: SessionSize::Xs,
                 speed_mode: crate::domain::agent::SpeedMode::default(),
@@ -269,6 +270,9 @@ impl SessionFixtureBuilder {
                 transcript: None,
                 updated_at: 0,
                 transient_messages: TransientMessageStore::default(),
+                pending_tool_approval: None,
+                post_turn_checks: None,
+                activity_log_expanded: false,
             },
         }
     }
@@ -280,6 +284,13 @@ impl SessionFixtureBuilder {
         self
     }
 
+    /// Overrides the base branch or imported base commit.
+    pub(crate) fn base_branch(mut self, base_branch: impl Into<String>) -> Self {
+        self.session.base_branch = base_branch.into();
+
+        self
+    }
+
     /// Overrides the draft flag.
     pub(crate) fn draft(mut self, is_draft: bool) -> Self {
         self.session.is_draft = is_draft;
@@ -446,6 +457,7 @@ pub(crate) fn test_app_clients_with_available_agent_kinds(
         .returning(|_, _| Box::pin(async { Ok(Vec::new()) }));
 
     app::AppClients::new()
+        .expect("app clients should build")
         .with_agent_availability_probe(Arc::new(StaticAgentAvailabilityProbe {
             available_agent_kinds,
         }))
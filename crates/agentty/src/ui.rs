@@ -1,3 +1,5 @@
+/// Live turn activity log formatting.
+pub(crate) mod activity_format;
 /// Calendar-style recent session activity renderer.
 pub mod activity_heatmap;
 mod app_render;
@@ -0,0 +1,369 @@
+//! Live turn activity log formatting.
+
+use ag_tui_text::text_util;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use crate::domain::session_message::TurnActivity;
+use crate::ui::icon::Icon;
+use crate::ui::style;
+
+const ACTIVITY_ENTRY_INDENT: &str = "  ";
+
+/// One display row in the activity log after start/finish pairing.
+#[derive(Debug, Eq, PartialEq)]
+enum ActivityEntry {
+    /// Patched file with its line counts.
+    File {
+        added_lines: u64,
+        deleted_lines: u64,
+        path: String,
+    },
+    /// Fetched URL or web search query.
+    Fetch { target: String },
+    /// Tool call with its latest known state.
+    Tool {
+        call_id: Option<String>,
+        command: Option<String>,
+        state: ToolState,
+        tool: String,
+    },
+}
+
+/// Lifecycle state of one paired tool call.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ToolState {
+    Failed(Option<i32>),
+    Running,
+    Succeeded,
+}
+
+/// Returns the rendered activity log for one run of consecutive activity rows.
+///
+/// The collapsed form shows a summary header plus the newest entry so long
+/// turns stay compact; the expanded form lists every entry in order.
+pub(crate) fn activity_log_lines(
+    activities: &[TurnActivity],
+    expanded: bool,
+    inner_width: usize,
+) -> Vec<Line<'static>> {
+    let entries = activity_entries(activities);
+    let Some(latest_entry) = entries.last() else {
+        return Vec::new();
+    };
+    let marker = if expanded { "▾" } else { "▸" };
+    let mut lines = vec![Line::from(Span::styled(
+        text_util::truncate_with_ellipsis(
+            &format!("{marker} Activity{}", activity_summary(&entries)),
+            inner_width,
+        ),
+        Style::default()
+            .fg(style::palette::text_muted())
+            .add_modifier(Modifier::BOLD),
+    ))];
+
+    if expanded {
+        lines.extend(
+            entries
+                .iter()
+                .map(|entry| activity_entry_line(entry, inner_width)),
+        );
+    } else {
+        lines.push(activity_entry_line(latest_entry, inner_width));
+    }
+
+    lines
+}
+
+/// Pairs tool start and finish events into display entries.
+///
+/// Finishes without a call id fall back to the newest running call with the
+/// same tool and command. Finishes whose call id matches no started tool
+/// belong to edits or fetches that already have their own entry, so they are
+/// dropped.
+fn activity_entries(activities: &[TurnActivity]) -> Vec<ActivityEntry> {
+    let mut entries: Vec<ActivityEntry> = Vec::new();
+
+    for activity in activities {
+        match activity {
+            TurnActivity::ToolStarted {
+                call_id,
+                command,
+                tool,
+            } => {
+                if call_id.is_some()
+                    && find_tool_entry(&mut entries, call_id.as_ref(), None).is_some()
+                {
+                    continue;
+                }
+
+                entries.push(ActivityEntry::Tool {
+                    call_id: call_id.clone(),
+                    command: command.clone(),
+                    state: ToolState::Running,
+                    tool: tool.clone(),
+                });
+            }
+            TurnActivity::ToolFinished {
+                call_id,
+                command,
+                exit_code,
+                succeeded,
+                tool,
+            } => {
+                let finished_state = if *succeeded {
+                    ToolState::Succeeded
+                } else {
+                    ToolState::Failed(*exit_code)
+                };
+                let fallback_key = (tool.as_str(), command.as_deref());
+                if let Some(ActivityEntry::Tool {
+                    command: entry_command,
+                    state,
+                    ..
+                }) = find_tool_entry(&mut entries, call_id.as_ref(), Some(fallback_key))
+                {
+                    *state = finished_state;
+                    if entry_command.is_none() {
+                        entry_command.clone_from(command);
+                    }
+                } else if call_id.is_none() {
+                    entries.push(ActivityEntry::Tool {
+                        call_id: None,
+                        command: command.clone(),
+                        state: finished_state,
+                        tool: tool.clone(),
+                    });
+                }
+            }
+            TurnActivity::FilePatched {
+                added_lines,
+                deleted_lines,
+                path,
+            } => entries.push(ActivityEntry::File {
+                added_lines: *added_lines,
+                deleted_lines: *deleted_lines,
+                path: path.clone(),
+            }),
+            TurnActivity::WebFetch { target } => entries.push(ActivityEntry::Fetch {
+                target: target.clone(),
+            }),
+        }
+    }
+
+    entries
+}
+
+/// Returns the newest tool entry matching `call_id`, or the newest running
+/// entry matching `fallback_key` when no call id is available.
+fn find_tool_entry<'entries>(
+    entries: &'entries mut [ActivityEntry],
+    call_id: Option<&String>,
+    fallback_key: Option<(&str, Option<&str>)>,
+) -> Option<&'entries mut ActivityEntry> {
+    entries.iter_mut().rev().find(|entry| {
+        let ActivityEntry::Tool {
+            call_id: entry_call_id,
+            command: entry_command,
+            state,
+            tool: entry_tool,
+        } = entry
+        else {
+            return false;
+        };
+
+        match call_id {
+            Some(call_id) => entry_call_id.as_ref() == Some(call_id),
+            None => fallback_key.is_some_and(|(tool, command)| {
+                *state == ToolState::Running
+                    && entry_tool == tool
+                    && entry_command.as_deref() == command
+            }),
+        }
+    })
+}
+
+/// Returns the ` · N tool calls · M file edits (+a −d) · K fetches` header
+/// suffix, omitting empty categories.
+fn activity_summary(entries: &[ActivityEntry]) -> String {
+    let mut tool_count = 0;
+    let mut file_count = 0;
+    let mut fetch_count = 0;
+    let mut added_total = 0;
+    let mut deleted_total = 0;
+    for entry in entries {
+        match entry {
+            ActivityEntry::File {
+                added_lines,
+                deleted_lines,
+                ..
+            } => {
+                file_count += 1;
+                added_total += added_lines;
+                deleted_total += deleted_lines;
+            }
+            ActivityEntry::Fetch { .. } => fetch_count += 1,
+            ActivityEntry::Tool { .. } => tool_count += 1,
+        }
+    }
+
+    let mut parts = Vec::new();
+    if tool_count > 0 {
+        parts.push(count_label(tool_count, "tool call", "tool calls"));
+    }
+    if file_count > 0 {
+        parts.push(format!(
+            "{} (+{added_total} −{deleted_total})",
+            count_label(file_count, "file edit", "file edits")
+        ));
+    }
+    if fetch_count > 0 {
+        parts.push(count_label(fetch_count, "fetch", "fetches"));
+    }
+
+    let mut summary = String::new();
+    for part in parts {
+        summary.push_str(" · ");
+        summary.push_str(&part);
+    }
+
+    summary
+}
+
+/// Formats one count with its singular or plural noun.
+fn count_label(count: usize, singular: &str, plural: &str) -> String {
+    let noun = if count == 1 { singular } else { plural };
+
+    format!("{count} {noun}")
+}
+
+/// Renders one indented activity entry row.
+fn activity_entry_line(entry: &ActivityEntry, inner_width: usize) -> Line<'static> {
+    let (icon, icon_color, text) = match entry {
+        ActivityEntry::File {
+            added_lines,
+            deleted_lines,
+            path,
+        } => (
+            "±",
+            style::palette::info(),
+            format!("{path} (+{added_lines} −{deleted_lines})"),
+        ),
+        ActivityEntry::Fetch { target } => ("↗", style::palette::info(), target.clone()),
+        ActivityEntry::Tool {
+            command,
+            state,
+            tool,
+            ..
+        } => {
+            let label = command.as_deref().unwrap_or(tool.as_str()).to_string();
+            match state {
+                ToolState::Running => (Icon::Pending.as_str(), style::palette::text_muted(), label),
+                ToolState::Succeeded => (Icon::Check.as_str(), style::palette::success(), label),
+                ToolState::Failed(Some(exit_code)) => (
+                    Icon::Cross.as_str(),
+                    style::palette::danger(),
+                    format!("{label} (exit {exit_code})"),
+                ),
+                ToolState::Failed(None) => (Icon::Cross.as_str(), style::palette::danger(), label),
+            }
+        }
+    };
+    let text_width = inner_width.saturating_sub(ACTIVITY_ENTRY_INDENT.len() + 2);
+
+    Line::from(vec![
+        Span::raw(ACTIVITY_ENTRY_INDENT),
+        Span::styled(format!("{icon} "), Style::default().fg(icon_color)),
+        Span::styled(
+            text_util::truncate_with_ellipsis(&text_util::inline_text(&text), text_width),
+            Style::default().fg(style::palette::text_muted()),
+        ),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the plain text of rendered activity lines.
+    fn line_texts(lines: &[Line<'static>]) -> Vec<String> {
+        lines.iter().map(ToString::to_string).collect()
+    }
+
+    /// Builds a turn with a failed command, an edit, and a running command.
+    fn sample_activities() -> Vec<TurnActivity> {
+        vec![
+            TurnActivity::ToolStarted {
+                call_id: Some("call-1".to_string()),
+                command: Some("cargo test".to_string()),
+                tool: "Bash".to_string(),
+            },
+            TurnActivity::ToolFinished {
+                call_id: Some("call-1".to_string()),
+                command: None,
+                exit_code: Some(101),
+                succeeded: false,
+                tool: "tool".to_string(),
+            },
+            TurnActivity::FilePatched {
+                added_lines: 3,
+                deleted_lines: 1,
+                path: "src/lib.rs".to_string(),
+            },
+            TurnActivity::ToolFinished {
+                call_id: Some("edit-1".to_string()),
+                command: None,
+                exit_code: None,
+                succeeded: true,
+                tool: "tool".to_string(),
+            },
+            TurnActivity::ToolStarted {
+                call_id: None,
+                command: Some("cargo clippy".to_string()),
+                tool: "command".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_activity_log_lines_collapsed_shows_summary_and_latest_entry() {
+        // Arrange
+        let activities = sample_activities();
+
+        // Act
+        let lines = activity_log_lines(&activities, false, 80);
+
+        // Assert
+        assert_eq!(
+            line_texts(&lines),
+            vec![
+                "▸ Activity · 2 tool calls · 1 file edit (+3 −1)".to_string(),
+                "  · cargo clippy".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_activity_log_lines_expanded_pairs_finishes_with_starts() {
+        // Arrange
+        let activities = sample_activities();
+
+        // Act
+        let lines = activity_log_lines(&activities, true, 80);
+
+        // Assert
+        assert_eq!(
+            line_texts(&lines),
+            vec![
+                "▾ Activity · 2 tool calls · 1 file edit (+3 −1)".to_string(),
+                "  ✗ cargo test (exit 101)".to_string(),
+                "  ± src/lib.rs (+3 −1)".to_string(),
+                "  · cargo clippy".to_string(),
+            ]
+        );
+        assert_eq!(
+            activity_log_lines(&[], true, 80),
+            Vec::<Line<'static>>::new()
+        );
+    }
+}
@@ -24,7 +24,10 @@ pub(crate) fn render_app(
                 session_id: review.session_id,
                 text: review.text,
             });
-    let _theme_scope = style::scoped_active_theme(snapshot.theme);
+    let _theme_scope = match snapshot.custom_theme {
+        Some(custom_theme) => style::scoped_active_custom_theme(custom_theme),
+        None => style::scoped_active_theme(snapshot.theme),
+    };
 
     super::render(
         frame,
@@ -38,6 +41,7 @@ pub(crate) fn render_app(
             git_upstream_ref: snapshot.git_upstream_ref,
             git_status: snapshot.git_status,
             is_tmux_session: snapshot.is_tmux_session,
+            keymap: snapshot.keymap,
             latest_available_version: snapshot.latest_available_version,
             update_status: snapshot.update_status,
             mode: snapshot.mode,
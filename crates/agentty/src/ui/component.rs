@@ -22,6 +22,8 @@ pub mod project_switcher_overlay;
 pub mod publish_branch_overlay;
 /// Calm pulse for queued-action indicators.
 pub mod queue_pulse;
+/// Review-request number or URL input popup.
+pub mod review_request_import_overlay;
 /// New-session action selector popup.
 pub mod session_creation_overlay;
 /// Session transcript, progress, and result rendering.
@@ -32,5 +34,7 @@ pub mod status_bar;
 pub mod tab;
 /// Animated terminal loading indicator.
 pub mod tachyon_loader;
+/// `Ask`-mode provider approval popup.
+pub mod tool_approval_overlay;
 /// Reusable terminal scrollbar component.
 pub mod vertical_scrollbar;
@@ -474,7 +474,7 @@ mod tests {
     #[test]
     fn test_render_uses_palette_border_for_file_explorer() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
         let parsed_lines = vec![DiffLine {
             kind: DiffLineKind::FileHeader,
             old_line: None,
@@ -5,6 +5,7 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
 use crate::presentation::app_mode::HelpContext;
+use crate::presentation::keymap::Keymap;
 use crate::ui::style::palette;
 use crate::ui::{Component, overlay};
 
@@ -22,14 +23,17 @@ const SCROLL_X_OFFSET: u16 = 0;
 pub struct HelpOverlay<'a> {
     context: &'a HelpContext,
 
+    keymap: &'a Keymap,
+
     scroll_offset: u16,
 }
 
 impl<'a> HelpOverlay<'a> {
-    /// Creates a help overlay for the given context.
-    pub fn new(context: &'a HelpContext) -> Self {
+    /// Creates a help overlay for the given context and user keymap.
+    pub fn new(context: &'a HelpContext, keymap: &'a Keymap) -> Self {
         Self {
             context,
+            keymap,
             scroll_offset: 0,
         }
     }
@@ -48,7 +52,7 @@ impl Component for HelpOverlay<'_> {
 
         overlay::clear_popup_area(f, popup_area);
 
-        let bindings = self.context.keybindings();
+        let bindings = self.context.keybindings(self.keymap);
 
         let key_width = bindings
             .iter()
@@ -161,9 +165,10 @@ mod tests {
         let context = HelpContext::List {
             keybindings: vec![HelpAction::new("quit", "q", "Quit")],
         };
+        let keymap = Keymap::default();
 
         // Act
-        let overlay = HelpOverlay::new(&context).scroll_offset(5);
+        let overlay = HelpOverlay::new(&context, &keymap).scroll_offset(5);
 
         // Assert
         assert_eq!(overlay.scroll_offset, 5);
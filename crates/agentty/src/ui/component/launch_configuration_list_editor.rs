@@ -19,8 +19,8 @@ const MIN_OVERLAY_WIDTH: u16 = 58;
 const OVERLAY_DIMENSIONS: overlay::OverlayDimensions =
     overlay::OverlayDimensions::new(70, 42, MIN_OVERLAY_WIDTH, MIN_OVERLAY_HEIGHT);
 
-/// Centered popup that edits the project-scoped `Launch Configurations` setting
-/// as a discrete command list.
+/// Centered popup that edits one project-scoped command-list setting, such as
+/// `Launch Configurations` or `Merge Gate Commands`, as discrete commands.
 pub struct LaunchConfigurationListEditor<'a> {
     editor: &'a LaunchConfigurationListEditorSnapshot,
 }
@@ -149,10 +149,7 @@ impl Component for LaunchConfigurationListEditor<'_> {
         let paragraph = Paragraph::new(lines)
             .alignment(Alignment::Left)
             .wrap(Wrap { trim: true })
-            .block(overlay::overlay_block(
-                "Launch Configurations",
-                palette::accent(),
-            ));
+            .block(overlay::overlay_block(self.editor.title, palette::accent()));
 
         overlay::clear_popup_area(f, popup_area);
         f.render_widget(paragraph, popup_area);
@@ -240,6 +237,7 @@ mod tests {
             input: None,
             mode: LaunchConfigurationListEditorMode::Browse,
             selected_index: 1,
+            title: "Launch Configurations",
         };
         let component = LaunchConfigurationListEditor::new(&editor);
 
@@ -272,6 +270,7 @@ mod tests {
             input: None,
             mode: LaunchConfigurationListEditorMode::Browse,
             selected_index: 1,
+            title: "Launch Configurations",
         };
         let component = LaunchConfigurationListEditor::new(&editor);
 
@@ -303,6 +302,7 @@ mod tests {
             input: Some(input),
             mode: LaunchConfigurationListEditorMode::Add,
             selected_index: 0,
+            title: "Launch Configurations",
         };
         let component = LaunchConfigurationListEditor::new(&editor);
 
@@ -5,12 +5,19 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Borders, Paragraph, Wrap};
 
 use crate::domain::input::InputState;
+use crate::presentation::publish_form::{PublishFormField, ReviewRequestPublishForm};
 use crate::ui::component::chat_input::ChatInput;
 use crate::ui::style::palette;
 use crate::ui::{Component, overlay};
 
 const REVIEW_REQUEST_EDITABLE_HELP_TEXT: &str = "Enter: publish review request | Esc: cancel";
 const REVIEW_REQUEST_LOCKED_HELP_TEXT: &str = "Enter: refresh review request | Esc: cancel";
+const REVIEW_REQUEST_FORM_HELP_TEXT: &str = "Enter: publish | Esc: cancel";
+const REVIEW_REQUEST_FORM_NAVIGATION_TEXT: &str = "↑↓: field | Space: draft | Tab: complete";
+const CHOICES_LOADING_TEXT: &str = "Loading labels, reviewers, and milestones from the forge...";
+const LABELS_TITLE: &str = "Labels";
+const MILESTONE_TITLE: &str = "Milestone";
+const REVIEWERS_TITLE: &str = "Reviewers";
 const REVIEW_REQUEST_TITLE: &str = "Publish Review Request";
 const INPUT_TITLE: &str = "Remote Branch";
 const MIN_OVERLAY_HEIGHT: u16 = 11;
@@ -18,6 +25,13 @@ const MIN_OVERLAY_WIDTH: u16 = 58;
 /// Popup dimensions for branch publishing and review-request refresh.
 const OVERLAY_DIMENSIONS: overlay::OverlayDimensions =
     overlay::OverlayDimensions::new(62, 42, MIN_OVERLAY_WIDTH, MIN_OVERLAY_HEIGHT);
+const MIN_FORM_OVERLAY_HEIGHT: u16 = 22;
+/// Height of the draft row, three metadata inputs, and the hint row.
+const FORM_SECTION_HEIGHT: u16 = 11;
+/// Taller popup dimensions used when the overlay also collects review-request
+/// metadata.
+const FORM_OVERLAY_DIMENSIONS: overlay::OverlayDimensions =
+    overlay::OverlayDimensions::new(62, 70, MIN_OVERLAY_WIDTH, MIN_FORM_OVERLAY_HEIGHT);
 
 /// Centered popup that collects an optional remote branch name before
 /// publishing or refreshing the linked review request.
@@ -25,6 +39,7 @@ pub struct PublishBranchOverlay<'a> {
     default_branch_name: &'a str,
     input: &'a InputState,
     locked_upstream_ref: Option<&'a str>,
+    review_request_form: Option<&'a ReviewRequestPublishForm>,
 }
 
 impl<'a> PublishBranchOverlay<'a> {
@@ -38,9 +53,21 @@ impl<'a> PublishBranchOverlay<'a> {
             default_branch_name,
             input,
             locked_upstream_ref,
+            review_request_form: None,
         }
     }
 
+    /// Adds draft, label, reviewer, and milestone fields for a new review
+    /// request.
+    #[must_use]
+    pub fn review_request_form(
+        mut self,
+        review_request_form: Option<&'a ReviewRequestPublishForm>,
+    ) -> Self {
+        self.review_request_form = review_request_form;
+        self
+    }
+
     /// Returns the placeholder shown before the first publish.
     fn placeholder(&self) -> String {
         format!("Leave blank to push as `{}`", self.default_branch_name)
@@ -62,13 +89,24 @@ impl<'a> PublishBranchOverlay<'a> {
 
     /// Returns the footer help line for the current overlay state.
     fn help_text(&self) -> &'static str {
-        if self.locked_upstream_ref.is_some() {
+        if self.review_request_form.is_some() {
+            REVIEW_REQUEST_FORM_HELP_TEXT
+        } else if self.locked_upstream_ref.is_some() {
             REVIEW_REQUEST_LOCKED_HELP_TEXT
         } else {
             REVIEW_REQUEST_EDITABLE_HELP_TEXT
         }
     }
 
+    /// Returns whether `field` owns keyboard focus, treating the plain
+    /// branch-only overlay as always focused on the branch input.
+    fn is_focused(&self, field: PublishFormField) -> bool {
+        self.review_request_form
+            .map_or(field == PublishFormField::Branch, |form| {
+                form.focus == field
+            })
+    }
+
     /// Returns the foreground style for non-editable branch text.
     fn locked_branch_text_style() -> Style {
         Style::default().fg(palette::text())
@@ -103,13 +141,24 @@ impl<'a> PublishBranchOverlay<'a> {
 
 impl Component for PublishBranchOverlay<'_> {
     fn render(&self, f: &mut Frame, area: Rect) {
-        let popup_area = OVERLAY_DIMENSIONS.centered_popup_area(area);
+        let dimensions = if self.review_request_form.is_some() {
+            FORM_OVERLAY_DIMENSIONS
+        } else {
+            OVERLAY_DIMENSIONS
+        };
+        let popup_area = dimensions.centered_popup_area(area);
         let block = overlay::overlay_block(REVIEW_REQUEST_TITLE, palette::accent());
         let inner_area = block.inner(popup_area);
+        let (form_height, help_height) = if self.review_request_form.is_some() {
+            (FORM_SECTION_HEIGHT, 2)
+        } else {
+            (0, 1)
+        };
         let sections = Layout::vertical([
             Constraint::Min(2),
             Constraint::Length(3),
-            Constraint::Length(1),
+            Constraint::Length(form_height),
+            Constraint::Length(help_height),
         ])
         .split(inner_area);
         let message = Paragraph::new(Line::from(vec![Span::styled(
@@ -117,13 +166,18 @@ impl Component for PublishBranchOverlay<'_> {
             Style::default().fg(palette::text_muted()),
         )]))
         .wrap(Wrap { trim: true });
-        let help = Paragraph::new(
-            Line::from(vec![Span::styled(
-                self.help_text(),
+        let mut help_lines = Vec::new();
+        if self.review_request_form.is_some() {
+            help_lines.push(Line::from(Span::styled(
+                REVIEW_REQUEST_FORM_NAVIGATION_TEXT,
                 Style::default().fg(palette::text_muted()),
-            )])
-            .alignment(Alignment::Center),
-        );
+            )));
+        }
+        help_lines.push(Line::from(Span::styled(
+            self.help_text(),
+            Style::default().fg(palette::text_muted()),
+        )));
+        let help = Paragraph::new(help_lines).alignment(Alignment::Center);
 
         overlay::clear_popup_area(f, popup_area);
         f.render_widget(block, popup_area);
@@ -134,15 +188,103 @@ impl Component for PublishBranchOverlay<'_> {
             let placeholder = self.placeholder();
             let input = ChatInput::new(INPUT_TITLE, self.input.text(), self.input.cursor)
                 .placeholder(&placeholder)
-                .clear_style(
-                    Style::default()
-                        .fg(palette::text())
-                        .bg(palette::surface_overlay()),
-                );
+                .active(self.is_focused(PublishFormField::Branch))
+                .clear_style(overlay_input_clear_style());
 
             input.render(f, sections[1]);
         }
-        f.render_widget(help, sections[2]);
+        if let Some(review_request_form) = self.review_request_form {
+            render_review_request_form(f, sections[2], review_request_form);
+        }
+        f.render_widget(help, sections[3]);
+    }
+}
+
+/// Returns the style reapplied after clearing overlay-hosted inputs.
+fn overlay_input_clear_style() -> Style {
+    Style::default()
+        .fg(palette::text())
+        .bg(palette::surface_overlay())
+}
+
+/// Renders the draft toggle, metadata inputs, and completion hint.
+fn render_review_request_form(f: &mut Frame, area: Rect, form: &ReviewRequestPublishForm) {
+    let rows = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(1),
+    ])
+    .split(area);
+    let draft_style = if form.focus == PublishFormField::Draft {
+        Style::default()
+            .fg(palette::accent())
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(palette::text())
+    };
+    let draft_label = if form.draft {
+        "[x] Open as draft"
+    } else {
+        "[ ] Open as draft (ready for review)"
+    };
+    let fields = [
+        (
+            LABELS_TITLE,
+            &form.labels,
+            PublishFormField::Labels,
+            "bug, enhancement",
+        ),
+        (
+            REVIEWERS_TITLE,
+            &form.reviewers,
+            PublishFormField::Reviewers,
+            "alice, org/team",
+        ),
+        (
+            MILESTONE_TITLE,
+            &form.milestone,
+            PublishFormField::Milestone,
+            "None",
+        ),
+    ];
+
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(draft_label, draft_style))),
+        rows[0],
+    );
+    for ((title, input, field, placeholder), row) in fields.into_iter().zip(&rows[1..4]) {
+        ChatInput::new(title, input.text(), input.cursor)
+            .placeholder(placeholder)
+            .active(form.focus == field)
+            .clear_style(overlay_input_clear_style())
+            .render(f, *row);
+    }
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            review_request_form_hint(form),
+            Style::default().fg(palette::text_muted()),
+        ))),
+        rows[4],
+    );
+}
+
+/// Returns the completion hint for the focused field, or the choice-loading
+/// status when no completion applies.
+fn review_request_form_hint(form: &ReviewRequestPublishForm) -> String {
+    if let Some(error) = &form.choices_error {
+        return error.clone();
+    }
+    if form.choices.is_none() {
+        return CHOICES_LOADING_TEXT.to_string();
+    }
+
+    let suggestions = form.suggestions();
+    if suggestions.is_empty() {
+        String::new()
+    } else {
+        format!("Tab: {}", suggestions.join(", "))
     }
 }
 
@@ -231,7 +373,7 @@ mod tests {
     #[test]
     fn test_publish_branch_overlay_green_theme_uses_session_list_text_color_for_locked_branch() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::Green);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Green);
         let backend = ratatui::backend::TestBackend::new(120, 40);
         let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
         let input = InputState::with_text("review/custom".to_string());
@@ -283,4 +425,45 @@ mod tests {
         assert!(text.contains("review request"));
         assert!(text.contains(REVIEW_REQUEST_EDITABLE_HELP_TEXT));
     }
+
+    #[test]
+    fn test_publish_branch_overlay_render_shows_review_request_form_fields() {
+        // Arrange
+        let backend = ratatui::backend::TestBackend::new(120, 40);
+        let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
+        let input = InputState::default();
+        let form = ReviewRequestPublishForm {
+            choices: Some(ag_forge::ReviewRequestPublishChoices {
+                labels: vec!["bug".to_string(), "docs".to_string()],
+                milestones: Vec::new(),
+                reviewers: Vec::new(),
+            }),
+            focus: PublishFormField::Labels,
+            ..ReviewRequestPublishForm::default()
+        };
+        let overlay =
+            PublishBranchOverlay::new(&input, "wt/ff45463f", None).review_request_form(Some(&form));
+
+        // Act
+        terminal
+            .draw(|frame| {
+                let area = frame.area();
+                overlay.render(frame, area);
+            })
+            .expect("failed to draw");
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+        assert!(text.contains("[x] Open as draft"));
+        assert!(text.contains(LABELS_TITLE));
+        assert!(text.contains(REVIEWERS_TITLE));
+        assert!(text.contains(MILESTONE_TITLE));
+        assert!(text.contains("Tab: bug, docs"));
+        assert!(text.contains("Space: draft"));
+    }
 }
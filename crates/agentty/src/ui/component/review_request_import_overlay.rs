@@ -0,0 +1,109 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, Wrap};
+
+use crate::domain::input::InputState;
+use crate::ui::component::chat_input::ChatInput;
+use crate::ui::style::palette;
+use crate::ui::{Component, overlay};
+
+const HELP_TEXT: &str = "Enter: import | Esc: cancel";
+const INPUT_PLACEHOLDER: &str = "42, #42, or a review-request URL";
+const INPUT_TITLE: &str = "Review Request";
+const MESSAGE_TEXT: &str = "Number or URL of an open review request in this repository. Agentty \
+                            checks out its head branch in a new session and starts the agent on \
+                            its unresolved review threads.";
+const OVERLAY_TITLE: &str = "Import Review Request";
+const MIN_OVERLAY_HEIGHT: u16 = 11;
+const MIN_OVERLAY_WIDTH: u16 = 58;
+/// Popup dimensions for the review-request reference input.
+const OVERLAY_DIMENSIONS: overlay::OverlayDimensions =
+    overlay::OverlayDimensions::new(62, 42, MIN_OVERLAY_WIDTH, MIN_OVERLAY_HEIGHT);
+
+/// Centered popup that collects the review request imported into a new
+/// session.
+pub struct ReviewRequestImportOverlay<'a> {
+    input: &'a InputState,
+}
+
+impl<'a> ReviewRequestImportOverlay<'a> {
+    /// Creates an import popup for the provided input state.
+    pub fn new(input: &'a InputState) -> Self {
+        Self { input }
+    }
+}
+
+impl Component for ReviewRequestImportOverlay<'_> {
+    fn render(&self, f: &mut Frame, area: Rect) {
+        let popup_area = OVERLAY_DIMENSIONS.centered_popup_area(area);
+        let block = overlay::overlay_block(OVERLAY_TITLE, palette::accent());
+        let inner_area = block.inner(popup_area);
+        let sections = Layout::vertical([
+            Constraint::Min(2),
+            Constraint::Length(3),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+        let message = Paragraph::new(Line::from(Span::styled(
+            MESSAGE_TEXT,
+            Style::default().fg(palette::text_muted()),
+        )))
+        .wrap(Wrap { trim: true });
+        let help = Paragraph::new(
+            Line::from(Span::styled(
+                HELP_TEXT,
+                Style::default().fg(palette::text_muted()),
+            ))
+            .alignment(Alignment::Center),
+        );
+        let input = ChatInput::new(INPUT_TITLE, self.input.text(), self.input.cursor)
+            .placeholder(INPUT_PLACEHOLDER)
+            .clear_style(
+                Style::default()
+                    .fg(palette::text())
+                    .bg(palette::surface_overlay()),
+            );
+
+        overlay::clear_popup_area(f, popup_area);
+        f.render_widget(block, popup_area);
+        f.render_widget(message, sections[0]);
+        input.render(f, sections[1]);
+        f.render_widget(help, sections[2]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_review_request_import_overlay_render_shows_input_and_help_text() {
+        // Arrange
+        let backend = ratatui::backend::TestBackend::new(120, 40);
+        let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
+        let input = InputState::default();
+        let overlay = ReviewRequestImportOverlay::new(&input);
+
+        // Act
+        terminal
+            .draw(|frame| {
+                let area = frame.area();
+                overlay.render(frame, area);
+            })
+            .expect("failed to draw");
+
+        // Assert
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+        assert!(text.contains(OVERLAY_TITLE));
+        assert!(text.contains(INPUT_PLACEHOLDER));
+        assert!(text.contains(HELP_TEXT));
+    }
+}
@@ -9,7 +9,7 @@ use crate::ui::{Component, overlay};
 
 /// Minimum popup height that leaves room for title, options, and hints
 /// without adding unused vertical space.
-const MIN_OVERLAY_HEIGHT: u16 = 12;
+const MIN_OVERLAY_HEIGHT: u16 = 13;
 /// Minimum popup width sized for the longest option row plus shared overlay
 /// chrome.
 const MIN_OVERLAY_WIDTH: u16 = 49;
@@ -65,8 +65,9 @@ impl SessionCreationOverlay {
                 ORCHESTRATOR_SESSION_PREVIEW_DETAIL,
                 false,
             ),
+            self.option_line(3, "Import", "From open PR or MR", false),
             self.option_line(
-                3,
+                4,
                 "Stacked",
                 if self.can_create_stacked_session {
                     STACKED_SESSION_PREVIEW_DETAIL
@@ -170,9 +171,9 @@ mod tests {
 
         // Assert
         assert_eq!(popup_area.width, 49);
-        assert_eq!(popup_area.height, 12);
+        assert_eq!(popup_area.height, 13);
         assert_eq!(popup_area.x, 15);
-        assert_eq!(popup_area.y, 4);
+        assert_eq!(popup_area.y, 3);
     }
 
     #[test]
@@ -215,6 +216,7 @@ mod tests {
         assert!(text.contains("Regular"));
         assert!(text.contains("Draft"));
         assert!(text.contains(ORCHESTRATOR_SESSION_PREVIEW_DETAIL));
+        assert!(text.contains("Import"));
         assert!(text.contains("Stacked"));
         assert!(text.contains(STACKED_SESSION_PREVIEW_DETAIL));
         assert!(text.contains("j/k: move | Enter: select | q: close"));
@@ -264,7 +266,7 @@ mod tests {
 
         // Act
         let lines = overlay.lines();
-        let stacked_line = &lines[5];
+        let stacked_line = &lines[6];
 
         // Assert
         assert!(
@@ -284,11 +286,11 @@ mod tests {
     #[test]
     fn test_session_creation_overlay_lines_enable_stacked_option() {
         // Arrange
-        let overlay = SessionCreationOverlay::new(3, true);
+        let overlay = SessionCreationOverlay::new(4, true);
 
         // Act
         let lines = overlay.lines();
-        let stacked_line = &lines[5];
+        let stacked_line = &lines[6];
 
         // Assert
         assert!(
@@ -40,6 +40,7 @@ const SESSION_OUTPUT_LAYOUT_CACHE_ENTRY_LIMIT: usize = 16;
 struct SessionOutputLayoutCacheKey {
     active_progress: TextFingerprint,
     active_prompt_output: TextFingerprint,
+    activity_log_expanded: bool,
     draft_prompt: TextFingerprint,
     /// Whether the draft preview should render stacked-session start guidance.
     is_stacked_child: bool,
@@ -60,6 +61,7 @@ struct SessionOutputLayoutCacheKey {
 /// session-status tail.
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct SessionOutputBodyCacheKey {
+    activity_log_expanded: bool,
     draft_prompt: TextFingerprint,
     has_active_turn: bool,
     is_stacked_child: bool,
@@ -599,6 +601,7 @@ impl<'a> SessionOutput<'a> {
         SessionOutputLayoutCacheKey {
             active_progress: TextFingerprint::from_text(context.active_progress),
             active_prompt_output: TextFingerprint::from_text(context.active_prompt_output),
+            activity_log_expanded: session.activity_log_expanded,
             draft_prompt: Self::draft_prompt_fingerprint(session),
             is_stacked_child: session.is_stacked_child(),
             markdown_render_version,
@@ -631,6 +634,7 @@ impl<'a> SessionOutput<'a> {
             panel_inner_width(output_area, session_format::session_output_panel_borders());
 
         SessionOutputBodyCacheKey {
+            activity_log_expanded: session.activity_log_expanded,
             draft_prompt: Self::draft_prompt_fingerprint(session),
             has_active_turn: session_output_assembly::status_has_active_turn(session.status),
             is_stacked_child: session.is_stacked_child(),
@@ -1276,7 +1280,7 @@ mod tests {
 
         // Act
         let current_layout = {
-            let _theme_scope = style::scoped_active_theme(ColorTheme::Current);
+            let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
             output_layout_cache.layout(
                 &session,
                 Rect::new(0, 0, 80, 8),
@@ -1285,7 +1289,7 @@ mod tests {
             )
         };
         let dark_horizon_layout = {
-            let _theme_scope = style::scoped_active_theme(ColorTheme::DarkHorizon);
+            let _theme_scope = style::scoped_active_theme(&ColorTheme::DarkHorizon);
             output_layout_cache.layout(
                 &session,
                 Rect::new(0, 0, 80, 8),
@@ -0,0 +1,130 @@
+use ag_tui_text::text_util::truncate_with_ellipsis;
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use crate::domain::permission::{ToolApprovalKind, ToolApprovalRequest};
+use crate::ui::style::palette;
+use crate::ui::{Component, overlay};
+
+const MIN_OVERLAY_HEIGHT: u16 = 9;
+const MIN_OVERLAY_WIDTH: u16 = 48;
+const OVERLAY_HEIGHT_PERCENT: u16 = 24;
+const OVERLAY_WIDTH_PERCENT: u16 = 56;
+
+/// Centered popup asking the user to answer one `Ask`-mode provider request.
+///
+/// The command summary and detail are truncated to single lines so the three
+/// answer shortcuts always stay visible.
+pub struct ToolApprovalOverlay<'a> {
+    request: &'a ToolApprovalRequest,
+}
+
+impl<'a> ToolApprovalOverlay<'a> {
+    /// Creates an approval popup for one pending provider request.
+    pub fn new(request: &'a ToolApprovalRequest) -> Self {
+        Self { request }
+    }
+
+    /// Returns the popup title for the request kind.
+    fn title(&self) -> &'static str {
+        match self.request.kind {
+            ToolApprovalKind::Command => "Approve command",
+            ToolApprovalKind::FileChange => "Approve file change",
+            ToolApprovalKind::Tool => "Approve tool call",
+        }
+    }
+}
+
+impl Component for ToolApprovalOverlay<'_> {
+    fn render(&self, f: &mut Frame, area: Rect) {
+        let popup_area = overlay::centered_popup_area(
+            area,
+            OVERLAY_WIDTH_PERCENT,
+            OVERLAY_HEIGHT_PERCENT,
+            MIN_OVERLAY_WIDTH,
+            MIN_OVERLAY_HEIGHT,
+        );
+        let content_width = overlay::overlay_content_width(popup_area.width);
+        let summary = truncate_with_ellipsis(&self.request.summary, content_width);
+        let detail = self
+            .request
+            .detail
+            .as_deref()
+            .map(|detail| truncate_with_ellipsis(detail, content_width))
+            .unwrap_or_default();
+        let key_style = Style::default()
+            .fg(palette::accent())
+            .add_modifier(Modifier::BOLD);
+        let label_style = Style::default().fg(palette::text());
+
+        let paragraph = Paragraph::new(vec![
+            Line::from(Span::styled(
+                summary,
+                Style::default()
+                    .fg(palette::text())
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(Span::styled(
+                detail,
+                Style::default().fg(palette::text_muted()),
+            )),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("y", key_style),
+                Span::styled(" allow once   ", label_style),
+                Span::styled("a", key_style),
+                Span::styled(" allow for session   ", label_style),
+                Span::styled("n", key_style),
+                Span::styled(" deny", label_style),
+            ]),
+        ])
+        .alignment(Alignment::Center)
+        .block(overlay::overlay_block(self.title(), palette::warning()));
+
+        overlay::clear_popup_area(f, popup_area);
+        f.render_widget(paragraph, popup_area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tool_approval_overlay_renders_command_and_answer_shortcuts() {
+        // Arrange
+        let backend = ratatui::backend::TestBackend::new(100, 24);
+        let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
+        let request = ToolApprovalRequest {
+            detail: Some("Publishes the crate".to_string()),
+            kind: ToolApprovalKind::Command,
+            summary: "cargo publish".to_string(),
+        };
+        let overlay = ToolApprovalOverlay::new(&request);
+
+        // Act
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                Component::render(&overlay, f, area);
+            })
+            .expect("failed to draw");
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+        assert!(text.contains("Approve command"));
+        assert!(text.contains("cargo publish"));
+        assert!(text.contains("Publishes the crate"));
+        assert!(text.contains("allow once"));
+        assert!(text.contains("allow for session"));
+        assert!(text.contains("deny"));
+    }
+}
@@ -1,6 +1,11 @@
+use std::fmt::Write as _;
+use std::ops::Range;
+
 use ag_protocol::AgentResponseSummary;
+use ag_tui_text::syntax::{SyntaxHighlighter, SyntaxLanguage, SyntaxSpan};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::Style;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Span;
 
 use crate::ui::style;
 
@@ -10,6 +15,8 @@ const DIFF_GIT_HEADER_PREFIX: &str = "diff --git ";
 const FOOTER_HEIGHT: u16 = 1;
 const GUTTER_EXTRA_WIDTH: usize = 2;
 const LINE_NUMBER_COLUMN_COUNT: usize = 2;
+/// Longest line, in word tokens, compared for intra-line change emphasis.
+const MAX_WORD_DIFF_TOKENS: usize = 256;
 const LAYOUT_MARGIN: u16 = 1;
 const MIN_GUTTER_WIDTH: usize = 1;
 const NO_NEWLINE_MARKER: &str = r"\ No newline at end of file";
@@ -44,6 +51,28 @@ pub struct DiffLine<'a> {
     pub content: &'a str,
 }
 
+/// One side-by-side diff row referencing parsed line indexes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitDiffRow {
+    /// File or hunk header spanning both columns.
+    Header(usize),
+    /// Old-file and new-file lines shown next to each other.
+    Body {
+        /// New-side line, or `None` for a blank cell.
+        new: Option<usize>,
+        /// Old-side line, or `None` for a blank cell.
+        old: Option<usize>,
+    },
+}
+
+/// Character class used to split lines into word-diff tokens.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum WordDiffCharClass {
+    Punctuation,
+    Whitespace,
+    Word,
+}
+
 /// Identifies what a tree line in the diff file explorer represents.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FileTreeItem {
@@ -271,6 +300,331 @@ pub fn body_diff_line_gutter(diff_line: &DiffLine<'_>, gutter_width: usize) -> S
     format!("{old_line}│{new_line} ")
 }
 
+/// Returns syntax spans for each row of one file's diff section.
+///
+/// Old-side rows (context and deletions) and new-side rows (context and
+/// additions) are highlighted as separate streams so each side parses as
+/// coherent source. Both streams restart at every hunk header because hunks
+/// begin mid-file. Context rows keep their new-side spans and header rows
+/// get none.
+pub fn highlight_diff_file_lines(
+    language: SyntaxLanguage,
+    lines: &[DiffLine<'_>],
+) -> Vec<Vec<SyntaxSpan>> {
+    let mut old_highlighter = SyntaxHighlighter::new(language);
+    let mut new_highlighter = SyntaxHighlighter::new(language);
+
+    lines
+        .iter()
+        .map(|diff_line| match diff_line.kind {
+            DiffLineKind::FileHeader => Vec::new(),
+            DiffLineKind::HunkHeader => {
+                old_highlighter.reset();
+                new_highlighter.reset();
+
+                Vec::new()
+            }
+            DiffLineKind::Deletion => old_highlighter.highlight_line(diff_line.content),
+            DiffLineKind::Addition => new_highlighter.highlight_line(diff_line.content),
+            DiffLineKind::Context => {
+                old_highlighter.highlight_line(diff_line.content);
+
+                new_highlighter.highlight_line(diff_line.content)
+            }
+        })
+        .collect()
+}
+
+/// Returns the syntax spans that overlap one wrapped content chunk, shifted so
+/// their ranges are relative to the chunk start.
+pub fn chunk_syntax_spans(
+    syntax_spans: &[SyntaxSpan],
+    chunk_range: Range<usize>,
+) -> Vec<SyntaxSpan> {
+    syntax_spans
+        .iter()
+        .filter_map(|syntax_span| {
+            let start = syntax_span.range.start.max(chunk_range.start);
+            let end = syntax_span.range.end.min(chunk_range.end);
+            if start >= end {
+                return None;
+            }
+
+            Some(SyntaxSpan {
+                kind: syntax_span.kind,
+                range: start - chunk_range.start..end - chunk_range.start,
+            })
+        })
+        .collect()
+}
+
+/// Returns the byte ranges that overlap one wrapped content chunk, shifted so
+/// they are relative to the chunk start.
+pub fn chunk_ranges(ranges: &[Range<usize>], chunk_range: Range<usize>) -> Vec<Range<usize>> {
+    ranges
+        .iter()
+        .filter_map(|range| {
+            let start = range.start.max(chunk_range.start);
+            let end = range.end.min(chunk_range.end);
+            if start >= end {
+                return None;
+            }
+
+            Some(start - chunk_range.start..end - chunk_range.start)
+        })
+        .collect()
+}
+
+/// Pairs parsed diff lines into side-by-side rows.
+///
+/// Context lines fill both columns. Within each hunk, a run of deletions is
+/// aligned positionally with the run of additions that follows it, and the
+/// shorter run leaves blank cells on its side.
+pub fn split_diff_rows(lines: &[DiffLine<'_>]) -> Vec<SplitDiffRow> {
+    let mut rows = Vec::with_capacity(lines.len());
+    let mut pending_old = Vec::new();
+    let mut pending_new = Vec::new();
+
+    for (line_index, diff_line) in lines.iter().enumerate() {
+        match diff_line.kind {
+            DiffLineKind::Deletion => {
+                if !pending_new.is_empty() {
+                    flush_split_diff_rows(&mut rows, &mut pending_old, &mut pending_new);
+                }
+                pending_old.push(line_index);
+            }
+            DiffLineKind::Addition => pending_new.push(line_index),
+            DiffLineKind::Context => {
+                flush_split_diff_rows(&mut rows, &mut pending_old, &mut pending_new);
+                rows.push(SplitDiffRow::Body {
+                    new: Some(line_index),
+                    old: Some(line_index),
+                });
+            }
+            DiffLineKind::FileHeader | DiffLineKind::HunkHeader => {
+                flush_split_diff_rows(&mut rows, &mut pending_old, &mut pending_new);
+                rows.push(SplitDiffRow::Header(line_index));
+            }
+        }
+    }
+    flush_split_diff_rows(&mut rows, &mut pending_old, &mut pending_new);
+
+    rows
+}
+
+/// Returns changed-word byte ranges for each parsed line.
+///
+/// Only deletion and addition lines paired by [`split_diff_rows`] get ranges;
+/// every other line, and pairs that share no words, get none so fully
+/// rewritten lines are not emphasized end to end.
+pub fn word_change_ranges(lines: &[DiffLine<'_>]) -> Vec<Vec<Range<usize>>> {
+    let mut ranges = vec![Vec::new(); lines.len()];
+
+    for row in split_diff_rows(lines) {
+        let SplitDiffRow::Body {
+            new: Some(new_index),
+            old: Some(old_index),
+        } = row
+        else {
+            continue;
+        };
+        if old_index == new_index {
+            continue;
+        }
+        let (old_ranges, new_ranges) =
+            word_diff_ranges(lines[old_index].content, lines[new_index].content);
+        ranges[old_index] = old_ranges;
+        ranges[new_index] = new_ranges;
+    }
+
+    ranges
+}
+
+/// Returns the byte ranges of words that differ between one modified line
+/// pair, as `(old_ranges, new_ranges)`.
+///
+/// Lines are split into word, whitespace, and single punctuation tokens and
+/// compared with a longest-common-subsequence pass. Lines with more than
+/// [`MAX_WORD_DIFF_TOKENS`] tokens, or pairs whose only shared tokens are
+/// whitespace, return no ranges.
+pub fn word_diff_ranges(old: &str, new: &str) -> (Vec<Range<usize>>, Vec<Range<usize>>) {
+    let old_tokens = word_diff_tokens(old);
+    let new_tokens = word_diff_tokens(new);
+    if old_tokens.len() > MAX_WORD_DIFF_TOKENS || new_tokens.len() > MAX_WORD_DIFF_TOKENS {
+        return (Vec::new(), Vec::new());
+    }
+
+    let old_words = old_tokens
+        .iter()
+        .map(|range| &old[range.clone()])
+        .collect::<Vec<_>>();
+    let new_words = new_tokens
+        .iter()
+        .map(|range| &new[range.clone()])
+        .collect::<Vec<_>>();
+    let (old_matched, new_matched) = longest_common_tokens(&old_words, &new_words);
+    let shares_word = old_words
+        .iter()
+        .zip(&old_matched)
+        .any(|(word, is_matched)| *is_matched && !word.trim().is_empty());
+    if !shares_word {
+        return (Vec::new(), Vec::new());
+    }
+
+    (
+        unmatched_token_ranges(&old_tokens, &old_matched),
+        unmatched_token_ranges(&new_tokens, &new_matched),
+    )
+}
+
+/// Adds `modifier` to the parts of `spans` covered by byte `ranges`, splitting
+/// spans at range edges.
+pub fn emphasize_span_ranges(
+    spans: Vec<Span<'static>>,
+    ranges: &[Range<usize>],
+    modifier: Modifier,
+) -> Vec<Span<'static>> {
+    if ranges.is_empty() {
+        return spans;
+    }
+
+    let mut emphasized_spans = Vec::with_capacity(spans.len().saturating_add(ranges.len() * 2));
+    let mut span_start = 0;
+    for span in spans {
+        let span_end = span_start + span.content.len();
+        let mut cursor = span_start;
+        for range in ranges {
+            let start = range.start.clamp(cursor, span_end);
+            let end = range.end.clamp(start, span_end);
+            if start == end {
+                continue;
+            }
+            if start > cursor {
+                emphasized_spans.push(Span::styled(
+                    span.content[cursor - span_start..start - span_start].to_string(),
+                    span.style,
+                ));
+            }
+            emphasized_spans.push(Span::styled(
+                span.content[start - span_start..end - span_start].to_string(),
+                span.style.add_modifier(modifier),
+            ));
+            cursor = end;
+        }
+        if cursor < span_end || span_start == span_end {
+            emphasized_spans.push(Span::styled(
+                span.content[cursor - span_start..].to_string(),
+                span.style,
+            ));
+        }
+        span_start = span_end;
+    }
+
+    emphasized_spans
+}
+
+/// Appends rows for buffered deletion and addition runs and clears both
+/// buffers.
+fn flush_split_diff_rows(
+    rows: &mut Vec<SplitDiffRow>,
+    pending_old: &mut Vec<usize>,
+    pending_new: &mut Vec<usize>,
+) {
+    let row_count = pending_old.len().max(pending_new.len());
+    rows.extend((0..row_count).map(|row_index| SplitDiffRow::Body {
+        new: pending_new.get(row_index).copied(),
+        old: pending_old.get(row_index).copied(),
+    }));
+    pending_old.clear();
+    pending_new.clear();
+}
+
+/// Splits one line into word, whitespace, and single-character punctuation
+/// token byte ranges.
+fn word_diff_tokens(line: &str) -> Vec<Range<usize>> {
+    let mut tokens: Vec<Range<usize>> = Vec::new();
+    let mut previous_class = None;
+
+    for (byte_index, character) in line.char_indices() {
+        let class = if character.is_alphanumeric() || character == '_' {
+            WordDiffCharClass::Word
+        } else if character.is_whitespace() {
+            WordDiffCharClass::Whitespace
+        } else {
+            WordDiffCharClass::Punctuation
+        };
+        let character_end = byte_index + character.len_utf8();
+        if previous_class == Some(class)
+            && class != WordDiffCharClass::Punctuation
+            && let Some(token) = tokens.last_mut()
+        {
+            token.end = character_end;
+        } else {
+            tokens.push(byte_index..character_end);
+        }
+        previous_class = Some(class);
+    }
+
+    tokens
+}
+
+/// Marks which tokens on each side belong to one longest common subsequence.
+fn longest_common_tokens(old_words: &[&str], new_words: &[&str]) -> (Vec<bool>, Vec<bool>) {
+    let column_count = new_words.len() + 1;
+    let mut lengths = vec![0_usize; (old_words.len() + 1) * column_count];
+    for old_index in (0..old_words.len()).rev() {
+        for new_index in (0..new_words.len()).rev() {
+            lengths[old_index * column_count + new_index] =
+                if old_words[old_index] == new_words[new_index] {
+                    lengths[(old_index + 1) * column_count + new_index + 1] + 1
+                } else {
+                    lengths[(old_index + 1) * column_count + new_index]
+                        .max(lengths[old_index * column_count + new_index + 1])
+                };
+        }
+    }
+
+    let mut old_matched = vec![false; old_words.len()];
+    let mut new_matched = vec![false; new_words.len()];
+    let (mut old_index, mut new_index) = (0, 0);
+    while old_index < old_words.len() && new_index < new_words.len() {
+        if old_words[old_index] == new_words[new_index] {
+            old_matched[old_index] = true;
+            new_matched[new_index] = true;
+            old_index += 1;
+            new_index += 1;
+        } else if lengths[(old_index + 1) * column_count + new_index]
+            >= lengths[old_index * column_count + new_index + 1]
+        {
+            old_index += 1;
+        } else {
+            new_index += 1;
+        }
+    }
+
+    (old_matched, new_matched)
+}
+
+/// Merges adjacent unmatched tokens into changed byte ranges.
+fn unmatched_token_ranges(tokens: &[Range<usize>], matched: &[bool]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (token, is_matched) in tokens.iter().zip(matched) {
+        if *is_matched {
+            continue;
+        }
+        if let Some(last_range) = ranges.last_mut()
+            && last_range.end == token.start
+        {
+            last_range.end = token.end;
+
+            continue;
+        }
+        ranges.push(token.clone());
+    }
+
+    ranges
+}
+
 /// Split a diff content string into chunks that fit within `max_width`
 /// characters. Returns at least one chunk (empty string if content is empty).
 pub fn wrap_diff_content(content: &str, max_width: usize) -> Vec<&str> {
@@ -506,6 +860,313 @@ fn diff_header_matches_item(header_line: &str, item: &FileTreeItem) -> bool {
     }
 }
 
+/// Unified patch whose reverse application discards selected diff rows.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiffRevertPatch {
+    /// Patch text suitable for `git apply -R` in the session worktree.
+    pub patch: String,
+    /// One human-readable description per file whose rows are discarded.
+    pub summary: Vec<String>,
+}
+
+/// Changed rows discarded from one file while building a [`DiffRevertPatch`].
+#[derive(Default)]
+struct DiscardedFileRows {
+    added_lines: Vec<u32>,
+    deleted_lines: Vec<u32>,
+}
+
+impl DiscardedFileRows {
+    /// Describes the discarded rows of `path` for the agent-facing notice.
+    fn summary(&self, path: &str) -> String {
+        let mut parts = Vec::new();
+        if let (Some(first), Some(last)) = (self.added_lines.first(), self.added_lines.last()) {
+            parts.push(format!(
+                "discarded {} ({})",
+                line_count_label(self.added_lines.len(), "added"),
+                line_span_label("new", *first, *last),
+            ));
+        }
+        if let (Some(first), Some(last)) = (self.deleted_lines.first(), self.deleted_lines.last()) {
+            parts.push(format!(
+                "restored {} ({})",
+                line_count_label(self.deleted_lines.len(), "deleted"),
+                line_span_label("old", *first, *last),
+            ));
+        }
+
+        format!("{path}: {}", parts.join(", "))
+    }
+}
+
+/// Builds a patch that discards the selected changed rows of `diff`.
+///
+/// `is_selected` receives each added row with its new-side path and each
+/// deleted row with its old-side path. With `whole_hunks`, one selected row
+/// selects every changed row of its hunk. Unselected additions become context
+/// and unselected deletions are dropped, so reverse-applying the patch to the
+/// worktree leaves every other change in place. Returns `None` when nothing
+/// is selected.
+pub fn diff_revert_patch(
+    diff: &str,
+    whole_hunks: bool,
+    mut is_selected: impl FnMut(&str, &DiffLine<'_>) -> bool,
+) -> Option<DiffRevertPatch> {
+    let raw_lines = diff
+        .split_inclusive('\n')
+        .map(|line| line.strip_suffix('\n').unwrap_or(line))
+        .collect::<Vec<_>>();
+    let parsed_lines = parse_diff_lines(diff);
+    let mut revert_patch = DiffRevertPatch {
+        patch: String::new(),
+        summary: Vec::new(),
+    };
+    let mut file_start = 0;
+
+    while file_start < parsed_lines.len() {
+        let file_end = (file_start.saturating_add(1)..parsed_lines.len())
+            .find(|index| raw_lines[*index].starts_with(DIFF_GIT_FILE_HEADER_PREFIX))
+            .unwrap_or(parsed_lines.len());
+        if raw_lines[file_start].starts_with(DIFF_GIT_FILE_HEADER_PREFIX)
+            && let Some((file_patch, summary)) = file_revert_patch(
+                &raw_lines[file_start..file_end],
+                &parsed_lines[file_start..file_end],
+                whole_hunks,
+                &mut is_selected,
+            )
+        {
+            revert_patch.patch.push_str(&file_patch);
+            revert_patch.summary.push(summary);
+        }
+        file_start = file_end;
+    }
+
+    (!revert_patch.patch.is_empty()).then_some(revert_patch)
+}
+
+/// Builds the revert patch section and summary for one `diff --git` block.
+fn file_revert_patch(
+    raw_lines: &[&str],
+    parsed_lines: &[DiffLine<'_>],
+    whole_hunks: bool,
+    is_selected: &mut impl FnMut(&str, &DiffLine<'_>) -> bool,
+) -> Option<(String, String)> {
+    let (old_path, new_path) = diff_header_paths(raw_lines.first()?)?;
+    let hunk_starts = (0..raw_lines.len())
+        .filter(|index| parsed_lines[*index].kind == DiffLineKind::HunkHeader)
+        .collect::<Vec<_>>();
+    let header_lines = raw_lines.get(..*hunk_starts.first()?)?;
+    let header_value = |prefix: &str| {
+        header_lines
+            .iter()
+            .find_map(|line| line.strip_prefix(prefix))
+    };
+    let old_token = header_value("--- ")?;
+    let new_token = header_value("+++ ")?;
+    let new_file_mode = header_value("new file mode ");
+    let deleted_file_mode = header_value("deleted file mode ");
+
+    let mut selected_rows = vec![false; raw_lines.len()];
+    for (hunk_position, hunk_start) in hunk_starts.iter().enumerate() {
+        let hunk_end = hunk_starts
+            .get(hunk_position.saturating_add(1))
+            .copied()
+            .unwrap_or(raw_lines.len());
+        let hunk_rows = hunk_start.saturating_add(1)..hunk_end;
+        for row_index in hunk_rows.clone() {
+            let diff_line = &parsed_lines[row_index];
+            selected_rows[row_index] = match diff_line.kind {
+                DiffLineKind::Addition => is_selected(&new_path, diff_line),
+                DiffLineKind::Deletion => is_selected(&old_path, diff_line),
+                _ => false,
+            };
+        }
+        if whole_hunks && hunk_rows.clone().any(|row_index| selected_rows[row_index]) {
+            for row_index in hunk_rows {
+                selected_rows[row_index] = matches!(
+                    parsed_lines[row_index].kind,
+                    DiffLineKind::Addition | DiffLineKind::Deletion
+                );
+            }
+        }
+    }
+
+    let reverts_whole_new_file = new_file_mode.is_some()
+        && (0..raw_lines.len()).all(|row_index| {
+            parsed_lines[row_index].kind != DiffLineKind::Addition || selected_rows[row_index]
+        });
+    let mut discarded_rows = DiscardedFileRows::default();
+    let mut hunks = String::new();
+    let mut line_delta: i64 = 0;
+    for (hunk_position, hunk_start) in hunk_starts.iter().enumerate() {
+        let hunk_end = hunk_starts
+            .get(hunk_position.saturating_add(1))
+            .copied()
+            .unwrap_or(raw_lines.len());
+        let hunk_rows = hunk_start.saturating_add(1)..hunk_end;
+        if let Some(hunk) = revert_hunk(
+            raw_lines[*hunk_start],
+            (
+                &raw_lines[hunk_rows.clone()],
+                &parsed_lines[hunk_rows.clone()],
+                &selected_rows[hunk_rows],
+            ),
+            &mut line_delta,
+            &mut discarded_rows,
+        ) {
+            hunks.push_str(&hunk);
+        }
+    }
+    if hunks.is_empty() {
+        return None;
+    }
+
+    let mut patch = String::new();
+    let summary_path;
+    if let Some(mode) = deleted_file_mode {
+        let new_side_token = swap_git_path_prefix(old_token, "a/", "b/")?;
+        let _ = write!(
+            patch,
+            "diff --git {old_token} {new_side_token}\ndeleted file mode {mode}\n--- \
+             {old_token}\n+++ /dev/null\n"
+        );
+        summary_path = old_path;
+    } else {
+        let old_side_token = swap_git_path_prefix(new_token, "b/", "a/")?;
+        let _ = writeln!(patch, "diff --git {old_side_token} {new_token}");
+        match new_file_mode {
+            Some(mode) if reverts_whole_new_file => {
+                let _ = write!(patch, "new file mode {mode}\n--- /dev/null\n");
+            }
+            _ => {
+                let _ = writeln!(patch, "--- {old_side_token}");
+            }
+        }
+        let _ = writeln!(patch, "+++ {new_token}");
+        summary_path = new_path;
+    }
+    patch.push_str(&hunks);
+
+    Some((patch, discarded_rows.summary(&summary_path)))
+}
+
+/// Rewrites one hunk so its new side matches the worktree and its old side
+/// keeps every unselected addition.
+///
+/// `line_delta` tracks the old-minus-new line offset accumulated by earlier
+/// rewritten hunks of the same file. Returns `None` when the hunk has no
+/// selected rows.
+fn revert_hunk(
+    hunk_header: &str,
+    hunk_rows: (&[&str], &[DiffLine<'_>], &[bool]),
+    line_delta: &mut i64,
+    discarded_rows: &mut DiscardedFileRows,
+) -> Option<String> {
+    let (raw_lines, parsed_lines, selected_rows) = hunk_rows;
+    let (_, _, new_start, _) = parse_hunk_header(hunk_header)?;
+    let mut body = String::new();
+    let mut previous_row_kept = false;
+    let mut old_count: u32 = 0;
+    let mut new_count: u32 = 0;
+    let mut has_selected_rows = false;
+
+    for ((raw_line, diff_line), is_selected) in
+        raw_lines.iter().zip(parsed_lines).zip(selected_rows)
+    {
+        let row_kept = match diff_line.kind {
+            DiffLineKind::Addition if *is_selected => {
+                has_selected_rows = true;
+                new_count = new_count.saturating_add(1);
+                discarded_rows.added_lines.extend(diff_line.new_line);
+                body.push_str(raw_line);
+
+                true
+            }
+            DiffLineKind::Addition => {
+                old_count = old_count.saturating_add(1);
+                new_count = new_count.saturating_add(1);
+                body.push(' ');
+                body.push_str(raw_line.get(1..).unwrap_or_default());
+
+                true
+            }
+            DiffLineKind::Deletion if *is_selected => {
+                has_selected_rows = true;
+                old_count = old_count.saturating_add(1);
+                discarded_rows.deleted_lines.extend(diff_line.old_line);
+                body.push_str(raw_line);
+
+                true
+            }
+            DiffLineKind::Context if diff_line.new_line.is_none() => {
+                if previous_row_kept {
+                    body.push_str(raw_line);
+                }
+
+                previous_row_kept
+            }
+            DiffLineKind::Context => {
+                old_count = old_count.saturating_add(1);
+                new_count = new_count.saturating_add(1);
+                body.push_str(raw_line);
+
+                true
+            }
+            DiffLineKind::Deletion | DiffLineKind::FileHeader | DiffLineKind::HunkHeader => false,
+        };
+        if row_kept {
+            body.push('\n');
+        }
+        previous_row_kept = row_kept;
+    }
+    if !has_selected_rows {
+        return None;
+    }
+
+    let lines_before_new = if new_count == 0 {
+        new_start
+    } else {
+        new_start.saturating_sub(1)
+    };
+    let lines_before_old =
+        u32::try_from(i64::from(lines_before_new) + *line_delta).unwrap_or_default();
+    let old_start = if old_count == 0 {
+        lines_before_old
+    } else {
+        lines_before_old.saturating_add(1)
+    };
+    *line_delta += i64::from(old_count) - i64::from(new_count);
+
+    Some(format!(
+        "@@ -{old_start},{old_count} +{new_start},{new_count} @@\n{body}"
+    ))
+}
+
+/// Replaces the `a/` or `b/` prefix of one raw, possibly quoted, path token.
+fn swap_git_path_prefix(token: &str, from: &str, to: &str) -> Option<String> {
+    if let Some(quoted_path) = token.strip_prefix('"') {
+        return Some(format!("\"{to}{}", quoted_path.strip_prefix(from)?));
+    }
+
+    Some(format!("{to}{}", token.strip_prefix(from)?))
+}
+
+/// Formats one row count such as `1 added line` or `3 deleted lines`.
+fn line_count_label(count: usize, kind: &str) -> String {
+    let noun = if count == 1 { "line" } else { "lines" };
+
+    format!("{count} {kind} {noun}")
+}
+
+/// Formats one side-specific line span such as `new lines 4-6`.
+fn line_span_label(side: &str, first: u32, last: u32) -> String {
+    if first == last {
+        return format!("{side} line {first}");
+    }
+
+    format!("{side} lines {first}-{last}")
+}
+
 const DEFAULT_REVIEW_COMMENT: &str = "Agent summary unavailable; review the highlighted changes.";
 const MAX_AGENT_COMMENT_COUNT: usize = 4;
 const MAX_REVIEW_HIGHLIGHT_COUNT: usize = 8;
@@ -1092,6 +1753,8 @@ fn parse_git_path_token(input: &str) -> Option<(String, &str)> {
 
 #[cfg(test)]
 mod tests {
+    use ag_tui_text::syntax::SyntaxTokenKind;
+
     use super::*;
 
     const DIFF_MAIN_HEADER: &str = "diff --git a/src/main.rs b/src/main.rs";
@@ -1394,6 +2057,153 @@ index abc..def 100644
         assert_eq!(totals, (0, 0));
     }
 
+    /// Two-hunk modification used by revert-patch tests.
+    fn revert_patch_fixture() -> &'static str {
+        concat!(
+            "diff --git a/src/lib.rs b/src/lib.rs\n",
+            "index 1111111..2222222 100644\n",
+            "--- a/src/lib.rs\n",
+            "+++ b/src/lib.rs\n",
+            "@@ -1,3 +1,4 @@\n",
+            " fn a() {}\n",
+            "-fn b() {}\n",
+            "+fn b2() {}\n",
+            "+fn c() {}\n",
+            " fn d() {}\n",
+            "@@ -10,2 +11,2 @@\n",
+            " fn x() {}\n",
+            "-fn y() {}\n",
+            "+fn y2() {}\n",
+        )
+    }
+
+    #[test]
+    fn test_diff_revert_patch_keeps_unselected_rows_of_partial_hunk() {
+        // Arrange
+        let diff = revert_patch_fixture();
+
+        // Act
+        let revert_patch = diff_revert_patch(diff, false, |path, diff_line| {
+            path == "src/lib.rs" && diff_line.new_line == Some(3)
+        });
+
+        // Assert
+        assert_eq!(
+            revert_patch,
+            Some(DiffRevertPatch {
+                patch: concat!(
+                    "diff --git a/src/lib.rs b/src/lib.rs\n",
+                    "--- a/src/lib.rs\n",
+                    "+++ b/src/lib.rs\n",
+                    "@@ -1,3 +1,4 @@\n",
+                    " fn a() {}\n",
+                    " fn b2() {}\n",
+                    "+fn c() {}\n",
+                    " fn d() {}\n",
+                )
+                .to_string(),
+                summary: vec!["src/lib.rs: discarded 1 added line (new line 3)".to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn test_diff_revert_patch_expands_selection_to_whole_hunk() {
+        // Arrange
+        let diff = revert_patch_fixture();
+
+        // Act
+        let revert_patch = diff_revert_patch(diff, true, |_, diff_line| {
+            diff_line.kind == DiffLineKind::Deletion && diff_line.old_line == Some(11)
+        });
+
+        // Assert
+        assert_eq!(
+            revert_patch,
+            Some(DiffRevertPatch {
+                patch: concat!(
+                    "diff --git a/src/lib.rs b/src/lib.rs\n",
+                    "--- a/src/lib.rs\n",
+                    "+++ b/src/lib.rs\n",
+                    "@@ -11,2 +11,2 @@\n",
+                    " fn x() {}\n",
+                    "-fn y() {}\n",
+                    "+fn y2() {}\n",
+                )
+                .to_string(),
+                summary: vec![
+                    "src/lib.rs: discarded 1 added line (new line 12), restored 1 deleted line \
+                     (old line 11)"
+                        .to_string()
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_diff_revert_patch_keeps_new_file_header_only_for_whole_file() {
+        // Arrange
+        let diff = concat!(
+            "diff --git a/notes.md b/notes.md\n",
+            "new file mode 100644\n",
+            "index 0000000..3333333\n",
+            "--- /dev/null\n",
+            "+++ b/notes.md\n",
+            "@@ -0,0 +1,2 @@\n",
+            "+keep\n",
+            "+drop\n",
+        );
+
+        // Act
+        let partial_patch =
+            diff_revert_patch(diff, false, |_, diff_line| diff_line.new_line == Some(2));
+        let whole_file_patch =
+            diff_revert_patch(diff, true, |_, diff_line| diff_line.new_line == Some(2));
+
+        // Assert
+        assert_eq!(
+            partial_patch.map(|revert_patch| revert_patch.patch),
+            Some(
+                concat!(
+                    "diff --git a/notes.md b/notes.md\n",
+                    "--- a/notes.md\n",
+                    "+++ b/notes.md\n",
+                    "@@ -1,1 +1,2 @@\n",
+                    " keep\n",
+                    "+drop\n",
+                )
+                .to_string()
+            )
+        );
+        assert_eq!(
+            whole_file_patch.map(|revert_patch| revert_patch.patch),
+            Some(
+                concat!(
+                    "diff --git a/notes.md b/notes.md\n",
+                    "new file mode 100644\n",
+                    "--- /dev/null\n",
+                    "+++ b/notes.md\n",
+                    "@@ -0,0 +1,2 @@\n",
+                    "+keep\n",
+                    "+drop\n",
+                )
+                .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_diff_revert_patch_returns_none_without_selected_rows() {
+        // Arrange
+        let diff = revert_patch_fixture();
+
+        // Act
+        let revert_patch = diff_revert_patch(diff, true, |_, _| false);
+
+        // Assert
+        assert_eq!(revert_patch, None);
+    }
+
     #[test]
     fn test_filter_diff_lines_by_file() {
         // Arrange
@@ -1432,6 +2242,174 @@ index abc..def 100644
         assert_eq!(filtered[3].content, "deleted in explorer");
     }
 
+    #[test]
+    fn test_highlight_diff_file_lines_highlights_old_and_new_sides_separately() {
+        // Arrange
+        let diff = concat!(
+            "diff --git a/src/main.rs b/src/main.rs\n",
+            "@@ -1,2 +1,2 @@\n",
+            "-/* removed\n",
+            "+let added = 1;\n",
+            " fn main() {}\n",
+        );
+        let lines = parse_diff_lines(diff);
+        let language = SyntaxLanguage::from_path("src/main.rs").expect("rust grammar is bundled");
+
+        // Act
+        let highlights = highlight_diff_file_lines(language, &lines);
+
+        // Assert
+        assert_eq!(highlights.len(), lines.len());
+        assert_eq!(highlights[0], [] as [SyntaxSpan; 0]);
+        assert_eq!(highlights[1], [] as [SyntaxSpan; 0]);
+        assert_eq!(highlights[2][0].kind, SyntaxTokenKind::Comment);
+        assert_eq!(highlights[3][0].kind, SyntaxTokenKind::Keyword);
+        assert_eq!(highlights[3][0].range, 0..3);
+        assert_eq!(highlights[4][0].kind, SyntaxTokenKind::Keyword);
+        assert_eq!(highlights[4][0].range, 0..2);
+    }
+
+    #[test]
+    fn test_chunk_syntax_spans_clips_and_shifts_overlapping_spans() {
+        // Arrange
+        let syntax_spans = [
+            SyntaxSpan {
+                kind: SyntaxTokenKind::Keyword,
+                range: 0..3,
+            },
+            SyntaxSpan {
+                kind: SyntaxTokenKind::String,
+                range: 6..12,
+            },
+        ];
+
+        // Act
+        let chunk_spans = chunk_syntax_spans(&syntax_spans, 4..8);
+
+        // Assert
+        assert_eq!(
+            chunk_spans,
+            [SyntaxSpan {
+                kind: SyntaxTokenKind::String,
+                range: 2..4,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_split_diff_rows_pairs_deletion_runs_with_following_additions() {
+        // Arrange
+        let diff = concat!(
+            "diff --git a/src/lib.rs b/src/lib.rs\n",
+            "@@ -1,4 +1,3 @@\n",
+            " keep\n",
+            "-old one\n",
+            "-old two\n",
+            "+new one\n",
+            " tail\n",
+            "+appended\n",
+        );
+        let lines = parse_diff_lines(diff);
+
+        // Act
+        let rows = split_diff_rows(&lines);
+
+        // Assert
+        assert_eq!(
+            rows,
+            [
+                SplitDiffRow::Header(0),
+                SplitDiffRow::Header(1),
+                SplitDiffRow::Body {
+                    new: Some(2),
+                    old: Some(2),
+                },
+                SplitDiffRow::Body {
+                    new: Some(5),
+                    old: Some(3),
+                },
+                SplitDiffRow::Body {
+                    new: None,
+                    old: Some(4),
+                },
+                SplitDiffRow::Body {
+                    new: Some(6),
+                    old: Some(6),
+                },
+                SplitDiffRow::Body {
+                    new: Some(7),
+                    old: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_word_change_ranges_marks_changed_tokens_in_modified_pairs() {
+        // Arrange
+        let diff = concat!(
+            "diff --git a/src/lib.rs b/src/lib.rs\n",
+            "@@ -1 +1 @@\n",
+            "-let value = compute(1);\n",
+            "+let value = compute(42);\n",
+        );
+        let lines = parse_diff_lines(diff);
+
+        let old_number_range = 20..21;
+        let new_number_range = 20..22;
+
+        // Act
+        let ranges = word_change_ranges(&lines);
+
+        // Assert
+        assert_eq!(ranges[0], [] as [Range<usize>; 0]);
+        assert_eq!(ranges[2], [old_number_range]);
+        assert_eq!(ranges[3], [new_number_range]);
+        assert_eq!(&lines[3].content[20..22], "42");
+    }
+
+    #[test]
+    fn test_word_diff_ranges_skips_pairs_without_shared_words() {
+        // Arrange
+        let old = "alpha beta";
+        let new = "gamma delta";
+
+        // Act
+        let ranges = word_diff_ranges(old, new);
+
+        // Assert
+        assert_eq!(ranges, (Vec::new(), Vec::new()));
+    }
+
+    #[test]
+    fn test_emphasize_span_ranges_splits_spans_at_range_edges() {
+        // Arrange
+        let base_style = Style::default();
+        let spans = vec![
+            Span::styled("let ", base_style),
+            Span::styled("value", base_style),
+        ];
+        let emphasized_range = 2..6;
+
+        // Act
+        let emphasized = emphasize_span_ranges(spans, &[emphasized_range], Modifier::BOLD);
+
+        // Assert
+        let parts = emphasized
+            .iter()
+            .map(|span| {
+                (
+                    span.content.as_ref(),
+                    span.style.add_modifier.contains(Modifier::BOLD),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            parts,
+            [("le", false), ("t ", true), ("va", true), ("lue", false)]
+        );
+    }
+
     #[test]
     fn test_wrap_diff_content_fits() {
         // Arrange
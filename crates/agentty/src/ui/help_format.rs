@@ -4,6 +4,7 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 
 use crate::presentation::help_action::HelpAction;
+use crate::presentation::keymap::{Keymap, KeymapMode};
 use crate::ui::style;
 
 /// Renders one-line footer help with emphasized keys and muted labels.
@@ -15,7 +16,7 @@ pub(crate) fn footer_line(actions: &[HelpAction]) -> Line<'static> {
             spans.push(footer_separator_span());
         }
 
-        spans.push(footer_key_span(action.key));
+        spans.push(footer_key_span(action.key.as_ref()));
         spans.push(footer_muted_span(": "));
         spans.push(footer_muted_span(action.footer_label));
     }
@@ -23,10 +24,21 @@ pub(crate) fn footer_line(actions: &[HelpAction]) -> Line<'static> {
     Line::from(spans)
 }
 
+/// Renders list-mode footer help, relabeling keys with `keymap` overrides
+/// when a keymap is set.
+pub(crate) fn list_footer_line(keymap: Option<&Keymap>, actions: Vec<HelpAction>) -> Line<'static> {
+    let actions = match keymap {
+        Some(keymap) => keymap.remap_help_actions(KeymapMode::List, actions),
+        None => actions,
+    };
+
+    footer_line(&actions)
+}
+
 /// Returns one highlighted footer key span.
-pub(crate) fn footer_key_span(key: &'static str) -> Span<'static> {
+pub(crate) fn footer_key_span(key: impl Into<String>) -> Span<'static> {
     Span::styled(
-        key.to_string(),
+        key.into(),
         Style::default()
             .fg(style::palette::accent())
             .add_modifier(Modifier::BOLD),
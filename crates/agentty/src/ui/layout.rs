@@ -363,6 +363,7 @@ mod tests {
     use crate::domain::theme::ColorTheme;
     use crate::presentation::app_mode::ChatFocus;
     use crate::presentation::help_action::{self, ViewActionAvailability, ViewHelpState};
+    use crate::presentation::keymap::Keymap;
     use crate::presentation::prompt::{PromptAtMentionState, PromptSlashStage, PromptSlashState};
     use crate::ui::input_layout::*;
     use crate::ui::prompt_format::*;
@@ -377,20 +378,23 @@ mod tests {
     }
 
     fn view_footer_text(session: &Session, can_open_worktree: bool) -> String {
-        session_view_footer_line(ViewHelpState {
-            can_fork_session: ViewActionAvailability::from_bool(session.allows_fork_action()),
-            can_merge_session_branch: ViewActionAvailability::Enabled,
-            can_mutate_session_branch: ViewActionAvailability::Enabled,
-            can_open_worktree: ViewActionAvailability::from_bool(can_open_worktree),
-            can_rebase_session_branch: ViewActionAvailability::Enabled,
-            can_show_diff: ViewActionAvailability::from_bool(session.stats.should_show_diff()),
-            reply_to_session: ViewActionAvailability::Enabled,
-            can_start_staged_session: ViewActionAvailability::from_bool(
-                session.can_start_staged_session(),
-            ),
-            publish_pull_request_action: session.publish_pull_request_action(),
-            session_state: help_action::session_view_state(session),
-        })
+        session_view_footer_line(
+            ViewHelpState {
+                can_fork_session: ViewActionAvailability::from_bool(session.allows_fork_action()),
+                can_merge_session_branch: ViewActionAvailability::Enabled,
+                can_mutate_session_branch: ViewActionAvailability::Enabled,
+                can_open_worktree: ViewActionAvailability::from_bool(can_open_worktree),
+                can_rebase_session_branch: ViewActionAvailability::Enabled,
+                can_show_diff: ViewActionAvailability::from_bool(session.stats.should_show_diff()),
+                reply_to_session: ViewActionAvailability::Enabled,
+                can_start_staged_session: ViewActionAvailability::from_bool(
+                    session.can_start_staged_session(),
+                ),
+                publish_pull_request_action: session.publish_pull_request_action(),
+                session_state: help_action::session_view_state(session),
+            },
+            &Keymap::default(),
+        )
         .to_string()
     }
 
@@ -528,7 +532,7 @@ mod tests {
     #[test]
     fn test_session_header_lines_use_theme_status_color() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::Green);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Green);
         let mut session = session_fixture();
         session.status = Status::Canceled;
         session.title = Some("Canceled session".to_string());
@@ -687,7 +691,7 @@ mod tests {
         let session = session_fixture();
 
         // Act
-        let footer_line = prompt_footer_line(&session, 2, ChatFocus::Input);
+        let footer_line = prompt_footer_line(&session, 2, ChatFocus::Input, &Keymap::default());
 
         // Assert
         assert_eq!(
@@ -723,7 +727,7 @@ mod tests {
         session.is_draft = true;
 
         // Act
-        let footer_line = prompt_footer_line(&session, 0, ChatFocus::Input);
+        let footer_line = prompt_footer_line(&session, 0, ChatFocus::Input, &Keymap::default());
 
         // Assert
         assert!(footer_line.to_string().contains("Enter: stage draft"));
@@ -737,9 +741,11 @@ mod tests {
         session.stats.diff_state = SessionDiffState::Empty;
 
         // Act
-        let unchanged_footer_line = prompt_footer_line(&session, 0, ChatFocus::Chat);
+        let unchanged_footer_line =
+            prompt_footer_line(&session, 0, ChatFocus::Chat, &Keymap::default());
         session.stats.diff_state = SessionDiffState::Present;
-        let changed_footer_line = prompt_footer_line(&session, 0, ChatFocus::Chat);
+        let changed_footer_line =
+            prompt_footer_line(&session, 0, ChatFocus::Chat, &Keymap::default());
 
         // Assert
         assert_eq!(
@@ -872,7 +878,7 @@ mod tests {
         // Assert
         assert_eq!(
             labels,
-            vec!["/model", "/personality", "/reasoning", "/speed"]
+            vec!["/model", "/personality", "/reasoning", "/rewind", "/speed"]
         );
         assert_eq!(menu.selected_index, 0);
     }
@@ -1177,10 +1183,11 @@ mod tests {
 
         // Act
         let unchanged_chat_focus_line =
-            question_help_footer_line(ChatFocus::Chat, false, false, false);
+            question_help_footer_line(ChatFocus::Chat, false, false, false, &Keymap::default());
         let changed_chat_focus_line =
-            question_help_footer_line(ChatFocus::Chat, true, false, false);
-        let answer_focus_line = question_help_footer_line(ChatFocus::Input, false, false, false);
+            question_help_footer_line(ChatFocus::Chat, true, false, false, &Keymap::default());
+        let answer_focus_line =
+            question_help_footer_line(ChatFocus::Input, false, false, false, &Keymap::default());
 
         // Assert
         assert_eq!(
@@ -1207,9 +1214,11 @@ mod tests {
 
         // Act
         let answer_focus_with_options =
-            question_help_footer_line(ChatFocus::Input, false, true, false).to_string();
+            question_help_footer_line(ChatFocus::Input, false, true, false, &Keymap::default())
+                .to_string();
         let answer_focus_with_overlay =
-            question_help_footer_line(ChatFocus::Input, false, false, true).to_string();
+            question_help_footer_line(ChatFocus::Input, false, false, true, &Keymap::default())
+                .to_string();
 
         // Assert
         assert_eq!(
@@ -5,6 +5,8 @@ use std::ops::Range;
 use std::path::Path;
 use std::sync::Arc;
 
+use ag_tui_text::TextPalette;
+use ag_tui_text::syntax::{self, SyntaxSpan};
 use ag_tui_text::text_util::{self, inline_text};
 use ratatui::Frame;
 use ratatui::layout::Rect;
@@ -15,9 +17,10 @@ use rustc_hash::FxHasher;
 
 use crate::domain::session::Session;
 use crate::presentation::app_mode::{
-    DiffFocus, DiffLineComment, DiffLineCommentAnchor, DiffLineComments, DiffLineSide, DiffPreview,
-    DiffPreviewUnavailableReason, DiffReviewComments, DiffSidebarFocus,
+    DiffFocus, DiffLayoutMode, DiffLineComment, DiffLineCommentAnchor, DiffLineComments,
+    DiffLineSide, DiffPreview, DiffPreviewUnavailableReason, DiffReviewComments, DiffSidebarFocus,
 };
+use crate::presentation::keymap::{Keymap, KeymapMode};
 use crate::presentation::{help_action, review_comment as review_comment_selection};
 use crate::ui::component::file_explorer::FileExplorer;
 use crate::ui::component::vertical_scrollbar::VerticalScrollbar;
@@ -27,12 +30,17 @@ use crate::ui::diff_util::{
     DiffLine, DiffLineKind, FileTreeItem, diff_header_new_path, diff_header_paths, parse_diff_lines,
 };
 use crate::ui::page::review_comment;
+use crate::ui::render_cache::{DiffSyntaxHighlights, SyntaxHighlightCache};
 use crate::ui::{Component, Page, diff_util, markdown, style};
 
 const WRAPPED_CHUNK_START_INDEX: usize = 0;
 const DIFF_CONTENT_CACHE_ENTRY_LIMIT: usize = 8;
 const DIFF_LAYOUT_CACHE_ENTRY_LIMIT: usize = 16;
 const FILE_LIST_CHANGE_TOTAL_SPAN_COUNT: usize = 4;
+const SPLIT_COLUMN_SEPARATOR: &str = "│";
+const SPLIT_COLUMN_SEPARATOR_WIDTH: usize = 1;
+const SPLIT_SIDE_PREFIX_EXTRA_WIDTH: usize = 2;
+const WORD_CHANGE_MODIFIER: Modifier = Modifier::BOLD.union(Modifier::UNDERLINED);
 
 /// Compact identity for one raw diff string.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -48,6 +56,7 @@ struct DiffLayoutCacheKey {
     diff_area_height: u16,
     diff_area_width: u16,
     diff_content: DiffContentCacheKey,
+    layout_mode: DiffLayoutMode,
     reserve_scrollbar_width: bool,
     selected_index: usize,
     style_version: u64,
@@ -495,6 +504,7 @@ impl DiffContentSnapshot {
 /// Cached fully assembled diff lines for one render-affecting key.
 #[derive(Clone)]
 struct DiffCachedLayout {
+    changed_line_columns: Arc<[Option<Range<usize>>]>,
     changed_line_ranges: Arc<[Range<usize>]>,
     line_count: usize,
     lines: Arc<[Line<'static>]>,
@@ -506,6 +516,7 @@ struct DiffCachedLayout {
 struct DiffLayoutRequest<'a> {
     content: &'a DiffContentSnapshot,
     diff_area: Rect,
+    layout_mode: DiffLayoutMode,
     reserve_scrollbar_width: bool,
     selected_index: usize,
 }
@@ -514,6 +525,7 @@ struct DiffLayoutRequest<'a> {
 #[derive(Clone)]
 pub(crate) struct DiffResolvedLayout {
     pub(crate) changed_line_ranges: Arc<[Range<usize>]>,
+    changed_line_columns: Arc<[Option<Range<usize>>]>,
     pub(crate) line_count: usize,
     pub(crate) lines: Arc<[Line<'static>]>,
     pub(crate) render_layout: diff_util::DiffRenderLayout,
@@ -521,6 +533,24 @@ pub(crate) struct DiffResolvedLayout {
     comment_insertions: Vec<DiffLineCommentInsertion>,
 }
 
+/// Rendered rows that share one highlight, optionally narrowed to the
+/// display columns of one split-layout side.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct DiffHighlightRegion {
+    columns: Option<Range<usize>>,
+    rows: Range<usize>,
+}
+
+impl DiffHighlightRegion {
+    /// Returns a region covering whole rendered rows.
+    fn rows(rows: Range<usize>) -> Self {
+        Self {
+            columns: None,
+            rows,
+        }
+    }
+}
+
 /// One inline comment row inserted after its changed source line.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 struct DiffLineCommentInsertion {
@@ -534,13 +564,13 @@ struct DiffLineCommentInsertion {
 /// Short-lived inputs used to paint one inline-comment diff viewport.
 struct DiffVisibleLineRequest<'a> {
     comment_insertions: &'a [DiffLineCommentInsertion],
-    comment_highlight_ranges: &'a [Range<usize>],
+    comment_highlight_regions: &'a [DiffHighlightRegion],
     content_width: usize,
     line_comments: &'a DiffLineComments,
     prefix_width: usize,
     scroll_offset: u16,
     selected_comment_index: Option<usize>,
-    selected_range: Option<&'a Range<usize>>,
+    selected_regions: &'a [DiffHighlightRegion],
     viewport_height: u16,
 }
 
@@ -571,6 +601,7 @@ impl DiffResolvedLayout {
         );
 
         Self {
+            changed_line_columns: cached_layout.changed_line_columns,
             changed_line_ranges,
             comment_insertions,
             line_count,
@@ -655,6 +686,63 @@ impl DiffResolvedLayout {
         Some(start_range.start..end_range.end)
     }
 
+    /// Returns highlight regions for inclusive changed-line bounds.
+    ///
+    /// Unified rows merge into one block; split rows keep one region per
+    /// changed line so only the owning column is painted.
+    fn changed_line_selection_regions(
+        &self,
+        start_changed_line_index: usize,
+        end_changed_line_index: usize,
+    ) -> Vec<DiffHighlightRegion> {
+        let Some(rows) =
+            self.changed_line_selection_range(start_changed_line_index, end_changed_line_index)
+        else {
+            return Vec::new();
+        };
+        let changed_line_indexes = start_changed_line_index..=end_changed_line_index;
+        if changed_line_indexes.clone().all(|changed_line_index| {
+            self.changed_line_columns
+                .get(changed_line_index)
+                .is_none_or(Option::is_none)
+        }) {
+            return vec![DiffHighlightRegion::rows(rows)];
+        }
+
+        changed_line_indexes
+            .filter_map(|changed_line_index| {
+                Some(DiffHighlightRegion {
+                    columns: self.changed_line_columns.get(changed_line_index)?.clone(),
+                    rows: self.changed_line_ranges.get(changed_line_index)?.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns highlight regions for the selected source or inline-comment
+    /// row.
+    fn content_selection_regions(
+        &self,
+        selected_changed_line_index: usize,
+        selected_comment_index: Option<usize>,
+    ) -> Vec<DiffHighlightRegion> {
+        let Some(rows) =
+            self.content_selection_range(selected_changed_line_index, selected_comment_index)
+        else {
+            return Vec::new();
+        };
+        if selected_comment_index.is_some() {
+            return vec![DiffHighlightRegion::rows(rows)];
+        }
+        let columns = self
+            .changed_line_columns
+            .get(selected_changed_line_index)
+            .cloned()
+            .flatten();
+
+        vec![DiffHighlightRegion { columns, rows }]
+    }
+
     /// Returns the rendered range for the selected source or inline-comment
     /// row.
     fn content_selection_range(
@@ -738,7 +826,13 @@ impl DiffResolvedLayout {
                     }),
             )
             .collect::<Vec<_>>();
-        selections.sort_unstable_by_key(|selection| selection.display_row);
+        selections.sort_unstable_by_key(|selection| {
+            (
+                selection.changed_line_index,
+                selection.comment_index.is_some(),
+                selection.display_row,
+            )
+        });
 
         selections
     }
@@ -772,12 +866,12 @@ impl DiffResolvedLayout {
         )
     }
 
-    /// Returns rendered source ranges owned by visible inline comments.
-    fn line_comment_highlight_ranges(&self) -> Vec<Range<usize>> {
+    /// Returns rendered source regions owned by visible inline comments.
+    fn line_comment_highlight_regions(&self) -> Vec<DiffHighlightRegion> {
         self.comment_insertions
             .iter()
-            .filter_map(|insertion| {
-                self.changed_line_selection_range(
+            .flat_map(|insertion| {
+                self.changed_line_selection_regions(
                     insertion.start_changed_line_index,
                     insertion.end_changed_line_index,
                 )
@@ -865,10 +959,60 @@ fn changed_line_ranges_with_comments(
 /// Fully assembled diff rows and the rendered range owned by each changed
 /// source line.
 struct DiffBuiltLines {
+    changed_line_columns: Vec<Option<Range<usize>>>,
     changed_line_ranges: Vec<Range<usize>>,
     lines: Vec<Line<'static>>,
 }
 
+/// Per-line syntax and changed-word styling applied while assembling rows.
+struct DiffLineDecorations {
+    palette: TextPalette,
+    syntax_highlights: DiffSyntaxHighlights,
+    word_change_ranges: Vec<Vec<Range<usize>>>,
+}
+
+impl DiffLineDecorations {
+    /// Returns the syntax spans and changed-word ranges for one parsed line.
+    fn line(&self, line_index: usize) -> DiffLineDecoration<'_> {
+        DiffLineDecoration {
+            palette: &self.palette,
+            syntax_spans: self.syntax_highlights.line_spans(line_index),
+            word_change_ranges: self
+                .word_change_ranges
+                .get(line_index)
+                .map_or(&[], Vec::as_slice),
+        }
+    }
+}
+
+/// Borrowed styling inputs for one parsed diff line.
+#[derive(Clone, Copy)]
+struct DiffLineDecoration<'a> {
+    palette: &'a TextPalette,
+    syntax_spans: &'a [SyntaxSpan],
+    word_change_ranges: &'a [Range<usize>],
+}
+
+impl DiffLineDecoration<'_> {
+    /// Styles one wrapped content chunk starting at `chunk_start` bytes into
+    /// the line.
+    fn chunk_spans(
+        self,
+        chunk: &str,
+        chunk_start: usize,
+        content_style: Style,
+    ) -> Vec<Span<'static>> {
+        let chunk_range = chunk_start..chunk_start + chunk.len();
+        let chunk_syntax_spans =
+            diff_util::chunk_syntax_spans(self.syntax_spans, chunk_range.clone());
+        let content_spans =
+            syntax::highlighted_spans(chunk, &chunk_syntax_spans, content_style, self.palette);
+        let chunk_word_ranges = diff_util::chunk_ranges(self.word_change_ranges, chunk_range);
+
+        diff_util::emphasize_span_ranges(content_spans, &chunk_word_ranges, WORD_CHANGE_MODIFIER)
+    }
+}
+
 /// Final markdown-preview rows selected for the current panel width.
 struct DiffPreviewLayout {
     lines: Arc<[Line<'static>]>,
@@ -896,12 +1040,16 @@ struct DiffLayoutCacheEntry {
 /// replacing the diff or theme invalidates the styled snapshot. The
 /// rendered-layout layer sits above styled diff assembly so
 /// scroll metrics and frame painting
-/// reuse the same rows until diff content, selection, panel width/height,
-/// scrollbar gutter state, or the active style version changes. Both LRU
-/// layers evict their oldest entries at their fixed limits.
+/// reuse the same rows until diff content, selection, unified or split layout
+/// mode, panel width/height, scrollbar gutter state, or the active style
+/// version changes. Both LRU
+/// layers evict their oldest entries at their fixed limits. Layout assembly
+/// reads per-file syntax spans from a [`SyntaxHighlightCache`], so a rebuilt
+/// layout only re-highlights files whose rows changed.
 pub struct DiffLayoutCache {
     content_entries: RefCell<VecDeque<DiffContentCacheEntry>>,
     layout_entries: RefCell<VecDeque<DiffLayoutCacheEntry>>,
+    syntax_highlight: SyntaxHighlightCache,
 }
 
 impl Default for DiffLayoutCache {
@@ -909,6 +1057,7 @@ impl Default for DiffLayoutCache {
         Self {
             content_entries: RefCell::new(VecDeque::with_capacity(DIFF_CONTENT_CACHE_ENTRY_LIMIT)),
             layout_entries: RefCell::new(VecDeque::with_capacity(DIFF_LAYOUT_CACHE_ENTRY_LIMIT)),
+            syntax_highlight: SyntaxHighlightCache::default(),
         }
     }
 }
@@ -954,12 +1103,14 @@ impl DiffLayoutCache {
         &self,
         content: &DiffContentSnapshot,
         line_comments: &DiffLineComments,
+        layout_mode: DiffLayoutMode,
         selected_index: usize,
         diff_area: Rect,
     ) -> DiffResolvedLayout {
         let layout_without_scrollbar = self.layout(DiffLayoutRequest {
             content,
             diff_area,
+            layout_mode,
             reserve_scrollbar_width: false,
             selected_index,
         });
@@ -976,6 +1127,7 @@ impl DiffLayoutCache {
         let layout_with_scrollbar = self.layout(DiffLayoutRequest {
             content,
             diff_area,
+            layout_mode,
             reserve_scrollbar_width: true,
             selected_index,
         });
@@ -1016,6 +1168,7 @@ impl DiffLayoutCache {
         let DiffLayoutRequest {
             content,
             diff_area,
+            layout_mode,
             reserve_scrollbar_width,
             selected_index,
         } = request;
@@ -1023,6 +1176,7 @@ impl DiffLayoutCache {
             diff_area_height: diff_area.height,
             diff_area_width: diff_area.width,
             diff_content: content.key,
+            layout_mode,
             reserve_scrollbar_width,
             selected_index,
             style_version: style::active_theme_cache_version(),
@@ -1034,8 +1188,22 @@ impl DiffLayoutCache {
         let selected_lines = content.selected_lines(selected_index);
         let render_layout =
             diff_util::diff_render_layout(&selected_lines, diff_area, reserve_scrollbar_width);
-        let built_lines = DiffPage::build_diff_lines(&selected_lines, render_layout);
+        let syntax_highlights = self.syntax_highlight.diff_highlights(&selected_lines);
+        let decorations = DiffLineDecorations {
+            palette: style::text_render_settings().palette,
+            syntax_highlights,
+            word_change_ranges: diff_util::word_change_ranges(&selected_lines),
+        };
+        let built_lines = match layout_mode {
+            DiffLayoutMode::Unified => {
+                DiffPage::build_diff_lines(&selected_lines, render_layout, &decorations)
+            }
+            DiffLayoutMode::Split => {
+                DiffPage::build_split_diff_lines(&selected_lines, render_layout, &decorations)
+            }
+        };
         let layout = DiffCachedLayout {
+            changed_line_columns: Arc::from(built_lines.changed_line_columns),
             changed_line_ranges: Arc::from(built_lines.changed_line_ranges),
             line_count: built_lines.lines.len(),
             lines: Arc::from(built_lines.lines),
@@ -1097,6 +1265,10 @@ pub struct DiffPage<'a> {
     pub file_explorer_selected_index: usize,
     /// Panel currently receiving changed-file navigation input.
     pub focus: DiffFocus,
+    /// User keymap applied to footer shortcut labels.
+    pub keymap: &'a Keymap,
+    /// Unified or side-by-side arrangement of the diff panel.
+    pub layout_mode: DiffLayoutMode,
     /// Inline changed-line comments accumulated for the next turn.
     pub line_comments: &'a DiffLineComments,
     /// Shared cache for rendered markdown preview rows.
@@ -1129,6 +1301,10 @@ pub struct DiffPageInput<'a> {
     pub file_explorer_selected_index: usize,
     /// Panel currently receiving changed-file navigation input.
     pub focus: DiffFocus,
+    /// User keymap applied to footer shortcut labels.
+    pub keymap: &'a Keymap,
+    /// Unified or side-by-side arrangement of the diff panel.
+    pub layout_mode: DiffLayoutMode,
     /// Inline changed-line comments accumulated for the next turn.
     pub line_comments: &'a DiffLineComments,
     /// Shared cache for rendered markdown preview rows.
@@ -1156,6 +1332,8 @@ impl<'a> DiffPage<'a> {
             diff_layout_cache,
             file_explorer_selected_index,
             focus,
+            keymap,
+            layout_mode,
             line_comments,
             markdown_render_cache,
             preview,
@@ -1172,6 +1350,8 @@ impl<'a> DiffPage<'a> {
             diff_layout_cache,
             file_explorer_selected_index,
             focus,
+            keymap,
+            layout_mode,
             line_comments,
             markdown_render_cache,
             preview,
@@ -1192,6 +1372,17 @@ impl<'a> DiffPage<'a> {
         self
     }
 
+    /// Returns the footer revert state for the visible session.
+    fn revert_footer_state(&self) -> help_action::DiffRevertFooterState {
+        match self.line_comments.pending_revert {
+            Some(scope) => help_action::DiffRevertFooterState::Confirming(scope),
+            None if self.session.allows_diff_revert_action() => {
+                help_action::DiffRevertFooterState::Ready
+            }
+            None => help_action::DiffRevertFooterState::Unavailable,
+        }
+    }
+
     /// Renders the right-side diff panel with line-number gutters and
     /// aggregate change totals prefixed in the title.
     fn render_diff_content(
@@ -1222,6 +1413,7 @@ impl<'a> DiffPage<'a> {
         let layout = self.diff_layout_cache.resolved_layout(
             content,
             self.line_comments,
+            self.layout_mode,
             self.file_explorer_selected_index,
             area,
         );
@@ -1231,37 +1423,32 @@ impl<'a> DiffPage<'a> {
             layout.line_count,
             layout.render_layout.viewport_height,
         );
-        let comment_highlight_ranges = layout.line_comment_highlight_ranges();
-        let selected_range = (self.focus == DiffFocus::Content)
-            .then(|| {
-                if self.line_comments.is_selecting() {
-                    let (start_changed_line_index, end_changed_line_index) = self
-                        .line_comments
-                        .selected_row_bounds(self.selected_diff_line_index);
-
-                    return layout.changed_line_selection_range(
-                        start_changed_line_index,
-                        end_changed_line_index,
-                    );
-                }
-
-                layout.content_selection_range(
-                    self.selected_diff_line_index,
-                    self.line_comments.selected_comment_index(),
-                )
-            })
-            .flatten();
+        let comment_highlight_regions = layout.line_comment_highlight_regions();
+        let selected_regions = if self.focus != DiffFocus::Content {
+            Vec::new()
+        } else if self.line_comments.is_selecting() {
+            let (start_changed_line_index, end_changed_line_index) = self
+                .line_comments
+                .selected_row_bounds(self.selected_diff_line_index);
+
+            layout.changed_line_selection_regions(start_changed_line_index, end_changed_line_index)
+        } else {
+            layout.content_selection_regions(
+                self.selected_diff_line_index,
+                self.line_comments.selected_comment_index(),
+            )
+        };
         let paint_lines = Self::borrowed_visible_lines_with_comments(
             &layout.lines,
             &DiffVisibleLineRequest {
                 comment_insertions: &layout.comment_insertions,
-                comment_highlight_ranges: &comment_highlight_ranges,
+                comment_highlight_regions: &comment_highlight_regions,
                 content_width: layout.render_layout.content_width,
                 line_comments: self.line_comments,
                 prefix_width: layout.render_layout.prefix_width,
                 scroll_offset,
                 selected_comment_index: self.line_comments.selected_comment_index(),
-                selected_range: selected_range.as_ref(),
+                selected_regions: &selected_regions,
                 viewport_height: layout.render_layout.viewport_height,
             },
         );
@@ -1319,24 +1506,23 @@ impl<'a> DiffPage<'a> {
                     .count();
                 let original_index = display_row.saturating_sub(preceding_comment_count);
                 let mut paint_line = text_util::borrowed_paint_line(lines.get(original_index)?);
-                if request
-                    .comment_highlight_ranges
+                for region in request
+                    .comment_highlight_regions
                     .iter()
-                    .any(|range| range.contains(&display_row))
+                    .filter(|region| region.rows.contains(&display_row))
                 {
-                    paint_line.style = paint_line.style.bg(style::palette::surface_prompt());
-                    for span in &mut paint_line.spans {
-                        span.style = span.style.bg(style::palette::surface_prompt());
-                    }
+                    restyle_region_spans(&mut paint_line, region.columns.as_ref(), |style| {
+                        style.bg(style::palette::surface_prompt())
+                    });
                 }
-                if request
-                    .selected_range
-                    .is_some_and(|range| range.contains(&display_row))
+                for region in request
+                    .selected_regions
+                    .iter()
+                    .filter(|region| region.rows.contains(&display_row))
                 {
-                    paint_line.style = paint_line.style.add_modifier(Modifier::REVERSED);
-                    for span in &mut paint_line.spans {
-                        span.style = span.style.add_modifier(Modifier::REVERSED);
-                    }
+                    restyle_region_spans(&mut paint_line, region.columns.as_ref(), |style| {
+                        style.add_modifier(Modifier::REVERSED)
+                    });
                 }
 
                 Some(paint_line)
@@ -1503,22 +1689,30 @@ impl<'a> DiffPage<'a> {
     }
 
     /// Builds wrapped diff lines for the diff panel, optionally reserving one
-    /// column for the scrollbar thumb.
+    /// column for the scrollbar thumb, with code and changed words styled by
+    /// `decorations`.
     fn build_diff_lines(
         parsed: &[DiffLine<'_>],
         layout: diff_util::DiffRenderLayout,
+        decorations: &DiffLineDecorations,
     ) -> DiffBuiltLines {
         let gutter_style = diff_util::body_diff_line_gutter_style();
         let mut lines: Vec<Line<'static>> = Vec::with_capacity(parsed.len());
         let mut changed_line_ranges = Vec::new();
 
-        for diff_line in parsed {
+        for (line_index, diff_line) in parsed.iter().enumerate() {
             let rendered_start_index = lines.len();
             if Self::append_special_diff_line(&mut lines, diff_line) {
                 continue;
             }
 
-            Self::append_body_diff_line(&mut lines, diff_line, layout, gutter_style);
+            Self::append_body_diff_line(
+                &mut lines,
+                diff_line,
+                layout,
+                gutter_style,
+                decorations.line(line_index),
+            );
             let is_changed_line = diff_line.kind == DiffLineKind::Addition
                 || diff_line.kind == DiffLineKind::Deletion;
             if is_changed_line {
@@ -1531,11 +1725,154 @@ impl<'a> DiffPage<'a> {
         }
 
         DiffBuiltLines {
+            changed_line_columns: vec![None; changed_line_ranges.len()],
+            changed_line_ranges,
+            lines,
+        }
+    }
+
+    /// Builds side-by-side diff rows with old lines on the left and new lines
+    /// on the right, aligned by [`diff_util::split_diff_rows`].
+    ///
+    /// Each changed line owns the rows and display columns of its cell, so
+    /// cursor and comment highlights paint only the side that line belongs
+    /// to.
+    fn build_split_diff_lines(
+        parsed: &[DiffLine<'_>],
+        layout: diff_util::DiffRenderLayout,
+        decorations: &DiffLineDecorations,
+    ) -> DiffBuiltLines {
+        let gutter_style = diff_util::body_diff_line_gutter_style();
+        let old_width = layout
+            .content_width
+            .saturating_sub(SPLIT_COLUMN_SEPARATOR_WIDTH)
+            / 2;
+        let new_start = old_width + SPLIT_COLUMN_SEPARATOR_WIDTH;
+        let new_width = layout.content_width.saturating_sub(new_start);
+        let mut lines: Vec<Line<'static>> = Vec::with_capacity(parsed.len());
+        let mut line_cells: Vec<Option<(Range<usize>, Range<usize>)>> = vec![None; parsed.len()];
+
+        for row in diff_util::split_diff_rows(parsed) {
+            let (old_index, new_index) = match row {
+                diff_util::SplitDiffRow::Header(line_index) => {
+                    Self::append_special_diff_line(&mut lines, &parsed[line_index]);
+
+                    continue;
+                }
+                diff_util::SplitDiffRow::Body { new, old } => (old, new),
+            };
+            let split_cell = |line_index: Option<usize>, side, width| {
+                line_index.map_or_else(Vec::new, |line_index| {
+                    Self::split_diff_cell(
+                        &parsed[line_index],
+                        (side, width),
+                        layout.gutter_width,
+                        gutter_style,
+                        decorations.line(line_index),
+                    )
+                })
+            };
+            let old_cell = split_cell(old_index, DiffLineSide::Old, old_width);
+            let new_cell = split_cell(new_index, DiffLineSide::New, new_width);
+            let rendered_start_index = lines.len();
+            for row_index in 0..old_cell.len().max(new_cell.len()).max(1) {
+                let mut spans = old_cell
+                    .get(row_index)
+                    .cloned()
+                    .unwrap_or_else(|| vec![Span::raw(" ".repeat(old_width))]);
+                spans.push(Span::styled(SPLIT_COLUMN_SEPARATOR, gutter_style));
+                spans.extend(
+                    new_cell
+                        .get(row_index)
+                        .cloned()
+                        .unwrap_or_else(|| vec![Span::raw(" ".repeat(new_width))]),
+                );
+                lines.push(Line::from(spans));
+            }
+            if let Some(old_index) = old_index {
+                line_cells[old_index] = Some((
+                    rendered_start_index..rendered_start_index + old_cell.len(),
+                    0..old_width,
+                ));
+            }
+            if let Some(new_index) = new_index.filter(|new_index| Some(*new_index) != old_index) {
+                line_cells[new_index] = Some((
+                    rendered_start_index..rendered_start_index + new_cell.len(),
+                    new_start..layout.content_width,
+                ));
+            }
+        }
+
+        if lines.is_empty() {
+            lines.push(Line::from(" No changes found. "));
+        }
+        let (changed_line_ranges, changed_line_columns) = parsed
+            .iter()
+            .zip(line_cells)
+            .filter(|(diff_line, _)| {
+                matches!(
+                    diff_line.kind,
+                    DiffLineKind::Addition | DiffLineKind::Deletion
+                )
+            })
+            .filter_map(|(_, cell)| cell)
+            .map(|(rows, columns)| (rows, Some(columns)))
+            .unzip();
+
+        DiffBuiltLines {
+            changed_line_columns,
             changed_line_ranges,
             lines,
         }
     }
 
+    /// Builds the wrapped rows of one split-layout cell, each padded to
+    /// `width` columns.
+    fn split_diff_cell(
+        diff_line: &DiffLine<'_>,
+        (side, width): (DiffLineSide, usize),
+        gutter_width: usize,
+        gutter_style: Style,
+        decoration: DiffLineDecoration<'_>,
+    ) -> Vec<Vec<Span<'static>>> {
+        let (sign, content_style) = diff_util::body_diff_line_style(diff_line.kind);
+        let line_number = match side {
+            DiffLineSide::Old => diff_line.old_line,
+            DiffLineSide::New => diff_line.new_line,
+        };
+        let gutter_text = line_number.map_or_else(
+            || " ".repeat(gutter_width + 1),
+            |line_number| format!("{line_number:>gutter_width$} "),
+        );
+        let prefix_width = gutter_width + SPLIT_SIDE_PREFIX_EXTRA_WIDTH;
+        let chunks =
+            diff_util::wrap_diff_content(diff_line.content, width.saturating_sub(prefix_width));
+        let mut chunk_start = 0;
+
+        chunks
+            .iter()
+            .enumerate()
+            .map(|(index, chunk)| {
+                let mut spans = if index == WRAPPED_CHUNK_START_INDEX {
+                    vec![
+                        Span::styled(gutter_text.clone(), gutter_style),
+                        Span::styled(sign, content_style),
+                    ]
+                } else {
+                    vec![Span::styled(" ".repeat(prefix_width), gutter_style)]
+                };
+                spans.extend(decoration.chunk_spans(chunk, chunk_start, content_style));
+                chunk_start += chunk.len();
+                let cell_width = spans.iter().map(Span::width).sum::<usize>();
+                if cell_width < width {
+                    spans.push(Span::raw(" ".repeat(width - cell_width)));
+                }
+
+                spans
+            })
+            .collect()
+    }
+
     /// Appends file and hunk headers, returning whether the line was consumed.
     fn append_special_diff_line(lines: &mut Vec<Line<'static>>, diff_line: &DiffLine<'_>) -> bool {
         match diff_line.kind {
@@ -1567,31 +1904,34 @@ impl<'a> DiffPage<'a> {
         )));
     }
 
-    /// Appends one addition, deletion, or context line with wrapped content.
+    /// Appends one addition, deletion, or context line with wrapped content
+    /// styled by its syntax spans and changed-word ranges.
     fn append_body_diff_line(
         lines: &mut Vec<Line<'static>>,
         diff_line: &DiffLine<'_>,
         layout: diff_util::DiffRenderLayout,
         gutter_style: Style,
+        decoration: DiffLineDecoration<'_>,
     ) {
         let (sign, content_style) = diff_util::body_diff_line_style(diff_line.kind);
         let gutter_text = diff_util::body_diff_line_gutter(diff_line, layout.gutter_width);
         let content_available = layout.content_width.saturating_sub(layout.prefix_width);
         let chunks = diff_util::wrap_diff_content(diff_line.content, content_available);
+        let mut chunk_start = 0;
 
         for (index, chunk) in chunks.iter().enumerate() {
-            if index == WRAPPED_CHUNK_START_INDEX {
-                lines.push(Line::from(vec![
+            let content_spans = decoration.chunk_spans(chunk, chunk_start, content_style);
+            chunk_start += chunk.len();
+            let mut spans = if index == WRAPPED_CHUNK_START_INDEX {
+                vec![
                     Span::styled(gutter_text.clone(), gutter_style),
                     Span::styled(sign, content_style),
-                    Span::styled((*chunk).to_string(), content_style),
-                ]));
+                ]
             } else {
-                lines.push(Line::from(vec![
-                    Span::styled(" ".repeat(layout.prefix_width), gutter_style),
-                    Span::styled((*chunk).to_string(), content_style),
-                ]));
-            }
+                vec![Span::styled(" ".repeat(layout.prefix_width), gutter_style)]
+            };
+            spans.extend(content_spans);
+            lines.push(Line::from(spans));
         }
     }
 }
@@ -1599,7 +1939,7 @@ impl<'a> DiffPage<'a> {
 /// Returns the max valid scroll offset for the selected diff panel.
 pub(crate) fn diff_view_max_scroll_offset(
     diff: &str,
-    line_comments: &DiffLineComments,
+    (line_comments, layout_mode): (&DiffLineComments, DiffLayoutMode),
     selected_index: usize,
     terminal_area: Rect,
     diff_layout_cache: &DiffLayoutCache,
@@ -1626,8 +1966,13 @@ pub(crate) fn diff_view_max_scroll_offset(
             _ => 0,
         };
     }
-    let layout =
-        diff_layout_cache.resolved_layout(&content, line_comments, selected_index, diff_area);
+    let layout = diff_layout_cache.resolved_layout(
+        &content,
+        line_comments,
+        layout_mode,
+        selected_index,
+        diff_area,
+    );
     if layout.render_layout.viewport_height == 0 {
         return 0;
     }
@@ -1642,7 +1987,7 @@ pub(crate) fn diff_view_max_scroll_offset(
 /// Returns cached layout metadata for changed-line navigation in one file.
 pub(crate) fn diff_changed_line_layout(
     diff: &str,
-    line_comments: &DiffLineComments,
+    (line_comments, layout_mode): (&DiffLineComments, DiffLayoutMode),
     selected_file_index: usize,
     terminal_area: Rect,
     diff_layout_cache: &DiffLayoutCache,
@@ -1650,7 +1995,13 @@ pub(crate) fn diff_changed_line_layout(
     let diff_area = diff_util::diff_page_areas(terminal_area).diff_area;
     let content = diff_layout_cache.content(diff);
 
-    diff_layout_cache.resolved_layout(&content, line_comments, selected_file_index, diff_area)
+    diff_layout_cache.resolved_layout(
+        &content,
+        line_comments,
+        layout_mode,
+        selected_file_index,
+        diff_area,
+    )
 }
 
 impl DiffPage<'_> {
@@ -1750,8 +2101,9 @@ impl Page for DiffPage<'_> {
         } else {
             (false, false)
         };
-        let help_message = Paragraph::new(crate::ui::help_format::footer_line(
-            &help_action::diff_footer_actions(help_action::DiffFooterContext {
+        let footer_actions = self.keymap.remap_help_actions(
+            KeymapMode::Diff,
+            help_action::diff_footer_actions(help_action::DiffFooterContext {
                 can_mark_selected,
                 can_submit,
                 focus: self.focus,
@@ -1767,9 +2119,11 @@ impl Page for DiffPage<'_> {
                         comment_count: self.line_comments.comments.len(),
                     }
                 },
+                revert_state: self.revert_footer_state(),
                 sidebar_focus: self.sidebar_focus,
             }),
-        ));
+        );
+        let help_message = Paragraph::new(crate::ui::help_format::footer_line(&footer_actions));
         f.render_widget(help_message, areas.footer_area);
     }
 }
@@ -1789,6 +2143,30 @@ fn preview_path_for_selection<'a>(
     Some(preview_path)
 }
 
+/// Applies `restyle` to the spans of one painted row, limited to spans that
+/// start inside `columns` when a split-layout side is given.
+fn restyle_region_spans(
+    line: &mut Line<'_>,
+    columns: Option<&Range<usize>>,
+    restyle: impl Fn(Style) -> Style,
+) {
+    let Some(columns) = columns else {
+        line.style = restyle(line.style);
+        for span in &mut line.spans {
+            span.style = restyle(span.style);
+        }
+
+        return;
+    };
+    let mut span_start = 0;
+    for span in &mut line.spans {
+        if columns.contains(&span_start) {
+            span.style = restyle(span.style);
+        }
+        span_start += span.width();
+    }
+}
+
 /// Inserts a visible cursor marker into one inline comment's text.
 fn input_text_with_cursor(input: &crate::domain::input::InputState) -> String {
     let character_count = input.text().chars().count();
@@ -1891,6 +2269,8 @@ mod tests {
             diff_layout_cache: test_diff_layout_cache(),
             file_explorer_selected_index,
             focus: DiffFocus::Files,
+            keymap: test_keymap(),
+            layout_mode: DiffLayoutMode::default(),
             line_comments: test_line_comments(),
             markdown_render_cache: test_markdown_render_cache(),
             preview: test_diff_preview(),
@@ -1915,6 +2295,8 @@ mod tests {
             diff_layout_cache: test_diff_layout_cache(),
             file_explorer_selected_index,
             focus: DiffFocus::Files,
+            keymap: test_keymap(),
+            layout_mode: DiffLayoutMode::default(),
             line_comments: test_line_comments(),
             markdown_render_cache: test_markdown_render_cache(),
             preview,
@@ -1930,6 +2312,10 @@ mod tests {
         Box::leak(Box::new(DiffLayoutCache::default()))
     }
 
+    fn test_keymap() -> &'static Keymap {
+        Box::leak(Box::new(Keymap::default()))
+    }
+
     fn test_markdown_render_cache() -> &'static markdown::MarkdownRenderCache {
         Box::leak(Box::new(markdown::MarkdownRenderCache::default()))
     }
@@ -1950,6 +2336,20 @@ mod tests {
             .collect()
     }
 
+    fn buffer_text_cell(buffer: &ratatui::buffer::Buffer, text: &str) -> Option<(u16, u16)> {
+        let area = buffer.area;
+
+        (area.top()..area.bottom()).find_map(|y| {
+            let row = (area.left()..area.right())
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>();
+            let byte_index = row.find(text)?;
+            let x = u16::try_from(row[..byte_index].chars().count()).ok()?;
+
+            Some((area.left() + x, y))
+        })
+    }
+
     fn background_cell_count(
         buffer: &ratatui::buffer::Buffer,
         color: ratatui::style::Color,
@@ -2169,6 +2569,8 @@ mod tests {
             diff_layout_cache: &diff_layout_cache,
             file_explorer_selected_index: 1,
             focus: DiffFocus::Content,
+            keymap: test_keymap(),
+            layout_mode: DiffLayoutMode::default(),
             line_comments: &line_comments,
             markdown_render_cache: &markdown_render_cache,
             preview: test_diff_preview(),
@@ -2248,6 +2650,8 @@ mod tests {
             diff_layout_cache: &diff_layout_cache,
             file_explorer_selected_index: 1,
             focus: DiffFocus::Content,
+            keymap: test_keymap(),
+            layout_mode: DiffLayoutMode::default(),
             line_comments: &line_comments,
             markdown_render_cache: &markdown_render_cache,
             preview: test_diff_preview(),
@@ -2311,6 +2715,8 @@ mod tests {
             diff_layout_cache: &diff_layout_cache,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Content,
+            keymap: test_keymap(),
+            layout_mode: DiffLayoutMode::default(),
             line_comments: &line_comments,
             markdown_render_cache: &markdown_render_cache,
             preview: test_diff_preview(),
@@ -2393,7 +2799,7 @@ mod tests {
         // Arrange
         let cache = DiffLayoutCache::default();
         let (current_lines, current_success_color, expected_current_success) = {
-            let _theme_scope = style::scoped_active_theme(ColorTheme::Current);
+            let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
             let content = cache.content(SAMPLE_DIFF);
             let lines = content.file_list_lines();
             let success_color = lines[0].spans[2].style.fg;
@@ -2403,7 +2809,7 @@ mod tests {
 
         // Act
         let (green_lines, green_success_color, expected_green_success) = {
-            let _theme_scope = style::scoped_active_theme(ColorTheme::Green);
+            let _theme_scope = style::scoped_active_theme(&ColorTheme::Green);
             let content = cache.content(SAMPLE_DIFF);
             let lines = content.file_list_lines();
             let success_color = lines[0].spans[2].style.fg;
@@ -2571,7 +2977,13 @@ mod tests {
         let line_comments = DiffLineComments::default();
 
         // Act
-        let layout = diff_changed_line_layout(&diff, &line_comments, 0, terminal_area, &cache);
+        let layout = diff_changed_line_layout(
+            &diff,
+            (&line_comments, DiffLayoutMode::Unified),
+            0,
+            terminal_area,
+            &cache,
+        );
         let scrolled_down = layout
             .changed_line_scroll_offset(20, 0)
             .expect("selected changed line should have a rendered range");
@@ -2584,8 +2996,13 @@ mod tests {
         let scrolled_up = layout
             .changed_line_scroll_offset(0, scrolled_down)
             .expect("first changed line should have a rendered range");
-        let zero_height_layout =
-            diff_changed_line_layout(&diff, &line_comments, 0, zero_height_area, &cache);
+        let zero_height_layout = diff_changed_line_layout(
+            &diff,
+            (&line_comments, DiffLayoutMode::Unified),
+            0,
+            zero_height_area,
+            &cache,
+        );
         let zero_height_scroll_offset = zero_height_layout
             .changed_line_scroll_offset(0, scrolled_down)
             .expect("first changed line should remain selectable without a viewport");
@@ -2616,7 +3033,7 @@ mod tests {
         let cache = DiffLayoutCache::default();
         let layout = diff_changed_line_layout(
             diff,
-            &DiffLineComments::default(),
+            (&DiffLineComments::default(), DiffLayoutMode::Unified),
             0,
             Rect::new(0, 0, 80, 12),
             &cache,
@@ -2637,7 +3054,7 @@ mod tests {
         let content = cache.content(diff);
         let changed_line_ranges = diff_changed_line_layout(
             diff,
-            &DiffLineComments::default(),
+            (&DiffLineComments::default(), DiffLayoutMode::Unified),
             0,
             Rect::new(0, 0, 80, 12),
             &cache,
@@ -2700,8 +3117,13 @@ mod tests {
             .expect("range comment should be editable")
             .insert_text("Explain this range");
         line_comments.finish_editing();
-        let layout =
-            diff_changed_line_layout(diff, &line_comments, 0, Rect::new(0, 0, 80, 12), &cache);
+        let layout = diff_changed_line_layout(
+            diff,
+            (&line_comments, DiffLayoutMode::Unified),
+            0,
+            Rect::new(0, 0, 80, 12),
+            &cache,
+        );
 
         // Act
         let next_selection =
@@ -2723,8 +3145,13 @@ mod tests {
         let empty_comments = DiffLineComments::default();
         let tall_area = Rect::new(0, 0, 50, 100);
         let sizing_diff = "diff --git a/main.rs b/main.rs\n@@ -0,0 +1 @@\n+x";
-        let sizing_layout =
-            diff_changed_line_layout(sizing_diff, &empty_comments, 0, tall_area, &cache);
+        let sizing_layout = diff_changed_line_layout(
+            sizing_diff,
+            (&empty_comments, DiffLayoutMode::Unified),
+            0,
+            tall_area,
+            &cache,
+        );
         let line_width = sizing_layout
             .render_layout
             .content_width
@@ -2733,12 +3160,23 @@ mod tests {
             "diff --git a/main.rs b/main.rs\n@@ -0,0 +1 @@\n+{}",
             "x".repeat(line_width),
         );
-        let tall_layout = diff_changed_line_layout(&diff, &empty_comments, 0, tall_area, &cache);
+        let tall_layout = diff_changed_line_layout(
+            &diff,
+            (&empty_comments, DiffLayoutMode::Unified),
+            0,
+            tall_area,
+            &cache,
+        );
         let fitting_height = u16::try_from(tall_layout.line_count.saturating_add(5))
             .expect("short diff height should fit in a terminal");
         let fitting_area = Rect::new(0, 0, 50, fitting_height);
-        let fitting_layout =
-            diff_changed_line_layout(&diff, &empty_comments, 0, fitting_area, &cache);
+        let fitting_layout = diff_changed_line_layout(
+            &diff,
+            (&empty_comments, DiffLayoutMode::Unified),
+            0,
+            fitting_area,
+            &cache,
+        );
         let content = cache.content(&diff);
         let anchor = content
             .selected_changed_line(0, 0)
@@ -2752,11 +3190,16 @@ mod tests {
         line_comments.finish_editing();
 
         // Act
-        let commented_layout =
-            diff_changed_line_layout(&diff, &line_comments, 0, fitting_area, &cache);
+        let commented_layout = diff_changed_line_layout(
+            &diff,
+            (&line_comments, DiffLayoutMode::Unified),
+            0,
+            fitting_area,
+            &cache,
+        );
         let max_scroll_offset = diff_view_max_scroll_offset(
             &diff,
-            &line_comments,
+            (&line_comments, DiffLayoutMode::Unified),
             0,
             fitting_area,
             &cache,
@@ -2894,18 +3337,61 @@ mod tests {
 
         // Act
         let line_comments = DiffLineComments::default();
-        let first_layout = cache.resolved_layout(&content, &line_comments, 0, area);
-        let second_layout = cache.resolved_layout(&content, &line_comments, 0, area);
+        let first_layout =
+            cache.resolved_layout(&content, &line_comments, DiffLayoutMode::Unified, 0, area);
+        let second_layout =
+            cache.resolved_layout(&content, &line_comments, DiffLayoutMode::Unified, 0, area);
 
         // Assert
         assert!(Arc::ptr_eq(&first_layout.lines, &second_layout.lines));
         assert_eq!(first_layout.line_count, second_layout.line_count);
     }
 
+    #[test]
+    fn test_diff_layout_highlights_code_over_diff_row_styles() {
+        // Arrange
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
+        let cache = DiffLayoutCache::default();
+        let content = cache.content(concat!(
+            "diff --git a/src/main.rs b/src/main.rs\n",
+            "@@ -0,0 +1 @@\n",
+            "+let value = 1;\n",
+        ));
+        let area = Rect::new(0, 0, 80, 12);
+
+        // Act
+        let layout = cache.resolved_layout(
+            &content,
+            &DiffLineComments::default(),
+            DiffLayoutMode::Unified,
+            0,
+            area,
+        );
+        let added_row = &layout.lines[2];
+        let keyword_span = added_row
+            .spans
+            .iter()
+            .find(|span| span.content == "let")
+            .expect("keyword should render as its own span");
+        let plain_span = added_row
+            .spans
+            .iter()
+            .find(|span| span.content == " value = ")
+            .expect("plain code should render as its own span");
+
+        // Assert
+        assert_eq!(keyword_span.style.fg, Some(style::palette::accent()));
+        assert_eq!(
+            keyword_span.style.bg,
+            Some(style::palette::surface_success())
+        );
+        assert_eq!(plain_span.style.fg, Some(style::palette::success()));
+    }
+
     #[test]
     fn test_render_shows_updated_diff_help_hint() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
         let mut session = session_fixture();
         session.stats.added_lines = 1;
         session.stats.deleted_lines = 0;
@@ -3060,6 +3546,108 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn test_split_layout_places_changed_pair_side_by_side() {
+        // Arrange
+        let cache = DiffLayoutCache::default();
+        let content = cache.content(concat!(
+            "diff --git a/src/main.rs b/src/main.rs\n",
+            "@@ -1,2 +1,2 @@\n",
+            " keep\n",
+            "-old content\n",
+            "+new content\n",
+        ));
+        let area = Rect::new(0, 0, 82, 12);
+
+        // Act
+        let layout = cache.resolved_layout(
+            &content,
+            &DiffLineComments::default(),
+            DiffLayoutMode::Split,
+            0,
+            area,
+        );
+
+        // Assert
+        let pair_row = layout.lines[3].to_string();
+        assert_eq!(layout.line_count, 4);
+        assert!(pair_row.contains("old content"));
+        assert!(pair_row.contains("new content"));
+        assert!(pair_row.find("old content") < pair_row.find("new content"));
+        assert_eq!(layout.changed_line_ranges.as_ref(), [3..4, 3..4]);
+        assert_eq!(
+            layout.changed_line_columns.as_ref(),
+            [Some(0..39), Some(40..80)]
+        );
+    }
+
+    #[test]
+    fn test_diff_layout_emphasizes_changed_words_in_modified_pairs() {
+        // Arrange
+        let cache = DiffLayoutCache::default();
+        let content = cache.content(concat!(
+            "diff --git a/notes.txt b/notes.txt\n",
+            "@@ -1 +1 @@\n",
+            "-retry count 1\n",
+            "+retry count 42\n",
+        ));
+        let area = Rect::new(0, 0, 80, 12);
+
+        // Act
+        let layout = cache.resolved_layout(
+            &content,
+            &DiffLineComments::default(),
+            DiffLayoutMode::Unified,
+            0,
+            area,
+        );
+
+        // Assert
+        let emphasized_text = |line: &Line<'_>| {
+            line.spans
+                .iter()
+                .filter(|span| span.style.add_modifier.contains(WORD_CHANGE_MODIFIER))
+                .map(|span| span.content.to_string())
+                .collect::<String>()
+        };
+        assert_eq!(emphasized_text(&layout.lines[2]), "1");
+        assert_eq!(emphasized_text(&layout.lines[3]), "42");
+    }
+
+    #[test]
+    fn test_render_split_layout_highlights_only_selected_side() {
+        // Arrange
+        let session = session_fixture();
+        let diff = concat!(
+            "diff --git a/src/main.rs b/src/main.rs\n",
+            "@@ -1,2 +1,2 @@\n",
+            "-old content\n",
+            "+new content\n"
+        );
+        let mut diff_page = new_diff_page(&session, diff, 0, 1);
+        diff_page.focus = DiffFocus::Content;
+        diff_page.layout_mode = DiffLayoutMode::Split;
+        diff_page.selected_diff_line_index = 1;
+        let backend = ratatui::backend::TestBackend::new(120, 30);
+        let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
+
+        // Act
+        terminal
+            .draw(|frame| {
+                let area = frame.area();
+                Page::render(&mut diff_page, frame, area);
+            })
+            .expect("failed to draw split diff page");
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let old_cell = buffer_text_cell(buffer, "old content").expect("old side should render");
+        let new_cell = buffer_text_cell(buffer, "new content").expect("new side should render");
+        assert_eq!(old_cell.1, new_cell.1);
+        assert!(!buffer[old_cell].modifier.contains(Modifier::REVERSED));
+        assert!(buffer[new_cell].modifier.contains(Modifier::REVERSED));
+    }
+
     #[test]
     fn test_render_shows_scrollbar_for_overflowing_diff() {
         // Arrange
@@ -3249,6 +3837,8 @@ mod tests {
             diff_layout_cache: &diff_layout_cache,
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            keymap: test_keymap(),
+            layout_mode: DiffLayoutMode::default(),
             line_comments: &line_comments,
             markdown_render_cache: &markdown_render_cache,
             preview: &preview,
@@ -3265,7 +3855,7 @@ mod tests {
         // Act
         let max_scroll_offset = diff_view_max_scroll_offset(
             diff,
-            &line_comments,
+            (&line_comments, DiffLayoutMode::Unified),
             0,
             terminal_area,
             &diff_layout_cache,
@@ -3298,7 +3888,7 @@ mod tests {
         // Act
         let max_scroll_offset = diff_view_max_scroll_offset(
             diff,
-            &line_comments,
+            (&line_comments, DiffLayoutMode::Unified),
             0,
             Rect::new(0, 0, 80, 12),
             &diff_layout_cache,
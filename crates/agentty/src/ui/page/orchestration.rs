@@ -1,4 +1,4 @@
-//! Board-first orchestration campaign page.
+//! Board-first orchestration campaign page and the full-screen plan board.
 
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Rect};
@@ -6,8 +6,11 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
+use crate::domain::orchestration::{OrchestrationBoard, OrchestrationBoardTask};
+use crate::presentation::app_mode::{OrchestrationTaskEditor, OrchestrationTaskField};
+use crate::ui::component::chat_input::ChatInput;
 use crate::ui::page::session_chat::{SessionChatPage, SessionChatPageInput};
-use crate::ui::{Page, style};
+use crate::ui::{Component, Page, style};
 
 /// Controller page that keeps campaign state above a compact conversation
 /// pane.
@@ -95,6 +98,273 @@ impl Page for OrchestrationPage<'_> {
     }
 }
 
+/// Full-screen campaign board listing every task with its editable plan.
+pub struct OrchestrationBoardPage<'a> {
+    board: &'a OrchestrationBoard,
+    editor: Option<&'a OrchestrationTaskEditor>,
+    expanded: bool,
+    notice: Option<&'a str>,
+    selected_index: usize,
+    title: &'a str,
+}
+
+impl<'a> OrchestrationBoardPage<'a> {
+    /// Creates a board page for one campaign snapshot.
+    pub fn new(board: &'a OrchestrationBoard, title: &'a str, selected_index: usize) -> Self {
+        Self {
+            board,
+            editor: None,
+            expanded: false,
+            notice: None,
+            selected_index,
+            title,
+        }
+    }
+
+    /// Sets the active task editor.
+    #[must_use]
+    pub fn editor(mut self, editor: Option<&'a OrchestrationTaskEditor>) -> Self {
+        self.editor = editor;
+
+        self
+    }
+
+    /// Sets whether the selected task shows its full plan.
+    #[must_use]
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+
+        self
+    }
+
+    /// Sets the outcome of the latest rejected board action.
+    #[must_use]
+    pub fn notice(mut self, notice: Option<&'a str>) -> Self {
+        self.notice = notice;
+
+        self
+    }
+
+    fn selected_task(&self) -> Option<&'a OrchestrationBoardTask> {
+        self.board.tasks.get(self.selected_index)
+    }
+
+    fn render_task_list(&self, frame: &mut Frame, area: Rect) {
+        let lines = self
+            .board
+            .tasks
+            .iter()
+            .enumerate()
+            .map(|(index, task)| {
+                let style = if index == self.selected_index {
+                    Style::default()
+                        .fg(style::palette::text())
+                        .bg(style::palette::surface_selection())
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(style::palette::text())
+                };
+
+                Line::from(vec![
+                    Span::styled(format!("{:>2}. ", index + 1), style),
+                    Span::styled(format!("[{}] ", task.status), task_status_style(task)),
+                    Span::styled(
+                        format!("{} - {} (wave {})", task.task_key, task.title, task.wave),
+                        style,
+                    ),
+                ])
+            })
+            .collect::<Vec<_>>();
+        let list = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(style::palette::border()))
+                .title(format!(
+                    " Campaign board: {} ({}) ",
+                    self.title, self.board.status
+                )),
+        );
+
+        frame.render_widget(list, area);
+    }
+
+    fn render_task_detail(&self, frame: &mut Frame, area: Rect) {
+        let lines = match self.selected_task() {
+            Some(task) if self.expanded => task_detail_lines(task),
+            Some(_) => vec![Line::from(Span::styled(
+                "Press Enter to show the prompt, acceptance criteria, and touched areas.",
+                Style::default().fg(style::palette::text_muted()),
+            ))],
+            None => vec![Line::from(Span::styled(
+                "This campaign has no tasks yet.",
+                Style::default().fg(style::palette::text_muted()),
+            ))],
+        };
+        let detail = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(style::palette::border()))
+                    .title(" Task "),
+            )
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(detail, area);
+    }
+
+    fn render_editor(editor: &OrchestrationTaskEditor, frame: &mut Frame, area: Rect) {
+        let fields = [
+            OrchestrationTaskField::Title,
+            OrchestrationTaskField::Prompt,
+            OrchestrationTaskField::AcceptanceCriteria,
+            OrchestrationTaskField::TouchedAreas,
+        ];
+        let areas: [Rect; 4] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(5),
+            Constraint::Length(6),
+            Constraint::Length(5),
+        ])
+        .areas(area);
+        for (field, field_area) in fields.into_iter().zip(areas) {
+            let input = editor.input(field);
+            ChatInput::new(field.label(), input.text(), input.cursor)
+                .active(editor.focus == field)
+                .render(frame, field_area);
+        }
+    }
+
+    fn footer_line(&self) -> Line<'a> {
+        if let Some(notice) = self.notice {
+            return Line::from(Span::styled(
+                notice,
+                Style::default().fg(style::palette::warning()),
+            ));
+        }
+        let hint = if self.editor.is_some() {
+            "Tab next field  Alt+Enter newline  Enter save  Esc cancel".to_string()
+        } else {
+            board_hint(self.board, self.selected_task())
+        };
+
+        Line::from(Span::styled(
+            hint,
+            Style::default().fg(style::palette::text_muted()),
+        ))
+    }
+}
+
+impl Page for OrchestrationBoardPage<'_> {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let [body_area, footer_area] =
+            Layout::vertical([Constraint::Min(6), Constraint::Length(1)]).areas(area);
+        let list_height = u16::try_from(self.board.tasks.len().saturating_add(2))
+            .unwrap_or(u16::MAX)
+            .clamp(3, body_area.height / 2);
+        let [list_area, detail_area] =
+            Layout::vertical([Constraint::Length(list_height), Constraint::Min(3)])
+                .areas(body_area);
+
+        self.render_task_list(frame, list_area);
+        if let Some(editor) = self.editor {
+            Self::render_editor(editor, frame, detail_area);
+        } else {
+            self.render_task_detail(frame, detail_area);
+        }
+        frame.render_widget(Paragraph::new(self.footer_line()), footer_area);
+    }
+}
+
+/// Colors one task status by whether it needs user attention.
+fn task_status_style(task: &OrchestrationBoardTask) -> Style {
+    let color = if task.can_retry() || task.can_skip_integration() {
+        style::palette::danger()
+    } else if task.is_editable() {
+        style::palette::accent()
+    } else {
+        style::palette::text_muted()
+    };
+
+    Style::default().fg(color)
+}
+
+/// Builds the expanded plan view for one task.
+fn task_detail_lines(task: &OrchestrationBoardTask) -> Vec<Line<'static>> {
+    let heading = Style::default()
+        .fg(style::palette::accent())
+        .add_modifier(Modifier::BOLD);
+    let text = Style::default().fg(style::palette::text());
+    let mut lines = vec![Line::from(vec![
+        Span::styled(format!("{} ", task.task_key), heading),
+        Span::styled(format!("{} task, wave {}", task.kind, task.wave), text),
+    ])];
+    if !task.depends_on.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("Depends on: {}", task.depends_on.join(", ")),
+            text,
+        )));
+    }
+    if let Some(last_error) = &task.last_error {
+        lines.push(Line::from(Span::styled(
+            format!("Last error: {last_error}"),
+            Style::default().fg(style::palette::danger()),
+        )));
+    }
+    if let Some(verdict) = &task.verification_verdict {
+        let reason = task.verification_reason.as_deref().unwrap_or_default();
+        lines.push(Line::from(Span::styled(
+            format!("Verification: {verdict} {reason}"),
+            text,
+        )));
+    }
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled("Prompt", heading)));
+    lines.extend(
+        task.prompt
+            .lines()
+            .map(|line| Line::from(Span::styled(line.to_string(), text))),
+    );
+    for (title, entries) in [
+        ("Acceptance criteria", &task.acceptance_criteria),
+        ("Touched areas", &task.touched_areas),
+    ] {
+        if entries.is_empty() {
+            continue;
+        }
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled(title, heading)));
+        lines.extend(
+            entries
+                .iter()
+                .map(|entry| Line::from(Span::styled(format!("- {entry}"), text))),
+        );
+    }
+
+    lines
+}
+
+/// Returns the key hints valid for the selected task.
+fn board_hint(board: &OrchestrationBoard, task: Option<&OrchestrationBoardTask>) -> String {
+    let mut hints = vec!["j/k select", "Enter expand"];
+    if let Some(task) = task {
+        if task.is_editable() {
+            hints.extend(["e edit", "d delete"]);
+        }
+        if board.can_reorder() {
+            hints.push("K/J move");
+        }
+        if task.can_retry() {
+            hints.push("r retry");
+        }
+        if task.can_skip_integration() {
+            hints.push("s skip");
+        }
+    }
+    hints.push("q back");
+
+    hints.join("  ")
+}
+
 /// Splits a controller page into its campaign board and chat areas.
 ///
 /// Runtime scroll metrics use the same chat area so line-step bounds match
@@ -124,6 +394,7 @@ mod tests {
     use crate::domain::session::{SessionRole, Status};
     use crate::presentation::app_mode::AppMode;
     use crate::presentation::frame_time::FrameTime;
+    use crate::presentation::keymap::Keymap;
     use crate::ui::component::session_output::SessionOutputLayoutCache;
     use crate::ui::markdown::MarkdownRenderCache;
 
@@ -146,6 +417,7 @@ mod tests {
             default_reasoning_level: ReasoningLevel::default(),
             frame_time: FrameTime::new(0, 0, 0),
             has_merge_conflict: false,
+            keymap: &Keymap::default(),
             markdown_render_cache: &markdown_render_cache,
             mode: &mode,
             output_layout_cache: &output_layout_cache,
@@ -175,6 +447,55 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn board_page_renders_expanded_plan_and_selected_task_hints() {
+        // Arrange
+        let board = OrchestrationBoard {
+            status: crate::domain::orchestration::OrchestrationStatus::AwaitingApproval,
+            tasks: vec![OrchestrationBoardTask {
+                acceptance_criteria: vec!["Schema migrates cleanly".to_string()],
+                child_session_id: None,
+                depends_on: Vec::new(),
+                id: 1,
+                kind: crate::domain::orchestration::OrchestrationTaskKind::Implementation,
+                last_error: None,
+                prompt: "Add the schema column".to_string(),
+                status: crate::domain::orchestration::OrchestrationTaskStatus::Proposed,
+                task_key: "schema".to_string(),
+                title: "Schema".to_string(),
+                touched_areas: vec!["crates/ag-store/".to_string()],
+                verification_reason: None,
+                verification_verdict: None,
+                wave: 0,
+            }],
+        };
+        let backend = TestBackend::new(100, 24);
+        let mut terminal = Terminal::new(backend).expect("failed to create terminal");
+
+        // Act
+        terminal
+            .draw(|frame| {
+                OrchestrationBoardPage::new(&board, "Campaign", 0)
+                    .expanded(true)
+                    .render(frame, frame.area());
+            })
+            .expect("failed to render board");
+        let frame = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect::<String>();
+
+        // Assert
+        assert!(frame.contains("[Proposed] schema - Schema (wave 0)"));
+        assert!(frame.contains("Add the schema column"));
+        assert!(frame.contains("- Schema migrates cleanly"));
+        assert!(frame.contains("- crates/ag-store/"));
+        assert!(frame.contains("e edit  d delete  K/J move"));
+    }
+
     #[test]
     fn campaign_board_height_has_readable_minimum() {
         // Arrange, Act
@@ -12,6 +12,7 @@ use crate::domain::agent::{AgentCliInfo, AgentCliVersion};
 use crate::domain::project::ProjectListItem;
 use crate::domain::session::DailyActivity;
 use crate::presentation::help_action;
+use crate::presentation::keymap::Keymap;
 use crate::ui::activity_heatmap::{
     RecentActivityStats, build_activity_heatmap_grid, build_recent_activity_stats,
     build_visible_heatmap_month_row, heatmap_intensity_level, heatmap_max_count,
@@ -44,6 +45,8 @@ pub struct ProjectListPage<'a> {
     pub projects: &'a [ProjectListItem],
     /// Persisted local-day session activity used by the projects heatmap.
     pub stats_activity: &'a [DailyActivity],
+    /// User keymap applied to footer shortcut labels.
+    keymap: Option<&'a Keymap>,
     /// Stateful cursor position for the project table.
     pub table_state: &'a mut TableState,
 }
@@ -63,11 +66,20 @@ impl<'a> ProjectListPage<'a> {
             active_project_id,
             activity_end_day_key,
             agent_clis,
+            keymap: None,
             projects,
             stats_activity,
             table_state,
         }
     }
+
+    /// Sets the user keymap whose overrides relabel footer shortcuts.
+    #[must_use]
+    pub fn keymap(mut self, keymap: &'a Keymap) -> Self {
+        self.keymap = Some(keymap);
+
+        self
+    }
 }
 
 impl Page for ProjectListPage<'_> {
@@ -151,7 +163,7 @@ impl Page for ProjectListPage<'_> {
         f.render_widget(details_panel, details_area);
         f.render_widget(agent_cli_panel, agent_cli_area);
 
-        let help_message = Paragraph::new(project_list_footer_line());
+        let help_message = Paragraph::new(project_list_footer_line(self.keymap));
         f.render_widget(help_message, areas.footer_area);
     }
 }
@@ -208,13 +220,7 @@ impl ProjectListPage<'_> {
 
     /// Returns the color used for one projects-page heatmap intensity.
     fn heatmap_color(intensity: u8) -> Color {
-        match intensity {
-            1 => Color::Rgb(14, 68, 41),
-            2 => Color::Rgb(0, 109, 50),
-            3 => Color::Rgb(38, 166, 65),
-            4 => Color::Rgb(57, 211, 83),
-            _ => Color::Rgb(33, 38, 45),
-        }
+        style::palette::heatmap(intensity)
     }
 }
 
@@ -383,8 +389,8 @@ fn stat_value_span(text: impl Into<String>) -> Span<'static> {
 }
 
 /// Returns the footer help content rendered below the projects table.
-fn project_list_footer_line() -> Line<'static> {
-    crate::ui::help_format::footer_line(&help_action::project_list_footer_actions())
+fn project_list_footer_line(keymap: Option<&Keymap>) -> Line<'static> {
+    crate::ui::help_format::list_footer_line(keymap, help_action::project_list_footer_actions())
 }
 
 /// Returns project row display values for reuse and testing.
@@ -558,7 +564,7 @@ mod tests {
     #[test]
     fn test_render_uses_palette_border_for_projects_table() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
         let projects = vec![ProjectListItem {
             active_session_count: 0,
             input_tokens: 0,
@@ -874,16 +880,29 @@ mod tests {
             crate::ui::help_format::footer_line(&help_action::project_list_footer_actions());
 
         // Act
-        let footer_line = project_list_footer_line();
+        let footer_line = project_list_footer_line(None);
 
         // Assert
         assert_eq!(footer_line, expected_line);
     }
 
+    #[test]
+    fn test_project_list_footer_line_relabels_keymap_overrides() {
+        // Arrange
+        let keymap = Keymap::from_toml("[list]\nselect_next = \"n\"\nquit = \"x\"\n")
+            .expect("keymap should load");
+
+        // Act
+        let footer_text = project_list_footer_line(Some(&keymap)).to_string();
+
+        // Assert
+        assert_eq!(footer_text, "x: quit | Enter: select | n/k: nav | ?: help");
+    }
+
     #[test]
     fn test_render_shows_activity_heatmap_in_separate_top_panel() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
         let projects = vec![ProjectListItem {
             active_session_count: 0,
             input_tokens: 12_345,
@@ -728,7 +728,7 @@ mod tests {
     #[test]
     fn test_render_shows_comment_selector_and_general_detail() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
         let snapshot = comment_snapshot();
 
         // Act
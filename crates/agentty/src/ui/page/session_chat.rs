@@ -14,6 +14,7 @@ use crate::domain::{input, review};
 use crate::presentation::app_mode::{AppMode, ChatFocus};
 use crate::presentation::frame_time::FrameTime;
 use crate::presentation::help_action::{self, ViewActionAvailability, ViewHelpState};
+use crate::presentation::keymap::Keymap;
 use crate::presentation::prompt::PromptAtMentionState;
 use crate::ui::component::chat_input::{ChatInput, SuggestionList};
 use crate::ui::component::session_output::{
@@ -38,7 +39,6 @@ const SINGLE_ROW_FOOTER_HEIGHT: u16 = 1;
 /// Prompt-panel data prepared once per render pass so layout and painting use
 /// the same suggestion set.
 struct PreparedPromptPanel {
-    footer_text: Line<'static>,
     /// Whether the transcript above the composer currently holds focus, which
     /// dims the composer border and hides its cursor.
     is_chat_focused: bool,
@@ -118,6 +118,8 @@ pub struct SessionChatPage<'a> {
     pub has_merge_conflict: bool,
     /// One coherent render-time clock snapshot.
     pub(crate) frame_time: FrameTime,
+    /// User keymap applied to footer shortcut labels.
+    pub keymap: &'a Keymap,
     /// Shared markdown cache reused across transcript renders in this page.
     pub markdown_render_cache: &'a markdown::MarkdownRenderCache,
     /// Current UI mode that controls the bottom panel and focus.
@@ -151,6 +153,8 @@ pub struct SessionChatPageInput<'a> {
     pub has_merge_conflict: bool,
     /// One coherent render-time clock snapshot.
     pub(crate) frame_time: FrameTime,
+    /// User keymap applied to footer shortcut labels.
+    pub keymap: &'a Keymap,
     /// Shared render cache for session transcript markdown.
     pub markdown_render_cache: &'a markdown::MarkdownRenderCache,
     /// Current UI mode that determines view, prompt, and question rendering.
@@ -178,6 +182,7 @@ impl<'a> SessionChatPage<'a> {
             default_reasoning_level,
             frame_time,
             has_merge_conflict,
+            keymap,
             markdown_render_cache,
             mode,
             output_layout_cache,
@@ -195,6 +200,7 @@ impl<'a> SessionChatPage<'a> {
             default_reasoning_level,
             frame_time,
             has_merge_conflict,
+            keymap,
             markdown_render_cache,
             mode,
             output_layout_cache,
@@ -287,7 +293,13 @@ impl<'a> SessionChatPage<'a> {
     ) {
         let bottom_area = layout_plan.areas.bottom_area;
 
-        if let AppMode::Prompt { input, .. } = self.mode {
+        if let AppMode::Prompt {
+            attachment_state,
+            focus,
+            input,
+            ..
+        } = self.mode
+        {
             let Some(prepared_prompt_panel) = layout_plan.prompt_panel.as_ref() else {
                 return;
             };
@@ -315,7 +327,12 @@ impl<'a> SessionChatPage<'a> {
 
             chat_input.render(f, panel_areas.input_area);
             f.render_widget(
-                Paragraph::new(prepared_prompt_panel.footer_text.clone()),
+                Paragraph::new(prompt_format::prompt_footer_line(
+                    session,
+                    attachment_state.attachments.len(),
+                    *focus,
+                    self.keymap,
+                )),
                 panel_areas.footer_area,
             );
 
@@ -342,6 +359,7 @@ impl<'a> SessionChatPage<'a> {
                     focus: *focus,
                     has_session_diff: session.stats.should_show_diff(),
                     input,
+                    keymap: self.keymap,
                     questions,
                     selected_option_index: *selected_option_index,
                 },
@@ -374,8 +392,10 @@ impl<'a> SessionChatPage<'a> {
             publish_pull_request_action: session.publish_pull_request_action(),
             session_state: help_action::session_view_state(session),
         };
-        let help_message =
-            Paragraph::new(session_format::session_view_footer_line(view_help_state));
+        let help_message = Paragraph::new(session_format::session_view_footer_line(
+            view_help_state,
+            self.keymap,
+        ));
         f.render_widget(help_message, bottom_area);
     }
 }
@@ -428,7 +448,6 @@ fn prepare_prompt_panel(
 ) -> Option<PreparedPromptPanel> {
     let AppMode::Prompt {
         at_mention_state,
-        attachment_state,
         focus,
         input,
         slash_state,
@@ -465,11 +484,6 @@ fn prepare_prompt_panel(
     let max_bottom_height = area.height.saturating_sub(1);
 
     Some(PreparedPromptPanel {
-        footer_text: prompt_format::prompt_footer_line(
-            session,
-            attachment_state.attachments.len(),
-            *focus,
-        ),
         is_chat_focused: *focus == ChatFocus::Chat,
         status: Some(session_format::prompt_session_status(session)),
         suggestion_list,
@@ -552,6 +566,7 @@ struct QuestionPanelState<'a> {
     focus: ChatFocus,
     has_session_diff: bool,
     input: &'a input::InputState,
+    keymap: &'a Keymap,
     questions: &'a [QuestionItem],
     selected_option_index: Option<usize>,
 }
@@ -570,6 +585,7 @@ fn render_question_panel(
         focus,
         has_session_diff,
         input,
+        keymap,
         questions,
         selected_option_index,
     } = *state;
@@ -642,11 +658,11 @@ fn render_question_panel(
     render_question_help_footer(
         f,
         panel_areas.help_area,
-        panel_areas.help_area.height,
         focus,
         has_session_diff,
         !is_free_text_mode,
         is_at_mention_open,
+        keymap,
     );
 }
 
@@ -663,13 +679,13 @@ fn render_question_panel(
 fn render_question_help_footer(
     f: &mut Frame,
     area: Rect,
-    help_height: u16,
     focus: ChatFocus,
     has_session_diff: bool,
     is_navigating_options: bool,
     is_at_mention_open: bool,
+    keymap: &Keymap,
 ) {
-    if help_height == 0 {
+    if area.height == 0 {
         return;
     }
 
@@ -678,6 +694,7 @@ fn render_question_help_footer(
         has_session_diff,
         is_navigating_options,
         is_at_mention_open,
+        keymap,
     ))
     .alignment(ratatui::layout::Alignment::Left);
     f.render_widget(help_para, area);
@@ -741,6 +758,7 @@ mod tests {
             default_reasoning_level: ReasoningLevel::default(),
             frame_time: FrameTime::new(0, 0, 0),
             has_merge_conflict: false,
+            keymap: test_keymap(),
             markdown_render_cache: test_markdown_render_cache(),
             mode,
             output_layout_cache: test_output_layout_cache(),
@@ -821,6 +839,12 @@ mod tests {
         );
     }
 
+    /// Returns a leaked default keymap for test page builders that need a
+    /// stable borrow across the page lifetime.
+    fn test_keymap() -> &'static Keymap {
+        Box::leak(Box::new(Keymap::default()))
+    }
+
     /// Returns a leaked markdown cache for test page builders that need a
     /// stable borrow across the page lifetime.
     fn test_markdown_render_cache() -> &'static markdown::MarkdownRenderCache {
@@ -1134,12 +1158,33 @@ mod tests {
         let session = session_fixture();
 
         // Act
-        let footer = prompt_format::prompt_footer_line(&session, 0, ChatFocus::Input);
+        let footer =
+            prompt_format::prompt_footer_line(&session, 0, ChatFocus::Input, &Keymap::default());
 
         // Assert
         assert!(footer.to_string().contains("Shift+Tab: switch mode"));
     }
 
+    #[test]
+    fn test_prompt_footer_and_status_lock_controller_to_read_only() {
+        // Arrange
+        let mut session = session_fixture();
+        session.agent = crate::domain::agent::AgentSelection::new(
+            crate::domain::agent::AgentKind::Codex,
+            crate::domain::agent::AgentModel::Gpt56Sol,
+        );
+        session.role = crate::domain::session::SessionRole::Orchestrator;
+
+        // Act
+        let footer =
+            prompt_format::prompt_footer_line(&session, 0, ChatFocus::Input, &Keymap::default());
+        let text = rendered_prompt_mode_text(&session);
+
+        // Assert
+        assert!(!footer.to_string().contains("Shift+Tab"));
+        assert!(text.contains("· Normal · Read Only"));
+    }
+
     #[test]
     fn test_render_prompt_composer_shows_read_only_after_speed_status() {
         // Arrange
@@ -1208,6 +1253,7 @@ mod tests {
             focus: ChatFocus::Input,
             has_session_diff: false,
             input: &input,
+            keymap: &Keymap::default(),
             questions: &questions,
             selected_option_index: None,
         };
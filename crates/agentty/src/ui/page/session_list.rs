@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 
+use ag_forge::ReviewCheckReport;
 use ag_tui_text::text_util::{format_duration_compact, inline_text, truncate_spans_with_ellipsis};
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Rect};
@@ -13,8 +14,9 @@ use crate::domain::agent::ReasoningLevel;
 use crate::domain::session::{Session, SessionId, SessionSize, Status};
 use crate::domain::session_order::{self, GroupedSessionRow, SessionGroup, SessionTreePosition};
 use crate::presentation::help_action;
+use crate::presentation::keymap::Keymap;
 use crate::ui::input_layout::first_table_column_width;
-use crate::ui::{Page, layout, markdown, style};
+use crate::ui::{Page, layout, markdown, session_format, style};
 
 /// Uses row-background highlighting without a textual cursor glyph.
 const ROW_HIGHLIGHT_SYMBOL: &str = "";
@@ -33,6 +35,8 @@ pub struct SessionListPage<'a> {
     /// Active project-scoped default reasoning level for sessions without an
     /// override.
     pub default_reasoning_level: ReasoningLevel,
+    /// User keymap applied to footer shortcut labels.
+    keymap: Option<&'a Keymap>,
     /// Session rows available for rendering.
     pub sessions: &'a [Session],
     /// Latest session branch comparisons keyed by stable session id.
@@ -53,6 +57,7 @@ impl<'a> SessionListPage<'a> {
     ) -> Self {
         Self {
             default_reasoning_level,
+            keymap: None,
             sessions,
             session_git_statuses: None,
             table_state,
@@ -70,6 +75,14 @@ impl<'a> SessionListPage<'a> {
 
         self
     }
+
+    /// Sets the user keymap whose overrides relabel footer shortcuts.
+    #[must_use]
+    pub fn keymap(mut self, keymap: &'a Keymap) -> Self {
+        self.keymap = Some(keymap);
+
+        self
+    }
 }
 
 /// Render-ready cells and exact display widths derived once per session row.
@@ -117,26 +130,49 @@ impl PreparedSessionCells {
         } else {
             forge_indicator.chars().count().saturating_add(1)
         };
+        let check_badge = session
+            .review_checks
+            .as_ref()
+            .and_then(ReviewCheckReport::outcome)
+            .map(|outcome| (session_format::review_check_badge(outcome), outcome));
+        let check_badge_width = check_badge
+            .as_ref()
+            .map_or(0, |(badge, _)| badge.chars().count().saturating_add(1));
         let status_width = status_label
             .chars()
             .count()
-            .saturating_add(forge_indicator_width);
-        let status_cell = if forge_indicator.is_empty() {
+            .saturating_add(forge_indicator_width)
+            .saturating_add(check_badge_width);
+        let status_cell = if forge_indicator.is_empty() && check_badge.is_none() {
             Cell::from(status_label).style(
                 Style::default().fg(session_detail_color(session, style::status_color(status))),
             )
         } else {
-            let review_state = session.review_request.as_ref().map(|rr| rr.summary.state);
-            let indicator_color =
-                session_detail_color(session, style::forge_indicator_color(review_state));
-
-            Cell::from(Line::from(vec![
-                Span::styled(
-                    format!("{status_label} "),
-                    Style::default().fg(session_detail_color(session, style::status_color(status))),
-                ),
-                Span::styled(forge_indicator, Style::default().fg(indicator_color)),
-            ]))
+            let mut spans = vec![Span::styled(
+                status_label,
+                Style::default().fg(session_detail_color(session, style::status_color(status))),
+            )];
+
+            if !forge_indicator.is_empty() {
+                let review_state = session.review_request.as_ref().map(|rr| rr.summary.state);
+                let indicator_color =
+                    session_detail_color(session, style::forge_indicator_color(review_state));
+
+                spans.push(Span::raw(" "));
+                spans.push(Span::styled(
+                    forge_indicator,
+                    Style::default().fg(indicator_color),
+                ));
+            }
+
+            if let Some((badge, outcome)) = check_badge {
+                let badge_color = session_detail_color(session, style::review_check_color(outcome));
+
+                spans.push(Span::raw(" "));
+                spans.push(Span::styled(badge, Style::default().fg(badge_color)));
+            }
+
+            Cell::from(Line::from(spans))
         };
 
         let timer_label = if session.has_in_progress_timer() {
@@ -277,13 +313,16 @@ impl Page for SessionListPage<'_> {
             .table_state
             .selected()
             .and_then(|selected_index| self.sessions.get(selected_index));
-        let help_message = Paragraph::new(session_list_help_line(selected_session));
+        let help_message = Paragraph::new(session_list_help_line(selected_session, self.keymap));
         f.render_widget(help_message, areas.footer_area);
     }
 }
 
 /// Builds footer help content for session list mode.
-fn session_list_help_line(selected_session: Option<&Session>) -> Line<'static> {
+fn session_list_help_line(
+    selected_session: Option<&Session>,
+    keymap: Option<&Keymap>,
+) -> Line<'static> {
     let can_cancel_selected_session = selected_session.is_some_and(Session::allows_cancel_action);
     let can_open_selected_session = selected_session.is_some();
     let actions = help_action::session_list_footer_actions(
@@ -291,7 +330,7 @@ fn session_list_help_line(selected_session: Option<&Session>) -> Line<'static> {
         can_open_selected_session,
     );
 
-    crate::ui::help_format::footer_line(&actions)
+    crate::ui::help_format::list_footer_line(keymap, actions)
 }
 
 /// Prepares list table state for grouped row rendering.
@@ -769,7 +808,7 @@ mod tests {
     #[test]
     fn test_render_uses_palette_border_for_sessions_table() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
         let backend = ratatui::backend::TestBackend::new(100, 12);
         let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
         let mut table_state = TableState::default();
@@ -864,7 +903,7 @@ mod tests {
     #[test]
     fn test_render_conflicted_session_appends_red_title_alert() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
         let backend = ratatui::backend::TestBackend::new(120, 12);
         let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
         let mut table_state = TableState::default();
@@ -900,7 +939,7 @@ mod tests {
     #[test]
     fn test_render_archive_rows_use_muted_text_across_columns() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::DarkHorizon);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::DarkHorizon);
         let backend = ratatui::backend::TestBackend::new(120, 16);
         let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
         let mut table_state = TableState::default();
@@ -1061,7 +1100,7 @@ mod tests {
     #[test]
     fn test_render_session_row_colors_reasoning_level_within_model_column() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
         let backend = ratatui::backend::TestBackend::new(100, 12);
         let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
         let mut table_state = TableState::default();
@@ -1096,7 +1135,7 @@ mod tests {
     #[test]
     fn test_render_selected_session_model_uses_selection_surface() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::DarkHorizon);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::DarkHorizon);
         let backend = ratatui::backend::TestBackend::new(100, 12);
         let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
         let mut table_state = TableState::default();
@@ -1155,7 +1194,7 @@ mod tests {
     #[test]
     fn test_render_session_row_connects_stacked_child_to_parent() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
         let backend = ratatui::backend::TestBackend::new(100, 12);
         let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
         let mut table_state = TableState::default();
@@ -1275,6 +1314,46 @@ mod tests {
         assert!(rendered.contains(&expected_title));
     }
 
+    #[test]
+    fn test_status_column_appends_ci_badge_after_forge_indicator() {
+        // Arrange
+        let mut session = crate::test_support::titled_session_fixture("session-1", Status::Review);
+        session.review_request = Some(ReviewRequest {
+            last_refreshed_at: 0,
+            summary: ReviewRequestSummary {
+                display_id: "#42".to_string(),
+                forge_kind: ForgeKind::GitHub,
+                source_branch: "wt/session-id".to_string(),
+                state: ReviewRequestState::Open,
+                status_summary: None,
+                target_branch: "main".to_string(),
+                title: "feat".to_string(),
+                web_url: String::new(),
+            },
+        });
+        session.review_checks = Some(ReviewCheckReport {
+            head_sha: "abc123".to_string(),
+            runs: vec![ag_forge::ReviewCheckRun {
+                conclusion: Some(ag_forge::ReviewCheckConclusion::Failure),
+                log_excerpt: None,
+                log_id: None,
+                name: "build".to_string(),
+                status: ag_forge::ReviewCheckStatus::Completed,
+                url: None,
+            }],
+        });
+        let sessions = vec![session];
+        let expected_label = "Review ⊙ #42 CI ✗";
+        let expected_width = u16::try_from(expected_label.chars().count()).unwrap_or(u16::MAX);
+        let rows = prepared_session_rows(&sessions, ReasoningLevel::High, None, 0);
+
+        // Act
+        let width = status_column_width(&rows);
+
+        // Assert
+        assert_eq!(width, Constraint::Length(expected_width));
+    }
+
     #[test]
     fn test_timer_column_width_uses_longest_rendered_timer_label() {
         // Arrange
@@ -1350,7 +1429,7 @@ mod tests {
         let session = crate::test_support::titled_session_fixture("session-1", Status::Review);
 
         // Act
-        let help_text = session_list_help_line(Some(&session)).to_string();
+        let help_text = session_list_help_line(Some(&session), None).to_string();
 
         // Assert
         assert!(help_text.contains("s: sync"));
@@ -1362,7 +1441,7 @@ mod tests {
         let session = crate::test_support::titled_session_fixture("session-1", Status::Review);
 
         // Act
-        let help_text = session_list_help_line(Some(&session)).to_string();
+        let help_text = session_list_help_line(Some(&session), None).to_string();
 
         // Assert
         assert!(help_text.contains("p: projects"));
@@ -1374,7 +1453,7 @@ mod tests {
         let session = crate::test_support::titled_session_fixture("session-1", Status::Draft);
 
         // Act
-        let help_text = session_list_help_line(Some(&session)).to_string();
+        let help_text = session_list_help_line(Some(&session), None).to_string();
 
         // Assert
         assert!(!help_text.contains("c: cancel"));
@@ -1387,7 +1466,7 @@ mod tests {
         session.is_draft = true;
 
         // Act
-        let help_text = session_list_help_line(Some(&session)).to_string();
+        let help_text = session_list_help_line(Some(&session), None).to_string();
 
         // Assert
         assert!(help_text.contains("c: cancel"));
@@ -1399,7 +1478,7 @@ mod tests {
         let session = crate::test_support::titled_session_fixture("session-1", Status::Canceled);
 
         // Act
-        let help_text = session_list_help_line(Some(&session)).to_string();
+        let help_text = session_list_help_line(Some(&session), None).to_string();
 
         // Assert
         assert!(help_text.contains("Enter: open session"));
@@ -1512,7 +1591,7 @@ mod tests {
     #[test]
     fn test_render_keeps_selected_new_status_text_visible() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
         let backend = ratatui::backend::TestBackend::new(100, 12);
         let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
         let mut table_state = TableState::default();
@@ -6,6 +6,7 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap};
 
 use crate::presentation::help_action;
+use crate::presentation::keymap::Keymap;
 use crate::presentation::settings::{
     SettingsScreenSnapshot, SettingsSelectorDropdown, SettingsSelectorDropdownOption,
 };
@@ -24,6 +25,7 @@ const DROPDOWN_WIDTH_PERCENT: u16 = 58;
 
 /// Renders the settings page table and inline editing hints.
 pub struct SettingsPage<'a> {
+    keymap: Option<&'a Keymap>,
     snapshot: &'a SettingsScreenSnapshot,
     project_name: Option<String>,
 }
@@ -32,11 +34,20 @@ impl<'a> SettingsPage<'a> {
     /// Creates a settings page renderer bound to the active settings snapshot.
     pub(crate) fn new(snapshot: &'a SettingsScreenSnapshot, project_name: Option<String>) -> Self {
         Self {
+            keymap: None,
             snapshot,
             project_name,
         }
     }
 
+    /// Sets the user keymap whose overrides relabel footer shortcuts.
+    #[must_use]
+    pub(crate) fn keymap(mut self, keymap: &'a Keymap) -> Self {
+        self.keymap = Some(keymap);
+
+        self
+    }
+
     /// Returns the title for the active project's settings section.
     fn project_section_title(&self) -> String {
         project_section_title(self.project_name.as_deref())
@@ -103,7 +114,7 @@ impl Page for SettingsPage<'_> {
         f.render_stateful_widget(global_table, table_chunks[0], &mut global_table_state);
         f.render_stateful_widget(project_table, table_chunks[1], &mut project_table_state);
 
-        let footer = Paragraph::new(settings_footer_line(self.snapshot));
+        let footer = Paragraph::new(settings_footer_line(self.snapshot, self.keymap));
 
         f.render_widget(footer, areas.footer_area);
 
@@ -366,22 +377,28 @@ fn settings_selector_dropdown_option_line(
 /// Selector dropdowns and command-list editing keep using the
 /// manager-provided hint string, while list mode uses the shared styled
 /// help-action rendering.
-fn settings_footer_line(snapshot: &SettingsScreenSnapshot) -> Line<'static> {
+fn settings_footer_line(
+    snapshot: &SettingsScreenSnapshot,
+    keymap: Option<&Keymap>,
+) -> Line<'static> {
     settings_footer_line_for_mode(
         snapshot.launch_configuration_list_editor.is_some() || snapshot.selector_dropdown.is_some(),
         snapshot.footer_hint,
+        keymap,
     )
 }
 
 /// Returns the footer help content for either list mode or overlay mode.
-fn settings_footer_line_for_mode(uses_inline_hint: bool, footer_hint: &str) -> Line<'static> {
+fn settings_footer_line_for_mode(
+    uses_inline_hint: bool,
+    footer_hint: &str,
+    keymap: Option<&Keymap>,
+) -> Line<'static> {
     if uses_inline_hint {
         return Line::from(footer_hint.to_string());
     }
 
-    let actions = help_action::settings_footer_actions();
-
-    crate::ui::help_format::footer_line(&actions)
+    crate::ui::help_format::list_footer_line(keymap, help_action::settings_footer_actions())
 }
 
 /// Builds single-line settings table rows.
@@ -683,7 +700,7 @@ mod tests {
         let footer_hint = "Editing launch configurations";
 
         // Act
-        let footer_line = settings_footer_line_for_mode(true, footer_hint);
+        let footer_line = settings_footer_line_for_mode(true, footer_hint, None);
 
         // Assert
         assert_eq!(footer_line, Line::from(footer_hint.to_string()));
@@ -697,7 +714,7 @@ mod tests {
             crate::ui::help_format::footer_line(&help_action::settings_footer_actions());
 
         // Act
-        let footer_line = settings_footer_line_for_mode(false, footer_hint);
+        let footer_line = settings_footer_line_for_mode(false, footer_hint, None);
 
         // Assert
         assert_eq!(footer_line, expected_line);
@@ -89,7 +89,7 @@ mod tests {
     #[test]
     fn test_user_prompt_styles_use_prompt_surface_background() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
 
         // Act
         let prefix_style = user_prompt_prefix_style();
@@ -103,7 +103,7 @@ mod tests {
     #[test]
     fn test_current_theme_prompt_surface_is_terminal_scheme_independent() {
         // Arrange
-        let _theme_scope = style::scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = style::scoped_active_theme(&ColorTheme::Current);
 
         // Act
         let prompt_surface = style::palette::surface_prompt();
@@ -6,6 +6,7 @@ use crate::domain::agent::AgentKind;
 use crate::domain::file_entry::filter_entries;
 use crate::presentation::app_mode::ChatFocus;
 use crate::presentation::help_action;
+use crate::presentation::keymap::{Keymap, KeymapMode};
 use crate::presentation::prompt::{
     PromptAtMentionState, PromptSlashState, PromptSuggestionList,
     build_prompt_slash_suggestion_list,
@@ -13,7 +14,7 @@ use crate::presentation::prompt::{
 use crate::ui::component::chat_input::{SuggestionItem, SuggestionList};
 
 const AT_MENTION_DEFAULT_MAX_VISIBLE: usize = 10;
-const NEW_SESSION_PROMPT_FOOTER_ACTIONS: [help_action::HelpAction; 4] = [
+static NEW_SESSION_PROMPT_FOOTER_ACTIONS: [help_action::HelpAction; 4] = [
     help_action::HelpAction::new("stage draft", "Enter", "Stage draft"),
     help_action::HelpAction::new("newline", "Alt+Enter", "Insert newline"),
     help_action::HelpAction::new(
@@ -23,7 +24,7 @@ const NEW_SESSION_PROMPT_FOOTER_ACTIONS: [help_action::HelpAction; 4] = [
     ),
     help_action::HelpAction::new("cancel", "Esc", "Cancel prompt"),
 ];
-const PROMPT_FOOTER_ACTIONS: [help_action::HelpAction; 4] = [
+static PROMPT_FOOTER_ACTIONS: [help_action::HelpAction; 4] = [
     help_action::HelpAction::new("send", "Enter", "Send prompt"),
     help_action::HelpAction::new("newline", "Alt+Enter", "Insert newline"),
     help_action::HelpAction::new(
@@ -39,7 +40,8 @@ const PROMPT_FOOTER_ACTIONS: [help_action::HelpAction; 4] = [
 /// toggle, so each focus target advertises its own action set: composing shows
 /// send/newline/cancel, and reading the transcript shows the scroll keys. The
 /// diff-preview shortcut is omitted only when persisted session statistics
-/// report a known-empty diff.
+/// report a known-empty diff, and the permission-mode shortcut is omitted for
+/// roles that are always read-only.
 ///
 /// Footer entries follow the canonical composer-footer ordering shared with
 /// question mode: the `Tab` focus toggle first as the stable anchor, then the
@@ -48,6 +50,7 @@ pub fn prompt_footer_line(
     session: &crate::domain::session::Session,
     attachment_count: usize,
     focus: ChatFocus,
+    keymap: &Keymap,
 ) -> Line<'static> {
     let is_chat_focused = focus == ChatFocus::Chat;
     let focus_label = if is_chat_focused { "Compose" } else { "Chat" };
@@ -60,13 +63,16 @@ pub fn prompt_footer_line(
         help_actions.push(help_action::HelpAction::new("sessions", "q", "Sessions"));
     } else {
         help_actions.extend_from_slice(prompt_footer_actions(session));
-        help_actions.push(help_action::HelpAction::new(
-            "switch mode",
-            "Shift+Tab",
-            "Switch permission mode",
-        ));
+        if !session.role.enforces_read_only() {
+            help_actions.push(help_action::HelpAction::new(
+                "switch mode",
+                "Shift+Tab",
+                "Switch permission mode",
+            ));
+        }
     }
 
+    let help_actions = keymap.remap_help_actions(KeymapMode::Prompt, help_actions);
     let mut footer_line = crate::ui::help_format::footer_line(&help_actions);
 
     if attachment_count > 0 {
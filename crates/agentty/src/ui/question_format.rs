@@ -6,6 +6,7 @@ use ratatui::text::{Line, Span};
 
 use crate::presentation::app_mode::ChatFocus;
 use crate::presentation::help_action;
+use crate::presentation::keymap::{Keymap, KeymapMode};
 use crate::ui::style;
 
 /// Returns wrapped question-panel lines with the correct focus styling.
@@ -99,6 +100,7 @@ pub fn question_help_footer_line(
     has_session_diff: bool,
     is_navigating_options: bool,
     is_at_mention_open: bool,
+    keymap: &Keymap,
 ) -> Line<'static> {
     let is_chat_focused = focus == ChatFocus::Chat;
     let focus_label = if is_chat_focused { "Answer" } else { "Chat" };
@@ -126,5 +128,7 @@ pub fn question_help_footer_line(
         ));
     }
 
+    let help_actions = keymap.remap_help_actions(KeymapMode::Question, help_actions);
+
     crate::ui::help_format::footer_line(&help_actions)
 }
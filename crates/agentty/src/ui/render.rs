@@ -13,6 +13,7 @@ use crate::domain::project::ProjectListItem;
 use crate::domain::session::{DailyActivity, Session, SessionId};
 use crate::presentation::app_mode::{AppMode, ConfirmationViewMode, HelpContext};
 use crate::presentation::frame_time::FrameTime;
+use crate::presentation::keymap::Keymap;
 use crate::ui::{RenderCacheStore, component, layout, page, router};
 
 /// Focused-review display state projected from the app cache for one visible
@@ -58,6 +59,8 @@ pub struct RenderContext<'a> {
     pub git_status: Option<(u32, u32)>,
     /// Whether tmux-only worktree actions can be rendered.
     pub is_tmux_session: bool,
+    /// User keymap whose overrides relabel footer and help shortcuts.
+    pub keymap: &'a Keymap,
     /// Newer stable version when one is available.
     pub latest_available_version: Option<&'a str>,
     /// Current app mode and its transient state.
@@ -291,7 +294,7 @@ mod tests {
     use std::path::{Path, PathBuf};
 
     use super::*;
-    use crate::presentation::app_mode::{DiffFocus, DiffLineComments};
+    use crate::presentation::app_mode::{DiffFocus, DiffLayoutMode, DiffLineComments};
     use crate::test_support::SessionFixtureBuilder;
 
     /// Builds one deterministic session fixture for footer render tests.
@@ -346,6 +349,7 @@ mod tests {
                 diff: String::new(),
                 file_explorer_selected_index: 0,
                 focus: DiffFocus::Files,
+                layout_mode: DiffLayoutMode::default(),
                 line_comments: DiffLineComments::default(),
                 selected_diff_line_index: 0,
                 preview: crate::presentation::app_mode::DiffPreview::default(),
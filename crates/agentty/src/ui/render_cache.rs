@@ -1,5 +1,17 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use ag_tui_text::syntax::{SyntaxLanguage, SyntaxSpan};
+use rustc_hash::FxHasher;
+
+use crate::ui::diff_util::{self, DiffLine, DiffLineKind};
 use crate::ui::{component, markdown, page};
 
+/// Maximum number of highlighted diff file sections cached at once.
+const SYNTAX_HIGHLIGHT_CACHE_ENTRY_LIMIT: usize = 64;
+
 /// UI-owned cache store shared by render and scroll-metric paths.
 ///
 /// The store keeps the concrete markdown, session-output, and diff cache
@@ -31,10 +43,155 @@ impl RenderCacheStore {
     }
 }
 
+/// Compact identity for one highlighted diff file section.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct SyntaxHighlightCacheKey {
+    content_hash: u64,
+    line_count: usize,
+}
+
+/// Cached syntax spans for one diff file section.
+struct SyntaxHighlightCacheEntry {
+    key: SyntaxHighlightCacheKey,
+    line_spans: Arc<[Vec<SyntaxSpan>]>,
+}
+
+/// Syntax spans for every row of one assembled diff.
+///
+/// File sections without a recognized language carry no spans, so their rows
+/// keep the plain added, removed, and context styles.
+#[derive(Default)]
+pub(crate) struct DiffSyntaxHighlights {
+    sections: Vec<(usize, Arc<[Vec<SyntaxSpan>]>)>,
+}
+
+impl DiffSyntaxHighlights {
+    /// Returns the syntax spans for the diff row at `line_index`.
+    pub(crate) fn line_spans(&self, line_index: usize) -> &[SyntaxSpan] {
+        let section_count = self
+            .sections
+            .partition_point(|(start_index, _)| *start_index <= line_index);
+        let Some((start_index, line_spans)) = section_count
+            .checked_sub(1)
+            .and_then(|section_index| self.sections.get(section_index))
+        else {
+            return &[];
+        };
+
+        line_spans
+            .get(line_index - start_index)
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Bounded per-file LRU cache of diff syntax highlights.
+///
+/// Entries are keyed by each file section's path and row content, so switching
+/// the file-tree selection, resizing the panel, or scrolling reuses spans that
+/// were already parsed. Spans store token kinds instead of colors, which keeps
+/// entries valid across theme changes.
+#[derive(Default)]
+pub(crate) struct SyntaxHighlightCache {
+    entries: RefCell<VecDeque<SyntaxHighlightCacheEntry>>,
+}
+
+impl SyntaxHighlightCache {
+    /// Returns syntax spans for each row of `lines`, highlighting every file
+    /// section whose path maps to a known language.
+    pub(crate) fn diff_highlights(&self, lines: &[DiffLine<'_>]) -> DiffSyntaxHighlights {
+        let mut sections = Vec::new();
+        let section_starts = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.kind == DiffLineKind::FileHeader)
+            .filter_map(|(line_index, line)| {
+                let path = diff_util::diff_header_new_path(line.content)?;
+
+                Some((line_index, path))
+            })
+            .collect::<Vec<_>>();
+
+        for (section_index, (start_index, path)) in section_starts.iter().enumerate() {
+            let Some(language) = SyntaxLanguage::from_path(path) else {
+                continue;
+            };
+            let end_index = section_starts
+                .get(section_index + 1)
+                .map_or(lines.len(), |(next_start_index, _)| *next_start_index);
+            let section_lines = &lines[*start_index..end_index];
+            sections.push((
+                *start_index,
+                self.file_highlights(language, path, section_lines),
+            ));
+        }
+
+        DiffSyntaxHighlights { sections }
+    }
+
+    /// Returns cached spans for one file section or highlights and stores
+    /// them.
+    fn file_highlights(
+        &self,
+        language: SyntaxLanguage,
+        path: &str,
+        lines: &[DiffLine<'_>],
+    ) -> Arc<[Vec<SyntaxSpan>]> {
+        let key = Self::cache_key(path, lines);
+        let mut entries = self.entries.borrow_mut();
+        if let Some(entry_index) = entries.iter().position(|entry| entry.key == key)
+            && let Some(entry) = entries.remove(entry_index)
+        {
+            let line_spans = Arc::clone(&entry.line_spans);
+            entries.push_front(entry);
+
+            return line_spans;
+        }
+
+        let line_spans: Arc<[Vec<SyntaxSpan>]> =
+            Arc::from(diff_util::highlight_diff_file_lines(language, lines));
+        entries.push_front(SyntaxHighlightCacheEntry {
+            key,
+            line_spans: Arc::clone(&line_spans),
+        });
+        while entries.len() > SYNTAX_HIGHLIGHT_CACHE_ENTRY_LIMIT {
+            entries.pop_back();
+        }
+
+        line_spans
+    }
+
+    /// Returns a compact key for one file path and its diff rows.
+    fn cache_key(path: &str, lines: &[DiffLine<'_>]) -> SyntaxHighlightCacheKey {
+        let mut hasher = FxHasher::default();
+        hasher.write(path.as_bytes());
+        for line in lines {
+            hasher.write_u8(line.kind as u8);
+            hasher.write(line.content.as_bytes());
+            hasher.write_u8(b'\n');
+        }
+
+        SyntaxHighlightCacheKey {
+            content_hash: hasher.finish(),
+            line_count: lines.len(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use ag_tui_text::syntax::SyntaxTokenKind;
+
     use super::*;
 
+    const HIGHLIGHT_TEST_DIFF: &str = concat!(
+        "diff --git a/notes.txt b/notes.txt\n",
+        "@@ -1 +1 @@\n",
+        "+let plain = 1;\n",
+        "diff --git a/src/lib.rs b/src/lib.rs\n",
+        "@@ -1 +1 @@\n",
+        "+let value = 1;\n",
+    );
+
     #[test]
     fn test_render_cache_store_reuses_shared_cache_instances() {
         // Arrange
@@ -59,4 +216,40 @@ mod tests {
             repeated_session_output_layout_cache
         ));
     }
+
+    #[test]
+    fn test_syntax_highlight_cache_highlights_only_known_languages() {
+        // Arrange
+        let cache = SyntaxHighlightCache::default();
+        let lines = diff_util::parse_diff_lines(HIGHLIGHT_TEST_DIFF);
+
+        // Act
+        let highlights = cache.diff_highlights(&lines);
+
+        // Assert
+        assert_eq!(highlights.line_spans(2), [] as [SyntaxSpan; 0]);
+        assert_eq!(
+            highlights.line_spans(5).first().map(|span| span.kind),
+            Some(SyntaxTokenKind::Keyword)
+        );
+        assert_eq!(highlights.line_spans(99), [] as [SyntaxSpan; 0]);
+    }
+
+    #[test]
+    fn test_syntax_highlight_cache_reuses_file_spans_across_selections() {
+        // Arrange
+        let cache = SyntaxHighlightCache::default();
+        let lines = diff_util::parse_diff_lines(HIGHLIGHT_TEST_DIFF);
+
+        // Act
+        let full_diff_highlights = cache.diff_highlights(&lines);
+        let file_highlights = cache.diff_highlights(&lines[3..]);
+
+        // Assert
+        assert!(std::ptr::eq(
+            full_diff_highlights.line_spans(5),
+            file_highlights.line_spans(2)
+        ));
+        assert_eq!(cache.entries.borrow().len(), 1);
+    }
 }
@@ -10,10 +10,12 @@ use crate::domain::agent::{AgentCliInfo, ReasoningLevel};
 use crate::domain::project::{ProjectListItem, ordered_project_items};
 use crate::domain::session::{DailyActivity, Session, SessionId, activity_day_key_with_offset};
 use crate::presentation::app_mode::{
-    AppMode, ConfirmationIntent, DiffFocus, DiffLineComments, DiffPreview, DiffRestoreTarget,
-    DiffReviewComments, DiffSidebarFocus, HelpContext, allows_diff_line_comment_reply,
+    AppMode, ConfirmationIntent, DiffFocus, DiffLayoutMode, DiffLineComments, DiffPreview,
+    DiffRestoreTarget, DiffReviewComments, DiffSidebarFocus, HelpContext,
+    allows_diff_line_comment_reply,
 };
 use crate::presentation::frame_time::FrameTime;
+use crate::presentation::keymap::Keymap;
 use crate::presentation::settings::SettingsScreenSnapshot;
 use crate::ui::{
     Component, Page, RenderContext, SessionReviewSnapshot, component, markdown, overlay, page,
@@ -27,6 +29,8 @@ struct RouteSharedContext<'a> {
     available_agent_clis: &'a [AgentCliInfo],
     current_tab: Tab,
     default_reasoning_level: ReasoningLevel,
+    /// User keymap applied to list-page footer labels.
+    keymap: &'a Keymap,
     /// Cached most-recently-opened ordering over `projects`.
     mru_project_order: &'a [usize],
     project_table_state: &'a mut TableState,
@@ -57,6 +61,7 @@ enum Surface<'a> {
         diff: &'a str,
         file_explorer_selected_index: usize,
         focus: DiffFocus,
+        layout_mode: DiffLayoutMode,
         line_comments: &'a DiffLineComments,
         preview: &'a DiffPreview,
         review_comments: Option<&'a DiffReviewComments>,
@@ -71,6 +76,7 @@ enum Surface<'a> {
         sidebar_focus: DiffSidebarFocus,
     },
     List,
+    OrchestrationBoard(&'a AppMode),
     Session {
         mode: SessionSurfaceMode<'a>,
         scroll_offset: Option<u16>,
@@ -84,6 +90,7 @@ impl Surface<'_> {
         match self {
             Self::Diff { .. } | Self::DiffLoading { .. } => SurfaceKind::Diff,
             Self::List => SurfaceKind::List,
+            Self::OrchestrationBoard(_) => SurfaceKind::OrchestrationBoard,
             Self::Session { .. } => SurfaceKind::Session,
         }
     }
@@ -94,6 +101,7 @@ impl Surface<'_> {
 pub(crate) enum SurfaceKind {
     Diff,
     List,
+    OrchestrationBoard,
     Session,
 }
 
@@ -110,6 +118,7 @@ struct SessionChatRenderContext<'a> {
     active_prompt_outputs: &'a HashMap<SessionId, String>,
     default_reasoning_level: ReasoningLevel,
     is_tmux_session: bool,
+    keymap: &'a Keymap,
     markdown_render_cache: &'a markdown::MarkdownRenderCache,
     mode: &'a AppMode,
     output_layout_cache: &'a component::session_output::SessionOutputLayoutCache,
@@ -134,6 +143,7 @@ struct FrameResources<'a> {
     default_reasoning_level: ReasoningLevel,
     diff_layout_cache: &'a page::diff::DiffLayoutCache,
     is_tmux_session: bool,
+    keymap: &'a Keymap,
     markdown_render_cache: &'a markdown::MarkdownRenderCache,
     output_layout_cache: &'a component::session_output::SessionOutputLayoutCache,
     review_snapshot: Option<&'a SessionReviewSnapshot<'a>>,
@@ -161,6 +171,7 @@ impl<'a> FrameResources<'a> {
             active_prompt_outputs: self.active_prompt_outputs,
             default_reasoning_level: self.default_reasoning_level,
             is_tmux_session: self.is_tmux_session,
+            keymap: self.keymap,
             markdown_render_cache: self.markdown_render_cache,
             mode,
             output_layout_cache: self.output_layout_cache,
@@ -201,6 +212,7 @@ pub(crate) fn route_frame(f: &mut Frame, area: Rect, context: RenderContext<'_>)
         table_state,
         frame_time,
         is_tmux_session,
+        keymap,
         ..
     } = context;
 
@@ -209,6 +221,7 @@ pub(crate) fn route_frame(f: &mut Frame, area: Rect, context: RenderContext<'_>)
         available_agent_clis,
         current_tab,
         default_reasoning_level,
+        keymap,
         mru_project_order,
         project_table_state,
         projects,
@@ -224,6 +237,7 @@ pub(crate) fn route_frame(f: &mut Frame, area: Rect, context: RenderContext<'_>)
         default_reasoning_level,
         diff_layout_cache: render_cache_store.diff_layout_cache(),
         is_tmux_session,
+        keymap,
         markdown_render_cache: render_cache_store.markdown_render_cache(),
         output_layout_cache: render_cache_store.session_output_layout_cache(),
         review_snapshot: session_review_snapshot,
@@ -267,11 +281,13 @@ fn surface_for_mode(mode: &AppMode) -> Surface<'_> {
         },
         AppMode::List
         | AppMode::SessionCreation { .. }
+        | AppMode::ReviewRequestImportInput { .. }
         | AppMode::PreCommitHookWarning { .. }
         | AppMode::ProjectSwitcher { .. }
         | AppMode::SyncBlockedPopup { .. }
         | AppMode::Confirmation { .. } => Surface::List,
         AppMode::Help { context, .. } => surface_for_help_context(context),
+        AppMode::OrchestrationBoard { .. } => Surface::OrchestrationBoard(mode),
         AppMode::View {
             session_id,
             scroll_offset,
@@ -306,6 +322,7 @@ fn surface_for_mode(mode: &AppMode) -> Surface<'_> {
             diff,
             file_explorer_selected_index,
             focus,
+            layout_mode,
             line_comments,
             preview,
             review_comments,
@@ -318,6 +335,7 @@ fn surface_for_mode(mode: &AppMode) -> Surface<'_> {
             diff,
             file_explorer_selected_index: *file_explorer_selected_index,
             focus: *focus,
+            layout_mode: *layout_mode,
             line_comments,
             preview,
             review_comments: review_comments.as_ref(),
@@ -351,6 +369,7 @@ fn surface_for_help_context(context: &HelpContext) -> Surface<'_> {
             diff,
             file_explorer_selected_index,
             focus,
+            layout_mode,
             line_comments,
             preview,
             review_comments,
@@ -363,6 +382,7 @@ fn surface_for_help_context(context: &HelpContext) -> Surface<'_> {
             diff,
             file_explorer_selected_index: *file_explorer_selected_index,
             focus: *focus,
+            layout_mode: *layout_mode,
             line_comments,
             preview,
             review_comments: review_comments.as_deref(),
@@ -389,6 +409,9 @@ fn render_surface(
 ) {
     match surface {
         Surface::List => render_list_background(f, area, shared, resources.frame_time),
+        Surface::OrchestrationBoard(mode) => {
+            render_orchestration_board_surface(f, area, mode, shared.sessions);
+        }
         Surface::Session {
             mode,
             scroll_offset,
@@ -415,6 +438,7 @@ fn render_surface(
                     file_explorer_selected_index: 0,
                     focus: DiffFocus::Files,
                     is_loading: true,
+                    layout_mode: DiffLayoutMode::default(),
                     line_comments: &DiffLineComments::default(),
                     preview: &preview,
                     review_comments: None,
@@ -432,6 +456,7 @@ fn render_surface(
             diff,
             file_explorer_selected_index,
             focus,
+            layout_mode,
             line_comments,
             preview,
             review_comments,
@@ -448,6 +473,7 @@ fn render_surface(
                 file_explorer_selected_index,
                 focus,
                 is_loading: false,
+                layout_mode,
                 line_comments,
                 preview,
                 review_comments,
@@ -472,12 +498,14 @@ fn render_mode_overlay(
     resources: FrameResources<'_>,
 ) {
     match mode {
+        AppMode::View { session_id, .. } | AppMode::Prompt { session_id, .. } => {
+            render_tool_approval_overlay(f, area, session_id, shared.sessions);
+        }
         AppMode::List
-        | AppMode::View { .. }
-        | AppMode::Prompt { .. }
         | AppMode::Question { .. }
         | AppMode::DiffLoading { .. }
-        | AppMode::Diff { .. } => {}
+        | AppMode::Diff { .. }
+        | AppMode::OrchestrationBoard { .. } => {}
         AppMode::SessionCreation {
             selected_option_index,
         } => component::session_creation_overlay::SessionCreationOverlay::new(
@@ -485,6 +513,10 @@ fn render_mode_overlay(
             shared.can_create_stacked_session(),
         )
         .render(f, area),
+        AppMode::ReviewRequestImportInput { input } => {
+            component::review_request_import_overlay::ReviewRequestImportOverlay::new(input)
+                .render(f, area);
+        }
         AppMode::PreCommitHookWarning { message } => {
             component::info_overlay::InfoOverlay::new("Pre-commit hook warning", message)
                 .render(f, area);
@@ -540,7 +572,7 @@ fn render_mode_overlay(
         AppMode::Help {
             context: help_context,
             scroll_offset,
-        } => component::help_overlay::HelpOverlay::new(help_context)
+        } => component::help_overlay::HelpOverlay::new(help_context, resources.keymap)
             .scroll_offset(*scroll_offset)
             .render(f, area),
         AppMode::LaunchConfigurationSelector {
@@ -554,16 +586,31 @@ fn render_mode_overlay(
             default_branch_name,
             input,
             locked_upstream_ref,
+            review_request_form,
             ..
         } => component::publish_branch_overlay::PublishBranchOverlay::new(
             input,
             default_branch_name,
             locked_upstream_ref.as_deref(),
         )
+        .review_request_form(review_request_form.as_deref())
         .render(f, area),
     }
 }
 
+/// Renders the pending `Ask`-mode approval request for the visible session.
+fn render_tool_approval_overlay(f: &mut Frame, area: Rect, session_id: &str, sessions: &[Session]) {
+    let Some(request) = sessions
+        .iter()
+        .find(|session| session.id == session_id)
+        .and_then(|session| session.pending_tool_approval.as_ref())
+    else {
+        return;
+    };
+
+    component::tool_approval_overlay::ToolApprovalOverlay::new(request).render(f, area);
+}
+
 /// Renders the confirmation overlay after its classified base surface.
 fn render_confirmation_overlay(f: &mut Frame, area: Rect, mode: &AppMode) {
     let AppMode::Confirmation {
@@ -591,6 +638,48 @@ fn render_confirmation_overlay(f: &mut Frame, area: Rect, mode: &AppMode) {
     }
 }
 
+/// Renders the full-screen campaign board for one orchestrator session.
+fn render_orchestration_board_surface(
+    f: &mut Frame,
+    area: Rect,
+    mode: &AppMode,
+    sessions: &[Session],
+) {
+    let AppMode::OrchestrationBoard {
+        board,
+        delete_confirmation,
+        editor,
+        expanded,
+        notice,
+        selected_index,
+        session_id,
+    } = mode
+    else {
+        return;
+    };
+    let title = sessions
+        .iter()
+        .find(|session| session.id == *session_id)
+        .map_or("Orchestration Campaign", Session::display_title);
+
+    page::orchestration::OrchestrationBoardPage::new(board, title, *selected_index)
+        .editor(editor.as_ref())
+        .expanded(*expanded)
+        .notice(notice.as_deref())
+        .render(f, area);
+
+    if let Some(delete_confirmation) = delete_confirmation {
+        let message = format!(
+            "Delete proposed task \"{}\"?",
+            delete_confirmation.task_title
+        );
+
+        component::confirmation_overlay::ConfirmationOverlay::new("Confirm Delete", &message)
+            .selected_first(delete_confirmation.selected_confirmation_index == 0)
+            .render(f, area);
+    }
+}
+
 /// Renders a session surface in either interactive or restored-view mode.
 fn render_session_surface(
     f: &mut Frame,
@@ -628,6 +717,7 @@ struct DiffSurfaceInput<'a> {
     file_explorer_selected_index: usize,
     focus: DiffFocus,
     is_loading: bool,
+    layout_mode: DiffLayoutMode,
     line_comments: &'a DiffLineComments,
     preview: &'a DiffPreview,
     review_comments: Option<&'a DiffReviewComments>,
@@ -659,6 +749,8 @@ fn render_diff_surface(
         diff_layout_cache: resources.diff_layout_cache,
         file_explorer_selected_index: input.file_explorer_selected_index,
         focus: input.focus,
+        keymap: resources.keymap,
+        layout_mode: input.layout_mode,
         line_comments: input.line_comments,
         markdown_render_cache: resources.markdown_render_cache,
         preview: input.preview,
@@ -681,6 +773,7 @@ fn render_session_chat(f: &mut Frame, area: Rect, context: SessionChatRenderCont
         active_prompt_outputs,
         default_reasoning_level,
         is_tmux_session,
+        keymap,
         markdown_render_cache,
         mode,
         output_layout_cache,
@@ -719,6 +812,7 @@ fn render_session_chat(f: &mut Frame, area: Rect, context: SessionChatRenderCont
         active_progress,
         default_reasoning_level,
         has_merge_conflict,
+        keymap,
         markdown_render_cache,
         mode,
         output_layout_cache,
@@ -777,6 +871,7 @@ fn render_list_background(
                     frame_time.local_utc_offset_seconds(),
                 ),
             )
+            .keymap(shared.keymap)
             .render(f, chunks[1]);
         }
         Tab::Sessions => {
@@ -787,6 +882,7 @@ fn render_list_background(
                 frame_time.unix_seconds(),
             )
             .session_git_statuses(shared.session_git_statuses)
+            .keymap(shared.keymap)
             .render(f, chunks[1]);
         }
         Tab::Settings => {
@@ -794,6 +890,7 @@ fn render_list_background(
                 active_project_name(shared.active_project_id, shared.projects);
             if let Some(settings_screen) = shared.settings_screen {
                 page::setting::SettingsPage::new(settings_screen, active_project_name)
+                    .keymap(shared.keymap)
                     .render(f, chunks[1]);
             }
         }
@@ -868,6 +965,7 @@ mod tests {
                 input: None,
                 mode: LaunchConfigurationListEditorMode::Browse,
                 selected_index: 0,
+                title: "Launch Configurations",
             }),
             project_rows: vec![("Launch Configurations", "cargo test".to_string())],
             selected_row_index: Some(0),
@@ -899,6 +997,7 @@ mod tests {
             available_agent_clis: &available_agent_clis,
             current_tab,
             default_reasoning_level: ReasoningLevel::Max,
+            keymap: &Keymap::default(),
             mru_project_order: &[],
             project_table_state: &mut project_table_state,
             projects: &projects,
@@ -934,6 +1033,7 @@ mod tests {
             available_agent_clis: &[],
             current_tab: Tab::Sessions,
             default_reasoning_level: ReasoningLevel::High,
+            keymap: &Keymap::default(),
             mru_project_order: &[],
             project_table_state: &mut project_table_state,
             projects: &[],
@@ -964,6 +1064,7 @@ mod tests {
                         default_reasoning_level: ReasoningLevel::High,
                         diff_layout_cache: &diff_layout_cache,
                         is_tmux_session: true,
+                        keymap: &Keymap::default(),
                         markdown_render_cache: &markdown_render_cache,
                         output_layout_cache: &output_layout_cache,
                         review_snapshot: None,
@@ -984,6 +1085,7 @@ mod tests {
                         default_reasoning_level: ReasoningLevel::High,
                         diff_layout_cache: &diff_layout_cache,
                         is_tmux_session: true,
+                        keymap: &Keymap::default(),
                         markdown_render_cache: &markdown_render_cache,
                         output_layout_cache: &output_layout_cache,
                         review_snapshot: None,
@@ -1008,7 +1110,9 @@ mod tests {
             keybindings: vec![],
         };
         let view_context = HelpContext::View {
+            can_fix_review_checks: false,
             can_fork_session: true,
+            can_mark_review_ready: false,
             can_merge_session_branch: true,
             can_mutate_session_branch: true,
             can_open_worktree: true,
@@ -1027,6 +1131,7 @@ mod tests {
             diff: "diff --git a/file b/file".to_string(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -1067,6 +1172,7 @@ mod tests {
                 diff: &diff,
                 file_explorer_selected_index: 0,
                 focus: DiffFocus::Files,
+                layout_mode: DiffLayoutMode::default(),
                 line_comments: &line_comments,
                 selected_diff_line_index: 0,
                 preview: &preview,
@@ -1081,6 +1187,7 @@ mod tests {
                 sidebar_focus: DiffSidebarFocus::Comments,
             },
             Surface::List,
+            Surface::OrchestrationBoard(&AppMode::List),
             Surface::Session {
                 mode: SessionSurfaceMode::View,
                 scroll_offset: None,
@@ -1098,6 +1205,7 @@ mod tests {
                 SurfaceKind::Diff,
                 SurfaceKind::Diff,
                 SurfaceKind::List,
+                SurfaceKind::OrchestrationBoard,
                 SurfaceKind::Session,
             ]
         );
@@ -1176,6 +1284,7 @@ mod tests {
             diff: String::new(),
             file_explorer_selected_index: 0,
             focus: DiffFocus::Files,
+            layout_mode: DiffLayoutMode::default(),
             line_comments: DiffLineComments::default(),
             selected_diff_line_index: 0,
             preview: DiffPreview::default(),
@@ -1262,6 +1371,7 @@ mod tests {
                 diff: "diff --git a/README.md b/README.md\n+# Preview".to_string(),
                 file_explorer_selected_index: 0,
                 focus: DiffFocus::Files,
+                layout_mode: DiffLayoutMode::default(),
                 line_comments: DiffLineComments::default(),
                 selected_diff_line_index: 0,
                 preview: crate::presentation::app_mode::DiffPreview::Ready {
@@ -1283,6 +1393,7 @@ mod tests {
             available_agent_clis: &[],
             current_tab: Tab::Sessions,
             default_reasoning_level: ReasoningLevel::default(),
+            keymap: &Keymap::default(),
             mru_project_order: &[],
             project_table_state: &mut project_table_state,
             projects: &[],
@@ -1310,6 +1421,7 @@ mod tests {
                         default_reasoning_level: ReasoningLevel::default(),
                         diff_layout_cache: &diff_layout_cache,
                         is_tmux_session: true,
+                        keymap: &Keymap::default(),
                         markdown_render_cache: &markdown_render_cache,
                         output_layout_cache: &output_layout_cache,
                         review_snapshot: None,
@@ -1330,6 +1442,7 @@ mod tests {
                         default_reasoning_level: ReasoningLevel::default(),
                         diff_layout_cache: &diff_layout_cache,
                         is_tmux_session: true,
+                        keymap: &Keymap::default(),
                         markdown_render_cache: &markdown_render_cache,
                         output_layout_cache: &output_layout_cache,
                         review_snapshot: None,
@@ -1363,6 +1476,12 @@ mod tests {
                 },
                 "New Session",
             ),
+            (
+                AppMode::ReviewRequestImportInput {
+                    input: InputState::default(),
+                },
+                "Import Review Request",
+            ),
             (
                 AppMode::PreCommitHookWarning {
                     message: "Install the hook".to_string(),
@@ -1466,7 +1585,9 @@ mod tests {
             (
                 AppMode::Help {
                     context: crate::presentation::app_mode::HelpContext::View {
+                        can_fix_review_checks: false,
                         can_fork_session: true,
+                        can_mark_review_ready: false,
                         can_merge_session_branch: true,
                         can_mutate_session_branch: true,
                         can_open_worktree: true,
@@ -1546,6 +1667,7 @@ mod tests {
                         default_reasoning_level: ReasoningLevel::default(),
                         diff_layout_cache: &diff_layout_cache,
                         is_tmux_session: false,
+                        keymap: &Keymap::default(),
                         markdown_render_cache: &cache,
                         output_layout_cache: &output_layout_cache,
                         review_snapshot: None,
@@ -1603,6 +1725,7 @@ mod tests {
                         default_reasoning_level: ReasoningLevel::default(),
                         diff_layout_cache: &diff_layout_cache,
                         is_tmux_session: true,
+                        keymap: &Keymap::default(),
                         markdown_render_cache: &cache,
                         output_layout_cache: &output_layout_cache,
                         review_snapshot: None,
@@ -1658,6 +1781,7 @@ mod tests {
                         default_reasoning_level: ReasoningLevel::default(),
                         diff_layout_cache: &diff_layout_cache,
                         is_tmux_session: true,
+                        keymap: &Keymap::default(),
                         markdown_render_cache: &cache,
                         output_layout_cache: &output_layout_cache,
                         review_snapshot: None,
@@ -1702,6 +1826,7 @@ mod tests {
                         file_explorer_selected_index: 0,
                         focus: DiffFocus::Files,
                         is_loading: false,
+                        layout_mode: DiffLayoutMode::default(),
                         line_comments: &DiffLineComments::default(),
                         selected_diff_line_index: 0,
                         preview: &DiffPreview::default(),
@@ -1717,6 +1842,7 @@ mod tests {
                         default_reasoning_level: ReasoningLevel::default(),
                         diff_layout_cache: &diff_layout_cache,
                         is_tmux_session: true,
+                        keymap: &Keymap::default(),
                         markdown_render_cache: &cache,
                         output_layout_cache: &output_layout_cache,
                         review_snapshot: None,
@@ -1757,6 +1883,7 @@ mod tests {
                         file_explorer_selected_index: 0,
                         focus: DiffFocus::Files,
                         is_loading: false,
+                        layout_mode: DiffLayoutMode::default(),
                         line_comments: &DiffLineComments::default(),
                         selected_diff_line_index: 0,
                         preview: &DiffPreview::default(),
@@ -1772,6 +1899,7 @@ mod tests {
                         default_reasoning_level: ReasoningLevel::default(),
                         diff_layout_cache: &diff_layout_cache,
                         is_tmux_session: true,
+                        keymap: &Keymap::default(),
                         markdown_render_cache: &markdown_render_cache,
                         output_layout_cache: &output_layout_cache,
                         review_snapshot: None,
@@ -1817,6 +1945,7 @@ mod tests {
                         file_explorer_selected_index: 0,
                         focus: DiffFocus::Files,
                         is_loading: false,
+                        layout_mode: DiffLayoutMode::default(),
                         line_comments: &DiffLineComments::default(),
                         selected_diff_line_index: 0,
                         preview: &DiffPreview::default(),
@@ -1832,6 +1961,7 @@ mod tests {
                         default_reasoning_level: ReasoningLevel::default(),
                         diff_layout_cache: &diff_layout_cache,
                         is_tmux_session: true,
+                        keymap: &Keymap::default(),
                         markdown_render_cache: &cache,
                         output_layout_cache: &output_layout_cache,
                         review_snapshot: None,
@@ -1893,6 +2023,7 @@ mod tests {
                 scroll_offset: None,
                 session_id: session_id.into(),
             },
+            review_request_form: None,
         };
 
         // Act
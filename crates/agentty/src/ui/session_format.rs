@@ -1,5 +1,6 @@
 //! Session header, footer, and transcript display formatting.
 
+use ag_forge::{ReviewCheckOutcome, ReviewCheckReport};
 use ag_protocol::AgentResponseSummary;
 use ag_tui_text::text_util;
 use ratatui::style::{Modifier, Style};
@@ -8,8 +9,12 @@ use ratatui::widgets::Borders;
 
 use crate::domain::agent::ReasoningLevel;
 use crate::domain::review;
-use crate::domain::session::{COMMITTING_PROGRESS_LABEL, Session, SessionId, Status};
+use crate::domain::session::{
+    COMMITTING_PROGRESS_LABEL, PostTurnCheckOutcome, PostTurnCheckReport, Session, SessionId,
+    Status,
+};
 use crate::presentation::help_action::{self, ViewHelpState};
+use crate::presentation::keymap::{Keymap, KeymapMode};
 use crate::ui::icon::Icon;
 use crate::ui::{markdown, style};
 
@@ -18,6 +23,10 @@ const REVIEW_SUGGESTIONS_HEADER: &str = "### Suggestions";
 const REVIEW_SUGGESTIONS_HEADER_WITH_HINT: &str =
     "### Suggestions (type \"/apply\" to verify and apply)";
 const SESSION_OUTPUT_DEFAULT_SUMMARY_TEXT: &str = "No changes";
+/// Maximum failed-check output lines shown below the header status row.
+const POST_TURN_CHECK_OUTPUT_PREVIEW_LINES: usize = 3;
+/// Maximum failed CI check rows shown below the header CI status row.
+const REVIEW_CHECK_FAILED_PREVIEW_ROWS: usize = 3;
 
 /// Formats the session title and metadata lines rendered above the output
 /// panel.
@@ -69,6 +78,14 @@ pub fn session_header_lines(
         )));
     }
 
+    if let Some(report) = &session.post_turn_checks {
+        lines.extend(post_turn_check_lines(report, title_width));
+    }
+
+    if let Some(report) = &session.review_checks {
+        lines.extend(review_check_lines(report, title_width));
+    }
+
     for metadata_text in metadata_lines {
         lines.push(Line::from(Span::styled(
             metadata_text,
@@ -79,6 +96,133 @@ pub fn session_header_lines(
     lines
 }
 
+/// Formats the post-turn checks status row and, for failures, the last few
+/// lines of the captured output.
+fn post_turn_check_lines(report: &PostTurnCheckReport, width: usize) -> Vec<Line<'static>> {
+    match &report.outcome {
+        PostTurnCheckOutcome::Passed { command_count } => {
+            let noun = if *command_count == 1 {
+                "command"
+            } else {
+                "commands"
+            };
+            let status_text = format!("{} Checks passed ({command_count} {noun})", Icon::Check);
+
+            vec![Line::from(Span::styled(
+                text_util::truncate_with_ellipsis(&status_text, width),
+                Style::default().fg(style::palette::success()),
+            ))]
+        }
+        PostTurnCheckOutcome::Failed {
+            command,
+            output,
+            timed_out,
+        } => {
+            let failure = if *timed_out { "timed out" } else { "failed" };
+            let auto_fix_text = if report.max_fix_attempts > 0 {
+                format!(
+                    " · auto-fix {}/{}",
+                    report.fix_attempts, report.max_fix_attempts
+                )
+            } else {
+                String::new()
+            };
+            let status_text = format!("{} Checks {failure}: {command}{auto_fix_text}", Icon::Cross);
+            let mut lines = vec![Line::from(Span::styled(
+                text_util::truncate_with_ellipsis(&status_text, width),
+                Style::default()
+                    .fg(style::palette::danger())
+                    .add_modifier(Modifier::BOLD),
+            ))];
+            let output_lines = output
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .collect::<Vec<_>>();
+            let preview_start = output_lines
+                .len()
+                .saturating_sub(POST_TURN_CHECK_OUTPUT_PREVIEW_LINES);
+            lines.extend(output_lines[preview_start..].iter().map(|line| {
+                Line::from(Span::styled(
+                    text_util::truncate_with_ellipsis(&text_util::inline_text(line), width),
+                    Style::default().fg(style::palette::text_muted()),
+                ))
+            }));
+
+            lines
+        }
+    }
+}
+
+/// Returns the compact CI badge shown next to the forge indicator in the
+/// session list.
+pub fn review_check_badge(outcome: ReviewCheckOutcome) -> String {
+    let icon = match outcome {
+        ReviewCheckOutcome::Pending => Icon::Pending,
+        ReviewCheckOutcome::Passed => Icon::Check,
+        ReviewCheckOutcome::Failed => Icon::Cross,
+    };
+
+    format!("CI {icon}")
+}
+
+/// Formats the CI status row for the linked review request and, for
+/// failures, one row per failed check with its details URL.
+fn review_check_lines(report: &ReviewCheckReport, width: usize) -> Vec<Line<'static>> {
+    let Some(outcome) = report.outcome() else {
+        return Vec::new();
+    };
+    let check_count = report.runs.len();
+    let noun = if check_count == 1 { "check" } else { "checks" };
+    let status_text = match outcome {
+        ReviewCheckOutcome::Passed => format!("{} CI passed ({check_count} {noun})", Icon::Check),
+        ReviewCheckOutcome::Pending => {
+            let pending_count = report.runs.iter().filter(|run| run.is_pending()).count();
+
+            format!(
+                "{} CI pending ({pending_count} of {check_count} {noun})",
+                Icon::Pending
+            )
+        }
+        ReviewCheckOutcome::Failed => {
+            let failed_count = report.failed_runs().count();
+
+            format!(
+                "{} CI failed ({failed_count} of {check_count} {noun})",
+                Icon::Cross
+            )
+        }
+    };
+    let status_style = Style::default().fg(style::review_check_color(outcome));
+    let status_style = if outcome == ReviewCheckOutcome::Failed {
+        status_style.add_modifier(Modifier::BOLD)
+    } else {
+        status_style
+    };
+    let mut lines = vec![Line::from(Span::styled(
+        text_util::truncate_with_ellipsis(&status_text, width),
+        status_style,
+    ))];
+
+    lines.extend(
+        report
+            .failed_runs()
+            .take(REVIEW_CHECK_FAILED_PREVIEW_ROWS)
+            .map(|run| {
+                let run_text = match run.url.as_deref() {
+                    Some(url) => format!("- {}: {url}", text_util::inline_text(&run.name)),
+                    None => format!("- {}", text_util::inline_text(&run.name)),
+                };
+
+                Line::from(Span::styled(
+                    text_util::truncate_with_ellipsis(&run_text, width),
+                    Style::default().fg(style::palette::text_muted()),
+                ))
+            }),
+    );
+
+    lines
+}
+
 /// Formats the size, timer, model, reasoning, speed, and token-usage row shown
 /// in single-line metadata contexts without any chat-header-only URL suffix.
 pub fn session_metadata_text(
@@ -201,7 +345,7 @@ pub(crate) fn session_speed_display(session: &Session) -> Option<&'static str> {
 /// input title.
 pub(crate) fn prompt_session_status(session: &Session) -> String {
     let speed_mode = session_speed_display(session);
-    let permission_mode = session.permission_mode.display_label();
+    let permission_mode = session.effective_permission_mode().display_label();
 
     speed_mode.map_or_else(
         || permission_mode.to_string(),
@@ -210,8 +354,14 @@ pub(crate) fn prompt_session_status(session: &Session) -> String {
 }
 
 /// Builds the compact session-view footer.
-pub(crate) fn session_view_footer_line(view_help_state: ViewHelpState) -> Line<'static> {
-    crate::ui::help_format::footer_line(&help_action::view_footer_actions(view_help_state))
+pub(crate) fn session_view_footer_line(
+    view_help_state: ViewHelpState,
+    keymap: &Keymap,
+) -> Line<'static> {
+    crate::ui::help_format::footer_line(&keymap.remap_help_actions(
+        KeymapMode::View,
+        help_action::view_footer_actions(view_help_state),
+    ))
 }
 
 /// Renders persisted summary payloads into display markdown.
@@ -595,6 +745,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_session_header_lines_show_passed_post_turn_checks_row() {
+        // Arrange
+        let mut session = SessionFixtureBuilder::new().build();
+        session.post_turn_checks = Some(PostTurnCheckReport {
+            fix_attempts: 0,
+            max_fix_attempts: 2,
+            outcome: PostTurnCheckOutcome::Passed { command_count: 2 },
+        });
+
+        // Act
+        let header_lines = session_header_lines(&session, 100, ReasoningLevel::default(), 0, false);
+
+        // Assert
+        assert_eq!(header_lines.len(), 3);
+        assert_eq!(
+            header_lines[1].to_string(),
+            format!("{} Checks passed (2 commands)", Icon::Check)
+        );
+        assert_eq!(
+            header_lines[1].spans[0].style.fg,
+            Some(style::palette::success())
+        );
+    }
+
+    #[test]
+    fn test_session_header_lines_show_failed_post_turn_checks_with_output_tail() {
+        // Arrange
+        let mut session = SessionFixtureBuilder::new().build();
+        session.post_turn_checks = Some(PostTurnCheckReport {
+            fix_attempts: 1,
+            max_fix_attempts: 2,
+            outcome: PostTurnCheckOutcome::Failed {
+                command: "cargo test".to_string(),
+                output: "line 1\nline 2\n\nline 3\nline 4\n".to_string(),
+                timed_out: false,
+            },
+        });
+
+        // Act
+        let header_lines = session_header_lines(&session, 100, ReasoningLevel::default(), 0, false);
+
+        // Assert
+        assert_eq!(header_lines.len(), 6);
+        assert_eq!(
+            header_lines[1].to_string(),
+            format!("{} Checks failed: cargo test · auto-fix 1/2", Icon::Cross)
+        );
+        assert_eq!(
+            header_lines[1].spans[0].style.fg,
+            Some(style::palette::danger())
+        );
+        assert_eq!(header_lines[2].to_string(), "line 2");
+        assert_eq!(header_lines[4].to_string(), "line 4");
+    }
+
+    #[test]
+    fn test_session_header_lines_show_failed_review_checks_with_job_urls() {
+        // Arrange
+        let mut session = SessionFixtureBuilder::new().build();
+        session.review_checks = Some(ReviewCheckReport {
+            head_sha: "abc123".to_string(),
+            runs: vec![
+                ag_forge::ReviewCheckRun {
+                    conclusion: Some(ag_forge::ReviewCheckConclusion::Failure),
+                    log_excerpt: None,
+                    log_id: None,
+                    name: "build".to_string(),
+                    status: ag_forge::ReviewCheckStatus::Completed,
+                    url: Some("https://ci.example/build".to_string()),
+                },
+                ag_forge::ReviewCheckRun {
+                    conclusion: None,
+                    log_excerpt: None,
+                    log_id: None,
+                    name: "lint".to_string(),
+                    status: ag_forge::ReviewCheckStatus::InProgress,
+                    url: None,
+                },
+            ],
+        });
+
+        // Act
+        let header_lines = session_header_lines(&session, 100, ReasoningLevel::default(), 0, false);
+
+        // Assert
+        assert_eq!(header_lines.len(), 4);
+        assert_eq!(
+            header_lines[1].to_string(),
+            format!("{} CI failed (1 of 2 checks)", Icon::Cross)
+        );
+        assert_eq!(
+            header_lines[1].spans[0].style.fg,
+            Some(style::palette::danger())
+        );
+        assert_eq!(
+            header_lines[2].to_string(),
+            "- build: https://ci.example/build"
+        );
+    }
+
     #[test]
     fn test_session_metadata_text_prints_agent_before_model() {
         // Arrange
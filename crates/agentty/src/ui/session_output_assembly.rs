@@ -12,15 +12,17 @@ use ag_tui_text::text_util;
 use ratatui::text::Line;
 
 use crate::domain::session::{QueuedMessage, Session, Status};
-use crate::domain::session_message::{SessionMessage, SessionMessageKind, SessionTranscript};
+use crate::domain::session_message::{
+    SessionMessage, SessionMessageKind, SessionTranscript, TurnActivity,
+};
 use crate::domain::transient_message::{
     TransientMessage, TransientMessageAnchor, TransientMessageBody, TransientMessageSlot,
 };
 use crate::ui::markdown::{self, render_markdown};
 use crate::ui::prompt_block::{self, USER_PROMPT_PREFIX, USER_PROMPT_RIGHT_GUTTER_WIDTH};
-use crate::ui::session_format;
 #[cfg(test)]
 use crate::ui::style;
+use crate::ui::{activity_format, session_format};
 
 const DRAFT_PREVIEW_HEADER: &str = "## Draft Session";
 const DRAFT_PREVIEW_EMPTY_NOTE: &str = "No draft messages staged yet. Use `Enter` to stage the \
@@ -270,6 +272,7 @@ impl SessionOutputAssembly<'_> {
             &self.completed_turn_section,
             self.inner_width,
             self.markdown_render_cache,
+            self.session.activity_log_expanded,
         );
     }
 
@@ -286,6 +289,7 @@ impl SessionOutputAssembly<'_> {
                 &self.trailing_notice_section,
                 self.inner_width,
                 self.markdown_render_cache,
+                self.session.activity_log_expanded,
             );
         }
     }
@@ -324,6 +328,7 @@ impl SessionOutputAssembly<'_> {
             &self.active_turn_section,
             self.inner_width,
             self.markdown_render_cache,
+            self.session.activity_log_expanded,
         );
     }
 
@@ -636,6 +641,7 @@ fn append_transcript_section(
     section: &SessionOutputTranscriptSection<'_>,
     inner_width: usize,
     markdown_render_cache: Option<&markdown::MarkdownRenderCache>,
+    activity_log_expanded: bool,
 ) {
     match section {
         SessionOutputTranscriptSection::Empty => {}
@@ -643,28 +649,76 @@ fn append_transcript_section(
             append_markdown_lines(lines, markdown, inner_width, markdown_render_cache);
         }
         SessionOutputTranscriptSection::Messages(messages) => {
-            append_transcript_messages(lines, messages, inner_width, markdown_render_cache);
+            append_transcript_messages(
+                lines,
+                messages,
+                inner_width,
+                markdown_render_cache,
+                activity_log_expanded,
+            );
         }
     }
 }
 
+/// Appends transcript rows, folding each run of consecutive activity rows
+/// into one activity-log block.
 fn append_transcript_messages(
     lines: &mut Vec<Line<'static>>,
     messages: &[SessionMessage],
     inner_width: usize,
     markdown_render_cache: Option<&markdown::MarkdownRenderCache>,
+    activity_log_expanded: bool,
 ) {
+    let mut pending_activities = Vec::new();
+
     for message in messages {
+        if message.kind == SessionMessageKind::TurnActivity {
+            pending_activities.extend(TurnActivity::decode(&message.content));
+
+            continue;
+        }
+
+        append_activity_log(
+            lines,
+            &mut pending_activities,
+            inner_width,
+            activity_log_expanded,
+        );
         match message.kind {
             SessionMessageKind::UserPrompt => {
                 append_user_prompt(lines, &message.content, inner_width, markdown_render_cache);
             }
-            SessionMessageKind::AgentPrompt => {}
+            SessionMessageKind::AgentPrompt | SessionMessageKind::TurnActivity => {}
             SessionMessageKind::AssistantAnswer | SessionMessageKind::WorkflowNotice => {
                 append_markdown_lines(lines, &message.content, inner_width, markdown_render_cache);
             }
         }
     }
+
+    append_activity_log(
+        lines,
+        &mut pending_activities,
+        inner_width,
+        activity_log_expanded,
+    );
+}
+
+/// Appends and drains one pending run of activity rows.
+fn append_activity_log(
+    lines: &mut Vec<Line<'static>>,
+    pending_activities: &mut Vec<TurnActivity>,
+    inner_width: usize,
+    activity_log_expanded: bool,
+) {
+    let activity_lines =
+        activity_format::activity_log_lines(pending_activities, activity_log_expanded, inner_width);
+    pending_activities.clear();
+    if activity_lines.is_empty() {
+        return;
+    }
+
+    append_block_separator(lines, SessionOutputSeparator::AfterPreviousContent);
+    lines.extend(activity_lines);
 }
 
 fn append_queued_entries(
@@ -1030,6 +1084,63 @@ mod tests {
         assert_eq!(output.transient_loader_line_index, None);
     }
 
+    #[test]
+    fn test_active_turn_folds_activity_rows_into_one_toggleable_log() {
+        // Arrange
+        let activity = |position, activity: TurnActivity| {
+            SessionMessage::new(
+                position,
+                SessionMessageKind::TurnActivity,
+                activity.encode(),
+            )
+        };
+        let mut session = crate::test_support::SessionFixtureBuilder::new()
+            .status(Status::InProgress)
+            .build();
+        session.transcript = Some(SessionTranscript::new(vec![
+            SessionMessage::conversation(0, SessionMessageKind::UserPrompt, "fix the tests"),
+            activity(
+                1,
+                TurnActivity::ToolStarted {
+                    call_id: Some("call-1".to_string()),
+                    command: Some("cargo test".to_string()),
+                    tool: "command".to_string(),
+                },
+            ),
+            activity(
+                2,
+                TurnActivity::WebFetch {
+                    target: "https://docs.rs".to_string(),
+                },
+            ),
+        ]));
+
+        // Act
+        let collapsed_text = output_lines(&session, 80, None, None)
+            .lines
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        session.activity_log_expanded = true;
+        let expanded_text = output_lines(&session, 80, None, None)
+            .lines
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert!(collapsed_text.contains(&"▸ Activity · 1 tool call · 1 fetch".to_string()));
+        assert!(collapsed_text.contains(&"  ↗ https://docs.rs".to_string()));
+        assert!(!collapsed_text.contains(&"  · cargo test".to_string()));
+        assert!(expanded_text.contains(&"▾ Activity · 1 tool call · 1 fetch".to_string()));
+        assert!(expanded_text.contains(&"  · cargo test".to_string()));
+        assert!(
+            !expanded_text
+                .iter()
+                .any(|line| line.contains("tool_started"))
+        );
+    }
+
     #[test]
     fn test_review_comment_resolution_loader_ignores_non_loading_body() {
         // Arrange
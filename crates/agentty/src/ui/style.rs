@@ -1,31 +1,49 @@
 //! Theme-aware semantic color helpers for Agentty's terminal UI.
 
-use std::sync::atomic::{AtomicU8, Ordering};
-use std::sync::{Mutex, MutexGuard};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::atomic::{AtomicU8, AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError, RwLock};
 
+use ag_forge::ReviewCheckOutcome;
 use ag_tui_text::{TextPalette, TextRenderSettings};
 use ratatui::style::{Color, Style};
 
 use super::icon::Icon;
 use crate::domain::session::{ReviewRequestState, Status};
-use crate::domain::theme::ColorTheme;
+use crate::domain::theme::{ColorTheme, CustomTheme, ThemeColor, ThemeColorKey};
 
-static ACTIVE_THEME: AtomicU8 = AtomicU8::new(theme_index(ColorTheme::Current));
+/// Active-theme atom value that selects [`CUSTOM_PALETTE`].
+const CUSTOM_THEME_INDEX: u8 = 3;
+/// Cache-version bit that separates user palettes from built-in theme indexes.
+const CUSTOM_THEME_CACHE_VERSION_BIT: u64 = 1 << 63;
+
+static ACTIVE_THEME: AtomicU8 = AtomicU8::new(theme_index(&ColorTheme::Current));
 static ACTIVE_THEME_SCOPE_LOCK: Mutex<()> = Mutex::new(());
+static CUSTOM_PALETTE: RwLock<ThemePalette> = RwLock::new(CURRENT_PALETTE);
+static CUSTOM_PALETTE_FINGERPRINT: AtomicU64 = AtomicU64::new(0);
 
 /// Scoped guard that keeps tests and temporary render checks serialized while
 /// they override the process-wide active color theme.
 pub(crate) struct ActiveThemeScope {
-    previous_theme: ColorTheme,
+    previous_state: ActiveThemeState,
     _lock_guard: MutexGuard<'static, ()>,
 }
 
 impl Drop for ActiveThemeScope {
     fn drop(&mut self) {
-        set_active_theme_unlocked(self.previous_theme);
+        set_active_theme_state_unlocked(self.previous_state);
     }
 }
 
+/// Snapshot of the process-wide theme storage restored by
+/// [`ActiveThemeScope`].
+#[derive(Clone, Copy)]
+struct ActiveThemeState {
+    custom_palette: ThemePalette,
+    custom_palette_fingerprint: u64,
+    theme_index: u8,
+}
+
 /// Shared semantic color tokens for the terminal UI.
 pub mod palette {
     use ratatui::style::Color;
@@ -169,6 +187,18 @@ pub mod palette {
         token_color(|palette| palette.warning_soft)
     }
 
+    /// Activity heatmap cell color for one intensity level from `0` to `4`.
+    #[must_use]
+    pub fn heatmap(intensity: u8) -> Color {
+        token_color(|palette| match intensity {
+            0 => palette.heatmap_empty,
+            1 => palette.heatmap_level_1,
+            2 => palette.heatmap_level_2,
+            3 => palette.heatmap_level_3,
+            _ => palette.heatmap_level_4,
+        })
+    }
+
     /// Returns the active theme's complete palette for tests and diagnostics.
     #[must_use]
     pub fn active() -> super::ThemePalette {
@@ -177,7 +207,7 @@ pub mod palette {
 }
 
 /// Complete set of semantic colors resolved for one UI theme.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct ThemePalette {
     /// Primary accent color used for focused UI elements and titles.
     pub accent: Color,
@@ -223,6 +253,16 @@ pub struct ThemePalette {
     pub warning: Color,
     /// Softer warning tone used for graded severity scales.
     pub warning_soft: Color,
+    /// Activity heatmap cell without activity.
+    pub heatmap_empty: Color,
+    /// Activity heatmap lowest intensity.
+    pub heatmap_level_1: Color,
+    /// Activity heatmap second intensity.
+    pub heatmap_level_2: Color,
+    /// Activity heatmap third intensity.
+    pub heatmap_level_3: Color,
+    /// Activity heatmap highest intensity.
+    pub heatmap_level_4: Color,
 }
 
 const CURRENT_PALETTE: ThemePalette = ThemePalette {
@@ -248,6 +288,11 @@ const CURRENT_PALETTE: ThemePalette = ThemePalette {
     success_soft: Color::LightGreen,
     warning: Color::Yellow,
     warning_soft: Color::LightYellow,
+    heatmap_empty: Color::Rgb(33, 38, 45),
+    heatmap_level_1: Color::Rgb(14, 68, 41),
+    heatmap_level_2: Color::Rgb(0, 109, 50),
+    heatmap_level_3: Color::Rgb(38, 166, 65),
+    heatmap_level_4: Color::Rgb(57, 211, 83),
 };
 
 /// Green phosphor terminal palette with restrained surfaces and muted status
@@ -275,6 +320,11 @@ const GREEN_PALETTE: ThemePalette = ThemePalette {
     success_soft: Color::Rgb(68, 145, 80),
     warning: Color::Rgb(181, 169, 99),
     warning_soft: Color::Rgb(198, 187, 116),
+    heatmap_empty: Color::Rgb(33, 38, 45),
+    heatmap_level_1: Color::Rgb(14, 68, 41),
+    heatmap_level_2: Color::Rgb(0, 109, 50),
+    heatmap_level_3: Color::Rgb(38, 166, 65),
+    heatmap_level_4: Color::Rgb(57, 211, 83),
 };
 
 /// Cool dark palette inspired by the Horizon editor theme: deep navy surfaces,
@@ -310,13 +360,21 @@ const DARK_HORIZON_PALETTE: ThemePalette = ThemePalette {
     success_soft: Color::Rgb(126, 219, 188),
     warning: Color::Rgb(250, 194, 154),
     warning_soft: Color::Rgb(252, 215, 188),
+    heatmap_empty: Color::Rgb(33, 38, 45),
+    heatmap_level_1: Color::Rgb(14, 68, 41),
+    heatmap_level_2: Color::Rgb(0, 109, 50),
+    heatmap_level_3: Color::Rgb(38, 166, 65),
+    heatmap_level_4: Color::Rgb(57, 211, 83),
 };
 
 /// Sets the process-wide active color theme used by semantic palette tokens.
-pub fn set_active_theme(theme: ColorTheme) {
+///
+/// User themes are applied through [`scoped_active_custom_theme`]; passing
+/// [`ColorTheme::Custom`] here selects the default palette.
+pub fn set_active_theme(theme: &ColorTheme) {
     let _lock_guard = ACTIVE_THEME_SCOPE_LOCK
         .lock()
-        .unwrap_or_else(std::sync::PoisonError::into_inner);
+        .unwrap_or_else(PoisonError::into_inner);
     set_active_theme_unlocked(theme);
 }
 
@@ -324,21 +382,57 @@ pub fn set_active_theme(theme: ColorTheme) {
 /// dropped.
 ///
 /// The guard serializes callers that need deterministic palette reads while
-/// holding a temporary theme override.
+/// holding a temporary theme override. [`ColorTheme::Custom`] without a loaded
+/// theme file falls back to the default palette.
 #[must_use]
-pub(crate) fn scoped_active_theme(theme: ColorTheme) -> ActiveThemeScope {
+pub(crate) fn scoped_active_theme(theme: &ColorTheme) -> ActiveThemeScope {
     let lock_guard = ACTIVE_THEME_SCOPE_LOCK
         .lock()
-        .unwrap_or_else(std::sync::PoisonError::into_inner);
-    let previous_theme = active_theme();
+        .unwrap_or_else(PoisonError::into_inner);
+    let previous_state = active_theme_state();
     set_active_theme_unlocked(theme);
 
     ActiveThemeScope {
-        previous_theme,
+        previous_state,
         _lock_guard: lock_guard,
     }
 }
 
+/// Temporarily applies one user theme until the returned guard is dropped.
+///
+/// Colors the theme file leaves out keep their `Agentty Default` values.
+#[must_use]
+pub(crate) fn scoped_active_custom_theme(theme: &CustomTheme) -> ActiveThemeScope {
+    let lock_guard = ACTIVE_THEME_SCOPE_LOCK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let previous_state = active_theme_state();
+    let custom_palette = custom_theme_palette(theme);
+    let mut hasher = DefaultHasher::new();
+    custom_palette.hash(&mut hasher);
+    set_active_theme_state_unlocked(ActiveThemeState {
+        custom_palette,
+        custom_palette_fingerprint: hasher.finish(),
+        theme_index: CUSTOM_THEME_INDEX,
+    });
+
+    ActiveThemeScope {
+        previous_state,
+        _lock_guard: lock_guard,
+    }
+}
+
+/// Resolves a user theme onto the `Agentty Default` palette.
+#[must_use]
+pub(crate) fn custom_theme_palette(theme: &CustomTheme) -> ThemePalette {
+    let mut palette = CURRENT_PALETTE;
+    for (key, color) in &theme.colors {
+        *palette_color_mut(&mut palette, *key) = terminal_color(*color);
+    }
+
+    palette
+}
+
 /// Returns the common border style for framed panels and table widgets.
 #[must_use]
 pub fn border_style() -> Style {
@@ -348,7 +442,12 @@ pub fn border_style() -> Style {
 /// Returns a stable cache discriminator for the active color theme.
 #[must_use]
 pub(crate) fn active_theme_cache_version() -> u64 {
-    u64::from(ACTIVE_THEME.load(Ordering::Relaxed))
+    let theme_index = ACTIVE_THEME.load(Ordering::Relaxed);
+    if theme_index == CUSTOM_THEME_INDEX {
+        return CUSTOM_THEME_CACHE_VERSION_BIT | CUSTOM_PALETTE_FINGERPRINT.load(Ordering::Relaxed);
+    }
+
+    u64::from(theme_index)
 }
 
 /// Returns the active theme settings for shared text-rendering crates.
@@ -417,31 +516,120 @@ pub fn forge_indicator_color(state: Option<ReviewRequestState>) -> Color {
     }
 }
 
-/// Returns the complete color palette for the active theme.
+/// Returns the color for the aggregate CI check outcome badge.
 #[must_use]
-fn active_palette() -> ThemePalette {
-    match active_theme() {
-        ColorTheme::Green => GREEN_PALETTE,
-        ColorTheme::DarkHorizon => DARK_HORIZON_PALETTE,
-        ColorTheme::Current => CURRENT_PALETTE,
+pub fn review_check_color(outcome: ReviewCheckOutcome) -> Color {
+    match outcome {
+        ReviewCheckOutcome::Pending => palette::warning(),
+        ReviewCheckOutcome::Passed => palette::success(),
+        ReviewCheckOutcome::Failed => palette::danger(),
     }
 }
 
-/// Returns the active theme represented by the process-wide theme atom.
+/// Returns the complete color palette for the active theme.
 #[must_use]
-fn active_theme() -> ColorTheme {
+fn active_palette() -> ThemePalette {
     match ACTIVE_THEME.load(Ordering::Relaxed) {
-        1 => ColorTheme::Green,
-        2 => ColorTheme::DarkHorizon,
-        _ => ColorTheme::Current,
+        1 => GREEN_PALETTE,
+        2 => DARK_HORIZON_PALETTE,
+        CUSTOM_THEME_INDEX => *CUSTOM_PALETTE
+            .read()
+            .unwrap_or_else(PoisonError::into_inner),
+        _ => CURRENT_PALETTE,
+    }
+}
+
+/// Returns the process-wide theme storage for later restoration.
+fn active_theme_state() -> ActiveThemeState {
+    ActiveThemeState {
+        custom_palette: *CUSTOM_PALETTE
+            .read()
+            .unwrap_or_else(PoisonError::into_inner),
+        custom_palette_fingerprint: CUSTOM_PALETTE_FINGERPRINT.load(Ordering::Relaxed),
+        theme_index: ACTIVE_THEME.load(Ordering::Relaxed),
     }
 }
 
 /// Stores a theme value after callers have already handled serialization.
-fn set_active_theme_unlocked(theme: ColorTheme) {
+fn set_active_theme_unlocked(theme: &ColorTheme) {
     ACTIVE_THEME.store(theme_index(theme), Ordering::Relaxed);
 }
 
+/// Stores complete theme storage after callers have already handled
+/// serialization.
+fn set_active_theme_state_unlocked(state: ActiveThemeState) {
+    *CUSTOM_PALETTE
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = state.custom_palette;
+    CUSTOM_PALETTE_FINGERPRINT.store(state.custom_palette_fingerprint, Ordering::Relaxed);
+    ACTIVE_THEME.store(state.theme_index, Ordering::Relaxed);
+}
+
+/// Returns the palette slot assigned by one theme-file color key.
+fn palette_color_mut(palette: &mut ThemePalette, key: ThemeColorKey) -> &mut Color {
+    match key {
+        ThemeColorKey::Accent => &mut palette.accent,
+        ThemeColorKey::AccentSoft => &mut palette.accent_soft,
+        ThemeColorKey::Border => &mut palette.border,
+        ThemeColorKey::Danger => &mut palette.danger,
+        ThemeColorKey::DangerSoft => &mut palette.danger_soft,
+        ThemeColorKey::Info => &mut palette.info,
+        ThemeColorKey::Question => &mut palette.question,
+        ThemeColorKey::Surface => &mut palette.surface,
+        ThemeColorKey::SurfaceClarification => &mut palette.surface_clarification,
+        ThemeColorKey::SurfaceDanger => &mut palette.surface_danger,
+        ThemeColorKey::SurfaceElevated => &mut palette.surface_elevated,
+        ThemeColorKey::SurfaceSelection => &mut palette.surface_selection,
+        ThemeColorKey::SurfaceSuccess => &mut palette.surface_success,
+        ThemeColorKey::SurfaceOverlay => &mut palette.surface_overlay,
+        ThemeColorKey::SurfacePrompt => &mut palette.surface_prompt,
+        ThemeColorKey::Text => &mut palette.text,
+        ThemeColorKey::TextMuted => &mut palette.text_muted,
+        ThemeColorKey::TextSubtle => &mut palette.text_subtle,
+        ThemeColorKey::Success => &mut palette.success,
+        ThemeColorKey::SuccessSoft => &mut palette.success_soft,
+        ThemeColorKey::Warning => &mut palette.warning,
+        ThemeColorKey::WarningSoft => &mut palette.warning_soft,
+        ThemeColorKey::HeatmapEmpty => &mut palette.heatmap_empty,
+        ThemeColorKey::HeatmapLevel1 => &mut palette.heatmap_level_1,
+        ThemeColorKey::HeatmapLevel2 => &mut palette.heatmap_level_2,
+        ThemeColorKey::HeatmapLevel3 => &mut palette.heatmap_level_3,
+        ThemeColorKey::HeatmapLevel4 => &mut palette.heatmap_level_4,
+    }
+}
+
+/// Converts one theme-file color into a terminal color, keeping the standard
+/// ANSI indexes as named colors so terminal palettes still apply.
+fn terminal_color(color: ThemeColor) -> Color {
+    const ANSI_COLORS: [Color; 16] = [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::Gray,
+        Color::DarkGray,
+        Color::LightRed,
+        Color::LightGreen,
+        Color::LightYellow,
+        Color::LightBlue,
+        Color::LightMagenta,
+        Color::LightCyan,
+        Color::White,
+    ];
+
+    match color {
+        ThemeColor::Default => Color::Reset,
+        ThemeColor::Indexed(index) => ANSI_COLORS
+            .get(usize::from(index))
+            .copied()
+            .unwrap_or(Color::Indexed(index)),
+        ThemeColor::Rgb(red, green, blue) => Color::Rgb(red, green, blue),
+    }
+}
+
 /// Applies one semantic color selector to the active palette.
 #[must_use]
 fn token_color(selector: impl FnOnce(ThemePalette) -> Color) -> Color {
@@ -449,9 +637,9 @@ fn token_color(selector: impl FnOnce(ThemePalette) -> Color) -> Color {
 }
 
 /// Returns the stable numeric storage for one theme in the active-theme atom.
-const fn theme_index(theme: ColorTheme) -> u8 {
+const fn theme_index(theme: &ColorTheme) -> u8 {
     match theme {
-        ColorTheme::Current => 0,
+        ColorTheme::Current | ColorTheme::Custom(_) => 0,
         ColorTheme::Green => 1,
         ColorTheme::DarkHorizon => 2,
     }
@@ -459,12 +647,47 @@ const fn theme_index(theme: ColorTheme) -> u8 {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use super::*;
 
+    #[test]
+    fn scoped_active_custom_theme_overrides_assigned_tokens_only() {
+        // Arrange
+        let theme = CustomTheme {
+            colors: BTreeMap::from([
+                (ThemeColorKey::Accent, ThemeColor::Rgb(1, 2, 3)),
+                (ThemeColorKey::HeatmapLevel4, ThemeColor::Indexed(12)),
+                (ThemeColorKey::Text, ThemeColor::Default),
+            ]),
+            id: "paper".to_string(),
+            name: "Paper".to_string(),
+        };
+        let builtin_cache_version = {
+            let _theme_scope = scoped_active_theme(&ColorTheme::Current);
+
+            active_theme_cache_version()
+        };
+
+        // Act
+        let (custom_palette, custom_cache_version) = {
+            let _theme_scope = scoped_active_custom_theme(&theme);
+
+            (palette::active(), active_theme_cache_version())
+        };
+
+        // Assert
+        assert_eq!(custom_palette.accent, Color::Rgb(1, 2, 3));
+        assert_eq!(custom_palette.heatmap_level_4, Color::LightBlue);
+        assert_eq!(custom_palette.text, Color::Reset);
+        assert_eq!(custom_palette.border, CURRENT_PALETTE.border);
+        assert_ne!(custom_cache_version, builtin_cache_version);
+    }
+
     #[test]
     fn status_color_returns_muted_text_for_new() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Current);
 
         // Act
         let color = status_color(Status::Draft);
@@ -476,7 +699,7 @@ mod tests {
     #[test]
     fn status_color_returns_success_for_merged_and_done() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Current);
 
         // Act
         let merged_color = status_color(Status::Merged);
@@ -490,7 +713,7 @@ mod tests {
     #[test]
     fn status_color_returns_danger_for_canceled() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Current);
 
         // Act
         let color = status_color(Status::Canceled);
@@ -502,7 +725,7 @@ mod tests {
     #[test]
     fn status_color_returns_warning_for_in_progress() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Current);
 
         // Act
         let color = status_color(Status::InProgress);
@@ -514,7 +737,7 @@ mod tests {
     #[test]
     fn status_color_returns_info_for_review() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Current);
 
         // Act
         let color = status_color(Status::Review);
@@ -526,7 +749,7 @@ mod tests {
     #[test]
     fn status_color_returns_accent_for_merging() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Current);
 
         // Act
         let color = status_color(Status::Merging);
@@ -538,7 +761,7 @@ mod tests {
     #[test]
     fn status_color_uses_green_palette_when_active() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Green);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Green);
 
         // Act
         let color = status_color(Status::Merging);
@@ -550,7 +773,7 @@ mod tests {
     #[test]
     fn active_palette_returns_current_table_contrast_tones() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Current);
 
         // Act
         let palette = palette::active();
@@ -566,7 +789,7 @@ mod tests {
     #[test]
     fn active_palette_returns_green_terminal_tones() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Green);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Green);
 
         // Act
         let palette = palette::active();
@@ -583,7 +806,7 @@ mod tests {
     #[test]
     fn active_palette_returns_muted_green_status_tones() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Green);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Green);
 
         // Act
         let palette = palette::active();
@@ -598,7 +821,7 @@ mod tests {
     #[test]
     fn status_color_uses_dark_horizon_palette_when_active() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::DarkHorizon);
+        let _theme_scope = scoped_active_theme(&ColorTheme::DarkHorizon);
 
         // Act
         let color = status_color(Status::Merging);
@@ -610,7 +833,7 @@ mod tests {
     #[test]
     fn active_palette_returns_dark_horizon_navy_tones() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::DarkHorizon);
+        let _theme_scope = scoped_active_theme(&ColorTheme::DarkHorizon);
 
         // Act
         let palette = palette::active();
@@ -628,7 +851,7 @@ mod tests {
     #[test]
     fn active_palette_returns_dark_horizon_status_tones() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::DarkHorizon);
+        let _theme_scope = scoped_active_theme(&ColorTheme::DarkHorizon);
 
         // Act
         let palette = palette::active();
@@ -643,7 +866,7 @@ mod tests {
     #[test]
     fn dark_horizon_selection_surface_is_lighter_than_base_surface() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::DarkHorizon);
+        let _theme_scope = scoped_active_theme(&ColorTheme::DarkHorizon);
 
         // Act
         let selection = palette::surface_selection();
@@ -674,7 +897,7 @@ mod tests {
     #[test]
     fn dark_horizon_accent_and_danger_are_distinct() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::DarkHorizon);
+        let _theme_scope = scoped_active_theme(&ColorTheme::DarkHorizon);
 
         // Act
         let accent = palette::accent();
@@ -691,7 +914,7 @@ mod tests {
     #[test]
     fn dark_horizon_accent_soft_and_question_are_distinct() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::DarkHorizon);
+        let _theme_scope = scoped_active_theme(&ColorTheme::DarkHorizon);
 
         // Act
         let accent_soft = palette::accent_soft();
@@ -708,7 +931,7 @@ mod tests {
     #[test]
     fn border_style_uses_active_palette_border_color() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Green);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Green);
 
         // Act
         let style = border_style();
@@ -749,7 +972,7 @@ mod tests {
     #[test]
     fn forge_indicator_color_returns_warning_for_open() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Current);
 
         // Act
         let color = forge_indicator_color(Some(ReviewRequestState::Open));
@@ -761,7 +984,7 @@ mod tests {
     #[test]
     fn forge_indicator_color_returns_success_for_merged() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Current);
 
         // Act
         let color = forge_indicator_color(Some(ReviewRequestState::Merged));
@@ -773,7 +996,7 @@ mod tests {
     #[test]
     fn forge_indicator_color_returns_danger_for_closed() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Current);
 
         // Act
         let color = forge_indicator_color(Some(ReviewRequestState::Closed));
@@ -785,7 +1008,7 @@ mod tests {
     #[test]
     fn forge_indicator_color_returns_accent_soft_for_published_only() {
         // Arrange
-        let _theme_scope = scoped_active_theme(ColorTheme::Current);
+        let _theme_scope = scoped_active_theme(&ColorTheme::Current);
 
         // Act
         let color = forge_indicator_color(None);
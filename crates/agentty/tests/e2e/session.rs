@@ -6046,6 +6046,7 @@ fn stacked_session_creation() -> E2eResult {
                     .press_key("Down")
                     .press_key("Down")
                     .press_key("Down")
+                    .press_key("Down")
                     .wait_for_text("[Preview] Stack on selected", 5000)
                     .capture_labeled("stacked_selector", "Stacked creation selector")
                     .press_key("Enter")
@@ -6234,6 +6235,7 @@ fn stacked_session_start_waits_for_parent_review() -> E2eResult {
                     .press_key("Down")
                     .press_key("Down")
                     .press_key("Down")
+                    .press_key("Down")
                     .wait_for_text("[Preview] Stack on selected", 5000)
                     .press_key("Enter")
                     .wait_for_text("Enter: stage draft", 5000)
@@ -7086,7 +7088,8 @@ fn review_request_publish_shortcut_opens_publish_popup() -> E2eResult {
             |frame, _report| {
                 let full = Region::full(frame.cols(), frame.rows());
                 assertion::assert_text_in_region(frame, "Publish Review Request", &full);
-                assertion::assert_text_in_region(frame, "Enter: publish review request", &full);
+                assertion::assert_text_in_region(frame, "Enter: publish", &full);
+                assertion::assert_text_in_region(frame, "[x] Open as draft", &full);
                 assertion::assert_text_in_region(
                     frame,
                     "Leave blank to push as `wt/review-s`",
@@ -132,6 +132,42 @@ async fn claude_sonnet_protocol_compliance_e2e() {
     }
 }
 
+/// Verifies a real harness turn (`qwen-plus`) through
+/// `create_agent_channel()` yields a non-empty protocol `answer`.
+#[tokio::test]
+#[ignore = "requires real harness provider credentials and network"]
+async fn harness_protocol_compliance_e2e() {
+    // Arrange
+    let model = AgentModel::QwenPlus;
+    if provider_preflight_skip_reason(AgentKind::Harness)
+        .await
+        .is_some()
+    {
+        return;
+    }
+
+    // Act
+    let result = assert_provider_protocol_compliance(AgentKind::Harness, model).await;
+
+    // Assert
+    if let Err(error) = result {
+        if is_skippable_provider_environment_failure(&error) {
+            return;
+        }
+        assert!(error.is_empty(), "{error}");
+    }
+}
+
+/// Returns a skip reason when the harness `qwen-plus` API key is unset.
+fn harness_preflight_skip_reason() -> Option<String> {
+    let api_key = std::env::var("DASHSCOPE_API_KEY").unwrap_or_default();
+    if api_key.trim().is_empty() {
+        return Some("Harness preflight skipped: `DASHSCOPE_API_KEY` is not set".to_string());
+    }
+
+    None
+}
+
 /// Returns a skip reason when the provider CLI is unavailable or unhealthy.
 async fn provider_preflight_skip_reason(kind: AgentKind) -> Option<String> {
     let (provider_name, executable_name) = match kind {
@@ -139,6 +175,7 @@ async fn provider_preflight_skip_reason(kind: AgentKind) -> Option<String> {
         AgentKind::Codex => ("Codex", "codex"),
         AgentKind::Gemini => ("Gemini", "gemini"),
         AgentKind::Claude => ("Claude", "claude"),
+        AgentKind::Harness => return harness_preflight_skip_reason(),
     };
     let mut command = tokio::process::Command::new(executable_name);
     command.arg("--version");
@@ -277,6 +314,7 @@ fn build_turn_request(folder: PathBuf, model: AgentModel) -> TurnRequest {
         reasoning_level: ReasoningLevel::default(),
         request_kind: AgentRequestKind::SessionStart,
         speed_mode: ag_agent::SpeedMode::default(),
+        tool_approval: None,
     }
 }
 